@@ -0,0 +1,152 @@
+//! Rehearses the account layout migrations of a restaking program upgrade against a snapshot of
+//! its accounts, before the upgrade ships.
+//!
+//! The snapshot is fetched from `--url`, or read from `--snapshot` to rehearse the same state
+//! repeatedly. Every account is migrated off-chain and checked against the current layout of its
+//! type, and the accounts to migrate are grouped into transaction batches written to `--output`,
+//! which `restaking-cli migrate` sends as is. Exits with an error if any account can't be
+//! migrated, since the upgraded program couldn't load it. Rent is computed with the default rent
+//! parameters, those of every public cluster.
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufReader, BufWriter},
+};
+
+use clap::{crate_version, value_t_or_exit, App, Arg};
+use jito_restaking_client::{
+    failover::{EndpointConfig, FailoverRpcClient},
+    migration::{rehearse, write_batches, Snapshot},
+};
+use solana_clap_utils::input_validators::{
+    is_parsable, is_pubkey, is_url_or_moniker, normalize_to_url_if_moniker,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, rent::Rent};
+
+/// The restaking program id declared by the program
+const RESTAKING_PROGRAM_ID: &str = "E5YF9Um1mwQWHffqaUEUwtwnhQKsbMEt33qtvjto3NDZ";
+
+fn app<'a, 'b>() -> App<'a, 'b> {
+    App::new("migration-rehearsal")
+        .about("Rehearses the restaking account layout migrations against a snapshot")
+        .version(crate_version!())
+        .arg(
+            Arg::with_name("url")
+                .long("url")
+                .short("u")
+                .value_name("URL_OR_MONIKER")
+                .takes_value(true)
+                .default_value("localhost")
+                .validator(is_url_or_moniker)
+                .help("JSON RPC URL the snapshot is fetched from"),
+        )
+        .arg(
+            Arg::with_name("restaking_program")
+                .long("restaking-program")
+                .value_name("PUBKEY")
+                .takes_value(true)
+                .default_value(RESTAKING_PROGRAM_ID)
+                .validator(is_pubkey)
+                .help("The restaking program id"),
+        )
+        .arg(
+            Arg::with_name("snapshot")
+                .long("snapshot")
+                .value_name("FILE")
+                .takes_value(true)
+                .help("Reads the snapshot from the file instead of fetching it"),
+        )
+        .arg(
+            Arg::with_name("save_snapshot")
+                .long("save-snapshot")
+                .value_name("FILE")
+                .takes_value(true)
+                .conflicts_with("snapshot")
+                .help("Writes the fetched snapshot to the file"),
+        )
+        .arg(
+            Arg::with_name("batch_size")
+                .long("batch-size")
+                .value_name("ACCOUNTS")
+                .takes_value(true)
+                .default_value("10")
+                .validator(is_parsable::<usize>)
+                .help("The max number of accounts migrated per transaction"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .short("o")
+                .value_name("FILE")
+                .takes_value(true)
+                .help("Writes the transaction batches to the file"),
+        )
+}
+
+async fn run() -> Result<(), Box<dyn Error>> {
+    let matches = app().get_matches();
+    let program_id = value_t_or_exit!(matches, "restaking_program", Pubkey);
+
+    let snapshot = if let Some(path) = matches.value_of("snapshot") {
+        Snapshot::read(BufReader::new(File::open(path)?))?
+    } else {
+        let url = normalize_to_url_if_moniker(value_t_or_exit!(matches, "url", String));
+        let rpc = FailoverRpcClient::new(
+            vec![EndpointConfig::new(url)],
+            CommitmentConfig::finalized(),
+        );
+        let snapshot = Snapshot::fetch(&rpc, &program_id).await?;
+        if let Some(path) = matches.value_of("save_snapshot") {
+            snapshot.write(BufWriter::new(File::create(path)?))?;
+        }
+        snapshot
+    };
+
+    let report = rehearse(&snapshot, &Rent::default());
+    for planned in &report.planned {
+        let fields = if planned.fields.is_empty() {
+            "no fields".to_string()
+        } else {
+            planned.fields.join(", ")
+        };
+        println!(
+            "{} {:?}: {} -> {} bytes, adds {}, rent top-up {} lamports",
+            planned.address,
+            planned.account_type,
+            planned.from_len,
+            planned.to_len,
+            fields,
+            planned.rent_top_up
+        );
+    }
+    for failure in &report.failures {
+        println!("{} FAILED: {}", failure.address, failure.reason);
+    }
+
+    let batches = report.batches(value_t_or_exit!(matches, "batch_size", usize));
+    println!(
+        "{} accounts: {} up to date, {} to migrate in {} transactions for {} lamports of rent, {} failed",
+        snapshot.accounts.len(),
+        report.up_to_date,
+        report.planned.len(),
+        batches.len(),
+        report.total_rent_top_up(),
+        report.failures.len()
+    );
+    if let Some(path) = matches.value_of("output") {
+        write_batches(BufWriter::new(File::create(path)?), &batches)?;
+    }
+
+    if !report.is_ok() {
+        return Err("some accounts can't be migrated".into());
+    }
+    Ok(())
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    if let Err(e) = run().await {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
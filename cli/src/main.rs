@@ -0,0 +1,368 @@
+//! Command line interface for the restaking and vault programs.
+//!
+//! Transactions are paid for and signed by `--keypair`, which also acts as the admin, operator
+//! admin or slasher the command needs. The keypair is resolved the way the Solana CLI resolves
+//! it: a keypair file, `prompt://`, or `usb://ledger` when `solana-remote-wallet` is built with
+//! hidapi. A key held by a remote signer is named by its URI instead: `vault://<address>/<key_name>`
+//! for a HashiCorp Vault transit key with the token in `VAULT_TOKEN`, `awskms://<region>/<key_id>`
+//! for an AWS KMS key with the credentials in `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`, or
+//! `gcpkms://<key_version>` for a Google Cloud KMS key version with the token in
+//! `GOOGLE_OAUTH_ACCESS_TOKEN`, see [`jito_restaking_signer`]. With `--dry-run`, transactions are
+//! simulated instead of sent and any failure is diagnosed.
+
+// Signers loaded by solana-clap-utils aren't `Sync`, so commands run on a single thread
+#![allow(clippy::future_not_send)]
+
+mod migration;
+mod restaking;
+mod vault;
+
+use std::{error::Error, fmt::Debug, rc::Rc};
+
+use clap::{crate_description, crate_version, value_t_or_exit, App, AppSettings, Arg, ArgMatches};
+use jito_restaking_client::{
+    error::decode_error,
+    failover::{EndpointConfig, FailoverRpcClient},
+    preflight::{preflight, ProgramIds},
+    restaking::RestakingClient,
+};
+use jito_restaking_signer::RemoteSignerConfig;
+use solana_clap_utils::{
+    input_validators::{
+        is_parsable, is_pubkey, is_url_or_moniker, is_valid_signer, normalize_to_url_if_moniker,
+    },
+    keypair::signer_from_path,
+};
+use solana_remote_wallet::remote_wallet::RemoteWalletManager;
+use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+    signer::null_signer::NullSigner,
+    transaction::TransactionError,
+};
+
+/// The program ids declared by the restaking and vault programs
+const RESTAKING_PROGRAM_ID: &str = "E5YF9Um1mwQWHffqaUEUwtwnhQKsbMEt33qtvjto3NDZ";
+const VAULT_PROGRAM_ID: &str = "DVoKuzt4i8EAakix852XwSAYmXnECdhegB6EDtabp4dg";
+
+pub type CliResult<T = ()> = Result<T, Box<dyn Error>>;
+
+pub struct CliContext {
+    pub client: RestakingClient,
+    pub signer: Box<dyn Signer>,
+    /// The AVS multisig signers added to AVS admin and slasher changes
+    pub multisig_signers: Vec<Box<dyn Signer>>,
+    pub dry_run: bool,
+}
+
+impl CliContext {
+    pub const fn restaking_program(&self) -> Pubkey {
+        self.client.program_ids().restaking_program
+    }
+
+    pub const fn vault_program(&self) -> Pubkey {
+        self.client.program_ids().vault_program
+    }
+
+    pub fn signer_pubkey(&self) -> Pubkey {
+        self.signer.pubkey()
+    }
+
+    /// Sends the instructions, or simulates them with `--dry-run`
+    pub async fn process(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+    ) -> CliResult {
+        let rpc = self.client.rpc();
+        let blockhash = rpc.get_latest_blockhash().await?;
+        let transaction =
+            self.client
+                .transaction(instructions, self.signer.as_ref(), signers, blockhash)?;
+
+        if self.dry_run {
+            let report = preflight(rpc, &transaction, self.client.program_ids()).await?;
+            for log in &report.logs {
+                println!("{log}");
+            }
+            if let Some(units_consumed) = report.units_consumed {
+                println!("Compute units consumed: {units_consumed}");
+            }
+            if !report.is_ok() {
+                for diagnostic in &report.diagnostics {
+                    println!("{diagnostic:?}");
+                }
+                return Err("simulation failed".into());
+            }
+            println!("Simulation succeeded");
+            return Ok(());
+        }
+
+        let signature = rpc
+            .send_and_confirm_transaction(&transaction)
+            .await
+            .map_err(describe_error)?;
+        println!("Signature: {signature}");
+        Ok(())
+    }
+
+    /// Sends the AVS instruction with the multisig signers appended, see
+    /// [`jito_restaking_sdk::with_avs_multisig_signers`]
+    pub async fn process_with_multisig(&self, instruction: Instruction) -> CliResult {
+        let keys: Vec<Pubkey> = self.multisig_signers.iter().map(|s| s.pubkey()).collect();
+        let signers: Vec<&dyn Signer> = self.multisig_signers.iter().map(|s| s.as_ref()).collect();
+        self.process(
+            &[jito_restaking_sdk::with_avs_multisig_signers(
+                instruction,
+                &keys,
+            )],
+            &signers,
+        )
+        .await
+    }
+}
+
+/// Turns a custom program error into the name of the error and the crate that raised it
+fn describe_error(error: ClientError) -> Box<dyn Error> {
+    if let Some(TransactionError::InstructionError(index, InstructionError::Custom(code))) =
+        error.get_transaction_error()
+    {
+        if let Some(decoded) = decode_error(code) {
+            return format!(
+                "instruction {index} failed with {} ({:?} error {code})",
+                decoded.name, decoded.source
+            )
+            .into();
+        }
+    }
+    error.into()
+}
+
+/// Pretty-prints an account, leaving out its reserved space
+pub fn print_account<T: Debug>(address: &Pubkey, account: &T) {
+    println!("{address}");
+    let mut in_reserved = false;
+    for line in format!("{account:#?}").lines() {
+        let field = line.trim_start();
+        if in_reserved {
+            in_reserved = !field.starts_with(']');
+        } else if field.starts_with("reserved") {
+            in_reserved = field.ends_with('[');
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
+/// A required positional pubkey argument
+pub fn pubkey_arg<'a, 'b>(name: &'a str, help: &'b str) -> Arg<'a, 'b> {
+    Arg::with_name(name)
+        .required(true)
+        .validator(is_pubkey)
+        .help(help)
+}
+
+pub fn pubkey_of(matches: &ArgMatches<'_>, name: &str) -> Pubkey {
+    value_t_or_exit!(matches, name, Pubkey)
+}
+
+fn app(default_keypair: &str) -> App<'_, '_> {
+    App::new("restaking-cli")
+        .about(crate_description!())
+        .version(crate_version!())
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("url")
+                .long("url")
+                .short("u")
+                .global(true)
+                .value_name("URL_OR_MONIKER")
+                .takes_value(true)
+                .default_value("localhost")
+                .validator(is_url_or_moniker)
+                .help("JSON RPC URL, or one of mainnet-beta, testnet, devnet and localhost"),
+        )
+        .arg(
+            Arg::with_name("keypair")
+                .long("keypair")
+                .short("k")
+                .global(true)
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .default_value(default_keypair)
+                .help(
+                    "The signer and fee payer: a keypair file, prompt://, usb://ledger, \
+                     vault://<address>/<key_name>, awskms://<region>/<key_id> or \
+                     gcpkms://<key_version>",
+                ),
+        )
+        .arg(
+            Arg::with_name("commitment")
+                .long("commitment")
+                .global(true)
+                .takes_value(true)
+                .possible_values(&["processed", "confirmed", "finalized"])
+                .default_value("confirmed")
+                .help("The commitment accounts are read and transactions confirmed at"),
+        )
+        .arg(
+            Arg::with_name("restaking_program")
+                .long("restaking-program")
+                .global(true)
+                .value_name("PUBKEY")
+                .takes_value(true)
+                .default_value(RESTAKING_PROGRAM_ID)
+                .validator(is_pubkey)
+                .help("The restaking program id"),
+        )
+        .arg(
+            Arg::with_name("vault_program")
+                .long("vault-program")
+                .global(true)
+                .value_name("PUBKEY")
+                .takes_value(true)
+                .default_value(VAULT_PROGRAM_ID)
+                .validator(is_pubkey)
+                .help("The vault program id"),
+        )
+        .arg(
+            Arg::with_name("priority_fee")
+                .long("priority-fee")
+                .global(true)
+                .value_name("MICRO_LAMPORTS")
+                .takes_value(true)
+                .validator(is_parsable::<u64>)
+                .help("The compute unit price of every transaction"),
+        )
+        .arg(
+            Arg::with_name("compute_unit_limit")
+                .long("compute-unit-limit")
+                .global(true)
+                .value_name("UNITS")
+                .takes_value(true)
+                .validator(is_parsable::<u32>)
+                .help("The compute unit limit of every transaction"),
+        )
+        .arg(
+            Arg::with_name("multisig_signer")
+                .long("multisig-signer")
+                .global(true)
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .validator(is_valid_signer_or_remote)
+                .help("An AVS multisig signer, for AVS admin and slasher changes"),
+        )
+        .arg(
+            Arg::with_name("dry_run")
+                .long("dry-run")
+                .global(true)
+                .help("Simulates transactions instead of sending them"),
+        )
+        .subcommand(restaking::config_command())
+        .subcommand(restaking::avs_command())
+        .subcommand(restaking::operator_command())
+        .subcommand(vault::config_command())
+        .subcommand(vault::vault_command())
+        .subcommand(migration::migrate_command())
+}
+
+fn is_read_only(matches: &ArgMatches<'_>) -> bool {
+    matches
+        .subcommand()
+        .1
+        .and_then(ArgMatches::subcommand_name)
+        .is_some_and(|name| matches!(name, "show" | "tickets"))
+}
+
+fn is_valid_signer_or_remote(path: String) -> Result<(), String> {
+    if RemoteSignerConfig::parse(&path).is_some() {
+        return Ok(());
+    }
+    is_valid_signer(path)
+}
+
+/// Loads the signer at the path, a remote signer when the path is the URI of one and otherwise the
+/// way the Solana CLI does
+fn load_signer(
+    matches: &ArgMatches<'_>,
+    path: &str,
+    name: &str,
+    wallet_manager: &mut Option<Rc<RemoteWalletManager>>,
+) -> CliResult<Box<dyn Signer>> {
+    match RemoteSignerConfig::parse(path) {
+        Some(config) => Ok(config.signer()?),
+        None => signer_from_path(matches, path, name, wallet_manager),
+    }
+}
+
+fn context(matches: &ArgMatches<'_>) -> CliResult<CliContext> {
+    let url = normalize_to_url_if_moniker(value_t_or_exit!(matches, "url", String));
+    let commitment = value_t_or_exit!(matches, "commitment", CommitmentConfig);
+    let program_ids = ProgramIds {
+        restaking_program: pubkey_of(matches, "restaking_program"),
+        vault_program: pubkey_of(matches, "vault_program"),
+    };
+    let mut client = RestakingClient::new(
+        FailoverRpcClient::new(vec![EndpointConfig::new(url)], commitment),
+        program_ids,
+    );
+    if let Some(micro_lamports) = matches.value_of("priority_fee") {
+        client = client.with_compute_unit_price(micro_lamports.parse()?);
+    }
+    if let Some(units) = matches.value_of("compute_unit_limit") {
+        client = client.with_compute_unit_limit(units.parse()?);
+    }
+
+    let mut wallet_manager = None;
+    let keypair = matches.value_of("keypair").unwrap_or_default();
+    let signer = match load_signer(matches, keypair, "keypair", &mut wallet_manager) {
+        Ok(signer) => signer,
+        // printing accounts doesn't need a keypair
+        Err(_) if is_read_only(matches) => Box::new(NullSigner::new(&Pubkey::default())),
+        Err(e) => return Err(format!("failed to load keypair {keypair}: {e}").into()),
+    };
+    let multisig_signers = matches
+        .values_of("multisig_signer")
+        .into_iter()
+        .flatten()
+        .map(|path| load_signer(matches, path, "multisig-signer", &mut wallet_manager))
+        .collect::<Result<_, _>>()?;
+
+    Ok(CliContext {
+        client,
+        signer,
+        multisig_signers,
+        dry_run: matches.is_present("dry_run"),
+    })
+}
+
+async fn run() -> CliResult {
+    let default_keypair = std::env::var("HOME")
+        .map(|home| format!("{home}/.config/solana/id.json"))
+        .unwrap_or_default();
+    let matches = app(&default_keypair).get_matches();
+    let ctx = context(&matches)?;
+
+    match matches.subcommand() {
+        ("config", Some(matches)) => restaking::process_config(&ctx, matches).await,
+        ("avs", Some(matches)) => restaking::process_avs(&ctx, matches).await,
+        ("operator", Some(matches)) => restaking::process_operator(&ctx, matches).await,
+        ("vault-config", Some(matches)) => vault::process_config(&ctx, matches).await,
+        ("vault", Some(matches)) => vault::process_vault(&ctx, matches).await,
+        ("migrate", Some(matches)) => migration::process_migrate(&ctx, matches).await,
+        _ => unreachable!(),
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    if let Err(e) = run().await {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
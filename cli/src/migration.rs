@@ -0,0 +1,37 @@
+//! Executes the account migration batches planned by `migration-rehearsal`
+use std::{fs::File, io::BufReader};
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+use jito_restaking_client::migration::{batch_instructions, read_batches};
+
+use crate::{CliContext, CliResult};
+
+pub fn migrate_command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("migrate")
+        .about("Migrates restaking accounts to their current layout, paid for by the signer")
+        .arg(
+            Arg::with_name("batches")
+                .required(true)
+                .value_name("FILE")
+                .help("The batches written by migration-rehearsal, one transaction per line"),
+        )
+}
+
+pub async fn process_migrate(ctx: &CliContext, matches: &ArgMatches<'_>) -> CliResult {
+    let path = matches.value_of("batches").unwrap_or_default();
+    let batches = read_batches(BufReader::new(File::open(path)?))?;
+    for (index, batch) in batches.iter().enumerate() {
+        println!(
+            "Batch {}/{}: {} accounts",
+            index.saturating_add(1),
+            batches.len(),
+            batch.len()
+        );
+        ctx.process(
+            &batch_instructions(&ctx.restaking_program(), &ctx.signer_pubkey(), batch),
+            &[],
+        )
+        .await?;
+    }
+    Ok(())
+}
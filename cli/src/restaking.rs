@@ -0,0 +1,637 @@
+//! Subcommands for the restaking program's config, AVSs and operators
+use clap::{value_t_or_exit, App, AppSettings, Arg, ArgMatches, SubCommand};
+use jito_restaking_client::program_accounts;
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket, operator_avs_capacity::OperatorAvsCapacity,
+    operator_avs_ticket::OperatorAvsTicket, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::{AvsAdminRole, OperatorAdminRole};
+use solana_clap_utils::input_validators::is_parsable;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::{print_account, pubkey_arg, pubkey_of, CliContext, CliResult};
+
+const AVS_ROLES: &[&str] = &[
+    "operator",
+    "vault",
+    "slasher",
+    "withdraw",
+    "metadata",
+    "slash-veto",
+];
+const OPERATOR_ROLES: &[&str] = &["avs", "vault", "withdraw", "metadata"];
+
+pub fn config_command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("config")
+        .about("The restaking program config")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("initialize")
+                .about("Initializes the config, signed by the program upgrade authority"),
+        )
+        .subcommand(SubCommand::with_name("show").about("Prints the config"))
+}
+
+pub fn avs_command<'a, 'b>() -> App<'a, 'b> {
+    let avs = || pubkey_arg("avs", "The AVS account");
+    SubCommand::with_name("avs")
+        .about("AVS accounts, administered by the signer")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("initialize")
+                .about("Initializes an AVS from a new base keypair, with the signer as admin"),
+        )
+        .subcommand(
+            SubCommand::with_name("show")
+                .about("Prints the AVS")
+                .arg(avs()),
+        )
+        .subcommand(
+            SubCommand::with_name("tickets")
+                .about("Prints the operators, vaults and slashers of the AVS")
+                .arg(avs()),
+        )
+        .subcommand(
+            SubCommand::with_name("add-vault")
+                .about("Adds a vault to the AVS")
+                .arg(avs())
+                .arg(pubkey_arg("vault", "The vault to add")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-vault")
+                .about("Removes a vault from the AVS")
+                .arg(avs())
+                .arg(pubkey_arg("vault", "The vault to remove")),
+        )
+        .subcommand(
+            SubCommand::with_name("add-operator")
+                .about("Adds an operator to the AVS")
+                .arg(avs())
+                .arg(pubkey_arg("operator", "The operator to add")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-operator")
+                .about("Removes an operator from the AVS")
+                .arg(avs())
+                .arg(pubkey_arg("operator", "The operator to remove")),
+        )
+        .subcommand(
+            SubCommand::with_name("add-slasher")
+                .about("Allows a slasher to slash a vault of the AVS")
+                .arg(avs())
+                .arg(pubkey_arg("vault", "The vault the slasher can slash"))
+                .arg(pubkey_arg("slasher", "The slasher"))
+                .arg(
+                    Arg::with_name("max_slashable_per_epoch")
+                        .long("max-slashable-per-epoch")
+                        .value_name("AMOUNT")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_parsable::<u64>)
+                        .help("The max amount the slasher can slash per epoch"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-slasher")
+                .about("Revokes a slasher of a vault of the AVS")
+                .arg(avs())
+                .arg(pubkey_arg("vault", "The vault the slasher can slash"))
+                .arg(pubkey_arg("slasher", "The slasher")),
+        )
+        .subcommand(
+            SubCommand::with_name("set-admin")
+                .about("Proposes a new admin, who accepts with accept-admin")
+                .arg(avs())
+                .arg(pubkey_arg("new_admin", "The proposed admin")),
+        )
+        .subcommand(
+            SubCommand::with_name("accept-admin")
+                .about("Accepts the admin role proposed to the signer")
+                .arg(avs()),
+        )
+        .subcommand(
+            SubCommand::with_name("set-secondary-admin")
+                .about("Sets the admin of one of the AVS roles")
+                .arg(avs())
+                .arg(pubkey_arg("new_admin", "The new admin of the role"))
+                .arg(
+                    Arg::with_name("role")
+                        .required(true)
+                        .possible_values(AVS_ROLES)
+                        .help("The role"),
+                ),
+        )
+}
+
+pub fn operator_command<'a, 'b>() -> App<'a, 'b> {
+    let operator = || pubkey_arg("operator", "The operator account");
+    SubCommand::with_name("operator")
+        .about("Operator accounts, administered by the signer")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("initialize")
+                .about("Initializes an operator from a new base keypair, with the signer as admin"),
+        )
+        .subcommand(
+            SubCommand::with_name("show")
+                .about("Prints the operator")
+                .arg(operator()),
+        )
+        .subcommand(
+            SubCommand::with_name("tickets")
+                .about("Prints the AVSs and vaults of the operator")
+                .arg(operator()),
+        )
+        .subcommand(
+            SubCommand::with_name("add-avs")
+                .about("Joins an AVS")
+                .arg(operator())
+                .arg(pubkey_arg("avs", "The AVS to join")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-avs")
+                .about("Leaves an AVS")
+                .arg(operator())
+                .arg(pubkey_arg("avs", "The AVS to leave")),
+        )
+        .subcommand(
+            SubCommand::with_name("set-fee")
+                .about("Sets the share of the rewards the operator keeps")
+                .arg(operator())
+                .arg(
+                    Arg::with_name("fee_bps")
+                        .required(true)
+                        .validator(is_parsable::<u16>)
+                        .help("The fee in basis points, up to the max of the config"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set-avs-capacity")
+                .about("Advertises the max additional stake the operator can handle for an AVS")
+                .arg(operator())
+                .arg(pubkey_arg("avs", "The AVS the operator joined"))
+                .arg(
+                    Arg::with_name("capacity")
+                        .required(true)
+                        .validator(is_parsable::<u64>)
+                        .help("The amount of tokens"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("add-vault")
+                .about("Supports a vault")
+                .arg(operator())
+                .arg(pubkey_arg("vault", "The vault to support")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-vault")
+                .about("Stops supporting a vault")
+                .arg(operator())
+                .arg(pubkey_arg("vault", "The vault to stop supporting")),
+        )
+        .subcommand(
+            SubCommand::with_name("set-admin")
+                .about("Proposes a new admin, who accepts with accept-admin")
+                .arg(operator())
+                .arg(pubkey_arg("new_admin", "The proposed admin")),
+        )
+        .subcommand(
+            SubCommand::with_name("accept-admin")
+                .about("Accepts the admin role proposed to the signer")
+                .arg(operator()),
+        )
+        .subcommand(
+            SubCommand::with_name("set-secondary-admin")
+                .about("Sets the admin of one of the operator roles")
+                .arg(operator())
+                .arg(pubkey_arg("new_admin", "The new admin of the role"))
+                .arg(
+                    Arg::with_name("role")
+                        .required(true)
+                        .possible_values(OPERATOR_ROLES)
+                        .help("The role"),
+                ),
+        )
+}
+
+pub async fn process_config(ctx: &CliContext, matches: &ArgMatches<'_>) -> CliResult {
+    let config = ctx.client.config_address();
+    match matches.subcommand() {
+        ("initialize", Some(_)) => {
+            ctx.process(
+                &[jito_restaking_sdk::initialize_config(
+                    &ctx.restaking_program(),
+                    &config,
+                    &ctx.signer_pubkey(),
+                    &ctx.vault_program(),
+                    &ctx.signer_pubkey(),
+                )],
+                &[],
+            )
+            .await
+        }
+        ("show", Some(_)) => {
+            print_account(&config, &ctx.client.get_config().await?);
+            Ok(())
+        }
+        _ => unreachable!(),
+    }
+}
+
+pub async fn process_avs(ctx: &CliContext, matches: &ArgMatches<'_>) -> CliResult {
+    let program_id = ctx.restaking_program();
+    let config = ctx.client.config_address();
+    let admin = ctx.signer_pubkey();
+    match matches.subcommand() {
+        ("initialize", Some(_)) => {
+            let base = Keypair::new();
+            let avs = ctx.client.avs_address(&base.pubkey());
+            println!("AVS: {avs}");
+            ctx.process(
+                &[jito_restaking_sdk::initialize_avs(
+                    &program_id,
+                    &config,
+                    &avs,
+                    &admin,
+                    &base.pubkey(),
+                    &admin,
+                )],
+                &[&base],
+            )
+            .await
+        }
+        ("show", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            print_account(&avs, &ctx.client.get_avs(&avs).await?);
+            Ok(())
+        }
+        ("tickets", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            let rpc = ctx.client.rpc();
+            for (address, ticket) in
+                program_accounts::get_all_avs_operator_tickets_for_avs(rpc, &program_id, &avs)
+                    .await?
+            {
+                print_account(&address, &ticket);
+            }
+            for (address, ticket) in
+                program_accounts::get_all_avs_vault_tickets_for_avs(rpc, &program_id, &avs).await?
+            {
+                print_account(&address, &ticket);
+            }
+            for (address, ticket) in
+                program_accounts::get_all_avs_vault_slasher_tickets_for_avs(rpc, &program_id, &avs)
+                    .await?
+            {
+                print_account(&address, &ticket);
+            }
+            Ok(())
+        }
+        ("add-vault", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            let vault = pubkey_of(matches, "vault");
+            ctx.process(
+                &[jito_restaking_sdk::avs_add_vault(
+                    &program_id,
+                    &config,
+                    &avs,
+                    &vault,
+                    &AvsVaultTicket::find_program_address(&program_id, &avs, &vault).0,
+                    &admin,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("remove-vault", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            let vault = pubkey_of(matches, "vault");
+            ctx.process(
+                &[jito_restaking_sdk::avs_remove_vault(
+                    &program_id,
+                    &config,
+                    &avs,
+                    &vault,
+                    &AvsVaultTicket::find_program_address(&program_id, &avs, &vault).0,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("add-operator", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            let operator = pubkey_of(matches, "operator");
+            ctx.process(
+                &[jito_restaking_sdk::avs_add_operator(
+                    &program_id,
+                    &config,
+                    &avs,
+                    &operator,
+                    &AvsOperatorTicket::find_program_address(&program_id, &avs, &operator).0,
+                    &OperatorAvsTicket::find_program_address(&program_id, &operator, &avs).0,
+                    &admin,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("remove-operator", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            let operator = pubkey_of(matches, "operator");
+            ctx.process(
+                &[jito_restaking_sdk::avs_remove_operator(
+                    &program_id,
+                    &config,
+                    &avs,
+                    &operator,
+                    &AvsOperatorTicket::find_program_address(&program_id, &avs, &operator).0,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("add-slasher", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            let vault = pubkey_of(matches, "vault");
+            let slasher = pubkey_of(matches, "slasher");
+            let max_slashable_per_epoch = value_t_or_exit!(matches, "max_slashable_per_epoch", u64);
+            ctx.process_with_multisig(jito_restaking_sdk::avs_add_vault_slasher(
+                &program_id,
+                &config,
+                &avs,
+                &vault,
+                &slasher,
+                &AvsVaultTicket::find_program_address(&program_id, &avs, &vault).0,
+                &AvsVaultSlasherTicket::find_program_address(&program_id, &avs, &vault, &slasher).0,
+                &admin,
+                &admin,
+                max_slashable_per_epoch,
+                [0; 32],
+            ))
+            .await
+        }
+        ("remove-slasher", Some(matches)) => {
+            let avs = pubkey_of(matches, "avs");
+            let vault = pubkey_of(matches, "vault");
+            let slasher = pubkey_of(matches, "slasher");
+            ctx.process(
+                &[jito_restaking_sdk::avs_remove_vault_slasher(
+                    &program_id,
+                    &config,
+                    &avs,
+                    &vault,
+                    &slasher,
+                    &AvsVaultTicket::find_program_address(&program_id, &avs, &vault).0,
+                    &AvsVaultSlasherTicket::find_program_address(
+                        &program_id,
+                        &avs,
+                        &vault,
+                        &slasher,
+                    )
+                    .0,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("set-admin", Some(matches)) => {
+            ctx.process_with_multisig(jito_restaking_sdk::avs_set_admin(
+                &program_id,
+                &pubkey_of(matches, "avs"),
+                &admin,
+                &pubkey_of(matches, "new_admin"),
+            ))
+            .await
+        }
+        ("accept-admin", Some(matches)) => {
+            ctx.process(
+                &[jito_restaking_sdk::avs_accept_admin(
+                    &program_id,
+                    &pubkey_of(matches, "avs"),
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("set-secondary-admin", Some(matches)) => {
+            let role = match matches.value_of("role") {
+                Some("operator") => AvsAdminRole::Operator,
+                Some("vault") => AvsAdminRole::Vault,
+                Some("slasher") => AvsAdminRole::Slasher,
+                Some("withdraw") => AvsAdminRole::Withdraw,
+                Some("slash-veto") => AvsAdminRole::SlashVeto,
+                _ => AvsAdminRole::Metadata,
+            };
+            ctx.process_with_multisig(jito_restaking_sdk::avs_set_secondary_admin(
+                &program_id,
+                &pubkey_of(matches, "avs"),
+                &admin,
+                &pubkey_of(matches, "new_admin"),
+                role,
+            ))
+            .await
+        }
+        _ => unreachable!(),
+    }
+}
+
+pub async fn process_operator(ctx: &CliContext, matches: &ArgMatches<'_>) -> CliResult {
+    let program_id = ctx.restaking_program();
+    let config = ctx.client.config_address();
+    let admin = ctx.signer_pubkey();
+    match matches.subcommand() {
+        ("initialize", Some(_)) => {
+            let base = Keypair::new();
+            let operator = ctx.client.operator_address(&base.pubkey());
+            println!("Operator: {operator}");
+            ctx.process(
+                &[jito_restaking_sdk::initialize_operator(
+                    &program_id,
+                    &config,
+                    &operator,
+                    &admin,
+                    &base.pubkey(),
+                    &admin,
+                )],
+                &[&base],
+            )
+            .await
+        }
+        ("show", Some(matches)) => {
+            let operator = pubkey_of(matches, "operator");
+            print_account(&operator, &ctx.client.get_operator(&operator).await?);
+            Ok(())
+        }
+        ("tickets", Some(matches)) => {
+            let operator = pubkey_of(matches, "operator");
+            let rpc = ctx.client.rpc();
+            for (address, ticket) in program_accounts::get_all_operator_avs_tickets_for_operator(
+                rpc,
+                &program_id,
+                &operator,
+            )
+            .await?
+            {
+                print_account(&address, &ticket);
+            }
+            for (address, ticket) in program_accounts::get_all_operator_vault_tickets_for_operator(
+                rpc,
+                &program_id,
+                &operator,
+            )
+            .await?
+            {
+                print_account(&address, &ticket);
+            }
+            Ok(())
+        }
+        ("add-avs", Some(matches)) => {
+            let operator = pubkey_of(matches, "operator");
+            let avs = pubkey_of(matches, "avs");
+            ctx.process(
+                &[jito_restaking_sdk::operator_add_avs(
+                    &program_id,
+                    &config,
+                    &operator,
+                    &avs,
+                    &OperatorAvsTicket::find_program_address(&program_id, &operator, &avs).0,
+                    &admin,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("remove-avs", Some(matches)) => {
+            let operator = pubkey_of(matches, "operator");
+            let avs = pubkey_of(matches, "avs");
+            ctx.process(
+                &[jito_restaking_sdk::operator_remove_avs(
+                    &program_id,
+                    &config,
+                    &operator,
+                    &avs,
+                    &OperatorAvsTicket::find_program_address(&program_id, &operator, &avs).0,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("set-fee", Some(matches)) => {
+            ctx.process(
+                &[jito_restaking_sdk::set_operator_fee(
+                    &program_id,
+                    &config,
+                    &pubkey_of(matches, "operator"),
+                    &admin,
+                    value_t_or_exit!(matches, "fee_bps", u16),
+                )],
+                &[],
+            )
+            .await
+        }
+        ("set-avs-capacity", Some(matches)) => {
+            let operator = pubkey_of(matches, "operator");
+            let avs = pubkey_of(matches, "avs");
+            ctx.process(
+                &[jito_restaking_sdk::operator_set_avs_capacity(
+                    &program_id,
+                    &config,
+                    &operator,
+                    &avs,
+                    &OperatorAvsTicket::find_program_address(&program_id, &operator, &avs).0,
+                    &OperatorAvsCapacity::find_program_address(&program_id, &operator, &avs).0,
+                    &admin,
+                    &admin,
+                    value_t_or_exit!(matches, "capacity", u64),
+                )],
+                &[],
+            )
+            .await
+        }
+        ("add-vault", Some(matches)) => {
+            let operator = pubkey_of(matches, "operator");
+            let vault = pubkey_of(matches, "vault");
+            ctx.process(
+                &[jito_restaking_sdk::operator_add_vault(
+                    &program_id,
+                    &config,
+                    &operator,
+                    &vault,
+                    &OperatorVaultTicket::find_program_address(&program_id, &operator, &vault).0,
+                    &admin,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("remove-vault", Some(matches)) => {
+            let operator = pubkey_of(matches, "operator");
+            let vault = pubkey_of(matches, "vault");
+            ctx.process(
+                &[jito_restaking_sdk::operator_remove_vault(
+                    &program_id,
+                    &config,
+                    &operator,
+                    &vault,
+                    &OperatorVaultTicket::find_program_address(&program_id, &operator, &vault).0,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("set-admin", Some(matches)) => {
+            ctx.process(
+                &[jito_restaking_sdk::operator_set_admin(
+                    &program_id,
+                    &pubkey_of(matches, "operator"),
+                    &admin,
+                    &pubkey_of(matches, "new_admin"),
+                )],
+                &[],
+            )
+            .await
+        }
+        ("accept-admin", Some(matches)) => {
+            ctx.process(
+                &[jito_restaking_sdk::operator_accept_admin(
+                    &program_id,
+                    &pubkey_of(matches, "operator"),
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("set-secondary-admin", Some(matches)) => {
+            let role = match matches.value_of("role") {
+                Some("avs") => OperatorAdminRole::Avs,
+                Some("vault") => OperatorAdminRole::Vault,
+                Some("withdraw") => OperatorAdminRole::Withdraw,
+                _ => OperatorAdminRole::Metadata,
+            };
+            ctx.process(
+                &[jito_restaking_sdk::operator_set_secondary_admin(
+                    &program_id,
+                    &pubkey_of(matches, "operator"),
+                    &admin,
+                    &pubkey_of(matches, "new_admin"),
+                    role,
+                )],
+                &[],
+            )
+            .await
+        }
+        _ => unreachable!(),
+    }
+}
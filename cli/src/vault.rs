@@ -0,0 +1,587 @@
+//! Subcommands for the vault program's config and vaults
+use clap::{value_t_or_exit, App, AppSettings, Arg, ArgMatches, SubCommand};
+use jito_restaking_client::program_accounts;
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::matching::{match_delegations, OperatorCapacity, VaultDemand};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList, vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket,
+};
+use solana_clap_utils::input_validators::is_parsable;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{print_account, pubkey_arg, pubkey_of, CliContext, CliResult};
+
+pub fn config_command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("vault-config")
+        .about("The vault program config")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("initialize")
+                .about("Initializes the config, signed by the program upgrade authority"),
+        )
+        .subcommand(SubCommand::with_name("show").about("Prints the config"))
+}
+
+pub fn vault_command<'a, 'b>() -> App<'a, 'b> {
+    let vault = || pubkey_arg("vault", "The vault account");
+    let fee_bps = |name, help| {
+        Arg::with_name(name)
+            .long(name)
+            .value_name("BPS")
+            .takes_value(true)
+            .default_value("0")
+            .validator(is_parsable::<u16>)
+            .help(help)
+    };
+    SubCommand::with_name("vault")
+        .about("Vault accounts, administered by the signer")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("initialize")
+                .about("Initializes a vault and its LRT mint, with the signer as admin")
+                .arg(pubkey_arg("token_mint", "The mint of the tokens deposited"))
+                .arg(fee_bps(
+                    "deposit-fee-bps",
+                    "The deposit fee in basis points",
+                ))
+                .arg(fee_bps(
+                    "withdrawal-fee-bps",
+                    "The withdrawal fee in basis points",
+                )),
+        )
+        .subcommand(
+            SubCommand::with_name("show")
+                .about("Prints the vault")
+                .arg(vault()),
+        )
+        .subcommand(
+            SubCommand::with_name("tickets")
+                .about("Prints the AVSs, operators and slashers of the vault")
+                .arg(vault()),
+        )
+        .subcommand(
+            SubCommand::with_name("add-avs")
+                .about("Adds an AVS that already added the vault")
+                .arg(vault())
+                .arg(pubkey_arg("avs", "The AVS to add")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-avs")
+                .about("Removes an AVS from the vault")
+                .arg(vault())
+                .arg(pubkey_arg("avs", "The AVS to remove")),
+        )
+        .subcommand(
+            SubCommand::with_name("add-operator")
+                .about("Adds an operator that already added the vault")
+                .arg(vault())
+                .arg(pubkey_arg("operator", "The operator to add")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-operator")
+                .about("Removes an operator from the vault")
+                .arg(vault())
+                .arg(pubkey_arg("operator", "The operator to remove")),
+        )
+        .subcommand(
+            SubCommand::with_name("add-slasher")
+                .about("Accepts a slasher the AVS allowed to slash the vault")
+                .arg(vault())
+                .arg(pubkey_arg("avs", "The AVS of the slasher"))
+                .arg(pubkey_arg("slasher", "The slasher")),
+        )
+        .subcommand(
+            SubCommand::with_name("set-admin")
+                .about("Proposes a new admin, who accepts with accept-admin")
+                .arg(vault())
+                .arg(pubkey_arg("new_admin", "The proposed admin")),
+        )
+        .subcommand(
+            SubCommand::with_name("accept-admin")
+                .about("Accepts the admin role proposed to the signer")
+                .arg(vault()),
+        )
+        .subcommand(
+            SubCommand::with_name("rebalance")
+                .about(
+                    "Delegates the undelegated stake of the vault within the capacity its \
+                     operators advertised, signed by the delegation admin",
+                )
+                .arg(vault()),
+        )
+        .subcommand(
+            SubCommand::with_name("slash")
+                .about("Slashes an operator's delegation from the vault, signed by the slasher")
+                .arg(vault())
+                .arg(pubkey_arg("avs", "The AVS the slasher slashes for"))
+                .arg(pubkey_arg("operator", "The operator to slash"))
+                .arg(
+                    Arg::with_name("amount")
+                        .required(true)
+                        .validator(is_parsable::<u64>)
+                        .help("The amount of tokens to slash"),
+                ),
+        )
+}
+
+pub async fn process_config(ctx: &CliContext, matches: &ArgMatches<'_>) -> CliResult {
+    let config = Config::find_program_address(&ctx.vault_program()).0;
+    match matches.subcommand() {
+        ("initialize", Some(_)) => {
+            ctx.process(
+                &[jito_vault_sdk::initialize_config(
+                    &ctx.vault_program(),
+                    &config,
+                    &ctx.signer_pubkey(),
+                    &ctx.restaking_program(),
+                    &ctx.signer_pubkey(),
+                )],
+                &[],
+            )
+            .await
+        }
+        ("show", Some(_)) => {
+            print_account(&config, &ctx.client.get_account::<Config>(&config).await?);
+            Ok(())
+        }
+        _ => unreachable!(),
+    }
+}
+
+pub async fn process_vault(ctx: &CliContext, matches: &ArgMatches<'_>) -> CliResult {
+    let program_id = ctx.vault_program();
+    let restaking_program = ctx.restaking_program();
+    let config = Config::find_program_address(&program_id).0;
+    let admin = ctx.signer_pubkey();
+    match matches.subcommand() {
+        ("initialize", Some(matches)) => {
+            let base = Keypair::new();
+            let lrt_mint = Keypair::new();
+            let vault = Vault::find_program_address(&program_id, &base.pubkey()).0;
+            let token_mint = pubkey_of(matches, "token_mint");
+            let token_program = token_program_of(ctx, &token_mint).await?;
+            println!("Vault: {vault}");
+            println!("LRT mint: {}", lrt_mint.pubkey());
+            ctx.process(
+                &[jito_vault_sdk::initialize_vault(
+                    &program_id,
+                    &config,
+                    &vault,
+                    &VaultDelegationList::find_program_address(&program_id, &vault).0,
+                    &lrt_mint.pubkey(),
+                    &token_mint,
+                    &admin,
+                    &base.pubkey(),
+                    &admin,
+                    &token_program,
+                    value_t_or_exit!(matches, "deposit-fee-bps", u16),
+                    value_t_or_exit!(matches, "withdrawal-fee-bps", u16),
+                )],
+                &[&lrt_mint, &base],
+            )
+            .await
+        }
+        ("show", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            print_account(&vault, &ctx.client.get_account::<Vault>(&vault).await?);
+            Ok(())
+        }
+        ("tickets", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let rpc = ctx.client.rpc();
+            for (address, ticket) in
+                program_accounts::get_all_vault_avs_tickets_for_vault(rpc, &program_id, &vault)
+                    .await?
+            {
+                print_account(&address, &ticket);
+            }
+            for (address, ticket) in
+                program_accounts::get_all_vault_operator_tickets_for_vault(rpc, &program_id, &vault)
+                    .await?
+            {
+                print_account(&address, &ticket);
+            }
+            for (address, ticket) in program_accounts::get_all_vault_avs_slasher_tickets_for_vault(
+                rpc,
+                &program_id,
+                &vault,
+            )
+            .await?
+            {
+                print_account(&address, &ticket);
+            }
+            Ok(())
+        }
+        ("add-avs", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let avs = pubkey_of(matches, "avs");
+            ctx.process(
+                &[jito_vault_sdk::add_avs(
+                    &program_id,
+                    &config,
+                    &vault,
+                    &avs,
+                    &AvsVaultTicket::find_program_address(&restaking_program, &avs, &vault).0,
+                    &VaultAvsTicket::find_program_address(&program_id, &vault, &avs).0,
+                    &admin,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("remove-avs", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let avs = pubkey_of(matches, "avs");
+            ctx.process(
+                &[jito_vault_sdk::remove_avs(
+                    &program_id,
+                    &config,
+                    &vault,
+                    &avs,
+                    &VaultAvsTicket::find_program_address(&program_id, &vault, &avs).0,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("add-operator", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let operator = pubkey_of(matches, "operator");
+            ctx.process(
+                &[jito_vault_sdk::add_operator(
+                    &program_id,
+                    &config,
+                    &vault,
+                    &operator,
+                    &OperatorVaultTicket::find_program_address(
+                        &restaking_program,
+                        &operator,
+                        &vault,
+                    )
+                    .0,
+                    &VaultOperatorTicket::find_program_address(&program_id, &vault, &operator).0,
+                    &admin,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("remove-operator", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let operator = pubkey_of(matches, "operator");
+            ctx.process(
+                &[jito_vault_sdk::remove_operator(
+                    &program_id,
+                    &config,
+                    &vault,
+                    &operator,
+                    &VaultOperatorTicket::find_program_address(&program_id, &vault, &operator).0,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("add-slasher", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let avs = pubkey_of(matches, "avs");
+            let slasher = pubkey_of(matches, "slasher");
+            ctx.process(
+                &[jito_vault_sdk::add_slasher(
+                    &program_id,
+                    &config,
+                    &vault,
+                    &avs,
+                    &slasher,
+                    &AvsVaultSlasherTicket::find_program_address(
+                        &restaking_program,
+                        &avs,
+                        &vault,
+                        &slasher,
+                    )
+                    .0,
+                    &VaultAvsSlasherTicket::find_program_address(
+                        &program_id,
+                        &vault,
+                        &avs,
+                        &slasher,
+                    )
+                    .0,
+                    &admin,
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("set-admin", Some(matches)) => {
+            ctx.process(
+                &[jito_vault_sdk::set_admin(
+                    &program_id,
+                    &pubkey_of(matches, "vault"),
+                    &admin,
+                    &pubkey_of(matches, "new_admin"),
+                )],
+                &[],
+            )
+            .await
+        }
+        ("accept-admin", Some(matches)) => {
+            ctx.process(
+                &[jito_vault_sdk::accept_admin(
+                    &program_id,
+                    &pubkey_of(matches, "vault"),
+                    &admin,
+                )],
+                &[],
+            )
+            .await
+        }
+        ("rebalance", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let rpc = ctx.client.rpc();
+            let slot = rpc.call(|client| client.get_slot()).await?;
+
+            let vault_delegation_list =
+                VaultDelegationList::find_program_address(&program_id, &vault).0;
+            let delegated = ctx
+                .client
+                .get_account::<VaultDelegationList>(&vault_delegation_list)
+                .await?
+                .total_delegation()
+                .unwrap_or(0);
+            let deposited = ctx
+                .client
+                .get_account::<Vault>(&vault)
+                .await?
+                .tokens_deposited();
+
+            let avss: Vec<_> =
+                program_accounts::get_all_vault_avs_tickets_for_vault(rpc, &program_id, &vault)
+                    .await?
+                    .into_iter()
+                    .filter(|(_, ticket)| ticket.check_active(slot).is_ok())
+                    .map(|(_, ticket)| ticket.avs())
+                    .collect();
+            // without a blacklist account the vault blacklisted no operator
+            let vault_operator_blacklist =
+                VaultOperatorBlacklist::find_program_address(&program_id, &vault).0;
+            let blacklist = ctx
+                .client
+                .get_account::<VaultOperatorBlacklist>(&vault_operator_blacklist)
+                .await
+                .ok();
+            let operators: Vec<_> = program_accounts::get_all_vault_operator_tickets_for_vault(
+                rpc,
+                &program_id,
+                &vault,
+            )
+            .await?
+            .into_iter()
+            .filter(|(_, ticket)| ticket.check_active(slot).is_ok())
+            .map(|(_, ticket)| ticket.operator())
+            .filter(|operator| {
+                !blacklist
+                    .as_ref()
+                    .is_some_and(|blacklist| blacklist.is_blacklisted(operator))
+            })
+            .collect();
+
+            let mut capacities = Vec::new();
+            for operator in &operators {
+                for (_, capacity) in program_accounts::get_all_operator_avs_capacities_for_operator(
+                    rpc,
+                    &restaking_program,
+                    operator,
+                )
+                .await?
+                {
+                    capacities.push(OperatorCapacity {
+                        operator: capacity.operator(),
+                        avs: capacity.avs(),
+                        capacity: capacity.capacity(),
+                    });
+                }
+            }
+
+            let demand = VaultDemand {
+                vault,
+                amount: deposited.saturating_sub(delegated),
+                avss,
+                operators,
+            };
+            let instructions: Vec<_> = match_delegations(&[demand], &capacities)
+                .into_iter()
+                .map(|allocation| {
+                    println!(
+                        "Delegating {} to operator {}",
+                        allocation.amount, allocation.operator
+                    );
+                    jito_vault_sdk::add_delegation(
+                        &program_id,
+                        &config,
+                        &vault,
+                        &allocation.operator,
+                        &VaultOperatorTicket::find_program_address(
+                            &program_id,
+                            &vault,
+                            &allocation.operator,
+                        )
+                        .0,
+                        &OperatorVaultTicket::find_program_address(
+                            &restaking_program,
+                            &allocation.operator,
+                            &vault,
+                        )
+                        .0,
+                        &vault_delegation_list,
+                        &admin,
+                        &admin,
+                        &vault_operator_blacklist,
+                        allocation.amount,
+                    )
+                })
+                .collect();
+            if instructions.is_empty() {
+                println!("No operator has room for the undelegated stake");
+                return Ok(());
+            }
+            ctx.process(&instructions, &[]).await
+        }
+        ("slash", Some(matches)) => {
+            let vault = pubkey_of(matches, "vault");
+            let avs = pubkey_of(matches, "avs");
+            let operator = pubkey_of(matches, "operator");
+            let amount = value_t_or_exit!(matches, "amount", u64);
+            // the slasher signs as the fee payer
+            let slasher = admin;
+
+            let epoch_length = ctx
+                .client
+                .get_account::<Config>(&config)
+                .await?
+                .epoch_length();
+            let slot = ctx.client.rpc().call(|client| client.get_slot()).await?;
+            let epoch = slot
+                .checked_div(epoch_length)
+                .ok_or("the vault config has no epoch length")?;
+            let supported_mint = ctx
+                .client
+                .get_account::<Vault>(&vault)
+                .await?
+                .supported_mint();
+            let token_program = token_program_of(ctx, &supported_mint).await?;
+
+            let vault_avs_slasher_ticket =
+                VaultAvsSlasherTicket::find_program_address(&program_id, &vault, &avs, &slasher).0;
+            let vault_avs_slasher_operator_ticket =
+                VaultAvsSlasherOperatorTicket::find_program_address(
+                    &program_id,
+                    &vault,
+                    &avs,
+                    &slasher,
+                    &operator,
+                    epoch,
+                )
+                .0;
+            let slasher_token_account = get_associated_token_address_with_program_id(
+                &slasher,
+                &supported_mint,
+                &token_program,
+            );
+
+            let mut instructions = vec![create_associated_token_account_idempotent(
+                &slasher,
+                &slasher,
+                &supported_mint,
+                &token_program,
+            )];
+            // the first slash of the epoch records it in a new ticket
+            let existing = ctx
+                .client
+                .rpc()
+                .get_multiple_accounts(&[vault_avs_slasher_operator_ticket])
+                .await?;
+            if !existing.iter().any(Option::is_some) {
+                instructions.push(
+                    jito_vault_sdk::initialize_vault_avs_slasher_operator_ticket(
+                        &program_id,
+                        &config,
+                        &vault,
+                        &avs,
+                        &slasher,
+                        &operator,
+                        &vault_avs_slasher_ticket,
+                        &vault_avs_slasher_operator_ticket,
+                        &slasher,
+                    ),
+                );
+            }
+            instructions.push(jito_vault_sdk::slash(
+                &program_id,
+                &config,
+                &vault,
+                &avs,
+                &operator,
+                &slasher,
+                &AvsOperatorTicket::find_program_address(&restaking_program, &avs, &operator).0,
+                &OperatorAvsTicket::find_program_address(&restaking_program, &operator, &avs).0,
+                &AvsVaultTicket::find_program_address(&restaking_program, &avs, &vault).0,
+                &OperatorVaultTicket::find_program_address(&restaking_program, &operator, &vault).0,
+                &VaultAvsTicket::find_program_address(&program_id, &vault, &avs).0,
+                &VaultOperatorTicket::find_program_address(&program_id, &vault, &operator).0,
+                &AvsVaultSlasherTicket::find_program_address(
+                    &restaking_program,
+                    &avs,
+                    &vault,
+                    &slasher,
+                )
+                .0,
+                &vault_avs_slasher_ticket,
+                &VaultDelegationList::find_program_address(&program_id, &vault).0,
+                &vault_avs_slasher_operator_ticket,
+                &get_associated_token_address_with_program_id(
+                    &vault,
+                    &supported_mint,
+                    &token_program,
+                ),
+                &slasher_token_account,
+                &supported_mint,
+                &token_program,
+                amount,
+            ));
+            ctx.process(&instructions, &[]).await
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// The token program owning the mint, SPL Token or Token-2022
+async fn token_program_of(ctx: &CliContext, mint: &Pubkey) -> CliResult<Pubkey> {
+    let account = ctx
+        .client
+        .rpc()
+        .get_multiple_accounts(&[*mint])
+        .await?
+        .pop()
+        .flatten()
+        .ok_or("the mint doesn't exist")?;
+    Ok(account.owner)
+}
@@ -0,0 +1,248 @@
+//! An in-memory account cache for keepers and dashboards that poll the same accounts over and
+//! over.
+//!
+//! Accounts are cached with the slot they were read at and expire once the cache's slot is more
+//! than [`AccountCache::max_age_slots`] past it. [`AccountCache::subscribe`] feeds the cache from
+//! a websocket: slot notifications advance the cache's slot, and accounts owned by the subscribed
+//! programs are updated as they change, so they stay cached for as long as the subscription is
+//! up. Attach the cache to a [`crate::failover::FailoverRpcClient`] with
+//! [`crate::failover::FailoverRpcClient::with_cache`].
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::Arc,
+};
+
+use futures_util::{stream::select_all, StreamExt};
+use solana_account_decoder::UiAccountEncoding;
+use solana_pubsub_client::nonblocking::pubsub_client::{PubsubClient, PubsubClientResult};
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_sdk::{account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+#[derive(Debug)]
+struct CachedAccount {
+    slot: u64,
+    account: Account,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    /// The latest slot the cache has seen
+    slot: u64,
+    entries: HashMap<Pubkey, CachedAccount>,
+    /// Programs whose accounts are kept up to date by a subscription
+    watched_programs: HashSet<Pubkey>,
+}
+
+#[derive(Debug)]
+pub struct AccountCache {
+    max_age_slots: u64,
+    state: RwLock<CacheState>,
+}
+
+impl AccountCache {
+    pub fn new(max_age_slots: u64) -> Self {
+        Self {
+            max_age_slots,
+            state: RwLock::new(CacheState::default()),
+        }
+    }
+
+    /// How many slots an account stays cached when its program isn't subscribed to
+    pub const fn max_age_slots(&self) -> u64 {
+        self.max_age_slots
+    }
+
+    /// The latest slot the cache has seen
+    pub async fn slot(&self) -> u64 {
+        self.state.read().await.slot
+    }
+
+    /// Returns the cached account, if any and not expired
+    pub async fn get(&self, pubkey: &Pubkey) -> Option<Account> {
+        let state = self.state.read().await;
+        state.entries.get(pubkey).and_then(|entry| {
+            let fresh = state.watched_programs.contains(&entry.account.owner)
+                || state.slot.saturating_sub(entry.slot) <= self.max_age_slots;
+            fresh.then(|| entry.account.clone())
+        })
+    }
+
+    /// Caches the account as read at `slot`, unless a later read is already cached. Accounts
+    /// without lamports have been closed and are evicted instead.
+    pub async fn insert(&self, pubkey: Pubkey, slot: u64, account: Account) {
+        let mut state = self.state.write().await;
+        state.slot = state.slot.max(slot);
+        if state
+            .entries
+            .get(&pubkey)
+            .is_some_and(|entry| entry.slot > slot)
+        {
+            return;
+        }
+        if account.lamports == 0 {
+            state.entries.remove(&pubkey);
+        } else {
+            state
+                .entries
+                .insert(pubkey, CachedAccount { slot, account });
+        }
+    }
+
+    /// Advances the cache's slot, expiring accounts read more than `max_age_slots` before it
+    pub async fn advance_slot(&self, slot: u64) {
+        let mut state = self.state.write().await;
+        state.slot = state.slot.max(slot);
+    }
+
+    /// Evicts every cached account
+    pub async fn clear(&self) {
+        self.state.write().await.entries.clear();
+    }
+
+    /// Marks the program's accounts as kept up to date, or stops doing so. Accounts the program
+    /// owns are evicted either way, since updates may have been missed while switching.
+    async fn set_watched(&self, program_id: &Pubkey, watched: bool) {
+        let mut state = self.state.write().await;
+        if watched {
+            state.watched_programs.insert(*program_id);
+        } else {
+            state.watched_programs.remove(program_id);
+        }
+        state
+            .entries
+            .retain(|_, entry| entry.account.owner != *program_id);
+    }
+
+    /// Connects to the websocket endpoint and feeds the cache with slot notifications and with
+    /// updates to the accounts owned by `program_ids`. The returned task runs until the
+    /// connection drops, after which the programs' accounts expire like any other.
+    pub fn subscribe(
+        self: &Arc<Self>,
+        pubsub_url: String,
+        program_ids: Vec<Pubkey>,
+        commitment: CommitmentConfig,
+    ) -> JoinHandle<PubsubClientResult> {
+        let cache = self.clone();
+        tokio::spawn(async move {
+            let result = cache
+                .run_subscription(&pubsub_url, &program_ids, commitment)
+                .await;
+            for program_id in &program_ids {
+                cache.set_watched(program_id, false).await;
+            }
+            result
+        })
+    }
+
+    async fn run_subscription(
+        &self,
+        pubsub_url: &str,
+        program_ids: &[Pubkey],
+        commitment: CommitmentConfig,
+    ) -> PubsubClientResult {
+        enum Update {
+            Slot(u64),
+            Account(u64, Pubkey, Option<Account>),
+        }
+
+        let client = PubsubClient::new(pubsub_url).await?;
+        let mut streams = Vec::with_capacity(program_ids.len().saturating_add(1));
+        let (slots, _) = client.slot_subscribe().await?;
+        streams.push(slots.map(|slot_info| Update::Slot(slot_info.slot)).boxed());
+        for program_id in program_ids {
+            let config = RpcProgramAccountsConfig {
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    commitment: Some(commitment),
+                    ..RpcAccountInfoConfig::default()
+                },
+                with_context: Some(true),
+                ..RpcProgramAccountsConfig::default()
+            };
+            let (accounts, _) = client.program_subscribe(program_id, Some(config)).await?;
+            streams.push(
+                accounts
+                    .filter_map(|response| async move {
+                        let pubkey = Pubkey::from_str(&response.value.pubkey).ok()?;
+                        Some(Update::Account(
+                            response.context.slot,
+                            pubkey,
+                            response.value.account.decode(),
+                        ))
+                    })
+                    .boxed(),
+            );
+            self.set_watched(program_id, true).await;
+        }
+
+        let mut updates = select_all(streams);
+        while let Some(update) = updates.next().await {
+            match update {
+                Update::Slot(slot) => self.advance_slot(slot).await,
+                // an account that can't be decoded is evicted rather than served stale
+                Update::Account(slot, pubkey, account) => {
+                    self.insert(pubkey, slot, account.unwrap_or_default()).await;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(owner: Pubkey, lamports: u64) -> Account {
+        Account {
+            lamports,
+            owner,
+            ..Account::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accounts_expire_after_max_age() {
+        let cache = AccountCache::new(10);
+        let pubkey = Pubkey::new_unique();
+        cache
+            .insert(pubkey, 100, account(Pubkey::new_unique(), 1))
+            .await;
+
+        cache.advance_slot(110).await;
+        assert!(cache.get(&pubkey).await.is_some());
+        cache.advance_slot(111).await;
+        assert!(cache.get(&pubkey).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_older_reads_are_ignored() {
+        let cache = AccountCache::new(10);
+        let pubkey = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        cache.insert(pubkey, 100, account(owner, 2)).await;
+        cache.insert(pubkey, 99, account(owner, 1)).await;
+        assert_eq!(cache.get(&pubkey).await.unwrap().lamports, 2);
+
+        // closing the account evicts it
+        cache.insert(pubkey, 101, account(owner, 0)).await;
+        assert!(cache.get(&pubkey).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_watched_accounts_dont_expire() {
+        let cache = AccountCache::new(10);
+        let program_id = Pubkey::new_unique();
+        let pubkey = Pubkey::new_unique();
+
+        cache.set_watched(&program_id, true).await;
+        cache.insert(pubkey, 100, account(program_id, 1)).await;
+        cache.advance_slot(1_000).await;
+        assert!(cache.get(&pubkey).await.is_some());
+
+        cache.set_watched(&program_id, false).await;
+        assert!(cache.get(&pubkey).await.is_none());
+    }
+}
@@ -0,0 +1,94 @@
+//! Decodes the custom error codes returned by the restaking and vault programs.
+//!
+//! Each crate raising errors owns a range of codes (see
+//! [`jito_restaking_sanitization::error_code`]), so a code identifies both the crate that raised
+//! it and the error, even for transactions calling both programs.
+use jito_restaking_core::result::RestakingCoreError;
+use jito_restaking_sanitization::{error_code::ErrorSource, result::SanitizationError};
+use jito_restaking_sdk::error::RestakingError;
+use jito_vault_core::result::VaultCoreError;
+use jito_vault_sdk::error::VaultError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodedError {
+    pub code: u32,
+    pub source: ErrorSource,
+    pub name: &'static str,
+}
+
+/// Returns the source and name of a custom error code, or `None` if no crate owns the code
+pub fn decode_error(code: u32) -> Option<DecodedError> {
+    let (source, offset) = ErrorSource::from_code(code)?;
+    let name = match source {
+        ErrorSource::Sanitization => SanitizationError::name(offset),
+        ErrorSource::Restaking => RestakingCoreError::name(offset),
+        ErrorSource::Vault => VaultCoreError::name(offset),
+        ErrorSource::RestakingProgram => RestakingError::name(offset),
+        ErrorSource::VaultProgram => VaultError::name(offset),
+    }?;
+    Some(DecodedError { code, source, name })
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::program_error::ProgramError;
+
+    use super::*;
+
+    fn custom_code(error: ProgramError) -> u32 {
+        match error {
+            ProgramError::Custom(code) => code,
+            error => panic!("{error:?} is not a custom error"),
+        }
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let code = custom_code(RestakingCoreError::AvsInvalidAdmin.into());
+        assert_eq!(
+            decode_error(code),
+            Some(DecodedError {
+                code,
+                source: ErrorSource::Restaking,
+                name: "AvsInvalidAdmin",
+            })
+        );
+
+        let code = custom_code(VaultCoreError::VaultInvalidData(String::new()).into());
+        assert_eq!(
+            decode_error(code),
+            Some(DecodedError {
+                code,
+                source: ErrorSource::Vault,
+                name: "VaultInvalidData",
+            })
+        );
+
+        let code = custom_code(VaultError::LrtMintMismatch.into());
+        assert_eq!(
+            decode_error(code),
+            Some(DecodedError {
+                code,
+                source: ErrorSource::VaultProgram,
+                name: "LrtMintMismatch",
+            })
+        );
+
+        let code = custom_code(SanitizationError::TokenAccountInvalidMint.into());
+        assert_eq!(
+            decode_error(code),
+            Some(DecodedError {
+                code,
+                source: ErrorSource::Sanitization,
+                name: "TokenAccountInvalidMint",
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_error() {
+        assert_eq!(decode_error(99), None);
+        assert_eq!(decode_error(1_999), None);
+        assert_eq!(decode_error(u32::MAX), None);
+    }
+}
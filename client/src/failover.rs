@@ -0,0 +1,405 @@
+//! An RPC client that spreads requests over a primary endpoint and a list of fallbacks.
+//!
+//! Requests go to the first healthy endpoint in order. Transport failures mark an endpoint as
+//! unhealthy for [`EndpointConfig::cooldown`] and the request is retried on the next endpoint,
+//! while errors returned by the node itself (bad params, missing accounts, etc.) are passed
+//! straight through. Each endpoint has its own token-bucket rate limiter so a keeper cranking
+//! many vaults stays under public RPC limits instead of getting throttled. Account reads can be
+//! served from an [`AccountCache`] attached with [`FailoverRpcClient::with_cache`].
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use solana_account_decoder::UiAccountEncoding;
+use solana_rpc_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
+use solana_rpc_client_api::{
+    client_error::{Error as ClientError, ErrorKind as ClientErrorKind, Result as ClientResult},
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+    request::{RpcError, RpcResponseErrorData},
+};
+use solana_sdk::{
+    account::Account, commitment_config::CommitmentConfig, hash::Hash, pubkey::Pubkey,
+    signature::Signature,
+};
+use tokio::{sync::Mutex, time::sleep};
+
+use crate::cache::AccountCache;
+
+#[derive(Debug, Clone)]
+pub struct EndpointConfig {
+    /// The RPC URL
+    pub url: String,
+
+    /// Max number of requests per second sent to the endpoint
+    pub requests_per_second: u32,
+
+    /// How long an endpoint is skipped after a transport failure
+    pub cooldown: Duration,
+}
+
+impl EndpointConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            requests_per_second: 10,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+
+    pub const fn with_requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = requests_per_second;
+        self
+    }
+
+    pub const fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+/// Token bucket allowing `capacity` requests per second
+#[derive(Debug)]
+struct RateLimiter {
+    capacity: u32,
+    state: Mutex<RateLimiterState>,
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    tokens: u32,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    fn new(capacity: u32) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            state: Mutex::new(RateLimiterState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request can be sent
+    async fn acquire(&self) {
+        let period = Duration::from_secs(1)
+            .checked_div(self.capacity)
+            .unwrap_or(Duration::from_secs(1));
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let elapsed = state.last_refill.elapsed();
+                let refilled = elapsed
+                    .as_nanos()
+                    .checked_div(period.as_nanos())
+                    .unwrap_or(0)
+                    .min(u128::from(self.capacity));
+                if refilled > 0 {
+                    state.tokens = state
+                        .tokens
+                        .saturating_add(refilled as u32)
+                        .min(self.capacity);
+                    state.last_refill = Instant::now();
+                }
+                if state.tokens > 0 {
+                    state.tokens = state.tokens.saturating_sub(1);
+                    return;
+                }
+                period.saturating_sub(state.last_refill.elapsed())
+            };
+            sleep(wait).await;
+        }
+    }
+}
+
+struct Endpoint {
+    config: EndpointConfig,
+    client: RpcClient,
+    rate_limiter: RateLimiter,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl Endpoint {
+    async fn is_healthy(&self) -> bool {
+        let unhealthy_until = *self.unhealthy_until.lock().await;
+        !matches!(unhealthy_until, Some(until) if Instant::now() < until)
+    }
+
+    async fn mark_unhealthy(&self) {
+        let now = Instant::now();
+        *self.unhealthy_until.lock().await =
+            Some(now.checked_add(self.config.cooldown).unwrap_or(now));
+    }
+
+    async fn mark_healthy(&self) {
+        *self.unhealthy_until.lock().await = None;
+    }
+}
+
+pub struct FailoverRpcClient {
+    endpoints: Vec<Endpoint>,
+    cache: Option<Arc<AccountCache>>,
+}
+
+impl FailoverRpcClient {
+    /// Creates a client from the primary endpoint followed by fallbacks, in priority order
+    pub fn new(endpoints: Vec<EndpointConfig>, commitment: CommitmentConfig) -> Self {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|config| Endpoint {
+                client: RpcClient::new_with_commitment(config.url.clone(), commitment),
+                rate_limiter: RateLimiter::new(config.requests_per_second),
+                unhealthy_until: Mutex::new(None),
+                config,
+            })
+            .collect();
+        Self {
+            endpoints,
+            cache: None,
+        }
+    }
+
+    /// Serves [`Self::get_account`] and [`Self::get_multiple_accounts`] from the cache when
+    /// possible, caching whatever they read from RPC
+    pub fn with_cache(mut self, cache: Arc<AccountCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub const fn cache(&self) -> Option<&Arc<AccountCache>> {
+        self.cache.as_ref()
+    }
+
+    /// Returns the URLs of the endpoints currently considered healthy
+    pub async fn healthy_endpoints(&self) -> Vec<String> {
+        let mut healthy = Vec::new();
+        for endpoint in &self.endpoints {
+            if endpoint.is_healthy().await {
+                healthy.push(endpoint.config.url.clone());
+            }
+        }
+        healthy
+    }
+
+    /// Calls `getHealth` on every endpoint and updates its status, ignoring cooldowns
+    pub async fn health_check(&self) {
+        for endpoint in &self.endpoints {
+            endpoint.rate_limiter.acquire().await;
+            match endpoint.client.get_health().await {
+                Ok(()) => endpoint.mark_healthy().await,
+                Err(_) => endpoint.mark_unhealthy().await,
+            }
+        }
+    }
+
+    /// Runs the request against the first healthy endpoint, failing over on transport errors.
+    /// When every endpoint is in cooldown, they are all tried anyway rather than failing outright.
+    pub async fn call<'a, T, F, Fut>(&'a self, request: F) -> ClientResult<T>
+    where
+        F: Fn(&'a RpcClient) -> Fut,
+        Fut: Future<Output = ClientResult<T>>,
+    {
+        let mut last_error = None;
+        for ignore_cooldown in [false, true] {
+            for endpoint in &self.endpoints {
+                if !ignore_cooldown && !endpoint.is_healthy().await {
+                    continue;
+                }
+                endpoint.rate_limiter.acquire().await;
+                match request(&endpoint.client).await {
+                    Ok(result) => return Ok(result),
+                    Err(e) if is_transport_error(&e) => {
+                        endpoint.mark_unhealthy().await;
+                        last_error = Some(e);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            if last_error.is_some() {
+                break;
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            ClientErrorKind::Custom("no RPC endpoints configured".to_string()).into()
+        }))
+    }
+
+    pub async fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Account> {
+        let Some(cache) = &self.cache else {
+            return self.call(|client| client.get_account(pubkey)).await;
+        };
+        if let Some(account) = cache.get(pubkey).await {
+            return Ok(account);
+        }
+
+        let response = self
+            .call(|client| client.get_account_with_commitment(pubkey, client.commitment()))
+            .await?;
+        let account = response
+            .value
+            .ok_or_else(|| RpcError::ForUser(format!("AccountNotFound: pubkey={pubkey}")))?;
+        cache
+            .insert(*pubkey, response.context.slot, account.clone())
+            .await;
+        Ok(account)
+    }
+
+    /// Returns the accounts in the order requested. With a cache, only the accounts that
+    /// aren't cached are read from RPC.
+    pub async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> ClientResult<Vec<Option<Account>>> {
+        let Some(cache) = &self.cache else {
+            return self
+                .call(|client| client.get_multiple_accounts(pubkeys))
+                .await;
+        };
+        let mut accounts = Vec::with_capacity(pubkeys.len());
+        for pubkey in pubkeys {
+            accounts.push(cache.get(pubkey).await);
+        }
+
+        let missing: Vec<_> = pubkeys
+            .iter()
+            .zip(&accounts)
+            .filter(|(_, account)| account.is_none())
+            .map(|(pubkey, _)| *pubkey)
+            .collect();
+        if missing.is_empty() {
+            return Ok(accounts);
+        }
+        let response = self
+            .call(|client| {
+                client.get_multiple_accounts_with_commitment(&missing, client.commitment())
+            })
+            .await?;
+        let mut fetched = missing.into_iter().zip(response.value);
+        for account in accounts.iter_mut().filter(|account| account.is_none()) {
+            if let Some((pubkey, Some(fetched))) = fetched.next() {
+                cache
+                    .insert(pubkey, response.context.slot, fetched.clone())
+                    .await;
+                *account = Some(fetched);
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Returns the accounts of the program matching every filter. Never served from the cache.
+    pub async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.call(|client| {
+            client.get_program_accounts_with_config(
+                program_id,
+                RpcProgramAccountsConfig {
+                    filters: Some(filters.clone()),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        commitment: Some(client.commitment()),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                    ..RpcProgramAccountsConfig::default()
+                },
+            )
+        })
+        .await
+    }
+
+    pub async fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        self.call(|client| client.get_latest_blockhash()).await
+    }
+
+    /// Sends a legacy or versioned transaction and waits for it to be confirmed
+    pub async fn send_and_confirm_transaction(
+        &self,
+        transaction: &(impl SerializableTransaction + Sync),
+    ) -> ClientResult<Signature> {
+        self.call(|client| client.send_and_confirm_transaction(transaction))
+            .await
+    }
+}
+
+/// Errors that indicate the endpoint itself is unreachable, throttling or behind, as opposed to
+/// the node rejecting the request
+fn is_transport_error(error: &ClientError) -> bool {
+    matches!(
+        error.kind(),
+        ClientErrorKind::Io(_)
+            | ClientErrorKind::Reqwest(_)
+            | ClientErrorKind::RpcError(RpcError::RpcRequestError(_))
+            | ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                data: RpcResponseErrorData::NodeUnhealthy { .. },
+                ..
+            })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Nothing listens on the discard port, so connections are refused immediately
+    const DEAD_ENDPOINT: &str = "http://127.0.0.1:9";
+
+    #[tokio::test]
+    async fn test_rate_limiter_waits_for_refill() {
+        let rate_limiter = RateLimiter::new(10);
+        let start = Instant::now();
+        for _ in 0..11 {
+            rate_limiter.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_failover_marks_dead_endpoints_unhealthy() {
+        let client = FailoverRpcClient::new(
+            vec![
+                EndpointConfig::new(DEAD_ENDPOINT),
+                EndpointConfig::new(DEAD_ENDPOINT).with_cooldown(Duration::ZERO),
+            ],
+            CommitmentConfig::confirmed(),
+        );
+        assert!(client.get_latest_blockhash().await.is_err());
+        assert_eq!(client.healthy_endpoints().await, vec![DEAD_ENDPOINT]);
+    }
+
+    #[tokio::test]
+    async fn test_no_endpoints_fails() {
+        let client = FailoverRpcClient::new(vec![], CommitmentConfig::confirmed());
+        assert!(client.get_latest_blockhash().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cached_accounts_skip_rpc() {
+        let cache = Arc::new(AccountCache::new(10));
+        let client =
+            FailoverRpcClient::new(vec![], CommitmentConfig::confirmed()).with_cache(cache.clone());
+        let cached = Pubkey::new_unique();
+        let account = Account {
+            lamports: 1,
+            ..Account::default()
+        };
+        cache.insert(cached, 100, account.clone()).await;
+
+        assert_eq!(client.get_account(&cached).await.unwrap(), account);
+        assert_eq!(
+            client.get_multiple_accounts(&[cached]).await.unwrap(),
+            vec![Some(account)]
+        );
+        // anything not cached still goes to RPC
+        assert!(client
+            .get_multiple_accounts(&[cached, Pubkey::new_unique()])
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod cache;
+pub mod error;
+pub mod failover;
+pub mod migration;
+pub mod preflight;
+pub mod program_accounts;
+pub mod restaking;
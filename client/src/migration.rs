@@ -0,0 +1,465 @@
+//! Off-chain rehearsal of the layout migrations in [`jito_restaking_core::migration`], so a
+//! program upgrade growing an account can be checked against mainnet state before it ships.
+//!
+//! A [`Snapshot`] holds every account of the restaking program, fetched with `getProgramAccounts`
+//! or read back from a file of `address lamports base64-data` lines. [`rehearse`] migrates each
+//! account off-chain, growing it and recording its layout version, and checks the result against
+//! the current layout of its type, and
+//! [`MigrationReport::batches`] groups the accounts to migrate into the transactions the CLI sends,
+//! one [`jito_restaking_sdk::migrate_account`] instruction per account. Batches are written one
+//! transaction per line with [`write_batches`], so the reviewed file is exactly what gets sent.
+use std::io::{self, BufRead, Write};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::BorshDeserialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::Avs,
+    avs_operator_ticket::AvsOperatorTicket,
+    avs_reward_budget::AvsRewardBudget,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket,
+    config::Config,
+    epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot,
+    metadata::Metadata,
+    migration::{self, layout_len},
+    operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity,
+    operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore,
+    operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake,
+    operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket,
+    reward_claim_status::RewardClaimStatus,
+    reward_distribution::RewardDistribution,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+    AccountType,
+};
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, rent::Rent};
+
+use crate::failover::FailoverRpcClient;
+
+/// An account of the snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotAccount {
+    pub address: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+/// The accounts of the restaking program at some point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    pub accounts: Vec<SnapshotAccount>,
+}
+
+impl Snapshot {
+    /// Fetches every account owned by the program
+    pub async fn fetch(rpc: &FailoverRpcClient, program_id: &Pubkey) -> ClientResult<Self> {
+        let mut accounts: Vec<_> = rpc
+            .get_program_accounts(program_id, vec![])
+            .await?
+            .into_iter()
+            .map(|(address, account)| SnapshotAccount {
+                address,
+                lamports: account.lamports,
+                data: account.data,
+            })
+            .collect();
+        accounts.sort_by_key(|account| account.address);
+        Ok(Self { accounts })
+    }
+
+    /// Reads a snapshot written by [`Snapshot::write`]
+    pub fn read(reader: impl BufRead) -> io::Result<Self> {
+        let mut accounts = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let account = parse_account(&line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid snapshot line {}", index.saturating_add(1)),
+                )
+            })?;
+            accounts.push(account);
+        }
+        Ok(Self { accounts })
+    }
+
+    /// Writes one `address lamports base64-data` line per account
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        for account in &self.accounts {
+            writeln!(
+                writer,
+                "{} {} {}",
+                account.address,
+                account.lamports,
+                STANDARD.encode(&account.data)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_account(line: &str) -> Option<SnapshotAccount> {
+    let mut parts = line.split_whitespace();
+    let account = SnapshotAccount {
+        address: parts.next()?.parse().ok()?,
+        lamports: parts.next()?.parse().ok()?,
+        data: STANDARD.decode(parts.next().unwrap_or_default()).ok()?,
+    };
+    parts.next().is_none().then_some(account)
+}
+
+/// An account the rehearsal migrated successfully
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedMigration {
+    pub address: Pubkey,
+    pub account_type: AccountType,
+    pub from_len: usize,
+    pub to_len: usize,
+
+    /// The fields inserted by each step, in order, none if only the version of the layout is
+    /// recorded
+    pub fields: Vec<&'static str>,
+
+    /// The lamports the payer transfers to keep the account rent exempt
+    pub rent_top_up: u64,
+
+    /// The account data after the migration
+    pub data: Vec<u8>,
+}
+
+/// An account the rehearsal couldn't migrate, which would fail the upgrade if left as is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RehearsalFailure {
+    pub address: Pubkey,
+    pub reason: String,
+}
+
+/// The outcome of [`rehearse`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// The number of accounts already at their current layout
+    pub up_to_date: usize,
+    pub planned: Vec<PlannedMigration>,
+    pub failures: Vec<RehearsalFailure>,
+}
+
+impl MigrationReport {
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// The total lamports the payer transfers for rent across all migrations
+    pub fn total_rent_top_up(&self) -> u64 {
+        self.planned
+            .iter()
+            .fold(0, |total, m| total.saturating_add(m.rent_top_up))
+    }
+
+    /// Groups the accounts to migrate into transactions of at most `max_per_transaction`
+    /// instructions
+    pub fn batches(&self, max_per_transaction: usize) -> Vec<Vec<Pubkey>> {
+        self.planned
+            .chunks(max_per_transaction.max(1))
+            .map(|chunk| chunk.iter().map(|m| m.address).collect())
+            .collect()
+    }
+}
+
+/// Migrates every account of the snapshot off-chain and checks the results against the current
+/// layouts
+pub fn rehearse(snapshot: &Snapshot, rent: &Rent) -> MigrationReport {
+    let mut report = MigrationReport::default();
+    for account in &snapshot.accounts {
+        match rehearse_account(account, rent) {
+            Ok(None) => report.up_to_date = report.up_to_date.saturating_add(1),
+            Ok(Some(planned)) => report.planned.push(planned),
+            Err(reason) => report.failures.push(RehearsalFailure {
+                address: account.address,
+                reason,
+            }),
+        }
+    }
+    report
+}
+
+fn rehearse_account(
+    account: &SnapshotAccount,
+    rent: &Rent,
+) -> Result<Option<PlannedMigration>, String> {
+    let data = &account.data;
+    let account_type = AccountType::deserialize(&mut data.as_slice())
+        .map_err(|_| "unknown account type".to_string())?;
+    let steps = migration::plan(account_type, data.len()).map_err(|_| {
+        format!(
+            "no migration from {} bytes to the {} bytes of the current {account_type:?} layout",
+            data.len(),
+            layout_len(account_type)
+        )
+    })?;
+    let migrated = migration::migrate(account_type, data).map_err(|e| format!("{e:?}"))?;
+    check_layout(account_type, &migrated)?;
+    if migrated == *data {
+        return Ok(None);
+    }
+
+    // the bytes before the first inserted field and the bump after the last one never move
+    let prefix_len = steps.iter().map(|step| step.offset()).min().unwrap_or(0);
+    if migrated.get(..prefix_len) != data.get(..prefix_len) {
+        return Err(format!(
+            "the first {prefix_len} bytes changed in the migrated {account_type:?}"
+        ));
+    }
+    if migrated.last() != data.last() {
+        return Err(format!("the bump moved in the migrated {account_type:?}"));
+    }
+
+    Ok(Some(PlannedMigration {
+        address: account.address,
+        account_type,
+        from_len: data.len(),
+        to_len: migrated.len(),
+        fields: steps.iter().map(|step| step.field).collect(),
+        rent_top_up: rent
+            .minimum_balance(migrated.len())
+            .saturating_sub(account.lamports),
+        data: migrated,
+    }))
+}
+
+/// Checks the data loads as the current layout of the account type
+fn check_layout(account_type: AccountType, data: &[u8]) -> Result<(), String> {
+    fn load<T: ZeroCopy>(data: &[u8]) -> Result<(), String> {
+        T::load(data).map(|_| ()).map_err(|e| e.to_string())
+    }
+    match account_type {
+        AccountType::Config => load::<Config>(data),
+        AccountType::Avs => load::<Avs>(data),
+        AccountType::AvsOperatorTicket => load::<AvsOperatorTicket>(data),
+        AccountType::AvsVaultSlasherTicket => load::<AvsVaultSlasherTicket>(data),
+        AccountType::AvsVaultTicket => load::<AvsVaultTicket>(data),
+        AccountType::Operator => load::<Operator>(data),
+        AccountType::OperatorAvsTicket => load::<OperatorAvsTicket>(data),
+        AccountType::OperatorVaultTicket => load::<OperatorVaultTicket>(data),
+        AccountType::OperatorAvsHeartbeat => load::<OperatorAvsHeartbeat>(data),
+        AccountType::AvsRewardBudget => load::<AvsRewardBudget>(data),
+        AccountType::Metadata => load::<Metadata>(data),
+        AccountType::OperatorAvsCapacity => load::<OperatorAvsCapacity>(data),
+        AccountType::EpochRewardRouter => load::<EpochRewardRouter>(data),
+        AccountType::EpochRewardSnapshot => load::<EpochRewardSnapshot>(data),
+        AccountType::OperatorEpochStake => load::<OperatorEpochStake>(data),
+        AccountType::VaultOperatorEpochStake => load::<VaultOperatorEpochStake>(data),
+        AccountType::OperatorAvsScore => load::<OperatorAvsScore>(data),
+        AccountType::OperatorStakeHistory => load::<OperatorStakeHistory>(data),
+        AccountType::RewardDistribution => load::<RewardDistribution>(data),
+        AccountType::RewardClaimStatus => load::<RewardClaimStatus>(data),
+    }
+}
+
+/// Writes one line of space separated addresses per transaction
+pub fn write_batches(mut writer: impl Write, batches: &[Vec<Pubkey>]) -> io::Result<()> {
+    for batch in batches {
+        let line: Vec<String> = batch.iter().map(Pubkey::to_string).collect();
+        writeln!(writer, "{}", line.join(" "))?;
+    }
+    Ok(())
+}
+
+/// Reads batches written by [`write_batches`]
+pub fn read_batches(reader: impl BufRead) -> io::Result<Vec<Vec<Pubkey>>> {
+    let mut batches = Vec::new();
+    for line in reader.lines() {
+        let batch = line?
+            .split_whitespace()
+            .map(|address| {
+                address.parse().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid address {address}"),
+                    )
+                })
+            })
+            .collect::<io::Result<Vec<Pubkey>>>()?;
+        if !batch.is_empty() {
+            batches.push(batch);
+        }
+    }
+    Ok(batches)
+}
+
+/// The instructions of a batch, paid for by `payer`
+pub fn batch_instructions(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    batch: &[Pubkey],
+) -> Vec<Instruction> {
+    batch
+        .iter()
+        .map(|account| jito_restaking_sdk::migrate_account(program_id, account, payer))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An AVS as written before the rent collector was added
+    fn legacy_avs(admin: Pubkey) -> (Vec<u8>, Vec<u8>) {
+        let avs = Avs::new(
+            Pubkey::new_unique(),
+            admin,
+            admin,
+            admin,
+            admin,
+            admin,
+            0,
+            254,
+        );
+        let current = avs.as_bytes().to_vec();
+        let offset = Avs::LEN - 129 - 32;
+        let legacy = [&current[..offset], &current[offset + 32..]].concat();
+        (legacy, current)
+    }
+
+    #[test]
+    fn test_rehearse_legacy_avs() {
+        let rent = Rent::default();
+        let (legacy, current) = legacy_avs(Pubkey::new_unique());
+        let address = Pubkey::new_unique();
+        let snapshot = Snapshot {
+            accounts: vec![
+                SnapshotAccount {
+                    address,
+                    lamports: rent.minimum_balance(legacy.len()),
+                    data: legacy.clone(),
+                },
+                SnapshotAccount {
+                    address: Pubkey::new_unique(),
+                    lamports: rent.minimum_balance(current.len()),
+                    data: current.clone(),
+                },
+            ],
+        };
+
+        let report = rehearse(&snapshot, &rent);
+        assert!(report.is_ok(), "{:?}", report.failures);
+        assert_eq!(report.up_to_date, 1);
+        assert_eq!(report.planned.len(), 1);
+        let planned = &report.planned[0];
+        assert_eq!(planned.address, address);
+        assert_eq!(planned.account_type, AccountType::Avs);
+        assert_eq!(planned.fields, vec!["rent_collector"]);
+        assert_eq!((planned.from_len, planned.to_len), (legacy.len(), Avs::LEN));
+        // the rent collector defaults to the admin, as for new AVSs
+        assert_eq!(planned.data, current);
+        assert_eq!(
+            planned.rent_top_up,
+            rent.minimum_balance(current.len()) - rent.minimum_balance(legacy.len())
+        );
+        assert_eq!(report.batches(10), vec![vec![address]]);
+    }
+
+    #[test]
+    fn test_rehearse_unversioned_account() {
+        let mut avs = Avs::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            254,
+        );
+        avs.set_version(0);
+        let unversioned = avs.as_bytes().to_vec();
+        avs.set_version(Avs::VERSION + 1);
+        let newer = avs.as_bytes().to_vec();
+        let snapshot = Snapshot {
+            accounts: vec![
+                SnapshotAccount {
+                    address: Pubkey::new_unique(),
+                    lamports: 0,
+                    data: unversioned.clone(),
+                },
+                SnapshotAccount {
+                    address: Pubkey::new_unique(),
+                    lamports: 0,
+                    data: newer,
+                },
+            ],
+        };
+
+        let report = rehearse(&snapshot, &Rent::default());
+        assert_eq!(report.planned.len(), 1);
+        let planned = &report.planned[0];
+        assert!(planned.fields.is_empty());
+        assert_eq!((planned.from_len, planned.to_len), (Avs::LEN, Avs::LEN));
+        assert_eq!(Avs::load(&planned.data).unwrap().version(), Avs::VERSION);
+        // an account written by a newer program is never downgraded
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].reason, "AccountVersionUnsupported");
+    }
+
+    #[test]
+    fn test_rehearse_unknown_layout_fails() {
+        let (legacy, _) = legacy_avs(Pubkey::new_unique());
+        let snapshot = Snapshot {
+            accounts: vec![
+                SnapshotAccount {
+                    address: Pubkey::new_unique(),
+                    lamports: 0,
+                    data: legacy[..legacy.len() - 1].to_vec(),
+                },
+                SnapshotAccount {
+                    address: Pubkey::new_unique(),
+                    lamports: 0,
+                    data: vec![u8::MAX; 8],
+                },
+            ],
+        };
+
+        let report = rehearse(&snapshot, &Rent::default());
+        assert!(report.planned.is_empty());
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failures[1].reason, "unknown account type");
+    }
+
+    #[test]
+    fn test_snapshot_and_batches_round_trip() {
+        let snapshot = Snapshot {
+            accounts: vec![
+                SnapshotAccount {
+                    address: Pubkey::new_unique(),
+                    lamports: 42,
+                    data: vec![1, 2, 3],
+                },
+                SnapshotAccount {
+                    address: Pubkey::new_unique(),
+                    lamports: 0,
+                    data: vec![],
+                },
+            ],
+        };
+        let mut written = Vec::new();
+        snapshot.write(&mut written).unwrap();
+        assert_eq!(Snapshot::read(written.as_slice()).unwrap(), snapshot);
+        assert!(Snapshot::read("not a snapshot".as_bytes()).is_err());
+
+        let batches = vec![
+            vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            vec![Pubkey::new_unique()],
+        ];
+        let mut written = Vec::new();
+        write_batches(&mut written, &batches).unwrap();
+        assert_eq!(read_batches(written.as_slice()).unwrap(), batches);
+    }
+}
@@ -0,0 +1,509 @@
+//! Simulates a transaction before it is broadcast and turns common failures into diagnostics a
+//! caller can act on.
+//!
+//! The restaking and vault programs surface most errors as a bare custom error code, so on an
+//! instruction failure the accounts passed to the failing instruction are fetched and inspected
+//! for the usual culprits: token accounts that haven't been created, tickets that aren't active
+//! and vault delegation lists that need to be cranked for the new epoch. When none of them apply,
+//! the error code is decoded into the crate and error that raised it.
+use borsh::BorshDeserialize;
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket, AccountType as RestakingAccountType,
+};
+use jito_vault_core::{
+    config::Config as VaultConfig, vault_avs_slasher_ticket::VaultAvsSlasherTicket,
+    vault_avs_ticket::VaultAvsTicket, vault_delegation_list::VaultDelegationList,
+    vault_operator_ticket::VaultOperatorTicket, AccountType as VaultAccountType,
+};
+use solana_rpc_client_api::{
+    client_error::Result as ClientResult, config::RpcSimulateTransactionConfig,
+};
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    transaction::{Transaction, TransactionError},
+};
+
+use crate::{
+    error::{decode_error, DecodedError},
+    failover::FailoverRpcClient,
+};
+
+/// The deployed program ids used to recognize restaking and vault accounts
+#[derive(Debug, Clone, Copy)]
+pub struct ProgramIds {
+    pub restaking_program: Pubkey,
+    pub vault_program: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreflightDiagnostic {
+    /// The fee payer account doesn't exist
+    FeePayerNotFound,
+    /// The fee payer can't cover the transaction fee
+    InsufficientFundsForFee,
+    /// The account would be left below the rent-exempt minimum
+    InsufficientFundsForRent { account: Pubkey },
+    /// A token account used by the failing instruction hasn't been created; create the ATA first
+    MissingTokenAccount { account: Pubkey },
+    /// A ticket used by the failing instruction isn't active at the simulated slot
+    InactiveTicket { account: Pubkey },
+    /// The vault delegation list is from a previous epoch; crank `UpdateDelegations` first
+    VaultNeedsUpdate { vault_delegation_list: Pubkey },
+    /// The instruction failed with a custom error raised by the restaking or vault program, or
+    /// by the sanitization shared by both
+    ProgramError {
+        instruction_index: u8,
+        program_id: Pubkey,
+        error: DecodedError,
+    },
+    /// The instruction failed for a reason that couldn't be narrowed down; see the logs
+    InstructionFailed {
+        instruction_index: u8,
+        error: InstructionError,
+    },
+    /// The transaction failed before any instruction ran
+    TransactionFailed(TransactionError),
+}
+
+#[derive(Debug, Clone)]
+pub struct PreflightReport {
+    pub diagnostics: Vec<PreflightDiagnostic>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+impl PreflightReport {
+    /// Returns true if the simulation succeeded
+    pub fn is_ok(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+}
+
+/// Simulates the transaction and diagnoses any failure. Signatures aren't verified and the
+/// blockhash is replaced, so the transaction can be checked before it is signed.
+pub async fn preflight(
+    client: &FailoverRpcClient,
+    transaction: &Transaction,
+    program_ids: &ProgramIds,
+) -> ClientResult<PreflightReport> {
+    let response = client
+        .call(|client| {
+            client.simulate_transaction_with_config(
+                transaction,
+                RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    ..RpcSimulateTransactionConfig::default()
+                },
+            )
+        })
+        .await?;
+    let slot = response.context.slot;
+    let result = response.value;
+
+    let accounts = match &result.err {
+        Some(TransactionError::InstructionError(index, _)) => {
+            let keys = instruction_accounts(transaction, *index);
+            let accounts = client.get_multiple_accounts(&keys).await?;
+            keys.into_iter().zip(accounts).collect()
+        }
+        _ => vec![],
+    };
+
+    Ok(PreflightReport {
+        diagnostics: result
+            .err
+            .map(|err| diagnose(&err, transaction, &accounts, slot, program_ids))
+            .unwrap_or_default(),
+        logs: result.logs.unwrap_or_default(),
+        units_consumed: result.units_consumed,
+    })
+}
+
+/// Returns the accounts passed to the instruction at the given index
+fn instruction_accounts(transaction: &Transaction, instruction_index: u8) -> Vec<Pubkey> {
+    let message = &transaction.message;
+    message
+        .instructions
+        .get(instruction_index as usize)
+        .map(|instruction| {
+            instruction
+                .accounts
+                .iter()
+                .filter_map(|index| message.account_keys.get(*index as usize).copied())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Maps a simulation error to diagnostics, using the state of the accounts passed to the failing
+/// instruction at the simulated slot
+pub fn diagnose(
+    err: &TransactionError,
+    transaction: &Transaction,
+    accounts: &[(Pubkey, Option<Account>)],
+    slot: u64,
+    program_ids: &ProgramIds,
+) -> Vec<PreflightDiagnostic> {
+    let (instruction_index, error) = match err {
+        TransactionError::AccountNotFound => return vec![PreflightDiagnostic::FeePayerNotFound],
+        TransactionError::InsufficientFundsForFee => {
+            return vec![PreflightDiagnostic::InsufficientFundsForFee]
+        }
+        TransactionError::InsufficientFundsForRent { account_index } => {
+            let account = transaction.message.account_keys[*account_index as usize];
+            return vec![PreflightDiagnostic::InsufficientFundsForRent { account }];
+        }
+        TransactionError::InstructionError(instruction_index, error) => (*instruction_index, error),
+        err => return vec![PreflightDiagnostic::TransactionFailed(err.clone())],
+    };
+
+    let message = &transaction.message;
+    let uses_token_program = accounts
+        .iter()
+        .any(|(pubkey, _)| *pubkey == spl_token::id());
+    let vault_epoch_length = accounts.iter().find_map(|(_, account)| {
+        let account = account.as_ref()?;
+        if account.owner != program_ids.vault_program
+            || VaultAccountType::deserialize(&mut account.data.as_slice()).ok()?
+                != VaultAccountType::Config
+        {
+            return None;
+        }
+        VaultConfig::deserialize(&mut account.data.as_slice())
+            .ok()
+            .map(|config| config.epoch_length())
+    });
+
+    let mut diagnostics = Vec::new();
+    for (pubkey, account) in accounts {
+        let is_signer = message
+            .account_keys
+            .iter()
+            .position(|key| key == pubkey)
+            .is_some_and(|index| message.is_signer(index));
+        let diagnostic = match account {
+            None if uses_token_program && !is_signer => {
+                Some(PreflightDiagnostic::MissingTokenAccount { account: *pubkey })
+            }
+            None => None,
+            Some(account) if account.owner == program_ids.restaking_program => {
+                inspect_restaking_account(pubkey, &account.data, slot)
+            }
+            Some(account) if account.owner == program_ids.vault_program => {
+                inspect_vault_account(pubkey, &account.data, slot, vault_epoch_length)
+            }
+            Some(_) => None,
+        };
+        diagnostics.extend(diagnostic);
+    }
+
+    if diagnostics.is_empty() {
+        let program_id = message
+            .instructions
+            .get(instruction_index as usize)
+            .and_then(|instruction| {
+                message
+                    .account_keys
+                    .get(instruction.program_id_index as usize)
+            })
+            .filter(|program_id| {
+                **program_id == program_ids.restaking_program
+                    || **program_id == program_ids.vault_program
+            });
+        let decoded = match error {
+            InstructionError::Custom(code) => decode_error(*code),
+            _ => None,
+        };
+        diagnostics.push(match (program_id, decoded) {
+            (Some(program_id), Some(error)) => PreflightDiagnostic::ProgramError {
+                instruction_index,
+                program_id: *program_id,
+                error,
+            },
+            _ => PreflightDiagnostic::InstructionFailed {
+                instruction_index,
+                error: error.clone(),
+            },
+        });
+    }
+    diagnostics
+}
+
+fn inspect_restaking_account(
+    pubkey: &Pubkey,
+    mut data: &[u8],
+    slot: u64,
+) -> Option<PreflightDiagnostic> {
+    let inactive = match RestakingAccountType::deserialize(&mut &data[..]).ok()? {
+        RestakingAccountType::AvsOperatorTicket => AvsOperatorTicket::deserialize(&mut data)
+            .ok()?
+            .check_active(slot)
+            .is_err(),
+        RestakingAccountType::AvsVaultTicket => AvsVaultTicket::deserialize(&mut data)
+            .ok()?
+            .check_active(slot)
+            .is_err(),
+        RestakingAccountType::AvsVaultSlasherTicket => {
+            AvsVaultSlasherTicket::deserialize(&mut data)
+                .ok()?
+                .check_active(slot)
+                .is_err()
+        }
+        RestakingAccountType::OperatorAvsTicket => OperatorAvsTicket::deserialize(&mut data)
+            .ok()?
+            .check_active(slot)
+            .is_err(),
+        RestakingAccountType::OperatorVaultTicket => OperatorVaultTicket::deserialize(&mut data)
+            .ok()?
+            .check_active(slot)
+            .is_err(),
+        _ => false,
+    };
+    inactive.then_some(PreflightDiagnostic::InactiveTicket { account: *pubkey })
+}
+
+fn inspect_vault_account(
+    pubkey: &Pubkey,
+    mut data: &[u8],
+    slot: u64,
+    epoch_length: Option<u64>,
+) -> Option<PreflightDiagnostic> {
+    let inactive = match VaultAccountType::deserialize(&mut &data[..]).ok()? {
+        VaultAccountType::VaultAvsTicket => VaultAvsTicket::deserialize(&mut data)
+            .ok()?
+            .check_active(slot)
+            .is_err(),
+        VaultAccountType::VaultOperatorTicket => VaultOperatorTicket::deserialize(&mut data)
+            .ok()?
+            .check_active(slot)
+            .is_err(),
+        VaultAccountType::VaultAvsSlasherTicket => VaultAvsSlasherTicket::deserialize(&mut data)
+            .ok()?
+            .check_active(slot)
+            .is_err(),
+        VaultAccountType::VaultDelegationList => {
+            let epoch_length = epoch_length.filter(|epoch_length| *epoch_length > 0)?;
+            let needs_update = VaultDelegationList::deserialize(&mut data)
+                .ok()?
+                .needs_update(slot, epoch_length);
+            return needs_update.then_some(PreflightDiagnostic::VaultNeedsUpdate {
+                vault_delegation_list: *pubkey,
+            });
+        }
+        _ => false,
+    };
+    inactive.then_some(PreflightDiagnostic::InactiveTicket { account: *pubkey })
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jito_restaking_core::avs_operator_ticket::AvsOperatorTicket;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        message::Message,
+    };
+
+    use super::*;
+
+    fn program_ids() -> ProgramIds {
+        ProgramIds {
+            restaking_program: Pubkey::new_unique(),
+            vault_program: Pubkey::new_unique(),
+        }
+    }
+
+    fn transaction(program_id: &Pubkey, accounts: &[Pubkey], payer: &Pubkey) -> Transaction {
+        let instruction = Instruction {
+            program_id: *program_id,
+            accounts: accounts
+                .iter()
+                .map(|pubkey| AccountMeta::new(*pubkey, false))
+                .collect(),
+            data: vec![],
+        };
+        Transaction::new_unsigned(Message::new(&[instruction], Some(payer)))
+    }
+
+    fn account(owner: &Pubkey, data: Vec<u8>) -> Account {
+        Account {
+            lamports: 1_000_000,
+            data,
+            owner: *owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_diagnose_insufficient_rent() {
+        let program_ids = program_ids();
+        let payer = Pubkey::new_unique();
+        let transaction = transaction(&program_ids.vault_program, &[], &payer);
+        assert_eq!(
+            diagnose(
+                &TransactionError::InsufficientFundsForRent { account_index: 0 },
+                &transaction,
+                &[],
+                0,
+                &program_ids,
+            ),
+            vec![PreflightDiagnostic::InsufficientFundsForRent { account: payer }]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_missing_token_account() {
+        let program_ids = program_ids();
+        let payer = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let transaction = transaction(
+            &program_ids.vault_program,
+            &[token_account, spl_token::id()],
+            &payer,
+        );
+        let accounts = vec![
+            (token_account, None),
+            (
+                spl_token::id(),
+                Some(account(&Pubkey::new_unique(), vec![])),
+            ),
+        ];
+        assert_eq!(
+            diagnose(
+                &TransactionError::InstructionError(0, InstructionError::Custom(0)),
+                &transaction,
+                &accounts,
+                0,
+                &program_ids,
+            ),
+            vec![PreflightDiagnostic::MissingTokenAccount {
+                account: token_account
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_inactive_ticket() {
+        let program_ids = program_ids();
+        let payer = Pubkey::new_unique();
+        let ticket = Pubkey::new_unique();
+        let transaction = transaction(&program_ids.restaking_program, &[ticket], &payer);
+
+        let mut avs_operator_ticket =
+            AvsOperatorTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 10, 255);
+        avs_operator_ticket.deactivate(20).unwrap();
+        let accounts = vec![(
+            ticket,
+            Some(account(
+                &program_ids.restaking_program,
+                avs_operator_ticket.try_to_vec().unwrap(),
+            )),
+        )];
+
+        assert_eq!(
+            diagnose(
+                &TransactionError::InstructionError(0, InstructionError::Custom(0)),
+                &transaction,
+                &accounts,
+                30,
+                &program_ids,
+            ),
+            vec![PreflightDiagnostic::InactiveTicket { account: ticket }]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_vault_needs_update() {
+        let program_ids = program_ids();
+        let payer = Pubkey::new_unique();
+        let config = Pubkey::new_unique();
+        let vault_delegation_list = Pubkey::new_unique();
+        let transaction = transaction(
+            &program_ids.vault_program,
+            &[config, vault_delegation_list],
+            &payer,
+        );
+
+        let vault_config = VaultConfig::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        let epoch_length = vault_config.epoch_length();
+        let accounts = vec![
+            (
+                config,
+                Some(account(
+                    &program_ids.vault_program,
+                    vault_config.try_to_vec().unwrap(),
+                )),
+            ),
+            (
+                vault_delegation_list,
+                Some(account(
+                    &program_ids.vault_program,
+                    VaultDelegationList::new(Pubkey::new_unique(), 255)
+                        .try_to_vec()
+                        .unwrap(),
+                )),
+            ),
+        ];
+
+        assert_eq!(
+            diagnose(
+                &TransactionError::InstructionError(0, InstructionError::Custom(0)),
+                &transaction,
+                &accounts,
+                epoch_length,
+                &program_ids,
+            ),
+            vec![PreflightDiagnostic::VaultNeedsUpdate {
+                vault_delegation_list
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_program_error() {
+        let program_ids = program_ids();
+        let payer = Pubkey::new_unique();
+        let transaction = transaction(&program_ids.restaking_program, &[], &payer);
+        let code = 1_000;
+        assert_eq!(
+            diagnose(
+                &TransactionError::InstructionError(0, InstructionError::Custom(code)),
+                &transaction,
+                &[],
+                0,
+                &program_ids,
+            ),
+            vec![PreflightDiagnostic::ProgramError {
+                instruction_index: 0,
+                program_id: program_ids.restaking_program,
+                error: decode_error(code).unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_unknown_instruction_error() {
+        let program_ids = program_ids();
+        let payer = Pubkey::new_unique();
+        let transaction = transaction(&program_ids.vault_program, &[], &payer);
+        assert_eq!(
+            diagnose(
+                &TransactionError::InstructionError(0, InstructionError::Custom(u32::MAX)),
+                &transaction,
+                &[],
+                0,
+                &program_ids,
+            ),
+            vec![PreflightDiagnostic::InstructionFailed {
+                instruction_index: 0,
+                error: InstructionError::Custom(u32::MAX),
+            }]
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Enumeration of restaking and vault accounts with `getProgramAccounts`.
+//!
+//! Every account starts with a one byte discriminator, so [`discriminator_filter`] narrows a
+//! query to one account type, and the tickets expose the byte offsets of the keys they link (e.g.
+//! [`OperatorAvsTicket::AVS_OFFSET`]) for [`pubkey_filter`]. The `get_all_*` functions combine
+//! both to answer questions like "which operators joined this AVS" in a single request.
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket, epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot, operator_avs_capacity::OperatorAvsCapacity,
+    operator_avs_heartbeat::OperatorAvsHeartbeat, operator_avs_score::OperatorAvsScore,
+    operator_avs_ticket::OperatorAvsTicket, operator_epoch_stake::OperatorEpochStake,
+    operator_vault_ticket::OperatorVaultTicket,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use jito_vault_core::{
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+    vault_operator_ticket::VaultOperatorTicket,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use solana_rpc_client_api::{
+    client_error::{ErrorKind as ClientErrorKind, Result as ClientResult},
+    filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::failover::FailoverRpcClient;
+
+/// Matches the accounts of type `T`
+pub fn discriminator_filter<T: ZeroCopy>() -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![T::DISCRIMINATOR]))
+}
+
+/// Matches the accounts holding `pubkey` at `offset`
+pub fn pubkey_filter(offset: usize, pubkey: &Pubkey) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, pubkey.to_bytes().to_vec()))
+}
+
+/// Returns the accounts of type `T` owned by the program and matching every filter
+pub async fn get_all<T: ZeroCopy>(
+    rpc: &FailoverRpcClient,
+    program_id: &Pubkey,
+    mut filters: Vec<RpcFilterType>,
+) -> ClientResult<Vec<(Pubkey, T)>> {
+    filters.insert(0, discriminator_filter::<T>());
+    let accounts = rpc.get_program_accounts(program_id, filters).await?;
+    let mut loaded = Vec::with_capacity(accounts.len());
+    for (address, account) in accounts {
+        let data = T::load(&account.data)
+            .map_err(|e| ClientErrorKind::Custom(format!("failed to load {address}: {e}")))?;
+        loaded.push((address, *data));
+    }
+    Ok(loaded)
+}
+
+macro_rules! get_all_by_key {
+    ($($(#[$meta:meta])* $name:ident($key:ident) -> $account:ident::$offset:ident;)*) => {
+        $(
+            $(#[$meta])*
+            pub async fn $name(
+                rpc: &FailoverRpcClient,
+                program_id: &Pubkey,
+                $key: &Pubkey,
+            ) -> ClientResult<Vec<(Pubkey, $account)>> {
+                get_all(rpc, program_id, vec![pubkey_filter($account::$offset, $key)]).await
+            }
+        )*
+    };
+}
+
+get_all_by_key! {
+    /// The operators the AVS added, active or not
+    get_all_avs_operator_tickets_for_avs(avs) -> AvsOperatorTicket::AVS_OFFSET;
+    /// The AVSs that added the operator, active or not
+    get_all_avs_operator_tickets_for_operator(operator) -> AvsOperatorTicket::OPERATOR_OFFSET;
+    /// The vaults the AVS added, active or not
+    get_all_avs_vault_tickets_for_avs(avs) -> AvsVaultTicket::AVS_OFFSET;
+    /// The AVSs that added the vault, active or not
+    get_all_avs_vault_tickets_for_vault(vault) -> AvsVaultTicket::VAULT_OFFSET;
+    /// The slashers the AVS allowed, across all of its vaults
+    get_all_avs_vault_slasher_tickets_for_avs(avs) -> AvsVaultSlasherTicket::AVS_OFFSET;
+    /// The slashers allowed to slash the vault, across all AVSs
+    get_all_avs_vault_slasher_tickets_for_vault(vault) -> AvsVaultSlasherTicket::VAULT_OFFSET;
+    /// The AVSs the operator joined, active or not
+    get_all_operator_avs_tickets_for_operator(operator) -> OperatorAvsTicket::OPERATOR_OFFSET;
+    /// The operators that joined the AVS, active or not
+    get_all_operator_avs_tickets_for_avs(avs) -> OperatorAvsTicket::AVS_OFFSET;
+    /// The vaults the operator supports, active or not
+    get_all_operator_vault_tickets_for_operator(operator) -> OperatorVaultTicket::OPERATOR_OFFSET;
+    /// The operators supporting the vault, active or not
+    get_all_operator_vault_tickets_for_vault(vault) -> OperatorVaultTicket::VAULT_OFFSET;
+    /// The heartbeats of the operator, one per AVS
+    get_all_operator_avs_heartbeats_for_operator(operator) -> OperatorAvsHeartbeat::OPERATOR_OFFSET;
+    /// The heartbeats of the operators of the AVS
+    get_all_operator_avs_heartbeats_for_avs(avs) -> OperatorAvsHeartbeat::AVS_OFFSET;
+    /// The attested scores of the operators of the AVS
+    get_all_operator_avs_scores_for_avs(avs) -> OperatorAvsScore::AVS_OFFSET;
+    /// The capacity the operator advertised, one per AVS
+    get_all_operator_avs_capacities_for_operator(operator) -> OperatorAvsCapacity::OPERATOR_OFFSET;
+    /// The capacity the operators of the AVS advertised
+    get_all_operator_avs_capacities_for_avs(avs) -> OperatorAvsCapacity::AVS_OFFSET;
+    /// The reward routers of the AVS, one per epoch and mint
+    get_all_epoch_reward_routers_for_avs(avs) -> EpochRewardRouter::AVS_OFFSET;
+    /// The stake snapshots of the router, paid out or not
+    get_all_epoch_reward_snapshots_for_router(router) -> EpochRewardSnapshot::ROUTER_OFFSET;
+    /// The stake delegated to the operator, one per snapshotted epoch
+    get_all_operator_epoch_stakes_for_operator(operator) -> OperatorEpochStake::OPERATOR_OFFSET;
+    /// The stake the vault delegated, one per operator and snapshotted epoch
+    get_all_vault_operator_epoch_stakes_for_vault(vault) -> VaultOperatorEpochStake::VAULT_OFFSET;
+    /// The stake delegated to the operator, one per vault and snapshotted epoch
+    get_all_vault_operator_epoch_stakes_for_operator(operator) -> VaultOperatorEpochStake::OPERATOR_OFFSET;
+    /// The AVSs the vault added, active or not
+    get_all_vault_avs_tickets_for_vault(vault) -> VaultAvsTicket::VAULT_OFFSET;
+    /// The vaults that added the AVS, active or not
+    get_all_vault_avs_tickets_for_avs(avs) -> VaultAvsTicket::AVS_OFFSET;
+    /// The operators the vault added, active or not
+    get_all_vault_operator_tickets_for_vault(vault) -> VaultOperatorTicket::VAULT_OFFSET;
+    /// The vaults that added the operator, active or not
+    get_all_vault_operator_tickets_for_operator(operator) -> VaultOperatorTicket::OPERATOR_OFFSET;
+    /// The slashers the vault accepted
+    get_all_vault_avs_slasher_tickets_for_vault(vault) -> VaultAvsSlasherTicket::VAULT_OFFSET;
+    /// The amounts slashed from the vault, one per slasher, operator and epoch
+    get_all_vault_avs_slasher_operator_tickets_for_vault(vault)
+        -> VaultAvsSlasherOperatorTicket::VAULT_OFFSET;
+    /// The amounts slashed for the operator, one per vault, slasher and epoch
+    get_all_vault_avs_slasher_operator_tickets_for_operator(operator)
+        -> VaultAvsSlasherOperatorTicket::OPERATOR_OFFSET;
+    /// The pending withdrawals of the staker, across all vaults
+    get_all_vault_staker_withdrawal_tickets_for_staker(staker)
+        -> VaultStakerWithdrawalTicket::STAKER_OFFSET;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(filters: &[RpcFilterType], data: &[u8]) -> bool {
+        filters.iter().all(|filter| match filter {
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn test_ticket_offsets_match_layout() {
+        let [a, b, c, d] = [(); 4].map(|_| Pubkey::new_unique());
+
+        let ticket = OperatorAvsTicket::new(a, b, 0, 0, 255);
+        assert!(matches(
+            &[
+                discriminator_filter::<OperatorAvsTicket>(),
+                pubkey_filter(OperatorAvsTicket::OPERATOR_OFFSET, &a),
+                pubkey_filter(OperatorAvsTicket::AVS_OFFSET, &b),
+            ],
+            ticket.as_bytes()
+        ));
+        assert!(!matches(
+            &[discriminator_filter::<AvsOperatorTicket>()],
+            ticket.as_bytes()
+        ));
+        assert!(!matches(
+            &[pubkey_filter(OperatorAvsTicket::AVS_OFFSET, &a)],
+            ticket.as_bytes()
+        ));
+
+        let ticket = AvsVaultSlasherTicket::new(a, b, c, 0, [0; 32], 0, 0, 255);
+        assert!(matches(
+            &[
+                pubkey_filter(AvsVaultSlasherTicket::AVS_OFFSET, &a),
+                pubkey_filter(AvsVaultSlasherTicket::VAULT_OFFSET, &b),
+                pubkey_filter(AvsVaultSlasherTicket::SLASHER_OFFSET, &c),
+            ],
+            ticket.as_bytes()
+        ));
+
+        let ticket = VaultAvsSlasherOperatorTicket::new(a, b, c, d, 0, 0, 255);
+        assert!(matches(
+            &[
+                pubkey_filter(VaultAvsSlasherOperatorTicket::VAULT_OFFSET, &a),
+                pubkey_filter(VaultAvsSlasherOperatorTicket::AVS_OFFSET, &b),
+                pubkey_filter(VaultAvsSlasherOperatorTicket::SLASHER_OFFSET, &c),
+                pubkey_filter(VaultAvsSlasherOperatorTicket::OPERATOR_OFFSET, &d),
+            ],
+            ticket.as_bytes()
+        ));
+
+        let ticket = VaultStakerWithdrawalTicket::new(a, b, c, 0, 0, 0, 255);
+        assert!(matches(
+            &[
+                pubkey_filter(VaultStakerWithdrawalTicket::VAULT_OFFSET, &a),
+                pubkey_filter(VaultStakerWithdrawalTicket::STAKER_OFFSET, &b),
+            ],
+            ticket.as_bytes()
+        ));
+
+        let stake = VaultOperatorEpochStake::new(a, b, 0, 0, 0, 255);
+        assert!(matches(
+            &[
+                discriminator_filter::<VaultOperatorEpochStake>(),
+                pubkey_filter(VaultOperatorEpochStake::VAULT_OFFSET, &a),
+                pubkey_filter(VaultOperatorEpochStake::OPERATOR_OFFSET, &b),
+            ],
+            stake.as_bytes()
+        ));
+    }
+}
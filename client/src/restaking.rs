@@ -0,0 +1,1177 @@
+//! A client for the restaking program, for operator tooling and keepers running against a real
+//! cluster.
+//!
+//! [`RestakingClient`] mirrors the test fixture's getters and instruction helpers, but reads and
+//! sends through a [`FailoverRpcClient`], so it inherits its failover, rate limiting and account
+//! cache. Transactions are confirmed at the commitment the RPC client was created with, and the
+//! compute unit price and limit set on the client are prepended to every transaction. PDAs are
+//! derived from the accounts they belong to, so callers only pass the AVS, operator and vault.
+//! Instructions touching many accounts can be sent in a v0 transaction with
+//! [`RestakingClient::send_versioned`], compressing the accounts found in address lookup tables.
+//! Signers are taken as `&dyn Signer`, so a keeper can sign with a keypair file or with a remote
+//! signer of `jito-restaking-signer` whose key never leaves Vault or a KMS.
+use borsh::BorshDeserialize;
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, avs_reward_budget::AvsRewardBudget,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config, epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot, metadata::Metadata, operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore, operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use jito_restaking_sdk::{batch::ComputeBudget, lookup_table::v0_message};
+use solana_rpc_client_api::client_error::{ErrorKind as ClientErrorKind, Result as ClientResult};
+use solana_sdk::{
+    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+    signer::{signers::Signers, SignerError},
+    transaction::{Transaction, VersionedTransaction},
+};
+
+use crate::{
+    failover::{EndpointConfig, FailoverRpcClient},
+    preflight::ProgramIds,
+};
+
+/// Reads restaking accounts and sends restaking instructions over RPC
+pub struct RestakingClient {
+    rpc: FailoverRpcClient,
+    program_ids: ProgramIds,
+    compute_unit_price: Option<u64>,
+    compute_unit_limit: Option<u32>,
+}
+
+impl RestakingClient {
+    pub const fn new(rpc: FailoverRpcClient, program_ids: ProgramIds) -> Self {
+        Self {
+            rpc,
+            program_ids,
+            compute_unit_price: None,
+            compute_unit_limit: None,
+        }
+    }
+
+    /// A client for a single endpoint confirming transactions at the confirmed commitment
+    pub fn from_url(url: impl Into<String>, program_ids: ProgramIds) -> Self {
+        Self::new(
+            FailoverRpcClient::new(
+                vec![EndpointConfig::new(url)],
+                CommitmentConfig::confirmed(),
+            ),
+            program_ids,
+        )
+    }
+
+    /// Sets the priority fee of every transaction, in micro-lamports per compute unit
+    pub const fn with_compute_unit_price(mut self, micro_lamports: u64) -> Self {
+        self.compute_unit_price = Some(micro_lamports);
+        self
+    }
+
+    /// Sets the compute unit limit of every transaction, which lowers the priority fee paid
+    pub const fn with_compute_unit_limit(mut self, units: u32) -> Self {
+        self.compute_unit_limit = Some(units);
+        self
+    }
+
+    pub const fn rpc(&self) -> &FailoverRpcClient {
+        &self.rpc
+    }
+
+    pub const fn program_ids(&self) -> &ProgramIds {
+        &self.program_ids
+    }
+
+    fn program_id(&self) -> &Pubkey {
+        &self.program_ids.restaking_program
+    }
+
+    pub fn config_address(&self) -> Pubkey {
+        Config::find_program_address(self.program_id()).0
+    }
+
+    pub fn avs_address(&self, base: &Pubkey) -> Pubkey {
+        Avs::find_program_address(self.program_id(), base).0
+    }
+
+    pub fn operator_address(&self, base: &Pubkey) -> Pubkey {
+        Operator::find_program_address(self.program_id(), base).0
+    }
+
+    pub async fn get_account<T: BorshDeserialize>(&self, address: &Pubkey) -> ClientResult<T> {
+        let account = self.rpc.get_account(address).await?;
+        T::deserialize(&mut account.data.as_slice()).map_err(|e| {
+            ClientErrorKind::Custom(format!("failed to deserialize {address}: {e}")).into()
+        })
+    }
+
+    pub async fn get_config(&self) -> ClientResult<Config> {
+        self.get_account(&self.config_address()).await
+    }
+
+    pub async fn get_avs(&self, avs: &Pubkey) -> ClientResult<Avs> {
+        self.get_account(avs).await
+    }
+
+    pub async fn get_operator(&self, operator: &Pubkey) -> ClientResult<Operator> {
+        self.get_account(operator).await
+    }
+
+    /// The nonce the next message the AVS admins sign off-chain shall carry, see
+    /// [`jito_restaking_sdk::relayed_message`]
+    pub async fn get_avs_nonce(&self, avs: &Pubkey) -> ClientResult<u64> {
+        Ok(self.get_avs(avs).await?.nonce())
+    }
+
+    /// The nonce the next message the operator admin signs off-chain shall carry, see
+    /// [`jito_restaking_sdk::relayed_message`]
+    pub async fn get_operator_nonce(&self, operator: &Pubkey) -> ClientResult<u64> {
+        Ok(self.get_operator(operator).await?.nonce())
+    }
+
+    pub async fn get_avs_operator_ticket(
+        &self,
+        avs: &Pubkey,
+        operator: &Pubkey,
+    ) -> ClientResult<AvsOperatorTicket> {
+        let address = AvsOperatorTicket::find_program_address(self.program_id(), avs, operator).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_avs_vault_ticket(
+        &self,
+        avs: &Pubkey,
+        vault: &Pubkey,
+    ) -> ClientResult<AvsVaultTicket> {
+        let address = AvsVaultTicket::find_program_address(self.program_id(), avs, vault).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_avs_vault_slasher_ticket(
+        &self,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+    ) -> ClientResult<AvsVaultSlasherTicket> {
+        let address =
+            AvsVaultSlasherTicket::find_program_address(self.program_id(), avs, vault, slasher).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_operator_avs_ticket(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> ClientResult<OperatorAvsTicket> {
+        let address = OperatorAvsTicket::find_program_address(self.program_id(), operator, avs).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_operator_vault_ticket(
+        &self,
+        operator: &Pubkey,
+        vault: &Pubkey,
+    ) -> ClientResult<OperatorVaultTicket> {
+        let address =
+            OperatorVaultTicket::find_program_address(self.program_id(), operator, vault).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_operator_avs_heartbeat(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> ClientResult<OperatorAvsHeartbeat> {
+        let address =
+            OperatorAvsHeartbeat::find_program_address(self.program_id(), operator, avs).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_operator_avs_score(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> ClientResult<OperatorAvsScore> {
+        let address = OperatorAvsScore::find_program_address(self.program_id(), operator, avs).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_operator_avs_capacity(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> ClientResult<OperatorAvsCapacity> {
+        let address = OperatorAvsCapacity::find_program_address(self.program_id(), operator, avs).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_avs_reward_budget(
+        &self,
+        avs: &Pubkey,
+        mint: &Pubkey,
+    ) -> ClientResult<AvsRewardBudget> {
+        let address = AvsRewardBudget::find_program_address(self.program_id(), avs, mint).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_epoch_reward_router(
+        &self,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+    ) -> ClientResult<EpochRewardRouter> {
+        let address =
+            EpochRewardRouter::find_program_address(self.program_id(), avs, mint, epoch).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_epoch_reward_snapshot(
+        &self,
+        router: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> ClientResult<EpochRewardSnapshot> {
+        let address =
+            EpochRewardSnapshot::find_program_address(self.program_id(), router, vault, operator).0;
+        self.get_account(&address).await
+    }
+
+    /// The stake delegated to the operator in the epoch, over the vaults snapshotted so far
+    pub async fn get_operator_epoch_stake(
+        &self,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> ClientResult<OperatorEpochStake> {
+        let address =
+            OperatorEpochStake::find_program_address(self.program_id(), operator, epoch).0;
+        self.get_account(&address).await
+    }
+
+    pub async fn get_vault_operator_epoch_stake(
+        &self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> ClientResult<VaultOperatorEpochStake> {
+        let address = VaultOperatorEpochStake::find_program_address(
+            self.program_id(),
+            vault,
+            operator,
+            epoch,
+        )
+        .0;
+        self.get_account(&address).await
+    }
+
+    /// The commitment over the epoch stake snapshots of the operator archived so far
+    pub async fn get_operator_stake_history(
+        &self,
+        operator: &Pubkey,
+    ) -> ClientResult<OperatorStakeHistory> {
+        let address = OperatorStakeHistory::find_program_address(self.program_id(), operator).0;
+        self.get_account(&address).await
+    }
+
+    /// The name, URI and icon of the AVS or operator, if its metadata admin set them
+    pub async fn get_metadata(&self, parent: &Pubkey) -> ClientResult<Metadata> {
+        let address = Metadata::find_program_address(self.program_id(), parent).0;
+        self.get_account(&address).await
+    }
+
+    /// The instructions with the compute budget instructions of the client prepended
+    pub fn with_compute_budget(&self, instructions: &[Instruction]) -> Vec<Instruction> {
+        self.compute_unit_limit
+            .map(ComputeBudgetInstruction::set_compute_unit_limit)
+            .into_iter()
+            .chain(
+                self.compute_unit_price
+                    .map(ComputeBudgetInstruction::set_compute_unit_price),
+            )
+            .chain(instructions.iter().cloned())
+            .collect()
+    }
+
+    /// Builds a transaction of the instructions with the compute budget of the client, signed by
+    /// the payer and signers
+    pub fn transaction<T: Signers + ?Sized>(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &T,
+        blockhash: Hash,
+    ) -> Result<Transaction, SignerError> {
+        let mut transaction = Transaction::new_with_payer(
+            &self.with_compute_budget(instructions),
+            Some(&payer.pubkey()),
+        );
+        transaction.try_partial_sign(&[payer], blockhash)?;
+        transaction.try_partial_sign(signers, blockhash)?;
+        if !transaction.is_signed() {
+            return Err(SignerError::NotEnoughSigners);
+        }
+        Ok(transaction)
+    }
+
+    /// The compute budget of the client, as put before the instructions of every transaction
+    pub const fn compute_budget(&self) -> ComputeBudget {
+        ComputeBudget {
+            unit_limit: self.compute_unit_limit,
+            unit_price: self.compute_unit_price,
+        }
+    }
+
+    /// Fetches the address lookup table, e.g. one created with
+    /// [`jito_restaking_sdk::lookup_table::create_lookup_table`]
+    pub async fn get_lookup_table(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<AddressLookupTableAccount> {
+        let account = self.rpc.get_account(address).await?;
+        let table = AddressLookupTable::deserialize(&account.data).map_err(|e| {
+            ClientErrorKind::Custom(format!("failed to deserialize {address}: {e}"))
+        })?;
+        Ok(AddressLookupTableAccount {
+            key: *address,
+            addresses: table.addresses.to_vec(),
+        })
+    }
+
+    /// Builds a v0 transaction of the instructions with the compute budget of the client, with the
+    /// accounts found in the lookup tables compressed, signed by the payer and signers
+    pub fn versioned_transaction(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+        lookup_tables: &[AddressLookupTableAccount],
+        blockhash: Hash,
+    ) -> ClientResult<VersionedTransaction> {
+        let message = v0_message(
+            instructions,
+            &payer.pubkey(),
+            &self.compute_budget(),
+            lookup_tables,
+            &blockhash,
+        )
+        .map_err(|e| ClientErrorKind::Custom(format!("failed to compile message: {e:?}")))?;
+
+        // a signer may hold several roles, each signs once
+        let mut unique_signers = vec![payer];
+        for &signer in signers {
+            if !unique_signers
+                .iter()
+                .any(|unique| unique.pubkey() == signer.pubkey())
+            {
+                unique_signers.push(signer);
+            }
+        }
+        Ok(VersionedTransaction::try_new(message, &unique_signers)?)
+    }
+
+    /// Signs the instructions with the payer and signers, sends them and waits for confirmation
+    pub async fn send(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> ClientResult<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = self.transaction(instructions, payer, signers, blockhash)?;
+        self.rpc.send_and_confirm_transaction(&transaction).await
+    }
+
+    /// Like [`Self::send`], in a v0 transaction compressing the accounts found in the lookup
+    /// tables, for instructions with too many accounts for a legacy transaction
+    pub async fn send_versioned(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+        lookup_tables: &[AddressLookupTableAccount],
+    ) -> ClientResult<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction =
+            self.versioned_transaction(instructions, payer, signers, lookup_tables, blockhash)?;
+        self.rpc.send_and_confirm_transaction(&transaction).await
+    }
+
+    pub async fn initialize_config(
+        &self,
+        config_admin: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::initialize_config(
+                self.program_id(),
+                &self.config_address(),
+                &config_admin.pubkey(),
+                &self.program_ids.vault_program,
+                &payer.pubkey(),
+            )],
+            payer,
+            &[config_admin],
+        )
+        .await
+    }
+
+    /// Initializes the AVS derived from `avs_base`, returning its address with the signature
+    pub async fn initialize_avs(
+        &self,
+        avs_admin: &dyn Signer,
+        avs_base: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<(Pubkey, Signature)> {
+        let avs = self.avs_address(&avs_base.pubkey());
+        let signature = self
+            .send(
+                &[jito_restaking_sdk::initialize_avs(
+                    self.program_id(),
+                    &self.config_address(),
+                    &avs,
+                    &avs_admin.pubkey(),
+                    &avs_base.pubkey(),
+                    &payer.pubkey(),
+                )],
+                payer,
+                &[avs_admin, avs_base],
+            )
+            .await?;
+        Ok((avs, signature))
+    }
+
+    /// Initializes the operator derived from `operator_base`, returning its address with the
+    /// signature
+    pub async fn initialize_operator(
+        &self,
+        operator_admin: &dyn Signer,
+        operator_base: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<(Pubkey, Signature)> {
+        let operator = self.operator_address(&operator_base.pubkey());
+        let signature = self
+            .send(
+                &[jito_restaking_sdk::initialize_operator(
+                    self.program_id(),
+                    &self.config_address(),
+                    &operator,
+                    &operator_admin.pubkey(),
+                    &operator_base.pubkey(),
+                    &payer.pubkey(),
+                )],
+                payer,
+                &[operator_admin, operator_base],
+            )
+            .await?;
+        Ok((operator, signature))
+    }
+
+    pub async fn avs_add_vault(
+        &self,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        avs_admin: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_add_vault(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                vault,
+                &AvsVaultTicket::find_program_address(self.program_id(), avs, vault).0,
+                &avs_admin.pubkey(),
+                &payer.pubkey(),
+            )],
+            payer,
+            &[avs_admin],
+        )
+        .await
+    }
+
+    pub async fn avs_remove_vault(
+        &self,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        avs_admin: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_remove_vault(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                vault,
+                &AvsVaultTicket::find_program_address(self.program_id(), avs, vault).0,
+                &avs_admin.pubkey(),
+            )],
+            avs_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn avs_add_operator(
+        &self,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        avs_admin: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_add_operator(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                operator,
+                &AvsOperatorTicket::find_program_address(self.program_id(), avs, operator).0,
+                &OperatorAvsTicket::find_program_address(self.program_id(), operator, avs).0,
+                &avs_admin.pubkey(),
+                &payer.pubkey(),
+            )],
+            payer,
+            &[avs_admin],
+        )
+        .await
+    }
+
+    pub async fn avs_remove_operator(
+        &self,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        avs_admin: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_remove_operator(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                operator,
+                &AvsOperatorTicket::find_program_address(self.program_id(), avs, operator).0,
+                &avs_admin.pubkey(),
+            )],
+            avs_admin,
+            &[],
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn avs_add_vault_slasher(
+        &self,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+        avs_admin: &dyn Signer,
+        payer: &dyn Signer,
+        max_slashable_per_epoch: u64,
+        metadata_hash: [u8; 32],
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_add_vault_slasher(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                vault,
+                slasher,
+                &AvsVaultTicket::find_program_address(self.program_id(), avs, vault).0,
+                &AvsVaultSlasherTicket::find_program_address(
+                    self.program_id(),
+                    avs,
+                    vault,
+                    slasher,
+                )
+                .0,
+                &avs_admin.pubkey(),
+                &payer.pubkey(),
+                max_slashable_per_epoch,
+                metadata_hash,
+            )],
+            payer,
+            &[avs_admin],
+        )
+        .await
+    }
+
+    pub async fn avs_remove_vault_slasher(
+        &self,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+        avs_admin: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_remove_vault_slasher(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                vault,
+                slasher,
+                &AvsVaultTicket::find_program_address(self.program_id(), avs, vault).0,
+                &AvsVaultSlasherTicket::find_program_address(
+                    self.program_id(),
+                    avs,
+                    vault,
+                    slasher,
+                )
+                .0,
+                &avs_admin.pubkey(),
+            )],
+            avs_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn avs_set_admin(
+        &self,
+        avs: &Pubkey,
+        old_admin: &dyn Signer,
+        new_admin: &Pubkey,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_set_admin(
+                self.program_id(),
+                avs,
+                &old_admin.pubkey(),
+                new_admin,
+            )],
+            old_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn avs_accept_admin(
+        &self,
+        avs: &Pubkey,
+        new_admin: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_accept_admin(
+                self.program_id(),
+                avs,
+                &new_admin.pubkey(),
+            )],
+            new_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn operator_set_admin(
+        &self,
+        operator: &Pubkey,
+        old_admin: &dyn Signer,
+        new_admin: &Pubkey,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_set_admin(
+                self.program_id(),
+                operator,
+                &old_admin.pubkey(),
+                new_admin,
+            )],
+            old_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn operator_accept_admin(
+        &self,
+        operator: &Pubkey,
+        new_admin: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_accept_admin(
+                self.program_id(),
+                operator,
+                &new_admin.pubkey(),
+            )],
+            new_admin,
+            &[],
+        )
+        .await
+    }
+
+    /// Rotates the voter of the operator, keeping the replaced one in the operator's voter history
+    pub async fn operator_set_voter(
+        &self,
+        operator: &Pubkey,
+        admin: &dyn Signer,
+        voter: &Pubkey,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_set_voter(
+                self.program_id(),
+                operator,
+                &admin.pubkey(),
+                voter,
+            )],
+            admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn operator_add_avs(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        operator_admin: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_add_avs(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                avs,
+                &OperatorAvsTicket::find_program_address(self.program_id(), operator, avs).0,
+                &operator_admin.pubkey(),
+                &payer.pubkey(),
+            )],
+            payer,
+            &[operator_admin],
+        )
+        .await
+    }
+
+    pub async fn operator_remove_avs(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        operator_admin: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_remove_avs(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                avs,
+                &OperatorAvsTicket::find_program_address(self.program_id(), operator, avs).0,
+                &operator_admin.pubkey(),
+            )],
+            operator_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn operator_add_vault(
+        &self,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        operator_admin: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_add_vault(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                vault,
+                &OperatorVaultTicket::find_program_address(self.program_id(), operator, vault).0,
+                &operator_admin.pubkey(),
+                &payer.pubkey(),
+            )],
+            payer,
+            &[operator_admin],
+        )
+        .await
+    }
+
+    pub async fn operator_remove_vault(
+        &self,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        operator_admin: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_remove_vault(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                vault,
+                &OperatorVaultTicket::find_program_address(self.program_id(), operator, vault).0,
+                &operator_admin.pubkey(),
+            )],
+            operator_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn operator_heartbeat(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        voter: &dyn Signer,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_heartbeat(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                avs,
+                &OperatorAvsTicket::find_program_address(self.program_id(), operator, avs).0,
+                &OperatorAvsHeartbeat::find_program_address(self.program_id(), operator, avs).0,
+                &voter.pubkey(),
+                &payer.pubkey(),
+            )],
+            payer,
+            &[voter],
+        )
+        .await
+    }
+
+    /// Attests the performance score of the operator, signed by the AVS scorer
+    pub async fn avs_attest_operator_score(
+        &self,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        scorer: &dyn Signer,
+        payer: &dyn Signer,
+        score_bps: u16,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::avs_attest_operator_score(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                operator,
+                &AvsOperatorTicket::find_program_address(self.program_id(), avs, operator).0,
+                &OperatorAvsScore::find_program_address(self.program_id(), operator, avs).0,
+                &scorer.pubkey(),
+                &payer.pubkey(),
+                score_bps,
+            )],
+            payer,
+            &[scorer],
+        )
+        .await
+    }
+
+    /// Sets the share of the rewards the operator keeps, signed by the operator admin
+    pub async fn set_operator_fee(
+        &self,
+        operator: &Pubkey,
+        admin: &dyn Signer,
+        operator_fee_bps: u16,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::set_operator_fee(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                &admin.pubkey(),
+                operator_fee_bps,
+            )],
+            admin,
+            &[],
+        )
+        .await
+    }
+
+    /// Advertises the max additional stake the operator can handle for the AVS
+    pub async fn operator_set_avs_capacity(
+        &self,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        admin: &dyn Signer,
+        payer: &dyn Signer,
+        capacity: u64,
+    ) -> ClientResult<Signature> {
+        self.send(
+            &[jito_restaking_sdk::operator_set_avs_capacity(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                avs,
+                &OperatorAvsTicket::find_program_address(self.program_id(), operator, avs).0,
+                &OperatorAvsCapacity::find_program_address(self.program_id(), operator, avs).0,
+                &admin.pubkey(),
+                &payer.pubkey(),
+                capacity,
+            )],
+            payer,
+            &[admin],
+        )
+        .await
+    }
+
+    /// Deposits rewards for the AVS to pay out pro-rata to the stake snapshotted during `epoch`.
+    /// The associated token account of the router must exist.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn avs_deposit_epoch_rewards(
+        &self,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        depositor_token_account: &Pubkey,
+        depositor: &dyn Signer,
+        payer: &dyn Signer,
+        epoch: u64,
+        amount: u64,
+    ) -> ClientResult<Signature> {
+        let vault_config =
+            jito_vault_core::config::Config::find_program_address(&self.program_ids.vault_program)
+                .0;
+        self.send(
+            &[jito_restaking_sdk::avs_deposit_epoch_rewards(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                &vault_config,
+                &EpochRewardRouter::find_program_address(self.program_id(), avs, mint, epoch).0,
+                mint,
+                depositor_token_account,
+                &depositor.pubkey(),
+                &payer.pubkey(),
+                epoch,
+                amount,
+            )],
+            payer,
+            &[depositor],
+        )
+        .await
+    }
+
+    /// Archives an epoch stake snapshot of the operator past the retention window into its stake
+    /// history, returning the rent of the snapshot to the rent collector of the operator
+    pub async fn archive_epoch_stake(
+        &self,
+        operator: &Pubkey,
+        epoch_stake: &Pubkey,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        let rent_collector = self.get_operator(operator).await?.rent_collector();
+        let vault_config =
+            jito_vault_core::config::Config::find_program_address(&self.program_ids.vault_program)
+                .0;
+        self.send(
+            &[jito_restaking_sdk::archive_epoch_stake(
+                self.program_id(),
+                &self.config_address(),
+                operator,
+                &vault_config,
+                &OperatorStakeHistory::find_program_address(self.program_id(), operator).0,
+                epoch_stake,
+                &rent_collector,
+                &payer.pubkey(),
+            )],
+            payer,
+            &[],
+        )
+        .await
+    }
+
+    /// Pays out the rewards of a snapshot once the epoch of its router ended
+    pub async fn distribute_epoch_rewards(
+        &self,
+        router: &Pubkey,
+        snapshot: &Pubkey,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        let epoch_reward_router = self.get_account::<EpochRewardRouter>(router).await?;
+        let epoch_reward_snapshot = self.get_account::<EpochRewardSnapshot>(snapshot).await?;
+        self.send(
+            &[jito_restaking_sdk::distribute_epoch_rewards(
+                self.program_id(),
+                &self.config_address(),
+                &epoch_reward_router.avs(),
+                router,
+                snapshot,
+                &epoch_reward_router.mint(),
+                &epoch_reward_snapshot.operator(),
+                &epoch_reward_snapshot.vault(),
+            )],
+            payer,
+            &[],
+        )
+        .await
+    }
+
+    /// Pays out the elapsed epochs of the AVS reward budget for `mint` from `avs_token_account` to
+    /// the receiver stored in the budget
+    pub async fn avs_distribute_rewards(
+        &self,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        avs_token_account: &Pubkey,
+        payer: &dyn Signer,
+    ) -> ClientResult<Signature> {
+        let avs_reward_budget =
+            AvsRewardBudget::find_program_address(self.program_id(), avs, mint).0;
+        let receiver = self
+            .get_account::<AvsRewardBudget>(&avs_reward_budget)
+            .await?
+            .receiver();
+        self.send(
+            &[jito_restaking_sdk::avs_distribute_rewards(
+                self.program_id(),
+                &self.config_address(),
+                avs,
+                &avs_reward_budget,
+                mint,
+                avs_token_account,
+                &receiver,
+                &spl_token::id(),
+            )],
+            payer,
+            &[],
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{compute_budget, signature::Keypair};
+
+    use super::*;
+
+    fn client() -> RestakingClient {
+        RestakingClient::new(
+            FailoverRpcClient::new(vec![], CommitmentConfig::confirmed()),
+            ProgramIds {
+                restaking_program: Pubkey::new_unique(),
+                vault_program: Pubkey::new_unique(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_compute_budget_is_prepended() {
+        let instruction = Instruction::new_with_bytes(Pubkey::new_unique(), &[1], vec![]);
+        assert_eq!(
+            client().with_compute_budget(std::slice::from_ref(&instruction)),
+            vec![instruction.clone()]
+        );
+
+        let instructions = client()
+            .with_compute_unit_price(1_000)
+            .with_compute_unit_limit(200_000)
+            .with_compute_budget(std::slice::from_ref(&instruction));
+        assert_eq!(
+            instructions,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                ComputeBudgetInstruction::set_compute_unit_price(1_000),
+                instruction,
+            ]
+        );
+        assert!(instructions[..2]
+            .iter()
+            .all(|instruction| instruction.program_id == compute_budget::id()));
+    }
+
+    #[test]
+    fn test_transaction_is_signed_by_payer_and_signers() {
+        let payer = Keypair::new();
+        let new_admin = Keypair::new();
+        let instruction = jito_restaking_sdk::avs_accept_admin(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &new_admin.pubkey(),
+        );
+
+        let transaction = client()
+            .transaction(
+                std::slice::from_ref(&instruction),
+                &payer,
+                &[&new_admin],
+                Hash::default(),
+            )
+            .unwrap();
+        assert_eq!(transaction.message.account_keys[0], payer.pubkey());
+        transaction.verify().unwrap();
+
+        assert!(client()
+            .transaction(&[instruction], &payer, &[] as &[&Keypair], Hash::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_versioned_transaction_compresses_lookup_table_accounts() {
+        let client = client().with_compute_unit_price(1_000);
+        let admin = Keypair::new();
+        let instruction = jito_restaking_sdk::avs_accept_admin(
+            client.program_id(),
+            &Pubkey::new_unique(),
+            &admin.pubkey(),
+        );
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: instruction
+                .accounts
+                .iter()
+                .map(|meta| meta.pubkey)
+                .filter(|pubkey| *pubkey != admin.pubkey())
+                .collect(),
+        };
+
+        // the admin pays and signs once for both roles
+        let transaction = client
+            .versioned_transaction(
+                std::slice::from_ref(&instruction),
+                &admin,
+                &[&admin],
+                std::slice::from_ref(&table),
+                Hash::default(),
+            )
+            .unwrap();
+        assert!(transaction
+            .verify_with_results()
+            .into_iter()
+            .all(|verified| verified));
+        assert_eq!(transaction.signatures.len(), 1);
+        let static_keys = transaction.message.static_account_keys();
+        assert_eq!(static_keys[0], admin.pubkey());
+        assert!(static_keys.iter().all(|key| !table.addresses.contains(key)));
+        assert_eq!(transaction.message.instructions().len(), 2);
+    }
+
+    #[test]
+    fn test_versioned_transaction_signs_with_boxed_signers() {
+        // remote signers are loaded as boxed signers, as keepers hold them
+        let payer: Box<dyn Signer> = Box::new(Keypair::new());
+        let new_admin: Box<dyn Signer> = Box::new(Keypair::new());
+        let instruction = jito_restaking_sdk::avs_accept_admin(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &new_admin.pubkey(),
+        );
+
+        let transaction = client()
+            .versioned_transaction(
+                &[instruction],
+                payer.as_ref(),
+                &[new_admin.as_ref()],
+                &[],
+                Hash::default(),
+            )
+            .unwrap();
+        assert_eq!(transaction.signatures.len(), 2);
+        assert!(transaction
+            .verify_with_results()
+            .into_iter()
+            .all(|verified| verified));
+    }
+
+    #[tokio::test]
+    async fn test_send_without_endpoints_fails() {
+        let payer = Keypair::new();
+        assert!(client()
+            .avs_accept_admin(&Pubkey::new_unique(), &payer)
+            .await
+            .is_err());
+    }
+}
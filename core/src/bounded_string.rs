@@ -0,0 +1,225 @@
+//! A fixed-capacity UTF-8 string for names, symbols and URIs.
+//!
+//! [`BoundedString`] always serializes to one length byte followed by `N` bytes of string data
+//! padded with zeros, so accounts and instructions holding one never change size with their
+//! contents. Every string is validated the same way when it is built and when it is deserialized.
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter},
+    io::{Error, ErrorKind, Read, Write},
+};
+
+use borsh::{
+    schema::{Declaration, Definition, Fields},
+    BorshDeserialize, BorshSchema, BorshSerialize,
+};
+use thiserror::Error;
+
+/// Max length of a token name, matching the Metaplex token metadata limit
+pub const MAX_NAME_LENGTH: usize = 32;
+
+/// Max length of a token symbol, matching the Metaplex token metadata limit
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+
+/// Max length of a metadata URI, matching the Metaplex token metadata limit
+pub const MAX_URI_LENGTH: usize = 200;
+
+pub type BoundedName = BoundedString<MAX_NAME_LENGTH>;
+pub type BoundedSymbol = BoundedString<MAX_SYMBOL_LENGTH>;
+pub type BoundedUri = BoundedString<MAX_URI_LENGTH>;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BoundedStringError {
+    #[error("string is {len} bytes, the max is {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("string contains a control character")]
+    ControlCharacter,
+    #[error("string is not valid UTF-8")]
+    InvalidUtf8,
+    #[error("string padding is not zeroed")]
+    InvalidPadding,
+}
+
+/// A UTF-8 string of at most `N` bytes, where `N` shall be at most 255. Control characters are
+/// rejected so names and URIs render the same everywhere.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BoundedString<const N: usize> {
+    len: u8,
+    data: [u8; N],
+}
+
+impl<const N: usize> BoundedString<N> {
+    pub fn new(value: &str) -> Result<Self, BoundedStringError> {
+        let bytes = value.as_bytes();
+        let len = u8::try_from(bytes.len())
+            .ok()
+            .filter(|len| usize::from(*len) <= N)
+            .ok_or(BoundedStringError::TooLong {
+                len: bytes.len(),
+                max: N,
+            })?;
+        if value.chars().any(char::is_control) {
+            return Err(BoundedStringError::ControlCharacter);
+        }
+
+        let mut data = [0; N];
+        data[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self { len, data })
+    }
+
+    pub fn as_str(&self) -> &str {
+        // validated when built or deserialized
+        std::str::from_utf8(&self.data[..usize::from(self.len)]).unwrap_or_default()
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The max number of bytes the string can hold
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    fn from_parts(len: u8, data: [u8; N]) -> Result<Self, BoundedStringError> {
+        let len_usize = usize::from(len);
+        if len_usize > N {
+            return Err(BoundedStringError::TooLong {
+                len: len_usize,
+                max: N,
+            });
+        }
+        if data[len_usize..].iter().any(|b| *b != 0) {
+            return Err(BoundedStringError::InvalidPadding);
+        }
+        let value =
+            std::str::from_utf8(&data[..len_usize]).map_err(|_| BoundedStringError::InvalidUtf8)?;
+        if value.chars().any(char::is_control) {
+            return Err(BoundedStringError::ControlCharacter);
+        }
+        Ok(Self { len, data })
+    }
+}
+
+impl<const N: usize> Default for BoundedString<N> {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            data: [0; N],
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for BoundedString<N> {
+    type Error = BoundedStringError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize> Debug for BoundedString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Display for BoundedString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> BorshSerialize for BoundedString<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[self.len])?;
+        writer.write_all(&self.data)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for BoundedString<N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut len = [0; 1];
+        reader.read_exact(&mut len)?;
+        let mut data = [0; N];
+        reader.read_exact(&mut data)?;
+        Self::from_parts(len[0], data).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+/// Described as the struct it serializes as, so decoders see the padded layout
+impl<const N: usize> BorshSchema for BoundedString<N> {
+    fn add_definitions_recursively(definitions: &mut HashMap<Declaration, Definition>) {
+        let fields = Fields::NamedFields(vec![
+            ("len".to_string(), u8::declaration()),
+            ("data".to_string(), <[u8; N]>::declaration()),
+        ]);
+        Self::add_definition(
+            Self::declaration(),
+            Definition::Struct { fields },
+            definitions,
+        );
+        <[u8; N]>::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!("BoundedString<{N}>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::*;
+
+    #[test]
+    fn test_bounded_string_round_trip() {
+        let symbol = BoundedSymbol::new("jitoSOL").unwrap();
+        assert_eq!(symbol.as_str(), "jitoSOL");
+        assert_eq!(symbol.len(), 7);
+
+        let data = symbol.try_to_vec().unwrap();
+        assert_eq!(data.len(), 1 + MAX_SYMBOL_LENGTH);
+        assert_eq!(data[0], 7);
+        assert_eq!(&data[1..8], b"jitoSOL");
+        assert_eq!(&data[8..], &[0; 3]);
+        assert_eq!(BoundedSymbol::try_from_slice(&data).unwrap(), symbol);
+    }
+
+    #[test]
+    fn test_bounded_string_too_long_fails() {
+        assert_eq!(
+            BoundedSymbol::new("restaked SOL"),
+            Err(BoundedStringError::TooLong { len: 12, max: 10 })
+        );
+        assert!(BoundedSymbol::new("restakSOL!").is_ok());
+    }
+
+    #[test]
+    fn test_bounded_string_control_character_fails() {
+        assert_eq!(
+            BoundedName::new("Restaked\0SOL"),
+            Err(BoundedStringError::ControlCharacter)
+        );
+    }
+
+    #[test]
+    fn test_bounded_string_invalid_data_fails() {
+        let mut data = BoundedSymbol::new("rSOL").unwrap().try_to_vec().unwrap();
+        data[6] = 1;
+        assert!(BoundedSymbol::try_from_slice(&data).is_err());
+
+        let mut data = BoundedSymbol::new("rSOL").unwrap().try_to_vec().unwrap();
+        data[0] = 11;
+        assert!(BoundedSymbol::try_from_slice(&data).is_err());
+
+        let mut data = BoundedSymbol::new("rSOL").unwrap().try_to_vec().unwrap();
+        data[1] = 0xff;
+        assert!(BoundedSymbol::try_from_slice(&data).is_err());
+    }
+}
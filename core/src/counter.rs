@@ -0,0 +1,116 @@
+//! A monotonically increasing count held in an account, e.g. the number of vaults or a nonce.
+//!
+//! [`Counter`] serializes exactly like a `u64`, so it can replace one without changing the account
+//! layout. Incrementing past `u64::MAX` returns the error the caller passes in instead of wrapping
+//! or being dropped, and every increment within [`Counter::NEAR_LIMIT_MARGIN`] of the max logs a
+//! `CounterNearLimit` event so indexers notice long before it fails.
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use serde::Serialize;
+use solana_program::msg;
+
+use crate::pod::PodU64;
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    BorshSerialize,
+    BorshSchema,
+    BorshDeserialize,
+    Serialize,
+    Pod,
+    Zeroable,
+)]
+#[repr(transparent)]
+pub struct Counter(PodU64);
+
+impl Counter {
+    /// Increments leaving fewer than this many increments before overflowing are logged
+    pub const NEAR_LIMIT_MARGIN: u64 = 1 << 32;
+
+    pub const fn new(value: u64) -> Self {
+        Self(PodU64::new(value))
+    }
+
+    pub const fn get(&self) -> u64 {
+        self.0.get()
+    }
+
+    /// The number of increments left before the counter overflows
+    pub const fn remaining(&self) -> u64 {
+        u64::MAX.saturating_sub(self.get())
+    }
+
+    pub const fn is_near_limit(&self) -> bool {
+        self.remaining() < Self::NEAR_LIMIT_MARGIN
+    }
+
+    /// Increments the counter, returning the new value or `overflow` if it is already at the max.
+    /// `name` identifies the counter in the near limit event.
+    pub fn increment<E>(&mut self, name: &str, overflow: E) -> Result<u64, E> {
+        let value = self.get().checked_add(1).ok_or(overflow)?;
+        self.0 = PodU64::new(value);
+        if self.is_near_limit() {
+            msg!(
+                "CounterNearLimit: counter={} value={} remaining={}",
+                name,
+                value,
+                self.remaining()
+            );
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+
+    use crate::counter::Counter;
+
+    #[test]
+    fn test_increment_ok() {
+        let mut counter = Counter::default();
+        assert_eq!(counter.increment("test", ()), Ok(1));
+        assert_eq!(counter.increment("test", ()), Ok(2));
+        assert_eq!(counter.get(), 2);
+        assert!(!counter.is_near_limit());
+    }
+
+    #[test]
+    fn test_increment_overflow_fails() {
+        let mut counter = Counter::new(u64::MAX);
+        assert_eq!(counter.increment("test", "overflow"), Err("overflow"));
+        assert_eq!(counter.get(), u64::MAX);
+    }
+
+    #[test]
+    fn test_increment_up_to_max_ok() {
+        let mut counter = Counter::new(u64::MAX - 1);
+        assert_eq!(counter.increment("test", ()), Ok(u64::MAX));
+        assert_eq!(counter.remaining(), 0);
+        assert!(counter.increment("test", ()).is_err());
+    }
+
+    #[test]
+    fn test_near_limit() {
+        let counter = Counter::new(u64::MAX - Counter::NEAR_LIMIT_MARGIN);
+        assert!(!counter.is_near_limit());
+        let counter = Counter::new(u64::MAX - Counter::NEAR_LIMIT_MARGIN + 1);
+        assert!(counter.is_near_limit());
+    }
+
+    #[test]
+    fn test_serializes_as_u64() {
+        assert_eq!(
+            Counter::new(42).try_to_vec().unwrap(),
+            42_u64.try_to_vec().unwrap()
+        );
+    }
+}
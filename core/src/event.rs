@@ -0,0 +1,126 @@
+//! Structured events emitted by the programs, so indexers can follow state transitions from the
+//! transaction logs instead of diffing accounts.
+//!
+//! An event is a Borsh-encoded enum logged with `sol_log_data`, which shows up in the logs as
+//! `Program data: <base64>`. The enum's variant index is the first byte of the encoding and
+//! identifies the event type, so new events are appended and existing ones are never reordered.
+//! [`Event::from_logs`] decodes the events a program emitted from a transaction's log messages,
+//! skipping data logged by other programs it invoked or was invoked by.
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+pub trait Event: BorshSerialize + BorshDeserialize {
+    /// Logs the event with `sol_log_data`
+    fn emit(&self) -> ProgramResult {
+        sol_log_data(&[&self.try_to_vec()?]);
+        Ok(())
+    }
+
+    /// Decodes an event from the data of one `sol_log_data` call
+    fn decode(data: &[u8]) -> Option<Self> {
+        Self::try_from_slice(data).ok()
+    }
+
+    /// Decodes the events `program_id` emitted, in order, from a transaction's log messages.
+    /// Data that doesn't decode as an event is skipped.
+    fn from_logs<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Vec<Self> {
+        program_data(program_id, logs)
+            .iter()
+            .filter_map(|data| Self::decode(data))
+            .collect()
+    }
+}
+
+/// Returns the data `program_id` logged with `sol_log_data`, in order, from a transaction's log
+/// messages. The fields of each call are concatenated.
+pub fn program_data<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Vec<Vec<u8>> {
+    let program_id = program_id.to_string();
+    // programs currently executing, innermost last
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut data = Vec::new();
+    for log in logs {
+        let log = log.as_ref();
+        if let Some(fields) = log.strip_prefix(PROGRAM_DATA) {
+            if invocations.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            let decoded: Option<Vec<Vec<u8>>> = fields
+                .split(' ')
+                .map(|field| STANDARD.decode(field).ok())
+                .collect();
+            if let Some(decoded) = decoded {
+                data.push(decoded.concat());
+            }
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            match (words.next(), words.next()) {
+                (Some(program), Some("invoke")) => invocations.push(program),
+                (Some(program), Some("success" | "failed:"))
+                    if invocations.last() == Some(&program) =>
+                {
+                    invocations.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::pubkey::Pubkey;
+
+    use super::Event;
+
+    #[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+    enum TestEvent {
+        Created { id: u64 },
+        Removed { id: u64 },
+    }
+
+    impl Event for TestEvent {}
+
+    fn data_log(event: &TestEvent) -> String {
+        format!(
+            "Program data: {}",
+            STANDARD.encode(event.try_to_vec().unwrap())
+        )
+    }
+
+    #[test]
+    fn test_from_logs_only_decodes_program_events() {
+        let program_id = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            data_log(&TestEvent::Created { id: 1 }),
+            format!("Program {other} invoke [2]"),
+            data_log(&TestEvent::Created { id: 2 }),
+            format!("Program {other} consumed 100 of 200000 compute units"),
+            format!("Program {other} success"),
+            "Program data: not-base64".to_string(),
+            data_log(&TestEvent::Removed { id: 1 }),
+            format!("Program {program_id} success"),
+            data_log(&TestEvent::Removed { id: 3 }),
+        ];
+
+        assert_eq!(
+            TestEvent::from_logs(&program_id, &logs),
+            vec![TestEvent::Created { id: 1 }, TestEvent::Removed { id: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_variant_index_is_discriminator() {
+        let data = TestEvent::Removed { id: 7 }.try_to_vec().unwrap();
+        assert_eq!(data[0], 1);
+        assert_eq!(TestEvent::decode(&data), Some(TestEvent::Removed { id: 7 }));
+        assert_eq!(TestEvent::decode(&[2, 0]), None);
+    }
+}
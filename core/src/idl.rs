@@ -0,0 +1,478 @@
+//! Exports a program's interface as a shank IDL.
+//!
+//! [`IdlExport`] reads the accounts of every instruction from the `#[account(...)]` attributes of
+//! the [`shank::ShankInstruction`] enum, parsed from the source it's given, and everything else
+//! from the [`BorshSchema`] of the instruction enum and the account types, so the IDL can't drift
+//! from the layouts the programs read. Types referenced by instructions and accounts are defined
+//! under `types`, in the order they're first referenced. `Option`, `Vec` and arrays are inlined
+//! and [`solana_program::pubkey::Pubkey`] is `publicKey`, as in IDLs generated by shank. Generic
+//! types are named after their declaration, so `BoundedString<32>` is `BoundedString32`.
+use std::collections::HashMap;
+
+use borsh::{
+    schema::{Declaration, Definition, Fields},
+    BorshSchema,
+};
+use jito_restaking_sanitization::error_code::ErrorSource;
+use shank_macro_impl::{
+    instruction::Instruction,
+    syn::{self, Attribute, Item, Lit, Meta},
+};
+
+const PRIMITIVES: [&str; 15] = [
+    "bool", "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "f32", "f64",
+    "string", "bytes",
+];
+
+#[derive(Default)]
+pub struct IdlExport {
+    name: String,
+    version: String,
+    instructions: Vec<InstructionEntry>,
+    accounts: Vec<Declaration>,
+    errors: Vec<(u32, &'static str)>,
+    definitions: HashMap<Declaration, Definition>,
+}
+
+impl IdlExport {
+    pub fn new(name: &str, version: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            version: version.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the program's instruction enum, whose [`shank::ShankInstruction`] derive is read from
+    /// `source`. Panics if the source doesn't declare the enum or its variants differ from `T`.
+    pub fn instruction<T: BorshSchema>(&mut self, source: &str) {
+        let declaration = T::declaration();
+        T::add_definitions_recursively(&mut self.definitions);
+
+        let file = syn::parse_file(source).expect("instruction source shall parse");
+        let item_enum = file
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::Enum(item_enum) if item_enum.ident == declaration => Some(item_enum),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("{declaration} isn't declared in the source"));
+        let instruction = Instruction::try_from_item_enum(item_enum, false)
+            .expect("instruction enum shall parse")
+            .unwrap_or_else(|| panic!("{declaration} doesn't derive ShankInstruction"));
+
+        let Some(Definition::Enum { variants }) = self.definitions.get(&declaration).cloned()
+        else {
+            panic!("{declaration} isn't an enum");
+        };
+        assert_eq!(
+            variants.len(),
+            instruction.variants.len(),
+            "{declaration} variants differ from its schema"
+        );
+
+        for ((variant, (name, fields)), syn_variant) in instruction
+            .variants
+            .iter()
+            .zip(variants.iter())
+            .zip(item_enum.variants.iter())
+        {
+            assert_eq!(
+                variant.ident, name,
+                "{declaration} variants differ from its schema"
+            );
+
+            let accounts: Vec<_> = variant
+                .accounts
+                .iter()
+                .map(|account| {
+                    let mut entry = format!(
+                        r#"{{"name": {}, "isMut": {}, "isSigner": {}"#,
+                        json_string(&camel_case(&account.name)),
+                        account.writable,
+                        account.signer
+                    );
+                    if account.optional {
+                        entry.push_str(r#", "isOptional": true"#);
+                    }
+                    if let Some(desc) = &account.desc {
+                        entry.push_str(&format!(r#", "docs": [{}]"#, json_string(desc)));
+                    }
+                    entry.push('}');
+                    entry
+                })
+                .collect();
+            self.instructions.push(InstructionEntry {
+                name: camel_case(name),
+                docs: docs_json(&syn_variant.attrs),
+                accounts,
+                args: fields.clone(),
+                discriminant: variant.discriminant,
+            });
+        }
+    }
+
+    /// Adds an account type
+    pub fn account<T: BorshSchema>(&mut self) {
+        self.accounts.push(T::declaration());
+        T::add_definitions_recursively(&mut self.definitions);
+    }
+
+    /// Adds the errors of `source`, named by `name` from the offset of the error in its range
+    pub fn errors(&mut self, source: ErrorSource, name: impl Fn(u32) -> Option<&'static str>) {
+        self.errors
+            .extend((0..).map_while(|offset| name(offset).map(|name| (source.code(offset), name))));
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut types = Types::default();
+        let instructions: Vec<_> = self
+            .instructions
+            .iter()
+            .map(|instruction| {
+                format!(
+                    r#"{{"name": {}, "docs": {}, "accounts": [{}], "args": {}, "discriminant": {{"type": "u8", "value": {}}}}}"#,
+                    json_string(&instruction.name),
+                    instruction.docs,
+                    instruction.accounts.join(", "),
+                    self.args_json(&instruction.args, &mut types),
+                    instruction.discriminant
+                )
+            })
+            .collect();
+        let accounts: Vec<_> = self
+            .accounts
+            .iter()
+            .map(|account| {
+                let Some(Definition::Struct { fields }) = self.definitions.get(account) else {
+                    panic!("account {account} isn't a struct");
+                };
+                format!(
+                    r#"{{"name": {}, "type": {{"kind": "struct", "fields": {}}}}}"#,
+                    json_string(account),
+                    self.struct_fields_json(fields, &mut types)
+                )
+            })
+            .collect();
+
+        let mut defined = Vec::new();
+        let mut next = 0;
+        while let Some(declaration) = types.order.get(next).cloned() {
+            defined.push(self.type_json(&declaration, &mut types));
+            next += 1;
+        }
+        let errors: Vec<_> = self
+            .errors
+            .iter()
+            .map(|(code, name)| format!(r#"{{"code": {code}, "name": {}}}"#, json_string(name)))
+            .collect();
+
+        format!(
+            "{{\n  \"version\": {},\n  \"name\": {},\n  \"instructions\": {},\n  \"accounts\": {},\n  \"types\": {},\n  \"errors\": {},\n  \"metadata\": {{\"origin\": \"shank\"}}\n}}\n",
+            json_string(&self.version),
+            json_string(&self.name),
+            json_lines(&instructions),
+            json_lines(&accounts),
+            json_lines(&defined),
+            json_lines(&errors)
+        )
+    }
+
+    /// The arguments of an instruction, named after their type if they're unnamed as shank does
+    fn args_json(&self, args: &Declaration, types: &mut Types) -> String {
+        match self.definitions.get(args) {
+            Some(Definition::Struct {
+                fields: Fields::UnnamedFields(fields),
+            }) => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|ty| {
+                        format!(
+                            r#"{{"name": {}, "type": {}}}"#,
+                            json_string(&camel_case(ty)),
+                            self.idl_type(ty, types)
+                        )
+                    })
+                    .collect();
+                format!("[{}]", fields.join(", "))
+            }
+            Some(Definition::Struct { fields }) => self.struct_fields_json(fields, types),
+            _ => "[]".to_string(),
+        }
+    }
+
+    fn struct_fields_json(&self, fields: &Fields, types: &mut Types) -> String {
+        let fields: Vec<_> = match fields {
+            Fields::NamedFields(fields) => fields
+                .iter()
+                .map(|(name, ty)| {
+                    format!(
+                        r#"{{"name": {}, "type": {}}}"#,
+                        json_string(&camel_case(name)),
+                        self.idl_type(ty, types)
+                    )
+                })
+                .collect(),
+            Fields::UnnamedFields(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(index, ty)| {
+                    format!(
+                        r#"{{"name": "{index}", "type": {}}}"#,
+                        self.idl_type(ty, types)
+                    )
+                })
+                .collect(),
+            Fields::Empty => Vec::new(),
+        };
+        format!("[{}]", fields.join(", "))
+    }
+
+    fn idl_type(&self, declaration: &Declaration, types: &mut Types) -> String {
+        if declaration == "Pubkey" {
+            return r#""publicKey""#.to_string();
+        }
+        if PRIMITIVES.contains(&declaration.as_str()) {
+            return json_string(declaration);
+        }
+        match self.definitions.get(declaration) {
+            Some(Definition::Array { length, elements }) => {
+                format!(
+                    r#"{{"array": [{}, {length}]}}"#,
+                    self.idl_type(elements, types)
+                )
+            }
+            Some(Definition::Sequence { elements }) => {
+                format!(r#"{{"vec": {}}}"#, self.idl_type(elements, types))
+            }
+            Some(Definition::Tuple { elements }) => {
+                let elements: Vec<_> = elements
+                    .iter()
+                    .map(|element| self.idl_type(element, types))
+                    .collect();
+                format!(r#"{{"tuple": [{}]}}"#, elements.join(", "))
+            }
+            Some(Definition::Enum { variants })
+                if declaration.starts_with("Option<") && variants.len() == 2 =>
+            {
+                format!(r#"{{"option": {}}}"#, self.idl_type(&variants[1].1, types))
+            }
+            _ => {
+                types.add(declaration);
+                format!(r#"{{"defined": {}}}"#, json_string(&type_name(declaration)))
+            }
+        }
+    }
+
+    fn type_json(&self, declaration: &Declaration, types: &mut Types) -> String {
+        let ty = match self.definitions.get(declaration) {
+            Some(Definition::Struct { fields }) => format!(
+                r#"{{"kind": "struct", "fields": {}}}"#,
+                self.struct_fields_json(fields, types)
+            ),
+            Some(Definition::Enum { variants }) => {
+                let variants: Vec<_> = variants
+                    .iter()
+                    .map(|(name, fields)| match self.definitions.get(fields) {
+                        Some(Definition::Struct {
+                            fields: fields @ Fields::NamedFields(_),
+                        }) => format!(
+                            r#"{{"name": {}, "fields": {}}}"#,
+                            json_string(name),
+                            self.struct_fields_json(fields, types)
+                        ),
+                        Some(Definition::Struct {
+                            fields: Fields::UnnamedFields(fields),
+                        }) => {
+                            let fields: Vec<_> =
+                                fields.iter().map(|ty| self.idl_type(ty, types)).collect();
+                            format!(
+                                r#"{{"name": {}, "fields": [{}]}}"#,
+                                json_string(name),
+                                fields.join(", ")
+                            )
+                        }
+                        _ => format!(r#"{{"name": {}}}"#, json_string(name)),
+                    })
+                    .collect();
+                format!(
+                    r#"{{"kind": "enum", "variants": [{}]}}"#,
+                    variants.join(", ")
+                )
+            }
+            _ => panic!("{declaration} has no definition"),
+        };
+        format!(
+            r#"{{"name": {}, "type": {ty}}}"#,
+            json_string(&type_name(declaration))
+        )
+    }
+}
+
+struct InstructionEntry {
+    name: String,
+    docs: String,
+    accounts: Vec<String>,
+    /// The Borsh declaration of the variant's fields
+    args: Declaration,
+    discriminant: usize,
+}
+
+/// The types defined by the IDL, in the order they're first referenced
+#[derive(Default)]
+struct Types {
+    order: Vec<Declaration>,
+}
+
+impl Types {
+    fn add(&mut self, declaration: &Declaration) {
+        if !self.order.contains(declaration) {
+            self.order.push(declaration.clone());
+        }
+    }
+}
+
+fn docs_json(attrs: &[Attribute]) -> String {
+    let docs: Vec<_> = attrs
+        .iter()
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(meta)) if meta.path.is_ident("doc") => match meta.lit {
+                Lit::Str(doc) => Some(json_string(doc.value().trim())),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    format!("[{}]", docs.join(", "))
+}
+
+fn json_lines(items: &[String]) -> String {
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+    format!("[\n    {}\n  ]", items.join(",\n    "))
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// The name of a defined type, without the generic arguments' punctuation Borsh declares them with
+fn type_name(declaration: &Declaration) -> String {
+    declaration
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect()
+}
+
+fn camel_case(value: &str) -> String {
+    let mut camel = String::with_capacity(value.len());
+    let mut upper = false;
+    for (index, c) in value.chars().enumerate() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else if index == 0 {
+            camel.extend(c.to_lowercase());
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+mod tests {
+    use borsh::BorshSchema;
+    use jito_restaking_sanitization::error_code::ErrorSource;
+    use solana_program::pubkey::Pubkey;
+
+    use super::IdlExport;
+
+    #[derive(BorshSchema)]
+    struct Account {
+        owner: Pubkey,
+        tag: Option<[u8; 2]>,
+        kind: Kind,
+    }
+
+    #[derive(BorshSchema)]
+    enum Kind {
+        Plain,
+        Tagged { tag: u8 },
+    }
+
+    #[derive(BorshSchema)]
+    enum Instruction {
+        Create,
+        Update { ids: Vec<u16> },
+    }
+
+    const SOURCE: &str = r#"
+        #[derive(BorshSchema, ShankInstruction)]
+        enum Instruction {
+            /// Creates the account
+            #[account(0, writable, name = "account")]
+            #[account(1, writable, signer, name = "payer_account", description = "Pays rent")]
+            Create,
+
+            #[account(0, writable, name = "account")]
+            #[account(1, signer, optional, name = "signer")]
+            Update { ids: Vec<u16> },
+        }
+    "#;
+
+    fn error_name(offset: u32) -> Option<&'static str> {
+        ["AccountInvalid", "AccountFull"]
+            .get(offset as usize)
+            .copied()
+    }
+
+    #[test]
+    fn test_idl_export() {
+        let mut export = IdlExport::new("program", "0.0.1");
+        export.instruction::<Instruction>(SOURCE);
+        export.account::<Account>();
+        export.errors(ErrorSource::Vault, error_name);
+
+        assert_eq!(
+            export.to_json(),
+            r#"{
+  "version": "0.0.1",
+  "name": "program",
+  "instructions": [
+    {"name": "create", "docs": ["Creates the account"], "accounts": [{"name": "account", "isMut": true, "isSigner": false}, {"name": "payerAccount", "isMut": true, "isSigner": true, "docs": ["Pays rent"]}], "args": [], "discriminant": {"type": "u8", "value": 0}},
+    {"name": "update", "docs": [], "accounts": [{"name": "account", "isMut": true, "isSigner": false}, {"name": "signer", "isMut": false, "isSigner": true, "isOptional": true}], "args": [{"name": "ids", "type": {"vec": "u16"}}], "discriminant": {"type": "u8", "value": 1}}
+  ],
+  "accounts": [
+    {"name": "Account", "type": {"kind": "struct", "fields": [{"name": "owner", "type": "publicKey"}, {"name": "tag", "type": {"option": {"array": ["u8", 2]}}}, {"name": "kind", "type": {"defined": "Kind"}}]}}
+  ],
+  "types": [
+    {"name": "Kind", "type": {"kind": "enum", "variants": [{"name": "Plain"}, {"name": "Tagged", "fields": [{"name": "tag", "type": "u8"}]}]}}
+  ],
+  "errors": [
+    {"code": 2000, "name": "AccountInvalid"},
+    {"code": 2001, "name": "AccountFull"}
+  ],
+  "metadata": {"origin": "shank"}
+}
+"#
+        );
+    }
+}
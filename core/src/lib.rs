@@ -1 +1,11 @@
+pub mod bounded_string;
+pub mod counter;
+pub mod event;
+#[cfg(not(target_os = "solana"))]
+pub mod idl;
+pub mod pod;
+pub mod remaining_accounts;
+pub mod schema;
+pub mod serde_fields;
 pub mod slot_toggled_field;
+pub mod zero_copy;
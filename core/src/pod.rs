@@ -0,0 +1,200 @@
+//! Byte-aligned integers and booleans for zero-copy account layouts.
+//!
+//! [`PodU16`], [`PodU64`] and [`PodBool`] hold their value as little-endian bytes, so structs made
+//! of them have no padding and their `#[repr(C)]` layout is exactly their Borsh encoding. Each one
+//! serializes and exports its schema as the primitive it wraps, so replacing a field with one
+//! doesn't change the account layout. Serde serializes them as the primitive too, so accounts
+//! read the same as JSON.
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    io::{Error, ErrorKind, Write},
+};
+
+use borsh::{
+    schema::{Declaration, Definition},
+    BorshDeserialize, BorshSchema, BorshSerialize,
+};
+use bytemuck::{Pod, Zeroable};
+use serde::{Serialize, Serializer};
+
+macro_rules! pod_int {
+    ($name:ident, $int:ty) => {
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Pod, Zeroable)]
+        #[repr(transparent)]
+        pub struct $name([u8; std::mem::size_of::<$int>()]);
+
+        impl $name {
+            pub const fn new(value: $int) -> Self {
+                Self(value.to_le_bytes())
+            }
+
+            pub const fn get(self) -> $int {
+                <$int>::from_le_bytes(self.0)
+            }
+        }
+
+        impl From<$int> for $name {
+            fn from(value: $int) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $int {
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl BorshSerialize for $name {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                writer.write_all(&self.0)
+            }
+        }
+
+        impl BorshDeserialize for $name {
+            fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                <$int>::deserialize_reader(reader).map(Self::new)
+            }
+        }
+
+        impl BorshSchema for $name {
+            fn add_definitions_recursively(_: &mut HashMap<Declaration, Definition>) {}
+
+            fn declaration() -> Declaration {
+                <$int>::declaration()
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                Serialize::serialize(&self.get(), serializer)
+            }
+        }
+    };
+}
+
+pod_int!(PodU16, u16);
+pod_int!(PodU64, u64);
+
+/// A `bool` stored as one byte. Any nonzero byte reads as true, but only 0 and 1 deserialize.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct PodBool(u8);
+
+impl PodBool {
+    pub const fn new(value: bool) -> Self {
+        Self(value as u8)
+    }
+
+    pub const fn get(self) -> bool {
+        self.0 != 0
+    }
+}
+
+impl From<bool> for PodBool {
+    fn from(value: bool) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<PodBool> for bool {
+    fn from(value: PodBool) -> Self {
+        value.get()
+    }
+}
+
+impl Debug for PodBool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl BorshSerialize for PodBool {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.get(), writer)
+    }
+}
+
+impl BorshDeserialize for PodBool {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::new(false)),
+            1 => Ok(Self::new(true)),
+            byte => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid bool {byte}"),
+            )),
+        }
+    }
+}
+
+impl BorshSchema for PodBool {
+    fn add_definitions_recursively(_: &mut HashMap<Declaration, Definition>) {}
+
+    fn declaration() -> Declaration {
+        bool::declaration()
+    }
+}
+
+impl Serialize for PodBool {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(&self.get(), serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+    use super::{PodBool, PodU16, PodU64};
+
+    #[test]
+    fn test_serializes_as_primitive() {
+        assert_eq!(
+            PodU64::new(u64::MAX - 1).try_to_vec().unwrap(),
+            (u64::MAX - 1).try_to_vec().unwrap()
+        );
+        assert_eq!(
+            PodU16::new(300).try_to_vec().unwrap(),
+            300_u16.try_to_vec().unwrap()
+        );
+        assert_eq!(
+            PodBool::new(true).try_to_vec().unwrap(),
+            true.try_to_vec().unwrap()
+        );
+        assert_eq!(bytemuck::bytes_of(&PodU64::new(7)), 7_u64.to_le_bytes());
+        assert_eq!(PodU64::declaration(), u64::declaration());
+    }
+
+    #[test]
+    fn test_pod_ordering() {
+        assert!(PodU64::new(256) > PodU64::new(1));
+        assert!(PodU16::new(1) < PodU16::new(256));
+    }
+
+    #[test]
+    fn test_pod_bool_rejects_invalid_byte() {
+        assert!(PodBool::try_from_slice(&[1]).unwrap().get());
+        assert!(PodBool::try_from_slice(&[2]).is_err());
+    }
+}
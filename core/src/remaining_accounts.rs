@@ -0,0 +1,285 @@
+//! Validation of the homogeneous account lists batched instructions take as remaining accounts.
+//!
+//! Every element is held to the same [`RemainingAccountsSpec`]: the owner, the leading
+//! discriminator bytes of its data out of the account types the list accepts, whether it is
+//! writable, and the address it is expected to derive to. A list with the same account twice is rejected, so a batch never counts or moves
+//! funds for one account more than once. Failures name the index of the offending element, both in
+//! the returned [`RemainingAccountsError`] and in the program log.
+use jito_restaking_sanitization::result::SanitizationError;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use thiserror::Error;
+
+/// The expectations every element of a remaining accounts list shall meet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemainingAccountsSpec<'s> {
+    /// The program that owns every account
+    pub owner: &'s Pubkey,
+    /// The bytes an account's data starts with, one per accepted account type, e.g. the
+    /// serialized account type
+    pub discriminators: &'s [&'s [u8]],
+    pub writable: bool,
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum RemainingAccountsError {
+    #[error("remaining account {index} is owned by {owner}")]
+    InvalidOwner { index: usize, owner: Pubkey },
+    #[error("remaining account {index} has an unexpected discriminator")]
+    InvalidDiscriminator { index: usize },
+    #[error("remaining account {index} is not writable")]
+    NotWritable { index: usize },
+    #[error("remaining account {index} is not at its expected address {expected}")]
+    InvalidAddress { index: usize, expected: Pubkey },
+    #[error("remaining account {index} is a duplicate of account {first}")]
+    Duplicate { index: usize, first: usize },
+}
+
+impl RemainingAccountsError {
+    /// The index of the offending element in the list
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::InvalidOwner { index, .. }
+            | Self::InvalidDiscriminator { index }
+            | Self::NotWritable { index }
+            | Self::InvalidAddress { index, .. }
+            | Self::Duplicate { index, .. } => *index,
+        }
+    }
+}
+
+impl From<RemainingAccountsError> for SanitizationError {
+    fn from(value: RemainingAccountsError) -> Self {
+        match value {
+            RemainingAccountsError::InvalidOwner { .. } => Self::RemainingAccountInvalidOwner,
+            RemainingAccountsError::InvalidDiscriminator { .. } => {
+                Self::RemainingAccountInvalidDiscriminator
+            }
+            RemainingAccountsError::NotWritable { .. } => Self::RemainingAccountNotWritable,
+            RemainingAccountsError::InvalidAddress { .. } => Self::RemainingAccountInvalidAddress,
+            RemainingAccountsError::Duplicate { .. } => Self::RemainingAccountDuplicate,
+        }
+    }
+}
+
+impl From<RemainingAccountsError> for ProgramError {
+    fn from(value: RemainingAccountsError) -> Self {
+        SanitizationError::from(value).into()
+    }
+}
+
+/// The program address of the seeds and bump, or the default address when they're off the curve,
+/// which no account can be at. Lets an `expected_address` derive a PDA from the bump it stores
+/// without searching for it.
+pub fn create_address(program_id: &Pubkey, mut seeds: Vec<Vec<u8>>, bump: u8) -> Pubkey {
+    seeds.push(vec![bump]);
+    let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    Pubkey::create_program_address(&seeds, program_id).unwrap_or_default()
+}
+
+impl<'s> RemainingAccountsSpec<'s> {
+    /// Checks the element at `index` of a list against the spec. `expected_address` is the
+    /// address the element derives to, typically a PDA seeded by fields of its own data.
+    pub fn check(
+        &self,
+        index: usize,
+        account: &AccountInfo,
+        expected_address: impl FnOnce(&AccountInfo) -> Pubkey,
+    ) -> Result<(), RemainingAccountsError> {
+        if account.owner != self.owner {
+            return Err(RemainingAccountsError::InvalidOwner {
+                index,
+                owner: *account.owner,
+            });
+        }
+        let data = account.data.borrow();
+        if !self
+            .discriminators
+            .iter()
+            .any(|discriminator| data.starts_with(discriminator))
+        {
+            return Err(RemainingAccountsError::InvalidDiscriminator { index });
+        }
+        drop(data);
+        if self.writable && !account.is_writable {
+            return Err(RemainingAccountsError::NotWritable { index });
+        }
+        let expected = expected_address(account);
+        if *account.key != expected {
+            return Err(RemainingAccountsError::InvalidAddress { index, expected });
+        }
+        Ok(())
+    }
+
+    /// Checks every element of the list against the spec and that no account appears twice. The
+    /// first failure is logged with the index and key of the element.
+    pub fn validate(
+        &self,
+        accounts: &[AccountInfo],
+        mut expected_address: impl FnMut(&AccountInfo) -> Pubkey,
+    ) -> Result<(), RemainingAccountsError> {
+        for (index, account) in accounts.iter().enumerate() {
+            let result = accounts[..index]
+                .iter()
+                .position(|other| other.key == account.key)
+                .map_or(Ok(()), |first| {
+                    Err(RemainingAccountsError::Duplicate { index, first })
+                })
+                .and_then(|()| self.check(index, account, &mut expected_address));
+            if let Err(e) = result {
+                msg!("Invalid remaining account {}: {}", account.key, e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use solana_program::{
+        account_info::AccountInfo, clock::Epoch, program_error::ProgramError, pubkey::Pubkey,
+    };
+
+    use crate::remaining_accounts::{
+        create_address, RemainingAccountsError, RemainingAccountsSpec,
+    };
+
+    const DISCRIMINATORS: [&[u8]; 2] = [&[4], &[6]];
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        writable: bool,
+    }
+
+    impl TestAccount {
+        fn new(owner: Pubkey, data: &[u8]) -> Self {
+            Self {
+                key: Pubkey::new_unique(),
+                owner,
+                lamports: 0,
+                data: data.to_vec(),
+                writable: true,
+            }
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                false,
+                self.writable,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                Epoch::MAX,
+            )
+        }
+    }
+
+    fn spec(owner: &Pubkey) -> RemainingAccountsSpec<'_> {
+        RemainingAccountsSpec {
+            owner,
+            discriminators: &DISCRIMINATORS,
+            writable: true,
+        }
+    }
+
+    #[test]
+    fn test_validate_ok() {
+        let owner = Pubkey::new_unique();
+        let mut first = TestAccount::new(owner, &[4, 1]);
+        let mut second = TestAccount::new(owner, &[6, 2]);
+        let accounts = [first.info(), second.info()];
+
+        spec(&owner)
+            .validate(&accounts, |account| *account.key)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_validate_reports_failing_index() {
+        let owner = Pubkey::new_unique();
+        let mut valid = TestAccount::new(owner, &[4]);
+        let mut other_owner = TestAccount::new(Pubkey::new_unique(), &[4]);
+        let mut other_type = TestAccount::new(owner, &[5]);
+        let mut read_only = TestAccount::new(owner, &[4]);
+        read_only.writable = false;
+        let spec = spec(&owner);
+
+        let accounts = [valid.info(), other_owner.info()];
+        assert_matches!(
+            spec.validate(&accounts, |account| *account.key),
+            Err(RemainingAccountsError::InvalidOwner { index: 1, .. })
+        );
+        let accounts = [valid.info(), other_type.info()];
+        assert_matches!(
+            spec.validate(&accounts, |account| *account.key),
+            Err(RemainingAccountsError::InvalidDiscriminator { index: 1 })
+        );
+        let accounts = [valid.info(), read_only.info()];
+        assert_matches!(
+            spec.validate(&accounts, |account| *account.key),
+            Err(RemainingAccountsError::NotWritable { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_validate_unexpected_address_fails() {
+        let owner = Pubkey::new_unique();
+        let mut account = TestAccount::new(owner, &[4]);
+        let expected = Pubkey::new_unique();
+        let accounts = [account.info()];
+
+        let err = spec(&owner).validate(&accounts, |_| expected).unwrap_err();
+        assert_eq!(
+            err,
+            RemainingAccountsError::InvalidAddress { index: 0, expected }
+        );
+        assert_eq!(err.index(), 0);
+    }
+
+    #[test]
+    fn test_validate_duplicate_fails() {
+        let owner = Pubkey::new_unique();
+        let mut first = TestAccount::new(owner, &[4]);
+        let mut second = TestAccount::new(owner, &[4]);
+        let first_info = first.info();
+        let accounts = [first_info.clone(), second.info(), first_info];
+
+        assert_eq!(
+            spec(&owner).validate(&accounts, |account| *account.key),
+            Err(RemainingAccountsError::Duplicate { index: 2, first: 0 })
+        );
+    }
+
+    #[test]
+    fn test_error_code_in_sanitization_range() {
+        assert_eq!(
+            ProgramError::from(RemainingAccountsError::InvalidOwner {
+                index: 0,
+                owner: Pubkey::default(),
+            }),
+            ProgramError::Custom(950)
+        );
+        assert_eq!(
+            ProgramError::from(RemainingAccountsError::Duplicate { index: 1, first: 0 }),
+            ProgramError::Custom(954)
+        );
+    }
+
+    #[test]
+    fn test_create_address() {
+        let program_id = Pubkey::new_unique();
+        let seeds = vec![b"ticket".to_vec()];
+        let (address, bump) = Pubkey::find_program_address(&[b"ticket"], &program_id);
+        assert_eq!(create_address(&program_id, seeds.clone(), bump), address);
+        assert_ne!(
+            create_address(&program_id, seeds, bump.wrapping_add(1)),
+            address
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Exports the Borsh layout of a program's accounts, instructions and events as JSON.
+//!
+//! [`SchemaExport`] collects the [`BorshSchema`] of every type it's given, so decoders in other
+//! languages can generate their layouts from one artifact instead of transcribing Rust structs.
+//! Every type is defined once under `definitions`, keyed by its Borsh declaration. Primitives
+//! such as `u8`, `u64` and `bool` aren't defined, as in [`borsh::schema`]. Entries are sorted, so
+//! the output only changes when a layout does.
+use std::collections::HashMap;
+
+use borsh::{
+    schema::{Declaration, Definition, Fields},
+    BorshSchema,
+};
+
+#[derive(Default)]
+pub struct SchemaExport {
+    instruction: Option<Declaration>,
+    event: Option<Declaration>,
+    accounts: Vec<Declaration>,
+    definitions: HashMap<Declaration, Definition>,
+}
+
+impl SchemaExport {
+    /// Sets the program's instruction enum
+    pub fn instruction<T: BorshSchema>(&mut self) {
+        self.instruction = Some(T::declaration());
+        T::add_definitions_recursively(&mut self.definitions);
+    }
+
+    /// Sets the program's event enum
+    pub fn event<T: BorshSchema>(&mut self) {
+        self.event = Some(T::declaration());
+        T::add_definitions_recursively(&mut self.definitions);
+    }
+
+    /// Adds an account type. Panics if another type was exported under the same declaration.
+    pub fn account<T: BorshSchema>(&mut self) {
+        self.accounts.push(T::declaration());
+        T::add_definitions_recursively(&mut self.definitions);
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut definitions: Vec<_> = self.definitions.iter().collect();
+        definitions.sort_by_key(|(declaration, _)| *declaration);
+        let definitions: Vec<_> = definitions
+            .into_iter()
+            .map(|(declaration, definition)| {
+                format!("\"{declaration}\": {}", definition_json(definition))
+            })
+            .collect();
+        let instruction = optional_string(self.instruction.as_ref());
+        let event = optional_string(self.event.as_ref());
+
+        format!(
+            "{{\n  \"instruction\": {instruction},\n  \"event\": {event},\n  \"accounts\": {},\n  \"definitions\": {{\n    {}\n  }}\n}}\n",
+            string_list(&self.accounts),
+            definitions.join(",\n    ")
+        )
+    }
+}
+
+fn optional_string(item: Option<&Declaration>) -> String {
+    item.map_or_else(|| "null".to_string(), |item| format!("\"{item}\""))
+}
+
+fn string_list(items: &[Declaration]) -> String {
+    let items: Vec<_> = items.iter().map(|item| format!("\"{item}\"")).collect();
+    format!("[{}]", items.join(", "))
+}
+
+fn named_list<'a>(items: impl Iterator<Item = &'a (String, Declaration)>) -> String {
+    let items: Vec<_> = items
+        .map(|(name, declaration)| format!(r#"{{"name": "{name}", "type": "{declaration}"}}"#))
+        .collect();
+    format!("[{}]", items.join(", "))
+}
+
+fn definition_json(definition: &Definition) -> String {
+    match definition {
+        Definition::Array { length, elements } => {
+            format!(r#"{{"kind": "array", "length": {length}, "elements": "{elements}"}}"#)
+        }
+        Definition::Sequence { elements } => {
+            format!(r#"{{"kind": "sequence", "elements": "{elements}"}}"#)
+        }
+        Definition::Tuple { elements } => {
+            format!(
+                r#"{{"kind": "tuple", "elements": {}}}"#,
+                string_list(elements)
+            )
+        }
+        Definition::Enum { variants } => {
+            format!(
+                r#"{{"kind": "enum", "variants": {}}}"#,
+                named_list(variants.iter())
+            )
+        }
+        Definition::Struct {
+            fields: Fields::NamedFields(fields),
+        } => format!(
+            r#"{{"kind": "struct", "fields": {}}}"#,
+            named_list(fields.iter())
+        ),
+        Definition::Struct {
+            fields: Fields::UnnamedFields(fields),
+        } => format!(
+            r#"{{"kind": "tuple_struct", "fields": {}}}"#,
+            string_list(fields)
+        ),
+        Definition::Struct {
+            fields: Fields::Empty,
+        } => r#"{"kind": "struct", "fields": []}"#.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+mod tests {
+    use borsh::BorshSchema;
+
+    use super::SchemaExport;
+
+    #[derive(BorshSchema)]
+    struct Account {
+        id: u64,
+        tag: Option<[u8; 2]>,
+    }
+
+    #[derive(BorshSchema)]
+    enum Instruction {
+        Create,
+        Update { ids: Vec<u16> },
+    }
+
+    #[derive(BorshSchema)]
+    enum Event {
+        Created { id: u64 },
+    }
+
+    #[test]
+    fn test_schema_export() {
+        let mut export = SchemaExport::default();
+        export.instruction::<Instruction>();
+        export.event::<Event>();
+        export.account::<Account>();
+
+        assert_eq!(
+            export.to_json(),
+            r#"{
+  "instruction": "Instruction",
+  "event": "Event",
+  "accounts": ["Account"],
+  "definitions": {
+    "Account": {"kind": "struct", "fields": [{"name": "id", "type": "u64"}, {"name": "tag", "type": "Option<Array<u8, 2>>"}]},
+    "Array<u8, 2>": {"kind": "array", "length": 2, "elements": "u8"},
+    "Event": {"kind": "enum", "variants": [{"name": "Created", "type": "EventCreated"}]},
+    "EventCreated": {"kind": "struct", "fields": [{"name": "id", "type": "u64"}]},
+    "Instruction": {"kind": "enum", "variants": [{"name": "Create", "type": "InstructionCreate"}, {"name": "Update", "type": "InstructionUpdate"}]},
+    "InstructionCreate": {"kind": "struct", "fields": []},
+    "InstructionUpdate": {"kind": "struct", "fields": [{"name": "ids", "type": "Vec<u16>"}]},
+    "Option<Array<u8, 2>>": {"kind": "enum", "variants": [{"name": "None", "type": "nil"}, {"name": "Some", "type": "Array<u8, 2>"}]},
+    "Vec<u16>": {"kind": "sequence", "elements": "u16"}
+  }
+}
+"#
+        );
+    }
+}
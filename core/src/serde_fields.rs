@@ -0,0 +1,37 @@
+//! `serialize_with` helpers for account fields serde can't serialize the way indexers read them.
+//!
+//! Pubkeys and hashes serialize as base58 strings like the RPC shows them, instead of arrays of
+//! bytes, and fixed-size byte buffers holding text serialize as the text without the zero padding.
+use serde::{ser::SerializeSeq, Serializer};
+use solana_program::{hash::Hash, pubkey::Pubkey};
+
+/// Serializes a pubkey as its base58 string
+pub fn pubkey<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(pubkey)
+}
+
+/// Serializes pubkeys as a sequence of base58 strings
+pub fn pubkeys<S: Serializer, const N: usize>(
+    pubkeys: &[Pubkey; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(N))?;
+    for pubkey in pubkeys {
+        seq.serialize_element(&pubkey.to_string())?;
+    }
+    seq.end()
+}
+
+/// Serializes a 32 byte hash as its base58 string
+pub fn hash<S: Serializer>(hash: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&Hash::new_from_array(*hash))
+}
+
+/// Serializes a zero padded buffer as the text before the padding, replacing invalid UTF-8
+pub fn text<S: Serializer, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let len = bytes.iter().position(|byte| *byte == 0).unwrap_or(N);
+    serializer.serialize_str(&String::from_utf8_lossy(&bytes[..len]))
+}
@@ -1,48 +1,153 @@
+//! A relationship toggled on and off at slots, e.g. an AVS opting in to an operator.
+//!
+//! [`SlotToggle`] records the slot the relationship was last activated in and the slot it was last
+//! deactivated in. [`SlotToggle::state`] walks it through the lifecycle
+//! [`SlotToggleState::WarmingUp`] → [`SlotToggleState::Active`] →
+//! [`SlotToggleState::CoolingDown`] → [`SlotToggleState::Inactive`], where warming up and cooling
+//! down last until the next epoch starts, so a relationship can't be toggled within an epoch to
+//! escape what it committed to. An epoch length of zero turns both off, which is how toggles
+//! behaved before epochs applied to them.
 use std::fmt::Debug;
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use serde::Serialize;
 
-#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+use crate::pod::PodU64;
+
+/// Where a [`SlotToggle`] is in its lifecycle at a slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotToggleState {
+    /// Deactivated in an earlier epoch, or in an earlier slot without epochs
+    Inactive,
+    /// Activated in the current epoch, becomes active once the next one starts
+    WarmingUp,
+    Active,
+    /// Deactivated in the current epoch, or in the current slot without epochs
+    CoolingDown,
+}
+
+#[derive(
+    Debug, Clone, Copy, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Pod, Zeroable,
+)]
+#[repr(C)]
 pub struct SlotToggle {
-    slot_added: u64,
-    slot_removed: u64,
+    slot_added: PodU64,
+    slot_removed: PodU64,
 }
 
 impl SlotToggle {
     pub const fn new(slot: u64) -> Self {
         Self {
-            slot_added: slot,
-            slot_removed: 0,
+            slot_added: PodU64::new(slot),
+            slot_removed: PodU64::new(0),
         }
     }
 
     pub const fn slot_added(&self) -> u64 {
-        self.slot_added
+        self.slot_added.get()
     }
 
     pub const fn slot_removed(&self) -> u64 {
-        self.slot_removed
+        self.slot_removed.get()
     }
 
     pub fn activate(&mut self, slot: u64) -> bool {
-        if self.slot_added >= self.slot_removed {
+        if self.slot_added() >= self.slot_removed() {
             false
         } else {
-            self.slot_added = slot;
+            self.slot_added = PodU64::new(slot);
             true
         }
     }
 
     pub fn deactivate(&mut self, slot: u64) -> bool {
-        if self.slot_added < self.slot_removed {
+        if self.slot_added() < self.slot_removed() {
             false
         } else {
-            self.slot_removed = slot;
+            self.slot_removed = PodU64::new(slot);
             true
         }
     }
 
+    /// Whether the toggle was deactivated since it was last activated, cooling down or not
+    pub const fn is_deactivated(&self) -> bool {
+        self.slot_added() < self.slot_removed()
+    }
+
     pub const fn is_active(&self, slot: u64) -> bool {
-        self.slot_added >= self.slot_removed && slot >= self.slot_added
+        self.slot_added() >= self.slot_removed() && slot >= self.slot_added()
+    }
+
+    /// The state of the toggle at the slot, with epochs `epoch_length` slots long. Zero disables
+    /// the warmup and the cooldown.
+    pub const fn state(&self, slot: u64, epoch_length: u64) -> SlotToggleState {
+        if self.slot_added() >= self.slot_removed() {
+            if slot >= Self::next_epoch_start(self.slot_added(), epoch_length) {
+                SlotToggleState::Active
+            } else {
+                SlotToggleState::WarmingUp
+            }
+        } else if epoch_length == 0 {
+            if slot > self.slot_removed() {
+                SlotToggleState::Inactive
+            } else {
+                SlotToggleState::CoolingDown
+            }
+        } else if slot >= Self::next_epoch_start(self.slot_removed(), epoch_length) {
+            SlotToggleState::Inactive
+        } else {
+            SlotToggleState::CoolingDown
+        }
+    }
+
+    /// The first slot of the epoch after the one of the slot, or the slot itself without epochs
+    const fn next_epoch_start(slot: u64, epoch_length: u64) -> u64 {
+        if epoch_length == 0 {
+            return slot;
+        }
+        (slot / epoch_length)
+            .saturating_add(1)
+            .saturating_mul(epoch_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SlotToggle, SlotToggleState};
+
+    #[test]
+    fn test_state_without_epochs() {
+        let mut toggle = SlotToggle::new(10);
+        assert_eq!(toggle.state(10, 0), SlotToggleState::Active);
+        assert!(toggle.deactivate(12));
+        assert_eq!(toggle.state(12, 0), SlotToggleState::CoolingDown);
+        assert_eq!(toggle.state(13, 0), SlotToggleState::Inactive);
+    }
+
+    #[test]
+    fn test_state_lifecycle() {
+        let mut toggle = SlotToggle::new(105);
+        assert_eq!(toggle.state(105, 100), SlotToggleState::WarmingUp);
+        assert_eq!(toggle.state(199, 100), SlotToggleState::WarmingUp);
+        assert_eq!(toggle.state(200, 100), SlotToggleState::Active);
+
+        assert!(toggle.deactivate(250));
+        assert_eq!(toggle.state(250, 100), SlotToggleState::CoolingDown);
+        assert_eq!(toggle.state(299, 100), SlotToggleState::CoolingDown);
+        assert_eq!(toggle.state(300, 100), SlotToggleState::Inactive);
+
+        // reactivating starts another warmup
+        assert!(toggle.activate(320));
+        assert_eq!(toggle.state(399, 100), SlotToggleState::WarmingUp);
+        assert_eq!(toggle.state(400, 100), SlotToggleState::Active);
+    }
+
+    #[test]
+    fn test_state_deactivated_while_warming_up() {
+        let mut toggle = SlotToggle::new(105);
+        assert!(toggle.deactivate(150));
+        assert_eq!(toggle.state(150, 100), SlotToggleState::CoolingDown);
+        assert_eq!(toggle.state(200, 100), SlotToggleState::Inactive);
     }
 }
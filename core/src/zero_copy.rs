@@ -0,0 +1,165 @@
+//! Zero-copy access to accounts stored as fixed-size `#[repr(C)]` structs.
+//!
+//! An account implementing [`ZeroCopy`] starts with a one byte discriminator identifying its type
+//! and is made only of byte-aligned fields (see [`crate::pod`]), so it can be borrowed straight
+//! out of the account data with [`ZeroCopy::load`] and [`ZeroCopy::load_mut`] instead of being
+//! deserialized and written back. Its size is fixed by the struct, not by what it holds.
+//!
+//! Fields are only ever added in place of reserved space, never moved, so the byte offsets of
+//! the fields are stable and can be used to filter accounts off-chain. Every account also records
+//! the [`ZeroCopy::VERSION`] of the layout it was written with, so a program never reads an
+//! account written by a newer version of itself as if it knew its layout.
+use bytemuck::Pod;
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyError {
+    #[error("account data is {len} bytes, expected at least {expected}")]
+    InvalidLength { len: usize, expected: usize },
+    #[error("account discriminator is {found}, expected {expected}")]
+    InvalidDiscriminator { found: u8, expected: u8 },
+    #[error("account version is {found}, newer than the supported {supported}")]
+    UnsupportedVersion { found: u8, supported: u8 },
+}
+
+pub trait ZeroCopy: Pod {
+    /// The first byte of the account data
+    const DISCRIMINATOR: u8;
+
+    /// The size of the account data
+    const LEN: usize = std::mem::size_of::<Self>();
+
+    /// The version of the layout written by this program. Accounts written before versioning
+    /// was introduced read as version `0`.
+    const VERSION: u8 = 1;
+
+    /// The version of the layout the account was written with
+    fn version(&self) -> u8;
+
+    /// Records the version of the layout the account is written with, once migrated to it
+    fn set_version(&mut self, version: u8);
+
+    /// Borrows the account from the start of `data`, rejecting accounts of a newer version
+    fn load(data: &[u8]) -> Result<&Self, ZeroCopyError> {
+        check::<Self>(data)?;
+        let account: &Self = bytemuck::from_bytes(&data[..Self::LEN]);
+        check_version::<Self>(account)?;
+        Ok(account)
+    }
+
+    /// Mutably borrows the account from the start of `data`, rejecting accounts of a newer
+    /// version
+    fn load_mut(data: &mut [u8]) -> Result<&mut Self, ZeroCopyError> {
+        check::<Self>(data)?;
+        let account: &mut Self = bytemuck::from_bytes_mut(&mut data[..Self::LEN]);
+        check_version::<Self>(account)?;
+        Ok(account)
+    }
+
+    /// The account as stored in account data
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+}
+
+fn check<T: ZeroCopy>(data: &[u8]) -> Result<(), ZeroCopyError> {
+    if data.len() < T::LEN {
+        return Err(ZeroCopyError::InvalidLength {
+            len: data.len(),
+            expected: T::LEN,
+        });
+    }
+    if data[0] != T::DISCRIMINATOR {
+        return Err(ZeroCopyError::InvalidDiscriminator {
+            found: data[0],
+            expected: T::DISCRIMINATOR,
+        });
+    }
+    Ok(())
+}
+
+fn check_version<T: ZeroCopy>(account: &T) -> Result<(), ZeroCopyError> {
+    if account.version() > T::VERSION {
+        return Err(ZeroCopyError::UnsupportedVersion {
+            found: account.version(),
+            supported: T::VERSION,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{Pod, Zeroable};
+
+    use super::{ZeroCopy, ZeroCopyError};
+    use crate::pod::PodU64;
+
+    #[derive(Clone, Copy, Pod, Zeroable)]
+    #[repr(C)]
+    struct Account {
+        discriminator: u8,
+        value: PodU64,
+        version: u8,
+    }
+
+    impl ZeroCopy for Account {
+        const DISCRIMINATOR: u8 = 3;
+
+        fn version(&self) -> u8 {
+            self.version
+        }
+
+        fn set_version(&mut self, version: u8) {
+            self.version = version;
+        }
+    }
+
+    #[test]
+    fn test_load_mut_writes_through() {
+        let mut data = [0; 12];
+        data[0] = 3;
+        Account::load_mut(&mut data).unwrap().value = PodU64::new(42);
+        assert_eq!(Account::LEN, 10);
+        assert_eq!(data[1..9], 42_u64.to_le_bytes());
+        assert_eq!(Account::load(&data).unwrap().value.get(), 42);
+    }
+
+    #[test]
+    fn test_load_newer_version_fails() {
+        let mut data = [0; 10];
+        data[0] = 3;
+        Account::load_mut(&mut data)
+            .unwrap()
+            .set_version(Account::VERSION);
+        assert_eq!(data[9], Account::VERSION);
+
+        data[9] = Account::VERSION + 1;
+        assert_eq!(
+            Account::load(&data).err(),
+            Some(ZeroCopyError::UnsupportedVersion {
+                found: Account::VERSION + 1,
+                supported: Account::VERSION
+            })
+        );
+        assert!(Account::load_mut(&mut data).is_err());
+    }
+
+    #[test]
+    fn test_load_invalid_data_fails() {
+        assert_eq!(
+            Account::load(&[3; 8]).err(),
+            Some(ZeroCopyError::InvalidLength {
+                len: 8,
+                expected: 10
+            })
+        );
+        assert_eq!(
+            Account::load(&[2; 10]).err(),
+            Some(ZeroCopyError::InvalidDiscriminator {
+                found: 2,
+                expected: 3
+            })
+        );
+    }
+}
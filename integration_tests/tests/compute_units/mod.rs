@@ -0,0 +1,171 @@
+//! Compute unit budgets of the program instructions, so cost regressions fail in-tree instead of
+//! surfacing as transactions running out of compute on mainnet.
+//!
+//! The budgets leave headroom over the units measured when they were last raised, and are only
+//! enforced when the programs run as SBF programs:
+//!
+//! $ cargo-build-sbf && SBF_OUT_DIR=$(pwd)/target/sbf-solana-solana/release cargo nextest run compute_units
+//!
+//! An instruction over its budget fails the test with the units it consumed.
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    compute_units::{metering_compute_units, ComputeUnitLog},
+    fixture::TestBuilder,
+};
+
+/// The most compute units each instruction may consume, by instruction name
+const BUDGETS: &[(&str, u64)] = &[
+    // restaking program
+    ("InitializeConfig", 40_000),
+    ("InitializeAvs", 60_000),
+    ("InitializeOperator", 60_000),
+    ("AvsAddVault", 60_000),
+    ("OperatorAddVault", 60_000),
+    ("OperatorAddAvs", 60_000),
+    ("AvsAddOperator", 70_000),
+    ("AvsAddVaultSlasher", 70_000),
+    // vault program
+    ("InitializeVault", 120_000),
+    ("AddAvs", 70_000),
+    ("AddOperator", 70_000),
+    ("AddSlasher", 70_000),
+    ("MintTo", 100_000),
+    ("AddDelegation", 50_000),
+    ("UpdateDelegations", 50_000),
+    ("SetFees", 30_000),
+    ("EnqueueWithdrawal", 100_000),
+    ("BurnWithdrawalTicket", 120_000),
+    ("UpdateVaultBalance", 70_000),
+];
+
+/// Fails for every instruction recorded that exceeds its budget or has none
+fn assert_within_budgets(compute_unit_log: &ComputeUnitLog) {
+    // the processors are linked natively, so there's nothing to hold to the budgets
+    if !metering_compute_units() {
+        return;
+    }
+
+    let max_units = compute_unit_log.max_units();
+    assert!(!max_units.is_empty(), "no compute units recorded");
+    let mut violations = Vec::new();
+    for (name, units) in max_units.iter() {
+        let budget = BUDGETS
+            .iter()
+            .find(|(budgeted, _)| budgeted == name)
+            .map(|(_, budget)| *budget);
+        match budget {
+            Some(budget) if *units <= budget => {}
+            Some(budget) => violations.push(format!("{name} consumed {units} CU of {budget}")),
+            None => violations.push(format!("{name} consumed {units} CU without a budget")),
+        }
+    }
+    assert!(violations.is_empty(), "{}", violations.join("\n"));
+}
+
+#[tokio::test]
+async fn test_instructions_within_compute_budgets() {
+    let mut fixture = TestBuilder::new().await;
+    let compute_unit_log = fixture.record_compute_units();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &graph.vault,
+            &graph.lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &graph.token_mint.pubkey()),
+            &graph.vault_token_account,
+            &get_associated_token_address(&depositor.pubkey(), &graph.lrt_mint.pubkey()),
+            &graph.vault_fee_token_account,
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    vault_program_client
+        .add_delegation(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.operator,
+            &graph.vault_operator_ticket,
+            &graph.operator_vault_ticket,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+            &graph.vault_admin,
+            10_000,
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .set_fees(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.vault_admin,
+            0,
+            0,
+            100,
+        )
+        .await
+        .unwrap();
+    let base = Keypair::new();
+    vault_program_client
+        .enqueue_withdrawal(&graph.vault, &depositor, &base, None, 1_000)
+        .await
+        .unwrap();
+
+    let epoch_length = vault_program_client
+        .get_config(&graph.vault_config)
+        .await
+        .unwrap()
+        .epoch_length();
+    let epoch = fixture.get_slot().await.unwrap() / epoch_length;
+    fixture
+        .warp_to_epoch(epoch + 2, epoch_length)
+        .await
+        .unwrap();
+
+    vault_program_client
+        .update_delegations(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+        )
+        .await
+        .unwrap();
+    // rewards to harvest before the withdrawal
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &graph.vault, 1_000)
+        .await
+        .unwrap();
+    vault_program_client
+        .update_vault_balance(&depositor, &graph.vault)
+        .await
+        .unwrap();
+    vault_program_client
+        .burn_withdrawal_ticket(
+            &depositor,
+            &graph.vault,
+            &depositor.pubkey(),
+            &base.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    assert_within_budgets(&compute_unit_log);
+}
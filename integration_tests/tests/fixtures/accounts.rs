@@ -0,0 +1,51 @@
+/// Generates the typed account getters of a program client.
+///
+/// Every client gets a generic `get_account::<T>(address)`, plus one named getter per entry:
+/// * `get_config(address) -> Config;` fetches the account at the given address.
+/// * `get_avs_vault_ticket(avs: &Pubkey, vault: &Pubkey) -> AvsVaultTicket;` derives the address
+///   with `AvsVaultTicket::find_program_address` from the given seeds, in order, then fetches it.
+///
+/// The client must hold its `BanksClient` in a `banks_client` field.
+macro_rules! account_getters {
+    (@getters $program_id:expr;) => {};
+    (@getters $program_id:expr; $name:ident(address) -> $account:ty; $($rest:tt)*) => {
+        pub async fn $name(
+            &mut self,
+            address: &::solana_program::pubkey::Pubkey,
+        ) -> Result<$account, ::solana_program_test::BanksClientError> {
+            self.get_account(address).await
+        }
+
+        account_getters!(@getters $program_id; $($rest)*);
+    };
+    (@getters $program_id:expr; $name:ident($($seed:ident: $seed_ty:ty),+) -> $account:ty; $($rest:tt)*) => {
+        pub async fn $name(
+            &mut self,
+            $($seed: $seed_ty),+
+        ) -> Result<$account, ::solana_program_test::BanksClientError> {
+            let address = <$account>::find_program_address(&$program_id, $($seed),+).0;
+            self.get_account(&address).await
+        }
+
+        account_getters!(@getters $program_id; $($rest)*);
+    };
+    ($program_id:expr; $($getters:tt)*) => {
+        pub async fn get_account<T: ::borsh::BorshDeserialize>(
+            &mut self,
+            address: &::solana_program::pubkey::Pubkey,
+        ) -> Result<T, ::solana_program_test::BanksClientError> {
+            let account = self
+                .banks_client
+                .get_account(*address)
+                .await?
+                .ok_or(::solana_program_test::BanksClientError::ClientError(
+                    "account not found",
+                ))?;
+            Ok(T::deserialize(&mut account.data.as_slice())?)
+        }
+
+        account_getters!(@getters $program_id; $($getters)*);
+    };
+}
+
+pub(crate) use account_getters;
@@ -0,0 +1,83 @@
+//! Compute units consumed by the instructions of the restaking and vault programs, measured by
+//! the program clients simulating each transaction before they process it.
+//!
+//! The programs only consume compute units when they run as SBF programs, see
+//! [`metering_compute_units`]. Natively linked processors aren't metered.
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use borsh::BorshDeserialize;
+use jito_restaking_sdk::RestakingInstruction;
+use jito_vault_sdk::VaultInstruction;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_sdk::transaction::VersionedTransaction;
+
+/// Whether the programs run as SBF programs, which `ProgramTest` loads from the directory in
+/// `SBF_OUT_DIR` or `BPF_OUT_DIR` instead of linking the processors natively
+pub fn metering_compute_units() -> bool {
+    std::env::var_os("SBF_OUT_DIR").is_some() || std::env::var_os("BPF_OUT_DIR").is_some()
+}
+
+/// The most compute units each instruction consumed so far, shared by every program client of a
+/// [`crate::fixtures::fixture::TestBuilder`] once it records them
+#[derive(Debug, Clone, Default)]
+pub struct ComputeUnitLog {
+    max_units: Arc<Mutex<BTreeMap<String, u64>>>,
+}
+
+impl ComputeUnitLog {
+    /// Simulates the transaction and records the compute units it consumed under the name of its
+    /// instruction. Only successful transactions made of a single instruction of either program
+    /// are recorded, so the units aren't shared with other instructions.
+    pub async fn record(
+        &self,
+        banks_client: &mut BanksClient,
+        tx: &(impl Into<VersionedTransaction> + Clone),
+    ) -> Result<(), BanksClientError> {
+        let tx: VersionedTransaction = tx.clone().into();
+        let [instruction] = tx.message.instructions() else {
+            return Ok(());
+        };
+        let program_id =
+            tx.message.static_account_keys()[usize::from(instruction.program_id_index)];
+        let Some(name) = instruction_name(&program_id, &instruction.data) else {
+            return Ok(());
+        };
+
+        let result = banks_client.simulate_transaction(tx).await?;
+        // failures are left for the processing of the transaction to report
+        if !matches!(result.result, Some(Ok(()))) {
+            return Ok(());
+        }
+        let Some(details) = result.simulation_details else {
+            return Ok(());
+        };
+        let mut max_units = self.max_units.lock().unwrap();
+        let units = max_units.entry(name).or_default();
+        *units = (*units).max(details.units_consumed);
+        Ok(())
+    }
+
+    /// The most compute units consumed by each instruction recorded, by instruction name
+    pub fn max_units(&self) -> BTreeMap<String, u64> {
+        self.max_units.lock().unwrap().clone()
+    }
+}
+
+/// The name of the instruction variant, e.g. `MintTo`, if the program is one of ours
+fn instruction_name(program_id: &Pubkey, data: &[u8]) -> Option<String> {
+    let instruction = if *program_id == jito_restaking_program::id() {
+        format!("{:?}", RestakingInstruction::try_from_slice(data).ok()?)
+    } else if *program_id == jito_vault_program::id() {
+        format!("{:?}", VaultInstruction::try_from_slice(data).ok()?)
+    } else {
+        return None;
+    };
+    instruction
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .map(str::to_string)
+}
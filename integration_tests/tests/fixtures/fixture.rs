@@ -1,7 +1,30 @@
 use std::fmt::{Debug, Formatter};
 
+use jito_restaking_client::error::decode_error;
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config as RestakingConfig, operator::Operator, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sanitization::program_data::program_data_address;
+use jito_restaking_sdk::flows::FlowTransaction;
+use jito_vault_core::{
+    config::Config as VaultConfig, vault::Vault, vault_avs_slasher_ticket::VaultAvsSlasherTicket,
+    vault_avs_ticket::VaultAvsTicket, vault_delegation_list::VaultDelegationList,
+    vault_operator_ticket::VaultOperatorTicket,
+};
 use solana_program::{
+    address_lookup_table::{
+        self,
+        state::{AddressLookupTable, LookupTableMeta},
+        AddressLookupTableAccount,
+    },
+    bpf_loader_upgradeable,
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
     native_token::sol_to_lamports,
+    program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
@@ -9,22 +32,124 @@ use solana_program::{
 };
 use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
+    account::{self, AccountSharedData},
     commitment_config::CommitmentLevel,
-    signature::{Keypair, Signer},
-    transaction::Transaction,
+    signature::{Keypair, Signature, Signer},
+    transaction::{Transaction, TransactionError},
 };
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
 };
 use spl_token::{
     instruction::initialize_mint2,
     state::{Account, Mint},
 };
+use spl_token_2022::extension::{
+    transfer_fee::instruction::initialize_transfer_fee_config, ExtensionType,
+};
 
-use crate::fixtures::{restaking_client::RestakingProgramClient, vault_client::VaultProgramClient};
+use crate::fixtures::{
+    compute_units::ComputeUnitLog, restaking_client::RestakingProgramClient,
+    vault_client::VaultProgramClient,
+};
+
+/// Returns the custom error a transaction failed with, or `None` if it failed otherwise
+pub fn custom_program_error(error: &BanksClientError) -> Option<u32> {
+    let error = match error {
+        BanksClientError::TransactionError(error) => error,
+        BanksClientError::SimulationError { err, .. } => err,
+        _ => return None,
+    };
+    match error {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(*code),
+        _ => None,
+    }
+}
+
+/// The name of a custom error code and the crate raising it, for failure messages
+fn describe_custom_error(code: u32) -> String {
+    match decode_error(code) {
+        Some(decoded) => format!("{:?}::{} ({code})", decoded.source, decoded.name),
+        None => format!("unknown custom error {code}"),
+    }
+}
+
+/// Asserts a transaction failed with `error`, which can be any of the custom errors of the
+/// programs, such as [`jito_restaking_sdk::error::RestakingError`] or
+/// [`jito_vault_core::result::VaultCoreError`]
+pub fn assert_program_error(result: Result<(), BanksClientError>, error: impl Into<ProgramError>) {
+    let expected = match error.into() {
+        ProgramError::Custom(code) => code,
+        error => panic!("{error:?} is not a custom error"),
+    };
+    let error = match result {
+        Ok(()) => panic!(
+            "expected {}, but the transaction succeeded",
+            describe_custom_error(expected)
+        ),
+        Err(error) => error,
+    };
+    match custom_program_error(&error) {
+        Some(code) => assert_eq!(
+            code,
+            expected,
+            "expected {}, got {}",
+            describe_custom_error(expected),
+            describe_custom_error(code)
+        ),
+        None => panic!(
+            "expected {}, got {error:?}",
+            describe_custom_error(expected)
+        ),
+    }
+}
+
+/// The max amount the slasher of [`TestBuilder::setup_vault_with_operator_and_avs`] can slash per
+/// epoch
+pub const GRAPH_MAX_SLASHABLE_PER_EPOCH: u64 = 1_000_000;
+
+/// An AVS, an operator and a vault that all opted in to each other, with a slasher approved by the
+/// AVS and the vault, as set up by [`TestBuilder::setup_vault_with_operator_and_avs`]. Every
+/// admin role of an account is held by its admin, which also paid for the tickets.
+#[derive(Debug)]
+pub struct RelationshipGraph {
+    pub restaking_config: Pubkey,
+    pub vault_config: Pubkey,
+
+    pub avs: Pubkey,
+    pub avs_admin: Keypair,
+
+    pub operator: Pubkey,
+    pub operator_admin: Keypair,
+
+    pub vault: Pubkey,
+    pub vault_admin: Keypair,
+    pub vault_delegation_list: Pubkey,
+    /// The supported mint, which the payer of the fixture can mint with [`TestBuilder::mint_to`]
+    pub token_mint: Keypair,
+    pub lrt_mint: Keypair,
+    /// The vault's ATA of the supported mint
+    pub vault_token_account: Pubkey,
+    /// The vault admin's ATA of the LRT mint, receiving the vault fees
+    pub vault_fee_token_account: Pubkey,
+
+    pub slasher: Keypair,
+
+    pub avs_operator_ticket: Pubkey,
+    pub avs_vault_ticket: Pubkey,
+    pub avs_vault_slasher_ticket: Pubkey,
+    pub operator_avs_ticket: Pubkey,
+    pub operator_vault_ticket: Pubkey,
+    pub vault_avs_ticket: Pubkey,
+    pub vault_operator_ticket: Pubkey,
+    pub vault_avs_slasher_ticket: Pubkey,
+}
 
 pub struct TestBuilder {
     context: ProgramTestContext,
+    upgrade_authority: Keypair,
+    compute_unit_log: Option<ComputeUnitLog>,
 }
 
 impl Debug for TestBuilder {
@@ -47,8 +172,47 @@ impl TestBuilder {
             processor!(jito_restaking_program::process_instruction),
         );
 
+        // both programs share an upgrade authority, which shall initialize their configs
+        let upgrade_authority = Keypair::new();
+        for program_id in [jito_vault_program::id(), jito_restaking_program::id()] {
+            program_test.add_account(
+                program_data_address(&program_id),
+                account::Account {
+                    lamports: sol_to_lamports(1.0),
+                    // UpgradeableLoaderState::ProgramData deployed at slot 0
+                    data: [
+                        [3, 0, 0, 0].as_slice(),
+                        &[0; 8],
+                        &[1],
+                        upgrade_authority.pubkey().as_ref(),
+                    ]
+                    .concat(),
+                    owner: bpf_loader_upgradeable::id(),
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            );
+        }
+
         let context = program_test.start_with_context().await;
-        Self { context }
+        Self {
+            context,
+            upgrade_authority,
+            compute_unit_log: None,
+        }
+    }
+
+    /// Records the compute units of the instructions processed by the program clients created
+    /// from now on, returning the log they share
+    pub fn record_compute_units(&mut self) -> ComputeUnitLog {
+        self.compute_unit_log
+            .get_or_insert_with(ComputeUnitLog::default)
+            .clone()
+    }
+
+    /// The upgrade authority of both programs
+    pub fn upgrade_authority(&self) -> Keypair {
+        self.upgrade_authority.insecure_clone()
     }
 
     // pub async fn store_account<T: BorshSerialize>(
@@ -70,6 +234,119 @@ impl TestBuilder {
     //     Ok(())
     // }
 
+    /// Grows the account data without adding lamports, like a realloc-based migration that didn't
+    /// top up rent
+    pub async fn grow_account(
+        &mut self,
+        pubkey: &Pubkey,
+        additional_len: usize,
+    ) -> Result<(), BanksClientError> {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*pubkey)
+            .await?
+            .unwrap();
+        let mut data = account.data.clone();
+        data.resize(data.len() + additional_len, 0);
+        let mut grown = AccountSharedData::new(account.lamports, data.len(), &account.owner);
+        grown.set_data_from_slice(&data);
+        self.context.set_account(pubkey, &grown);
+        Ok(())
+    }
+
+    pub async fn get_account_data(&mut self, pubkey: &Pubkey) -> Result<Vec<u8>, BanksClientError> {
+        Ok(self
+            .context
+            .banks_client
+            .get_account(*pubkey)
+            .await?
+            .unwrap()
+            .data)
+    }
+
+    /// Replaces the account data, funding the account with exactly the rent-exempt minimum of its
+    /// new length, like an account written by an earlier version of the program
+    pub async fn set_account_data(
+        &mut self,
+        pubkey: &Pubkey,
+        data: &[u8],
+    ) -> Result<(), BanksClientError> {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*pubkey)
+            .await?
+            .unwrap();
+        let rent = self.get_rent().await?;
+        let mut replaced =
+            AccountSharedData::new(rent.minimum_balance(data.len()), data.len(), &account.owner);
+        replaced.set_data_from_slice(data);
+        self.context.set_account(pubkey, &replaced);
+        Ok(())
+    }
+
+    async fn get_account(
+        &mut self,
+        pubkey: &Pubkey,
+    ) -> Result<Option<account::Account>, BanksClientError> {
+        self.context.banks_client.get_account(*pubkey).await
+    }
+
+    pub async fn get_lamports(&mut self, pubkey: &Pubkey) -> Result<u64, BanksClientError> {
+        self.context.banks_client.get_balance(*pubkey).await
+    }
+
+    pub async fn get_rent(&mut self) -> Result<Rent, BanksClientError> {
+        self.context.banks_client.get_sysvar().await
+    }
+
+    /// Asserts each account holds exactly the rent-exempt minimum for its data, so an instruction
+    /// neither underfunds the accounts it creates or resizes nor strands lamports in them
+    pub async fn assert_rent_exempt(&mut self, pubkeys: &[Pubkey]) {
+        let rent = self.get_rent().await.unwrap();
+        for pubkey in pubkeys {
+            let account = self
+                .context
+                .banks_client
+                .get_account(*pubkey)
+                .await
+                .unwrap()
+                .unwrap_or_else(|| panic!("account {pubkey} doesn't exist"));
+            assert_eq!(
+                account.lamports,
+                rent.minimum_balance(account.data.len()),
+                "account {pubkey} with {} bytes of data isn't exactly rent-exempt",
+                account.data.len()
+            );
+        }
+    }
+
+    /// Asserts the account was closed and the recipient received exactly `refund` lamports since
+    /// holding `recipient_lamports_before`, both recorded before the close
+    pub async fn assert_closed_with_refund(
+        &mut self,
+        closed: &Pubkey,
+        recipient: &Pubkey,
+        recipient_lamports_before: u64,
+        refund: u64,
+    ) {
+        assert!(
+            self.context
+                .banks_client
+                .get_account(*closed)
+                .await
+                .unwrap()
+                .is_none(),
+            "account {closed} wasn't closed"
+        );
+        assert_eq!(
+            self.get_lamports(recipient).await.unwrap(),
+            recipient_lamports_before.checked_add(refund).unwrap(),
+            "{recipient} wasn't refunded exactly {refund} lamports"
+        );
+    }
+
     pub async fn transfer(&mut self, to: &Pubkey, sol: f64) -> Result<(), BanksClientError> {
         let blockhash = self.context.banks_client.get_latest_blockhash().await?;
         self.context
@@ -90,10 +367,96 @@ impl TestBuilder {
             .await
     }
 
-    // pub async fn get_mint(&mut self, mint: &Pubkey) -> Result<Mint, BanksClientError> {
-    //     let account = self.context.banks_client.get_account(*mint).await?.unwrap();
-    //     Ok(Mint::unpack(&account.data).unwrap())
-    // }
+    /// Processes the instruction as is, paid by the payer of the fixture, e.g. an instruction with
+    /// an account meta tampered with
+    pub async fn process_instruction(
+        &mut self,
+        instruction: &Instruction,
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        if let Some(compute_unit_log) = &self.compute_unit_log {
+            compute_unit_log
+                .record(&mut self.context.banks_client, &transaction)
+                .await?;
+        }
+        self.context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment(
+                transaction,
+                CommitmentLevel::Processed,
+            )
+            .await
+    }
+
+    /// Stores an address lookup table of the addresses owned by the lookup table program, as if
+    /// it was created and extended in an earlier slot, so transactions can use it right away
+    pub async fn create_lookup_table(
+        &mut self,
+        addresses: &[Pubkey],
+    ) -> Result<AddressLookupTableAccount, BanksClientError> {
+        let address = Pubkey::new_unique();
+        let data = AddressLookupTable {
+            meta: LookupTableMeta::new(self.context.payer.pubkey()),
+            addresses: addresses.into(),
+        }
+        .serialize_for_tests()
+        .map_err(|_| BanksClientError::ClientError("failed to serialize lookup table"))?;
+        let rent = self.get_rent().await?;
+        let mut account = AccountSharedData::new(
+            rent.minimum_balance(data.len()),
+            data.len(),
+            &address_lookup_table::program::id(),
+        );
+        account.set_data_from_slice(&data);
+        self.context.set_account(&address, &account);
+        Ok(AddressLookupTableAccount {
+            key: address,
+            addresses: addresses.to_vec(),
+        })
+    }
+
+    /// Copies the account to another address with the same owner, e.g. to pass a look-alike of a
+    /// PDA that isn't at its derived address
+    pub async fn copy_account(
+        &mut self,
+        from: &Pubkey,
+        to: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*from)
+            .await?
+            .ok_or(BanksClientError::ClientError("account not found"))?;
+        self.context.set_account(to, &account.into());
+        Ok(())
+    }
+
+    /// The mint, without the extensions of Token-2022 mints
+    pub async fn get_mint(&mut self, mint: &Pubkey) -> Result<Mint, BanksClientError> {
+        let account = self.context.banks_client.get_account(*mint).await?.unwrap();
+        Ok(Mint::unpack(&account.data[..Mint::LEN]).unwrap())
+    }
+
+    /// The token program owning the mint, SPL Token or Token-2022
+    pub async fn get_token_program(&mut self, mint: &Pubkey) -> Result<Pubkey, BanksClientError> {
+        Ok(self
+            .context
+            .banks_client
+            .get_account(*mint)
+            .await?
+            .unwrap()
+            .owner)
+    }
 
     pub async fn get_token_account(
         &mut self,
@@ -105,7 +468,7 @@ impl TestBuilder {
             .get_account(*token_account)
             .await?
             .unwrap();
-        Ok(Account::unpack(&account.data).unwrap())
+        Ok(Account::unpack(&account.data[..Account::LEN]).unwrap())
     }
 
     /// Mints tokens to an ATA owned by the `to` address
@@ -115,6 +478,7 @@ impl TestBuilder {
         to: &Pubkey,
         amount: u64,
     ) -> Result<(), BanksClientError> {
+        let token_program = self.get_token_program(mint).await?;
         let blockhash = self.context.banks_client.get_latest_blockhash().await?;
         self.context
             .banks_client
@@ -125,12 +489,12 @@ impl TestBuilder {
                             &self.context.payer.pubkey(),
                             to,
                             mint,
-                            &spl_token::id(),
+                            &token_program,
                         ),
-                        spl_token::instruction::mint_to(
-                            &spl_token::id(),
+                        spl_token_2022::instruction::mint_to(
+                            &token_program,
                             mint,
-                            &get_associated_token_address(to, mint),
+                            &get_associated_token_address_with_program_id(to, mint, &token_program),
                             &self.context.payer.pubkey(),
                             &[],
                             amount,
@@ -179,11 +543,77 @@ impl TestBuilder {
             .await
     }
 
+    /// Creates a Token-2022 mint charging the transfer fee, with a permanent delegate if one is
+    /// given
+    pub async fn create_token_2022_mint(
+        &mut self,
+        mint: &Keypair,
+        transfer_fee_bps: u16,
+        permanent_delegate: Option<&Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let mut extension_types = vec![ExtensionType::TransferFeeConfig];
+        let mut instructions = vec![initialize_transfer_fee_config(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            None,
+            None,
+            transfer_fee_bps,
+            u64::MAX,
+        )
+        .unwrap()];
+        if let Some(delegate) = permanent_delegate {
+            extension_types.push(ExtensionType::PermanentDelegate);
+            instructions.push(
+                spl_token_2022::instruction::initialize_permanent_delegate(
+                    &spl_token_2022::id(),
+                    &mint.pubkey(),
+                    delegate,
+                )
+                .unwrap(),
+            );
+        }
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+            &extension_types,
+        )
+        .unwrap();
+
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let rent: Rent = self.context.banks_client.get_sysvar().await?;
+        let create = create_account(
+            &self.context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &spl_token_2022::id(),
+        );
+        let initialize = spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &self.context.payer.pubkey(),
+            None,
+            6,
+        )
+        .unwrap();
+        self.context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment(
+                Transaction::new_signed_with_payer(
+                    &[vec![create], instructions, vec![initialize]].concat(),
+                    Some(&self.context.payer.pubkey()),
+                    &[&self.context.payer, mint],
+                    blockhash,
+                ),
+                CommitmentLevel::Processed,
+            )
+            .await
+    }
+
     pub async fn create_ata(
         &mut self,
         mint: &Pubkey,
         owner: &Pubkey,
     ) -> Result<(), BanksClientError> {
+        let token_program = self.get_token_program(mint).await?;
         let blockhash = self.context.banks_client.get_latest_blockhash().await?;
         self.context
             .banks_client
@@ -193,7 +623,7 @@ impl TestBuilder {
                         &self.context.payer.pubkey(),
                         owner,
                         mint,
-                        &spl_token::id(),
+                        &token_program,
                     )],
                     Some(&self.context.payer.pubkey()),
                     &[&self.context.payer],
@@ -204,19 +634,429 @@ impl TestBuilder {
             .await
     }
 
-    // pub async fn warp_to_next_slot(&mut self) -> Result<(), BanksClientError> {
-    //     let clock: Clock = self.context.banks_client.get_sysvar().await?;
-    //     self.context
-    //         .warp_to_slot(clock.slot.checked_add(1).unwrap())
-    //         .map_err(|_| BanksClientError::ClientError("failed to warp slot"))?;
-    //     Ok(())
-    // }
+    /// Initializes a vault around a new mint, along with the vault config if it doesn't exist yet.
+    /// For tests that only need a vault the restaking program accepts.
+    pub async fn create_vault(&mut self) -> Result<Pubkey, BanksClientError> {
+        let mut vault_program_client = self.vault_program_client();
+
+        let config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+        if self
+            .context
+            .banks_client
+            .get_account(config)
+            .await?
+            .is_none()
+        {
+            let config_admin = self.upgrade_authority();
+            self.transfer(&config_admin.pubkey(), 1.0).await?;
+            vault_program_client
+                .initialize_config(&config, &config_admin)
+                .await?;
+        }
+
+        let token_mint = Keypair::new();
+        self.create_token_mint(&token_mint).await?;
+        let vault_admin = Keypair::new();
+        self.transfer(&vault_admin.pubkey(), 1.0).await?;
+        let vault_base = Keypair::new();
+        let vault = Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+        let vault_delegation_list =
+            VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault).0;
+        vault_program_client
+            .initialize_vault(
+                &config,
+                &vault,
+                &vault_delegation_list,
+                &Keypair::new(),
+                &token_mint,
+                &vault_admin,
+                &vault_base,
+                0,
+                0,
+            )
+            .await?;
+
+        Ok(vault)
+    }
+
+    /// Initializes the configs of both programs that don't exist yet, returning their addresses
+    pub async fn initialize_configs(&mut self) -> Result<(Pubkey, Pubkey), BanksClientError> {
+        let restaking_config =
+            RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
+        let vault_config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+        let config_admin = self.upgrade_authority();
+        self.transfer(&config_admin.pubkey(), 1.0).await?;
+
+        if self.get_account(&restaking_config).await?.is_none() {
+            self.restaking_program_client()
+                .initialize_config(&restaking_config, &config_admin)
+                .await?;
+        }
+        if self.get_account(&vault_config).await?.is_none() {
+            self.vault_program_client()
+                .initialize_config(&vault_config, &config_admin)
+                .await?;
+        }
+        Ok((restaking_config, vault_config))
+    }
+
+    /// Creates a mint and mints each holder the amount to its ATA, returning the mint
+    pub async fn create_funded_mint(
+        &mut self,
+        holders: &[(Pubkey, u64)],
+    ) -> Result<Pubkey, BanksClientError> {
+        let mint = Keypair::new();
+        self.create_token_mint(&mint).await?;
+        for (holder, amount) in holders {
+            self.mint_to(&mint.pubkey(), holder, *amount).await?;
+        }
+        Ok(mint.pubkey())
+    }
+
+    /// Sets up an AVS, an operator and a vault with every relationship between them active, see
+    /// [`RelationshipGraph`]. The configs are initialized if they don't exist yet.
+    pub async fn setup_vault_with_operator_and_avs(
+        &mut self,
+    ) -> Result<RelationshipGraph, BanksClientError> {
+        let restaking_program = jito_restaking_program::id();
+        let vault_program = jito_vault_program::id();
+        let mut restaking_program_client = self.restaking_program_client();
+        let mut vault_program_client = self.vault_program_client();
+        let (restaking_config, vault_config) = self.initialize_configs().await?;
+
+        let avs_admin = Keypair::new();
+        let avs_base = Keypair::new();
+        self.transfer(&avs_admin.pubkey(), 10.0).await?;
+        let avs = Avs::find_program_address(&restaking_program, &avs_base.pubkey()).0;
+        restaking_program_client
+            .initialize_avs(&restaking_config, &avs, &avs_admin, &avs_base)
+            .await?;
+
+        let operator_admin = Keypair::new();
+        let operator_base = Keypair::new();
+        self.transfer(&operator_admin.pubkey(), 10.0).await?;
+        let operator =
+            Operator::find_program_address(&restaking_program, &operator_base.pubkey()).0;
+        restaking_program_client
+            .initialize_operator(
+                &restaking_config,
+                &operator,
+                &operator_admin,
+                &operator_base,
+            )
+            .await?;
+
+        let vault_admin = Keypair::new();
+        let vault_base = Keypair::new();
+        let token_mint = Keypair::new();
+        let lrt_mint = Keypair::new();
+        self.transfer(&vault_admin.pubkey(), 10.0).await?;
+        self.create_token_mint(&token_mint).await?;
+        let vault = Vault::find_program_address(&vault_program, &vault_base.pubkey()).0;
+        let vault_delegation_list =
+            VaultDelegationList::find_program_address(&vault_program, &vault).0;
+        vault_program_client
+            .initialize_vault(
+                &vault_config,
+                &vault,
+                &vault_delegation_list,
+                &lrt_mint,
+                &token_mint,
+                &vault_admin,
+                &vault_base,
+                0,
+                0,
+            )
+            .await?;
+        self.create_ata(&token_mint.pubkey(), &vault).await?;
+        self.create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+            .await?;
+
+        let avs_vault_ticket =
+            AvsVaultTicket::find_program_address(&restaking_program, &avs, &vault).0;
+        restaking_program_client
+            .avs_add_vault(
+                &restaking_config,
+                &avs,
+                &vault,
+                &avs_vault_ticket,
+                &avs_admin,
+                &avs_admin,
+            )
+            .await?;
+
+        let operator_vault_ticket =
+            OperatorVaultTicket::find_program_address(&restaking_program, &operator, &vault).0;
+        restaking_program_client
+            .operator_add_vault(
+                &restaking_config,
+                &operator,
+                &vault,
+                &operator_vault_ticket,
+                &operator_admin,
+                &operator_admin,
+            )
+            .await?;
+
+        let operator_avs_ticket =
+            OperatorAvsTicket::find_program_address(&restaking_program, &operator, &avs).0;
+        restaking_program_client
+            .operator_add_avs(
+                &restaking_config,
+                &operator,
+                &avs,
+                &operator_avs_ticket,
+                &operator_admin,
+                &operator_admin,
+            )
+            .await?;
+        self.warp_past_ticket_warmup().await?;
+
+        let avs_operator_ticket =
+            AvsOperatorTicket::find_program_address(&restaking_program, &avs, &operator).0;
+        restaking_program_client
+            .avs_add_operator(
+                &restaking_config,
+                &avs,
+                &operator,
+                &avs_operator_ticket,
+                &operator_avs_ticket,
+                &avs_admin,
+                &avs_admin,
+            )
+            .await?;
+
+        let vault_avs_ticket = VaultAvsTicket::find_program_address(&vault_program, &vault, &avs).0;
+        vault_program_client
+            .add_avs(
+                &vault_config,
+                &vault,
+                &avs,
+                &avs_vault_ticket,
+                &vault_avs_ticket,
+                &vault_admin,
+                &vault_admin,
+            )
+            .await?;
+
+        let vault_operator_ticket =
+            VaultOperatorTicket::find_program_address(&vault_program, &vault, &operator).0;
+        vault_program_client
+            .add_operator(
+                &vault_config,
+                &vault,
+                &operator,
+                &operator_vault_ticket,
+                &vault_operator_ticket,
+                &vault_admin,
+                &vault_admin,
+            )
+            .await?;
+
+        let slasher = Keypair::new();
+        self.transfer(&slasher.pubkey(), 1.0).await?;
+        let avs_vault_slasher_ticket = AvsVaultSlasherTicket::find_program_address(
+            &restaking_program,
+            &avs,
+            &vault,
+            &slasher.pubkey(),
+        )
+        .0;
+        restaking_program_client
+            .avs_add_vault_slasher(
+                &restaking_config,
+                &avs,
+                &vault,
+                &slasher.pubkey(),
+                &avs_vault_ticket,
+                &avs_vault_slasher_ticket,
+                &avs_admin,
+                &avs_admin,
+                GRAPH_MAX_SLASHABLE_PER_EPOCH,
+                [0; 32],
+            )
+            .await?;
+
+        let vault_avs_slasher_ticket = VaultAvsSlasherTicket::find_program_address(
+            &vault_program,
+            &vault,
+            &avs,
+            &slasher.pubkey(),
+        )
+        .0;
+        vault_program_client
+            .add_slasher(
+                &vault_config,
+                &vault,
+                &avs,
+                &slasher.pubkey(),
+                &avs_vault_slasher_ticket,
+                &vault_avs_slasher_ticket,
+                &vault_admin,
+                &vault_admin,
+            )
+            .await?;
+        self.warp_past_ticket_warmup().await?;
+
+        Ok(RelationshipGraph {
+            restaking_config,
+            vault_config,
+            avs,
+            avs_admin,
+            operator,
+            operator_admin,
+            vault,
+            vault_token_account: get_associated_token_address(&vault, &token_mint.pubkey()),
+            vault_fee_token_account: get_associated_token_address(
+                &vault_admin.pubkey(),
+                &lrt_mint.pubkey(),
+            ),
+            vault_admin,
+            vault_delegation_list,
+            token_mint,
+            lrt_mint,
+            slasher,
+            avs_operator_ticket,
+            avs_vault_ticket,
+            avs_vault_slasher_ticket,
+            operator_avs_ticket,
+            operator_vault_ticket,
+            vault_avs_ticket,
+            vault_operator_ticket,
+            vault_avs_slasher_ticket,
+        })
+    }
+
+    /// The current slot of the bank
+    pub async fn get_slot(&mut self) -> Result<u64, BanksClientError> {
+        let clock: Clock = self.context.banks_client.get_sysvar().await?;
+        Ok(clock.slot)
+    }
+
+    /// Warps the bank to the slot, which shall be ahead of the current one
+    pub async fn warp_to_slot(&mut self, slot: u64) -> Result<(), BanksClientError> {
+        self.context
+            .warp_to_slot(slot)
+            .map_err(|_| BanksClientError::ClientError("failed to warp slot"))
+    }
+
+    /// Warps the bank to the first slot of the epoch of the programs, which are `epoch_length`
+    /// slots long as set in their configs
+    pub async fn warp_to_epoch(
+        &mut self,
+        epoch: u64,
+        epoch_length: u64,
+    ) -> Result<(), BanksClientError> {
+        self.warp_to_slot(epoch.checked_mul(epoch_length).unwrap())
+            .await
+    }
+
+    /// Warps the bank to the first slot that starts a new epoch of both programs, so the
+    /// relationship tickets toggled until now have warmed up or cooled down. A program whose
+    /// config isn't initialized doesn't count.
+    pub async fn warp_past_ticket_warmup(&mut self) -> Result<(), BanksClientError> {
+        let restaking_config =
+            RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
+        let vault_config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+        let mut epoch_lengths = Vec::new();
+        if self.get_account(&restaking_config).await?.is_some() {
+            let config = self
+                .restaking_program_client()
+                .get_config(&restaking_config)
+                .await?;
+            epoch_lengths.push(config.ticket_epoch_length());
+        }
+        if self.get_account(&vault_config).await?.is_some() {
+            let config = self
+                .vault_program_client()
+                .get_config(&vault_config)
+                .await?;
+            epoch_lengths.push(config.epoch_length());
+        }
+
+        let slot = self.get_slot().await?;
+        let next_epoch_start = epoch_lengths
+            .into_iter()
+            .filter(|epoch_length| *epoch_length > 0)
+            .map(|epoch_length| (slot / epoch_length + 1).checked_mul(epoch_length).unwrap())
+            .max()
+            .unwrap_or_else(|| slot.checked_add(1).unwrap());
+        self.warp_to_slot(next_epoch_start).await
+    }
+
+    pub async fn warp_to_next_slot(&mut self) -> Result<(), BanksClientError> {
+        let clock: Clock = self.context.banks_client.get_sysvar().await?;
+        self.context
+            .warp_to_slot(clock.slot.checked_add(1).unwrap())
+            .map_err(|_| BanksClientError::ClientError("failed to warp slot"))?;
+        Ok(())
+    }
+
+    pub async fn warp_slot_incremental(
+        &mut self,
+        incremental_slots: u64,
+    ) -> Result<(), BanksClientError> {
+        let clock: Clock = self.context.banks_client.get_sysvar().await?;
+        self.context
+            .warp_to_slot(clock.slot.checked_add(incremental_slots).unwrap())
+            .map_err(|_| BanksClientError::ClientError("failed to warp slot"))?;
+        Ok(())
+    }
+
+    /// Signs and processes a transaction built by a [`jito_restaking_sdk::flows`] builder
+    pub async fn process_flow_transaction(
+        &mut self,
+        transaction: &FlowTransaction,
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        self.context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment(
+                Transaction::new(signers, transaction.message.clone(), blockhash),
+                CommitmentLevel::Processed,
+            )
+            .await
+    }
+
+    /// Processes a transaction built by a [`jito_restaking_sdk::flows`] builder, with each signer
+    /// signing the message on its own as separate parties would
+    pub async fn process_partially_signed_flow_transaction(
+        &mut self,
+        transaction: &FlowTransaction,
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let mut transaction = transaction.clone();
+        transaction.message.recent_blockhash =
+            self.context.banks_client.get_latest_blockhash().await?;
+        let message_data = transaction.message_data();
+
+        let mut signatures = vec![Signature::default(); transaction.signers().len()];
+        for signer in signers {
+            let index = transaction
+                .signer_index(&signer.pubkey())
+                .ok_or(BanksClientError::ClientError("unexpected signer"))?;
+            signatures[index] = signer.sign_message(&message_data);
+        }
+        self.context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment(
+                Transaction {
+                    signatures,
+                    message: transaction.message,
+                },
+                CommitmentLevel::Processed,
+            )
+            .await
+    }
 
     pub fn vault_program_client(&self) -> VaultProgramClient {
         VaultProgramClient::new(self.context.banks_client.clone())
+            .with_compute_unit_log(self.compute_unit_log.clone())
     }
 
     pub fn restaking_program_client(&self) -> RestakingProgramClient {
         RestakingProgramClient::new(self.context.banks_client.clone())
+            .with_compute_unit_log(self.compute_unit_log.clone())
     }
 }
@@ -1,3 +1,5 @@
+pub mod accounts;
+pub mod compute_units;
 pub mod fixture;
 pub mod restaking_client;
 pub mod vault_client;
@@ -1,120 +1,129 @@
-use borsh::BorshDeserialize;
 use jito_restaking_core::{
-    avs::Avs, avs_operator_ticket::AvsOperatorTicket,
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, avs_reward_budget::AvsRewardBudget,
     avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
-    config::Config, operator::Operator, operator_avs_ticket::OperatorAvsTicket,
-    operator_vault_ticket::OperatorVaultTicket,
+    config::Config, epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot, metadata::Metadata, operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore, operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket, reward_claim_status::RewardClaimStatus,
+    reward_distribution::RewardDistribution, vault_operator_epoch_stake::VaultOperatorEpochStake,
 };
+use jito_restaking_sanitization::ed25519::new_ed25519_instruction;
 use jito_restaking_sdk::{
-    avs_add_operator, avs_add_vault, avs_add_vault_slasher, initialize_avs, initialize_config,
-    initialize_operator, operator_add_avs, operator_add_vault,
+    avs_accept_admin, avs_add_operator, avs_add_vault, avs_add_vault_slasher, avs_remove_operator,
+    avs_remove_vault, avs_set_admin, avs_set_multisig, avs_set_secondary_admin,
+    avs_withdrawal_asset, config_accept_admin, config_set_admin, initialize_avs, initialize_config,
+    initialize_operator, operator_accept_admin, operator_add_avs, operator_add_vault,
+    operator_remove_avs, operator_remove_vault, operator_set_admin, operator_set_secondary_admin,
+    operator_set_voter, operator_withdrawal_asset, relayed_message, with_avs_multisig_signers,
+    AvsAdminRole, ConfigParameter, OperatorAdminRole, RelayedOperation,
+};
+use jito_restaking_sdk::{batch::ComputeBudget, lookup_table::v0_message};
+use jito_vault_core::{config::Config as VaultConfig, vault_delegation_list::VaultDelegationList};
+use solana_program::{
+    address_lookup_table::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::Instruction,
+    message::{Message, VersionedMessage},
+    pubkey::Pubkey,
 };
-use solana_program::pubkey::Pubkey;
 use solana_program_test::{BanksClient, BanksClientError};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    signer::signers::Signers,
+    transaction::VersionedTransaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
 };
 
+use crate::fixtures::{accounts::account_getters, compute_units::ComputeUnitLog};
+
 pub struct RestakingProgramClient {
     banks_client: BanksClient,
+    compute_unit_log: Option<ComputeUnitLog>,
+    compute_budget: ComputeBudget,
+    lookup_tables: Vec<AddressLookupTableAccount>,
 }
 
 impl RestakingProgramClient {
     pub const fn new(banks_client: BanksClient) -> Self {
-        Self { banks_client }
-    }
-
-    pub async fn get_avs(&mut self, avs: &Pubkey) -> Result<Avs, BanksClientError> {
-        let account = self.banks_client.get_account(*avs).await?.unwrap();
-
-        Ok(Avs::deserialize(&mut account.data.as_slice())?)
-    }
-
-    pub async fn get_config(&mut self, account: &Pubkey) -> Result<Config, BanksClientError> {
-        let account = self.banks_client.get_account(*account).await?.unwrap();
-        Ok(Config::deserialize(&mut account.data.as_slice())?)
+        Self {
+            banks_client,
+            compute_unit_log: None,
+            compute_budget: ComputeBudget {
+                unit_limit: None,
+                unit_price: None,
+            },
+            lookup_tables: Vec::new(),
+        }
     }
 
-    pub async fn get_avs_vault_ticket(
-        &mut self,
-        avs: &Pubkey,
-        vault: &Pubkey,
-    ) -> Result<AvsVaultTicket, BanksClientError> {
-        let account =
-            AvsVaultTicket::find_program_address(&jito_restaking_program::id(), &avs, &vault).0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(AvsVaultTicket::deserialize(&mut account.data.as_slice())?)
-    }
-
-    pub async fn get_avs_operator_ticket(
-        &mut self,
-        avs: &Pubkey,
-        operator: &Pubkey,
-    ) -> Result<AvsOperatorTicket, BanksClientError> {
-        let account =
-            AvsOperatorTicket::find_program_address(&jito_restaking_program::id(), &avs, &operator)
-                .0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(AvsOperatorTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+    /// Records the compute units of every transaction processed from now on in the log
+    pub fn with_compute_unit_log(mut self, compute_unit_log: Option<ComputeUnitLog>) -> Self {
+        self.compute_unit_log = compute_unit_log;
+        self
     }
 
-    pub async fn get_avs_vault_slasher_ticket(
-        &mut self,
-        avs: &Pubkey,
-        vault: &Pubkey,
-        slasher: &Pubkey,
-    ) -> Result<AvsVaultSlasherTicket, BanksClientError> {
-        let account = AvsVaultSlasherTicket::find_program_address(
-            &jito_restaking_program::id(),
-            &avs,
-            &vault,
-            &slasher,
-        )
-        .0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(AvsVaultSlasherTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+    /// Puts the compute budget instructions before the instructions of every transaction
+    pub fn with_compute_budget(mut self, compute_budget: ComputeBudget) -> Self {
+        self.compute_budget = compute_budget;
+        self
     }
 
-    pub async fn get_operator(&mut self, account: &Pubkey) -> Result<Operator, BanksClientError> {
-        let account = self.banks_client.get_account(*account).await?.unwrap();
-        Ok(Operator::deserialize(&mut account.data.as_slice())?)
+    /// Sends every transaction as a v0 transaction compressing the accounts found in the lookup
+    /// tables, see [`crate::fixtures::fixture::TestBuilder::create_lookup_table`]
+    pub fn with_lookup_tables(mut self, lookup_tables: Vec<AddressLookupTableAccount>) -> Self {
+        self.lookup_tables = lookup_tables;
+        self
     }
 
-    pub async fn get_operator_vault_ticket(
-        &mut self,
-        operator: &Pubkey,
-        vault: &Pubkey,
-    ) -> Result<OperatorVaultTicket, BanksClientError> {
-        let account = OperatorVaultTicket::find_program_address(
-            &jito_restaking_program::id(),
-            &operator,
-            &vault,
-        )
-        .0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(OperatorVaultTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+    /// The token program owning the mint, SPL Token or Token-2022
+    pub async fn get_token_program(&mut self, mint: &Pubkey) -> Result<Pubkey, BanksClientError> {
+        Ok(self
+            .banks_client
+            .get_account(*mint)
+            .await?
+            .expect("mint exists")
+            .owner)
     }
 
-    pub async fn get_operator_avs_ticket(
-        &mut self,
-        operator: &Pubkey,
-        avs: &Pubkey,
-    ) -> Result<OperatorAvsTicket, BanksClientError> {
-        let account =
-            OperatorAvsTicket::find_program_address(&jito_restaking_program::id(), &operator, &avs)
-                .0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(OperatorAvsTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+    account_getters! {
+        jito_restaking_program::id();
+        get_config(address) -> Config;
+        get_avs(address) -> Avs;
+        get_operator(address) -> Operator;
+        get_avs_operator_ticket(avs: &Pubkey, operator: &Pubkey) -> AvsOperatorTicket;
+        get_avs_vault_ticket(avs: &Pubkey, vault: &Pubkey) -> AvsVaultTicket;
+        get_avs_vault_slasher_ticket(
+            avs: &Pubkey,
+            vault: &Pubkey,
+            slasher: &Pubkey
+        ) -> AvsVaultSlasherTicket;
+        get_operator_avs_ticket(operator: &Pubkey, avs: &Pubkey) -> OperatorAvsTicket;
+        get_operator_vault_ticket(operator: &Pubkey, vault: &Pubkey) -> OperatorVaultTicket;
+        get_operator_avs_heartbeat(operator: &Pubkey, avs: &Pubkey) -> OperatorAvsHeartbeat;
+        get_operator_avs_score(operator: &Pubkey, avs: &Pubkey) -> OperatorAvsScore;
+        get_avs_reward_budget(avs: &Pubkey, mint: &Pubkey) -> AvsRewardBudget;
+        get_metadata(parent: &Pubkey) -> Metadata;
+        get_operator_avs_capacity(operator: &Pubkey, avs: &Pubkey) -> OperatorAvsCapacity;
+        get_epoch_reward_router(avs: &Pubkey, mint: &Pubkey, epoch: u64) -> EpochRewardRouter;
+        get_epoch_reward_snapshot(
+            router: &Pubkey,
+            vault: &Pubkey,
+            operator: &Pubkey
+        ) -> EpochRewardSnapshot;
+        get_operator_epoch_stake(operator: &Pubkey, epoch: u64) -> OperatorEpochStake;
+        get_vault_operator_epoch_stake(
+            vault: &Pubkey,
+            operator: &Pubkey,
+            epoch: u64
+        ) -> VaultOperatorEpochStake;
+        get_operator_stake_history(operator: &Pubkey) -> OperatorStakeHistory;
+        get_reward_distribution(source: &Pubkey, mint: &Pubkey, epoch: u64) -> RewardDistribution;
+        get_reward_claim_status(distribution: &Pubkey, claimant: &Pubkey) -> RewardClaimStatus;
     }
 
     pub async fn initialize_config(
@@ -123,12 +132,13 @@ impl RestakingProgramClient {
         config_admin: &Keypair,
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-        self.process_transaction(&Transaction::new_signed_with_payer(
+        self.process_transaction(&self.transaction(
             &[initialize_config(
                 &jito_restaking_program::id(),
                 config,
                 &config_admin.pubkey(),
                 &jito_vault_program::id(),
+                &config_admin.pubkey(),
             )],
             Some(&config_admin.pubkey()),
             &[config_admin],
@@ -137,6 +147,48 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn config_set_admin(
+        &mut self,
+        config: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[config_set_admin(
+                &jito_restaking_program::id(),
+                config,
+                &old_admin.pubkey(),
+                new_admin,
+            )],
+            Some(&old_admin.pubkey()),
+            &[old_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_accept_admin(
+        &mut self,
+        config: &Pubkey,
+        new_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[config_accept_admin(
+                &jito_restaking_program::id(),
+                config,
+                &new_admin.pubkey(),
+            )],
+            Some(&new_admin.pubkey()),
+            &[new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn initialize_avs(
         &mut self,
         config: &Pubkey,
@@ -146,13 +198,14 @@ impl RestakingProgramClient {
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
-        self.process_transaction(&Transaction::new_signed_with_payer(
+        self.process_transaction(&self.transaction(
             &[initialize_avs(
                 &jito_restaking_program::id(),
-                &config,
-                &avs,
+                config,
+                avs,
                 &avs_admin.pubkey(),
                 &avs_base.pubkey(),
+                &avs_admin.pubkey(),
             )],
             Some(&avs_admin.pubkey()),
             &[&avs_admin, &avs_base],
@@ -172,7 +225,7 @@ impl RestakingProgramClient {
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
-        self.process_transaction(&Transaction::new_signed_with_payer(
+        self.process_transaction(&self.transaction(
             &[avs_add_vault(
                 &jito_restaking_program::id(),
                 config,
@@ -189,32 +242,61 @@ impl RestakingProgramClient {
         .await
     }
 
-    // pub async fn avs_remove_vault(
-    //     &mut self,
-    //     config: &Pubkey,
-    //     avs: &Pubkey,
-    //     vault: &Pubkey,
-    //     avs_vault_ticket: &Pubkey,
-    //     avs_admin: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[avs_remove_vault(
-    //             &jito_restaking_program::id(),
-    //             config,
-    //             avs,
-    //             vault,
-    //             avs_vault_ticket,
-    //             &avs_admin.pubkey(),
-    //         )],
-    //         Some(&avs_admin.pubkey()),
-    //         &[avs_admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
+    pub async fn avs_set_vault_service_fee(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        avs_vault_ticket: &Pubkey,
+        avs_admin: &Keypair,
+        fee_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_set_vault_service_fee(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                vault,
+                avs_vault_ticket,
+                &avs_admin.pubkey(),
+                fee_bps,
+            )],
+            Some(&avs_admin.pubkey()),
+            &[avs_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_remove_vault(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        avs_vault_ticket: &Pubkey,
+        avs_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[avs_remove_vault(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                vault,
+                avs_vault_ticket,
+                &avs_admin.pubkey(),
+            )],
+            Some(&avs_admin.pubkey()),
+            &[avs_admin],
+            blockhash,
+        ))
+        .await
+    }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn avs_add_operator(
         &mut self,
         config: &Pubkey,
@@ -227,7 +309,7 @@ impl RestakingProgramClient {
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
-        self.process_transaction(&Transaction::new_signed_with_payer(
+        self.process_transaction(&self.transaction(
             &[avs_add_operator(
                 &jito_restaking_program::id(),
                 config,
@@ -245,32 +327,33 @@ impl RestakingProgramClient {
         .await
     }
 
-    // pub async fn avs_remove_operator(
-    //     &mut self,
-    //     config: &Pubkey,
-    //     avs: &Pubkey,
-    //     operator: &Pubkey,
-    //     avs_operator_ticket: &Pubkey,
-    //     avs_admin: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[avs_remove_operator(
-    //             &jito_restaking_program::id(),
-    //             config,
-    //             avs,
-    //             operator,
-    //             avs_operator_ticket,
-    //             &avs_admin.pubkey(),
-    //         )],
-    //         Some(&avs_admin.pubkey()),
-    //         &[avs_admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
+    pub async fn avs_remove_operator(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        avs_operator_ticket: &Pubkey,
+        avs_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[avs_remove_operator(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                operator,
+                avs_operator_ticket,
+                &avs_admin.pubkey(),
+            )],
+            Some(&avs_admin.pubkey()),
+            &[avs_admin],
+            blockhash,
+        ))
+        .await
+    }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn avs_add_vault_slasher(
         &mut self,
         config: &Pubkey,
@@ -282,11 +365,82 @@ impl RestakingProgramClient {
         avs_admin: &Keypair,
         payer: &Keypair,
         max_slash_amount: u64,
+        metadata_hash: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        self.avs_add_vault_slasher_with_multisig(
+            config,
+            avs,
+            vault,
+            slasher,
+            avs_vault_ticket,
+            avs_slasher_ticket,
+            avs_admin,
+            payer,
+            max_slash_amount,
+            metadata_hash,
+            &[],
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn avs_add_vault_slasher_with_multisig(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+        avs_vault_ticket: &Pubkey,
+        avs_slasher_ticket: &Pubkey,
+        avs_admin: &Keypair,
+        payer: &Keypair,
+        max_slash_amount: u64,
+        metadata_hash: [u8; 32],
+        multisig: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        let mut signers = vec![avs_admin, payer];
+        signers.extend_from_slice(multisig);
+        self.process_transaction(&self.transaction(
+            &[with_avs_multisig_signers(
+                avs_add_vault_slasher(
+                    &jito_restaking_program::id(),
+                    config,
+                    avs,
+                    vault,
+                    slasher,
+                    avs_vault_ticket,
+                    avs_slasher_ticket,
+                    &avs_admin.pubkey(),
+                    &payer.pubkey(),
+                    max_slash_amount,
+                    metadata_hash,
+                ),
+                &multisig.iter().map(|k| k.pubkey()).collect::<Vec<_>>(),
+            )],
+            Some(&payer.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn avs_remove_vault_slasher(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+        avs_vault_ticket: &Pubkey,
+        avs_slasher_ticket: &Pubkey,
+        avs_admin: &Keypair,
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
-        self.process_transaction(&Transaction::new_signed_with_payer(
-            &[avs_add_vault_slasher(
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_remove_vault_slasher(
                 &jito_restaking_program::id(),
                 config,
                 avs,
@@ -295,327 +449,1679 @@ impl RestakingProgramClient {
                 avs_vault_ticket,
                 avs_slasher_ticket,
                 &avs_admin.pubkey(),
-                &payer.pubkey(),
-                max_slash_amount,
             )],
-            Some(&payer.pubkey()),
-            &[avs_admin, payer],
+            Some(&avs_admin.pubkey()),
+            &[avs_admin],
             blockhash,
         ))
         .await
     }
 
-    // pub async fn avs_remove_vault_slasher(
-    //     &mut self,
-    //     config: &Pubkey,
-    //     avs: &Pubkey,
-    //     vault: &Pubkey,
-    //     slasher: &Pubkey,
-    //     avs_slasher_ticket: &Pubkey,
-    //     avs_admin: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[avs_remove_vault_slasher(
-    //             &jito_restaking_program::id(),
-    //             config,
-    //             avs,
-    //             vault,
-    //             slasher,
-    //             avs_slasher_ticket,
-    //             &avs_admin.pubkey(),
-    //         )],
-    //         Some(&avs_admin.pubkey()),
-    //         &[avs_admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-    //
-    // pub async fn avs_set_admin(
-    //     &mut self,
-    //     avs: &Pubkey,
-    //     old_admin: &Keypair,
-    //     new_admin: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[avs_set_admin(
-    //             &jito_restaking_program::id(),
-    //             avs,
-    //             &old_admin.pubkey(),
-    //             &new_admin.pubkey(),
-    //         )],
-    //         Some(&old_admin.pubkey()),
-    //         &[old_admin, new_admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-    //
-    // pub async fn avs_set_secondary_admin(
-    //     &mut self,
-    //     avs: &Pubkey,
-    //     admin: &Keypair,
-    //     new_admin: &Pubkey,
-    //     role: AvsAdminRole,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[avs_set_secondary_admin(
-    //             &jito_restaking_program::id(),
-    //             avs,
-    //             &admin.pubkey(),
-    //             new_admin,
-    //             role,
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
+    pub async fn avs_set_admin(
+        &mut self,
+        avs: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.avs_set_admin_with_multisig(avs, old_admin, new_admin, &[])
+            .await
+    }
+
+    pub async fn avs_set_admin_with_multisig(
+        &mut self,
+        avs: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Pubkey,
+        multisig: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        let mut signers = vec![old_admin];
+        signers.extend_from_slice(multisig);
+        self.process_transaction(&self.transaction(
+            &[with_avs_multisig_signers(
+                avs_set_admin(
+                    &jito_restaking_program::id(),
+                    avs,
+                    &old_admin.pubkey(),
+                    new_admin,
+                ),
+                &multisig.iter().map(|k| k.pubkey()).collect::<Vec<_>>(),
+            )],
+            Some(&old_admin.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
 
-    pub async fn initialize_operator(
+    pub async fn avs_set_multisig(
         &mut self,
-        config: &Pubkey,
-        operator: &Pubkey,
+        avs: &Pubkey,
         admin: &Keypair,
-        base: &Keypair,
+        signers: [Pubkey; 3],
+        threshold: u8,
+        multisig: &[&Keypair],
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
-        self.process_transaction(&Transaction::new_signed_with_payer(
-            &[initialize_operator(
+        let mut tx_signers = vec![admin];
+        tx_signers.extend_from_slice(multisig);
+        self.process_transaction(&self.transaction(
+            &[with_avs_multisig_signers(
+                avs_set_multisig(
+                    &jito_restaking_program::id(),
+                    avs,
+                    &admin.pubkey(),
+                    signers,
+                    threshold,
+                ),
+                &multisig.iter().map(|k| k.pubkey()).collect::<Vec<_>>(),
+            )],
+            Some(&admin.pubkey()),
+            &tx_signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_accept_admin(
+        &mut self,
+        avs: &Pubkey,
+        new_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[avs_accept_admin(
                 &jito_restaking_program::id(),
-                config,
-                operator,
+                avs,
+                &new_admin.pubkey(),
+            )],
+            Some(&new_admin.pubkey()),
+            &[new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_set_secondary_admin(
+        &mut self,
+        avs: &Pubkey,
+        admin: &Keypair,
+        new_admin: &Pubkey,
+        role: AvsAdminRole,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[avs_set_secondary_admin(
+                &jito_restaking_program::id(),
+                avs,
                 &admin.pubkey(),
-                &base.pubkey(),
+                new_admin,
+                role,
             )],
             Some(&admin.pubkey()),
-            &[admin, base],
+            &[admin],
             blockhash,
         ))
         .await
     }
 
-    // pub async fn operator_set_admin(
-    //     &mut self,
-    //     node_operator: &Pubkey,
-    //     old_admin: &Keypair,
-    //     new_admin: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[operator_set_admin(
-    //             &jito_restaking_program::id(),
-    //             node_operator,
-    //             &old_admin.pubkey(),
-    //             &new_admin.pubkey(),
-    //         )],
-    //         Some(&old_admin.pubkey()),
-    //         &[old_admin, new_admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-    //
-    // pub async fn operator_set_voter(
-    //     &mut self,
-    //     node_operator: &Pubkey,
-    //     admin: &Keypair,
-    //     voter: &Pubkey,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[operator_set_voter(
-    //             &jito_restaking_program::id(),
-    //             node_operator,
-    //             &admin.pubkey(),
-    //             voter,
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-
-    pub async fn operator_add_vault(
+    pub async fn operator_set_secondary_admin(
         &mut self,
-        config: &Pubkey,
         operator: &Pubkey,
-        vault: &Pubkey,
-        operator_vault_ticket: &Pubkey,
         admin: &Keypair,
-        payer: &Keypair,
+        new_admin: &Pubkey,
+        role: OperatorAdminRole,
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
-        self.process_transaction(&Transaction::new_signed_with_payer(
-            &[operator_add_vault(
+        self.process_transaction(&self.transaction(
+            &[operator_set_secondary_admin(
                 &jito_restaking_program::id(),
-                config,
                 operator,
-                vault,
-                operator_vault_ticket,
                 &admin.pubkey(),
-                &payer.pubkey(),
+                new_admin,
+                role,
             )],
-            Some(&payer.pubkey()),
-            &[admin, payer],
+            Some(&admin.pubkey()),
+            &[admin],
             blockhash,
         ))
         .await
     }
 
-    // pub async fn operator_remove_vault(
-    //     &mut self,
-    //     config: &Pubkey,
-    //     operator: &Pubkey,
-    //     vault: &Pubkey,
-    //     operator_vault_ticket: &Pubkey,
-    //     admin: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[operator_remove_vault(
-    //             &jito_restaking_program::id(),
-    //             config,
-    //             operator,
-    //             vault,
-    //             operator_vault_ticket,
-    //             &admin.pubkey(),
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-
-    pub async fn operator_add_avs(
+    pub async fn initialize_operator(
         &mut self,
         config: &Pubkey,
         operator: &Pubkey,
-        avs: &Pubkey,
-        operator_avs_ticket: &Pubkey,
         admin: &Keypair,
-        payer: &Keypair,
+        base: &Keypair,
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
-        self.process_transaction(&Transaction::new_signed_with_payer(
-            &[operator_add_avs(
+        self.process_transaction(&self.transaction(
+            &[initialize_operator(
                 &jito_restaking_program::id(),
                 config,
                 operator,
-                avs,
-                operator_avs_ticket,
                 &admin.pubkey(),
-                &payer.pubkey(),
+                &base.pubkey(),
+                &admin.pubkey(),
             )],
-            Some(&payer.pubkey()),
-            &[admin, payer],
+            Some(&admin.pubkey()),
+            &[admin, base],
             blockhash,
         ))
         .await
     }
 
-    // pub async fn operator_remove_avs(
-    //     &mut self,
-    //     config: &Pubkey,
-    //     operator: &Pubkey,
-    //     avs: &Pubkey,
-    //     operator_avs_ticket: &Pubkey,
-    //     admin: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[operator_remove_avs(
-    //             &jito_restaking_program::id(),
-    //             config,
-    //             operator,
-    //             avs,
-    //             operator_avs_ticket,
-    //             &admin.pubkey(),
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-    //
-    // pub async fn avs_withdrawal_asset(
-    //     &mut self,
-    //     avs: &Pubkey,
-    //     avs_token_account: &Pubkey,
-    //     receiver_token_account: &Pubkey,
-    //     admin: &Keypair,
-    //     token_program: &Pubkey,
-    //     token_mint: Pubkey,
-    //     amount: u64,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[avs_withdrawal_asset(
-    //             &jito_restaking_program::id(),
-    //             avs,
-    //             avs_token_account,
-    //             receiver_token_account,
-    //             &admin.pubkey(),
-    //             token_program,
-    //             token_mint,
-    //             amount,
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-    //
-    // pub async fn operator_withdrawal_asset(
-    //     &mut self,
-    //     operator: &Pubkey,
-    //     admin: &Keypair,
-    //     operator_token_account: &Pubkey,
-    //     receiver_token_account: &Pubkey,
-    //     token_program: &Pubkey,
-    //     token_mint: Pubkey,
-    //     amount: u64,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[operator_withdrawal_asset(
-    //             &jito_restaking_program::id(),
-    //             operator,
-    //             &admin.pubkey(),
-    //             operator_token_account,
-    //             receiver_token_account,
-    //             token_program,
-    //             token_mint,
-    //             amount,
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-
-    pub async fn process_transaction(&mut self, tx: &Transaction) -> Result<(), BanksClientError> {
+    pub async fn operator_set_admin(
+        &mut self,
+        operator: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_set_admin(
+                &jito_restaking_program::id(),
+                operator,
+                &old_admin.pubkey(),
+                new_admin,
+            )],
+            Some(&old_admin.pubkey()),
+            &[old_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_accept_admin(
+        &mut self,
+        operator: &Pubkey,
+        new_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_accept_admin(
+                &jito_restaking_program::id(),
+                operator,
+                &new_admin.pubkey(),
+            )],
+            Some(&new_admin.pubkey()),
+            &[new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_set_voter(
+        &mut self,
+        node_operator: &Pubkey,
+        admin: &Keypair,
+        voter: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_set_voter(
+                &jito_restaking_program::id(),
+                node_operator,
+                &admin.pubkey(),
+                voter,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_add_vault(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        operator_vault_ticket: &Pubkey,
+        admin: &Keypair,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_add_vault(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                vault,
+                operator_vault_ticket,
+                &admin.pubkey(),
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[admin, payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_remove_vault(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        operator_vault_ticket: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_remove_vault(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                vault,
+                operator_vault_ticket,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_add_avs(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        operator_avs_ticket: &Pubkey,
+        admin: &Keypair,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_add_avs(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                avs,
+                operator_avs_ticket,
+                &admin.pubkey(),
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[admin, payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_remove_avs(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        operator_avs_ticket: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_remove_avs(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                avs,
+                operator_avs_ticket,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn operator_heartbeat(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        operator_avs_ticket: &Pubkey,
+        operator_avs_heartbeat: &Pubkey,
+        voter: &Keypair,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::operator_heartbeat(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                avs,
+                operator_avs_ticket,
+                operator_avs_heartbeat,
+                &voter.pubkey(),
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[voter, payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_set_avs_capacity(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        avs: &Pubkey,
+        admin: &Keypair,
+        capacity: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::operator_set_avs_capacity(
+                    &jito_restaking_program::id(),
+                    config,
+                    operator,
+                    avs,
+                    &OperatorAvsTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        avs,
+                    )
+                    .0,
+                    &OperatorAvsCapacity::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        avs,
+                    )
+                    .0,
+                    &admin.pubkey(),
+                    &admin.pubkey(),
+                    capacity,
+                )],
+                Some(&admin.pubkey()),
+                &[admin],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    pub async fn avs_set_max_heartbeat_age(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        avs_admin: &Keypair,
+        max_heartbeat_age: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_set_max_heartbeat_age(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                &avs_admin.pubkey(),
+                max_heartbeat_age,
+            )],
+            Some(&avs_admin.pubkey()),
+            &[avs_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_set_max_slashers_per_vault(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        avs_admin: &Keypair,
+        max_slashers_per_vault: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_set_max_slashers_per_vault(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                &avs_admin.pubkey(),
+                max_slashers_per_vault,
+            )],
+            Some(&avs_admin.pubkey()),
+            &[avs_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_eject_stale_operator(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        avs_operator_ticket: &Pubkey,
+        operator_avs_heartbeat: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_eject_stale_operator(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                operator,
+                avs_operator_ticket,
+                operator_avs_heartbeat,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_reactivate_operator(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        avs_operator_ticket: &Pubkey,
+        operator_avs_heartbeat: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_reactivate_operator(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                operator,
+                avs_operator_ticket,
+                operator_avs_heartbeat,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_exit_all(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        operator_avs_tickets: &[Pubkey],
+        operator_vault_tickets: &[Pubkey],
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::operator_exit_all(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                &admin.pubkey(),
+                operator_avs_tickets,
+                operator_vault_tickets,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn assert_avs_healthy(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        tickets: &[Pubkey],
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::assert_avs_healthy(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                tickets,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn assert_operator_healthy(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        tickets: &[Pubkey],
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::assert_operator_healthy(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                tickets,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_add_slasher_program(
+        &mut self,
+        config: &Pubkey,
+        config_admin: &Keypair,
+        program: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::config_add_slasher_program(
+                &jito_restaking_program::id(),
+                config,
+                &config_admin.pubkey(),
+                program,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_set_operator_fee_params(
+        &mut self,
+        config: &Pubkey,
+        config_admin: &Keypair,
+        max_operator_fee_bps: u16,
+        operator_fee_cooldown_slots: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::config_set_operator_fee_params(
+                &jito_restaking_program::id(),
+                config,
+                &config_admin.pubkey(),
+                max_operator_fee_bps,
+                operator_fee_cooldown_slots,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_set_parameter(
+        &mut self,
+        config: &Pubkey,
+        config_admin: &Keypair,
+        parameter: ConfigParameter,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::config_set_parameter(
+                &jito_restaking_program::id(),
+                config,
+                &config_admin.pubkey(),
+                parameter,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Posts the reward root of the source, funded from the admin's associated token account. The
+    /// associated token account of the distribution must exist.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn post_reward_root(
+        &mut self,
+        config: &Pubkey,
+        source: &Pubkey,
+        mint: &Pubkey,
+        admin: &Keypair,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    ) -> Result<(), BanksClientError> {
+        let token_program = self.get_token_program(mint).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::post_reward_root(
+                    &jito_restaking_program::id(),
+                    config,
+                    source,
+                    &RewardDistribution::find_program_address(
+                        &jito_restaking_program::id(),
+                        source,
+                        mint,
+                        epoch,
+                    )
+                    .0,
+                    mint,
+                    &get_associated_token_address_with_program_id(
+                        &admin.pubkey(),
+                        mint,
+                        &token_program,
+                    ),
+                    &admin.pubkey(),
+                    &admin.pubkey(),
+                    &token_program,
+                    epoch,
+                    merkle_root,
+                    max_total_claim,
+                    max_num_nodes,
+                )],
+                Some(&admin.pubkey()),
+                &[admin],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    /// Claims the reward of the claimant to its associated token account
+    pub async fn claim_reward(
+        &mut self,
+        reward_distribution: &Pubkey,
+        claimant: &Pubkey,
+        mint: &Pubkey,
+        payer: &Keypair,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), BanksClientError> {
+        let token_program = self.get_token_program(mint).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::claim_reward(
+                    &jito_restaking_program::id(),
+                    reward_distribution,
+                    &RewardClaimStatus::find_program_address(
+                        &jito_restaking_program::id(),
+                        reward_distribution,
+                        claimant,
+                    )
+                    .0,
+                    claimant,
+                    mint,
+                    &payer.pubkey(),
+                    &token_program,
+                    amount,
+                    proof,
+                )],
+                Some(&payer.pubkey()),
+                &[payer],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    pub async fn finalize_operator_avs_removal(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        vaults: &[Pubkey],
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let avs_rent_collector = self.get_avs(avs).await?.rent_collector();
+        let operator_rent_collector = self.get_operator(operator).await?.rent_collector();
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::finalize_operator_avs_removal(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                operator,
+                &avs_rent_collector,
+                &operator_rent_collector,
+                &jito_vault_program::id(),
+                vaults,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_operator_fee(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        admin: &Keypair,
+        operator_fee_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::set_operator_fee(
+                &jito_restaking_program::id(),
+                config,
+                operator,
+                &admin.pubkey(),
+                operator_fee_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_remove_slasher_program(
+        &mut self,
+        config: &Pubkey,
+        config_admin: &Keypair,
+        program: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::config_remove_slasher_program(
+                &jito_restaking_program::id(),
+                config,
+                &config_admin.pubkey(),
+                program,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_set_ticket_epoch_length(
+        &mut self,
+        config: &Pubkey,
+        config_admin: &Keypair,
+        ticket_epoch_length: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::config_set_ticket_epoch_length(
+                &jito_restaking_program::id(),
+                config,
+                &config_admin.pubkey(),
+                ticket_epoch_length,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Sweeps the balance of the PDA's associated token account into the treasury's associated
+    /// token account
+    pub async fn sweep_tokens(
+        &mut self,
+        config: &Pubkey,
+        parent: &Pubkey,
+        pda: &Pubkey,
+        treasury: &Pubkey,
+        admin: &Keypair,
+        token_mint: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::sweep_tokens(
+                &jito_restaking_program::id(),
+                config,
+                parent,
+                pda,
+                &get_associated_token_address(pda, token_mint),
+                &get_associated_token_address(treasury, token_mint),
+                &admin.pubkey(),
+                &spl_token::id(),
+                *token_mint,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn top_up_rent(
+        &mut self,
+        account: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::top_up_rent(
+                &jito_restaking_program::id(),
+                account,
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn migrate_account(
+        &mut self,
+        account: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::migrate_account(
+                &jito_restaking_program::id(),
+                account,
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn sweep_lamports(
+        &mut self,
+        config: &Pubkey,
+        parent: &Pubkey,
+        pda: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::sweep_lamports(
+                &jito_restaking_program::id(),
+                config,
+                parent,
+                pda,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn update_avs_metadata(
+        &mut self,
+        avs: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        uri: &str,
+        icon: &str,
+    ) -> Result<(), BanksClientError> {
+        let metadata = Metadata::find_program_address(&jito_restaking_program::id(), avs).0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::update_avs_metadata(
+                &jito_restaking_program::id(),
+                avs,
+                &metadata,
+                &metadata_admin.pubkey(),
+                &metadata_admin.pubkey(),
+                name.to_string(),
+                uri.to_string(),
+                icon.to_string(),
+            )],
+            Some(&metadata_admin.pubkey()),
+            &[metadata_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn update_operator_metadata(
+        &mut self,
+        operator: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        uri: &str,
+        icon: &str,
+    ) -> Result<(), BanksClientError> {
+        let metadata = Metadata::find_program_address(&jito_restaking_program::id(), operator).0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::update_operator_metadata(
+                &jito_restaking_program::id(),
+                operator,
+                &metadata,
+                &metadata_admin.pubkey(),
+                &metadata_admin.pubkey(),
+                name.to_string(),
+                uri.to_string(),
+                icon.to_string(),
+            )],
+            Some(&metadata_admin.pubkey()),
+            &[metadata_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_set_rent_collector(
+        &mut self,
+        avs: &Pubkey,
+        admin: &Keypair,
+        rent_collector: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_set_rent_collector(
+                &jito_restaking_program::id(),
+                avs,
+                &admin.pubkey(),
+                rent_collector,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_set_rent_collector(
+        &mut self,
+        operator: &Pubkey,
+        admin: &Keypair,
+        rent_collector: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::operator_set_rent_collector(
+                &jito_restaking_program::id(),
+                operator,
+                &admin.pubkey(),
+                rent_collector,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn close_ticket(
+        &mut self,
+        config: &Pubkey,
+        parent: &Pubkey,
+        ticket: &Pubkey,
+        rent_collector: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::close_ticket(
+                &jito_restaking_program::id(),
+                config,
+                parent,
+                ticket,
+                rent_collector,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_set_relayer(
+        &mut self,
+        avs: &Pubkey,
+        admin: &Keypair,
+        relayer: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_set_relayer(
+                &jito_restaking_program::id(),
+                avs,
+                &admin.pubkey(),
+                relayer,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_set_scorer(
+        &mut self,
+        avs: &Pubkey,
+        admin: &Keypair,
+        scorer: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_set_scorer(
+                &jito_restaking_program::id(),
+                avs,
+                &admin.pubkey(),
+                scorer,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_attest_operator_score(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        scorer: &Keypair,
+        payer: &Keypair,
+        score_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::avs_attest_operator_score(
+                    &jito_restaking_program::id(),
+                    config,
+                    avs,
+                    operator,
+                    &AvsOperatorTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        avs,
+                        operator,
+                    )
+                    .0,
+                    &OperatorAvsScore::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        avs,
+                    )
+                    .0,
+                    &scorer.pubkey(),
+                    &payer.pubkey(),
+                    score_bps,
+                )],
+                Some(&payer.pubkey()),
+                &[scorer, payer],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    pub async fn operator_set_relayer(
+        &mut self,
+        operator: &Pubkey,
+        admin: &Keypair,
+        relayer: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::operator_set_relayer(
+                &jito_restaking_program::id(),
+                operator,
+                &admin.pubkey(),
+                relayer,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Relays an operation signed off-chain by `signer`, with the relayer paying the fees
+    pub async fn relay_admin_operation(
+        &mut self,
+        config: &Pubkey,
+        account: &Pubkey,
+        relayer: &Keypair,
+        signer: &Keypair,
+        nonce: u64,
+        operation: RelayedOperation,
+    ) -> Result<(), BanksClientError> {
+        let message = relayed_message(&jito_restaking_program::id(), account, nonce, &operation);
+        let signature: [u8; 64] = signer.sign_message(&message).into();
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&self.transaction(
+            &[
+                new_ed25519_instruction(&signer.pubkey(), &signature, &message),
+                jito_restaking_sdk::relay_admin_operation(
+                    &jito_restaking_program::id(),
+                    config,
+                    account,
+                    &relayer.pubkey(),
+                    nonce,
+                    operation,
+                ),
+            ],
+            Some(&relayer.pubkey()),
+            &[relayer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Creates the AVS reward budget for the mint, paying out to the receiver's associated token
+    /// account
+    #[allow(clippy::too_many_arguments)]
+    pub async fn avs_initialize_reward_budget(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        receiver: &Pubkey,
+        admin: &Keypair,
+        epoch_length: u64,
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_initialize_reward_budget(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                &AvsRewardBudget::find_program_address(&jito_restaking_program::id(), avs, mint).0,
+                mint,
+                &get_associated_token_address(receiver, mint),
+                &admin.pubkey(),
+                &admin.pubkey(),
+                epoch_length,
+                tokens_per_epoch,
+                decay_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn avs_set_reward_budget(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        admin: &Keypair,
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_set_reward_budget(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                &AvsRewardBudget::find_program_address(&jito_restaking_program::id(), avs, mint).0,
+                mint,
+                &admin.pubkey(),
+                tokens_per_epoch,
+                decay_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Pays out the AVS reward budget from the AVS associated token account to the receiver's
+    pub async fn avs_distribute_rewards(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        receiver: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_distribute_rewards(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                &AvsRewardBudget::find_program_address(&jito_restaking_program::id(), avs, mint).0,
+                mint,
+                &get_associated_token_address(avs, mint),
+                &get_associated_token_address(receiver, mint),
+                &spl_token::id(),
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Deposits rewards for the epoch from the depositor's associated token account
+    pub async fn avs_deposit_epoch_rewards(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        depositor: &Keypair,
+        epoch: u64,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::avs_deposit_epoch_rewards(
+                    &jito_restaking_program::id(),
+                    config,
+                    avs,
+                    &VaultConfig::find_program_address(&jito_vault_program::id()).0,
+                    &EpochRewardRouter::find_program_address(
+                        &jito_restaking_program::id(),
+                        avs,
+                        mint,
+                        epoch,
+                    )
+                    .0,
+                    mint,
+                    &get_associated_token_address(&depositor.pubkey(), mint),
+                    &depositor.pubkey(),
+                    &depositor.pubkey(),
+                    epoch,
+                    amount,
+                )],
+                Some(&depositor.pubkey()),
+                &[depositor],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    pub async fn snapshot_epoch_reward_stake(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        epoch_reward_router: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::snapshot_epoch_reward_stake(
+                    &jito_restaking_program::id(),
+                    config,
+                    avs,
+                    operator,
+                    vault,
+                    &VaultConfig::find_program_address(&jito_vault_program::id()).0,
+                    &VaultDelegationList::find_program_address(&jito_vault_program::id(), vault).0,
+                    &AvsOperatorTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        avs,
+                        operator,
+                    )
+                    .0,
+                    &OperatorAvsTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        avs,
+                    )
+                    .0,
+                    &AvsVaultTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        avs,
+                        vault,
+                    )
+                    .0,
+                    &OperatorVaultTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        vault,
+                    )
+                    .0,
+                    epoch_reward_router,
+                    &EpochRewardSnapshot::find_program_address(
+                        &jito_restaking_program::id(),
+                        epoch_reward_router,
+                        vault,
+                        operator,
+                    )
+                    .0,
+                    &OperatorAvsScore::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        avs,
+                    )
+                    .0,
+                    &payer.pubkey(),
+                )],
+                Some(&payer.pubkey()),
+                &[payer],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    /// Snapshots the stake the vault delegates to the operator in `epoch`, which must be the
+    /// current epoch
+    pub async fn snapshot_epoch_stake(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::snapshot_epoch_stake(
+                    &jito_restaking_program::id(),
+                    config,
+                    operator,
+                    vault,
+                    &VaultConfig::find_program_address(&jito_vault_program::id()).0,
+                    &VaultDelegationList::find_program_address(&jito_vault_program::id(), vault).0,
+                    &OperatorVaultTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        vault,
+                    )
+                    .0,
+                    &OperatorEpochStake::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        epoch,
+                    )
+                    .0,
+                    &VaultOperatorEpochStake::find_program_address(
+                        &jito_restaking_program::id(),
+                        vault,
+                        operator,
+                        epoch,
+                    )
+                    .0,
+                    &payer.pubkey(),
+                )],
+                Some(&payer.pubkey()),
+                &[payer],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    pub async fn archive_epoch_stake(
+        &mut self,
+        config: &Pubkey,
+        operator: &Pubkey,
+        epoch_stake: &Pubkey,
+        rent_collector: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::archive_epoch_stake(
+                    &jito_restaking_program::id(),
+                    config,
+                    operator,
+                    &VaultConfig::find_program_address(&jito_vault_program::id()).0,
+                    &OperatorStakeHistory::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                    )
+                    .0,
+                    epoch_stake,
+                    rent_collector,
+                    &payer.pubkey(),
+                )],
+                Some(&payer.pubkey()),
+                &[payer],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    /// Pays out the snapshot of the vault and operator to their associated token accounts
+    #[allow(clippy::too_many_arguments)]
+    pub async fn distribute_epoch_rewards(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        epoch_reward_router: &Pubkey,
+        mint: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(
+            &self.transaction(
+                &[jito_restaking_sdk::distribute_epoch_rewards(
+                    &jito_restaking_program::id(),
+                    config,
+                    avs,
+                    epoch_reward_router,
+                    &EpochRewardSnapshot::find_program_address(
+                        &jito_restaking_program::id(),
+                        epoch_reward_router,
+                        vault,
+                        operator,
+                    )
+                    .0,
+                    mint,
+                    operator,
+                    vault,
+                )],
+                Some(&payer.pubkey()),
+                &[payer],
+                blockhash,
+            ),
+        )
+        .await
+    }
+
+    /// Reclaims what's left in the router to the receiver's associated token account
+    pub async fn avs_reclaim_epoch_rewards(
+        &mut self,
+        config: &Pubkey,
+        avs: &Pubkey,
+        epoch_reward_router: &Pubkey,
+        mint: &Pubkey,
+        receiver: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[jito_restaking_sdk::avs_reclaim_epoch_rewards(
+                &jito_restaking_program::id(),
+                config,
+                avs,
+                epoch_reward_router,
+                mint,
+                &get_associated_token_address(receiver, mint),
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn avs_withdrawal_asset(
+        &mut self,
+        avs: &Pubkey,
+        avs_token_account: &Pubkey,
+        receiver_token_account: &Pubkey,
+        admin: &Keypair,
+        token_program: &Pubkey,
+        token_mint: Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[avs_withdrawal_asset(
+                &jito_restaking_program::id(),
+                avs,
+                avs_token_account,
+                receiver_token_account,
+                &admin.pubkey(),
+                token_program,
+                token_mint,
+                amount,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn operator_withdrawal_asset(
+        &mut self,
+        operator: &Pubkey,
+        admin: &Keypair,
+        operator_token_account: &Pubkey,
+        receiver_token_account: &Pubkey,
+        token_program: &Pubkey,
+        token_mint: Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&self.transaction(
+            &[operator_withdrawal_asset(
+                &jito_restaking_program::id(),
+                operator,
+                &admin.pubkey(),
+                operator_token_account,
+                receiver_token_account,
+                token_program,
+                token_mint,
+                amount,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Signs the instructions with the compute budget of the client, in a legacy transaction or in
+    /// a v0 transaction when the client has lookup tables. A signer holding several roles may be
+    /// passed more than once.
+    fn transaction<T: Signers + ?Sized>(
+        &self,
+        instructions: &[Instruction],
+        payer: Option<&Pubkey>,
+        signers: &T,
+        blockhash: Hash,
+    ) -> VersionedTransaction {
+        let payer = payer.expect("transactions have a payer");
+        let message = if self.lookup_tables.is_empty() {
+            VersionedMessage::Legacy(Message::new_with_blockhash(
+                &[self.compute_budget.instructions(), instructions.to_vec()].concat(),
+                Some(payer),
+                &blockhash,
+            ))
+        } else {
+            v0_message(
+                instructions,
+                payer,
+                &self.compute_budget,
+                &self.lookup_tables,
+                &blockhash,
+            )
+            .unwrap()
+        };
+
+        let message_data = message.serialize();
+        let keys = signers.pubkeys();
+        let signatures = signers.sign_message(&message_data);
+        let num_signers = usize::from(message.header().num_required_signatures);
+        let signatures = message.static_account_keys()[..num_signers]
+            .iter()
+            .map(|signer| {
+                let index = keys
+                    .iter()
+                    .position(|key| key == signer)
+                    .unwrap_or_else(|| panic!("{signer} didn't sign"));
+                signatures[index]
+            })
+            .collect();
+        VersionedTransaction {
+            signatures,
+            message,
+        }
+    }
+
+    pub async fn process_transaction(
+        &mut self,
+        tx: &VersionedTransaction,
+    ) -> Result<(), BanksClientError> {
+        if let Some(compute_unit_log) = &self.compute_unit_log {
+            compute_unit_log.record(&mut self.banks_client, tx).await?;
+        }
         self.banks_client
             .process_transaction_with_preflight_and_commitment(
                 tx.clone(),
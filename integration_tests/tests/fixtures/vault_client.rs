@@ -1,10 +1,19 @@
 use borsh::BorshDeserialize;
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_ticket::AvsVaultTicket,
+};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    config::Config, slash_proposal::SlashProposal, vault::Vault,
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
     vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
-    vault_delegation_list::VaultDelegationList, vault_operator_ticket::VaultOperatorTicket,
+    vault_delegation_list::VaultDelegationList, vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket, vault_referrer::VaultReferrer,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::{
+    add_delegation, initialize_config, initialize_vault, protocol_parameters::ProtocolParameters,
+    remove_delegation, ConfigParameter, VaultAdminRole,
 };
-use jito_vault_sdk::{add_delegation, initialize_config, initialize_vault};
 use solana_program::pubkey::Pubkey;
 use solana_program_test::{BanksClient, BanksClientError};
 use solana_sdk::{
@@ -12,121 +21,246 @@ use solana_sdk::{
     signature::{Keypair, Signer},
     transaction::Transaction,
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::fixtures::{accounts::account_getters, compute_units::ComputeUnitLog};
 
 pub struct VaultProgramClient {
     banks_client: BanksClient,
+    compute_unit_log: Option<ComputeUnitLog>,
 }
 
 impl VaultProgramClient {
     pub const fn new(banks_client: BanksClient) -> Self {
-        Self { banks_client }
+        Self {
+            banks_client,
+            compute_unit_log: None,
+        }
     }
 
-    pub async fn get_config(&mut self, account: &Pubkey) -> Result<Config, BanksClientError> {
-        let account = self.banks_client.get_account(*account).await?.unwrap();
-        Ok(Config::deserialize(&mut account.data.as_slice())?)
+    /// Records the compute units of every transaction processed from now on in the log
+    pub fn with_compute_unit_log(mut self, compute_unit_log: Option<ComputeUnitLog>) -> Self {
+        self.compute_unit_log = compute_unit_log;
+        self
     }
 
-    pub async fn get_vault(&mut self, account: &Pubkey) -> Result<Vault, BanksClientError> {
-        let account = self.banks_client.get_account(*account).await?.unwrap();
-        Ok(Vault::deserialize(&mut account.data.as_slice())?)
+    account_getters! {
+        jito_vault_program::id();
+        get_config(address) -> Config;
+        get_vault(address) -> Vault;
+        get_vault_delegation_list(address) -> VaultDelegationList;
+        get_vault_avs_ticket(vault: &Pubkey, avs: &Pubkey) -> VaultAvsTicket;
+        get_vault_operator_ticket(vault: &Pubkey, operator: &Pubkey) -> VaultOperatorTicket;
+        get_vault_avs_slasher_ticket(
+            vault: &Pubkey,
+            avs: &Pubkey,
+            slasher: &Pubkey
+        ) -> VaultAvsSlasherTicket;
+        get_vault_avs_slasher_operator_ticket(
+            vault: &Pubkey,
+            avs: &Pubkey,
+            slasher: &Pubkey,
+            operator: &Pubkey,
+            epoch: u64
+        ) -> VaultAvsSlasherOperatorTicket;
+        get_vault_staker_withdrawal_ticket(
+            vault: &Pubkey,
+            staker: &Pubkey,
+            base: &Pubkey
+        ) -> VaultStakerWithdrawalTicket;
+        get_vault_operator_blacklist(vault: &Pubkey) -> VaultOperatorBlacklist;
+        get_vault_referrer(vault: &Pubkey, referrer: &Pubkey) -> VaultReferrer;
+        get_slash_proposal(address) -> SlashProposal;
     }
 
-    pub async fn get_vault_avs_ticket(
+    /// The token program owning the mint, SPL Token or Token-2022
+    pub async fn get_token_program(&mut self, mint: &Pubkey) -> Result<Pubkey, BanksClientError> {
+        Ok(self
+            .banks_client
+            .get_account(*mint)
+            .await?
+            .expect("mint exists")
+            .owner)
+    }
+
+    pub async fn initialize_config(
         &mut self,
-        vault: &Pubkey,
-        avs: &Pubkey,
-    ) -> Result<VaultAvsTicket, BanksClientError> {
-        let account = VaultAvsTicket::find_program_address(&jito_vault_program::id(), vault, avs).0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(VaultAvsTicket::deserialize(&mut account.data.as_slice())?)
+        config: &Pubkey,
+        config_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[initialize_config(
+                &jito_vault_program::id(),
+                config,
+                &config_admin.pubkey(),
+                &jito_restaking_program::id(),
+                &config_admin.pubkey(),
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn get_vault_operator_ticket(
+    pub async fn config_set_admin(
         &mut self,
-        vault: &Pubkey,
-        operator: &Pubkey,
-    ) -> Result<VaultOperatorTicket, BanksClientError> {
-        let account =
-            VaultOperatorTicket::find_program_address(&jito_vault_program::id(), vault, operator).0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(VaultOperatorTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+        config: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::config_set_admin(
+                &jito_vault_program::id(),
+                config,
+                &old_admin.pubkey(),
+                new_admin,
+            )],
+            Some(&old_admin.pubkey()),
+            &[old_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn get_vault_delegation_list(
+    pub async fn config_accept_admin(
         &mut self,
-        account: &Pubkey,
-    ) -> Result<VaultDelegationList, BanksClientError> {
-        let account = self.banks_client.get_account(*account).await?.unwrap();
-        Ok(VaultDelegationList::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+        config: &Pubkey,
+        new_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::config_accept_admin(
+                &jito_vault_program::id(),
+                config,
+                &new_admin.pubkey(),
+            )],
+            Some(&new_admin.pubkey()),
+            &[new_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn get_vault_avs_slasher_ticket(
+    pub async fn config_set_epoch_length(
         &mut self,
-        vault: &Pubkey,
-        avs: &Pubkey,
-        slasher: &Pubkey,
-    ) -> Result<VaultAvsSlasherTicket, BanksClientError> {
-        let account = VaultAvsSlasherTicket::find_program_address(
-            &jito_vault_program::id(),
-            vault,
-            avs,
-            slasher,
-        )
-        .0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(VaultAvsSlasherTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+        config: &Pubkey,
+        admin: &Keypair,
+        epoch_length: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::config_set_epoch_length(
+                &jito_vault_program::id(),
+                config,
+                &admin.pubkey(),
+                epoch_length,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_set_parameter(
+        &mut self,
+        config: &Pubkey,
+        admin: &Keypair,
+        parameter: ConfigParameter,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::config_set_parameter(
+                &jito_vault_program::id(),
+                config,
+                &admin.pubkey(),
+                parameter,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_set_paused(
+        &mut self,
+        config: &Pubkey,
+        admin: &Keypair,
+        paused: bool,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::config_set_paused(
+                &jito_vault_program::id(),
+                config,
+                &admin.pubkey(),
+                paused,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn get_vault_avs_slasher_operator_ticket(
+    pub async fn config_set_vault_paused(
         &mut self,
+        config: &Pubkey,
         vault: &Pubkey,
-        avs: &Pubkey,
-        slasher: &Pubkey,
-        operator: &Pubkey,
-        epoch: u64,
-    ) -> Result<VaultAvsSlasherOperatorTicket, BanksClientError> {
-        let account = VaultAvsSlasherOperatorTicket::find_program_address(
-            &jito_vault_program::id(),
-            vault,
-            avs,
-            slasher,
-            operator,
-            epoch,
-        )
-        .0;
-        let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(VaultAvsSlasherOperatorTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?)
+        admin: &Keypair,
+        paused: bool,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::config_set_vault_paused(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+                paused,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn initialize_config(
+    pub async fn config_set_program_fee(
         &mut self,
         config: &Pubkey,
-        config_admin: &Keypair,
+        admin: &Keypair,
+        program_fee_wallet: &Pubkey,
+        fee_bps: u16,
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
+
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[initialize_config(
+            &[jito_vault_sdk::config_set_program_fee(
                 &jito_vault_program::id(),
-                &config,
-                &config_admin.pubkey(),
-                &jito_restaking_program::id(),
+                config,
+                &admin.pubkey(),
+                program_fee_wallet,
+                fee_bps,
             )],
-            Some(&config_admin.pubkey()),
-            &[config_admin],
+            Some(&admin.pubkey()),
+            &[admin],
             blockhash,
         ))
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn initialize_vault(
         &mut self,
         config: &Pubkey,
@@ -139,18 +273,21 @@ impl VaultProgramClient {
         deposit_fee_bps: u16,
         withdrawal_fee_bps: u16,
     ) -> Result<(), BanksClientError> {
+        let token_program = self.get_token_program(&token_mint.pubkey()).await?;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[initialize_vault(
                 &jito_vault_program::id(),
-                &config,
-                &vault,
-                &vault_delegation_list,
+                config,
+                vault,
+                vault_delegation_list,
                 &lrt_mint.pubkey(),
                 &token_mint.pubkey(),
                 &vault_admin.pubkey(),
                 &vault_base.pubkey(),
+                &vault_admin.pubkey(),
+                &token_program,
                 deposit_fee_bps,
                 withdrawal_fee_bps,
             )],
@@ -161,6 +298,7 @@ impl VaultProgramClient {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn add_avs(
         &mut self,
         config: &Pubkey,
@@ -215,6 +353,7 @@ impl VaultProgramClient {
     //     .await
     // }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn add_operator(
         &mut self,
         config: &Pubkey,
@@ -269,12 +408,14 @@ impl VaultProgramClient {
     //     .await
     // }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn add_delegation(
         &mut self,
         config: &Pubkey,
         vault: &Pubkey,
         operator: &Pubkey,
         vault_operator_ticket: &Pubkey,
+        operator_vault_ticket: &Pubkey,
         vault_delegation_list: &Pubkey,
         admin: &Keypair,
         payer: &Keypair,
@@ -288,9 +429,11 @@ impl VaultProgramClient {
                 vault,
                 operator,
                 vault_operator_ticket,
+                operator_vault_ticket,
                 vault_delegation_list,
                 &admin.pubkey(),
                 &payer.pubkey(),
+                &VaultOperatorBlacklist::find_program_address(&jito_vault_program::id(), vault).0,
                 amount,
             )],
             Some(&payer.pubkey()),
@@ -300,33 +443,50 @@ impl VaultProgramClient {
         .await
     }
 
-    // pub async fn remove_delegation(
-    //     &mut self,
-    //     config: &Pubkey,
-    //     vault: &Pubkey,
-    //     operator: &Pubkey,
-    //     vault_delegation_list: &Pubkey,
-    //     admin: &Keypair,
-    //     amount: u64,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[remove_delegation(
-    //             &jito_vault_program::id(),
-    //             config,
-    //             vault,
-    //             operator,
-    //             vault_delegation_list,
-    //             &admin.pubkey(),
-    //             amount,
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
+    pub async fn remove_delegation(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        vault_delegation_list: &Pubkey,
+        admin: &Keypair,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[remove_delegation(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                operator,
+                vault_delegation_list,
+                &admin.pubkey(),
+                amount,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// The LRT account of the program fee wallet set in the config
+    pub async fn program_fee_token_account(
+        &mut self,
+        lrt_mint: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Result<Pubkey, BanksClientError> {
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+        Ok(get_associated_token_address_with_program_id(
+            &config.program_fee_wallet(),
+            lrt_mint,
+            token_program,
+        ))
+    }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn mint_to(
         &mut self,
         vault: &Pubkey,
@@ -339,6 +499,40 @@ impl VaultProgramClient {
         mint_signer: Option<&Keypair>,
         amount: u64,
     ) -> Result<(), BanksClientError> {
+        self.mint_to_with_referrer(
+            vault,
+            lrt_mint,
+            depositor,
+            depositor_token_account,
+            vault_token_account,
+            depositor_lrt_token_account,
+            vault_fee_token_account,
+            mint_signer,
+            None,
+            amount,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn mint_to_with_referrer(
+        &mut self,
+        vault: &Pubkey,
+        lrt_mint: &Pubkey,
+        depositor: &Keypair,
+        depositor_token_account: &Pubkey,
+        vault_token_account: &Pubkey,
+        depositor_lrt_token_account: &Pubkey,
+        vault_fee_token_account: &Pubkey,
+        mint_signer: Option<&Keypair>,
+        referrer: Option<&Pubkey>,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let supported_mint = self.get_vault(vault).await?.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
+        let program_fee_token_account = self
+            .program_fee_token_account(lrt_mint, &token_program)
+            .await?;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         let mut signers = vec![depositor];
         if let Some(signer) = mint_signer {
@@ -347,6 +541,7 @@ impl VaultProgramClient {
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[jito_vault_sdk::mint_to(
                 &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
                 vault,
                 lrt_mint,
                 &depositor.pubkey(),
@@ -354,7 +549,20 @@ impl VaultProgramClient {
                 vault_token_account,
                 depositor_lrt_token_account,
                 vault_fee_token_account,
+                &program_fee_token_account,
+                &supported_mint,
+                &token_program,
                 mint_signer.map(|s| s.pubkey()).as_ref(),
+                referrer
+                    .map(|referrer| {
+                        VaultReferrer::find_program_address(
+                            &jito_vault_program::id(),
+                            vault,
+                            referrer,
+                        )
+                        .0
+                    })
+                    .as_ref(),
                 amount,
             )],
             Some(&depositor.pubkey()),
@@ -385,79 +593,563 @@ impl VaultProgramClient {
     //     .await
     // }
 
-    // pub async fn set_admin(
-    //     &mut self,
-    //     vault: &Pubkey,
-    //     old_admin: &Keypair,
-    //     new_admin: &Pubkey,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[jito_vault_sdk::set_admin(
-    //             &jito_vault_program::id(),
-    //             vault,
-    //             &old_admin.pubkey(),
-    //             new_admin,
-    //         )],
-    //         Some(&old_admin.pubkey()),
-    //         &[old_admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-
-    // pub async fn set_secondary_admin(
-    //     &mut self,
-    //     vault: &Pubkey,
-    //     admin: &Keypair,
-    //     new_admin: &Pubkey,
-    //     role: VaultAdminRole,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[jito_vault_sdk::set_secondary_admin(
-    //             &jito_vault_program::id(),
-    //             vault,
-    //             &admin.pubkey(),
-    //             new_admin,
-    //             role,
-    //         )],
-    //         Some(&admin.pubkey()),
-    //         &[admin],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-
-    // pub async fn update_delegations(
-    //     &mut self,
-    //     config: &Pubkey,
-    //     vault: &Pubkey,
-    //     vault_delegation_list: &Pubkey,
-    //     payer: &Keypair,
-    // ) -> Result<(), BanksClientError> {
-    //     let blockhash = self.banks_client.get_latest_blockhash().await?;
-    //     self.process_transaction(&Transaction::new_signed_with_payer(
-    //         &[jito_vault_sdk::update_delegations(
-    //             &jito_vault_program::id(),
-    //             config,
-    //             vault,
-    //             vault_delegation_list,
-    //             &payer.pubkey(),
-    //         )],
-    //         Some(&payer.pubkey()),
-    //         &[payer],
-    //         blockhash,
-    //     ))
-    //     .await
-    // }
-
-    pub async fn add_slasher(
+    pub async fn set_admin(
         &mut self,
-        config: &Pubkey,
         vault: &Pubkey,
-        avs: &Pubkey,
-        slasher: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_admin(
+                &jito_vault_program::id(),
+                vault,
+                &old_admin.pubkey(),
+                new_admin,
+            )],
+            Some(&old_admin.pubkey()),
+            &[old_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn accept_admin(
+        &mut self,
+        vault: &Pubkey,
+        new_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::accept_admin(
+                &jito_vault_program::id(),
+                vault,
+                &new_admin.pubkey(),
+            )],
+            Some(&new_admin.pubkey()),
+            &[new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_secondary_admin(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        new_admin: &Pubkey,
+        role: VaultAdminRole,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_secondary_admin(
+                &jito_vault_program::id(),
+                vault,
+                &admin.pubkey(),
+                new_admin,
+                role,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_fees(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        fee_admin: &Keypair,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_fees(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &fee_admin.pubkey(),
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+            )],
+            Some(&fee_admin.pubkey()),
+            &[fee_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn update_delegations(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        vault_delegation_list: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::update_delegations(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                vault_delegation_list,
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_crank_tip(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        lamports: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_crank_tip(
+                &jito_vault_program::id(),
+                vault,
+                &admin.pubkey(),
+                lamports,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn migrate_account(
+        &mut self,
+        account: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::migrate_account(
+                &jito_vault_program::id(),
+                account,
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_mint_burn_authority(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        mint_burn_authority: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_mint_burn_authority(
+                &jito_vault_program::id(),
+                vault,
+                &admin.pubkey(),
+                mint_burn_authority,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn clear_mint_burn_authority(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::clear_mint_burn_authority(
+                &jito_vault_program::id(),
+                vault,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn update_vault_balance(
+        &mut self,
+        payer: &Keypair,
+        vault: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let vault_account = self.get_vault(vault).await?;
+        let lrt_mint = vault_account.lrt_mint();
+        let supported_mint = vault_account.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::update_vault_balance(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &get_associated_token_address_with_program_id(
+                    vault,
+                    &supported_mint,
+                    &token_program,
+                ),
+                &lrt_mint,
+                &get_associated_token_address_with_program_id(
+                    &vault_account.fee_owner(),
+                    &lrt_mint,
+                    &token_program,
+                ),
+                &token_program,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn blacklist_operator(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        delegation_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::blacklist_operator(
+                &jito_vault_program::id(),
+                vault,
+                &VaultOperatorBlacklist::find_program_address(&jito_vault_program::id(), vault).0,
+                operator,
+                &delegation_admin.pubkey(),
+                &delegation_admin.pubkey(),
+            )],
+            Some(&delegation_admin.pubkey()),
+            &[delegation_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn unblacklist_operator(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        delegation_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::unblacklist_operator(
+                &jito_vault_program::id(),
+                vault,
+                &VaultOperatorBlacklist::find_program_address(&jito_vault_program::id(), vault).0,
+                operator,
+                &delegation_admin.pubkey(),
+            )],
+            Some(&delegation_admin.pubkey()),
+            &[delegation_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_referral_fee(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        fee_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_referral_fee(
+                &jito_vault_program::id(),
+                vault,
+                &admin.pubkey(),
+                fee_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initialize_vault_referrer(
+        &mut self,
+        vault: &Pubkey,
+        referrer: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::initialize_vault_referrer(
+                &jito_vault_program::id(),
+                vault,
+                &VaultReferrer::find_program_address(&jito_vault_program::id(), vault, referrer).0,
+                referrer,
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn claim_referral_fees(
+        &mut self,
+        vault: &Pubkey,
+        lrt_mint: &Pubkey,
+        referrer: &Pubkey,
+        signer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let token_program = self.get_token_program(lrt_mint).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::claim_referral_fees(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                lrt_mint,
+                &VaultReferrer::find_program_address(&jito_vault_program::id(), vault, referrer).0,
+                &get_associated_token_address_with_program_id(referrer, lrt_mint, &token_program),
+                &signer.pubkey(),
+                &token_program,
+            )],
+            Some(&signer.pubkey()),
+            &[signer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_emergency_mode(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        admin: &Keypair,
+        enabled: bool,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_emergency_mode(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+                enabled,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_withdrawal_fee(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        fee_admin: &Keypair,
+        fee_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_withdrawal_fee(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &fee_admin.pubkey(),
+                fee_bps,
+            )],
+            Some(&fee_admin.pubkey()),
+            &[fee_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_deposit_fee(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        fee_admin: &Keypair,
+        fee_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_deposit_fee(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &fee_admin.pubkey(),
+                fee_bps,
+            )],
+            Some(&fee_admin.pubkey()),
+            &[fee_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Simulates `GetProtocolParameters` paid for by `payer`, decoding the parameters from the
+    /// return data
+    pub async fn get_protocol_parameters(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<ProtocolParameters, BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let result = self
+            .banks_client
+            .simulate_transaction(Transaction::new_signed_with_payer(
+                &[jito_vault_sdk::get_protocol_parameters(
+                    &jito_vault_program::id(),
+                    config,
+                    vault,
+                )],
+                Some(&payer.pubkey()),
+                &[payer],
+                blockhash,
+            ))
+            .await?;
+        if let Some(Err(e)) = result.result {
+            return Err(BanksClientError::TransactionError(e));
+        }
+        let return_data = result
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .ok_or(BanksClientError::ClientError("no return data"))?;
+        Ok(ProtocolParameters::try_from_slice(&return_data.data)?)
+    }
+
+    pub async fn set_withdrawal_fee_grace_period(
+        &mut self,
+        config: &Pubkey,
+        admin: &Keypair,
+        epochs: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_withdrawal_fee_grace_period(
+                &jito_vault_program::id(),
+                config,
+                &admin.pubkey(),
+                epochs,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_avs_service_fee(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        avs: &Pubkey,
+        vault_avs_ticket: &Pubkey,
+        admin: &Keypair,
+        fee_bps: u16,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_avs_service_fee(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                avs,
+                vault_avs_ticket,
+                &admin.pubkey(),
+                fee_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Pays the AVS service fee out of the vault rewards, passing in the AVS operator ticket of
+    /// every operator the vault delegates to. The AVS token account shall exist.
+    pub async fn pay_avs_service_fee(
+        &mut self,
+        payer: &Keypair,
+        vault: &Pubkey,
+        avs: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let vault_program = jito_vault_program::id();
+        let restaking_program = jito_restaking_program::id();
+        let supported_mint = self.get_vault(vault).await?.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
+        let vault_delegation_list =
+            VaultDelegationList::find_program_address(&vault_program, vault).0;
+        let avs_operator_tickets: Vec<Pubkey> = self
+            .get_vault_delegation_list(&vault_delegation_list)
+            .await?
+            .delegations()
+            .iter()
+            .map(|delegation| {
+                AvsOperatorTicket::find_program_address(
+                    &restaking_program,
+                    avs,
+                    &delegation.operator(),
+                )
+                .0
+            })
+            .collect();
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::pay_avs_service_fee(
+                &vault_program,
+                &Config::find_program_address(&vault_program).0,
+                vault,
+                avs,
+                &AvsVaultTicket::find_program_address(&restaking_program, avs, vault).0,
+                &VaultAvsTicket::find_program_address(&vault_program, vault, avs).0,
+                &vault_delegation_list,
+                &get_associated_token_address_with_program_id(
+                    vault,
+                    &supported_mint,
+                    &token_program,
+                ),
+                &get_associated_token_address_with_program_id(avs, &supported_mint, &token_program),
+                &supported_mint,
+                &token_program,
+                &avs_operator_tickets,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn add_slasher(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        avs: &Pubkey,
+        slasher: &Pubkey,
         avs_slasher_ticket: &Pubkey,
         vault_slasher_ticket: &Pubkey,
         admin: &Keypair,
@@ -465,64 +1157,195 @@ impl VaultProgramClient {
     ) -> Result<(), BanksClientError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::add_slasher(
+            &[jito_vault_sdk::add_slasher(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                avs,
+                slasher,
+                avs_slasher_ticket,
+                vault_slasher_ticket,
+                &admin.pubkey(),
+                &payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+            &[admin, payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn initialize_vault_avs_slasher_operator_ticket(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        avs: &Pubkey,
+        slasher: &Pubkey,
+        operator: &Pubkey,
+        vault_avs_slasher_ticket: &Pubkey,
+        vault_avs_slasher_operator_ticket: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                jito_vault_sdk::initialize_vault_avs_slasher_operator_ticket(
+                    &jito_vault_program::id(),
+                    config,
+                    vault,
+                    avs,
+                    slasher,
+                    operator,
+                    vault_avs_slasher_ticket,
+                    vault_avs_slasher_operator_ticket,
+                    &payer.pubkey(),
+                ),
+            ],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn slash(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Keypair,
+        avs_operator_ticket: &Pubkey,
+        operator_avs_ticket: &Pubkey,
+        avs_vault_ticket: &Pubkey,
+        operator_vault_ticket: &Pubkey,
+        vault_avs_ticket: &Pubkey,
+        vault_operator_ticket: &Pubkey,
+        avs_vault_slasher_ticket: &Pubkey,
+        vault_avs_slasher_ticket: &Pubkey,
+        vault_delegation_list: &Pubkey,
+        vault_avs_slasher_operator_ticket: &Pubkey,
+        vault_token_account: &Pubkey,
+        slasher_token_account: &Pubkey,
+        amount: u64,
+    ) -> Result<Vec<String>, BanksClientError> {
+        let supported_mint = self.get_vault(vault).await?.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction_with_logs(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::slash(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                avs,
+                operator,
+                &slasher.pubkey(),
+                avs_operator_ticket,
+                operator_avs_ticket,
+                avs_vault_ticket,
+                operator_vault_ticket,
+                vault_avs_ticket,
+                vault_operator_ticket,
+                avs_vault_slasher_ticket,
+                vault_avs_slasher_ticket,
+                vault_delegation_list,
+                vault_avs_slasher_operator_ticket,
+                vault_token_account,
+                slasher_token_account,
+                &supported_mint,
+                &token_program,
+                amount,
+            )],
+            Some(&slasher.pubkey()),
+            &[slasher],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn propose_slash(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        avs: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Keypair,
+        avs_vault_slasher_ticket: &Pubkey,
+        vault_avs_slasher_ticket: &Pubkey,
+        slash_proposal: &Pubkey,
+        amount: u64,
+        evidence_hash: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::propose_slash(
                 &jito_vault_program::id(),
                 config,
                 vault,
                 avs,
-                slasher,
-                avs_slasher_ticket,
-                vault_slasher_ticket,
-                &admin.pubkey(),
-                &payer.pubkey(),
+                operator,
+                &slasher.pubkey(),
+                avs_vault_slasher_ticket,
+                vault_avs_slasher_ticket,
+                slash_proposal,
+                &slasher.pubkey(),
+                &VaultOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    operator,
+                )
+                .0,
+                &VaultAvsTicket::find_program_address(&jito_vault_program::id(), vault, avs).0,
+                amount,
+                evidence_hash,
             )],
-            Some(&payer.pubkey()),
-            &[admin, payer],
+            Some(&slasher.pubkey()),
+            &[slasher],
             blockhash,
         ))
         .await
     }
 
-    pub async fn initialize_vault_avs_slasher_operator_ticket(
+    pub async fn veto_slash(
         &mut self,
         config: &Pubkey,
-        vault: &Pubkey,
         avs: &Pubkey,
-        slasher: &Pubkey,
-        operator: &Pubkey,
-        vault_avs_slasher_ticket: &Pubkey,
-        vault_avs_slasher_operator_ticket: &Pubkey,
-        payer: &Keypair,
+        slash_proposal: &Pubkey,
+        slash_veto_admin: &Keypair,
     ) -> Result<(), BanksClientError> {
+        let proposal = self.get_slash_proposal(slash_proposal).await?;
+        let vault_avs_ticket =
+            VaultAvsTicket::find_program_address(&jito_vault_program::id(), &proposal.vault(), avs)
+                .0;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[
-                jito_vault_sdk::initialize_vault_avs_slasher_operator_ticket(
-                    &jito_vault_program::id(),
-                    config,
-                    vault,
-                    avs,
-                    slasher,
-                    operator,
-                    vault_avs_slasher_ticket,
-                    vault_avs_slasher_operator_ticket,
-                    &payer.pubkey(),
-                ),
-            ],
-            Some(&payer.pubkey()),
-            &[payer],
+            &[jito_vault_sdk::veto_slash(
+                &jito_vault_program::id(),
+                config,
+                avs,
+                slash_proposal,
+                &slash_veto_admin.pubkey(),
+                &vault_avs_ticket,
+            )],
+            Some(&slash_veto_admin.pubkey()),
+            &[slash_veto_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn slash(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_slash(
         &mut self,
+        slash_proposal: &Pubkey,
         config: &Pubkey,
         vault: &Pubkey,
         avs: &Pubkey,
         operator: &Pubkey,
-        slasher: &Keypair,
+        slasher: &Pubkey,
         avs_operator_ticket: &Pubkey,
         operator_avs_ticket: &Pubkey,
         avs_vault_ticket: &Pubkey,
@@ -535,17 +1358,20 @@ impl VaultProgramClient {
         vault_avs_slasher_operator_ticket: &Pubkey,
         vault_token_account: &Pubkey,
         slasher_token_account: &Pubkey,
-        amount: u64,
+        payer: &Keypair,
     ) -> Result<(), BanksClientError> {
+        let supported_mint = self.get_vault(vault).await?.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::slash(
+            &[jito_vault_sdk::execute_slash(
                 &jito_vault_program::id(),
+                slash_proposal,
                 config,
                 vault,
                 avs,
                 operator,
-                &slasher.pubkey(),
+                slasher,
                 avs_operator_ticket,
                 operator_avs_ticket,
                 avs_vault_ticket,
@@ -558,16 +1384,333 @@ impl VaultProgramClient {
                 vault_avs_slasher_operator_ticket,
                 vault_token_account,
                 slasher_token_account,
+                &supported_mint,
+                &token_program,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn propose_mint_authority_handoff(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        admin: &Keypair,
+        config_admin: &Keypair,
+        new_authority: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::propose_mint_authority_handoff(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+                &config_admin.pubkey(),
+                new_authority,
+            )],
+            Some(&admin.pubkey()),
+            &[admin, config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn execute_mint_authority_handoff(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        lrt_mint: &Pubkey,
+        admin: &Keypair,
+        config_admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let token_program = self.get_token_program(lrt_mint).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::execute_mint_authority_handoff(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                lrt_mint,
+                &admin.pubkey(),
+                &config_admin.pubkey(),
+                &token_program,
+            )],
+            Some(&admin.pubkey()),
+            &[admin, config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn cancel_mint_authority_handoff(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::cancel_mint_authority_handoff(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_migration_target(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        admin: &Keypair,
+        migration_target: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::set_migration_target(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+                migration_target,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn migrate_deposit(
+        &mut self,
+        source_vault: &Pubkey,
+        source_lrt_mint: &Pubkey,
+        destination_vault: &Pubkey,
+        destination_lrt_mint: &Pubkey,
+        staker: &Keypair,
+        staker_source_lrt_token_account: &Pubkey,
+        staker_destination_lrt_token_account: &Pubkey,
+        source_vault_token_account: &Pubkey,
+        destination_vault_token_account: &Pubkey,
+        burn_signer: Option<&Keypair>,
+        mint_signer: Option<&Keypair>,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let supported_mint = self.get_vault(source_vault).await?.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
+        let source_vault_delegation_list =
+            VaultDelegationList::find_program_address(&jito_vault_program::id(), source_vault).0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![staker];
+        signers.extend(burn_signer);
+        signers.extend(mint_signer);
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::migrate_deposit(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                source_vault,
+                source_lrt_mint,
+                destination_vault,
+                destination_lrt_mint,
+                &staker.pubkey(),
+                staker_source_lrt_token_account,
+                staker_destination_lrt_token_account,
+                source_vault_token_account,
+                destination_vault_token_account,
+                &supported_mint,
+                &token_program,
+                &source_vault_delegation_list,
+                burn_signer.map(|s| s.pubkey()).as_ref(),
+                mint_signer.map(|s| s.pubkey()).as_ref(),
                 amount,
             )],
-            Some(&slasher.pubkey()),
-            &[slasher],
+            Some(&staker.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn zap_deposit(
+        &mut self,
+        vault: &Pubkey,
+        lrt_mint: &Pubkey,
+        depositor: &Keypair,
+        recipient: &Pubkey,
+        vault_fee_token_account: &Pubkey,
+        mint_signer: Option<&Keypair>,
+        amount: u64,
+        min_lrt_out: u64,
+    ) -> Result<(), BanksClientError> {
+        let supported_mint = self.get_vault(vault).await?.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
+        let program_fee_token_account = self
+            .program_fee_token_account(lrt_mint, &token_program)
+            .await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![depositor];
+        if let Some(signer) = mint_signer {
+            signers.push(signer);
+        }
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::zap_deposit(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                lrt_mint,
+                &depositor.pubkey(),
+                &get_associated_token_address_with_program_id(
+                    &depositor.pubkey(),
+                    &supported_mint,
+                    &token_program,
+                ),
+                &get_associated_token_address_with_program_id(
+                    vault,
+                    &supported_mint,
+                    &token_program,
+                ),
+                recipient,
+                &get_associated_token_address_with_program_id(recipient, lrt_mint, &token_program),
+                vault_fee_token_account,
+                &program_fee_token_account,
+                &supported_mint,
+                &token_program,
+                mint_signer.map(|s| s.pubkey()).as_ref(),
+                amount,
+                min_lrt_out,
+            )],
+            Some(&depositor.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn enqueue_withdrawal(
+        &mut self,
+        vault: &Pubkey,
+        staker: &Keypair,
+        base: &Keypair,
+        burn_signer: Option<&Keypair>,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let lrt_mint = self.get_vault(vault).await?.lrt_mint();
+        let token_program = self.get_token_program(&lrt_mint).await?;
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            &staker.pubkey(),
+            &base.pubkey(),
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![staker, base];
+        if let Some(signer) = burn_signer {
+            signers.push(signer);
+        }
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::enqueue_withdrawal(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &vault_staker_withdrawal_ticket,
+                &get_associated_token_address_with_program_id(
+                    &vault_staker_withdrawal_ticket,
+                    &lrt_mint,
+                    &token_program,
+                ),
+                &lrt_mint,
+                &staker.pubkey(),
+                &get_associated_token_address_with_program_id(
+                    &staker.pubkey(),
+                    &lrt_mint,
+                    &token_program,
+                ),
+                &base.pubkey(),
+                &token_program,
+                burn_signer.map(|s| s.pubkey()).as_ref(),
+                amount,
+            )],
+            Some(&staker.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn burn_withdrawal_ticket(
+        &mut self,
+        payer: &Keypair,
+        vault: &Pubkey,
+        staker: &Pubkey,
+        base: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let vault_account = self.get_vault(vault).await?;
+        let lrt_mint = vault_account.lrt_mint();
+        let supported_mint = vault_account.supported_mint();
+        let token_program = self.get_token_program(&supported_mint).await?;
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            staker,
+            base,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::burn_withdrawal_ticket(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &get_associated_token_address_with_program_id(
+                    vault,
+                    &supported_mint,
+                    &token_program,
+                ),
+                &lrt_mint,
+                staker,
+                &get_associated_token_address_with_program_id(
+                    staker,
+                    &supported_mint,
+                    &token_program,
+                ),
+                &vault_staker_withdrawal_ticket,
+                &get_associated_token_address_with_program_id(
+                    &vault_staker_withdrawal_ticket,
+                    &lrt_mint,
+                    &token_program,
+                ),
+                &get_associated_token_address_with_program_id(
+                    &vault_account.fee_owner(),
+                    &lrt_mint,
+                    &token_program,
+                ),
+                &supported_mint,
+                &token_program,
+                &VaultDelegationList::find_program_address(&jito_vault_program::id(), vault).0,
+            )],
+            Some(&payer.pubkey()),
+            &[payer],
             blockhash,
         ))
         .await
     }
 
     pub async fn process_transaction(&mut self, tx: &Transaction) -> Result<(), BanksClientError> {
+        if let Some(compute_unit_log) = &self.compute_unit_log {
+            compute_unit_log.record(&mut self.banks_client, tx).await?;
+        }
         self.banks_client
             .process_transaction_with_preflight_and_commitment(
                 tx.clone(),
@@ -575,4 +1718,23 @@ impl VaultProgramClient {
             )
             .await
     }
+
+    /// Processes the transaction, returning the log messages it emitted
+    pub async fn process_transaction_with_logs(
+        &mut self,
+        tx: &Transaction,
+    ) -> Result<Vec<String>, BanksClientError> {
+        if let Some(compute_unit_log) = &self.compute_unit_log {
+            compute_unit_log.record(&mut self.banks_client, tx).await?;
+        }
+        let result = self
+            .banks_client
+            .process_transaction_with_metadata(tx.clone())
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+        Ok(result
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default())
+    }
 }
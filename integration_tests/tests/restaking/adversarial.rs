@@ -0,0 +1,190 @@
+//! Instructions sent by someone trying to get around the checks of the restaking program: signing
+//! for another admin, passing look-alike accounts or tickets of other relationships, and
+//! dropping the signer or writable flag of an account.
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, operator_vault_ticket::OperatorVaultTicket,
+    result::RestakingCoreError,
+};
+use jito_restaking_sanitization::result::SanitizationError;
+use jito_restaking_sdk::{error::RestakingError, operator_add_vault, top_up_rent};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, RelationshipGraph, TestBuilder};
+
+/// Two independent graphs, so each has accounts the other's admins have no say over
+async fn setup() -> (TestBuilder, RelationshipGraph, RelationshipGraph) {
+    let mut fixture = TestBuilder::new().await;
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    let other = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    (fixture, graph, other)
+}
+
+/// The operator of `graph` opting in to the vault of `other`, which hasn't happened yet
+fn operator_add_other_vault(
+    graph: &RelationshipGraph,
+    other: &RelationshipGraph,
+    admin: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    operator_add_vault(
+        &jito_restaking_program::id(),
+        &graph.restaking_config,
+        &graph.operator,
+        &other.vault,
+        &OperatorVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &graph.operator,
+            &other.vault,
+        )
+        .0,
+        admin,
+        payer,
+    )
+}
+
+fn set_account_meta(instruction: &mut Instruction, pubkey: &Pubkey, signer: bool, writable: bool) {
+    let meta = instruction
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == *pubkey)
+        .unwrap();
+    meta.is_signer = signer;
+    meta.is_writable = writable;
+}
+
+#[tokio::test]
+async fn test_operator_add_vault_other_admin_fails() {
+    let (mut fixture, graph, other) = setup().await;
+
+    // the admin of another operator signs
+    let instruction = operator_add_other_vault(
+        &graph,
+        &other,
+        &other.operator_admin.pubkey(),
+        &other.operator_admin.pubkey(),
+    );
+    let result = fixture
+        .process_instruction(&instruction, &[&other.operator_admin])
+        .await;
+
+    assert_program_error(result, RestakingCoreError::OperatorInvalidVaultAdmin);
+}
+
+#[tokio::test]
+async fn test_operator_add_vault_admin_not_signer_fails() {
+    let (mut fixture, graph, other) = setup().await;
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+
+    let mut instruction = operator_add_other_vault(
+        &graph,
+        &other,
+        &graph.operator_admin.pubkey(),
+        &payer.pubkey(),
+    );
+    set_account_meta(
+        &mut instruction,
+        &graph.operator_admin.pubkey(),
+        false,
+        false,
+    );
+    let result = fixture.process_instruction(&instruction, &[&payer]).await;
+
+    assert_program_error(result, SanitizationError::SignerNotSigner);
+}
+
+#[tokio::test]
+async fn test_operator_add_vault_fake_config_fails() {
+    let (mut fixture, graph, other) = setup().await;
+
+    // a copy of the config, owned by the program but not at the config PDA
+    let fake_config = Pubkey::new_unique();
+    fixture
+        .copy_account(&graph.restaking_config, &fake_config)
+        .await
+        .unwrap();
+    let mut instruction = operator_add_other_vault(
+        &graph,
+        &other,
+        &graph.operator_admin.pubkey(),
+        &graph.operator_admin.pubkey(),
+    );
+    instruction.accounts[0].pubkey = fake_config;
+    let result = fixture
+        .process_instruction(&instruction, &[&graph.operator_admin])
+        .await;
+
+    assert_program_error(result, RestakingCoreError::ConfigInvalidPda);
+}
+
+#[tokio::test]
+async fn test_operator_add_vault_operator_not_writable_fails() {
+    let (mut fixture, graph, other) = setup().await;
+
+    let mut instruction = operator_add_other_vault(
+        &graph,
+        &other,
+        &graph.operator_admin.pubkey(),
+        &graph.operator_admin.pubkey(),
+    );
+    set_account_meta(&mut instruction, &graph.operator, false, false);
+    let result = fixture
+        .process_instruction(&instruction, &[&graph.operator_admin])
+        .await;
+
+    assert_program_error(result, RestakingCoreError::OperatorNotWritable);
+}
+
+#[tokio::test]
+async fn test_avs_add_operator_ticket_for_other_avs_fails() {
+    let (fixture, graph, other) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // the operator of `other` opted in to the AVS of `other`, not to the AVS of `graph`
+    let avs_operator_ticket = AvsOperatorTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &graph.avs,
+        &other.operator,
+    )
+    .0;
+    let result = restaking_program_client
+        .avs_add_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &other.operator,
+            &avs_operator_ticket,
+            &other.operator_avs_ticket,
+            &graph.avs_admin,
+            &graph.avs_admin,
+        )
+        .await;
+
+    assert_program_error(result, RestakingCoreError::OperatorAvsTicketInvalidPda);
+}
+
+#[tokio::test]
+async fn test_top_up_rent_not_writable_fails() {
+    let (mut fixture, graph, _) = setup().await;
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+
+    let mut instruction = top_up_rent(&jito_restaking_program::id(), &graph.avs, &payer.pubkey());
+    set_account_meta(&mut instruction, &graph.avs, false, false);
+    let result = fixture.process_instruction(&instruction, &[&payer]).await;
+
+    assert_program_error(result, RestakingError::AccountExpectedWritable);
+}
+
+#[tokio::test]
+async fn test_top_up_rent_other_program_account_fails() {
+    let (mut fixture, graph, _) = setup().await;
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+
+    // the vault is owned by the vault program
+    let instruction = top_up_rent(&jito_restaking_program::id(), &graph.vault, &payer.pubkey());
+    let result = fixture.process_instruction(&instruction, &[&payer]).await;
+
+    assert_program_error(result, RestakingError::AccountInvalidProgramOwner);
+}
@@ -0,0 +1,239 @@
+use jito_restaking_core::{
+    avs::Avs, avs_vault_ticket::AvsVaultTicket, config::Config, operator::Operator,
+    operator_avs_ticket::OperatorAvsTicket, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sanitization::result::SanitizationError;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_assert_avs_healthy() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    // AVS adds two vaults
+    let mut vaults = vec![];
+    let mut avs_vault_tickets = vec![];
+    for _ in 0..2 {
+        let vault_pubkey = fixture.create_vault().await.unwrap();
+        let avs_vault_ticket = AvsVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &avs_pubkey,
+            &vault_pubkey,
+        )
+        .0;
+        restaking_program_client
+            .avs_add_vault(
+                &config,
+                &avs_pubkey,
+                &vault_pubkey,
+                &avs_vault_ticket,
+                &avs_admin,
+                &avs_admin,
+            )
+            .await
+            .unwrap();
+        vaults.push(vault_pubkey);
+        avs_vault_tickets.push(avs_vault_ticket);
+    }
+
+    restaking_program_client
+        .assert_avs_healthy(&config, &avs_pubkey, &avs_vault_tickets, &avs_admin)
+        .await
+        .unwrap();
+
+    // a missing ticket
+    assert!(restaking_program_client
+        .assert_avs_healthy(&config, &avs_pubkey, &avs_vault_tickets[..1], &avs_admin)
+        .await
+        .is_err());
+
+    // a ticket passed in twice in place of another
+    let result = restaking_program_client
+        .assert_avs_healthy(
+            &config,
+            &avs_pubkey,
+            &[avs_vault_tickets[0], avs_vault_tickets[0]],
+            &avs_admin,
+        )
+        .await;
+    assert_program_error(result, SanitizationError::RemainingAccountDuplicate);
+
+    // an account that isn't an AVS ticket
+    let result = restaking_program_client
+        .assert_avs_healthy(
+            &config,
+            &avs_pubkey,
+            &[avs_vault_tickets[0], avs_pubkey],
+            &avs_admin,
+        )
+        .await;
+    assert_program_error(
+        result,
+        SanitizationError::RemainingAccountInvalidDiscriminator,
+    );
+
+    // a closed ticket is no longer expected
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .avs_remove_vault(
+            &config,
+            &avs_pubkey,
+            &vaults[0],
+            &avs_vault_tickets[0],
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    restaking_program_client
+        .close_ticket(
+            &config,
+            &avs_pubkey,
+            &avs_vault_tickets[0],
+            &avs_admin.pubkey(),
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .assert_avs_healthy(&config, &avs_pubkey, &avs_vault_tickets[1..], &avs_admin)
+        .await
+        .unwrap();
+    assert!(restaking_program_client
+        .assert_avs_healthy(&config, &avs_pubkey, &[], &avs_admin)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_assert_operator_healthy() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Operator adds an AVS and a vault
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .assert_operator_healthy(
+            &config,
+            &operator_pubkey,
+            &[operator_avs_ticket, operator_vault_ticket],
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    assert!(restaking_program_client
+        .assert_operator_healthy(
+            &config,
+            &operator_pubkey,
+            &[operator_avs_ticket],
+            &operator_admin,
+        )
+        .await
+        .is_err());
+
+    // tickets of other entities aren't accepted
+    assert!(restaking_program_client
+        .assert_operator_healthy(
+            &config,
+            &operator_pubkey,
+            &[operator_avs_ticket, operator_vault_ticket, avs_pubkey],
+            &operator_admin,
+        )
+        .await
+        .is_err());
+}
@@ -1,10 +1,10 @@
 use jito_restaking_core::{
     avs::Avs, avs_operator_ticket::AvsOperatorTicket, config::Config, operator::Operator,
-    operator_avs_ticket::OperatorAvsTicket,
+    operator_avs_ticket::OperatorAvsTicket, result::RestakingCoreError,
 };
 use solana_sdk::signature::{Keypair, Signer};
 
-use crate::fixtures::fixture::TestBuilder;
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
 
 #[tokio::test]
 async fn test_avs_add_operator_ok() {
@@ -12,7 +12,7 @@ async fn test_avs_add_operator_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -76,6 +76,22 @@ async fn test_avs_add_operator_ok() {
         &operator_pubkey,
     )
     .0;
+    // the operator's ticket warms up until the next epoch
+    let result = restaking_program_client
+        .avs_add_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &avs_admin,
+            &payer,
+        )
+        .await;
+    assert_program_error(result, RestakingCoreError::OperatorAvsTicketWarmingUp);
+
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let slot = fixture.get_slot().await.unwrap();
     restaking_program_client
         .avs_add_operator(
             &config,
@@ -101,7 +117,10 @@ async fn test_avs_add_operator_ok() {
     assert_eq!(ticket.avs(), avs_pubkey);
     assert_eq!(ticket.operator(), operator_pubkey);
     assert_eq!(ticket.index(), 0);
-    assert_eq!(ticket.state().slot_added(), 1);
+    assert_eq!(ticket.state().slot_added(), slot);
+    fixture
+        .assert_rent_exempt(&[avs_pubkey, avs_operator_ticket, operator_avs_ticket])
+        .await;
 }
 
 #[tokio::test]
@@ -110,7 +129,7 @@ async fn test_avs_add_operator_without_operator_opt_in_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -183,7 +202,7 @@ async fn test_avs_add_operator_non_admin_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -272,7 +291,7 @@ async fn test_avs_add_operator_duplicate_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -336,6 +355,7 @@ async fn test_avs_add_operator_duplicate_fails() {
         &operator_pubkey,
     )
     .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
     restaking_program_client
         .avs_add_operator(
             &config,
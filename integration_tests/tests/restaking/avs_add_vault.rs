@@ -1,5 +1,5 @@
 use jito_restaking_core::{avs::Avs, avs_vault_ticket::AvsVaultTicket, config::Config};
-use jito_vault_core::vault::Vault;
+use jito_vault_core::config::Config as VaultConfig;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
 
@@ -11,7 +11,7 @@ async fn test_avs_add_vault_happy_path() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -32,8 +32,7 @@ async fn test_avs_add_vault_happy_path() {
         .await
         .unwrap();
 
-    let vault_pubkey =
-        Vault::find_program_address(&jito_restaking_program::id(), &Pubkey::new_unique()).0;
+    let vault_pubkey = fixture.create_vault().await.unwrap();
 
     // AVS adds vault
     let avs_vault_ticket = AvsVaultTicket::find_program_address(
@@ -67,4 +66,57 @@ async fn test_avs_add_vault_happy_path() {
     assert_eq!(ticket.vault(), vault_pubkey);
     assert_eq!(ticket.index(), 0);
     assert_eq!(ticket.state().slot_added(), 1);
+    fixture
+        .assert_rent_exempt(&[avs_pubkey, avs_vault_ticket])
+        .await;
+}
+
+#[tokio::test]
+async fn test_avs_add_vault_not_vault_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+    fixture.create_vault().await.unwrap();
+    let vault_config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+
+    // an uninitialized key, an account of another program and a vault program account that
+    // isn't a vault
+    for not_vault in [Pubkey::new_unique(), avs_pubkey, vault_config] {
+        let avs_vault_ticket = AvsVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &avs_pubkey,
+            &not_vault,
+        )
+        .0;
+        assert!(restaking_program_client
+            .avs_add_vault(
+                &config,
+                &avs_pubkey,
+                &not_vault,
+                &avs_vault_ticket,
+                &avs_admin,
+                &avs_admin,
+            )
+            .await
+            .is_err());
+    }
 }
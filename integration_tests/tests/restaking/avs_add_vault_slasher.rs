@@ -2,7 +2,6 @@ use jito_restaking_core::{
     avs::Avs, avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
     config::Config,
 };
-use jito_vault_core::vault::Vault;
 use solana_sdk::signature::{Keypair, Signer};
 
 use crate::fixtures::fixture::TestBuilder;
@@ -13,7 +12,7 @@ async fn test_avs_add_vault_slasher_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -35,9 +34,7 @@ async fn test_avs_add_vault_slasher_ok() {
         .unwrap();
 
     // Initialize Vault
-    let vault_base = Keypair::new();
-    let vault_pubkey =
-        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_pubkey = fixture.create_vault().await.unwrap();
 
     // AVS adds vault
     let avs_vault_ticket = AvsVaultTicket::find_program_address(
@@ -68,6 +65,9 @@ async fn test_avs_add_vault_slasher_ok() {
     )
     .0;
     let max_slashable_per_epoch = 1000;
+    let metadata_hash = [7; 32];
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let slot = fixture.get_slot().await.unwrap();
     restaking_program_client
         .avs_add_vault_slasher(
             &config,
@@ -79,6 +79,7 @@ async fn test_avs_add_vault_slasher_ok() {
             &avs_admin,
             &avs_admin,
             max_slashable_per_epoch,
+            metadata_hash,
         )
         .await
         .unwrap();
@@ -86,6 +87,14 @@ async fn test_avs_add_vault_slasher_ok() {
     // Verify AVS state
     let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
     assert_eq!(avs.slasher_count(), 1);
+    fixture
+        .assert_rent_exempt(&[avs_pubkey, avs_vault_ticket, avs_vault_slasher_ticket])
+        .await;
+    let avs_vault_ticket = restaking_program_client
+        .get_avs_vault_ticket(&avs_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(avs_vault_ticket.slasher_count(), 1);
 
     // Verify AVS vault slasher ticket
     let ticket = restaking_program_client
@@ -96,6 +105,139 @@ async fn test_avs_add_vault_slasher_ok() {
     assert_eq!(ticket.vault(), vault_pubkey);
     assert_eq!(ticket.slasher(), slasher.pubkey());
     assert_eq!(ticket.max_slashable_per_epoch(), max_slashable_per_epoch);
+    assert_eq!(ticket.metadata_hash(), metadata_hash);
     assert_eq!(ticket.index(), 0);
-    assert_eq!(ticket.state().slot_added(), 1);
+    assert_eq!(ticket.state().slot_added(), slot);
+}
+
+#[tokio::test]
+async fn test_avs_add_vault_slasher_max_slashers_per_vault() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .avs_set_max_slashers_per_vault(&config, &avs_pubkey, &avs_admin, 1)
+        .await
+        .unwrap();
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.max_slashers_per_vault(), 1);
+
+    let [first_slasher, second_slasher] = [(); 2].map(|_| Keypair::new().pubkey());
+    let [first_slasher_ticket, second_slasher_ticket] =
+        [first_slasher, second_slasher].map(|slasher| {
+            AvsVaultSlasherTicket::find_program_address(
+                &jito_restaking_program::id(),
+                &avs_pubkey,
+                &vault_pubkey,
+                &slasher,
+            )
+            .0
+        });
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    restaking_program_client
+        .avs_add_vault_slasher(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &first_slasher,
+            &avs_vault_ticket,
+            &first_slasher_ticket,
+            &avs_admin,
+            &avs_admin,
+            1000,
+            [1; 32],
+        )
+        .await
+        .unwrap();
+
+    // the vault is at the cap
+    assert!(restaking_program_client
+        .avs_add_vault_slasher(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &second_slasher,
+            &avs_vault_ticket,
+            &second_slasher_ticket,
+            &avs_admin,
+            &avs_admin,
+            1000,
+            [2; 32],
+        )
+        .await
+        .is_err());
+
+    // removing a slasher frees its slot
+    restaking_program_client
+        .avs_remove_vault_slasher(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &first_slasher,
+            &avs_vault_ticket,
+            &first_slasher_ticket,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+    let ticket = restaking_program_client
+        .get_avs_vault_ticket(&avs_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.slasher_count(), 0);
+
+    restaking_program_client
+        .avs_add_vault_slasher(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &second_slasher,
+            &avs_vault_ticket,
+            &second_slasher_ticket,
+            &avs_admin,
+            &avs_admin,
+            1000,
+            [2; 32],
+        )
+        .await
+        .unwrap();
 }
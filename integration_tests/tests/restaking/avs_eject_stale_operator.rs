@@ -0,0 +1,303 @@
+use jito_restaking_core::{
+    avs::{Avs, MIN_MAX_HEARTBEAT_AGE},
+    avs_operator_ticket::AvsOperatorTicket,
+    config::Config,
+    operator::Operator,
+    operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_ticket::OperatorAvsTicket,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_avs_eject_stale_operator_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 10.0).await.unwrap();
+
+    // Operator adds AVS
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    // AVS adds operator
+    let avs_operator_ticket = AvsOperatorTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &operator_pubkey,
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    restaking_program_client
+        .avs_add_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &avs_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    // an age this short would eject operators over a few dropped heartbeats
+    assert!(restaking_program_client
+        .avs_set_max_heartbeat_age(&config, &avs_pubkey, &avs_admin, 1)
+        .await
+        .is_err());
+    restaking_program_client
+        .avs_set_max_heartbeat_age(&config, &avs_pubkey, &avs_admin, MIN_MAX_HEARTBEAT_AGE)
+        .await
+        .unwrap();
+
+    let operator_avs_heartbeat = OperatorAvsHeartbeat::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_heartbeat(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_avs_heartbeat,
+            &operator_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    // Heartbeat is fresh, so the operator can't be ejected yet
+    assert!(restaking_program_client
+        .avs_eject_stale_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_heartbeat,
+            &payer,
+        )
+        .await
+        .is_err());
+
+    fixture
+        .warp_slot_incremental(MIN_MAX_HEARTBEAT_AGE + 1)
+        .await
+        .unwrap();
+    let ejected_slot = fixture.get_slot().await.unwrap();
+
+    restaking_program_client
+        .avs_eject_stale_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_heartbeat,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    let ticket = restaking_program_client
+        .get_avs_operator_ticket(&avs_pubkey, &operator_pubkey)
+        .await
+        .unwrap();
+    assert!(ticket.ejected());
+    assert_eq!(ticket.state().slot_removed(), ejected_slot);
+
+    // The operator recovers and is re-activated
+    restaking_program_client
+        .operator_heartbeat(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_avs_heartbeat,
+            &operator_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_reactivate_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_heartbeat,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    let ticket = restaking_program_client
+        .get_avs_operator_ticket(&avs_pubkey, &operator_pubkey)
+        .await
+        .unwrap();
+    assert!(!ticket.ejected());
+    assert!(ticket.state().is_active(ejected_slot));
+}
+
+#[tokio::test]
+async fn test_avs_eject_stale_operator_disabled_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 10.0).await.unwrap();
+
+    // Operator adds AVS
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    // AVS adds operator
+    let avs_operator_ticket = AvsOperatorTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &operator_pubkey,
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    restaking_program_client
+        .avs_add_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &avs_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    let operator_avs_heartbeat = OperatorAvsHeartbeat::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+
+    fixture.warp_to_next_slot().await.unwrap();
+    fixture.warp_to_next_slot().await.unwrap();
+
+    // The operator never sent a heartbeat, but the AVS hasn't enabled ejection
+    assert!(restaking_program_client
+        .avs_eject_stale_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_heartbeat,
+            &payer,
+        )
+        .await
+        .is_err());
+}
@@ -0,0 +1,219 @@
+use jito_restaking_core::{
+    avs::Avs, avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config, result::RestakingCoreError,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    restaking_client::RestakingProgramClient,
+};
+
+/// Initializes the config and an AVS, returning the config, AVS and AVS admin
+async fn setup(
+    fixture: &mut TestBuilder,
+    restaking_program_client: &mut RestakingProgramClient,
+) -> (Pubkey, Pubkey, Keypair) {
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    (config, avs, avs_admin)
+}
+
+#[tokio::test]
+async fn test_avs_multisig_guards_admin_changes() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let (_config, avs, avs_admin) = setup(&mut fixture, &mut restaking_program_client).await;
+
+    let [a, b, c] = [(); 3].map(|_| Keypair::new());
+    let signers = [a.pubkey(), b.pubkey(), c.pubkey()];
+
+    assert_program_error(
+        restaking_program_client
+            .avs_set_multisig(&avs, &avs_admin, signers, 1, &[])
+            .await,
+        RestakingCoreError::AvsInvalidMultisig,
+    );
+    assert_program_error(
+        restaking_program_client
+            .avs_set_multisig(
+                &avs,
+                &avs_admin,
+                [a.pubkey(), a.pubkey(), c.pubkey()],
+                2,
+                &[],
+            )
+            .await,
+        RestakingCoreError::AvsInvalidMultisig,
+    );
+    restaking_program_client
+        .avs_set_multisig(&avs, &avs_admin, signers, 2, &[])
+        .await
+        .unwrap();
+    let avs_account = restaking_program_client.get_avs(&avs).await.unwrap();
+    assert_eq!(avs_account.multisig_signers(), signers);
+    assert_eq!(avs_account.multisig_threshold(), 2);
+
+    // The admin alone, or with a single multisig key, can't change admins anymore
+    let new_admin = Pubkey::new_unique();
+    assert_program_error(
+        restaking_program_client
+            .avs_set_admin(&avs, &avs_admin, &new_admin)
+            .await,
+        RestakingCoreError::AvsMultisigThresholdNotMet,
+    );
+    assert_program_error(
+        restaking_program_client
+            .avs_set_admin_with_multisig(&avs, &avs_admin, &new_admin, &[&a])
+            .await,
+        RestakingCoreError::AvsMultisigThresholdNotMet,
+    );
+    let stranger = Keypair::new();
+    assert_program_error(
+        restaking_program_client
+            .avs_set_admin_with_multisig(&avs, &avs_admin, &new_admin, &[&a, &stranger])
+            .await,
+        RestakingCoreError::AvsMultisigThresholdNotMet,
+    );
+    restaking_program_client
+        .avs_set_admin_with_multisig(&avs, &avs_admin, &new_admin, &[&a, &c])
+        .await
+        .unwrap();
+    assert_eq!(
+        restaking_program_client
+            .get_avs(&avs)
+            .await
+            .unwrap()
+            .pending_admin(),
+        Some(new_admin)
+    );
+
+    // Nor remove the multisig
+    assert_program_error(
+        restaking_program_client
+            .avs_set_multisig(&avs, &avs_admin, [Pubkey::default(); 3], 0, &[])
+            .await,
+        RestakingCoreError::AvsMultisigThresholdNotMet,
+    );
+    restaking_program_client
+        .avs_set_multisig(&avs, &avs_admin, [Pubkey::default(); 3], 0, &[&b, &c])
+        .await
+        .unwrap();
+    assert_eq!(
+        restaking_program_client
+            .get_avs(&avs)
+            .await
+            .unwrap()
+            .multisig_threshold(),
+        0
+    );
+    restaking_program_client
+        .avs_set_admin(&avs, &avs_admin, &new_admin)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_avs_multisig_guards_add_vault_slasher() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let (config, avs, avs_admin) = setup(&mut fixture, &mut restaking_program_client).await;
+
+    let vault = fixture.create_vault().await.unwrap();
+    let avs_vault_ticket =
+        AvsVaultTicket::find_program_address(&jito_restaking_program::id(), &avs, &vault).0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs,
+            &vault,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    let [a, b, c] = [(); 3].map(|_| Keypair::new());
+    restaking_program_client
+        .avs_set_multisig(
+            &avs,
+            &avs_admin,
+            [a.pubkey(), b.pubkey(), c.pubkey()],
+            3,
+            &[],
+        )
+        .await
+        .unwrap();
+
+    let slasher = Pubkey::new_unique();
+    let avs_vault_slasher_ticket = AvsVaultSlasherTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs,
+        &vault,
+        &slasher,
+    )
+    .0;
+    assert_program_error(
+        restaking_program_client
+            .avs_add_vault_slasher_with_multisig(
+                &config,
+                &avs,
+                &vault,
+                &slasher,
+                &avs_vault_ticket,
+                &avs_vault_slasher_ticket,
+                &avs_admin,
+                &avs_admin,
+                1_000,
+                [0; 32],
+                &[&a, &b],
+            )
+            .await,
+        RestakingCoreError::AvsMultisigThresholdNotMet,
+    );
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    restaking_program_client
+        .avs_add_vault_slasher_with_multisig(
+            &config,
+            &avs,
+            &vault,
+            &slasher,
+            &avs_vault_ticket,
+            &avs_vault_slasher_ticket,
+            &avs_admin,
+            &avs_admin,
+            1_000,
+            [0; 32],
+            &[&a, &b, &c],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        restaking_program_client
+            .get_avs(&avs)
+            .await
+            .unwrap()
+            .slasher_count(),
+        1
+    );
+}
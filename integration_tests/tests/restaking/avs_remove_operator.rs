@@ -0,0 +1,235 @@
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, config::Config, operator::Operator,
+    operator_avs_ticket::OperatorAvsTicket,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_avs_remove_operator_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    // Operator adds AVS
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    // AVS adds operator
+    let avs_operator_ticket = AvsOperatorTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &operator_pubkey,
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let slot = fixture.get_slot().await.unwrap();
+    restaking_program_client
+        .avs_add_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    // AVS removes operator
+    restaking_program_client
+        .avs_remove_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    let ticket = restaking_program_client
+        .get_avs_operator_ticket(&avs_pubkey, &operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_added(), slot);
+    assert_eq!(ticket.state().slot_removed(), slot + 1);
+
+    // the operator's side of the relationship is left to the operator
+    let ticket = restaking_program_client
+        .get_operator_avs_ticket(&operator_pubkey, &avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_removed(), 0);
+
+    // removing an inactive ticket fails
+    fixture.warp_to_next_slot().await.unwrap();
+    assert!(restaking_program_client
+        .avs_remove_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &avs_admin,
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_avs_remove_operator_non_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    // Operator adds AVS
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    // AVS adds operator
+    let avs_operator_ticket = AvsOperatorTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &operator_pubkey,
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    restaking_program_client
+        .avs_add_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    // Non-admin attempts to remove operator
+    let non_admin = Keypair::new();
+    fixture.transfer(&non_admin.pubkey(), 10.0).await.unwrap();
+    assert!(restaking_program_client
+        .avs_remove_operator(
+            &config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket,
+            &non_admin,
+        )
+        .await
+        .is_err());
+
+    let ticket = restaking_program_client
+        .get_avs_operator_ticket(&avs_pubkey, &operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_removed(), 0);
+}
@@ -0,0 +1,158 @@
+use jito_restaking_core::{avs::Avs, avs_vault_ticket::AvsVaultTicket, config::Config};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_avs_remove_vault_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+
+    // AVS adds vault
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    // AVS removes vault
+    restaking_program_client
+        .avs_remove_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    let ticket = restaking_program_client
+        .get_avs_vault_ticket(&avs_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_added(), 1);
+    assert_eq!(ticket.state().slot_removed(), 2);
+
+    // removing an inactive ticket fails
+    fixture.warp_to_next_slot().await.unwrap();
+    assert!(restaking_program_client
+        .avs_remove_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_avs_remove_vault_non_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+
+    // AVS adds vault
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    // Non-admin attempts to remove vault
+    let non_admin = Keypair::new();
+    fixture.transfer(&non_admin.pubkey(), 10.0).await.unwrap();
+    assert!(restaking_program_client
+        .avs_remove_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &non_admin,
+        )
+        .await
+        .is_err());
+
+    let ticket = restaking_program_client
+        .get_avs_vault_ticket(&avs_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_removed(), 0);
+}
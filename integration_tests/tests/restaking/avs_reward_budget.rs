@@ -0,0 +1,229 @@
+use jito_restaking_core::{avs::Avs, config::Config, result::RestakingCoreError};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    restaking_client::RestakingProgramClient,
+};
+
+struct RewardBudgetSetup {
+    config: Pubkey,
+    avs: Pubkey,
+    avs_admin: Keypair,
+    mint: Pubkey,
+    receiver: Pubkey,
+}
+
+/// Initializes an AVS holding `funds` of a new mint, with an empty token account for the receiver
+async fn setup(
+    fixture: &mut TestBuilder,
+    restaking_program_client: &mut RestakingProgramClient,
+    funds: u64,
+) -> RewardBudgetSetup {
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let mint = Keypair::new();
+    fixture.create_token_mint(&mint).await.unwrap();
+    fixture.mint_to(&mint.pubkey(), &avs, funds).await.unwrap();
+    let receiver = Pubkey::new_unique();
+    fixture.create_ata(&mint.pubkey(), &receiver).await.unwrap();
+
+    RewardBudgetSetup {
+        config,
+        avs,
+        avs_admin,
+        mint: mint.pubkey(),
+        receiver,
+    }
+}
+
+#[tokio::test]
+async fn test_avs_distribute_rewards_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardBudgetSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        receiver,
+    } = setup(&mut fixture, &mut restaking_program_client, 10_000).await;
+
+    restaking_program_client
+        .avs_initialize_reward_budget(
+            &config, &avs, &mint, &receiver, &avs_admin, 100, 1_000, 1_000,
+        )
+        .await
+        .unwrap();
+
+    // The current epoch is paid out right away
+    restaking_program_client
+        .avs_distribute_rewards(&config, &avs, &mint, &receiver, &avs_admin)
+        .await
+        .unwrap();
+    let receiver_token_account = get_associated_token_address(&receiver, &mint);
+    assert_eq!(
+        fixture
+            .get_token_account(&receiver_token_account)
+            .await
+            .unwrap()
+            .amount,
+        1_000
+    );
+
+    fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .avs_distribute_rewards(&config, &avs, &mint, &receiver, &avs_admin)
+            .await,
+        RestakingCoreError::AvsRewardBudgetAlreadyDistributed,
+    );
+
+    // Two more epochs, each decaying by 10%
+    fixture.warp_slot_incremental(200).await.unwrap();
+    restaking_program_client
+        .avs_distribute_rewards(&config, &avs, &mint, &receiver, &avs_admin)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture
+            .get_token_account(&receiver_token_account)
+            .await
+            .unwrap()
+            .amount,
+        2_710
+    );
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(&avs, &mint))
+            .await
+            .unwrap()
+            .amount,
+        7_290
+    );
+
+    let budget = restaking_program_client
+        .get_avs_reward_budget(&avs, &mint)
+        .await
+        .unwrap();
+    assert_eq!(budget.next_epoch(), budget.start_epoch() + 3);
+    assert_eq!(budget.next_emission(), 729);
+    assert_eq!(budget.total_distributed(), 2_710);
+}
+
+#[tokio::test]
+async fn test_avs_distribute_rewards_insufficient_funds_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardBudgetSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        receiver,
+    } = setup(&mut fixture, &mut restaking_program_client, 500).await;
+
+    restaking_program_client
+        .avs_initialize_reward_budget(&config, &avs, &mint, &receiver, &avs_admin, 100, 1_000, 0)
+        .await
+        .unwrap();
+
+    assert_program_error(
+        restaking_program_client
+            .avs_distribute_rewards(&config, &avs, &mint, &avs_admin.pubkey(), &avs_admin)
+            .await,
+        RestakingError::AvsRewardBudgetInvalidReceiver,
+    );
+    assert_program_error(
+        restaking_program_client
+            .avs_distribute_rewards(&config, &avs, &mint, &receiver, &avs_admin)
+            .await,
+        RestakingError::AvsInsufficientFunds,
+    );
+}
+
+#[tokio::test]
+async fn test_avs_set_reward_budget_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardBudgetSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        receiver,
+    } = setup(&mut fixture, &mut restaking_program_client, 10_000).await;
+
+    restaking_program_client
+        .avs_initialize_reward_budget(&config, &avs, &mint, &receiver, &avs_admin, 100, 1_000, 0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_distribute_rewards(&config, &avs, &mint, &receiver, &avs_admin)
+        .await
+        .unwrap();
+
+    let stranger = Keypair::new();
+    fixture.transfer(&stranger.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .avs_set_reward_budget(&config, &avs, &mint, &stranger, 2_000, 0)
+            .await,
+        RestakingCoreError::AvsInvalidWithdrawAdmin,
+    );
+    assert_program_error(
+        restaking_program_client
+            .avs_set_reward_budget(&config, &avs, &mint, &avs_admin, 2_000, 10_001)
+            .await,
+        RestakingCoreError::AvsRewardBudgetInvalidSchedule,
+    );
+
+    // The current epoch was already paid out, so the new schedule starts at the next one
+    restaking_program_client
+        .avs_set_reward_budget(&config, &avs, &mint, &avs_admin, 2_000, 500)
+        .await
+        .unwrap();
+    let budget = restaking_program_client
+        .get_avs_reward_budget(&avs, &mint)
+        .await
+        .unwrap();
+    assert_eq!(budget.tokens_per_epoch(), 2_000);
+    assert_eq!(budget.decay_bps(), 500);
+    assert_eq!(budget.start_epoch(), budget.next_epoch());
+    assert_eq!(budget.next_emission(), 2_000);
+
+    fixture.warp_slot_incremental(100).await.unwrap();
+    restaking_program_client
+        .avs_distribute_rewards(&config, &avs, &mint, &receiver, &avs_admin)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(&receiver, &mint))
+            .await
+            .unwrap()
+            .amount,
+        3_000
+    );
+}
@@ -0,0 +1,216 @@
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, config::MAX_MAX_VAULTS_PER_OPERATOR,
+    operator_avs_ticket::OperatorAvsTicket, operator_vault_ticket::OperatorVaultTicket,
+    result::RestakingCoreError,
+};
+use jito_restaking_sdk::ConfigParameter;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, RelationshipGraph, TestBuilder};
+
+/// Two graphs sharing the configs, so the operator and AVS of one can join the other
+async fn setup() -> (TestBuilder, RelationshipGraph, RelationshipGraph) {
+    let mut fixture = TestBuilder::new().await;
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    let other = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    (fixture, graph, other)
+}
+
+#[tokio::test]
+async fn test_max_vaults_per_operator() {
+    let (fixture, graph, other) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config_admin = fixture.upgrade_authority();
+
+    restaking_program_client
+        .config_set_parameter(
+            &graph.restaking_config,
+            &config_admin,
+            ConfigParameter::MaxVaultsPerOperator(1),
+        )
+        .await
+        .unwrap();
+    let config = restaking_program_client
+        .get_config(&graph.restaking_config)
+        .await
+        .unwrap();
+    assert_eq!(config.max_vaults_per_operator(), 1);
+
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &graph.operator,
+        &other.vault,
+    )
+    .0;
+    let result = restaking_program_client
+        .operator_add_vault(
+            &graph.restaking_config,
+            &graph.operator,
+            &other.vault,
+            &operator_vault_ticket,
+            &graph.operator_admin,
+            &graph.operator_admin,
+        )
+        .await;
+    assert_program_error(result, RestakingCoreError::OperatorMaxVaultsReached);
+
+    // the limit can't be lifted, only raised
+    for max_vaults_per_operator in [0, MAX_MAX_VAULTS_PER_OPERATOR + 1] {
+        assert_program_error(
+            restaking_program_client
+                .config_set_parameter(
+                    &graph.restaking_config,
+                    &config_admin,
+                    ConfigParameter::MaxVaultsPerOperator(max_vaults_per_operator),
+                )
+                .await,
+            RestakingCoreError::ConfigInvalidParameter,
+        );
+    }
+    restaking_program_client
+        .config_set_parameter(
+            &graph.restaking_config,
+            &config_admin,
+            ConfigParameter::MaxVaultsPerOperator(2),
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .operator_add_vault(
+            &graph.restaking_config,
+            &graph.operator,
+            &other.vault,
+            &operator_vault_ticket,
+            &graph.operator_admin,
+            &graph.operator_admin,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_max_operators_per_avs() {
+    let (mut fixture, graph, other) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config_admin = fixture.upgrade_authority();
+
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &other.operator,
+        &graph.avs,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &graph.restaking_config,
+            &other.operator,
+            &graph.avs,
+            &operator_avs_ticket,
+            &other.operator_admin,
+            &other.operator_admin,
+        )
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .config_set_parameter(
+            &graph.restaking_config,
+            &config_admin,
+            ConfigParameter::MaxOperatorsPerAvs(1),
+        )
+        .await
+        .unwrap();
+
+    let avs_operator_ticket = AvsOperatorTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &graph.avs,
+        &other.operator,
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let result = restaking_program_client
+        .avs_add_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &other.operator,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &graph.avs_admin,
+            &graph.avs_admin,
+        )
+        .await;
+    assert_program_error(result, RestakingCoreError::AvsMaxOperatorsReached);
+
+    restaking_program_client
+        .config_set_parameter(
+            &graph.restaking_config,
+            &config_admin,
+            ConfigParameter::MaxOperatorsPerAvs(2),
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_add_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &other.operator,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &graph.avs_admin,
+            &graph.avs_admin,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_default_max_slashers_per_vault() {
+    let (mut fixture, graph, _) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config_admin = fixture.upgrade_authority();
+
+    assert_program_error(
+        restaking_program_client
+            .config_set_parameter(
+                &graph.restaking_config,
+                &config_admin,
+                ConfigParameter::DefaultMaxSlashersPerVault(0),
+            )
+            .await,
+        RestakingCoreError::ConfigInvalidParameter,
+    );
+    restaking_program_client
+        .config_set_parameter(
+            &graph.restaking_config,
+            &config_admin,
+            ConfigParameter::DefaultMaxSlashersPerVault(2),
+        )
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&graph.restaking_config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.max_slashers_per_vault(), 2);
+}
+
+#[tokio::test]
+async fn test_config_set_parameter_not_admin_fails() {
+    let (fixture, graph, _) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let result = restaking_program_client
+        .config_set_parameter(
+            &graph.restaking_config,
+            &graph.avs_admin,
+            ConfigParameter::MaxOperatorsPerAvs(1),
+        )
+        .await;
+    assert_program_error(result, RestakingCoreError::ConfigInvalidAdmin);
+}
@@ -0,0 +1,161 @@
+use jito_restaking_core::{
+    avs::Avs, avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_config_slasher_programs_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let slasher_program = Pubkey::new_unique();
+    restaking_program_client
+        .config_add_slasher_program(&config, &config_admin, slasher_program)
+        .await
+        .unwrap();
+    assert!(restaking_program_client
+        .config_add_slasher_program(&config, &config_admin, slasher_program)
+        .await
+        .is_err());
+
+    // Only the config admin can approve programs
+    let not_admin = Keypair::new();
+    fixture.transfer(&not_admin.pubkey(), 10.0).await.unwrap();
+    assert!(restaking_program_client
+        .config_add_slasher_program(&config, &not_admin, Pubkey::new_unique())
+        .await
+        .is_err());
+
+    let config_account = restaking_program_client.get_config(&config).await.unwrap();
+    assert_eq!(config_account.slasher_programs(), vec![slasher_program]);
+
+    restaking_program_client
+        .config_remove_slasher_program(&config, &config_admin, slasher_program)
+        .await
+        .unwrap();
+    assert!(restaking_program_client
+        .config_remove_slasher_program(&config, &config_admin, slasher_program)
+        .await
+        .is_err());
+
+    let config_account = restaking_program_client.get_config(&config).await.unwrap();
+    assert!(config_account.slasher_programs().is_empty());
+}
+
+#[tokio::test]
+async fn test_avs_add_vault_slasher_unapproved_program_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // The slasher is a wallet owned by the system program, which isn't approved
+    let slasher_program = Pubkey::new_unique();
+    restaking_program_client
+        .config_add_slasher_program(&config, &config_admin, slasher_program)
+        .await
+        .unwrap();
+
+    let slasher = Keypair::new();
+    let avs_vault_slasher_ticket = AvsVaultSlasherTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+        &slasher.pubkey(),
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    assert!(restaking_program_client
+        .avs_add_vault_slasher(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &slasher.pubkey(),
+            &avs_vault_ticket,
+            &avs_vault_slasher_ticket,
+            &avs_admin,
+            &avs_admin,
+            1_000,
+            [0; 32],
+        )
+        .await
+        .is_err());
+
+    // Without any approved programs, any slasher is accepted
+    restaking_program_client
+        .config_remove_slasher_program(&config, &config_admin, slasher_program)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_add_vault_slasher(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &slasher.pubkey(),
+            &avs_vault_ticket,
+            &avs_vault_slasher_ticket,
+            &avs_admin,
+            &avs_admin,
+            1_000,
+            [0; 32],
+        )
+        .await
+        .unwrap();
+
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.slasher_count(), 1);
+}
@@ -0,0 +1,664 @@
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config as RestakingConfig, epoch_reward_router::EpochRewardRouter, operator::Operator,
+    operator_avs_ticket::OperatorAvsTicket, operator_vault_ticket::OperatorVaultTicket,
+    result::RestakingCoreError,
+};
+use jito_restaking_sdk::error::RestakingError;
+use jito_vault_core::{
+    config::{Config as VaultConfig, DEFAULT_EPOCH_LENGTH},
+    vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+    vault_operator_ticket::VaultOperatorTicket,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+struct EpochRewardsSetup {
+    fixture: TestBuilder,
+    restaking_config: Pubkey,
+    config_admin: Keypair,
+    avs: Pubkey,
+    avs_admin: Keypair,
+    vault: Pubkey,
+    operators: Vec<(Pubkey, Keypair)>,
+    reward_mint: Pubkey,
+    /// The epoch the tickets have warmed up by and the delegations were added in
+    epoch: u64,
+    epoch_reward_router: Pubkey,
+}
+
+/// Sets up an AVS and a vault with 100_000 deposited tokens, joined by operators the vault
+/// delegates `stakes` to, and an AVS admin holding 10_000 reward tokens. The token accounts of the
+/// router of the current epoch, the vault and the operators for the reward mint exist.
+async fn setup(stakes: &[u64]) -> EpochRewardsSetup {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let restaking_config = RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
+    restaking_program_client
+        .initialize_config(&restaking_config, &config_admin)
+        .await
+        .unwrap();
+    let vault_config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+    vault_program_client
+        .initialize_config(&vault_config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&restaking_config, &avs, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault = Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault).0;
+    let lrt_mint = Keypair::new();
+    let token_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &vault_config,
+            &vault,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_add_vault(
+            &restaking_config,
+            &avs,
+            &vault,
+            &AvsVaultTicket::find_program_address(&jito_restaking_program::id(), &avs, &vault).0,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault)
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &vault,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &token_mint.pubkey()),
+            &get_associated_token_address(&vault, &token_mint.pubkey()),
+            &get_associated_token_address(&depositor.pubkey(), &lrt_mint.pubkey()),
+            &get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey()),
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    let mut operators = Vec::new();
+    for _ in stakes {
+        let operator_admin = Keypair::new();
+        let operator_base = Keypair::new();
+        fixture
+            .transfer(&operator_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+        let operator =
+            Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey())
+                .0;
+        restaking_program_client
+            .initialize_operator(
+                &restaking_config,
+                &operator,
+                &operator_admin,
+                &operator_base,
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .operator_add_avs(
+                &restaking_config,
+                &operator,
+                &avs,
+                &OperatorAvsTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &operator,
+                    &avs,
+                )
+                .0,
+                &operator_admin,
+                &operator_admin,
+            )
+            .await
+            .unwrap();
+
+        let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &operator,
+            &vault,
+        )
+        .0;
+        restaking_program_client
+            .operator_add_vault(
+                &restaking_config,
+                &operator,
+                &vault,
+                &operator_vault_ticket,
+                &operator_admin,
+                &operator_admin,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .add_operator(
+                &vault_config,
+                &vault,
+                &operator,
+                &operator_vault_ticket,
+                &VaultOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault,
+                    &operator,
+                )
+                .0,
+                &vault_admin,
+                &vault_admin,
+            )
+            .await
+            .unwrap();
+
+        operators.push((operator, operator_admin));
+    }
+
+    // the AVS adds the operators once their tickets warmed up, and the vault delegates to them in
+    // the epoch the rewards are for, once the AVS's tickets warmed up too
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    for (operator, _) in operators.iter() {
+        restaking_program_client
+            .avs_add_operator(
+                &restaking_config,
+                &avs,
+                operator,
+                &AvsOperatorTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &avs,
+                    operator,
+                )
+                .0,
+                &OperatorAvsTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    operator,
+                    &avs,
+                )
+                .0,
+                &avs_admin,
+                &avs_admin,
+            )
+            .await
+            .unwrap();
+    }
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    for ((operator, _), stake) in operators.iter().zip(stakes) {
+        vault_program_client
+            .add_delegation(
+                &vault_config,
+                &vault,
+                operator,
+                &VaultOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault,
+                    operator,
+                )
+                .0,
+                &OperatorVaultTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    operator,
+                    &vault,
+                )
+                .0,
+                &vault_delegation_list,
+                &vault_admin,
+                &vault_admin,
+                *stake,
+            )
+            .await
+            .unwrap();
+    }
+    let epoch = fixture.get_slot().await.unwrap() / DEFAULT_EPOCH_LENGTH;
+
+    let reward_mint = Keypair::new();
+    fixture.create_token_mint(&reward_mint).await.unwrap();
+    let reward_mint = reward_mint.pubkey();
+    fixture
+        .mint_to(&reward_mint, &avs_admin.pubkey(), 10_000)
+        .await
+        .unwrap();
+    let epoch_reward_router = EpochRewardRouter::find_program_address(
+        &jito_restaking_program::id(),
+        &avs,
+        &reward_mint,
+        epoch,
+    )
+    .0;
+    for owner in [epoch_reward_router, vault]
+        .into_iter()
+        .chain(operators.iter().map(|(operator, _)| *operator))
+    {
+        fixture.create_ata(&reward_mint, &owner).await.unwrap();
+    }
+
+    EpochRewardsSetup {
+        fixture,
+        restaking_config,
+        config_admin,
+        avs,
+        avs_admin,
+        vault,
+        operators,
+        reward_mint,
+        epoch,
+        epoch_reward_router,
+    }
+}
+
+impl EpochRewardsSetup {
+    async fn token_balance(&mut self, owner: Pubkey) -> u64 {
+        self.fixture
+            .get_token_account(&get_associated_token_address(&owner, &self.reward_mint))
+            .await
+            .unwrap()
+            .amount
+    }
+}
+
+#[tokio::test]
+async fn test_epoch_rewards_distributed_pro_rata_ok() {
+    let mut setup = setup(&[3_000, 1_000]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+    let operator_a = setup.operators[0].0;
+    let operator_b = setup.operators[1].0;
+
+    // the first operator keeps 10% of its rewards
+    restaking_program_client
+        .config_set_operator_fee_params(&setup.restaking_config, &setup.config_admin, 1_000, 0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .set_operator_fee(
+            &setup.restaking_config,
+            &operator_a,
+            &setup.operators[0].1,
+            1_000,
+        )
+        .await
+        .unwrap();
+
+    // the first deposit creates the router
+    restaking_program_client
+        .avs_deposit_epoch_rewards(
+            &setup.restaking_config,
+            &setup.avs,
+            &setup.reward_mint,
+            &setup.avs_admin,
+            setup.epoch,
+            1_001,
+        )
+        .await
+        .unwrap();
+    let router = restaking_program_client
+        .get_epoch_reward_router(&setup.avs, &setup.reward_mint, setup.epoch)
+        .await
+        .unwrap();
+    assert_eq!(router.avs(), setup.avs);
+    assert_eq!(router.mint(), setup.reward_mint);
+    assert_eq!(router.epoch(), setup.epoch);
+    assert_eq!(router.epoch_length(), DEFAULT_EPOCH_LENGTH);
+    assert_eq!(router.total_rewards(), 1_001);
+    assert_eq!(setup.token_balance(setup.epoch_reward_router).await, 1_001);
+    setup
+        .fixture
+        .assert_rent_exempt(&[setup.epoch_reward_router])
+        .await;
+
+    for operator in [operator_a, operator_b] {
+        restaking_program_client
+            .snapshot_epoch_reward_stake(
+                &setup.restaking_config,
+                &setup.avs,
+                &operator,
+                &setup.vault,
+                &setup.epoch_reward_router,
+                &setup.avs_admin,
+            )
+            .await
+            .unwrap();
+    }
+    let snapshot = restaking_program_client
+        .get_epoch_reward_snapshot(&setup.epoch_reward_router, &setup.vault, &operator_a)
+        .await
+        .unwrap();
+    assert_eq!(snapshot.stake(), 3_000);
+    assert_eq!(snapshot.operator_fee_bps(), 1_000);
+    assert_eq!(snapshot.operator_score_bps(), None);
+    assert!(!snapshot.distributed());
+    let router = restaking_program_client
+        .get_epoch_reward_router(&setup.avs, &setup.reward_mint, setup.epoch)
+        .await
+        .unwrap();
+    assert_eq!(router.total_stake(), 4_000);
+    assert_eq!(router.snapshot_count(), 2);
+
+    // rewards are paid out once the epoch ended
+    assert_program_error(
+        restaking_program_client
+            .distribute_epoch_rewards(
+                &setup.restaking_config,
+                &setup.avs,
+                &setup.epoch_reward_router,
+                &setup.reward_mint,
+                &operator_a,
+                &setup.vault,
+                &setup.avs_admin,
+            )
+            .await,
+        RestakingCoreError::EpochRewardsNotEnded,
+    );
+    setup
+        .fixture
+        .warp_slot_incremental(DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+
+    for operator in [operator_a, operator_b] {
+        restaking_program_client
+            .distribute_epoch_rewards(
+                &setup.restaking_config,
+                &setup.avs,
+                &setup.epoch_reward_router,
+                &setup.reward_mint,
+                &operator,
+                &setup.vault,
+                &setup.avs_admin,
+            )
+            .await
+            .unwrap();
+    }
+    // 1_001 * 3_000 / 4_000 = 750, of which the operator keeps 75, and 1_001 * 1_000 / 4_000 = 250
+    assert_eq!(setup.token_balance(operator_a).await, 75);
+    assert_eq!(setup.token_balance(operator_b).await, 0);
+    assert_eq!(setup.token_balance(setup.vault).await, 925);
+    assert_eq!(setup.token_balance(setup.epoch_reward_router).await, 1);
+
+    // a snapshot is paid out once
+    setup.fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .distribute_epoch_rewards(
+                &setup.restaking_config,
+                &setup.avs,
+                &setup.epoch_reward_router,
+                &setup.reward_mint,
+                &operator_a,
+                &setup.vault,
+                &setup.avs_admin,
+            )
+            .await,
+        RestakingCoreError::EpochRewardSnapshotAlreadyDistributed,
+    );
+
+    // the rounding dust goes back to the AVS
+    restaking_program_client
+        .avs_reclaim_epoch_rewards(
+            &setup.restaking_config,
+            &setup.avs,
+            &setup.epoch_reward_router,
+            &setup.reward_mint,
+            &setup.avs_admin.pubkey(),
+            &setup.avs_admin,
+        )
+        .await
+        .unwrap();
+    assert_eq!(setup.token_balance(setup.epoch_reward_router).await, 0);
+    assert_eq!(
+        setup.token_balance(setup.avs_admin.pubkey()).await,
+        10_000 - 1_000
+    );
+}
+
+#[tokio::test]
+async fn test_epoch_reward_snapshot_records_operator_score_ok() {
+    let setup = setup(&[3_000, 1_000]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+    let operator_a = setup.operators[0].0;
+    let operator_b = setup.operators[1].0;
+
+    // only the first operator is scored
+    let scorer = Keypair::new();
+    restaking_program_client
+        .avs_set_scorer(&setup.avs, &setup.avs_admin, &scorer.pubkey())
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_attest_operator_score(
+            &setup.restaking_config,
+            &setup.avs,
+            &operator_a,
+            &scorer,
+            &setup.avs_admin,
+            9_500,
+        )
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .avs_deposit_epoch_rewards(
+            &setup.restaking_config,
+            &setup.avs,
+            &setup.reward_mint,
+            &setup.avs_admin,
+            setup.epoch,
+            1_000,
+        )
+        .await
+        .unwrap();
+    for operator in [operator_a, operator_b] {
+        restaking_program_client
+            .snapshot_epoch_reward_stake(
+                &setup.restaking_config,
+                &setup.avs,
+                &operator,
+                &setup.vault,
+                &setup.epoch_reward_router,
+                &setup.avs_admin,
+            )
+            .await
+            .unwrap();
+    }
+
+    let snapshot = restaking_program_client
+        .get_epoch_reward_snapshot(&setup.epoch_reward_router, &setup.vault, &operator_a)
+        .await
+        .unwrap();
+    assert_eq!(snapshot.operator_score_bps(), Some(9_500));
+    let snapshot = restaking_program_client
+        .get_epoch_reward_snapshot(&setup.epoch_reward_router, &setup.vault, &operator_b)
+        .await
+        .unwrap();
+    assert_eq!(snapshot.operator_score_bps(), None);
+}
+
+#[tokio::test]
+async fn test_epoch_rewards_closed_epoch_fails() {
+    let mut setup = setup(&[1_000]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+    let operator = setup.operators[0].0;
+
+    restaking_program_client
+        .avs_deposit_epoch_rewards(
+            &setup.restaking_config,
+            &setup.avs,
+            &setup.reward_mint,
+            &setup.avs_admin,
+            setup.epoch,
+            1_000,
+        )
+        .await
+        .unwrap();
+
+    // rewards for a later epoch can't be snapshotted yet
+    let next_router = EpochRewardRouter::find_program_address(
+        &jito_restaking_program::id(),
+        &setup.avs,
+        &setup.reward_mint,
+        setup.epoch + 1,
+    )
+    .0;
+    setup
+        .fixture
+        .create_ata(&setup.reward_mint, &next_router)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_deposit_epoch_rewards(
+            &setup.restaking_config,
+            &setup.avs,
+            &setup.reward_mint,
+            &setup.avs_admin,
+            setup.epoch + 1,
+            1_000,
+        )
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .snapshot_epoch_reward_stake(
+                &setup.restaking_config,
+                &setup.avs,
+                &operator,
+                &setup.vault,
+                &next_router,
+                &setup.avs_admin,
+            )
+            .await,
+        RestakingCoreError::EpochRewardsNotStarted,
+    );
+
+    restaking_program_client
+        .snapshot_epoch_reward_stake(
+            &setup.restaking_config,
+            &setup.avs,
+            &operator,
+            &setup.vault,
+            &setup.epoch_reward_router,
+            &setup.avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // the rewards can't be reclaimed before every snapshot is paid out, nor by anyone else
+    let bad_admin = Keypair::new();
+    setup
+        .fixture
+        .transfer(&bad_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .avs_reclaim_epoch_rewards(
+                &setup.restaking_config,
+                &setup.avs,
+                &setup.epoch_reward_router,
+                &setup.reward_mint,
+                &bad_admin.pubkey(),
+                &bad_admin,
+            )
+            .await,
+        RestakingCoreError::AvsInvalidWithdrawAdmin,
+    );
+    setup
+        .fixture
+        .warp_slot_incremental(DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .avs_reclaim_epoch_rewards(
+                &setup.restaking_config,
+                &setup.avs,
+                &setup.epoch_reward_router,
+                &setup.reward_mint,
+                &setup.avs_admin.pubkey(),
+                &setup.avs_admin,
+            )
+            .await,
+        RestakingCoreError::EpochRewardsPendingDistribution,
+    );
+
+    // once the epoch ended, no more rewards or stake are added
+    assert_program_error(
+        restaking_program_client
+            .avs_deposit_epoch_rewards(
+                &setup.restaking_config,
+                &setup.avs,
+                &setup.reward_mint,
+                &setup.avs_admin,
+                setup.epoch,
+                1_000,
+            )
+            .await,
+        RestakingCoreError::EpochRewardsClosed,
+    );
+
+    // the delegations need to be updated for the epoch before they're snapshotted
+    assert_program_error(
+        restaking_program_client
+            .snapshot_epoch_reward_stake(
+                &setup.restaking_config,
+                &setup.avs,
+                &operator,
+                &setup.vault,
+                &next_router,
+                &setup.avs_admin,
+            )
+            .await,
+        RestakingError::EpochRewardDelegationOutdated,
+    );
+}
@@ -0,0 +1,490 @@
+use jito_restaking_core::{
+    config::Config as RestakingConfig,
+    operator::Operator,
+    operator_epoch_stake::OperatorEpochStake,
+    operator_stake_history::{OperatorStakeHistory, SNAPSHOT_RETENTION_EPOCHS},
+    operator_vault_ticket::OperatorVaultTicket,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use jito_restaking_sanitization::result::SanitizationError;
+use jito_restaking_sdk::{error::RestakingError, stake_history::ArchivedSnapshotProof};
+use jito_vault_core::{
+    config::{Config as VaultConfig, DEFAULT_EPOCH_LENGTH},
+    vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+    vault_operator_ticket::VaultOperatorTicket,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+struct EpochStakeSetup {
+    fixture: TestBuilder,
+    restaking_config: Pubkey,
+    operator: Pubkey,
+    vaults: Vec<Pubkey>,
+    payer: Keypair,
+    /// The epoch the delegations were made in, once the tickets warmed up
+    epoch: u64,
+}
+
+/// Sets up an operator and a vault per entry of `stakes`, each with 100_000 deposited tokens and
+/// the operator added. Once the tickets warmed up, vaults with a non-zero stake delegate it to the
+/// operator, and the others update their delegations for the epoch.
+async fn setup(stakes: &[u64]) -> EpochStakeSetup {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let restaking_config = RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
+    restaking_program_client
+        .initialize_config(&restaking_config, &config_admin)
+        .await
+        .unwrap();
+    let vault_config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+    vault_program_client
+        .initialize_config(&vault_config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(
+            &restaking_config,
+            &operator,
+            &operator_admin,
+            &operator_base,
+        )
+        .await
+        .unwrap();
+
+    let mut vaults = Vec::new();
+    let mut delegations = Vec::new();
+    for stake in stakes {
+        let vault_base = Keypair::new();
+        let vault = Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+        let vault_delegation_list =
+            VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault).0;
+        let lrt_mint = Keypair::new();
+        let token_mint = Keypair::new();
+        let vault_admin = Keypair::new();
+        fixture.create_token_mint(&token_mint).await.unwrap();
+        fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+        vault_program_client
+            .initialize_vault(
+                &vault_config,
+                &vault,
+                &vault_delegation_list,
+                &lrt_mint,
+                &token_mint,
+                &vault_admin,
+                &vault_base,
+                0,
+                0,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+        fixture
+            .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&token_mint.pubkey(), &vault)
+            .await
+            .unwrap();
+        vault_program_client
+            .mint_to(
+                &vault,
+                &lrt_mint.pubkey(),
+                &depositor,
+                &get_associated_token_address(&depositor.pubkey(), &token_mint.pubkey()),
+                &get_associated_token_address(&vault, &token_mint.pubkey()),
+                &get_associated_token_address(&depositor.pubkey(), &lrt_mint.pubkey()),
+                &get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey()),
+                None,
+                100_000,
+            )
+            .await
+            .unwrap();
+
+        let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &operator,
+            &vault,
+        )
+        .0;
+        restaking_program_client
+            .operator_add_vault(
+                &restaking_config,
+                &operator,
+                &vault,
+                &operator_vault_ticket,
+                &operator_admin,
+                &operator_admin,
+            )
+            .await
+            .unwrap();
+        let vault_operator_ticket =
+            VaultOperatorTicket::find_program_address(&jito_vault_program::id(), &vault, &operator)
+                .0;
+        vault_program_client
+            .add_operator(
+                &vault_config,
+                &vault,
+                &operator,
+                &operator_vault_ticket,
+                &vault_operator_ticket,
+                &vault_admin,
+                &vault_admin,
+            )
+            .await
+            .unwrap();
+
+        vaults.push(vault);
+        delegations.push((
+            vault,
+            vault_operator_ticket,
+            operator_vault_ticket,
+            vault_delegation_list,
+            vault_admin,
+            *stake,
+        ));
+    }
+
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    for (
+        vault,
+        vault_operator_ticket,
+        operator_vault_ticket,
+        vault_delegation_list,
+        vault_admin,
+        stake,
+    ) in delegations
+    {
+        if stake > 0 {
+            vault_program_client
+                .add_delegation(
+                    &vault_config,
+                    &vault,
+                    &operator,
+                    &vault_operator_ticket,
+                    &operator_vault_ticket,
+                    &vault_delegation_list,
+                    &vault_admin,
+                    &vault_admin,
+                    stake,
+                )
+                .await
+                .unwrap();
+        } else {
+            vault_program_client
+                .update_delegations(&vault_config, &vault, &vault_delegation_list, &vault_admin)
+                .await
+                .unwrap();
+        }
+    }
+    let epoch = fixture.get_slot().await.unwrap() / DEFAULT_EPOCH_LENGTH;
+
+    EpochStakeSetup {
+        fixture,
+        restaking_config,
+        operator,
+        vaults,
+        payer: operator_admin,
+        epoch,
+    }
+}
+
+#[tokio::test]
+async fn test_snapshot_epoch_stake_ok() {
+    let mut setup = setup(&[3_000, 1_000]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+
+    for vault in setup.vaults.iter() {
+        restaking_program_client
+            .snapshot_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                vault,
+                setup.epoch,
+                &setup.payer,
+            )
+            .await
+            .unwrap();
+    }
+
+    let vault_operator_epoch_stake = restaking_program_client
+        .get_vault_operator_epoch_stake(&setup.vaults[0], &setup.operator, setup.epoch)
+        .await
+        .unwrap();
+    assert_eq!(vault_operator_epoch_stake.vault(), setup.vaults[0]);
+    assert_eq!(vault_operator_epoch_stake.operator(), setup.operator);
+    assert_eq!(vault_operator_epoch_stake.epoch(), setup.epoch);
+    assert_eq!(vault_operator_epoch_stake.stake(), 3_000);
+
+    // the stake of the operator sums the vaults
+    let operator_epoch_stake = restaking_program_client
+        .get_operator_epoch_stake(&setup.operator, setup.epoch)
+        .await
+        .unwrap();
+    assert_eq!(operator_epoch_stake.operator(), setup.operator);
+    assert_eq!(operator_epoch_stake.epoch(), setup.epoch);
+    assert_eq!(operator_epoch_stake.stake(), 4_000);
+    assert_eq!(operator_epoch_stake.vault_count(), 2);
+    setup
+        .fixture
+        .assert_rent_exempt(&[
+            OperatorEpochStake::find_program_address(
+                &jito_restaking_program::id(),
+                &setup.operator,
+                setup.epoch,
+            )
+            .0,
+            VaultOperatorEpochStake::find_program_address(
+                &jito_restaking_program::id(),
+                &setup.vaults[1],
+                &setup.operator,
+                setup.epoch,
+            )
+            .0,
+        ])
+        .await;
+
+    // a vault is snapshotted once per epoch
+    setup.fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .snapshot_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                &setup.vaults[0],
+                setup.epoch,
+                &setup.payer,
+            )
+            .await,
+        SanitizationError::EmptyAccountNotEmpty,
+    );
+}
+
+#[tokio::test]
+async fn test_snapshot_epoch_stake_no_stake_fails() {
+    let setup = setup(&[0]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+
+    assert_program_error(
+        restaking_program_client
+            .snapshot_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                &setup.vaults[0],
+                setup.epoch,
+                &setup.payer,
+            )
+            .await,
+        RestakingError::EpochStakeNoStake,
+    );
+}
+
+#[tokio::test]
+async fn test_snapshot_epoch_stake_outdated_delegation_fails() {
+    let mut setup = setup(&[1_000]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+
+    // the delegations need to be updated for the epoch before they're snapshotted
+    setup
+        .fixture
+        .warp_slot_incremental(DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .snapshot_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                &setup.vaults[0],
+                setup.epoch + 1,
+                &setup.payer,
+            )
+            .await,
+        RestakingError::EpochStakeDelegationOutdated,
+    );
+}
+
+#[tokio::test]
+async fn test_snapshot_epoch_stake_wrong_epoch_fails() {
+    let setup = setup(&[1_000]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+
+    // the snapshot accounts are derived from the current epoch
+    assert_program_error(
+        restaking_program_client
+            .snapshot_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                &setup.vaults[0],
+                setup.epoch + 1,
+                &setup.payer,
+            )
+            .await,
+        RestakingError::OperatorEpochStakeInvalidPda,
+    );
+}
+
+#[tokio::test]
+async fn test_archive_epoch_stake_ok() {
+    let mut setup = setup(&[3_000]).await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+
+    restaking_program_client
+        .snapshot_epoch_stake(
+            &setup.restaking_config,
+            &setup.operator,
+            &setup.vaults[0],
+            setup.epoch,
+            &setup.payer,
+        )
+        .await
+        .unwrap();
+    let vault_operator_epoch_stake = VaultOperatorEpochStake::find_program_address(
+        &jito_restaking_program::id(),
+        &setup.vaults[0],
+        &setup.operator,
+        setup.epoch,
+    )
+    .0;
+    let operator_epoch_stake = OperatorEpochStake::find_program_address(
+        &jito_restaking_program::id(),
+        &setup.operator,
+        setup.epoch,
+    )
+    .0;
+
+    // the operator admin is the rent collector, so the archival is paid by someone else
+    let cranker = Keypair::new();
+    setup
+        .fixture
+        .transfer(&cranker.pubkey(), 1.0)
+        .await
+        .unwrap();
+
+    // snapshots are kept for the retention window
+    setup
+        .fixture
+        .warp_slot_incremental(DEFAULT_EPOCH_LENGTH * (SNAPSHOT_RETENTION_EPOCHS - 1))
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .archive_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                &vault_operator_epoch_stake,
+                &setup.payer.pubkey(),
+                &cranker,
+            )
+            .await,
+        RestakingError::EpochStakeRetained,
+    );
+
+    setup
+        .fixture
+        .warp_slot_incremental(DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .archive_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                &vault_operator_epoch_stake,
+                &cranker.pubkey(),
+                &cranker,
+            )
+            .await,
+        RestakingError::RentCollectorMismatch,
+    );
+
+    let mut snapshots = Vec::new();
+    for epoch_stake in [vault_operator_epoch_stake, operator_epoch_stake] {
+        let data = setup.fixture.get_account_data(&epoch_stake).await.unwrap();
+        let lamports = setup.fixture.get_lamports(&epoch_stake).await.unwrap();
+        let rent_collector_lamports = setup
+            .fixture
+            .get_lamports(&setup.payer.pubkey())
+            .await
+            .unwrap();
+        restaking_program_client
+            .archive_epoch_stake(
+                &setup.restaking_config,
+                &setup.operator,
+                &epoch_stake,
+                &setup.payer.pubkey(),
+                &cranker,
+            )
+            .await
+            .unwrap();
+        setup
+            .fixture
+            .assert_closed_with_refund(
+                &epoch_stake,
+                &setup.payer.pubkey(),
+                rent_collector_lamports,
+                lamports,
+            )
+            .await;
+        snapshots.push(data);
+    }
+
+    let history = restaking_program_client
+        .get_operator_stake_history(&setup.operator)
+        .await
+        .unwrap();
+    assert_eq!(history.operator(), setup.operator);
+    assert_eq!(history.archived_count(), 2);
+    assert_eq!(history.last_archived_epoch(), setup.epoch);
+    setup
+        .fixture
+        .assert_rent_exempt(&[OperatorStakeHistory::find_program_address(
+            &jito_restaking_program::id(),
+            &setup.operator,
+        )
+        .0])
+        .await;
+
+    // the archived values are proven against the commitment of the history
+    let proof = ArchivedSnapshotProof::new(&snapshots, 0).unwrap();
+    let archived = proof
+        .prove::<VaultOperatorEpochStake>(&snapshots[0], &history.commitment())
+        .unwrap();
+    assert_eq!(archived.stake(), 3_000);
+    let proof = ArchivedSnapshotProof::new(&snapshots, 1).unwrap();
+    let archived = proof
+        .prove::<OperatorEpochStake>(&snapshots[1], &history.commitment())
+        .unwrap();
+    assert_eq!(archived.stake(), 3_000);
+}
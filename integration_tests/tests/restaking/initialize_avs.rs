@@ -1,8 +1,10 @@
-use jito_restaking_core::{avs::Avs, config::Config};
+use jito_restaking_core::{avs::Avs, config::Config, result::RestakingCoreError};
+use jito_restaking_sanitization::result::SanitizationError;
+use jito_restaking_sdk::error::RestakingError;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
 
-use crate::fixtures::fixture::TestBuilder;
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
 
 #[tokio::test]
 async fn test_initialize_avs_ok() {
@@ -10,7 +12,7 @@ async fn test_initialize_avs_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config first
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -48,6 +50,7 @@ async fn test_initialize_avs_ok() {
 
     let updated_config = restaking_program_client.get_config(&config).await.unwrap();
     assert_eq!(updated_config.avs_count(), 1);
+    fixture.assert_rent_exempt(&[config, avs_pubkey]).await;
 }
 
 #[tokio::test]
@@ -56,7 +59,7 @@ async fn test_initialize_avs_bad_derivation_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -78,8 +81,7 @@ async fn test_initialize_avs_bad_derivation_fails() {
         .initialize_avs(&config, &incorrect_avs_pubkey, &avs_admin, &avs_base)
         .await;
 
-    // TODO (LB): check for specific error
-    assert!(result.is_err());
+    assert_program_error(result, RestakingError::AvsInvalidPda);
 }
 
 #[tokio::test]
@@ -88,7 +90,7 @@ async fn test_initialize_avs_already_initialized_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -111,12 +113,13 @@ async fn test_initialize_avs_already_initialized_fails() {
         .await
         .unwrap();
 
+    // in a new slot, so the transaction isn't rejected as a duplicate
+    fixture.warp_to_next_slot().await.unwrap();
     let result = restaking_program_client
         .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
         .await;
 
-    // TODO (LB): check for specific error
-    assert!(result.is_err());
+    assert_program_error(result, SanitizationError::EmptyAccountNotEmpty);
 }
 
 #[tokio::test]
@@ -136,8 +139,7 @@ async fn test_initialize_avs_with_uninitialized_config_fails() {
         .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
         .await;
 
-    // TODO (LB): check for specific error
-    assert!(result.is_err());
+    assert_program_error(result, RestakingCoreError::ConfigEmpty);
 }
 
 #[tokio::test]
@@ -146,7 +148,7 @@ async fn test_initialize_multiple_avs_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -10,7 +10,7 @@ async fn test_initialize_config_ok() {
 
     let mut restaking_program_client = fixture.restaking_program_client();
 
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
 
     fixture
@@ -23,6 +23,8 @@ async fn test_initialize_config_ok() {
         .await
         .unwrap();
 
+    fixture.assert_rent_exempt(&[config]).await;
+
     let config = restaking_program_client.get_config(&config).await.unwrap();
     assert_eq!(config.admin(), config_admin.pubkey());
     assert_eq!(config.vault_program(), jito_vault_program::id());
@@ -34,7 +36,7 @@ async fn test_initialize_config_ok() {
 async fn test_initialize_config_double_init_fails() {
     let mut fixture = TestBuilder::new().await;
     let mut restaking_program_client = fixture.restaking_program_client();
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
 
     fixture
@@ -59,7 +61,7 @@ async fn test_initialize_config_double_init_fails() {
 async fn test_initialize_config_bad_pda_fails() {
     let mut fixture = TestBuilder::new().await;
     let mut restaking_program_client = fixture.restaking_program_client();
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
 
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -73,3 +75,28 @@ async fn test_initialize_config_bad_pda_fails() {
     // TODO (LB): check specific error
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_initialize_config_not_upgrade_authority_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+
+    // someone racing the deployer to the config address
+    let attacker = Keypair::new();
+    fixture.transfer(&attacker.pubkey(), 10.0).await.unwrap();
+    assert!(restaking_program_client
+        .initialize_config(&config, &attacker)
+        .await
+        .is_err());
+
+    let config_admin = fixture.upgrade_authority();
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+}
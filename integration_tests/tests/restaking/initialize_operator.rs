@@ -1,8 +1,9 @@
-use jito_restaking_core::{config::Config, operator::Operator};
+use jito_restaking_core::{config::Config, operator::Operator, result::RestakingCoreError};
+use jito_restaking_sdk::error::RestakingError;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
 
-use crate::fixtures::fixture::TestBuilder;
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
 
 #[tokio::test]
 async fn test_initialize_operator_ok() {
@@ -10,7 +11,7 @@ async fn test_initialize_operator_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config first
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -50,6 +51,7 @@ async fn test_initialize_operator_ok() {
 
     let updated_config = restaking_program_client.get_config(&config).await.unwrap();
     assert_eq!(updated_config.operators_count(), 1);
+    fixture.assert_rent_exempt(&[config, operator_pubkey]).await;
 }
 
 #[tokio::test]
@@ -58,7 +60,7 @@ async fn test_initialize_operator_bad_derivation_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -88,8 +90,7 @@ async fn test_initialize_operator_bad_derivation_fails() {
         )
         .await;
 
-    // TODO (LB): check specific error
-    assert!(result.is_err());
+    assert_program_error(result, RestakingError::OperatorInvalidPda);
 }
 
 #[tokio::test]
@@ -98,7 +99,7 @@ async fn test_initialize_operator_already_initialized_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -155,8 +156,7 @@ async fn test_initialize_operator_with_uninitialized_config_fails() {
         .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
         .await;
 
-    // TODO (LB): check specific error
-    assert!(result.is_err());
+    assert_program_error(result, RestakingCoreError::ConfigEmpty);
 }
 
 #[tokio::test]
@@ -165,7 +165,7 @@ async fn test_initialize_multiple_operators_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -0,0 +1,155 @@
+use jito_restaking_core::{
+    avs::Avs, config::Config, metadata::Metadata, operator::Operator, result::RestakingCoreError,
+};
+use jito_restaking_sdk::{AvsAdminRole, MAX_METADATA_NAME_LEN};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_update_avs_metadata_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let metadata_admin = Keypair::new();
+    fixture
+        .transfer(&metadata_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &avs_admin,
+            &metadata_admin.pubkey(),
+            AvsAdminRole::Metadata,
+        )
+        .await
+        .unwrap();
+
+    // only the metadata admin can update the metadata
+    assert_program_error(
+        restaking_program_client
+            .update_avs_metadata(&avs_pubkey, &avs_admin, "AVS", "", "")
+            .await,
+        RestakingCoreError::AvsInvalidMetadataAdmin,
+    );
+
+    // the first update creates the metadata account
+    restaking_program_client
+        .update_avs_metadata(
+            &avs_pubkey,
+            &metadata_admin,
+            "AVS",
+            "https://example.com/avs.json",
+            "https://example.com/avs.png",
+        )
+        .await
+        .unwrap();
+    let metadata = restaking_program_client
+        .get_metadata(&avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(metadata.parent(), avs_pubkey);
+    assert_eq!(metadata.name(), "AVS");
+    assert_eq!(metadata.uri(), "https://example.com/avs.json");
+    assert_eq!(metadata.icon(), "https://example.com/avs.png");
+    let metadata_pubkey =
+        Metadata::find_program_address(&jito_restaking_program::id(), &avs_pubkey).0;
+    fixture.assert_rent_exempt(&[metadata_pubkey]).await;
+
+    // later ones replace every field
+    restaking_program_client
+        .update_avs_metadata(&avs_pubkey, &metadata_admin, "Renamed AVS", "", "")
+        .await
+        .unwrap();
+    let metadata = restaking_program_client
+        .get_metadata(&avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(metadata.name(), "Renamed AVS");
+    assert_eq!(metadata.uri(), "");
+    assert_eq!(metadata.icon(), "");
+
+    assert_program_error(
+        restaking_program_client
+            .update_avs_metadata(
+                &avs_pubkey,
+                &metadata_admin,
+                &"a".repeat(MAX_METADATA_NAME_LEN + 1),
+                "",
+                "",
+            )
+            .await,
+        RestakingCoreError::MetadataFieldTooLong,
+    );
+}
+
+#[tokio::test]
+async fn test_update_operator_metadata_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // the metadata admin defaults to the operator admin
+    restaking_program_client
+        .update_operator_metadata(
+            &operator_pubkey,
+            &operator_admin,
+            "Operator",
+            "https://example.com/operator.json",
+            "",
+        )
+        .await
+        .unwrap();
+    let metadata = restaking_program_client
+        .get_metadata(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(metadata.parent(), operator_pubkey);
+    assert_eq!(metadata.name(), "Operator");
+    assert_eq!(metadata.uri(), "https://example.com/operator.json");
+    assert_eq!(metadata.icon(), "");
+}
@@ -0,0 +1,172 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::Avs, config::Config, operator::Operator, result::RestakingCoreError,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+/// Removes the rent collector inserted before the reserved space and bump, giving the layout
+/// written before it was added
+fn without_rent_collector(data: &[u8], tail_len: usize) -> Vec<u8> {
+    let offset = data.len() - tail_len - 32;
+    [&data[..offset], &data[offset + 32..]].concat()
+}
+
+async fn setup() -> (TestBuilder, Pubkey, Pubkey, Keypair) {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let admin = Keypair::new();
+    fixture.transfer(&admin.pubkey(), 10.0).await.unwrap();
+
+    let avs_base = Keypair::new();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &admin, &avs_base)
+        .await
+        .unwrap();
+
+    let operator_base = Keypair::new();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &admin, &operator_base)
+        .await
+        .unwrap();
+
+    (fixture, avs_pubkey, operator_pubkey, admin)
+}
+
+#[tokio::test]
+async fn test_migrate_avs_ok() {
+    let (mut fixture, avs_pubkey, _, admin) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let current = fixture.get_account_data(&avs_pubkey).await.unwrap();
+    let legacy = without_rent_collector(&current, 129);
+    fixture
+        .set_account_data(&avs_pubkey, &legacy)
+        .await
+        .unwrap();
+
+    // anyone can migrate, the rent collector defaults to the admin as for new AVSs
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .migrate_account(&avs_pubkey, &payer)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.get_account_data(&avs_pubkey).await.unwrap(),
+        current
+    );
+    fixture.assert_rent_exempt(&[avs_pubkey]).await;
+
+    assert_program_error(
+        restaking_program_client
+            .migrate_account(&avs_pubkey, &admin)
+            .await,
+        RestakingError::AccountAlreadyMigrated,
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_operator_ok() {
+    let (mut fixture, _, operator_pubkey, admin) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let current = fixture.get_account_data(&operator_pubkey).await.unwrap();
+    let legacy = without_rent_collector(&current, 1025);
+    fixture
+        .set_account_data(&operator_pubkey, &legacy)
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .migrate_account(&operator_pubkey, &admin)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.get_account_data(&operator_pubkey).await.unwrap(),
+        current
+    );
+    fixture.assert_rent_exempt(&[operator_pubkey]).await;
+}
+
+#[tokio::test]
+async fn test_migrate_unknown_layout_fails() {
+    let (mut fixture, avs_pubkey, _, admin) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let current = fixture.get_account_data(&avs_pubkey).await.unwrap();
+    fixture
+        .set_account_data(&avs_pubkey, &current[..current.len() - 1])
+        .await
+        .unwrap();
+
+    assert_program_error(
+        restaking_program_client
+            .migrate_account(&avs_pubkey, &admin)
+            .await,
+        RestakingCoreError::AccountMigrationNotFound,
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_unversioned_avs_ok() {
+    let (mut fixture, avs_pubkey, _, admin) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // accounts written before versioning read as version 0
+    let current = fixture.get_account_data(&avs_pubkey).await.unwrap();
+    let mut unversioned = current.clone();
+    Avs::load_mut(&mut unversioned).unwrap().set_version(0);
+    fixture
+        .set_account_data(&avs_pubkey, &unversioned)
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .migrate_account(&avs_pubkey, &admin)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.get_account_data(&avs_pubkey).await.unwrap(),
+        current
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_newer_version_fails() {
+    let (mut fixture, avs_pubkey, _, admin) = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let mut data = fixture.get_account_data(&avs_pubkey).await.unwrap();
+    Avs::load_mut(&mut data)
+        .unwrap()
+        .set_version(Avs::VERSION + 1);
+    fixture.set_account_data(&avs_pubkey, &data).await.unwrap();
+
+    assert_program_error(
+        restaking_program_client
+            .migrate_account(&avs_pubkey, &admin)
+            .await,
+        RestakingCoreError::AccountVersionUnsupported,
+    );
+}
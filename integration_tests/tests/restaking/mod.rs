@@ -1,8 +1,39 @@
+mod adversarial;
+mod assert_healthy;
 mod avs_add_operator;
 mod avs_add_vault;
 mod avs_add_vault_slasher;
+mod avs_eject_stale_operator;
+mod avs_multisig;
+mod avs_remove_operator;
+mod avs_remove_vault;
+mod avs_reward_budget;
+mod config_parameters;
+mod config_slasher_programs;
+mod epoch_rewards;
+mod epoch_stake_snapshot;
 mod initialize_avs;
 mod initialize_config;
 mod initialize_operator;
+mod metadata;
+mod migrate_account;
+mod onboarding_flows;
 mod operator_add_avs;
 mod operator_add_vault;
+mod operator_avs_capacity;
+mod operator_avs_removal;
+mod operator_exit_all;
+mod operator_fee;
+mod operator_heartbeat;
+mod operator_remove_avs;
+mod operator_remove_vault;
+mod operator_score;
+mod operator_set_voter;
+mod relay_admin_operation;
+mod rent_management;
+mod reward_claims;
+mod set_admin;
+mod set_secondary_admin;
+mod sweep_tokens;
+mod ticket_epoch_length;
+mod withdraw_asset;
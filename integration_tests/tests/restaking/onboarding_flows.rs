@@ -0,0 +1,321 @@
+use jito_restaking_core::{avs::Avs, config::Config as RestakingConfig, operator::Operator};
+use jito_restaking_sdk::flows::{
+    operator_onboarding, operator_onboarding_atomic, vault_slasher_approval,
+    vault_slasher_approval_atomic, OperatorOnboarding, ProgramIds, VaultSlasherApproval,
+};
+use jito_vault_core::{
+    config::Config as VaultConfig, vault::Vault, vault_delegation_list::VaultDelegationList,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+const PROGRAM_IDS: ProgramIds = ProgramIds {
+    restaking_program: jito_restaking_program::id(),
+    vault_program: jito_vault_program::id(),
+};
+
+struct Parties {
+    fixture: TestBuilder,
+    operator: Pubkey,
+    operator_admin: Keypair,
+    avs: Pubkey,
+    avs_admin: Keypair,
+    vault: Pubkey,
+    vault_admin: Keypair,
+    payer: Keypair,
+}
+
+/// Sets up an operator, an AVS and a vault with different admins, and a payer that isn't an admin
+/// of any of them
+async fn setup() -> Parties {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let restaking_config = RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
+    restaking_program_client
+        .initialize_config(&restaking_config, &config_admin)
+        .await
+        .unwrap();
+    let vault_config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+    vault_program_client
+        .initialize_config(&vault_config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(
+            &restaking_config,
+            &operator_pubkey,
+            &operator_admin,
+            &operator_base,
+        )
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&restaking_config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_admin = Keypair::new();
+    let vault_base = Keypair::new();
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+    fixture.transfer(&vault_admin.pubkey(), 10.0).await.unwrap();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    vault_program_client
+        .initialize_vault(
+            &vault_config,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &Keypair::new(),
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 10.0).await.unwrap();
+
+    Parties {
+        fixture,
+        operator: operator_pubkey,
+        operator_admin,
+        avs: avs_pubkey,
+        avs_admin,
+        vault: vault_pubkey,
+        vault_admin,
+        payer,
+    }
+}
+
+#[tokio::test]
+async fn test_onboarding_flows_ok() {
+    let Parties {
+        mut fixture,
+        operator: operator_pubkey,
+        operator_admin,
+        avs: avs_pubkey,
+        avs_admin,
+        vault: vault_pubkey,
+        vault_admin,
+        payer,
+    } = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    // the payer isn't an admin of any party, so it signs every transaction
+    let slasher = Pubkey::new_unique();
+
+    let approval = vault_slasher_approval(
+        &PROGRAM_IDS,
+        &VaultSlasherApproval {
+            avs: avs_pubkey,
+            avs_vault_admin: avs_admin.pubkey(),
+            avs_slasher_admin: avs_admin.pubkey(),
+            vault: vault_pubkey,
+            vault_avs_admin: vault_admin.pubkey(),
+            vault_slasher_admin: vault_admin.pubkey(),
+            slasher,
+            max_slashable_per_epoch: 100,
+            metadata_hash: [0; 32],
+            payer: payer.pubkey(),
+        },
+    );
+    let onboarding = operator_onboarding(
+        &PROGRAM_IDS,
+        &OperatorOnboarding {
+            operator: operator_pubkey,
+            operator_avs_admin: operator_admin.pubkey(),
+            operator_vault_admin: operator_admin.pubkey(),
+            avs: avs_pubkey,
+            avs_operator_admin: avs_admin.pubkey(),
+            vault: vault_pubkey,
+            vault_operator_admin: vault_admin.pubkey(),
+            payer: payer.pubkey(),
+        },
+    );
+    assert_eq!(
+        onboarding[0][0].signers(),
+        [payer.pubkey(), operator_admin.pubkey()]
+    );
+
+    fixture
+        .process_flow_transaction(&approval[0][0], &[&payer, &avs_admin])
+        .await
+        .unwrap();
+    fixture
+        .process_flow_transaction(&approval[0][1], &[&payer, &vault_admin])
+        .await
+        .unwrap();
+    fixture
+        .process_flow_transaction(&onboarding[0][0], &[&payer, &operator_admin])
+        .await
+        .unwrap();
+    fixture
+        .process_flow_transaction(&onboarding[0][1], &[&payer, &vault_admin])
+        .await
+        .unwrap();
+
+    // the AVS can't rely on the tickets before they've warmed up
+    assert!(fixture
+        .process_flow_transaction(&approval[1][0], &[&payer, &avs_admin])
+        .await
+        .is_err());
+    fixture.warp_past_ticket_warmup().await.unwrap();
+
+    fixture
+        .process_flow_transaction(&approval[1][0], &[&payer, &avs_admin])
+        .await
+        .unwrap();
+    fixture
+        .process_flow_transaction(&approval[1][1], &[&payer, &vault_admin])
+        .await
+        .unwrap();
+    fixture
+        .process_flow_transaction(&onboarding[1][0], &[&payer, &avs_admin])
+        .await
+        .unwrap();
+
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.operator_count(), 1);
+    assert_eq!(avs.vault_count(), 1);
+    assert_eq!(avs.slasher_count(), 1);
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.avs_count(), 1);
+    assert_eq!(operator.vault_count(), 1);
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.avs_count(), 1);
+    assert_eq!(vault.operator_count(), 1);
+    assert_eq!(vault.slasher_count(), 1);
+}
+
+#[tokio::test]
+async fn test_atomic_onboarding_flows_ok() {
+    let Parties {
+        mut fixture,
+        operator: operator_pubkey,
+        operator_admin,
+        avs: avs_pubkey,
+        avs_admin,
+        vault: vault_pubkey,
+        vault_admin,
+        payer,
+    } = setup().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let approval = vault_slasher_approval_atomic(
+        &PROGRAM_IDS,
+        &VaultSlasherApproval {
+            avs: avs_pubkey,
+            avs_vault_admin: avs_admin.pubkey(),
+            avs_slasher_admin: avs_admin.pubkey(),
+            vault: vault_pubkey,
+            vault_avs_admin: vault_admin.pubkey(),
+            vault_slasher_admin: vault_admin.pubkey(),
+            slasher: Pubkey::new_unique(),
+            max_slashable_per_epoch: 100,
+            metadata_hash: [0; 32],
+            payer: payer.pubkey(),
+        },
+    );
+    // the vault admin's signature alone isn't enough, every party shall sign
+    assert!(fixture
+        .process_partially_signed_flow_transaction(&approval[0], &[&payer, &vault_admin])
+        .await
+        .is_err());
+    fixture
+        .process_partially_signed_flow_transaction(
+            &approval[0],
+            &[&vault_admin, &avs_admin, &payer],
+        )
+        .await
+        .unwrap();
+
+    let onboarding = operator_onboarding_atomic(
+        &PROGRAM_IDS,
+        &OperatorOnboarding {
+            operator: operator_pubkey,
+            operator_avs_admin: operator_admin.pubkey(),
+            operator_vault_admin: operator_admin.pubkey(),
+            avs: avs_pubkey,
+            avs_operator_admin: avs_admin.pubkey(),
+            vault: vault_pubkey,
+            vault_operator_admin: vault_admin.pubkey(),
+            payer: payer.pubkey(),
+        },
+    );
+    // every admin of the epoch signs once, in whichever order the message lists them
+    assert_eq!(onboarding[0].signers().len(), 3);
+    assert_eq!(onboarding[0].signer_index(&payer.pubkey()), Some(0));
+    fixture
+        .process_partially_signed_flow_transaction(
+            &onboarding[0],
+            &[&operator_admin, &vault_admin, &payer],
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    fixture
+        .process_partially_signed_flow_transaction(
+            &approval[1],
+            &[&vault_admin, &avs_admin, &payer],
+        )
+        .await
+        .unwrap();
+    fixture
+        .process_partially_signed_flow_transaction(&onboarding[1], &[&avs_admin, &payer])
+        .await
+        .unwrap();
+
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.operator_count(), 1);
+    assert_eq!(avs.vault_count(), 1);
+    assert_eq!(avs.slasher_count(), 1);
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.avs_count(), 1);
+    assert_eq!(operator.vault_count(), 1);
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.avs_count(), 1);
+    assert_eq!(vault.operator_count(), 1);
+    assert_eq!(vault.slasher_count(), 1);
+}
@@ -11,7 +11,7 @@ async fn test_operator_add_avs_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -84,6 +84,9 @@ async fn test_operator_add_avs_ok() {
     assert_eq!(ticket.avs(), avs_pubkey);
     assert_eq!(ticket.index(), 0);
     assert_eq!(ticket.state().slot_added(), 1);
+    fixture
+        .assert_rent_exempt(&[operator_pubkey, operator_avs_ticket])
+        .await;
 }
 
 #[tokio::test]
@@ -92,7 +95,7 @@ async fn test_operator_add_multiple_avs_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -212,7 +215,7 @@ async fn test_operator_add_avs_duplicate_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -292,7 +295,7 @@ async fn test_operator_add_avs_non_admin_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -362,7 +365,7 @@ async fn test_operator_add_avs_uninitialized_operator_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -422,7 +425,7 @@ async fn test_operator_add_avs_uninitialized_avs_fails() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -1,8 +1,6 @@
 use jito_restaking_core::{
     avs::Avs, config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
 };
-use jito_vault_core::vault::Vault;
-use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
 
 use crate::fixtures::fixture::TestBuilder;
@@ -13,7 +11,7 @@ async fn test_operator_add_vault_ok() {
     let mut restaking_program_client = fixture.restaking_program_client();
 
     // Initialize config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let config = Config::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -50,8 +48,7 @@ async fn test_operator_add_vault_ok() {
         .await
         .unwrap();
 
-    let vault_pubkey =
-        Vault::find_program_address(&jito_restaking_program::id(), &Pubkey::new_unique()).0;
+    let vault_pubkey = fixture.create_vault().await.unwrap();
 
     // Operator adds vault
     let operator_vault_ticket = OperatorVaultTicket::find_program_address(
@@ -88,4 +85,7 @@ async fn test_operator_add_vault_ok() {
     assert_eq!(ticket.vault(), vault_pubkey);
     assert_eq!(ticket.index(), 0);
     assert_eq!(ticket.state().slot_added(), 1);
+    fixture
+        .assert_rent_exempt(&[operator_pubkey, operator_vault_ticket])
+        .await;
 }
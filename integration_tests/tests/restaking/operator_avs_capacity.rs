@@ -0,0 +1,201 @@
+use jito_restaking_core::{
+    avs::Avs, config::Config, operator::Operator, operator_avs_capacity::OperatorAvsCapacity,
+    operator_avs_ticket::OperatorAvsTicket, result::RestakingCoreError,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_operator_set_avs_capacity_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let slot = fixture.get_slot().await.unwrap();
+
+    // only the operator AVS admin can advertise capacity
+    let bad_admin = Keypair::new();
+    fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .operator_set_avs_capacity(&config, &operator_pubkey, &avs_pubkey, &bad_admin, 1_000)
+            .await,
+        RestakingCoreError::OperatorInvalidAvsAdmin,
+    );
+
+    // the first update creates the capacity account
+    restaking_program_client
+        .operator_set_avs_capacity(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_admin,
+            1_000,
+        )
+        .await
+        .unwrap();
+    let capacity = restaking_program_client
+        .get_operator_avs_capacity(&operator_pubkey, &avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(capacity.operator(), operator_pubkey);
+    assert_eq!(capacity.avs(), avs_pubkey);
+    assert_eq!(capacity.capacity(), 1_000);
+    assert_eq!(capacity.last_updated_slot(), slot);
+    let capacity_pubkey = OperatorAvsCapacity::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    fixture.assert_rent_exempt(&[capacity_pubkey]).await;
+
+    // later ones replace it
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .operator_set_avs_capacity(&config, &operator_pubkey, &avs_pubkey, &operator_admin, 0)
+        .await
+        .unwrap();
+    let capacity = restaking_program_client
+        .get_operator_avs_capacity(&operator_pubkey, &avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(capacity.capacity(), 0);
+    assert_eq!(capacity.last_updated_slot(), slot + 1);
+}
+
+#[tokio::test]
+async fn test_operator_set_avs_capacity_inactive_ticket_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    // an operator that left the AVS can't advertise capacity for it
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .operator_remove_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_to_next_slot().await.unwrap();
+
+    assert_program_error(
+        restaking_program_client
+            .operator_set_avs_capacity(
+                &config,
+                &operator_pubkey,
+                &avs_pubkey,
+                &operator_admin,
+                1_000,
+            )
+            .await,
+        RestakingCoreError::OperatorAvsTicketNotActive,
+    );
+}
@@ -0,0 +1,608 @@
+use jito_jsm_core::slot_toggled_field::SlotToggleState;
+use jito_restaking_core::{
+    avs::MIN_MAX_HEARTBEAT_AGE, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    result::RestakingCoreError,
+};
+use jito_restaking_sdk::{error::RestakingError, AvsAdminRole};
+use jito_vault_core::slash_proposal::SlashProposal;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, RelationshipGraph, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+/// Asserts the tickets between the AVS and the operator of the graph were closed, refunding their
+/// rent to the admins, which are the rent collectors of the graph. `lamports_before` is what
+/// [`lamports`] returned before.
+async fn assert_tickets_closed(
+    fixture: &mut TestBuilder,
+    graph: &RelationshipGraph,
+    lamports_before: [u64; 4],
+) {
+    let [avs_admin, operator_admin, avs_operator_ticket, operator_avs_ticket] = lamports_before;
+    fixture
+        .assert_closed_with_refund(
+            &graph.avs_operator_ticket,
+            &graph.avs_admin.pubkey(),
+            avs_admin,
+            avs_operator_ticket,
+        )
+        .await;
+    fixture
+        .assert_closed_with_refund(
+            &graph.operator_avs_ticket,
+            &graph.operator_admin.pubkey(),
+            operator_admin,
+            operator_avs_ticket,
+        )
+        .await;
+
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let avs = restaking_program_client.get_avs(&graph.avs).await.unwrap();
+    assert_eq!(avs.closed_operator_count(), 1);
+    let operator = restaking_program_client
+        .get_operator(&graph.operator)
+        .await
+        .unwrap();
+    assert_eq!(operator.closed_avs_count(), 1);
+}
+
+/// The lamports of the admins and the tickets between the AVS and the operator of the graph
+async fn lamports(fixture: &mut TestBuilder, graph: &RelationshipGraph) -> [u64; 4] {
+    [
+        fixture
+            .get_lamports(&graph.avs_admin.pubkey())
+            .await
+            .unwrap(),
+        fixture
+            .get_lamports(&graph.operator_admin.pubkey())
+            .await
+            .unwrap(),
+        fixture
+            .get_lamports(&graph.avs_operator_ticket)
+            .await
+            .unwrap(),
+        fixture
+            .get_lamports(&graph.operator_avs_ticket)
+            .await
+            .unwrap(),
+    ]
+}
+
+/// Deposits into the vault of the graph and delegates the amount to its operator
+async fn delegate(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+    graph: &RelationshipGraph,
+    amount: u64,
+) {
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &depositor.pubkey(), amount)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &graph.vault,
+            &graph.lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &graph.token_mint.pubkey()),
+            &graph.vault_token_account,
+            &get_associated_token_address(&depositor.pubkey(), &graph.lrt_mint.pubkey()),
+            &graph.vault_fee_token_account,
+            None,
+            amount,
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .add_delegation(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.operator,
+            &graph.vault_operator_ticket,
+            &graph.operator_vault_ticket,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+            &graph.vault_admin,
+            amount,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_finalize_operator_avs_removal_after_undelegation_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    delegate(&mut fixture, &mut vault_program_client, &graph, 10_000).await;
+
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .operator_remove_avs(
+            &graph.restaking_config,
+            &graph.operator,
+            &graph.avs,
+            &graph.operator_avs_ticket,
+            &graph.operator_admin,
+        )
+        .await
+        .unwrap();
+    let ticket_epoch_length = restaking_program_client
+        .get_config(&graph.restaking_config)
+        .await
+        .unwrap()
+        .ticket_epoch_length();
+    let slot = fixture.get_slot().await.unwrap();
+    let ticket = restaking_program_client
+        .get_operator_avs_ticket(&graph.operator, &graph.avs)
+        .await
+        .unwrap();
+    assert_eq!(
+        ticket.state().state(slot, ticket_epoch_length),
+        SlotToggleState::CoolingDown
+    );
+
+    // the tickets are only closed by finalizing the removal
+    assert_program_error(
+        restaking_program_client
+            .close_ticket(
+                &graph.restaking_config,
+                &graph.operator,
+                &graph.operator_avs_ticket,
+                &graph.operator_admin.pubkey(),
+                &graph.operator_admin,
+            )
+            .await,
+        RestakingError::TicketNotClosable,
+    );
+
+    // every vault of the AVS shall be passed in
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[],
+                &payer,
+            )
+            .await,
+        RestakingCoreError::AvsVaultCountMismatch,
+    );
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[graph.vault],
+                &payer,
+            )
+            .await,
+        RestakingError::OperatorAvsRemovalDelegated,
+    );
+
+    // undelegated stake blocks the removal until its cooldown completes
+    vault_program_client
+        .remove_delegation(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.operator,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+            10_000,
+        )
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[graph.vault],
+                &payer,
+            )
+            .await,
+        RestakingError::OperatorAvsRemovalDelegated,
+    );
+
+    let epoch_length = vault_program_client
+        .get_config(&graph.vault_config)
+        .await
+        .unwrap()
+        .epoch_length();
+    let epoch = fixture.get_slot().await.unwrap() / epoch_length;
+    for epoch in [epoch + 1, epoch + 2] {
+        fixture.warp_to_epoch(epoch, epoch_length).await.unwrap();
+        vault_program_client
+            .update_delegations(
+                &graph.vault_config,
+                &graph.vault,
+                &graph.vault_delegation_list,
+                &graph.vault_admin,
+            )
+            .await
+            .unwrap();
+    }
+
+    let lamports_before = lamports(&mut fixture, &graph).await;
+    restaking_program_client
+        .finalize_operator_avs_removal(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &[graph.vault],
+            &payer,
+        )
+        .await
+        .unwrap();
+    assert_tickets_closed(&mut fixture, &graph, lamports_before).await;
+}
+
+#[tokio::test]
+async fn test_finalize_operator_avs_removal_cooling_down_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    // neither side removed the other
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[graph.vault],
+                &payer,
+            )
+            .await,
+        RestakingCoreError::AvsOperatorTicketNotCoolingDown,
+    );
+
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .avs_remove_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &graph.avs_operator_ticket,
+            &graph.avs_admin,
+        )
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[graph.vault],
+                &payer,
+            )
+            .await,
+        RestakingError::TicketNotDeactivated,
+    );
+
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let lamports_before = lamports(&mut fixture, &graph).await;
+    restaking_program_client
+        .finalize_operator_avs_removal(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &[graph.vault],
+            &payer,
+        )
+        .await
+        .unwrap();
+    assert_tickets_closed(&mut fixture, &graph, lamports_before).await;
+}
+
+#[tokio::test]
+async fn test_finalize_operator_avs_removal_after_closing_vault_ticket_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    delegate(&mut fixture, &mut vault_program_client, &graph, 10_000).await;
+
+    // the AVS stops securing the vault, whose stake is then no longer at stake for it
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .avs_remove_vault(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.vault,
+            &graph.avs_vault_ticket,
+            &graph.avs_admin,
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .operator_remove_avs(
+            &graph.restaking_config,
+            &graph.operator,
+            &graph.avs,
+            &graph.operator_avs_ticket,
+            &graph.operator_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_past_ticket_warmup().await.unwrap();
+
+    // the vault ticket shall be passed in until it's closed
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[],
+                &payer,
+            )
+            .await,
+        RestakingCoreError::AvsVaultCountMismatch,
+    );
+    restaking_program_client
+        .close_ticket(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.avs_vault_ticket,
+            &graph.avs_admin.pubkey(),
+            &graph.avs_admin,
+        )
+        .await
+        .unwrap();
+    let avs = restaking_program_client.get_avs(&graph.avs).await.unwrap();
+    assert_eq!(avs.closed_vault_count(), 1);
+
+    // the closed ticket is skipped, and the vault still delegating doesn't block the removal
+    let lamports_before = lamports(&mut fixture, &graph).await;
+    restaking_program_client
+        .finalize_operator_avs_removal(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &[],
+            &payer,
+        )
+        .await
+        .unwrap();
+    assert_tickets_closed(&mut fixture, &graph, lamports_before).await;
+}
+
+#[tokio::test]
+async fn test_finalize_operator_avs_removal_slash_pending_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let slash_veto_admin = Keypair::new();
+    fixture
+        .transfer(&slash_veto_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &graph.avs,
+            &graph.avs_admin,
+            &slash_veto_admin.pubkey(),
+            AvsAdminRole::SlashVeto,
+        )
+        .await
+        .unwrap();
+
+    let slash_proposal = SlashProposal::find_program_address(
+        &jito_vault_program::id(),
+        &graph.vault,
+        &graph.avs,
+        &graph.slasher.pubkey(),
+        &graph.operator,
+        &[1; 32],
+    )
+    .0;
+    vault_program_client
+        .propose_slash(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.avs,
+            &graph.operator,
+            &graph.slasher,
+            &graph.avs_vault_slasher_ticket,
+            &graph.vault_avs_slasher_ticket,
+            &slash_proposal,
+            100,
+            [1; 32],
+        )
+        .await
+        .unwrap();
+    let vault_avs_ticket = vault_program_client
+        .get_vault_avs_ticket(&graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    assert_eq!(vault_avs_ticket.pending_slash_proposals(), 1);
+
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .avs_remove_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &graph.avs_operator_ticket,
+            &graph.avs_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_past_ticket_warmup().await.unwrap();
+
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[graph.vault],
+                &payer,
+            )
+            .await,
+        RestakingError::OperatorAvsRemovalSlashPending,
+    );
+
+    // once the slash is vetoed the removal can be finalized, only once
+    vault_program_client
+        .veto_slash(
+            &graph.vault_config,
+            &graph.avs,
+            &slash_proposal,
+            &slash_veto_admin,
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .finalize_operator_avs_removal(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &[graph.vault],
+            &payer,
+        )
+        .await
+        .unwrap();
+    assert!(restaking_program_client
+        .get_avs_operator_ticket(&graph.avs, &graph.operator)
+        .await
+        .is_err());
+
+    fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[graph.vault],
+                &payer,
+            )
+            .await,
+        RestakingCoreError::AvsOperatorTicketEmpty,
+    );
+}
+
+#[tokio::test]
+async fn test_finalize_operator_avs_removal_ejected_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .avs_set_max_heartbeat_age(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.avs_admin,
+            MIN_MAX_HEARTBEAT_AGE,
+        )
+        .await
+        .unwrap();
+    let operator_avs_heartbeat = OperatorAvsHeartbeat::find_program_address(
+        &jito_restaking_program::id(),
+        &graph.operator,
+        &graph.avs,
+    )
+    .0;
+    restaking_program_client
+        .operator_heartbeat(
+            &graph.restaking_config,
+            &graph.operator,
+            &graph.avs,
+            &graph.operator_avs_ticket,
+            &operator_avs_heartbeat,
+            &graph.operator_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+    fixture
+        .warp_slot_incremental(MIN_MAX_HEARTBEAT_AGE + 1)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_eject_stale_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &graph.avs_operator_ticket,
+            &operator_avs_heartbeat,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+    // the ejected ticket cooled down, but an ejection isn't a removal
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .finalize_operator_avs_removal(
+                &graph.restaking_config,
+                &graph.avs,
+                &graph.operator,
+                &[graph.vault],
+                &payer,
+            )
+            .await,
+        RestakingCoreError::AvsOperatorTicketNotCoolingDown,
+    );
+
+    // the operator can still recover and be re-activated
+    restaking_program_client
+        .operator_heartbeat(
+            &graph.restaking_config,
+            &graph.operator,
+            &graph.avs,
+            &graph.operator_avs_ticket,
+            &operator_avs_heartbeat,
+            &graph.operator_admin,
+            &payer,
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_reactivate_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &graph.avs_operator_ticket,
+            &operator_avs_heartbeat,
+            &payer,
+        )
+        .await
+        .unwrap();
+    let slot = fixture.get_slot().await.unwrap();
+    let ticket = restaking_program_client
+        .get_avs_operator_ticket(&graph.avs, &graph.operator)
+        .await
+        .unwrap();
+    assert!(!ticket.ejected());
+    assert!(ticket.state().is_active(slot));
+}
@@ -0,0 +1,203 @@
+use jito_restaking_core::{
+    avs::Avs, config::Config, operator::Operator, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sanitization::result::SanitizationError;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_operator_exit_all_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Operator adds two AVSs and a vault
+    let mut operator_avs_tickets = vec![];
+    for _ in 0..2 {
+        let avs_admin = Keypair::new();
+        let avs_base = Keypair::new();
+        fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+        let avs_pubkey =
+            Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+        restaking_program_client
+            .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+            .await
+            .unwrap();
+
+        let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &operator_pubkey,
+            &avs_pubkey,
+        )
+        .0;
+        restaking_program_client
+            .operator_add_avs(
+                &config,
+                &operator_pubkey,
+                &avs_pubkey,
+                &operator_avs_ticket,
+                &operator_admin,
+                &operator_admin,
+            )
+            .await
+            .unwrap();
+        operator_avs_tickets.push((avs_pubkey, operator_avs_ticket));
+    }
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    let avs_tickets: Vec<_> = operator_avs_tickets
+        .iter()
+        .map(|(_, ticket)| *ticket)
+        .collect();
+
+    // the tickets are validated as a list before any is deactivated
+    let result = restaking_program_client
+        .operator_exit_all(
+            &config,
+            &operator_pubkey,
+            &[avs_tickets[0], avs_tickets[0]],
+            &[],
+            &operator_admin,
+        )
+        .await;
+    assert_program_error(result, SanitizationError::RemainingAccountDuplicate);
+    let result = restaking_program_client
+        .operator_exit_all(
+            &config,
+            &operator_pubkey,
+            &[operator_pubkey],
+            &[],
+            &operator_admin,
+        )
+        .await;
+    assert_program_error(
+        result,
+        SanitizationError::RemainingAccountInvalidDiscriminator,
+    );
+
+    restaking_program_client
+        .operator_exit_all(
+            &config,
+            &operator_pubkey,
+            &avs_tickets,
+            &[operator_vault_ticket],
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    for (avs_pubkey, _) in &operator_avs_tickets {
+        let ticket = restaking_program_client
+            .get_operator_avs_ticket(&operator_pubkey, avs_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ticket.state().slot_removed(), 2);
+    }
+    let ticket = restaking_program_client
+        .get_operator_vault_ticket(&operator_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_removed(), 2);
+
+    // retrying with tickets that are already inactive is a no-op
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .operator_exit_all(
+            &config,
+            &operator_pubkey,
+            &avs_tickets,
+            &[operator_vault_ticket],
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+    let ticket = restaking_program_client
+        .get_operator_vault_ticket(&operator_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_removed(), 2);
+}
+
+#[tokio::test]
+async fn test_operator_exit_all_bad_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let bad_admin = Keypair::new();
+    fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+    assert!(restaking_program_client
+        .operator_exit_all(&config, &operator_pubkey, &[], &[], &bad_admin)
+        .await
+        .is_err());
+}
@@ -0,0 +1,146 @@
+use jito_restaking_core::{config::Config, operator::Operator, result::RestakingCoreError};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_set_operator_fee_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // operators can't charge a fee until the config admin allows it
+    assert_program_error(
+        restaking_program_client
+            .set_operator_fee(&config, &operator_pubkey, &operator_admin, 1)
+            .await,
+        RestakingCoreError::OperatorFeeTooHigh,
+    );
+
+    restaking_program_client
+        .config_set_operator_fee_params(&config, &config_admin, 1_000, 100)
+        .await
+        .unwrap();
+    let config_account = restaking_program_client.get_config(&config).await.unwrap();
+    assert_eq!(config_account.max_operator_fee_bps(), 1_000);
+    assert_eq!(config_account.operator_fee_cooldown_slots(), 100);
+
+    assert_program_error(
+        restaking_program_client
+            .set_operator_fee(&config, &operator_pubkey, &operator_admin, 1_001)
+            .await,
+        RestakingCoreError::OperatorFeeTooHigh,
+    );
+    restaking_program_client
+        .set_operator_fee(&config, &operator_pubkey, &operator_admin, 500)
+        .await
+        .unwrap();
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.operator_fee_bps(), 500);
+    assert_eq!(operator.last_fee_change_slot(), 1);
+    assert_eq!(operator.split_rewards(1_001), (50, 951));
+
+    // the fee can't change again until the cooldown elapsed
+    fixture.warp_slot_incremental(99).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .set_operator_fee(&config, &operator_pubkey, &operator_admin, 600)
+            .await,
+        RestakingCoreError::OperatorFeeCooldown,
+    );
+    fixture.warp_slot_incremental(1).await.unwrap();
+    restaking_program_client
+        .set_operator_fee(&config, &operator_pubkey, &operator_admin, 600)
+        .await
+        .unwrap();
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.operator_fee_bps(), 600);
+    assert_eq!(operator.last_fee_change_slot(), 101);
+}
+
+#[tokio::test]
+async fn test_set_operator_fee_bad_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // only the config admin sets the fee params, up to all of the rewards
+    assert_program_error(
+        restaking_program_client
+            .config_set_operator_fee_params(&config, &operator_admin, 1_000, 0)
+            .await,
+        RestakingCoreError::ConfigInvalidAdmin,
+    );
+    assert_program_error(
+        restaking_program_client
+            .config_set_operator_fee_params(&config, &config_admin, 10_001, 0)
+            .await,
+        RestakingCoreError::ConfigInvalidMaxOperatorFee,
+    );
+    restaking_program_client
+        .config_set_operator_fee_params(&config, &config_admin, 1_000, 0)
+        .await
+        .unwrap();
+
+    // only the operator admin sets the fee
+    let bad_admin = Keypair::new();
+    fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .set_operator_fee(&config, &operator_pubkey, &bad_admin, 500)
+            .await,
+        RestakingCoreError::OperatorInvalidAdmin,
+    );
+}
@@ -0,0 +1,200 @@
+use jito_restaking_core::{
+    avs::Avs, config::Config, operator::Operator, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_ticket::OperatorAvsTicket,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_operator_heartbeat_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    // Operator adds AVS
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    // The voter defaults to the operator admin
+    let operator_avs_heartbeat = OperatorAvsHeartbeat::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let slot = fixture.get_slot().await.unwrap();
+    restaking_program_client
+        .operator_heartbeat(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_avs_heartbeat,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    let heartbeat = restaking_program_client
+        .get_operator_avs_heartbeat(&operator_pubkey, &avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(heartbeat.operator(), operator_pubkey);
+    assert_eq!(heartbeat.avs(), avs_pubkey);
+    assert_eq!(heartbeat.last_heartbeat_slot(), slot);
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    restaking_program_client
+        .operator_heartbeat(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_avs_heartbeat,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    let heartbeat = restaking_program_client
+        .get_operator_avs_heartbeat(&operator_pubkey, &avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(heartbeat.last_heartbeat_slot(), slot + 1);
+}
+
+#[tokio::test]
+async fn test_operator_heartbeat_bad_voter_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    // Operator adds AVS
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    let operator_avs_heartbeat = OperatorAvsHeartbeat::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    let bad_voter = Keypair::new();
+    assert!(restaking_program_client
+        .operator_heartbeat(
+            &config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket,
+            &operator_avs_heartbeat,
+            &bad_voter,
+            &operator_admin,
+        )
+        .await
+        .is_err());
+}
@@ -0,0 +1,168 @@
+use jito_restaking_core::{
+    config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_operator_remove_vault_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+
+    // Operator adds vault
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    // Operator removes vault
+    restaking_program_client
+        .operator_remove_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    let ticket = restaking_program_client
+        .get_operator_vault_ticket(&operator_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_added(), 1);
+    assert_eq!(ticket.state().slot_removed(), 2);
+
+    // removing an inactive ticket fails
+    fixture.warp_to_next_slot().await.unwrap();
+    assert!(restaking_program_client
+        .operator_remove_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_operator_remove_vault_non_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    // Initialize config
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+
+    // Operator adds vault
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    // Non-admin attempts to remove vault
+    let non_admin = Keypair::new();
+    fixture.transfer(&non_admin.pubkey(), 10.0).await.unwrap();
+    assert!(restaking_program_client
+        .operator_remove_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &non_admin,
+        )
+        .await
+        .is_err());
+
+    let ticket = restaking_program_client
+        .get_operator_vault_ticket(&operator_pubkey, &vault_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.state().slot_removed(), 0);
+}
@@ -0,0 +1,202 @@
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, config::Config, operator::Operator,
+    operator_avs_score::MAX_SCORE_BPS, operator_avs_ticket::OperatorAvsTicket,
+    result::RestakingCoreError,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    restaking_client::RestakingProgramClient,
+};
+
+struct OperatorScoreSetup {
+    fixture: TestBuilder,
+    restaking_program_client: RestakingProgramClient,
+    config: Pubkey,
+    avs: Pubkey,
+    avs_admin: Keypair,
+    operator: Pubkey,
+    scorer: Keypair,
+}
+
+/// Sets up an AVS and an operator that added each other, with a scorer set on the AVS
+async fn setup() -> OperatorScoreSetup {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let operator_avs_ticket =
+        OperatorAvsTicket::find_program_address(&jito_restaking_program::id(), &operator, &avs).0;
+    restaking_program_client
+        .operator_add_avs(
+            &config,
+            &operator,
+            &avs,
+            &operator_avs_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let avs_operator_ticket =
+        AvsOperatorTicket::find_program_address(&jito_restaking_program::id(), &avs, &operator).0;
+    restaking_program_client
+        .avs_add_operator(
+            &config,
+            &avs,
+            &operator,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_past_ticket_warmup().await.unwrap();
+
+    let scorer = Keypair::new();
+    restaking_program_client
+        .avs_set_scorer(&avs, &avs_admin, &scorer.pubkey())
+        .await
+        .unwrap();
+
+    OperatorScoreSetup {
+        fixture,
+        restaking_program_client,
+        config,
+        avs,
+        avs_admin,
+        operator,
+        scorer,
+    }
+}
+
+#[tokio::test]
+async fn test_avs_attest_operator_score_ok() {
+    let OperatorScoreSetup {
+        mut fixture,
+        mut restaking_program_client,
+        config,
+        avs,
+        avs_admin,
+        operator,
+        scorer,
+    } = setup().await;
+
+    let avs_account = restaking_program_client.get_avs(&avs).await.unwrap();
+    assert_eq!(avs_account.scorer(), Some(scorer.pubkey()));
+
+    // the first attestation creates the score account
+    let slot = fixture.get_slot().await.unwrap();
+    restaking_program_client
+        .avs_attest_operator_score(&config, &avs, &operator, &scorer, &avs_admin, 9_500)
+        .await
+        .unwrap();
+    let score = restaking_program_client
+        .get_operator_avs_score(&operator, &avs)
+        .await
+        .unwrap();
+    assert_eq!(score.operator(), operator);
+    assert_eq!(score.avs(), avs);
+    assert_eq!(score.score_bps(), 9_500);
+    assert_eq!(score.last_attestation_slot(), slot);
+    assert_eq!(score.attestation_count(), 1);
+
+    fixture.warp_to_next_slot().await.unwrap();
+
+    restaking_program_client
+        .avs_attest_operator_score(&config, &avs, &operator, &scorer, &avs_admin, 7_000)
+        .await
+        .unwrap();
+    let score = restaking_program_client
+        .get_operator_avs_score(&operator, &avs)
+        .await
+        .unwrap();
+    assert_eq!(score.score_bps(), 7_000);
+    assert_eq!(score.last_attestation_slot(), slot + 1);
+    assert_eq!(score.attestation_count(), 2);
+}
+
+#[tokio::test]
+async fn test_avs_attest_operator_score_bad_scorer_fails() {
+    let OperatorScoreSetup {
+        mut fixture,
+        mut restaking_program_client,
+        config,
+        avs,
+        avs_admin,
+        operator,
+        ..
+    } = setup().await;
+
+    let bad_scorer = Keypair::new();
+    fixture.transfer(&bad_scorer.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .avs_attest_operator_score(&config, &avs, &operator, &bad_scorer, &avs_admin, 9_500)
+            .await,
+        RestakingCoreError::AvsInvalidScorer,
+    );
+}
+
+#[tokio::test]
+async fn test_avs_attest_operator_score_above_max_fails() {
+    let OperatorScoreSetup {
+        mut restaking_program_client,
+        config,
+        avs,
+        avs_admin,
+        operator,
+        scorer,
+        ..
+    } = setup().await;
+
+    assert_program_error(
+        restaking_program_client
+            .avs_attest_operator_score(
+                &config,
+                &avs,
+                &operator,
+                &scorer,
+                &avs_admin,
+                MAX_SCORE_BPS + 1,
+            )
+            .await,
+        RestakingCoreError::OperatorAvsScoreInvalidScore,
+    );
+}
@@ -0,0 +1,88 @@
+use jito_restaking_core::{config::Config, operator::Operator, result::RestakingCoreError};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_operator_set_voter_keeps_rotation_history() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    // only the operator admin can rotate the voter
+    let bad_admin = Keypair::new();
+    fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .operator_set_voter(&operator_pubkey, &bad_admin, &Pubkey::new_unique())
+            .await,
+        RestakingCoreError::OperatorInvalidAdmin,
+    );
+
+    let first_voter = Pubkey::new_unique();
+    fixture.warp_slot_incremental(100).await.unwrap();
+    restaking_program_client
+        .operator_set_voter(&operator_pubkey, &operator_admin, &first_voter)
+        .await
+        .unwrap();
+    let second_voter = Pubkey::new_unique();
+    fixture.warp_slot_incremental(100).await.unwrap();
+    restaking_program_client
+        .operator_set_voter(&operator_pubkey, &operator_admin, &second_voter)
+        .await
+        .unwrap();
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.voter(), second_voter);
+    let history: Vec<_> = operator.voter_history().copied().collect();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].voter(), first_voter);
+    assert_eq!(history[1].voter(), operator_admin.pubkey());
+    assert_eq!(
+        history[0].deactivated_slot(),
+        operator.voter_activated_slot()
+    );
+
+    // a message signed right before a rotation still verifies against the voter of its slot
+    let rotation_slot = operator.voter_activated_slot();
+    operator
+        .check_voter_at_slot(&first_voter, rotation_slot - 1)
+        .unwrap();
+    operator
+        .check_voter_at_slot(&second_voter, rotation_slot)
+        .unwrap();
+    assert_eq!(
+        operator.check_voter_at_slot(&first_voter, rotation_slot),
+        Err(RestakingCoreError::OperatorInvalidVoter)
+    );
+    operator
+        .check_voter_at_slot(&operator_admin.pubkey(), history[0].activated_slot() - 1)
+        .unwrap();
+}
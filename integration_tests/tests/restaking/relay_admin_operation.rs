@@ -0,0 +1,279 @@
+use jito_restaking_core::{avs::Avs, config::Config, operator::Operator};
+use jito_restaking_sdk::RelayedOperation;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::{fixture::TestBuilder, restaking_client::RestakingProgramClient};
+
+async fn setup_config(
+    fixture: &mut TestBuilder,
+    restaking_program_client: &mut RestakingProgramClient,
+) -> Pubkey {
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+    config
+}
+
+#[tokio::test]
+async fn test_relay_avs_set_max_heartbeat_age_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config = setup_config(&mut fixture, &mut restaking_program_client).await;
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 1.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let relayer = Keypair::new();
+    fixture.transfer(&relayer.pubkey(), 1.0).await.unwrap();
+
+    // nothing can be relayed until the AVS sets a relayer
+    assert!(restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &avs_pubkey,
+            &relayer,
+            &avs_admin,
+            0,
+            RelayedOperation::AvsSetMaxHeartbeatAge {
+                max_heartbeat_age: 1_000,
+            },
+        )
+        .await
+        .is_err());
+
+    restaking_program_client
+        .avs_set_relayer(&avs_pubkey, &avs_admin, &relayer.pubkey())
+        .await
+        .unwrap();
+    restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &avs_pubkey,
+            &relayer,
+            &avs_admin,
+            0,
+            RelayedOperation::AvsSetMaxHeartbeatAge {
+                max_heartbeat_age: 1_000,
+            },
+        )
+        .await
+        .unwrap();
+
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.relayer(), Some(relayer.pubkey()));
+    assert_eq!(avs.max_heartbeat_age(), 1_000);
+    assert_eq!(avs.nonce(), 1);
+
+    // the nonce was used
+    assert!(restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &avs_pubkey,
+            &relayer,
+            &avs_admin,
+            0,
+            RelayedOperation::AvsSetMaxHeartbeatAge {
+                max_heartbeat_age: 2_000,
+            },
+        )
+        .await
+        .is_err());
+
+    restaking_program_client
+        .avs_set_relayer(&avs_pubkey, &avs_admin, &Pubkey::default())
+        .await
+        .unwrap();
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.relayer(), None);
+}
+
+#[tokio::test]
+async fn test_relay_operator_set_voter_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config = setup_config(&mut fixture, &mut restaking_program_client).await;
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let relayer = Keypair::new();
+    fixture.transfer(&relayer.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .operator_set_relayer(&operator_pubkey, &operator_admin, &relayer.pubkey())
+        .await
+        .unwrap();
+
+    let voter = Pubkey::new_unique();
+    restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &operator_pubkey,
+            &relayer,
+            &operator_admin,
+            0,
+            RelayedOperation::OperatorSetVoter { voter },
+        )
+        .await
+        .unwrap();
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.voter(), voter);
+    assert_eq!(operator.nonce(), 1);
+}
+
+#[tokio::test]
+async fn test_relay_not_signed_by_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config = setup_config(&mut fixture, &mut restaking_program_client).await;
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let relayer = Keypair::new();
+    fixture.transfer(&relayer.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .operator_set_relayer(&operator_pubkey, &operator_admin, &relayer.pubkey())
+        .await
+        .unwrap();
+
+    // a valid signature, but not by the operator admin
+    assert!(restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &operator_pubkey,
+            &relayer,
+            &relayer,
+            0,
+            RelayedOperation::OperatorSetVoter {
+                voter: relayer.pubkey(),
+            },
+        )
+        .await
+        .is_err());
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.voter(), operator_admin.pubkey());
+    assert_eq!(operator.nonce(), 0);
+}
+
+#[tokio::test]
+async fn test_relay_operator_grant_session_key_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let config = setup_config(&mut fixture, &mut restaking_program_client).await;
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let relayer = Keypair::new();
+    fixture.transfer(&relayer.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .operator_set_relayer(&operator_pubkey, &operator_admin, &relayer.pubkey())
+        .await
+        .unwrap();
+
+    // session key grants and other relayed operations share the nonce
+    let voter = Pubkey::new_unique();
+    restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &operator_pubkey,
+            &relayer,
+            &operator_admin,
+            0,
+            RelayedOperation::OperatorSetVoter { voter },
+        )
+        .await
+        .unwrap();
+
+    let session_key = Pubkey::new_unique();
+    let grant = RelayedOperation::OperatorGrantSessionKey {
+        session_key,
+        expiry_slot: 1_000,
+    };
+    restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &operator_pubkey,
+            &relayer,
+            &operator_admin,
+            1,
+            grant.clone(),
+        )
+        .await
+        .unwrap();
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.nonce(), 2);
+    assert_eq!(operator.session_key(999), Some(session_key));
+    assert_eq!(operator.session_key(1_000), None);
+    assert!(operator.check_heartbeat_signer(&session_key, 999).is_ok());
+    assert!(operator.check_heartbeat_signer(&voter, 1_000).is_ok());
+    assert!(operator
+        .check_heartbeat_signer(&session_key, 1_000)
+        .is_err());
+
+    // the signed grant can't be replayed
+    assert!(restaking_program_client
+        .relay_admin_operation(
+            &config,
+            &operator_pubkey,
+            &relayer,
+            &operator_admin,
+            1,
+            grant,
+        )
+        .await
+        .is_err());
+}
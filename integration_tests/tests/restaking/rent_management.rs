@@ -0,0 +1,482 @@
+use borsh::BorshSerialize;
+use jito_restaking_core::{
+    avs::Avs, avs_vault_ticket::AvsVaultTicket, config::Config, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sanitization::result::SanitizationError;
+use jito_restaking_sdk::{avs_add_vault, initialize_avs, operator_add_vault};
+use solana_sdk::{
+    native_token::sol_to_lamports,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_top_up_rent_and_sweep_lamports_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // The ticket grows without being topped up, then anyone can pay for the rent
+    fixture.grow_account(&avs_vault_ticket, 64).await.unwrap();
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .top_up_rent(&avs_vault_ticket, &payer)
+        .await
+        .unwrap();
+
+    let rent = fixture.get_rent().await.unwrap();
+    let ticket_size = AvsVaultTicket::new(avs_pubkey, vault_pubkey, 0, 0, 0)
+        .try_to_vec()
+        .unwrap()
+        .len();
+    let minimum_balance = rent.minimum_balance(ticket_size + 64);
+    assert_eq!(
+        fixture.get_lamports(&avs_vault_ticket).await.unwrap(),
+        minimum_balance
+    );
+    fixture.assert_rent_exempt(&[avs_vault_ticket]).await;
+
+    // SOL sent to the ticket by mistake is swept back to the AVS by its withdraw admin
+    fixture.transfer(&avs_vault_ticket, 1.0).await.unwrap();
+    let avs_lamports = fixture.get_lamports(&avs_pubkey).await.unwrap();
+    assert!(restaking_program_client
+        .sweep_lamports(&config, &avs_pubkey, &avs_vault_ticket, &config_admin)
+        .await
+        .is_err());
+    restaking_program_client
+        .sweep_lamports(&config, &avs_pubkey, &avs_vault_ticket, &avs_admin)
+        .await
+        .unwrap();
+    fixture.assert_rent_exempt(&[avs_vault_ticket]).await;
+    assert_eq!(
+        fixture.get_lamports(&avs_pubkey).await.unwrap(),
+        avs_lamports + sol_to_lamports(1.0)
+    );
+}
+
+#[tokio::test]
+async fn test_close_avs_ticket_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    let rent_collector = Keypair::new().pubkey();
+    restaking_program_client
+        .avs_set_rent_collector(&avs_pubkey, &avs_admin, &rent_collector)
+        .await
+        .unwrap();
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.rent_collector(), rent_collector);
+
+    // an active ticket can't be closed
+    assert!(restaking_program_client
+        .close_ticket(
+            &config,
+            &avs_pubkey,
+            &avs_vault_ticket,
+            &rent_collector,
+            &avs_admin
+        )
+        .await
+        .is_err());
+
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .avs_remove_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // nor one still cooling down
+    assert!(restaking_program_client
+        .close_ticket(
+            &config,
+            &avs_pubkey,
+            &avs_vault_ticket,
+            &rent_collector,
+            &avs_admin
+        )
+        .await
+        .is_err());
+
+    fixture.warp_past_ticket_warmup().await.unwrap();
+
+    // the rent only goes to the rent collector of the AVS
+    assert!(restaking_program_client
+        .close_ticket(
+            &config,
+            &avs_pubkey,
+            &avs_vault_ticket,
+            &avs_admin.pubkey(),
+            &avs_admin
+        )
+        .await
+        .is_err());
+
+    fixture.assert_rent_exempt(&[avs_vault_ticket]).await;
+    let ticket_lamports = fixture.get_lamports(&avs_vault_ticket).await.unwrap();
+    let rent_collector_lamports = fixture.get_lamports(&rent_collector).await.unwrap();
+    restaking_program_client
+        .close_ticket(
+            &config,
+            &avs_pubkey,
+            &avs_vault_ticket,
+            &rent_collector,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+    fixture
+        .assert_closed_with_refund(
+            &avs_vault_ticket,
+            &rent_collector,
+            rent_collector_lamports,
+            ticket_lamports,
+        )
+        .await;
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.closed_vault_count(), 1);
+}
+
+#[tokio::test]
+async fn test_close_operator_ticket_bad_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .operator_remove_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+    fixture.warp_past_ticket_warmup().await.unwrap();
+
+    let rent_collector = Keypair::new().pubkey();
+    restaking_program_client
+        .operator_set_rent_collector(&operator_pubkey, &operator_admin, &rent_collector)
+        .await
+        .unwrap();
+
+    let bad_admin = Keypair::new();
+    fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+    assert!(restaking_program_client
+        .close_ticket(
+            &config,
+            &operator_pubkey,
+            &operator_vault_ticket,
+            &rent_collector,
+            &bad_admin,
+        )
+        .await
+        .is_err());
+
+    let ticket_lamports = fixture.get_lamports(&operator_vault_ticket).await.unwrap();
+    restaking_program_client
+        .close_ticket(
+            &config,
+            &operator_pubkey,
+            &operator_vault_ticket,
+            &rent_collector,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+    fixture
+        .assert_closed_with_refund(&operator_vault_ticket, &rent_collector, 0, ticket_lamports)
+        .await;
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.closed_vault_count(), 1);
+}
+
+#[tokio::test]
+async fn test_initialize_avs_separate_payer_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    // the admin never holds any SOL
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    fixture
+        .process_instruction(
+            &initialize_avs(
+                &jito_restaking_program::id(),
+                &graph.restaking_config,
+                &avs_pubkey,
+                &avs_admin.pubkey(),
+                &avs_base.pubkey(),
+                &payer.pubkey(),
+            ),
+            &[&avs_admin, &avs_base, &payer],
+        )
+        .await
+        .unwrap();
+
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.admin(), avs_admin.pubkey());
+    assert_eq!(fixture.get_lamports(&avs_admin.pubkey()).await.unwrap(), 0);
+    fixture.assert_rent_exempt(&[avs_pubkey]).await;
+}
+
+#[tokio::test]
+async fn test_avs_add_vault_funded_by_avs_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+
+    fixture.transfer(&graph.avs, 1.0).await.unwrap();
+    let avs_lamports = fixture.get_lamports(&graph.avs).await.unwrap();
+    let admin_lamports = fixture
+        .get_lamports(&graph.avs_admin.pubkey())
+        .await
+        .unwrap();
+
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &graph.avs,
+        &vault_pubkey,
+    )
+    .0;
+    let instruction = avs_add_vault(
+        &jito_restaking_program::id(),
+        &graph.restaking_config,
+        &graph.avs,
+        &vault_pubkey,
+        &avs_vault_ticket,
+        &graph.avs_admin.pubkey(),
+        &graph.avs,
+    );
+    fixture
+        .process_instruction(&instruction, &[&graph.avs_admin])
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .get_avs_vault_ticket(&graph.avs, &vault_pubkey)
+        .await
+        .unwrap();
+    let ticket_lamports = fixture.get_lamports(&avs_vault_ticket).await.unwrap();
+    assert_eq!(
+        fixture.get_lamports(&graph.avs).await.unwrap(),
+        avs_lamports - ticket_lamports
+    );
+    assert_eq!(
+        fixture
+            .get_lamports(&graph.avs_admin.pubkey())
+            .await
+            .unwrap(),
+        admin_lamports
+    );
+    fixture.assert_rent_exempt(&[avs_vault_ticket]).await;
+}
+
+#[tokio::test]
+async fn test_avs_add_vault_funded_by_avs_insufficient_lamports_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+
+    // the AVS only holds its own rent
+    let instruction = avs_add_vault(
+        &jito_restaking_program::id(),
+        &graph.restaking_config,
+        &graph.avs,
+        &vault_pubkey,
+        &AvsVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &graph.avs,
+            &vault_pubkey,
+        )
+        .0,
+        &graph.avs_admin.pubkey(),
+        &graph.avs,
+    );
+    let result = fixture
+        .process_instruction(&instruction, &[&graph.avs_admin])
+        .await;
+
+    assert_program_error(
+        result,
+        SanitizationError::RentPayerParentInsufficientLamports,
+    );
+}
+
+#[tokio::test]
+async fn test_operator_add_vault_funded_by_operator_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+
+    fixture.transfer(&graph.operator, 1.0).await.unwrap();
+    let operator_lamports = fixture.get_lamports(&graph.operator).await.unwrap();
+
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &graph.operator,
+        &vault_pubkey,
+    )
+    .0;
+    let instruction = operator_add_vault(
+        &jito_restaking_program::id(),
+        &graph.restaking_config,
+        &graph.operator,
+        &vault_pubkey,
+        &operator_vault_ticket,
+        &graph.operator_admin.pubkey(),
+        &graph.operator,
+    );
+    fixture
+        .process_instruction(&instruction, &[&graph.operator_admin])
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .get_operator_vault_ticket(&graph.operator, &vault_pubkey)
+        .await
+        .unwrap();
+    let ticket_lamports = fixture.get_lamports(&operator_vault_ticket).await.unwrap();
+    assert_eq!(
+        fixture.get_lamports(&graph.operator).await.unwrap(),
+        operator_lamports - ticket_lamports
+    );
+    fixture.assert_rent_exempt(&[operator_vault_ticket]).await;
+}
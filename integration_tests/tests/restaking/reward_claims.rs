@@ -0,0 +1,486 @@
+use jito_restaking_core::{
+    avs::Avs, config::Config, result::RestakingCoreError, reward_distribution::RewardDistribution,
+};
+use jito_restaking_sanitization::result::SanitizationError;
+use jito_restaking_sdk::{
+    batch::ComputeBudget, flows::ProgramIds, lookup_table::common_addresses,
+    reward_merkle_tree::RewardMerkleTree,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    restaking_client::RestakingProgramClient,
+};
+
+struct RewardClaimsSetup {
+    config: Pubkey,
+    avs: Pubkey,
+    avs_admin: Keypair,
+    mint: Pubkey,
+    claims: Vec<(Pubkey, u64)>,
+    tree: RewardMerkleTree,
+    reward_distribution: Pubkey,
+}
+
+/// Initializes an AVS whose admin holds 10_000 tokens of a new mint, and the reward tree of three
+/// claimants owed 6_000 in total for epoch 1. The token accounts of the claimants and of the
+/// distribution exist.
+async fn setup(
+    fixture: &mut TestBuilder,
+    restaking_program_client: &mut RestakingProgramClient,
+) -> RewardClaimsSetup {
+    let mint = Keypair::new();
+    fixture.create_token_mint(&mint).await.unwrap();
+    setup_with_mint(fixture, restaking_program_client, mint.pubkey()).await
+}
+
+/// [`setup`] around an existing mint, SPL Token or Token-2022
+async fn setup_with_mint(
+    fixture: &mut TestBuilder,
+    restaking_program_client: &mut RestakingProgramClient,
+    mint: Pubkey,
+) -> RewardClaimsSetup {
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    fixture
+        .mint_to(&mint, &avs_admin.pubkey(), 10_000)
+        .await
+        .unwrap();
+
+    let claims: Vec<_> = [1_000, 2_000, 3_000]
+        .into_iter()
+        .map(|amount| (Pubkey::new_unique(), amount))
+        .collect();
+    for (claimant, _) in &claims {
+        fixture.create_ata(&mint, claimant).await.unwrap();
+    }
+    let tree = RewardMerkleTree::new(&claims).unwrap();
+    let reward_distribution =
+        RewardDistribution::find_program_address(&jito_restaking_program::id(), &avs, &mint, 1).0;
+    fixture
+        .create_ata(&mint, &reward_distribution)
+        .await
+        .unwrap();
+
+    RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        claims,
+        tree,
+        reward_distribution,
+    }
+}
+
+#[tokio::test]
+async fn test_claim_reward_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        claims,
+        tree,
+        reward_distribution,
+    } = setup(&mut fixture, &mut restaking_program_client).await;
+
+    restaking_program_client
+        .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 6_000, 3)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(&reward_distribution, &mint))
+            .await
+            .unwrap()
+            .amount,
+        6_000
+    );
+
+    // Anyone can crank the claims, the claimants don't sign
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    for (index, (claimant, amount)) in claims.iter().enumerate() {
+        restaking_program_client
+            .claim_reward(
+                &reward_distribution,
+                claimant,
+                &mint,
+                &payer,
+                *amount,
+                tree.proof(index).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address(claimant, &mint))
+                .await
+                .unwrap()
+                .amount,
+            *amount
+        );
+        let claim_status = restaking_program_client
+            .get_reward_claim_status(&reward_distribution, claimant)
+            .await
+            .unwrap();
+        assert_eq!(claim_status.amount(), *amount);
+    }
+
+    let distribution = restaking_program_client
+        .get_reward_distribution(&avs, &mint, 1)
+        .await
+        .unwrap();
+    assert_eq!(distribution.total_claimed(), 6_000);
+    assert_eq!(distribution.num_claimed(), 3);
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(&reward_distribution, &mint))
+            .await
+            .unwrap()
+            .amount,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_claim_reward_token_2022_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mint = Keypair::new();
+    fixture
+        .create_token_2022_mint(&mint, 0, None)
+        .await
+        .unwrap();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        claims,
+        tree,
+        reward_distribution,
+    } = setup_with_mint(&mut fixture, &mut restaking_program_client, mint.pubkey()).await;
+
+    // the transfers go through the token program owning the mint
+    restaking_program_client
+        .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 6_000, 3)
+        .await
+        .unwrap();
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    let (claimant, amount) = claims[0];
+    restaking_program_client
+        .claim_reward(
+            &reward_distribution,
+            &claimant,
+            &mint,
+            &payer,
+            amount,
+            tree.proof(0).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address_with_program_id(
+                &claimant,
+                &mint,
+                &spl_token_2022::id(),
+            ))
+            .await
+            .unwrap()
+            .amount,
+        amount
+    );
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address_with_program_id(
+                &reward_distribution,
+                &mint,
+                &spl_token_2022::id(),
+            ))
+            .await
+            .unwrap()
+            .amount,
+        6_000 - amount
+    );
+}
+
+#[tokio::test]
+async fn test_claim_reward_v0_transaction_with_priority_fee_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        claims,
+        tree,
+        reward_distribution,
+    } = setup(&mut fixture, &mut restaking_program_client).await;
+
+    let lookup_table = fixture
+        .create_lookup_table(&common_addresses(&ProgramIds {
+            restaking_program: jito_restaking_program::id(),
+            vault_program: jito_vault_program::id(),
+        }))
+        .await
+        .unwrap();
+    fixture.warp_to_next_slot().await.unwrap();
+    let mut restaking_program_client = fixture
+        .restaking_program_client()
+        .with_compute_budget(ComputeBudget {
+            unit_limit: Some(400_000),
+            unit_price: Some(10_000),
+        })
+        .with_lookup_tables(vec![lookup_table]);
+
+    restaking_program_client
+        .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 6_000, 3)
+        .await
+        .unwrap();
+    let (claimant, amount) = claims[2];
+    restaking_program_client
+        .claim_reward(
+            &reward_distribution,
+            &claimant,
+            &mint,
+            &avs_admin,
+            amount,
+            tree.proof(2).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(&claimant, &mint))
+            .await
+            .unwrap()
+            .amount,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn test_claim_reward_twice_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        claims,
+        tree,
+        reward_distribution,
+    } = setup(&mut fixture, &mut restaking_program_client).await;
+
+    restaking_program_client
+        .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 6_000, 3)
+        .await
+        .unwrap();
+    let (claimant, amount) = claims[0];
+    restaking_program_client
+        .claim_reward(
+            &reward_distribution,
+            &claimant,
+            &mint,
+            &avs_admin,
+            amount,
+            tree.proof(0).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .claim_reward(
+                &reward_distribution,
+                &claimant,
+                &mint,
+                &avs_admin,
+                amount,
+                tree.proof(0).unwrap(),
+            )
+            .await,
+        SanitizationError::EmptyAccountNotEmpty,
+    );
+}
+
+#[tokio::test]
+async fn test_claim_reward_invalid_proof_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        claims,
+        tree,
+        reward_distribution,
+    } = setup(&mut fixture, &mut restaking_program_client).await;
+
+    restaking_program_client
+        .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 6_000, 3)
+        .await
+        .unwrap();
+
+    // Claiming more than the leaf of the claimant
+    let (claimant, amount) = claims[0];
+    assert_program_error(
+        restaking_program_client
+            .claim_reward(
+                &reward_distribution,
+                &claimant,
+                &mint,
+                &avs_admin,
+                amount + 1,
+                tree.proof(0).unwrap(),
+            )
+            .await,
+        RestakingCoreError::RewardDistributionInvalidProof,
+    );
+
+    // Claiming the leaf of another claimant
+    assert_program_error(
+        restaking_program_client
+            .claim_reward(
+                &reward_distribution,
+                &claimant,
+                &mint,
+                &avs_admin,
+                claims[1].1,
+                tree.proof(1).unwrap(),
+            )
+            .await,
+        RestakingCoreError::RewardDistributionInvalidProof,
+    );
+}
+
+#[tokio::test]
+async fn test_claim_reward_max_total_claim_exceeded_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        claims,
+        tree,
+        reward_distribution,
+    } = setup(&mut fixture, &mut restaking_program_client).await;
+
+    // The distribution is funded for less than the tree owes
+    restaking_program_client
+        .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 2_500, 3)
+        .await
+        .unwrap();
+    restaking_program_client
+        .claim_reward(
+            &reward_distribution,
+            &claims[1].0,
+            &mint,
+            &avs_admin,
+            claims[1].1,
+            tree.proof(1).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .claim_reward(
+                &reward_distribution,
+                &claims[0].0,
+                &mint,
+                &avs_admin,
+                claims[0].1,
+                tree.proof(0).unwrap(),
+            )
+            .await,
+        RestakingCoreError::RewardDistributionMaxTotalClaimExceeded,
+    );
+}
+
+#[tokio::test]
+async fn test_post_reward_root_bad_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        mint,
+        tree,
+        ..
+    } = setup(&mut fixture, &mut restaking_program_client).await;
+
+    let bad_admin = Keypair::new();
+    fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&mint, &bad_admin.pubkey(), 10_000)
+        .await
+        .unwrap();
+    assert_program_error(
+        restaking_program_client
+            .post_reward_root(&config, &avs, &mint, &bad_admin, 1, tree.root(), 6_000, 3)
+            .await,
+        RestakingCoreError::AvsInvalidWithdrawAdmin,
+    );
+}
+
+#[tokio::test]
+async fn test_post_reward_root_twice_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let RewardClaimsSetup {
+        config,
+        avs,
+        avs_admin,
+        mint,
+        tree,
+        ..
+    } = setup(&mut fixture, &mut restaking_program_client).await;
+
+    restaking_program_client
+        .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 3_000, 3)
+        .await
+        .unwrap();
+    fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        restaking_program_client
+            .post_reward_root(&config, &avs, &mint, &avs_admin, 1, tree.root(), 3_000, 3)
+            .await,
+        SanitizationError::EmptyAccountNotEmpty,
+    );
+}
@@ -0,0 +1,174 @@
+use jito_restaking_core::{avs::Avs, config::Config, operator::Operator};
+use jito_restaking_sdk::{AvsAdminRole, OperatorAdminRole};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_avs_set_admin_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let new_admin = Keypair::new();
+    let impostor = Keypair::new();
+    fixture.transfer(&new_admin.pubkey(), 1.0).await.unwrap();
+    fixture.transfer(&impostor.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .avs_set_admin(&avs_pubkey, &avs_admin, &new_admin.pubkey())
+        .await
+        .unwrap();
+
+    // the old admin stays in charge until the transfer is accepted
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.admin(), avs_admin.pubkey());
+    assert_eq!(avs.pending_admin(), Some(new_admin.pubkey()));
+    assert!(restaking_program_client
+        .avs_accept_admin(&avs_pubkey, &impostor)
+        .await
+        .is_err());
+
+    restaking_program_client
+        .avs_accept_admin(&avs_pubkey, &new_admin)
+        .await
+        .unwrap();
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.admin(), new_admin.pubkey());
+    assert_eq!(avs.pending_admin(), None);
+
+    assert!(restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &avs_admin,
+            &avs_admin.pubkey(),
+            AvsAdminRole::Vault,
+        )
+        .await
+        .is_err());
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &new_admin,
+            &new_admin.pubkey(),
+            AvsAdminRole::Vault,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_operator_set_admin_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let new_admin = Keypair::new();
+    fixture.transfer(&new_admin.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .operator_set_admin(&operator_pubkey, &operator_admin, &new_admin.pubkey())
+        .await
+        .unwrap();
+    restaking_program_client
+        .operator_accept_admin(&operator_pubkey, &new_admin)
+        .await
+        .unwrap();
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.admin(), new_admin.pubkey());
+    assert_eq!(operator.pending_admin(), None);
+
+    assert!(restaking_program_client
+        .operator_set_secondary_admin(
+            &operator_pubkey,
+            &operator_admin,
+            &operator_admin.pubkey(),
+            OperatorAdminRole::Vault,
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_config_set_admin_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let new_admin = Keypair::new();
+    fixture.transfer(&new_admin.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .config_set_admin(&config_pubkey, &config_admin, &new_admin.pubkey())
+        .await
+        .unwrap();
+    restaking_program_client
+        .config_accept_admin(&config_pubkey, &new_admin)
+        .await
+        .unwrap();
+
+    let config = restaking_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.admin(), new_admin.pubkey());
+    assert_eq!(config.pending_admin(), None);
+
+    assert!(restaking_program_client
+        .config_set_admin(&config_pubkey, &config_admin, &config_admin.pubkey())
+        .await
+        .is_err());
+}
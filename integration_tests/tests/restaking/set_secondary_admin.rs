@@ -0,0 +1,198 @@
+use jito_restaking_core::{
+    avs::Avs, avs_vault_ticket::AvsVaultTicket, config::Config, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::{AvsAdminRole, OperatorAdminRole};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_avs_set_secondary_admin_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &avs_admin,
+            &vault_admin.pubkey(),
+            AvsAdminRole::Vault,
+        )
+        .await
+        .unwrap();
+
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.admin(), avs_admin.pubkey());
+    assert_eq!(avs.vault_admin(), vault_admin.pubkey());
+    assert_eq!(avs.operator_admin(), avs_admin.pubkey());
+    assert_eq!(avs.metadata_admin(), avs_admin.pubkey());
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+
+    // the admin no longer manages vaults once the role is handed off
+    assert!(restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .is_err());
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &vault_admin,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+
+    // only the admin can hand off roles
+    assert!(restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &vault_admin,
+            &vault_admin.pubkey(),
+            AvsAdminRole::Operator,
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_operator_set_secondary_admin_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator_pubkey, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let vault_admin = Keypair::new();
+    let withdraw_admin = Pubkey::new_unique();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    restaking_program_client
+        .operator_set_secondary_admin(
+            &operator_pubkey,
+            &operator_admin,
+            &vault_admin.pubkey(),
+            OperatorAdminRole::Vault,
+        )
+        .await
+        .unwrap();
+    restaking_program_client
+        .operator_set_secondary_admin(
+            &operator_pubkey,
+            &operator_admin,
+            &withdraw_admin,
+            OperatorAdminRole::Withdraw,
+        )
+        .await
+        .unwrap();
+
+    let operator = restaking_program_client
+        .get_operator(&operator_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(operator.admin(), operator_admin.pubkey());
+    assert_eq!(operator.vault_admin(), vault_admin.pubkey());
+    assert_eq!(operator.withdraw_admin(), withdraw_admin);
+    assert_eq!(operator.avs_admin(), operator_admin.pubkey());
+    assert_eq!(operator.metadata_admin(), operator_admin.pubkey());
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    assert!(restaking_program_client
+        .operator_add_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .is_err());
+    restaking_program_client
+        .operator_add_vault(
+            &config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket,
+            &vault_admin,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+
+    assert!(restaking_program_client
+        .operator_set_secondary_admin(
+            &operator_pubkey,
+            &vault_admin,
+            &vault_admin.pubkey(),
+            OperatorAdminRole::Avs,
+        )
+        .await
+        .is_err());
+}
@@ -0,0 +1,143 @@
+use jito_restaking_core::{avs::Avs, avs_vault_ticket::AvsVaultTicket, config::Config};
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_sweep_tokens_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let vault_pubkey = fixture.create_vault().await.unwrap();
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // Tokens are sent to the ticket and the config by mistake
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &avs_vault_ticket, 1_000)
+        .await
+        .unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &config, 2_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &avs_pubkey)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &config_admin.pubkey())
+        .await
+        .unwrap();
+
+    // The ticket belongs to the AVS, so the config admin can't sweep it
+    assert!(restaking_program_client
+        .sweep_tokens(
+            &config,
+            &avs_pubkey,
+            &avs_vault_ticket,
+            &avs_pubkey,
+            &config_admin,
+            &token_mint.pubkey(),
+        )
+        .await
+        .is_err());
+
+    restaking_program_client
+        .sweep_tokens(
+            &config,
+            &avs_pubkey,
+            &avs_vault_ticket,
+            &avs_pubkey,
+            &avs_admin,
+            &token_mint.pubkey(),
+        )
+        .await
+        .unwrap();
+    let ticket_token_account = fixture
+        .get_token_account(&get_associated_token_address(
+            &avs_vault_ticket,
+            &token_mint.pubkey(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(ticket_token_account.amount, 0);
+    let avs_token_account = fixture
+        .get_token_account(&get_associated_token_address(
+            &avs_pubkey,
+            &token_mint.pubkey(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(avs_token_account.amount, 1_000);
+
+    // The config balance goes to the config admin
+    assert!(restaking_program_client
+        .sweep_tokens(
+            &config,
+            &config,
+            &config,
+            &avs_admin.pubkey(),
+            &avs_admin,
+            &token_mint.pubkey(),
+        )
+        .await
+        .is_err());
+    restaking_program_client
+        .sweep_tokens(
+            &config,
+            &config,
+            &config,
+            &config_admin.pubkey(),
+            &config_admin,
+            &token_mint.pubkey(),
+        )
+        .await
+        .unwrap();
+    let admin_token_account = fixture
+        .get_token_account(&get_associated_token_address(
+            &config_admin.pubkey(),
+            &token_mint.pubkey(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(admin_token_account.amount, 2_000);
+}
@@ -0,0 +1,152 @@
+use jito_restaking_core::{
+    avs::Avs,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket,
+    config::{
+        Config, DEFAULT_TICKET_EPOCH_LENGTH, MAX_TICKET_EPOCH_LENGTH, MIN_TICKET_EPOCH_LENGTH,
+    },
+    result::RestakingCoreError,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_config_set_ticket_epoch_length_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+    let config_account = restaking_program_client.get_config(&config).await.unwrap();
+    assert_eq!(
+        config_account.ticket_epoch_length(),
+        DEFAULT_TICKET_EPOCH_LENGTH
+    );
+
+    restaking_program_client
+        .config_set_ticket_epoch_length(&config, &config_admin, MIN_TICKET_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    let config_account = restaking_program_client.get_config(&config).await.unwrap();
+    assert_eq!(
+        config_account.ticket_epoch_length(),
+        MIN_TICKET_EPOCH_LENGTH
+    );
+
+    // The length is bounded, so warmups can be neither skipped nor made to last forever
+    for ticket_epoch_length in [0, MIN_TICKET_EPOCH_LENGTH - 1, MAX_TICKET_EPOCH_LENGTH + 1] {
+        assert_program_error(
+            restaking_program_client
+                .config_set_ticket_epoch_length(&config, &config_admin, ticket_epoch_length)
+                .await,
+            RestakingCoreError::ConfigInvalidTicketEpochLength,
+        );
+    }
+
+    // Only the config admin can set it
+    let not_admin = Keypair::new();
+    fixture.transfer(&not_admin.pubkey(), 10.0).await.unwrap();
+    assert!(restaking_program_client
+        .config_set_ticket_epoch_length(&config, &not_admin, MAX_TICKET_EPOCH_LENGTH)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_avs_add_vault_slasher_waits_for_warmup() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    let config = graph.restaking_config;
+
+    let config_admin = fixture.upgrade_authority();
+    restaking_program_client
+        .config_set_ticket_epoch_length(&config, &config_admin, MIN_TICKET_EPOCH_LENGTH)
+        .await
+        .unwrap();
+
+    // A second AVS secures the vault of the graph
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let avs_vault_ticket = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &graph.vault,
+    )
+    .0;
+    restaking_program_client
+        .avs_add_vault(
+            &config,
+            &avs_pubkey,
+            &graph.vault,
+            &avs_vault_ticket,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // The AVS vault ticket warms up until the next epoch starts
+    let slasher = Keypair::new();
+    let avs_vault_slasher_ticket = AvsVaultSlasherTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &graph.vault,
+        &slasher.pubkey(),
+    )
+    .0;
+    assert_program_error(
+        restaking_program_client
+            .avs_add_vault_slasher(
+                &config,
+                &avs_pubkey,
+                &graph.vault,
+                &slasher.pubkey(),
+                &avs_vault_ticket,
+                &avs_vault_slasher_ticket,
+                &avs_admin,
+                &avs_admin,
+                1_000,
+                [0; 32],
+            )
+            .await,
+        RestakingCoreError::AvsVaultTicketWarmingUp,
+    );
+
+    let slot = fixture.get_slot().await.unwrap();
+    fixture
+        .warp_to_slot((slot / MIN_TICKET_EPOCH_LENGTH + 1) * MIN_TICKET_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_add_vault_slasher(
+            &config,
+            &avs_pubkey,
+            &graph.vault,
+            &slasher.pubkey(),
+            &avs_vault_ticket,
+            &avs_vault_slasher_ticket,
+            &avs_admin,
+            &avs_admin,
+            1_000,
+            [0; 32],
+        )
+        .await
+        .unwrap();
+}
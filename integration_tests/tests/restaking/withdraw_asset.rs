@@ -0,0 +1,244 @@
+use jito_restaking_core::{
+    avs::Avs, config::Config, operator::Operator, result::RestakingCoreError,
+};
+use jito_restaking_sdk::{error::RestakingError, AvsAdminRole, OperatorAdminRole};
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_avs_withdrawal_asset_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&config, &avs, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    let withdraw_admin = Keypair::new();
+    fixture
+        .transfer(&withdraw_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &avs,
+            &avs_admin,
+            &withdraw_admin.pubkey(),
+            AvsAdminRole::Withdraw,
+        )
+        .await
+        .unwrap();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &avs, 1_000)
+        .await
+        .unwrap();
+    let receiver = Keypair::new();
+    fixture
+        .create_ata(&token_mint.pubkey(), &receiver.pubkey())
+        .await
+        .unwrap();
+    let avs_token_account = get_associated_token_address(&avs, &token_mint.pubkey());
+    let receiver_token_account =
+        get_associated_token_address(&receiver.pubkey(), &token_mint.pubkey());
+
+    // the AVS admin no longer holds the withdraw role
+    assert_program_error(
+        restaking_program_client
+            .avs_withdrawal_asset(
+                &avs,
+                &avs_token_account,
+                &receiver_token_account,
+                &avs_admin,
+                &spl_token::id(),
+                token_mint.pubkey(),
+                400,
+            )
+            .await,
+        RestakingCoreError::AvsInvalidWithdrawAdmin,
+    );
+
+    restaking_program_client
+        .avs_withdrawal_asset(
+            &avs,
+            &avs_token_account,
+            &receiver_token_account,
+            &withdraw_admin,
+            &spl_token::id(),
+            token_mint.pubkey(),
+            400,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture
+            .get_token_account(&avs_token_account)
+            .await
+            .unwrap()
+            .amount,
+        600
+    );
+    assert_eq!(
+        fixture
+            .get_token_account(&receiver_token_account)
+            .await
+            .unwrap()
+            .amount,
+        400
+    );
+
+    assert_program_error(
+        restaking_program_client
+            .avs_withdrawal_asset(
+                &avs,
+                &avs_token_account,
+                &receiver_token_account,
+                &withdraw_admin,
+                &spl_token::id(),
+                token_mint.pubkey(),
+                601,
+            )
+            .await,
+        RestakingError::AvsInsufficientFunds,
+    );
+}
+
+#[tokio::test]
+async fn test_operator_withdrawal_asset_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    let config = Config::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let operator =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(&config, &operator, &operator_admin, &operator_base)
+        .await
+        .unwrap();
+
+    let withdraw_admin = Keypair::new();
+    fixture
+        .transfer(&withdraw_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .operator_set_secondary_admin(
+            &operator,
+            &operator_admin,
+            &withdraw_admin.pubkey(),
+            OperatorAdminRole::Withdraw,
+        )
+        .await
+        .unwrap();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &operator, 1_000)
+        .await
+        .unwrap();
+    let receiver = Keypair::new();
+    fixture
+        .create_ata(&token_mint.pubkey(), &receiver.pubkey())
+        .await
+        .unwrap();
+    let operator_token_account = get_associated_token_address(&operator, &token_mint.pubkey());
+    let receiver_token_account =
+        get_associated_token_address(&receiver.pubkey(), &token_mint.pubkey());
+
+    // the operator admin no longer holds the withdraw role
+    assert_program_error(
+        restaking_program_client
+            .operator_withdrawal_asset(
+                &operator,
+                &operator_admin,
+                &operator_token_account,
+                &receiver_token_account,
+                &spl_token::id(),
+                token_mint.pubkey(),
+                1_000,
+            )
+            .await,
+        RestakingCoreError::OperatorInvalidWithdrawAdmin,
+    );
+
+    restaking_program_client
+        .operator_withdrawal_asset(
+            &operator,
+            &withdraw_admin,
+            &operator_token_account,
+            &receiver_token_account,
+            &spl_token::id(),
+            token_mint.pubkey(),
+            1_000,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture
+            .get_token_account(&operator_token_account)
+            .await
+            .unwrap()
+            .amount,
+        0
+    );
+    assert_eq!(
+        fixture
+            .get_token_account(&receiver_token_account)
+            .await
+            .unwrap()
+            .amount,
+        1_000
+    );
+
+    assert_program_error(
+        restaking_program_client
+            .operator_withdrawal_asset(
+                &operator,
+                &withdraw_admin,
+                &operator_token_account,
+                &receiver_token_account,
+                &spl_token::id(),
+                token_mint.pubkey(),
+                1,
+            )
+            .await,
+        RestakingError::OperatorInsufficientFunds,
+    );
+}
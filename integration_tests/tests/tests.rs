@@ -1,3 +1,4 @@
+mod compute_units;
 mod fixtures;
 mod restaking;
 mod vault;
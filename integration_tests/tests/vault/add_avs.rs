@@ -23,7 +23,7 @@ async fn test_add_avs_ok() {
         .unwrap();
 
     let vault_config_pubkey = VaultConfig::find_program_address(&jito_vault_program::id()).0;
-    let vault_config_admin = Keypair::new();
+    let vault_config_admin = fixture.upgrade_authority();
 
     fixture
         .transfer(&vault_config_admin.pubkey(), 1.0)
@@ -63,7 +63,7 @@ async fn test_add_avs_ok() {
 
     let restaking_config_pubkey =
         RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
-    let restaking_config_admin = Keypair::new();
+    let restaking_config_admin = fixture.upgrade_authority();
 
     fixture
         .transfer(&restaking_config_admin.pubkey(), 1.0)
@@ -125,4 +125,7 @@ async fn test_add_avs_ok() {
     assert_eq!(vault_avs_ticket_account.avs(), avs_pubkey);
     assert_eq!(vault_avs_ticket_account.index(), 0);
     assert_eq!(vault_avs_ticket_account.state().slot_added(), 1);
+    fixture
+        .assert_rent_exempt(&[vault_pubkey, vault_avs_ticket])
+        .await;
 }
@@ -0,0 +1,356 @@
+use jito_restaking_core::{
+    config::Config as RestakingConfig, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    config::Config as VaultConfig, result::VaultCoreError, vault::Vault,
+    vault_delegation_list::VaultDelegationList, vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+struct DelegationSetup {
+    fixture: TestBuilder,
+    restaking_config: Pubkey,
+    vault_config: Pubkey,
+    vault: Pubkey,
+    vault_admin: Keypair,
+    vault_delegation_list: Pubkey,
+    operator: Pubkey,
+    operator_admin: Keypair,
+    vault_operator_ticket: Pubkey,
+    operator_vault_ticket: Pubkey,
+}
+
+/// Sets up a vault holding 100_000 deposited tokens and an operator opted in to it both ways
+async fn setup() -> DelegationSetup {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_admin = fixture.upgrade_authority();
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    let restaking_config = RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
+    restaking_program_client
+        .initialize_config(&restaking_config, &config_admin)
+        .await
+        .unwrap();
+    let vault_config = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+    vault_program_client
+        .initialize_config(&vault_config, &config_admin)
+        .await
+        .unwrap();
+
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    let operator =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(
+            &restaking_config,
+            &operator,
+            &operator_admin,
+            &operator_base,
+        )
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault = Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault).0;
+    let lrt_mint = Keypair::new();
+    let token_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &vault_config,
+            &vault,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    let operator_vault_ticket =
+        OperatorVaultTicket::find_program_address(&jito_restaking_program::id(), &operator, &vault)
+            .0;
+    restaking_program_client
+        .operator_add_vault(
+            &restaking_config,
+            &operator,
+            &vault,
+            &operator_vault_ticket,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+    let vault_operator_ticket =
+        VaultOperatorTicket::find_program_address(&jito_vault_program::id(), &vault, &operator).0;
+    vault_program_client
+        .add_operator(
+            &vault_config,
+            &vault,
+            &operator,
+            &operator_vault_ticket,
+            &vault_operator_ticket,
+            &vault_admin,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault)
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &vault,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &token_mint.pubkey()),
+            &get_associated_token_address(&vault, &token_mint.pubkey()),
+            &get_associated_token_address(&depositor.pubkey(), &lrt_mint.pubkey()),
+            &get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey()),
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    DelegationSetup {
+        fixture,
+        restaking_config,
+        vault_config,
+        vault,
+        vault_admin,
+        vault_delegation_list,
+        operator,
+        operator_admin,
+        vault_operator_ticket,
+        operator_vault_ticket,
+    }
+}
+
+#[tokio::test]
+async fn test_add_delegation_ok() {
+    let mut setup = setup().await;
+    let mut vault_program_client = setup.fixture.vault_program_client();
+
+    // the vault operator ticket warms up until the next epoch starts
+    assert_program_error(
+        vault_program_client
+            .add_delegation(
+                &setup.vault_config,
+                &setup.vault,
+                &setup.operator,
+                &setup.vault_operator_ticket,
+                &setup.operator_vault_ticket,
+                &setup.vault_delegation_list,
+                &setup.vault_admin,
+                &setup.vault_admin,
+                10_000,
+            )
+            .await,
+        VaultCoreError::VaultOperatorTicketWarmingUp,
+    );
+
+    setup.fixture.warp_past_ticket_warmup().await.unwrap();
+    vault_program_client
+        .add_delegation(
+            &setup.vault_config,
+            &setup.vault,
+            &setup.operator,
+            &setup.vault_operator_ticket,
+            &setup.operator_vault_ticket,
+            &setup.vault_delegation_list,
+            &setup.vault_admin,
+            &setup.vault_admin,
+            10_000,
+        )
+        .await
+        .unwrap();
+
+    let vault_delegation_list = vault_program_client
+        .get_vault_delegation_list(&setup.vault_delegation_list)
+        .await
+        .unwrap();
+    let delegations = vault_delegation_list.delegations();
+    assert_eq!(delegations.len(), 1);
+    assert_eq!(delegations[0].operator(), setup.operator);
+    assert_eq!(delegations[0].active_amount(), 10_000);
+    setup
+        .fixture
+        .assert_rent_exempt(&[setup.vault_delegation_list])
+        .await;
+
+    // can't delegate more than is deposited
+    assert!(vault_program_client
+        .add_delegation(
+            &setup.vault_config,
+            &setup.vault,
+            &setup.operator,
+            &setup.vault_operator_ticket,
+            &setup.operator_vault_ticket,
+            &setup.vault_delegation_list,
+            &setup.vault_admin,
+            &setup.vault_admin,
+            90_001,
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_add_delegation_operator_removed_vault_fails() {
+    let mut setup = setup().await;
+    let mut restaking_program_client = setup.fixture.restaking_program_client();
+    let mut vault_program_client = setup.fixture.vault_program_client();
+
+    setup.fixture.warp_to_next_slot().await.unwrap();
+    restaking_program_client
+        .operator_remove_vault(
+            &setup.restaking_config,
+            &setup.operator,
+            &setup.vault,
+            &setup.operator_vault_ticket,
+            &setup.operator_admin,
+        )
+        .await
+        .unwrap();
+
+    // the vault still lists the operator, but the operator no longer accepts its stake
+    assert!(vault_program_client
+        .add_delegation(
+            &setup.vault_config,
+            &setup.vault,
+            &setup.operator,
+            &setup.vault_operator_ticket,
+            &setup.operator_vault_ticket,
+            &setup.vault_delegation_list,
+            &setup.vault_admin,
+            &setup.vault_admin,
+            10_000,
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_add_delegation_blacklisted_operator_fails() {
+    let mut setup = setup().await;
+    let mut vault_program_client = setup.fixture.vault_program_client();
+    setup.fixture.warp_past_ticket_warmup().await.unwrap();
+
+    vault_program_client
+        .blacklist_operator(&setup.vault, &setup.operator, &setup.vault_admin)
+        .await
+        .unwrap();
+    let vault_operator_blacklist = vault_program_client
+        .get_vault_operator_blacklist(&setup.vault)
+        .await
+        .unwrap();
+    assert_eq!(vault_operator_blacklist.vault(), setup.vault);
+    assert_eq!(vault_operator_blacklist.operators(), &[setup.operator]);
+    let vault_operator_blacklist_pubkey =
+        VaultOperatorBlacklist::find_program_address(&jito_vault_program::id(), &setup.vault).0;
+    setup
+        .fixture
+        .assert_rent_exempt(&[vault_operator_blacklist_pubkey])
+        .await;
+
+    // both tickets are active, but the vault refuses to delegate to the operator
+    assert!(vault_program_client
+        .add_delegation(
+            &setup.vault_config,
+            &setup.vault,
+            &setup.operator,
+            &setup.vault_operator_ticket,
+            &setup.operator_vault_ticket,
+            &setup.vault_delegation_list,
+            &setup.vault_admin,
+            &setup.vault_admin,
+            10_000,
+        )
+        .await
+        .is_err());
+
+    vault_program_client
+        .unblacklist_operator(&setup.vault, &setup.operator, &setup.vault_admin)
+        .await
+        .unwrap();
+    vault_program_client
+        .add_delegation(
+            &setup.vault_config,
+            &setup.vault,
+            &setup.operator,
+            &setup.vault_operator_ticket,
+            &setup.operator_vault_ticket,
+            &setup.vault_delegation_list,
+            &setup.vault_admin,
+            &setup.vault_admin,
+            10_000,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_blacklist_operator_bad_delegation_admin_fails() {
+    let mut setup = setup().await;
+    let mut vault_program_client = setup.fixture.vault_program_client();
+
+    let bad_admin = Keypair::new();
+    setup
+        .fixture
+        .transfer(&bad_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    assert!(vault_program_client
+        .blacklist_operator(&setup.vault, &setup.operator, &bad_admin)
+        .await
+        .is_err());
+
+    vault_program_client
+        .blacklist_operator(&setup.vault, &setup.operator, &setup.vault_admin)
+        .await
+        .unwrap();
+    assert!(vault_program_client
+        .unblacklist_operator(&setup.vault, &setup.operator, &bad_admin)
+        .await
+        .is_err());
+}
@@ -24,7 +24,7 @@ async fn test_add_operator_ok() {
         .unwrap();
 
     let vault_config_pubkey = VaultConfig::find_program_address(&jito_vault_program::id()).0;
-    let vault_config_admin = Keypair::new();
+    let vault_config_admin = fixture.upgrade_authority();
 
     fixture
         .transfer(&vault_config_admin.pubkey(), 1.0)
@@ -64,7 +64,7 @@ async fn test_add_operator_ok() {
 
     let restaking_config_pubkey =
         RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
-    let restaking_config_admin = Keypair::new();
+    let restaking_config_admin = fixture.upgrade_authority();
 
     fixture
         .transfer(&restaking_config_admin.pubkey(), 1.0)
@@ -130,6 +130,9 @@ async fn test_add_operator_ok() {
         )
         .await
         .unwrap();
+    fixture
+        .assert_rent_exempt(&[vault_pubkey, vault_operator_ticket])
+        .await;
 
     let vault_operator_ticket = vault_program_client
         .get_vault_operator_ticket(&vault_pubkey, &operator_pubkey)
@@ -17,7 +17,7 @@ async fn test_add_slasher_ok() {
     let mut vault_program_client = fixture.vault_program_client();
 
     // Initialize restaking config
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
     let restaking_config = RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
     fixture
         .transfer(&config_admin.pubkey(), 10.0)
@@ -40,7 +40,7 @@ async fn test_add_slasher_ok() {
 
     // Initialize vault config
     let vault_config_pubkey = VaultConfig::find_program_address(&jito_vault_program::id()).0;
-    let vault_config_admin = Keypair::new();
+    let vault_config_admin = fixture.upgrade_authority();
     fixture
         .transfer(&vault_config_admin.pubkey(), 1.0)
         .await
@@ -114,6 +114,8 @@ async fn test_add_slasher_ok() {
         )
         .await
         .unwrap();
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let slot = fixture.get_slot().await.unwrap();
 
     // AVS adds slasher
     let slasher = Keypair::new();
@@ -136,6 +138,7 @@ async fn test_add_slasher_ok() {
             &avs_admin,
             &avs_admin,
             100,
+            [0; 32],
         )
         .await
         .unwrap();
@@ -172,5 +175,8 @@ async fn test_add_slasher_ok() {
     assert_eq!(vault_avs_slasher.slasher(), slasher.pubkey());
     assert_eq!(vault_avs_slasher.index(), 0);
     assert_eq!(vault_avs_slasher.max_slashable_per_epoch(), 100);
-    assert_eq!(vault_avs_slasher.state().slot_added(), 1);
+    assert_eq!(vault_avs_slasher.state().slot_added(), slot);
+    fixture
+        .assert_rent_exempt(&[vault_pubkey, vault_slasher_ticket_pubkey])
+        .await;
 }
@@ -0,0 +1,357 @@
+use jito_vault_core::{
+    config::Config, result::VaultCoreError, vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::ConfigParameter;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+const EPOCH_LENGTH: u64 = 864_000;
+
+/// Sets up a vault with a 1% withdrawal fee and a staker holding 100_000 LRT
+async fn setup() -> (TestBuilder, Pubkey, Keypair, Keypair) {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            100,
+        )
+        .await
+        .unwrap();
+
+    let staker = Keypair::new();
+    fixture.transfer(&staker.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &staker.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &staker.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    vault_program_client
+        .mint_to(
+            &vault_pubkey,
+            &lrt_mint.pubkey(),
+            &staker,
+            &get_associated_token_address(&staker.pubkey(), &token_mint.pubkey()),
+            &get_associated_token_address(&vault_pubkey, &token_mint.pubkey()),
+            &get_associated_token_address(&staker.pubkey(), &lrt_mint.pubkey()),
+            &get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey()),
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    (fixture, vault_pubkey, staker, vault_admin)
+}
+
+#[tokio::test]
+async fn test_burn_withdrawal_ticket_ok() {
+    let (mut fixture, vault_pubkey, staker, vault_admin) = setup().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    let (lrt_mint, token_mint) = (vault.lrt_mint(), vault.supported_mint());
+
+    let base = Keypair::new();
+    vault_program_client
+        .enqueue_withdrawal(&vault_pubkey, &staker, &base, None, 10_000)
+        .await
+        .unwrap();
+
+    let ticket = vault_program_client
+        .get_vault_staker_withdrawal_ticket(&vault_pubkey, &staker.pubkey(), &base.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(ticket.lrt_amount(), 10_000);
+    assert_eq!(ticket.sequence(), 0);
+    let ticket_pubkey = VaultStakerWithdrawalTicket::find_program_address(
+        &jito_vault_program::id(),
+        &vault_pubkey,
+        &staker.pubkey(),
+        &base.pubkey(),
+    )
+    .0;
+    let ticket_token_account = get_associated_token_address(&ticket_pubkey, &lrt_mint);
+    assert_eq!(
+        fixture
+            .get_token_account(&ticket_token_account)
+            .await
+            .unwrap()
+            .amount,
+        10_000
+    );
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(&staker.pubkey(), &lrt_mint))
+            .await
+            .unwrap()
+            .amount,
+        90_000
+    );
+
+    // the ticket can't be redeemed before the next epoch
+    assert!(vault_program_client
+        .burn_withdrawal_ticket(&staker, &vault_pubkey, &staker.pubkey(), &base.pubkey())
+        .await
+        .is_err());
+
+    fixture.warp_slot_incremental(EPOCH_LENGTH).await.unwrap();
+    let cranker = Keypair::new();
+    fixture.transfer(&cranker.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .burn_withdrawal_ticket(&cranker, &vault_pubkey, &staker.pubkey(), &base.pubkey())
+        .await
+        .unwrap();
+
+    // 9_900 LRT burned after the 1% fee, at one token per LRT
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(&staker.pubkey(), &token_mint))
+            .await
+            .unwrap()
+            .amount,
+        9_900
+    );
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_admin.pubkey(),
+                &lrt_mint
+            ))
+            .await
+            .unwrap()
+            .amount,
+        100
+    );
+    assert_eq!(fixture.get_lamports(&ticket_pubkey).await.unwrap(), 0);
+    assert_eq!(
+        fixture.get_lamports(&ticket_token_account).await.unwrap(),
+        0
+    );
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.withdrawal_queue_head(), 1);
+    assert_eq!(vault.lrt_supply(), 90_100);
+    assert_eq!(vault.tokens_deposited(), 90_100);
+    assert_eq!(fixture.get_mint(&lrt_mint).await.unwrap().supply, 90_100);
+}
+
+#[tokio::test]
+async fn test_burn_withdrawal_ticket_out_of_order_fails() {
+    let (mut fixture, vault_pubkey, staker, _) = setup().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let first_base = Keypair::new();
+    let second_base = Keypair::new();
+    vault_program_client
+        .enqueue_withdrawal(&vault_pubkey, &staker, &first_base, None, 1_000)
+        .await
+        .unwrap();
+    vault_program_client
+        .enqueue_withdrawal(&vault_pubkey, &staker, &second_base, None, 2_000)
+        .await
+        .unwrap();
+    let second_ticket = vault_program_client
+        .get_vault_staker_withdrawal_ticket(&vault_pubkey, &staker.pubkey(), &second_base.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(second_ticket.sequence(), 1);
+
+    fixture.warp_slot_incremental(EPOCH_LENGTH).await.unwrap();
+
+    // the second ticket waits for the first one
+    assert!(vault_program_client
+        .burn_withdrawal_ticket(
+            &staker,
+            &vault_pubkey,
+            &staker.pubkey(),
+            &second_base.pubkey()
+        )
+        .await
+        .is_err());
+    vault_program_client
+        .burn_withdrawal_ticket(
+            &staker,
+            &vault_pubkey,
+            &staker.pubkey(),
+            &first_base.pubkey(),
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .burn_withdrawal_ticket(
+            &staker,
+            &vault_pubkey,
+            &staker.pubkey(),
+            &second_base.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.withdrawal_queue_head(), 2);
+    assert_eq!(vault.withdrawal_queue_tail(), 2);
+}
+
+#[tokio::test]
+async fn test_burn_withdrawal_ticket_waits_cooldown_epochs() {
+    let (mut fixture, vault_pubkey, staker, _) = setup().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    vault_program_client
+        .config_set_parameter(
+            &config_pubkey,
+            &fixture.upgrade_authority(),
+            ConfigParameter::WithdrawalCooldownEpochs(2),
+        )
+        .await
+        .unwrap();
+
+    let base = Keypair::new();
+    vault_program_client
+        .enqueue_withdrawal(&vault_pubkey, &staker, &base, None, 1_000)
+        .await
+        .unwrap();
+
+    // one epoch boundary isn't enough anymore
+    fixture.warp_slot_incremental(EPOCH_LENGTH).await.unwrap();
+    assert_program_error(
+        vault_program_client
+            .burn_withdrawal_ticket(&staker, &vault_pubkey, &staker.pubkey(), &base.pubkey())
+            .await,
+        VaultCoreError::VaultStakerWithdrawalTicketNotWithdrawable,
+    );
+
+    fixture.warp_slot_incremental(EPOCH_LENGTH).await.unwrap();
+    vault_program_client
+        .burn_withdrawal_ticket(&staker, &vault_pubkey, &staker.pubkey(), &base.pubkey())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_burn_withdrawal_ticket_emergency_mode_pro_rata_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let staker = Keypair::new();
+    fixture.transfer(&staker.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &staker.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.lrt_mint.pubkey(), &staker.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &graph.vault,
+            &graph.lrt_mint.pubkey(),
+            &staker,
+            &get_associated_token_address(&staker.pubkey(), &graph.token_mint.pubkey()),
+            &graph.vault_token_account,
+            &get_associated_token_address(&staker.pubkey(), &graph.lrt_mint.pubkey()),
+            &graph.vault_fee_token_account,
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .add_delegation(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.operator,
+            &graph.vault_operator_ticket,
+            &graph.operator_vault_ticket,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+            &graph.vault_admin,
+            60_000,
+        )
+        .await
+        .unwrap();
+
+    let base = Keypair::new();
+    vault_program_client
+        .enqueue_withdrawal(&graph.vault, &staker, &base, None, 10_000)
+        .await
+        .unwrap();
+    vault_program_client
+        .set_emergency_mode(
+            &graph.vault_config,
+            &graph.vault,
+            &fixture.upgrade_authority(),
+            true,
+        )
+        .await
+        .unwrap();
+
+    fixture.warp_slot_incremental(EPOCH_LENGTH).await.unwrap();
+    vault_program_client
+        .burn_withdrawal_ticket(&staker, &graph.vault, &staker.pubkey(), &base.pubkey())
+        .await
+        .unwrap();
+
+    // 40% of the deposits aren't delegated, so 10% of the supply is redeemed for 4% of them
+    assert_eq!(
+        fixture
+            .get_token_account(&get_associated_token_address(
+                &staker.pubkey(),
+                &graph.token_mint.pubkey()
+            ))
+            .await
+            .unwrap()
+            .amount,
+        4_000
+    );
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.lrt_supply(), 90_000);
+    assert_eq!(vault.tokens_deposited(), 96_000);
+}
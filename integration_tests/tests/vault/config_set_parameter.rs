@@ -0,0 +1,114 @@
+use jito_vault_core::{
+    config::{
+        Config, DEFAULT_WITHDRAWAL_COOLDOWN_EPOCHS, MAX_WITHDRAWAL_COOLDOWN_EPOCHS,
+        MIN_EPOCH_LENGTH,
+    },
+    result::VaultCoreError,
+};
+use jito_vault_sdk::ConfigParameter;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+async fn setup() -> (TestBuilder, VaultProgramClient, Pubkey, Keypair) {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    (fixture, vault_program_client, config_pubkey, config_admin)
+}
+
+#[tokio::test]
+async fn test_config_set_parameter_ok() {
+    let (_fixture, mut vault_program_client, config_pubkey, config_admin) = setup().await;
+
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        config.withdrawal_cooldown_epochs(),
+        DEFAULT_WITHDRAWAL_COOLDOWN_EPOCHS
+    );
+
+    for parameter in [
+        ConfigParameter::EpochLength(MIN_EPOCH_LENGTH),
+        ConfigParameter::WithdrawalFeeGraceEpochs(3),
+        ConfigParameter::WithdrawalCooldownEpochs(MAX_WITHDRAWAL_COOLDOWN_EPOCHS),
+    ] {
+        vault_program_client
+            .config_set_parameter(&config_pubkey, &config_admin, parameter)
+            .await
+            .unwrap();
+    }
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.epoch_length(), MIN_EPOCH_LENGTH);
+    assert_eq!(config.withdrawal_fee_grace_epochs(), 3);
+    assert_eq!(
+        config.withdrawal_cooldown_epochs(),
+        MAX_WITHDRAWAL_COOLDOWN_EPOCHS
+    );
+}
+
+#[tokio::test]
+async fn test_config_set_parameter_out_of_bounds_fails() {
+    let (_fixture, mut vault_program_client, config_pubkey, config_admin) = setup().await;
+
+    for (parameter, error) in [
+        (
+            ConfigParameter::EpochLength(MIN_EPOCH_LENGTH - 1),
+            VaultCoreError::ConfigInvalidEpochLength,
+        ),
+        (
+            ConfigParameter::WithdrawalFeeGraceEpochs(0),
+            VaultCoreError::ConfigInvalidWithdrawalFeeGraceEpochs,
+        ),
+        (
+            ConfigParameter::WithdrawalCooldownEpochs(0),
+            VaultCoreError::ConfigInvalidWithdrawalCooldownEpochs,
+        ),
+        (
+            ConfigParameter::WithdrawalCooldownEpochs(MAX_WITHDRAWAL_COOLDOWN_EPOCHS + 1),
+            VaultCoreError::ConfigInvalidWithdrawalCooldownEpochs,
+        ),
+    ] {
+        assert_program_error(
+            vault_program_client
+                .config_set_parameter(&config_pubkey, &config_admin, parameter)
+                .await,
+            error,
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_config_set_parameter_not_admin_fails() {
+    let (mut fixture, mut vault_program_client, config_pubkey, _) = setup().await;
+
+    let attacker = Keypair::new();
+    fixture.transfer(&attacker.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        vault_program_client
+            .config_set_parameter(
+                &config_pubkey,
+                &attacker,
+                ConfigParameter::WithdrawalCooldownEpochs(2),
+            )
+            .await,
+        VaultCoreError::ConfigInvalidAdmin,
+    );
+}
@@ -0,0 +1,103 @@
+use jito_vault_core::{
+    config::{Config, DEFAULT_EPOCH_LENGTH},
+    result::VaultCoreError,
+    vault::{Vault, MAX_CRANK_TIP_LAMPORTS},
+    vault_delegation_list::VaultDelegationList,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_update_delegations_pays_crank_tip_once_per_epoch() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            100,
+        )
+        .await
+        .unwrap();
+
+    assert_program_error(
+        vault_program_client
+            .set_crank_tip(&vault_pubkey, &vault_admin, MAX_CRANK_TIP_LAMPORTS + 1)
+            .await,
+        VaultCoreError::VaultCrankTipTooHigh,
+    );
+    let tip = 5_000;
+    vault_program_client
+        .set_crank_tip(&vault_pubkey, &vault_admin, tip)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.crank_tip_lamports(), tip);
+
+    // tips are funded by lamports sent to the vault on top of its rent-exempt minimum
+    fixture.transfer(&vault_pubkey, 0.001).await.unwrap();
+
+    let cranker = Keypair::new();
+    fixture.transfer(&cranker.pubkey(), 1.0).await.unwrap();
+    fixture
+        .warp_slot_incremental(DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    let vault_lamports = fixture.get_lamports(&vault_pubkey).await.unwrap();
+    vault_program_client
+        .update_delegations(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &cranker,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.get_lamports(&vault_pubkey).await.unwrap(),
+        vault_lamports - tip
+    );
+
+    // the delegations are already up to date for the epoch, so there's nothing to tip
+    fixture.warp_to_next_slot().await.unwrap();
+    vault_program_client
+        .update_delegations(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &cranker,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.get_lamports(&vault_pubkey).await.unwrap(),
+        vault_lamports - tip
+    );
+}
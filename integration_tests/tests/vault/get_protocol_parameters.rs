@@ -0,0 +1,113 @@
+use jito_vault_core::{
+    config::{Config, DEFAULT_EPOCH_LENGTH, DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS},
+    vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+};
+use jito_vault_sdk::protocol_parameters::ProtocolParameters;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_get_protocol_parameters_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            50,
+        )
+        .await
+        .unwrap();
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    let parameters = vault_program_client
+        .get_protocol_parameters(&config_pubkey, &vault_pubkey, &vault_admin)
+        .await
+        .unwrap();
+    assert_eq!(
+        parameters,
+        ProtocolParameters {
+            epoch_length: DEFAULT_EPOCH_LENGTH,
+            epoch: 0,
+            withdrawal_fee_grace_epochs: DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS,
+            deposit_fee_bps: 100,
+            withdrawal_fee_bps: 50,
+            pending_withdrawal_fee: None,
+            capacity: vault.capacity(),
+            tokens_deposited: 0,
+            lrt_supply: 0,
+            emergency_mode: false,
+            mint_burn_authority: None,
+            program_fee_bps: 0,
+            reward_fee_bps: 0,
+            pending_fees: None,
+        }
+    );
+
+    // a raised withdrawal fee is reported as pending until its grace window passes
+    vault_program_client
+        .set_withdrawal_fee(&config_pubkey, &vault_pubkey, &vault_admin, 80)
+        .await
+        .unwrap();
+    vault_program_client
+        .set_emergency_mode(&config_pubkey, &vault_pubkey, &config_admin, true)
+        .await
+        .unwrap();
+    let parameters = vault_program_client
+        .get_protocol_parameters(&config_pubkey, &vault_pubkey, &vault_admin)
+        .await
+        .unwrap();
+    assert_eq!(parameters.withdrawal_fee_bps, 50);
+    assert_eq!(
+        parameters.pending_withdrawal_fee,
+        Some((80, DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS))
+    );
+    assert!(parameters.emergency_mode);
+}
+
+#[tokio::test]
+async fn test_get_protocol_parameters_invalid_vault_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    assert!(vault_program_client
+        .get_protocol_parameters(&config_pubkey, &config_pubkey, &config_admin)
+        .await
+        .is_err());
+}
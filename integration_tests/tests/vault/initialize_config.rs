@@ -9,7 +9,7 @@ async fn test_initialize_config_ok() {
     let mut vault_program_client = fixture.vault_program_client();
 
     let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
 
     fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
 
@@ -18,6 +18,8 @@ async fn test_initialize_config_ok() {
         .await
         .unwrap();
 
+    fixture.assert_rent_exempt(&[config_pubkey]).await;
+
     let config = vault_program_client
         .get_config(&config_pubkey)
         .await
@@ -28,3 +30,18 @@ async fn test_initialize_config_ok() {
     assert_eq!(config.epoch_length(), 864_000);
     assert_eq!(config.vaults_count(), 0);
 }
+
+#[tokio::test]
+async fn test_initialize_config_not_upgrade_authority_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let attacker = Keypair::new();
+    fixture.transfer(&attacker.pubkey(), 1.0).await.unwrap();
+
+    assert!(vault_program_client
+        .initialize_config(&config_pubkey, &attacker)
+        .await
+        .is_err());
+}
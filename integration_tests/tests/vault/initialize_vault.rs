@@ -15,7 +15,7 @@ async fn test_initialize_vault_ok() {
         .unwrap();
 
     let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
-    let config_admin = Keypair::new();
+    let config_admin = fixture.upgrade_authority();
 
     fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
 
@@ -69,4 +69,7 @@ async fn test_initialize_vault_ok() {
     assert_eq!(vault.avs_count(), 0);
     assert_eq!(vault.operator_count(), 0);
     assert_eq!(vault.slasher_count(), 0);
+    fixture
+        .assert_rent_exempt(&[config_pubkey, vault_pubkey, vault_delegation_list])
+        .await;
 }
@@ -0,0 +1,149 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_vault_core::{
+    config::Config, result::VaultCoreError, vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+async fn setup() -> (TestBuilder, Pubkey, Pubkey, Keypair) {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            100,
+        )
+        .await
+        .unwrap();
+
+    (fixture, vault_pubkey, vault_delegation_list, vault_admin)
+}
+
+#[tokio::test]
+async fn test_migrate_unversioned_vault_ok() {
+    let (mut fixture, vault_pubkey, _, vault_admin) = setup().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    // accounts written before versioning read as version 0
+    let current = fixture.get_account_data(&vault_pubkey).await.unwrap();
+    let mut unversioned = current.clone();
+    Vault::load_mut(&mut unversioned).unwrap().set_version(0);
+    fixture
+        .set_account_data(&vault_pubkey, &unversioned)
+        .await
+        .unwrap();
+
+    // anyone can migrate
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .migrate_account(&vault_pubkey, &payer)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.get_account_data(&vault_pubkey).await.unwrap(),
+        current
+    );
+
+    assert_program_error(
+        vault_program_client
+            .migrate_account(&vault_pubkey, &vault_admin)
+            .await,
+        VaultError::AccountAlreadyMigrated,
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_unversioned_delegation_list_ok() {
+    let (mut fixture, _, vault_delegation_list, vault_admin) = setup().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let current = fixture
+        .get_account_data(&vault_delegation_list)
+        .await
+        .unwrap();
+    let mut list = VaultDelegationList::deserialize(&mut current.as_slice()).unwrap();
+    list.set_version(0);
+    let mut unversioned = current.clone();
+    let serialized = list.try_to_vec().unwrap();
+    unversioned[..serialized.len()].copy_from_slice(&serialized);
+    fixture
+        .set_account_data(&vault_delegation_list, &unversioned)
+        .await
+        .unwrap();
+
+    vault_program_client
+        .migrate_account(&vault_delegation_list, &vault_admin)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture
+            .get_account_data(&vault_delegation_list)
+            .await
+            .unwrap(),
+        current
+    );
+}
+
+#[tokio::test]
+async fn test_newer_version_fails() {
+    let (mut fixture, vault_pubkey, _, vault_admin) = setup().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let mut data = fixture.get_account_data(&vault_pubkey).await.unwrap();
+    Vault::load_mut(&mut data)
+        .unwrap()
+        .set_version(Vault::VERSION + 1);
+    fixture
+        .set_account_data(&vault_pubkey, &data)
+        .await
+        .unwrap();
+
+    // an account written by a newer program is neither read nor downgraded
+    assert_program_error(
+        vault_program_client
+            .set_crank_tip(&vault_pubkey, &vault_admin, 0)
+            .await,
+        VaultCoreError::VaultInvalidData(String::new()),
+    );
+    assert_program_error(
+        vault_program_client
+            .migrate_account(&vault_pubkey, &vault_admin)
+            .await,
+        VaultCoreError::AccountVersionUnsupported,
+    );
+}
@@ -0,0 +1,352 @@
+use jito_vault_core::{
+    config::Config, result::VaultCoreError, vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+/// Initializes a vault backed by `token_mint` with its token and fee accounts created
+async fn setup_vault(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+    config: &Pubkey,
+    token_mint: &Keypair,
+) -> (Pubkey, Keypair, Keypair) {
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            config,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    (vault_pubkey, lrt_mint, vault_admin)
+}
+
+/// A staker holding 100_000 LRT of a source vault, with the accounts migrating them to a
+/// destination vault supporting the same token
+struct Migration {
+    config_admin: Keypair,
+    source_vault: Pubkey,
+    source_lrt_mint: Pubkey,
+    source_vault_admin: Keypair,
+    destination_vault: Pubkey,
+    destination_lrt_mint: Pubkey,
+    staker: Keypair,
+    staker_source_lrt_token_account: Pubkey,
+    staker_destination_lrt_token_account: Pubkey,
+    source_vault_token_account: Pubkey,
+    destination_vault_token_account: Pubkey,
+}
+
+impl Migration {
+    async fn migrate(
+        &self,
+        vault_program_client: &mut VaultProgramClient,
+        burn_signer: Option<&Keypair>,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        vault_program_client
+            .migrate_deposit(
+                &self.source_vault,
+                &self.source_lrt_mint,
+                &self.destination_vault,
+                &self.destination_lrt_mint,
+                &self.staker,
+                &self.staker_source_lrt_token_account,
+                &self.staker_destination_lrt_token_account,
+                &self.source_vault_token_account,
+                &self.destination_vault_token_account,
+                burn_signer,
+                None,
+                amount,
+            )
+            .await
+    }
+}
+
+async fn setup_migration() -> (TestBuilder, VaultProgramClient, Migration) {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let (source_vault, source_lrt_mint, source_vault_admin) = setup_vault(
+        &mut fixture,
+        &mut vault_program_client,
+        &config_pubkey,
+        &token_mint,
+    )
+    .await;
+    let (destination_vault, destination_lrt_mint, _) = setup_vault(
+        &mut fixture,
+        &mut vault_program_client,
+        &config_pubkey,
+        &token_mint,
+    )
+    .await;
+
+    let staker = Keypair::new();
+    fixture.transfer(&staker.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &staker.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&source_lrt_mint.pubkey(), &staker.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&destination_lrt_mint.pubkey(), &staker.pubkey())
+        .await
+        .unwrap();
+
+    let staker_token_account = get_associated_token_address(&staker.pubkey(), &token_mint.pubkey());
+    let staker_source_lrt_token_account =
+        get_associated_token_address(&staker.pubkey(), &source_lrt_mint.pubkey());
+    let source_vault_token_account =
+        get_associated_token_address(&source_vault, &token_mint.pubkey());
+
+    vault_program_client
+        .mint_to(
+            &source_vault,
+            &source_lrt_mint.pubkey(),
+            &staker,
+            &staker_token_account,
+            &source_vault_token_account,
+            &staker_source_lrt_token_account,
+            &get_associated_token_address(&source_vault_admin.pubkey(), &source_lrt_mint.pubkey()),
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    let migration = Migration {
+        config_admin,
+        source_vault,
+        source_lrt_mint: source_lrt_mint.pubkey(),
+        source_vault_admin,
+        destination_vault,
+        destination_lrt_mint: destination_lrt_mint.pubkey(),
+        staker_source_lrt_token_account,
+        staker_destination_lrt_token_account: get_associated_token_address(
+            &staker.pubkey(),
+            &destination_lrt_mint.pubkey(),
+        ),
+        source_vault_token_account,
+        destination_vault_token_account: get_associated_token_address(
+            &destination_vault,
+            &token_mint.pubkey(),
+        ),
+        staker,
+    };
+    (fixture, vault_program_client, migration)
+}
+
+async fn open_migration(vault_program_client: &mut VaultProgramClient, migration: &Migration) {
+    vault_program_client
+        .set_migration_target(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &migration.source_vault,
+            &migration.config_admin,
+            &migration.destination_vault,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate_deposit_ok() {
+    let (mut fixture, mut vault_program_client, migration) = setup_migration().await;
+
+    // migrations shall be opened by the configuration admin first
+    assert!(migration
+        .migrate(&mut vault_program_client, None, 40_000)
+        .await
+        .is_err());
+
+    open_migration(&mut vault_program_client, &migration).await;
+    migration
+        .migrate(&mut vault_program_client, None, 40_000)
+        .await
+        .unwrap();
+
+    let source = vault_program_client
+        .get_vault(&migration.source_vault)
+        .await
+        .unwrap();
+    assert_eq!(source.lrt_supply(), 60_000);
+    assert_eq!(source.tokens_deposited(), 60_000);
+    let destination = vault_program_client
+        .get_vault(&migration.destination_vault)
+        .await
+        .unwrap();
+    assert_eq!(destination.lrt_supply(), 40_000);
+    assert_eq!(destination.tokens_deposited(), 40_000);
+
+    let staker_source_lrt = fixture
+        .get_token_account(&migration.staker_source_lrt_token_account)
+        .await
+        .unwrap();
+    assert_eq!(staker_source_lrt.amount, 60_000);
+    let staker_destination_lrt = fixture
+        .get_token_account(&migration.staker_destination_lrt_token_account)
+        .await
+        .unwrap();
+    assert_eq!(staker_destination_lrt.amount, 40_000);
+    let destination_tokens = fixture
+        .get_token_account(&migration.destination_vault_token_account)
+        .await
+        .unwrap();
+    assert_eq!(destination_tokens.amount, 40_000);
+}
+
+#[tokio::test]
+async fn test_migrate_deposit_requires_source_burn_signer() {
+    let (_fixture, mut vault_program_client, migration) = setup_migration().await;
+    open_migration(&mut vault_program_client, &migration).await;
+
+    let mint_burn_authority = Keypair::new();
+    vault_program_client
+        .set_mint_burn_authority(
+            &migration.source_vault,
+            &migration.source_vault_admin,
+            &mint_burn_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    // without the co-signer, the instruction lacks the burn signer account
+    let result = migration
+        .migrate(&mut vault_program_client, None, 40_000)
+        .await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    let impostor = Keypair::new();
+    let result = migration
+        .migrate(&mut vault_program_client, Some(&impostor), 40_000)
+        .await;
+    assert_program_error(result, VaultError::MintBurnSignerMismatch);
+
+    migration
+        .migrate(
+            &mut vault_program_client,
+            Some(&mint_burn_authority),
+            40_000,
+        )
+        .await
+        .unwrap();
+    let source = vault_program_client
+        .get_vault(&migration.source_vault)
+        .await
+        .unwrap();
+    assert_eq!(source.lrt_supply(), 60_000);
+}
+
+#[tokio::test]
+async fn test_migrate_deposit_with_queued_withdrawals_fails() {
+    let (_fixture, mut vault_program_client, migration) = setup_migration().await;
+    open_migration(&mut vault_program_client, &migration).await;
+
+    vault_program_client
+        .enqueue_withdrawal(
+            &migration.source_vault,
+            &migration.staker,
+            &Keypair::new(),
+            None,
+            10_000,
+        )
+        .await
+        .unwrap();
+
+    // the migration would skip the cooldown ahead of the queued withdrawal
+    let result = migration
+        .migrate(&mut vault_program_client, None, 40_000)
+        .await;
+    assert_program_error(result, VaultCoreError::VaultMigrationWithdrawalsQueued);
+}
+
+#[tokio::test]
+async fn test_set_migration_target_vault_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let (vault_pubkey, _, vault_admin) = setup_vault(
+        &mut fixture,
+        &mut vault_program_client,
+        &config_pubkey,
+        &token_mint,
+    )
+    .await;
+
+    assert!(vault_program_client
+        .set_migration_target(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_admin,
+            &Pubkey::new_unique(),
+        )
+        .await
+        .is_err());
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.migration_target(), None);
+}
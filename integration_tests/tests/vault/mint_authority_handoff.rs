@@ -0,0 +1,201 @@
+use jito_vault_core::{config::Config, vault::Vault, vault_delegation_list::VaultDelegationList};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::{fixture::TestBuilder, vault_client::VaultProgramClient};
+
+/// Initializes a vault backed by `backing_token_mint`, returning the vault, its LRT mint and admin
+async fn setup_vault(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+    config: &Pubkey,
+    backing_token_mint: &Keypair,
+) -> (Pubkey, Pubkey, Keypair) {
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            config,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            backing_token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    (vault_pubkey, lrt_mint.pubkey(), vault_admin)
+}
+
+#[tokio::test]
+async fn test_mint_authority_handoff_and_back_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let backing_token_mint = Keypair::new();
+    fixture
+        .create_token_mint(&backing_token_mint)
+        .await
+        .unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+    let epoch_length = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap()
+        .epoch_length();
+
+    let (old_vault, lrt_mint, old_vault_admin) = setup_vault(
+        &mut fixture,
+        &mut vault_program_client,
+        &config_pubkey,
+        &backing_token_mint,
+    )
+    .await;
+    let (new_vault, _, new_vault_admin) = setup_vault(
+        &mut fixture,
+        &mut vault_program_client,
+        &config_pubkey,
+        &backing_token_mint,
+    )
+    .await;
+
+    // hand the mint off to the new vault
+    vault_program_client
+        .propose_mint_authority_handoff(
+            &config_pubkey,
+            &old_vault,
+            &old_vault_admin,
+            &config_admin,
+            &new_vault,
+        )
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&old_vault).await.unwrap();
+    assert_eq!(vault.mint_authority_handoff_target(), Some(new_vault));
+
+    assert!(vault_program_client
+        .execute_mint_authority_handoff(
+            &config_pubkey,
+            &old_vault,
+            &lrt_mint,
+            &old_vault_admin,
+            &config_admin,
+        )
+        .await
+        .is_err());
+
+    fixture.warp_slot_incremental(epoch_length).await.unwrap();
+    vault_program_client
+        .execute_mint_authority_handoff(
+            &config_pubkey,
+            &old_vault,
+            &lrt_mint,
+            &old_vault_admin,
+            &config_admin,
+        )
+        .await
+        .unwrap();
+    let mint = fixture.get_mint(&lrt_mint).await.unwrap();
+    assert_eq!(mint.mint_authority, COption::Some(new_vault));
+    let vault = vault_program_client.get_vault(&old_vault).await.unwrap();
+    assert_eq!(vault.mint_authority_handoff_target(), None);
+
+    // and recover it back to the original vault
+    vault_program_client
+        .propose_mint_authority_handoff(
+            &config_pubkey,
+            &new_vault,
+            &new_vault_admin,
+            &config_admin,
+            &old_vault,
+        )
+        .await
+        .unwrap();
+    fixture.warp_slot_incremental(epoch_length).await.unwrap();
+    vault_program_client
+        .execute_mint_authority_handoff(
+            &config_pubkey,
+            &new_vault,
+            &lrt_mint,
+            &new_vault_admin,
+            &config_admin,
+        )
+        .await
+        .unwrap();
+    let mint = fixture.get_mint(&lrt_mint).await.unwrap();
+    assert_eq!(mint.mint_authority, COption::Some(old_vault));
+}
+
+#[tokio::test]
+async fn test_mint_authority_handoff_without_config_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let backing_token_mint = Keypair::new();
+    fixture
+        .create_token_mint(&backing_token_mint)
+        .await
+        .unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let (vault_pubkey, _, vault_admin) = setup_vault(
+        &mut fixture,
+        &mut vault_program_client,
+        &config_pubkey,
+        &backing_token_mint,
+    )
+    .await;
+
+    let new_authority = Pubkey::new_unique();
+    assert!(vault_program_client
+        .propose_mint_authority_handoff(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_admin,
+            &vault_admin,
+            &new_authority,
+        )
+        .await
+        .is_err());
+
+    vault_program_client
+        .propose_mint_authority_handoff(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_admin,
+            &config_admin,
+            &new_authority,
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .cancel_mint_authority_handoff(&config_pubkey, &vault_pubkey, &config_admin)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.mint_authority_handoff_target(), None);
+}
@@ -0,0 +1,172 @@
+use jito_vault_core::result::VaultCoreError;
+use jito_vault_sdk::error::VaultError;
+use solana_program::instruction::InstructionError;
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, RelationshipGraph, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+/// A vault with a depositor holding 100_000 of its supported tokens and the LRT ATA to deposit to
+async fn setup() -> (TestBuilder, VaultProgramClient, RelationshipGraph, Keypair) {
+    let mut fixture = TestBuilder::new().await;
+    let vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+
+    (fixture, vault_program_client, graph, depositor)
+}
+
+async fn deposit(
+    vault_program_client: &mut VaultProgramClient,
+    graph: &RelationshipGraph,
+    depositor: &Keypair,
+    mint_signer: Option<&Keypair>,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    vault_program_client
+        .mint_to(
+            &graph.vault,
+            &graph.lrt_mint.pubkey(),
+            depositor,
+            &get_associated_token_address(&depositor.pubkey(), &graph.token_mint.pubkey()),
+            &graph.vault_token_account,
+            &get_associated_token_address(&depositor.pubkey(), &graph.lrt_mint.pubkey()),
+            &graph.vault_fee_token_account,
+            mint_signer,
+            amount,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_mint_burn_authority_gates_deposits_and_withdrawals() {
+    let (_fixture, mut vault_program_client, graph, depositor) = setup().await;
+
+    let mint_burn_authority = Keypair::new();
+    vault_program_client
+        .set_mint_burn_authority(
+            &graph.vault,
+            &graph.vault_admin,
+            &mint_burn_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(
+        vault.mint_burn_authority(),
+        Some(mint_burn_authority.pubkey())
+    );
+
+    // without the co-signer, the instruction lacks the mint signer account
+    let result = deposit(&mut vault_program_client, &graph, &depositor, None, 10_000).await;
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    let impostor = Keypair::new();
+    let result = deposit(
+        &mut vault_program_client,
+        &graph,
+        &depositor,
+        Some(&impostor),
+        10_000,
+    )
+    .await;
+    assert_program_error(result, VaultError::MintBurnSignerMismatch);
+
+    deposit(
+        &mut vault_program_client,
+        &graph,
+        &depositor,
+        Some(&mint_burn_authority),
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let base = Keypair::new();
+    let result = vault_program_client
+        .enqueue_withdrawal(&graph.vault, &depositor, &base, Some(&impostor), 1_000)
+        .await;
+    assert_program_error(result, VaultError::MintBurnSignerMismatch);
+    vault_program_client
+        .enqueue_withdrawal(
+            &graph.vault,
+            &depositor,
+            &base,
+            Some(&mint_burn_authority),
+            1_000,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_clear_mint_burn_authority_ok() {
+    let (_fixture, mut vault_program_client, graph, depositor) = setup().await;
+
+    let mint_burn_authority = Keypair::new();
+    vault_program_client
+        .set_mint_burn_authority(
+            &graph.vault,
+            &graph.vault_admin,
+            &mint_burn_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .clear_mint_burn_authority(&graph.vault, &graph.vault_admin)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.mint_burn_authority(), None);
+
+    // permissionless again
+    deposit(&mut vault_program_client, &graph, &depositor, None, 10_000)
+        .await
+        .unwrap();
+    vault_program_client
+        .enqueue_withdrawal(&graph.vault, &depositor, &Keypair::new(), None, 1_000)
+        .await
+        .unwrap();
+
+    let result = vault_program_client
+        .clear_mint_burn_authority(&graph.vault, &graph.vault_admin)
+        .await;
+    assert_program_error(result, VaultCoreError::VaultMintBurnAuthorityNotSet);
+}
+
+#[tokio::test]
+async fn test_set_mint_burn_authority_bad_admin_fails() {
+    let (mut fixture, mut vault_program_client, graph, _) = setup().await;
+
+    let bad_admin = Keypair::new();
+    fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+    let result = vault_program_client
+        .set_mint_burn_authority(&graph.vault, &bad_admin, &bad_admin.pubkey())
+        .await;
+    assert_program_error(result, VaultCoreError::VaultInvalidAdmin);
+
+    let result = vault_program_client
+        .clear_mint_burn_authority(&graph.vault, &bad_admin)
+        .await;
+    assert_program_error(result, VaultCoreError::VaultInvalidAdmin);
+}
@@ -1,7 +1,32 @@
 mod add_avs;
+mod add_delegation;
 mod add_operator;
 mod add_slasher;
+mod burn_withdrawal_ticket;
+mod config_set_parameter;
+mod crank_tip;
+mod get_protocol_parameters;
 mod initialize_config;
 mod initialize_vault;
+mod migrate_account;
+mod migrate_deposit;
+mod mint_authority_handoff;
+mod mint_burn_authority;
 mod mint_to;
+mod pause;
+mod pay_avs_service_fee;
+mod program_fee;
+mod referral_fee;
+mod relationship_graph;
+mod set_admin;
+mod set_avs_service_fee;
+mod set_deposit_fee;
+mod set_emergency_mode;
+mod set_epoch_length;
+mod set_fees;
+mod set_withdrawal_fee;
 mod slash;
+mod slash_proposal;
+mod token_2022;
+mod update_vault_balance;
+mod zap_deposit;
@@ -0,0 +1,207 @@
+use jito_vault_core::{
+    config::Config, result::VaultCoreError, vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+struct PauseSetup {
+    config: Pubkey,
+    config_admin: Keypair,
+    vault: Pubkey,
+    lrt_mint: Pubkey,
+    token_mint: Pubkey,
+    vault_admin: Keypair,
+    depositor: Keypair,
+}
+
+/// A vault without fees and a depositor holding 100_000 tokens
+async fn setup(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+) -> PauseSetup {
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    PauseSetup {
+        config: config_pubkey,
+        config_admin,
+        vault: vault_pubkey,
+        lrt_mint: lrt_mint.pubkey(),
+        token_mint: token_mint.pubkey(),
+        vault_admin,
+        depositor,
+    }
+}
+
+async fn deposit(
+    vault_program_client: &mut VaultProgramClient,
+    setup: &PauseSetup,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    vault_program_client
+        .mint_to(
+            &setup.vault,
+            &setup.lrt_mint,
+            &setup.depositor,
+            &get_associated_token_address(&setup.depositor.pubkey(), &setup.token_mint),
+            &get_associated_token_address(&setup.vault, &setup.token_mint),
+            &get_associated_token_address(&setup.depositor.pubkey(), &setup.lrt_mint),
+            &get_associated_token_address(&setup.vault_admin.pubkey(), &setup.lrt_mint),
+            None,
+            amount,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_config_set_paused_blocks_deposits() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let setup = setup(&mut fixture, &mut vault_program_client).await;
+
+    vault_program_client
+        .config_set_paused(&setup.config, &setup.config_admin, true)
+        .await
+        .unwrap();
+    let config = vault_program_client
+        .get_config(&setup.config)
+        .await
+        .unwrap();
+    assert!(config.paused());
+
+    assert_program_error(
+        deposit(&mut vault_program_client, &setup, 1_000).await,
+        VaultCoreError::ConfigPaused,
+    );
+
+    // resuming restores deposits
+    vault_program_client
+        .config_set_paused(&setup.config, &setup.config_admin, false)
+        .await
+        .unwrap();
+    let config = vault_program_client
+        .get_config(&setup.config)
+        .await
+        .unwrap();
+    assert!(!config.paused());
+
+    deposit(&mut vault_program_client, &setup, 1_000)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&setup.vault).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 1_000);
+}
+
+#[tokio::test]
+async fn test_config_set_vault_paused_blocks_deposits() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let setup = setup(&mut fixture, &mut vault_program_client).await;
+
+    vault_program_client
+        .config_set_vault_paused(&setup.config, &setup.vault, &setup.config_admin, true)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&setup.vault).await.unwrap();
+    assert!(vault.paused());
+
+    assert_program_error(
+        deposit(&mut vault_program_client, &setup, 1_000).await,
+        VaultCoreError::VaultPaused,
+    );
+
+    vault_program_client
+        .config_set_vault_paused(&setup.config, &setup.vault, &setup.config_admin, false)
+        .await
+        .unwrap();
+    deposit(&mut vault_program_client, &setup, 1_000)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_config_set_paused_non_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let setup = setup(&mut fixture, &mut vault_program_client).await;
+
+    // the vault admin can't pause the program or its own vault
+    assert_program_error(
+        vault_program_client
+            .config_set_paused(&setup.config, &setup.vault_admin, true)
+            .await,
+        VaultCoreError::ConfigInvalidAdmin,
+    );
+    assert_program_error(
+        vault_program_client
+            .config_set_vault_paused(&setup.config, &setup.vault, &setup.vault_admin, true)
+            .await,
+        VaultCoreError::ConfigInvalidAdmin,
+    );
+
+    let config = vault_program_client
+        .get_config(&setup.config)
+        .await
+        .unwrap();
+    assert!(!config.paused());
+    let vault = vault_program_client.get_vault(&setup.vault).await.unwrap();
+    assert!(!vault.paused());
+}
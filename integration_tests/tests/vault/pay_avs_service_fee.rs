@@ -0,0 +1,234 @@
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{RelationshipGraph, TestBuilder},
+    restaking_client::RestakingProgramClient,
+    vault_client::VaultProgramClient,
+};
+
+/// A vault holding a 100_000 token deposit, all of it delegated to the operator, and a 1% service
+/// fee agreed with the AVS two epochs ago, so 2_000 tokens of fees have accrued
+async fn setup() -> (
+    TestBuilder,
+    RestakingProgramClient,
+    VaultProgramClient,
+    RelationshipGraph,
+) {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &graph.vault,
+            &graph.lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &graph.token_mint.pubkey()),
+            &graph.vault_token_account,
+            &get_associated_token_address(&depositor.pubkey(), &graph.lrt_mint.pubkey()),
+            &graph.vault_fee_token_account,
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .add_delegation(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.operator,
+            &graph.vault_operator_ticket,
+            &graph.operator_vault_ticket,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+            &graph.vault_admin,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    restaking_program_client
+        .avs_set_vault_service_fee(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.vault,
+            &graph.avs_vault_ticket,
+            &graph.avs_admin,
+            100,
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .set_avs_service_fee(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.avs,
+            &graph.vault_avs_ticket,
+            &graph.vault_admin,
+            100,
+        )
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.token_mint.pubkey(), &graph.avs)
+        .await
+        .unwrap();
+
+    let epoch_length = vault_program_client
+        .get_config(&graph.vault_config)
+        .await
+        .unwrap()
+        .epoch_length();
+    let epoch = fixture.get_slot().await.unwrap() / epoch_length;
+    fixture
+        .warp_to_epoch(epoch + 2, epoch_length)
+        .await
+        .unwrap();
+
+    (
+        fixture,
+        restaking_program_client,
+        vault_program_client,
+        graph,
+    )
+}
+
+#[tokio::test]
+async fn test_pay_avs_service_fee_keeps_shortfall_owed() {
+    let (mut fixture, _, mut vault_program_client, graph) = setup().await;
+    let avs_token_account = get_associated_token_address(&graph.avs, &graph.token_mint.pubkey());
+
+    // without rewards nothing is paid, but the fee isn't forgiven
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .pay_avs_service_fee(&payer, &graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    let vault_avs_ticket = vault_program_client
+        .get_vault_avs_ticket(&graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    assert_eq!(vault_avs_ticket.service_fee_owed(), 2_000);
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.service_fees_owed(), 2_000);
+
+    // rewards earned later in the epoch pay the fee owed first
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &graph.vault, 1_500)
+        .await
+        .unwrap();
+    vault_program_client
+        .pay_avs_service_fee(&payer, &graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    let avs_token = fixture.get_token_account(&avs_token_account).await.unwrap();
+    assert_eq!(avs_token.amount, 1_500);
+    let vault_avs_ticket = vault_program_client
+        .get_vault_avs_ticket(&graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    assert_eq!(vault_avs_ticket.service_fee_owed(), 500);
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.service_fees_owed(), 500);
+    assert_eq!(vault.tokens_deposited(), 100_000);
+}
+
+#[tokio::test]
+async fn test_pay_avs_service_fee_skips_operators_not_securing_avs() {
+    let (mut fixture, mut restaking_program_client, mut vault_program_client, graph) =
+        setup().await;
+    let avs_token_account = get_associated_token_address(&graph.avs, &graph.token_mint.pubkey());
+
+    // the vault still delegates to the operator, but the AVS removed it
+    restaking_program_client
+        .avs_remove_operator(
+            &graph.restaking_config,
+            &graph.avs,
+            &graph.operator,
+            &graph.avs_operator_ticket,
+            &graph.avs_admin,
+        )
+        .await
+        .unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &graph.vault, 5_000)
+        .await
+        .unwrap();
+
+    let payer = Keypair::new();
+    fixture.transfer(&payer.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .pay_avs_service_fee(&payer, &graph.vault, &graph.avs)
+        .await
+        .unwrap();
+
+    let avs_token = fixture.get_token_account(&avs_token_account).await.unwrap();
+    assert_eq!(avs_token.amount, 0);
+    let vault_avs_ticket = vault_program_client
+        .get_vault_avs_ticket(&graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    assert_eq!(vault_avs_ticket.service_fee_owed(), 0);
+}
+
+#[tokio::test]
+async fn test_update_vault_balance_before_pay_avs_service_fee_keeps_fee() {
+    let (mut fixture, _, mut vault_program_client, graph) = setup().await;
+    let avs_token_account = get_associated_token_address(&graph.avs, &graph.token_mint.pubkey());
+    let cranker = Keypair::new();
+    fixture.transfer(&cranker.pubkey(), 1.0).await.unwrap();
+
+    // the crank runs ahead of the payment and harvests the rewards the fee would've come out of
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &graph.vault, 5_000)
+        .await
+        .unwrap();
+    vault_program_client
+        .update_vault_balance(&cranker, &graph.vault)
+        .await
+        .unwrap();
+    vault_program_client
+        .pay_avs_service_fee(&cranker, &graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 105_000);
+    assert_eq!(vault.service_fees_owed(), 2_000);
+
+    // the fee stays owed and is held back from the next harvest, cranked in a new slot so the
+    // transaction isn't a duplicate
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &graph.vault, 3_000)
+        .await
+        .unwrap();
+    fixture.warp_to_next_slot().await.unwrap();
+    vault_program_client
+        .update_vault_balance(&cranker, &graph.vault)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 106_000);
+
+    vault_program_client
+        .pay_avs_service_fee(&cranker, &graph.vault, &graph.avs)
+        .await
+        .unwrap();
+    let avs_token = fixture.get_token_account(&avs_token_account).await.unwrap();
+    assert_eq!(avs_token.amount, 2_000);
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.service_fees_owed(), 0);
+    assert_eq!(vault.tokens_deposited(), 106_000);
+}
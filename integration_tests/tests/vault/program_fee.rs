@@ -0,0 +1,187 @@
+use jito_vault_core::{
+    config::{Config, DEFAULT_EPOCH_LENGTH, MAX_PROGRAM_FEE_BPS, PROGRAM_FEE_CHANGE_DELAY_EPOCHS},
+    result::VaultCoreError,
+    vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_config_set_program_fee_bounds_and_timelock() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let attacker = Keypair::new();
+    fixture.transfer(&attacker.pubkey(), 1.0).await.unwrap();
+    let wallet = Pubkey::new_unique();
+    assert_program_error(
+        vault_program_client
+            .config_set_program_fee(&config_pubkey, &attacker, &wallet, 10)
+            .await,
+        VaultCoreError::ConfigInvalidAdmin,
+    );
+    assert_program_error(
+        vault_program_client
+            .config_set_program_fee(
+                &config_pubkey,
+                &config_admin,
+                &wallet,
+                MAX_PROGRAM_FEE_BPS + 1,
+            )
+            .await,
+        VaultCoreError::ConfigInvalidProgramFee,
+    );
+    // a fee needs somewhere to go
+    assert_program_error(
+        vault_program_client
+            .config_set_program_fee(&config_pubkey, &config_admin, &Pubkey::default(), 10)
+            .await,
+        VaultCoreError::ConfigInvalidProgramFee,
+    );
+
+    vault_program_client
+        .config_set_program_fee(&config_pubkey, &config_admin, &wallet, MAX_PROGRAM_FEE_BPS)
+        .await
+        .unwrap();
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.program_fee_bps(), MAX_PROGRAM_FEE_BPS);
+    assert_eq!(config.program_fee_wallet(), wallet);
+
+    // the fee can't move again until the delay has passed
+    assert_program_error(
+        vault_program_client
+            .config_set_program_fee(&config_pubkey, &config_admin, &wallet, 0)
+            .await,
+        VaultCoreError::ConfigProgramFeeTimelocked,
+    );
+    fixture
+        .warp_slot_incremental(PROGRAM_FEE_CHANGE_DELAY_EPOCHS * DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    vault_program_client
+        .config_set_program_fee(&config_pubkey, &config_admin, &wallet, 0)
+        .await
+        .unwrap();
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.program_fee_bps(), 0);
+}
+
+#[tokio::test]
+async fn test_mint_to_charges_program_fee() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    // a vault with a 10% deposit fee
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            1_000,
+            0,
+        )
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    let program_fee_wallet = Keypair::new();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &program_fee_wallet.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .config_set_program_fee(
+            &config_pubkey,
+            &config_admin,
+            &program_fee_wallet.pubkey(),
+            100,
+        )
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &vault_pubkey,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &token_mint.pubkey()),
+            &get_associated_token_address(&vault_pubkey, &token_mint.pubkey()),
+            &get_associated_token_address(&depositor.pubkey(), &lrt_mint.pubkey()),
+            &get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey()),
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    // 1% to the program, then 10% of the rest to the vault
+    for (owner, amount) in [
+        (program_fee_wallet.pubkey(), 1_000),
+        (vault_admin.pubkey(), 9_900),
+        (depositor.pubkey(), 89_100),
+    ] {
+        let account = fixture
+            .get_token_account(&get_associated_token_address(&owner, &lrt_mint.pubkey()))
+            .await
+            .unwrap();
+        assert_eq!(account.amount, amount);
+    }
+}
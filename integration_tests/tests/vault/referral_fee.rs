@@ -0,0 +1,288 @@
+use jito_vault_core::{
+    config::Config, result::VaultCoreError, vault::Vault,
+    vault_delegation_list::VaultDelegationList,
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+struct ReferralSetup {
+    vault: Pubkey,
+    lrt_mint: Pubkey,
+    token_mint: Pubkey,
+    vault_admin: Keypair,
+}
+
+/// A vault with a 10% deposit fee, a quarter of which goes to referrers
+async fn setup(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+) -> ReferralSetup {
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            1_000,
+            0,
+        )
+        .await
+        .unwrap();
+    vault_program_client
+        .set_referral_fee(&vault_pubkey, &vault_admin, 2_500)
+        .await
+        .unwrap();
+
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    ReferralSetup {
+        vault: vault_pubkey,
+        lrt_mint: lrt_mint.pubkey(),
+        token_mint: token_mint.pubkey(),
+        vault_admin,
+    }
+}
+
+async fn deposit(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+    setup: &ReferralSetup,
+    referrer: Option<&Pubkey>,
+    amount: u64,
+) -> Keypair {
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&setup.token_mint, &depositor.pubkey(), amount)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&setup.lrt_mint, &depositor.pubkey())
+        .await
+        .unwrap();
+
+    vault_program_client
+        .mint_to_with_referrer(
+            &setup.vault,
+            &setup.lrt_mint,
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &setup.token_mint),
+            &get_associated_token_address(&setup.vault, &setup.token_mint),
+            &get_associated_token_address(&depositor.pubkey(), &setup.lrt_mint),
+            &get_associated_token_address(&setup.vault_admin.pubkey(), &setup.lrt_mint),
+            None,
+            referrer,
+            amount,
+        )
+        .await
+        .unwrap();
+    depositor
+}
+
+#[tokio::test]
+async fn test_referrer_accrues_and_claims_share_of_deposit_fee() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let setup = setup(&mut fixture, &mut vault_program_client).await;
+
+    let referrer = Keypair::new();
+    fixture.transfer(&referrer.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault_referrer(&setup.vault, &referrer.pubkey(), &referrer)
+        .await
+        .unwrap();
+
+    let depositor = deposit(
+        &mut fixture,
+        &mut vault_program_client,
+        &setup,
+        Some(&referrer.pubkey()),
+        100_000,
+    )
+    .await;
+
+    // the 10_000 LRT fee is split between the fee wallet and the referrer
+    let depositor_account = fixture
+        .get_token_account(&get_associated_token_address(
+            &depositor.pubkey(),
+            &setup.lrt_mint,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(depositor_account.amount, 90_000);
+    let fee_account = fixture
+        .get_token_account(&get_associated_token_address(
+            &setup.vault_admin.pubkey(),
+            &setup.lrt_mint,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(fee_account.amount, 7_500);
+
+    let vault_referrer = vault_program_client
+        .get_vault_referrer(&setup.vault, &referrer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(vault_referrer.referrer(), referrer.pubkey());
+    assert_eq!(vault_referrer.accrued_lrt(), 2_500);
+    let vault = vault_program_client.get_vault(&setup.vault).await.unwrap();
+    assert_eq!(vault.lrt_supply(), 100_000);
+
+    fixture
+        .create_ata(&setup.lrt_mint, &referrer.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .claim_referral_fees(&setup.vault, &setup.lrt_mint, &referrer.pubkey(), &referrer)
+        .await
+        .unwrap();
+
+    let referrer_account = fixture
+        .get_token_account(&get_associated_token_address(
+            &referrer.pubkey(),
+            &setup.lrt_mint,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(referrer_account.amount, 2_500);
+    let vault_referrer = vault_program_client
+        .get_vault_referrer(&setup.vault, &referrer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(vault_referrer.accrued_lrt(), 0);
+    assert_eq!(vault_referrer.claimed_lrt(), 2_500);
+
+    // nothing left to claim
+    fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        vault_program_client
+            .claim_referral_fees(&setup.vault, &setup.lrt_mint, &referrer.pubkey(), &referrer)
+            .await,
+        VaultCoreError::VaultReferrerNothingToClaim,
+    );
+}
+
+#[tokio::test]
+async fn test_deposit_without_referrer_pays_full_fee() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let setup = setup(&mut fixture, &mut vault_program_client).await;
+
+    deposit(
+        &mut fixture,
+        &mut vault_program_client,
+        &setup,
+        None,
+        100_000,
+    )
+    .await;
+
+    let fee_account = fixture
+        .get_token_account(&get_associated_token_address(
+            &setup.vault_admin.pubkey(),
+            &setup.lrt_mint,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(fee_account.amount, 10_000);
+}
+
+#[tokio::test]
+async fn test_set_referral_fee_invalid_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let setup = setup(&mut fixture, &mut vault_program_client).await;
+
+    let non_admin = Keypair::new();
+    fixture.transfer(&non_admin.pubkey(), 1.0).await.unwrap();
+    assert!(vault_program_client
+        .set_referral_fee(&setup.vault, &non_admin, 5_000)
+        .await
+        .is_err());
+    assert_program_error(
+        vault_program_client
+            .set_referral_fee(&setup.vault, &setup.vault_admin, 10_001)
+            .await,
+        VaultCoreError::VaultInvalidReferralFee,
+    );
+
+    let vault = vault_program_client.get_vault(&setup.vault).await.unwrap();
+    assert_eq!(vault.referral_fee_bps(), 2_500);
+}
+
+#[tokio::test]
+async fn test_claim_referral_fees_wrong_referrer_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let setup = setup(&mut fixture, &mut vault_program_client).await;
+
+    let referrer = Keypair::new();
+    fixture.transfer(&referrer.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault_referrer(&setup.vault, &referrer.pubkey(), &referrer)
+        .await
+        .unwrap();
+    deposit(
+        &mut fixture,
+        &mut vault_program_client,
+        &setup,
+        Some(&referrer.pubkey()),
+        100_000,
+    )
+    .await;
+
+    // another key can't claim the referrer's fees
+    let thief = Keypair::new();
+    fixture.transfer(&thief.pubkey(), 1.0).await.unwrap();
+    fixture
+        .create_ata(&setup.lrt_mint, &referrer.pubkey())
+        .await
+        .unwrap();
+    assert_program_error(
+        vault_program_client
+            .claim_referral_fees(&setup.vault, &setup.lrt_mint, &referrer.pubkey(), &thief)
+            .await,
+        VaultCoreError::VaultReferrerInvalidReferrer,
+    );
+
+    let vault_referrer = vault_program_client
+        .get_vault_referrer(&setup.vault, &referrer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(vault_referrer.accrued_lrt(), 2_500);
+}
@@ -0,0 +1,131 @@
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_setup_vault_with_operator_and_avs_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let avs = restaking_program_client.get_avs(&graph.avs).await.unwrap();
+    assert_eq!(avs.vault_count(), 1);
+    assert_eq!(avs.operator_count(), 1);
+    assert_eq!(avs.slasher_count(), 1);
+    let operator = restaking_program_client
+        .get_operator(&graph.operator)
+        .await
+        .unwrap();
+    assert_eq!(operator.vault_count(), 1);
+    assert_eq!(operator.avs_count(), 1);
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.avs_count(), 1);
+    assert_eq!(vault.operator_count(), 1);
+    assert_eq!(vault.slasher_count(), 1);
+
+    // a second graph reuses the configs
+    let other = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+    assert_eq!(other.restaking_config, graph.restaking_config);
+    assert_ne!(other.vault, graph.vault);
+}
+
+#[tokio::test]
+async fn test_deposit_and_delegate_across_epochs_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &graph.vault,
+            &graph.lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &graph.token_mint.pubkey()),
+            &graph.vault_token_account,
+            &get_associated_token_address(&depositor.pubkey(), &graph.lrt_mint.pubkey()),
+            &graph.vault_fee_token_account,
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    vault_program_client
+        .add_delegation(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.operator,
+            &graph.vault_operator_ticket,
+            &graph.operator_vault_ticket,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+            &graph.vault_admin,
+            10_000,
+        )
+        .await
+        .unwrap();
+
+    let epoch_length = vault_program_client
+        .get_config(&graph.vault_config)
+        .await
+        .unwrap()
+        .epoch_length();
+    let epoch = fixture.get_slot().await.unwrap() / epoch_length;
+    fixture
+        .warp_to_epoch(epoch + 2, epoch_length)
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.get_slot().await.unwrap(),
+        (epoch + 2) * epoch_length
+    );
+
+    vault_program_client
+        .update_delegations(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.vault_delegation_list,
+            &graph.vault_admin,
+        )
+        .await
+        .unwrap();
+    let vault_delegation_list = vault_program_client
+        .get_vault_delegation_list(&graph.vault_delegation_list)
+        .await
+        .unwrap();
+    let delegations = vault_delegation_list.delegations();
+    assert_eq!(delegations.len(), 1);
+    assert_eq!(delegations[0].operator(), graph.operator);
+    assert_eq!(delegations[0].active_amount(), 10_000);
+}
+
+#[tokio::test]
+async fn test_create_funded_mint_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let holders = [(Pubkey::new_unique(), 1_000), (Pubkey::new_unique(), 2_000)];
+
+    let mint = fixture.create_funded_mint(&holders).await.unwrap();
+
+    for (holder, amount) in holders {
+        let token_account = fixture
+            .get_token_account(&get_associated_token_address(&holder, &mint))
+            .await
+            .unwrap();
+        assert_eq!(token_account.amount, amount);
+    }
+    assert_eq!(fixture.get_mint(&mint).await.unwrap().supply, 3_000);
+}
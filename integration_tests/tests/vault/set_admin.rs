@@ -0,0 +1,116 @@
+use jito_vault_core::{config::Config, vault::Vault, vault_delegation_list::VaultDelegationList};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_set_admin_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    let new_admin = Keypair::new();
+    let impostor = Keypair::new();
+    fixture.transfer(&new_admin.pubkey(), 1.0).await.unwrap();
+    fixture.transfer(&impostor.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .set_admin(&vault_pubkey, &vault_admin, &new_admin.pubkey())
+        .await
+        .unwrap();
+
+    // the old admin stays in charge until the transfer is accepted
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.admin(), vault_admin.pubkey());
+    assert_eq!(vault.pending_admin(), Some(new_admin.pubkey()));
+    assert!(vault_program_client
+        .accept_admin(&vault_pubkey, &impostor)
+        .await
+        .is_err());
+
+    vault_program_client
+        .accept_admin(&vault_pubkey, &new_admin)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.admin(), new_admin.pubkey());
+    assert_eq!(vault.pending_admin(), None);
+
+    assert!(vault_program_client
+        .set_crank_tip(&vault_pubkey, &vault_admin, 100)
+        .await
+        .is_err());
+    vault_program_client
+        .set_crank_tip(&vault_pubkey, &new_admin, 100)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_config_set_admin_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let new_admin = Keypair::new();
+    fixture.transfer(&new_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .config_set_admin(&config_pubkey, &config_admin, &new_admin.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .config_accept_admin(&config_pubkey, &new_admin)
+        .await
+        .unwrap();
+
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.admin(), new_admin.pubkey());
+    assert_eq!(config.pending_admin(), None);
+
+    assert!(vault_program_client
+        .config_set_admin(&config_pubkey, &config_admin, &config_admin.pubkey())
+        .await
+        .is_err());
+}
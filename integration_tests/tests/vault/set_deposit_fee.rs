@@ -0,0 +1,187 @@
+use jito_vault_core::{
+    config::{Config, DEFAULT_EPOCH_LENGTH},
+    result::VaultCoreError,
+    vault::{Vault, FEE_CHANGE_DELAY_EPOCHS, MAX_FEE_DELTA_BPS},
+    vault_delegation_list::VaultDelegationList,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_set_deposit_fee_applies_to_deposits_after_the_delay() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            0,
+        )
+        .await
+        .unwrap();
+
+    vault_program_client
+        .set_deposit_fee(&config_pubkey, &vault_pubkey, &vault_admin, 300)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.deposit_fee_bps(), 100);
+    assert_eq!(
+        vault.pending_fees(),
+        Some((300, 0, FEE_CHANGE_DELAY_EPOCHS))
+    );
+    fixture
+        .warp_slot_incremental(FEE_CHANGE_DELAY_EPOCHS * DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    let depositor_lrt_token_account =
+        get_associated_token_address(&depositor.pubkey(), &lrt_mint.pubkey());
+    let vault_fee_token_account =
+        get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey());
+    vault_program_client
+        .mint_to(
+            &vault_pubkey,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &token_mint.pubkey()),
+            &get_associated_token_address(&vault_pubkey, &token_mint.pubkey()),
+            &depositor_lrt_token_account,
+            &vault_fee_token_account,
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    let fee_account = fixture
+        .get_token_account(&vault_fee_token_account)
+        .await
+        .unwrap();
+    assert_eq!(fee_account.amount, 3_000);
+    let depositor_account = fixture
+        .get_token_account(&depositor_lrt_token_account)
+        .await
+        .unwrap();
+    assert_eq!(depositor_account.amount, 97_000);
+}
+
+#[tokio::test]
+async fn test_set_deposit_fee_invalid_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            0,
+        )
+        .await
+        .unwrap();
+
+    // only the fee admin can set the fee
+    let non_admin = Keypair::new();
+    fixture.transfer(&non_admin.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        vault_program_client
+            .set_deposit_fee(&config_pubkey, &vault_pubkey, &non_admin, 200)
+            .await,
+        VaultCoreError::VaultInvalidFeeAdmin,
+    );
+
+    // the fee can neither exceed 100% nor jump by more than the bound
+    assert_program_error(
+        vault_program_client
+            .set_deposit_fee(&config_pubkey, &vault_pubkey, &vault_admin, 10_001)
+            .await,
+        VaultCoreError::VaultInvalidDepositFee,
+    );
+    assert_program_error(
+        vault_program_client
+            .set_deposit_fee(
+                &config_pubkey,
+                &vault_pubkey,
+                &vault_admin,
+                100 + MAX_FEE_DELTA_BPS + 1,
+            )
+            .await,
+        VaultCoreError::VaultFeeDeltaExceeded,
+    );
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.deposit_fee_bps(), 100);
+    assert_eq!(vault.pending_fees(), None);
+}
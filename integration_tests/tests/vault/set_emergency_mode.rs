@@ -0,0 +1,111 @@
+use jito_vault_core::{config::Config, vault::Vault, vault_delegation_list::VaultDelegationList};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::TestBuilder;
+
+#[tokio::test]
+async fn test_set_emergency_mode_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let backing_token_mint = Keypair::new();
+    fixture
+        .create_token_mint(&backing_token_mint)
+        .await
+        .unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &backing_token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    vault_program_client
+        .set_emergency_mode(&config_pubkey, &vault_pubkey, &config_admin, true)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert!(vault.emergency_mode());
+
+    vault_program_client
+        .set_emergency_mode(&config_pubkey, &vault_pubkey, &config_admin, false)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert!(!vault.emergency_mode());
+}
+
+#[tokio::test]
+async fn test_set_emergency_mode_vault_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let backing_token_mint = Keypair::new();
+    fixture
+        .create_token_mint(&backing_token_mint)
+        .await
+        .unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &backing_token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await
+        .unwrap();
+
+    assert!(vault_program_client
+        .set_emergency_mode(&config_pubkey, &vault_pubkey, &vault_admin, true)
+        .await
+        .is_err());
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert!(!vault.emergency_mode());
+}
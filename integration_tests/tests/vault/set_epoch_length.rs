@@ -0,0 +1,108 @@
+use jito_vault_core::{
+    config::{Config, DEFAULT_EPOCH_LENGTH, MAX_EPOCH_LENGTH, MIN_EPOCH_LENGTH},
+    result::VaultCoreError,
+};
+use jito_vault_sdk::ConfigParameter;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_config_set_epoch_length_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    vault_program_client
+        .config_set_epoch_length(&config_pubkey, &config_admin, MIN_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.epoch_length(), MIN_EPOCH_LENGTH);
+
+    // cooldowns can neither be made instant nor effectively infinite
+    assert_program_error(
+        vault_program_client
+            .config_set_epoch_length(&config_pubkey, &config_admin, MIN_EPOCH_LENGTH - 1)
+            .await,
+        VaultCoreError::ConfigInvalidEpochLength,
+    );
+    assert_program_error(
+        vault_program_client
+            .config_set_epoch_length(&config_pubkey, &config_admin, MAX_EPOCH_LENGTH + 1)
+            .await,
+        VaultCoreError::ConfigInvalidEpochLength,
+    );
+    assert_program_error(
+        vault_program_client
+            .config_set_epoch_length(&config_pubkey, &config_admin, 0)
+            .await,
+        VaultCoreError::ConfigInvalidEpochLength,
+    );
+}
+
+#[tokio::test]
+async fn test_config_set_epoch_length_not_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let attacker = Keypair::new();
+    fixture.transfer(&attacker.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        vault_program_client
+            .config_set_epoch_length(&config_pubkey, &attacker, MAX_EPOCH_LENGTH)
+            .await,
+        VaultCoreError::ConfigInvalidAdmin,
+    );
+}
+
+#[tokio::test]
+async fn test_config_set_epoch_length_after_vault_initialized_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.create_vault().await.unwrap();
+
+    // the epochs vaults recorded their state in would map to other slots
+    assert_program_error(
+        vault_program_client
+            .config_set_epoch_length(&config_pubkey, &config_admin, MIN_EPOCH_LENGTH)
+            .await,
+        VaultCoreError::ConfigEpochLengthLocked,
+    );
+    assert_program_error(
+        vault_program_client
+            .config_set_parameter(
+                &config_pubkey,
+                &config_admin,
+                ConfigParameter::EpochLength(MIN_EPOCH_LENGTH),
+            )
+            .await,
+        VaultCoreError::ConfigEpochLengthLocked,
+    );
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.epoch_length(), DEFAULT_EPOCH_LENGTH);
+}
@@ -0,0 +1,121 @@
+use jito_vault_core::{
+    config::{Config, DEFAULT_EPOCH_LENGTH},
+    result::VaultCoreError,
+    vault::{Vault, FEE_CHANGE_DELAY_EPOCHS, MAX_FEE_DELTA_BPS},
+    vault_delegation_list::VaultDelegationList,
+};
+use jito_vault_sdk::VaultAdminRole;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_set_fees_applies_after_a_full_epoch() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            100,
+        )
+        .await
+        .unwrap();
+
+    let fee_admin = Keypair::new();
+    fixture.transfer(&fee_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .set_secondary_admin(
+            &vault_pubkey,
+            &vault_admin,
+            &fee_admin.pubkey(),
+            VaultAdminRole::FeeAdmin,
+        )
+        .await
+        .unwrap();
+
+    // only the fee admin can change fees now
+    assert_program_error(
+        vault_program_client
+            .set_fees(&config_pubkey, &vault_pubkey, &vault_admin, 200, 200, 500)
+            .await,
+        VaultCoreError::VaultInvalidFeeAdmin,
+    );
+    assert_program_error(
+        vault_program_client
+            .set_fees(
+                &config_pubkey,
+                &vault_pubkey,
+                &fee_admin,
+                100,
+                100 + MAX_FEE_DELTA_BPS + 1,
+                0,
+            )
+            .await,
+        VaultCoreError::VaultFeeDeltaExceeded,
+    );
+
+    vault_program_client
+        .set_fees(&config_pubkey, &vault_pubkey, &fee_admin, 200, 350, 250)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.deposit_fee_bps_at(0), 100);
+    assert_eq!(vault.withdrawal_fee_bps_at(0), 100);
+    assert_eq!(
+        vault.pending_fees(),
+        Some((200, 250, FEE_CHANGE_DELAY_EPOCHS))
+    );
+
+    // after the delay the new fees are in effect and bound the next update
+    fixture
+        .warp_slot_incremental(FEE_CHANGE_DELAY_EPOCHS * DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    let parameters = vault_program_client
+        .get_protocol_parameters(&config_pubkey, &vault_pubkey, &fee_admin)
+        .await
+        .unwrap();
+    assert_eq!(parameters.deposit_fee_bps, 200);
+    assert_eq!(parameters.withdrawal_fee_bps, 350);
+    assert_eq!(parameters.reward_fee_bps, 250);
+    assert_eq!(parameters.pending_fees, None);
+
+    vault_program_client
+        .set_fees(
+            &config_pubkey,
+            &vault_pubkey,
+            &fee_admin,
+            200,
+            350 + MAX_FEE_DELTA_BPS,
+            250,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,218 @@
+use jito_vault_core::{
+    config::{Config, DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS, MAX_WITHDRAWAL_FEE_GRACE_EPOCHS},
+    result::VaultCoreError,
+    vault::{Vault, FEE_CHANGE_DELAY_EPOCHS, MAX_FEE_DELTA_BPS},
+    vault_delegation_list::VaultDelegationList,
+};
+use jito_vault_sdk::VaultAdminRole;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_set_withdrawal_fee_increase_waits_grace_period() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let backing_token_mint = Keypair::new();
+    fixture
+        .create_token_mint(&backing_token_mint)
+        .await
+        .unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(
+        config.withdrawal_fee_grace_epochs(),
+        DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS
+    );
+
+    vault_program_client
+        .set_withdrawal_fee_grace_period(&config_pubkey, &config_admin, 3)
+        .await
+        .unwrap();
+    let config = vault_program_client
+        .get_config(&config_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(config.withdrawal_fee_grace_epochs(), 3);
+
+    // raised fees can neither apply at once nor be put off indefinitely
+    assert!(vault_program_client
+        .set_withdrawal_fee_grace_period(&config_pubkey, &config_admin, 0)
+        .await
+        .is_err());
+    assert!(vault_program_client
+        .set_withdrawal_fee_grace_period(
+            &config_pubkey,
+            &config_admin,
+            MAX_WITHDRAWAL_FEE_GRACE_EPOCHS + 1
+        )
+        .await
+        .is_err());
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &backing_token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            100,
+        )
+        .await
+        .unwrap();
+
+    // the test validator is in epoch 0 of the vault program
+    vault_program_client
+        .set_withdrawal_fee(&config_pubkey, &vault_pubkey, &vault_admin, 300)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.withdrawal_fee_bps(), 100);
+    assert_eq!(vault.pending_withdrawal_fee(), Some((300, 3)));
+    assert_eq!(vault.withdrawal_fee_bps_at(2), 100);
+    assert_eq!(vault.withdrawal_fee_bps_at(3), 300);
+
+    // a decrease replaces the pending increase, after the fee change delay
+    vault_program_client
+        .set_withdrawal_fee(&config_pubkey, &vault_pubkey, &vault_admin, 50)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.withdrawal_fee_bps(), 100);
+    assert_eq!(
+        vault.pending_withdrawal_fee(),
+        Some((50, FEE_CHANGE_DELAY_EPOCHS))
+    );
+}
+
+#[tokio::test]
+async fn test_set_withdrawal_fee_bounded_and_fee_admin_only() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let backing_token_mint = Keypair::new();
+    fixture
+        .create_token_mint(&backing_token_mint)
+        .await
+        .unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &backing_token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            100,
+        )
+        .await
+        .unwrap();
+
+    let fee_admin = Keypair::new();
+    fixture.transfer(&fee_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .set_secondary_admin(
+            &vault_pubkey,
+            &vault_admin,
+            &fee_admin.pubkey(),
+            VaultAdminRole::FeeAdmin,
+        )
+        .await
+        .unwrap();
+
+    // the vault admin no longer sets fees, and the fee admin only within the bound
+    assert_program_error(
+        vault_program_client
+            .set_withdrawal_fee(&config_pubkey, &vault_pubkey, &vault_admin, 200)
+            .await,
+        VaultCoreError::VaultInvalidFeeAdmin,
+    );
+    assert_program_error(
+        vault_program_client
+            .set_withdrawal_fee(
+                &config_pubkey,
+                &vault_pubkey,
+                &fee_admin,
+                100 + MAX_FEE_DELTA_BPS + 1,
+            )
+            .await,
+        VaultCoreError::VaultFeeDeltaExceeded,
+    );
+    vault_program_client
+        .set_withdrawal_fee(
+            &config_pubkey,
+            &vault_pubkey,
+            &fee_admin,
+            100 + MAX_FEE_DELTA_BPS,
+        )
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.withdrawal_fee_bps(), 100);
+    assert_eq!(
+        vault.pending_withdrawal_fee(),
+        Some((100 + MAX_FEE_DELTA_BPS, DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS))
+    );
+}
+
+#[tokio::test]
+async fn test_set_withdrawal_fee_grace_period_vault_admin_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    assert!(vault_program_client
+        .set_withdrawal_fee_grace_period(&config_pubkey, &vault_admin, 0)
+        .await
+        .is_err());
+}
@@ -0,0 +1,649 @@
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config as RestakingConfig, operator::Operator, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket, result::RestakingCoreError,
+};
+use jito_restaking_sdk::AvsAdminRole;
+use jito_vault_core::{
+    config::{Config as VaultConfig, DEFAULT_EPOCH_LENGTH},
+    result::VaultCoreError,
+    slash_proposal::{SlashProposal, SlashProposalState, SLASH_VETO_WINDOW_EPOCHS},
+    vault::Vault,
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket,
+    vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList,
+    vault_operator_ticket::VaultOperatorTicket,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::fixture::{assert_program_error, TestBuilder};
+
+#[tokio::test]
+async fn test_slash_proposal_veto_and_execute() {
+    let mut fixture = TestBuilder::new().await;
+    let mut restaking_program_client = fixture.restaking_program_client();
+    let mut vault_program_client = fixture.vault_program_client();
+
+    // Initialize restaking config
+    let config_admin = fixture.upgrade_authority();
+    let restaking_config = RestakingConfig::find_program_address(&jito_restaking_program::id()).0;
+    fixture
+        .transfer(&config_admin.pubkey(), 10.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .initialize_config(&restaking_config, &config_admin)
+        .await
+        .unwrap();
+
+    // Initialize AVS
+    let avs_admin = Keypair::new();
+    let avs_base = Keypair::new();
+    fixture.transfer(&avs_admin.pubkey(), 10.0).await.unwrap();
+    let avs_pubkey = Avs::find_program_address(&jito_restaking_program::id(), &avs_base.pubkey()).0;
+    restaking_program_client
+        .initialize_avs(&restaking_config, &avs_pubkey, &avs_admin, &avs_base)
+        .await
+        .unwrap();
+
+    // Initialize operator
+    let operator_admin = Keypair::new();
+    let operator_base = Keypair::new();
+    fixture
+        .transfer(&operator_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    let operator_pubkey =
+        Operator::find_program_address(&jito_restaking_program::id(), &operator_base.pubkey()).0;
+    restaking_program_client
+        .initialize_operator(
+            &restaking_config,
+            &operator_pubkey,
+            &operator_admin,
+            &operator_base,
+        )
+        .await
+        .unwrap();
+
+    // Initialize vault config
+    let vault_config_pubkey = VaultConfig::find_program_address(&jito_vault_program::id()).0;
+    let vault_config_admin = fixture.upgrade_authority();
+    fixture
+        .transfer(&vault_config_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    vault_program_client
+        .initialize_config(&vault_config_pubkey, &vault_config_admin)
+        .await
+        .unwrap();
+
+    // Initialize Vault
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegate_list_pubkey =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let token_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+
+    fixture.create_token_mint(&token_mint).await.unwrap();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+
+    vault_program_client
+        .initialize_vault(
+            &vault_config_pubkey,
+            &vault_pubkey,
+            &vault_delegate_list_pubkey,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            100,
+        )
+        .await
+        .unwrap();
+
+    let avs_vault_ticket_pubkey = AvsVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+
+    // AVS adds vault
+    restaking_program_client
+        .avs_add_vault(
+            &restaking_config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &avs_vault_ticket_pubkey,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // Operator adds vault
+    let operator_vault_ticket_pubkey = OperatorVaultTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &vault_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_vault(
+            &restaking_config,
+            &operator_pubkey,
+            &vault_pubkey,
+            &operator_vault_ticket_pubkey,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    // operator adds avs
+    let operator_avs_ticket_pubkey = OperatorAvsTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &operator_pubkey,
+        &avs_pubkey,
+    )
+    .0;
+    restaking_program_client
+        .operator_add_avs(
+            &restaking_config,
+            &operator_pubkey,
+            &avs_pubkey,
+            &operator_avs_ticket_pubkey,
+            &operator_admin,
+            &operator_admin,
+        )
+        .await
+        .unwrap();
+
+    // avs adds operator
+    let avs_operator_ticket_pubkey = AvsOperatorTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &operator_pubkey,
+    )
+    .0;
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    restaking_program_client
+        .avs_add_operator(
+            &restaking_config,
+            &avs_pubkey,
+            &operator_pubkey,
+            &avs_operator_ticket_pubkey,
+            &operator_avs_ticket_pubkey,
+            &avs_admin,
+            &avs_admin,
+        )
+        .await
+        .unwrap();
+
+    // vault adds avs
+    let vault_avs_ticket_pubkey =
+        VaultAvsTicket::find_program_address(&jito_vault_program::id(), &vault_pubkey, &avs_pubkey)
+            .0;
+    vault_program_client
+        .add_avs(
+            &vault_config_pubkey,
+            &vault_pubkey,
+            &avs_pubkey,
+            &avs_vault_ticket_pubkey,
+            &vault_avs_ticket_pubkey,
+            &vault_admin,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+
+    // vault adds operator
+    let vault_operator_ticket = VaultOperatorTicket::find_program_address(
+        &jito_vault_program::id(),
+        &vault_pubkey,
+        &operator_pubkey,
+    )
+    .0;
+    vault_program_client
+        .add_operator(
+            &vault_config_pubkey,
+            &vault_pubkey,
+            &operator_pubkey,
+            &operator_vault_ticket_pubkey,
+            &vault_operator_ticket,
+            &vault_admin,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+
+    // AVS adds slasher
+    let slasher = Keypair::new();
+    fixture.transfer(&slasher.pubkey(), 1.0).await.unwrap();
+    let avs_slasher_ticket_pubkey = AvsVaultSlasherTicket::find_program_address(
+        &jito_restaking_program::id(),
+        &avs_pubkey,
+        &vault_pubkey,
+        &slasher.pubkey(),
+    )
+    .0;
+
+    restaking_program_client
+        .avs_add_vault_slasher(
+            &restaking_config,
+            &avs_pubkey,
+            &vault_pubkey,
+            &slasher.pubkey(),
+            &avs_vault_ticket_pubkey,
+            &avs_slasher_ticket_pubkey,
+            &avs_admin,
+            &avs_admin,
+            100,
+            [0; 32],
+        )
+        .await
+        .unwrap();
+
+    // vault adds slasher
+    let vault_slasher_ticket_pubkey = VaultAvsSlasherTicket::find_program_address(
+        &jito_vault_program::id(),
+        &vault_pubkey,
+        &avs_pubkey,
+        &slasher.pubkey(),
+    )
+    .0;
+
+    vault_program_client
+        .add_slasher(
+            &vault_config_pubkey,
+            &vault_pubkey,
+            &avs_pubkey,
+            &slasher.pubkey(),
+            &avs_slasher_ticket_pubkey,
+            &vault_slasher_ticket_pubkey,
+            &vault_admin,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+
+    let depositor_token_account =
+        get_associated_token_address(&depositor.pubkey(), &token_mint.pubkey());
+    let depositor_lrt_token_account =
+        get_associated_token_address(&depositor.pubkey(), &lrt_mint.pubkey());
+    let vault_fee_token_account =
+        get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey());
+    let vault_token_account = get_associated_token_address(&vault_pubkey, &token_mint.pubkey());
+
+    // deposit lrt receiver
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+
+    // vault fee account
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    // vault holdings
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    vault_program_client
+        .mint_to(
+            &vault_pubkey,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &depositor_token_account,
+            &vault_token_account,
+            &depositor_lrt_token_account,
+            &vault_fee_token_account,
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.lrt_supply(), 100_000);
+
+    let fee_account = fixture
+        .get_token_account(&vault_fee_token_account)
+        .await
+        .unwrap();
+    assert_eq!(fee_account.amount, 1_000);
+
+    let user_account = fixture
+        .get_token_account(&depositor_lrt_token_account)
+        .await
+        .unwrap();
+    assert_eq!(user_account.amount, 99_000);
+
+    fixture.warp_past_ticket_warmup().await.unwrap();
+    let epoch = fixture.get_slot().await.unwrap() / DEFAULT_EPOCH_LENGTH;
+    vault_program_client
+        .add_delegation(
+            &vault_config_pubkey,
+            &vault_pubkey,
+            &operator_pubkey,
+            &vault_operator_ticket,
+            &operator_vault_ticket_pubkey,
+            &vault_delegate_list_pubkey,
+            &vault_admin,
+            &vault_admin,
+            10_000,
+        )
+        .await
+        .unwrap();
+
+    let vault_delegation_list = vault_program_client
+        .get_vault_delegation_list(&vault_delegate_list_pubkey)
+        .await
+        .unwrap();
+    let delegations = vault_delegation_list.delegations();
+    assert_eq!(delegations.len(), 1);
+    assert_eq!(delegations[0].operator(), operator_pubkey);
+    assert_eq!(delegations[0].active_amount(), 10_000);
+
+    let slasher_token_account =
+        get_associated_token_address(&slasher.pubkey(), &token_mint.pubkey());
+    fixture
+        .create_ata(&token_mint.pubkey(), &slasher.pubkey())
+        .await
+        .unwrap();
+
+    // once the AVS sets a slash veto admin, slashes shall be proposed
+    let slash_veto_admin = Keypair::new();
+    fixture
+        .transfer(&slash_veto_admin.pubkey(), 1.0)
+        .await
+        .unwrap();
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &avs_admin,
+            &slash_veto_admin.pubkey(),
+            AvsAdminRole::SlashVeto,
+        )
+        .await
+        .unwrap();
+
+    let vault_avs_slasher_operator_ticket = VaultAvsSlasherOperatorTicket::find_program_address(
+        &jito_vault_program::id(),
+        &vault_pubkey,
+        &avs_pubkey,
+        &slasher.pubkey(),
+        &operator_pubkey,
+        epoch,
+    )
+    .0;
+    vault_program_client
+        .initialize_vault_avs_slasher_operator_ticket(
+            &vault_config_pubkey,
+            &vault_pubkey,
+            &avs_pubkey,
+            &slasher.pubkey(),
+            &operator_pubkey,
+            &vault_slasher_ticket_pubkey,
+            &vault_avs_slasher_operator_ticket,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+    assert_program_error(
+        vault_program_client
+            .slash(
+                &vault_config_pubkey,
+                &vault_pubkey,
+                &avs_pubkey,
+                &operator_pubkey,
+                &slasher,
+                &avs_operator_ticket_pubkey,
+                &operator_avs_ticket_pubkey,
+                &avs_vault_ticket_pubkey,
+                &operator_vault_ticket_pubkey,
+                &vault_avs_ticket_pubkey,
+                &vault_operator_ticket,
+                &avs_slasher_ticket_pubkey,
+                &vault_slasher_ticket_pubkey,
+                &vault_delegate_list_pubkey,
+                &vault_avs_slasher_operator_ticket,
+                &vault_token_account,
+                &slasher_token_account,
+                100,
+            )
+            .await
+            .map(|_| ()),
+        VaultError::SlashRequiresProposal,
+    );
+
+    // clearing the role doesn't let the AVS slash right away
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &avs_admin,
+            &Pubkey::default(),
+            AvsAdminRole::SlashVeto,
+        )
+        .await
+        .unwrap();
+    let avs = restaking_program_client.get_avs(&avs_pubkey).await.unwrap();
+    assert_eq!(avs.slash_veto_admin(), None);
+    assert!(avs.slash_proposals_required());
+    fixture.warp_to_next_slot().await.unwrap();
+    assert_program_error(
+        vault_program_client
+            .slash(
+                &vault_config_pubkey,
+                &vault_pubkey,
+                &avs_pubkey,
+                &operator_pubkey,
+                &slasher,
+                &avs_operator_ticket_pubkey,
+                &operator_avs_ticket_pubkey,
+                &avs_vault_ticket_pubkey,
+                &operator_vault_ticket_pubkey,
+                &vault_avs_ticket_pubkey,
+                &vault_operator_ticket,
+                &avs_slasher_ticket_pubkey,
+                &vault_slasher_ticket_pubkey,
+                &vault_delegate_list_pubkey,
+                &vault_avs_slasher_operator_ticket,
+                &vault_token_account,
+                &slasher_token_account,
+                100,
+            )
+            .await
+            .map(|_| ()),
+        VaultError::SlashRequiresProposal,
+    );
+    restaking_program_client
+        .avs_set_secondary_admin(
+            &avs_pubkey,
+            &avs_admin,
+            &slash_veto_admin.pubkey(),
+            AvsAdminRole::SlashVeto,
+        )
+        .await
+        .unwrap();
+
+    let [executed, vetoed] = [[1; 32], [2; 32]].map(|evidence_hash| {
+        SlashProposal::find_program_address(
+            &jito_vault_program::id(),
+            &vault_pubkey,
+            &avs_pubkey,
+            &slasher.pubkey(),
+            &operator_pubkey,
+            &evidence_hash,
+        )
+        .0
+    });
+    for (slash_proposal, evidence_hash) in [(executed, [1; 32]), (vetoed, [2; 32])] {
+        vault_program_client
+            .propose_slash(
+                &vault_config_pubkey,
+                &vault_pubkey,
+                &avs_pubkey,
+                &operator_pubkey,
+                &slasher,
+                &avs_slasher_ticket_pubkey,
+                &vault_slasher_ticket_pubkey,
+                &slash_proposal,
+                100,
+                evidence_hash,
+            )
+            .await
+            .unwrap();
+    }
+    let slash_proposal = vault_program_client
+        .get_slash_proposal(&executed)
+        .await
+        .unwrap();
+    assert_eq!(slash_proposal.amount(), 100);
+    assert_eq!(
+        slash_proposal.executable_epoch(),
+        epoch + SLASH_VETO_WINDOW_EPOCHS
+    );
+    assert_eq!(slash_proposal.state(), SlashProposalState::Pending);
+    let ticket = vault_program_client
+        .get_vault_avs_ticket(&vault_pubkey, &avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.pending_slash_proposals(), 2);
+
+    // only the slash veto admin can veto, and only within the window
+    let attacker = Keypair::new();
+    fixture.transfer(&attacker.pubkey(), 1.0).await.unwrap();
+    assert_program_error(
+        vault_program_client
+            .veto_slash(&vault_config_pubkey, &avs_pubkey, &vetoed, &attacker)
+            .await,
+        RestakingCoreError::AvsInvalidSlashVetoAdmin,
+    );
+    vault_program_client
+        .veto_slash(
+            &vault_config_pubkey,
+            &avs_pubkey,
+            &vetoed,
+            &slash_veto_admin,
+        )
+        .await
+        .unwrap();
+    let slash_proposal = vault_program_client
+        .get_slash_proposal(&vetoed)
+        .await
+        .unwrap();
+    assert_eq!(slash_proposal.state(), SlashProposalState::Vetoed);
+
+    fixture
+        .warp_slot_incremental(SLASH_VETO_WINDOW_EPOCHS * DEFAULT_EPOCH_LENGTH)
+        .await
+        .unwrap();
+    assert_program_error(
+        vault_program_client
+            .veto_slash(
+                &vault_config_pubkey,
+                &avs_pubkey,
+                &executed,
+                &slash_veto_admin,
+            )
+            .await,
+        VaultCoreError::SlashProposalVetoWindowClosed,
+    );
+
+    // anyone can execute the slash once the window has passed
+    let vault_avs_slasher_operator_ticket = VaultAvsSlasherOperatorTicket::find_program_address(
+        &jito_vault_program::id(),
+        &vault_pubkey,
+        &avs_pubkey,
+        &slasher.pubkey(),
+        &operator_pubkey,
+        epoch + SLASH_VETO_WINDOW_EPOCHS,
+    )
+    .0;
+    vault_program_client
+        .initialize_vault_avs_slasher_operator_ticket(
+            &vault_config_pubkey,
+            &vault_pubkey,
+            &avs_pubkey,
+            &slasher.pubkey(),
+            &operator_pubkey,
+            &vault_slasher_ticket_pubkey,
+            &vault_avs_slasher_operator_ticket,
+            &vault_admin,
+        )
+        .await
+        .unwrap();
+    for (slash_proposal, result) in [
+        (executed, Ok(())),
+        (executed, Err(VaultCoreError::SlashProposalNotPending)),
+        (vetoed, Err(VaultCoreError::SlashProposalNotPending)),
+    ] {
+        let executed = vault_program_client
+            .execute_slash(
+                &slash_proposal,
+                &vault_config_pubkey,
+                &vault_pubkey,
+                &avs_pubkey,
+                &operator_pubkey,
+                &slasher.pubkey(),
+                &avs_operator_ticket_pubkey,
+                &operator_avs_ticket_pubkey,
+                &avs_vault_ticket_pubkey,
+                &operator_vault_ticket_pubkey,
+                &vault_avs_ticket_pubkey,
+                &vault_operator_ticket,
+                &avs_slasher_ticket_pubkey,
+                &vault_slasher_ticket_pubkey,
+                &vault_delegate_list_pubkey,
+                &vault_avs_slasher_operator_ticket,
+                &vault_token_account,
+                &slasher_token_account,
+                &attacker,
+            )
+            .await;
+        match result {
+            Ok(()) => executed.unwrap(),
+            Err(error) => assert_program_error(executed, error),
+        }
+    }
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 99_900);
+    let slasher_account = fixture
+        .get_token_account(&slasher_token_account)
+        .await
+        .unwrap();
+    assert_eq!(slasher_account.amount, 100);
+    let slash_proposal = vault_program_client
+        .get_slash_proposal(&executed)
+        .await
+        .unwrap();
+    assert_eq!(slash_proposal.state(), SlashProposalState::Executed);
+    let ticket = vault_program_client
+        .get_vault_avs_ticket(&vault_pubkey, &avs_pubkey)
+        .await
+        .unwrap();
+    assert_eq!(ticket.pending_slash_proposals(), 0);
+}
@@ -0,0 +1,175 @@
+use jito_vault_core::{config::Config, vault::Vault, vault_delegation_list::VaultDelegationList};
+use jito_vault_sdk::error::VaultError;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+const EPOCH_LENGTH: u64 = 864_000;
+
+fn ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, &spl_token_2022::id())
+}
+
+/// Initializes the config and a vault without fees around the mint
+async fn initialize_vault(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+    token_mint: &Keypair,
+) -> (Pubkey, Keypair, Keypair, Result<(), BanksClientError>) {
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    let result = vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            token_mint,
+            &vault_admin,
+            &vault_base,
+            0,
+            0,
+        )
+        .await;
+
+    (vault_pubkey, lrt_mint, vault_admin, result)
+}
+
+#[tokio::test]
+async fn test_token_2022_transfer_fee_deposit_and_withdraw_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    // 1% transfer fee
+    let token_mint = Keypair::new();
+    fixture
+        .create_token_2022_mint(&token_mint, 100, None)
+        .await
+        .unwrap();
+    let (vault_pubkey, lrt_mint, vault_admin, result) =
+        initialize_vault(&mut fixture, &mut vault_program_client, &token_mint).await;
+    result.unwrap();
+    let (token_mint, lrt_mint) = (token_mint.pubkey(), lrt_mint.pubkey());
+
+    // the LRT mint lives under the supported mint's token program
+    assert_eq!(
+        fixture.get_token_program(&lrt_mint).await.unwrap(),
+        spl_token_2022::id()
+    );
+
+    let staker = Keypair::new();
+    fixture.transfer(&staker.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint, &staker.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint, &staker.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint, &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint, &vault_pubkey)
+        .await
+        .unwrap();
+
+    vault_program_client
+        .mint_to(
+            &vault_pubkey,
+            &lrt_mint,
+            &staker,
+            &ata(&staker.pubkey(), &token_mint),
+            &ata(&vault_pubkey, &token_mint),
+            &ata(&staker.pubkey(), &lrt_mint),
+            &ata(&vault_admin.pubkey(), &lrt_mint),
+            None,
+            10_000,
+        )
+        .await
+        .unwrap();
+
+    // LRT is only minted for the 9_900 tokens left after the transfer fee
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 9_900);
+    assert_eq!(vault.lrt_supply(), 9_900);
+    assert_eq!(
+        fixture
+            .get_token_account(&ata(&vault_pubkey, &token_mint))
+            .await
+            .unwrap()
+            .amount,
+        9_900
+    );
+    assert_eq!(
+        fixture
+            .get_token_account(&ata(&staker.pubkey(), &lrt_mint))
+            .await
+            .unwrap()
+            .amount,
+        9_900
+    );
+
+    let base = Keypair::new();
+    vault_program_client
+        .enqueue_withdrawal(&vault_pubkey, &staker, &base, None, 9_900)
+        .await
+        .unwrap();
+    fixture.warp_slot_incremental(EPOCH_LENGTH).await.unwrap();
+    vault_program_client
+        .burn_withdrawal_ticket(&staker, &vault_pubkey, &staker.pubkey(), &base.pubkey())
+        .await
+        .unwrap();
+
+    // the vault sends all 9_900 tokens and the staker bears the transfer fee on the way out
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 0);
+    assert_eq!(vault.lrt_supply(), 0);
+    assert_eq!(
+        fixture
+            .get_token_account(&ata(&staker.pubkey(), &token_mint))
+            .await
+            .unwrap()
+            .amount,
+        90_000 + 9_801
+    );
+}
+
+#[tokio::test]
+async fn test_token_2022_disallowed_extension_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+
+    // a permanent delegate could move the vault's tokens
+    let token_mint = Keypair::new();
+    fixture
+        .create_token_2022_mint(&token_mint, 0, Some(&Pubkey::new_unique()))
+        .await
+        .unwrap();
+    let (_, _, _, result) =
+        initialize_vault(&mut fixture, &mut vault_program_client, &token_mint).await;
+
+    assert_program_error(result, VaultError::SupportedMintExtensionNotAllowed);
+}
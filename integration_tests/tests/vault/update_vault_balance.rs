@@ -0,0 +1,150 @@
+use jito_vault_core::{result::VaultCoreError, vault::FEE_CHANGE_DELAY_EPOCHS};
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{
+    fixture::{assert_program_error, RelationshipGraph, TestBuilder},
+    vault_client::VaultProgramClient,
+};
+
+/// A vault holding a 100_000 token deposit, a 2.5% reward fee in effect and 10_000 tokens donated
+/// to it since
+async fn setup() -> (TestBuilder, VaultProgramClient, RelationshipGraph, Keypair) {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let graph = fixture.setup_vault_with_operator_and_avs().await.unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&graph.lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .mint_to(
+            &graph.vault,
+            &graph.lrt_mint.pubkey(),
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &graph.token_mint.pubkey()),
+            &graph.vault_token_account,
+            &get_associated_token_address(&depositor.pubkey(), &graph.lrt_mint.pubkey()),
+            &graph.vault_fee_token_account,
+            None,
+            100_000,
+        )
+        .await
+        .unwrap();
+
+    vault_program_client
+        .set_fees(
+            &graph.vault_config,
+            &graph.vault,
+            &graph.vault_admin,
+            0,
+            0,
+            250,
+        )
+        .await
+        .unwrap();
+    let epoch_length = vault_program_client
+        .get_config(&graph.vault_config)
+        .await
+        .unwrap()
+        .epoch_length();
+    let epoch = fixture.get_slot().await.unwrap() / epoch_length;
+    fixture
+        .warp_to_epoch(epoch + FEE_CHANGE_DELAY_EPOCHS, epoch_length)
+        .await
+        .unwrap();
+
+    // rewards airdropped straight to the vault token account
+    fixture
+        .mint_to(&graph.token_mint.pubkey(), &graph.vault, 10_000)
+        .await
+        .unwrap();
+
+    (fixture, vault_program_client, graph, depositor)
+}
+
+#[tokio::test]
+async fn test_update_vault_balance_takes_reward_fee_ok() {
+    let (mut fixture, mut vault_program_client, graph, _) = setup().await;
+
+    // anyone can crank
+    let cranker = Keypair::new();
+    fixture.transfer(&cranker.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .update_vault_balance(&cranker, &graph.vault)
+        .await
+        .unwrap();
+
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 110_000);
+    // 250 of the 10_000 tokens of rewards are the fee, so the fee owner holds 250 / 110_000 of the
+    // LRT after the harvest
+    let lrt_to_reward_fee = 250 * 100_000 / 109_750;
+    assert_eq!(vault.lrt_supply(), 100_000 + lrt_to_reward_fee);
+    let fee_token_account = fixture
+        .get_token_account(&graph.vault_fee_token_account)
+        .await
+        .unwrap();
+    assert_eq!(fee_token_account.amount, lrt_to_reward_fee);
+
+    // nothing left to harvest, cranked in a new slot so the transaction isn't a duplicate
+    fixture.warp_to_next_slot().await.unwrap();
+    vault_program_client
+        .update_vault_balance(&cranker, &graph.vault)
+        .await
+        .unwrap();
+    let vault = vault_program_client.get_vault(&graph.vault).await.unwrap();
+    assert_eq!(vault.lrt_supply(), 100_000 + lrt_to_reward_fee);
+}
+
+#[tokio::test]
+async fn test_withdrawal_before_update_vault_balance_fails() {
+    let (mut fixture, mut vault_program_client, graph, depositor) = setup().await;
+
+    let base = Keypair::new();
+    vault_program_client
+        .enqueue_withdrawal(&graph.vault, &depositor, &base, None, 10_000)
+        .await
+        .unwrap();
+    let epoch_length = vault_program_client
+        .get_config(&graph.vault_config)
+        .await
+        .unwrap()
+        .epoch_length();
+    let epoch = fixture.get_slot().await.unwrap() / epoch_length;
+    fixture
+        .warp_to_epoch(epoch + 2, epoch_length)
+        .await
+        .unwrap();
+
+    let result = vault_program_client
+        .burn_withdrawal_ticket(
+            &depositor,
+            &graph.vault,
+            &depositor.pubkey(),
+            &base.pubkey(),
+        )
+        .await;
+    assert_program_error(result, VaultCoreError::VaultBalanceUpdateRequired);
+
+    vault_program_client
+        .update_vault_balance(&depositor, &graph.vault)
+        .await
+        .unwrap();
+    vault_program_client
+        .burn_withdrawal_ticket(
+            &depositor,
+            &graph.vault,
+            &depositor.pubkey(),
+            &base.pubkey(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,169 @@
+use jito_vault_core::{config::Config, vault::Vault, vault_delegation_list::VaultDelegationList};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::fixtures::{fixture::TestBuilder, vault_client::VaultProgramClient};
+
+/// Initializes a vault with a 1% deposit fee and returns it with its LRT mint, fee token account
+/// and a depositor holding 100,000 supported tokens
+async fn setup(
+    fixture: &mut TestBuilder,
+    vault_program_client: &mut VaultProgramClient,
+) -> (Pubkey, Keypair, Pubkey, Keypair) {
+    let token_mint = Keypair::new();
+    fixture.create_token_mint(&token_mint).await.unwrap();
+
+    let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+    let config_admin = fixture.upgrade_authority();
+    fixture.transfer(&config_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_config(&config_pubkey, &config_admin)
+        .await
+        .unwrap();
+
+    let vault_base = Keypair::new();
+    let vault_pubkey =
+        Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
+    let vault_delegation_list =
+        VaultDelegationList::find_program_address(&jito_vault_program::id(), &vault_pubkey).0;
+    let lrt_mint = Keypair::new();
+    let vault_admin = Keypair::new();
+    fixture.transfer(&vault_admin.pubkey(), 1.0).await.unwrap();
+    vault_program_client
+        .initialize_vault(
+            &config_pubkey,
+            &vault_pubkey,
+            &vault_delegation_list,
+            &lrt_mint,
+            &token_mint,
+            &vault_admin,
+            &vault_base,
+            100,
+            0,
+        )
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &vault_admin.pubkey())
+        .await
+        .unwrap();
+    fixture
+        .create_ata(&token_mint.pubkey(), &vault_pubkey)
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    fixture.transfer(&depositor.pubkey(), 1.0).await.unwrap();
+    fixture
+        .mint_to(&token_mint.pubkey(), &depositor.pubkey(), 100_000)
+        .await
+        .unwrap();
+
+    let vault_fee_token_account =
+        get_associated_token_address(&vault_admin.pubkey(), &lrt_mint.pubkey());
+    (vault_pubkey, lrt_mint, vault_fee_token_account, depositor)
+}
+
+#[tokio::test]
+async fn test_zap_deposit_to_new_recipient_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let (vault_pubkey, lrt_mint, vault_fee_token_account, depositor) =
+        setup(&mut fixture, &mut vault_program_client).await;
+
+    // the recipient has no LRT token account yet
+    let recipient = Pubkey::new_unique();
+    vault_program_client
+        .zap_deposit(
+            &vault_pubkey,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &recipient,
+            &vault_fee_token_account,
+            None,
+            100_000,
+            99_000,
+        )
+        .await
+        .unwrap();
+
+    let recipient_lrt = fixture
+        .get_token_account(&get_associated_token_address(
+            &recipient,
+            &lrt_mint.pubkey(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(recipient_lrt.amount, 99_000);
+    let fee_lrt = fixture
+        .get_token_account(&vault_fee_token_account)
+        .await
+        .unwrap();
+    assert_eq!(fee_lrt.amount, 1_000);
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 100_000);
+    assert_eq!(vault.lrt_supply(), 100_000);
+}
+
+#[tokio::test]
+async fn test_zap_deposit_to_existing_recipient_ok() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let (vault_pubkey, lrt_mint, vault_fee_token_account, depositor) =
+        setup(&mut fixture, &mut vault_program_client).await;
+
+    fixture
+        .create_ata(&lrt_mint.pubkey(), &depositor.pubkey())
+        .await
+        .unwrap();
+    vault_program_client
+        .zap_deposit(
+            &vault_pubkey,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &depositor.pubkey(),
+            &vault_fee_token_account,
+            None,
+            50_000,
+            0,
+        )
+        .await
+        .unwrap();
+
+    let depositor_lrt = fixture
+        .get_token_account(&get_associated_token_address(
+            &depositor.pubkey(),
+            &lrt_mint.pubkey(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(depositor_lrt.amount, 49_500);
+}
+
+#[tokio::test]
+async fn test_zap_deposit_slippage_exceeded_fails() {
+    let mut fixture = TestBuilder::new().await;
+    let mut vault_program_client = fixture.vault_program_client();
+    let (vault_pubkey, lrt_mint, vault_fee_token_account, depositor) =
+        setup(&mut fixture, &mut vault_program_client).await;
+
+    assert!(vault_program_client
+        .zap_deposit(
+            &vault_pubkey,
+            &lrt_mint.pubkey(),
+            &depositor,
+            &Pubkey::new_unique(),
+            &vault_fee_token_account,
+            None,
+            100_000,
+            99_001,
+        )
+        .await
+        .is_err());
+
+    let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+    assert_eq!(vault.tokens_deposited(), 0);
+    assert_eq!(vault.lrt_supply(), 0);
+}
@@ -1,54 +1,165 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    counter::Counter,
+    pod::{PodBool, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+/// The max number of slashers a new AVS allows per vault, unless the config admin sets another
+/// default
+pub const DEFAULT_MAX_SLASHERS_PER_VAULT: u64 = 8;
+
+/// The lowest nonzero max heartbeat age an AVS can set, about five minutes at 400ms slots. Any
+/// lower and operators could be ejected over a few dropped transactions.
+pub const MIN_MAX_HEARTBEAT_AGE: u64 = 750;
+
+/// The highest max heartbeat age an AVS can set, about forty days
+pub const MAX_MAX_HEARTBEAT_AGE: u64 = 8_640_000;
+
+/// The number of keys in the multisig of an AVS
+pub const AVS_MULTISIG_SIGNERS: usize = 3;
+
+/// The lowest threshold of an enabled AVS multisig, so a single leaked key can't pass it
+pub const MIN_AVS_MULTISIG_THRESHOLD: u8 = 2;
+
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct Avs {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The base account used as a PDA seed
+    #[serde(serialize_with = "serde_fields::pubkey")]
     base: Pubkey,
 
     /// The admin of the AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     admin: Pubkey,
 
     /// The operator admin of the AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator_admin: Pubkey,
 
     /// The vault admin of the AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault_admin: Pubkey,
 
     /// The slasher admin of the AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     slasher_admin: Pubkey,
 
     /// The withdraw admin of the AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     withdraw_admin: Pubkey,
 
     /// The index of the AVS
-    index: u64,
+    index: PodU64,
 
     /// Number of operator accounts associated with the AVS
-    operator_count: u64,
+    operator_count: Counter,
 
     /// Number of vault accounts associated with the AVS
-    vault_count: u64,
+    vault_count: Counter,
 
     /// Number of slasher accounts associated with the AVS
-    slasher_count: u64,
+    slasher_count: Counter,
+
+    /// The max number of slots since an operator's last heartbeat before it can be ejected.
+    /// Zero disables ejection.
+    max_heartbeat_age: PodU64,
+
+    /// The key allowed to submit admin operations signed off-chain by the AVS admins, or the
+    /// default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    relayer: Pubkey,
+
+    /// The nonce the next authorization signed off-chain by the admins shall carry, see
+    /// [`Avs::use_nonce`]
+    nonce: Counter,
+
+    /// The max number of active slashers the AVS allows for each vault
+    max_slashers_per_vault: PodU64,
+
+    /// The metadata admin of the AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    metadata_admin: Pubkey,
+
+    /// The key proposed to take over as admin, or the default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    pending_admin: Pubkey,
+
+    /// The keys of the multisig guarding the high-risk operations of the AVS
+    #[serde(serialize_with = "serde_fields::pubkeys")]
+    multisig_signers: [Pubkey; AVS_MULTISIG_SIGNERS],
+
+    /// The number of multisig keys that shall sign high-risk operations. Zero disables the
+    /// multisig.
+    multisig_threshold: u8,
+
+    /// The account receiving the rent of the AVS tickets closed once deactivated
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    rent_collector: Pubkey,
+
+    /// The key allowed to attest the performance scores of the operators of the AVS, or the
+    /// default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    scorer: Pubkey,
+
+    /// The key allowed to veto slash proposals against the AVS's vaults during the veto window,
+    /// or the default pubkey if none. Once set, slashes of the AVS shall go through a proposal.
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    slash_veto_admin: Pubkey,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Number of operator tickets of the AVS closed, which the health checks no longer expect
+    closed_operator_count: Counter,
+
+    /// Number of vault tickets of the AVS closed, which the health checks no longer expect
+    closed_vault_count: Counter,
+
+    /// Number of slasher tickets of the AVS closed, which the health checks no longer expect
+    closed_slasher_count: Counter,
+
+    /// Whether a slash veto admin was ever set, after which slashes of the AVS shall go through
+    /// a proposal even if the role is cleared, so clearing it can't skip the veto window
+    slash_proposals_required: PodBool,
 
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 38],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
+impl ZeroCopy for Avs {
+    const DISCRIMINATOR: u8 = AccountType::Avs as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl Avs {
     #[allow(clippy::too_many_arguments)]
     pub const fn new(
@@ -62,18 +173,34 @@ impl Avs {
         bump: u8,
     ) -> Self {
         Self {
-            account_type: AccountType::Avs,
+            discriminator: AccountType::Avs as u8,
             base,
             admin,
             operator_admin,
             vault_admin,
             slasher_admin,
             withdraw_admin,
-            index: avs_index,
-            operator_count: 0,
-            vault_count: 0,
-            slasher_count: 0,
-            reserved: [0; 128],
+            index: PodU64::new(avs_index),
+            operator_count: Counter::new(0),
+            vault_count: Counter::new(0),
+            slasher_count: Counter::new(0),
+            max_heartbeat_age: PodU64::new(0),
+            relayer: Pubkey::new_from_array([0; 32]),
+            nonce: Counter::new(0),
+            max_slashers_per_vault: PodU64::new(DEFAULT_MAX_SLASHERS_PER_VAULT),
+            metadata_admin: admin,
+            pending_admin: Pubkey::new_from_array([0; 32]),
+            multisig_signers: [Pubkey::new_from_array([0; 32]); AVS_MULTISIG_SIGNERS],
+            multisig_threshold: 0,
+            rent_collector: admin,
+            scorer: Pubkey::new_from_array([0; 32]),
+            slash_veto_admin: Pubkey::new_from_array([0; 32]),
+            version: Self::VERSION,
+            closed_operator_count: Counter::new(0),
+            closed_vault_count: Counter::new(0),
+            closed_slasher_count: Counter::new(0),
+            slash_proposals_required: PodBool::new(false),
+            reserved: [0; 38],
             bump,
         }
     }
@@ -102,8 +229,12 @@ impl Avs {
         self.withdraw_admin
     }
 
+    pub const fn metadata_admin(&self) -> Pubkey {
+        self.metadata_admin
+    }
+
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn bump(&self) -> u8 {
@@ -111,43 +242,257 @@ impl Avs {
     }
 
     pub const fn operator_count(&self) -> u64 {
-        self.operator_count
+        self.operator_count.get()
     }
 
     pub fn increment_operator_count(&mut self) -> RestakingCoreResult<()> {
-        self.operator_count = self
-            .operator_count
-            .checked_add(1)
-            .ok_or(RestakingCoreError::AvsOperatorCountOverflow)?;
+        self.operator_count.increment(
+            "operator_count",
+            RestakingCoreError::AvsOperatorCountOverflow,
+        )?;
         Ok(())
     }
 
     pub const fn vault_count(&self) -> u64 {
-        self.vault_count
+        self.vault_count.get()
     }
 
     pub fn increment_vault_count(&mut self) -> RestakingCoreResult<()> {
-        self.vault_count = self
-            .vault_count
-            .checked_add(1)
-            .ok_or(RestakingCoreError::AvsVaultCountOverflow)?;
+        self.vault_count
+            .increment("vault_count", RestakingCoreError::AvsVaultCountOverflow)?;
         Ok(())
     }
 
     pub const fn slasher_count(&self) -> u64 {
-        self.slasher_count
+        self.slasher_count.get()
     }
 
     pub fn increment_slasher_count(&mut self) -> RestakingCoreResult<()> {
-        self.slasher_count = self
-            .slasher_count
-            .checked_add(1)
-            .ok_or(RestakingCoreError::AvsSlasherCountOverflow)?;
+        self.slasher_count
+            .increment("slasher_count", RestakingCoreError::AvsSlasherCountOverflow)?;
+        Ok(())
+    }
+
+    pub const fn closed_operator_count(&self) -> u64 {
+        self.closed_operator_count.get()
+    }
+
+    pub fn increment_closed_operator_count(&mut self) -> RestakingCoreResult<()> {
+        self.closed_operator_count.increment(
+            "closed_operator_count",
+            RestakingCoreError::AvsOperatorCountOverflow,
+        )?;
+        Ok(())
+    }
+
+    pub const fn closed_vault_count(&self) -> u64 {
+        self.closed_vault_count.get()
+    }
+
+    pub fn increment_closed_vault_count(&mut self) -> RestakingCoreResult<()> {
+        self.closed_vault_count.increment(
+            "closed_vault_count",
+            RestakingCoreError::AvsVaultCountOverflow,
+        )?;
         Ok(())
     }
 
-    pub fn set_admin(&mut self, admin: Pubkey) {
-        self.admin = admin;
+    pub const fn closed_slasher_count(&self) -> u64 {
+        self.closed_slasher_count.get()
+    }
+
+    pub fn increment_closed_slasher_count(&mut self) -> RestakingCoreResult<()> {
+        self.closed_slasher_count.increment(
+            "closed_slasher_count",
+            RestakingCoreError::AvsSlasherCountOverflow,
+        )?;
+        Ok(())
+    }
+
+    pub const fn max_heartbeat_age(&self) -> u64 {
+        self.max_heartbeat_age.get()
+    }
+
+    /// Sets the max heartbeat age, which shall be zero to disable ejection or within
+    /// [`MIN_MAX_HEARTBEAT_AGE`] and [`MAX_MAX_HEARTBEAT_AGE`]
+    pub fn set_max_heartbeat_age(&mut self, max_heartbeat_age: u64) -> RestakingCoreResult<()> {
+        if max_heartbeat_age != 0
+            && !(MIN_MAX_HEARTBEAT_AGE..=MAX_MAX_HEARTBEAT_AGE).contains(&max_heartbeat_age)
+        {
+            return Err(RestakingCoreError::AvsInvalidMaxHeartbeatAge);
+        }
+        self.max_heartbeat_age = PodU64::new(max_heartbeat_age);
+        Ok(())
+    }
+
+    /// Check if an operator heartbeat of the given age is stale enough to eject the operator
+    pub const fn check_heartbeat_stale(&self, heartbeat_age: u64) -> RestakingCoreResult<()> {
+        if self.max_heartbeat_age.get() == 0 {
+            return Err(RestakingCoreError::AvsHeartbeatEjectionDisabled);
+        }
+        if heartbeat_age <= self.max_heartbeat_age.get() {
+            return Err(RestakingCoreError::AvsOperatorHeartbeatNotStale);
+        }
+        Ok(())
+    }
+
+    /// Check if an operator heartbeat of the given age is recent enough to re-activate the operator
+    pub const fn check_heartbeat_fresh(&self, heartbeat_age: u64) -> RestakingCoreResult<()> {
+        if self.max_heartbeat_age.get() != 0 && heartbeat_age > self.max_heartbeat_age.get() {
+            return Err(RestakingCoreError::AvsOperatorHeartbeatStale);
+        }
+        Ok(())
+    }
+
+    pub const fn max_slashers_per_vault(&self) -> u64 {
+        self.max_slashers_per_vault.get()
+    }
+
+    pub fn set_max_slashers_per_vault(&mut self, max_slashers_per_vault: u64) {
+        self.max_slashers_per_vault = PodU64::new(max_slashers_per_vault);
+    }
+
+    pub fn relayer(&self) -> Option<Pubkey> {
+        if self.relayer == Pubkey::default() {
+            None
+        } else {
+            Some(self.relayer)
+        }
+    }
+
+    pub fn set_relayer(&mut self, relayer: Pubkey) {
+        self.relayer = relayer;
+    }
+
+    pub const fn rent_collector(&self) -> Pubkey {
+        self.rent_collector
+    }
+
+    pub fn set_rent_collector(&mut self, rent_collector: Pubkey) {
+        self.rent_collector = rent_collector;
+    }
+
+    /// Check if the provided pubkey is the relayer of the AVS
+    pub fn check_relayer(&self, relayer: &Pubkey) -> RestakingCoreResult<()> {
+        if self.relayer() != Some(*relayer) {
+            return Err(RestakingCoreError::AvsInvalidRelayer);
+        }
+        Ok(())
+    }
+
+    pub fn scorer(&self) -> Option<Pubkey> {
+        if self.scorer == Pubkey::default() {
+            None
+        } else {
+            Some(self.scorer)
+        }
+    }
+
+    pub fn set_scorer(&mut self, scorer: Pubkey) {
+        self.scorer = scorer;
+    }
+
+    /// Check if the provided pubkey is the scorer of the AVS
+    pub fn check_scorer(&self, scorer: &Pubkey) -> RestakingCoreResult<()> {
+        if self.scorer() != Some(*scorer) {
+            return Err(RestakingCoreError::AvsInvalidScorer);
+        }
+        Ok(())
+    }
+
+    /// The nonce the next message signed off-chain by the admins shall carry. Signers fetch it
+    /// before signing a relayed admin operation or a session key grant.
+    pub const fn nonce(&self) -> u64 {
+        self.nonce.get()
+    }
+
+    /// Consumes the nonce of a message signed off-chain by the admins, so the message can't be
+    /// replayed. Every signature-authorized flow shares the nonce, so messages land in the order
+    /// they were signed.
+    pub fn use_nonce(&mut self, nonce: u64) -> RestakingCoreResult<()> {
+        if nonce != self.nonce.get() {
+            return Err(RestakingCoreError::AvsInvalidNonce);
+        }
+        self.nonce
+            .increment("nonce", RestakingCoreError::AvsInvalidNonce)?;
+        Ok(())
+    }
+
+    /// The key proposed to take over as admin, if a transfer is pending
+    pub fn pending_admin(&self) -> Option<Pubkey> {
+        if self.pending_admin != Pubkey::default() {
+            Some(self.pending_admin)
+        } else {
+            None
+        }
+    }
+
+    /// Proposes a new admin, replacing any transfer that is already pending. The transfer
+    /// completes once the new admin accepts it.
+    pub fn set_pending_admin(&mut self, pending_admin: Pubkey) {
+        self.pending_admin = pending_admin;
+    }
+
+    /// Completes the pending admin transfer to `new_admin`
+    pub fn accept_admin(&mut self, new_admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.pending_admin() != Some(*new_admin) {
+            return Err(RestakingCoreError::AvsInvalidPendingAdmin);
+        }
+        self.admin = *new_admin;
+        self.pending_admin = Pubkey::default();
+        Ok(())
+    }
+
+    pub const fn multisig_signers(&self) -> [Pubkey; AVS_MULTISIG_SIGNERS] {
+        self.multisig_signers
+    }
+
+    pub const fn multisig_threshold(&self) -> u8 {
+        self.multisig_threshold
+    }
+
+    /// Sets the multisig guarding high-risk operations. A zero threshold disables it, otherwise
+    /// the threshold shall be at least [`MIN_AVS_MULTISIG_THRESHOLD`] and the keys distinct.
+    pub fn set_multisig(
+        &mut self,
+        signers: [Pubkey; AVS_MULTISIG_SIGNERS],
+        threshold: u8,
+    ) -> RestakingCoreResult<()> {
+        if threshold == 0 {
+            self.multisig_signers = [Pubkey::default(); AVS_MULTISIG_SIGNERS];
+            self.multisig_threshold = 0;
+            return Ok(());
+        }
+        if !(MIN_AVS_MULTISIG_THRESHOLD as usize..=AVS_MULTISIG_SIGNERS)
+            .contains(&(threshold as usize))
+        {
+            return Err(RestakingCoreError::AvsInvalidMultisig);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if *signer == Pubkey::default() || signers[..i].contains(signer) {
+                return Err(RestakingCoreError::AvsInvalidMultisig);
+            }
+        }
+        self.multisig_signers = signers;
+        self.multisig_threshold = threshold;
+        Ok(())
+    }
+
+    /// Check if enough multisig keys signed among the accounts, if the multisig is enabled
+    pub fn check_multisig(&self, accounts: &[AccountInfo]) -> RestakingCoreResult<()> {
+        let signed = self
+            .multisig_signers
+            .iter()
+            .filter(|signer| {
+                accounts
+                    .iter()
+                    .any(|account| account.is_signer && account.key == *signer)
+            })
+            .count();
+        if signed < self.multisig_threshold as usize {
+            return Err(RestakingCoreError::AvsMultisigThresholdNotMet);
+        }
+        Ok(())
     }
 
     /// Check if the provided pubkey is the admin of the AVS
@@ -206,6 +551,48 @@ impl Avs {
         Ok(())
     }
 
+    pub fn set_metadata_admin(&mut self, metadata_admin: Pubkey) {
+        self.metadata_admin = metadata_admin;
+    }
+
+    /// Check if the provided pubkey is the metadata admin of the AVS
+    pub fn check_metadata_admin(&self, metadata_admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.metadata_admin != *metadata_admin {
+            return Err(RestakingCoreError::AvsInvalidMetadataAdmin);
+        }
+        Ok(())
+    }
+
+    pub fn slash_veto_admin(&self) -> Option<Pubkey> {
+        if self.slash_veto_admin == Pubkey::default() {
+            None
+        } else {
+            Some(self.slash_veto_admin)
+        }
+    }
+
+    /// Sets the slash veto admin. Setting one requires proposals for every later slash of the
+    /// AVS, see [`Avs::slash_proposals_required`].
+    pub fn set_slash_veto_admin(&mut self, slash_veto_admin: Pubkey) {
+        self.slash_veto_admin = slash_veto_admin;
+        if slash_veto_admin != Pubkey::default() {
+            self.slash_proposals_required = PodBool::new(true);
+        }
+    }
+
+    /// Whether slashes of the AVS shall be proposed, which holds once a slash veto admin was set
+    pub const fn slash_proposals_required(&self) -> bool {
+        self.slash_proposals_required.get()
+    }
+
+    /// Check if the provided pubkey is the slash veto admin of the AVS
+    pub fn check_slash_veto_admin(&self, slash_veto_admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.slash_veto_admin() != Some(*slash_veto_admin) {
+            return Err(RestakingCoreError::AvsInvalidSlashVetoAdmin);
+        }
+        Ok(())
+    }
+
     pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([b"avs".to_vec(), base.as_ref().to_vec()])
     }
@@ -228,11 +615,10 @@ impl Avs {
             return Err(RestakingCoreError::AvsInvalidOwner);
         }
 
-        let avs_state = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::AvsInvalidData(e.to_string()))?;
-        if avs_state.account_type != AccountType::Avs {
-            return Err(RestakingCoreError::AvsInvalidAccountType);
-        }
+        let avs_state = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => RestakingCoreError::AvsInvalidAccountType,
+            e => RestakingCoreError::AvsInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(&avs_state.base());
         seeds.push(vec![avs_state.bump()]);
@@ -248,7 +634,6 @@ impl Avs {
 
 pub struct SanitizedAvs<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    avs: Box<Avs>,
 }
 
 impl<'a, 'info> SanitizedAvs<'a, 'info> {
@@ -260,25 +645,24 @@ impl<'a, 'info> SanitizedAvs<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(RestakingCoreError::AvsNotWritable);
         }
-        let avs = Box::new(Avs::deserialize_checked(program_id, account)?);
+        Avs::deserialize_checked(program_id, account)?;
 
-        Ok(SanitizedAvs { account, avs })
+        Ok(SanitizedAvs { account })
     }
 
-    pub const fn account(&self) -> &AccountInfo<'info> {
+    pub const fn account(&self) -> &'a AccountInfo<'info> {
         self.account
     }
 
-    pub const fn avs(&self) -> &Avs {
-        &self.avs
-    }
-
-    pub fn avs_mut(&mut self) -> &mut Avs {
-        &mut self.avs
+    pub fn avs(&self) -> Ref<'_, Avs> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..Avs::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(&mut self.account.data.borrow_mut()[..], &self.avs)?;
-        Ok(())
+    pub fn avs_mut(&mut self) -> RefMut<'_, Avs> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..Avs::LEN])
+        })
     }
 }
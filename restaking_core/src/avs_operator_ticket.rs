@@ -1,46 +1,87 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::{PodBool, PodU64},
+    serde_fields,
+    slot_toggled_field::{SlotToggle, SlotToggleState},
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct AvsOperatorTicket {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs: Pubkey,
 
     /// The operator
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator: Pubkey,
 
     /// The index
-    index: u64,
+    index: PodU64,
 
     /// The state
     state: SlotToggle,
 
+    /// Whether the ticket was deactivated by the stale-operator crank rather than the AVS
+    ejected: PodBool,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 127],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
+impl ZeroCopy for AvsOperatorTicket {
+    const DISCRIMINATOR: u8 = AccountType::AvsOperatorTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl AvsOperatorTicket {
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 1;
+
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 33;
+
     pub const fn new(avs: Pubkey, operator: Pubkey, index: u64, slot_added: u64, bump: u8) -> Self {
         Self {
-            account_type: AccountType::AvsOperatorTicket,
+            discriminator: AccountType::AvsOperatorTicket as u8,
             avs,
             operator,
-            index,
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            ejected: PodBool::new(false),
+            version: Self::VERSION,
+            reserved: [0; 127],
             bump,
         }
     }
@@ -54,7 +95,7 @@ impl AvsOperatorTicket {
     }
 
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn state(&self) -> &SlotToggle {
@@ -69,7 +110,39 @@ impl AvsOperatorTicket {
         }
     }
 
+    /// The ticket shall have warmed up, see [`crate::config::Config::ticket_epoch_length`]
+    pub const fn check_warmed_up(&self, slot: u64, epoch_length: u64) -> RestakingCoreResult<()> {
+        match self.state.state(slot, epoch_length) {
+            SlotToggleState::Active => Ok(()),
+            SlotToggleState::WarmingUp => Err(RestakingCoreError::AvsOperatorTicketWarmingUp),
+            SlotToggleState::CoolingDown | SlotToggleState::Inactive => {
+                Err(RestakingCoreError::AvsOperatorTicketInactive)
+            }
+        }
+    }
+
+    /// The operator can be slashed for the AVS while the ticket is active, or deactivated until
+    /// the removal is finalized, which closes the ticket
+    pub const fn check_slashable(&self, slot: u64) -> RestakingCoreResult<()> {
+        if self.state.is_active(slot) || self.state.is_deactivated() {
+            Ok(())
+        } else {
+            Err(RestakingCoreError::AvsOperatorTicketInactive)
+        }
+    }
+
+    pub const fn ejected(&self) -> bool {
+        self.ejected.get()
+    }
+
+    /// Deactivates the ticket, which cools down until the next epoch starts and is closed once the
+    /// removal is finalized. If the operator was previously ejected, the AVS removing it clears
+    /// the ejection so the operator can no longer be re-activated by the crank.
     pub fn deactivate(&mut self, slot: u64) -> RestakingCoreResult<()> {
+        if self.ejected.get() {
+            self.ejected = PodBool::new(false);
+            return Ok(());
+        }
         if self.state.deactivate(slot) {
             Ok(())
         } else {
@@ -77,6 +150,28 @@ impl AvsOperatorTicket {
         }
     }
 
+    /// Deactivates the ticket because the operator stopped sending heartbeats. The ticket cools
+    /// down like a removed one.
+    pub fn eject(&mut self, slot: u64) -> RestakingCoreResult<()> {
+        if !self.state.deactivate(slot) {
+            return Err(RestakingCoreError::AvsOperatorTicketInactive);
+        }
+        self.ejected = PodBool::new(true);
+        Ok(())
+    }
+
+    /// Re-activates a ticket that was previously ejected
+    pub fn reactivate(&mut self, slot: u64) -> RestakingCoreResult<()> {
+        if !self.ejected.get() {
+            return Err(RestakingCoreError::AvsOperatorTicketNotEjected);
+        }
+        if !self.state.activate(slot) {
+            return Err(RestakingCoreError::AvsOperatorTicketInvalidState);
+        }
+        self.ejected = PodBool::new(false);
+        Ok(())
+    }
+
     pub const fn bump(&self) -> u8 {
         self.bump
     }
@@ -114,11 +209,12 @@ impl AvsOperatorTicket {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let avs_operator_ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::AvsOperatorTicketInvalidData(e.to_string()))?;
-        if avs_operator_ticket.account_type != AccountType::AvsOperatorTicket {
-            return Err(RestakingCoreError::AvsOperatorTicketInvalidAccountType);
-        }
+        let avs_operator_ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::AvsOperatorTicketInvalidAccountType
+            }
+            e => RestakingCoreError::AvsOperatorTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(avs, operator);
         seeds.push(vec![avs_operator_ticket.bump]);
@@ -135,7 +231,6 @@ impl AvsOperatorTicket {
 
 pub struct SanitizedAvsOperatorTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    avs_operator_ticket: Box<AvsOperatorTicket>,
 }
 
 impl<'a, 'info> SanitizedAvsOperatorTicket<'a, 'info> {
@@ -149,33 +244,24 @@ impl<'a, 'info> SanitizedAvsOperatorTicket<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(RestakingCoreError::AvsOperatorTicketExpectedWritable);
         }
-        let avs_operator_ticket = Box::new(AvsOperatorTicket::deserialize_checked(
-            program_id, account, avs, operator,
-        )?);
+        AvsOperatorTicket::deserialize_checked(program_id, account, avs, operator)?;
 
-        Ok(SanitizedAvsOperatorTicket {
-            account,
-            avs_operator_ticket,
-        })
+        Ok(SanitizedAvsOperatorTicket { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn avs_operator_ticket(&self) -> &AvsOperatorTicket {
-        &self.avs_operator_ticket
-    }
-
-    pub fn avs_operator_ticket_mut(&mut self) -> &mut AvsOperatorTicket {
-        &mut self.avs_operator_ticket
+    pub fn avs_operator_ticket(&self) -> Ref<'_, AvsOperatorTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..AvsOperatorTicket::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &self.avs_operator_ticket,
-        )?;
-        Ok(())
+    pub fn avs_operator_ticket_mut(&mut self) -> RefMut<'_, AvsOperatorTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..AvsOperatorTicket::LEN])
+        })
     }
 }
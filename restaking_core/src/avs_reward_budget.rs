@@ -0,0 +1,324 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::{PodU16, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The max number of epochs paid out by one distribution. Epochs past it are paid out by the next
+/// distribution, so a budget left alone for a long time can't exceed the compute limit.
+pub const MAX_DISTRIBUTION_EPOCHS: u64 = 64;
+
+const MAX_BPS: u64 = 10_000;
+
+/// An AVS emission schedule for one mint. Each epoch, anyone can pull the epoch's emission from
+/// the AVS token account into the receiver, so the AVS doesn't need to fund rewards by hand.
+///
+/// The emission starts at `tokens_per_epoch` and decays by `decay_bps` every epoch after that.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct AvsRewardBudget {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The AVS account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    avs: Pubkey,
+
+    /// The mint of the rewards
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    mint: Pubkey,
+
+    /// The token account the emissions are sent to
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    receiver: Pubkey,
+
+    /// The length of an epoch in slots
+    epoch_length: PodU64,
+
+    /// The emission of the first epoch of the schedule
+    tokens_per_epoch: PodU64,
+
+    /// The decay of the emission each epoch in basis points
+    decay_bps: PodU16,
+
+    /// The first epoch of the schedule
+    start_epoch: PodU64,
+
+    /// The first epoch not paid out yet
+    next_epoch: PodU64,
+
+    /// The emission of `next_epoch`
+    next_emission: PodU64,
+
+    /// The total number of tokens paid out
+    total_distributed: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for AvsRewardBudget {
+    const DISCRIMINATOR: u8 = AccountType::AvsRewardBudget as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl AvsRewardBudget {
+    /// A budget whose schedule starts at `epoch`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        avs: Pubkey,
+        mint: Pubkey,
+        receiver: Pubkey,
+        epoch_length: u64,
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+        epoch: u64,
+        bump: u8,
+    ) -> RestakingCoreResult<Self> {
+        if epoch_length == 0 {
+            return Err(RestakingCoreError::AvsRewardBudgetInvalidSchedule);
+        }
+        let mut budget = Self {
+            discriminator: AccountType::AvsRewardBudget as u8,
+            avs,
+            mint,
+            receiver,
+            epoch_length: PodU64::new(epoch_length),
+            tokens_per_epoch: PodU64::new(0),
+            decay_bps: PodU16::new(0),
+            start_epoch: PodU64::new(epoch),
+            next_epoch: PodU64::new(epoch),
+            next_emission: PodU64::new(0),
+            total_distributed: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        };
+        budget.set_schedule(tokens_per_epoch, decay_bps, epoch)?;
+        Ok(budget)
+    }
+
+    pub const fn avs(&self) -> Pubkey {
+        self.avs
+    }
+
+    pub const fn mint(&self) -> Pubkey {
+        self.mint
+    }
+
+    pub const fn receiver(&self) -> Pubkey {
+        self.receiver
+    }
+
+    pub const fn epoch_length(&self) -> u64 {
+        self.epoch_length.get()
+    }
+
+    pub const fn tokens_per_epoch(&self) -> u64 {
+        self.tokens_per_epoch.get()
+    }
+
+    pub const fn decay_bps(&self) -> u16 {
+        self.decay_bps.get()
+    }
+
+    pub const fn start_epoch(&self) -> u64 {
+        self.start_epoch.get()
+    }
+
+    pub const fn next_epoch(&self) -> u64 {
+        self.next_epoch.get()
+    }
+
+    pub const fn next_emission(&self) -> u64 {
+        self.next_emission.get()
+    }
+
+    pub const fn total_distributed(&self) -> u64 {
+        self.total_distributed.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// The budget epoch of the slot
+    pub fn epoch(&self, slot: u64) -> u64 {
+        slot.checked_div(self.epoch_length.get())
+            .unwrap_or_default()
+    }
+
+    /// Restarts the schedule at `epoch`, or at the first epoch not paid out yet if that is later.
+    /// Epochs before it that weren't paid out are dropped.
+    pub fn set_schedule(
+        &mut self,
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+        epoch: u64,
+    ) -> RestakingCoreResult<()> {
+        if decay_bps as u64 > MAX_BPS {
+            return Err(RestakingCoreError::AvsRewardBudgetInvalidSchedule);
+        }
+        let start_epoch = epoch.max(self.next_epoch.get());
+        self.tokens_per_epoch = PodU64::new(tokens_per_epoch);
+        self.decay_bps = PodU16::new(decay_bps);
+        self.start_epoch = PodU64::new(start_epoch);
+        self.next_epoch = PodU64::new(start_epoch);
+        self.next_emission = PodU64::new(tokens_per_epoch);
+        Ok(())
+    }
+
+    /// Pays out every epoch up to and including `epoch`, at most [`MAX_DISTRIBUTION_EPOCHS`] at
+    /// a time, and returns the number of tokens to transfer
+    pub fn distribute(&mut self, epoch: u64) -> RestakingCoreResult<u64> {
+        if self.next_epoch.get() > epoch {
+            return Err(RestakingCoreError::AvsRewardBudgetAlreadyDistributed);
+        }
+
+        let mut next_epoch = self.next_epoch.get();
+        let mut emission = self.next_emission.get();
+        let mut amount: u64 = 0;
+        let last_epoch = epoch.min(next_epoch.saturating_add(MAX_DISTRIBUTION_EPOCHS - 1));
+        let retained_bps = MAX_BPS.saturating_sub(self.decay_bps.get() as u64);
+        while next_epoch <= last_epoch && emission > 0 {
+            amount = amount
+                .checked_add(emission)
+                .ok_or(RestakingCoreError::AvsRewardBudgetOverflow)?;
+            emission = (emission as u128)
+                .checked_mul(retained_bps as u128)
+                .and_then(|emission| emission.checked_div(MAX_BPS as u128))
+                .and_then(|emission| u64::try_from(emission).ok())
+                .ok_or(RestakingCoreError::AvsRewardBudgetOverflow)?;
+            next_epoch = next_epoch.saturating_add(1);
+        }
+        if emission == 0 {
+            // nothing left to pay out until the schedule is reset
+            next_epoch = epoch.saturating_add(1);
+        }
+
+        self.next_epoch = PodU64::new(next_epoch);
+        self.next_emission = PodU64::new(emission);
+        self.total_distributed = PodU64::new(
+            self.total_distributed
+                .get()
+                .checked_add(amount)
+                .ok_or(RestakingCoreError::AvsRewardBudgetOverflow)?,
+        );
+        Ok(amount)
+    }
+
+    pub fn seeds(avs: &Pubkey, mint: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"avs_reward_budget".to_vec(),
+            avs.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        avs: &Pubkey,
+        mint: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(avs, mint);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        avs: &Pubkey,
+        mint: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::AvsRewardBudgetEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::AvsRewardBudgetInvalidOwner);
+        }
+
+        let budget = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::AvsRewardBudgetInvalidAccountType
+            }
+            e => RestakingCoreError::AvsRewardBudgetInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(avs, mint);
+        seeds.push(vec![budget.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::AvsRewardBudgetInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::AvsRewardBudgetInvalidPda);
+        }
+
+        Ok(budget)
+    }
+}
+
+pub struct SanitizedAvsRewardBudget<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAvsRewardBudget<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        avs: &Pubkey,
+        mint: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::AvsRewardBudgetNotWritable);
+        }
+
+        AvsRewardBudget::deserialize_checked(program_id, account, avs, mint)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn avs_reward_budget(&self) -> Ref<'_, AvsRewardBudget> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..AvsRewardBudget::LEN])
+        })
+    }
+
+    pub fn avs_reward_budget_mut(&mut self) -> RefMut<'_, AvsRewardBudget> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..AvsRewardBudget::LEN])
+        })
+    }
+}
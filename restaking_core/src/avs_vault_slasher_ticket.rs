@@ -1,61 +1,111 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    slot_toggled_field::SlotToggle,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[derive(
+    BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Debug, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct AvsVaultSlasherTicket {
-    account_type: AccountType,
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs: Pubkey,
 
     /// The vault account this slasher can slash
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
     /// The slasher signer
+    #[serde(serialize_with = "serde_fields::pubkey")]
     slasher: Pubkey,
 
-    /// The max slashable funds per epoch
-    max_slashable_per_epoch: u64,
+    /// The max slashable funds per epoch. The vault program tracks the amount slashed in the
+    /// current epoch on its mirror of this ticket, since it can't write to this account.
+    max_slashable_per_epoch: PodU64,
 
     /// The index
-    index: u64,
+    index: PodU64,
 
     /// State of the AVS slasher
     state: SlotToggle,
 
+    /// Hash of the slasher's metadata, e.g. its name and URI, identifying the program or entity
+    /// behind the slasher key
+    #[serde(serialize_with = "serde_fields::hash")]
+    metadata_hash: [u8; 32],
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 127],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
+impl ZeroCopy for AvsVaultSlasherTicket {
+    const DISCRIMINATOR: u8 = AccountType::AvsVaultSlasherTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl AvsVaultSlasherTicket {
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 1;
+
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 33;
+
+    /// The byte offset of the slasher in the account data, for `memcmp` filters
+    pub const SLASHER_OFFSET: usize = 65;
+
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         avs: Pubkey,
         vault: Pubkey,
         slasher: Pubkey,
         max_slashable_per_epoch: u64,
+        metadata_hash: [u8; 32],
         index: u64,
         slot_added: u64,
         bump: u8,
     ) -> Self {
         Self {
-            account_type: AccountType::AvsVaultSlasherTicket,
+            discriminator: AccountType::AvsVaultSlasherTicket as u8,
             avs,
             vault,
             slasher,
-            max_slashable_per_epoch,
-            index,
+            max_slashable_per_epoch: PodU64::new(max_slashable_per_epoch),
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            metadata_hash,
+            version: Self::VERSION,
+            reserved: [0; 127],
             bump,
         }
     }
@@ -73,11 +123,15 @@ impl AvsVaultSlasherTicket {
     }
 
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn max_slashable_per_epoch(&self) -> u64 {
-        self.max_slashable_per_epoch
+        self.max_slashable_per_epoch.get()
+    }
+
+    pub const fn metadata_hash(&self) -> [u8; 32] {
+        self.metadata_hash
     }
 
     pub const fn state(&self) -> &SlotToggle {
@@ -140,11 +194,12 @@ impl AvsVaultSlasherTicket {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let avs_slasher_ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::AvsSlasherTicketInvalidData(e.to_string()))?;
-        if avs_slasher_ticket.account_type != AccountType::AvsVaultSlasherTicket {
-            return Err(RestakingCoreError::AvsSlasherTicketInvalidAccountType);
-        }
+        let avs_slasher_ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::AvsSlasherTicketInvalidAccountType
+            }
+            e => RestakingCoreError::AvsSlasherTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(avs, vault, slasher);
         seeds.push(vec![avs_slasher_ticket.bump]);
@@ -161,7 +216,6 @@ impl AvsVaultSlasherTicket {
 
 pub struct SanitizedAvsVaultSlasherTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    avs_slasher_ticket: Box<AvsVaultSlasherTicket>,
 }
 
 impl<'a, 'info> SanitizedAvsVaultSlasherTicket<'a, 'info> {
@@ -177,33 +231,24 @@ impl<'a, 'info> SanitizedAvsVaultSlasherTicket<'a, 'info> {
             return Err(RestakingCoreError::AvsSlasherTicketNotWritable);
         }
 
-        let avs_slasher_ticket = Box::new(AvsVaultSlasherTicket::deserialize_checked(
-            program_id, account, avs, vault, slasher,
-        )?);
+        AvsVaultSlasherTicket::deserialize_checked(program_id, account, avs, vault, slasher)?;
 
-        Ok(Self {
-            account,
-            avs_slasher_ticket,
-        })
+        Ok(Self { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn avs_vault_slasher_ticket(&self) -> &AvsVaultSlasherTicket {
-        &self.avs_slasher_ticket
-    }
-
-    pub fn avs_vault_slasher_ticket_mut(&mut self) -> &mut AvsVaultSlasherTicket {
-        &mut self.avs_slasher_ticket
+    pub fn avs_vault_slasher_ticket(&self) -> Ref<'_, AvsVaultSlasherTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..AvsVaultSlasherTicket::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &self.avs_slasher_ticket,
-        )?;
-        Ok(())
+    pub fn avs_vault_slasher_ticket_mut(&mut self) -> RefMut<'_, AvsVaultSlasherTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..AvsVaultSlasherTicket::LEN])
+        })
     }
 }
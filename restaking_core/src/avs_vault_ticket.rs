@@ -1,43 +1,97 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::{PodU16, PodU64},
+    serde_fields,
+    slot_toggled_field::{SlotToggle, SlotToggleState},
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
+/// The maximum service fee an AVS can charge a vault, in basis points
+pub const MAX_SERVICE_FEE_BPS: u16 = 10_000;
+
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct AvsVaultTicket {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The AVS
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs: Pubkey,
 
     /// The vault account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
-    index: u64,
+    index: PodU64,
 
     state: SlotToggle,
 
+    /// The service fee in basis points of the vault's delegated stake the AVS charges the vault
+    /// per epoch. The fee is only collected once the vault accepts the same fee on its ticket.
+    service_fee_bps: PodU16,
+
+    /// The number of active slashers the AVS approved for the vault
+    slasher_count: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// The slot the AVS last set the service fee in, or zero if it set the fee before the slot
+    /// was recorded. Fees only accrue from the epoch of this slot.
+    service_fee_slot: PodU64,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 119],
 
     bump: u8,
 }
 
+impl ZeroCopy for AvsVaultTicket {
+    const DISCRIMINATOR: u8 = AccountType::AvsVaultTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl AvsVaultTicket {
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 1;
+
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 33;
+
     pub const fn new(avs: Pubkey, vault: Pubkey, index: u64, slot_added: u64, bump: u8) -> Self {
         Self {
-            account_type: AccountType::AvsVaultTicket,
+            discriminator: AccountType::AvsVaultTicket as u8,
             avs,
             vault,
-            index,
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            service_fee_bps: PodU16::new(0),
+            slasher_count: PodU64::new(0),
+            version: Self::VERSION,
+            service_fee_slot: PodU64::new(0),
+            reserved: [0; 119],
             bump,
         }
     }
@@ -62,6 +116,17 @@ impl AvsVaultTicket {
         }
     }
 
+    /// The ticket shall have warmed up, see [`crate::config::Config::ticket_epoch_length`]
+    pub const fn check_warmed_up(&self, slot: u64, epoch_length: u64) -> RestakingCoreResult<()> {
+        match self.state().state(slot, epoch_length) {
+            SlotToggleState::Active => Ok(()),
+            SlotToggleState::WarmingUp => Err(RestakingCoreError::AvsVaultTicketWarmingUp),
+            SlotToggleState::CoolingDown | SlotToggleState::Inactive => {
+                Err(RestakingCoreError::AvsVaultTicketInactive)
+            }
+        }
+    }
+
     pub fn deactivate(&mut self, slot: u64) -> RestakingCoreResult<()> {
         if self.state.deactivate(slot) {
             Ok(())
@@ -70,8 +135,47 @@ impl AvsVaultTicket {
         }
     }
 
+    pub const fn service_fee_bps(&self) -> u16 {
+        self.service_fee_bps.get()
+    }
+
+    pub const fn service_fee_slot(&self) -> u64 {
+        self.service_fee_slot.get()
+    }
+
+    /// Sets the service fee the AVS charges the vault from the slot on
+    pub fn set_service_fee_bps(
+        &mut self,
+        service_fee_bps: u16,
+        slot: u64,
+    ) -> RestakingCoreResult<()> {
+        if service_fee_bps > MAX_SERVICE_FEE_BPS {
+            return Err(RestakingCoreError::AvsVaultTicketInvalidServiceFee);
+        }
+        self.service_fee_bps = PodU16::new(service_fee_bps);
+        self.service_fee_slot = PodU64::new(slot);
+        Ok(())
+    }
+
+    pub const fn slasher_count(&self) -> u64 {
+        self.slasher_count.get()
+    }
+
+    /// Counts a newly approved slasher, failing if the vault already has `max_slashers`
+    pub fn increment_slasher_count(&mut self, max_slashers: u64) -> RestakingCoreResult<()> {
+        if self.slasher_count.get() >= max_slashers {
+            return Err(RestakingCoreError::AvsVaultTicketMaxSlashersReached);
+        }
+        self.slasher_count = PodU64::new(self.slasher_count.get().saturating_add(1));
+        Ok(())
+    }
+
+    pub fn decrement_slasher_count(&mut self) {
+        self.slasher_count = PodU64::new(self.slasher_count.get().saturating_sub(1));
+    }
+
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn bump(&self) -> u8 {
@@ -111,11 +215,12 @@ impl AvsVaultTicket {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let avs_vault_ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::AvsVaultTicketInvalidData(e.to_string()))?;
-        if avs_vault_ticket.account_type != AccountType::AvsVaultTicket {
-            return Err(RestakingCoreError::AvsVaultTicketInvalidAccountType);
-        }
+        let avs_vault_ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::AvsVaultTicketInvalidAccountType
+            }
+            e => RestakingCoreError::AvsVaultTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(avs, vault);
         seeds.push(vec![avs_vault_ticket.bump]);
@@ -132,7 +237,6 @@ impl AvsVaultTicket {
 
 pub struct SanitizedAvsVaultTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    avs_vault_ticket: Box<AvsVaultTicket>,
 }
 
 impl<'a, 'info> SanitizedAvsVaultTicket<'a, 'info> {
@@ -147,33 +251,24 @@ impl<'a, 'info> SanitizedAvsVaultTicket<'a, 'info> {
             return Err(RestakingCoreError::AvsVaultTicketNotWritable);
         }
 
-        let avs_vault_ticket = Box::new(AvsVaultTicket::deserialize_checked(
-            program_id, account, avs, vault,
-        )?);
+        AvsVaultTicket::deserialize_checked(program_id, account, avs, vault)?;
 
-        Ok(Self {
-            account,
-            avs_vault_ticket,
-        })
+        Ok(Self { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn avs_vault_ticket(&self) -> &AvsVaultTicket {
-        &self.avs_vault_ticket
-    }
-
-    pub fn avs_vault_ticket_mut(&mut self) -> &mut AvsVaultTicket {
-        &mut self.avs_vault_ticket
+    pub fn avs_vault_ticket(&self) -> Ref<'_, AvsVaultTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..AvsVaultTicket::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &self.avs_vault_ticket,
-        )?;
-        Ok(())
+    pub fn avs_vault_ticket_mut(&mut self) -> RefMut<'_, AvsVaultTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..AvsVaultTicket::LEN])
+        })
     }
 }
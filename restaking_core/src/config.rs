@@ -1,71 +1,170 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    counter::Counter,
+    pod::{PodU16, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
+    avs::DEFAULT_MAX_SLASHERS_PER_VAULT,
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
+/// The max number of slasher programs that can be approved in the [`Config`]
+pub const MAX_SLASHER_PROGRAMS: usize = 8;
+
+/// The max operator fee in basis points, all of the rewards
+pub const MAX_OPERATOR_FEE_BPS: u16 = 10_000;
+
+/// The bound of the max number of slashers per vault a new AVS starts with
+pub const MAX_DEFAULT_MAX_SLASHERS_PER_VAULT: u64 = 64;
+
+/// The max number of operators an AVS can add until the admin sets it
+pub const DEFAULT_MAX_OPERATORS_PER_AVS: u64 = 256;
+
+/// The bound of the max number of operators an AVS can add
+pub const MAX_MAX_OPERATORS_PER_AVS: u64 = 4_096;
+
+/// The max number of vaults an operator can opt in to until the admin sets it
+pub const DEFAULT_MAX_VAULTS_PER_OPERATOR: u64 = 64;
+
+/// The bound of the max number of vaults an operator can opt in to
+pub const MAX_MAX_VAULTS_PER_OPERATOR: u64 = 1_024;
+
+/// The epoch length tickets of a new config warm up and cool down over, about four days, the
+/// default epoch length of the vault program
+pub const DEFAULT_TICKET_EPOCH_LENGTH: u64 = 864_000;
+
+/// The shortest ticket epoch length the admin can set, about two days
+pub const MIN_TICKET_EPOCH_LENGTH: u64 = 432_000;
+
+/// The longest ticket epoch length the admin can set, about forty days
+pub const MAX_TICKET_EPOCH_LENGTH: u64 = 8_640_000;
+
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct Config {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The configuration admin
+    #[serde(serialize_with = "serde_fields::pubkey")]
     admin: Pubkey,
 
     /// The vault program
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault_program: Pubkey,
 
     /// The number of AVS managed by the program
-    avs_count: u64,
+    avs_count: Counter,
 
     /// The number of operators managed by the program
-    operator_count: u64,
+    operator_count: Counter,
+
+    /// Programs allowed to own slasher accounts. Unused slots are the default pubkey and an empty
+    /// list allows any slasher.
+    #[serde(serialize_with = "serde_fields::pubkeys")]
+    slasher_programs: [Pubkey; MAX_SLASHER_PROGRAMS],
+
+    /// The epoch length relationship tickets warm up and cool down over, see
+    /// [`jito_jsm_core::slot_toggled_field::SlotToggle::state`]. It's zero on configs created
+    /// before tickets had a lifecycle, where tickets toggle instantly until the admin sets it.
+    ticket_epoch_length: PodU64,
+
+    /// The key proposed to take over as admin, or the default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    pending_admin: Pubkey,
+
+    /// The max fee an operator can charge on rewards, in basis points
+    max_operator_fee_bps: PodU16,
+
+    /// The slots an operator waits between two changes of its fee
+    operator_fee_cooldown_slots: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// The max number of operators an AVS can add, or zero for [`DEFAULT_MAX_OPERATORS_PER_AVS`]
+    max_operators_per_avs: PodU64,
+
+    /// The max number of vaults an operator can opt in to, or zero for
+    /// [`DEFAULT_MAX_VAULTS_PER_OPERATOR`]
+    max_vaults_per_operator: PodU64,
+
+    /// The max number of slashers per vault a new AVS starts with, or zero for
+    /// [`DEFAULT_MAX_SLASHERS_PER_VAULT`]
+    default_max_slashers_per_vault: PodU64,
 
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 85],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
+impl ZeroCopy for Config {
+    const DISCRIMINATOR: u8 = AccountType::Config as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl Config {
     pub const fn new(admin: Pubkey, vault_program: Pubkey, bump: u8) -> Self {
         Self {
-            account_type: AccountType::Config,
+            discriminator: AccountType::Config as u8,
             admin,
             vault_program,
-            avs_count: 0,
-            operator_count: 0,
-            reserved: [0; 128],
+            avs_count: Counter::new(0),
+            operator_count: Counter::new(0),
+            slasher_programs: [Pubkey::new_from_array([0; 32]); MAX_SLASHER_PROGRAMS],
+            ticket_epoch_length: PodU64::new(DEFAULT_TICKET_EPOCH_LENGTH),
+            pending_admin: Pubkey::new_from_array([0; 32]),
+            max_operator_fee_bps: PodU16::new(0),
+            operator_fee_cooldown_slots: PodU64::new(0),
+            version: Self::VERSION,
+            max_operators_per_avs: PodU64::new(0),
+            max_vaults_per_operator: PodU64::new(0),
+            default_max_slashers_per_vault: PodU64::new(0),
+            reserved: [0; 85],
             bump,
         }
     }
 
     pub fn increment_avs(&mut self) -> RestakingCoreResult<()> {
-        self.avs_count = self
-            .avs_count
-            .checked_add(1)
-            .ok_or(RestakingCoreError::AvsOverflow)?;
+        self.avs_count
+            .increment("avs_count", RestakingCoreError::AvsOverflow)?;
         Ok(())
     }
 
     pub const fn avs_count(&self) -> u64 {
-        self.avs_count
+        self.avs_count.get()
     }
 
     pub fn increment_operators(&mut self) -> RestakingCoreResult<()> {
-        self.operator_count = self
-            .operator_count
-            .checked_add(1)
-            .ok_or(RestakingCoreError::OperatorOverflow)?;
+        self.operator_count
+            .increment("operator_count", RestakingCoreError::OperatorOverflow)?;
         Ok(())
     }
 
     pub const fn operators_count(&self) -> u64 {
-        self.operator_count
+        self.operator_count.get()
     }
 
     pub const fn vault_program(&self) -> Pubkey {
@@ -76,6 +175,208 @@ impl Config {
         self.admin
     }
 
+    pub fn check_admin(&self, admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.admin != *admin {
+            return Err(RestakingCoreError::ConfigInvalidAdmin);
+        }
+        Ok(())
+    }
+
+    /// The key proposed to take over as admin, if a transfer is pending
+    pub fn pending_admin(&self) -> Option<Pubkey> {
+        if self.pending_admin != Pubkey::default() {
+            Some(self.pending_admin)
+        } else {
+            None
+        }
+    }
+
+    /// Proposes a new admin, replacing any transfer that is already pending. The transfer
+    /// completes once the new admin accepts it.
+    pub fn set_pending_admin(&mut self, pending_admin: Pubkey) {
+        self.pending_admin = pending_admin;
+    }
+
+    /// Completes the pending admin transfer to `new_admin`
+    pub fn accept_admin(&mut self, new_admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.pending_admin() != Some(*new_admin) {
+            return Err(RestakingCoreError::ConfigInvalidPendingAdmin);
+        }
+        self.admin = *new_admin;
+        self.pending_admin = Pubkey::default();
+        Ok(())
+    }
+
+    /// The approved slasher programs
+    pub fn slasher_programs(&self) -> Vec<Pubkey> {
+        self.slasher_programs
+            .iter()
+            .filter(|program| **program != Pubkey::default())
+            .copied()
+            .collect()
+    }
+
+    pub fn add_slasher_program(&mut self, program: Pubkey) -> RestakingCoreResult<()> {
+        if program == Pubkey::default() {
+            return Err(RestakingCoreError::ConfigSlasherProgramInvalid);
+        }
+        if self.slasher_programs.contains(&program) {
+            return Err(RestakingCoreError::ConfigSlasherProgramAlreadyApproved);
+        }
+        let slot = self
+            .slasher_programs
+            .iter_mut()
+            .find(|slot| **slot == Pubkey::default())
+            .ok_or(RestakingCoreError::ConfigSlasherProgramsFull)?;
+        *slot = program;
+        Ok(())
+    }
+
+    pub fn remove_slasher_program(&mut self, program: &Pubkey) -> RestakingCoreResult<()> {
+        let slot = self
+            .slasher_programs
+            .iter_mut()
+            .find(|slot| **slot == *program && *program != Pubkey::default())
+            .ok_or(RestakingCoreError::ConfigSlasherProgramNotApproved)?;
+        *slot = Pubkey::default();
+        Ok(())
+    }
+
+    /// A slasher shall be owned by an approved program, unless no programs have been approved
+    pub fn check_slasher_owner(&self, owner: &Pubkey) -> RestakingCoreResult<()> {
+        let slasher_programs = self.slasher_programs();
+        if !slasher_programs.is_empty() && !slasher_programs.contains(owner) {
+            return Err(RestakingCoreError::ConfigSlasherProgramNotApproved);
+        }
+        Ok(())
+    }
+
+    /// The epoch length relationship tickets warm up and cool down over, zero if they toggle
+    /// instantly on a config created before tickets had a lifecycle
+    pub const fn ticket_epoch_length(&self) -> u64 {
+        self.ticket_epoch_length.get()
+    }
+
+    /// Sets the epoch length tickets warm up and cool down over. It applies to existing tickets
+    /// too, as their lifecycle is derived from the slots they were toggled in.
+    ///
+    /// It shall be within [`MIN_TICKET_EPOCH_LENGTH`] and [`MAX_TICKET_EPOCH_LENGTH`].
+    pub fn set_ticket_epoch_length(&mut self, ticket_epoch_length: u64) -> RestakingCoreResult<()> {
+        if !(MIN_TICKET_EPOCH_LENGTH..=MAX_TICKET_EPOCH_LENGTH).contains(&ticket_epoch_length) {
+            return Err(RestakingCoreError::ConfigInvalidTicketEpochLength);
+        }
+        self.ticket_epoch_length = PodU64::new(ticket_epoch_length);
+        Ok(())
+    }
+
+    /// The max fee an operator can charge on rewards. It's zero until the config admin sets it,
+    /// so operators charge no fee by default.
+    pub const fn max_operator_fee_bps(&self) -> u16 {
+        self.max_operator_fee_bps.get()
+    }
+
+    pub const fn operator_fee_cooldown_slots(&self) -> u64 {
+        self.operator_fee_cooldown_slots.get()
+    }
+
+    /// Sets the bounds of the operator fees. Operators charging more than the new max keep their
+    /// fee until they change it.
+    pub fn set_operator_fee_params(
+        &mut self,
+        max_operator_fee_bps: u16,
+        operator_fee_cooldown_slots: u64,
+    ) -> RestakingCoreResult<()> {
+        if max_operator_fee_bps > MAX_OPERATOR_FEE_BPS {
+            return Err(RestakingCoreError::ConfigInvalidMaxOperatorFee);
+        }
+        self.max_operator_fee_bps = PodU16::new(max_operator_fee_bps);
+        self.operator_fee_cooldown_slots = PodU64::new(operator_fee_cooldown_slots);
+        Ok(())
+    }
+
+    /// The max number of operators an AVS can add
+    pub const fn max_operators_per_avs(&self) -> u64 {
+        match self.max_operators_per_avs.get() {
+            0 => DEFAULT_MAX_OPERATORS_PER_AVS,
+            max => max,
+        }
+    }
+
+    /// Limits the number of operators an AVS can add, within one and
+    /// [`MAX_MAX_OPERATORS_PER_AVS`]. AVSs over the new limit keep their operators but can't add
+    /// more.
+    pub fn set_max_operators_per_avs(
+        &mut self,
+        max_operators_per_avs: u64,
+    ) -> RestakingCoreResult<()> {
+        if max_operators_per_avs == 0 || max_operators_per_avs > MAX_MAX_OPERATORS_PER_AVS {
+            return Err(RestakingCoreError::ConfigInvalidParameter);
+        }
+        self.max_operators_per_avs = PodU64::new(max_operators_per_avs);
+        Ok(())
+    }
+
+    /// An AVS that added `operator_count` operators shall be under the limit to add another
+    pub const fn check_avs_operator_limit(&self, operator_count: u64) -> RestakingCoreResult<()> {
+        if operator_count >= self.max_operators_per_avs() {
+            return Err(RestakingCoreError::AvsMaxOperatorsReached);
+        }
+        Ok(())
+    }
+
+    /// The max number of vaults an operator can opt in to
+    pub const fn max_vaults_per_operator(&self) -> u64 {
+        match self.max_vaults_per_operator.get() {
+            0 => DEFAULT_MAX_VAULTS_PER_OPERATOR,
+            max => max,
+        }
+    }
+
+    /// Limits the number of vaults an operator can opt in to, within one and
+    /// [`MAX_MAX_VAULTS_PER_OPERATOR`]. Operators over the new limit keep their vaults but can't
+    /// opt in to more.
+    pub fn set_max_vaults_per_operator(
+        &mut self,
+        max_vaults_per_operator: u64,
+    ) -> RestakingCoreResult<()> {
+        if max_vaults_per_operator == 0 || max_vaults_per_operator > MAX_MAX_VAULTS_PER_OPERATOR {
+            return Err(RestakingCoreError::ConfigInvalidParameter);
+        }
+        self.max_vaults_per_operator = PodU64::new(max_vaults_per_operator);
+        Ok(())
+    }
+
+    /// An operator that opted in to `vault_count` vaults shall be under the limit to opt in to
+    /// another
+    pub const fn check_operator_vault_limit(&self, vault_count: u64) -> RestakingCoreResult<()> {
+        if vault_count >= self.max_vaults_per_operator() {
+            return Err(RestakingCoreError::OperatorMaxVaultsReached);
+        }
+        Ok(())
+    }
+
+    /// The max number of slashers per vault a new AVS starts with
+    pub const fn default_max_slashers_per_vault(&self) -> u64 {
+        match self.default_max_slashers_per_vault.get() {
+            0 => DEFAULT_MAX_SLASHERS_PER_VAULT,
+            max => max,
+        }
+    }
+
+    /// Sets the max number of slashers per vault new AVSs start with. Existing AVSs keep theirs.
+    pub fn set_default_max_slashers_per_vault(
+        &mut self,
+        default_max_slashers_per_vault: u64,
+    ) -> RestakingCoreResult<()> {
+        if default_max_slashers_per_vault == 0
+            || default_max_slashers_per_vault > MAX_DEFAULT_MAX_SLASHERS_PER_VAULT
+        {
+            return Err(RestakingCoreError::ConfigInvalidParameter);
+        }
+        self.default_max_slashers_per_vault = PodU64::new(default_max_slashers_per_vault);
+        Ok(())
+    }
+
     pub const fn bump(&self) -> u8 {
         self.bump
     }
@@ -103,11 +404,12 @@ impl Config {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let config = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::ConfigInvalidData(e.to_string()))?;
-        if config.account_type != AccountType::Config {
-            return Err(RestakingCoreError::ConfigInvalidAccountType);
-        }
+        let config = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::ConfigInvalidAccountType
+            }
+            e => RestakingCoreError::ConfigInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds();
         seeds.push(vec![config.bump]);
@@ -125,7 +427,6 @@ impl Config {
 
 pub struct SanitizedConfig<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    config: Box<Config>,
 }
 
 impl<'a, 'info> SanitizedConfig<'a, 'info> {
@@ -137,25 +438,162 @@ impl<'a, 'info> SanitizedConfig<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(RestakingCoreError::ConfigNotWritable);
         }
-        let config = Box::new(Config::deserialize_checked(program_id, account)?);
+        Config::deserialize_checked(program_id, account)?;
 
-        Ok(SanitizedConfig { account, config })
+        Ok(SanitizedConfig { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn config(&self) -> &Config {
-        &self.config
+    pub fn config(&self) -> Ref<'_, Config> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..Config::LEN])
+        })
     }
 
-    pub fn config_mut(&mut self) -> &mut Config {
-        &mut self.config
+    pub fn config_mut(&mut self) -> RefMut<'_, Config> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..Config::LEN])
+        })
     }
+}
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(&mut self.account.data.borrow_mut()[..], &self.config)?;
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        avs::DEFAULT_MAX_SLASHERS_PER_VAULT,
+        config::{
+            Config, DEFAULT_MAX_OPERATORS_PER_AVS, DEFAULT_MAX_VAULTS_PER_OPERATOR,
+            DEFAULT_TICKET_EPOCH_LENGTH, MAX_DEFAULT_MAX_SLASHERS_PER_VAULT,
+            MAX_MAX_OPERATORS_PER_AVS, MAX_MAX_VAULTS_PER_OPERATOR, MAX_TICKET_EPOCH_LENGTH,
+            MIN_TICKET_EPOCH_LENGTH,
+        },
+        result::RestakingCoreError,
+    };
+
+    #[test]
+    fn test_limits_unset_are_default() {
+        let config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        assert_eq!(
+            config.max_operators_per_avs(),
+            DEFAULT_MAX_OPERATORS_PER_AVS
+        );
+        assert_eq!(
+            config.max_vaults_per_operator(),
+            DEFAULT_MAX_VAULTS_PER_OPERATOR
+        );
+        assert!(config
+            .check_avs_operator_limit(DEFAULT_MAX_OPERATORS_PER_AVS - 1)
+            .is_ok());
+        assert_eq!(
+            config.check_avs_operator_limit(DEFAULT_MAX_OPERATORS_PER_AVS),
+            Err(RestakingCoreError::AvsMaxOperatorsReached)
+        );
+        assert_eq!(
+            config.check_operator_vault_limit(DEFAULT_MAX_VAULTS_PER_OPERATOR),
+            Err(RestakingCoreError::OperatorMaxVaultsReached)
+        );
+        assert_eq!(
+            config.default_max_slashers_per_vault(),
+            DEFAULT_MAX_SLASHERS_PER_VAULT
+        );
+    }
+
+    #[test]
+    fn test_set_ticket_epoch_length() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        assert_eq!(config.ticket_epoch_length(), DEFAULT_TICKET_EPOCH_LENGTH);
+
+        assert_eq!(
+            config.set_ticket_epoch_length(0),
+            Err(RestakingCoreError::ConfigInvalidTicketEpochLength)
+        );
+        assert_eq!(
+            config.set_ticket_epoch_length(MIN_TICKET_EPOCH_LENGTH - 1),
+            Err(RestakingCoreError::ConfigInvalidTicketEpochLength)
+        );
+        assert_eq!(
+            config.set_ticket_epoch_length(MAX_TICKET_EPOCH_LENGTH + 1),
+            Err(RestakingCoreError::ConfigInvalidTicketEpochLength)
+        );
+
+        config
+            .set_ticket_epoch_length(MIN_TICKET_EPOCH_LENGTH)
+            .unwrap();
+        assert_eq!(config.ticket_epoch_length(), MIN_TICKET_EPOCH_LENGTH);
+    }
+
+    #[test]
+    fn test_limits() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        config.set_max_operators_per_avs(2).unwrap();
+        config.set_max_vaults_per_operator(3).unwrap();
+
+        assert!(config.check_avs_operator_limit(1).is_ok());
+        assert_eq!(
+            config.check_avs_operator_limit(2),
+            Err(RestakingCoreError::AvsMaxOperatorsReached)
+        );
+        assert!(config.check_operator_vault_limit(2).is_ok());
+        assert_eq!(
+            config.check_operator_vault_limit(3),
+            Err(RestakingCoreError::OperatorMaxVaultsReached)
+        );
+
+        config.set_max_operators_per_avs(3).unwrap();
+        assert!(config.check_avs_operator_limit(2).is_ok());
+    }
+
+    #[test]
+    fn test_set_limits_bounds() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        for max_operators_per_avs in [0, MAX_MAX_OPERATORS_PER_AVS + 1] {
+            assert_eq!(
+                config.set_max_operators_per_avs(max_operators_per_avs),
+                Err(RestakingCoreError::ConfigInvalidParameter)
+            );
+        }
+        for max_vaults_per_operator in [0, MAX_MAX_VAULTS_PER_OPERATOR + 1] {
+            assert_eq!(
+                config.set_max_vaults_per_operator(max_vaults_per_operator),
+                Err(RestakingCoreError::ConfigInvalidParameter)
+            );
+        }
+
+        config
+            .set_max_operators_per_avs(MAX_MAX_OPERATORS_PER_AVS)
+            .unwrap();
+        config
+            .set_max_vaults_per_operator(MAX_MAX_VAULTS_PER_OPERATOR)
+            .unwrap();
+        assert_eq!(config.max_operators_per_avs(), MAX_MAX_OPERATORS_PER_AVS);
+        assert_eq!(
+            config.max_vaults_per_operator(),
+            MAX_MAX_VAULTS_PER_OPERATOR
+        );
+    }
+
+    #[test]
+    fn test_set_default_max_slashers_per_vault_bounds() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        assert_eq!(
+            config.set_default_max_slashers_per_vault(0),
+            Err(RestakingCoreError::ConfigInvalidParameter)
+        );
+        assert_eq!(
+            config.set_default_max_slashers_per_vault(MAX_DEFAULT_MAX_SLASHERS_PER_VAULT + 1),
+            Err(RestakingCoreError::ConfigInvalidParameter)
+        );
+        config
+            .set_default_max_slashers_per_vault(MAX_DEFAULT_MAX_SLASHERS_PER_VAULT)
+            .unwrap();
+        assert_eq!(
+            config.default_max_slashers_per_vault(),
+            MAX_DEFAULT_MAX_SLASHERS_PER_VAULT
+        );
     }
 }
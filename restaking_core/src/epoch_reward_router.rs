@@ -0,0 +1,318 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The rewards an AVS pays for one epoch in one mint, held in the associated token account of the
+/// router until they are distributed.
+///
+/// Anyone can deposit rewards until the epoch ends. During the epoch, the active delegation of
+/// each vault to each operator of the AVS is recorded in an
+/// [`crate::epoch_reward_snapshot::EpochRewardSnapshot`]. Once the epoch ended, the rewards are
+/// paid out pro-rata to the recorded stake, and whatever is left after every snapshot was paid
+/// out goes back to the AVS.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct EpochRewardRouter {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The AVS paying the rewards
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    avs: Pubkey,
+
+    /// The mint of the rewards
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    mint: Pubkey,
+
+    /// The epoch the rewards are paid for
+    epoch: PodU64,
+
+    /// The length of an epoch in slots, copied from the vault config when the router was created
+    epoch_length: PodU64,
+
+    /// The total number of tokens deposited
+    total_rewards: PodU64,
+
+    /// The total stake recorded by the snapshots
+    total_stake: PodU64,
+
+    /// The number of snapshots recorded
+    snapshot_count: PodU64,
+
+    /// The number of snapshots paid out
+    distributed_count: PodU64,
+
+    /// The total number of tokens paid out
+    total_distributed: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for EpochRewardRouter {
+    const DISCRIMINATOR: u8 = AccountType::EpochRewardRouter as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl EpochRewardRouter {
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 1;
+
+    pub const fn new(avs: Pubkey, mint: Pubkey, epoch: u64, epoch_length: u64, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::EpochRewardRouter as u8,
+            avs,
+            mint,
+            epoch: PodU64::new(epoch),
+            epoch_length: PodU64::new(epoch_length),
+            total_rewards: PodU64::new(0),
+            total_stake: PodU64::new(0),
+            snapshot_count: PodU64::new(0),
+            distributed_count: PodU64::new(0),
+            total_distributed: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn avs(&self) -> Pubkey {
+        self.avs
+    }
+
+    pub const fn mint(&self) -> Pubkey {
+        self.mint
+    }
+
+    pub const fn epoch(&self) -> u64 {
+        self.epoch.get()
+    }
+
+    pub const fn epoch_length(&self) -> u64 {
+        self.epoch_length.get()
+    }
+
+    pub const fn total_rewards(&self) -> u64 {
+        self.total_rewards.get()
+    }
+
+    pub const fn total_stake(&self) -> u64 {
+        self.total_stake.get()
+    }
+
+    pub const fn snapshot_count(&self) -> u64 {
+        self.snapshot_count.get()
+    }
+
+    pub const fn distributed_count(&self) -> u64 {
+        self.distributed_count.get()
+    }
+
+    pub const fn total_distributed(&self) -> u64 {
+        self.total_distributed.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// The epoch of the slot, in epochs of the router's length
+    pub fn current_epoch(&self, slot: u64) -> u64 {
+        slot.checked_div(self.epoch_length()).unwrap_or(0)
+    }
+
+    /// Adds deposited rewards, which is only possible until the epoch ends
+    pub fn deposit(&mut self, amount: u64, slot: u64) -> RestakingCoreResult<()> {
+        if self.current_epoch(slot) > self.epoch() {
+            return Err(RestakingCoreError::EpochRewardsClosed);
+        }
+        let total_rewards = self
+            .total_rewards()
+            .checked_add(amount)
+            .ok_or(RestakingCoreError::EpochRewardOverflow)?;
+        self.total_rewards = PodU64::new(total_rewards);
+        Ok(())
+    }
+
+    /// Records the stake of a snapshot, which is only possible during the epoch
+    pub fn record_stake(&mut self, stake: u64, slot: u64) -> RestakingCoreResult<()> {
+        let current_epoch = self.current_epoch(slot);
+        if current_epoch < self.epoch() {
+            return Err(RestakingCoreError::EpochRewardsNotStarted);
+        }
+        if current_epoch > self.epoch() {
+            return Err(RestakingCoreError::EpochRewardsClosed);
+        }
+        let total_stake = self
+            .total_stake()
+            .checked_add(stake)
+            .ok_or(RestakingCoreError::EpochRewardOverflow)?;
+        let snapshot_count = self
+            .snapshot_count()
+            .checked_add(1)
+            .ok_or(RestakingCoreError::EpochRewardOverflow)?;
+        self.total_stake = PodU64::new(total_stake);
+        self.snapshot_count = PodU64::new(snapshot_count);
+        Ok(())
+    }
+
+    /// The rewards are only paid out once the epoch ended, so no stake or rewards are added after
+    pub fn check_ended(&self, slot: u64) -> RestakingCoreResult<()> {
+        if self.current_epoch(slot) <= self.epoch() {
+            return Err(RestakingCoreError::EpochRewardsNotEnded);
+        }
+        Ok(())
+    }
+
+    /// The share of the rewards of a snapshot with the stake, rounded down
+    pub fn share(&self, stake: u64) -> RestakingCoreResult<u64> {
+        if self.total_stake() == 0 {
+            return Ok(0);
+        }
+        (self.total_rewards() as u128)
+            .checked_mul(stake as u128)
+            .and_then(|share| share.checked_div(self.total_stake() as u128))
+            .and_then(|share| u64::try_from(share).ok())
+            .ok_or(RestakingCoreError::EpochRewardOverflow)
+    }
+
+    /// Records a snapshot paid out
+    pub fn record_distribution(&mut self, amount: u64) -> RestakingCoreResult<()> {
+        let distributed_count = self
+            .distributed_count()
+            .checked_add(1)
+            .ok_or(RestakingCoreError::EpochRewardOverflow)?;
+        let total_distributed = self
+            .total_distributed()
+            .checked_add(amount)
+            .ok_or(RestakingCoreError::EpochRewardOverflow)?;
+        self.distributed_count = PodU64::new(distributed_count);
+        self.total_distributed = PodU64::new(total_distributed);
+        Ok(())
+    }
+
+    /// The rewards left over can only go back to the AVS once every snapshot was paid out
+    pub fn check_fully_distributed(&self) -> RestakingCoreResult<()> {
+        if self.distributed_count() < self.snapshot_count() {
+            return Err(RestakingCoreError::EpochRewardsPendingDistribution);
+        }
+        Ok(())
+    }
+
+    pub fn seeds(avs: &Pubkey, mint: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"epoch_reward_router".to_vec(),
+            avs.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        avs: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(avs, mint, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::EpochRewardRouterEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::EpochRewardRouterInvalidOwner);
+        }
+
+        let router = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::EpochRewardRouterInvalidAccountType
+            }
+            e => RestakingCoreError::EpochRewardRouterInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(avs, &router.mint, router.epoch());
+        seeds.push(vec![router.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::EpochRewardRouterInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::EpochRewardRouterInvalidPda);
+        }
+
+        Ok(router)
+    }
+}
+
+pub struct SanitizedEpochRewardRouter<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedEpochRewardRouter<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::EpochRewardRouterNotWritable);
+        }
+
+        EpochRewardRouter::deserialize_checked(program_id, account, avs)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn epoch_reward_router(&self) -> Ref<'_, EpochRewardRouter> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..EpochRewardRouter::LEN])
+        })
+    }
+
+    pub fn epoch_reward_router_mut(&mut self) -> RefMut<'_, EpochRewardRouter> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..EpochRewardRouter::LEN])
+        })
+    }
+}
@@ -0,0 +1,256 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::{PodBool, PodU16, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    operator::split_rewards,
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The active delegation of a vault to an operator of the AVS during the epoch of an
+/// [`crate::epoch_reward_router::EpochRewardRouter`].
+///
+/// The operator fee is recorded with it, so the split of the rewards doesn't change after the
+/// epoch.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct EpochRewardSnapshot {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The router the rewards come from
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    router: Pubkey,
+
+    /// The vault delegating the stake
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    vault: Pubkey,
+
+    /// The operator the stake is delegated to
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The active delegation of the vault to the operator
+    stake: PodU64,
+
+    /// The fee of the operator when the snapshot was taken, in basis points
+    operator_fee_bps: PodU16,
+
+    /// Whether the rewards of the snapshot were paid out
+    distributed: PodBool,
+
+    /// The performance score the AVS scorer last attested for the operator when the snapshot was
+    /// taken, in basis points
+    operator_score_bps: PodU16,
+
+    /// Whether the operator had been scored when the snapshot was taken
+    scored: PodBool,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 60],
+
+    bump: u8,
+}
+
+impl ZeroCopy for EpochRewardSnapshot {
+    const DISCRIMINATOR: u8 = AccountType::EpochRewardSnapshot as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl EpochRewardSnapshot {
+    /// The byte offset of the router in the account data, for `memcmp` filters
+    pub const ROUTER_OFFSET: usize = 1;
+
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 33;
+
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 65;
+
+    pub const fn new(
+        router: Pubkey,
+        vault: Pubkey,
+        operator: Pubkey,
+        stake: u64,
+        operator_fee_bps: u16,
+        operator_score_bps: Option<u16>,
+        bump: u8,
+    ) -> Self {
+        Self {
+            discriminator: AccountType::EpochRewardSnapshot as u8,
+            router,
+            vault,
+            operator,
+            stake: PodU64::new(stake),
+            operator_fee_bps: PodU16::new(operator_fee_bps),
+            distributed: PodBool::new(false),
+            operator_score_bps: match operator_score_bps {
+                Some(score_bps) => PodU16::new(score_bps),
+                None => PodU16::new(0),
+            },
+            scored: PodBool::new(operator_score_bps.is_some()),
+            version: Self::VERSION,
+            reserved: [0; 60],
+            bump,
+        }
+    }
+
+    pub const fn router(&self) -> Pubkey {
+        self.router
+    }
+
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn stake(&self) -> u64 {
+        self.stake.get()
+    }
+
+    pub const fn operator_fee_bps(&self) -> u16 {
+        self.operator_fee_bps.get()
+    }
+
+    pub const fn distributed(&self) -> bool {
+        self.distributed.get()
+    }
+
+    /// The performance score of the operator when the snapshot was taken, or `None` if the AVS
+    /// scorer hadn't attested one yet
+    pub const fn operator_score_bps(&self) -> Option<u16> {
+        if self.scored.get() {
+            Some(self.operator_score_bps.get())
+        } else {
+            None
+        }
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// Marks the snapshot paid out and splits its rewards into the operator fee and the share of
+    /// the vault
+    pub fn distribute(&mut self, rewards: u64) -> RestakingCoreResult<(u64, u64)> {
+        if self.distributed() {
+            return Err(RestakingCoreError::EpochRewardSnapshotAlreadyDistributed);
+        }
+        self.distributed = PodBool::new(true);
+        Ok(split_rewards(rewards, self.operator_fee_bps()))
+    }
+
+    pub fn seeds(router: &Pubkey, vault: &Pubkey, operator: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"epoch_reward_snapshot".to_vec(),
+            router.to_bytes().to_vec(),
+            vault.to_bytes().to_vec(),
+            operator.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        router: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(router, vault, operator);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        router: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::EpochRewardSnapshotEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::EpochRewardSnapshotInvalidOwner);
+        }
+
+        let snapshot = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::EpochRewardSnapshotInvalidAccountType
+            }
+            e => RestakingCoreError::EpochRewardSnapshotInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(router, &snapshot.vault, &snapshot.operator);
+        seeds.push(vec![snapshot.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::EpochRewardSnapshotInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::EpochRewardSnapshotInvalidPda);
+        }
+
+        Ok(snapshot)
+    }
+}
+
+pub struct SanitizedEpochRewardSnapshot<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedEpochRewardSnapshot<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        router: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::EpochRewardSnapshotNotWritable);
+        }
+
+        EpochRewardSnapshot::deserialize_checked(program_id, account, router)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn epoch_reward_snapshot(&self) -> Ref<'_, EpochRewardSnapshot> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..EpochRewardSnapshot::LEN])
+        })
+    }
+
+    pub fn epoch_reward_snapshot_mut(&mut self) -> RefMut<'_, EpochRewardSnapshot> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..EpochRewardSnapshot::LEN])
+        })
+    }
+}
@@ -1,18 +1,30 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use bytemuck::{Pod, Zeroable};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 
 pub mod avs;
 pub mod avs_operator_ticket;
+pub mod avs_reward_budget;
 pub mod avs_vault_slasher_ticket;
 pub mod avs_vault_ticket;
 pub mod config;
+pub mod epoch_reward_router;
+pub mod epoch_reward_snapshot;
+pub mod metadata;
+pub mod migration;
 pub mod operator;
+pub mod operator_avs_capacity;
+pub mod operator_avs_heartbeat;
+pub mod operator_avs_score;
 pub mod operator_avs_ticket;
+pub mod operator_epoch_stake;
+pub mod operator_stake_history;
 pub mod operator_vault_ticket;
 pub mod result;
+pub mod reward_claim_status;
+pub mod reward_distribution;
+pub mod vault_operator_epoch_stake;
 
-#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize, Copy)]
-#[repr(u32)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema, Copy)]
+#[repr(u8)]
 pub enum AccountType {
     Config,
     Avs,
@@ -22,7 +34,562 @@ pub enum AccountType {
     Operator,
     OperatorAvsTicket,
     OperatorVaultTicket,
+    OperatorAvsHeartbeat,
+    AvsRewardBudget,
+    Metadata,
+    OperatorAvsCapacity,
+    EpochRewardRouter,
+    EpochRewardSnapshot,
+    OperatorEpochStake,
+    VaultOperatorEpochStake,
+    OperatorAvsScore,
+    OperatorStakeHistory,
+    RewardDistribution,
+    RewardClaimStatus,
 }
 
-unsafe impl Pod for AccountType {}
-unsafe impl Zeroable for AccountType {}
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::zero_copy::ZeroCopy;
+    use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+    use crate::{
+        avs::Avs, avs_operator_ticket::AvsOperatorTicket, avs_reward_budget::AvsRewardBudget,
+        avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+        config::Config, epoch_reward_router::EpochRewardRouter,
+        epoch_reward_snapshot::EpochRewardSnapshot, metadata::Metadata, operator::Operator,
+        operator_avs_capacity::OperatorAvsCapacity, operator_avs_heartbeat::OperatorAvsHeartbeat,
+        operator_avs_score::OperatorAvsScore, operator_avs_ticket::OperatorAvsTicket,
+        operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+        operator_vault_ticket::OperatorVaultTicket, result::RestakingCoreError,
+        reward_claim_status::RewardClaimStatus, reward_distribution::RewardDistribution,
+        vault_operator_epoch_stake::VaultOperatorEpochStake,
+    };
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([0xaa; 32]);
+
+    /// The errors `deserialize_checked` of an account type returns for each way the account can
+    /// be malformed
+    struct Errors {
+        empty: RestakingCoreError,
+        invalid_owner: RestakingCoreError,
+        invalid_account_type: RestakingCoreError,
+        invalid_data: fn(&RestakingCoreError) -> bool,
+        invalid_pda: RestakingCoreError,
+    }
+
+    macro_rules! errors {
+        ($empty:ident, $owner:ident, $account_type:ident, $data:ident, $pda:ident) => {
+            Errors {
+                empty: RestakingCoreError::$empty,
+                invalid_owner: RestakingCoreError::$owner,
+                invalid_account_type: RestakingCoreError::$account_type,
+                invalid_data: |e| matches!(e, RestakingCoreError::$data(_)),
+                invalid_pda: RestakingCoreError::$pda,
+            }
+        };
+    }
+
+    /// Feeds `deserialize` the account at `pda` serialized by `data` with its bump, then
+    /// variations of it that shall each fail with the error of the account type
+    #[track_caller]
+    fn assert_deserialize_checked(
+        (pda, bump): (Pubkey, u8),
+        data: impl Fn(u8) -> Vec<u8>,
+        deserialize: impl Fn(&AccountInfo) -> Result<(), RestakingCoreError>,
+        errors: Errors,
+    ) {
+        let check = |key: Pubkey, owner: Pubkey, mut data: Vec<u8>| {
+            let mut lamports = 0;
+            let account = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            );
+            deserialize(&account)
+        };
+        let valid = data(bump);
+
+        assert_eq!(check(pda, PROGRAM_ID, valid.clone()), Ok(()));
+        // accounts only grow when their layout is migrated, so data past the layout is ignored
+        let mut oversized = valid.clone();
+        oversized.extend([0; 64]);
+        assert_eq!(check(pda, PROGRAM_ID, oversized), Ok(()));
+
+        assert_eq!(check(pda, PROGRAM_ID, Vec::new()), Err(errors.empty));
+        assert_eq!(
+            check(pda, Pubkey::new_unique(), valid.clone()),
+            Err(errors.invalid_owner)
+        );
+        let mut tampered = valid.clone();
+        tampered[0] = tampered[0].wrapping_add(1);
+        assert_eq!(
+            check(pda, PROGRAM_ID, tampered),
+            Err(errors.invalid_account_type)
+        );
+        let truncated = valid[..valid.len() - 1].to_vec();
+        let error = check(pda, PROGRAM_ID, truncated).unwrap_err();
+        assert!((errors.invalid_data)(&error), "{error:?}");
+        // a bump or address that doesn't derive from the seeds
+        for (key, data) in [
+            (pda, data(bump.wrapping_sub(1))),
+            (Pubkey::new_unique(), valid),
+        ] {
+            assert_eq!(
+                check(key, PROGRAM_ID, data).err().as_ref(),
+                Some(&errors.invalid_pda)
+            );
+        }
+    }
+
+    /// A PDA with its bump, dropping the seeds
+    fn pda((address, bump, _): (Pubkey, u8, Vec<Vec<u8>>)) -> (Pubkey, u8) {
+        (address, bump)
+    }
+
+    #[test]
+    fn test_deserialize_checked_rejects_malformed_accounts() {
+        let [avs, operator, vault, slasher, mint, router, base, admin] =
+            [(); 8].map(|_| Pubkey::new_unique());
+
+        assert_deserialize_checked(
+            pda(Config::find_program_address(&PROGRAM_ID)),
+            |bump| Config::new(base, vault, bump).as_bytes().to_vec(),
+            |account| Config::deserialize_checked(&PROGRAM_ID, account).map(|_| ()),
+            errors!(
+                ConfigEmpty,
+                ConfigInvalidOwner,
+                ConfigInvalidAccountType,
+                ConfigInvalidData,
+                ConfigInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(Avs::find_program_address(&PROGRAM_ID, &base)),
+            |bump| {
+                Avs::new(base, admin, admin, admin, admin, admin, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| Avs::deserialize_checked(&PROGRAM_ID, account).map(|_| ()),
+            errors!(
+                AvsEmpty,
+                AvsInvalidOwner,
+                AvsInvalidAccountType,
+                AvsInvalidData,
+                AvsInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(Operator::find_program_address(&PROGRAM_ID, &base)),
+            |bump| {
+                Operator::new(base, admin, admin, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| Operator::deserialize_checked(&PROGRAM_ID, account).map(|_| ()),
+            errors!(
+                OperatorDataEmpty,
+                OperatorInvalidOwner,
+                OperatorInvalidAccountType,
+                OperatorInvalidData,
+                OperatorInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(AvsOperatorTicket::find_program_address(
+                &PROGRAM_ID,
+                &avs,
+                &operator,
+            )),
+            |bump| {
+                AvsOperatorTicket::new(avs, operator, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                AvsOperatorTicket::deserialize_checked(&PROGRAM_ID, account, &avs, &operator)
+                    .map(|_| ())
+            },
+            errors!(
+                AvsOperatorTicketEmpty,
+                AvsOperatorTicketInvalidOwner,
+                AvsOperatorTicketInvalidAccountType,
+                AvsOperatorTicketInvalidData,
+                AvsOperatorTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(AvsVaultTicket::find_program_address(
+                &PROGRAM_ID,
+                &avs,
+                &vault,
+            )),
+            |bump| {
+                AvsVaultTicket::new(avs, vault, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                AvsVaultTicket::deserialize_checked(&PROGRAM_ID, account, &avs, &vault).map(|_| ())
+            },
+            errors!(
+                AvsVaultTicketEmpty,
+                AvsVaultTicketInvalidOwner,
+                AvsVaultTicketInvalidAccountType,
+                AvsVaultTicketInvalidData,
+                AvsVaultTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(AvsVaultSlasherTicket::find_program_address(
+                &PROGRAM_ID,
+                &avs,
+                &vault,
+                &slasher,
+            )),
+            |bump| {
+                AvsVaultSlasherTicket::new(avs, vault, slasher, 0, [0; 32], 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                AvsVaultSlasherTicket::deserialize_checked(
+                    &PROGRAM_ID,
+                    account,
+                    &avs,
+                    &vault,
+                    &slasher,
+                )
+                .map(|_| ())
+            },
+            errors!(
+                AvsSlasherTicketEmpty,
+                AvsSlasherTicketInvalidOwner,
+                AvsSlasherTicketInvalidAccountType,
+                AvsSlasherTicketInvalidData,
+                AvsSlasherTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(OperatorAvsTicket::find_program_address(
+                &PROGRAM_ID,
+                &operator,
+                &avs,
+            )),
+            |bump| {
+                OperatorAvsTicket::new(operator, avs, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                OperatorAvsTicket::deserialize_checked(&PROGRAM_ID, account, &operator, &avs)
+                    .map(|_| ())
+            },
+            errors!(
+                OperatorAvsTicketEmpty,
+                OperatorAvsTicketInvalidOwner,
+                OperatorAvsTicketInvalidAccountType,
+                OperatorAvsTicketInvalidData,
+                OperatorAvsTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(OperatorVaultTicket::find_program_address(
+                &PROGRAM_ID,
+                &operator,
+                &vault,
+            )),
+            |bump| {
+                OperatorVaultTicket::new(operator, vault, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                OperatorVaultTicket::deserialize_checked(&PROGRAM_ID, account, &operator, &vault)
+                    .map(|_| ())
+            },
+            errors!(
+                OperatorVaultTicketEmpty,
+                OperatorVaultTicketInvalidOwner,
+                OperatorVaultTicketInvalidAccountType,
+                OperatorVaultTicketInvalidData,
+                OperatorVaultTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(OperatorAvsHeartbeat::find_program_address(
+                &PROGRAM_ID,
+                &operator,
+                &avs,
+            )),
+            |bump| {
+                OperatorAvsHeartbeat::new(operator, avs, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                OperatorAvsHeartbeat::deserialize_checked(&PROGRAM_ID, account, &operator, &avs)
+                    .map(|_| ())
+            },
+            errors!(
+                OperatorAvsHeartbeatEmpty,
+                OperatorAvsHeartbeatInvalidOwner,
+                OperatorAvsHeartbeatInvalidAccountType,
+                OperatorAvsHeartbeatInvalidData,
+                OperatorAvsHeartbeatInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(OperatorAvsCapacity::find_program_address(
+                &PROGRAM_ID,
+                &operator,
+                &avs,
+            )),
+            |bump| {
+                OperatorAvsCapacity::new(operator, avs, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                OperatorAvsCapacity::deserialize_checked(&PROGRAM_ID, account, &operator, &avs)
+                    .map(|_| ())
+            },
+            errors!(
+                OperatorAvsCapacityEmpty,
+                OperatorAvsCapacityInvalidOwner,
+                OperatorAvsCapacityInvalidAccountType,
+                OperatorAvsCapacityInvalidData,
+                OperatorAvsCapacityInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(AvsRewardBudget::find_program_address(
+                &PROGRAM_ID,
+                &avs,
+                &mint,
+            )),
+            |bump| {
+                AvsRewardBudget::new(avs, mint, admin, 10, 0, 0, 0, bump)
+                    .unwrap()
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                AvsRewardBudget::deserialize_checked(&PROGRAM_ID, account, &avs, &mint).map(|_| ())
+            },
+            errors!(
+                AvsRewardBudgetEmpty,
+                AvsRewardBudgetInvalidOwner,
+                AvsRewardBudgetInvalidAccountType,
+                AvsRewardBudgetInvalidData,
+                AvsRewardBudgetInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(Metadata::find_program_address(&PROGRAM_ID, &avs)),
+            |bump| Metadata::new(avs, bump).as_bytes().to_vec(),
+            |account| Metadata::deserialize_checked(&PROGRAM_ID, account, &avs).map(|_| ()),
+            errors!(
+                MetadataEmpty,
+                MetadataInvalidOwner,
+                MetadataInvalidAccountType,
+                MetadataInvalidData,
+                MetadataInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(EpochRewardRouter::find_program_address(
+                &PROGRAM_ID,
+                &avs,
+                &mint,
+                3,
+            )),
+            |bump| {
+                EpochRewardRouter::new(avs, mint, 3, 10, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                EpochRewardRouter::deserialize_checked(&PROGRAM_ID, account, &avs).map(|_| ())
+            },
+            errors!(
+                EpochRewardRouterEmpty,
+                EpochRewardRouterInvalidOwner,
+                EpochRewardRouterInvalidAccountType,
+                EpochRewardRouterInvalidData,
+                EpochRewardRouterInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(EpochRewardSnapshot::find_program_address(
+                &PROGRAM_ID,
+                &router,
+                &vault,
+                &operator,
+            )),
+            |bump| {
+                EpochRewardSnapshot::new(router, vault, operator, 0, 0, None, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                EpochRewardSnapshot::deserialize_checked(&PROGRAM_ID, account, &router).map(|_| ())
+            },
+            errors!(
+                EpochRewardSnapshotEmpty,
+                EpochRewardSnapshotInvalidOwner,
+                EpochRewardSnapshotInvalidAccountType,
+                EpochRewardSnapshotInvalidData,
+                EpochRewardSnapshotInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(OperatorEpochStake::find_program_address(
+                &PROGRAM_ID,
+                &operator,
+                3,
+            )),
+            |bump| {
+                OperatorEpochStake::new(operator, 3, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                OperatorEpochStake::deserialize_checked(&PROGRAM_ID, account, &operator, 3)
+                    .map(|_| ())
+            },
+            errors!(
+                OperatorEpochStakeEmpty,
+                OperatorEpochStakeInvalidOwner,
+                OperatorEpochStakeInvalidAccountType,
+                OperatorEpochStakeInvalidData,
+                OperatorEpochStakeInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultOperatorEpochStake::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &operator,
+                3,
+            )),
+            |bump| {
+                VaultOperatorEpochStake::new(vault, operator, 3, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                VaultOperatorEpochStake::deserialize_checked(
+                    &PROGRAM_ID,
+                    account,
+                    &vault,
+                    &operator,
+                    3,
+                )
+                .map(|_| ())
+            },
+            errors!(
+                VaultOperatorEpochStakeEmpty,
+                VaultOperatorEpochStakeInvalidOwner,
+                VaultOperatorEpochStakeInvalidAccountType,
+                VaultOperatorEpochStakeInvalidData,
+                VaultOperatorEpochStakeInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(OperatorAvsScore::find_program_address(
+                &PROGRAM_ID,
+                &operator,
+                &avs,
+            )),
+            |bump| {
+                OperatorAvsScore::new(operator, avs, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                OperatorAvsScore::deserialize_checked(&PROGRAM_ID, account, &operator, &avs)
+                    .map(|_| ())
+            },
+            errors!(
+                OperatorAvsScoreEmpty,
+                OperatorAvsScoreInvalidOwner,
+                OperatorAvsScoreInvalidAccountType,
+                OperatorAvsScoreInvalidData,
+                OperatorAvsScoreInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(OperatorStakeHistory::find_program_address(
+                &PROGRAM_ID,
+                &operator,
+            )),
+            |bump| {
+                OperatorStakeHistory::new(operator, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                OperatorStakeHistory::deserialize_checked(&PROGRAM_ID, account, &operator)
+                    .map(|_| ())
+            },
+            errors!(
+                OperatorStakeHistoryEmpty,
+                OperatorStakeHistoryInvalidOwner,
+                OperatorStakeHistoryInvalidAccountType,
+                OperatorStakeHistoryInvalidData,
+                OperatorStakeHistoryInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(RewardDistribution::find_program_address(
+                &PROGRAM_ID,
+                &avs,
+                &mint,
+                3,
+            )),
+            |bump| {
+                RewardDistribution::new(avs, mint, 3, [7; 32], 1_000, 10, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| RewardDistribution::deserialize_checked(&PROGRAM_ID, account).map(|_| ()),
+            errors!(
+                RewardDistributionEmpty,
+                RewardDistributionInvalidOwner,
+                RewardDistributionInvalidAccountType,
+                RewardDistributionInvalidData,
+                RewardDistributionInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(RewardClaimStatus::find_program_address(
+                &PROGRAM_ID,
+                &router,
+                &admin,
+            )),
+            |bump| {
+                RewardClaimStatus::new(router, admin, 100, 5, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                RewardClaimStatus::deserialize_checked(&PROGRAM_ID, account, &router, &admin)
+                    .map(|_| ())
+            },
+            errors!(
+                RewardClaimStatusEmpty,
+                RewardClaimStatusInvalidOwner,
+                RewardClaimStatusInvalidAccountType,
+                RewardClaimStatusInvalidData,
+                RewardClaimStatusInvalidPda
+            ),
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The max length of a name in bytes
+pub const MAX_METADATA_NAME_LEN: usize = 32;
+
+/// The max length of a URI in bytes
+pub const MAX_METADATA_URI_LEN: usize = 128;
+
+/// The max length of an icon URI in bytes
+pub const MAX_METADATA_ICON_LEN: usize = 128;
+
+/// The public identity of an AVS or operator, so frontends can display it without a separate
+/// registry. Created by the first metadata update of its metadata admin.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct Metadata {
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The AVS or operator account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    parent: Pubkey,
+
+    /// The display name, the first `name_len` bytes are in use
+    #[serde(serialize_with = "serde_fields::text")]
+    name: [u8; MAX_METADATA_NAME_LEN],
+
+    #[serde(skip)]
+    name_len: u8,
+
+    /// The URI of a page or JSON document describing the parent, the first `uri_len` bytes are in
+    /// use
+    #[serde(serialize_with = "serde_fields::text")]
+    uri: [u8; MAX_METADATA_URI_LEN],
+
+    #[serde(skip)]
+    uri_len: u8,
+
+    /// The URI of the icon, the first `icon_len` bytes are in use
+    #[serde(serialize_with = "serde_fields::text")]
+    icon: [u8; MAX_METADATA_ICON_LEN],
+
+    #[serde(skip)]
+    icon_len: u8,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for Metadata {
+    const DISCRIMINATOR: u8 = AccountType::Metadata as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl Metadata {
+    pub const fn new(parent: Pubkey, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::Metadata as u8,
+            parent,
+            name: [0; MAX_METADATA_NAME_LEN],
+            name_len: 0,
+            uri: [0; MAX_METADATA_URI_LEN],
+            uri_len: 0,
+            icon: [0; MAX_METADATA_ICON_LEN],
+            icon_len: 0,
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn parent(&self) -> Pubkey {
+        self.parent
+    }
+
+    pub fn name(&self) -> &str {
+        Self::field(&self.name, self.name_len)
+    }
+
+    pub fn uri(&self) -> &str {
+        Self::field(&self.uri, self.uri_len)
+    }
+
+    pub fn icon(&self) -> &str {
+        Self::field(&self.icon, self.icon_len)
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// Replaces every field, empty strings clear them
+    pub fn set(&mut self, name: &str, uri: &str, icon: &str) -> RestakingCoreResult<()> {
+        if name.len() > MAX_METADATA_NAME_LEN
+            || uri.len() > MAX_METADATA_URI_LEN
+            || icon.len() > MAX_METADATA_ICON_LEN
+        {
+            return Err(RestakingCoreError::MetadataFieldTooLong);
+        }
+        self.name_len = Self::set_field(&mut self.name, name);
+        self.uri_len = Self::set_field(&mut self.uri, uri);
+        self.icon_len = Self::set_field(&mut self.icon, icon);
+        Ok(())
+    }
+
+    /// Fields are only written from strings, so they're valid UTF-8 unless the account is corrupt
+    fn field(bytes: &[u8], len: u8) -> &str {
+        bytes
+            .get(..len as usize)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .unwrap_or_default()
+    }
+
+    fn set_field(bytes: &mut [u8], value: &str) -> u8 {
+        bytes.fill(0);
+        bytes[..value.len()].copy_from_slice(value.as_bytes());
+        value.len() as u8
+    }
+
+    pub fn seeds(parent: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([b"metadata".to_vec(), parent.to_bytes().to_vec()])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        parent: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(parent);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        parent: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::MetadataEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::MetadataInvalidOwner);
+        }
+
+        let metadata = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::MetadataInvalidAccountType
+            }
+            e => RestakingCoreError::MetadataInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(parent);
+        seeds.push(vec![metadata.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::MetadataInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::MetadataInvalidPda);
+        }
+        Ok(metadata)
+    }
+}
+
+pub struct SanitizedMetadata<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedMetadata<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        parent: &Pubkey,
+    ) -> RestakingCoreResult<SanitizedMetadata<'a, 'info>> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::MetadataNotWritable);
+        }
+        Metadata::deserialize_checked(program_id, account, parent)?;
+
+        Ok(SanitizedMetadata { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn metadata(&self) -> Ref<'_, Metadata> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..Metadata::LEN])
+        })
+    }
+
+    pub fn metadata_mut(&mut self) -> RefMut<'_, Metadata> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..Metadata::LEN])
+        })
+    }
+}
@@ -0,0 +1,208 @@
+//! Layout migrations of accounts written by earlier versions of the program.
+//!
+//! Accounts grow by inserting fields right before their reserved space, so each step of
+//! [`MIGRATIONS`] inserts a field at a fixed distance from the end of the account: the bytes
+//! before the field and the reserved space and bump after it are kept as they are. [`migrate`]
+//! chains the steps from the length of an account up to the length of its current layout, then
+//! records the [`ZeroCopy::VERSION`] of the layout in the account.
+use jito_jsm_core::zero_copy::{ZeroCopy, ZeroCopyError};
+
+use crate::{
+    avs::Avs,
+    avs_operator_ticket::AvsOperatorTicket,
+    avs_reward_budget::AvsRewardBudget,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket,
+    config::Config,
+    epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot,
+    metadata::Metadata,
+    operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity,
+    operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore,
+    operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake,
+    operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket,
+    result::{RestakingCoreError, RestakingCoreResult},
+    reward_claim_status::RewardClaimStatus,
+    reward_distribution::RewardDistribution,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+    AccountType,
+};
+
+/// The offset of the admin in the AVS and operator layouts, after the discriminator and base
+const ADMIN_OFFSET: usize = 33;
+
+/// A step growing the accounts of one type from an earlier layout
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutMigration {
+    pub account_type: AccountType,
+
+    /// The length of the accounts before the step
+    pub from_len: usize,
+
+    /// The name of the inserted field
+    pub field: &'static str,
+
+    /// The length of the inserted field
+    pub field_len: usize,
+
+    /// The length of the layout after the inserted field, the reserved space and bump
+    pub tail_len: usize,
+
+    /// Returns the initial value of the field, given the account before the step
+    pub fill: fn(&[u8]) -> Vec<u8>,
+}
+
+impl LayoutMigration {
+    /// The length of the accounts after the step
+    pub const fn to_len(&self) -> usize {
+        self.from_len.saturating_add(self.field_len)
+    }
+
+    /// The offset the field is inserted at
+    pub const fn offset(&self) -> usize {
+        self.from_len.saturating_sub(self.tail_len)
+    }
+
+    /// Inserts the field into the account data, which shall be `from_len` long
+    pub fn apply(&self, data: &[u8]) -> RestakingCoreResult<Vec<u8>> {
+        if data.len() != self.from_len {
+            return Err(RestakingCoreError::AccountMigrationNotFound);
+        }
+        let field = (self.fill)(data);
+        if field.len() != self.field_len {
+            return Err(RestakingCoreError::AccountMigrationNotFound);
+        }
+        let (head, tail) = data.split_at(self.offset());
+
+        let mut migrated = Vec::with_capacity(self.to_len());
+        migrated.extend_from_slice(head);
+        migrated.extend_from_slice(&field);
+        migrated.extend_from_slice(tail);
+        Ok(migrated)
+    }
+}
+
+/// Copies the admin of the account, the default of fields naming a key the admin may change
+fn admin(data: &[u8]) -> Vec<u8> {
+    data.get(ADMIN_OFFSET..ADMIN_OFFSET.saturating_add(32))
+        .unwrap_or_default()
+        .to_vec()
+}
+
+/// Every layout migration, in the order they were introduced
+pub const MIGRATIONS: &[LayoutMigration] = &[
+    LayoutMigration {
+        account_type: AccountType::Avs,
+        from_len: Avs::LEN.saturating_sub(32),
+        field: "rent_collector",
+        field_len: 32,
+        tail_len: 129,
+        fill: admin,
+    },
+    LayoutMigration {
+        account_type: AccountType::Operator,
+        from_len: Operator::LEN.saturating_sub(32),
+        field: "rent_collector",
+        field_len: 32,
+        tail_len: 1025,
+        fill: admin,
+    },
+];
+
+/// The length of the current layout of the account type
+pub const fn layout_len(account_type: AccountType) -> usize {
+    match account_type {
+        AccountType::Config => Config::LEN,
+        AccountType::Avs => Avs::LEN,
+        AccountType::AvsOperatorTicket => AvsOperatorTicket::LEN,
+        AccountType::AvsVaultSlasherTicket => AvsVaultSlasherTicket::LEN,
+        AccountType::AvsVaultTicket => AvsVaultTicket::LEN,
+        AccountType::Operator => Operator::LEN,
+        AccountType::OperatorAvsTicket => OperatorAvsTicket::LEN,
+        AccountType::OperatorVaultTicket => OperatorVaultTicket::LEN,
+        AccountType::OperatorAvsHeartbeat => OperatorAvsHeartbeat::LEN,
+        AccountType::AvsRewardBudget => AvsRewardBudget::LEN,
+        AccountType::Metadata => Metadata::LEN,
+        AccountType::OperatorAvsCapacity => OperatorAvsCapacity::LEN,
+        AccountType::EpochRewardRouter => EpochRewardRouter::LEN,
+        AccountType::EpochRewardSnapshot => EpochRewardSnapshot::LEN,
+        AccountType::OperatorEpochStake => OperatorEpochStake::LEN,
+        AccountType::VaultOperatorEpochStake => VaultOperatorEpochStake::LEN,
+        AccountType::OperatorAvsScore => OperatorAvsScore::LEN,
+        AccountType::OperatorStakeHistory => OperatorStakeHistory::LEN,
+        AccountType::RewardDistribution => RewardDistribution::LEN,
+        AccountType::RewardClaimStatus => RewardClaimStatus::LEN,
+    }
+}
+
+/// Returns the steps migrating an account of the type and length to the current layout, none if
+/// it's up to date
+pub fn plan(account_type: AccountType, len: usize) -> RestakingCoreResult<Vec<LayoutMigration>> {
+    let target_len = layout_len(account_type);
+    let mut steps = Vec::new();
+    let mut len = len;
+    while len < target_len {
+        let step = MIGRATIONS
+            .iter()
+            .find(|m| m.account_type == account_type && m.from_len == len)
+            .ok_or(RestakingCoreError::AccountMigrationNotFound)?;
+        steps.push(*step);
+        len = step.to_len();
+    }
+    if !steps.is_empty() && len != target_len {
+        return Err(RestakingCoreError::AccountMigrationNotFound);
+    }
+    Ok(steps)
+}
+
+/// Migrates the account data to the current layout of the account type. Accounts already at
+/// their current layout and version are returned as they are.
+pub fn migrate(account_type: AccountType, data: &[u8]) -> RestakingCoreResult<Vec<u8>> {
+    let mut migrated = data.to_vec();
+    for step in plan(account_type, data.len())? {
+        migrated = step.apply(&migrated)?;
+    }
+    set_current_version(account_type, &mut migrated)?;
+    Ok(migrated)
+}
+
+/// Records the current version of the account type in the data, which shall be at its current
+/// layout. Accounts written by a newer version of the program are rejected.
+fn set_current_version(account_type: AccountType, data: &mut [u8]) -> RestakingCoreResult<()> {
+    fn set<T: ZeroCopy>(data: &mut [u8]) -> RestakingCoreResult<()> {
+        let account = T::load_mut(data).map_err(|e| match e {
+            ZeroCopyError::UnsupportedVersion { .. } => {
+                RestakingCoreError::AccountVersionUnsupported
+            }
+            _ => RestakingCoreError::AccountMigrationNotFound,
+        })?;
+        account.set_version(T::VERSION);
+        Ok(())
+    }
+    match account_type {
+        AccountType::Config => set::<Config>(data),
+        AccountType::Avs => set::<Avs>(data),
+        AccountType::AvsOperatorTicket => set::<AvsOperatorTicket>(data),
+        AccountType::AvsVaultSlasherTicket => set::<AvsVaultSlasherTicket>(data),
+        AccountType::AvsVaultTicket => set::<AvsVaultTicket>(data),
+        AccountType::Operator => set::<Operator>(data),
+        AccountType::OperatorAvsTicket => set::<OperatorAvsTicket>(data),
+        AccountType::OperatorVaultTicket => set::<OperatorVaultTicket>(data),
+        AccountType::OperatorAvsHeartbeat => set::<OperatorAvsHeartbeat>(data),
+        AccountType::AvsRewardBudget => set::<AvsRewardBudget>(data),
+        AccountType::Metadata => set::<Metadata>(data),
+        AccountType::OperatorAvsCapacity => set::<OperatorAvsCapacity>(data),
+        AccountType::EpochRewardRouter => set::<EpochRewardRouter>(data),
+        AccountType::EpochRewardSnapshot => set::<EpochRewardSnapshot>(data),
+        AccountType::OperatorEpochStake => set::<OperatorEpochStake>(data),
+        AccountType::VaultOperatorEpochStake => set::<VaultOperatorEpochStake>(data),
+        AccountType::OperatorAvsScore => set::<OperatorAvsScore>(data),
+        AccountType::OperatorStakeHistory => set::<OperatorStakeHistory>(data),
+        AccountType::RewardDistribution => set::<RewardDistribution>(data),
+        AccountType::RewardClaimStatus => set::<RewardClaimStatus>(data),
+    }
+}
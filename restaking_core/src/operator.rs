@@ -1,86 +1,279 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    counter::Counter,
+    pod::{PodU16, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
+    config::{Config, MAX_OPERATOR_FEE_BPS},
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+/// Splits rewards into the operator fee of `operator_fee_bps` and the share of the vault stakers.
+/// The fee rounds down, so the stakers get the remainder.
+pub fn split_rewards(rewards: u64, operator_fee_bps: u16) -> (u64, u64) {
+    let fee_bps = operator_fee_bps.min(MAX_OPERATOR_FEE_BPS);
+    let fee = (rewards as u128)
+        .saturating_mul(fee_bps as u128)
+        .checked_div(MAX_OPERATOR_FEE_BPS as u128)
+        .and_then(|fee| u64::try_from(fee).ok())
+        .unwrap_or(0);
+    (fee, rewards.saturating_sub(fee))
+}
+
+/// The number of past voters an [`Operator`] keeps, see [`Operator::voter_history`]
+pub const MAX_VOTER_ROTATIONS: usize = 8;
+
+/// A voter the operator rotated away from and the slots it was active in
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+    Serialize,
+    Pod,
+    Zeroable,
+)]
+#[repr(C)]
+pub struct VoterRotation {
+    /// The voter, or the default pubkey if the entry is unused
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    voter: Pubkey,
+
+    /// The slot the voter became active in
+    activated_slot: PodU64,
+
+    /// The slot the next voter replaced it in
+    deactivated_slot: PodU64,
+}
+
+impl VoterRotation {
+    pub const fn voter(&self) -> Pubkey {
+        self.voter
+    }
+
+    pub const fn activated_slot(&self) -> u64 {
+        self.activated_slot.get()
+    }
+
+    pub const fn deactivated_slot(&self) -> u64 {
+        self.deactivated_slot.get()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.voter == Pubkey::default()
+    }
+
+    fn contains(&self, slot: u64) -> bool {
+        self.activated_slot() <= slot && slot < self.deactivated_slot()
+    }
+}
+
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct Operator {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The base pubkey used as a seed for the PDA
+    #[serde(serialize_with = "serde_fields::pubkey")]
     base: Pubkey,
 
     /// The admin pubkey
+    #[serde(serialize_with = "serde_fields::pubkey")]
     admin: Pubkey,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs_admin: Pubkey,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault_admin: Pubkey,
 
     /// The voter pubkey
+    #[serde(serialize_with = "serde_fields::pubkey")]
     voter: Pubkey,
 
     /// The operator index
-    index: u64,
+    index: PodU64,
+
+    avs_count: Counter,
+
+    vault_count: Counter,
+
+    /// The key allowed to submit admin operations signed off-chain by the operator admin, or the
+    /// default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    relayer: Pubkey,
+
+    /// The nonce the next authorization signed off-chain by the admins shall carry, see
+    /// [`Operator::use_nonce`]
+    nonce: Counter,
+
+    /// The admin allowed to withdraw tokens held by the operator
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    withdraw_admin: Pubkey,
+
+    /// The admin allowed to update the operator's metadata
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    metadata_admin: Pubkey,
+
+    /// The key proposed to take over as admin, or the default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    pending_admin: Pubkey,
+
+    /// The account receiving the rent of the operator tickets closed once deactivated
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    rent_collector: Pubkey,
+
+    /// The share of the rewards the operator keeps before the rest goes to the vault stakers, in
+    /// basis points
+    operator_fee_bps: PodU16,
+
+    /// The slot the fee last changed in, or zero if it never changed
+    last_fee_change_slot: PodU64,
+
+    /// A key the operator admin allowed to sign heartbeats in place of the voter, or the default
+    /// pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    session_key: Pubkey,
+
+    /// The slot the session key expires at
+    session_key_expiry_slot: PodU64,
+
+    /// The slot the voter became active in
+    voter_activated_slot: PodU64,
 
-    avs_count: u64,
+    /// The voters the operator rotated away from, newest first
+    voter_history: [VoterRotation; MAX_VOTER_ROTATIONS],
 
-    vault_count: u64,
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Number of AVS tickets of the operator closed, which the health checks no longer expect
+    closed_avs_count: Counter,
+
+    /// Number of vault tickets of the operator closed, which the health checks no longer expect
+    closed_vault_count: Counter,
 
     /// Reserved space
-    reserved_space: [u8; 1024],
+    #[serde(skip)]
+    reserved_space: [u8; 565],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
+impl ZeroCopy for Operator {
+    const DISCRIMINATOR: u8 = AccountType::Operator as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl Operator {
     pub const fn new(base: Pubkey, admin: Pubkey, voter: Pubkey, index: u64, bump: u8) -> Self {
         Self {
-            account_type: AccountType::Operator,
+            discriminator: AccountType::Operator as u8,
             base,
             admin,
             avs_admin: admin,
             vault_admin: admin,
             voter,
-            index,
-            avs_count: 0,
-            vault_count: 0,
-            reserved_space: [0; 1024],
+            index: PodU64::new(index),
+            avs_count: Counter::new(0),
+            vault_count: Counter::new(0),
+            relayer: Pubkey::new_from_array([0; 32]),
+            nonce: Counter::new(0),
+            withdraw_admin: admin,
+            metadata_admin: admin,
+            pending_admin: Pubkey::new_from_array([0; 32]),
+            rent_collector: admin,
+            operator_fee_bps: PodU16::new(0),
+            last_fee_change_slot: PodU64::new(0),
+            session_key: Pubkey::new_from_array([0; 32]),
+            session_key_expiry_slot: PodU64::new(0),
+            voter_activated_slot: PodU64::new(0),
+            voter_history: [VoterRotation {
+                voter: Pubkey::new_from_array([0; 32]),
+                activated_slot: PodU64::new(0),
+                deactivated_slot: PodU64::new(0),
+            }; MAX_VOTER_ROTATIONS],
+            version: Self::VERSION,
+            closed_avs_count: Counter::new(0),
+            closed_vault_count: Counter::new(0),
+            reserved_space: [0; 565],
             bump,
         }
     }
 
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn avs_count(&self) -> u64 {
-        self.avs_count
+        self.avs_count.get()
     }
 
     pub fn increment_avs_count(&mut self) -> RestakingCoreResult<()> {
-        self.avs_count = self
-            .avs_count
-            .checked_add(1)
-            .ok_or(RestakingCoreError::OperatorAvsCountOverflow)?;
+        self.avs_count
+            .increment("avs_count", RestakingCoreError::OperatorAvsCountOverflow)?;
         Ok(())
     }
 
     pub const fn vault_count(&self) -> u64 {
-        self.vault_count
+        self.vault_count.get()
     }
 
     pub fn increment_vault_count(&mut self) -> RestakingCoreResult<()> {
-        self.vault_count = self
-            .vault_count
-            .checked_add(1)
-            .ok_or(RestakingCoreError::OperatorVaultCountOverflow)?;
+        self.vault_count.increment(
+            "vault_count",
+            RestakingCoreError::OperatorVaultCountOverflow,
+        )?;
+        Ok(())
+    }
+
+    pub const fn closed_avs_count(&self) -> u64 {
+        self.closed_avs_count.get()
+    }
+
+    pub fn increment_closed_avs_count(&mut self) -> RestakingCoreResult<()> {
+        self.closed_avs_count.increment(
+            "closed_avs_count",
+            RestakingCoreError::OperatorAvsCountOverflow,
+        )?;
+        Ok(())
+    }
+
+    pub const fn closed_vault_count(&self) -> u64 {
+        self.closed_vault_count.get()
+    }
+
+    pub fn increment_closed_vault_count(&mut self) -> RestakingCoreResult<()> {
+        self.closed_vault_count.increment(
+            "closed_vault_count",
+            RestakingCoreError::OperatorVaultCountOverflow,
+        )?;
         Ok(())
     }
 
@@ -103,8 +296,29 @@ impl Operator {
         Ok(())
     }
 
-    pub fn set_admin(&mut self, admin: Pubkey) {
-        self.admin = admin;
+    /// The key proposed to take over as admin, if a transfer is pending
+    pub fn pending_admin(&self) -> Option<Pubkey> {
+        if self.pending_admin != Pubkey::default() {
+            Some(self.pending_admin)
+        } else {
+            None
+        }
+    }
+
+    /// Proposes a new admin, replacing any transfer that is already pending. The transfer
+    /// completes once the new admin accepts it.
+    pub fn set_pending_admin(&mut self, pending_admin: Pubkey) {
+        self.pending_admin = pending_admin;
+    }
+
+    /// Completes the pending admin transfer to `new_admin`
+    pub fn accept_admin(&mut self, new_admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.pending_admin() != Some(*new_admin) {
+            return Err(RestakingCoreError::OperatorInvalidPendingAdmin);
+        }
+        self.admin = *new_admin;
+        self.pending_admin = Pubkey::default();
+        Ok(())
     }
 
     pub const fn avs_admin(&self) -> Pubkey {
@@ -137,12 +351,208 @@ impl Operator {
         self.vault_admin = vault_admin;
     }
 
+    pub const fn withdraw_admin(&self) -> Pubkey {
+        self.withdraw_admin
+    }
+
+    pub fn check_withdraw_admin(&self, withdraw_admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.withdraw_admin != *withdraw_admin {
+            return Err(RestakingCoreError::OperatorInvalidWithdrawAdmin);
+        }
+        Ok(())
+    }
+
+    pub fn set_withdraw_admin(&mut self, withdraw_admin: Pubkey) {
+        self.withdraw_admin = withdraw_admin;
+    }
+
+    pub const fn metadata_admin(&self) -> Pubkey {
+        self.metadata_admin
+    }
+
+    pub fn check_metadata_admin(&self, metadata_admin: &Pubkey) -> RestakingCoreResult<()> {
+        if self.metadata_admin != *metadata_admin {
+            return Err(RestakingCoreError::OperatorInvalidMetadataAdmin);
+        }
+        Ok(())
+    }
+
+    pub fn set_metadata_admin(&mut self, metadata_admin: Pubkey) {
+        self.metadata_admin = metadata_admin;
+    }
+
     pub const fn voter(&self) -> Pubkey {
         self.voter
     }
 
-    pub fn set_voter(&mut self, voter: Pubkey) {
+    pub const fn voter_activated_slot(&self) -> u64 {
+        self.voter_activated_slot.get()
+    }
+
+    /// The voters the operator rotated away from, newest first
+    pub fn voter_history(&self) -> impl Iterator<Item = &VoterRotation> {
+        self.voter_history
+            .iter()
+            .take_while(|rotation| !rotation.is_empty())
+    }
+
+    /// Rotates to a new voter from the slot on, keeping the replaced voter in
+    /// [`Operator::voter_history`]. The oldest rotation is dropped once the history is full.
+    /// Setting the active voter again is a no-op.
+    pub fn set_voter(&mut self, voter: Pubkey, slot: u64) {
+        if voter == self.voter {
+            return;
+        }
+        self.voter_history.rotate_right(1);
+        self.voter_history[0] = VoterRotation {
+            voter: self.voter,
+            activated_slot: self.voter_activated_slot,
+            deactivated_slot: PodU64::new(slot),
+        };
         self.voter = voter;
+        self.voter_activated_slot = PodU64::new(slot);
+    }
+
+    pub fn check_voter(&self, voter: &Pubkey) -> RestakingCoreResult<()> {
+        if self.voter != *voter {
+            return Err(RestakingCoreError::OperatorInvalidVoter);
+        }
+        Ok(())
+    }
+
+    /// Fails unless `voter` was the active voter at the slot, so a message signed by the voter
+    /// before a rotation verifies the same whether it lands before or after the rotation. Fails
+    /// with [`RestakingCoreError::OperatorVoterHistoryUnavailable`] if the slot predates the
+    /// rotations the operator keeps.
+    pub fn check_voter_at_slot(&self, voter: &Pubkey, slot: u64) -> RestakingCoreResult<()> {
+        if slot >= self.voter_activated_slot() {
+            return self.check_voter(voter);
+        }
+        match self
+            .voter_history()
+            .find(|rotation| rotation.contains(slot))
+        {
+            Some(rotation) if rotation.voter == *voter => Ok(()),
+            Some(_) => Err(RestakingCoreError::OperatorInvalidVoter),
+            // a full history may have dropped the voter of the slot
+            None if self
+                .voter_history
+                .iter()
+                .all(|rotation| !rotation.is_empty()) =>
+            {
+                Err(RestakingCoreError::OperatorVoterHistoryUnavailable)
+            }
+            None => Err(RestakingCoreError::OperatorInvalidVoter),
+        }
+    }
+
+    /// The session key, as long as it hasn't expired at the slot
+    pub fn session_key(&self, slot: u64) -> Option<Pubkey> {
+        if self.session_key == Pubkey::default() || slot >= self.session_key_expiry_slot() {
+            None
+        } else {
+            Some(self.session_key)
+        }
+    }
+
+    pub const fn session_key_expiry_slot(&self) -> u64 {
+        self.session_key_expiry_slot.get()
+    }
+
+    /// Allows the session key to sign heartbeats until the expiry slot. The default pubkey revokes
+    /// the session key.
+    pub fn set_session_key(&mut self, session_key: Pubkey, expiry_slot: u64) {
+        self.session_key = session_key;
+        self.session_key_expiry_slot = PodU64::new(expiry_slot);
+    }
+
+    /// Fails unless the signer is the voter or the session key, see [`Operator::session_key`]
+    pub fn check_heartbeat_signer(&self, signer: &Pubkey, slot: u64) -> RestakingCoreResult<()> {
+        if self.voter != *signer && self.session_key(slot) != Some(*signer) {
+            return Err(RestakingCoreError::OperatorInvalidVoter);
+        }
+        Ok(())
+    }
+
+    pub fn relayer(&self) -> Option<Pubkey> {
+        if self.relayer == Pubkey::default() {
+            None
+        } else {
+            Some(self.relayer)
+        }
+    }
+
+    pub fn set_relayer(&mut self, relayer: Pubkey) {
+        self.relayer = relayer;
+    }
+
+    pub const fn rent_collector(&self) -> Pubkey {
+        self.rent_collector
+    }
+
+    pub fn set_rent_collector(&mut self, rent_collector: Pubkey) {
+        self.rent_collector = rent_collector;
+    }
+
+    pub const fn operator_fee_bps(&self) -> u16 {
+        self.operator_fee_bps.get()
+    }
+
+    pub const fn last_fee_change_slot(&self) -> u64 {
+        self.last_fee_change_slot.get()
+    }
+
+    /// Sets the fee the operator charges on rewards. The fee is bounded by the max of the config
+    /// and can change again once the config cooldown elapsed since the last change.
+    pub fn set_operator_fee_bps(
+        &mut self,
+        operator_fee_bps: u16,
+        config: &Config,
+        slot: u64,
+    ) -> RestakingCoreResult<()> {
+        if operator_fee_bps > config.max_operator_fee_bps() {
+            return Err(RestakingCoreError::OperatorFeeTooHigh);
+        }
+        let last_change = self.last_fee_change_slot();
+        if last_change != 0
+            && slot < last_change.saturating_add(config.operator_fee_cooldown_slots())
+        {
+            return Err(RestakingCoreError::OperatorFeeCooldown);
+        }
+        self.operator_fee_bps = PodU16::new(operator_fee_bps);
+        self.last_fee_change_slot = PodU64::new(slot);
+        Ok(())
+    }
+
+    /// Splits rewards earned through the operator into its current fee and the share of the
+    /// vault stakers, see [`split_rewards`]
+    pub fn split_rewards(&self, rewards: u64) -> (u64, u64) {
+        split_rewards(rewards, self.operator_fee_bps())
+    }
+
+    pub fn check_relayer(&self, relayer: &Pubkey) -> RestakingCoreResult<()> {
+        if self.relayer() != Some(*relayer) {
+            return Err(RestakingCoreError::OperatorInvalidRelayer);
+        }
+        Ok(())
+    }
+
+    /// The nonce the next message signed off-chain by the admins shall carry. Signers fetch it
+    /// before signing a relayed admin operation or a session key grant.
+    pub const fn nonce(&self) -> u64 {
+        self.nonce.get()
+    }
+
+    /// Consumes the nonce of a message signed off-chain by the admins, so the message can't be
+    /// replayed. Every signature-authorized flow shares the nonce, so messages land in the order
+    /// they were signed.
+    pub fn use_nonce(&mut self, nonce: u64) -> RestakingCoreResult<()> {
+        if nonce != self.nonce.get() {
+            return Err(RestakingCoreError::OperatorInvalidNonce);
+        }
+        self.nonce
+            .increment("nonce", RestakingCoreError::OperatorInvalidNonce)?;
+        Ok(())
     }
 
     pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
@@ -168,11 +578,12 @@ impl Operator {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let operator = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::OperatorInvalidData(e.to_string()))?;
-        if operator.account_type != AccountType::Operator {
-            return Err(RestakingCoreError::OperatorInvalidAccountType);
-        }
+        let operator = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(&operator.base);
         seeds.push(vec![operator.bump]);
@@ -189,7 +600,6 @@ impl Operator {
 
 pub struct SanitizedOperator<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    operator: Box<Operator>,
 }
 
 impl<'a, 'info> SanitizedOperator<'a, 'info> {
@@ -202,25 +612,83 @@ impl<'a, 'info> SanitizedOperator<'a, 'info> {
             return Err(RestakingCoreError::OperatorNotWritable);
         }
 
-        let operator = Box::new(Operator::deserialize_checked(program_id, account)?);
+        Operator::deserialize_checked(program_id, account)?;
 
-        Ok(Self { account, operator })
+        Ok(Self { account })
     }
 
     pub const fn account(&self) -> &'a AccountInfo<'info> {
         self.account
     }
 
-    pub const fn operator(&self) -> &Operator {
-        &self.operator
+    pub fn operator(&self) -> Ref<'_, Operator> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..Operator::LEN])
+        })
     }
 
-    pub fn operator_mut(&mut self) -> &mut Operator {
-        &mut self.operator
+    pub fn operator_mut(&mut self) -> RefMut<'_, Operator> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..Operator::LEN])
+        })
     }
+}
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(&mut self.account.data.borrow_mut()[..], &self.operator)?;
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::{Operator, MAX_VOTER_ROTATIONS};
+    use crate::result::RestakingCoreError;
+
+    #[test]
+    fn test_check_voter_at_slot_across_rotations() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let third = Pubkey::new_unique();
+        let mut operator = Operator::new(Pubkey::new_unique(), first, first, 0, 255);
+
+        operator.set_voter(second, 100);
+        operator.set_voter(third, 200);
+        // setting the active voter again doesn't rotate
+        operator.set_voter(third, 300);
+
+        assert_eq!(operator.voter(), third);
+        assert_eq!(operator.voter_activated_slot(), 200);
+        assert_eq!(operator.voter_history().count(), 2);
+
+        operator.check_voter_at_slot(&first, 0).unwrap();
+        operator.check_voter_at_slot(&first, 99).unwrap();
+        operator.check_voter_at_slot(&second, 100).unwrap();
+        operator.check_voter_at_slot(&second, 199).unwrap();
+        operator.check_voter_at_slot(&third, 200).unwrap();
+        assert_eq!(
+            operator.check_voter_at_slot(&first, 100),
+            Err(RestakingCoreError::OperatorInvalidVoter)
+        );
+        assert_eq!(
+            operator.check_voter_at_slot(&second, 200),
+            Err(RestakingCoreError::OperatorInvalidVoter)
+        );
+    }
+
+    #[test]
+    fn test_check_voter_at_slot_before_history() {
+        let admin = Pubkey::new_unique();
+        let mut operator = Operator::new(Pubkey::new_unique(), admin, admin, 0, 255);
+        for slot in 1..=MAX_VOTER_ROTATIONS as u64 + 1 {
+            operator.set_voter(Pubkey::new_unique(), slot * 10);
+        }
+
+        assert_eq!(operator.voter_history().count(), MAX_VOTER_ROTATIONS);
+        // the first voter, active until slot 10, was dropped along with the oldest rotation
+        assert_eq!(
+            operator.check_voter_at_slot(&admin, 5),
+            Err(RestakingCoreError::OperatorVoterHistoryUnavailable)
+        );
+        let oldest = operator.voter_history().last().unwrap();
+        operator
+            .check_voter_at_slot(&oldest.voter(), oldest.activated_slot())
+            .unwrap();
     }
 }
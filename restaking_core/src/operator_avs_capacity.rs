@@ -0,0 +1,197 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The stake an operator advertises it can take on for an AVS, so vaults can match their
+/// delegations to operators with room for them. The operator lowers it as stake arrives.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct OperatorAvsCapacity {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The operator account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The AVS account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    avs: Pubkey,
+
+    /// The max additional stake the operator can handle for the AVS
+    capacity: PodU64,
+
+    /// The slot the capacity was last set
+    last_updated_slot: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for OperatorAvsCapacity {
+    const DISCRIMINATOR: u8 = AccountType::OperatorAvsCapacity as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl OperatorAvsCapacity {
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
+    pub const fn new(operator: Pubkey, avs: Pubkey, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::OperatorAvsCapacity as u8,
+            operator,
+            avs,
+            capacity: PodU64::new(0),
+            last_updated_slot: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn avs(&self) -> Pubkey {
+        self.avs
+    }
+
+    pub const fn capacity(&self) -> u64 {
+        self.capacity.get()
+    }
+
+    pub const fn last_updated_slot(&self) -> u64 {
+        self.last_updated_slot.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    pub fn set_capacity(&mut self, capacity: u64, slot: u64) {
+        self.capacity = PodU64::new(capacity);
+        self.last_updated_slot = PodU64::new(slot);
+    }
+
+    pub fn seeds(operator: &Pubkey, avs: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"operator_avs_capacity".to_vec(),
+            operator.to_bytes().to_vec(),
+            avs.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(operator, avs);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::OperatorAvsCapacityEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::OperatorAvsCapacityInvalidOwner);
+        }
+
+        let capacity = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorAvsCapacityInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorAvsCapacityInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(operator, avs);
+        seeds.push(vec![capacity.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::OperatorAvsCapacityInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::OperatorAvsCapacityInvalidPda);
+        }
+
+        Ok(capacity)
+    }
+}
+
+pub struct SanitizedOperatorAvsCapacity<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedOperatorAvsCapacity<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::OperatorAvsCapacityNotWritable);
+        }
+
+        OperatorAvsCapacity::deserialize_checked(program_id, account, operator, avs)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn operator_avs_capacity(&self) -> Ref<'_, OperatorAvsCapacity> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..OperatorAvsCapacity::LEN])
+        })
+    }
+
+    pub fn operator_avs_capacity_mut(&mut self) -> RefMut<'_, OperatorAvsCapacity> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..OperatorAvsCapacity::LEN])
+        })
+    }
+}
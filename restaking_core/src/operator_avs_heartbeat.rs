@@ -0,0 +1,198 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// Tracks the last slot an operator's voter signaled it was online for an AVS.
+/// AVS reward logic and dashboards can use this to exclude offline operators.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct OperatorAvsHeartbeat {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The operator account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The AVS account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    avs: Pubkey,
+
+    /// The last slot the operator's voter sent a heartbeat
+    last_heartbeat_slot: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for OperatorAvsHeartbeat {
+    const DISCRIMINATOR: u8 = AccountType::OperatorAvsHeartbeat as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl OperatorAvsHeartbeat {
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
+    pub const fn new(operator: Pubkey, avs: Pubkey, slot: u64, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::OperatorAvsHeartbeat as u8,
+            operator,
+            avs,
+            last_heartbeat_slot: PodU64::new(slot),
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn avs(&self) -> Pubkey {
+        self.avs
+    }
+
+    pub const fn last_heartbeat_slot(&self) -> u64 {
+        self.last_heartbeat_slot.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// Records a heartbeat at the given slot. Heartbeats can't move backwards in time.
+    pub fn record_heartbeat(&mut self, slot: u64) -> RestakingCoreResult<()> {
+        if slot < self.last_heartbeat_slot.get() {
+            return Err(RestakingCoreError::OperatorAvsHeartbeatInvalidSlot);
+        }
+        self.last_heartbeat_slot = PodU64::new(slot);
+        Ok(())
+    }
+
+    /// Returns the number of slots since the last heartbeat
+    pub const fn heartbeat_age(&self, slot: u64) -> u64 {
+        slot.saturating_sub(self.last_heartbeat_slot.get())
+    }
+
+    pub fn seeds(operator: &Pubkey, avs: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"operator_avs_heartbeat".to_vec(),
+            operator.to_bytes().to_vec(),
+            avs.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(operator, avs);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::OperatorAvsHeartbeatEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::OperatorAvsHeartbeatInvalidOwner);
+        }
+
+        let heartbeat = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorAvsHeartbeatInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorAvsHeartbeatInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(operator, avs);
+        seeds.push(vec![heartbeat.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::OperatorAvsHeartbeatInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::OperatorAvsHeartbeatInvalidPda);
+        }
+
+        Ok(heartbeat)
+    }
+}
+
+pub struct SanitizedOperatorAvsHeartbeat<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedOperatorAvsHeartbeat<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::OperatorAvsHeartbeatNotWritable);
+        }
+
+        OperatorAvsHeartbeat::deserialize_checked(program_id, account, operator, avs)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn operator_avs_heartbeat(&self) -> Ref<'_, OperatorAvsHeartbeat> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..OperatorAvsHeartbeat::LEN])
+        })
+    }
+
+    pub fn operator_avs_heartbeat_mut(&mut self) -> RefMut<'_, OperatorAvsHeartbeat> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..OperatorAvsHeartbeat::LEN])
+        })
+    }
+}
@@ -0,0 +1,218 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::{PodU16, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The highest performance score, in basis points
+pub const MAX_SCORE_BPS: u16 = 10_000;
+
+/// The latest performance score the scorer of an AVS attested for one of its operators.
+/// Reward routing and delegation rebalancing can weight operators by it.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct OperatorAvsScore {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The operator account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The AVS account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    avs: Pubkey,
+
+    /// The latest score of the operator, in basis points of [`MAX_SCORE_BPS`]
+    score_bps: PodU16,
+
+    /// The slot of the latest attestation
+    last_attestation_slot: PodU64,
+
+    /// The number of attestations recorded
+    attestation_count: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for OperatorAvsScore {
+    const DISCRIMINATOR: u8 = AccountType::OperatorAvsScore as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl OperatorAvsScore {
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
+    pub const fn new(operator: Pubkey, avs: Pubkey, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::OperatorAvsScore as u8,
+            operator,
+            avs,
+            score_bps: PodU16::new(0),
+            last_attestation_slot: PodU64::new(0),
+            attestation_count: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn avs(&self) -> Pubkey {
+        self.avs
+    }
+
+    pub const fn score_bps(&self) -> u16 {
+        self.score_bps.get()
+    }
+
+    pub const fn last_attestation_slot(&self) -> u64 {
+        self.last_attestation_slot.get()
+    }
+
+    pub const fn attestation_count(&self) -> u64 {
+        self.attestation_count.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// Records a score attested at the given slot. Scores are capped at [`MAX_SCORE_BPS`] and
+    /// attestations can't move backwards in time.
+    pub fn record_score(&mut self, score_bps: u16, slot: u64) -> RestakingCoreResult<()> {
+        if score_bps > MAX_SCORE_BPS {
+            return Err(RestakingCoreError::OperatorAvsScoreInvalidScore);
+        }
+        if slot < self.last_attestation_slot.get() {
+            return Err(RestakingCoreError::OperatorAvsScoreInvalidSlot);
+        }
+        self.score_bps = PodU16::new(score_bps);
+        self.last_attestation_slot = PodU64::new(slot);
+        self.attestation_count = PodU64::new(self.attestation_count.get().saturating_add(1));
+        Ok(())
+    }
+
+    pub fn seeds(operator: &Pubkey, avs: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"operator_avs_score".to_vec(),
+            operator.to_bytes().to_vec(),
+            avs.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(operator, avs);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::OperatorAvsScoreEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::OperatorAvsScoreInvalidOwner);
+        }
+
+        let score = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorAvsScoreInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorAvsScoreInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(operator, avs);
+        seeds.push(vec![score.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::OperatorAvsScoreInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::OperatorAvsScoreInvalidPda);
+        }
+
+        Ok(score)
+    }
+}
+
+pub struct SanitizedOperatorAvsScore<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedOperatorAvsScore<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        operator: &Pubkey,
+        avs: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::OperatorAvsScoreNotWritable);
+        }
+
+        OperatorAvsScore::deserialize_checked(program_id, account, operator, avs)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn operator_avs_score(&self) -> Ref<'_, OperatorAvsScore> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..OperatorAvsScore::LEN])
+        })
+    }
+
+    pub fn operator_avs_score_mut(&mut self) -> RefMut<'_, OperatorAvsScore> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..OperatorAvsScore::LEN])
+        })
+    }
+}
@@ -1,42 +1,79 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    slot_toggled_field::{SlotToggle, SlotToggleState},
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct OperatorAvsTicket {
-    account_type: AccountType,
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The operator account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator: Pubkey,
 
     /// The AVS account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs: Pubkey,
 
-    index: u64,
+    index: PodU64,
 
     state: SlotToggle,
 
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 127],
 
     bump: u8,
 }
 
+impl ZeroCopy for OperatorAvsTicket {
+    const DISCRIMINATOR: u8 = AccountType::OperatorAvsTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl OperatorAvsTicket {
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
     pub const fn new(operator: Pubkey, avs: Pubkey, index: u64, slot_added: u64, bump: u8) -> Self {
         Self {
-            account_type: AccountType::OperatorAvsTicket,
+            discriminator: AccountType::OperatorAvsTicket as u8,
             operator,
             avs,
-            index,
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            version: Self::VERSION,
+            reserved: [0; 127],
             bump,
         }
     }
@@ -53,6 +90,8 @@ impl OperatorAvsTicket {
         &self.state
     }
 
+    /// Deactivates the ticket, which cools down until the next epoch starts and is closed once the
+    /// removal is finalized
     pub fn deactivate(&mut self, slot: u64) -> RestakingCoreResult<()> {
         if self.state.deactivate(slot) {
             Ok(())
@@ -62,7 +101,7 @@ impl OperatorAvsTicket {
     }
 
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn bump(&self) -> u8 {
@@ -77,6 +116,27 @@ impl OperatorAvsTicket {
         }
     }
 
+    /// The ticket shall have warmed up, see [`crate::config::Config::ticket_epoch_length`]
+    pub const fn check_warmed_up(&self, slot: u64, epoch_length: u64) -> RestakingCoreResult<()> {
+        match self.state.state(slot, epoch_length) {
+            SlotToggleState::Active => Ok(()),
+            SlotToggleState::WarmingUp => Err(RestakingCoreError::OperatorAvsTicketWarmingUp),
+            SlotToggleState::CoolingDown | SlotToggleState::Inactive => {
+                Err(RestakingCoreError::OperatorAvsTicketNotActive)
+            }
+        }
+    }
+
+    /// The operator can be slashed for the AVS while the ticket is active, or deactivated until
+    /// the removal is finalized, which closes the ticket
+    pub const fn check_slashable(&self, slot: u64) -> RestakingCoreResult<()> {
+        if self.state.is_active(slot) || self.state.is_deactivated() {
+            Ok(())
+        } else {
+            Err(RestakingCoreError::OperatorAvsTicketNotActive)
+        }
+    }
+
     pub fn seeds(operator: &Pubkey, avs: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"operator_avs_ticket".to_vec(),
@@ -110,11 +170,12 @@ impl OperatorAvsTicket {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let avs_vault_ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::OperatorAvsTicketInvalidData(e.to_string()))?;
-        if avs_vault_ticket.account_type != AccountType::OperatorAvsTicket {
-            return Err(RestakingCoreError::OperatorAvsTicketInvalidAccountType);
-        }
+        let avs_vault_ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorAvsTicketInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorAvsTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(operator, avs);
         seeds.push(vec![avs_vault_ticket.bump]);
@@ -131,7 +192,6 @@ impl OperatorAvsTicket {
 
 pub struct SanitizedOperatorAvsTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    operator_avs_ticket: Box<OperatorAvsTicket>,
 }
 
 impl<'a, 'info> SanitizedOperatorAvsTicket<'a, 'info> {
@@ -146,33 +206,24 @@ impl<'a, 'info> SanitizedOperatorAvsTicket<'a, 'info> {
             return Err(RestakingCoreError::OperatorAvsTicketNotWritable);
         }
 
-        let operator_avs_ticket = Box::new(OperatorAvsTicket::deserialize_checked(
-            program_id, account, operator, avs,
-        )?);
+        OperatorAvsTicket::deserialize_checked(program_id, account, operator, avs)?;
 
-        Ok(Self {
-            account,
-            operator_avs_ticket,
-        })
+        Ok(Self { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn operator_avs_ticket(&self) -> &OperatorAvsTicket {
-        &self.operator_avs_ticket
-    }
-
-    pub fn operator_avs_ticket_mut(&mut self) -> &mut OperatorAvsTicket {
-        &mut self.operator_avs_ticket
+    pub fn operator_avs_ticket(&self) -> Ref<'_, OperatorAvsTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..OperatorAvsTicket::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &self.operator_avs_ticket,
-        )?;
-        Ok(())
+    pub fn operator_avs_ticket_mut(&mut self) -> RefMut<'_, OperatorAvsTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..OperatorAvsTicket::LEN])
+        })
     }
 }
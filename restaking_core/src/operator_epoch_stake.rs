@@ -0,0 +1,206 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The stake delegated to an operator during an epoch, summed over the
+/// [`crate::vault_operator_epoch_stake::VaultOperatorEpochStake`] snapshots of its vaults.
+///
+/// Rewards and slashing read the stake of an epoch from the snapshots instead of the live
+/// delegation lists, which keep changing during and after the epoch.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct OperatorEpochStake {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The operator the stake is delegated to
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The epoch of the snapshot
+    epoch: PodU64,
+
+    /// The total stake of the vaults snapshotted so far
+    stake: PodU64,
+
+    /// The number of vaults snapshotted so far
+    vault_count: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 63],
+
+    bump: u8,
+}
+
+impl ZeroCopy for OperatorEpochStake {
+    const DISCRIMINATOR: u8 = AccountType::OperatorEpochStake as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl OperatorEpochStake {
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 1;
+
+    pub const fn new(operator: Pubkey, epoch: u64, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::OperatorEpochStake as u8,
+            operator,
+            epoch: PodU64::new(epoch),
+            stake: PodU64::new(0),
+            vault_count: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 63],
+            bump,
+        }
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn epoch(&self) -> u64 {
+        self.epoch.get()
+    }
+
+    pub const fn stake(&self) -> u64 {
+        self.stake.get()
+    }
+
+    pub const fn vault_count(&self) -> u64 {
+        self.vault_count.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// Adds the stake of a vault snapshotted for the epoch
+    pub fn record_vault_stake(&mut self, stake: u64) -> RestakingCoreResult<()> {
+        let total_stake = self
+            .stake()
+            .checked_add(stake)
+            .ok_or(RestakingCoreError::EpochStakeOverflow)?;
+        let vault_count = self
+            .vault_count()
+            .checked_add(1)
+            .ok_or(RestakingCoreError::EpochStakeOverflow)?;
+        self.stake = PodU64::new(total_stake);
+        self.vault_count = PodU64::new(vault_count);
+        Ok(())
+    }
+
+    pub fn seeds(operator: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"operator_epoch_stake".to_vec(),
+            operator.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(operator, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::OperatorEpochStakeEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::OperatorEpochStakeInvalidOwner);
+        }
+
+        let operator_epoch_stake = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorEpochStakeInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorEpochStakeInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(operator, epoch);
+        seeds.push(vec![operator_epoch_stake.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::OperatorEpochStakeInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::OperatorEpochStakeInvalidPda);
+        }
+
+        Ok(operator_epoch_stake)
+    }
+}
+
+pub struct SanitizedOperatorEpochStake<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedOperatorEpochStake<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::OperatorEpochStakeNotWritable);
+        }
+
+        OperatorEpochStake::deserialize_checked(program_id, account, operator, epoch)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn operator_epoch_stake(&self) -> Ref<'_, OperatorEpochStake> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..OperatorEpochStake::LEN])
+        })
+    }
+
+    pub fn operator_epoch_stake_mut(&mut self) -> RefMut<'_, OperatorEpochStake> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..OperatorEpochStake::LEN])
+        })
+    }
+}
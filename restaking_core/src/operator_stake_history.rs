@@ -0,0 +1,214 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, hash::hashv, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The number of epochs an epoch stake snapshot is kept around before it can be archived
+pub const SNAPSHOT_RETENTION_EPOCHS: u64 = 4;
+
+/// A hash chain over the epoch stake snapshots of an operator that were archived and closed.
+///
+/// Each archived snapshot is hashed into a leaf with [`OperatorStakeHistory::leaf`] and chained
+/// onto the commitment with [`OperatorStakeHistory::chain`]. The leaves are emitted when they're
+/// archived, so a historical value can be proven off-chain by replaying them up to the
+/// commitment.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct OperatorStakeHistory {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The operator the snapshots belong to
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The commitment over every snapshot archived so far
+    #[serde(serialize_with = "serde_fields::hash")]
+    commitment: [u8; 32],
+
+    /// The number of snapshots archived so far
+    archived_count: PodU64,
+
+    /// The latest epoch of an archived snapshot
+    last_archived_epoch: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 63],
+
+    bump: u8,
+}
+
+impl ZeroCopy for OperatorStakeHistory {
+    const DISCRIMINATOR: u8 = AccountType::OperatorStakeHistory as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl OperatorStakeHistory {
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 1;
+
+    pub const fn new(operator: Pubkey, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::OperatorStakeHistory as u8,
+            operator,
+            commitment: [0; 32],
+            archived_count: PodU64::new(0),
+            last_archived_epoch: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 63],
+            bump,
+        }
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn commitment(&self) -> [u8; 32] {
+        self.commitment
+    }
+
+    pub const fn archived_count(&self) -> u64 {
+        self.archived_count.get()
+    }
+
+    pub const fn last_archived_epoch(&self) -> u64 {
+        self.last_archived_epoch.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// The leaf of a snapshot, hashed over the data of the snapshot account
+    pub fn leaf(snapshot_data: &[u8]) -> [u8; 32] {
+        hashv(&[snapshot_data]).to_bytes()
+    }
+
+    /// The commitment after chaining the leaf onto the previous commitment
+    pub fn chain(commitment: &[u8; 32], leaf: &[u8; 32]) -> [u8; 32] {
+        hashv(&[commitment, leaf]).to_bytes()
+    }
+
+    /// Chains the leaf of a snapshot of the epoch onto the commitment
+    pub fn archive(&mut self, leaf: &[u8; 32], epoch: u64) -> RestakingCoreResult<()> {
+        let archived_count = self
+            .archived_count()
+            .checked_add(1)
+            .ok_or(RestakingCoreError::OperatorStakeHistoryOverflow)?;
+        self.commitment = Self::chain(&self.commitment, leaf);
+        self.archived_count = PodU64::new(archived_count);
+        self.last_archived_epoch = PodU64::new(self.last_archived_epoch().max(epoch));
+        Ok(())
+    }
+
+    pub fn seeds(operator: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"operator_stake_history".to_vec(),
+            operator.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        operator: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(operator);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        operator: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::OperatorStakeHistoryEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::OperatorStakeHistoryInvalidOwner);
+        }
+
+        let history = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorStakeHistoryInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorStakeHistoryInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(operator);
+        seeds.push(vec![history.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::OperatorStakeHistoryInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::OperatorStakeHistoryInvalidPda);
+        }
+
+        Ok(history)
+    }
+}
+
+pub struct SanitizedOperatorStakeHistory<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedOperatorStakeHistory<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        operator: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::OperatorStakeHistoryNotWritable);
+        }
+
+        OperatorStakeHistory::deserialize_checked(program_id, account, operator)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn operator_stake_history(&self) -> Ref<'_, OperatorStakeHistory> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..OperatorStakeHistory::LEN])
+        })
+    }
+
+    pub fn operator_stake_history_mut(&mut self) -> RefMut<'_, OperatorStakeHistory> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..OperatorStakeHistory::LEN])
+        })
+    }
+}
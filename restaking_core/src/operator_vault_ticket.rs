@@ -1,37 +1,73 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    slot_toggled_field::{SlotToggle, SlotToggleState},
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{RestakingCoreError, RestakingCoreResult},
     AccountType,
 };
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
 #[repr(C)]
 pub struct OperatorVaultTicket {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The operator account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator: Pubkey,
 
     /// The vault account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
     /// The index
-    index: u64,
+    index: PodU64,
 
     /// The slot toggle
     state: SlotToggle,
 
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 127],
 
     bump: u8,
 }
 
+impl ZeroCopy for OperatorVaultTicket {
+    const DISCRIMINATOR: u8 = AccountType::OperatorVaultTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl OperatorVaultTicket {
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 1;
+
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 33;
+
     pub const fn new(
         operator: Pubkey,
         vault: Pubkey,
@@ -40,12 +76,13 @@ impl OperatorVaultTicket {
         bump: u8,
     ) -> Self {
         Self {
-            account_type: AccountType::OperatorVaultTicket,
+            discriminator: AccountType::OperatorVaultTicket as u8,
             operator,
             vault,
-            index,
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            version: Self::VERSION,
+            reserved: [0; 127],
             bump,
         }
     }
@@ -78,8 +115,19 @@ impl OperatorVaultTicket {
         }
     }
 
+    /// The ticket shall have warmed up, see [`crate::config::Config::ticket_epoch_length`]
+    pub const fn check_warmed_up(&self, slot: u64, epoch_length: u64) -> RestakingCoreResult<()> {
+        match self.state.state(slot, epoch_length) {
+            SlotToggleState::Active => Ok(()),
+            SlotToggleState::WarmingUp => Err(RestakingCoreError::OperatorVaultTicketWarmingUp),
+            SlotToggleState::CoolingDown | SlotToggleState::Inactive => {
+                Err(RestakingCoreError::OperatorVaultTicketInactive)
+            }
+        }
+    }
+
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn bump(&self) -> u8 {
@@ -119,11 +167,12 @@ impl OperatorVaultTicket {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let avs_vault_ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| RestakingCoreError::OperatorVaultTicketInvalidData(e.to_string()))?;
-        if avs_vault_ticket.account_type != AccountType::OperatorVaultTicket {
-            return Err(RestakingCoreError::OperatorVaultTicketInvalidAccountType);
-        }
+        let avs_vault_ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::OperatorVaultTicketInvalidAccountType
+            }
+            e => RestakingCoreError::OperatorVaultTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(operator, vault);
         seeds.push(vec![avs_vault_ticket.bump]);
@@ -140,7 +189,6 @@ impl OperatorVaultTicket {
 
 pub struct SanitizedOperatorVaultTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    operator_vault_ticket: Box<OperatorVaultTicket>,
 }
 
 impl<'a, 'info> SanitizedOperatorVaultTicket<'a, 'info> {
@@ -155,33 +203,24 @@ impl<'a, 'info> SanitizedOperatorVaultTicket<'a, 'info> {
             return Err(RestakingCoreError::OperatorVaultTicketNotWritable);
         }
 
-        let operator_vault_ticket = Box::new(OperatorVaultTicket::deserialize_checked(
-            program_id, account, operator, avs,
-        )?);
+        OperatorVaultTicket::deserialize_checked(program_id, account, operator, avs)?;
 
-        Ok(Self {
-            account,
-            operator_vault_ticket,
-        })
+        Ok(Self { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn operator_vault_ticket(&self) -> &OperatorVaultTicket {
-        &self.operator_vault_ticket
-    }
-
-    pub fn operator_vault_ticket_mut(&mut self) -> &mut OperatorVaultTicket {
-        &mut self.operator_vault_ticket
+    pub fn operator_vault_ticket(&self) -> Ref<'_, OperatorVaultTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..OperatorVaultTicket::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut &mut self.account.data.borrow_mut()[..],
-            &self.operator_vault_ticket,
-        )?;
-        Ok(())
+    pub fn operator_vault_ticket_mut(&mut self) -> RefMut<'_, OperatorVaultTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..OperatorVaultTicket::LEN])
+        })
     }
 }
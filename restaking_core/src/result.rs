@@ -1,3 +1,4 @@
+use jito_restaking_sanitization::error_code::ErrorSource;
 use solana_program::program_error::ProgramError;
 
 pub type RestakingCoreResult<T> = Result<T, RestakingCoreError>;
@@ -93,10 +94,624 @@ pub enum RestakingCoreError {
     OperatorInvalidAccountType,
     OperatorInvalidPda,
     OperatorNotWritable,
+    AvsVaultTicketInvalidServiceFee,
+    OperatorInvalidVoter,
+    OperatorAvsHeartbeatEmpty,
+    OperatorAvsHeartbeatInvalidOwner,
+    OperatorAvsHeartbeatInvalidData(String),
+    OperatorAvsHeartbeatInvalidAccountType,
+    OperatorAvsHeartbeatInvalidPda,
+    OperatorAvsHeartbeatNotWritable,
+    OperatorAvsHeartbeatInvalidSlot,
+    AvsHeartbeatEjectionDisabled,
+    AvsOperatorHeartbeatNotStale,
+    AvsOperatorHeartbeatStale,
+    AvsOperatorTicketNotEjected,
+    AvsOperatorCountMismatch,
+    AvsVaultCountMismatch,
+    AvsSlasherCountMismatch,
+    OperatorAvsCountMismatch,
+    OperatorVaultCountMismatch,
+    ConfigInvalidAdmin,
+    ConfigSlasherProgramInvalid,
+    ConfigSlasherProgramAlreadyApproved,
+    ConfigSlasherProgramsFull,
+    ConfigSlasherProgramNotApproved,
+    AvsInvalidRelayer,
+    AvsInvalidNonce,
+    OperatorInvalidRelayer,
+    OperatorInvalidNonce,
+    AvsVaultTicketMaxSlashersReached,
+    AvsOperatorTicketWarmingUp,
+    OperatorAvsTicketWarmingUp,
+    AvsVaultTicketWarmingUp,
+    OperatorVaultTicketWarmingUp,
+    AvsInvalidMetadataAdmin,
+    OperatorInvalidWithdrawAdmin,
+    OperatorInvalidMetadataAdmin,
+    ConfigInvalidPendingAdmin,
+    AvsInvalidPendingAdmin,
+    OperatorInvalidPendingAdmin,
+    AvsInvalidMaxHeartbeatAge,
+    AvsRewardBudgetEmpty,
+    AvsRewardBudgetInvalidOwner,
+    AvsRewardBudgetInvalidData(String),
+    AvsRewardBudgetInvalidAccountType,
+    AvsRewardBudgetInvalidPda,
+    AvsRewardBudgetNotWritable,
+    AvsRewardBudgetInvalidSchedule,
+    AvsRewardBudgetAlreadyDistributed,
+    AvsRewardBudgetOverflow,
+    AvsInvalidMultisig,
+    AvsMultisigThresholdNotMet,
+    MetadataEmpty,
+    MetadataInvalidOwner,
+    MetadataInvalidData(String),
+    MetadataInvalidAccountType,
+    MetadataInvalidPda,
+    MetadataNotWritable,
+    MetadataFieldTooLong,
+    AccountMigrationNotFound,
+    OperatorAvsCapacityEmpty,
+    OperatorAvsCapacityInvalidOwner,
+    OperatorAvsCapacityInvalidData(String),
+    OperatorAvsCapacityInvalidAccountType,
+    OperatorAvsCapacityInvalidPda,
+    OperatorAvsCapacityNotWritable,
+    ConfigInvalidMaxOperatorFee,
+    OperatorFeeTooHigh,
+    OperatorFeeCooldown,
+    EpochRewardRouterEmpty,
+    EpochRewardRouterInvalidOwner,
+    EpochRewardRouterInvalidData(String),
+    EpochRewardRouterInvalidAccountType,
+    EpochRewardRouterInvalidPda,
+    EpochRewardRouterNotWritable,
+    EpochRewardSnapshotEmpty,
+    EpochRewardSnapshotInvalidOwner,
+    EpochRewardSnapshotInvalidData(String),
+    EpochRewardSnapshotInvalidAccountType,
+    EpochRewardSnapshotInvalidPda,
+    EpochRewardSnapshotNotWritable,
+    EpochRewardsClosed,
+    EpochRewardsNotStarted,
+    EpochRewardsNotEnded,
+    EpochRewardSnapshotAlreadyDistributed,
+    EpochRewardsPendingDistribution,
+    EpochRewardOverflow,
+    OperatorEpochStakeEmpty,
+    OperatorEpochStakeInvalidOwner,
+    OperatorEpochStakeInvalidData(String),
+    OperatorEpochStakeInvalidAccountType,
+    OperatorEpochStakeInvalidPda,
+    OperatorEpochStakeNotWritable,
+    VaultOperatorEpochStakeEmpty,
+    VaultOperatorEpochStakeInvalidOwner,
+    VaultOperatorEpochStakeInvalidData(String),
+    VaultOperatorEpochStakeInvalidAccountType,
+    VaultOperatorEpochStakeInvalidPda,
+    VaultOperatorEpochStakeNotWritable,
+    EpochStakeOverflow,
+    OperatorAvsScoreEmpty,
+    OperatorAvsScoreInvalidOwner,
+    OperatorAvsScoreInvalidData(String),
+    OperatorAvsScoreInvalidAccountType,
+    OperatorAvsScoreInvalidPda,
+    OperatorAvsScoreNotWritable,
+    OperatorAvsScoreInvalidScore,
+    OperatorAvsScoreInvalidSlot,
+    AvsInvalidScorer,
+    OperatorStakeHistoryEmpty,
+    OperatorStakeHistoryInvalidOwner,
+    OperatorStakeHistoryInvalidData(String),
+    OperatorStakeHistoryInvalidAccountType,
+    OperatorStakeHistoryInvalidPda,
+    OperatorStakeHistoryNotWritable,
+    OperatorStakeHistoryOverflow,
+    AvsInvalidSlashVetoAdmin,
+    OperatorVoterHistoryUnavailable,
+    AccountVersionUnsupported,
+    OperatorStakeInsufficient,
+    ConfigInvalidParameter,
+    AvsMaxOperatorsReached,
+    OperatorMaxVaultsReached,
+    RewardDistributionEmpty,
+    RewardDistributionInvalidOwner,
+    RewardDistributionInvalidData(String),
+    RewardDistributionInvalidAccountType,
+    RewardDistributionInvalidPda,
+    RewardDistributionNotWritable,
+    RewardDistributionInvalidProof,
+    RewardDistributionMaxTotalClaimExceeded,
+    RewardDistributionMaxNumNodesExceeded,
+    RewardDistributionInvalidClaims,
+    RewardClaimStatusEmpty,
+    RewardClaimStatusInvalidOwner,
+    RewardClaimStatusInvalidData(String),
+    RewardClaimStatusInvalidAccountType,
+    RewardClaimStatusInvalidPda,
+    AvsOperatorTicketNotCoolingDown,
+    OperatorAvsTicketNotCoolingDown,
+    ConfigInvalidTicketEpochLength,
 }
 
+impl RestakingCoreError {
+    /// The offset of the error within the restaking program range in
+    /// [`jito_restaking_sanitization::error_code`]. Offsets follow the declaration order and shall
+    /// never change, so new errors are appended.
+    pub const fn offset(&self) -> u32 {
+        match self {
+            Self::AvsInvalidAdmin => 0,
+            Self::VaultFailedToActivate => 1,
+            Self::VaultFailedToDeactivate => 2,
+            Self::VaultNotFound => 3,
+            Self::OperatorInvalidAdmin => 4,
+            Self::VaultNotActive => 5,
+            Self::AvsInvalidOperatorAdmin => 6,
+            Self::AvsInvalidVaultAdmin => 7,
+            Self::OperatorAlreadyAdded => 8,
+            Self::AvsNotActive => 9,
+            Self::AvsNotFound => 10,
+            Self::AvsInvalidSlasherAdmin => 11,
+            Self::VaultNotActiveOrCoolingDown => 12,
+            Self::VaultSlasherAlreadyExists => 13,
+            Self::VaultSlasherNotActive => 14,
+            Self::VaultSlasherNotFound => 15,
+            Self::OperatorAlreadyRemoved => 16,
+            Self::OperatorNotFound => 17,
+            Self::AvsInvalidWithdrawAdmin => 18,
+            Self::AvsFailedToActivate => 19,
+            Self::AvsFailedToDeactivate => 20,
+            Self::SlasherNotActive => 21,
+            Self::OperatorNotActive => 22,
+            Self::AvsOperatorTicketEmpty => 23,
+            Self::AvsOperatorTicketInvalidOwner => 24,
+            Self::AvsOperatorTicketInvalidAccountType => 25,
+            Self::AvsOperatorTicketInvalidPda => 26,
+            Self::AvsSlasherTicketEmpty => 27,
+            Self::AvsSlasherTicketInvalidOwner => 28,
+            Self::AvsSlasherTicketInvalidAccountType => 29,
+            Self::AvsSlasherTicketInvalidPda => 30,
+            Self::AvsVaultTicketEmpty => 31,
+            Self::AvsVaultTicketInvalidOwner => 32,
+            Self::AvsVaultTicketInvalidAccountType => 33,
+            Self::AvsVaultTicketInvalidPda => 34,
+            Self::OperatorAvsTicketEmpty => 35,
+            Self::OperatorAvsTicketInvalidOwner => 36,
+            Self::OperatorAvsTicketInvalidAccountType => 37,
+            Self::OperatorAvsTicketInvalidPda => 38,
+            Self::OperatorVaultTicketEmpty => 39,
+            Self::OperatorVaultTicketInvalidOwner => 40,
+            Self::OperatorVaultTicketInvalidAccountType => 41,
+            Self::OperatorVaultTicketInvalidPda => 42,
+            Self::OperatorVaultTicketInvalidData(_) => 43,
+            Self::AvsOperatorTicketInvalidData(_) => 44,
+            Self::AvsSlasherTicketInvalidData(_) => 45,
+            Self::AvsVaultTicketInvalidData(_) => 46,
+            Self::OperatorAvsTicketInvalidData(_) => 47,
+            Self::AvsOperatorTicketExpectedWritable => 48,
+            Self::AvsSlasherTicketNotWritable => 49,
+            Self::AvsVaultTicketNotWritable => 50,
+            Self::OperatorAvsTicketNotWritable => 51,
+            Self::OperatorVaultTicketNotWritable => 52,
+            Self::OperatorCountOverflow => 53,
+            Self::AvsOperatorCountOverflow => 54,
+            Self::OperatorAvsTicketNotActive => 55,
+            Self::AvsVaultCountOverflow => 56,
+            Self::AvsVaultTicketInactive => 57,
+            Self::AvsSlasherCountOverflow => 58,
+            Self::AvsVaultSlasherTicketInactive => 59,
+            Self::AvsOperatorTicketInvalidState => 60,
+            Self::AvsOperatorTicketInactive => 61,
+            Self::OperatorInvalidAvsAdmin => 62,
+            Self::OperatorInvalidVaultAdmin => 63,
+            Self::OperatorAvsCountOverflow => 64,
+            Self::OperatorVaultCountOverflow => 65,
+            Self::OperatorVaultTicketAlreadyDeactivated => 66,
+            Self::AvsEmpty => 67,
+            Self::AvsInvalidOwner => 68,
+            Self::AvsInvalidData(_) => 69,
+            Self::AvsInvalidAccountType => 70,
+            Self::AvsInvalidPda => 71,
+            Self::AvsNotWritable => 72,
+            Self::OperatorVaultTicketInactive => 73,
+            Self::OperatorAvsTicketAlreadyInactive => 74,
+            Self::AvsOverflow => 75,
+            Self::OperatorOverflow => 76,
+            Self::ConfigNotWritable => 77,
+            Self::ConfigEmpty => 78,
+            Self::ConfigInvalidOwner => 79,
+            Self::ConfigInvalidData(_) => 80,
+            Self::ConfigInvalidAccountType => 81,
+            Self::ConfigInvalidPda => 82,
+            Self::OperatorDataEmpty => 83,
+            Self::OperatorInvalidOwner => 84,
+            Self::OperatorInvalidData(_) => 85,
+            Self::OperatorInvalidAccountType => 86,
+            Self::OperatorInvalidPda => 87,
+            Self::OperatorNotWritable => 88,
+            Self::AvsVaultTicketInvalidServiceFee => 89,
+            Self::OperatorInvalidVoter => 90,
+            Self::OperatorAvsHeartbeatEmpty => 91,
+            Self::OperatorAvsHeartbeatInvalidOwner => 92,
+            Self::OperatorAvsHeartbeatInvalidData(_) => 93,
+            Self::OperatorAvsHeartbeatInvalidAccountType => 94,
+            Self::OperatorAvsHeartbeatInvalidPda => 95,
+            Self::OperatorAvsHeartbeatNotWritable => 96,
+            Self::OperatorAvsHeartbeatInvalidSlot => 97,
+            Self::AvsHeartbeatEjectionDisabled => 98,
+            Self::AvsOperatorHeartbeatNotStale => 99,
+            Self::AvsOperatorHeartbeatStale => 100,
+            Self::AvsOperatorTicketNotEjected => 101,
+            Self::AvsOperatorCountMismatch => 102,
+            Self::AvsVaultCountMismatch => 103,
+            Self::AvsSlasherCountMismatch => 104,
+            Self::OperatorAvsCountMismatch => 105,
+            Self::OperatorVaultCountMismatch => 106,
+            Self::ConfigInvalidAdmin => 107,
+            Self::ConfigSlasherProgramInvalid => 108,
+            Self::ConfigSlasherProgramAlreadyApproved => 109,
+            Self::ConfigSlasherProgramsFull => 110,
+            Self::ConfigSlasherProgramNotApproved => 111,
+            Self::AvsInvalidRelayer => 112,
+            Self::AvsInvalidNonce => 113,
+            Self::OperatorInvalidRelayer => 114,
+            Self::OperatorInvalidNonce => 115,
+            Self::AvsVaultTicketMaxSlashersReached => 116,
+            Self::AvsOperatorTicketWarmingUp => 117,
+            Self::OperatorAvsTicketWarmingUp => 118,
+            Self::AvsVaultTicketWarmingUp => 119,
+            Self::OperatorVaultTicketWarmingUp => 120,
+            Self::AvsInvalidMetadataAdmin => 121,
+            Self::OperatorInvalidWithdrawAdmin => 122,
+            Self::OperatorInvalidMetadataAdmin => 123,
+            Self::ConfigInvalidPendingAdmin => 124,
+            Self::AvsInvalidPendingAdmin => 125,
+            Self::OperatorInvalidPendingAdmin => 126,
+            Self::AvsInvalidMaxHeartbeatAge => 127,
+            Self::AvsRewardBudgetEmpty => 128,
+            Self::AvsRewardBudgetInvalidOwner => 129,
+            Self::AvsRewardBudgetInvalidData(_) => 130,
+            Self::AvsRewardBudgetInvalidAccountType => 131,
+            Self::AvsRewardBudgetInvalidPda => 132,
+            Self::AvsRewardBudgetNotWritable => 133,
+            Self::AvsRewardBudgetInvalidSchedule => 134,
+            Self::AvsRewardBudgetAlreadyDistributed => 135,
+            Self::AvsRewardBudgetOverflow => 136,
+            Self::AvsInvalidMultisig => 137,
+            Self::AvsMultisigThresholdNotMet => 138,
+            Self::MetadataEmpty => 139,
+            Self::MetadataInvalidOwner => 140,
+            Self::MetadataInvalidData(_) => 141,
+            Self::MetadataInvalidAccountType => 142,
+            Self::MetadataInvalidPda => 143,
+            Self::MetadataNotWritable => 144,
+            Self::MetadataFieldTooLong => 145,
+            Self::AccountMigrationNotFound => 146,
+            Self::OperatorAvsCapacityEmpty => 147,
+            Self::OperatorAvsCapacityInvalidOwner => 148,
+            Self::OperatorAvsCapacityInvalidData(_) => 149,
+            Self::OperatorAvsCapacityInvalidAccountType => 150,
+            Self::OperatorAvsCapacityInvalidPda => 151,
+            Self::OperatorAvsCapacityNotWritable => 152,
+            Self::ConfigInvalidMaxOperatorFee => 153,
+            Self::OperatorFeeTooHigh => 154,
+            Self::OperatorFeeCooldown => 155,
+            Self::EpochRewardRouterEmpty => 156,
+            Self::EpochRewardRouterInvalidOwner => 157,
+            Self::EpochRewardRouterInvalidData(_) => 158,
+            Self::EpochRewardRouterInvalidAccountType => 159,
+            Self::EpochRewardRouterInvalidPda => 160,
+            Self::EpochRewardRouterNotWritable => 161,
+            Self::EpochRewardSnapshotEmpty => 162,
+            Self::EpochRewardSnapshotInvalidOwner => 163,
+            Self::EpochRewardSnapshotInvalidData(_) => 164,
+            Self::EpochRewardSnapshotInvalidAccountType => 165,
+            Self::EpochRewardSnapshotInvalidPda => 166,
+            Self::EpochRewardSnapshotNotWritable => 167,
+            Self::EpochRewardsClosed => 168,
+            Self::EpochRewardsNotStarted => 169,
+            Self::EpochRewardsNotEnded => 170,
+            Self::EpochRewardSnapshotAlreadyDistributed => 171,
+            Self::EpochRewardsPendingDistribution => 172,
+            Self::EpochRewardOverflow => 173,
+            Self::OperatorEpochStakeEmpty => 174,
+            Self::OperatorEpochStakeInvalidOwner => 175,
+            Self::OperatorEpochStakeInvalidData(_) => 176,
+            Self::OperatorEpochStakeInvalidAccountType => 177,
+            Self::OperatorEpochStakeInvalidPda => 178,
+            Self::OperatorEpochStakeNotWritable => 179,
+            Self::VaultOperatorEpochStakeEmpty => 180,
+            Self::VaultOperatorEpochStakeInvalidOwner => 181,
+            Self::VaultOperatorEpochStakeInvalidData(_) => 182,
+            Self::VaultOperatorEpochStakeInvalidAccountType => 183,
+            Self::VaultOperatorEpochStakeInvalidPda => 184,
+            Self::VaultOperatorEpochStakeNotWritable => 185,
+            Self::EpochStakeOverflow => 186,
+            Self::OperatorAvsScoreEmpty => 187,
+            Self::OperatorAvsScoreInvalidOwner => 188,
+            Self::OperatorAvsScoreInvalidData(_) => 189,
+            Self::OperatorAvsScoreInvalidAccountType => 190,
+            Self::OperatorAvsScoreInvalidPda => 191,
+            Self::OperatorAvsScoreNotWritable => 192,
+            Self::OperatorAvsScoreInvalidScore => 193,
+            Self::OperatorAvsScoreInvalidSlot => 194,
+            Self::AvsInvalidScorer => 195,
+            Self::OperatorStakeHistoryEmpty => 196,
+            Self::OperatorStakeHistoryInvalidOwner => 197,
+            Self::OperatorStakeHistoryInvalidData(_) => 198,
+            Self::OperatorStakeHistoryInvalidAccountType => 199,
+            Self::OperatorStakeHistoryInvalidPda => 200,
+            Self::OperatorStakeHistoryNotWritable => 201,
+            Self::OperatorStakeHistoryOverflow => 202,
+            Self::AvsInvalidSlashVetoAdmin => 203,
+            Self::OperatorVoterHistoryUnavailable => 204,
+            Self::AccountVersionUnsupported => 205,
+            Self::OperatorStakeInsufficient => 206,
+            Self::ConfigInvalidParameter => 207,
+            Self::AvsMaxOperatorsReached => 208,
+            Self::OperatorMaxVaultsReached => 209,
+            Self::RewardDistributionEmpty => 210,
+            Self::RewardDistributionInvalidOwner => 211,
+            Self::RewardDistributionInvalidData(_) => 212,
+            Self::RewardDistributionInvalidAccountType => 213,
+            Self::RewardDistributionInvalidPda => 214,
+            Self::RewardDistributionNotWritable => 215,
+            Self::RewardDistributionInvalidProof => 216,
+            Self::RewardDistributionMaxTotalClaimExceeded => 217,
+            Self::RewardDistributionMaxNumNodesExceeded => 218,
+            Self::RewardDistributionInvalidClaims => 219,
+            Self::RewardClaimStatusEmpty => 220,
+            Self::RewardClaimStatusInvalidOwner => 221,
+            Self::RewardClaimStatusInvalidData(_) => 222,
+            Self::RewardClaimStatusInvalidAccountType => 223,
+            Self::RewardClaimStatusInvalidPda => 224,
+            Self::AvsOperatorTicketNotCoolingDown => 225,
+            Self::OperatorAvsTicketNotCoolingDown => 226,
+            Self::ConfigInvalidTicketEpochLength => 227,
+        }
+    }
+
+    /// Returns the name of the error at the offset, for decoding error codes off-chain
+    pub fn name(offset: u32) -> Option<&'static str> {
+        ERROR_NAMES.get(offset as usize).copied()
+    }
+}
+
+/// Error names indexed by [`RestakingCoreError::offset`]
+const ERROR_NAMES: [&str; 228] = [
+    "AvsInvalidAdmin",
+    "VaultFailedToActivate",
+    "VaultFailedToDeactivate",
+    "VaultNotFound",
+    "OperatorInvalidAdmin",
+    "VaultNotActive",
+    "AvsInvalidOperatorAdmin",
+    "AvsInvalidVaultAdmin",
+    "OperatorAlreadyAdded",
+    "AvsNotActive",
+    "AvsNotFound",
+    "AvsInvalidSlasherAdmin",
+    "VaultNotActiveOrCoolingDown",
+    "VaultSlasherAlreadyExists",
+    "VaultSlasherNotActive",
+    "VaultSlasherNotFound",
+    "OperatorAlreadyRemoved",
+    "OperatorNotFound",
+    "AvsInvalidWithdrawAdmin",
+    "AvsFailedToActivate",
+    "AvsFailedToDeactivate",
+    "SlasherNotActive",
+    "OperatorNotActive",
+    "AvsOperatorTicketEmpty",
+    "AvsOperatorTicketInvalidOwner",
+    "AvsOperatorTicketInvalidAccountType",
+    "AvsOperatorTicketInvalidPda",
+    "AvsSlasherTicketEmpty",
+    "AvsSlasherTicketInvalidOwner",
+    "AvsSlasherTicketInvalidAccountType",
+    "AvsSlasherTicketInvalidPda",
+    "AvsVaultTicketEmpty",
+    "AvsVaultTicketInvalidOwner",
+    "AvsVaultTicketInvalidAccountType",
+    "AvsVaultTicketInvalidPda",
+    "OperatorAvsTicketEmpty",
+    "OperatorAvsTicketInvalidOwner",
+    "OperatorAvsTicketInvalidAccountType",
+    "OperatorAvsTicketInvalidPda",
+    "OperatorVaultTicketEmpty",
+    "OperatorVaultTicketInvalidOwner",
+    "OperatorVaultTicketInvalidAccountType",
+    "OperatorVaultTicketInvalidPda",
+    "OperatorVaultTicketInvalidData",
+    "AvsOperatorTicketInvalidData",
+    "AvsSlasherTicketInvalidData",
+    "AvsVaultTicketInvalidData",
+    "OperatorAvsTicketInvalidData",
+    "AvsOperatorTicketExpectedWritable",
+    "AvsSlasherTicketNotWritable",
+    "AvsVaultTicketNotWritable",
+    "OperatorAvsTicketNotWritable",
+    "OperatorVaultTicketNotWritable",
+    "OperatorCountOverflow",
+    "AvsOperatorCountOverflow",
+    "OperatorAvsTicketNotActive",
+    "AvsVaultCountOverflow",
+    "AvsVaultTicketInactive",
+    "AvsSlasherCountOverflow",
+    "AvsVaultSlasherTicketInactive",
+    "AvsOperatorTicketInvalidState",
+    "AvsOperatorTicketInactive",
+    "OperatorInvalidAvsAdmin",
+    "OperatorInvalidVaultAdmin",
+    "OperatorAvsCountOverflow",
+    "OperatorVaultCountOverflow",
+    "OperatorVaultTicketAlreadyDeactivated",
+    "AvsEmpty",
+    "AvsInvalidOwner",
+    "AvsInvalidData",
+    "AvsInvalidAccountType",
+    "AvsInvalidPda",
+    "AvsNotWritable",
+    "OperatorVaultTicketInactive",
+    "OperatorAvsTicketAlreadyInactive",
+    "AvsOverflow",
+    "OperatorOverflow",
+    "ConfigNotWritable",
+    "ConfigEmpty",
+    "ConfigInvalidOwner",
+    "ConfigInvalidData",
+    "ConfigInvalidAccountType",
+    "ConfigInvalidPda",
+    "OperatorDataEmpty",
+    "OperatorInvalidOwner",
+    "OperatorInvalidData",
+    "OperatorInvalidAccountType",
+    "OperatorInvalidPda",
+    "OperatorNotWritable",
+    "AvsVaultTicketInvalidServiceFee",
+    "OperatorInvalidVoter",
+    "OperatorAvsHeartbeatEmpty",
+    "OperatorAvsHeartbeatInvalidOwner",
+    "OperatorAvsHeartbeatInvalidData",
+    "OperatorAvsHeartbeatInvalidAccountType",
+    "OperatorAvsHeartbeatInvalidPda",
+    "OperatorAvsHeartbeatNotWritable",
+    "OperatorAvsHeartbeatInvalidSlot",
+    "AvsHeartbeatEjectionDisabled",
+    "AvsOperatorHeartbeatNotStale",
+    "AvsOperatorHeartbeatStale",
+    "AvsOperatorTicketNotEjected",
+    "AvsOperatorCountMismatch",
+    "AvsVaultCountMismatch",
+    "AvsSlasherCountMismatch",
+    "OperatorAvsCountMismatch",
+    "OperatorVaultCountMismatch",
+    "ConfigInvalidAdmin",
+    "ConfigSlasherProgramInvalid",
+    "ConfigSlasherProgramAlreadyApproved",
+    "ConfigSlasherProgramsFull",
+    "ConfigSlasherProgramNotApproved",
+    "AvsInvalidRelayer",
+    "AvsInvalidNonce",
+    "OperatorInvalidRelayer",
+    "OperatorInvalidNonce",
+    "AvsVaultTicketMaxSlashersReached",
+    "AvsOperatorTicketWarmingUp",
+    "OperatorAvsTicketWarmingUp",
+    "AvsVaultTicketWarmingUp",
+    "OperatorVaultTicketWarmingUp",
+    "AvsInvalidMetadataAdmin",
+    "OperatorInvalidWithdrawAdmin",
+    "OperatorInvalidMetadataAdmin",
+    "ConfigInvalidPendingAdmin",
+    "AvsInvalidPendingAdmin",
+    "OperatorInvalidPendingAdmin",
+    "AvsInvalidMaxHeartbeatAge",
+    "AvsRewardBudgetEmpty",
+    "AvsRewardBudgetInvalidOwner",
+    "AvsRewardBudgetInvalidData",
+    "AvsRewardBudgetInvalidAccountType",
+    "AvsRewardBudgetInvalidPda",
+    "AvsRewardBudgetNotWritable",
+    "AvsRewardBudgetInvalidSchedule",
+    "AvsRewardBudgetAlreadyDistributed",
+    "AvsRewardBudgetOverflow",
+    "AvsInvalidMultisig",
+    "AvsMultisigThresholdNotMet",
+    "MetadataEmpty",
+    "MetadataInvalidOwner",
+    "MetadataInvalidData",
+    "MetadataInvalidAccountType",
+    "MetadataInvalidPda",
+    "MetadataNotWritable",
+    "MetadataFieldTooLong",
+    "AccountMigrationNotFound",
+    "OperatorAvsCapacityEmpty",
+    "OperatorAvsCapacityInvalidOwner",
+    "OperatorAvsCapacityInvalidData",
+    "OperatorAvsCapacityInvalidAccountType",
+    "OperatorAvsCapacityInvalidPda",
+    "OperatorAvsCapacityNotWritable",
+    "ConfigInvalidMaxOperatorFee",
+    "OperatorFeeTooHigh",
+    "OperatorFeeCooldown",
+    "EpochRewardRouterEmpty",
+    "EpochRewardRouterInvalidOwner",
+    "EpochRewardRouterInvalidData",
+    "EpochRewardRouterInvalidAccountType",
+    "EpochRewardRouterInvalidPda",
+    "EpochRewardRouterNotWritable",
+    "EpochRewardSnapshotEmpty",
+    "EpochRewardSnapshotInvalidOwner",
+    "EpochRewardSnapshotInvalidData",
+    "EpochRewardSnapshotInvalidAccountType",
+    "EpochRewardSnapshotInvalidPda",
+    "EpochRewardSnapshotNotWritable",
+    "EpochRewardsClosed",
+    "EpochRewardsNotStarted",
+    "EpochRewardsNotEnded",
+    "EpochRewardSnapshotAlreadyDistributed",
+    "EpochRewardsPendingDistribution",
+    "EpochRewardOverflow",
+    "OperatorEpochStakeEmpty",
+    "OperatorEpochStakeInvalidOwner",
+    "OperatorEpochStakeInvalidData",
+    "OperatorEpochStakeInvalidAccountType",
+    "OperatorEpochStakeInvalidPda",
+    "OperatorEpochStakeNotWritable",
+    "VaultOperatorEpochStakeEmpty",
+    "VaultOperatorEpochStakeInvalidOwner",
+    "VaultOperatorEpochStakeInvalidData",
+    "VaultOperatorEpochStakeInvalidAccountType",
+    "VaultOperatorEpochStakeInvalidPda",
+    "VaultOperatorEpochStakeNotWritable",
+    "EpochStakeOverflow",
+    "OperatorAvsScoreEmpty",
+    "OperatorAvsScoreInvalidOwner",
+    "OperatorAvsScoreInvalidData",
+    "OperatorAvsScoreInvalidAccountType",
+    "OperatorAvsScoreInvalidPda",
+    "OperatorAvsScoreNotWritable",
+    "OperatorAvsScoreInvalidScore",
+    "OperatorAvsScoreInvalidSlot",
+    "AvsInvalidScorer",
+    "OperatorStakeHistoryEmpty",
+    "OperatorStakeHistoryInvalidOwner",
+    "OperatorStakeHistoryInvalidData",
+    "OperatorStakeHistoryInvalidAccountType",
+    "OperatorStakeHistoryInvalidPda",
+    "OperatorStakeHistoryNotWritable",
+    "OperatorStakeHistoryOverflow",
+    "AvsInvalidSlashVetoAdmin",
+    "OperatorVoterHistoryUnavailable",
+    "AccountVersionUnsupported",
+    "OperatorStakeInsufficient",
+    "ConfigInvalidParameter",
+    "AvsMaxOperatorsReached",
+    "OperatorMaxVaultsReached",
+    "RewardDistributionEmpty",
+    "RewardDistributionInvalidOwner",
+    "RewardDistributionInvalidData",
+    "RewardDistributionInvalidAccountType",
+    "RewardDistributionInvalidPda",
+    "RewardDistributionNotWritable",
+    "RewardDistributionInvalidProof",
+    "RewardDistributionMaxTotalClaimExceeded",
+    "RewardDistributionMaxNumNodesExceeded",
+    "RewardDistributionInvalidClaims",
+    "RewardClaimStatusEmpty",
+    "RewardClaimStatusInvalidOwner",
+    "RewardClaimStatusInvalidData",
+    "RewardClaimStatusInvalidAccountType",
+    "RewardClaimStatusInvalidPda",
+    "AvsOperatorTicketNotCoolingDown",
+    "OperatorAvsTicketNotCoolingDown",
+    "ConfigInvalidTicketEpochLength",
+];
+
 impl From<RestakingCoreError> for ProgramError {
-    fn from(_value: RestakingCoreError) -> Self {
-        Self::Custom(0)
+    fn from(value: RestakingCoreError) -> Self {
+        Self::Custom(ErrorSource::Restaking.code(value.offset()))
     }
 }
@@ -0,0 +1,152 @@
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The claim of a claimant from a [`crate::reward_distribution::RewardDistribution`]. It's
+/// created by the claim, so its existence is what keeps a claimant from claiming twice.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct RewardClaimStatus {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The distribution claimed from
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    distribution: Pubkey,
+
+    /// The claimant
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    claimant: Pubkey,
+
+    /// The number of tokens claimed
+    amount: PodU64,
+
+    /// The slot of the claim
+    slot_claimed: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 63],
+
+    bump: u8,
+}
+
+impl ZeroCopy for RewardClaimStatus {
+    const DISCRIMINATOR: u8 = AccountType::RewardClaimStatus as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl RewardClaimStatus {
+    pub const fn new(
+        distribution: Pubkey,
+        claimant: Pubkey,
+        amount: u64,
+        slot_claimed: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            discriminator: AccountType::RewardClaimStatus as u8,
+            distribution,
+            claimant,
+            amount: PodU64::new(amount),
+            slot_claimed: PodU64::new(slot_claimed),
+            version: Self::VERSION,
+            reserved: [0; 63],
+            bump,
+        }
+    }
+
+    pub const fn distribution(&self) -> Pubkey {
+        self.distribution
+    }
+
+    pub const fn claimant(&self) -> Pubkey {
+        self.claimant
+    }
+
+    pub const fn amount(&self) -> u64 {
+        self.amount.get()
+    }
+
+    pub const fn slot_claimed(&self) -> u64 {
+        self.slot_claimed.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    pub fn seeds(distribution: &Pubkey, claimant: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"reward_claim_status".to_vec(),
+            distribution.to_bytes().to_vec(),
+            claimant.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        distribution: &Pubkey,
+        claimant: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(distribution, claimant);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        distribution: &Pubkey,
+        claimant: &Pubkey,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::RewardClaimStatusEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::RewardClaimStatusInvalidOwner);
+        }
+
+        let claim_status = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::RewardClaimStatusInvalidAccountType
+            }
+            e => RestakingCoreError::RewardClaimStatusInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(distribution, claimant);
+        seeds.push(vec![claim_status.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::RewardClaimStatusInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::RewardClaimStatusInvalidPda);
+        }
+
+        Ok(claim_status)
+    }
+}
@@ -0,0 +1,366 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, hash::hashv, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// Prefixes the preimage of a leaf, so a leaf can't be passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Prefixes the preimage of an inner node
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Rewards an AVS or vault pays out to stakers for one epoch in one mint, committed to by the root
+/// of a merkle tree over `(claimant, amount)` leaves and held in the associated token account of
+/// the distribution.
+///
+/// The reward admin of the source computes the shares off-chain, posts the root and funds the
+/// token account with the total of the claims. Each claimant then proves its leaf against the
+/// root to receive its amount, once: the first claim creates a
+/// [`crate::reward_claim_status::RewardClaimStatus`] for the claimant.
+///
+/// Leaves are hashed with [`RewardDistribution::leaf`] and each level of the tree hashes the
+/// sorted pair of its children with [`RewardDistribution::node`], so a proof is the list of
+/// siblings from the leaf up to the root.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct RewardDistribution {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The AVS or vault paying the rewards
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    source: Pubkey,
+
+    /// The mint of the rewards
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    mint: Pubkey,
+
+    /// The epoch the rewards are paid for
+    epoch: PodU64,
+
+    /// The root of the merkle tree over the claims
+    #[serde(serialize_with = "serde_fields::hash")]
+    merkle_root: [u8; 32],
+
+    /// The total number of tokens the claims add up to, funded when the root was posted
+    max_total_claim: PodU64,
+
+    /// The number of leaves of the tree
+    max_num_nodes: PodU64,
+
+    /// The total number of tokens claimed
+    total_claimed: PodU64,
+
+    /// The number of claims made
+    num_claimed: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for RewardDistribution {
+    const DISCRIMINATOR: u8 = AccountType::RewardDistribution as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl RewardDistribution {
+    /// The byte offset of the source in the account data, for `memcmp` filters
+    pub const SOURCE_OFFSET: usize = 1;
+
+    pub const fn new(
+        source: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            discriminator: AccountType::RewardDistribution as u8,
+            source,
+            mint,
+            epoch: PodU64::new(epoch),
+            merkle_root,
+            max_total_claim: PodU64::new(max_total_claim),
+            max_num_nodes: PodU64::new(max_num_nodes),
+            total_claimed: PodU64::new(0),
+            num_claimed: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn source(&self) -> Pubkey {
+        self.source
+    }
+
+    pub const fn mint(&self) -> Pubkey {
+        self.mint
+    }
+
+    pub const fn epoch(&self) -> u64 {
+        self.epoch.get()
+    }
+
+    pub const fn merkle_root(&self) -> [u8; 32] {
+        self.merkle_root
+    }
+
+    pub const fn max_total_claim(&self) -> u64 {
+        self.max_total_claim.get()
+    }
+
+    pub const fn max_num_nodes(&self) -> u64 {
+        self.max_num_nodes.get()
+    }
+
+    pub const fn total_claimed(&self) -> u64 {
+        self.total_claimed.get()
+    }
+
+    pub const fn num_claimed(&self) -> u64 {
+        self.num_claimed.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// The leaf of the claim of `amount` tokens by `claimant`
+    pub fn leaf(claimant: &Pubkey, amount: u64) -> [u8; 32] {
+        hashv(&[LEAF_PREFIX, claimant.as_ref(), &amount.to_le_bytes()]).to_bytes()
+    }
+
+    /// The parent of two nodes, which doesn't depend on their order
+    pub fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[NODE_PREFIX, left, right]).to_bytes()
+    }
+
+    /// The root reached by hashing the leaf up with the siblings of the proof
+    pub fn root_of(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+        proof
+            .iter()
+            .fold(leaf, |node, sibling| Self::node(&node, sibling))
+    }
+
+    /// Checks the claim against the root and records it. A claimant claims at most once, which
+    /// the program enforces by creating its claim status.
+    pub fn claim(
+        &mut self,
+        claimant: &Pubkey,
+        amount: u64,
+        proof: &[[u8; 32]],
+    ) -> RestakingCoreResult<()> {
+        if Self::root_of(Self::leaf(claimant, amount), proof) != self.merkle_root {
+            return Err(RestakingCoreError::RewardDistributionInvalidProof);
+        }
+        let total_claimed = self
+            .total_claimed()
+            .checked_add(amount)
+            .filter(|total_claimed| *total_claimed <= self.max_total_claim())
+            .ok_or(RestakingCoreError::RewardDistributionMaxTotalClaimExceeded)?;
+        let num_claimed = self
+            .num_claimed()
+            .checked_add(1)
+            .filter(|num_claimed| *num_claimed <= self.max_num_nodes())
+            .ok_or(RestakingCoreError::RewardDistributionMaxNumNodesExceeded)?;
+        self.total_claimed = PodU64::new(total_claimed);
+        self.num_claimed = PodU64::new(num_claimed);
+        Ok(())
+    }
+
+    pub fn seeds(source: &Pubkey, mint: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"reward_distribution".to_vec(),
+            source.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        source: &Pubkey,
+        mint: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(source, mint, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::RewardDistributionEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::RewardDistributionInvalidOwner);
+        }
+
+        let distribution = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                RestakingCoreError::RewardDistributionInvalidAccountType
+            }
+            e => RestakingCoreError::RewardDistributionInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(
+            &distribution.source,
+            &distribution.mint,
+            distribution.epoch(),
+        );
+        seeds.push(vec![distribution.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::RewardDistributionInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::RewardDistributionInvalidPda);
+        }
+
+        Ok(distribution)
+    }
+}
+
+pub struct SanitizedRewardDistribution<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedRewardDistribution<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::RewardDistributionNotWritable);
+        }
+
+        RewardDistribution::deserialize_checked(program_id, account)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn reward_distribution(&self) -> Ref<'_, RewardDistribution> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..RewardDistribution::LEN])
+        })
+    }
+
+    pub fn reward_distribution_mut(&mut self) -> RefMut<'_, RewardDistribution> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..RewardDistribution::LEN])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{result::RestakingCoreError, reward_distribution::RewardDistribution};
+
+    #[test]
+    fn test_claim() {
+        let (alice, bob, carol) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let leaves = [
+            RewardDistribution::leaf(&alice, 100),
+            RewardDistribution::leaf(&bob, 200),
+            RewardDistribution::leaf(&carol, 300),
+        ];
+        let left = RewardDistribution::node(&leaves[0], &leaves[1]);
+        let root = RewardDistribution::node(&left, &leaves[2]);
+        let mut distribution = RewardDistribution::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            root,
+            600,
+            3,
+            0,
+        );
+
+        distribution
+            .claim(&bob, 200, &[leaves[0], leaves[2]])
+            .unwrap();
+        distribution.claim(&carol, 300, &[left]).unwrap();
+        assert_eq!(distribution.total_claimed(), 500);
+        assert_eq!(distribution.num_claimed(), 2);
+
+        // the amount is part of the leaf
+        assert_eq!(
+            distribution.claim(&alice, 101, &[leaves[1], leaves[2]]),
+            Err(RestakingCoreError::RewardDistributionInvalidProof)
+        );
+        // an inner node isn't a leaf
+        assert_eq!(
+            distribution.claim(&alice, 100, &[leaves[2]]),
+            Err(RestakingCoreError::RewardDistributionInvalidProof)
+        );
+        distribution
+            .claim(&alice, 100, &[leaves[1], leaves[2]])
+            .unwrap();
+        assert_eq!(distribution.total_claimed(), 600);
+    }
+
+    #[test]
+    fn test_claim_bounded_by_max_total_claim() {
+        let claimant = Pubkey::new_unique();
+        let root = RewardDistribution::leaf(&claimant, 1_000);
+        let mut distribution = RewardDistribution::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            root,
+            999,
+            1,
+            0,
+        );
+
+        assert_eq!(
+            distribution.claim(&claimant, 1_000, &[]),
+            Err(RestakingCoreError::RewardDistributionMaxTotalClaimExceeded)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::cell::Ref;
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{RestakingCoreError, RestakingCoreResult},
+    AccountType,
+};
+
+/// The active delegation of a vault to an operator during an epoch, recorded once per epoch.
+///
+/// The stake is also added to the [`crate::operator_epoch_stake::OperatorEpochStake`] of the
+/// operator, so the share of each vault in the stake of the operator is known.
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct VaultOperatorEpochStake {
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The vault delegating the stake
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    vault: Pubkey,
+
+    /// The operator the stake is delegated to
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The epoch of the snapshot
+    epoch: PodU64,
+
+    /// The active delegation of the vault to the operator
+    stake: PodU64,
+
+    /// The slot the snapshot was taken at
+    slot: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 63],
+
+    bump: u8,
+}
+
+impl ZeroCopy for VaultOperatorEpochStake {
+    const DISCRIMINATOR: u8 = AccountType::VaultOperatorEpochStake as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl VaultOperatorEpochStake {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 33;
+
+    pub const fn new(
+        vault: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+        stake: u64,
+        slot: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            discriminator: AccountType::VaultOperatorEpochStake as u8,
+            vault,
+            operator,
+            epoch: PodU64::new(epoch),
+            stake: PodU64::new(stake),
+            slot: PodU64::new(slot),
+            version: Self::VERSION,
+            reserved: [0; 63],
+            bump,
+        }
+    }
+
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn epoch(&self) -> u64 {
+        self.epoch.get()
+    }
+
+    pub const fn stake(&self) -> u64 {
+        self.stake.get()
+    }
+
+    pub const fn slot(&self) -> u64 {
+        self.slot.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    pub fn seeds(vault: &Pubkey, operator: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_operator_epoch_stake".to_vec(),
+            vault.to_bytes().to_vec(),
+            operator.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, operator, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> RestakingCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(RestakingCoreError::VaultOperatorEpochStakeEmpty);
+        }
+        if account.owner != program_id {
+            return Err(RestakingCoreError::VaultOperatorEpochStakeInvalidOwner);
+        }
+
+        let vault_operator_epoch_stake =
+            *Self::load(&account.data.borrow()).map_err(|e| match e {
+                ZeroCopyError::InvalidDiscriminator { .. } => {
+                    RestakingCoreError::VaultOperatorEpochStakeInvalidAccountType
+                }
+                e => RestakingCoreError::VaultOperatorEpochStakeInvalidData(e.to_string()),
+            })?;
+
+        let mut seeds = Self::seeds(vault, operator, epoch);
+        seeds.push(vec![vault_operator_epoch_stake.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| RestakingCoreError::VaultOperatorEpochStakeInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(RestakingCoreError::VaultOperatorEpochStakeInvalidPda);
+        }
+
+        Ok(vault_operator_epoch_stake)
+    }
+}
+
+pub struct SanitizedVaultOperatorEpochStake<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedVaultOperatorEpochStake<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> RestakingCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(RestakingCoreError::VaultOperatorEpochStakeNotWritable);
+        }
+
+        VaultOperatorEpochStake::deserialize_checked(program_id, account, vault, operator, epoch)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn vault_operator_epoch_stake(&self) -> Ref<'_, VaultOperatorEpochStake> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultOperatorEpochStake::LEN])
+        })
+    }
+}
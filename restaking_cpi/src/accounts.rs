@@ -0,0 +1,86 @@
+//! Loaders for the restaking accounts passed to another program. Each one checks the owner,
+//! discriminator and PDA of the account like the restaking program does, and returns a copy of
+//! the account so no borrow of the data outlives the call.
+
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, config::Config, operator::Operator,
+    operator_avs_ticket::OperatorAvsTicket, operator_epoch_stake::OperatorEpochStake,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+pub fn load_config(
+    restaking_program: &Pubkey,
+    config: &AccountInfo,
+) -> Result<Config, ProgramError> {
+    Ok(Config::deserialize_checked(restaking_program, config)?)
+}
+
+pub fn load_avs(restaking_program: &Pubkey, avs: &AccountInfo) -> Result<Avs, ProgramError> {
+    Ok(Avs::deserialize_checked(restaking_program, avs)?)
+}
+
+pub fn load_operator(
+    restaking_program: &Pubkey,
+    operator: &AccountInfo,
+) -> Result<Operator, ProgramError> {
+    Ok(Operator::deserialize_checked(restaking_program, operator)?)
+}
+
+pub fn load_avs_operator_ticket(
+    restaking_program: &Pubkey,
+    avs_operator_ticket: &AccountInfo,
+    avs: &Pubkey,
+    operator: &Pubkey,
+) -> Result<AvsOperatorTicket, ProgramError> {
+    Ok(AvsOperatorTicket::deserialize_checked(
+        restaking_program,
+        avs_operator_ticket,
+        avs,
+        operator,
+    )?)
+}
+
+pub fn load_operator_avs_ticket(
+    restaking_program: &Pubkey,
+    operator_avs_ticket: &AccountInfo,
+    operator: &Pubkey,
+    avs: &Pubkey,
+) -> Result<OperatorAvsTicket, ProgramError> {
+    Ok(OperatorAvsTicket::deserialize_checked(
+        restaking_program,
+        operator_avs_ticket,
+        operator,
+        avs,
+    )?)
+}
+
+pub fn load_operator_epoch_stake(
+    restaking_program: &Pubkey,
+    operator_epoch_stake: &AccountInfo,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<OperatorEpochStake, ProgramError> {
+    Ok(OperatorEpochStake::deserialize_checked(
+        restaking_program,
+        operator_epoch_stake,
+        operator,
+        epoch,
+    )?)
+}
+
+pub fn load_vault_operator_epoch_stake(
+    restaking_program: &Pubkey,
+    vault_operator_epoch_stake: &AccountInfo,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Result<VaultOperatorEpochStake, ProgramError> {
+    Ok(VaultOperatorEpochStake::deserialize_checked(
+        restaking_program,
+        vault_operator_epoch_stake,
+        vault,
+        operator,
+        epoch,
+    )?)
+}
@@ -0,0 +1,259 @@
+//! Reads restaking state from inside another program, e.g. an AVS program checking that the
+//! operator submitting a task is registered with the AVS and how much stake backs it.
+//!
+//! Everything operates on the [`AccountInfo`]s passed to the calling program and the restaking
+//! program id it trusts, so AVS programs don't need to copy the account layouts or re-implement
+//! the owner and PDA checks of the restaking program. The crate only depends on
+//! `solana-program` and [`jito_restaking_core`], and has no entrypoint.
+//!
+//! Errors are returned as [`ProgramError`]s wrapping the
+//! [`jito_restaking_core::result::RestakingCoreError`] the restaking program itself would return.
+pub mod accounts;
+pub mod pda;
+
+use jito_restaking_core::result::RestakingCoreError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::accounts::{
+    load_avs, load_avs_operator_ticket, load_operator, load_operator_avs_ticket,
+    load_operator_epoch_stake,
+};
+
+/// Asserts the operator is registered with the AVS at the slot: the AVS added the operator and the
+/// operator opted in to the AVS, and neither side removed the other since.
+pub fn assert_operator_active_for_avs(
+    restaking_program: &Pubkey,
+    avs: &AccountInfo,
+    operator: &AccountInfo,
+    avs_operator_ticket: &AccountInfo,
+    operator_avs_ticket: &AccountInfo,
+    slot: u64,
+) -> ProgramResult {
+    load_avs(restaking_program, avs)?;
+    load_operator(restaking_program, operator)?;
+    load_avs_operator_ticket(
+        restaking_program,
+        avs_operator_ticket,
+        avs.key,
+        operator.key,
+    )?
+    .check_active(slot)?;
+    load_operator_avs_ticket(
+        restaking_program,
+        operator_avs_ticket,
+        operator.key,
+        avs.key,
+    )?
+    .check_active(slot)?;
+    Ok(())
+}
+
+/// The stake delegated to the operator during the epoch, summed over the vaults snapshotted so
+/// far, see [`jito_restaking_core::operator_epoch_stake::OperatorEpochStake`]
+pub fn operator_stake(
+    restaking_program: &Pubkey,
+    operator: &Pubkey,
+    operator_epoch_stake: &AccountInfo,
+    epoch: u64,
+) -> Result<u64, ProgramError> {
+    Ok(
+        load_operator_epoch_stake(restaking_program, operator_epoch_stake, operator, epoch)?
+            .stake(),
+    )
+}
+
+/// Asserts at least `min_stake` was delegated to the operator during the epoch
+pub fn assert_operator_stake_at_least(
+    restaking_program: &Pubkey,
+    operator: &Pubkey,
+    operator_epoch_stake: &AccountInfo,
+    epoch: u64,
+    min_stake: u64,
+) -> ProgramResult {
+    if operator_stake(restaking_program, operator, operator_epoch_stake, epoch)? < min_stake {
+        return Err(RestakingCoreError::OperatorStakeInsufficient.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::zero_copy::ZeroCopy;
+    use jito_restaking_core::{
+        avs::Avs, avs_operator_ticket::AvsOperatorTicket, operator::Operator,
+        operator_avs_ticket::OperatorAvsTicket, operator_epoch_stake::OperatorEpochStake,
+        result::RestakingCoreError,
+    };
+    use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+    use crate::{assert_operator_active_for_avs, assert_operator_stake_at_least, operator_stake};
+
+    const RESTAKING_PROGRAM: Pubkey = Pubkey::new_from_array([0xaa; 32]);
+
+    /// The key, lamports, data and owner an [`AccountInfo`] borrows
+    struct TestAccount {
+        key: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        owner: Pubkey,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, data: &[u8]) -> Self {
+            Self {
+                key,
+                lamports: 0,
+                data: data.to_vec(),
+                owner: RESTAKING_PROGRAM,
+            }
+        }
+
+        fn info(&mut self) -> AccountInfo {
+            AccountInfo::new(
+                &self.key,
+                false,
+                false,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    /// An AVS and an operator that added each other at slot 10
+    struct Registration {
+        avs: TestAccount,
+        operator: TestAccount,
+        avs_operator_ticket: TestAccount,
+        operator_avs_ticket: TestAccount,
+    }
+
+    impl Registration {
+        fn new() -> Self {
+            let base = Pubkey::new_unique();
+            let admin = Pubkey::new_unique();
+            let (avs, avs_bump, _) = Avs::find_program_address(&RESTAKING_PROGRAM, &base);
+            let (operator, operator_bump, _) =
+                Operator::find_program_address(&RESTAKING_PROGRAM, &base);
+            let (avs_operator_ticket, avs_operator_ticket_bump, _) =
+                AvsOperatorTicket::find_program_address(&RESTAKING_PROGRAM, &avs, &operator);
+            let (operator_avs_ticket, operator_avs_ticket_bump, _) =
+                OperatorAvsTicket::find_program_address(&RESTAKING_PROGRAM, &operator, &avs);
+            Self {
+                avs: TestAccount::new(
+                    avs,
+                    Avs::new(base, admin, admin, admin, admin, admin, 0, avs_bump).as_bytes(),
+                ),
+                operator: TestAccount::new(
+                    operator,
+                    Operator::new(base, admin, admin, 0, operator_bump).as_bytes(),
+                ),
+                avs_operator_ticket: TestAccount::new(
+                    avs_operator_ticket,
+                    AvsOperatorTicket::new(avs, operator, 0, 10, avs_operator_ticket_bump)
+                        .as_bytes(),
+                ),
+                operator_avs_ticket: TestAccount::new(
+                    operator_avs_ticket,
+                    OperatorAvsTicket::new(operator, avs, 0, 10, operator_avs_ticket_bump)
+                        .as_bytes(),
+                ),
+            }
+        }
+
+        fn assert_active(&mut self, slot: u64) -> ProgramResult {
+            assert_operator_active_for_avs(
+                &RESTAKING_PROGRAM,
+                &self.avs.info(),
+                &self.operator.info(),
+                &self.avs_operator_ticket.info(),
+                &self.operator_avs_ticket.info(),
+                slot,
+            )
+        }
+    }
+
+    #[test]
+    fn test_assert_operator_active_for_avs() {
+        let mut registration = Registration::new();
+        assert_eq!(registration.assert_active(10), Ok(()));
+        assert_eq!(
+            registration.assert_active(9),
+            Err(RestakingCoreError::AvsOperatorTicketInactive.into())
+        );
+
+        // the operator opted out
+        let mut ticket = *OperatorAvsTicket::load(&registration.operator_avs_ticket.data).unwrap();
+        ticket.deactivate(20).unwrap();
+        registration.operator_avs_ticket.data = ticket.as_bytes().to_vec();
+        assert_eq!(
+            registration.assert_active(21),
+            Err(RestakingCoreError::OperatorAvsTicketNotActive.into())
+        );
+    }
+
+    #[test]
+    fn test_assert_operator_active_for_avs_rejects_look_alikes() {
+        let mut registration = Registration::new();
+        registration.avs.owner = Pubkey::new_unique();
+        assert_eq!(
+            registration.assert_active(10),
+            Err(RestakingCoreError::AvsInvalidOwner.into())
+        );
+
+        // the ticket of another operator of the AVS
+        let mut registration = Registration::new();
+        let other = Registration::new();
+        registration.avs_operator_ticket.key = other.avs_operator_ticket.key;
+        assert_eq!(
+            registration.assert_active(10),
+            Err(RestakingCoreError::AvsOperatorTicketInvalidPda.into())
+        );
+    }
+
+    #[test]
+    fn test_operator_stake() {
+        let operator = Pubkey::new_unique();
+        let (address, bump, _) =
+            OperatorEpochStake::find_program_address(&RESTAKING_PROGRAM, &operator, 3);
+        let mut epoch_stake = OperatorEpochStake::new(operator, 3, bump);
+        epoch_stake.record_vault_stake(1_000).unwrap();
+        epoch_stake.record_vault_stake(500).unwrap();
+        let mut account = TestAccount::new(address, epoch_stake.as_bytes());
+
+        assert_eq!(
+            operator_stake(&RESTAKING_PROGRAM, &operator, &account.info(), 3),
+            Ok(1_500)
+        );
+        assert_eq!(
+            assert_operator_stake_at_least(
+                &RESTAKING_PROGRAM,
+                &operator,
+                &account.info(),
+                3,
+                1_500
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            assert_operator_stake_at_least(
+                &RESTAKING_PROGRAM,
+                &operator,
+                &account.info(),
+                3,
+                1_501
+            ),
+            Err(RestakingCoreError::OperatorStakeInsufficient.into())
+        );
+        // the snapshot of another epoch
+        assert_eq!(
+            operator_stake(&RESTAKING_PROGRAM, &operator, &account.info(), 4),
+            Err(RestakingCoreError::OperatorEpochStakeInvalidPda.into())
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Addresses of the restaking accounts, derived from the restaking program id the caller trusts
+
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, config::Config, operator::Operator,
+    operator_avs_ticket::OperatorAvsTicket, operator_epoch_stake::OperatorEpochStake,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use solana_program::pubkey::Pubkey;
+
+pub fn config_address(restaking_program: &Pubkey) -> Pubkey {
+    Config::find_program_address(restaking_program).0
+}
+
+pub fn avs_address(restaking_program: &Pubkey, base: &Pubkey) -> Pubkey {
+    Avs::find_program_address(restaking_program, base).0
+}
+
+pub fn operator_address(restaking_program: &Pubkey, base: &Pubkey) -> Pubkey {
+    Operator::find_program_address(restaking_program, base).0
+}
+
+/// The ticket the AVS holds once it added the operator
+pub fn avs_operator_ticket_address(
+    restaking_program: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+) -> Pubkey {
+    AvsOperatorTicket::find_program_address(restaking_program, avs, operator).0
+}
+
+/// The ticket the operator holds once it opted in to the AVS
+pub fn operator_avs_ticket_address(
+    restaking_program: &Pubkey,
+    operator: &Pubkey,
+    avs: &Pubkey,
+) -> Pubkey {
+    OperatorAvsTicket::find_program_address(restaking_program, operator, avs).0
+}
+
+pub fn operator_epoch_stake_address(
+    restaking_program: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Pubkey {
+    OperatorEpochStake::find_program_address(restaking_program, operator, epoch).0
+}
+
+pub fn vault_operator_epoch_stake_address(
+    restaking_program: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> Pubkey {
+    VaultOperatorEpochStake::find_program_address(restaking_program, vault, operator, epoch).0
+}
@@ -0,0 +1,250 @@
+use borsh::BorshDeserialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    config::SanitizedConfig,
+    operator::SanitizedOperator,
+    operator_epoch_stake::OperatorEpochStake,
+    operator_stake_history::{
+        OperatorStakeHistory, SanitizedOperatorStakeHistory, SNAPSHOT_RETENTION_EPOCHS,
+    },
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+    AccountType,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, close_program_account, create_account, empty_account::EmptyAccount,
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::config::SanitizedConfig as SanitizedVaultConfig;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Archives an epoch stake snapshot of the operator that is older than
+/// [`SNAPSHOT_RETENTION_EPOCHS`]. The hash of the snapshot is chained onto the stake history of
+/// the operator, which is created on the first archival, and the snapshot is closed with its rent
+/// going to the rent collector of the operator. The snapshot data and the new commitment are
+/// emitted so historical values can be proven against the history later.
+///
+/// [`crate::RestakingInstruction::ArchiveEpochStake`]
+pub fn process_archive_epoch_stake(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        operator,
+        vault_config,
+        operator_stake_history_account,
+        epoch_stake,
+        rent_collector,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let epoch = epoch_stake_epoch(program_id, epoch_stake, operator.account().key)?;
+
+    let epoch_length = vault_config.config().epoch_length();
+    let current_epoch = Clock::get()?
+        .slot
+        .checked_div(epoch_length)
+        .unwrap_or_default();
+    assert_with_msg(
+        epoch.saturating_add(SNAPSHOT_RETENTION_EPOCHS) <= current_epoch,
+        RestakingError::EpochStakeRetained,
+        "Epoch stake snapshot is still within the retention window",
+    )?;
+
+    let expected_rent_collector = operator.operator().rent_collector();
+    if *rent_collector.key != expected_rent_collector {
+        msg!(
+            "Rent collector {} doesn't match {}",
+            rent_collector.key,
+            expected_rent_collector
+        );
+        return Err(RestakingError::RentCollectorMismatch.into());
+    }
+
+    if operator_stake_history_account.data_is_empty() {
+        _create_operator_stake_history(
+            program_id,
+            &operator,
+            &EmptyAccount::sanitize(operator_stake_history_account, true)?,
+            &payer,
+            &system_program,
+            &Rent::get()?,
+        )?;
+    }
+    let mut operator_stake_history = SanitizedOperatorStakeHistory::sanitize(
+        program_id,
+        operator_stake_history_account,
+        true,
+        operator.account().key,
+    )?;
+
+    let data = epoch_stake.data.borrow().to_vec();
+    let leaf = OperatorStakeHistory::leaf(&data);
+    operator_stake_history
+        .operator_stake_history_mut()
+        .archive(&leaf, epoch)?;
+    let (commitment, archived_count) = {
+        let history = operator_stake_history.operator_stake_history();
+        (history.commitment(), history.archived_count())
+    };
+
+    close_program_account(epoch_stake, rent_collector)?;
+
+    msg!(
+        "EpochStakeArchived: operator={} snapshot={} epoch={} archived_count={}",
+        operator.account().key,
+        epoch_stake.key,
+        epoch,
+        archived_count
+    );
+    RestakingEvent::EpochStakeArchived {
+        operator: *operator.account().key,
+        snapshot: *epoch_stake.key,
+        epoch,
+        data,
+        commitment,
+        archived_count,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Verifies the snapshot is an epoch stake of the operator and returns its epoch
+fn epoch_stake_epoch(
+    program_id: &Pubkey,
+    epoch_stake: &AccountInfo,
+    operator: &Pubkey,
+) -> Result<u64, ProgramError> {
+    let account_type = AccountType::deserialize(&mut epoch_stake.data.borrow().as_ref())
+        .map_err(|_| RestakingError::AccountInvalidType)?;
+    let epoch = match account_type {
+        AccountType::OperatorEpochStake => {
+            let epoch =
+                OperatorEpochStake::deserialize(&mut epoch_stake.data.borrow().as_ref())?.epoch();
+            OperatorEpochStake::deserialize_checked(program_id, epoch_stake, operator, epoch)?;
+            epoch
+        }
+        AccountType::VaultOperatorEpochStake => {
+            let snapshot =
+                VaultOperatorEpochStake::deserialize(&mut epoch_stake.data.borrow().as_ref())?;
+            let (vault, epoch) = (snapshot.vault(), snapshot.epoch());
+            VaultOperatorEpochStake::deserialize_checked(
+                program_id,
+                epoch_stake,
+                &vault,
+                operator,
+                epoch,
+            )?;
+            epoch
+        }
+        _ => {
+            msg!("Only epoch stake snapshots can be archived");
+            return Err(RestakingError::AccountInvalidType.into());
+        }
+    };
+    Ok(epoch)
+}
+
+fn _create_operator_stake_history<'a, 'info>(
+    program_id: &Pubkey,
+    operator: &SanitizedOperator<'a, 'info>,
+    operator_stake_history_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+) -> ProgramResult {
+    let (address, bump, mut seeds) =
+        OperatorStakeHistory::find_program_address(program_id, operator.account().key);
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *operator_stake_history_account.account().key,
+        RestakingError::OperatorStakeHistoryInvalidPda,
+        "Invalid operator stake history PDA",
+    )?;
+
+    let operator_stake_history = OperatorStakeHistory::new(*operator.account().key, bump);
+
+    msg!(
+        "Creating operator stake history: {:?}",
+        operator_stake_history_account.account().key
+    );
+    let serialized = operator_stake_history.as_bytes();
+    create_account(
+        payer.account(),
+        operator_stake_history_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    operator_stake_history_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    vault_config: SanitizedVaultConfig<'a, 'info>,
+    operator_stake_history_account: &'a AccountInfo<'info>,
+    epoch_stake: &'a AccountInfo<'info>,
+    rent_collector: &'a AccountInfo<'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::ArchiveEpochStake`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault_config = SanitizedVaultConfig::sanitize(
+            &config.config().vault_program(),
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let operator_stake_history_account = next_account_info(&mut accounts_iter)?;
+        // checked against the snapshot types in the processor
+        let epoch_stake = next_account_info(&mut accounts_iter)?;
+        if epoch_stake.owner != program_id {
+            msg!(
+                "Account {} is not owned by the restaking program",
+                epoch_stake.key
+            );
+            return Err(RestakingError::AccountInvalidProgramOwner.into());
+        }
+        let rent_collector = next_account_info(&mut accounts_iter)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            operator,
+            vault_config,
+            operator_stake_history_account,
+            epoch_stake,
+            rent_collector,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use jito_jsm_core::{
+    remaining_accounts::{create_address, RemainingAccountsSpec},
+    zero_copy::ZeroCopy,
+};
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    avs_operator_ticket::AvsOperatorTicket,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket,
+    config::SanitizedConfig,
+    result::{RestakingCoreError, RestakingCoreResult},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Read-only probe that verifies the operator, vault and slasher counts of an AVS match the
+/// tickets passed in as remaining accounts. Every ticket the AVS created and didn't close shall be
+/// passed in, active or not, since the counts also serve as the next ticket index. The tickets are
+/// validated as a list first, so a ticket of another AVS or one passed twice fails with its index.
+///
+/// [`crate::RestakingInstruction::AssertAvsHealthy`]
+pub fn process_assert_avs_healthy(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts { avs, tickets } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let avs_key = avs.account().key;
+    RemainingAccountsSpec {
+        owner: program_id,
+        discriminators: &[
+            &[AvsOperatorTicket::DISCRIMINATOR],
+            &[AvsVaultTicket::DISCRIMINATOR],
+            &[AvsVaultSlasherTicket::DISCRIMINATOR],
+        ],
+        writable: false,
+    }
+    .validate(tickets, |ticket| {
+        avs_ticket_address(program_id, avs_key, ticket)
+    })?;
+
+    let mut operator_indices = Vec::new();
+    let mut vault_indices = Vec::new();
+    let mut slasher_indices = Vec::new();
+    for ticket in tickets {
+        let data = ticket.data.borrow();
+        if let Ok(ticket) = AvsOperatorTicket::load(&data) {
+            operator_indices.push(ticket.index());
+        } else if let Ok(ticket) = AvsVaultTicket::load(&data) {
+            vault_indices.push(ticket.index());
+        } else {
+            let ticket =
+                AvsVaultSlasherTicket::load(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+            slasher_indices.push(ticket.index());
+        }
+    }
+
+    check_ticket_indices(
+        operator_indices,
+        avs.avs().operator_count(),
+        avs.avs().closed_operator_count(),
+        RestakingCoreError::AvsOperatorCountMismatch,
+    )?;
+    check_ticket_indices(
+        vault_indices,
+        avs.avs().vault_count(),
+        avs.avs().closed_vault_count(),
+        RestakingCoreError::AvsVaultCountMismatch,
+    )?;
+    check_ticket_indices(
+        slasher_indices,
+        avs.avs().slasher_count(),
+        avs.avs().closed_slasher_count(),
+        RestakingCoreError::AvsSlasherCountMismatch,
+    )?;
+
+    Ok(())
+}
+
+/// The address the AVS's ticket derives to from the operator, vault or slasher and the bump it
+/// stores
+fn avs_ticket_address(program_id: &Pubkey, avs: &Pubkey, ticket: &AccountInfo) -> Pubkey {
+    let data = ticket.data.borrow();
+    if let Ok(ticket) = AvsOperatorTicket::load(&data) {
+        create_address(
+            program_id,
+            AvsOperatorTicket::seeds(avs, &ticket.operator()),
+            ticket.bump(),
+        )
+    } else if let Ok(ticket) = AvsVaultTicket::load(&data) {
+        create_address(
+            program_id,
+            AvsVaultTicket::seeds(avs, &ticket.vault()),
+            ticket.bump(),
+        )
+    } else if let Ok(ticket) = AvsVaultSlasherTicket::load(&data) {
+        create_address(
+            program_id,
+            AvsVaultSlasherTicket::seeds(avs, &ticket.vault(), &ticket.slasher()),
+            ticket.bump(),
+        )
+    } else {
+        Pubkey::default()
+    }
+}
+
+/// The indices of the tickets shall be distinct and within `0..count`, and there shall be one per
+/// ticket not closed, which catches both missing and duplicated tickets. Closed tickets are only
+/// counted, since their indices are gone with them.
+pub fn check_ticket_indices(
+    mut indices: Vec<u64>,
+    count: u64,
+    closed_count: u64,
+    error: RestakingCoreError,
+) -> RestakingCoreResult<()> {
+    indices.sort_unstable();
+    let expected = count.saturating_sub(closed_count);
+    if indices.len() as u64 != expected
+        || indices.windows(2).any(|pair| pair[0] == pair[1])
+        || indices.last().is_some_and(|&index| index >= count)
+    {
+        msg!(
+            "Expected {} tickets with distinct indices within 0..{}, got {:?}",
+            expected,
+            count,
+            indices
+        );
+        return Err(error);
+    }
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    tickets: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AssertAvsHealthy`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let tickets = accounts_iter.as_slice();
+
+        Ok(SanitizedAccounts { avs, tickets })
+    }
+}
@@ -0,0 +1,102 @@
+use borsh::BorshDeserialize;
+use jito_restaking_core::{
+    config::SanitizedConfig, operator::SanitizedOperator, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket, result::RestakingCoreError, AccountType,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::assert_avs_healthy::check_ticket_indices;
+
+/// Read-only probe that verifies the AVS and vault counts of an operator match the tickets
+/// passed in as remaining accounts. Every ticket the operator created and didn't close shall be
+/// passed in, active or not.
+///
+/// [`crate::RestakingInstruction::AssertOperatorHealthy`]
+pub fn process_assert_operator_healthy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts { operator, tickets } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let mut avs_indices = Vec::new();
+    let mut vault_indices = Vec::new();
+    for ticket in tickets {
+        let data = ticket.data.borrow();
+        match AccountType::deserialize(&mut data.as_ref())
+            .map_err(|_| RestakingError::AccountInvalidType)?
+        {
+            AccountType::OperatorAvsTicket => {
+                let avs = OperatorAvsTicket::deserialize(&mut data.as_ref())?.avs();
+                drop(data);
+                let ticket = OperatorAvsTicket::deserialize_checked(
+                    program_id,
+                    ticket,
+                    operator.account().key,
+                    &avs,
+                )?;
+                avs_indices.push(ticket.index());
+            }
+            AccountType::OperatorVaultTicket => {
+                let vault = OperatorVaultTicket::deserialize(&mut data.as_ref())?.vault();
+                drop(data);
+                let ticket = OperatorVaultTicket::deserialize_checked(
+                    program_id,
+                    ticket,
+                    operator.account().key,
+                    &vault,
+                )?;
+                vault_indices.push(ticket.index());
+            }
+            _ => {
+                msg!("Account {} is not an operator ticket", ticket.key);
+                return Err(RestakingError::NotOperatorTicket.into());
+            }
+        }
+    }
+
+    check_ticket_indices(
+        avs_indices,
+        operator.operator().avs_count(),
+        operator.operator().closed_avs_count(),
+        RestakingCoreError::OperatorAvsCountMismatch,
+    )?;
+    check_ticket_indices(
+        vault_indices,
+        operator.operator().vault_count(),
+        operator.operator().closed_vault_count(),
+        RestakingCoreError::OperatorVaultCountMismatch,
+    )?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    tickets: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AssertOperatorHealthy`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let tickets = accounts_iter.as_slice();
+
+        Ok(SanitizedAccounts { operator, tickets })
+    }
+}
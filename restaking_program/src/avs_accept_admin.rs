@@ -0,0 +1,47 @@
+use jito_restaking_core::avs::SanitizedAvs;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The admin proposed with [`crate::RestakingInstruction::AvsSetAdmin`] takes over the AVS.
+///
+/// Instruction: [`crate::RestakingInstruction::AvsAcceptAdmin`]
+pub fn process_avs_accept_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts { mut avs, new_admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs_mut().accept_admin(new_admin.account().key)?;
+
+    msg!(
+        "AvsAdminAccepted: avs={} admin={}",
+        avs.account().key,
+        new_admin.account().key
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    new_admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsAcceptAdmin`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let new_admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { avs, new_admin })
+    }
+}
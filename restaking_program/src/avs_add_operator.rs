@@ -1,11 +1,15 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{
     avs::SanitizedAvs, avs_operator_ticket::AvsOperatorTicket, config::SanitizedConfig,
     operator::SanitizedOperator, operator_avs_ticket::SanitizedOperatorAvsTicket,
 };
 use jito_restaking_sanitization::{
-    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
-    system_program::SanitizedSystemProgram,
+    assert_with_msg, empty_account::EmptyAccount, rent_payer::RentPayer,
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
 };
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -24,6 +28,7 @@ use solana_program::{
 /// [`crate::RestakingInstruction::AvsAddOperator`]
 pub fn process_avs_add_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let SanitizedAccounts {
+        config,
         mut avs,
         operator,
         avs_operator_ticket_account,
@@ -36,10 +41,13 @@ pub fn process_avs_add_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -
     avs.avs().check_operator_admin(admin.account().key)?;
 
     let slot = Clock::get()?.slot;
+    let ticket_epoch_length = config.config().ticket_epoch_length();
+    let index = avs.avs().operator_count();
+    config.config().check_avs_operator_limit(index)?;
 
     operator_avs_ticket
         .operator_avs_ticket()
-        .check_active(slot)?;
+        .check_warmed_up(slot, ticket_epoch_length)?;
 
     _create_avs_operator_ticket(
         program_id,
@@ -54,7 +62,26 @@ pub fn process_avs_add_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -
 
     avs.avs_mut().increment_operator_count()?;
 
-    avs.save()?;
+    msg!(
+        "AvsOperatorTicketCreated: avs={} operator={} ticket={} index={} operator_avs_ticket={} operator_avs_ticket_index={} activation_slot={}",
+        avs.account().key,
+        operator.account().key,
+        avs_operator_ticket_account.account().key,
+        index,
+        operator_avs_ticket.account().key,
+        operator_avs_ticket.operator_avs_ticket().index(),
+        slot
+    );
+    RestakingEvent::AvsOperatorTicketCreated {
+        avs: *avs.account().key,
+        operator: *operator.account().key,
+        ticket: *avs_operator_ticket_account.account().key,
+        index,
+        operator_avs_ticket: *operator_avs_ticket.account().key,
+        operator_avs_ticket_index: operator_avs_ticket.operator_avs_ticket().index(),
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -65,7 +92,7 @@ fn _create_avs_operator_ticket<'a, 'info>(
     avs: &SanitizedAvs<'a, 'info>,
     operator: &SanitizedOperator<'a, 'info>,
     avs_operator_ticket_account: &EmptyAccount<'a, 'info>,
-    payer: &SanitizedSignerAccount<'a, 'info>,
+    payer: &RentPayer<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
     slot: u64,
@@ -79,7 +106,7 @@ fn _create_avs_operator_ticket<'a, 'info>(
 
     assert_with_msg(
         address == *avs_operator_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::AvsOperatorTicketInvalidPda,
         "AVS operator ticket is not at the correct PDA",
     )?;
 
@@ -95,9 +122,8 @@ fn _create_avs_operator_ticket<'a, 'info>(
         "Creating AVS operator ticket: {:?}",
         avs_operator_ticket_account.account().key
     );
-    let serialized = avs_operator_ticket.try_to_vec()?;
-    create_account(
-        payer.account(),
+    let serialized = avs_operator_ticket.as_bytes();
+    payer.create_account(
         avs_operator_ticket_account.account(),
         system_program.account(),
         program_id,
@@ -106,17 +132,18 @@ fn _create_avs_operator_ticket<'a, 'info>(
         &seeds,
     )?;
     avs_operator_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
     avs: SanitizedAvs<'a, 'info>,
     operator: SanitizedOperator<'a, 'info>,
     avs_operator_ticket_account: EmptyAccount<'a, 'info>,
     operator_avs_ticket: SanitizedOperatorAvsTicket<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
-    payer: SanitizedSignerAccount<'a, 'info>,
+    payer: RentPayer<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
 }
 
@@ -128,7 +155,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let _config =
+        let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
         let operator =
@@ -143,10 +170,11 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             avs.account().key,
         )?;
         let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
-        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let payer = RentPayer::sanitize(next_account_info(accounts_iter)?, avs.account())?;
         let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
 
         Ok(SanitizedAccounts {
+            config,
             avs,
             operator,
             avs_operator_ticket_account,
@@ -1,11 +1,16 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{
     avs::SanitizedAvs, avs_vault_ticket::AvsVaultTicket, config::SanitizedConfig,
 };
 use jito_restaking_sanitization::{
-    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
-    system_program::SanitizedSystemProgram,
+    assert_with_msg, empty_account::EmptyAccount, rent_payer::RentPayer,
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::vault::SanitizedVault;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -34,11 +39,12 @@ pub fn process_avs_add_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
     avs.avs().check_vault_admin(admin.account().key)?;
 
     let slot = Clock::get()?.slot;
+    let index = avs.avs().vault_count();
 
     _create_avs_vault_ticket(
         program_id,
         &avs,
-        vault,
+        &vault,
         &avs_vault_ticket_account,
         &payer,
         &system_program,
@@ -48,7 +54,22 @@ pub fn process_avs_add_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
 
     avs.avs_mut().increment_vault_count()?;
 
-    avs.save()?;
+    msg!(
+        "AvsVaultTicketCreated: avs={} vault={} ticket={} index={} activation_slot={}",
+        avs.account().key,
+        vault.account().key,
+        avs_vault_ticket_account.account().key,
+        index,
+        slot
+    );
+    RestakingEvent::AvsVaultTicketCreated {
+        avs: *avs.account().key,
+        vault: *vault.account().key,
+        ticket: *avs_vault_ticket_account.account().key,
+        index,
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -57,26 +78,26 @@ pub fn process_avs_add_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
 fn _create_avs_vault_ticket<'a, 'info>(
     program_id: &Pubkey,
     avs: &SanitizedAvs<'a, 'info>,
-    vault: &AccountInfo<'info>,
+    vault: &SanitizedVault<'a, 'info>,
     avs_vault_ticket_account: &EmptyAccount<'a, 'info>,
-    payer: &SanitizedSignerAccount<'a, 'info>,
+    payer: &RentPayer<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
     slot: u64,
 ) -> ProgramResult {
     let (address, bump, mut seeds) =
-        AvsVaultTicket::find_program_address(program_id, avs.account().key, vault.key);
+        AvsVaultTicket::find_program_address(program_id, avs.account().key, vault.account().key);
     seeds.push(vec![bump]);
 
     assert_with_msg(
         address == *avs_vault_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::AvsVaultTicketInvalidPda,
         "Invalid AVS vault ticket PDA",
     )?;
 
     let avs_vault_ticket = AvsVaultTicket::new(
         *avs.account().key,
-        *vault.key,
+        *vault.account().key,
         avs.avs().vault_count(),
         slot,
         bump,
@@ -86,9 +107,8 @@ fn _create_avs_vault_ticket<'a, 'info>(
         "Creating AVS vault ticket: {:?}",
         avs_vault_ticket_account.account().key
     );
-    let serialized = avs_vault_ticket.try_to_vec()?;
-    create_account(
-        payer.account(),
+    let serialized = avs_vault_ticket.as_bytes();
+    payer.create_account(
         avs_vault_ticket_account.account(),
         system_program.account(),
         program_id,
@@ -97,16 +117,16 @@ fn _create_avs_vault_ticket<'a, 'info>(
         &seeds,
     )?;
     avs_vault_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
     avs: SanitizedAvs<'a, 'info>,
-    vault: &'a AccountInfo<'info>,
+    vault: SanitizedVault<'a, 'info>,
     avs_vault_ticket_account: EmptyAccount<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
-    payer: SanitizedSignerAccount<'a, 'info>,
+    payer: RentPayer<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
 }
 
@@ -118,15 +138,18 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let _config =
+        let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
-        // TODO (LB): should deser vault?
-        let vault = next_account_info(accounts_iter)?;
+        let vault = SanitizedVault::sanitize(
+            &config.config().vault_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
         let avs_vault_ticket_account =
             EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
         let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
-        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let payer = RentPayer::sanitize(next_account_info(accounts_iter)?, avs.account())?;
         let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
 
         Ok(SanitizedAccounts {
@@ -1,16 +1,22 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{
     avs::SanitizedAvs, avs_vault_slasher_ticket::AvsVaultSlasherTicket,
     avs_vault_ticket::SanitizedAvsVaultTicket, config::SanitizedConfig,
 };
 use jito_restaking_sanitization::{
-    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
-    system_program::SanitizedSystemProgram,
+    assert_with_msg, empty_account::EmptyAccount, rent_payer::RentPayer,
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::vault::SanitizedVault;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    hash::Hash,
     msg,
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -18,31 +24,47 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
+/// The AVS slasher admin allows a slasher to slash a vault the AVS supports, with the multisig of
+/// the AVS, if any, co-signing.
+///
+/// [`crate::RestakingInstruction::AvsAddVaultSlasher`]
 pub fn process_avs_add_vault_slasher(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     max_slashable_per_epoch: u64,
+    metadata_hash: [u8; 32],
 ) -> ProgramResult {
     let SanitizedAccounts {
+        config,
         mut avs,
         vault,
         slasher,
-        avs_vault_ticket,
+        mut avs_vault_ticket,
         avs_vault_slasher_ticket_account,
         admin,
         payer,
         system_program,
+        multisig_signers,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     avs.avs().check_slasher_admin(admin.account().key)?;
+    avs.avs().check_multisig(multisig_signers)?;
+    config.config().check_slasher_owner(slasher.owner)?;
 
     let slot = Clock::get()?.slot;
-    avs_vault_ticket.avs_vault_ticket().check_active(slot)?;
+    let index = avs.avs().slasher_count();
+    avs_vault_ticket
+        .avs_vault_ticket()
+        .check_warmed_up(slot, config.config().ticket_epoch_length())?;
+    let max_slashers = avs.avs().max_slashers_per_vault();
+    avs_vault_ticket
+        .avs_vault_ticket_mut()
+        .increment_slasher_count(max_slashers)?;
 
     _create_avs_vault_slasher_ticket(
         program_id,
         &avs,
-        vault,
+        &vault,
         slasher,
         &avs_vault_slasher_ticket_account,
         &payer,
@@ -50,11 +72,36 @@ pub fn process_avs_add_vault_slasher(
         &Rent::get()?,
         slot,
         max_slashable_per_epoch,
+        metadata_hash,
     )?;
 
     avs.avs_mut().increment_slasher_count()?;
 
-    avs.save()?;
+    msg!(
+        "AvsVaultSlasherTicketCreated: avs={} vault={} slasher={} ticket={} index={} avs_vault_ticket={} avs_vault_ticket_index={} metadata_hash={} activation_slot={}",
+        avs.account().key,
+        vault.account().key,
+        slasher.key,
+        avs_vault_slasher_ticket_account.account().key,
+        index,
+        avs_vault_ticket.account().key,
+        avs_vault_ticket.avs_vault_ticket().index(),
+        Hash::new_from_array(metadata_hash),
+        slot
+    );
+    RestakingEvent::AvsVaultSlasherTicketCreated {
+        avs: *avs.account().key,
+        vault: *vault.account().key,
+        slasher: *slasher.key,
+        ticket: *avs_vault_slasher_ticket_account.account().key,
+        index,
+        avs_vault_ticket: *avs_vault_ticket.account().key,
+        avs_vault_ticket_index: avs_vault_ticket.avs_vault_ticket().index(),
+        max_slashable_per_epoch,
+        metadata_hash,
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -63,34 +110,36 @@ pub fn process_avs_add_vault_slasher(
 fn _create_avs_vault_slasher_ticket<'a, 'info>(
     program_id: &Pubkey,
     avs: &SanitizedAvs<'a, 'info>,
-    vault: &AccountInfo<'info>,
+    vault: &SanitizedVault<'a, 'info>,
     slasher: &AccountInfo<'info>,
     avs_vault_slasher_ticket_account: &EmptyAccount<'a, 'info>,
-    payer: &SanitizedSignerAccount<'a, 'info>,
+    payer: &RentPayer<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
     slot: u64,
     max_slashable_per_epoch: u64,
+    metadata_hash: [u8; 32],
 ) -> ProgramResult {
     let (address, bump, mut seeds) = AvsVaultSlasherTicket::find_program_address(
         program_id,
         avs.account().key,
-        vault.key,
+        vault.account().key,
         slasher.key,
     );
     seeds.push(vec![bump]);
 
     assert_with_msg(
         address == *avs_vault_slasher_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::AvsVaultSlasherTicketInvalidPda,
         "Invalid AVS vault slasher ticket PDA",
     )?;
 
     let avs_vault_slasher_ticket = AvsVaultSlasherTicket::new(
         *avs.account().key,
-        *vault.key,
+        *vault.account().key,
         *slasher.key,
         max_slashable_per_epoch,
+        metadata_hash,
         avs.avs().slasher_count(),
         slot,
         bump,
@@ -100,9 +149,8 @@ fn _create_avs_vault_slasher_ticket<'a, 'info>(
         "Creating AVS vault slasher ticket: {:?}",
         avs_vault_slasher_ticket_account.account().key
     );
-    let serialized = avs_vault_slasher_ticket.try_to_vec()?;
-    create_account(
-        payer.account(),
+    let serialized = avs_vault_slasher_ticket.as_bytes();
+    payer.create_account(
         avs_vault_slasher_ticket_account.account(),
         system_program.account(),
         program_id,
@@ -111,19 +159,21 @@ fn _create_avs_vault_slasher_ticket<'a, 'info>(
         &seeds,
     )?;
     avs_vault_slasher_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
     avs: SanitizedAvs<'a, 'info>,
-    vault: &'a AccountInfo<'info>,
+    vault: SanitizedVault<'a, 'info>,
     slasher: &'a AccountInfo<'info>,
     avs_vault_ticket: SanitizedAvsVaultTicket<'a, 'info>,
     avs_vault_slasher_ticket_account: EmptyAccount<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
-    payer: SanitizedSignerAccount<'a, 'info>,
+    payer: RentPayer<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
+    multisig_signers: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
@@ -134,25 +184,32 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let _config =
+        let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
-        let vault = next_account_info(accounts_iter)?;
+        let vault = SanitizedVault::sanitize(
+            &config.config().vault_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
         let slasher = next_account_info(accounts_iter)?;
         let avs_vault_ticket = SanitizedAvsVaultTicket::sanitize(
             program_id,
             next_account_info(accounts_iter)?,
-            false,
+            true,
             avs.account().key,
-            vault.key,
+            vault.account().key,
         )?;
         let avs_vault_slasher_ticket_account =
             EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
         let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
-        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let payer = RentPayer::sanitize(next_account_info(accounts_iter)?, avs.account())?;
         let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+        // checked against the multisig of the AVS
+        let multisig_signers = accounts_iter.as_slice();
 
         Ok(SanitizedAccounts {
+            config,
             avs,
             vault,
             slasher,
@@ -161,6 +218,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             admin,
             payer,
             system_program,
+            multisig_signers,
         })
     }
 }
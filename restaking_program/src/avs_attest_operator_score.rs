@@ -0,0 +1,199 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    avs_operator_ticket::SanitizedAvsOperatorTicket,
+    config::SanitizedConfig,
+    operator::SanitizedOperator,
+    operator_avs_score::{OperatorAvsScore, SanitizedOperatorAvsScore},
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// The AVS scorer attests the performance score of an operator the AVS added. The score account
+/// is created on the first attestation and updated afterwards, and later reward snapshots record
+/// the latest score.
+///
+/// [`crate::RestakingInstruction::AvsAttestOperatorScore`]
+pub fn process_avs_attest_operator_score(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    score_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        avs,
+        operator,
+        avs_operator_ticket,
+        operator_avs_score_account,
+        scorer,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_scorer(scorer.account().key)?;
+
+    let slot = Clock::get()?.slot;
+    let ticket_epoch_length = config.config().ticket_epoch_length();
+    avs_operator_ticket
+        .avs_operator_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+
+    if operator_avs_score_account.data_is_empty() {
+        let operator_avs_score_account = EmptyAccount::sanitize(operator_avs_score_account, true)?;
+        _create_operator_avs_score(
+            program_id,
+            &operator,
+            &avs,
+            &operator_avs_score_account,
+            &payer,
+            &system_program,
+            &Rent::get()?,
+            score_bps,
+            slot,
+        )?;
+    } else {
+        let mut operator_avs_score = SanitizedOperatorAvsScore::sanitize(
+            program_id,
+            operator_avs_score_account,
+            true,
+            operator.account().key,
+            avs.account().key,
+        )?;
+        operator_avs_score
+            .operator_avs_score_mut()
+            .record_score(score_bps, slot)?;
+    }
+
+    msg!(
+        "OperatorScoreAttested: avs={} operator={} score_bps={} slot={}",
+        avs.account().key,
+        operator.account().key,
+        score_bps,
+        slot
+    );
+    RestakingEvent::OperatorScoreAttested {
+        avs: *avs.account().key,
+        operator: *operator.account().key,
+        score_bps,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn _create_operator_avs_score<'a, 'info>(
+    program_id: &Pubkey,
+    operator: &SanitizedOperator<'a, 'info>,
+    avs: &SanitizedAvs<'a, 'info>,
+    operator_avs_score_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+    score_bps: u16,
+    slot: u64,
+) -> ProgramResult {
+    let (address, bump, mut seeds) = OperatorAvsScore::find_program_address(
+        program_id,
+        operator.account().key,
+        avs.account().key,
+    );
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *operator_avs_score_account.account().key,
+        RestakingError::OperatorAvsScoreInvalidPda,
+        "Invalid operator AVS score PDA",
+    )?;
+
+    let mut operator_avs_score =
+        OperatorAvsScore::new(*operator.account().key, *avs.account().key, bump);
+    operator_avs_score.record_score(score_bps, slot)?;
+
+    msg!(
+        "Creating operator AVS score: {:?}",
+        operator_avs_score_account.account().key
+    );
+    let serialized = operator_avs_score.as_bytes();
+    create_account(
+        payer.account(),
+        operator_avs_score_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    operator_avs_score_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    avs: SanitizedAvs<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    avs_operator_ticket: SanitizedAvsOperatorTicket<'a, 'info>,
+    operator_avs_score_account: &'a AccountInfo<'info>,
+    scorer: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsAttestOperatorScore`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs =
+            SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs_operator_ticket = SanitizedAvsOperatorTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            avs.account().key,
+            operator.account().key,
+        )?;
+        let operator_avs_score_account = next_account_info(&mut accounts_iter)?;
+        let scorer =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            avs,
+            operator,
+            avs_operator_ticket,
+            operator_avs_score_account,
+            scorer,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,243 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    config::SanitizedConfig,
+    epoch_reward_router::{EpochRewardRouter, SanitizedEpochRewardRouter},
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount, create_account,
+    empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram, token_account::SanitizedTokenAccount,
+    token_mint::SanitizedTokenMint, token_program::SanitizedTokenProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::config::SanitizedConfig as SanitizedVaultConfig;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_token::instruction::transfer;
+
+/// Deposits rewards the AVS pays for an epoch into the router of the epoch and mint, which is
+/// created on first use with the epoch length of the vault program. The router token account is
+/// the associated token account of the router and must exist.
+///
+/// [`crate::RestakingInstruction::AvsDepositEpochRewards`]
+pub fn process_avs_deposit_epoch_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    amount: u64,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        vault_config,
+        epoch_reward_router_account,
+        mint,
+        router_token_account,
+        depositor_token_account,
+        depositor,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    if epoch_reward_router_account.data_is_empty() {
+        let epoch_reward_router_account =
+            EmptyAccount::sanitize(epoch_reward_router_account, true)?;
+        let epoch_length = vault_config.config().epoch_length();
+        _create_epoch_reward_router(
+            program_id,
+            &avs,
+            &mint,
+            &epoch_reward_router_account,
+            &payer,
+            &system_program,
+            &Rent::get()?,
+            epoch,
+            epoch_length,
+        )?;
+    }
+    let mut epoch_reward_router = SanitizedEpochRewardRouter::sanitize(
+        program_id,
+        epoch_reward_router_account,
+        true,
+        avs.account().key,
+    )?;
+    assert_with_msg(
+        epoch_reward_router.epoch_reward_router().mint() == *mint.account().key
+            && epoch_reward_router.epoch_reward_router().epoch() == epoch,
+        RestakingError::EpochRewardRouterInvalidPda,
+        "Epoch reward router doesn't match the mint and epoch",
+    )?;
+    let router_token_account = SanitizedAssociatedTokenAccount::sanitize(
+        router_token_account,
+        mint.account().key,
+        epoch_reward_router.account().key,
+    )?;
+
+    let slot = Clock::get()?.slot;
+    epoch_reward_router
+        .epoch_reward_router_mut()
+        .deposit(amount, slot)?;
+
+    invoke(
+        &transfer(
+            &spl_token::id(),
+            depositor_token_account.account().key,
+            router_token_account.account().key,
+            depositor.account().key,
+            &[],
+            amount,
+        )?,
+        &[
+            depositor_token_account.account().clone(),
+            router_token_account.account().clone(),
+            depositor.account().clone(),
+        ],
+    )?;
+
+    let total_rewards = epoch_reward_router.epoch_reward_router().total_rewards();
+    msg!(
+        "EpochRewardsDeposited: avs={} router={} mint={} epoch={} amount={} total_rewards={}",
+        avs.account().key,
+        epoch_reward_router.account().key,
+        mint.account().key,
+        epoch,
+        amount,
+        total_rewards
+    );
+    RestakingEvent::EpochRewardsDeposited {
+        avs: *avs.account().key,
+        router: *epoch_reward_router.account().key,
+        mint: *mint.account().key,
+        epoch,
+        amount,
+        total_rewards,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn _create_epoch_reward_router<'a, 'info>(
+    program_id: &Pubkey,
+    avs: &SanitizedAvs<'a, 'info>,
+    mint: &SanitizedTokenMint<'a, 'info>,
+    epoch_reward_router_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+    epoch: u64,
+    epoch_length: u64,
+) -> ProgramResult {
+    let (address, bump, mut seeds) = EpochRewardRouter::find_program_address(
+        program_id,
+        avs.account().key,
+        mint.account().key,
+        epoch,
+    );
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *epoch_reward_router_account.account().key,
+        RestakingError::EpochRewardRouterInvalidPda,
+        "Invalid epoch reward router PDA",
+    )?;
+
+    let epoch_reward_router = EpochRewardRouter::new(
+        *avs.account().key,
+        *mint.account().key,
+        epoch,
+        epoch_length,
+        bump,
+    );
+
+    msg!(
+        "Creating epoch reward router: {:?}",
+        epoch_reward_router_account.account().key
+    );
+    let serialized = epoch_reward_router.as_bytes();
+    create_account(
+        payer.account(),
+        epoch_reward_router_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    epoch_reward_router_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    vault_config: SanitizedVaultConfig<'a, 'info>,
+    epoch_reward_router_account: &'a AccountInfo<'info>,
+    mint: SanitizedTokenMint<'a, 'info>,
+    router_token_account: &'a AccountInfo<'info>,
+    depositor_token_account: SanitizedTokenAccount<'a, 'info>,
+    depositor: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsDepositEpochRewards`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs =
+            SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault_config = SanitizedVaultConfig::sanitize(
+            &config.config().vault_program(),
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let epoch_reward_router_account = next_account_info(&mut accounts_iter)?;
+        let mint = SanitizedTokenMint::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let router_token_account = next_account_info(&mut accounts_iter)?;
+        let depositor_token_account = next_account_info(&mut accounts_iter)?;
+        let depositor =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let depositor_token_account = SanitizedTokenAccount::sanitize(
+            depositor_token_account,
+            mint.account().key,
+            depositor.account().key,
+        )?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let _token_program =
+            SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            vault_config,
+            epoch_reward_router_account,
+            mint,
+            router_token_account,
+            depositor_token_account,
+            depositor,
+            payer,
+            system_program,
+        })
+    }
+}
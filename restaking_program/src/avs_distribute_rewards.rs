@@ -0,0 +1,148 @@
+use jito_restaking_core::{
+    avs::{Avs, SanitizedAvs},
+    avs_reward_budget::SanitizedAvsRewardBudget,
+    config::SanitizedConfig,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, token_account::SanitizedTokenAccount, token_program::SanitizedTokenProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::instruction::transfer;
+
+/// Anyone can pay out the emissions of the epochs elapsed since the last distribution, including
+/// the current one, from the AVS token account to the reward budget receiver. Fails if the AVS
+/// token account can't cover them, so the epochs are paid out once it is funded.
+///
+/// [`crate::RestakingInstruction::AvsDistributeRewards`]
+pub fn process_avs_distribute_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        mut avs_reward_budget,
+        avs_token_account,
+        receiver_token_account,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    assert_with_msg(
+        *receiver_token_account.key == avs_reward_budget.avs_reward_budget().receiver(),
+        RestakingError::AvsRewardBudgetInvalidReceiver,
+        "Receiver token account does not match the AVS reward budget",
+    )?;
+
+    let slot = Clock::get()?.slot;
+    let epoch = avs_reward_budget.avs_reward_budget().epoch(slot);
+    let amount = avs_reward_budget
+        .avs_reward_budget_mut()
+        .distribute(epoch)?;
+
+    assert_with_msg(
+        avs_token_account.token_account().amount >= amount,
+        RestakingError::AvsInsufficientFunds,
+        "Not enough funds in AVS token account",
+    )?;
+
+    if amount > 0 {
+        let mut avs_seeds = Avs::seeds(&avs.avs().base());
+        avs_seeds.push(vec![avs.avs().bump()]);
+        let avs_seeds_slice = avs_seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>();
+
+        invoke_signed(
+            &transfer(
+                &spl_token::id(),
+                avs_token_account.account().key,
+                receiver_token_account.key,
+                avs.account().key,
+                &[],
+                amount,
+            )?,
+            &[
+                avs_token_account.account().clone(),
+                receiver_token_account.clone(),
+                avs.account().clone(),
+            ],
+            &[avs_seeds_slice.as_slice()],
+        )?;
+    }
+
+    let next_epoch = avs_reward_budget.avs_reward_budget().next_epoch();
+    msg!(
+        "AvsRewardsDistributed: avs={} budget={} receiver={} amount={} next_epoch={}",
+        avs.account().key,
+        avs_reward_budget.account().key,
+        receiver_token_account.key,
+        amount,
+        next_epoch
+    );
+    RestakingEvent::AvsRewardsDistributed {
+        avs: *avs.account().key,
+        budget: *avs_reward_budget.account().key,
+        receiver: *receiver_token_account.key,
+        amount,
+        next_epoch,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    avs_reward_budget: SanitizedAvsRewardBudget<'a, 'info>,
+    avs_token_account: SanitizedTokenAccount<'a, 'info>,
+    receiver_token_account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsDistributeRewards`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs_reward_budget = next_account_info(accounts_iter)?;
+        let mint = next_account_info(accounts_iter)?;
+        let avs_reward_budget = SanitizedAvsRewardBudget::sanitize(
+            program_id,
+            avs_reward_budget,
+            true,
+            avs.account().key,
+            mint.key,
+        )?;
+        let avs_token_account = SanitizedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            mint.key,
+            avs.account().key,
+        )?;
+        let receiver_token_account = next_account_info(accounts_iter)?; // let token program handle this
+        let _token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            avs_reward_budget,
+            avs_token_account,
+            receiver_token_account,
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    avs_operator_ticket::SanitizedAvsOperatorTicket,
+    config::SanitizedConfig,
+    operator::SanitizedOperator,
+    operator_avs_heartbeat::{OperatorAvsHeartbeat, SanitizedOperatorAvsHeartbeat},
+};
+use jito_restaking_sanitization::assert_with_msg;
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Permissionless crank that deactivates the [`jito_restaking_core::avs_operator_ticket::AvsOperatorTicket`]
+/// of an operator whose last heartbeat is older than the AVS's max heartbeat age.
+/// An operator that never sent a heartbeat is aged from the slot it was added to the AVS.
+///
+/// [`crate::RestakingInstruction::AvsEjectStaleOperator`]
+pub fn process_avs_eject_stale_operator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        operator,
+        mut avs_operator_ticket,
+        operator_avs_heartbeat_account,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+
+    let last_heartbeat_slot = if operator_avs_heartbeat_account.data_is_empty() {
+        let (address, _, _) = OperatorAvsHeartbeat::find_program_address(
+            program_id,
+            operator.account().key,
+            avs.account().key,
+        );
+        assert_with_msg(
+            address == *operator_avs_heartbeat_account.key,
+            RestakingError::OperatorAvsHeartbeatInvalidPda,
+            "Invalid operator AVS heartbeat PDA",
+        )?;
+        avs_operator_ticket
+            .avs_operator_ticket()
+            .state()
+            .slot_added()
+    } else {
+        SanitizedOperatorAvsHeartbeat::sanitize(
+            program_id,
+            operator_avs_heartbeat_account,
+            false,
+            operator.account().key,
+            avs.account().key,
+        )?
+        .operator_avs_heartbeat()
+        .last_heartbeat_slot()
+    };
+
+    avs.avs()
+        .check_heartbeat_stale(slot.saturating_sub(last_heartbeat_slot))?;
+
+    avs_operator_ticket.avs_operator_ticket_mut().eject(slot)?;
+
+    msg!(
+        "OperatorEjected: avs={} operator={} last_heartbeat_slot={} slot={}",
+        avs.account().key,
+        operator.account().key,
+        last_heartbeat_slot,
+        slot
+    );
+    RestakingEvent::OperatorEjected {
+        avs: *avs.account().key,
+        operator: *operator.account().key,
+        last_heartbeat_slot,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    avs_operator_ticket: SanitizedAvsOperatorTicket<'a, 'info>,
+    operator_avs_heartbeat_account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsEjectStaleOperator`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs_operator_ticket = SanitizedAvsOperatorTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            avs.account().key,
+            operator.account().key,
+        )?;
+        let operator_avs_heartbeat_account = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            operator,
+            avs_operator_ticket,
+            operator_avs_heartbeat_account,
+        })
+    }
+}
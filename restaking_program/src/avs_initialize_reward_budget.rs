@@ -0,0 +1,153 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_reward_budget::AvsRewardBudget, config::SanitizedConfig,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram, token_mint::SanitizedTokenMint,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// The AVS withdraw admin creates the reward budget for a mint. From the current epoch on, anyone
+/// can pay out each epoch's emission from the AVS token account to the receiver with
+/// [`crate::RestakingInstruction::AvsDistributeRewards`].
+///
+/// [`crate::RestakingInstruction::AvsInitializeRewardBudget`]
+pub fn process_avs_initialize_reward_budget(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch_length: u64,
+    tokens_per_epoch: u64,
+    decay_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        avs_reward_budget_account,
+        mint,
+        receiver_token_account,
+        admin,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_withdraw_admin(admin.account().key)?;
+
+    let (address, bump, mut seeds) =
+        AvsRewardBudget::find_program_address(program_id, avs.account().key, mint.account().key);
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *avs_reward_budget_account.account().key,
+        RestakingError::AvsRewardBudgetInvalidPda,
+        "AVS reward budget is not at the correct PDA",
+    )?;
+
+    let slot = Clock::get()?.slot;
+    let epoch = slot.checked_div(epoch_length).unwrap_or_default();
+    let avs_reward_budget = AvsRewardBudget::new(
+        *avs.account().key,
+        *mint.account().key,
+        *receiver_token_account.key,
+        epoch_length,
+        tokens_per_epoch,
+        decay_bps,
+        epoch,
+        bump,
+    )?;
+
+    msg!(
+        "Creating AVS reward budget: {:?}",
+        avs_reward_budget_account.account().key
+    );
+    let serialized = avs_reward_budget.as_bytes();
+    create_account(
+        payer.account(),
+        avs_reward_budget_account.account(),
+        system_program.account(),
+        program_id,
+        &Rent::get()?,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    avs_reward_budget_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+
+    msg!(
+        "AvsRewardBudgetInitialized: avs={} budget={} mint={} receiver={} epoch_length={} tokens_per_epoch={} decay_bps={} start_epoch={}",
+        avs.account().key,
+        avs_reward_budget_account.account().key,
+        mint.account().key,
+        receiver_token_account.key,
+        epoch_length,
+        tokens_per_epoch,
+        decay_bps,
+        epoch
+    );
+    RestakingEvent::AvsRewardBudgetInitialized {
+        avs: *avs.account().key,
+        budget: *avs_reward_budget_account.account().key,
+        mint: *mint.account().key,
+        receiver: *receiver_token_account.key,
+        epoch_length,
+        tokens_per_epoch,
+        decay_bps,
+        start_epoch: epoch,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    avs_reward_budget_account: EmptyAccount<'a, 'info>,
+    mint: SanitizedTokenMint<'a, 'info>,
+    receiver_token_account: &'a AccountInfo<'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsInitializeRewardBudget`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs_reward_budget_account =
+            EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let mint = SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, false)?;
+        let receiver_token_account = next_account_info(accounts_iter)?; // checked on each distribution
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            avs_reward_budget_account,
+            mint,
+            receiver_token_account,
+            admin,
+            payer,
+            system_program,
+        })
+    }
+}
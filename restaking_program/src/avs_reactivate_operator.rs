@@ -0,0 +1,102 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_operator_ticket::SanitizedAvsOperatorTicket, config::SanitizedConfig,
+    operator::SanitizedOperator, operator_avs_heartbeat::SanitizedOperatorAvsHeartbeat,
+};
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Permissionless crank that re-activates an operator previously ejected for missing heartbeats,
+/// once the operator has sent a heartbeat within the AVS's max heartbeat age.
+/// Operators removed by the AVS operator admin can't be re-activated this way.
+///
+/// [`crate::RestakingInstruction::AvsReactivateOperator`]
+pub fn process_avs_reactivate_operator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        operator,
+        mut avs_operator_ticket,
+        operator_avs_heartbeat,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+
+    avs.avs().check_heartbeat_fresh(
+        operator_avs_heartbeat
+            .operator_avs_heartbeat()
+            .heartbeat_age(slot),
+    )?;
+
+    avs_operator_ticket
+        .avs_operator_ticket_mut()
+        .reactivate(slot)?;
+
+    msg!(
+        "OperatorReactivated: avs={} operator={} slot={}",
+        avs.account().key,
+        operator.account().key,
+        slot
+    );
+    RestakingEvent::OperatorReactivated {
+        avs: *avs.account().key,
+        operator: *operator.account().key,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    avs_operator_ticket: SanitizedAvsOperatorTicket<'a, 'info>,
+    operator_avs_heartbeat: SanitizedOperatorAvsHeartbeat<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsReactivateOperator`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs_operator_ticket = SanitizedAvsOperatorTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            avs.account().key,
+            operator.account().key,
+        )?;
+        let operator_avs_heartbeat = SanitizedOperatorAvsHeartbeat::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            false,
+            operator.account().key,
+            avs.account().key,
+        )?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            operator,
+            avs_operator_ticket,
+            operator_avs_heartbeat,
+        })
+    }
+}
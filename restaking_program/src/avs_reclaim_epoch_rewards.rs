@@ -0,0 +1,139 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    config::SanitizedConfig,
+    epoch_reward_router::{EpochRewardRouter, SanitizedEpochRewardRouter},
+};
+use jito_restaking_sanitization::{
+    associated_token_account::SanitizedAssociatedTokenAccount, signer::SanitizedSignerAccount,
+    token_program::SanitizedTokenProgram,
+};
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::instruction::transfer;
+
+/// The AVS withdraw admin takes back what's left in the router token account once the epoch
+/// ended and every snapshot was paid out, such as rounding dust or the rewards of an epoch nobody
+/// had stake in.
+///
+/// [`crate::RestakingInstruction::AvsReclaimEpochRewards`]
+pub fn process_avs_reclaim_epoch_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        epoch_reward_router,
+        router_token_account,
+        receiver_token_account,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_withdraw_admin(admin.account().key)?;
+
+    let slot = Clock::get()?.slot;
+    let router = *epoch_reward_router.epoch_reward_router();
+    router.check_ended(slot)?;
+    router.check_fully_distributed()?;
+
+    let amount = router_token_account.token_account().amount;
+    if amount > 0 {
+        let mut router_seeds =
+            EpochRewardRouter::seeds(&router.avs(), &router.mint(), router.epoch());
+        router_seeds.push(vec![router.bump()]);
+        let router_seeds_slice = router_seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>();
+
+        invoke_signed(
+            &transfer(
+                &spl_token::id(),
+                router_token_account.account().key,
+                receiver_token_account.key,
+                epoch_reward_router.account().key,
+                &[],
+                amount,
+            )?,
+            &[
+                router_token_account.account().clone(),
+                receiver_token_account.clone(),
+                epoch_reward_router.account().clone(),
+            ],
+            &[router_seeds_slice.as_slice()],
+        )?;
+    }
+
+    msg!(
+        "EpochRewardsReclaimed: avs={} router={} receiver={} amount={}",
+        avs.account().key,
+        epoch_reward_router.account().key,
+        receiver_token_account.key,
+        amount
+    );
+    RestakingEvent::EpochRewardsReclaimed {
+        avs: *avs.account().key,
+        router: *epoch_reward_router.account().key,
+        receiver: *receiver_token_account.key,
+        amount,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    epoch_reward_router: SanitizedEpochRewardRouter<'a, 'info>,
+    router_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    receiver_token_account: &'a AccountInfo<'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsReclaimEpochRewards`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs =
+            SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let epoch_reward_router = SanitizedEpochRewardRouter::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            avs.account().key,
+        )?;
+        let mint = epoch_reward_router.epoch_reward_router().mint();
+        let router_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            &mint,
+            epoch_reward_router.account().key,
+        )?;
+        let receiver_token_account = next_account_info(&mut accounts_iter)?; // let token program handle this
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let _token_program =
+            SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            epoch_reward_router,
+            router_token_account,
+            receiver_token_account,
+            admin,
+        })
+    }
+}
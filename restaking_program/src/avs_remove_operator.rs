@@ -3,10 +3,12 @@ use jito_restaking_core::{
     operator::SanitizedOperator,
 };
 use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -30,7 +32,22 @@ pub fn process_avs_remove_operator(program_id: &Pubkey, accounts: &[AccountInfo]
         .avs_operator_ticket_mut()
         .deactivate(slot)?;
 
-    avs_operator_ticket.save()?;
+    msg!(
+        "AvsOperatorTicketDeactivated: avs={} operator={} ticket={} index={} slot={}",
+        avs.account().key,
+        avs_operator_ticket.avs_operator_ticket().operator(),
+        avs_operator_ticket.account().key,
+        avs_operator_ticket.avs_operator_ticket().index(),
+        slot
+    );
+    RestakingEvent::AvsOperatorTicketDeactivated {
+        avs: *avs.account().key,
+        operator: avs_operator_ticket.avs_operator_ticket().operator(),
+        ticket: *avs_operator_ticket.account().key,
+        index: avs_operator_ticket.avs_operator_ticket().index(),
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
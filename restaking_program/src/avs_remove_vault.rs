@@ -2,10 +2,13 @@ use jito_restaking_core::{
     avs::SanitizedAvs, avs_vault_ticket::SanitizedAvsVaultTicket, config::SanitizedConfig,
 };
 use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use jito_vault_core::vault::SanitizedVault;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -24,7 +27,22 @@ pub fn process_avs_remove_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -
     let slot = Clock::get()?.slot;
     avs_vault_ticket.avs_vault_ticket_mut().deactivate(slot)?;
 
-    avs_vault_ticket.save()?;
+    msg!(
+        "AvsVaultTicketDeactivated: avs={} vault={} ticket={} index={} slot={}",
+        avs.account().key,
+        avs_vault_ticket.avs_vault_ticket().vault(),
+        avs_vault_ticket.account().key,
+        avs_vault_ticket.avs_vault_ticket().index(),
+        slot
+    );
+    RestakingEvent::AvsVaultTicketDeactivated {
+        avs: *avs.account().key,
+        vault: avs_vault_ticket.avs_vault_ticket().vault(),
+        ticket: *avs_vault_ticket.account().key,
+        index: avs_vault_ticket.avs_vault_ticket().index(),
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -43,16 +61,20 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let _config =
+        let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
-        let vault = next_account_info(accounts_iter)?;
+        let vault = SanitizedVault::sanitize(
+            &config.config().vault_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
         let avs_vault_ticket = SanitizedAvsVaultTicket::sanitize(
             program_id,
             next_account_info(accounts_iter)?,
             true,
             avs.account().key,
-            vault.key,
+            vault.account().key,
         )?;
         let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
 
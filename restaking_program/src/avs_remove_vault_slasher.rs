@@ -1,12 +1,15 @@
 use jito_restaking_core::{
     avs::SanitizedAvs, avs_vault_slasher_ticket::SanitizedAvsVaultSlasherTicket,
-    config::SanitizedConfig,
+    avs_vault_ticket::SanitizedAvsVaultTicket, config::SanitizedConfig,
 };
 use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use jito_vault_core::vault::SanitizedVault;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -15,25 +18,51 @@ use solana_program::{
 pub fn process_avs_remove_slasher(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let SanitizedAccounts {
         avs,
+        mut avs_vault_ticket,
         mut avs_vault_slasher_ticket,
         admin,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     avs.avs().check_slasher_admin(admin.account().key)?;
 
-    let clock = Clock::get()?;
+    let slot = Clock::get()?.slot;
 
     avs_vault_slasher_ticket
         .avs_vault_slasher_ticket_mut()
-        .deactivate(clock.slot)?;
+        .deactivate(slot)?;
+    avs_vault_ticket
+        .avs_vault_ticket_mut()
+        .decrement_slasher_count();
 
-    avs_vault_slasher_ticket.save()?;
+    msg!(
+        "AvsVaultSlasherTicketDeactivated: avs={} vault={} slasher={} ticket={} index={} slot={}",
+        avs.account().key,
+        avs_vault_slasher_ticket.avs_vault_slasher_ticket().vault(),
+        avs_vault_slasher_ticket
+            .avs_vault_slasher_ticket()
+            .slasher(),
+        avs_vault_slasher_ticket.account().key,
+        avs_vault_slasher_ticket.avs_vault_slasher_ticket().index(),
+        slot
+    );
+    RestakingEvent::AvsVaultSlasherTicketDeactivated {
+        avs: *avs.account().key,
+        vault: avs_vault_slasher_ticket.avs_vault_slasher_ticket().vault(),
+        slasher: avs_vault_slasher_ticket
+            .avs_vault_slasher_ticket()
+            .slasher(),
+        ticket: *avs_vault_slasher_ticket.account().key,
+        index: avs_vault_slasher_ticket.avs_vault_slasher_ticket().index(),
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
     avs: SanitizedAvs<'a, 'info>,
+    avs_vault_ticket: SanitizedAvsVaultTicket<'a, 'info>,
     avs_vault_slasher_ticket: SanitizedAvsVaultSlasherTicket<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
 }
@@ -46,23 +75,35 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let _config =
+        let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
-        let vault = next_account_info(accounts_iter)?;
+        let vault = SanitizedVault::sanitize(
+            &config.config().vault_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
         let slasher = next_account_info(accounts_iter)?;
+        let avs_vault_ticket = SanitizedAvsVaultTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            avs.account().key,
+            vault.account().key,
+        )?;
         let avs_vault_slasher_ticket = SanitizedAvsVaultSlasherTicket::sanitize(
             program_id,
             next_account_info(accounts_iter)?,
             true,
             avs.account().key,
-            vault.key,
+            vault.account().key,
             slasher.key,
         )?;
         let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
 
         Ok(SanitizedAccounts {
             avs,
+            avs_vault_ticket,
             avs_vault_slasher_ticket,
             admin,
         })
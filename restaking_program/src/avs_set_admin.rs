@@ -3,21 +3,33 @@ use jito_restaking_sanitization::signer::SanitizedSignerAccount;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+/// The AVS admin proposes a new admin. The transfer only completes once the new admin signs
+/// [`crate::RestakingInstruction::AvsAcceptAdmin`], so a mistyped key can't take over the AVS.
+/// If the AVS has a multisig, it shall sign as well.
+///
+/// Instruction: [`crate::RestakingInstruction::AvsSetAdmin`]
 pub fn process_avs_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let SanitizedAccounts {
         mut avs,
         old_admin,
         new_admin,
+        multisig_signers,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     avs.avs().check_admin(old_admin.account().key)?;
-    avs.avs_mut().set_admin(*new_admin.account().key);
+    avs.avs().check_multisig(multisig_signers)?;
+    avs.avs_mut().set_pending_admin(*new_admin.key);
 
-    avs.save()?;
+    msg!(
+        "AvsAdminProposed: avs={} pending_admin={}",
+        avs.account().key,
+        new_admin.key
+    );
 
     Ok(())
 }
@@ -25,7 +37,8 @@ pub fn process_avs_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
 struct SanitizedAccounts<'a, 'info> {
     avs: SanitizedAvs<'a, 'info>,
     old_admin: SanitizedSignerAccount<'a, 'info>,
-    new_admin: SanitizedSignerAccount<'a, 'info>,
+    new_admin: &'a AccountInfo<'info>,
+    multisig_signers: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
@@ -38,13 +51,15 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
         let avs = SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
         let old_admin =
             SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
-        let new_admin =
-            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let new_admin = next_account_info(&mut accounts_iter)?;
+        // checked against the multisig of the AVS
+        let multisig_signers = accounts_iter.as_slice();
 
         Ok(Self {
             avs,
             old_admin,
             new_admin,
+            multisig_signers,
         })
     }
 }
@@ -0,0 +1,56 @@
+use jito_restaking_core::{avs::SanitizedAvs, config::SanitizedConfig};
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The AVS operator admin sets the max number of slots an operator can go without a heartbeat
+/// before anyone can eject it from the AVS. Setting it to zero disables ejection, otherwise it
+/// shall be within [`jito_restaking_core::avs::MIN_MAX_HEARTBEAT_AGE`] and
+/// [`jito_restaking_core::avs::MAX_MAX_HEARTBEAT_AGE`].
+///
+/// [`crate::RestakingInstruction::AvsSetMaxHeartbeatAge`]
+pub fn process_avs_set_max_heartbeat_age(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_heartbeat_age: u64,
+) -> ProgramResult {
+    let SanitizedAccounts { mut avs, admin } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_operator_admin(admin.account().key)?;
+
+    msg!(
+        "Setting max heartbeat age on AVS {} to {} slots",
+        avs.account().key,
+        max_heartbeat_age
+    );
+    avs.avs_mut().set_max_heartbeat_age(max_heartbeat_age)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetMaxHeartbeatAge`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { avs, admin })
+    }
+}
@@ -0,0 +1,55 @@
+use jito_restaking_core::{avs::SanitizedAvs, config::SanitizedConfig};
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The AVS slasher admin sets the max number of active slashers the AVS allows for each vault.
+/// Lowering it below a vault's current count only stops new slashers from being added.
+///
+/// [`crate::RestakingInstruction::AvsSetMaxSlashersPerVault`]
+pub fn process_avs_set_max_slashers_per_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_slashers_per_vault: u64,
+) -> ProgramResult {
+    let SanitizedAccounts { mut avs, admin } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_slasher_admin(admin.account().key)?;
+
+    msg!(
+        "Setting max slashers per vault on AVS {} to {}",
+        avs.account().key,
+        max_slashers_per_vault
+    );
+    avs.avs_mut()
+        .set_max_slashers_per_vault(max_slashers_per_vault);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetMaxSlashersPerVault`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { avs, admin })
+    }
+}
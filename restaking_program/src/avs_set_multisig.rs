@@ -0,0 +1,75 @@
+use jito_restaking_core::avs::{SanitizedAvs, AVS_MULTISIG_SIGNERS};
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The AVS admin sets the multisig that shall co-sign the high-risk operations of the AVS: adding
+/// a vault slasher and changing admins. Once enabled, the multisig shall also sign any change to
+/// itself, so the admin key alone can't remove it.
+///
+/// [`crate::RestakingInstruction::AvsSetMultisig`]
+pub fn process_avs_set_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    signers: [Pubkey; AVS_MULTISIG_SIGNERS],
+    threshold: u8,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut avs,
+        admin,
+        multisig_signers,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_admin(admin.account().key)?;
+    avs.avs().check_multisig(multisig_signers)?;
+    avs.avs_mut().set_multisig(signers, threshold)?;
+
+    let signers = avs.avs().multisig_signers();
+    msg!(
+        "AvsMultisigSet: avs={} signers={:?} threshold={}",
+        avs.account().key,
+        signers,
+        threshold
+    );
+    RestakingEvent::AvsMultisigSet {
+        avs: *avs.account().key,
+        signers,
+        threshold,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    multisig_signers: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetMultisig`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        // checked against the multisig of the AVS
+        let multisig_signers = accounts_iter.as_slice();
+
+        Ok(SanitizedAccounts {
+            avs,
+            admin,
+            multisig_signers,
+        })
+    }
+}
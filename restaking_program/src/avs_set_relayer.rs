@@ -0,0 +1,59 @@
+use jito_restaking_core::avs::SanitizedAvs;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The AVS admin sets the relayer allowed to submit admin operations signed off-chain by the AVS
+/// admins, so those admins don't need to hold SOL for fees. Setting the default pubkey removes
+/// the relayer.
+///
+/// [`crate::RestakingInstruction::AvsSetRelayer`]
+pub fn process_avs_set_relayer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        mut avs,
+        admin,
+        relayer,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_admin(admin.account().key)?;
+
+    msg!(
+        "Setting relayer on AVS {} to {}",
+        avs.account().key,
+        relayer.key
+    );
+    avs.avs_mut().set_relayer(*relayer.key);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    relayer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetRelayer`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let relayer = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            admin,
+            relayer,
+        })
+    }
+}
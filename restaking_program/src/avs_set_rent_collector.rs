@@ -0,0 +1,61 @@
+use jito_restaking_core::avs::SanitizedAvs;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The AVS admin sets the account receiving the rent of its tickets closed with
+/// [`crate::RestakingInstruction::CloseTicket`].
+///
+/// [`crate::RestakingInstruction::AvsSetRentCollector`]
+pub fn process_avs_set_rent_collector(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut avs,
+        admin,
+        rent_collector,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_admin(admin.account().key)?;
+
+    msg!(
+        "Setting rent collector on AVS {} to {}",
+        avs.account().key,
+        rent_collector.key
+    );
+    avs.avs_mut().set_rent_collector(*rent_collector.key);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    rent_collector: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetRentCollector`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let rent_collector = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            admin,
+            rent_collector,
+        })
+    }
+}
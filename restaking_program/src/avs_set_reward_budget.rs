@@ -0,0 +1,96 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_reward_budget::SanitizedAvsRewardBudget, config::SanitizedConfig,
+};
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The AVS withdraw admin restarts the emission schedule of a reward budget from the current
+/// epoch. Epochs already paid out aren't paid again, and elapsed epochs that weren't paid out yet
+/// are dropped, so distribute them first to keep them.
+///
+/// [`crate::RestakingInstruction::AvsSetRewardBudget`]
+pub fn process_avs_set_reward_budget(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    tokens_per_epoch: u64,
+    decay_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        mut avs_reward_budget,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_withdraw_admin(admin.account().key)?;
+
+    let slot = Clock::get()?.slot;
+    let epoch = avs_reward_budget.avs_reward_budget().epoch(slot);
+    avs_reward_budget
+        .avs_reward_budget_mut()
+        .set_schedule(tokens_per_epoch, decay_bps, epoch)?;
+
+    let start_epoch = avs_reward_budget.avs_reward_budget().start_epoch();
+    msg!(
+        "AvsRewardBudgetUpdated: avs={} budget={} tokens_per_epoch={} decay_bps={} start_epoch={}",
+        avs.account().key,
+        avs_reward_budget.account().key,
+        tokens_per_epoch,
+        decay_bps,
+        start_epoch
+    );
+    RestakingEvent::AvsRewardBudgetUpdated {
+        avs: *avs.account().key,
+        budget: *avs_reward_budget.account().key,
+        tokens_per_epoch,
+        decay_bps,
+        start_epoch,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    avs_reward_budget: SanitizedAvsRewardBudget<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetRewardBudget`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs_reward_budget = next_account_info(accounts_iter)?;
+        let mint = next_account_info(accounts_iter)?;
+        let avs_reward_budget = SanitizedAvsRewardBudget::sanitize(
+            program_id,
+            avs_reward_budget,
+            true,
+            avs.account().key,
+            mint.key,
+        )?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            avs_reward_budget,
+            admin,
+        })
+    }
+}
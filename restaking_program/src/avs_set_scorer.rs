@@ -0,0 +1,55 @@
+use jito_restaking_core::avs::SanitizedAvs;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The AVS admin sets the key allowed to attest the performance scores of the operators of the
+/// AVS, typically an off-chain service monitoring them. Setting the default pubkey removes the
+/// scorer.
+///
+/// [`crate::RestakingInstruction::AvsSetScorer`]
+pub fn process_avs_set_scorer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        mut avs,
+        admin,
+        scorer,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_admin(admin.account().key)?;
+
+    msg!(
+        "Setting scorer on AVS {} to {}",
+        avs.account().key,
+        scorer.key
+    );
+    avs.avs_mut().set_scorer(*scorer.key);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    scorer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetScorer`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let scorer = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts { avs, admin, scorer })
+    }
+}
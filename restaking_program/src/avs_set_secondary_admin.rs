@@ -4,10 +4,16 @@ use jito_restaking_sdk::AvsAdminRole;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+/// The AVS admin hands one of the AVS's duties to another key, so it can be carried out without
+/// the primary admin key. The new admin doesn't need to sign, but the multisig of the AVS, if any,
+/// shall.
+///
+/// Instruction: [`crate::RestakingInstruction::AvsSetSecondaryAdmin`]
 pub fn process_avs_set_secondary_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -17,26 +23,39 @@ pub fn process_avs_set_secondary_admin(
         mut avs,
         admin,
         new_admin,
+        multisig_signers,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     avs.avs().check_admin(admin.account().key)?;
+    avs.avs().check_multisig(multisig_signers)?;
 
     match role {
         AvsAdminRole::Operator => {
-            avs.avs_mut().set_operator_admin(*new_admin.account().key);
+            avs.avs_mut().set_operator_admin(*new_admin.key);
         }
         AvsAdminRole::Vault => {
-            avs.avs_mut().set_vault_admin(*new_admin.account().key);
+            avs.avs_mut().set_vault_admin(*new_admin.key);
         }
         AvsAdminRole::Slasher => {
-            avs.avs_mut().set_slasher_admin(*new_admin.account().key);
+            avs.avs_mut().set_slasher_admin(*new_admin.key);
         }
         AvsAdminRole::Withdraw => {
-            avs.avs_mut().set_withdraw_admin(*new_admin.account().key);
+            avs.avs_mut().set_withdraw_admin(*new_admin.key);
+        }
+        AvsAdminRole::Metadata => {
+            avs.avs_mut().set_metadata_admin(*new_admin.key);
+        }
+        AvsAdminRole::SlashVeto => {
+            avs.avs_mut().set_slash_veto_admin(*new_admin.key);
         }
     }
 
-    avs.save()?;
+    msg!(
+        "AvsSecondaryAdminSet: avs={} role={:?} admin={}",
+        avs.account().key,
+        role,
+        new_admin.key
+    );
 
     Ok(())
 }
@@ -44,7 +63,8 @@ pub fn process_avs_set_secondary_admin(
 struct SanitizedAccounts<'a, 'info> {
     avs: SanitizedAvs<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
-    new_admin: SanitizedSignerAccount<'a, 'info>,
+    new_admin: &'a AccountInfo<'info>,
+    multisig_signers: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
@@ -57,13 +77,15 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
         let avs = SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
         let admin =
             SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
-        let new_admin =
-            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let new_admin = next_account_info(&mut accounts_iter)?;
+        // checked against the multisig of the AVS
+        let multisig_signers = accounts_iter.as_slice();
 
         Ok(Self {
             avs,
             admin,
             new_admin,
+            multisig_signers,
         })
     }
 }
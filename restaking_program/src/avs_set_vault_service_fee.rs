@@ -0,0 +1,86 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_vault_ticket::SanitizedAvsVaultTicket, config::SanitizedConfig,
+};
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::vault::SanitizedVault;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The AVS sets the service fee it charges a vault for the security it provides. The fee is
+/// only collected once the vault agrees to the same fee on the vault side, and only accrues from
+/// the later of the epochs the two sides set it in.
+///
+/// [`crate::RestakingInstruction::AvsSetVaultServiceFee`]
+pub fn process_avs_set_vault_service_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        mut avs_vault_ticket,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs().check_vault_admin(admin.account().key)?;
+
+    let slot = Clock::get()?.slot;
+    avs_vault_ticket.avs_vault_ticket().check_active(slot)?;
+
+    msg!(
+        "Setting service fee on AVS vault ticket {} to {} bps",
+        avs_vault_ticket.account().key,
+        fee_bps
+    );
+    avs_vault_ticket
+        .avs_vault_ticket_mut()
+        .set_service_fee_bps(fee_bps, slot)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    avs_vault_ticket: SanitizedAvsVaultTicket<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsSetVaultServiceFee`]
+    pub fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(
+            &config.config().vault_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
+        let avs_vault_ticket = SanitizedAvsVaultTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            avs.account().key,
+            vault.account().key,
+        )?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            avs_vault_ticket,
+            admin,
+        })
+    }
+}
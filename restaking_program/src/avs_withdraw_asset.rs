@@ -3,15 +3,25 @@ use jito_restaking_sanitization::{
     assert_with_msg, signer::SanitizedSignerAccount, token_account::SanitizedTokenAccount,
     token_program::SanitizedTokenProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    msg,
     program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
 use spl_token::instruction::transfer;
 
+/// The AVS withdraw admin moves tokens out of a token account owned by the AVS, signing for the
+/// AVS PDA. Tokens end up there when they're earned by the AVS, swept out of its tickets with
+/// [`crate::RestakingInstruction::SweepTokens`], or sent to it by mistake.
+///
+/// [`crate::RestakingInstruction::AvsWithdrawalAsset`]
 pub fn process_avs_withdraw_asset(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -29,12 +39,27 @@ pub fn process_avs_withdraw_asset(
 
     assert_with_msg(
         avs_token_account.token_account().amount >= amount,
-        ProgramError::InsufficientFunds,
+        RestakingError::AvsInsufficientFunds,
         "Not enough funds in AVS token account",
     )?;
 
     _withdraw_avs_asset(&avs, &avs_token_account, receiver_token_account, amount)?;
 
+    msg!(
+        "AvsAssetWithdrawn: avs={} mint={} receiver={} amount={}",
+        avs.account().key,
+        token_mint,
+        receiver_token_account.key,
+        amount
+    );
+    RestakingEvent::AvsAssetWithdrawn {
+        avs: *avs.account().key,
+        mint: token_mint,
+        receiver: *receiver_token_account.key,
+        amount,
+    }
+    .emit()?;
+
     Ok(())
 }
 
@@ -80,7 +105,7 @@ struct SanitizedAccounts<'a, 'info> {
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
-    /// [`jito_restaking_sdk::RestakingInstruction::AvsWithdrawalAsset`]
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::AvsWithdrawalAsset`]
     fn sanitize(
         program_id: &Pubkey,
         accounts: &'a [AccountInfo<'info>],
@@ -0,0 +1,198 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    reward_claim_status::RewardClaimStatus,
+    reward_distribution::{RewardDistribution, SanitizedRewardDistribution},
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount, create_account,
+    empty_account::EmptyAccount, result::SanitizationError, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram, token_account::SanitizedTokenAccount,
+    token_mint::SanitizedTokenMint, token_program::SanitizedTokenProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::transfer_checked;
+
+/// Pays out the reward of a claimant proven against the merkle root of the distribution. The
+/// claim status is created on the claim, so a second claim of the claimant fails. The reward is
+/// transferred with the token program owning the mint, SPL Token or Token-2022.
+///
+/// [`crate::RestakingInstruction::ClaimReward`]
+pub fn process_claim_reward(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut reward_distribution,
+        claim_status,
+        claimant,
+        mint,
+        distribution_token_account,
+        claimant_token_account,
+        payer,
+        token_program,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    reward_distribution
+        .reward_distribution_mut()
+        .claim(claimant.key, amount, &proof)?;
+
+    let (address, bump, mut seeds) = RewardClaimStatus::find_program_address(
+        program_id,
+        reward_distribution.account().key,
+        claimant.key,
+    );
+    seeds.push(vec![bump]);
+    assert_with_msg(
+        address == *claim_status.account().key,
+        RestakingError::RewardClaimStatusInvalidPda,
+        "Invalid reward claim status PDA",
+    )?;
+
+    let slot = Clock::get()?.slot;
+    let status = RewardClaimStatus::new(
+        *reward_distribution.account().key,
+        *claimant.key,
+        amount,
+        slot,
+        bump,
+    );
+    msg!("Creating reward claim status: {:?}", address);
+    let serialized = status.as_bytes();
+    create_account(
+        payer.account(),
+        claim_status.account(),
+        system_program.account(),
+        program_id,
+        &Rent::get()?,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    claim_status.account().data.borrow_mut()[..serialized.len()].copy_from_slice(serialized);
+
+    let distribution = *reward_distribution.reward_distribution();
+    let mut distribution_seeds = RewardDistribution::seeds(
+        &distribution.source(),
+        &distribution.mint(),
+        distribution.epoch(),
+    );
+    distribution_seeds.push(vec![distribution.bump()]);
+    let distribution_seeds_slice = distribution_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+    invoke_signed(
+        &transfer_checked(
+            token_program.account().key,
+            distribution_token_account.account().key,
+            mint.account().key,
+            claimant_token_account.account().key,
+            reward_distribution.account().key,
+            &[],
+            amount,
+            mint.mint().decimals,
+        )?,
+        &[
+            distribution_token_account.account().clone(),
+            mint.account().clone(),
+            claimant_token_account.account().clone(),
+            reward_distribution.account().clone(),
+        ],
+        &[distribution_seeds_slice.as_slice()],
+    )?;
+
+    msg!(
+        "RewardClaimed: distribution={} claimant={} amount={}",
+        reward_distribution.account().key,
+        claimant.key,
+        amount
+    );
+    RestakingEvent::RewardClaimed {
+        distribution: *reward_distribution.account().key,
+        claimant: *claimant.key,
+        amount,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    reward_distribution: SanitizedRewardDistribution<'a, 'info>,
+    claim_status: EmptyAccount<'a, 'info>,
+    claimant: &'a AccountInfo<'info>,
+    mint: SanitizedTokenMint<'a, 'info>,
+    distribution_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    claimant_token_account: SanitizedTokenAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::ClaimReward`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let reward_distribution = SanitizedRewardDistribution::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+        )?;
+        let claim_status = EmptyAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let claimant = next_account_info(&mut accounts_iter)?;
+
+        let mint = SanitizedTokenMint::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        assert_with_msg(
+            *mint.account().key == reward_distribution.reward_distribution().mint(),
+            SanitizationError::TokenAccountInvalidMint,
+            "Mint does not match the reward distribution mint",
+        )?;
+        let distribution_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            mint.account().key,
+            reward_distribution.account().key,
+        )?;
+        let claimant_token_account = SanitizedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            mint.account().key,
+            claimant.key,
+        )?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let token_program =
+            SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            reward_distribution,
+            claim_status,
+            claimant,
+            mint,
+            distribution_token_account,
+            claimant_token_account,
+            payer,
+            token_program,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,205 @@
+use borsh::BorshDeserialize;
+use jito_jsm_core::slot_toggled_field::{SlotToggle, SlotToggleState};
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket, config::SanitizedConfig, operator::SanitizedOperator,
+    operator_vault_ticket::OperatorVaultTicket, AccountType,
+};
+use jito_restaking_sanitization::{close_program_account, signer::SanitizedSignerAccount};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Closes an AVS or operator ticket once it's inactive, after cooling down for the rest of the
+/// epoch it was deactivated in, so its rent isn't locked up forever. The admin of the AVS or
+/// operator that created the ticket signs, the rent goes to the rent collector it set and the
+/// parent counts the closed ticket for the health checks. Tickets between an AVS and an operator
+/// are closed by [`crate::RestakingInstruction::FinalizeOperatorAvsRemoval`] instead.
+///
+/// [`crate::RestakingInstruction::CloseTicket`]
+pub fn process_close_ticket(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        ticket_epoch_length,
+        parent,
+        ticket,
+        rent_collector,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let account_type = AccountType::deserialize(&mut ticket.data.borrow().as_ref())
+        .map_err(|_| RestakingError::AccountInvalidType)?;
+    let (state, expected_rent_collector) = match account_type {
+        AccountType::AvsOperatorTicket
+        | AccountType::AvsVaultTicket
+        | AccountType::AvsVaultSlasherTicket => {
+            let mut avs = SanitizedAvs::sanitize(program_id, parent, true)?;
+            avs.avs().check_admin(admin.account().key)?;
+            let avs_rent_collector = avs.avs().rent_collector();
+            let state = avs_ticket_state(program_id, ticket, avs.account().key, account_type)?;
+            if account_type == AccountType::AvsVaultTicket {
+                avs.avs_mut().increment_closed_vault_count()?;
+            } else {
+                avs.avs_mut().increment_closed_slasher_count()?;
+            }
+
+            (state, avs_rent_collector)
+        }
+        AccountType::OperatorAvsTicket | AccountType::OperatorVaultTicket => {
+            let mut operator = SanitizedOperator::sanitize(program_id, parent, true)?;
+            operator.operator().check_admin(admin.account().key)?;
+            let operator_rent_collector = operator.operator().rent_collector();
+            let state =
+                operator_ticket_state(program_id, ticket, operator.account().key, account_type)?;
+            operator.operator_mut().increment_closed_vault_count()?;
+
+            (state, operator_rent_collector)
+        }
+        _ => {
+            msg!("Only AVS and operator tickets can be closed");
+            return Err(RestakingError::TicketNotClosable.into());
+        }
+    };
+
+    if *rent_collector.key != expected_rent_collector {
+        msg!(
+            "Rent collector {} doesn't match {}",
+            rent_collector.key,
+            expected_rent_collector
+        );
+        return Err(RestakingError::RentCollectorMismatch.into());
+    }
+
+    let slot = Clock::get()?.slot;
+    if state.state(slot, ticket_epoch_length) != SlotToggleState::Inactive {
+        msg!("Ticket {} is active or still cooling down", ticket.key);
+        return Err(RestakingError::TicketNotDeactivated.into());
+    }
+
+    let lamports = ticket.lamports();
+    close_program_account(ticket, rent_collector)?;
+
+    msg!(
+        "TicketClosed: parent={} ticket={} rent_collector={} lamports={}",
+        parent.key,
+        ticket.key,
+        rent_collector.key,
+        lamports
+    );
+    RestakingEvent::TicketClosed {
+        parent: *parent.key,
+        ticket: *ticket.key,
+        rent_collector: *rent_collector.key,
+        lamports,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Verifies an AVS ticket belongs to the AVS and returns its state
+fn avs_ticket_state(
+    program_id: &Pubkey,
+    ticket: &AccountInfo,
+    avs: &Pubkey,
+    account_type: AccountType,
+) -> Result<SlotToggle, ProgramError> {
+    let state = match account_type {
+        AccountType::AvsOperatorTicket => {
+            msg!("AVS operator tickets are closed when the removal of the operator is finalized");
+            return Err(RestakingError::TicketNotClosable.into());
+        }
+        AccountType::AvsVaultTicket => {
+            let vault = AvsVaultTicket::deserialize(&mut ticket.data.borrow().as_ref())?.vault();
+            *AvsVaultTicket::deserialize_checked(program_id, ticket, avs, &vault)?.state()
+        }
+        AccountType::AvsVaultSlasherTicket => {
+            let slasher_ticket =
+                AvsVaultSlasherTicket::deserialize(&mut ticket.data.borrow().as_ref())?;
+            let (vault, slasher) = (slasher_ticket.vault(), slasher_ticket.slasher());
+            *AvsVaultSlasherTicket::deserialize_checked(program_id, ticket, avs, &vault, &slasher)?
+                .state()
+        }
+        _ => return Err(RestakingError::NotAvsTicket.into()),
+    };
+    Ok(state)
+}
+
+/// Verifies an operator ticket belongs to the operator and returns its state
+fn operator_ticket_state(
+    program_id: &Pubkey,
+    ticket: &AccountInfo,
+    operator: &Pubkey,
+    account_type: AccountType,
+) -> Result<SlotToggle, ProgramError> {
+    let state = match account_type {
+        AccountType::OperatorAvsTicket => {
+            msg!("Operator AVS tickets are closed when the removal from the AVS is finalized");
+            return Err(RestakingError::TicketNotClosable.into());
+        }
+        AccountType::OperatorVaultTicket => {
+            let vault =
+                OperatorVaultTicket::deserialize(&mut ticket.data.borrow().as_ref())?.vault();
+            *OperatorVaultTicket::deserialize_checked(program_id, ticket, operator, &vault)?.state()
+        }
+        _ => return Err(RestakingError::NotOperatorTicket.into()),
+    };
+    Ok(state)
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    ticket_epoch_length: u64,
+    parent: &'a AccountInfo<'info>,
+    ticket: &'a AccountInfo<'info>,
+    rent_collector: &'a AccountInfo<'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::CloseTicket`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let ticket_epoch_length = config.config().ticket_epoch_length();
+        // checked against the ticket type in the processor
+        let parent = next_account_info(accounts_iter)?;
+        let ticket = next_account_info(accounts_iter)?;
+        if ticket.owner != program_id {
+            msg!(
+                "Account {} is not owned by the restaking program",
+                ticket.key
+            );
+            return Err(RestakingError::AccountInvalidProgramOwner.into());
+        }
+        if !ticket.is_writable {
+            return Err(RestakingError::AccountExpectedWritable.into());
+        }
+        let rent_collector = next_account_info(accounts_iter)?;
+        if !rent_collector.is_writable {
+            return Err(RestakingError::AccountExpectedWritable.into());
+        }
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            ticket_epoch_length,
+            parent,
+            ticket,
+            rent_collector,
+            admin,
+        })
+    }
+}
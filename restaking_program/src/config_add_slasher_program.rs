@@ -0,0 +1,51 @@
+use jito_restaking_core::config::SanitizedConfig;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin approves a program to own slasher accounts. While at least one program is
+/// approved, [`crate::RestakingInstruction::AvsAddVaultSlasher`] rejects slashers owned by any
+/// other program, which keeps an AVS admin from registering a wallet as a slasher by mistake.
+///
+/// [`crate::RestakingInstruction::ConfigAddSlasherProgram`]
+pub fn process_config_add_slasher_program(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    program: Pubkey,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!("Approving slasher program {}", program);
+    config.config_mut().add_slasher_program(program)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::ConfigAddSlasherProgram`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
@@ -0,0 +1,50 @@
+use jito_restaking_core::config::SanitizedConfig;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin removes a program from the approved slasher programs. Slashers already added
+/// are left untouched. Removing the last program lets AVS admins add slashers owned by any program.
+///
+/// [`crate::RestakingInstruction::ConfigRemoveSlasherProgram`]
+pub fn process_config_remove_slasher_program(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    program: Pubkey,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!("Removing slasher program {}", program);
+    config.config_mut().remove_slasher_program(&program)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::ConfigRemoveSlasherProgram`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
@@ -0,0 +1,58 @@
+use jito_restaking_core::config::SanitizedConfig;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin sets the max fee an operator can charge with
+/// [`crate::RestakingInstruction::SetOperatorFee`] and the slots an operator waits between two
+/// fee changes, so stakers have time to react before a fee goes up again.
+///
+/// [`crate::RestakingInstruction::ConfigSetOperatorFeeParams`]
+pub fn process_config_set_operator_fee_params(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_operator_fee_bps: u16,
+    operator_fee_cooldown_slots: u64,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!(
+        "Setting operator fee params to max {} bps and a cooldown of {} slots",
+        max_operator_fee_bps,
+        operator_fee_cooldown_slots
+    );
+    config
+        .config_mut()
+        .set_operator_fee_params(max_operator_fee_bps, operator_fee_cooldown_slots)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::ConfigSetOperatorFeeParams`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
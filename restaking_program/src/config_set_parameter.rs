@@ -0,0 +1,63 @@
+use jito_restaking_core::config::SanitizedConfig;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::ConfigParameter;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin sets a deployment parameter, within the bounds of its setter on
+/// [`jito_restaking_core::config::Config`], so deployments can be tuned without changing the
+/// program. The limits apply to relationships created afterwards.
+///
+/// [`crate::RestakingInstruction::ConfigSetParameter`]
+pub fn process_config_set_parameter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    parameter: ConfigParameter,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!("Setting config parameter {:?}", parameter);
+    let mut config = config.config_mut();
+    match parameter {
+        ConfigParameter::MaxOperatorsPerAvs(max_operators_per_avs) => {
+            config.set_max_operators_per_avs(max_operators_per_avs)?;
+        }
+        ConfigParameter::MaxVaultsPerOperator(max_vaults_per_operator) => {
+            config.set_max_vaults_per_operator(max_vaults_per_operator)?;
+        }
+        ConfigParameter::DefaultMaxSlashersPerVault(default_max_slashers_per_vault) => {
+            config.set_default_max_slashers_per_vault(default_max_slashers_per_vault)?;
+        }
+    }
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::ConfigSetParameter`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
@@ -0,0 +1,55 @@
+use jito_restaking_core::config::SanitizedConfig;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin sets the epoch length relationship tickets warm up and cool down over. A
+/// ticket added in an epoch only counts as active once the next epoch starts, and a ticket removed
+/// in an epoch keeps cooling down until then, so neither side can toggle a relationship within an
+/// epoch. The length shall be within [`jito_restaking_core::config::MIN_TICKET_EPOCH_LENGTH`] and
+/// [`jito_restaking_core::config::MAX_TICKET_EPOCH_LENGTH`].
+///
+/// [`crate::RestakingInstruction::ConfigSetTicketEpochLength`]
+pub fn process_config_set_ticket_epoch_length(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    ticket_epoch_length: u64,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!("Setting the ticket epoch length to {}", ticket_epoch_length);
+    config
+        .config_mut()
+        .set_ticket_epoch_length(ticket_epoch_length)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::ConfigSetTicketEpochLength`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
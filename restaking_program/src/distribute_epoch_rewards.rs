@@ -0,0 +1,169 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    config::SanitizedConfig,
+    epoch_reward_router::{EpochRewardRouter, SanitizedEpochRewardRouter},
+    epoch_reward_snapshot::SanitizedEpochRewardSnapshot,
+};
+use jito_restaking_sanitization::{
+    associated_token_account::SanitizedAssociatedTokenAccount, token_program::SanitizedTokenProgram,
+};
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::instruction::transfer;
+
+/// Pays out the pro-rata share of the router rewards of a snapshot once the epoch ended: the
+/// operator fee goes to the operator token account and the rest to the vault token account, both
+/// the associated token accounts of the mint.
+///
+/// [`crate::RestakingInstruction::DistributeEpochRewards`]
+pub fn process_distribute_epoch_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut epoch_reward_router,
+        mut epoch_reward_snapshot,
+        router_token_account,
+        operator_token_account,
+        vault_token_account,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+    epoch_reward_router
+        .epoch_reward_router()
+        .check_ended(slot)?;
+
+    let stake = epoch_reward_snapshot.epoch_reward_snapshot().stake();
+    let share = epoch_reward_router.epoch_reward_router().share(stake)?;
+    let (operator_amount, vault_amount) = epoch_reward_snapshot
+        .epoch_reward_snapshot_mut()
+        .distribute(share)?;
+    epoch_reward_router
+        .epoch_reward_router_mut()
+        .record_distribution(share)?;
+
+    let router = *epoch_reward_router.epoch_reward_router();
+    let mut router_seeds = EpochRewardRouter::seeds(&router.avs(), &router.mint(), router.epoch());
+    router_seeds.push(vec![router.bump()]);
+    let router_seeds_slice = router_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+
+    for (destination, amount) in [
+        (&operator_token_account, operator_amount),
+        (&vault_token_account, vault_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        invoke_signed(
+            &transfer(
+                &spl_token::id(),
+                router_token_account.account().key,
+                destination.account().key,
+                epoch_reward_router.account().key,
+                &[],
+                amount,
+            )?,
+            &[
+                router_token_account.account().clone(),
+                destination.account().clone(),
+                epoch_reward_router.account().clone(),
+            ],
+            &[router_seeds_slice.as_slice()],
+        )?;
+    }
+
+    let snapshot = *epoch_reward_snapshot.epoch_reward_snapshot();
+    msg!(
+        "EpochRewardsDistributed: router={} vault={} operator={} operator_amount={} vault_amount={}",
+        epoch_reward_router.account().key,
+        snapshot.vault(),
+        snapshot.operator(),
+        operator_amount,
+        vault_amount
+    );
+    RestakingEvent::EpochRewardsDistributed {
+        router: *epoch_reward_router.account().key,
+        vault: snapshot.vault(),
+        operator: snapshot.operator(),
+        operator_amount,
+        vault_amount,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    epoch_reward_router: SanitizedEpochRewardRouter<'a, 'info>,
+    epoch_reward_snapshot: SanitizedEpochRewardSnapshot<'a, 'info>,
+    router_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    operator_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::DistributeEpochRewards`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs =
+            SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let epoch_reward_router = SanitizedEpochRewardRouter::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+            avs.account().key,
+        )?;
+        let epoch_reward_snapshot = SanitizedEpochRewardSnapshot::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+            epoch_reward_router.account().key,
+        )?;
+
+        let mint = epoch_reward_router.epoch_reward_router().mint();
+        let snapshot = *epoch_reward_snapshot.epoch_reward_snapshot();
+        let router_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            &mint,
+            epoch_reward_router.account().key,
+        )?;
+        let operator_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            &mint,
+            &snapshot.operator(),
+        )?;
+        let vault_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            &mint,
+            &snapshot.vault(),
+        )?;
+        let _token_program =
+            SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            epoch_reward_router,
+            epoch_reward_snapshot,
+            router_token_account,
+            operator_token_account,
+            vault_token_account,
+        })
+    }
+}
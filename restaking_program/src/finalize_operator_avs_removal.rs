@@ -0,0 +1,265 @@
+use borsh::BorshDeserialize;
+use jito_jsm_core::slot_toggled_field::{SlotToggle, SlotToggleState};
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_operator_ticket::SanitizedAvsOperatorTicket,
+    avs_vault_ticket::AvsVaultTicket, config::SanitizedConfig, operator::SanitizedOperator,
+    operator_avs_ticket::SanitizedOperatorAvsTicket, result::RestakingCoreError,
+};
+use jito_restaking_sanitization::{assert_with_msg, close_program_account};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::{
+    result::VaultCoreError, vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::assert_avs_healthy::check_ticket_indices;
+
+/// Finalizes the removal of an operator from an AVS once the removed ticket cooled down, no vault
+/// securing the AVS delegates to the operator and no slash filed for the AVS is pending in its
+/// vaults. Both tickets are closed, returning their rent to the rent collectors of the AVS and the
+/// operator. Every vault ticket of the AVS not closed is passed in as remaining accounts, as its
+/// AVS vault ticket, delegation list and vault AVS ticket. The delegation of a vault whose AVS
+/// vault ticket went inactive isn't at stake for the AVS, so it doesn't block. The vault AVS
+/// ticket of a vault that never added the AVS is passed in empty. An operator ejected for missing
+/// heartbeats isn't removed, as the crank can still reactivate it. Anyone can call it.
+///
+/// [`crate::RestakingInstruction::FinalizeOperatorAvsRemoval`]
+pub fn process_finalize_operator_avs_removal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        ticket_epoch_length,
+        vault_program,
+        mut avs,
+        mut operator,
+        avs_operator_ticket,
+        operator_avs_ticket,
+        avs_rent_collector,
+        operator_rent_collector,
+        vault_accounts,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+    let operator_key = operator.account().key;
+    let mut vault_indices = Vec::with_capacity(vault_accounts.len() / 3);
+    let mut vault_accounts_iter = vault_accounts.iter();
+    while let Some(avs_vault_ticket) = vault_accounts_iter.next() {
+        let vault =
+            AvsVaultTicket::deserialize(&mut avs_vault_ticket.data.borrow().as_ref())?.vault();
+        let ticket = AvsVaultTicket::deserialize_checked(
+            program_id,
+            avs_vault_ticket,
+            avs.account().key,
+            &vault,
+        )?;
+        vault_indices.push(ticket.index());
+
+        let delegation_list = VaultDelegationList::deserialize_checked(
+            &vault_program,
+            next_account_info(&mut vault_accounts_iter)?,
+            &vault,
+        )?;
+        let secures_vault =
+            ticket.state().state(slot, ticket_epoch_length) != SlotToggleState::Inactive;
+        if let Some(delegation) = delegation_list
+            .delegations()
+            .iter()
+            .find(|delegation| delegation.operator() == *operator_key)
+            .filter(|_| secures_vault)
+        {
+            assert_with_msg(
+                delegation.active_amount() == 0
+                    && delegation.cooling_down_amount() == 0
+                    && delegation.enqueued_for_cooldown_amount() == 0,
+                RestakingError::OperatorAvsRemovalDelegated,
+                &format!("Vault {} still delegates to the operator", vault),
+            )?;
+        }
+
+        let vault_avs_ticket = next_account_info(&mut vault_accounts_iter)?;
+        if vault_avs_ticket.data_is_empty() {
+            let expected =
+                VaultAvsTicket::find_program_address(&vault_program, &vault, avs.account().key).0;
+            if *vault_avs_ticket.key != expected {
+                msg!(
+                    "Vault AVS ticket {} doesn't match {}",
+                    vault_avs_ticket.key,
+                    expected
+                );
+                return Err(VaultCoreError::VaultAvsTicketInvalidPda.into());
+            }
+        } else {
+            let ticket = VaultAvsTicket::deserialize_checked(
+                &vault_program,
+                vault_avs_ticket,
+                &vault,
+                avs.account().key,
+            )?;
+            assert_with_msg(
+                ticket.pending_slash_proposals() == 0,
+                RestakingError::OperatorAvsRemovalSlashPending,
+                &format!("A slash filed for the AVS is pending in vault {}", vault),
+            )?;
+        }
+    }
+    check_ticket_indices(
+        vault_indices,
+        avs.avs().vault_count(),
+        avs.avs().closed_vault_count(),
+        RestakingCoreError::AvsVaultCountMismatch,
+    )?;
+
+    // An ejected ticket is deactivated too, but stays reactivatable until the AVS removes it
+    let avs_state = *avs_operator_ticket.avs_operator_ticket().state();
+    let avs_removed =
+        avs_state.is_deactivated() && !avs_operator_ticket.avs_operator_ticket().ejected();
+    let operator_state = *operator_avs_ticket.operator_avs_ticket().state();
+    if !avs_removed && !operator_state.is_deactivated() {
+        msg!("Neither the AVS nor the operator removed the other, an ejection isn't a removal");
+        return Err(RestakingCoreError::AvsOperatorTicketNotCoolingDown.into());
+    }
+    check_cooled_down(
+        avs_operator_ticket.account(),
+        &avs_state,
+        slot,
+        ticket_epoch_length,
+    )?;
+    check_cooled_down(
+        operator_avs_ticket.account(),
+        &operator_state,
+        slot,
+        ticket_epoch_length,
+    )?;
+
+    close_program_account(avs_operator_ticket.account(), avs_rent_collector)?;
+    close_program_account(operator_avs_ticket.account(), operator_rent_collector)?;
+    avs.avs_mut().increment_closed_operator_count()?;
+    operator.operator_mut().increment_closed_avs_count()?;
+
+    msg!(
+        "OperatorAvsRemovalFinalized: avs={} operator={} slot={}",
+        avs.account().key,
+        operator_key,
+        slot
+    );
+    RestakingEvent::OperatorAvsRemovalFinalized {
+        avs: *avs.account().key,
+        operator: *operator_key,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// A removed ticket shall have cooled down for the rest of the epoch it was deactivated in
+fn check_cooled_down(
+    ticket: &AccountInfo,
+    state: &SlotToggle,
+    slot: u64,
+    ticket_epoch_length: u64,
+) -> ProgramResult {
+    if state.is_deactivated() && state.state(slot, ticket_epoch_length) != SlotToggleState::Inactive
+    {
+        msg!("Ticket {} is still cooling down", ticket.key);
+        return Err(RestakingError::TicketNotDeactivated.into());
+    }
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    ticket_epoch_length: u64,
+    vault_program: Pubkey,
+    avs: SanitizedAvs<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    avs_operator_ticket: SanitizedAvsOperatorTicket<'a, 'info>,
+    operator_avs_ticket: SanitizedOperatorAvsTicket<'a, 'info>,
+    avs_rent_collector: &'a AccountInfo<'info>,
+    operator_rent_collector: &'a AccountInfo<'info>,
+    vault_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::FinalizeOperatorAvsRemoval`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let ticket_epoch_length = config.config().ticket_epoch_length();
+        let vault_program = config.config().vault_program();
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let avs_operator_ticket = SanitizedAvsOperatorTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+            avs.account().key,
+            operator.account().key,
+        )?;
+        let operator_avs_ticket = SanitizedOperatorAvsTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+            operator.account().key,
+            avs.account().key,
+        )?;
+        let avs_rent_collector = rent_collector(
+            next_account_info(&mut accounts_iter)?,
+            avs.avs().rent_collector(),
+        )?;
+        let operator_rent_collector = rent_collector(
+            next_account_info(&mut accounts_iter)?,
+            operator.operator().rent_collector(),
+        )?;
+        let vault_accounts = accounts_iter.as_slice();
+        if vault_accounts.len() % 3 != 0 {
+            msg!("Expected an AVS vault ticket, delegation list and vault AVS ticket per vault");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        Ok(SanitizedAccounts {
+            ticket_epoch_length,
+            vault_program,
+            avs,
+            operator,
+            avs_operator_ticket,
+            operator_avs_ticket,
+            avs_rent_collector,
+            operator_rent_collector,
+            vault_accounts,
+        })
+    }
+}
+
+/// The rent collector shall be the one the AVS or operator set, and writable
+fn rent_collector<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+    expected: Pubkey,
+) -> Result<&'a AccountInfo<'info>, ProgramError> {
+    if *account.key != expected {
+        msg!("Rent collector {} doesn't match {}", account.key, expected);
+        return Err(RestakingError::RentCollectorMismatch.into());
+    }
+    if !account.is_writable {
+        return Err(RestakingError::AccountExpectedWritable.into());
+    }
+    Ok(account)
+}
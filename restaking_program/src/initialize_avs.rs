@@ -1,9 +1,13 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{avs::Avs, config::SanitizedConfig};
 use jito_restaking_sanitization::{
     assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
     system_program::SanitizedSystemProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -22,10 +26,12 @@ pub fn process_initialize_avs(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         avs_account,
         admin,
         base,
+        payer,
         system_program,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     let rent = Rent::get()?;
+    let index = config.config().avs_count();
 
     _create_avs(
         program_id,
@@ -33,22 +39,32 @@ pub fn process_initialize_avs(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         &avs_account,
         &base,
         &admin,
+        &payer,
         &system_program,
         &rent,
     )?;
 
     config.config_mut().increment_avs()?;
-    config.save()?;
+
+    RestakingEvent::AvsInitialized {
+        avs: *avs_account.account().key,
+        admin: *admin.account().key,
+        base: *base.account().key,
+        index,
+    }
+    .emit()?;
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn _create_avs<'a, 'info>(
     program_id: &Pubkey,
     config: &SanitizedConfig,
     avs_account: &EmptyAccount<'a, 'info>,
     base: &SanitizedSignerAccount<'a, 'info>,
     admin: &SanitizedSignerAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
 ) -> ProgramResult {
@@ -57,11 +73,11 @@ fn _create_avs<'a, 'info>(
     avs_seeds.push(vec![avs_bump]);
     assert_with_msg(
         expected_avs_pubkey == *avs_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::AvsInvalidPda,
         "AVS account is not at the correct PDA",
     )?;
 
-    let avs = Avs::new(
+    let mut avs = Avs::new(
         *base.account().key,
         *admin.account().key,
         *admin.account().key,
@@ -71,11 +87,12 @@ fn _create_avs<'a, 'info>(
         config.config().avs_count(),
         avs_bump,
     );
+    avs.set_max_slashers_per_vault(config.config().default_max_slashers_per_vault());
 
     msg!("Initializing AVS @ address {}", avs_account.account().key);
-    let serialized_avs = avs.try_to_vec()?;
+    let serialized_avs = avs.as_bytes();
     create_account(
-        admin.account(),
+        payer.account(),
         avs_account.account(),
         system_program.account(),
         program_id,
@@ -83,8 +100,7 @@ fn _create_avs<'a, 'info>(
         serialized_avs.len() as u64,
         &avs_seeds,
     )?;
-    avs_account.account().data.borrow_mut()[..serialized_avs.len()]
-        .copy_from_slice(&serialized_avs);
+    avs_account.account().data.borrow_mut()[..serialized_avs.len()].copy_from_slice(serialized_avs);
 
     Ok(())
 }
@@ -94,6 +110,7 @@ struct SanitizedAccounts<'a, 'info> {
     avs_account: EmptyAccount<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
     base: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
 }
 
@@ -107,8 +124,9 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
         let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
         let avs_account = EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
-        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
         let base = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
         let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
 
         Ok(SanitizedAccounts {
@@ -116,6 +134,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             avs_account,
             admin,
             base,
+            payer,
             system_program,
         })
     }
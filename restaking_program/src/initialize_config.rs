@@ -1,9 +1,14 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::config::Config;
 use jito_restaking_sanitization::{
-    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    assert_with_msg, create_account, empty_account::EmptyAccount,
+    program_data::SanitizedProgramData, signer::SanitizedSignerAccount,
     system_program::SanitizedSystemProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -15,20 +20,26 @@ use solana_program::{
 };
 
 /// Initializes the global configuration for the restaking program
+/// The admin shall be the program's upgrade authority.
 /// [`crate::RestakingInstruction::InitializeConfig`]
 pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let SanitizedAccounts {
         config_account,
         admin,
         vault_program,
+        payer,
         system_program,
-    } = SanitizedAccounts::sanitize(accounts)?;
+        program_data,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    // the config is at a fixed address, so anyone could initialize it first on a fresh deployment
+    program_data.check_upgrade_authority(admin.account().key)?;
 
     let (expected_config_key, bump, mut config_seeds) = Config::find_program_address(program_id);
     config_seeds.push(vec![bump]);
     assert_with_msg(
         expected_config_key == *config_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::ConfigInvalidPda,
         "Config account is not at the correct PDA",
     )?;
 
@@ -38,9 +49,9 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
         "Initializing config @ address {}",
         config_account.account().key
     );
-    let config_serialized = config.try_to_vec()?;
+    let config_serialized = config.as_bytes();
     create_account(
-        admin.account(),
+        payer.account(),
         config_account.account(),
         system_program.account(),
         program_id,
@@ -49,7 +60,14 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
         &config_seeds,
     )?;
     config_account.account().data.borrow_mut()[..config_serialized.len()]
-        .copy_from_slice(&config_serialized);
+        .copy_from_slice(config_serialized);
+
+    RestakingEvent::ConfigInitialized {
+        config: *config_account.account().key,
+        admin: *admin.account().key,
+        vault_program: *vault_program.key,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -58,26 +76,35 @@ struct SanitizedAccounts<'a, 'info> {
     config_account: EmptyAccount<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
     vault_program: &'a AccountInfo<'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
+    program_data: SanitizedProgramData<'a, 'info>,
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     fn sanitize(
+        program_id: &Pubkey,
         accounts: &'a [AccountInfo<'info>],
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let mut accounts_iter = accounts.iter();
 
         let config_account = EmptyAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
-        let admin = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
         let vault_program = next_account_info(&mut accounts_iter)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
         let system_program =
             SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+        let program_data =
+            SanitizedProgramData::sanitize(next_account_info(&mut accounts_iter)?, program_id)?;
 
         Ok(SanitizedAccounts {
             config_account,
             admin,
             vault_program,
+            payer,
             system_program,
+            program_data,
         })
     }
 }
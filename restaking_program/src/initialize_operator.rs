@@ -1,9 +1,13 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{config::SanitizedConfig, operator::Operator};
 use jito_restaking_sanitization::{
     assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
     system_program::SanitizedSystemProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -22,10 +26,12 @@ pub fn process_initialize_operator(program_id: &Pubkey, accounts: &[AccountInfo]
         operator_account,
         admin,
         base,
+        payer,
         system_program,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     let rent = Rent::get()?;
+    let index = config.config().operators_count();
 
     _create_operator(
         program_id,
@@ -33,22 +39,32 @@ pub fn process_initialize_operator(program_id: &Pubkey, accounts: &[AccountInfo]
         &operator_account,
         &base,
         &admin,
+        &payer,
         &system_program,
         &rent,
     )?;
 
     config.config_mut().increment_operators()?;
-    config.save()?;
+
+    RestakingEvent::OperatorInitialized {
+        operator: *operator_account.account().key,
+        admin: *admin.account().key,
+        base: *base.account().key,
+        index,
+    }
+    .emit()?;
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn _create_operator<'a, 'info>(
     program_id: &Pubkey,
     config: &SanitizedConfig,
     operator_account: &EmptyAccount<'a, 'info>,
     base: &SanitizedSignerAccount<'a, 'info>,
     admin: &SanitizedSignerAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
 ) -> ProgramResult {
@@ -57,7 +73,7 @@ fn _create_operator<'a, 'info>(
     operator_seeds.push(vec![operator_bump]);
     assert_with_msg(
         expected_operator_pubkey == *operator_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::OperatorInvalidPda,
         "Operator account is not at the correct PDA",
     )?;
 
@@ -69,9 +85,9 @@ fn _create_operator<'a, 'info>(
         operator_bump,
     );
 
-    let serialized_operator = operator.try_to_vec()?;
+    let serialized_operator = operator.as_bytes();
     create_account(
-        admin.account(),
+        payer.account(),
         operator_account.account(),
         system_program.account(),
         program_id,
@@ -80,7 +96,7 @@ fn _create_operator<'a, 'info>(
         &operator_seeds,
     )?;
     operator_account.account().data.borrow_mut()[..serialized_operator.len()]
-        .copy_from_slice(&serialized_operator);
+        .copy_from_slice(serialized_operator);
 
     Ok(())
 }
@@ -90,6 +106,7 @@ struct SanitizedAccounts<'a, 'info> {
     operator_account: EmptyAccount<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
     base: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
 }
 
@@ -105,8 +122,9 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
         let operator_account = EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
 
-        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
         let base = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
         let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
 
         Ok(SanitizedAccounts {
@@ -114,6 +132,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             operator_account,
             admin,
             base,
+            payer,
             system_program,
         })
     }
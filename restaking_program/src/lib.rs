@@ -1,22 +1,70 @@
+mod archive_epoch_stake;
+mod assert_avs_healthy;
+mod assert_operator_healthy;
+mod avs_accept_admin;
 mod avs_add_operator;
 mod avs_add_vault;
 mod avs_add_vault_slasher;
+mod avs_attest_operator_score;
+mod avs_deposit_epoch_rewards;
+mod avs_distribute_rewards;
+mod avs_eject_stale_operator;
+mod avs_initialize_reward_budget;
+mod avs_reactivate_operator;
+mod avs_reclaim_epoch_rewards;
 mod avs_remove_operator;
 mod avs_remove_vault;
 mod avs_remove_vault_slasher;
 mod avs_set_admin;
+mod avs_set_max_heartbeat_age;
+mod avs_set_max_slashers_per_vault;
+mod avs_set_multisig;
+mod avs_set_relayer;
+mod avs_set_rent_collector;
+mod avs_set_reward_budget;
+mod avs_set_scorer;
 mod avs_set_secondary_admin;
+mod avs_set_vault_service_fee;
 mod avs_withdraw_asset;
+mod claim_reward;
+mod close_ticket;
+mod config_accept_admin;
+mod config_add_slasher_program;
+mod config_remove_slasher_program;
+mod config_set_admin;
+mod config_set_operator_fee_params;
+mod config_set_parameter;
+mod config_set_ticket_epoch_length;
+mod distribute_epoch_rewards;
+mod finalize_operator_avs_removal;
 mod initialize_avs;
 mod initialize_config;
 mod initialize_operator;
+mod migrate_account;
+mod operator_accept_admin;
 mod operator_add_avs;
 mod operator_add_vault;
+mod operator_exit_all;
+mod operator_heartbeat;
 mod operator_remove_avs;
 mod operator_remove_vault;
 mod operator_set_admin;
+mod operator_set_avs_capacity;
+mod operator_set_relayer;
+mod operator_set_rent_collector;
+mod operator_set_secondary_admin;
 mod operator_set_voter;
 mod operator_withdrawal_asset;
+mod post_reward_root;
+mod relay_admin_operation;
+mod set_operator_fee;
+mod snapshot_epoch_reward_stake;
+mod snapshot_epoch_stake;
+mod sweep_lamports;
+mod sweep_tokens;
+mod top_up_rent;
+mod update_avs_metadata;
+mod update_operator_metadata;
 
 use borsh::BorshDeserialize;
 use jito_restaking_sdk::RestakingInstruction;
@@ -28,19 +76,59 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
-    avs_add_operator::process_avs_add_operator, avs_add_vault::process_avs_add_vault,
-    avs_add_vault_slasher::process_avs_add_vault_slasher,
+    archive_epoch_stake::process_archive_epoch_stake,
+    assert_avs_healthy::process_assert_avs_healthy,
+    assert_operator_healthy::process_assert_operator_healthy,
+    avs_accept_admin::process_avs_accept_admin, avs_add_operator::process_avs_add_operator,
+    avs_add_vault::process_avs_add_vault, avs_add_vault_slasher::process_avs_add_vault_slasher,
+    avs_attest_operator_score::process_avs_attest_operator_score,
+    avs_deposit_epoch_rewards::process_avs_deposit_epoch_rewards,
+    avs_distribute_rewards::process_avs_distribute_rewards,
+    avs_eject_stale_operator::process_avs_eject_stale_operator,
+    avs_initialize_reward_budget::process_avs_initialize_reward_budget,
+    avs_reactivate_operator::process_avs_reactivate_operator,
+    avs_reclaim_epoch_rewards::process_avs_reclaim_epoch_rewards,
     avs_remove_operator::process_avs_remove_operator, avs_remove_vault::process_avs_remove_vault,
     avs_remove_vault_slasher::process_avs_remove_slasher, avs_set_admin::process_avs_set_admin,
+    avs_set_max_heartbeat_age::process_avs_set_max_heartbeat_age,
+    avs_set_max_slashers_per_vault::process_avs_set_max_slashers_per_vault,
+    avs_set_multisig::process_avs_set_multisig, avs_set_relayer::process_avs_set_relayer,
+    avs_set_rent_collector::process_avs_set_rent_collector,
+    avs_set_reward_budget::process_avs_set_reward_budget, avs_set_scorer::process_avs_set_scorer,
     avs_set_secondary_admin::process_avs_set_secondary_admin,
-    avs_withdraw_asset::process_avs_withdraw_asset, initialize_avs::process_initialize_avs,
-    initialize_config::process_initialize_config, initialize_operator::process_initialize_operator,
+    avs_set_vault_service_fee::process_avs_set_vault_service_fee,
+    avs_withdraw_asset::process_avs_withdraw_asset, claim_reward::process_claim_reward,
+    close_ticket::process_close_ticket, config_accept_admin::process_config_accept_admin,
+    config_add_slasher_program::process_config_add_slasher_program,
+    config_remove_slasher_program::process_config_remove_slasher_program,
+    config_set_admin::process_config_set_admin,
+    config_set_operator_fee_params::process_config_set_operator_fee_params,
+    config_set_parameter::process_config_set_parameter,
+    config_set_ticket_epoch_length::process_config_set_ticket_epoch_length,
+    distribute_epoch_rewards::process_distribute_epoch_rewards,
+    finalize_operator_avs_removal::process_finalize_operator_avs_removal,
+    initialize_avs::process_initialize_avs, initialize_config::process_initialize_config,
+    initialize_operator::process_initialize_operator, migrate_account::process_migrate_account,
+    operator_accept_admin::process_operator_accept_admin,
     operator_add_avs::process_operator_add_avs, operator_add_vault::process_operator_add_vault,
+    operator_exit_all::process_operator_exit_all, operator_heartbeat::process_operator_heartbeat,
     operator_remove_avs::process_operator_remove_avs,
     operator_remove_vault::process_operator_remove_vault,
     operator_set_admin::process_set_node_operator_admin,
+    operator_set_avs_capacity::process_operator_set_avs_capacity,
+    operator_set_relayer::process_operator_set_relayer,
+    operator_set_rent_collector::process_operator_set_rent_collector,
+    operator_set_secondary_admin::process_operator_set_secondary_admin,
     operator_set_voter::process_set_node_operator_voter,
     operator_withdrawal_asset::process_operator_withdrawal_asset,
+    post_reward_root::process_post_reward_root,
+    relay_admin_operation::process_relay_admin_operation,
+    set_operator_fee::process_set_operator_fee,
+    snapshot_epoch_reward_stake::process_snapshot_epoch_reward_stake,
+    snapshot_epoch_stake::process_snapshot_epoch_stake, sweep_lamports::process_sweep_lamports,
+    sweep_tokens::process_sweep_tokens, top_up_rent::process_top_up_rent,
+    update_avs_metadata::process_update_avs_metadata,
+    update_operator_metadata::process_update_operator_metadata,
 };
 
 declare_id!("E5YF9Um1mwQWHffqaUEUwtwnhQKsbMEt33qtvjto3NDZ");
@@ -76,6 +164,14 @@ pub fn process_instruction(
             msg!("Instruction: InitializeConfig");
             process_initialize_config(program_id, accounts)
         }
+        RestakingInstruction::ConfigSetAdmin => {
+            msg!("Instruction: ConfigSetAdmin");
+            process_config_set_admin(program_id, accounts)
+        }
+        RestakingInstruction::ConfigAcceptAdmin => {
+            msg!("Instruction: ConfigAcceptAdmin");
+            process_config_accept_admin(program_id, accounts)
+        }
         RestakingInstruction::InitializeAvs => {
             msg!("Instruction: InitializeAvs");
             process_initialize_avs(program_id, accounts)
@@ -96,9 +192,17 @@ pub fn process_instruction(
             msg!("Instruction: AvsRemoveOperator");
             process_avs_remove_operator(program_id, accounts)
         }
-        RestakingInstruction::AvsAddVaultSlasher(max_slashable_per_epoch) => {
+        RestakingInstruction::AvsAddVaultSlasher {
+            max_slashable_per_epoch,
+            metadata_hash,
+        } => {
             msg!("Instruction: AvsAddVaultSlasher");
-            process_avs_add_vault_slasher(program_id, accounts, max_slashable_per_epoch)
+            process_avs_add_vault_slasher(
+                program_id,
+                accounts,
+                max_slashable_per_epoch,
+                metadata_hash,
+            )
         }
         RestakingInstruction::AvsRemoveVaultSlasher => {
             msg!("Instruction: AvsRemoveVaultSlasher");
@@ -108,6 +212,10 @@ pub fn process_instruction(
             msg!("Instruction: AvsSetAdmin");
             process_avs_set_admin(program_id, accounts)
         }
+        RestakingInstruction::AvsAcceptAdmin => {
+            msg!("Instruction: AvsAcceptAdmin");
+            process_avs_accept_admin(program_id, accounts)
+        }
         RestakingInstruction::AvsSetSecondaryAdmin(role) => {
             msg!("Instruction: AvsSetSecondaryAdmin");
             process_avs_set_secondary_admin(program_id, accounts, role)
@@ -120,6 +228,10 @@ pub fn process_instruction(
             msg!("Instruction: OperatorSetAdmin");
             process_set_node_operator_admin(program_id, accounts)
         }
+        RestakingInstruction::OperatorAcceptAdmin => {
+            msg!("Instruction: OperatorAcceptAdmin");
+            process_operator_accept_admin(program_id, accounts)
+        }
         RestakingInstruction::OperatorSetVoter => {
             msg!("Instruction: OperatorSetVoter");
             process_set_node_operator_voter(program_id, accounts)
@@ -148,5 +260,218 @@ pub fn process_instruction(
             msg!("Instruction: OperatorWithdrawalAsset");
             process_operator_withdrawal_asset(program_id, accounts, token_mint, amount)
         }
+        RestakingInstruction::AvsSetVaultServiceFee { fee_bps } => {
+            msg!("Instruction: AvsSetVaultServiceFee");
+            process_avs_set_vault_service_fee(program_id, accounts, fee_bps)
+        }
+        RestakingInstruction::OperatorHeartbeat => {
+            msg!("Instruction: OperatorHeartbeat");
+            process_operator_heartbeat(program_id, accounts)
+        }
+        RestakingInstruction::AvsSetMaxHeartbeatAge { max_heartbeat_age } => {
+            msg!("Instruction: AvsSetMaxHeartbeatAge");
+            process_avs_set_max_heartbeat_age(program_id, accounts, max_heartbeat_age)
+        }
+        RestakingInstruction::AvsEjectStaleOperator => {
+            msg!("Instruction: AvsEjectStaleOperator");
+            process_avs_eject_stale_operator(program_id, accounts)
+        }
+        RestakingInstruction::AvsReactivateOperator => {
+            msg!("Instruction: AvsReactivateOperator");
+            process_avs_reactivate_operator(program_id, accounts)
+        }
+        RestakingInstruction::OperatorExitAll => {
+            msg!("Instruction: OperatorExitAll");
+            process_operator_exit_all(program_id, accounts)
+        }
+        RestakingInstruction::AssertAvsHealthy => {
+            msg!("Instruction: AssertAvsHealthy");
+            process_assert_avs_healthy(program_id, accounts)
+        }
+        RestakingInstruction::AssertOperatorHealthy => {
+            msg!("Instruction: AssertOperatorHealthy");
+            process_assert_operator_healthy(program_id, accounts)
+        }
+        RestakingInstruction::ConfigAddSlasherProgram { program } => {
+            msg!("Instruction: ConfigAddSlasherProgram");
+            process_config_add_slasher_program(program_id, accounts, program)
+        }
+        RestakingInstruction::ConfigRemoveSlasherProgram { program } => {
+            msg!("Instruction: ConfigRemoveSlasherProgram");
+            process_config_remove_slasher_program(program_id, accounts, program)
+        }
+        RestakingInstruction::SweepTokens { token_mint } => {
+            msg!("Instruction: SweepTokens");
+            process_sweep_tokens(program_id, accounts, token_mint)
+        }
+        RestakingInstruction::TopUpRent => {
+            msg!("Instruction: TopUpRent");
+            process_top_up_rent(program_id, accounts)
+        }
+        RestakingInstruction::SweepLamports => {
+            msg!("Instruction: SweepLamports");
+            process_sweep_lamports(program_id, accounts)
+        }
+        RestakingInstruction::AvsSetRelayer => {
+            msg!("Instruction: AvsSetRelayer");
+            process_avs_set_relayer(program_id, accounts)
+        }
+        RestakingInstruction::OperatorSetRelayer => {
+            msg!("Instruction: OperatorSetRelayer");
+            process_operator_set_relayer(program_id, accounts)
+        }
+        RestakingInstruction::RelayAdminOperation { nonce, operation } => {
+            msg!("Instruction: RelayAdminOperation");
+            process_relay_admin_operation(program_id, accounts, nonce, operation)
+        }
+        RestakingInstruction::AvsSetMaxSlashersPerVault {
+            max_slashers_per_vault,
+        } => {
+            msg!("Instruction: AvsSetMaxSlashersPerVault");
+            process_avs_set_max_slashers_per_vault(program_id, accounts, max_slashers_per_vault)
+        }
+        RestakingInstruction::ConfigSetTicketEpochLength {
+            ticket_epoch_length,
+        } => {
+            msg!("Instruction: ConfigSetTicketEpochLength");
+            process_config_set_ticket_epoch_length(program_id, accounts, ticket_epoch_length)
+        }
+        RestakingInstruction::OperatorSetSecondaryAdmin(role) => {
+            msg!("Instruction: OperatorSetSecondaryAdmin");
+            process_operator_set_secondary_admin(program_id, accounts, role)
+        }
+        RestakingInstruction::AvsInitializeRewardBudget {
+            epoch_length,
+            tokens_per_epoch,
+            decay_bps,
+        } => {
+            msg!("Instruction: AvsInitializeRewardBudget");
+            process_avs_initialize_reward_budget(
+                program_id,
+                accounts,
+                epoch_length,
+                tokens_per_epoch,
+                decay_bps,
+            )
+        }
+        RestakingInstruction::AvsSetRewardBudget {
+            tokens_per_epoch,
+            decay_bps,
+        } => {
+            msg!("Instruction: AvsSetRewardBudget");
+            process_avs_set_reward_budget(program_id, accounts, tokens_per_epoch, decay_bps)
+        }
+        RestakingInstruction::AvsDistributeRewards => {
+            msg!("Instruction: AvsDistributeRewards");
+            process_avs_distribute_rewards(program_id, accounts)
+        }
+        RestakingInstruction::AvsSetMultisig { signers, threshold } => {
+            msg!("Instruction: AvsSetMultisig");
+            process_avs_set_multisig(program_id, accounts, signers, threshold)
+        }
+        RestakingInstruction::AvsSetRentCollector => {
+            msg!("Instruction: AvsSetRentCollector");
+            process_avs_set_rent_collector(program_id, accounts)
+        }
+        RestakingInstruction::OperatorSetRentCollector => {
+            msg!("Instruction: OperatorSetRentCollector");
+            process_operator_set_rent_collector(program_id, accounts)
+        }
+        RestakingInstruction::CloseTicket => {
+            msg!("Instruction: CloseTicket");
+            process_close_ticket(program_id, accounts)
+        }
+        RestakingInstruction::UpdateAvsMetadata { name, uri, icon } => {
+            msg!("Instruction: UpdateAvsMetadata");
+            process_update_avs_metadata(program_id, accounts, name, uri, icon)
+        }
+        RestakingInstruction::UpdateOperatorMetadata { name, uri, icon } => {
+            msg!("Instruction: UpdateOperatorMetadata");
+            process_update_operator_metadata(program_id, accounts, name, uri, icon)
+        }
+        RestakingInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        RestakingInstruction::OperatorSetAvsCapacity { capacity } => {
+            msg!("Instruction: OperatorSetAvsCapacity");
+            process_operator_set_avs_capacity(program_id, accounts, capacity)
+        }
+        RestakingInstruction::ConfigSetOperatorFeeParams {
+            max_operator_fee_bps,
+            operator_fee_cooldown_slots,
+        } => {
+            msg!("Instruction: ConfigSetOperatorFeeParams");
+            process_config_set_operator_fee_params(
+                program_id,
+                accounts,
+                max_operator_fee_bps,
+                operator_fee_cooldown_slots,
+            )
+        }
+        RestakingInstruction::SetOperatorFee { operator_fee_bps } => {
+            msg!("Instruction: SetOperatorFee");
+            process_set_operator_fee(program_id, accounts, operator_fee_bps)
+        }
+        RestakingInstruction::AvsDepositEpochRewards { epoch, amount } => {
+            msg!("Instruction: AvsDepositEpochRewards");
+            process_avs_deposit_epoch_rewards(program_id, accounts, epoch, amount)
+        }
+        RestakingInstruction::SnapshotEpochRewardStake => {
+            msg!("Instruction: SnapshotEpochRewardStake");
+            process_snapshot_epoch_reward_stake(program_id, accounts)
+        }
+        RestakingInstruction::DistributeEpochRewards => {
+            msg!("Instruction: DistributeEpochRewards");
+            process_distribute_epoch_rewards(program_id, accounts)
+        }
+        RestakingInstruction::AvsReclaimEpochRewards => {
+            msg!("Instruction: AvsReclaimEpochRewards");
+            process_avs_reclaim_epoch_rewards(program_id, accounts)
+        }
+        RestakingInstruction::SnapshotEpochStake => {
+            msg!("Instruction: SnapshotEpochStake");
+            process_snapshot_epoch_stake(program_id, accounts)
+        }
+        RestakingInstruction::AvsSetScorer => {
+            msg!("Instruction: AvsSetScorer");
+            process_avs_set_scorer(program_id, accounts)
+        }
+        RestakingInstruction::AvsAttestOperatorScore { score_bps } => {
+            msg!("Instruction: AvsAttestOperatorScore");
+            process_avs_attest_operator_score(program_id, accounts, score_bps)
+        }
+        RestakingInstruction::ArchiveEpochStake => {
+            msg!("Instruction: ArchiveEpochStake");
+            process_archive_epoch_stake(program_id, accounts)
+        }
+        RestakingInstruction::ConfigSetParameter(parameter) => {
+            msg!("Instruction: ConfigSetParameter");
+            process_config_set_parameter(program_id, accounts, parameter)
+        }
+        RestakingInstruction::PostRewardRoot {
+            epoch,
+            merkle_root,
+            max_total_claim,
+            max_num_nodes,
+        } => {
+            msg!("Instruction: PostRewardRoot");
+            process_post_reward_root(
+                program_id,
+                accounts,
+                epoch,
+                merkle_root,
+                max_total_claim,
+                max_num_nodes,
+            )
+        }
+        RestakingInstruction::ClaimReward { amount, proof } => {
+            msg!("Instruction: ClaimReward");
+            process_claim_reward(program_id, accounts, amount, proof)
+        }
+        RestakingInstruction::FinalizeOperatorAvsRemoval => {
+            msg!("Instruction: FinalizeOperatorAvsRemoval");
+            process_finalize_operator_avs_removal(program_id, accounts)
+        }
     }
 }
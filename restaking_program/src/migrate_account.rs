@@ -0,0 +1,101 @@
+use borsh::BorshDeserialize;
+use jito_restaking_core::{migration, AccountType};
+use jito_restaking_sanitization::{
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram, top_up_rent,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Anyone can migrate an account written by an earlier version of the program to its current
+/// layout and version, paying for the rent of the grown account. Accounts already at their
+/// current layout and version are rejected, so batches replayed after a partial run fail loudly instead of paying fees for
+/// nothing.
+///
+/// [`crate::RestakingInstruction::MigrateAccount`]
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        account,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let account_type = AccountType::deserialize(&mut account.data.borrow().as_ref())
+        .map_err(|_| RestakingError::AccountInvalidType)?;
+    let from_len = account.data_len();
+    let migrated = migration::migrate(account_type, &account.data.borrow())?;
+    if *migrated == **account.data.borrow() {
+        msg!("Account {} is already at its current layout", account.key);
+        return Err(RestakingError::AccountAlreadyMigrated.into());
+    }
+
+    account.realloc(migrated.len(), false)?;
+    account.data.borrow_mut().copy_from_slice(&migrated);
+    top_up_rent(
+        account,
+        payer.account(),
+        system_program.account(),
+        &Rent::get()?,
+    )?;
+
+    msg!(
+        "AccountMigrated: account={} from_len={} to_len={}",
+        account.key,
+        from_len,
+        migrated.len()
+    );
+    RestakingEvent::AccountMigrated {
+        account: *account.key,
+        from_len: from_len as u64,
+        to_len: migrated.len() as u64,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::MigrateAccount`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let account = next_account_info(accounts_iter)?;
+        if account.owner != program_id {
+            msg!(
+                "Account {} is not owned by the restaking program",
+                account.key
+            );
+            return Err(RestakingError::AccountInvalidProgramOwner.into());
+        }
+        if !account.is_writable {
+            return Err(RestakingError::AccountExpectedWritable.into());
+        }
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            account,
+            payer,
+            system_program,
+        })
+    }
+}
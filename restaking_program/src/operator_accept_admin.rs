@@ -0,0 +1,59 @@
+use jito_restaking_core::operator::SanitizedOperator;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The admin proposed with [`crate::RestakingInstruction::OperatorSetAdmin`] takes over the
+/// node operator.
+///
+/// Instruction: [`crate::RestakingInstruction::OperatorAcceptAdmin`]
+pub fn process_operator_accept_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut operator,
+        new_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator
+        .operator_mut()
+        .accept_admin(new_admin.account().key)?;
+
+    msg!(
+        "OperatorAdminAccepted: operator={} admin={}",
+        operator.account().key,
+        new_admin.account().key
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    new_admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorAcceptAdmin`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let new_admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            operator,
+            new_admin,
+        })
+    }
+}
@@ -1,11 +1,15 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{
     avs::SanitizedAvs, config::SanitizedConfig, operator::SanitizedOperator,
     operator_avs_ticket::OperatorAvsTicket,
 };
 use jito_restaking_sanitization::{
-    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
-    system_program::SanitizedSystemProgram,
+    assert_with_msg, empty_account::EmptyAccount, rent_payer::RentPayer,
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
 };
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -35,6 +39,7 @@ pub fn process_operator_add_avs(program_id: &Pubkey, accounts: &[AccountInfo]) -
     operator.operator().check_avs_admin(admin.account().key)?;
 
     let slot = Clock::get()?.slot;
+    let index = operator.operator().avs_count();
     let rent = Rent::get()?;
     _create_operator_avs_ticket(
         program_id,
@@ -49,7 +54,22 @@ pub fn process_operator_add_avs(program_id: &Pubkey, accounts: &[AccountInfo]) -
 
     operator.operator_mut().increment_avs_count()?;
 
-    operator.save()?;
+    msg!(
+        "OperatorAvsTicketCreated: operator={} avs={} ticket={} index={} activation_slot={}",
+        operator.account().key,
+        avs.account().key,
+        operator_avs_ticket_account.account().key,
+        index,
+        slot
+    );
+    RestakingEvent::OperatorAvsTicketCreated {
+        operator: *operator.account().key,
+        avs: *avs.account().key,
+        ticket: *operator_avs_ticket_account.account().key,
+        index,
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -60,7 +80,7 @@ fn _create_operator_avs_ticket<'a, 'info>(
     operator: &SanitizedOperator<'a, 'info>,
     avs: &SanitizedAvs<'a, 'info>,
     operator_avs_ticket_account: &EmptyAccount<'a, 'info>,
-    payer: &SanitizedSignerAccount<'a, 'info>,
+    payer: &RentPayer<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
     slot: u64,
@@ -74,7 +94,7 @@ fn _create_operator_avs_ticket<'a, 'info>(
 
     assert_with_msg(
         address == *operator_avs_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::OperatorAvsTicketInvalidPda,
         "Invalid operator AVS ticket PDA",
     )?;
 
@@ -90,9 +110,8 @@ fn _create_operator_avs_ticket<'a, 'info>(
         "Creating operator AVS ticket: {:?}",
         operator_avs_ticket_account.account().key
     );
-    let serialized = operator_avs_ticket.try_to_vec()?;
-    create_account(
-        payer.account(),
+    let serialized = operator_avs_ticket.as_bytes();
+    payer.create_account(
         operator_avs_ticket_account.account(),
         system_program.account(),
         program_id,
@@ -101,7 +120,7 @@ fn _create_operator_avs_ticket<'a, 'info>(
         &seeds,
     )?;
     operator_avs_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
@@ -110,7 +129,7 @@ struct SanitizedAccounts<'a, 'info> {
     avs: SanitizedAvs<'a, 'info>,
     operator_avs_ticket_account: EmptyAccount<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
-    payer: SanitizedSignerAccount<'a, 'info>,
+    payer: RentPayer<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
 }
 
@@ -132,7 +151,8 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             EmptyAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
         let admin =
             SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
-        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let payer =
+            RentPayer::sanitize(next_account_info(&mut accounts_iter)?, operator.account())?;
         let system_program =
             SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
 
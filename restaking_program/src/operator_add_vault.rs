@@ -1,12 +1,17 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{
     config::SanitizedConfig, operator::SanitizedOperator,
     operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_restaking_sanitization::{
-    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
-    system_program::SanitizedSystemProgram,
+    assert_with_msg, empty_account::EmptyAccount, rent_payer::RentPayer,
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::vault::SanitizedVault;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -25,6 +30,7 @@ use solana_program::{
 /// [`crate::RestakingInstruction::OperatorAddVault`]
 pub fn process_operator_add_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let SanitizedAccounts {
+        config,
         mut operator,
         vault,
         operator_vault_ticket_account,
@@ -36,12 +42,14 @@ pub fn process_operator_add_vault(program_id: &Pubkey, accounts: &[AccountInfo])
     operator.operator().check_vault_admin(admin.account().key)?;
 
     let slot = Clock::get()?.slot;
+    let index = operator.operator().vault_count();
+    config.config().check_operator_vault_limit(index)?;
     let rent = Rent::get()?;
 
     _create_operator_vault_ticket(
         program_id,
         &operator,
-        vault,
+        &vault,
         &operator_vault_ticket_account,
         &payer,
         &system_program,
@@ -51,7 +59,22 @@ pub fn process_operator_add_vault(program_id: &Pubkey, accounts: &[AccountInfo])
 
     operator.operator_mut().increment_vault_count()?;
 
-    operator.save()?;
+    msg!(
+        "OperatorVaultTicketCreated: operator={} vault={} ticket={} index={} activation_slot={}",
+        operator.account().key,
+        vault.account().key,
+        operator_vault_ticket_account.account().key,
+        index,
+        slot
+    );
+    RestakingEvent::OperatorVaultTicketCreated {
+        operator: *operator.account().key,
+        vault: *vault.account().key,
+        ticket: *operator_vault_ticket_account.account().key,
+        index,
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -60,26 +83,29 @@ pub fn process_operator_add_vault(program_id: &Pubkey, accounts: &[AccountInfo])
 fn _create_operator_vault_ticket<'a, 'info>(
     program_id: &Pubkey,
     operator: &SanitizedOperator<'a, 'info>,
-    vault: &AccountInfo<'info>,
+    vault: &SanitizedVault<'a, 'info>,
     operator_vault_ticket_account: &EmptyAccount<'a, 'info>,
-    payer: &SanitizedSignerAccount<'a, 'info>,
+    payer: &RentPayer<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
     slot: u64,
 ) -> ProgramResult {
-    let (address, bump, mut seeds) =
-        OperatorVaultTicket::find_program_address(program_id, operator.account().key, vault.key);
+    let (address, bump, mut seeds) = OperatorVaultTicket::find_program_address(
+        program_id,
+        operator.account().key,
+        vault.account().key,
+    );
     seeds.push(vec![bump]);
 
     assert_with_msg(
         address == *operator_vault_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        RestakingError::OperatorVaultTicketInvalidPda,
         "Invalid operator vault ticket PDA",
     )?;
 
     let operator_vault_ticket = OperatorVaultTicket::new(
         *operator.account().key,
-        *vault.key,
+        *vault.account().key,
         operator.operator().vault_count(),
         slot,
         bump,
@@ -89,9 +115,8 @@ fn _create_operator_vault_ticket<'a, 'info>(
         "Creating operator vault ticket: {:?}",
         operator_vault_ticket_account.account().key
     );
-    let serialized = operator_vault_ticket.try_to_vec()?;
-    create_account(
-        payer.account(),
+    let serialized = operator_vault_ticket.as_bytes();
+    payer.create_account(
         operator_vault_ticket_account.account(),
         system_program.account(),
         program_id,
@@ -100,16 +125,17 @@ fn _create_operator_vault_ticket<'a, 'info>(
         &seeds,
     )?;
     operator_vault_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
     operator: SanitizedOperator<'a, 'info>,
-    vault: &'a AccountInfo<'info>,
+    vault: SanitizedVault<'a, 'info>,
     operator_vault_ticket_account: EmptyAccount<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
-    payer: SanitizedSignerAccount<'a, 'info>,
+    payer: RentPayer<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
 }
 
@@ -121,18 +147,23 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let _config =
+        let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let operator =
             SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
-        let vault = next_account_info(accounts_iter)?;
+        let vault = SanitizedVault::sanitize(
+            &config.config().vault_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
         let operator_vault_ticket_account =
             EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
         let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
-        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let payer = RentPayer::sanitize(next_account_info(accounts_iter)?, operator.account())?;
         let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
 
         Ok(SanitizedAccounts {
+            config,
             operator,
             vault,
             operator_vault_ticket_account,
@@ -0,0 +1,157 @@
+use jito_jsm_core::{
+    remaining_accounts::{create_address, RemainingAccountsSpec},
+    zero_copy::ZeroCopy,
+};
+use jito_restaking_core::{
+    config::SanitizedConfig, operator::SanitizedOperator, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Begins cooldown on every [`OperatorAvsTicket`] and [`OperatorVaultTicket`] passed in as
+/// remaining accounts, for operators winding down. Tickets that are already inactive are
+/// skipped so the instruction can be retried with the same accounts. The tickets are validated
+/// as a list first, so a ticket of another operator or one passed twice fails the instruction
+/// with the index of the ticket.
+///
+/// [`crate::RestakingInstruction::OperatorExitAll`]
+pub fn process_operator_exit_all(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        operator,
+        admin,
+        tickets,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator.operator().check_admin(admin.account().key)?;
+
+    let operator_key = operator.account().key;
+    RemainingAccountsSpec {
+        owner: program_id,
+        discriminators: &[
+            &[OperatorAvsTicket::DISCRIMINATOR],
+            &[OperatorVaultTicket::DISCRIMINATOR],
+        ],
+        writable: true,
+    }
+    .validate(tickets, |ticket| {
+        operator_ticket_address(program_id, operator_key, ticket)
+    })?;
+
+    let slot = Clock::get()?.slot;
+
+    for ticket in tickets {
+        let mut data = ticket.data.borrow_mut();
+        if data[0] == OperatorAvsTicket::DISCRIMINATOR {
+            let operator_avs_ticket = OperatorAvsTicket::load_mut(&mut data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            if operator_avs_ticket.deactivate(slot).is_err() {
+                continue;
+            }
+            let (avs, index) = (operator_avs_ticket.avs(), operator_avs_ticket.index());
+            drop(data);
+
+            msg!(
+                "OperatorAvsTicketDeactivated: operator={} avs={} ticket={} index={} slot={}",
+                operator_key,
+                avs,
+                ticket.key,
+                index,
+                slot
+            );
+            RestakingEvent::OperatorAvsTicketDeactivated {
+                operator: *operator_key,
+                avs,
+                ticket: *ticket.key,
+                index,
+                slot,
+            }
+            .emit()?;
+        } else {
+            let operator_vault_ticket = OperatorVaultTicket::load_mut(&mut data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            if operator_vault_ticket.deactivate(slot).is_err() {
+                continue;
+            }
+            let (vault, index) = (operator_vault_ticket.vault(), operator_vault_ticket.index());
+            drop(data);
+
+            msg!(
+                "OperatorVaultTicketDeactivated: operator={} vault={} ticket={} index={} slot={}",
+                operator_key,
+                vault,
+                ticket.key,
+                index,
+                slot
+            );
+            RestakingEvent::OperatorVaultTicketDeactivated {
+                operator: *operator_key,
+                vault,
+                ticket: *ticket.key,
+                index,
+                slot,
+            }
+            .emit()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The address the operator's ticket derives to from the AVS or vault and the bump it stores
+fn operator_ticket_address(program_id: &Pubkey, operator: &Pubkey, ticket: &AccountInfo) -> Pubkey {
+    let data = ticket.data.borrow();
+    if let Ok(ticket) = OperatorAvsTicket::load(&data) {
+        create_address(
+            program_id,
+            OperatorAvsTicket::seeds(operator, &ticket.avs()),
+            ticket.bump(),
+        )
+    } else if let Ok(ticket) = OperatorVaultTicket::load(&data) {
+        create_address(
+            program_id,
+            OperatorVaultTicket::seeds(operator, &ticket.vault()),
+            ticket.bump(),
+        )
+    } else {
+        Pubkey::default()
+    }
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    tickets: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorExitAll`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let tickets = accounts_iter.as_slice();
+
+        Ok(SanitizedAccounts {
+            operator,
+            admin,
+            tickets,
+        })
+    }
+}
@@ -0,0 +1,187 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    config::SanitizedConfig,
+    operator::SanitizedOperator,
+    operator_avs_heartbeat::{OperatorAvsHeartbeat, SanitizedOperatorAvsHeartbeat},
+    operator_avs_ticket::SanitizedOperatorAvsTicket,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// The operator's voter, or the session key the operator admin granted, signals the operator is
+/// online for an AVS.
+/// The heartbeat account is created on the first heartbeat and updated afterwards.
+///
+/// [`crate::RestakingInstruction::OperatorHeartbeat`]
+pub fn process_operator_heartbeat(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        operator,
+        avs,
+        operator_avs_ticket,
+        operator_avs_heartbeat_account,
+        voter,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+    let ticket_epoch_length = config.config().ticket_epoch_length();
+    operator
+        .operator()
+        .check_heartbeat_signer(voter.account().key, slot)?;
+
+    operator_avs_ticket
+        .operator_avs_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+
+    if operator_avs_heartbeat_account.data_is_empty() {
+        let operator_avs_heartbeat_account =
+            EmptyAccount::sanitize(operator_avs_heartbeat_account, true)?;
+        _create_operator_avs_heartbeat(
+            program_id,
+            &operator,
+            &avs,
+            &operator_avs_heartbeat_account,
+            &payer,
+            &system_program,
+            &Rent::get()?,
+            slot,
+        )?;
+    } else {
+        let mut operator_avs_heartbeat = SanitizedOperatorAvsHeartbeat::sanitize(
+            program_id,
+            operator_avs_heartbeat_account,
+            true,
+            operator.account().key,
+            avs.account().key,
+        )?;
+        operator_avs_heartbeat
+            .operator_avs_heartbeat_mut()
+            .record_heartbeat(slot)?;
+    }
+
+    RestakingEvent::OperatorHeartbeat {
+        operator: *operator.account().key,
+        avs: *avs.account().key,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn _create_operator_avs_heartbeat<'a, 'info>(
+    program_id: &Pubkey,
+    operator: &SanitizedOperator<'a, 'info>,
+    avs: &SanitizedAvs<'a, 'info>,
+    operator_avs_heartbeat_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+    slot: u64,
+) -> ProgramResult {
+    let (address, bump, mut seeds) = OperatorAvsHeartbeat::find_program_address(
+        program_id,
+        operator.account().key,
+        avs.account().key,
+    );
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *operator_avs_heartbeat_account.account().key,
+        RestakingError::OperatorAvsHeartbeatInvalidPda,
+        "Invalid operator AVS heartbeat PDA",
+    )?;
+
+    let operator_avs_heartbeat =
+        OperatorAvsHeartbeat::new(*operator.account().key, *avs.account().key, slot, bump);
+
+    msg!(
+        "Creating operator AVS heartbeat: {:?}",
+        operator_avs_heartbeat_account.account().key
+    );
+    let serialized = operator_avs_heartbeat.as_bytes();
+    create_account(
+        payer.account(),
+        operator_avs_heartbeat_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    operator_avs_heartbeat_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    avs: SanitizedAvs<'a, 'info>,
+    operator_avs_ticket: SanitizedOperatorAvsTicket<'a, 'info>,
+    operator_avs_heartbeat_account: &'a AccountInfo<'info>,
+    voter: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorHeartbeat`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs =
+            SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator_avs_ticket = SanitizedOperatorAvsTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            operator.account().key,
+            avs.account().key,
+        )?;
+        let operator_avs_heartbeat_account = next_account_info(&mut accounts_iter)?;
+        let voter =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            operator,
+            avs,
+            operator_avs_ticket,
+            operator_avs_heartbeat_account,
+            voter,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -3,10 +3,12 @@ use jito_restaking_core::{
     operator_avs_ticket::SanitizedOperatorAvsTicket,
 };
 use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -29,7 +31,22 @@ pub fn process_operator_remove_avs(program_id: &Pubkey, accounts: &[AccountInfo]
         .operator_avs_ticket_mut()
         .deactivate(slot)?;
 
-    operator_avs_ticket.save()?;
+    msg!(
+        "OperatorAvsTicketDeactivated: operator={} avs={} ticket={} index={} slot={}",
+        operator.account().key,
+        operator_avs_ticket.operator_avs_ticket().avs(),
+        operator_avs_ticket.account().key,
+        operator_avs_ticket.operator_avs_ticket().index(),
+        slot
+    );
+    RestakingEvent::OperatorAvsTicketDeactivated {
+        operator: *operator.account().key,
+        avs: operator_avs_ticket.operator_avs_ticket().avs(),
+        ticket: *operator_avs_ticket.account().key,
+        index: operator_avs_ticket.operator_avs_ticket().index(),
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -3,10 +3,13 @@ use jito_restaking_core::{
     operator_vault_ticket::SanitizedOperatorVaultTicket,
 };
 use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use jito_vault_core::vault::SanitizedVault;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -30,7 +33,22 @@ pub fn process_operator_remove_vault(
         .operator_vault_ticket_mut()
         .deactivate(slot)?;
 
-    operator_vault_ticket.save()?;
+    msg!(
+        "OperatorVaultTicketDeactivated: operator={} vault={} ticket={} index={} slot={}",
+        operator.account().key,
+        operator_vault_ticket.operator_vault_ticket().vault(),
+        operator_vault_ticket.account().key,
+        operator_vault_ticket.operator_vault_ticket().index(),
+        slot
+    );
+    RestakingEvent::OperatorVaultTicketDeactivated {
+        operator: *operator.account().key,
+        vault: operator_vault_ticket.operator_vault_ticket().vault(),
+        ticket: *operator_vault_ticket.account().key,
+        index: operator_vault_ticket.operator_vault_ticket().index(),
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -49,17 +67,21 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let _config =
+        let config =
             SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let operator =
             SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
-        let vault = next_account_info(accounts_iter)?;
+        let vault = SanitizedVault::sanitize(
+            &config.config().vault_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
         let operator_vault_ticket = SanitizedOperatorVaultTicket::sanitize(
             program_id,
             next_account_info(accounts_iter)?,
             true,
             operator.account().key,
-            vault.key,
+            vault.account().key,
         )?;
         let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
 
@@ -3,12 +3,13 @@ use jito_restaking_sanitization::signer::SanitizedSignerAccount;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
 
-/// The node operator admin can set a new admin for the node operator.
-/// This method is permissioned to the node operator admin and both the old and new admins must sign.
+/// The node operator admin proposes a new admin for the node operator. The transfer only
+/// completes once the new admin signs [`crate::RestakingInstruction::OperatorAcceptAdmin`].
 ///
 /// [`crate::RestakingInstruction::OperatorSetAdmin`]
 pub fn process_set_node_operator_admin(
@@ -22,9 +23,13 @@ pub fn process_set_node_operator_admin(
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     operator.operator().check_admin(old_admin.account().key)?;
-    operator.operator_mut().set_admin(*new_admin.account().key);
+    operator.operator_mut().set_pending_admin(*new_admin.key);
 
-    operator.save()?;
+    msg!(
+        "OperatorAdminProposed: operator={} pending_admin={}",
+        operator.account().key,
+        new_admin.key
+    );
 
     Ok(())
 }
@@ -32,7 +37,7 @@ pub fn process_set_node_operator_admin(
 struct SanitizedAccounts<'a, 'info> {
     operator: SanitizedOperator<'a, 'info>,
     old_admin: SanitizedSignerAccount<'a, 'info>,
-    new_admin: SanitizedSignerAccount<'a, 'info>,
+    new_admin: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
@@ -45,7 +50,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
         let operator =
             SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
         let old_admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
-        let new_admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let new_admin = next_account_info(accounts_iter)?;
 
         Ok(SanitizedAccounts {
             operator,
@@ -0,0 +1,192 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    config::SanitizedConfig,
+    operator::SanitizedOperator,
+    operator_avs_capacity::{OperatorAvsCapacity, SanitizedOperatorAvsCapacity},
+    operator_avs_ticket::SanitizedOperatorAvsTicket,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// The operator AVS admin advertises the max additional stake the operator can handle for an AVS
+/// it's active for. The capacity account is created on first use and updated afterwards.
+///
+/// [`crate::RestakingInstruction::OperatorSetAvsCapacity`]
+pub fn process_operator_set_avs_capacity(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    capacity: u64,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        operator,
+        avs,
+        operator_avs_ticket,
+        operator_avs_capacity_account,
+        admin,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator.operator().check_avs_admin(admin.account().key)?;
+
+    let slot = Clock::get()?.slot;
+    let ticket_epoch_length = config.config().ticket_epoch_length();
+    operator_avs_ticket
+        .operator_avs_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+
+    if operator_avs_capacity_account.data_is_empty() {
+        let operator_avs_capacity_account =
+            EmptyAccount::sanitize(operator_avs_capacity_account, true)?;
+        _create_operator_avs_capacity(
+            program_id,
+            &operator,
+            &avs,
+            &operator_avs_capacity_account,
+            &payer,
+            &system_program,
+            &Rent::get()?,
+        )?;
+    }
+    let mut operator_avs_capacity = SanitizedOperatorAvsCapacity::sanitize(
+        program_id,
+        operator_avs_capacity_account,
+        true,
+        operator.account().key,
+        avs.account().key,
+    )?;
+    operator_avs_capacity
+        .operator_avs_capacity_mut()
+        .set_capacity(capacity, slot);
+
+    msg!(
+        "OperatorAvsCapacitySet: operator={} avs={} capacity={} slot={}",
+        operator.account().key,
+        avs.account().key,
+        capacity,
+        slot
+    );
+    RestakingEvent::OperatorAvsCapacitySet {
+        operator: *operator.account().key,
+        avs: *avs.account().key,
+        capacity,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+fn _create_operator_avs_capacity<'a, 'info>(
+    program_id: &Pubkey,
+    operator: &SanitizedOperator<'a, 'info>,
+    avs: &SanitizedAvs<'a, 'info>,
+    operator_avs_capacity_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+) -> ProgramResult {
+    let (address, bump, mut seeds) = OperatorAvsCapacity::find_program_address(
+        program_id,
+        operator.account().key,
+        avs.account().key,
+    );
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *operator_avs_capacity_account.account().key,
+        RestakingError::OperatorAvsCapacityInvalidPda,
+        "Invalid operator AVS capacity PDA",
+    )?;
+
+    let operator_avs_capacity =
+        OperatorAvsCapacity::new(*operator.account().key, *avs.account().key, bump);
+
+    msg!(
+        "Creating operator AVS capacity: {:?}",
+        operator_avs_capacity_account.account().key
+    );
+    let serialized = operator_avs_capacity.as_bytes();
+    create_account(
+        payer.account(),
+        operator_avs_capacity_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    operator_avs_capacity_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    avs: SanitizedAvs<'a, 'info>,
+    operator_avs_ticket: SanitizedOperatorAvsTicket<'a, 'info>,
+    operator_avs_capacity_account: &'a AccountInfo<'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorSetAvsCapacity`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs =
+            SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator_avs_ticket = SanitizedOperatorAvsTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            operator.account().key,
+            avs.account().key,
+        )?;
+        let operator_avs_capacity_account = next_account_info(&mut accounts_iter)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            operator,
+            avs,
+            operator_avs_ticket,
+            operator_avs_capacity_account,
+            admin,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use jito_restaking_core::operator::SanitizedOperator;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The operator admin sets the relayer allowed to submit admin operations it signed off-chain.
+/// Setting the default pubkey removes the relayer.
+///
+/// [`crate::RestakingInstruction::OperatorSetRelayer`]
+pub fn process_operator_set_relayer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut operator,
+        admin,
+        relayer,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator.operator().check_admin(admin.account().key)?;
+
+    msg!(
+        "Setting relayer on operator {} to {}",
+        operator.account().key,
+        relayer.key
+    );
+    operator.operator_mut().set_relayer(*relayer.key);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    relayer: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorSetRelayer`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let relayer = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            operator,
+            admin,
+            relayer,
+        })
+    }
+}
@@ -0,0 +1,64 @@
+use jito_restaking_core::operator::SanitizedOperator;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The operator admin sets the account receiving the rent of its tickets closed with
+/// [`crate::RestakingInstruction::CloseTicket`].
+///
+/// [`crate::RestakingInstruction::OperatorSetRentCollector`]
+pub fn process_operator_set_rent_collector(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut operator,
+        admin,
+        rent_collector,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator.operator().check_admin(admin.account().key)?;
+
+    msg!(
+        "Setting rent collector on operator {} to {}",
+        operator.account().key,
+        rent_collector.key
+    );
+    operator
+        .operator_mut()
+        .set_rent_collector(*rent_collector.key);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    rent_collector: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorSetRentCollector`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let rent_collector = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            operator,
+            admin,
+            rent_collector,
+        })
+    }
+}
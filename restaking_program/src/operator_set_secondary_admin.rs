@@ -0,0 +1,79 @@
+use jito_restaking_core::operator::SanitizedOperator;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::OperatorAdminRole;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The operator admin hands one of the operator's duties to another key, so it can be carried out
+/// without the primary admin key. The new admin doesn't need to sign.
+///
+/// Instruction: [`crate::RestakingInstruction::OperatorSetSecondaryAdmin`]
+pub fn process_operator_set_secondary_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: OperatorAdminRole,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut operator,
+        admin,
+        new_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator.operator().check_admin(admin.account().key)?;
+
+    match role {
+        OperatorAdminRole::Avs => {
+            operator.operator_mut().set_avs_admin(*new_admin.key);
+        }
+        OperatorAdminRole::Vault => {
+            operator.operator_mut().set_vault_admin(*new_admin.key);
+        }
+        OperatorAdminRole::Withdraw => {
+            operator.operator_mut().set_withdraw_admin(*new_admin.key);
+        }
+        OperatorAdminRole::Metadata => {
+            operator.operator_mut().set_metadata_admin(*new_admin.key);
+        }
+    }
+
+    msg!(
+        "OperatorSecondaryAdminSet: operator={} role={:?} admin={}",
+        operator.account().key,
+        role,
+        new_admin.key
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    new_admin: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorSetSecondaryAdmin`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let new_admin = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            operator,
+            admin,
+            new_admin,
+        })
+    }
+}
@@ -1,15 +1,23 @@
 use jito_restaking_core::operator::SanitizedOperator;
 use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// The node operator admin can set a new voter for the node operator.
 /// This method is permissioned to the node operator admin.
 ///
+/// The replaced voter is kept in the operator's voter history, so AVSs can still verify messages
+/// it signed before the rotation with
+/// [`jito_restaking_core::operator::Operator::check_voter_at_slot`].
+///
 /// [`crate::RestakingInstruction::OperatorSetVoter`]
 pub fn process_set_node_operator_voter(
     program_id: &Pubkey,
@@ -22,8 +30,22 @@ pub fn process_set_node_operator_voter(
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     operator.operator().check_admin(admin.account().key)?;
-    operator.operator_mut().set_voter(*voter.key);
-    operator.save()?;
+
+    let slot = Clock::get()?.slot;
+    operator.operator_mut().set_voter(*voter.key, slot);
+
+    msg!(
+        "OperatorVoterSet: operator={} voter={} slot={}",
+        operator.account().key,
+        voter.key,
+        slot
+    );
+    RestakingEvent::OperatorVoterSet {
+        operator: *operator.account().key,
+        voter: *voter.key,
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -43,7 +65,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
 
         let operator =
             SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
-        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
         let voter = next_account_info(accounts_iter)?;
 
         Ok(SanitizedAccounts {
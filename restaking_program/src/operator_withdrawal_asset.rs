@@ -3,15 +3,25 @@ use jito_restaking_sanitization::{
     assert_with_msg, signer::SanitizedSignerAccount, token_account::SanitizedTokenAccount,
     token_program::SanitizedTokenProgram,
 };
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    msg,
     program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
 use spl_token::instruction::transfer;
 
+/// The operator withdraw admin moves tokens out of a token account owned by the operator, signing
+/// for the operator PDA. Tokens end up there when they're earned by the operator, swept out of its
+/// tickets with [`crate::RestakingInstruction::SweepTokens`], or sent to it by mistake.
+///
+/// [`crate::RestakingInstruction::OperatorWithdrawalAsset`]
 pub fn process_operator_withdrawal_asset(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -25,12 +35,14 @@ pub fn process_operator_withdrawal_asset(
         receiver_token_account,
     } = SanitizedAccounts::sanitize(program_id, accounts, &token_mint)?;
 
-    operator.operator().check_admin(admin.account().key)?;
+    operator
+        .operator()
+        .check_withdraw_admin(admin.account().key)?;
 
     assert_with_msg(
         operator_token_account.token_account().amount >= amount,
-        ProgramError::InsufficientFunds,
-        "Not enough funds in AVS token account",
+        RestakingError::OperatorInsufficientFunds,
+        "Not enough funds in operator token account",
     )?;
 
     _withdraw_operator_asset(
@@ -40,6 +52,21 @@ pub fn process_operator_withdrawal_asset(
         amount,
     )?;
 
+    msg!(
+        "OperatorAssetWithdrawn: operator={} mint={} receiver={} amount={}",
+        operator.account().key,
+        token_mint,
+        receiver_token_account.key,
+        amount
+    );
+    RestakingEvent::OperatorAssetWithdrawn {
+        operator: *operator.account().key,
+        mint: token_mint,
+        receiver: *receiver_token_account.key,
+        amount,
+    }
+    .emit()?;
+
     Ok(())
 }
 
@@ -84,6 +111,7 @@ struct SanitizedAccounts<'a, 'info> {
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::OperatorWithdrawalAsset`]
     fn sanitize(
         program_id: &Pubkey,
         accounts: &'a [AccountInfo<'info>],
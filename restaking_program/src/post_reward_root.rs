@@ -0,0 +1,207 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs, config::SanitizedConfig, result::RestakingCoreError,
+    reward_distribution::RewardDistribution,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount, create_account,
+    empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram, token_account::SanitizedTokenAccount,
+    token_mint::SanitizedTokenMint, token_program::SanitizedTokenProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::vault::SanitizedVault;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::transfer_checked;
+
+/// Posts the merkle root of the rewards an AVS or vault pays for an epoch in a mint, creating the
+/// distribution and funding its associated token account with the max total claim. The AVS
+/// withdraw admin posts for an AVS and the vault admin for a vault. The funds are transferred with
+/// the token program owning the mint, SPL Token or Token-2022.
+///
+/// [`crate::RestakingInstruction::PostRewardRoot`]
+pub fn process_post_reward_root(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    merkle_root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        source,
+        reward_distribution,
+        mint,
+        distribution_token_account,
+        admin_token_account,
+        admin,
+        payer,
+        token_program,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    assert_with_msg(
+        max_total_claim > 0 && max_num_nodes > 0,
+        RestakingCoreError::RewardDistributionInvalidClaims,
+        "Reward distribution must have a max total claim and max num nodes",
+    )?;
+
+    let (address, bump, mut seeds) =
+        RewardDistribution::find_program_address(program_id, source.key, mint.account().key, epoch);
+    seeds.push(vec![bump]);
+    assert_with_msg(
+        address == *reward_distribution.account().key,
+        RestakingError::RewardDistributionInvalidPda,
+        "Invalid reward distribution PDA",
+    )?;
+    let distribution_token_account = SanitizedAssociatedTokenAccount::sanitize(
+        distribution_token_account,
+        mint.account().key,
+        &address,
+    )?;
+
+    let distribution = RewardDistribution::new(
+        *source.key,
+        *mint.account().key,
+        epoch,
+        merkle_root,
+        max_total_claim,
+        max_num_nodes,
+        bump,
+    );
+    msg!(
+        "Creating reward distribution: {:?}",
+        reward_distribution.account().key
+    );
+    let serialized = distribution.as_bytes();
+    create_account(
+        payer.account(),
+        reward_distribution.account(),
+        system_program.account(),
+        program_id,
+        &Rent::get()?,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    reward_distribution.account().data.borrow_mut()[..serialized.len()].copy_from_slice(serialized);
+
+    invoke(
+        &transfer_checked(
+            token_program.account().key,
+            admin_token_account.account().key,
+            mint.account().key,
+            distribution_token_account.account().key,
+            admin.account().key,
+            &[],
+            max_total_claim,
+            mint.mint().decimals,
+        )?,
+        &[
+            admin_token_account.account().clone(),
+            mint.account().clone(),
+            distribution_token_account.account().clone(),
+            admin.account().clone(),
+        ],
+    )?;
+
+    msg!(
+        "RewardRootPosted: source={} distribution={} mint={} epoch={} max_total_claim={} max_num_nodes={}",
+        source.key,
+        address,
+        mint.account().key,
+        epoch,
+        max_total_claim,
+        max_num_nodes
+    );
+    RestakingEvent::RewardRootPosted {
+        source: *source.key,
+        distribution: address,
+        mint: *mint.account().key,
+        epoch,
+        merkle_root,
+        max_total_claim,
+        max_num_nodes,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    source: &'a AccountInfo<'info>,
+    reward_distribution: EmptyAccount<'a, 'info>,
+    mint: SanitizedTokenMint<'a, 'info>,
+    distribution_token_account: &'a AccountInfo<'info>,
+    admin_token_account: SanitizedTokenAccount<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::PostRewardRoot`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let source = next_account_info(&mut accounts_iter)?;
+        let reward_distribution =
+            EmptyAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let mint = SanitizedTokenMint::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let distribution_token_account = next_account_info(&mut accounts_iter)?;
+        let admin_token_account = next_account_info(&mut accounts_iter)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let admin_token_account = SanitizedTokenAccount::sanitize(
+            admin_token_account,
+            mint.account().key,
+            admin.account().key,
+        )?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let token_program =
+            SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        let vault_program = config.config().vault_program();
+        if source.owner == program_id {
+            let avs = SanitizedAvs::sanitize(program_id, source, false)?;
+            avs.avs().check_withdraw_admin(admin.account().key)?;
+        } else if *source.owner == vault_program {
+            let vault = SanitizedVault::sanitize(&vault_program, source, false)?;
+            vault.vault().check_admin(admin.account().key)?;
+        } else {
+            msg!("Reward source must be an AVS or a vault");
+            return Err(RestakingError::RewardSourceInvalid.into());
+        }
+
+        Ok(SanitizedAccounts {
+            source,
+            reward_distribution,
+            mint,
+            distribution_token_account,
+            admin_token_account,
+            admin,
+            payer,
+            token_program,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs, config::SanitizedConfig, operator::SanitizedOperator,
+};
+use jito_restaking_sanitization::{
+    ed25519::verify_ed25519_signature, signer::SanitizedSignerAccount,
+};
+use jito_restaking_sdk::{relayed_message, RelayedOperation};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The relayer of an AVS or operator submits an admin operation the admin signed off-chain, paying
+/// the fees on their behalf.
+///
+/// # Behavior
+/// * The relayer shall be the one set on the AVS or operator.
+/// * The instruction right before this one shall be an ed25519 program instruction verifying the
+///   admin's signature over [`jito_restaking_sdk::relayed_message`].
+/// * The nonce shall be the next one expected by the AVS or operator, so each signed operation
+///   runs at most once and in order.
+///
+/// [`crate::RestakingInstruction::RelayAdminOperation`]
+pub fn process_relay_admin_operation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    nonce: u64,
+    operation: RelayedOperation,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        account,
+        relayer,
+        instructions_sysvar,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let message = relayed_message(program_id, account.key, nonce, &operation);
+    match operation {
+        RelayedOperation::AvsSetMaxHeartbeatAge { max_heartbeat_age } => {
+            let mut avs = SanitizedAvs::sanitize(program_id, account, true)?;
+            avs.avs().check_relayer(relayer.account().key)?;
+            verify_ed25519_signature(instructions_sysvar, &avs.avs().operator_admin(), &message)?;
+            avs.avs_mut().use_nonce(nonce)?;
+
+            avs.avs_mut().set_max_heartbeat_age(max_heartbeat_age)?;
+        }
+        RelayedOperation::OperatorSetVoter { voter } => {
+            let mut operator = SanitizedOperator::sanitize(program_id, account, true)?;
+            operator.operator().check_relayer(relayer.account().key)?;
+            verify_ed25519_signature(instructions_sysvar, &operator.operator().admin(), &message)?;
+            operator.operator_mut().use_nonce(nonce)?;
+
+            operator.operator_mut().set_voter(voter, Clock::get()?.slot);
+        }
+        RelayedOperation::OperatorGrantSessionKey {
+            session_key,
+            expiry_slot,
+        } => {
+            let mut operator = SanitizedOperator::sanitize(program_id, account, true)?;
+            operator.operator().check_relayer(relayer.account().key)?;
+            verify_ed25519_signature(instructions_sysvar, &operator.operator().admin(), &message)?;
+            operator.operator_mut().use_nonce(nonce)?;
+
+            operator
+                .operator_mut()
+                .set_session_key(session_key, expiry_slot);
+        }
+    }
+
+    msg!(
+        "AdminOperationRelayed: account={} nonce={} relayer={}",
+        account.key,
+        nonce,
+        relayer.account().key
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+    relayer: SanitizedSignerAccount<'a, 'info>,
+    instructions_sysvar: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::RelayAdminOperation`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let _config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        // sanitized as an AVS or operator depending on the operation
+        let account = next_account_info(accounts_iter)?;
+        let relayer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        // checked when verifying the signature
+        let instructions_sysvar = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            account,
+            relayer,
+            instructions_sysvar,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use jito_restaking_core::{config::SanitizedConfig, operator::SanitizedOperator};
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_restaking_sdk::event::{Event, RestakingEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The operator admin sets the share of the rewards the operator keeps, see
+/// [`jito_restaking_core::operator::Operator::split_rewards`]. The fee is bounded by the max of
+/// the config and can't change again until the config cooldown elapsed.
+///
+/// [`crate::RestakingInstruction::SetOperatorFee`]
+pub fn process_set_operator_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    operator_fee_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut operator,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator.operator().check_admin(admin.account().key)?;
+
+    let slot = Clock::get()?.slot;
+    operator
+        .operator_mut()
+        .set_operator_fee_bps(operator_fee_bps, &config.config(), slot)?;
+
+    msg!(
+        "OperatorFeeSet: operator={} operator_fee_bps={} slot={}",
+        operator.account().key,
+        operator_fee_bps,
+        slot
+    );
+    RestakingEvent::OperatorFeeSet {
+        operator: *operator.account().key,
+        operator_fee_bps,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::SetOperatorFee`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            operator,
+            admin,
+        })
+    }
+}
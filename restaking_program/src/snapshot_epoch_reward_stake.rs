@@ -0,0 +1,329 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    avs_operator_ticket::SanitizedAvsOperatorTicket,
+    avs_vault_ticket::SanitizedAvsVaultTicket,
+    config::SanitizedConfig,
+    epoch_reward_router::SanitizedEpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot,
+    operator::SanitizedOperator,
+    operator_avs_score::{OperatorAvsScore, SanitizedOperatorAvsScore},
+    operator_avs_ticket::SanitizedOperatorAvsTicket,
+    operator_vault_ticket::SanitizedOperatorVaultTicket,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::{
+    config::SanitizedConfig as SanitizedVaultConfig, vault::SanitizedVault,
+    vault_delegation_list::SanitizedVaultDelegationList,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Records the stake a vault actively delegates to an operator of the AVS during the epoch of the
+/// router, along with the operator fee and the operator's latest attested performance score at
+/// the time. The AVS, operator and vault must be active for each other, and the delegation list
+/// must be updated for the epoch so the stake is current. An operator the AVS scorer never
+/// attested is recorded as unscored.
+///
+/// [`crate::RestakingInstruction::SnapshotEpochRewardStake`]
+pub fn process_snapshot_epoch_reward_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        operator,
+        vault,
+        vault_config,
+        vault_delegation_list,
+        avs_operator_ticket,
+        operator_avs_ticket,
+        avs_vault_ticket,
+        operator_vault_ticket,
+        mut epoch_reward_router,
+        epoch_reward_snapshot_account,
+        operator_score_bps,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+    let ticket_epoch_length = config.config().ticket_epoch_length();
+    avs_operator_ticket
+        .avs_operator_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+    operator_avs_ticket
+        .operator_avs_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+    avs_vault_ticket
+        .avs_vault_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+    operator_vault_ticket
+        .operator_vault_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+
+    let epoch_length = vault_config.config().epoch_length();
+    assert_with_msg(
+        !vault_delegation_list
+            .vault_delegation_list()
+            .needs_update(slot, epoch_length),
+        RestakingError::EpochRewardDelegationOutdated,
+        "Vault delegation list needs to be updated for the epoch",
+    )?;
+    let stake = vault_delegation_list
+        .vault_delegation_list()
+        .delegations()
+        .iter()
+        .find(|delegation| delegation.operator() == *operator.account().key)
+        .map(|delegation| delegation.active_amount())
+        .unwrap_or(0);
+    assert_with_msg(
+        stake > 0,
+        RestakingError::EpochRewardNoStake,
+        "Vault has no active stake on the operator",
+    )?;
+
+    epoch_reward_router
+        .epoch_reward_router_mut()
+        .record_stake(stake, slot)?;
+
+    let operator_fee_bps = operator.operator().operator_fee_bps();
+    let epoch_reward_snapshot_account =
+        EmptyAccount::sanitize(epoch_reward_snapshot_account, true)?;
+    _create_epoch_reward_snapshot(
+        program_id,
+        &epoch_reward_router,
+        &vault,
+        &operator,
+        &epoch_reward_snapshot_account,
+        &payer,
+        &system_program,
+        &Rent::get()?,
+        stake,
+        operator_fee_bps,
+        operator_score_bps,
+    )?;
+
+    msg!(
+        "EpochRewardStakeSnapshotted: router={} vault={} operator={} stake={} operator_fee_bps={} operator_score_bps={:?}",
+        epoch_reward_router.account().key,
+        vault.account().key,
+        operator.account().key,
+        stake,
+        operator_fee_bps,
+        operator_score_bps
+    );
+    RestakingEvent::EpochRewardStakeSnapshotted {
+        router: *epoch_reward_router.account().key,
+        vault: *vault.account().key,
+        operator: *operator.account().key,
+        stake,
+        operator_fee_bps,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn _create_epoch_reward_snapshot<'a, 'info>(
+    program_id: &Pubkey,
+    epoch_reward_router: &SanitizedEpochRewardRouter<'a, 'info>,
+    vault: &SanitizedVault<'a, 'info>,
+    operator: &SanitizedOperator<'a, 'info>,
+    epoch_reward_snapshot_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+    stake: u64,
+    operator_fee_bps: u16,
+    operator_score_bps: Option<u16>,
+) -> ProgramResult {
+    let (address, bump, mut seeds) = EpochRewardSnapshot::find_program_address(
+        program_id,
+        epoch_reward_router.account().key,
+        vault.account().key,
+        operator.account().key,
+    );
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *epoch_reward_snapshot_account.account().key,
+        RestakingError::EpochRewardSnapshotInvalidPda,
+        "Invalid epoch reward snapshot PDA",
+    )?;
+
+    let epoch_reward_snapshot = EpochRewardSnapshot::new(
+        *epoch_reward_router.account().key,
+        *vault.account().key,
+        *operator.account().key,
+        stake,
+        operator_fee_bps,
+        operator_score_bps,
+        bump,
+    );
+
+    msg!(
+        "Creating epoch reward snapshot: {:?}",
+        epoch_reward_snapshot_account.account().key
+    );
+    let serialized = epoch_reward_snapshot.as_bytes();
+    create_account(
+        payer.account(),
+        epoch_reward_snapshot_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    epoch_reward_snapshot_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    vault_config: SanitizedVaultConfig<'a, 'info>,
+    vault_delegation_list: SanitizedVaultDelegationList<'a, 'info>,
+    avs_operator_ticket: SanitizedAvsOperatorTicket<'a, 'info>,
+    operator_avs_ticket: SanitizedOperatorAvsTicket<'a, 'info>,
+    avs_vault_ticket: SanitizedAvsVaultTicket<'a, 'info>,
+    operator_vault_ticket: SanitizedOperatorVaultTicket<'a, 'info>,
+    epoch_reward_router: SanitizedEpochRewardRouter<'a, 'info>,
+    epoch_reward_snapshot_account: &'a AccountInfo<'info>,
+    operator_score_bps: Option<u16>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::SnapshotEpochRewardStake`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault_program = config.config().vault_program();
+        let avs =
+            SanitizedAvs::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(
+            &vault_program,
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let vault_config = SanitizedVaultConfig::sanitize(
+            &vault_program,
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
+            &vault_program,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            vault.account().key,
+        )?;
+        let avs_operator_ticket = SanitizedAvsOperatorTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            avs.account().key,
+            operator.account().key,
+        )?;
+        let operator_avs_ticket = SanitizedOperatorAvsTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            operator.account().key,
+            avs.account().key,
+        )?;
+        let avs_vault_ticket = SanitizedAvsVaultTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            avs.account().key,
+            vault.account().key,
+        )?;
+        let operator_vault_ticket = SanitizedOperatorVaultTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            operator.account().key,
+            vault.account().key,
+        )?;
+        let epoch_reward_router = SanitizedEpochRewardRouter::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+            avs.account().key,
+        )?;
+        let epoch_reward_snapshot_account = next_account_info(&mut accounts_iter)?;
+        // the score account is only created by the first attestation, so it may still be empty
+        let operator_avs_score_account = next_account_info(&mut accounts_iter)?;
+        let operator_score_bps = if operator_avs_score_account.data_is_empty() {
+            let (address, _, _) = OperatorAvsScore::find_program_address(
+                program_id,
+                operator.account().key,
+                avs.account().key,
+            );
+            assert_with_msg(
+                address == *operator_avs_score_account.key,
+                RestakingError::OperatorAvsScoreInvalidPda,
+                "Invalid operator AVS score PDA",
+            )?;
+            None
+        } else {
+            let operator_avs_score = SanitizedOperatorAvsScore::sanitize(
+                program_id,
+                operator_avs_score_account,
+                false,
+                operator.account().key,
+                avs.account().key,
+            )?;
+            let score_bps = operator_avs_score.operator_avs_score().score_bps();
+            Some(score_bps)
+        };
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            operator,
+            vault,
+            vault_config,
+            vault_delegation_list,
+            avs_operator_ticket,
+            operator_avs_ticket,
+            avs_vault_ticket,
+            operator_vault_ticket,
+            epoch_reward_router,
+            epoch_reward_snapshot_account,
+            operator_score_bps,
+            payer,
+            system_program,
+        })
+    }
+}
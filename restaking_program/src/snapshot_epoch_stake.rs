@@ -0,0 +1,306 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    config::SanitizedConfig,
+    operator::SanitizedOperator,
+    operator_epoch_stake::{OperatorEpochStake, SanitizedOperatorEpochStake},
+    operator_vault_ticket::SanitizedOperatorVaultTicket,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use jito_vault_core::{
+    config::SanitizedConfig as SanitizedVaultConfig, vault::SanitizedVault,
+    vault_delegation_list::SanitizedVaultDelegationList,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Records the stake a vault actively delegates to an operator in the current epoch and adds it
+/// to the stake of the operator for the epoch. The stake of the operator account is created by
+/// the first vault snapshotted in the epoch. The delegation list must be updated for the epoch so
+/// the stake is current.
+///
+/// [`crate::RestakingInstruction::SnapshotEpochStake`]
+pub fn process_snapshot_epoch_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        operator,
+        vault,
+        vault_config,
+        vault_delegation_list,
+        operator_vault_ticket,
+        operator_epoch_stake_account,
+        vault_operator_epoch_stake_account,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+    let ticket_epoch_length = config.config().ticket_epoch_length();
+    operator_vault_ticket
+        .operator_vault_ticket()
+        .check_warmed_up(slot, ticket_epoch_length)?;
+
+    let epoch_length = vault_config.config().epoch_length();
+    let epoch = slot.checked_div(epoch_length).unwrap_or_default();
+    assert_with_msg(
+        !vault_delegation_list
+            .vault_delegation_list()
+            .needs_update(slot, epoch_length),
+        RestakingError::EpochStakeDelegationOutdated,
+        "Vault delegation list needs to be updated for the epoch",
+    )?;
+    let stake = vault_delegation_list
+        .vault_delegation_list()
+        .delegations()
+        .iter()
+        .find(|delegation| delegation.operator() == *operator.account().key)
+        .map(|delegation| delegation.active_amount())
+        .unwrap_or(0);
+    assert_with_msg(
+        stake > 0,
+        RestakingError::EpochStakeNoStake,
+        "Vault has no active stake on the operator",
+    )?;
+
+    let rent = Rent::get()?;
+    if operator_epoch_stake_account.data_is_empty() {
+        _create_operator_epoch_stake(
+            program_id,
+            &operator,
+            &EmptyAccount::sanitize(operator_epoch_stake_account, true)?,
+            &payer,
+            &system_program,
+            &rent,
+            epoch,
+        )?;
+    }
+    let mut operator_epoch_stake = SanitizedOperatorEpochStake::sanitize(
+        program_id,
+        operator_epoch_stake_account,
+        true,
+        operator.account().key,
+        epoch,
+    )?;
+    operator_epoch_stake
+        .operator_epoch_stake_mut()
+        .record_vault_stake(stake)?;
+    let operator_stake = operator_epoch_stake.operator_epoch_stake().stake();
+
+    _create_vault_operator_epoch_stake(
+        program_id,
+        &vault,
+        &operator,
+        &EmptyAccount::sanitize(vault_operator_epoch_stake_account, true)?,
+        &payer,
+        &system_program,
+        &rent,
+        epoch,
+        stake,
+        slot,
+    )?;
+
+    msg!(
+        "EpochStakeSnapshotted: vault={} operator={} epoch={} stake={} operator_stake={}",
+        vault.account().key,
+        operator.account().key,
+        epoch,
+        stake,
+        operator_stake
+    );
+    RestakingEvent::EpochStakeSnapshotted {
+        vault: *vault.account().key,
+        operator: *operator.account().key,
+        epoch,
+        stake,
+        operator_stake,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+fn _create_operator_epoch_stake<'a, 'info>(
+    program_id: &Pubkey,
+    operator: &SanitizedOperator<'a, 'info>,
+    operator_epoch_stake_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+    epoch: u64,
+) -> ProgramResult {
+    let (address, bump, mut seeds) =
+        OperatorEpochStake::find_program_address(program_id, operator.account().key, epoch);
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *operator_epoch_stake_account.account().key,
+        RestakingError::OperatorEpochStakeInvalidPda,
+        "Invalid operator epoch stake PDA",
+    )?;
+
+    let operator_epoch_stake = OperatorEpochStake::new(*operator.account().key, epoch, bump);
+
+    msg!(
+        "Creating operator epoch stake: {:?}",
+        operator_epoch_stake_account.account().key
+    );
+    let serialized = operator_epoch_stake.as_bytes();
+    create_account(
+        payer.account(),
+        operator_epoch_stake_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    operator_epoch_stake_account.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn _create_vault_operator_epoch_stake<'a, 'info>(
+    program_id: &Pubkey,
+    vault: &SanitizedVault<'a, 'info>,
+    operator: &SanitizedOperator<'a, 'info>,
+    vault_operator_epoch_stake_account: &EmptyAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+    epoch: u64,
+    stake: u64,
+    slot: u64,
+) -> ProgramResult {
+    let (address, bump, mut seeds) = VaultOperatorEpochStake::find_program_address(
+        program_id,
+        vault.account().key,
+        operator.account().key,
+        epoch,
+    );
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *vault_operator_epoch_stake_account.account().key,
+        RestakingError::VaultOperatorEpochStakeInvalidPda,
+        "Invalid vault operator epoch stake PDA",
+    )?;
+
+    let vault_operator_epoch_stake = VaultOperatorEpochStake::new(
+        *vault.account().key,
+        *operator.account().key,
+        epoch,
+        stake,
+        slot,
+        bump,
+    );
+
+    msg!(
+        "Creating vault operator epoch stake: {:?}",
+        vault_operator_epoch_stake_account.account().key
+    );
+    let serialized = vault_operator_epoch_stake.as_bytes();
+    create_account(
+        payer.account(),
+        vault_operator_epoch_stake_account.account(),
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    vault_operator_epoch_stake_account
+        .account()
+        .data
+        .borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    vault_config: SanitizedVaultConfig<'a, 'info>,
+    vault_delegation_list: SanitizedVaultDelegationList<'a, 'info>,
+    operator_vault_ticket: SanitizedOperatorVaultTicket<'a, 'info>,
+    operator_epoch_stake_account: &'a AccountInfo<'info>,
+    vault_operator_epoch_stake_account: &'a AccountInfo<'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::SnapshotEpochStake`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault_program = config.config().vault_program();
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(
+            &vault_program,
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let vault_config = SanitizedVaultConfig::sanitize(
+            &vault_program,
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
+            &vault_program,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            vault.account().key,
+        )?;
+        let operator_vault_ticket = SanitizedOperatorVaultTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            operator.account().key,
+            vault.account().key,
+        )?;
+        let operator_epoch_stake_account = next_account_info(&mut accounts_iter)?;
+        let vault_operator_epoch_stake_account = next_account_info(&mut accounts_iter)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let system_program =
+            SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            operator,
+            vault,
+            vault_config,
+            vault_delegation_list,
+            operator_vault_ticket,
+            operator_epoch_stake_account,
+            vault_operator_epoch_stake_account,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use jito_restaking_core::config::SanitizedConfig;
+use jito_restaking_sanitization::{signer::SanitizedSignerAccount, sweep_excess_lamports};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+use crate::sweep_tokens::check_sweep_admin;
+
+/// Moves the lamports held by the config or a ticket PDA above its rent-exempt minimum to the
+/// same treasury and under the same admin as [`crate::RestakingInstruction::SweepTokens`]: the AVS
+/// or operator that created the ticket, or the config admin for the config.
+///
+/// [`crate::RestakingInstruction::SweepLamports`]
+pub fn process_sweep_lamports(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        parent,
+        pda,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let (_, treasury) = check_sweep_admin(program_id, &config, parent, pda, admin.account().key)?;
+    let receiver = if pda.key == config.account().key {
+        admin.account()
+    } else {
+        parent
+    };
+    if !receiver.is_writable {
+        msg!("Treasury {} is not writable", receiver.key);
+        return Err(RestakingError::AccountExpectedWritable.into());
+    }
+
+    let amount = sweep_excess_lamports(pda, receiver, &Rent::get()?)?;
+
+    msg!(
+        "LamportsSwept: account={} amount={} treasury={}",
+        pda.key,
+        amount,
+        treasury
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    parent: &'a AccountInfo<'info>,
+    pda: &'a AccountInfo<'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::SweepLamports`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        // checked against the ticket type in the processor
+        let parent = next_account_info(accounts_iter)?;
+        let pda = next_account_info(accounts_iter)?;
+        if pda.owner != program_id {
+            msg!("Account {} is not owned by the restaking program", pda.key);
+            return Err(RestakingError::AccountInvalidProgramOwner.into());
+        }
+        if !pda.is_writable {
+            return Err(RestakingError::AccountExpectedWritable.into());
+        }
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            parent,
+            pda,
+            admin,
+        })
+    }
+}
@@ -0,0 +1,312 @@
+use borsh::BorshDeserialize;
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    avs_operator_ticket::AvsOperatorTicket,
+    avs_reward_budget::AvsRewardBudget,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket,
+    config::{Config, SanitizedConfig},
+    operator::SanitizedOperator,
+    operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore,
+    operator_avs_ticket::OperatorAvsTicket,
+    operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket,
+    AccountType,
+};
+use jito_restaking_sanitization::{
+    signer::SanitizedSignerAccount, token_account::SanitizedTokenAccount,
+    token_program::SanitizedTokenProgram,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::transfer;
+
+/// Sweeps the whole balance of a token account owned by the config or a ticket PDA. Nothing in the
+/// program moves tokens out of these accounts, so anything airdropped or sent there by mistake
+/// would otherwise be stuck.
+///
+/// Ticket balances go to a token account owned by the AVS or operator that created the ticket,
+/// where they can be withdrawn with [`crate::RestakingInstruction::AvsWithdrawalAsset`] or
+/// [`crate::RestakingInstruction::OperatorWithdrawalAsset`]. Tickets are swept by the withdraw
+/// admin of the AVS or operator. The config balance is swept by the config admin into a token
+/// account it owns.
+///
+/// [`crate::RestakingInstruction::SweepTokens`]
+pub fn process_sweep_tokens(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    token_mint: Pubkey,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        parent,
+        pda,
+        pda_token_account,
+        treasury_token_account,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts, &token_mint)?;
+
+    let (pda_seeds, treasury) =
+        check_sweep_admin(program_id, &config, parent, pda, admin.account().key)?;
+
+    let treasury_token_account =
+        SanitizedTokenAccount::sanitize(treasury_token_account, &token_mint, &treasury)?;
+
+    let amount = pda_token_account.token_account().amount;
+    let pda_seeds_slice = pda_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+
+    invoke_signed(
+        &transfer(
+            &spl_token::id(),
+            pda_token_account.account().key,
+            treasury_token_account.account().key,
+            pda.key,
+            &[],
+            amount,
+        )?,
+        &[
+            pda_token_account.account().clone(),
+            treasury_token_account.account().clone(),
+            pda.clone(),
+        ],
+        &[pda_seeds_slice.as_slice()],
+    )?;
+
+    msg!(
+        "TokensSwept: account={} mint={} amount={} treasury={}",
+        pda.key,
+        token_mint,
+        amount,
+        treasury
+    );
+
+    Ok(())
+}
+
+/// Checks the admin may sweep the config or ticket PDA, returning the PDA signer seeds and the
+/// treasury the balance goes to
+pub fn check_sweep_admin(
+    program_id: &Pubkey,
+    config: &SanitizedConfig,
+    parent: &AccountInfo,
+    pda: &AccountInfo,
+    admin: &Pubkey,
+) -> Result<(Vec<Vec<u8>>, Pubkey), ProgramError> {
+    let account_type = AccountType::deserialize(&mut pda.data.borrow().as_ref())
+        .map_err(|_| RestakingError::AccountInvalidType)?;
+    let sweep = match account_type {
+        AccountType::Config => {
+            if pda.key != config.account().key {
+                msg!("Config account mismatch");
+                return Err(RestakingError::SweepInvalidConfig.into());
+            }
+            config.config().check_admin(admin)?;
+
+            let mut seeds = Config::seeds();
+            seeds.push(vec![config.config().bump()]);
+            (seeds, *admin)
+        }
+        AccountType::AvsOperatorTicket
+        | AccountType::AvsVaultTicket
+        | AccountType::AvsVaultSlasherTicket
+        | AccountType::AvsRewardBudget => {
+            let avs = SanitizedAvs::sanitize(program_id, parent, false)?;
+            avs.avs().check_withdraw_admin(admin)?;
+
+            (
+                avs_ticket_seeds(program_id, pda, avs.account().key, account_type)?,
+                *avs.account().key,
+            )
+        }
+        AccountType::OperatorAvsTicket
+        | AccountType::OperatorVaultTicket
+        | AccountType::OperatorAvsHeartbeat
+        | AccountType::OperatorAvsScore
+        | AccountType::OperatorStakeHistory => {
+            let operator = SanitizedOperator::sanitize(program_id, parent, false)?;
+            operator.operator().check_withdraw_admin(admin)?;
+
+            (
+                operator_ticket_seeds(program_id, pda, operator.account().key, account_type)?,
+                *operator.account().key,
+            )
+        }
+        AccountType::Avs | AccountType::Operator => {
+            msg!("AVS and operator accounts hold their own balances and can't be swept");
+            return Err(RestakingError::SweepAccountNotSweepable.into());
+        }
+        AccountType::Metadata | AccountType::OperatorAvsCapacity => {
+            msg!("Metadata and capacity accounts can't be swept");
+            return Err(RestakingError::SweepAccountNotSweepable.into());
+        }
+        AccountType::EpochRewardRouter | AccountType::EpochRewardSnapshot => {
+            msg!("Epoch rewards are paid out with DistributeEpochRewards and can't be swept");
+            return Err(RestakingError::SweepAccountNotSweepable.into());
+        }
+        AccountType::OperatorEpochStake | AccountType::VaultOperatorEpochStake => {
+            msg!("Epoch stake snapshots can't be swept");
+            return Err(RestakingError::SweepAccountNotSweepable.into());
+        }
+        AccountType::RewardDistribution | AccountType::RewardClaimStatus => {
+            msg!("Reward distributions are paid out with ClaimReward and can't be swept");
+            return Err(RestakingError::SweepAccountNotSweepable.into());
+        }
+    };
+    Ok(sweep)
+}
+
+/// Verifies an AVS ticket belongs to the AVS and returns its signer seeds
+fn avs_ticket_seeds(
+    program_id: &Pubkey,
+    ticket: &AccountInfo,
+    avs: &Pubkey,
+    account_type: AccountType,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let seeds = match account_type {
+        AccountType::AvsOperatorTicket => {
+            let operator =
+                AvsOperatorTicket::deserialize(&mut ticket.data.borrow().as_ref())?.operator();
+            let ticket =
+                AvsOperatorTicket::deserialize_checked(program_id, ticket, avs, &operator)?;
+            let mut seeds = AvsOperatorTicket::seeds(avs, &operator);
+            seeds.push(vec![ticket.bump()]);
+            seeds
+        }
+        AccountType::AvsVaultTicket => {
+            let vault = AvsVaultTicket::deserialize(&mut ticket.data.borrow().as_ref())?.vault();
+            let ticket = AvsVaultTicket::deserialize_checked(program_id, ticket, avs, &vault)?;
+            let mut seeds = AvsVaultTicket::seeds(avs, &vault);
+            seeds.push(vec![ticket.bump()]);
+            seeds
+        }
+        AccountType::AvsVaultSlasherTicket => {
+            let slasher_ticket =
+                AvsVaultSlasherTicket::deserialize(&mut ticket.data.borrow().as_ref())?;
+            let (vault, slasher) = (slasher_ticket.vault(), slasher_ticket.slasher());
+            let ticket = AvsVaultSlasherTicket::deserialize_checked(
+                program_id, ticket, avs, &vault, &slasher,
+            )?;
+            let mut seeds = AvsVaultSlasherTicket::seeds(avs, &vault, &slasher);
+            seeds.push(vec![ticket.bump()]);
+            seeds
+        }
+        AccountType::AvsRewardBudget => {
+            let mint = AvsRewardBudget::deserialize(&mut ticket.data.borrow().as_ref())?.mint();
+            let budget = AvsRewardBudget::deserialize_checked(program_id, ticket, avs, &mint)?;
+            let mut seeds = AvsRewardBudget::seeds(avs, &mint);
+            seeds.push(vec![budget.bump()]);
+            seeds
+        }
+        _ => return Err(RestakingError::SweepAccountNotSweepable.into()),
+    };
+    Ok(seeds)
+}
+
+/// Verifies an operator ticket belongs to the operator and returns its signer seeds
+fn operator_ticket_seeds(
+    program_id: &Pubkey,
+    ticket: &AccountInfo,
+    operator: &Pubkey,
+    account_type: AccountType,
+) -> Result<Vec<Vec<u8>>, ProgramError> {
+    let seeds = match account_type {
+        AccountType::OperatorAvsTicket => {
+            let avs = OperatorAvsTicket::deserialize(&mut ticket.data.borrow().as_ref())?.avs();
+            let ticket =
+                OperatorAvsTicket::deserialize_checked(program_id, ticket, operator, &avs)?;
+            let mut seeds = OperatorAvsTicket::seeds(operator, &avs);
+            seeds.push(vec![ticket.bump()]);
+            seeds
+        }
+        AccountType::OperatorVaultTicket => {
+            let vault =
+                OperatorVaultTicket::deserialize(&mut ticket.data.borrow().as_ref())?.vault();
+            let ticket =
+                OperatorVaultTicket::deserialize_checked(program_id, ticket, operator, &vault)?;
+            let mut seeds = OperatorVaultTicket::seeds(operator, &vault);
+            seeds.push(vec![ticket.bump()]);
+            seeds
+        }
+        AccountType::OperatorAvsHeartbeat => {
+            let avs = OperatorAvsHeartbeat::deserialize(&mut ticket.data.borrow().as_ref())?.avs();
+            let heartbeat =
+                OperatorAvsHeartbeat::deserialize_checked(program_id, ticket, operator, &avs)?;
+            let mut seeds = OperatorAvsHeartbeat::seeds(operator, &avs);
+            seeds.push(vec![heartbeat.bump()]);
+            seeds
+        }
+        AccountType::OperatorAvsScore => {
+            let avs = OperatorAvsScore::deserialize(&mut ticket.data.borrow().as_ref())?.avs();
+            let score = OperatorAvsScore::deserialize_checked(program_id, ticket, operator, &avs)?;
+            let mut seeds = OperatorAvsScore::seeds(operator, &avs);
+            seeds.push(vec![score.bump()]);
+            seeds
+        }
+        AccountType::OperatorStakeHistory => {
+            let history = OperatorStakeHistory::deserialize_checked(program_id, ticket, operator)?;
+            let mut seeds = OperatorStakeHistory::seeds(operator);
+            seeds.push(vec![history.bump()]);
+            seeds
+        }
+        _ => return Err(RestakingError::SweepAccountNotSweepable.into()),
+    };
+    Ok(seeds)
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    parent: &'a AccountInfo<'info>,
+    pda: &'a AccountInfo<'info>,
+    pda_token_account: SanitizedTokenAccount<'a, 'info>,
+    treasury_token_account: &'a AccountInfo<'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::SweepTokens`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+        token_mint: &Pubkey,
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        // checked against the ticket type in the processor
+        let parent = next_account_info(accounts_iter)?;
+        let pda = next_account_info(accounts_iter)?;
+        if pda.owner != program_id {
+            msg!("Account {} is not owned by the restaking program", pda.key);
+            return Err(RestakingError::AccountInvalidProgramOwner.into());
+        }
+        let pda_token_account = SanitizedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            token_mint,
+            pda.key,
+        )?;
+        let treasury_token_account = next_account_info(accounts_iter)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let _token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            parent,
+            pda,
+            pda_token_account,
+            treasury_token_account,
+            admin,
+        })
+    }
+}
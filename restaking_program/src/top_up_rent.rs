@@ -0,0 +1,77 @@
+use jito_restaking_sanitization::{
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram, top_up_rent,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Anyone can pay to bring an account owned by the program back to rent exemption at its current
+/// size, for accounts that grew through a realloc-based migration without being topped up.
+///
+/// [`crate::RestakingInstruction::TopUpRent`]
+pub fn process_top_up_rent(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        account,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let amount = top_up_rent(
+        account,
+        payer.account(),
+        system_program.account(),
+        &Rent::get()?,
+    )?;
+
+    msg!(
+        "RentToppedUp: account={} payer={} amount={}",
+        account.key,
+        payer.account().key,
+        amount
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::TopUpRent`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let account = next_account_info(accounts_iter)?;
+        if account.owner != program_id {
+            msg!(
+                "Account {} is not owned by the restaking program",
+                account.key
+            );
+            return Err(RestakingError::AccountInvalidProgramOwner.into());
+        }
+        if !account.is_writable {
+            return Err(RestakingError::AccountExpectedWritable.into());
+        }
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            account,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,150 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs,
+    metadata::{Metadata, SanitizedMetadata},
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_restaking_sdk::{
+    error::RestakingError,
+    event::{Event, RestakingEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// The AVS metadata admin sets the name, URI and icon of the AVS, creating the metadata account on
+/// first use.
+///
+/// [`crate::RestakingInstruction::UpdateAvsMetadata`]
+pub fn process_update_avs_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+    uri: String,
+    icon: String,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        avs,
+        metadata,
+        metadata_admin,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    avs.avs()
+        .check_metadata_admin(metadata_admin.account().key)?;
+
+    update_metadata(
+        program_id,
+        avs.account().key,
+        metadata,
+        &payer,
+        &system_program,
+        &name,
+        &uri,
+        &icon,
+    )
+}
+
+/// Writes the metadata of the AVS or operator, creating the account at its PDA if it's empty
+#[allow(clippy::too_many_arguments)]
+pub fn update_metadata<'a, 'info>(
+    program_id: &Pubkey,
+    parent: &Pubkey,
+    metadata_account: &'a AccountInfo<'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    name: &str,
+    uri: &str,
+    icon: &str,
+) -> ProgramResult {
+    if metadata_account.data_is_empty() {
+        let (address, bump, mut seeds) = Metadata::find_program_address(program_id, parent);
+        seeds.push(vec![bump]);
+
+        assert_with_msg(
+            address == *metadata_account.key,
+            RestakingError::MetadataInvalidPda,
+            "Metadata is not at the correct PDA",
+        )?;
+
+        msg!("Creating metadata: {:?}", metadata_account.key);
+        let metadata = Metadata::new(*parent, bump);
+        let serialized = metadata.as_bytes();
+        create_account(
+            payer.account(),
+            metadata_account,
+            system_program.account(),
+            program_id,
+            &Rent::get()?,
+            serialized.len() as u64,
+            &seeds,
+        )?;
+        metadata_account.data.borrow_mut()[..serialized.len()].copy_from_slice(serialized);
+    }
+
+    let mut metadata = SanitizedMetadata::sanitize(program_id, metadata_account, true, parent)?;
+    metadata.metadata_mut().set(name, uri, icon)?;
+
+    msg!(
+        "MetadataUpdated: parent={} metadata={} name={} uri={} icon={}",
+        parent,
+        metadata_account.key,
+        name,
+        uri,
+        icon
+    );
+    RestakingEvent::MetadataUpdated {
+        parent: *parent,
+        metadata: *metadata_account.key,
+        name: name.to_string(),
+        uri: uri.to_string(),
+        icon: icon.to_string(),
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    avs: SanitizedAvs<'a, 'info>,
+    metadata: &'a AccountInfo<'info>,
+    metadata_admin: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::UpdateAvsMetadata`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let avs = SanitizedAvs::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        // sanitized once it exists, it may be created by the instruction
+        let metadata = next_account_info(accounts_iter)?;
+        let metadata_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            avs,
+            metadata,
+            metadata_admin,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,82 @@
+use jito_restaking_core::operator::SanitizedOperator;
+use jito_restaking_sanitization::{
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::update_avs_metadata::update_metadata;
+
+/// The operator metadata admin sets the name, URI and icon of the operator, creating the metadata
+/// account on first use.
+///
+/// [`crate::RestakingInstruction::UpdateOperatorMetadata`]
+pub fn process_update_operator_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+    uri: String,
+    icon: String,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        operator,
+        metadata,
+        metadata_admin,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    operator
+        .operator()
+        .check_metadata_admin(metadata_admin.account().key)?;
+
+    update_metadata(
+        program_id,
+        operator.account().key,
+        metadata,
+        &payer,
+        &system_program,
+        &name,
+        &uri,
+        &icon,
+    )
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    operator: SanitizedOperator<'a, 'info>,
+    metadata: &'a AccountInfo<'info>,
+    metadata_admin: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::RestakingInstruction::UpdateOperatorMetadata`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let operator =
+            SanitizedOperator::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        // sanitized once it exists, it may be created by the instruction
+        let metadata = next_account_info(accounts_iter)?;
+        let metadata_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            operator,
+            metadata,
+            metadata_admin,
+            payer,
+            system_program,
+        })
+    }
+}
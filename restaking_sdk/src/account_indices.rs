@@ -0,0 +1,479 @@
+//! Account positions for each [`crate::RestakingInstruction`] variant.
+//!
+//! These match the `#[account]` attributes on the enum and the order used by the instruction
+//! builders. Transaction inspection tools and external signers can use them to find an account
+//! by role.
+
+/// [`crate::RestakingInstruction::InitializeConfig`]
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_CONFIG: usize = 0;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_ADMIN: usize = 1;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_VAULT_PROGRAM: usize = 2;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_PAYER: usize = 3;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 4;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_PROGRAM_DATA: usize = 5;
+
+/// [`crate::RestakingInstruction::InitializeAvs`]
+pub const INITIALIZE_AVS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const INITIALIZE_AVS_IX_ACCOUNT_AVS: usize = 1;
+pub const INITIALIZE_AVS_IX_ACCOUNT_ADMIN: usize = 2;
+pub const INITIALIZE_AVS_IX_ACCOUNT_BASE: usize = 3;
+pub const INITIALIZE_AVS_IX_ACCOUNT_PAYER: usize = 4;
+pub const INITIALIZE_AVS_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 5;
+
+/// [`crate::RestakingInstruction::AvsAddVault`]
+pub const AVS_ADD_VAULT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_ADD_VAULT_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_ADD_VAULT_IX_ACCOUNT_VAULT: usize = 2;
+pub const AVS_ADD_VAULT_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 3;
+pub const AVS_ADD_VAULT_IX_ACCOUNT_ADMIN: usize = 4;
+pub const AVS_ADD_VAULT_IX_ACCOUNT_PAYER: usize = 5;
+pub const AVS_ADD_VAULT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 6;
+
+/// [`crate::RestakingInstruction::AvsRemoveVault`]
+pub const AVS_REMOVE_VAULT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_REMOVE_VAULT_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_REMOVE_VAULT_IX_ACCOUNT_VAULT: usize = 2;
+pub const AVS_REMOVE_VAULT_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 3;
+pub const AVS_REMOVE_VAULT_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::RestakingInstruction::AvsAddOperator`]
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 3;
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 4;
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_ADMIN: usize = 5;
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_PAYER: usize = 6;
+pub const AVS_ADD_OPERATOR_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::RestakingInstruction::AvsRemoveOperator`]
+pub const AVS_REMOVE_OPERATOR_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_REMOVE_OPERATOR_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_REMOVE_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const AVS_REMOVE_OPERATOR_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 3;
+pub const AVS_REMOVE_OPERATOR_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::RestakingInstruction::AvsAddVaultSlasher`]
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_VAULT: usize = 2;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_SLASHER: usize = 3;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 4;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_AVS_SLASHER_TICKET: usize = 5;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_ADMIN: usize = 6;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_PAYER: usize = 7;
+pub const AVS_ADD_VAULT_SLASHER_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 8;
+
+/// [`crate::RestakingInstruction::AvsRemoveVaultSlasher`]
+pub const AVS_REMOVE_VAULT_SLASHER_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_REMOVE_VAULT_SLASHER_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_REMOVE_VAULT_SLASHER_IX_ACCOUNT_VAULT: usize = 2;
+pub const AVS_REMOVE_VAULT_SLASHER_IX_ACCOUNT_SLASHER: usize = 3;
+pub const AVS_REMOVE_VAULT_SLASHER_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 4;
+pub const AVS_REMOVE_VAULT_SLASHER_IX_ACCOUNT_AVS_SLASHER_TICKET: usize = 5;
+pub const AVS_REMOVE_VAULT_SLASHER_IX_ACCOUNT_ADMIN: usize = 6;
+
+/// [`crate::RestakingInstruction::AvsSetAdmin`]
+pub const AVS_SET_ADMIN_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_SET_ADMIN_IX_ACCOUNT_OLD_ADMIN: usize = 1;
+pub const AVS_SET_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::AvsSetSecondaryAdmin`]
+pub const AVS_SET_SECONDARY_ADMIN_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_SET_SECONDARY_ADMIN_IX_ACCOUNT_ADMIN: usize = 1;
+pub const AVS_SET_SECONDARY_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::InitializeOperator`]
+pub const INITIALIZE_OPERATOR_IX_ACCOUNT_CONFIG: usize = 0;
+pub const INITIALIZE_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const INITIALIZE_OPERATOR_IX_ACCOUNT_ADMIN: usize = 2;
+pub const INITIALIZE_OPERATOR_IX_ACCOUNT_BASE: usize = 3;
+pub const INITIALIZE_OPERATOR_IX_ACCOUNT_PAYER: usize = 4;
+pub const INITIALIZE_OPERATOR_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 5;
+
+/// [`crate::RestakingInstruction::OperatorSetAdmin`]
+pub const OPERATOR_SET_ADMIN_IX_ACCOUNT_NODE_OPERATOR: usize = 0;
+pub const OPERATOR_SET_ADMIN_IX_ACCOUNT_OLD_ADMIN: usize = 1;
+pub const OPERATOR_SET_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::OperatorSetVoter`]
+pub const OPERATOR_SET_VOTER_IX_ACCOUNT_NODE_OPERATOR: usize = 0;
+pub const OPERATOR_SET_VOTER_IX_ACCOUNT_ADMIN: usize = 1;
+pub const OPERATOR_SET_VOTER_IX_ACCOUNT_VOTER: usize = 2;
+
+/// [`crate::RestakingInstruction::OperatorAddVault`]
+pub const OPERATOR_ADD_VAULT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const OPERATOR_ADD_VAULT_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const OPERATOR_ADD_VAULT_IX_ACCOUNT_VAULT: usize = 2;
+pub const OPERATOR_ADD_VAULT_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 3;
+pub const OPERATOR_ADD_VAULT_IX_ACCOUNT_ADMIN: usize = 4;
+pub const OPERATOR_ADD_VAULT_IX_ACCOUNT_PAYER: usize = 5;
+pub const OPERATOR_ADD_VAULT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 6;
+
+/// [`crate::RestakingInstruction::OperatorRemoveVault`]
+pub const OPERATOR_REMOVE_VAULT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const OPERATOR_REMOVE_VAULT_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const OPERATOR_REMOVE_VAULT_IX_ACCOUNT_VAULT: usize = 2;
+pub const OPERATOR_REMOVE_VAULT_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 3;
+pub const OPERATOR_REMOVE_VAULT_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::RestakingInstruction::OperatorAddAvs`]
+pub const OPERATOR_ADD_AVS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const OPERATOR_ADD_AVS_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const OPERATOR_ADD_AVS_IX_ACCOUNT_AVS: usize = 2;
+pub const OPERATOR_ADD_AVS_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 3;
+pub const OPERATOR_ADD_AVS_IX_ACCOUNT_ADMIN: usize = 4;
+pub const OPERATOR_ADD_AVS_IX_ACCOUNT_PAYER: usize = 5;
+pub const OPERATOR_ADD_AVS_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 6;
+
+/// [`crate::RestakingInstruction::OperatorRemoveAvs`]
+pub const OPERATOR_REMOVE_AVS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const OPERATOR_REMOVE_AVS_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const OPERATOR_REMOVE_AVS_IX_ACCOUNT_AVS: usize = 2;
+pub const OPERATOR_REMOVE_AVS_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 3;
+pub const OPERATOR_REMOVE_AVS_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::RestakingInstruction::AvsWithdrawalAsset`]
+pub const AVS_WITHDRAWAL_ASSET_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_WITHDRAWAL_ASSET_IX_ACCOUNT_AVS_TOKEN_ACCOUNT: usize = 1;
+pub const AVS_WITHDRAWAL_ASSET_IX_ACCOUNT_RECEIVER_TOKEN_ACCOUNT: usize = 2;
+pub const AVS_WITHDRAWAL_ASSET_IX_ACCOUNT_ADMIN: usize = 3;
+pub const AVS_WITHDRAWAL_ASSET_IX_ACCOUNT_TOKEN_PROGRAM: usize = 4;
+
+/// [`crate::RestakingInstruction::OperatorWithdrawalAsset`]
+pub const OPERATOR_WITHDRAWAL_ASSET_IX_ACCOUNT_OPERATOR: usize = 0;
+pub const OPERATOR_WITHDRAWAL_ASSET_IX_ACCOUNT_ADMIN: usize = 1;
+pub const OPERATOR_WITHDRAWAL_ASSET_IX_ACCOUNT_OPERATOR_TOKEN_ACCOUNT: usize = 2;
+pub const OPERATOR_WITHDRAWAL_ASSET_IX_ACCOUNT_RECEIVER_TOKEN_ACCOUNT: usize = 3;
+pub const OPERATOR_WITHDRAWAL_ASSET_IX_ACCOUNT_TOKEN_PROGRAM: usize = 4;
+
+/// [`crate::RestakingInstruction::AvsSetVaultServiceFee`]
+pub const AVS_SET_VAULT_SERVICE_FEE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_SET_VAULT_SERVICE_FEE_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_SET_VAULT_SERVICE_FEE_IX_ACCOUNT_VAULT: usize = 2;
+pub const AVS_SET_VAULT_SERVICE_FEE_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 3;
+pub const AVS_SET_VAULT_SERVICE_FEE_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::RestakingInstruction::OperatorHeartbeat`]
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_AVS: usize = 2;
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 3;
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_OPERATOR_AVS_HEARTBEAT: usize = 4;
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_VOTER: usize = 5;
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_PAYER: usize = 6;
+pub const OPERATOR_HEARTBEAT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::RestakingInstruction::AvsSetMaxHeartbeatAge`]
+pub const AVS_SET_MAX_HEARTBEAT_AGE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_SET_MAX_HEARTBEAT_AGE_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_SET_MAX_HEARTBEAT_AGE_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::AvsEjectStaleOperator`]
+pub const AVS_EJECT_STALE_OPERATOR_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_EJECT_STALE_OPERATOR_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_EJECT_STALE_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const AVS_EJECT_STALE_OPERATOR_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 3;
+pub const AVS_EJECT_STALE_OPERATOR_IX_ACCOUNT_OPERATOR_AVS_HEARTBEAT: usize = 4;
+
+/// [`crate::RestakingInstruction::AvsReactivateOperator`]
+pub const AVS_REACTIVATE_OPERATOR_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_REACTIVATE_OPERATOR_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_REACTIVATE_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const AVS_REACTIVATE_OPERATOR_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 3;
+pub const AVS_REACTIVATE_OPERATOR_IX_ACCOUNT_OPERATOR_AVS_HEARTBEAT: usize = 4;
+
+/// [`crate::RestakingInstruction::OperatorExitAll`]
+pub const OPERATOR_EXIT_ALL_IX_ACCOUNT_CONFIG: usize = 0;
+pub const OPERATOR_EXIT_ALL_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const OPERATOR_EXIT_ALL_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::AssertAvsHealthy`]
+pub const ASSERT_AVS_HEALTHY_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ASSERT_AVS_HEALTHY_IX_ACCOUNT_AVS: usize = 1;
+
+/// [`crate::RestakingInstruction::AssertOperatorHealthy`]
+pub const ASSERT_OPERATOR_HEALTHY_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ASSERT_OPERATOR_HEALTHY_IX_ACCOUNT_OPERATOR: usize = 1;
+
+/// [`crate::RestakingInstruction::ConfigAddSlasherProgram`]
+pub const CONFIG_ADD_SLASHER_PROGRAM_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_ADD_SLASHER_PROGRAM_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::ConfigRemoveSlasherProgram`]
+pub const CONFIG_REMOVE_SLASHER_PROGRAM_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_REMOVE_SLASHER_PROGRAM_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::SweepTokens`]
+pub const SWEEP_TOKENS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SWEEP_TOKENS_IX_ACCOUNT_PARENT: usize = 1;
+pub const SWEEP_TOKENS_IX_ACCOUNT_PDA: usize = 2;
+pub const SWEEP_TOKENS_IX_ACCOUNT_PDA_TOKEN_ACCOUNT: usize = 3;
+pub const SWEEP_TOKENS_IX_ACCOUNT_TREASURY_TOKEN_ACCOUNT: usize = 4;
+pub const SWEEP_TOKENS_IX_ACCOUNT_ADMIN: usize = 5;
+pub const SWEEP_TOKENS_IX_ACCOUNT_TOKEN_PROGRAM: usize = 6;
+
+/// [`crate::RestakingInstruction::TopUpRent`]
+pub const TOP_UP_RENT_IX_ACCOUNT_ACCOUNT: usize = 0;
+pub const TOP_UP_RENT_IX_ACCOUNT_PAYER: usize = 1;
+pub const TOP_UP_RENT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 2;
+
+/// [`crate::RestakingInstruction::SweepLamports`]
+pub const SWEEP_LAMPORTS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SWEEP_LAMPORTS_IX_ACCOUNT_PARENT: usize = 1;
+pub const SWEEP_LAMPORTS_IX_ACCOUNT_PDA: usize = 2;
+pub const SWEEP_LAMPORTS_IX_ACCOUNT_ADMIN: usize = 3;
+
+/// [`crate::RestakingInstruction::AvsSetRelayer`]
+pub const AVS_SET_RELAYER_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_SET_RELAYER_IX_ACCOUNT_ADMIN: usize = 1;
+pub const AVS_SET_RELAYER_IX_ACCOUNT_RELAYER: usize = 2;
+
+/// [`crate::RestakingInstruction::OperatorSetRelayer`]
+pub const OPERATOR_SET_RELAYER_IX_ACCOUNT_OPERATOR: usize = 0;
+pub const OPERATOR_SET_RELAYER_IX_ACCOUNT_ADMIN: usize = 1;
+pub const OPERATOR_SET_RELAYER_IX_ACCOUNT_RELAYER: usize = 2;
+
+/// [`crate::RestakingInstruction::RelayAdminOperation`]
+pub const RELAY_ADMIN_OPERATION_IX_ACCOUNT_CONFIG: usize = 0;
+pub const RELAY_ADMIN_OPERATION_IX_ACCOUNT_ACCOUNT: usize = 1;
+pub const RELAY_ADMIN_OPERATION_IX_ACCOUNT_RELAYER: usize = 2;
+pub const RELAY_ADMIN_OPERATION_IX_ACCOUNT_INSTRUCTIONS_SYSVAR: usize = 3;
+
+/// [`crate::RestakingInstruction::AvsSetMaxSlashersPerVault`]
+pub const AVS_SET_MAX_SLASHERS_PER_VAULT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_SET_MAX_SLASHERS_PER_VAULT_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_SET_MAX_SLASHERS_PER_VAULT_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::ConfigSetTicketEpochLength`]
+pub const CONFIG_SET_TICKET_EPOCH_LENGTH_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_TICKET_EPOCH_LENGTH_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::OperatorSetSecondaryAdmin`]
+pub const OPERATOR_SET_SECONDARY_ADMIN_IX_ACCOUNT_OPERATOR: usize = 0;
+pub const OPERATOR_SET_SECONDARY_ADMIN_IX_ACCOUNT_ADMIN: usize = 1;
+pub const OPERATOR_SET_SECONDARY_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::AvsAcceptAdmin`]
+pub const AVS_ACCEPT_ADMIN_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_ACCEPT_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::OperatorAcceptAdmin`]
+pub const OPERATOR_ACCEPT_ADMIN_IX_ACCOUNT_OPERATOR: usize = 0;
+pub const OPERATOR_ACCEPT_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::ConfigSetAdmin`]
+pub const CONFIG_SET_ADMIN_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_ADMIN_IX_ACCOUNT_ADMIN: usize = 1;
+pub const CONFIG_SET_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::ConfigAcceptAdmin`]
+pub const CONFIG_ACCEPT_ADMIN_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_ACCEPT_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::AvsInitializeRewardBudget`]
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_AVS_REWARD_BUDGET: usize = 2;
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_MINT: usize = 3;
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_RECEIVER_TOKEN_ACCOUNT: usize = 4;
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_ADMIN: usize = 5;
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_PAYER: usize = 6;
+pub const AVS_INITIALIZE_REWARD_BUDGET_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::RestakingInstruction::AvsSetRewardBudget`]
+pub const AVS_SET_REWARD_BUDGET_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_SET_REWARD_BUDGET_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_SET_REWARD_BUDGET_IX_ACCOUNT_AVS_REWARD_BUDGET: usize = 2;
+pub const AVS_SET_REWARD_BUDGET_IX_ACCOUNT_MINT: usize = 3;
+pub const AVS_SET_REWARD_BUDGET_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::RestakingInstruction::AvsDistributeRewards`]
+pub const AVS_DISTRIBUTE_REWARDS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_DISTRIBUTE_REWARDS_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_DISTRIBUTE_REWARDS_IX_ACCOUNT_AVS_REWARD_BUDGET: usize = 2;
+pub const AVS_DISTRIBUTE_REWARDS_IX_ACCOUNT_MINT: usize = 3;
+pub const AVS_DISTRIBUTE_REWARDS_IX_ACCOUNT_AVS_TOKEN_ACCOUNT: usize = 4;
+pub const AVS_DISTRIBUTE_REWARDS_IX_ACCOUNT_RECEIVER_TOKEN_ACCOUNT: usize = 5;
+pub const AVS_DISTRIBUTE_REWARDS_IX_ACCOUNT_TOKEN_PROGRAM: usize = 6;
+
+/// [`crate::RestakingInstruction::AvsSetMultisig`]
+pub const AVS_SET_MULTISIG_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_SET_MULTISIG_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::AvsSetRentCollector`]
+pub const AVS_SET_RENT_COLLECTOR_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_SET_RENT_COLLECTOR_IX_ACCOUNT_ADMIN: usize = 1;
+pub const AVS_SET_RENT_COLLECTOR_IX_ACCOUNT_RENT_COLLECTOR: usize = 2;
+
+/// [`crate::RestakingInstruction::OperatorSetRentCollector`]
+pub const OPERATOR_SET_RENT_COLLECTOR_IX_ACCOUNT_OPERATOR: usize = 0;
+pub const OPERATOR_SET_RENT_COLLECTOR_IX_ACCOUNT_ADMIN: usize = 1;
+pub const OPERATOR_SET_RENT_COLLECTOR_IX_ACCOUNT_RENT_COLLECTOR: usize = 2;
+
+/// [`crate::RestakingInstruction::CloseTicket`]
+pub const CLOSE_TICKET_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CLOSE_TICKET_IX_ACCOUNT_PARENT: usize = 1;
+pub const CLOSE_TICKET_IX_ACCOUNT_TICKET: usize = 2;
+pub const CLOSE_TICKET_IX_ACCOUNT_RENT_COLLECTOR: usize = 3;
+pub const CLOSE_TICKET_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::RestakingInstruction::UpdateAvsMetadata`]
+pub const UPDATE_AVS_METADATA_IX_ACCOUNT_AVS: usize = 0;
+pub const UPDATE_AVS_METADATA_IX_ACCOUNT_METADATA: usize = 1;
+pub const UPDATE_AVS_METADATA_IX_ACCOUNT_METADATA_ADMIN: usize = 2;
+pub const UPDATE_AVS_METADATA_IX_ACCOUNT_PAYER: usize = 3;
+pub const UPDATE_AVS_METADATA_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 4;
+
+/// [`crate::RestakingInstruction::UpdateOperatorMetadata`]
+pub const UPDATE_OPERATOR_METADATA_IX_ACCOUNT_OPERATOR: usize = 0;
+pub const UPDATE_OPERATOR_METADATA_IX_ACCOUNT_METADATA: usize = 1;
+pub const UPDATE_OPERATOR_METADATA_IX_ACCOUNT_METADATA_ADMIN: usize = 2;
+pub const UPDATE_OPERATOR_METADATA_IX_ACCOUNT_PAYER: usize = 3;
+pub const UPDATE_OPERATOR_METADATA_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 4;
+
+/// [`crate::RestakingInstruction::MigrateAccount`]
+pub const MIGRATE_ACCOUNT_IX_ACCOUNT_ACCOUNT: usize = 0;
+pub const MIGRATE_ACCOUNT_IX_ACCOUNT_PAYER: usize = 1;
+pub const MIGRATE_ACCOUNT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 2;
+
+/// [`crate::RestakingInstruction::OperatorSetAvsCapacity`]
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_CONFIG: usize = 0;
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_AVS: usize = 2;
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 3;
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_OPERATOR_AVS_CAPACITY: usize = 4;
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_ADMIN: usize = 5;
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_PAYER: usize = 6;
+pub const OPERATOR_SET_AVS_CAPACITY_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::RestakingInstruction::ConfigSetOperatorFeeParams`]
+pub const CONFIG_SET_OPERATOR_FEE_PARAMS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_OPERATOR_FEE_PARAMS_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::SetOperatorFee`]
+pub const SET_OPERATOR_FEE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_OPERATOR_FEE_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const SET_OPERATOR_FEE_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::RestakingInstruction::AvsDepositEpochRewards`]
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_VAULT_CONFIG: usize = 2;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_EPOCH_REWARD_ROUTER: usize = 3;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_MINT: usize = 4;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_ROUTER_TOKEN_ACCOUNT: usize = 5;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_DEPOSITOR_TOKEN_ACCOUNT: usize = 6;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_DEPOSITOR: usize = 7;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_PAYER: usize = 8;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_TOKEN_PROGRAM: usize = 9;
+pub const AVS_DEPOSIT_EPOCH_REWARDS_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 10;
+
+/// [`crate::RestakingInstruction::SnapshotEpochRewardStake`]
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_AVS: usize = 1;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_VAULT: usize = 3;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_VAULT_CONFIG: usize = 4;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 5;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 6;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 7;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 8;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 9;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_EPOCH_REWARD_ROUTER: usize = 10;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_EPOCH_REWARD_SNAPSHOT: usize = 11;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_OPERATOR_AVS_SCORE: usize = 12;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_PAYER: usize = 13;
+pub const SNAPSHOT_EPOCH_REWARD_STAKE_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 14;
+
+/// [`crate::RestakingInstruction::DistributeEpochRewards`]
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_AVS: usize = 1;
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_EPOCH_REWARD_ROUTER: usize = 2;
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_EPOCH_REWARD_SNAPSHOT: usize = 3;
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_ROUTER_TOKEN_ACCOUNT: usize = 4;
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_OPERATOR_TOKEN_ACCOUNT: usize = 5;
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 6;
+pub const DISTRIBUTE_EPOCH_REWARDS_IX_ACCOUNT_TOKEN_PROGRAM: usize = 7;
+
+/// [`crate::RestakingInstruction::AvsReclaimEpochRewards`]
+pub const AVS_RECLAIM_EPOCH_REWARDS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_RECLAIM_EPOCH_REWARDS_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_RECLAIM_EPOCH_REWARDS_IX_ACCOUNT_EPOCH_REWARD_ROUTER: usize = 2;
+pub const AVS_RECLAIM_EPOCH_REWARDS_IX_ACCOUNT_ROUTER_TOKEN_ACCOUNT: usize = 3;
+pub const AVS_RECLAIM_EPOCH_REWARDS_IX_ACCOUNT_RECEIVER_TOKEN_ACCOUNT: usize = 4;
+pub const AVS_RECLAIM_EPOCH_REWARDS_IX_ACCOUNT_ADMIN: usize = 5;
+pub const AVS_RECLAIM_EPOCH_REWARDS_IX_ACCOUNT_TOKEN_PROGRAM: usize = 6;
+
+/// [`crate::RestakingInstruction::SnapshotEpochStake`]
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_VAULT: usize = 2;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_VAULT_CONFIG: usize = 3;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 4;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 5;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_OPERATOR_EPOCH_STAKE: usize = 6;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_VAULT_OPERATOR_EPOCH_STAKE: usize = 7;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_PAYER: usize = 8;
+pub const SNAPSHOT_EPOCH_STAKE_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 9;
+
+/// [`crate::RestakingInstruction::AvsSetScorer`]
+pub const AVS_SET_SCORER_IX_ACCOUNT_AVS: usize = 0;
+pub const AVS_SET_SCORER_IX_ACCOUNT_ADMIN: usize = 1;
+pub const AVS_SET_SCORER_IX_ACCOUNT_SCORER: usize = 2;
+
+/// [`crate::RestakingInstruction::AvsAttestOperatorScore`]
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_AVS: usize = 1;
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 3;
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_OPERATOR_AVS_SCORE: usize = 4;
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_SCORER: usize = 5;
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_PAYER: usize = 6;
+pub const AVS_ATTEST_OPERATOR_SCORE_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::RestakingInstruction::ArchiveEpochStake`]
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_OPERATOR: usize = 1;
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_VAULT_CONFIG: usize = 2;
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_OPERATOR_STAKE_HISTORY: usize = 3;
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_EPOCH_STAKE: usize = 4;
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_RENT_COLLECTOR: usize = 5;
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_PAYER: usize = 6;
+pub const ARCHIVE_EPOCH_STAKE_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::RestakingInstruction::ConfigSetParameter`]
+pub const CONFIG_SET_PARAMETER_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_PARAMETER_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::RestakingInstruction::PostRewardRoot`]
+pub const POST_REWARD_ROOT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_SOURCE: usize = 1;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_REWARD_DISTRIBUTION: usize = 2;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_MINT: usize = 3;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_DISTRIBUTION_TOKEN_ACCOUNT: usize = 4;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_ADMIN_TOKEN_ACCOUNT: usize = 5;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_ADMIN: usize = 6;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_PAYER: usize = 7;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_TOKEN_PROGRAM: usize = 8;
+pub const POST_REWARD_ROOT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 9;
+
+/// [`crate::RestakingInstruction::ClaimReward`]
+pub const CLAIM_REWARD_IX_ACCOUNT_REWARD_DISTRIBUTION: usize = 0;
+pub const CLAIM_REWARD_IX_ACCOUNT_CLAIM_STATUS: usize = 1;
+pub const CLAIM_REWARD_IX_ACCOUNT_CLAIMANT: usize = 2;
+pub const CLAIM_REWARD_IX_ACCOUNT_MINT: usize = 3;
+pub const CLAIM_REWARD_IX_ACCOUNT_DISTRIBUTION_TOKEN_ACCOUNT: usize = 4;
+pub const CLAIM_REWARD_IX_ACCOUNT_CLAIMANT_TOKEN_ACCOUNT: usize = 5;
+pub const CLAIM_REWARD_IX_ACCOUNT_PAYER: usize = 6;
+pub const CLAIM_REWARD_IX_ACCOUNT_TOKEN_PROGRAM: usize = 7;
+pub const CLAIM_REWARD_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 8;
+
+/// [`crate::RestakingInstruction::FinalizeOperatorAvsRemoval`]
+pub const FINALIZE_OPERATOR_AVS_REMOVAL_IX_ACCOUNT_CONFIG: usize = 0;
+pub const FINALIZE_OPERATOR_AVS_REMOVAL_IX_ACCOUNT_AVS: usize = 1;
+pub const FINALIZE_OPERATOR_AVS_REMOVAL_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const FINALIZE_OPERATOR_AVS_REMOVAL_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 3;
+pub const FINALIZE_OPERATOR_AVS_REMOVAL_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 4;
+pub const FINALIZE_OPERATOR_AVS_REMOVAL_IX_ACCOUNT_AVS_RENT_COLLECTOR: usize = 5;
+pub const FINALIZE_OPERATOR_AVS_REMOVAL_IX_ACCOUNT_OPERATOR_RENT_COLLECTOR: usize = 6;
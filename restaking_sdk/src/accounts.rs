@@ -0,0 +1,158 @@
+//! Decodes the raw data of restaking program accounts for indexers and explorers.
+//!
+//! The first byte of every account is its [`AccountType`], which picks the layout
+//! [`decode_restaking_account`] loads the data as. The decoded accounts serialize with serde, so
+//! `serde_json::to_value(&account)` gives `{"type": "Avs", "account": {...}}` with pubkeys and
+//! hashes as base58 strings and without the discriminator or reserved space. The account types are
+//! re-exported here so callers only need this crate.
+use borsh::BorshDeserialize;
+use jito_jsm_core::zero_copy::{ZeroCopy, ZeroCopyError};
+pub use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, avs_reward_budget::AvsRewardBudget,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config, epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot, metadata::Metadata, operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore, operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket, reward_claim_status::RewardClaimStatus,
+    reward_distribution::RewardDistribution, vault_operator_epoch_stake::VaultOperatorEpochStake,
+    AccountType,
+};
+use serde::Serialize;
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum AccountDecodeError {
+    #[error("account data is empty")]
+    Empty,
+    #[error("unknown account type {0}")]
+    UnknownAccountType(u8),
+    #[error(transparent)]
+    InvalidLayout(#[from] ZeroCopyError),
+}
+
+macro_rules! restaking_accounts {
+    ($($account:ident),* $(,)?) => {
+        /// An account of the restaking program, see [`decode_restaking_account`]
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Debug, Clone, Copy, Serialize)]
+        #[serde(tag = "type", content = "account")]
+        pub enum RestakingAccount {
+            $($account($account),)*
+        }
+
+        impl RestakingAccount {
+            pub const fn account_type(&self) -> AccountType {
+                match self {
+                    $(Self::$account(_) => AccountType::$account,)*
+                }
+            }
+        }
+
+        /// Decodes the data of a restaking program account by its account type
+        pub fn decode_restaking_account(
+            data: &[u8],
+        ) -> Result<RestakingAccount, AccountDecodeError> {
+            let discriminator = *data.first().ok_or(AccountDecodeError::Empty)?;
+            let account_type = AccountType::try_from_slice(&[discriminator])
+                .map_err(|_| AccountDecodeError::UnknownAccountType(discriminator))?;
+            match account_type {
+                $(AccountType::$account => Ok(RestakingAccount::$account(*$account::load(data)?)),)*
+            }
+        }
+    };
+}
+
+restaking_accounts!(
+    Config,
+    Avs,
+    AvsOperatorTicket,
+    AvsVaultSlasherTicket,
+    AvsVaultTicket,
+    Operator,
+    OperatorAvsTicket,
+    OperatorVaultTicket,
+    OperatorAvsHeartbeat,
+    AvsRewardBudget,
+    Metadata,
+    OperatorAvsCapacity,
+    EpochRewardRouter,
+    EpochRewardSnapshot,
+    OperatorEpochStake,
+    VaultOperatorEpochStake,
+    OperatorAvsScore,
+    OperatorStakeHistory,
+    RewardDistribution,
+    RewardClaimStatus,
+);
+
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::zero_copy::{ZeroCopy, ZeroCopyError};
+    use serde_json::json;
+    use solana_program::pubkey::Pubkey;
+
+    use super::{
+        decode_restaking_account, AccountDecodeError, AccountType, AvsVaultTicket, Metadata,
+        RestakingAccount,
+    };
+
+    #[test]
+    fn test_decode_restaking_account() {
+        let (avs, vault) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let ticket = AvsVaultTicket::new(avs, vault, 3, 100, 254);
+        let RestakingAccount::AvsVaultTicket(decoded) =
+            decode_restaking_account(ticket.as_bytes()).unwrap()
+        else {
+            panic!("decoded as another account type");
+        };
+        assert_eq!(decoded.as_bytes(), ticket.as_bytes());
+
+        let json = serde_json::to_value(RestakingAccount::AvsVaultTicket(decoded)).unwrap();
+        assert_eq!(json["type"], "AvsVaultTicket");
+        assert_eq!(json["account"]["avs"], avs.to_string());
+        assert_eq!(json["account"]["vault"], vault.to_string());
+        assert_eq!(json["account"]["index"], 3);
+        assert_eq!(
+            json["account"]["state"],
+            json!({ "slot_added": 100, "slot_removed": 0 })
+        );
+        assert_eq!(json["account"].get("reserved"), None);
+        assert_eq!(json["account"].get("discriminator"), None);
+    }
+
+    #[test]
+    fn test_decode_metadata_as_text() {
+        let mut metadata = Metadata::new(Pubkey::new_unique(), 254);
+        metadata
+            .set("AVS", "https://example.com/avs.json", "")
+            .unwrap();
+        let decoded = decode_restaking_account(metadata.as_bytes()).unwrap();
+        assert_eq!(decoded.account_type(), AccountType::Metadata);
+        let json = serde_json::to_value(decoded).unwrap();
+        assert_eq!(json["account"]["name"], "AVS");
+        assert_eq!(json["account"]["uri"], "https://example.com/avs.json");
+        assert_eq!(json["account"]["icon"], "");
+    }
+
+    #[test]
+    fn test_decode_restaking_account_rejects_malformed_data() {
+        assert_eq!(
+            decode_restaking_account(&[]).unwrap_err(),
+            AccountDecodeError::Empty
+        );
+        assert_eq!(
+            decode_restaking_account(&[u8::MAX]).unwrap_err(),
+            AccountDecodeError::UnknownAccountType(u8::MAX)
+        );
+        let ticket = AvsVaultTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 254);
+        assert_eq!(
+            decode_restaking_account(&ticket.as_bytes()[..10]).unwrap_err(),
+            AccountDecodeError::InvalidLayout(ZeroCopyError::InvalidLength {
+                len: 10,
+                expected: AvsVaultTicket::LEN,
+            })
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Estimates of the yield an operator earned for the stake delegated to it, per AVS.
+//!
+//! The programs don't record reward history, commissions or stake over time, so the caller
+//! collects them, e.g. from an indexer, into an [`OperatorAvsHistory`] per operator and AVS.
+//! Yields are stake-weighted over the epochs of the history, net of the operator's commission,
+//! and annualized without compounding. Like fees, they are expressed in basis points.
+use std::cmp::Reverse;
+
+use solana_program::pubkey::Pubkey;
+
+/// The rewards an AVS paid for an operator in an epoch and the stake delegated to the operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochRewards {
+    pub epoch: u64,
+    /// Rewards paid before the operator's commission
+    pub rewards: u64,
+    /// Stake delegated to the operator during the epoch
+    pub stake: u64,
+}
+
+/// The reward history of an operator for an AVS
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorAvsHistory {
+    pub operator: Pubkey,
+    pub avs: Pubkey,
+    /// The share of rewards the operator keeps
+    pub commission_bps: u16,
+    pub epochs: Vec<EpochRewards>,
+}
+
+/// The realized yield of an operator for an AVS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApyEstimate {
+    pub operator: Pubkey,
+    pub avs: Pubkey,
+    pub apy_bps: u64,
+    /// The number of epochs with stake the estimate is based on
+    pub epochs: u64,
+}
+
+/// The annualized yield delegators earned over `epochs`, net of `commission_bps`. Returns None if
+/// no stake was delegated or the commission exceeds 100%.
+pub fn realized_apy_bps(
+    epochs: &[EpochRewards],
+    commission_bps: u16,
+    epochs_per_year: u64,
+) -> Option<u64> {
+    let delegator_share_bps = 10_000_u128.checked_sub(u128::from(commission_bps))?;
+    let (rewards, stake_epochs) =
+        epochs
+            .iter()
+            .try_fold((0_u128, 0_u128), |(rewards, stake_epochs), epoch| {
+                Some((
+                    rewards.checked_add(u128::from(epoch.rewards))?,
+                    stake_epochs.checked_add(u128::from(epoch.stake))?,
+                ))
+            })?;
+    let apy_bps = rewards
+        .checked_mul(delegator_share_bps)?
+        .checked_mul(u128::from(epochs_per_year))?
+        .checked_div(stake_epochs)?;
+    u64::try_from(apy_bps).ok()
+}
+
+/// Estimates the yield of each operator and AVS, highest first. Histories without stake are left
+/// out.
+pub fn estimate_apys(histories: &[OperatorAvsHistory], epochs_per_year: u64) -> Vec<ApyEstimate> {
+    let mut estimates: Vec<_> = histories
+        .iter()
+        .filter_map(|history| {
+            Some(ApyEstimate {
+                operator: history.operator,
+                avs: history.avs,
+                apy_bps: realized_apy_bps(
+                    &history.epochs,
+                    history.commission_bps,
+                    epochs_per_year,
+                )?,
+                epochs: history
+                    .epochs
+                    .iter()
+                    .filter(|epoch| epoch.stake > 0)
+                    .count() as u64,
+            })
+        })
+        .collect();
+    estimates.sort_by_key(|estimate| Reverse(estimate.apy_bps));
+    estimates
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::apy::{estimate_apys, realized_apy_bps, EpochRewards, OperatorAvsHistory};
+
+    const EPOCHS_PER_YEAR: u64 = 100;
+
+    fn epoch(epoch: u64, rewards: u64, stake: u64) -> EpochRewards {
+        EpochRewards {
+            epoch,
+            rewards,
+            stake,
+        }
+    }
+
+    #[test]
+    fn test_realized_apy_is_stake_weighted() {
+        // 0.1% per epoch on 1_000, then 0.1% per epoch on 3_000
+        let epochs = [epoch(0, 1, 1_000), epoch(1, 3, 3_000)];
+        assert_eq!(realized_apy_bps(&epochs, 0, EPOCHS_PER_YEAR), Some(1_000));
+        // a 10% commission leaves delegators 90% of the rewards
+        assert_eq!(realized_apy_bps(&epochs, 1_000, EPOCHS_PER_YEAR), Some(900));
+    }
+
+    #[test]
+    fn test_realized_apy_without_stake_or_bad_commission_is_none() {
+        assert_eq!(realized_apy_bps(&[], 0, EPOCHS_PER_YEAR), None);
+        assert_eq!(
+            realized_apy_bps(&[epoch(0, 10, 0)], 0, EPOCHS_PER_YEAR),
+            None
+        );
+        assert_eq!(
+            realized_apy_bps(&[epoch(0, 1, 1_000)], 10_001, EPOCHS_PER_YEAR),
+            None
+        );
+    }
+
+    #[test]
+    fn test_estimate_apys_sorted_highest_first() {
+        let avs = Pubkey::new_unique();
+        let low = OperatorAvsHistory {
+            operator: Pubkey::new_unique(),
+            avs,
+            commission_bps: 0,
+            epochs: vec![epoch(0, 1, 1_000), epoch(1, 0, 0)],
+        };
+        let high = OperatorAvsHistory {
+            operator: Pubkey::new_unique(),
+            avs,
+            commission_bps: 0,
+            epochs: vec![epoch(0, 2, 1_000)],
+        };
+        let unstaked = OperatorAvsHistory {
+            operator: Pubkey::new_unique(),
+            avs,
+            commission_bps: 0,
+            epochs: vec![],
+        };
+
+        let estimates = estimate_apys(&[low.clone(), high.clone(), unstaked], EPOCHS_PER_YEAR);
+        assert_eq!(estimates.len(), 2);
+        assert_eq!(estimates[0].operator, high.operator);
+        assert_eq!(estimates[0].apy_bps, 2_000);
+        assert_eq!(estimates[1].operator, low.operator);
+        assert_eq!(estimates[1].apy_bps, 1_000);
+        assert_eq!(estimates[1].epochs, 1);
+    }
+}
@@ -0,0 +1,395 @@
+//! Orders instructions by their declared prerequisites and packs them into as few transactions as
+//! fit, e.g. to crank a vault, claim referral fees and rebalance its delegations in one go.
+//!
+//! Each instruction is added with a priority. An instruction lands after every instruction it
+//! depends on, and among the instructions whose prerequisites are met the one with the highest
+//! priority goes first, ties keeping the order they were added in. The ordered instructions are then
+//! packed into transactions, starting a new one when the next instruction would push the current one
+//! over the packet size or the account lock limit. A prerequisite packed in an earlier transaction is
+//! only met once that transaction lands, so the transactions shall be landed in order.
+//!
+//! A [`ComputeBudget`] set on the batch is prepended to every transaction, its instructions counted
+//! against the size of the transaction.
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
+};
+
+use solana_program::{instruction::Instruction, pubkey, pubkey::Pubkey};
+use thiserror::Error;
+
+use crate::flows::FlowTransaction;
+
+/// The max number of accounts a transaction can lock, the program IDs included
+pub const MAX_TX_ACCOUNT_LOCKS: usize = 64;
+
+/// The compute budget program, whose instructions set the compute limit and priority fee of the
+/// transaction they're in
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// The compute unit limit and priority fee of each transaction of a batch, none setting the
+/// runtime defaults
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+    /// The max compute units a transaction can consume
+    pub unit_limit: Option<u32>,
+
+    /// The priority fee in micro-lamports per compute unit
+    pub unit_price: Option<u64>,
+}
+
+impl ComputeBudget {
+    /// The compute budget instructions, to be put before the other instructions of a transaction
+    pub fn instructions(&self) -> Vec<Instruction> {
+        // the variants of the compute budget program's instruction enum
+        const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+        const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+        let limit = self
+            .unit_limit
+            .map(|units| [&[SET_COMPUTE_UNIT_LIMIT][..], &units.to_le_bytes()].concat());
+        let price = self.unit_price.map(|micro_lamports| {
+            [&[SET_COMPUTE_UNIT_PRICE][..], &micro_lamports.to_le_bytes()].concat()
+        });
+        limit
+            .into_iter()
+            .chain(price)
+            .map(|data| Instruction {
+                program_id: COMPUTE_BUDGET_PROGRAM_ID,
+                accounts: vec![],
+                data,
+            })
+            .collect()
+    }
+}
+
+/// An instruction added to a [`BatchBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BatchStep(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum BatchError {
+    #[error("step {0:?} wasn't added to this batch")]
+    UnknownStep(BatchStep),
+    #[error("the prerequisites of step {0:?} depend on it")]
+    DependencyCycle(BatchStep),
+    #[error("step {0:?} doesn't fit in a transaction on its own")]
+    InstructionTooLarge(BatchStep),
+}
+
+#[derive(Debug, Clone)]
+struct Step {
+    instruction: Instruction,
+    priority: u8,
+    prerequisites: BTreeSet<BatchStep>,
+}
+
+/// Builds the transactions of a batch of instructions, see the module docs
+#[derive(Debug, Clone)]
+pub struct BatchBuilder {
+    payer: Pubkey,
+    compute_budget: ComputeBudget,
+    steps: Vec<Step>,
+}
+
+impl BatchBuilder {
+    /// Starts a batch whose transactions are paid by the payer
+    pub const fn new(payer: Pubkey) -> Self {
+        Self {
+            payer,
+            compute_budget: ComputeBudget {
+                unit_limit: None,
+                unit_price: None,
+            },
+            steps: Vec::new(),
+        }
+    }
+
+    /// Sets the compute budget of every transaction of the batch
+    pub fn with_compute_budget(mut self, compute_budget: ComputeBudget) -> Self {
+        self.compute_budget = compute_budget;
+        self
+    }
+
+    /// The payer of the transactions of the batch
+    pub const fn payer(&self) -> &Pubkey {
+        &self.payer
+    }
+
+    /// Adds an instruction to the batch. Instructions with a higher priority go first when their
+    /// prerequisites allow it.
+    pub fn add(&mut self, instruction: Instruction, priority: u8) -> BatchStep {
+        self.steps.push(Step {
+            instruction,
+            priority,
+            prerequisites: BTreeSet::new(),
+        });
+        BatchStep(self.steps.len() - 1)
+    }
+
+    /// Declares that the step shall land after the prerequisite, e.g. that a vault's delegations
+    /// are updated before its delegation changes
+    pub fn depends_on(
+        &mut self,
+        step: BatchStep,
+        prerequisite: BatchStep,
+    ) -> Result<&mut Self, BatchError> {
+        if prerequisite.0 >= self.steps.len() {
+            return Err(BatchError::UnknownStep(prerequisite));
+        }
+        if step == prerequisite {
+            return Err(BatchError::DependencyCycle(step));
+        }
+        self.steps
+            .get_mut(step.0)
+            .ok_or(BatchError::UnknownStep(step))?
+            .prerequisites
+            .insert(prerequisite);
+        Ok(self)
+    }
+
+    /// The steps in the order they land, see the module docs
+    pub fn order(&self) -> Result<Vec<BatchStep>, BatchError> {
+        let mut dependents = vec![Vec::new(); self.steps.len()];
+        let mut pending: Vec<usize> = Vec::with_capacity(self.steps.len());
+        for (index, step) in self.steps.iter().enumerate() {
+            for prerequisite in step.prerequisites.iter() {
+                dependents[prerequisite.0].push(index);
+            }
+            pending.push(step.prerequisites.len());
+        }
+
+        let mut ready: BinaryHeap<(u8, Reverse<usize>)> = pending
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count == 0)
+            .map(|(index, _)| (self.steps[index].priority, Reverse(index)))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.steps.len());
+        while let Some((_, Reverse(index))) = ready.pop() {
+            order.push(BatchStep(index));
+            for dependent in dependents[index].iter() {
+                pending[*dependent] -= 1;
+                if pending[*dependent] == 0 {
+                    ready.push((self.steps[*dependent].priority, Reverse(*dependent)));
+                }
+            }
+        }
+
+        // the steps left are on a cycle or depend on one
+        if let Some(index) = pending.iter().position(|count| *count > 0) {
+            return Err(BatchError::DependencyCycle(BatchStep(index)));
+        }
+        Ok(order)
+    }
+
+    /// Builds the transactions of the batch, to be landed in order
+    pub fn build(&self) -> Result<Vec<FlowTransaction>, BatchError> {
+        let mut transactions = Vec::new();
+        let mut instructions: Vec<Instruction> = Vec::new();
+        for step in self.order()? {
+            instructions.push(self.steps[step.0].instruction.clone());
+            if self.fits(&instructions) {
+                continue;
+            }
+
+            let instruction = instructions.pop().expect("just pushed");
+            if instructions.is_empty() {
+                return Err(BatchError::InstructionTooLarge(step));
+            }
+            transactions.push(self.transaction(&instructions));
+            instructions = vec![instruction];
+            if !self.fits(&instructions) {
+                return Err(BatchError::InstructionTooLarge(step));
+            }
+        }
+        if !instructions.is_empty() {
+            transactions.push(self.transaction(&instructions));
+        }
+        Ok(transactions)
+    }
+
+    fn transaction(&self, instructions: &[Instruction]) -> FlowTransaction {
+        let mut budgeted = self.compute_budget.instructions();
+        budgeted.extend_from_slice(instructions);
+        FlowTransaction::new(&budgeted, &self.payer)
+    }
+
+    fn fits(&self, instructions: &[Instruction]) -> bool {
+        let transaction = self.transaction(instructions);
+        transaction.fits_in_packet()
+            && transaction.message.account_keys.len() <= MAX_TX_ACCOUNT_LOCKS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    use crate::batch::{
+        BatchBuilder, BatchError, BatchStep, ComputeBudget, COMPUTE_BUDGET_PROGRAM_ID,
+        MAX_TX_ACCOUNT_LOCKS,
+    };
+
+    fn instruction(num_accounts: usize) -> Instruction {
+        Instruction {
+            program_id: Pubkey::new_from_array([1; 32]),
+            accounts: (0..num_accounts)
+                .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+                .collect(),
+            data: vec![],
+        }
+    }
+
+    #[test]
+    fn test_order_by_priority_then_insertion() {
+        let mut batch = BatchBuilder::new(Pubkey::new_unique());
+        let low = batch.add(instruction(1), 0);
+        let high = batch.add(instruction(1), 2);
+        let medium = batch.add(instruction(1), 1);
+        let other_low = batch.add(instruction(1), 0);
+
+        assert_eq!(batch.order().unwrap(), [high, medium, low, other_low]);
+    }
+
+    #[test]
+    fn test_prerequisites_go_first() {
+        let vault_program = Pubkey::new_from_array([2; 32]);
+        let config = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let vault_delegation_list = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut batch = BatchBuilder::new(payer);
+        let add_delegation = batch.add(instruction(3), 1);
+        let crank = batch.add(
+            jito_vault_sdk::update_delegations(
+                &vault_program,
+                &config,
+                &vault,
+                &vault_delegation_list,
+                &payer,
+            ),
+            0,
+        );
+        batch.depends_on(add_delegation, crank).unwrap();
+
+        assert_eq!(batch.order().unwrap(), [crank, add_delegation]);
+        let transactions = batch.build().unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].message.program_id(0), Some(&vault_program));
+    }
+
+    #[test]
+    fn test_dependency_cycle_fails() {
+        let mut batch = BatchBuilder::new(Pubkey::new_unique());
+        let a = batch.add(instruction(1), 0);
+        let b = batch.add(instruction(1), 0);
+        let c = batch.add(instruction(1), 0);
+        batch.depends_on(a, b).unwrap();
+        batch.depends_on(b, a).unwrap();
+        batch.depends_on(c, a).unwrap();
+
+        assert_eq!(batch.order(), Err(BatchError::DependencyCycle(a)));
+        assert_eq!(
+            batch.depends_on(c, c).err(),
+            Some(BatchError::DependencyCycle(c))
+        );
+        assert_eq!(
+            batch.depends_on(c, BatchStep(3)).err(),
+            Some(BatchError::UnknownStep(BatchStep(3)))
+        );
+    }
+
+    #[test]
+    fn test_build_splits_on_account_locks() {
+        let mut batch = BatchBuilder::new(Pubkey::new_unique());
+        // the payer and the program take two locks, so only one of them fits per transaction
+        for _ in 0..3 {
+            batch.add(instruction(MAX_TX_ACCOUNT_LOCKS / 2), 0);
+        }
+
+        let transactions = batch.build().unwrap();
+        assert_eq!(transactions.len(), 3);
+        for transaction in transactions.iter() {
+            assert_eq!(transaction.message.instructions.len(), 1);
+            assert!(transaction.message.account_keys.len() <= MAX_TX_ACCOUNT_LOCKS);
+        }
+    }
+
+    #[test]
+    fn test_build_splits_on_packet_size() {
+        let mut batch = BatchBuilder::new(Pubkey::new_unique());
+        let mut large = instruction(1);
+        large.data = vec![0; 700];
+        batch.add(large.clone(), 0);
+        batch.add(large, 0);
+
+        let transactions = batch.build().unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert!(transactions.iter().all(|t| t.fits_in_packet()));
+    }
+
+    #[test]
+    fn test_build_instruction_too_large_fails() {
+        let mut batch = BatchBuilder::new(Pubkey::new_unique());
+        batch.add(instruction(1), 0);
+        let too_large = batch.add(instruction(MAX_TX_ACCOUNT_LOCKS), 0);
+
+        assert_eq!(
+            batch.build().err(),
+            Some(BatchError::InstructionTooLarge(too_large))
+        );
+    }
+
+    #[test]
+    fn test_compute_budget_matches_runtime_encoding() {
+        use solana_sdk::compute_budget::{self, ComputeBudgetInstruction};
+
+        assert_eq!(COMPUTE_BUDGET_PROGRAM_ID, compute_budget::id());
+        let budget = ComputeBudget {
+            unit_limit: Some(400_000),
+            unit_price: Some(10_000),
+        };
+        assert_eq!(
+            budget.instructions(),
+            [
+                ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+                ComputeBudgetInstruction::set_compute_unit_price(10_000),
+            ]
+        );
+        assert!(ComputeBudget::default().instructions().is_empty());
+    }
+
+    #[test]
+    fn test_build_prepends_compute_budget() {
+        let payer = Pubkey::new_unique();
+        let mut large = instruction(1);
+        large.data = vec![0; 500];
+        let mut batch = BatchBuilder::new(payer);
+        batch.add(large.clone(), 0);
+        batch.add(large, 0);
+        assert_eq!(batch.build().unwrap().len(), 1);
+
+        // the compute budget instructions count against the packet size
+        let batch = batch.with_compute_budget(ComputeBudget {
+            unit_limit: Some(400_000),
+            unit_price: Some(10_000),
+        });
+        let transactions = batch.build().unwrap();
+        assert_eq!(transactions.len(), 2);
+        for transaction in transactions.iter() {
+            assert!(transaction.fits_in_packet());
+            assert_eq!(transaction.message.instructions.len(), 3);
+            assert_eq!(
+                transaction.message.program_id(0),
+                Some(&COMPUTE_BUDGET_PROGRAM_ID)
+            );
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Prints the shank IDL of the restaking program, so clients generated from IDLs can call it
+//! without transcribing its instructions from `restaking_sdk/idl.json` by hand.
+//!
+//! Regenerate with `cargo run -p jito-restaking-sdk --bin restaking-idl > restaking_sdk/idl.json`
+use jito_jsm_core::idl::IdlExport;
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config, epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot, metadata::Metadata, operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore, operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket, result::RestakingCoreError,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use jito_restaking_sanitization::error_code::ErrorSource;
+use jito_restaking_sdk::{error::RestakingError, RestakingInstruction};
+
+fn generate() -> String {
+    let mut export = IdlExport::new("jito_restaking_program", env!("CARGO_PKG_VERSION"));
+    export.instruction::<RestakingInstruction>(include_str!("../lib.rs"));
+    export.account::<Config>();
+    export.account::<Avs>();
+    export.account::<Operator>();
+    export.account::<AvsVaultTicket>();
+    export.account::<AvsOperatorTicket>();
+    export.account::<AvsVaultSlasherTicket>();
+    export.account::<OperatorAvsTicket>();
+    export.account::<OperatorVaultTicket>();
+    export.account::<OperatorAvsHeartbeat>();
+    export.account::<Metadata>();
+    export.account::<OperatorAvsCapacity>();
+    export.account::<EpochRewardRouter>();
+    export.account::<EpochRewardSnapshot>();
+    export.account::<OperatorEpochStake>();
+    export.account::<VaultOperatorEpochStake>();
+    export.account::<OperatorAvsScore>();
+    export.account::<OperatorStakeHistory>();
+    export.errors(ErrorSource::Restaking, RestakingCoreError::name);
+    export.errors(ErrorSource::RestakingProgram, RestakingError::name);
+    export.to_json()
+}
+
+fn main() {
+    print!("{}", generate());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+
+    #[test]
+    fn test_idl_up_to_date() {
+        assert_eq!(
+            generate(),
+            include_str!("../../idl.json"),
+            "IDL is stale, regenerate it with the restaking-idl binary"
+        );
+    }
+}
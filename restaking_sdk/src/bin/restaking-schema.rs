@@ -0,0 +1,59 @@
+//! Prints the Borsh schema of the restaking program's instructions, events and accounts as JSON,
+//! so decoders in other languages can generate their layouts from `restaking_sdk/schema.json`.
+//!
+//! Regenerate with `cargo run -p jito-restaking-sdk --bin restaking-schema > restaking_sdk/schema.json`
+use jito_jsm_core::schema::SchemaExport;
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config, epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot, metadata::Metadata, operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore, operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket,
+    vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use jito_restaking_sdk::{event::RestakingEvent, RestakingInstruction};
+
+fn generate() -> String {
+    let mut export = SchemaExport::default();
+    export.instruction::<RestakingInstruction>();
+    export.event::<RestakingEvent>();
+    export.account::<Config>();
+    export.account::<Avs>();
+    export.account::<Operator>();
+    export.account::<AvsVaultTicket>();
+    export.account::<AvsOperatorTicket>();
+    export.account::<AvsVaultSlasherTicket>();
+    export.account::<OperatorAvsTicket>();
+    export.account::<OperatorVaultTicket>();
+    export.account::<OperatorAvsHeartbeat>();
+    export.account::<Metadata>();
+    export.account::<OperatorAvsCapacity>();
+    export.account::<EpochRewardRouter>();
+    export.account::<EpochRewardSnapshot>();
+    export.account::<OperatorEpochStake>();
+    export.account::<VaultOperatorEpochStake>();
+    export.account::<OperatorAvsScore>();
+    export.account::<OperatorStakeHistory>();
+    export.to_json()
+}
+
+fn main() {
+    print!("{}", generate());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+
+    #[test]
+    fn test_schema_up_to_date() {
+        assert_eq!(
+            generate(),
+            include_str!("../../schema.json"),
+            "schema is stale, regenerate it with the restaking-schema binary"
+        );
+    }
+}
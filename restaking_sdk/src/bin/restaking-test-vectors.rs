@@ -0,0 +1,542 @@
+//! Prints deterministic test vectors for the restaking program as JSON: instruction data,
+//! PDA derivations and account layouts for fixed inputs. Third-party clients can check their
+//! encoders against `restaking_sdk/test_vectors.json`.
+//!
+//! Regenerate with `cargo run -p jito-restaking-sdk --bin restaking-test-vectors > restaking_sdk/test_vectors.json`
+use std::fmt::Write;
+
+use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::Avs, avs_operator_ticket::AvsOperatorTicket, avs_reward_budget::AvsRewardBudget,
+    avs_vault_slasher_ticket::AvsVaultSlasherTicket, avs_vault_ticket::AvsVaultTicket,
+    config::Config, epoch_reward_router::EpochRewardRouter,
+    epoch_reward_snapshot::EpochRewardSnapshot, metadata::Metadata, operator::Operator,
+    operator_avs_capacity::OperatorAvsCapacity, operator_avs_heartbeat::OperatorAvsHeartbeat,
+    operator_avs_score::OperatorAvsScore, operator_avs_ticket::OperatorAvsTicket,
+    operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+    operator_vault_ticket::OperatorVaultTicket, reward_claim_status::RewardClaimStatus,
+    reward_distribution::RewardDistribution, vault_operator_epoch_stake::VaultOperatorEpochStake,
+};
+use jito_restaking_sdk::{
+    AvsAdminRole, ConfigParameter, OperatorAdminRole, RelayedOperation, RestakingInstruction,
+};
+use solana_program::pubkey::Pubkey;
+
+/// Program id the PDAs are derived against. Vectors are about the derivation, so a fixed
+/// key keeps them independent of the deployed program id.
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([0xaa; 32]);
+
+const fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().fold(String::new(), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+#[derive(Default)]
+struct TestVectors {
+    instructions: Vec<String>,
+    pdas: Vec<String>,
+    accounts: Vec<String>,
+}
+
+impl TestVectors {
+    fn instruction(&mut self, name: &str, instruction: RestakingInstruction) {
+        self.instructions.push(format!(
+            r#"{{"name": "{name}", "data": "{}"}}"#,
+            hex(&instruction.try_to_vec().unwrap())
+        ));
+    }
+
+    fn pda(&mut self, name: &str, (address, bump, seeds): (Pubkey, u8, Vec<Vec<u8>>)) {
+        let seeds: Vec<_> = seeds.iter().map(|s| format!(r#""{}""#, hex(s))).collect();
+        self.pdas.push(format!(
+            r#"{{"name": "{name}", "seeds": [{}], "address": "{address}", "bump": {bump}}}"#,
+            seeds.join(", ")
+        ));
+    }
+
+    fn account<T: BorshSerialize>(&mut self, name: &str, account: &T) {
+        let data = account.try_to_vec().unwrap();
+        self.accounts.push(format!(
+            r#"{{"name": "{name}", "size": {}, "data": "{}"}}"#,
+            data.len(),
+            hex(&data)
+        ));
+    }
+
+    fn to_json(&self) -> String {
+        let section = |entries: &[String]| format!("[\n    {}\n  ]", entries.join(",\n    "));
+        format!(
+            "{{\n  \"program_id\": \"{PROGRAM_ID}\",\n  \"instructions\": {},\n  \"pdas\": {},\n  \"accounts\": {}\n}}\n",
+            section(&self.instructions),
+            section(&self.pdas),
+            section(&self.accounts)
+        )
+    }
+}
+
+fn generate() -> String {
+    let mut vectors = TestVectors::default();
+
+    vectors.instruction("InitializeConfig", RestakingInstruction::InitializeConfig);
+    vectors.instruction("InitializeAvs", RestakingInstruction::InitializeAvs);
+    vectors.instruction("AvsAddVault", RestakingInstruction::AvsAddVault);
+    vectors.instruction("AvsRemoveVault", RestakingInstruction::AvsRemoveVault);
+    vectors.instruction("AvsAddOperator", RestakingInstruction::AvsAddOperator);
+    vectors.instruction("AvsRemoveOperator", RestakingInstruction::AvsRemoveOperator);
+    vectors.instruction(
+        "AvsAddVaultSlasher",
+        RestakingInstruction::AvsAddVaultSlasher {
+            max_slashable_per_epoch: 1_000_000,
+            metadata_hash: [6; 32],
+        },
+    );
+    vectors.instruction(
+        "AvsRemoveVaultSlasher",
+        RestakingInstruction::AvsRemoveVaultSlasher,
+    );
+    vectors.instruction("AvsSetAdmin", RestakingInstruction::AvsSetAdmin);
+    vectors.instruction(
+        "AvsSetSecondaryAdmin",
+        RestakingInstruction::AvsSetSecondaryAdmin(AvsAdminRole::Slasher),
+    );
+    vectors.instruction(
+        "InitializeOperator",
+        RestakingInstruction::InitializeOperator,
+    );
+    vectors.instruction("OperatorSetAdmin", RestakingInstruction::OperatorSetAdmin);
+    vectors.instruction("OperatorSetVoter", RestakingInstruction::OperatorSetVoter);
+    vectors.instruction("OperatorAddVault", RestakingInstruction::OperatorAddVault);
+    vectors.instruction(
+        "OperatorRemoveVault",
+        RestakingInstruction::OperatorRemoveVault,
+    );
+    vectors.instruction("OperatorAddAvs", RestakingInstruction::OperatorAddAvs);
+    vectors.instruction("OperatorRemoveAvs", RestakingInstruction::OperatorRemoveAvs);
+    vectors.instruction(
+        "AvsWithdrawalAsset",
+        RestakingInstruction::AvsWithdrawalAsset {
+            token_mint: key(1),
+            amount: 1_000_000,
+        },
+    );
+    vectors.instruction(
+        "OperatorWithdrawalAsset",
+        RestakingInstruction::OperatorWithdrawalAsset {
+            token_mint: key(1),
+            amount: 1_000_000,
+        },
+    );
+    vectors.instruction(
+        "AvsSetVaultServiceFee",
+        RestakingInstruction::AvsSetVaultServiceFee { fee_bps: 250 },
+    );
+    vectors.instruction("OperatorHeartbeat", RestakingInstruction::OperatorHeartbeat);
+    vectors.instruction(
+        "AvsSetMaxHeartbeatAge",
+        RestakingInstruction::AvsSetMaxHeartbeatAge {
+            max_heartbeat_age: 9_000,
+        },
+    );
+    vectors.instruction(
+        "AvsEjectStaleOperator",
+        RestakingInstruction::AvsEjectStaleOperator,
+    );
+    vectors.instruction(
+        "AvsReactivateOperator",
+        RestakingInstruction::AvsReactivateOperator,
+    );
+    vectors.instruction("OperatorExitAll", RestakingInstruction::OperatorExitAll);
+    vectors.instruction("AssertAvsHealthy", RestakingInstruction::AssertAvsHealthy);
+    vectors.instruction(
+        "AssertOperatorHealthy",
+        RestakingInstruction::AssertOperatorHealthy,
+    );
+    vectors.instruction(
+        "ConfigAddSlasherProgram",
+        RestakingInstruction::ConfigAddSlasherProgram { program: key(1) },
+    );
+    vectors.instruction(
+        "ConfigRemoveSlasherProgram",
+        RestakingInstruction::ConfigRemoveSlasherProgram { program: key(1) },
+    );
+    vectors.instruction(
+        "SweepTokens",
+        RestakingInstruction::SweepTokens { token_mint: key(1) },
+    );
+    vectors.instruction("TopUpRent", RestakingInstruction::TopUpRent);
+    vectors.instruction("SweepLamports", RestakingInstruction::SweepLamports);
+    vectors.instruction("AvsSetRelayer", RestakingInstruction::AvsSetRelayer);
+    vectors.instruction(
+        "OperatorSetRelayer",
+        RestakingInstruction::OperatorSetRelayer,
+    );
+    vectors.instruction(
+        "RelayAdminOperation",
+        RestakingInstruction::RelayAdminOperation {
+            nonce: 7,
+            operation: RelayedOperation::AvsSetMaxHeartbeatAge {
+                max_heartbeat_age: 1_000,
+            },
+        },
+    );
+    vectors.instruction(
+        "RelayAdminOperationGrantSessionKey",
+        RestakingInstruction::RelayAdminOperation {
+            nonce: 8,
+            operation: RelayedOperation::OperatorGrantSessionKey {
+                session_key: key(9),
+                expiry_slot: 5_000,
+            },
+        },
+    );
+    vectors.instruction(
+        "AvsSetMaxSlashersPerVault",
+        RestakingInstruction::AvsSetMaxSlashersPerVault {
+            max_slashers_per_vault: 4,
+        },
+    );
+    vectors.instruction(
+        "ConfigSetTicketEpochLength",
+        RestakingInstruction::ConfigSetTicketEpochLength {
+            ticket_epoch_length: 432_000,
+        },
+    );
+    vectors.instruction(
+        "OperatorSetSecondaryAdmin",
+        RestakingInstruction::OperatorSetSecondaryAdmin(OperatorAdminRole::Withdraw),
+    );
+    vectors.instruction("AvsAcceptAdmin", RestakingInstruction::AvsAcceptAdmin);
+    vectors.instruction(
+        "OperatorAcceptAdmin",
+        RestakingInstruction::OperatorAcceptAdmin,
+    );
+    vectors.instruction("ConfigSetAdmin", RestakingInstruction::ConfigSetAdmin);
+    vectors.instruction("ConfigAcceptAdmin", RestakingInstruction::ConfigAcceptAdmin);
+    vectors.instruction(
+        "AvsInitializeRewardBudget",
+        RestakingInstruction::AvsInitializeRewardBudget {
+            epoch_length: 432_000,
+            tokens_per_epoch: 1_000_000,
+            decay_bps: 100,
+        },
+    );
+    vectors.instruction(
+        "AvsSetRewardBudget",
+        RestakingInstruction::AvsSetRewardBudget {
+            tokens_per_epoch: 1_000_000,
+            decay_bps: 100,
+        },
+    );
+    vectors.instruction(
+        "AvsDistributeRewards",
+        RestakingInstruction::AvsDistributeRewards,
+    );
+    vectors.instruction(
+        "AvsSetMultisig",
+        RestakingInstruction::AvsSetMultisig {
+            signers: [key(8), key(9), key(10)],
+            threshold: 2,
+        },
+    );
+    vectors.instruction(
+        "AvsSetRentCollector",
+        RestakingInstruction::AvsSetRentCollector,
+    );
+    vectors.instruction(
+        "OperatorSetRentCollector",
+        RestakingInstruction::OperatorSetRentCollector,
+    );
+    vectors.instruction("CloseTicket", RestakingInstruction::CloseTicket);
+    vectors.instruction(
+        "UpdateAvsMetadata",
+        RestakingInstruction::UpdateAvsMetadata {
+            name: "AVS".to_string(),
+            uri: "https://example.com/avs.json".to_string(),
+            icon: "https://example.com/avs.png".to_string(),
+        },
+    );
+    vectors.instruction(
+        "UpdateOperatorMetadata",
+        RestakingInstruction::UpdateOperatorMetadata {
+            name: "Operator".to_string(),
+            uri: "https://example.com/operator.json".to_string(),
+            icon: String::new(),
+        },
+    );
+    vectors.instruction("MigrateAccount", RestakingInstruction::MigrateAccount);
+    vectors.instruction(
+        "OperatorSetAvsCapacity",
+        RestakingInstruction::OperatorSetAvsCapacity {
+            capacity: 1_000_000,
+        },
+    );
+    vectors.instruction(
+        "ConfigSetOperatorFeeParams",
+        RestakingInstruction::ConfigSetOperatorFeeParams {
+            max_operator_fee_bps: 2_000,
+            operator_fee_cooldown_slots: 432_000,
+        },
+    );
+    vectors.instruction(
+        "SetOperatorFee",
+        RestakingInstruction::SetOperatorFee {
+            operator_fee_bps: 500,
+        },
+    );
+    vectors.instruction(
+        "AvsDepositEpochRewards",
+        RestakingInstruction::AvsDepositEpochRewards {
+            epoch: 10,
+            amount: 1_000_000,
+        },
+    );
+    vectors.instruction(
+        "SnapshotEpochRewardStake",
+        RestakingInstruction::SnapshotEpochRewardStake,
+    );
+    vectors.instruction(
+        "DistributeEpochRewards",
+        RestakingInstruction::DistributeEpochRewards,
+    );
+    vectors.instruction(
+        "AvsReclaimEpochRewards",
+        RestakingInstruction::AvsReclaimEpochRewards,
+    );
+    vectors.instruction(
+        "SnapshotEpochStake",
+        RestakingInstruction::SnapshotEpochStake,
+    );
+    vectors.instruction("AvsSetScorer", RestakingInstruction::AvsSetScorer);
+    vectors.instruction(
+        "AvsAttestOperatorScore",
+        RestakingInstruction::AvsAttestOperatorScore { score_bps: 9_500 },
+    );
+    vectors.instruction("ArchiveEpochStake", RestakingInstruction::ArchiveEpochStake);
+    vectors.instruction(
+        "ConfigSetParameter",
+        RestakingInstruction::ConfigSetParameter(ConfigParameter::MaxOperatorsPerAvs(256)),
+    );
+    vectors.instruction(
+        "PostRewardRoot",
+        RestakingInstruction::PostRewardRoot {
+            epoch: 10,
+            merkle_root: [6; 32],
+            max_total_claim: 1_000_000,
+            max_num_nodes: 2,
+        },
+    );
+    vectors.instruction(
+        "ClaimReward",
+        RestakingInstruction::ClaimReward {
+            amount: 400_000,
+            proof: vec![RewardDistribution::leaf(&key(12), 600_000)],
+        },
+    );
+    vectors.instruction(
+        "FinalizeOperatorAvsRemoval",
+        RestakingInstruction::FinalizeOperatorAvsRemoval,
+    );
+
+    let (avs, operator, vault, slasher) = (key(2), key(3), key(4), key(5));
+
+    vectors.pda("Config", Config::find_program_address(&PROGRAM_ID));
+    vectors.pda("Avs", Avs::find_program_address(&PROGRAM_ID, &key(6)));
+    vectors.pda(
+        "Operator",
+        Operator::find_program_address(&PROGRAM_ID, &key(7)),
+    );
+    vectors.pda(
+        "AvsVaultTicket",
+        AvsVaultTicket::find_program_address(&PROGRAM_ID, &avs, &vault),
+    );
+    vectors.pda(
+        "AvsOperatorTicket",
+        AvsOperatorTicket::find_program_address(&PROGRAM_ID, &avs, &operator),
+    );
+    vectors.pda(
+        "AvsVaultSlasherTicket",
+        AvsVaultSlasherTicket::find_program_address(&PROGRAM_ID, &avs, &vault, &slasher),
+    );
+    vectors.pda(
+        "OperatorAvsTicket",
+        OperatorAvsTicket::find_program_address(&PROGRAM_ID, &operator, &avs),
+    );
+    vectors.pda(
+        "OperatorVaultTicket",
+        OperatorVaultTicket::find_program_address(&PROGRAM_ID, &operator, &vault),
+    );
+    vectors.pda(
+        "OperatorAvsHeartbeat",
+        OperatorAvsHeartbeat::find_program_address(&PROGRAM_ID, &operator, &avs),
+    );
+    vectors.pda(
+        "AvsRewardBudget",
+        AvsRewardBudget::find_program_address(&PROGRAM_ID, &avs, &key(1)),
+    );
+    vectors.pda(
+        "Metadata",
+        Metadata::find_program_address(&PROGRAM_ID, &avs),
+    );
+    vectors.pda(
+        "OperatorAvsCapacity",
+        OperatorAvsCapacity::find_program_address(&PROGRAM_ID, &operator, &avs),
+    );
+    vectors.pda(
+        "EpochRewardRouter",
+        EpochRewardRouter::find_program_address(&PROGRAM_ID, &avs, &key(1), 10),
+    );
+    vectors.pda(
+        "EpochRewardSnapshot",
+        EpochRewardSnapshot::find_program_address(&PROGRAM_ID, &key(10), &vault, &operator),
+    );
+    vectors.pda(
+        "OperatorEpochStake",
+        OperatorEpochStake::find_program_address(&PROGRAM_ID, &operator, 10),
+    );
+    vectors.pda(
+        "VaultOperatorEpochStake",
+        VaultOperatorEpochStake::find_program_address(&PROGRAM_ID, &vault, &operator, 10),
+    );
+    vectors.pda(
+        "OperatorAvsScore",
+        OperatorAvsScore::find_program_address(&PROGRAM_ID, &operator, &avs),
+    );
+    vectors.pda(
+        "OperatorStakeHistory",
+        OperatorStakeHistory::find_program_address(&PROGRAM_ID, &operator),
+    );
+    vectors.pda(
+        "RewardDistribution",
+        RewardDistribution::find_program_address(&PROGRAM_ID, &avs, &key(1), 10),
+    );
+    vectors.pda(
+        "RewardClaimStatus",
+        RewardClaimStatus::find_program_address(&PROGRAM_ID, &key(13), &key(11)),
+    );
+
+    vectors.account("Config", &Config::new(key(8), key(9), 254));
+    vectors.account(
+        "Avs",
+        &Avs::new(key(6), key(8), key(8), key(8), key(8), key(8), 1, 254),
+    );
+    vectors.account("Operator", &Operator::new(key(7), key(8), key(8), 1, 254));
+    vectors.account(
+        "AvsVaultTicket",
+        &AvsVaultTicket::new(avs, vault, 1, 100, 254),
+    );
+    vectors.account(
+        "AvsOperatorTicket",
+        &AvsOperatorTicket::new(avs, operator, 1, 100, 254),
+    );
+    vectors.account(
+        "AvsVaultSlasherTicket",
+        &AvsVaultSlasherTicket::new(avs, vault, slasher, 1_000_000, [6; 32], 1, 100, 254),
+    );
+    vectors.account(
+        "OperatorAvsTicket",
+        &OperatorAvsTicket::new(operator, avs, 1, 100, 254),
+    );
+    vectors.account(
+        "OperatorVaultTicket",
+        &OperatorVaultTicket::new(operator, vault, 1, 100, 254),
+    );
+    vectors.account(
+        "OperatorAvsHeartbeat",
+        &OperatorAvsHeartbeat::new(operator, avs, 100, 254),
+    );
+    vectors.account(
+        "AvsRewardBudget",
+        &AvsRewardBudget::new(avs, key(1), key(9), 432_000, 1_000_000, 100, 1, 254).unwrap(),
+    );
+    let mut metadata = Metadata::new(avs, 254);
+    metadata
+        .set(
+            "AVS",
+            "https://example.com/avs.json",
+            "https://example.com/avs.png",
+        )
+        .unwrap();
+    vectors.account("Metadata", &metadata);
+    let mut operator_avs_capacity = OperatorAvsCapacity::new(operator, avs, 254);
+    operator_avs_capacity.set_capacity(1_000_000, 100);
+    vectors.account("OperatorAvsCapacity", &operator_avs_capacity);
+    let mut epoch_reward_router = EpochRewardRouter::new(avs, key(1), 10, 432_000, 254);
+    epoch_reward_router.deposit(1_000_000, 4_320_000).unwrap();
+    epoch_reward_router
+        .record_stake(500_000, 4_320_000)
+        .unwrap();
+    vectors.account("EpochRewardRouter", &epoch_reward_router);
+    vectors.account(
+        "EpochRewardSnapshot",
+        &EpochRewardSnapshot::new(key(10), vault, operator, 500_000, 500, Some(9_500), 254),
+    );
+    let mut operator_epoch_stake = OperatorEpochStake::new(operator, 10, 254);
+    operator_epoch_stake.record_vault_stake(500_000).unwrap();
+    vectors.account("OperatorEpochStake", &operator_epoch_stake);
+    vectors.account(
+        "VaultOperatorEpochStake",
+        &VaultOperatorEpochStake::new(vault, operator, 10, 500_000, 4_320_000, 254),
+    );
+    let mut operator_avs_score = OperatorAvsScore::new(operator, avs, 254);
+    operator_avs_score.record_score(9_500, 4_320_000).unwrap();
+    vectors.account("OperatorAvsScore", &operator_avs_score);
+    let mut operator_stake_history = OperatorStakeHistory::new(operator, 254);
+    operator_stake_history
+        .archive(
+            &OperatorStakeHistory::leaf(operator_epoch_stake.as_bytes()),
+            10,
+        )
+        .unwrap();
+    vectors.account("OperatorStakeHistory", &operator_stake_history);
+    let mut reward_distribution = RewardDistribution::new(
+        avs,
+        key(1),
+        10,
+        RewardDistribution::node(
+            &RewardDistribution::leaf(&key(11), 400_000),
+            &RewardDistribution::leaf(&key(12), 600_000),
+        ),
+        1_000_000,
+        2,
+        254,
+    );
+    reward_distribution
+        .claim(
+            &key(11),
+            400_000,
+            &[RewardDistribution::leaf(&key(12), 600_000)],
+        )
+        .unwrap();
+    vectors.account("RewardDistribution", &reward_distribution);
+    vectors.account(
+        "RewardClaimStatus",
+        &RewardClaimStatus::new(key(13), key(11), 400_000, 4_320_000, 254),
+    );
+
+    vectors.to_json()
+}
+
+fn main() {
+    print!("{}", generate());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+
+    #[test]
+    fn test_vectors_up_to_date() {
+        assert_eq!(
+            generate(),
+            include_str!("../../test_vectors.json"),
+            "test vectors are stale, regenerate them with the restaking-test-vectors binary"
+        );
+    }
+}
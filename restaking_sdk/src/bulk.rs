@@ -0,0 +1,324 @@
+//! Adds one relationship per counterparty to a [`BatchBuilder`], e.g. an operator opting in to 20
+//! vaults, deriving every ticket along the way.
+//!
+//! The helpers only add instructions, so several of them can share a batch and land in as few
+//! transactions as fit, and the steps they return can be used to declare prerequisites between
+//! them, e.g. that the vaults add the operator once it opted in to them. The transactions are paid
+//! by the payer of the batch, which also pays for the tickets.
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_ticket::AvsVaultTicket,
+    operator_avs_ticket::OperatorAvsTicket, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    vault_avs_ticket::VaultAvsTicket, vault_operator_ticket::VaultOperatorTicket,
+};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    avs_add_operator, avs_add_vault,
+    batch::{BatchBuilder, BatchStep},
+    flows::ProgramIds,
+    operator_add_avs, operator_add_vault,
+};
+
+/// The operator's vault admin opts the operator in to each vault
+pub fn operator_add_vaults(
+    batch: &mut BatchBuilder,
+    program_ids: &ProgramIds,
+    operator: &Pubkey,
+    operator_vault_admin: &Pubkey,
+    vaults: &[Pubkey],
+) -> Vec<BatchStep> {
+    let restaking_program = &program_ids.restaking_program;
+    let config = program_ids.restaking_config();
+    let payer = *batch.payer();
+    vaults
+        .iter()
+        .map(|vault| {
+            let operator_vault_ticket =
+                OperatorVaultTicket::find_program_address(restaking_program, operator, vault).0;
+            batch.add(
+                operator_add_vault(
+                    restaking_program,
+                    &config,
+                    operator,
+                    vault,
+                    &operator_vault_ticket,
+                    operator_vault_admin,
+                    &payer,
+                ),
+                0,
+            )
+        })
+        .collect()
+}
+
+/// The operator's AVS admin opts the operator in to each AVS
+pub fn operator_add_avss(
+    batch: &mut BatchBuilder,
+    program_ids: &ProgramIds,
+    operator: &Pubkey,
+    operator_avs_admin: &Pubkey,
+    avss: &[Pubkey],
+) -> Vec<BatchStep> {
+    let restaking_program = &program_ids.restaking_program;
+    let config = program_ids.restaking_config();
+    let payer = *batch.payer();
+    avss.iter()
+        .map(|avs| {
+            let operator_avs_ticket =
+                OperatorAvsTicket::find_program_address(restaking_program, operator, avs).0;
+            batch.add(
+                operator_add_avs(
+                    restaking_program,
+                    &config,
+                    operator,
+                    avs,
+                    &operator_avs_ticket,
+                    operator_avs_admin,
+                    &payer,
+                ),
+                0,
+            )
+        })
+        .collect()
+}
+
+/// The AVS's operator admin adds each operator, which shall have opted in to the AVS
+pub fn avs_add_operators(
+    batch: &mut BatchBuilder,
+    program_ids: &ProgramIds,
+    avs: &Pubkey,
+    avs_operator_admin: &Pubkey,
+    operators: &[Pubkey],
+) -> Vec<BatchStep> {
+    let restaking_program = &program_ids.restaking_program;
+    let config = program_ids.restaking_config();
+    let payer = *batch.payer();
+    operators
+        .iter()
+        .map(|operator| {
+            let avs_operator_ticket =
+                AvsOperatorTicket::find_program_address(restaking_program, avs, operator).0;
+            let operator_avs_ticket =
+                OperatorAvsTicket::find_program_address(restaking_program, operator, avs).0;
+            batch.add(
+                avs_add_operator(
+                    restaking_program,
+                    &config,
+                    avs,
+                    operator,
+                    &avs_operator_ticket,
+                    &operator_avs_ticket,
+                    avs_operator_admin,
+                    &payer,
+                ),
+                0,
+            )
+        })
+        .collect()
+}
+
+/// The AVS's vault admin adds each vault
+pub fn avs_add_vaults(
+    batch: &mut BatchBuilder,
+    program_ids: &ProgramIds,
+    avs: &Pubkey,
+    avs_vault_admin: &Pubkey,
+    vaults: &[Pubkey],
+) -> Vec<BatchStep> {
+    let restaking_program = &program_ids.restaking_program;
+    let config = program_ids.restaking_config();
+    let payer = *batch.payer();
+    vaults
+        .iter()
+        .map(|vault| {
+            let avs_vault_ticket =
+                AvsVaultTicket::find_program_address(restaking_program, avs, vault).0;
+            batch.add(
+                avs_add_vault(
+                    restaking_program,
+                    &config,
+                    avs,
+                    vault,
+                    &avs_vault_ticket,
+                    avs_vault_admin,
+                    &payer,
+                ),
+                0,
+            )
+        })
+        .collect()
+}
+
+/// The vault's operator admin adds each operator, which shall have opted in to the vault
+pub fn vault_add_operators(
+    batch: &mut BatchBuilder,
+    program_ids: &ProgramIds,
+    vault: &Pubkey,
+    vault_operator_admin: &Pubkey,
+    operators: &[Pubkey],
+) -> Vec<BatchStep> {
+    let ProgramIds {
+        restaking_program,
+        vault_program,
+    } = program_ids;
+    let config = program_ids.vault_config();
+    let payer = *batch.payer();
+    operators
+        .iter()
+        .map(|operator| {
+            let operator_vault_ticket =
+                OperatorVaultTicket::find_program_address(restaking_program, operator, vault).0;
+            let vault_operator_ticket =
+                VaultOperatorTicket::find_program_address(vault_program, vault, operator).0;
+            batch.add(
+                jito_vault_sdk::add_operator(
+                    vault_program,
+                    &config,
+                    vault,
+                    operator,
+                    &operator_vault_ticket,
+                    &vault_operator_ticket,
+                    vault_operator_admin,
+                    &payer,
+                ),
+                0,
+            )
+        })
+        .collect()
+}
+
+/// The vault's AVS admin adds each AVS, which shall have added the vault
+pub fn vault_add_avss(
+    batch: &mut BatchBuilder,
+    program_ids: &ProgramIds,
+    vault: &Pubkey,
+    vault_avs_admin: &Pubkey,
+    avss: &[Pubkey],
+) -> Vec<BatchStep> {
+    let ProgramIds {
+        restaking_program,
+        vault_program,
+    } = program_ids;
+    let config = program_ids.vault_config();
+    let payer = *batch.payer();
+    avss.iter()
+        .map(|avs| {
+            let avs_vault_ticket =
+                AvsVaultTicket::find_program_address(restaking_program, avs, vault).0;
+            let vault_avs_ticket =
+                VaultAvsTicket::find_program_address(vault_program, vault, avs).0;
+            batch.add(
+                jito_vault_sdk::add_avs(
+                    vault_program,
+                    &config,
+                    vault,
+                    avs,
+                    &avs_vault_ticket,
+                    &vault_avs_ticket,
+                    vault_avs_admin,
+                    &payer,
+                ),
+                0,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::operator_vault_ticket::OperatorVaultTicket;
+    use jito_vault_core::vault_operator_ticket::VaultOperatorTicket;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        batch::{BatchBuilder, ComputeBudget, COMPUTE_BUDGET_PROGRAM_ID},
+        bulk::{operator_add_vaults, vault_add_operators},
+        flows::ProgramIds,
+    };
+
+    const PROGRAM_IDS: ProgramIds = ProgramIds {
+        restaking_program: Pubkey::new_from_array([1; 32]),
+        vault_program: Pubkey::new_from_array([2; 32]),
+    };
+
+    #[test]
+    fn test_operator_add_vaults_splits_into_transactions() {
+        let operator = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let vaults: Vec<Pubkey> = (0..20).map(|_| Pubkey::new_unique()).collect();
+
+        let mut batch = BatchBuilder::new(payer).with_compute_budget(ComputeBudget {
+            unit_limit: None,
+            unit_price: Some(1_000),
+        });
+        let steps = operator_add_vaults(&mut batch, &PROGRAM_IDS, &operator, &admin, &vaults);
+        assert_eq!(steps.len(), vaults.len());
+
+        let transactions = batch.build().unwrap();
+        assert!(transactions.len() > 1);
+        let mut tickets = Vec::new();
+        for transaction in transactions.iter() {
+            assert!(transaction.fits_in_packet());
+            assert_eq!(transaction.signers(), [payer, admin]);
+            let message = &transaction.message;
+            assert_eq!(message.program_id(0), Some(&COMPUTE_BUDGET_PROGRAM_ID));
+            for instruction in message.instructions.iter().skip(1) {
+                tickets.push(message.account_keys[usize::from(instruction.accounts[3])]);
+            }
+        }
+        let expected: Vec<Pubkey> = vaults
+            .iter()
+            .map(|vault| {
+                OperatorVaultTicket::find_program_address(
+                    &PROGRAM_IDS.restaking_program,
+                    &operator,
+                    vault,
+                )
+                .0
+            })
+            .collect();
+        assert_eq!(tickets, expected);
+    }
+
+    #[test]
+    fn test_relationships_share_a_batch() {
+        let operator = Pubkey::new_unique();
+        let operator_admin = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let vault_admin = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut batch = BatchBuilder::new(payer);
+        let vault_adds =
+            vault_add_operators(&mut batch, &PROGRAM_IDS, &vault, &vault_admin, &[operator]);
+        let opt_ins = operator_add_vaults(
+            &mut batch,
+            &PROGRAM_IDS,
+            &operator,
+            &operator_admin,
+            &[vault],
+        );
+        batch.depends_on(vault_adds[0], opt_ins[0]).unwrap();
+
+        assert_eq!(batch.order().unwrap(), [opt_ins[0], vault_adds[0]]);
+        let transactions = batch.build().unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(
+            transactions[0].signers(),
+            [payer, operator_admin, vault_admin]
+        );
+        let vault_operator_ticket = VaultOperatorTicket::find_program_address(
+            &PROGRAM_IDS.vault_program,
+            &vault,
+            &operator,
+        )
+        .0;
+        assert!(transactions[0]
+            .message
+            .account_keys
+            .contains(&vault_operator_ticket));
+    }
+}
@@ -0,0 +1,191 @@
+//! Errors raised by the restaking program's processors. Errors about the state of an account are
+//! in [`jito_restaking_core::result::RestakingCoreError`] instead.
+use jito_restaking_sanitization::error_code::ErrorSource;
+use solana_program::program_error::ProgramError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestakingError {
+    ConfigInvalidPda,
+    AvsInvalidPda,
+    OperatorInvalidPda,
+    AvsOperatorTicketInvalidPda,
+    OperatorAvsTicketInvalidPda,
+    AvsVaultTicketInvalidPda,
+    AvsVaultSlasherTicketInvalidPda,
+    OperatorVaultTicketInvalidPda,
+    OperatorAvsHeartbeatInvalidPda,
+    AvsInsufficientFunds,
+    OperatorInsufficientFunds,
+    AccountInvalidType,
+    AccountInvalidProgramOwner,
+    AccountExpectedWritable,
+    NotAvsTicket,
+    NotOperatorTicket,
+    SweepInvalidConfig,
+    SweepAccountNotSweepable,
+    AvsRewardBudgetInvalidPda,
+    AvsRewardBudgetInvalidReceiver,
+    TicketNotClosable,
+    TicketNotDeactivated,
+    RentCollectorMismatch,
+    MetadataInvalidPda,
+    AccountAlreadyMigrated,
+    OperatorAvsCapacityInvalidPda,
+    EpochRewardRouterInvalidPda,
+    EpochRewardSnapshotInvalidPda,
+    EpochRewardNoStake,
+    EpochRewardDelegationOutdated,
+    OperatorEpochStakeInvalidPda,
+    VaultOperatorEpochStakeInvalidPda,
+    EpochStakeDelegationOutdated,
+    EpochStakeNoStake,
+    OperatorAvsScoreInvalidPda,
+    OperatorStakeHistoryInvalidPda,
+    EpochStakeRetained,
+    RewardDistributionInvalidPda,
+    RewardClaimStatusInvalidPda,
+    RewardSourceInvalid,
+    TicketCoolingDown,
+    OperatorAvsRemovalDelegated,
+    OperatorAvsRemovalSlashPending,
+}
+
+impl RestakingError {
+    /// The offset of the error within the restaking program processor range in
+    /// [`jito_restaking_sanitization::error_code`]. Offsets follow the declaration order and shall
+    /// never change, so new errors are appended.
+    pub const fn offset(&self) -> u32 {
+        match self {
+            Self::ConfigInvalidPda => 0,
+            Self::AvsInvalidPda => 1,
+            Self::OperatorInvalidPda => 2,
+            Self::AvsOperatorTicketInvalidPda => 3,
+            Self::OperatorAvsTicketInvalidPda => 4,
+            Self::AvsVaultTicketInvalidPda => 5,
+            Self::AvsVaultSlasherTicketInvalidPda => 6,
+            Self::OperatorVaultTicketInvalidPda => 7,
+            Self::OperatorAvsHeartbeatInvalidPda => 8,
+            Self::AvsInsufficientFunds => 9,
+            Self::OperatorInsufficientFunds => 10,
+            Self::AccountInvalidType => 11,
+            Self::AccountInvalidProgramOwner => 12,
+            Self::AccountExpectedWritable => 13,
+            Self::NotAvsTicket => 14,
+            Self::NotOperatorTicket => 15,
+            Self::SweepInvalidConfig => 16,
+            Self::SweepAccountNotSweepable => 17,
+            Self::AvsRewardBudgetInvalidPda => 18,
+            Self::AvsRewardBudgetInvalidReceiver => 19,
+            Self::TicketNotClosable => 20,
+            Self::TicketNotDeactivated => 21,
+            Self::RentCollectorMismatch => 22,
+            Self::MetadataInvalidPda => 23,
+            Self::AccountAlreadyMigrated => 24,
+            Self::OperatorAvsCapacityInvalidPda => 25,
+            Self::EpochRewardRouterInvalidPda => 26,
+            Self::EpochRewardSnapshotInvalidPda => 27,
+            Self::EpochRewardNoStake => 28,
+            Self::EpochRewardDelegationOutdated => 29,
+            Self::OperatorEpochStakeInvalidPda => 30,
+            Self::VaultOperatorEpochStakeInvalidPda => 31,
+            Self::EpochStakeDelegationOutdated => 32,
+            Self::EpochStakeNoStake => 33,
+            Self::OperatorAvsScoreInvalidPda => 34,
+            Self::OperatorStakeHistoryInvalidPda => 35,
+            Self::EpochStakeRetained => 36,
+            Self::RewardDistributionInvalidPda => 37,
+            Self::RewardClaimStatusInvalidPda => 38,
+            Self::RewardSourceInvalid => 39,
+            Self::TicketCoolingDown => 40,
+            Self::OperatorAvsRemovalDelegated => 41,
+            Self::OperatorAvsRemovalSlashPending => 42,
+        }
+    }
+
+    /// Returns the name of the error at the offset, for decoding error codes off-chain
+    pub fn name(offset: u32) -> Option<&'static str> {
+        ERROR_NAMES.get(offset as usize).copied()
+    }
+}
+
+/// Error names indexed by [`RestakingError::offset`]
+const ERROR_NAMES: [&str; 43] = [
+    "ConfigInvalidPda",
+    "AvsInvalidPda",
+    "OperatorInvalidPda",
+    "AvsOperatorTicketInvalidPda",
+    "OperatorAvsTicketInvalidPda",
+    "AvsVaultTicketInvalidPda",
+    "AvsVaultSlasherTicketInvalidPda",
+    "OperatorVaultTicketInvalidPda",
+    "OperatorAvsHeartbeatInvalidPda",
+    "AvsInsufficientFunds",
+    "OperatorInsufficientFunds",
+    "AccountInvalidType",
+    "AccountInvalidProgramOwner",
+    "AccountExpectedWritable",
+    "NotAvsTicket",
+    "NotOperatorTicket",
+    "SweepInvalidConfig",
+    "SweepAccountNotSweepable",
+    "AvsRewardBudgetInvalidPda",
+    "AvsRewardBudgetInvalidReceiver",
+    "TicketNotClosable",
+    "TicketNotDeactivated",
+    "RentCollectorMismatch",
+    "MetadataInvalidPda",
+    "AccountAlreadyMigrated",
+    "OperatorAvsCapacityInvalidPda",
+    "EpochRewardRouterInvalidPda",
+    "EpochRewardSnapshotInvalidPda",
+    "EpochRewardNoStake",
+    "EpochRewardDelegationOutdated",
+    "OperatorEpochStakeInvalidPda",
+    "VaultOperatorEpochStakeInvalidPda",
+    "EpochStakeDelegationOutdated",
+    "EpochStakeNoStake",
+    "OperatorAvsScoreInvalidPda",
+    "OperatorStakeHistoryInvalidPda",
+    "EpochStakeRetained",
+    "RewardDistributionInvalidPda",
+    "RewardClaimStatusInvalidPda",
+    "RewardSourceInvalid",
+    "TicketCoolingDown",
+    "OperatorAvsRemovalDelegated",
+    "OperatorAvsRemovalSlashPending",
+];
+
+impl From<RestakingError> for ProgramError {
+    fn from(value: RestakingError) -> Self {
+        Self::Custom(ErrorSource::RestakingProgram.code(value.offset()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_names_match_offsets() {
+        for error in [
+            RestakingError::ConfigInvalidPda,
+            RestakingError::OperatorAvsHeartbeatInvalidPda,
+            RestakingError::AccountInvalidType,
+            RestakingError::SweepAccountNotSweepable,
+        ] {
+            assert_eq!(
+                RestakingError::name(error.offset()),
+                Some(format!("{error:?}").as_str())
+            );
+        }
+        assert_eq!(RestakingError::name(ERROR_NAMES.len() as u32), None);
+    }
+
+    #[test]
+    fn test_error_code_in_restaking_program_range() {
+        assert_eq!(
+            ProgramError::from(RestakingError::AvsInvalidPda),
+            ProgramError::Custom(3_001)
+        );
+    }
+}
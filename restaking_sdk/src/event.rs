@@ -0,0 +1,299 @@
+//! Events emitted by the restaking program. Decode them from a transaction's logs with
+//! [`Event::from_logs`].
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+pub use jito_jsm_core::event::Event;
+use solana_program::pubkey::Pubkey;
+
+/// New variants are appended, since the variant index identifies the event
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub enum RestakingEvent {
+    ConfigInitialized {
+        config: Pubkey,
+        admin: Pubkey,
+        vault_program: Pubkey,
+    },
+    AvsInitialized {
+        avs: Pubkey,
+        admin: Pubkey,
+        base: Pubkey,
+        index: u64,
+    },
+    OperatorInitialized {
+        operator: Pubkey,
+        admin: Pubkey,
+        base: Pubkey,
+        index: u64,
+    },
+    AvsVaultTicketCreated {
+        avs: Pubkey,
+        vault: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        activation_slot: u64,
+    },
+    AvsVaultTicketDeactivated {
+        avs: Pubkey,
+        vault: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        slot: u64,
+    },
+    AvsOperatorTicketCreated {
+        avs: Pubkey,
+        operator: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        operator_avs_ticket: Pubkey,
+        operator_avs_ticket_index: u64,
+        activation_slot: u64,
+    },
+    AvsOperatorTicketDeactivated {
+        avs: Pubkey,
+        operator: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        slot: u64,
+    },
+    AvsVaultSlasherTicketCreated {
+        avs: Pubkey,
+        vault: Pubkey,
+        slasher: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        avs_vault_ticket: Pubkey,
+        avs_vault_ticket_index: u64,
+        max_slashable_per_epoch: u64,
+        metadata_hash: [u8; 32],
+        activation_slot: u64,
+    },
+    AvsVaultSlasherTicketDeactivated {
+        avs: Pubkey,
+        vault: Pubkey,
+        slasher: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        slot: u64,
+    },
+    OperatorAvsTicketCreated {
+        operator: Pubkey,
+        avs: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        activation_slot: u64,
+    },
+    OperatorAvsTicketDeactivated {
+        operator: Pubkey,
+        avs: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        slot: u64,
+    },
+    OperatorVaultTicketCreated {
+        operator: Pubkey,
+        vault: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        activation_slot: u64,
+    },
+    OperatorVaultTicketDeactivated {
+        operator: Pubkey,
+        vault: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        slot: u64,
+    },
+    OperatorHeartbeat {
+        operator: Pubkey,
+        avs: Pubkey,
+        slot: u64,
+    },
+    OperatorEjected {
+        avs: Pubkey,
+        operator: Pubkey,
+        last_heartbeat_slot: u64,
+        slot: u64,
+    },
+    OperatorReactivated {
+        avs: Pubkey,
+        operator: Pubkey,
+        slot: u64,
+    },
+    AvsRewardBudgetInitialized {
+        avs: Pubkey,
+        budget: Pubkey,
+        mint: Pubkey,
+        receiver: Pubkey,
+        epoch_length: u64,
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+        start_epoch: u64,
+    },
+    AvsRewardBudgetUpdated {
+        avs: Pubkey,
+        budget: Pubkey,
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+        start_epoch: u64,
+    },
+    AvsRewardsDistributed {
+        avs: Pubkey,
+        budget: Pubkey,
+        receiver: Pubkey,
+        amount: u64,
+        next_epoch: u64,
+    },
+    AvsMultisigSet {
+        avs: Pubkey,
+        signers: [Pubkey; 3],
+        threshold: u8,
+    },
+    TicketClosed {
+        parent: Pubkey,
+        ticket: Pubkey,
+        rent_collector: Pubkey,
+        lamports: u64,
+    },
+    MetadataUpdated {
+        parent: Pubkey,
+        metadata: Pubkey,
+        name: String,
+        uri: String,
+        icon: String,
+    },
+    AccountMigrated {
+        account: Pubkey,
+        from_len: u64,
+        to_len: u64,
+    },
+    OperatorAvsCapacitySet {
+        operator: Pubkey,
+        avs: Pubkey,
+        capacity: u64,
+        slot: u64,
+    },
+    OperatorFeeSet {
+        operator: Pubkey,
+        operator_fee_bps: u16,
+        slot: u64,
+    },
+    EpochRewardsDeposited {
+        avs: Pubkey,
+        router: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        amount: u64,
+        total_rewards: u64,
+    },
+    EpochRewardStakeSnapshotted {
+        router: Pubkey,
+        vault: Pubkey,
+        operator: Pubkey,
+        stake: u64,
+        operator_fee_bps: u16,
+    },
+    EpochRewardsDistributed {
+        router: Pubkey,
+        vault: Pubkey,
+        operator: Pubkey,
+        operator_amount: u64,
+        vault_amount: u64,
+    },
+    EpochRewardsReclaimed {
+        avs: Pubkey,
+        router: Pubkey,
+        receiver: Pubkey,
+        amount: u64,
+    },
+    EpochStakeSnapshotted {
+        vault: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+        stake: u64,
+        operator_stake: u64,
+    },
+    OperatorScoreAttested {
+        avs: Pubkey,
+        operator: Pubkey,
+        score_bps: u16,
+        slot: u64,
+    },
+    AvsAssetWithdrawn {
+        avs: Pubkey,
+        mint: Pubkey,
+        receiver: Pubkey,
+        amount: u64,
+    },
+    OperatorAssetWithdrawn {
+        operator: Pubkey,
+        mint: Pubkey,
+        receiver: Pubkey,
+        amount: u64,
+    },
+    EpochStakeArchived {
+        operator: Pubkey,
+        snapshot: Pubkey,
+        epoch: u64,
+        /// The data of the snapshot account, the preimage of its leaf in the history
+        data: Vec<u8>,
+        commitment: [u8; 32],
+        archived_count: u64,
+    },
+    OperatorVoterSet {
+        operator: Pubkey,
+        voter: Pubkey,
+        slot: u64,
+    },
+    RewardRootPosted {
+        source: Pubkey,
+        distribution: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    },
+    RewardClaimed {
+        distribution: Pubkey,
+        claimant: Pubkey,
+        amount: u64,
+    },
+    OperatorAvsRemovalFinalized {
+        avs: Pubkey,
+        operator: Pubkey,
+        slot: u64,
+    },
+}
+
+impl Event for RestakingEvent {}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+
+    use super::RestakingEvent;
+
+    #[test]
+    fn test_event_discriminators_are_pinned() {
+        assert_eq!(
+            RestakingEvent::ConfigInitialized {
+                config: Pubkey::default(),
+                admin: Pubkey::default(),
+                vault_program: Pubkey::default(),
+            }
+            .try_to_vec()
+            .unwrap()[0],
+            0
+        );
+        assert_eq!(
+            RestakingEvent::AvsMultisigSet {
+                avs: Pubkey::default(),
+                signers: [Pubkey::default(); 3],
+                threshold: 0,
+            }
+            .try_to_vec()
+            .unwrap()[0],
+            19
+        );
+    }
+}
@@ -0,0 +1,508 @@
+//! Builders for the instruction sequences of common onboarding flows, which span both the
+//! restaking and the vault program.
+//!
+//! A flow spans two epochs, as some of its tickets shall warm up before the other party relies on
+//! them, see [`Config::ticket_epoch_length`]. Each flow returns the transactions of each epoch in
+//! the order they shall be landed, the second epoch's once the next epoch has started.
+//! Instructions are grouped by the party that signs them, so an operator, an AVS and a vault with
+//! different admins each sign only their own transaction.
+//!
+//! The `_atomic` variant of a flow lands the instructions of each epoch in one transaction
+//! instead, so either every party has taken its part in the epoch or none has. Each admin then
+//! signs the same message, which they can do separately with [`FlowTransaction::message_data`] and
+//! [`FlowTransaction::signer_index`].
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_slasher_ticket::AvsVaultSlasherTicket,
+    avs_vault_ticket::AvsVaultTicket, config::Config, operator_avs_ticket::OperatorAvsTicket,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+    vault_operator_ticket::VaultOperatorTicket,
+};
+use solana_program::{instruction::Instruction, message::Message, pubkey::Pubkey};
+
+use crate::{
+    avs_add_operator, avs_add_vault, avs_add_vault_slasher, operator_add_avs, operator_add_vault,
+};
+
+/// An unsigned transaction of a flow
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowTransaction {
+    /// The message to sign, with the payer as fee payer. The recent blockhash is left for the
+    /// caller to set when signing.
+    pub message: Message,
+}
+
+impl FlowTransaction {
+    pub(crate) fn new(instructions: &[Instruction], payer: &Pubkey) -> Self {
+        Self {
+            message: Message::new(instructions, Some(payer)),
+        }
+    }
+
+    /// The keys that shall sign the transaction, fee payer first. A key holding several roles is
+    /// only listed once.
+    pub fn signers(&self) -> &[Pubkey] {
+        let num_signers = usize::from(self.message.header.num_required_signatures);
+        &self.message.account_keys[..num_signers]
+    }
+
+    /// The position of the signer's signature in the transaction, so signatures collected from
+    /// separate parties can be put in order
+    pub fn signer_index(&self, signer: &Pubkey) -> Option<usize> {
+        self.signers().iter().position(|key| key == signer)
+    }
+
+    /// The bytes every signer signs. The recent blockhash shall be set first, as it is part of the
+    /// signed message.
+    pub fn message_data(&self) -> Vec<u8> {
+        self.message.serialize()
+    }
+
+    /// The size of the transaction once signed
+    pub fn serialized_size(&self) -> usize {
+        // compact-u16 signature count, below 128 signers it takes one byte
+        let signatures = self.signers().len().saturating_mul(SIGNATURE_SIZE);
+        self.message_data()
+            .len()
+            .saturating_add(signatures)
+            .saturating_add(1)
+    }
+
+    /// Whether the transaction fits in a single packet once signed. Larger atomic flows shall be
+    /// landed as separate transactions instead.
+    pub fn fits_in_packet(&self) -> bool {
+        self.serialized_size() <= PACKET_DATA_SIZE
+    }
+}
+
+/// The size of an ed25519 signature
+const SIGNATURE_SIZE: usize = 64;
+
+/// The max size of a serialized transaction
+pub const PACKET_DATA_SIZE: usize = 1232;
+
+/// The program IDs of a deployment, as the flows build instructions for both programs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramIds {
+    pub restaking_program: Pubkey,
+    pub vault_program: Pubkey,
+}
+
+impl ProgramIds {
+    pub(crate) fn restaking_config(&self) -> Pubkey {
+        Config::find_program_address(&self.restaking_program).0
+    }
+
+    pub(crate) fn vault_config(&self) -> Pubkey {
+        jito_vault_core::config::Config::find_program_address(&self.vault_program).0
+    }
+}
+
+/// The accounts of an operator joining an AVS and receiving delegations from a vault for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorOnboarding {
+    pub operator: Pubkey,
+    /// The operator's AVS admin
+    pub operator_avs_admin: Pubkey,
+    /// The operator's vault admin
+    pub operator_vault_admin: Pubkey,
+    pub avs: Pubkey,
+    /// The AVS's operator admin
+    pub avs_operator_admin: Pubkey,
+    pub vault: Pubkey,
+    /// The vault's operator admin
+    pub vault_operator_admin: Pubkey,
+    /// Pays for the tickets and the transaction fees
+    pub payer: Pubkey,
+}
+
+/// Builds the transactions onboarding an operator to an AVS with a vault, for each epoch:
+/// 1. the operator opts in to the AVS and the vault, and the vault adds the operator
+/// 2. once the operator's AVS ticket has warmed up, the AVS adds the operator
+pub fn operator_onboarding(
+    program_ids: &ProgramIds,
+    onboarding: &OperatorOnboarding,
+) -> Vec<Vec<FlowTransaction>> {
+    party_transactions(
+        &operator_onboarding_instructions(program_ids, onboarding),
+        &onboarding.payer,
+    )
+}
+
+/// Builds a transaction for each epoch of onboarding an operator to an AVS with a vault, signed by
+/// every admin of [`operator_onboarding`] in the epoch
+pub fn operator_onboarding_atomic(
+    program_ids: &ProgramIds,
+    onboarding: &OperatorOnboarding,
+) -> Vec<FlowTransaction> {
+    epoch_transactions(
+        &operator_onboarding_instructions(program_ids, onboarding),
+        &onboarding.payer,
+    )
+}
+
+/// The instructions of [`operator_onboarding`] for each epoch, grouped by the party that signs
+/// them
+fn operator_onboarding_instructions(
+    program_ids: &ProgramIds,
+    onboarding: &OperatorOnboarding,
+) -> Vec<Vec<Vec<Instruction>>> {
+    let ProgramIds {
+        restaking_program,
+        vault_program,
+    } = program_ids;
+    let config = program_ids.restaking_config();
+    let operator_avs_ticket = OperatorAvsTicket::find_program_address(
+        restaking_program,
+        &onboarding.operator,
+        &onboarding.avs,
+    )
+    .0;
+    let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+        restaking_program,
+        &onboarding.operator,
+        &onboarding.vault,
+    )
+    .0;
+    let avs_operator_ticket = AvsOperatorTicket::find_program_address(
+        restaking_program,
+        &onboarding.avs,
+        &onboarding.operator,
+    )
+    .0;
+    let vault_operator_ticket = VaultOperatorTicket::find_program_address(
+        vault_program,
+        &onboarding.vault,
+        &onboarding.operator,
+    )
+    .0;
+
+    vec![
+        vec![
+            vec![
+                operator_add_avs(
+                    restaking_program,
+                    &config,
+                    &onboarding.operator,
+                    &onboarding.avs,
+                    &operator_avs_ticket,
+                    &onboarding.operator_avs_admin,
+                    &onboarding.payer,
+                ),
+                operator_add_vault(
+                    restaking_program,
+                    &config,
+                    &onboarding.operator,
+                    &onboarding.vault,
+                    &operator_vault_ticket,
+                    &onboarding.operator_vault_admin,
+                    &onboarding.payer,
+                ),
+            ],
+            vec![jito_vault_sdk::add_operator(
+                vault_program,
+                &program_ids.vault_config(),
+                &onboarding.vault,
+                &onboarding.operator,
+                &operator_vault_ticket,
+                &vault_operator_ticket,
+                &onboarding.vault_operator_admin,
+                &onboarding.payer,
+            )],
+        ],
+        vec![vec![avs_add_operator(
+            restaking_program,
+            &config,
+            &onboarding.avs,
+            &onboarding.operator,
+            &avs_operator_ticket,
+            &operator_avs_ticket,
+            &onboarding.avs_operator_admin,
+            &onboarding.payer,
+        )]],
+    ]
+}
+
+/// The accounts of an AVS approving a vault and a slasher for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultSlasherApproval {
+    pub avs: Pubkey,
+    /// The AVS's vault admin
+    pub avs_vault_admin: Pubkey,
+    /// The AVS's slasher admin
+    pub avs_slasher_admin: Pubkey,
+    pub vault: Pubkey,
+    /// The vault's AVS admin
+    pub vault_avs_admin: Pubkey,
+    /// The vault's slasher admin
+    pub vault_slasher_admin: Pubkey,
+    pub slasher: Pubkey,
+    /// The max amount the slasher can slash from the vault per epoch
+    pub max_slashable_per_epoch: u64,
+    /// Hash of the slasher's metadata, e.g. its name and URI
+    pub metadata_hash: [u8; 32],
+    /// Pays for the tickets and the transaction fees
+    pub payer: Pubkey,
+}
+
+/// Builds the transactions of an AVS approving a vault and a slasher for it, for each epoch:
+/// 1. the AVS adds the vault, and the vault adds the AVS
+/// 2. once the AVS's vault ticket has warmed up, the AVS adds the slasher, and the vault adds the
+///    slasher
+pub fn vault_slasher_approval(
+    program_ids: &ProgramIds,
+    approval: &VaultSlasherApproval,
+) -> Vec<Vec<FlowTransaction>> {
+    party_transactions(
+        &vault_slasher_approval_instructions(program_ids, approval),
+        &approval.payer,
+    )
+}
+
+/// Builds a transaction for each epoch of an AVS approving a vault and a slasher for it, signed by
+/// every admin of [`vault_slasher_approval`] in the epoch
+pub fn vault_slasher_approval_atomic(
+    program_ids: &ProgramIds,
+    approval: &VaultSlasherApproval,
+) -> Vec<FlowTransaction> {
+    epoch_transactions(
+        &vault_slasher_approval_instructions(program_ids, approval),
+        &approval.payer,
+    )
+}
+
+/// The instructions of [`vault_slasher_approval`] for each epoch, grouped by the party that signs
+/// them
+fn vault_slasher_approval_instructions(
+    program_ids: &ProgramIds,
+    approval: &VaultSlasherApproval,
+) -> Vec<Vec<Vec<Instruction>>> {
+    let ProgramIds {
+        restaking_program,
+        vault_program,
+    } = program_ids;
+    let config = program_ids.restaking_config();
+    let vault_config = program_ids.vault_config();
+    let avs_vault_ticket =
+        AvsVaultTicket::find_program_address(restaking_program, &approval.avs, &approval.vault).0;
+    let avs_vault_slasher_ticket = AvsVaultSlasherTicket::find_program_address(
+        restaking_program,
+        &approval.avs,
+        &approval.vault,
+        &approval.slasher,
+    )
+    .0;
+    let vault_avs_ticket =
+        VaultAvsTicket::find_program_address(vault_program, &approval.vault, &approval.avs).0;
+    let vault_avs_slasher_ticket = VaultAvsSlasherTicket::find_program_address(
+        vault_program,
+        &approval.vault,
+        &approval.avs,
+        &approval.slasher,
+    )
+    .0;
+
+    vec![
+        vec![
+            vec![avs_add_vault(
+                restaking_program,
+                &config,
+                &approval.avs,
+                &approval.vault,
+                &avs_vault_ticket,
+                &approval.avs_vault_admin,
+                &approval.payer,
+            )],
+            vec![jito_vault_sdk::add_avs(
+                vault_program,
+                &vault_config,
+                &approval.vault,
+                &approval.avs,
+                &avs_vault_ticket,
+                &vault_avs_ticket,
+                &approval.vault_avs_admin,
+                &approval.payer,
+            )],
+        ],
+        vec![
+            vec![avs_add_vault_slasher(
+                restaking_program,
+                &config,
+                &approval.avs,
+                &approval.vault,
+                &approval.slasher,
+                &avs_vault_ticket,
+                &avs_vault_slasher_ticket,
+                &approval.avs_slasher_admin,
+                &approval.payer,
+                approval.max_slashable_per_epoch,
+                approval.metadata_hash,
+            )],
+            vec![jito_vault_sdk::add_slasher(
+                vault_program,
+                &vault_config,
+                &approval.vault,
+                &approval.avs,
+                &approval.slasher,
+                &avs_vault_slasher_ticket,
+                &vault_avs_slasher_ticket,
+                &approval.vault_slasher_admin,
+                &approval.payer,
+            )],
+        ],
+    ]
+}
+
+/// A transaction for each party of each epoch of a flow
+fn party_transactions(
+    epochs: &[Vec<Vec<Instruction>>],
+    payer: &Pubkey,
+) -> Vec<Vec<FlowTransaction>> {
+    epochs
+        .iter()
+        .map(|parties| {
+            parties
+                .iter()
+                .map(|instructions| FlowTransaction::new(instructions, payer))
+                .collect()
+        })
+        .collect()
+}
+
+/// A transaction for each epoch of a flow, signed by every party of the epoch
+fn epoch_transactions(epochs: &[Vec<Vec<Instruction>>], payer: &Pubkey) -> Vec<FlowTransaction> {
+    epochs
+        .iter()
+        .map(|parties| FlowTransaction::new(&parties.concat(), payer))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::flows::{
+        operator_onboarding, operator_onboarding_atomic, vault_slasher_approval,
+        vault_slasher_approval_atomic, OperatorOnboarding, ProgramIds, VaultSlasherApproval,
+    };
+
+    const PROGRAM_IDS: ProgramIds = ProgramIds {
+        restaking_program: Pubkey::new_from_array([1; 32]),
+        vault_program: Pubkey::new_from_array([2; 32]),
+    };
+
+    #[test]
+    fn test_operator_onboarding_signers() {
+        let onboarding = OperatorOnboarding {
+            operator: Pubkey::new_unique(),
+            operator_avs_admin: Pubkey::new_unique(),
+            operator_vault_admin: Pubkey::new_unique(),
+            avs: Pubkey::new_unique(),
+            avs_operator_admin: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            vault_operator_admin: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+        };
+        let epochs = operator_onboarding(&PROGRAM_IDS, &onboarding);
+
+        assert_eq!(epochs.len(), 2);
+        assert_eq!(epochs[0].len(), 2);
+        assert_eq!(
+            epochs[0][0].signers(),
+            [
+                onboarding.payer,
+                onboarding.operator_avs_admin,
+                onboarding.operator_vault_admin
+            ]
+        );
+        assert_eq!(
+            epochs[0][1].signers(),
+            [onboarding.payer, onboarding.vault_operator_admin]
+        );
+        // the AVS only adds the operator once the operator's ticket has warmed up
+        assert_eq!(epochs[1].len(), 1);
+        assert_eq!(
+            epochs[1][0].signers(),
+            [onboarding.payer, onboarding.avs_operator_admin]
+        );
+    }
+
+    #[test]
+    fn test_operator_onboarding_atomic_signers() {
+        let onboarding = OperatorOnboarding {
+            operator: Pubkey::new_unique(),
+            operator_avs_admin: Pubkey::new_unique(),
+            operator_vault_admin: Pubkey::new_unique(),
+            avs: Pubkey::new_unique(),
+            avs_operator_admin: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            vault_operator_admin: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+        };
+        let transactions = operator_onboarding_atomic(&PROGRAM_IDS, &onboarding);
+        let epochs = operator_onboarding(&PROGRAM_IDS, &onboarding);
+        assert_eq!(transactions.len(), epochs.len());
+
+        for (transaction, parties) in transactions.iter().zip(&epochs) {
+            let instructions: usize = parties
+                .iter()
+                .map(|transaction| transaction.message.instructions.len())
+                .sum();
+            assert_eq!(transaction.message.instructions.len(), instructions);
+            assert!(transaction.fits_in_packet());
+        }
+
+        // the fee payer comes first, the admins are listed in the message's key order
+        let transaction = &transactions[0];
+        assert_eq!(transaction.signer_index(&onboarding.payer), Some(0));
+        let mut admins = transaction.signers()[1..].to_vec();
+        admins.sort();
+        let mut expected = vec![
+            onboarding.operator_avs_admin,
+            onboarding.operator_vault_admin,
+            onboarding.vault_operator_admin,
+        ];
+        expected.sort();
+        assert_eq!(admins, expected);
+        assert_eq!(transaction.signer_index(&onboarding.operator), None);
+        assert_eq!(
+            transactions[1].signers(),
+            [onboarding.payer, onboarding.avs_operator_admin]
+        );
+    }
+
+    #[test]
+    fn test_vault_slasher_approval_shared_admin_signs_once() {
+        let avs_admin = Pubkey::new_unique();
+        let vault_admin = Pubkey::new_unique();
+        let approval = VaultSlasherApproval {
+            avs: Pubkey::new_unique(),
+            avs_vault_admin: avs_admin,
+            avs_slasher_admin: avs_admin,
+            vault: Pubkey::new_unique(),
+            vault_avs_admin: vault_admin,
+            vault_slasher_admin: vault_admin,
+            slasher: Pubkey::new_unique(),
+            max_slashable_per_epoch: 100,
+            metadata_hash: [0; 32],
+            payer: avs_admin,
+        };
+        let epochs = vault_slasher_approval(&PROGRAM_IDS, &approval);
+
+        assert_eq!(epochs.len(), 2);
+        for parties in &epochs {
+            assert_eq!(parties.len(), 2);
+            assert_eq!(parties[0].signers(), [avs_admin]);
+            assert_eq!(parties[1].signers(), [avs_admin, vault_admin]);
+        }
+
+        let transactions = vault_slasher_approval_atomic(&PROGRAM_IDS, &approval);
+        assert_eq!(transactions.len(), 2);
+        for transaction in &transactions {
+            assert_eq!(transaction.signers(), [avs_admin, vault_admin]);
+            assert!(transaction.fits_in_packet());
+        }
+    }
+}
@@ -1,29 +1,64 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+// the BorshSchema derive on an enum defines a struct per variant whose fields are never read
+#![allow(dead_code)]
+
+pub mod account_indices;
+pub mod accounts;
+pub mod apy;
+pub mod batch;
+pub mod bulk;
+pub mod error;
+pub mod event;
+pub mod flows;
+pub mod lookup_table;
+pub mod matching;
+pub mod reward_merkle_tree;
+pub mod stake_history;
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+pub use jito_restaking_core::{
+    avs::{MAX_MAX_HEARTBEAT_AGE, MIN_MAX_HEARTBEAT_AGE},
+    metadata::{Metadata, MAX_METADATA_ICON_LEN, MAX_METADATA_NAME_LEN, MAX_METADATA_URI_LEN},
+};
+use jito_restaking_core::{
+    avs_operator_ticket::AvsOperatorTicket, avs_vault_ticket::AvsVaultTicket,
+    operator_avs_ticket::OperatorAvsTicket,
+};
+use jito_restaking_sanitization::program_data::program_data_address;
+use jito_vault_core::{
+    vault_avs_ticket::VaultAvsTicket, vault_delegation_list::VaultDelegationList,
+};
 use shank::ShankInstruction;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     system_program,
 };
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
+#[derive(Debug, BorshSerialize, BorshSchema, BorshDeserialize, ShankInstruction)]
 pub enum RestakingInstruction {
     /// Initializes the global configuration
     #[account(0, writable, name = "config")]
-    #[account(1, writable, signer, name = "admin")]
+    #[account(1, signer, name = "admin")]
     #[account(2, name = "vault_program")]
-    #[account(3, name = "system_program")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    #[account(5, name = "program_data")]
     InitializeConfig,
 
     /// Initializes the AVS
     #[account(0, writable, name = "config")]
     #[account(1, writable, name = "avs")]
-    #[account(2, writable, signer, name = "admin")]
+    #[account(2, signer, name = "admin")]
     #[account(3, signer, name = "base")]
-    #[account(4, name = "system_program")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
     InitializeAvs,
 
-    /// AVS adds support for receiving delegation from a vault
+    /// AVS adds support for receiving delegation from a vault. The payer may be the AVS itself,
+    /// funding the ticket out of its own lamports.
     #[account(0, name = "config")]
     #[account(1, writable, name = "avs")]
     #[account(2, name = "vault")]
@@ -42,7 +77,8 @@ pub enum RestakingInstruction {
     AvsRemoveVault,
 
     /// After the operator has signaled they are ready to join the network,
-    /// the AVS admin can add the operator to the AVS
+    /// the AVS admin can add the operator to the AVS. The payer may be the AVS itself, funding the
+    /// ticket out of its own lamports.
     #[account(0, name = "config")]
     #[account(1, writable, name = "avs")]
     #[account(2, name = "operator")]
@@ -60,35 +96,47 @@ pub enum RestakingInstruction {
     #[account(4, signer, name = "admin")]
     AvsRemoveOperator,
 
-    /// The AVS adds support for a vault slasher
+    /// The AVS adds support for a vault slasher. Requires the AVS multisig, if any, passed in as
+    /// remaining signer accounts. The payer may be the AVS itself, funding the ticket out of its
+    /// own lamports.
     ///
     /// # Arguments
-    /// * `u64` - The maximum amount that can be slashed from the vault per epoch
+    /// * `max_slashable_per_epoch` - The maximum amount that can be slashed from the vault per epoch
+    /// * `metadata_hash` - Hash of the slasher's metadata, e.g. its name and URI
     #[account(0, name = "config")]
     #[account(1, writable, name = "avs")]
     #[account(2, name = "vault")]
     #[account(3, name = "slasher")]
-    #[account(4, name = "avs_vault_ticket")]
+    #[account(4, writable, name = "avs_vault_ticket")]
     #[account(5, writable, name = "avs_slasher_ticket")]
     #[account(6, signer, name = "admin")]
     #[account(7, writable, signer, name = "payer")]
     #[account(8, name = "system_program")]
-    AvsAddVaultSlasher(u64),
+    AvsAddVaultSlasher {
+        max_slashable_per_epoch: u64,
+        metadata_hash: [u8; 32],
+    },
 
     /// AVS removes support for a slasher
     #[account(0, name = "config")]
     #[account(1, name = "avs")]
     #[account(2, name = "vault")]
     #[account(3, name = "slasher")]
-    #[account(4, writable, name = "avs_slasher_ticket")]
-    #[account(5, signer, name = "admin")]
+    #[account(4, writable, name = "avs_vault_ticket")]
+    #[account(5, writable, name = "avs_slasher_ticket")]
+    #[account(6, signer, name = "admin")]
     AvsRemoveVaultSlasher,
 
+    /// The AVS admin proposes a new admin, who takes over with
+    /// [`RestakingInstruction::AvsAcceptAdmin`]. Requires the AVS multisig, if any, passed in as
+    /// remaining signer accounts.
     #[account(0, writable, name = "avs")]
     #[account(1, signer, name = "old_admin")]
-    #[account(2, signer, name = "new_admin")]
+    #[account(2, name = "new_admin")]
     AvsSetAdmin,
 
+    /// The AVS admin delegates one of the AVS's duties to another key. Requires the AVS multisig,
+    /// if any, passed in as remaining signer accounts.
     #[account(0, writable, name = "avs")]
     #[account(1, signer, name = "admin")]
     #[account(2, name = "new_admin")]
@@ -97,15 +145,17 @@ pub enum RestakingInstruction {
     /// Initializes a operator
     #[account(0, writable, name = "config")]
     #[account(1, writable, name = "operator")]
-    #[account(2, writable, signer, name = "admin")]
+    #[account(2, signer, name = "admin")]
     #[account(3, signer, name = "base")]
-    #[account(4, name = "system_program")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
     InitializeOperator,
 
-    /// Sets the admin for a node operator
+    /// Proposes a new admin for a node operator, who takes over with
+    /// [`RestakingInstruction::OperatorAcceptAdmin`]
     #[account(0, writable, name = "node_operator")]
     #[account(1, signer, name = "old_admin")]
-    #[account(2, signer, name = "new_admin")]
+    #[account(2, name = "new_admin")]
     OperatorSetAdmin,
 
     /// Sets the voter for a node operator
@@ -114,7 +164,8 @@ pub enum RestakingInstruction {
     #[account(2, name = "voter")]
     OperatorSetVoter,
 
-    /// Operator adds support for receiving delegation from a vault
+    /// Operator adds support for receiving delegation from a vault. The payer may be the operator
+    /// itself, funding the ticket out of its own lamports.
     #[account(0, name = "config")]
     #[account(1, writable, name = "operator")]
     #[account(2, name = "vault")]
@@ -132,7 +183,8 @@ pub enum RestakingInstruction {
     #[account(4, signer, name = "admin")]
     OperatorRemoveVault,
 
-    /// Node operator adds support for running an AVS
+    /// Node operator adds support for running an AVS. The payer may be the operator itself,
+    /// funding the ticket out of its own lamports.
     #[account(0, name = "config")]
     #[account(1, writable, name = "operator")]
     #[account(2, name = "avs")]
@@ -150,6 +202,7 @@ pub enum RestakingInstruction {
     #[account(4, signer, name = "admin")]
     OperatorRemoveAvs,
 
+    /// AVS withdraw admin withdraws tokens held by a token account the AVS owns
     #[account(0, name = "avs")]
     #[account(1, writable, name = "avs_token_account")]
     #[account(2, writable, name = "receiver_token_account")]
@@ -157,20 +210,541 @@ pub enum RestakingInstruction {
     #[account(4, name = "token_program")]
     AvsWithdrawalAsset { token_mint: Pubkey, amount: u64 },
 
+    /// Operator withdraw admin withdraws tokens held by a token account the operator owns
     #[account(0, name = "operator")]
     #[account(1, signer, name = "admin")]
     #[account(2, writable, name = "operator_token_account")]
     #[account(3, writable, name = "receiver_token_account")]
     #[account(4, name = "token_program")]
     OperatorWithdrawalAsset { token_mint: Pubkey, amount: u64 },
+
+    /// AVS sets the service fee charged to a vault, in basis points of the vault's
+    /// delegated stake per epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, name = "vault")]
+    #[account(3, writable, name = "avs_vault_ticket")]
+    #[account(4, signer, name = "admin")]
+    AvsSetVaultServiceFee { fee_bps: u16 },
+
+    /// The operator voter signals the operator is online for an AVS
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, name = "avs")]
+    #[account(3, name = "operator_avs_ticket")]
+    #[account(4, writable, name = "operator_avs_heartbeat")]
+    #[account(5, signer, name = "voter")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    OperatorHeartbeat,
+
+    /// The AVS sets the max number of slots an operator can go without a heartbeat
+    /// before it can be ejected. Zero disables ejection, otherwise it shall be within
+    /// [`MIN_MAX_HEARTBEAT_AGE`] and [`MAX_MAX_HEARTBEAT_AGE`].
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "avs")]
+    #[account(2, signer, name = "admin")]
+    AvsSetMaxHeartbeatAge { max_heartbeat_age: u64 },
+
+    /// Permissionless crank that deactivates an operator whose heartbeat is stale
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "avs_operator_ticket")]
+    #[account(4, name = "operator_avs_heartbeat")]
+    AvsEjectStaleOperator,
+
+    /// Permissionless crank that re-activates an ejected operator once its heartbeat is fresh
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "avs_operator_ticket")]
+    #[account(4, name = "operator_avs_heartbeat")]
+    AvsReactivateOperator,
+
+    /// Node operator begins cooldown on every AVS and vault ticket it holds. The operator AVS
+    /// and operator vault tickets are passed in as writable remaining accounts.
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, signer, name = "admin")]
+    OperatorExitAll,
+
+    /// Read-only check that the AVS operator, vault and slasher counts match the AVS operator,
+    /// AVS vault and AVS vault slasher tickets not closed, passed in as remaining accounts
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    AssertAvsHealthy,
+
+    /// Read-only check that the operator AVS and vault counts match the operator AVS and
+    /// operator vault tickets not closed, passed in as remaining accounts
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    AssertOperatorHealthy,
+
+    /// Config admin approves a program to own slasher accounts. Once any program is approved,
+    /// AVS admins can only add slashers owned by an approved program.
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigAddSlasherProgram { program: Pubkey },
+
+    /// Config admin removes a program from the approved slasher programs
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigRemoveSlasherProgram { program: Pubkey },
+
+    /// Sweeps tokens sent to the config or a ticket PDA into the respective treasury. The parent
+    /// is the AVS or operator that created the ticket, or the config itself.
+    #[account(0, name = "config")]
+    #[account(1, name = "parent")]
+    #[account(2, name = "pda")]
+    #[account(3, writable, name = "pda_token_account")]
+    #[account(4, writable, name = "treasury_token_account")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, name = "token_program")]
+    SweepTokens { token_mint: Pubkey },
+
+    /// Pays for an account owned by the program to be rent exempt at its current size
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    TopUpRent,
+
+    /// Sweeps lamports above the rent-exempt minimum from the config or a ticket PDA into the
+    /// respective treasury. The parent is the AVS or operator that created the ticket, or the
+    /// config itself, in which case the lamports go to the admin.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "parent")]
+    #[account(2, writable, name = "pda")]
+    #[account(3, writable, signer, name = "admin")]
+    SweepLamports,
+
+    /// Sets the key allowed to submit admin operations signed off-chain by the AVS admins. The
+    /// default pubkey removes the relayer.
+    #[account(0, writable, name = "avs")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "relayer")]
+    AvsSetRelayer,
+
+    /// Sets the key allowed to submit admin operations signed off-chain by the operator admin.
+    /// The default pubkey removes the relayer.
+    #[account(0, writable, name = "operator")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "relayer")]
+    OperatorSetRelayer,
+
+    /// The relayer submits an admin operation signed by the admin, with the signature verified
+    /// by an ed25519 program instruction placed right before this one
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "account", description = "The AVS or operator")]
+    #[account(2, signer, name = "relayer")]
+    #[account(3, name = "instructions_sysvar")]
+    RelayAdminOperation {
+        nonce: u64,
+        operation: RelayedOperation,
+    },
+
+    /// The AVS sets the max number of active slashers it allows for each vault
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "avs")]
+    #[account(2, signer, name = "admin")]
+    AvsSetMaxSlashersPerVault { max_slashers_per_vault: u64 },
+
+    /// Config admin sets the epoch length relationship tickets warm up over after being added and
+    /// cool down over after being removed, within the bounds of the config
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetTicketEpochLength { ticket_epoch_length: u64 },
+
+    /// The operator admin delegates one of the operator's duties to another key
+    #[account(0, writable, name = "operator")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "new_admin")]
+    OperatorSetSecondaryAdmin(OperatorAdminRole),
+
+    /// The admin proposed with [`RestakingInstruction::AvsSetAdmin`] takes over the AVS
+    #[account(0, writable, name = "avs")]
+    #[account(1, signer, name = "new_admin")]
+    AvsAcceptAdmin,
+
+    /// The admin proposed with [`RestakingInstruction::OperatorSetAdmin`] takes over the operator
+    #[account(0, writable, name = "operator")]
+    #[account(1, signer, name = "new_admin")]
+    OperatorAcceptAdmin,
+
+    /// The config admin proposes a new admin, who takes over with
+    /// [`RestakingInstruction::ConfigAcceptAdmin`]
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "new_admin")]
+    ConfigSetAdmin,
+
+    /// The admin proposed with [`RestakingInstruction::ConfigSetAdmin`] takes over the config
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "new_admin")]
+    ConfigAcceptAdmin,
+
+    /// AVS withdraw admin creates the reward budget for a mint, emitting `tokens_per_epoch` from
+    /// the current epoch and decaying by `decay_bps` each epoch after that
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, writable, name = "avs_reward_budget")]
+    #[account(3, name = "mint")]
+    #[account(4, name = "receiver_token_account")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    AvsInitializeRewardBudget {
+        epoch_length: u64,
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+    },
+
+    /// AVS withdraw admin restarts the reward budget emission schedule from the current epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, writable, name = "avs_reward_budget")]
+    #[account(3, name = "mint")]
+    #[account(4, signer, name = "admin")]
+    AvsSetRewardBudget {
+        tokens_per_epoch: u64,
+        decay_bps: u16,
+    },
+
+    /// Permissionless crank that pays the emissions of the epochs elapsed since the last
+    /// distribution from the AVS token account to the reward budget receiver
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, writable, name = "avs_reward_budget")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "avs_token_account")]
+    #[account(5, writable, name = "receiver_token_account")]
+    #[account(6, name = "token_program")]
+    AvsDistributeRewards,
+
+    /// The AVS admin sets the multisig guarding the high-risk operations of the AVS. A zero
+    /// threshold disables it. Changing an enabled multisig requires it, passed in as remaining
+    /// signer accounts.
+    #[account(0, writable, name = "avs")]
+    #[account(1, signer, name = "admin")]
+    AvsSetMultisig { signers: [Pubkey; 3], threshold: u8 },
+
+    /// Sets the account receiving the rent of the AVS tickets closed with
+    /// [`RestakingInstruction::CloseTicket`]
+    #[account(0, writable, name = "avs")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "rent_collector")]
+    AvsSetRentCollector,
+
+    /// Sets the account receiving the rent of the operator tickets closed with
+    /// [`RestakingInstruction::CloseTicket`]
+    #[account(0, writable, name = "operator")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "rent_collector")]
+    OperatorSetRentCollector,
+
+    /// Closes a ticket deactivated in an earlier slot, returning its rent to the rent collector of
+    /// the AVS or operator that created it, which counts the closed ticket. The parent admin signs.
+    /// Tickets between an AVS and an operator are closed by
+    /// [`RestakingInstruction::FinalizeOperatorAvsRemoval`] instead.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "parent")]
+    #[account(2, writable, name = "ticket")]
+    #[account(3, writable, name = "rent_collector")]
+    #[account(4, signer, name = "admin")]
+    CloseTicket,
+
+    /// The AVS metadata admin sets the name, URI and icon of the AVS, creating its metadata
+    /// account on first use
+    #[account(0, name = "avs")]
+    #[account(1, writable, name = "metadata")]
+    #[account(2, signer, name = "metadata_admin")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    UpdateAvsMetadata {
+        name: String,
+        uri: String,
+        icon: String,
+    },
+
+    /// The operator metadata admin sets the name, URI and icon of the operator, creating its
+    /// metadata account on first use
+    #[account(0, name = "operator")]
+    #[account(1, writable, name = "metadata")]
+    #[account(2, signer, name = "metadata_admin")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    UpdateOperatorMetadata {
+        name: String,
+        uri: String,
+        icon: String,
+    },
+
+    /// Migrates an account written by an earlier version of the program to its current layout,
+    /// see [`jito_restaking_core::migration`]. Anyone can call it, the payer covers the rent of
+    /// the grown account.
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+    /// The operator AVS admin advertises the max additional stake the operator can handle for an
+    /// AVS it joined, creating the capacity account on first use
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, name = "avs")]
+    #[account(3, name = "operator_avs_ticket")]
+    #[account(4, writable, name = "operator_avs_capacity")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    OperatorSetAvsCapacity { capacity: u64 },
+
+    /// Config admin bounds the fee operators charge on rewards and sets the slots an operator
+    /// waits between two fee changes
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetOperatorFeeParams {
+        max_operator_fee_bps: u16,
+        operator_fee_cooldown_slots: u64,
+    },
+
+    /// Operator admin sets the share of the rewards the operator keeps before the rest goes to the
+    /// vault stakers
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "operator")]
+    #[account(2, signer, name = "admin")]
+    SetOperatorFee { operator_fee_bps: u16 },
+
+    /// Deposits rewards the AVS pays for an epoch into the router of the epoch and mint, creating
+    /// the router on first use. Deposits are accepted until the epoch ends.
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, name = "vault_config")]
+    #[account(3, writable, name = "epoch_reward_router")]
+    #[account(4, name = "mint")]
+    #[account(5, writable, name = "router_token_account")]
+    #[account(6, writable, name = "depositor_token_account")]
+    #[account(7, signer, name = "depositor")]
+    #[account(8, writable, signer, name = "payer")]
+    #[account(9, name = "token_program")]
+    #[account(10, name = "system_program")]
+    AvsDepositEpochRewards { epoch: u64, amount: u64 },
+
+    /// Records the active delegation of a vault to an operator of the AVS during the epoch of the
+    /// router, along with the operator fee and the latest performance score the AVS scorer
+    /// attested for the operator, if any. Anyone can call it once per vault and operator.
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "vault")]
+    #[account(4, name = "vault_config")]
+    #[account(5, name = "vault_delegation_list")]
+    #[account(6, name = "avs_operator_ticket")]
+    #[account(7, name = "operator_avs_ticket")]
+    #[account(8, name = "avs_vault_ticket")]
+    #[account(9, name = "operator_vault_ticket")]
+    #[account(10, writable, name = "epoch_reward_router")]
+    #[account(11, writable, name = "epoch_reward_snapshot")]
+    #[account(12, name = "operator_avs_score")]
+    #[account(13, writable, signer, name = "payer")]
+    #[account(14, name = "system_program")]
+    SnapshotEpochRewardStake,
+
+    /// Pays out the share of a snapshot once the epoch of the router ended, the operator fee to
+    /// the operator token account and the rest to the vault token account. Anyone can call it.
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, writable, name = "epoch_reward_router")]
+    #[account(3, writable, name = "epoch_reward_snapshot")]
+    #[account(4, writable, name = "router_token_account")]
+    #[account(5, writable, name = "operator_token_account")]
+    #[account(6, writable, name = "vault_token_account")]
+    #[account(7, name = "token_program")]
+    DistributeEpochRewards,
+
+    /// AVS withdraw admin takes back the rewards left in the router once every snapshot was paid
+    /// out, such as rounding dust or rewards of an epoch without stake
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, name = "epoch_reward_router")]
+    #[account(3, writable, name = "router_token_account")]
+    #[account(4, writable, name = "receiver_token_account")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, name = "token_program")]
+    AvsReclaimEpochRewards,
+
+    /// Records the active delegation of a vault to an operator for the current epoch and adds it
+    /// to the stake of the operator for the epoch, for rewards and slashing to read later. Anyone
+    /// can call it once per vault, operator and epoch.
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, name = "vault")]
+    #[account(3, name = "vault_config")]
+    #[account(4, name = "vault_delegation_list")]
+    #[account(5, name = "operator_vault_ticket")]
+    #[account(6, writable, name = "operator_epoch_stake")]
+    #[account(7, writable, name = "vault_operator_epoch_stake")]
+    #[account(8, writable, signer, name = "payer")]
+    #[account(9, name = "system_program")]
+    SnapshotEpochStake,
+
+    /// Sets the key allowed to attest the performance scores of the operators of the AVS. The
+    /// default pubkey removes the scorer.
+    #[account(0, writable, name = "avs")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "scorer")]
+    AvsSetScorer,
+
+    /// The AVS scorer attests the performance score of an operator of the AVS, in basis points.
+    /// The score account is created on the first attestation and updated afterwards.
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "avs_operator_ticket")]
+    #[account(4, writable, name = "operator_avs_score")]
+    #[account(5, signer, name = "scorer")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    AvsAttestOperatorScore { score_bps: u16 },
+
+    /// Archives an epoch stake snapshot of the operator once it's older than the retention
+    /// window, chaining its hash onto the stake history of the operator and closing it. The rent
+    /// goes to the rent collector of the operator. Anyone can call it.
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, name = "vault_config")]
+    #[account(3, writable, name = "operator_stake_history")]
+    #[account(4, writable, name = "epoch_stake")]
+    #[account(5, writable, name = "rent_collector")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    ArchiveEpochStake,
+
+    /// Config admin sets a deployment parameter, see [`ConfigParameter`]
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetParameter(ConfigParameter),
+
+    /// The reward admin of an AVS or vault posts the merkle root of the rewards of an epoch for a
+    /// mint, funding the distribution with `max_total_claim` from the admin token account. The
+    /// distribution token account is the associated token account of the distribution and must
+    /// exist. The AVS withdraw admin or the vault admin posts for its source. The token program
+    /// is the one owning the mint, SPL Token or Token-2022.
+    #[account(0, name = "config")]
+    #[account(1, name = "source")]
+    #[account(2, writable, name = "reward_distribution")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "distribution_token_account")]
+    #[account(5, writable, name = "admin_token_account")]
+    #[account(6, signer, name = "admin")]
+    #[account(7, writable, signer, name = "payer")]
+    #[account(8, name = "token_program")]
+    #[account(9, name = "system_program")]
+    PostRewardRoot {
+        epoch: u64,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    },
+
+    /// Pays out the reward of a claimant proven against the merkle root of a distribution to a
+    /// token account of the claimant, creating the claim status so it's paid exactly once. The
+    /// claimant doesn't need to sign, so anyone can crank claims.
+    #[account(0, writable, name = "reward_distribution")]
+    #[account(1, writable, name = "claim_status")]
+    #[account(2, name = "claimant")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "distribution_token_account")]
+    #[account(5, writable, name = "claimant_token_account")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "token_program")]
+    #[account(8, name = "system_program")]
+    ClaimReward { amount: u64, proof: Vec<[u8; 32]> },
+
+    /// Finalizes the removal of an operator from an AVS, by either side, once the removed ticket
+    /// cooled down, no vault securing the AVS delegates to the operator and no slash filed for the
+    /// AVS is pending in them, closing both tickets and returning their rent to the rent
+    /// collectors. Until then the operator stays slashable. An operator ejected for missing
+    /// heartbeats shall be removed by the AVS first. Every vault ticket of the AVS not closed is passed
+    /// in as its AVS vault ticket, vault delegation list and vault AVS ticket in the remaining
+    /// accounts. Anyone can call it.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "avs")]
+    #[account(2, writable, name = "operator")]
+    #[account(3, writable, name = "avs_operator_ticket")]
+    #[account(4, writable, name = "operator_avs_ticket")]
+    #[account(5, writable, name = "avs_rent_collector")]
+    #[account(6, writable, name = "operator_rent_collector")]
+    FinalizeOperatorAvsRemoval,
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+/// A deployment parameter the config admin sets with [`RestakingInstruction::ConfigSetParameter`]
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshSchema, BorshDeserialize, PartialEq, Eq)]
+pub enum ConfigParameter {
+    /// The max number of operators an AVS can add, within one and
+    /// [`jito_restaking_core::config::MAX_MAX_OPERATORS_PER_AVS`]
+    MaxOperatorsPerAvs(u64),
+
+    /// The max number of vaults an operator can opt in to, within one and
+    /// [`jito_restaking_core::config::MAX_MAX_VAULTS_PER_OPERATOR`]
+    MaxVaultsPerOperator(u64),
+
+    /// The max number of slashers per vault new AVSs start with
+    DefaultMaxSlashersPerVault(u64),
+}
+
+/// An admin operation that can be signed off-chain and submitted by a relayer with
+/// [`RestakingInstruction::RelayAdminOperation`]
+#[derive(Debug, Clone, BorshSerialize, BorshSchema, BorshDeserialize, PartialEq, Eq)]
+pub enum RelayedOperation {
+    /// Signed by the AVS operator admin
+    AvsSetMaxHeartbeatAge { max_heartbeat_age: u64 },
+
+    /// Signed by the operator admin
+    OperatorSetVoter { voter: Pubkey },
+
+    /// Signed by the operator admin, allows the session key to sign heartbeats in place of the
+    /// voter until the expiry slot. The default pubkey revokes the session key.
+    OperatorGrantSessionKey {
+        session_key: Pubkey,
+        expiry_slot: u64,
+    },
+}
+
+/// Prefix of relayed messages, so a signature over one can't be mistaken for anything else
+pub const RELAYED_MESSAGE_PREFIX: &[u8] = b"jito-restaking-relay";
+
+/// Returns the message the admin signs to authorize a relayed operation on the AVS or operator
+pub fn relayed_message(
+    program_id: &Pubkey,
+    account: &Pubkey,
+    nonce: u64,
+    operation: &RelayedOperation,
+) -> Vec<u8> {
+    let mut message = RELAYED_MESSAGE_PREFIX.to_vec();
+    message.extend_from_slice(program_id.as_ref());
+    message.extend_from_slice(account.as_ref());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&operation.try_to_vec().unwrap());
+    message
+}
+
+#[derive(Debug, BorshSerialize, BorshSchema, BorshDeserialize, PartialEq, Eq)]
 pub enum AvsAdminRole {
     Operator,
     Vault,
     Slasher,
     Withdraw,
+    Metadata,
+    SlashVeto,
+}
+
+#[derive(Debug, BorshSerialize, BorshSchema, BorshDeserialize, PartialEq, Eq)]
+pub enum OperatorAdminRole {
+    Avs,
+    Vault,
+    Withdraw,
+    Metadata,
 }
 
 pub fn initialize_config(
@@ -178,12 +752,15 @@ pub fn initialize_config(
     config: &Pubkey,
     admin: &Pubkey,
     vault_program: &Pubkey,
+    payer: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*config, false),
-        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(*admin, true),
         AccountMeta::new_readonly(*vault_program, false),
+        AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(program_data_address(program_id), false),
     ];
     Instruction {
         program_id: *program_id,
@@ -198,12 +775,14 @@ pub fn initialize_avs(
     avs: &Pubkey,
     admin: &Pubkey,
     base: &Pubkey,
+    payer: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*config, false),
         AccountMeta::new(*avs, false),
-        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(*admin, true),
         AccountMeta::new_readonly(*base, true),
+        AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     Instruction {
@@ -213,6 +792,8 @@ pub fn initialize_avs(
     }
 }
 
+/// Builds [`RestakingInstruction::AvsAddVault`]. Passing the AVS as `payer` funds the ticket out of
+/// the AVS's own lamports instead of a signer's.
 pub fn avs_add_vault(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -228,7 +809,7 @@ pub fn avs_add_vault(
         AccountMeta::new_readonly(*vault, false),
         AccountMeta::new(*avs_vault_ticket, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new(*payer, true),
+        AccountMeta::new(*payer, payer != avs),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     Instruction {
@@ -261,6 +842,8 @@ pub fn avs_remove_vault(
 }
 
 #[allow(clippy::too_many_arguments)]
+/// Builds [`RestakingInstruction::AvsAddOperator`]. Passing the AVS as `payer` funds the ticket out
+/// of the AVS's own lamports instead of a signer's.
 pub fn avs_add_operator(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -278,7 +861,7 @@ pub fn avs_add_operator(
         AccountMeta::new(*avs_operator_ticket, false),
         AccountMeta::new_readonly(*operator_avs_ticket, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new(*payer, true),
+        AccountMeta::new(*payer, payer != avs),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     Instruction {
@@ -313,6 +896,8 @@ pub fn avs_remove_operator(
 }
 
 #[allow(clippy::too_many_arguments)]
+/// Builds [`RestakingInstruction::AvsAddVaultSlasher`]. Passing the AVS as `payer` funds the ticket
+/// out of the AVS's own lamports instead of a signer's.
 pub fn avs_add_vault_slasher(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -324,34 +909,40 @@ pub fn avs_add_vault_slasher(
     admin: &Pubkey,
     payer: &Pubkey,
 
-    max_slash_amount: u64,
+    max_slashable_per_epoch: u64,
+    metadata_hash: [u8; 32],
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*avs, false),
         AccountMeta::new_readonly(*vault, false),
         AccountMeta::new_readonly(*slasher, false),
-        AccountMeta::new_readonly(*avs_vault_ticket, false),
+        AccountMeta::new(*avs_vault_ticket, false),
         AccountMeta::new(*avs_slasher_ticket, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new(*payer, true),
+        AccountMeta::new(*payer, payer != avs),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: RestakingInstruction::AvsAddVaultSlasher(max_slash_amount)
-            .try_to_vec()
-            .unwrap(),
+        data: RestakingInstruction::AvsAddVaultSlasher {
+            max_slashable_per_epoch,
+            metadata_hash,
+        }
+        .try_to_vec()
+        .unwrap(),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn avs_remove_vault_slasher(
     program_id: &Pubkey,
     config: &Pubkey,
     avs: &Pubkey,
     vault: &Pubkey,
     slasher: &Pubkey,
+    avs_vault_ticket: &Pubkey,
     avs_slasher_ticket: &Pubkey,
     admin: &Pubkey,
 ) -> Instruction {
@@ -360,6 +951,7 @@ pub fn avs_remove_vault_slasher(
         AccountMeta::new_readonly(*avs, false),
         AccountMeta::new_readonly(*vault, false),
         AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*avs_vault_ticket, false),
         AccountMeta::new(*avs_slasher_ticket, false),
         AccountMeta::new_readonly(*admin, true),
     ];
@@ -381,7 +973,7 @@ pub fn avs_set_admin(
     let accounts = vec![
         AccountMeta::new(*avs, false),
         AccountMeta::new_readonly(*old_admin, true),
-        AccountMeta::new_readonly(*new_admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -417,12 +1009,14 @@ pub fn initialize_operator(
     operator: &Pubkey,
     admin: &Pubkey,
     base: &Pubkey,
+    payer: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*config, false),
         AccountMeta::new(*operator, false),
-        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(*admin, true),
         AccountMeta::new_readonly(*base, true),
+        AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     Instruction {
@@ -443,7 +1037,7 @@ pub fn operator_set_admin(
     let accounts = vec![
         AccountMeta::new(*node_operator, false),
         AccountMeta::new_readonly(*old_admin, true),
-        AccountMeta::new_readonly(*new_admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -470,6 +1064,8 @@ pub fn operator_set_voter(
     }
 }
 
+/// Builds [`RestakingInstruction::OperatorAddVault`]. Passing the operator as `payer` funds the
+/// ticket out of the operator's own lamports instead of a signer's.
 pub fn operator_add_vault(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -485,7 +1081,7 @@ pub fn operator_add_vault(
         AccountMeta::new_readonly(*vault, false),
         AccountMeta::new(*operator_vault_ticket, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new(*payer, true),
+        AccountMeta::new(*payer, payer != operator),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     Instruction {
@@ -519,6 +1115,8 @@ pub fn operator_remove_vault(
     }
 }
 
+/// Builds [`RestakingInstruction::OperatorAddAvs`]. Passing the operator as `payer` funds the
+/// ticket out of the operator's own lamports instead of a signer's.
 pub fn operator_add_avs(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -534,7 +1132,7 @@ pub fn operator_add_avs(
         AccountMeta::new_readonly(*avs, false),
         AccountMeta::new(*operator_avs_ticket, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new(*payer, true),
+        AccountMeta::new(*payer, payer != operator),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
     Instruction {
@@ -621,3 +1219,1711 @@ pub fn operator_withdrawal_asset(
             .unwrap(),
     }
 }
+
+pub fn avs_set_vault_service_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    vault: &Pubkey,
+    avs_vault_ticket: &Pubkey,
+    admin: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*avs_vault_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetVaultServiceFee { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn operator_heartbeat(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    avs: &Pubkey,
+    operator_avs_ticket: &Pubkey,
+    operator_avs_heartbeat: &Pubkey,
+    voter: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator_avs_ticket, false),
+        AccountMeta::new(*operator_avs_heartbeat, false),
+        AccountMeta::new_readonly(*voter, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorHeartbeat
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_set_max_heartbeat_age(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    admin: &Pubkey,
+    max_heartbeat_age: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*avs, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetMaxHeartbeatAge { max_heartbeat_age }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_eject_stale_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+    avs_operator_ticket: &Pubkey,
+    operator_avs_heartbeat: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*avs_operator_ticket, false),
+        AccountMeta::new_readonly(*operator_avs_heartbeat, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsEjectStaleOperator
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_reactivate_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+    avs_operator_ticket: &Pubkey,
+    operator_avs_heartbeat: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*avs_operator_ticket, false),
+        AccountMeta::new_readonly(*operator_avs_heartbeat, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsReactivateOperator
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::OperatorExitAll`] for the given operator AVS and operator vault
+/// tickets. Operators with many tickets may need to split them across several transactions.
+pub fn operator_exit_all(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    operator_avs_tickets: &[Pubkey],
+    operator_vault_tickets: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    accounts.extend(
+        operator_avs_tickets
+            .iter()
+            .chain(operator_vault_tickets)
+            .map(|ticket| AccountMeta::new(*ticket, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorExitAll.try_to_vec().unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::AssertAvsHealthy`] with every ticket created by the AVS
+pub fn assert_avs_healthy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    tickets: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+    ];
+    accounts.extend(
+        tickets
+            .iter()
+            .map(|ticket| AccountMeta::new_readonly(*ticket, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AssertAvsHealthy.try_to_vec().unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::AssertOperatorHealthy`] with every ticket created by the
+/// operator
+pub fn assert_operator_healthy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    tickets: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+    ];
+    accounts.extend(
+        tickets
+            .iter()
+            .map(|ticket| AccountMeta::new_readonly(*ticket, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AssertOperatorHealthy
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_add_slasher_program(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    program: Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigAddSlasherProgram { program }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_remove_slasher_program(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    program: Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigRemoveSlasherProgram { program }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::SweepTokens`].
+///
+/// Ticket balances are swept into a token account owned by the `parent` AVS or operator, and the
+/// config balance into one owned by the config admin, in which case `parent` is the config.
+#[allow(clippy::too_many_arguments)]
+pub fn sweep_tokens(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    parent: &Pubkey,
+    pda: &Pubkey,
+    pda_token_account: &Pubkey,
+    treasury_token_account: &Pubkey,
+    admin: &Pubkey,
+    token_program: &Pubkey,
+    token_mint: Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*parent, false),
+        AccountMeta::new_readonly(*pda, false),
+        AccountMeta::new(*pda_token_account, false),
+        AccountMeta::new(*treasury_token_account, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SweepTokens { token_mint }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn top_up_rent(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::TopUpRent.try_to_vec().unwrap(),
+    }
+}
+
+pub fn sweep_lamports(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    parent: &Pubkey,
+    pda: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*parent, false),
+        AccountMeta::new(*pda, false),
+        AccountMeta::new(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SweepLamports.try_to_vec().unwrap(),
+    }
+}
+
+pub fn avs_set_relayer(
+    program_id: &Pubkey,
+    avs: &Pubkey,
+    admin: &Pubkey,
+    relayer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*avs, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*relayer, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetRelayer.try_to_vec().unwrap(),
+    }
+}
+
+pub fn operator_set_relayer(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    relayer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*relayer, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetRelayer
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds the instruction relaying an admin operation. It shall directly follow the ed25519
+/// program instruction verifying the admin's signature over [`relayed_message`].
+pub fn relay_admin_operation(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    account: &Pubkey,
+    relayer: &Pubkey,
+    nonce: u64,
+    operation: RelayedOperation,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*relayer, true),
+        AccountMeta::new_readonly(solana_program::sysvar::instructions::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::RelayAdminOperation { nonce, operation }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_set_max_slashers_per_vault(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    admin: &Pubkey,
+    max_slashers_per_vault: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*avs, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetMaxSlashersPerVault {
+            max_slashers_per_vault,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn config_set_ticket_epoch_length(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    ticket_epoch_length: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigSetTicketEpochLength {
+            ticket_epoch_length,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn operator_set_secondary_admin(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+    role: OperatorAdminRole,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetSecondaryAdmin(role)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_accept_admin(program_id: &Pubkey, avs: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*avs, false),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsAcceptAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn operator_accept_admin(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorAcceptAdmin
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_set_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigSetAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn config_accept_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigAcceptAdmin
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn avs_initialize_reward_budget(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    avs_reward_budget: &Pubkey,
+    mint: &Pubkey,
+    receiver_token_account: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    epoch_length: u64,
+    tokens_per_epoch: u64,
+    decay_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new(*avs_reward_budget, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*receiver_token_account, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsInitializeRewardBudget {
+            epoch_length,
+            tokens_per_epoch,
+            decay_bps,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn avs_set_reward_budget(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    avs_reward_budget: &Pubkey,
+    mint: &Pubkey,
+    admin: &Pubkey,
+    tokens_per_epoch: u64,
+    decay_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new(*avs_reward_budget, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetRewardBudget {
+            tokens_per_epoch,
+            decay_bps,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn avs_distribute_rewards(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    avs_reward_budget: &Pubkey,
+    mint: &Pubkey,
+    avs_token_account: &Pubkey,
+    receiver_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new(*avs_reward_budget, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(*avs_token_account, false),
+        AccountMeta::new(*receiver_token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsDistributeRewards
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_set_multisig(
+    program_id: &Pubkey,
+    avs: &Pubkey,
+    admin: &Pubkey,
+    signers: [Pubkey; 3],
+    threshold: u8,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*avs, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetMultisig { signers, threshold }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_set_rent_collector(
+    program_id: &Pubkey,
+    avs: &Pubkey,
+    admin: &Pubkey,
+    rent_collector: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*avs, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*rent_collector, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetRentCollector
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn operator_set_rent_collector(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    rent_collector: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*rent_collector, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetRentCollector
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::CloseTicket`]. The `parent` is the AVS or operator that created
+/// the ticket and `admin` its admin.
+pub fn close_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    parent: &Pubkey,
+    ticket: &Pubkey,
+    rent_collector: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*parent, false),
+        AccountMeta::new(*ticket, false),
+        AccountMeta::new(*rent_collector, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::CloseTicket.try_to_vec().unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::UpdateAvsMetadata`], the metadata account being at
+/// [`Metadata::find_program_address`] of the AVS
+#[allow(clippy::too_many_arguments)]
+pub fn update_avs_metadata(
+    program_id: &Pubkey,
+    avs: &Pubkey,
+    metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    payer: &Pubkey,
+    name: String,
+    uri: String,
+    icon: String,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new(*metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::UpdateAvsMetadata { name, uri, icon }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::UpdateOperatorMetadata`], the metadata account being at
+/// [`Metadata::find_program_address`] of the operator
+#[allow(clippy::too_many_arguments)]
+pub fn update_operator_metadata(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    payer: &Pubkey,
+    name: String,
+    uri: String,
+    icon: String,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::UpdateOperatorMetadata { name, uri, icon }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::MigrateAccount`]
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::OperatorSetAvsCapacity`], the capacity account being at
+/// [`jito_restaking_core::operator_avs_capacity::OperatorAvsCapacity::find_program_address`] of
+/// the operator and AVS
+#[allow(clippy::too_many_arguments)]
+pub fn operator_set_avs_capacity(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    avs: &Pubkey,
+    operator_avs_ticket: &Pubkey,
+    operator_avs_capacity: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    capacity: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator_avs_ticket, false),
+        AccountMeta::new(*operator_avs_capacity, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetAvsCapacity { capacity }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_set_operator_fee_params(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    max_operator_fee_bps: u16,
+    operator_fee_cooldown_slots: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigSetOperatorFeeParams {
+            max_operator_fee_bps,
+            operator_fee_cooldown_slots,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn set_operator_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    operator_fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SetOperatorFee { operator_fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::AvsDepositEpochRewards`], the router token account being the
+/// associated token account of the router for the mint
+#[allow(clippy::too_many_arguments)]
+pub fn avs_deposit_epoch_rewards(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    vault_config: &Pubkey,
+    epoch_reward_router: &Pubkey,
+    mint: &Pubkey,
+    depositor_token_account: &Pubkey,
+    depositor: &Pubkey,
+    payer: &Pubkey,
+    epoch: u64,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*vault_config, false),
+        AccountMeta::new(*epoch_reward_router, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(
+            get_associated_token_address(epoch_reward_router, mint),
+            false,
+        ),
+        AccountMeta::new(*depositor_token_account, false),
+        AccountMeta::new_readonly(*depositor, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsDepositEpochRewards { epoch, amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn snapshot_epoch_reward_stake(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    vault_config: &Pubkey,
+    vault_delegation_list: &Pubkey,
+    avs_operator_ticket: &Pubkey,
+    operator_avs_ticket: &Pubkey,
+    avs_vault_ticket: &Pubkey,
+    operator_vault_ticket: &Pubkey,
+    epoch_reward_router: &Pubkey,
+    epoch_reward_snapshot: &Pubkey,
+    operator_avs_score: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*vault_config, false),
+        AccountMeta::new_readonly(*vault_delegation_list, false),
+        AccountMeta::new_readonly(*avs_operator_ticket, false),
+        AccountMeta::new_readonly(*operator_avs_ticket, false),
+        AccountMeta::new_readonly(*avs_vault_ticket, false),
+        AccountMeta::new_readonly(*operator_vault_ticket, false),
+        AccountMeta::new(*epoch_reward_router, false),
+        AccountMeta::new(*epoch_reward_snapshot, false),
+        AccountMeta::new_readonly(*operator_avs_score, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SnapshotEpochRewardStake
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::DistributeEpochRewards`], paying out to the associated token
+/// accounts of the operator and vault for the mint
+#[allow(clippy::too_many_arguments)]
+pub fn distribute_epoch_rewards(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    epoch_reward_router: &Pubkey,
+    epoch_reward_snapshot: &Pubkey,
+    mint: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new(*epoch_reward_router, false),
+        AccountMeta::new(*epoch_reward_snapshot, false),
+        AccountMeta::new(
+            get_associated_token_address(epoch_reward_router, mint),
+            false,
+        ),
+        AccountMeta::new(get_associated_token_address(operator, mint), false),
+        AccountMeta::new(get_associated_token_address(vault, mint), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::DistributeEpochRewards
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_reclaim_epoch_rewards(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    epoch_reward_router: &Pubkey,
+    mint: &Pubkey,
+    receiver_token_account: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*epoch_reward_router, false),
+        AccountMeta::new(
+            get_associated_token_address(epoch_reward_router, mint),
+            false,
+        ),
+        AccountMeta::new(*receiver_token_account, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsReclaimEpochRewards
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn snapshot_epoch_stake(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    vault_config: &Pubkey,
+    vault_delegation_list: &Pubkey,
+    operator_vault_ticket: &Pubkey,
+    operator_epoch_stake: &Pubkey,
+    vault_operator_epoch_stake: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*vault_config, false),
+        AccountMeta::new_readonly(*vault_delegation_list, false),
+        AccountMeta::new_readonly(*operator_vault_ticket, false),
+        AccountMeta::new(*operator_epoch_stake, false),
+        AccountMeta::new(*vault_operator_epoch_stake, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SnapshotEpochStake
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn avs_set_scorer(
+    program_id: &Pubkey,
+    avs: &Pubkey,
+    admin: &Pubkey,
+    scorer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*avs, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*scorer, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsSetScorer.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn avs_attest_operator_score(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+    avs_operator_ticket: &Pubkey,
+    operator_avs_score: &Pubkey,
+    scorer: &Pubkey,
+    payer: &Pubkey,
+    score_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*avs_operator_ticket, false),
+        AccountMeta::new(*operator_avs_score, false),
+        AccountMeta::new_readonly(*scorer, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::AvsAttestOperatorScore { score_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::ArchiveEpochStake`]. The `epoch_stake` is an
+/// [`jito_restaking_core::operator_epoch_stake::OperatorEpochStake`] or a
+/// [`jito_restaking_core::vault_operator_epoch_stake::VaultOperatorEpochStake`] of the operator.
+#[allow(clippy::too_many_arguments)]
+pub fn archive_epoch_stake(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    vault_config: &Pubkey,
+    operator_stake_history: &Pubkey,
+    epoch_stake: &Pubkey,
+    rent_collector: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault_config, false),
+        AccountMeta::new(*operator_stake_history, false),
+        AccountMeta::new(*epoch_stake, false),
+        AccountMeta::new(*rent_collector, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ArchiveEpochStake
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Appends the AVS multisig keys signing a high-risk operation to its instruction
+pub fn config_set_parameter(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    parameter: ConfigParameter,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigSetParameter(parameter)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::PostRewardRoot`], funding the associated token account of the
+/// distribution for the mint, owned by the token program
+#[allow(clippy::too_many_arguments)]
+pub fn post_reward_root(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    source: &Pubkey,
+    reward_distribution: &Pubkey,
+    mint: &Pubkey,
+    admin_token_account: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    token_program: &Pubkey,
+    epoch: u64,
+    merkle_root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*source, false),
+        AccountMeta::new(*reward_distribution, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(reward_distribution, mint, token_program),
+            false,
+        ),
+        AccountMeta::new(*admin_token_account, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::PostRewardRoot {
+            epoch,
+            merkle_root,
+            max_total_claim,
+            max_num_nodes,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::ClaimReward`], paying out to the associated token account of
+/// the claimant for the mint, owned by the token program
+#[allow(clippy::too_many_arguments)]
+pub fn claim_reward(
+    program_id: &Pubkey,
+    reward_distribution: &Pubkey,
+    claim_status: &Pubkey,
+    claimant: &Pubkey,
+    mint: &Pubkey,
+    payer: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_distribution, false),
+        AccountMeta::new(*claim_status, false),
+        AccountMeta::new_readonly(*claimant, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(reward_distribution, mint, token_program),
+            false,
+        ),
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(claimant, mint, token_program),
+            false,
+        ),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ClaimReward { amount, proof }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`RestakingInstruction::FinalizeOperatorAvsRemoval`] with the accounts of every vault the
+/// AVS added and didn't close the ticket of
+#[allow(clippy::too_many_arguments)]
+pub fn finalize_operator_avs_removal(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+    avs_rent_collector: &Pubkey,
+    operator_rent_collector: &Pubkey,
+    vault_program: &Pubkey,
+    vaults: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*avs, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new(
+            AvsOperatorTicket::find_program_address(program_id, avs, operator).0,
+            false,
+        ),
+        AccountMeta::new(
+            OperatorAvsTicket::find_program_address(program_id, operator, avs).0,
+            false,
+        ),
+        AccountMeta::new(*avs_rent_collector, false),
+        AccountMeta::new(*operator_rent_collector, false),
+    ];
+    for vault in vaults {
+        accounts.extend([
+            AccountMeta::new_readonly(
+                AvsVaultTicket::find_program_address(program_id, avs, vault).0,
+                false,
+            ),
+            AccountMeta::new_readonly(
+                VaultDelegationList::find_program_address(vault_program, vault).0,
+                false,
+            ),
+            AccountMeta::new_readonly(
+                VaultAvsTicket::find_program_address(vault_program, vault, avs).0,
+                false,
+            ),
+        ]);
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::FinalizeOperatorAvsRemoval
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn with_avs_multisig_signers(mut instruction: Instruction, signers: &[Pubkey]) -> Instruction {
+    instruction.accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+    instruction
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        account_indices::*, avs_add_vault, AvsAdminRole, ConfigParameter, OperatorAdminRole,
+        RelayedOperation, RestakingInstruction,
+    };
+
+    /// Deployed clients encode the discriminant as the variant's position in the enum, so these
+    /// values can never change. New variants shall be appended at the end of the enum.
+    const fn discriminant(instruction: &RestakingInstruction) -> u8 {
+        match instruction {
+            RestakingInstruction::InitializeConfig => 0,
+            RestakingInstruction::InitializeAvs => 1,
+            RestakingInstruction::AvsAddVault => 2,
+            RestakingInstruction::AvsRemoveVault => 3,
+            RestakingInstruction::AvsAddOperator => 4,
+            RestakingInstruction::AvsRemoveOperator => 5,
+            RestakingInstruction::AvsAddVaultSlasher { .. } => 6,
+            RestakingInstruction::AvsRemoveVaultSlasher => 7,
+            RestakingInstruction::AvsSetAdmin => 8,
+            RestakingInstruction::AvsSetSecondaryAdmin(_) => 9,
+            RestakingInstruction::InitializeOperator => 10,
+            RestakingInstruction::OperatorSetAdmin => 11,
+            RestakingInstruction::OperatorSetVoter => 12,
+            RestakingInstruction::OperatorAddVault => 13,
+            RestakingInstruction::OperatorRemoveVault => 14,
+            RestakingInstruction::OperatorAddAvs => 15,
+            RestakingInstruction::OperatorRemoveAvs => 16,
+            RestakingInstruction::AvsWithdrawalAsset { .. } => 17,
+            RestakingInstruction::OperatorWithdrawalAsset { .. } => 18,
+            RestakingInstruction::AvsSetVaultServiceFee { .. } => 19,
+            RestakingInstruction::OperatorHeartbeat => 20,
+            RestakingInstruction::AvsSetMaxHeartbeatAge { .. } => 21,
+            RestakingInstruction::AvsEjectStaleOperator => 22,
+            RestakingInstruction::AvsReactivateOperator => 23,
+            RestakingInstruction::OperatorExitAll => 24,
+            RestakingInstruction::AssertAvsHealthy => 25,
+            RestakingInstruction::AssertOperatorHealthy => 26,
+            RestakingInstruction::ConfigAddSlasherProgram { .. } => 27,
+            RestakingInstruction::ConfigRemoveSlasherProgram { .. } => 28,
+            RestakingInstruction::SweepTokens { .. } => 29,
+            RestakingInstruction::TopUpRent => 30,
+            RestakingInstruction::SweepLamports => 31,
+            RestakingInstruction::AvsSetRelayer => 32,
+            RestakingInstruction::OperatorSetRelayer => 33,
+            RestakingInstruction::RelayAdminOperation { .. } => 34,
+            RestakingInstruction::AvsSetMaxSlashersPerVault { .. } => 35,
+            RestakingInstruction::ConfigSetTicketEpochLength { .. } => 36,
+            RestakingInstruction::OperatorSetSecondaryAdmin(_) => 37,
+            RestakingInstruction::AvsAcceptAdmin => 38,
+            RestakingInstruction::OperatorAcceptAdmin => 39,
+            RestakingInstruction::ConfigSetAdmin => 40,
+            RestakingInstruction::ConfigAcceptAdmin => 41,
+            RestakingInstruction::AvsInitializeRewardBudget { .. } => 42,
+            RestakingInstruction::AvsSetRewardBudget { .. } => 43,
+            RestakingInstruction::AvsDistributeRewards => 44,
+            RestakingInstruction::AvsSetMultisig { .. } => 45,
+            RestakingInstruction::AvsSetRentCollector => 46,
+            RestakingInstruction::OperatorSetRentCollector => 47,
+            RestakingInstruction::CloseTicket => 48,
+            RestakingInstruction::UpdateAvsMetadata { .. } => 49,
+            RestakingInstruction::UpdateOperatorMetadata { .. } => 50,
+            RestakingInstruction::MigrateAccount => 51,
+            RestakingInstruction::OperatorSetAvsCapacity { .. } => 52,
+            RestakingInstruction::ConfigSetOperatorFeeParams { .. } => 53,
+            RestakingInstruction::SetOperatorFee { .. } => 54,
+            RestakingInstruction::AvsDepositEpochRewards { .. } => 55,
+            RestakingInstruction::SnapshotEpochRewardStake => 56,
+            RestakingInstruction::DistributeEpochRewards => 57,
+            RestakingInstruction::AvsReclaimEpochRewards => 58,
+            RestakingInstruction::SnapshotEpochStake => 59,
+            RestakingInstruction::AvsSetScorer => 60,
+            RestakingInstruction::AvsAttestOperatorScore { .. } => 61,
+            RestakingInstruction::ArchiveEpochStake => 62,
+            RestakingInstruction::ConfigSetParameter(_) => 63,
+            RestakingInstruction::PostRewardRoot { .. } => 64,
+            RestakingInstruction::ClaimReward { .. } => 65,
+            RestakingInstruction::FinalizeOperatorAvsRemoval => 66,
+        }
+    }
+
+    fn all_instructions() -> Vec<(RestakingInstruction, Vec<u8>)> {
+        let token_mint = Pubkey::new_from_array([7; 32]);
+        let with_mint_and_amount = |discriminant: u8| {
+            let mut data = vec![discriminant];
+            data.extend_from_slice(&[7; 32]);
+            data.extend_from_slice(&1_000_u64.to_le_bytes());
+            data
+        };
+        let with_mint = |discriminant: u8| {
+            let mut data = vec![discriminant];
+            data.extend_from_slice(&[7; 32]);
+            data
+        };
+
+        vec![
+            (RestakingInstruction::InitializeConfig, vec![0]),
+            (RestakingInstruction::InitializeAvs, vec![1]),
+            (RestakingInstruction::AvsAddVault, vec![2]),
+            (RestakingInstruction::AvsRemoveVault, vec![3]),
+            (RestakingInstruction::AvsAddOperator, vec![4]),
+            (RestakingInstruction::AvsRemoveOperator, vec![5]),
+            (
+                RestakingInstruction::AvsAddVaultSlasher {
+                    max_slashable_per_epoch: 1_000,
+                    metadata_hash: [7; 32],
+                },
+                {
+                    let mut data = vec![6, 0xe8, 0x03, 0, 0, 0, 0, 0, 0];
+                    data.extend_from_slice(&[7; 32]);
+                    data
+                },
+            ),
+            (RestakingInstruction::AvsRemoveVaultSlasher, vec![7]),
+            (RestakingInstruction::AvsSetAdmin, vec![8]),
+            (
+                RestakingInstruction::AvsSetSecondaryAdmin(AvsAdminRole::Operator),
+                vec![9, 0],
+            ),
+            (
+                RestakingInstruction::AvsSetSecondaryAdmin(AvsAdminRole::Vault),
+                vec![9, 1],
+            ),
+            (
+                RestakingInstruction::AvsSetSecondaryAdmin(AvsAdminRole::Slasher),
+                vec![9, 2],
+            ),
+            (
+                RestakingInstruction::AvsSetSecondaryAdmin(AvsAdminRole::Withdraw),
+                vec![9, 3],
+            ),
+            (
+                RestakingInstruction::AvsSetSecondaryAdmin(AvsAdminRole::Metadata),
+                vec![9, 4],
+            ),
+            (
+                RestakingInstruction::AvsSetSecondaryAdmin(AvsAdminRole::SlashVeto),
+                vec![9, 5],
+            ),
+            (RestakingInstruction::InitializeOperator, vec![10]),
+            (RestakingInstruction::OperatorSetAdmin, vec![11]),
+            (RestakingInstruction::OperatorSetVoter, vec![12]),
+            (RestakingInstruction::OperatorAddVault, vec![13]),
+            (RestakingInstruction::OperatorRemoveVault, vec![14]),
+            (RestakingInstruction::OperatorAddAvs, vec![15]),
+            (RestakingInstruction::OperatorRemoveAvs, vec![16]),
+            (
+                RestakingInstruction::AvsWithdrawalAsset {
+                    token_mint,
+                    amount: 1_000,
+                },
+                with_mint_and_amount(17),
+            ),
+            (
+                RestakingInstruction::OperatorWithdrawalAsset {
+                    token_mint,
+                    amount: 1_000,
+                },
+                with_mint_and_amount(18),
+            ),
+            (
+                RestakingInstruction::AvsSetVaultServiceFee { fee_bps: 500 },
+                vec![19, 0xf4, 0x01],
+            ),
+            (RestakingInstruction::OperatorHeartbeat, vec![20]),
+            (
+                RestakingInstruction::AvsSetMaxHeartbeatAge {
+                    max_heartbeat_age: 1_000,
+                },
+                vec![21, 0xe8, 0x03, 0, 0, 0, 0, 0, 0],
+            ),
+            (RestakingInstruction::AvsEjectStaleOperator, vec![22]),
+            (RestakingInstruction::AvsReactivateOperator, vec![23]),
+            (RestakingInstruction::OperatorExitAll, vec![24]),
+            (RestakingInstruction::AssertAvsHealthy, vec![25]),
+            (RestakingInstruction::AssertOperatorHealthy, vec![26]),
+            (
+                RestakingInstruction::ConfigAddSlasherProgram {
+                    program: token_mint,
+                },
+                with_mint(27),
+            ),
+            (
+                RestakingInstruction::ConfigRemoveSlasherProgram {
+                    program: token_mint,
+                },
+                with_mint(28),
+            ),
+            (
+                RestakingInstruction::SweepTokens { token_mint },
+                with_mint(29),
+            ),
+            (RestakingInstruction::TopUpRent, vec![30]),
+            (RestakingInstruction::SweepLamports, vec![31]),
+            (RestakingInstruction::AvsSetRelayer, vec![32]),
+            (RestakingInstruction::OperatorSetRelayer, vec![33]),
+            (
+                RestakingInstruction::RelayAdminOperation {
+                    nonce: 1_000,
+                    operation: RelayedOperation::AvsSetMaxHeartbeatAge {
+                        max_heartbeat_age: 1_000,
+                    },
+                },
+                vec![
+                    34, 0xe8, 0x03, 0, 0, 0, 0, 0, 0, 0, 0xe8, 0x03, 0, 0, 0, 0, 0, 0,
+                ],
+            ),
+            (
+                RestakingInstruction::RelayAdminOperation {
+                    nonce: 0,
+                    operation: RelayedOperation::OperatorSetVoter { voter: token_mint },
+                },
+                {
+                    let mut data = vec![34, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+                    data.extend_from_slice(&[7; 32]);
+                    data
+                },
+            ),
+            (
+                RestakingInstruction::RelayAdminOperation {
+                    nonce: 0,
+                    operation: RelayedOperation::OperatorGrantSessionKey {
+                        session_key: token_mint,
+                        expiry_slot: 1_000,
+                    },
+                },
+                {
+                    let mut data = vec![34, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+                    data.extend_from_slice(&[7; 32]);
+                    data.extend_from_slice(&[0xe8, 0x03, 0, 0, 0, 0, 0, 0]);
+                    data
+                },
+            ),
+            (
+                RestakingInstruction::AvsSetMaxSlashersPerVault {
+                    max_slashers_per_vault: 1_000,
+                },
+                vec![35, 0xe8, 0x03, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                RestakingInstruction::ConfigSetTicketEpochLength {
+                    ticket_epoch_length: 1_000,
+                },
+                vec![36, 0xe8, 0x03, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                RestakingInstruction::OperatorSetSecondaryAdmin(OperatorAdminRole::Avs),
+                vec![37, 0],
+            ),
+            (
+                RestakingInstruction::OperatorSetSecondaryAdmin(OperatorAdminRole::Vault),
+                vec![37, 1],
+            ),
+            (
+                RestakingInstruction::OperatorSetSecondaryAdmin(OperatorAdminRole::Withdraw),
+                vec![37, 2],
+            ),
+            (
+                RestakingInstruction::OperatorSetSecondaryAdmin(OperatorAdminRole::Metadata),
+                vec![37, 3],
+            ),
+            (RestakingInstruction::AvsAcceptAdmin, vec![38]),
+            (RestakingInstruction::OperatorAcceptAdmin, vec![39]),
+            (RestakingInstruction::ConfigSetAdmin, vec![40]),
+            (RestakingInstruction::ConfigAcceptAdmin, vec![41]),
+            (
+                RestakingInstruction::AvsInitializeRewardBudget {
+                    epoch_length: 1_000,
+                    tokens_per_epoch: 1_000,
+                    decay_bps: 500,
+                },
+                vec![
+                    42, 0xe8, 0x03, 0, 0, 0, 0, 0, 0, 0xe8, 0x03, 0, 0, 0, 0, 0, 0, 0xf4, 0x01,
+                ],
+            ),
+            (
+                RestakingInstruction::AvsSetRewardBudget {
+                    tokens_per_epoch: 1_000,
+                    decay_bps: 500,
+                },
+                vec![43, 0xe8, 0x03, 0, 0, 0, 0, 0, 0, 0xf4, 0x01],
+            ),
+            (RestakingInstruction::AvsDistributeRewards, vec![44]),
+            (
+                RestakingInstruction::AvsSetMultisig {
+                    signers: [Pubkey::new_from_array([7; 32]); 3],
+                    threshold: 2,
+                },
+                [vec![45], [7; 96].to_vec(), vec![2]].concat(),
+            ),
+            (RestakingInstruction::AvsSetRentCollector, vec![46]),
+            (RestakingInstruction::OperatorSetRentCollector, vec![47]),
+            (RestakingInstruction::CloseTicket, vec![48]),
+            (
+                RestakingInstruction::UpdateAvsMetadata {
+                    name: "a".to_string(),
+                    uri: String::new(),
+                    icon: "b".to_string(),
+                },
+                vec![49, 1, 0, 0, 0, b'a', 0, 0, 0, 0, 1, 0, 0, 0, b'b'],
+            ),
+            (
+                RestakingInstruction::UpdateOperatorMetadata {
+                    name: "a".to_string(),
+                    uri: String::new(),
+                    icon: "b".to_string(),
+                },
+                vec![50, 1, 0, 0, 0, b'a', 0, 0, 0, 0, 1, 0, 0, 0, b'b'],
+            ),
+            (RestakingInstruction::MigrateAccount, vec![51]),
+            (
+                RestakingInstruction::OperatorSetAvsCapacity { capacity: 1 },
+                vec![52, 1, 0, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                RestakingInstruction::ConfigSetOperatorFeeParams {
+                    max_operator_fee_bps: 1,
+                    operator_fee_cooldown_slots: 2,
+                },
+                vec![53, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                RestakingInstruction::SetOperatorFee {
+                    operator_fee_bps: 1,
+                },
+                vec![54, 1, 0],
+            ),
+            (
+                RestakingInstruction::AvsDepositEpochRewards {
+                    epoch: 1,
+                    amount: 2,
+                },
+                vec![55, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0],
+            ),
+            (RestakingInstruction::SnapshotEpochRewardStake, vec![56]),
+            (RestakingInstruction::DistributeEpochRewards, vec![57]),
+            (RestakingInstruction::AvsReclaimEpochRewards, vec![58]),
+            (RestakingInstruction::SnapshotEpochStake, vec![59]),
+            (RestakingInstruction::AvsSetScorer, vec![60]),
+            (
+                RestakingInstruction::AvsAttestOperatorScore { score_bps: 9_500 },
+                vec![61, 28, 37],
+            ),
+            (RestakingInstruction::ArchiveEpochStake, vec![62]),
+            (
+                RestakingInstruction::ConfigSetParameter(ConfigParameter::MaxVaultsPerOperator(2)),
+                vec![63, 1, 2, 0, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                RestakingInstruction::PostRewardRoot {
+                    epoch: 3,
+                    merkle_root: [9; 32],
+                    max_total_claim: 1_000,
+                    max_num_nodes: 2,
+                },
+                [
+                    vec![64],
+                    3_u64.to_le_bytes().to_vec(),
+                    vec![9; 32],
+                    1_000_u64.to_le_bytes().to_vec(),
+                    2_u64.to_le_bytes().to_vec(),
+                ]
+                .concat(),
+            ),
+            (
+                RestakingInstruction::ClaimReward {
+                    amount: 500,
+                    proof: vec![[4; 32]],
+                },
+                [
+                    vec![65],
+                    500_u64.to_le_bytes().to_vec(),
+                    1_u32.to_le_bytes().to_vec(),
+                    vec![4; 32],
+                ]
+                .concat(),
+            ),
+            (RestakingInstruction::FinalizeOperatorAvsRemoval, vec![66]),
+        ]
+    }
+
+    #[test]
+    fn test_instruction_wire_format() {
+        for (instruction, expected) in all_instructions() {
+            assert_eq!(
+                instruction.try_to_vec().unwrap(),
+                expected,
+                "{instruction:?} serialized to unexpected bytes"
+            );
+            assert_eq!(
+                expected[0],
+                discriminant(&instruction),
+                "{instruction:?} discriminant changed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_variant_is_pinned() {
+        let mut pinned: Vec<_> = all_instructions()
+            .iter()
+            .map(|(instruction, _)| discriminant(instruction))
+            .collect();
+        pinned.dedup();
+        let expected: Vec<u8> =
+            (0..=discriminant(&RestakingInstruction::FinalizeOperatorAvsRemoval)).collect();
+        assert_eq!(pinned, expected);
+    }
+
+    #[test]
+    fn test_account_indices_match_builder() {
+        let [program_id, config, avs, vault, avs_vault_ticket, admin, payer] =
+            [(); 7].map(|_| Pubkey::new_unique());
+        let instruction = avs_add_vault(
+            &program_id,
+            &config,
+            &avs,
+            &vault,
+            &avs_vault_ticket,
+            &admin,
+            &payer,
+        );
+
+        let accounts = &instruction.accounts;
+        assert_eq!(accounts[AVS_ADD_VAULT_IX_ACCOUNT_CONFIG].pubkey, config);
+        assert_eq!(accounts[AVS_ADD_VAULT_IX_ACCOUNT_AVS].pubkey, avs);
+        assert_eq!(accounts[AVS_ADD_VAULT_IX_ACCOUNT_VAULT].pubkey, vault);
+        assert_eq!(
+            accounts[AVS_ADD_VAULT_IX_ACCOUNT_AVS_VAULT_TICKET].pubkey,
+            avs_vault_ticket
+        );
+        assert_eq!(accounts[AVS_ADD_VAULT_IX_ACCOUNT_ADMIN].pubkey, admin);
+        assert_eq!(accounts[AVS_ADD_VAULT_IX_ACCOUNT_PAYER].pubkey, payer);
+        assert_eq!(
+            accounts[AVS_ADD_VAULT_IX_ACCOUNT_SYSTEM_PROGRAM].pubkey,
+            solana_program::system_program::id()
+        );
+    }
+}
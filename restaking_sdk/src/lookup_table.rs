@@ -0,0 +1,191 @@
+//! Address lookup tables and v0 transactions for instructions touching many accounts, such as
+//! slashing and reward claims, which don't fit in a legacy transaction once a compute budget is
+//! added.
+//!
+//! [`common_addresses`] lists the accounts most restaking and vault transactions share: the configs
+//! of both programs and the programs passed as accounts. A deployment creates a table of them once
+//! with [`create_lookup_table`], and [`v0_message`] compiles instructions against it, so each of
+//! the addresses takes one byte in the message instead of 32. The table can be used from the slot
+//! after it was extended. Invoked program IDs always stay in the static keys of the message, as the
+//! runtime doesn't load them from tables.
+use solana_program::{
+    address_lookup_table::{
+        instruction::{
+            create_lookup_table as create_lookup_table_instruction, extend_lookup_table,
+        },
+        AddressLookupTableAccount,
+    },
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, CompileError, VersionedMessage},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use crate::{
+    batch::ComputeBudget,
+    flows::{ProgramIds, PACKET_DATA_SIZE},
+};
+
+/// The max number of addresses added by one extend instruction, so it fits in a transaction with
+/// the authority and payer signatures
+pub const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+/// The addresses shared by most transactions of a deployment, to be put in its lookup table
+pub fn common_addresses(program_ids: &ProgramIds) -> Vec<Pubkey> {
+    vec![
+        program_ids.restaking_config(),
+        program_ids.vault_config(),
+        program_ids.restaking_program,
+        program_ids.vault_program,
+        spl_token::id(),
+        spl_associated_token_account::id(),
+        system_program::id(),
+        sysvar::instructions::id(),
+    ]
+}
+
+/// The address of the lookup table owned by the authority and the instructions creating it with
+/// the addresses. The recent slot shall be a slot the cluster recently rooted, it seeds the address
+/// of the table.
+pub fn create_lookup_table(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    recent_slot: u64,
+    addresses: &[Pubkey],
+) -> (Pubkey, Vec<Instruction>) {
+    let (create, address) = create_lookup_table_instruction(*authority, *payer, recent_slot);
+    let extends = addresses
+        .chunks(MAX_ADDRESSES_PER_EXTEND)
+        .map(|chunk| extend_lookup_table(address, *authority, Some(*payer), chunk.to_vec()));
+    (address, std::iter::once(create).chain(extends).collect())
+}
+
+/// Builds a v0 message paid by the payer with the compute budget put before the instructions,
+/// compressing the accounts found in the lookup tables. The message is left for the signers to sign.
+pub fn v0_message(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    compute_budget: &ComputeBudget,
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: &Hash,
+) -> Result<VersionedMessage, CompileError> {
+    let mut budgeted = compute_budget.instructions();
+    budgeted.extend_from_slice(instructions);
+    Ok(VersionedMessage::V0(v0::Message::try_compile(
+        payer,
+        &budgeted,
+        lookup_tables,
+        *recent_blockhash,
+    )?))
+}
+
+/// The size of the transaction of the message once signed
+pub fn serialized_size(message: &VersionedMessage) -> usize {
+    // compact-u16 signature count, below 128 signers it takes one byte
+    let num_signers = usize::from(message.header().num_required_signatures);
+    message
+        .serialize()
+        .len()
+        .saturating_add(num_signers.saturating_mul(64))
+        .saturating_add(1)
+}
+
+/// Whether the transaction of the message fits in a single packet once signed
+pub fn fits_in_packet(message: &VersionedMessage) -> bool {
+    serialized_size(message) <= PACKET_DATA_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        address_lookup_table::instruction::derive_lookup_table_address, hash::Hash,
+        message::VersionedMessage, pubkey::Pubkey,
+    };
+
+    use super::*;
+    use crate::{batch::COMPUTE_BUDGET_PROGRAM_ID, flows::FlowTransaction, post_reward_root};
+
+    fn program_ids() -> ProgramIds {
+        ProgramIds {
+            restaking_program: Pubkey::new_unique(),
+            vault_program: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn test_create_lookup_table() {
+        let (authority, payer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let addresses: Vec<_> = (0..MAX_ADDRESSES_PER_EXTEND + 1)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let (address, instructions) = create_lookup_table(&authority, &payer, 42, &addresses);
+        assert_eq!(address, derive_lookup_table_address(&authority, 42).0);
+        // the create instruction, then the addresses split over two extends
+        assert_eq!(instructions.len(), 3);
+
+        let (_, instructions) = create_lookup_table(&authority, &payer, 42, &[]);
+        assert_eq!(instructions.len(), 1);
+    }
+
+    #[test]
+    fn test_v0_message_compresses_common_addresses() {
+        let program_ids = program_ids();
+        let (source, mint, admin) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let instruction = post_reward_root(
+            &program_ids.restaking_program,
+            &program_ids.restaking_config(),
+            &source,
+            &Pubkey::new_unique(),
+            &mint,
+            &Pubkey::new_unique(),
+            &admin,
+            &admin,
+            &spl_token::id(),
+            1,
+            [1; 32],
+            1_000,
+            10,
+        );
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: common_addresses(&program_ids),
+        };
+        let compute_budget = ComputeBudget {
+            unit_limit: Some(400_000),
+            unit_price: Some(10_000),
+        };
+
+        let message = v0_message(
+            std::slice::from_ref(&instruction),
+            &admin,
+            &compute_budget,
+            std::slice::from_ref(&table),
+            &Hash::new_unique(),
+        )
+        .unwrap();
+        let VersionedMessage::V0(v0) = &message else {
+            panic!("not a v0 message");
+        };
+        let static_keys = &v0.account_keys;
+        assert_eq!(static_keys[0], admin);
+        assert!(!static_keys.contains(&program_ids.restaking_config()));
+        assert!(!static_keys.contains(&spl_token::id()));
+        assert!(!static_keys.contains(&system_program::id()));
+        // invoked programs stay static
+        assert!(static_keys.contains(&program_ids.restaking_program));
+        assert!(static_keys.contains(&COMPUTE_BUDGET_PROGRAM_ID));
+        assert_eq!(v0.instructions.len(), 3);
+
+        let legacy = FlowTransaction::new(
+            &[compute_budget.instructions(), vec![instruction]].concat(),
+            &admin,
+        );
+        assert!(serialized_size(&message) < legacy.serialized_size());
+        assert!(fits_in_packet(&message));
+    }
+}
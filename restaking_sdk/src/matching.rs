@@ -0,0 +1,232 @@
+//! Suggests how vaults delegate their stake to operators without exceeding the capacity operators
+//! advertise per AVS with [`crate::RestakingInstruction::OperatorSetAvsCapacity`].
+//!
+//! Stake delegated to an operator secures every AVS the operator runs for the vault, so the
+//! operator can take at most the least capacity it advertised across the AVSs of the vault, and an
+//! allocation uses up that capacity for each of them. Operators that advertised no capacity for any
+//! AVS of a vault aren't matched with it. Each vault's stake is spread evenly across its operators,
+//! capped by their capacity, and vaults are matched in the order given, so earlier vaults get the
+//! first pick of contended capacity. Stake no operator has room for is left undelegated.
+use std::collections::HashMap;
+
+use solana_program::pubkey::Pubkey;
+
+/// The capacity an operator advertised for an AVS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorCapacity {
+    pub operator: Pubkey,
+    pub avs: Pubkey,
+    /// The max additional stake the operator can handle for the AVS
+    pub capacity: u64,
+}
+
+/// The stake a vault has left to delegate and where it can go
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultDemand {
+    pub vault: Pubkey,
+    /// The stake the vault hasn't delegated yet
+    pub amount: u64,
+    /// The AVSs the vault added
+    pub avss: Vec<Pubkey>,
+    /// The operators the vault added, which it can delegate to
+    pub operators: Vec<Pubkey>,
+}
+
+/// A delegation the vault should add
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocation {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+}
+
+/// Matches the stake of each vault to the capacity of its operators, see the module docs
+pub fn match_delegations(
+    vaults: &[VaultDemand],
+    capacities: &[OperatorCapacity],
+) -> Vec<Allocation> {
+    let mut remaining: HashMap<(Pubkey, Pubkey), u64> = capacities
+        .iter()
+        .map(|c| ((c.operator, c.avs), c.capacity))
+        .collect();
+
+    let mut allocations = Vec::new();
+    for vault in vaults {
+        let mut operators = vault.operators.clone();
+        operators.sort();
+        operators.dedup();
+
+        // the AVSs of the vault each operator advertised capacity for, and its room across them
+        let candidates: Vec<(Pubkey, Vec<Pubkey>, u64)> = operators
+            .into_iter()
+            .filter_map(|operator| {
+                let avss: Vec<Pubkey> = vault
+                    .avss
+                    .iter()
+                    .copied()
+                    .filter(|avs| remaining.contains_key(&(operator, *avs)))
+                    .collect();
+                let room = avss
+                    .iter()
+                    .filter_map(|avs| remaining.get(&(operator, *avs)).copied())
+                    .min()?;
+                Some((operator, avss, room))
+            })
+            .collect();
+
+        let rooms: Vec<u64> = candidates.iter().map(|(_, _, room)| *room).collect();
+        let amounts = spread_evenly(vault.amount, &rooms);
+
+        for ((operator, avss, _), amount) in candidates.into_iter().zip(amounts) {
+            if amount == 0 {
+                continue;
+            }
+            for avs in avss {
+                if let Some(room) = remaining.get_mut(&(operator, avs)) {
+                    *room = room.saturating_sub(amount);
+                }
+            }
+            allocations.push(Allocation {
+                vault: vault.vault,
+                operator,
+                amount,
+            });
+        }
+    }
+    allocations
+}
+
+/// Splits `amount` as evenly as the rooms allow, never giving more than a room holds. The first
+/// rooms get the remainder of uneven splits.
+fn spread_evenly(amount: u64, rooms: &[u64]) -> Vec<u64> {
+    let mut amounts = vec![0_u64; rooms.len()];
+    let mut left = amount;
+    loop {
+        let open: Vec<usize> = (0..rooms.len())
+            .filter(|&i| amounts[i] < rooms[i])
+            .collect();
+        if left == 0 || open.is_empty() {
+            return amounts;
+        }
+        let share = left.checked_div(open.len() as u64).unwrap_or(0).max(1);
+        for i in open {
+            let give = share.min(rooms[i].saturating_sub(amounts[i])).min(left);
+            amounts[i] = amounts[i].saturating_add(give);
+            left = left.saturating_sub(give);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::matching::{match_delegations, Allocation, OperatorCapacity, VaultDemand};
+
+    fn capacity(operator: Pubkey, avs: Pubkey, capacity: u64) -> OperatorCapacity {
+        OperatorCapacity {
+            operator,
+            avs,
+            capacity,
+        }
+    }
+
+    fn sorted_operators(count: usize) -> Vec<Pubkey> {
+        let mut operators: Vec<_> = (0..count).map(|_| Pubkey::new_unique()).collect();
+        operators.sort();
+        operators
+    }
+
+    fn amounts(allocations: &[Allocation]) -> Vec<u64> {
+        allocations.iter().map(|a| a.amount).collect()
+    }
+
+    #[test]
+    fn test_stake_is_spread_evenly_within_capacity() {
+        let avs = Pubkey::new_unique();
+        let operators = sorted_operators(3);
+        let vault = VaultDemand {
+            vault: Pubkey::new_unique(),
+            amount: 1_000,
+            avss: vec![avs],
+            operators: operators.clone(),
+        };
+
+        let capacities: Vec<_> = operators
+            .iter()
+            .map(|operator| capacity(*operator, avs, 10_000))
+            .collect();
+        let allocations = match_delegations(std::slice::from_ref(&vault), &capacities);
+        assert_eq!(amounts(&allocations), vec![334, 333, 333]);
+        assert!(allocations.iter().all(|a| a.vault == vault.vault));
+
+        // the operator with little room is filled up, the others take the rest
+        let capacities = vec![
+            capacity(operators[0], avs, 100),
+            capacity(operators[1], avs, 10_000),
+            capacity(operators[2], avs, 10_000),
+        ];
+        let allocations = match_delegations(&[vault], &capacities);
+        assert_eq!(amounts(&allocations), vec![100, 450, 450]);
+    }
+
+    #[test]
+    fn test_operator_room_is_its_least_capacity_across_the_vault_avss() {
+        let [avs_a, avs_b, avs_c] = [(); 3].map(|_| Pubkey::new_unique());
+        let operators = sorted_operators(2);
+        let vault = VaultDemand {
+            vault: Pubkey::new_unique(),
+            amount: 1_000,
+            avss: vec![avs_a, avs_b],
+            operators: operators.clone(),
+        };
+        let capacities = vec![
+            capacity(operators[0], avs_a, 500),
+            capacity(operators[0], avs_b, 200),
+            // the vault didn't add this AVS
+            capacity(operators[0], avs_c, 0),
+            // without any capacity for the vault's AVSs, the operator isn't matched
+            capacity(operators[1], avs_c, 10_000),
+        ];
+
+        let allocations = match_delegations(&[vault], &capacities);
+        assert_eq!(
+            allocations
+                .iter()
+                .map(|a| (a.operator, a.amount))
+                .collect::<Vec<_>>(),
+            vec![(operators[0], 200)]
+        );
+    }
+
+    #[test]
+    fn test_capacity_is_shared_across_vaults() {
+        let avs = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let vaults: Vec<_> = (0..2)
+            .map(|_| VaultDemand {
+                vault: Pubkey::new_unique(),
+                amount: 600,
+                avss: vec![avs],
+                operators: vec![operator],
+            })
+            .collect();
+
+        let allocations = match_delegations(&vaults, &[capacity(operator, avs, 1_000)]);
+        assert_eq!(
+            allocations,
+            vec![
+                Allocation {
+                    vault: vaults[0].vault,
+                    operator,
+                    amount: 600,
+                },
+                Allocation {
+                    vault: vaults[1].vault,
+                    operator,
+                    amount: 400,
+                },
+            ]
+        );
+    }
+}
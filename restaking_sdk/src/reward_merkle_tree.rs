@@ -0,0 +1,137 @@
+//! Builds the merkle tree of the rewards of a [`RewardDistribution`] off-chain, for the root an
+//! admin posts with [`crate::RestakingInstruction::PostRewardRoot`] and the proofs claimants submit
+//! with [`crate::RestakingInstruction::ClaimReward`].
+//!
+//! Leaves are hashed with [`RewardDistribution::leaf`] in the order given and paired up level by
+//! level with [`RewardDistribution::node`]. The last node of a level with an odd number of nodes is
+//! carried up unpaired, so it adds no sibling to the proofs through it.
+use jito_restaking_core::reward_distribution::RewardDistribution;
+use solana_program::pubkey::Pubkey;
+
+/// The reward merkle tree, see the module docs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardMerkleTree {
+    /// The levels of the tree, from the leaves up to the root
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl RewardMerkleTree {
+    /// Builds the tree of the claimants and their amounts, returning `None` without claims
+    pub fn new(claims: &[(Pubkey, u64)]) -> Option<Self> {
+        if claims.is_empty() {
+            return None;
+        }
+        let mut levels = vec![claims
+            .iter()
+            .map(|(claimant, amount)| RewardDistribution::leaf(claimant, *amount))
+            .collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => RewardDistribution::node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Some(Self { levels })
+    }
+
+    /// The root to post for the distribution
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// The number of claims, the least `max_num_nodes` to post
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The proof of the claim at the index, `None` if it's out of range
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut proof = Vec::new();
+        let mut index = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::reward_distribution::RewardDistribution;
+    use solana_program::pubkey::Pubkey;
+
+    use super::RewardMerkleTree;
+
+    #[test]
+    fn test_every_proof_reaches_the_root() {
+        for len in 1..=9 {
+            let claims: Vec<_> = (0..len)
+                .map(|i| (Pubkey::new_unique(), 100 * (i as u64 + 1)))
+                .collect();
+            let tree = RewardMerkleTree::new(&claims).unwrap();
+            assert_eq!(tree.len(), len);
+            for (index, (claimant, amount)) in claims.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert_eq!(
+                    RewardDistribution::root_of(
+                        RewardDistribution::leaf(claimant, *amount),
+                        &proof
+                    ),
+                    tree.root()
+                );
+                assert_ne!(
+                    RewardDistribution::root_of(
+                        RewardDistribution::leaf(claimant, amount + 1),
+                        &proof
+                    ),
+                    tree.root()
+                );
+            }
+            assert_eq!(tree.proof(len), None);
+        }
+        assert_eq!(RewardMerkleTree::new(&[]), None);
+    }
+
+    #[test]
+    fn test_claim_against_posted_root() {
+        let claims = [
+            (Pubkey::new_unique(), 300),
+            (Pubkey::new_unique(), 200),
+            (Pubkey::new_unique(), 500),
+        ];
+        let tree = RewardMerkleTree::new(&claims).unwrap();
+        let mut distribution = RewardDistribution::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            tree.root(),
+            1_000,
+            tree.len() as u64,
+            255,
+        );
+        for (index, (claimant, amount)) in claims.iter().enumerate() {
+            distribution
+                .claim(claimant, *amount, &tree.proof(index).unwrap())
+                .unwrap();
+        }
+        assert_eq!(distribution.total_claimed(), 1_000);
+        assert_eq!(distribution.num_claimed(), 3);
+    }
+}
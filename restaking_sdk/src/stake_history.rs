@@ -0,0 +1,121 @@
+//! Proofs of epoch stake snapshots archived into the stake history of an operator.
+//!
+//! [`crate::RestakingInstruction::ArchiveEpochStake`] closes a snapshot and chains the hash of its
+//! data onto the commitment of the [`OperatorStakeHistory`]. The data of every archived snapshot
+//! is emitted in [`crate::event::RestakingEvent::EpochStakeArchived`], so an indexer can keep the
+//! snapshots of an operator in archival order and prove any of them against the commitment the
+//! history holds on-chain.
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::operator_stake_history::OperatorStakeHistory;
+
+/// Proves the data of an archived snapshot against the commitment of an operator stake history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedSnapshotProof {
+    /// The commitment before the snapshot was archived
+    pub prior_commitment: [u8; 32],
+    /// The leaves archived after the snapshot, in archival order
+    pub later_leaves: Vec<[u8; 32]>,
+}
+
+impl ArchivedSnapshotProof {
+    /// Builds the proof of the snapshot at `index` out of the data of every snapshot archived
+    /// into the history, in archival order
+    pub fn new<D: AsRef<[u8]>>(snapshots: &[D], index: usize) -> Option<Self> {
+        if index >= snapshots.len() {
+            return None;
+        }
+        let leaves: Vec<_> = snapshots
+            .iter()
+            .map(|data| OperatorStakeHistory::leaf(data.as_ref()))
+            .collect();
+        let prior_commitment = leaves[..index].iter().fold([0; 32], |commitment, leaf| {
+            OperatorStakeHistory::chain(&commitment, leaf)
+        });
+        Some(Self {
+            prior_commitment,
+            later_leaves: leaves[index + 1..].to_vec(),
+        })
+    }
+
+    /// The commitment of the history once the snapshot and the later leaves are archived
+    pub fn commitment(&self, snapshot: &[u8]) -> [u8; 32] {
+        let commitment = OperatorStakeHistory::chain(
+            &self.prior_commitment,
+            &OperatorStakeHistory::leaf(snapshot),
+        );
+        self.later_leaves
+            .iter()
+            .fold(commitment, |commitment, leaf| {
+                OperatorStakeHistory::chain(&commitment, leaf)
+            })
+    }
+
+    /// Whether the snapshot data was archived into a history with the commitment
+    pub fn verify(&self, snapshot: &[u8], commitment: &[u8; 32]) -> bool {
+        self.commitment(snapshot) == *commitment
+    }
+
+    /// Decodes the snapshot once it's proven against the commitment, e.g. an
+    /// [`jito_restaking_core::operator_epoch_stake::OperatorEpochStake`] or a
+    /// [`jito_restaking_core::vault_operator_epoch_stake::VaultOperatorEpochStake`]
+    pub fn prove<T: ZeroCopy>(&self, snapshot: &[u8], commitment: &[u8; 32]) -> Option<T> {
+        if !self.verify(snapshot, commitment) {
+            return None;
+        }
+        T::load(snapshot).ok().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::zero_copy::ZeroCopy;
+    use jito_restaking_core::{
+        operator_epoch_stake::OperatorEpochStake, operator_stake_history::OperatorStakeHistory,
+        vault_operator_epoch_stake::VaultOperatorEpochStake,
+    };
+    use solana_program::pubkey::Pubkey;
+
+    use super::ArchivedSnapshotProof;
+
+    #[test]
+    fn test_prove_archived_snapshots() {
+        let (vault, operator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut operator_epoch_stake = OperatorEpochStake::new(operator, 3, 254);
+        operator_epoch_stake.record_vault_stake(1_000).unwrap();
+        let snapshots = vec![
+            VaultOperatorEpochStake::new(vault, operator, 3, 1_000, 10, 254)
+                .as_bytes()
+                .to_vec(),
+            operator_epoch_stake.as_bytes().to_vec(),
+            VaultOperatorEpochStake::new(vault, operator, 4, 2_000, 20, 254)
+                .as_bytes()
+                .to_vec(),
+        ];
+
+        let mut history = OperatorStakeHistory::new(operator, 254);
+        for snapshot in &snapshots {
+            history
+                .archive(&OperatorStakeHistory::leaf(snapshot), 4)
+                .unwrap();
+        }
+        let commitment = history.commitment();
+
+        let proof = ArchivedSnapshotProof::new(&snapshots, 1).unwrap();
+        let proven = proof
+            .prove::<OperatorEpochStake>(&snapshots[1], &commitment)
+            .unwrap();
+        assert_eq!(proven.stake(), 1_000);
+        assert_eq!(proven.epoch(), 3);
+
+        let proof = ArchivedSnapshotProof::new(&snapshots, 2).unwrap();
+        let proven = proof
+            .prove::<VaultOperatorEpochStake>(&snapshots[2], &commitment)
+            .unwrap();
+        assert_eq!(proven.stake(), 2_000);
+
+        // a tampered snapshot doesn't match the commitment
+        let tampered = VaultOperatorEpochStake::new(vault, operator, 4, 3_000, 20, 254);
+        assert!(!proof.verify(tampered.as_bytes(), &commitment));
+        assert!(ArchivedSnapshotProof::new(&snapshots, 3).is_none());
+    }
+}
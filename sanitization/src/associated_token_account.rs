@@ -1,8 +1,11 @@
 use solana_program::{account_info::AccountInfo, program_pack::Pack, pubkey::Pubkey};
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_token::state::Account;
 
-use crate::result::{SanitizationError, SanitizationResult};
+use crate::{
+    result::{SanitizationError, SanitizationResult},
+    token_state::{is_token_program, unpack_token_account},
+};
 
 pub struct SanitizedAssociatedTokenAccount<'a, 'info> {
     inner: &'a AccountInfo<'info>,
@@ -15,19 +18,27 @@ impl<'a, 'info> SanitizedAssociatedTokenAccount<'a, 'info> {
         mint: &Pubkey,
         owner: &Pubkey,
     ) -> SanitizationResult<SanitizedAssociatedTokenAccount<'a, 'info>> {
-        let expected_ata = get_associated_token_address(owner, mint);
+        // the address depends on the token program, so it's derived with the one owning the account
+        let token_program = if is_token_program(account.owner) {
+            *account.owner
+        } else {
+            spl_token::id()
+        };
+        let expected_ata =
+            get_associated_token_address_with_program_id(owner, mint, &token_program);
 
         if *account.key != expected_ata {
             return Err(SanitizationError::AssociatedTokenAccountInvalidAddress);
         }
-        if account.owner != &spl_token::id() {
+        if !is_token_program(account.owner) {
             return Err(SanitizationError::AssociatedTokenAccountInvalidOwner);
         }
-        if account.data_len() != Account::LEN {
+        // Token-2022 accounts carry their extensions after the base state
+        if *account.owner == spl_token::id() && account.data_len() != Account::LEN {
             return Err(SanitizationError::AssociatedTokenAccountInvalidAccountData);
         }
 
-        let token_account = Account::unpack(&account.data.borrow())
+        let token_account = unpack_token_account(account.owner, &account.data.borrow())
             .map_err(|_| SanitizationError::AssociatedTokenAccountInvalidAccountData)?;
 
         Ok(SanitizedAssociatedTokenAccount {
@@ -46,7 +57,7 @@ impl<'a, 'info> SanitizedAssociatedTokenAccount<'a, 'info> {
 
     /// Reload needs to be called after CPIs to ensure the data is up-to-date
     pub fn reload(&mut self) -> SanitizationResult<()> {
-        self.token_account = Account::unpack(&self.inner.data.borrow())
+        self.token_account = unpack_token_account(self.inner.owner, &self.inner.data.borrow())
             .map_err(|_| SanitizationError::AssociatedTokenAccountFailedReload)?;
         Ok(())
     }
@@ -0,0 +1,182 @@
+//! Verifies that a message was signed by a key using the ed25519 native program.
+//!
+//! Programs can't check ed25519 signatures themselves within the compute budget, so the signature
+//! is checked by an ed25519 program instruction placed right before the instruction that relies on
+//! it. The runtime fails the transaction if that signature is invalid, and the program reads the
+//! instructions sysvar to check the ed25519 instruction covered the expected signer and message.
+use solana_program::{
+    account_info::AccountInfo,
+    ed25519_program,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::result::{SanitizationError, SanitizationResult};
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+
+/// The instruction index used by the ed25519 program to refer to its own data
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Builds an ed25519 program instruction verifying a single signature over the message
+pub fn new_ed25519_instruction(
+    signer: &Pubkey,
+    signature: &[u8; SIGNATURE_LEN],
+    message: &[u8],
+) -> Instruction {
+    let public_key_offset = SIGNATURE_OFFSETS_START.saturating_add(SIGNATURE_OFFSETS_LEN);
+    let signature_offset = public_key_offset.saturating_add(PUBKEY_LEN);
+    let message_data_offset = signature_offset.saturating_add(SIGNATURE_LEN);
+
+    let mut data = vec![1, 0];
+    for value in [
+        signature_offset as u16,
+        CURRENT_INSTRUCTION,
+        public_key_offset as u16,
+        CURRENT_INSTRUCTION,
+        message_data_offset as u16,
+        message.len() as u16,
+        CURRENT_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Checks the instruction right before the one being processed is an ed25519 program instruction
+/// verifying a signature by `signer` over `message`
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> SanitizationResult<()> {
+    if !solana_program::sysvar::instructions::check_id(instructions_sysvar.key) {
+        return Err(SanitizationError::Ed25519InstructionsSysvarInvalidAddress);
+    }
+    let current_index = load_current_index_checked(instructions_sysvar)
+        .map_err(|_| SanitizationError::Ed25519InstructionsSysvarInvalidAddress)?;
+    let previous_index = current_index
+        .checked_sub(1)
+        .ok_or(SanitizationError::Ed25519InstructionMissing)?;
+    let instruction = load_instruction_at_checked(previous_index as usize, instructions_sysvar)
+        .map_err(|_| SanitizationError::Ed25519InstructionMissing)?;
+    if instruction.program_id != ed25519_program::id() {
+        return Err(SanitizationError::Ed25519InstructionMissing);
+    }
+
+    check_ed25519_instruction_data(&instruction.data, signer, message)
+}
+
+/// Checks the data of an ed25519 program instruction holds exactly one signature, by `signer` over
+/// `message`, with the key and message read from the instruction itself
+fn check_ed25519_instruction_data(
+    data: &[u8],
+    signer: &Pubkey,
+    message: &[u8],
+) -> SanitizationResult<()> {
+    if data.first() != Some(&1) {
+        return Err(SanitizationError::Ed25519InstructionInvalidData);
+    }
+    let offsets = data
+        .get(SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START.saturating_add(SIGNATURE_OFFSETS_LEN))
+        .ok_or(SanitizationError::Ed25519InstructionInvalidData)?;
+    let read_u16 = |index: usize| {
+        let start = index.saturating_mul(2);
+        u16::from_le_bytes([offsets[start], offsets[start.saturating_add(1)]])
+    };
+    let (signature_ix, public_key_offset, public_key_ix) = (read_u16(1), read_u16(2), read_u16(3));
+    let (message_offset, message_size, message_ix) = (read_u16(4), read_u16(5), read_u16(6));
+    // data in other instructions could be changed without changing what this instruction signed
+    if [signature_ix, public_key_ix, message_ix]
+        .iter()
+        .any(|index| *index != CURRENT_INSTRUCTION)
+    {
+        return Err(SanitizationError::Ed25519InstructionInvalidData);
+    }
+
+    let public_key_offset = usize::from(public_key_offset);
+    let public_key = data
+        .get(public_key_offset..public_key_offset.saturating_add(PUBKEY_LEN))
+        .ok_or(SanitizationError::Ed25519InstructionInvalidData)?;
+    if public_key != signer.as_ref() {
+        return Err(SanitizationError::Ed25519SignerMismatch);
+    }
+
+    let message_offset = usize::from(message_offset);
+    let signed_message = data
+        .get(message_offset..message_offset.saturating_add(usize::from(message_size)))
+        .ok_or(SanitizationError::Ed25519InstructionInvalidData)?;
+    if signed_message != message {
+        return Err(SanitizationError::Ed25519MessageMismatch);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        ed25519::{check_ed25519_instruction_data, new_ed25519_instruction},
+        result::SanitizationError,
+    };
+
+    #[test]
+    fn test_check_ed25519_instruction_data_ok() {
+        let signer = Pubkey::new_unique();
+        let instruction = new_ed25519_instruction(&signer, &[1; 64], b"message");
+        check_ed25519_instruction_data(&instruction.data, &signer, b"message").unwrap();
+    }
+
+    #[test]
+    fn test_check_ed25519_instruction_data_wrong_signer_fails() {
+        let instruction = new_ed25519_instruction(&Pubkey::new_unique(), &[1; 64], b"message");
+        assert_matches!(
+            check_ed25519_instruction_data(&instruction.data, &Pubkey::new_unique(), b"message"),
+            Err(SanitizationError::Ed25519SignerMismatch)
+        );
+    }
+
+    #[test]
+    fn test_check_ed25519_instruction_data_wrong_message_fails() {
+        let signer = Pubkey::new_unique();
+        let instruction = new_ed25519_instruction(&signer, &[1; 64], b"message");
+        assert_matches!(
+            check_ed25519_instruction_data(&instruction.data, &signer, b"other message"),
+            Err(SanitizationError::Ed25519MessageMismatch)
+        );
+    }
+
+    #[test]
+    fn test_check_ed25519_instruction_data_other_instruction_fails() {
+        let signer = Pubkey::new_unique();
+        let mut data = new_ed25519_instruction(&signer, &[1; 64], b"message").data;
+        // message read from the first instruction of the transaction
+        data[14..16].copy_from_slice(&0_u16.to_le_bytes());
+        assert_matches!(
+            check_ed25519_instruction_data(&data, &signer, b"message"),
+            Err(SanitizationError::Ed25519InstructionInvalidData)
+        );
+
+        let mut data = new_ed25519_instruction(&signer, &[1; 64], b"message").data;
+        data[0] = 2;
+        assert_matches!(
+            check_ed25519_instruction_data(&data, &signer, b"message"),
+            Err(SanitizationError::Ed25519InstructionInvalidData)
+        );
+    }
+}
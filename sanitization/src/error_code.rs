@@ -0,0 +1,103 @@
+//! Custom error code ranges shared by the programs in the workspace.
+//!
+//! Every crate that converts its errors into [`solana_program::program_error::ProgramError::Custom`]
+//! owns a range below, so a code alone tells which crate raised it even when a transaction calls
+//! both programs. Codes within a range are offsets in the declaration order of the error enum and
+//! shall never change; new errors are appended.
+use std::ops::Range;
+
+/// Errors raised while sanitizing accounts, by either program
+pub const SANITIZATION_ERROR_CODES: Range<u32> = 0..1_000;
+
+/// Errors raised by the restaking program
+pub const RESTAKING_ERROR_CODES: Range<u32> = 1_000..2_000;
+
+/// Errors raised by the vault program
+pub const VAULT_ERROR_CODES: Range<u32> = 2_000..3_000;
+
+/// Errors raised by the restaking program's processors rather than its accounts
+pub const RESTAKING_PROGRAM_ERROR_CODES: Range<u32> = 3_000..4_000;
+
+/// Errors raised by the vault program's processors rather than its accounts
+pub const VAULT_PROGRAM_ERROR_CODES: Range<u32> = 4_000..5_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSource {
+    Sanitization,
+    Restaking,
+    Vault,
+    RestakingProgram,
+    VaultProgram,
+}
+
+impl ErrorSource {
+    pub const fn range(&self) -> Range<u32> {
+        match self {
+            Self::Sanitization => SANITIZATION_ERROR_CODES,
+            Self::Restaking => RESTAKING_ERROR_CODES,
+            Self::Vault => VAULT_ERROR_CODES,
+            Self::RestakingProgram => RESTAKING_PROGRAM_ERROR_CODES,
+            Self::VaultProgram => VAULT_PROGRAM_ERROR_CODES,
+        }
+    }
+
+    /// Returns the source of a custom error code and the offset of the code within its range
+    pub fn from_code(code: u32) -> Option<(Self, u32)> {
+        [
+            Self::Sanitization,
+            Self::Restaking,
+            Self::Vault,
+            Self::RestakingProgram,
+            Self::VaultProgram,
+        ]
+        .into_iter()
+        .find(|source| source.range().contains(&code))
+        .map(|source| (source, code.saturating_sub(source.range().start)))
+    }
+
+    /// Returns the custom error code for the offset within the range of the source
+    pub fn code(&self, offset: u32) -> u32 {
+        let range = self.range();
+        let code = range.start.saturating_add(offset);
+        assert!(range.contains(&code), "{self:?} error code out of range");
+        code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_ranges_dont_overlap() {
+        assert_eq!(SANITIZATION_ERROR_CODES.end, RESTAKING_ERROR_CODES.start);
+        assert_eq!(RESTAKING_ERROR_CODES.end, VAULT_ERROR_CODES.start);
+        assert_eq!(VAULT_ERROR_CODES.end, RESTAKING_PROGRAM_ERROR_CODES.start);
+        assert_eq!(
+            RESTAKING_PROGRAM_ERROR_CODES.end,
+            VAULT_PROGRAM_ERROR_CODES.start
+        );
+    }
+
+    #[test]
+    fn test_error_source_from_code() {
+        assert_eq!(
+            ErrorSource::from_code(401),
+            Some((ErrorSource::Sanitization, 401))
+        );
+        assert_eq!(
+            ErrorSource::from_code(1_005),
+            Some((ErrorSource::Restaking, 5))
+        );
+        assert_eq!(ErrorSource::from_code(2_000), Some((ErrorSource::Vault, 0)));
+        assert_eq!(
+            ErrorSource::from_code(3_000),
+            Some((ErrorSource::RestakingProgram, 0))
+        );
+        assert_eq!(
+            ErrorSource::from_code(4_999),
+            Some((ErrorSource::VaultProgram, 999))
+        );
+        assert_eq!(ErrorSource::from_code(5_000), None);
+    }
+}
@@ -1,11 +1,16 @@
 pub mod associated_token_account;
+pub mod ed25519;
 pub mod empty_account;
+pub mod error_code;
+pub mod program_data;
+pub mod rent_payer;
 pub mod result;
 pub mod signer;
 pub mod system_program;
 pub mod token_account;
 pub mod token_mint;
 pub mod token_program;
+pub mod token_state;
 
 use solana_program::{
     account_info::AccountInfo,
@@ -62,29 +67,41 @@ pub fn create_account<'a, 'info>(
                 &[payer.clone(), new_account.clone(), system_program.clone()],
             )?;
         }
-        // Allocate space.
-        invoke_signed(
-            &system_instruction::allocate(new_account.key, space),
-            &[new_account.clone(), system_program.clone()],
-            &[seeds
-                .iter()
-                .map(|seed| seed.as_slice())
-                .collect::<Vec<&[u8]>>()
-                .as_slice()],
-        )?;
-        // Assign to the specified program
-        invoke_signed(
-            &system_instruction::assign(new_account.key, program_owner),
-            &[new_account.clone(), system_program.clone()],
-            &[seeds
-                .iter()
-                .map(|seed| seed.as_slice())
-                .collect::<Vec<&[u8]>>()
-                .as_slice()],
-        )
+        allocate_and_assign(new_account, system_program, program_owner, space, seeds)
     }
 }
 
+/// Allocates the space of a PDA still owned by the system program and assigns it to the program,
+/// leaving its lamports untouched
+pub fn allocate_and_assign<'a, 'info>(
+    new_account: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    program_owner: &Pubkey,
+    space: u64,
+    seeds: &[Vec<u8>],
+) -> ProgramResult {
+    // Allocate space.
+    invoke_signed(
+        &system_instruction::allocate(new_account.key, space),
+        &[new_account.clone(), system_program.clone()],
+        &[seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )?;
+    // Assign to the specified program
+    invoke_signed(
+        &system_instruction::assign(new_account.key, program_owner),
+        &[new_account.clone(), system_program.clone()],
+        &[seeds
+            .iter()
+            .map(|seed| seed.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )
+}
+
 pub fn realloc<'a, 'info>(
     account: &'a AccountInfo<'info>,
     new_size: usize,
@@ -102,6 +119,78 @@ pub fn realloc<'a, 'info>(
     Ok(())
 }
 
+/// Transfers enough lamports from the payer for the account to be rent exempt at its current size,
+/// such as after a realloc. Returns the amount transferred.
+pub fn top_up_rent<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    rent: &Rent,
+) -> Result<u64, ProgramError> {
+    let lamports_diff = rent
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.lamports());
+    if lamports_diff > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, lamports_diff),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    Ok(lamports_diff)
+}
+
+/// Moves the lamports above the rent-exempt minimum out of an account owned by the calling program.
+/// Returns the amount moved.
+pub fn sweep_excess_lamports<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+    receiver: &'a AccountInfo<'info>,
+    rent: &Rent,
+) -> Result<u64, ProgramError> {
+    pay_excess_lamports(account, receiver, rent, u64::MAX)
+}
+
+/// Moves up to `max_amount` of the lamports above the rent-exempt minimum out of an account owned
+/// by the calling program, so the account stays rent-exempt. Returns the amount moved.
+pub fn pay_excess_lamports<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+    receiver: &'a AccountInfo<'info>,
+    rent: &Rent,
+    max_amount: u64,
+) -> Result<u64, ProgramError> {
+    let amount = account
+        .lamports()
+        .saturating_sub(rent.minimum_balance(account.data_len()))
+        .min(max_amount);
+    if amount > 0 {
+        **account.try_borrow_mut_lamports()? = account
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **receiver.try_borrow_mut_lamports()? = receiver
+            .lamports()
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+    Ok(amount)
+}
+
+/// Closes an account owned by the calling program, moving all its lamports to the receiver,
+/// zeroing its data and handing it back to the system program
+pub fn close_program_account<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+    receiver: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    **receiver.try_borrow_mut_lamports()? = receiver
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.try_borrow_mut_data()?.fill(0);
+    account.realloc(0, false)?;
+    account.assign(&solana_program::system_program::id());
+    Ok(())
+}
+
 #[inline(always)]
 pub fn assert_with_msg(v: bool, err: impl Into<ProgramError>, msg: &str) -> ProgramResult {
     if v {
@@ -0,0 +1,179 @@
+use solana_program::{account_info::AccountInfo, bpf_loader_upgradeable, pubkey::Pubkey};
+
+use crate::result::{SanitizationError, SanitizationResult};
+
+/// The serialized `UpgradeableLoaderState::ProgramData` variant
+const PROGRAM_DATA_TAG: [u8; 4] = [3, 0, 0, 0];
+/// The offset of the optional upgrade authority, after the tag and the deployment slot
+const UPGRADE_AUTHORITY_OFFSET: usize = 12;
+
+/// The address of the ProgramData account of an upgradeable program
+pub fn program_data_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
+}
+
+/// The ProgramData account of an upgradeable program, which holds its upgrade authority
+#[derive(Debug)]
+pub struct SanitizedProgramData<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+    upgrade_authority: Option<Pubkey>,
+}
+
+impl<'a, 'info> SanitizedProgramData<'a, 'info> {
+    /// Sanitizes the ProgramData account of the program
+    pub fn sanitize(
+        account: &'a AccountInfo<'info>,
+        program_id: &Pubkey,
+    ) -> SanitizationResult<SanitizedProgramData<'a, 'info>> {
+        if *account.key != program_data_address(program_id) {
+            return Err(SanitizationError::ProgramDataInvalidAddress);
+        }
+        if *account.owner != bpf_loader_upgradeable::id() {
+            return Err(SanitizationError::ProgramDataInvalidProgramOwner);
+        }
+        let upgrade_authority = read_upgrade_authority(&account.data.borrow())?;
+
+        Ok(SanitizedProgramData {
+            account,
+            upgrade_authority,
+        })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    /// The upgrade authority, or None if the program is immutable
+    pub const fn upgrade_authority(&self) -> Option<Pubkey> {
+        self.upgrade_authority
+    }
+
+    /// Checks the key is the upgrade authority of the program
+    pub fn check_upgrade_authority(&self, authority: &Pubkey) -> SanitizationResult<()> {
+        if self.upgrade_authority != Some(*authority) {
+            return Err(SanitizationError::ProgramDataInvalidUpgradeAuthority);
+        }
+        Ok(())
+    }
+}
+
+fn read_upgrade_authority(data: &[u8]) -> SanitizationResult<Option<Pubkey>> {
+    if data.get(..PROGRAM_DATA_TAG.len()) != Some(&PROGRAM_DATA_TAG) {
+        return Err(SanitizationError::ProgramDataInvalidAccountData);
+    }
+    match data.get(UPGRADE_AUTHORITY_OFFSET) {
+        Some(0) => Ok(None),
+        Some(1) => {
+            let start = UPGRADE_AUTHORITY_OFFSET.saturating_add(1);
+            let authority = data
+                .get(start..start.saturating_add(32))
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .ok_or(SanitizationError::ProgramDataInvalidAccountData)?;
+            Ok(Some(Pubkey::new_from_array(authority)))
+        }
+        _ => Err(SanitizationError::ProgramDataInvalidAccountData),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use solana_program::{
+        account_info::AccountInfo, bpf_loader_upgradeable, clock::Epoch, pubkey::Pubkey,
+    };
+
+    use crate::{
+        program_data::{program_data_address, SanitizedProgramData},
+        result::SanitizationError,
+    };
+
+    fn program_data(upgrade_authority: Option<Pubkey>) -> Vec<u8> {
+        let mut data = vec![3, 0, 0, 0];
+        data.extend_from_slice(&42_u64.to_le_bytes());
+        match upgrade_authority {
+            Some(authority) => {
+                data.push(1);
+                data.extend_from_slice(authority.as_ref());
+            }
+            None => data.push(0),
+        }
+        data
+    }
+
+    #[test]
+    fn test_upgrade_authority_ok() {
+        let program_id = Pubkey::new_unique();
+        let key = program_data_address(&program_id);
+        let authority = Pubkey::new_unique();
+        let mut data = program_data(Some(authority));
+        let mut lamports = 0;
+        let owner = bpf_loader_upgradeable::id();
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::MAX,
+        );
+
+        let program_data = SanitizedProgramData::sanitize(&account_info, &program_id).unwrap();
+        assert_eq!(program_data.upgrade_authority(), Some(authority));
+        program_data.check_upgrade_authority(&authority).unwrap();
+        assert_matches!(
+            program_data.check_upgrade_authority(&Pubkey::new_unique()),
+            Err(SanitizationError::ProgramDataInvalidUpgradeAuthority)
+        );
+    }
+
+    #[test]
+    fn test_immutable_program_has_no_authority() {
+        let program_id = Pubkey::new_unique();
+        let key = program_data_address(&program_id);
+        let mut data = program_data(None);
+        let mut lamports = 0;
+        let owner = bpf_loader_upgradeable::id();
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::MAX,
+        );
+
+        let program_data = SanitizedProgramData::sanitize(&account_info, &program_id).unwrap();
+        assert_eq!(program_data.upgrade_authority(), None);
+        assert_matches!(
+            program_data.check_upgrade_authority(&Pubkey::default()),
+            Err(SanitizationError::ProgramDataInvalidUpgradeAuthority)
+        );
+    }
+
+    #[test]
+    fn test_other_program_fails() {
+        let key = program_data_address(&Pubkey::new_unique());
+        let mut data = program_data(Some(Pubkey::new_unique()));
+        let mut lamports = 0;
+        let owner = bpf_loader_upgradeable::id();
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::MAX,
+        );
+
+        assert_matches!(
+            SanitizedProgramData::sanitize(&account_info, &Pubkey::new_unique()),
+            Err(SanitizationError::ProgramDataInvalidAddress)
+        );
+    }
+}
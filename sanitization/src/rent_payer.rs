@@ -0,0 +1,145 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, rent::Rent,
+};
+
+use crate::{
+    allocate_and_assign, create_account, pay_excess_lamports,
+    result::{SanitizationError, SanitizationResult},
+    signer::SanitizedSignerAccount,
+};
+
+/// Pays the rent of an account created by an instruction. The payer is either a writable signer
+/// or, so the authority of the parent account never needs a SOL balance, the parent account
+/// itself, which funds the rent out of the lamports it holds above its rent-exempt minimum.
+#[derive(Debug)]
+pub enum RentPayer<'a, 'info> {
+    Signer(SanitizedSignerAccount<'a, 'info>),
+    Parent(&'a AccountInfo<'info>),
+}
+
+impl<'a, 'info> RentPayer<'a, 'info> {
+    /// Sanitizes the payer account. The parent shall already be sanitized as a writable account
+    /// owned by the calling program, and the instruction shall check the signature of its
+    /// authority.
+    pub fn sanitize(
+        account: &'a AccountInfo<'info>,
+        parent: &'a AccountInfo<'info>,
+    ) -> SanitizationResult<RentPayer<'a, 'info>> {
+        if account.key == parent.key {
+            return Ok(RentPayer::Parent(parent));
+        }
+        Ok(RentPayer::Signer(SanitizedSignerAccount::sanitize(
+            account, true,
+        )?))
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        match self {
+            RentPayer::Signer(signer) => signer.account(),
+            RentPayer::Parent(parent) => parent,
+        }
+    }
+
+    /// Creates the account like [`create_account`]. When the parent pays, the account is assigned
+    /// to the program before its rent is moved out of the parent, as the system program can't
+    /// debit an account owned by another program and the CPIs only sync the new account.
+    pub fn create_account(
+        &self,
+        new_account: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        program_owner: &Pubkey,
+        rent: &Rent,
+        space: u64,
+        seeds: &[Vec<u8>],
+    ) -> ProgramResult {
+        match self {
+            RentPayer::Signer(signer) => create_account(
+                signer.account(),
+                new_account,
+                system_program,
+                program_owner,
+                rent,
+                space,
+                seeds,
+            ),
+            RentPayer::Parent(parent) => {
+                let required_lamports = rent
+                    .minimum_balance(space as usize)
+                    .max(1)
+                    .saturating_sub(new_account.lamports());
+                let excess_lamports = parent
+                    .lamports()
+                    .saturating_sub(rent.minimum_balance(parent.data_len()));
+                if excess_lamports < required_lamports {
+                    return Err(SanitizationError::RentPayerParentInsufficientLamports.into());
+                }
+                allocate_and_assign(new_account, system_program, program_owner, space, seeds)?;
+                pay_excess_lamports(parent, new_account, rent, required_lamports)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use solana_program::{account_info::AccountInfo, clock::Epoch, pubkey::Pubkey};
+
+    use crate::{rent_payer::RentPayer, result::SanitizationError};
+
+    #[test]
+    fn test_parent_pays() {
+        let parent_key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut parent_lamports = 1_000_000;
+        let mut parent_data: Vec<u8> = vec![0; 8];
+        let parent = AccountInfo::new(
+            &parent_key,
+            false,
+            true,
+            &mut parent_lamports,
+            &mut parent_data,
+            &owner,
+            false,
+            Epoch::MAX,
+        );
+
+        let payer = RentPayer::sanitize(&parent, &parent).unwrap();
+        assert_matches!(payer, RentPayer::Parent(_));
+        assert_eq!(payer.account().key, &parent_key);
+    }
+
+    #[test]
+    fn test_other_payer_not_signer_fails() {
+        let parent_key = Pubkey::new_unique();
+        let payer_key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let system_program = solana_program::system_program::id();
+        let (mut parent_lamports, mut payer_lamports) = (1_000_000, 1_000_000);
+        let (mut parent_data, mut payer_data): (Vec<u8>, Vec<u8>) = (vec![0; 8], vec![]);
+        let parent = AccountInfo::new(
+            &parent_key,
+            false,
+            true,
+            &mut parent_lamports,
+            &mut parent_data,
+            &owner,
+            false,
+            Epoch::MAX,
+        );
+        let payer = AccountInfo::new(
+            &payer_key,
+            false,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &system_program,
+            false,
+            Epoch::MAX,
+        );
+
+        let err = RentPayer::sanitize(&payer, &parent).unwrap_err();
+        assert_matches!(err, SanitizationError::SignerNotSigner);
+    }
+}
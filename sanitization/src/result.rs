@@ -25,8 +25,72 @@ pub enum SanitizationError {
     TokenMintExpectedWritable,
     TokenMintInvalidAccountData,
     TokenMintInvalidProgramOwner,
+    TokenMintInvalidMintAuthority,
+    TokenMintInvalidDecimals,
 
     TokenProgramInvalidAddress,
+
+    Ed25519InstructionsSysvarInvalidAddress,
+    Ed25519InstructionMissing,
+    Ed25519InstructionInvalidData,
+    Ed25519SignerMismatch,
+    Ed25519MessageMismatch,
+
+    ProgramDataInvalidAddress,
+    ProgramDataInvalidProgramOwner,
+    ProgramDataInvalidAccountData,
+    ProgramDataInvalidUpgradeAuthority,
+
+    RentPayerParentInsufficientLamports,
+
+    RemainingAccountInvalidOwner,
+    RemainingAccountInvalidDiscriminator,
+    RemainingAccountNotWritable,
+    RemainingAccountInvalidAddress,
+    RemainingAccountDuplicate,
+}
+
+impl SanitizationError {
+    /// Returns the name of the error with the custom error code, for decoding error codes off-chain
+    pub const fn name(code: u32) -> Option<&'static str> {
+        match code {
+            0 => Some("AssociatedTokenAccountInvalidAddress"),
+            1 => Some("AssociatedTokenAccountInvalidOwner"),
+            2 => Some("AssociatedTokenAccountInvalidAccountData"),
+            3 => Some("AssociatedTokenAccountFailedReload"),
+            100 => Some("EmptyAccountNotWritable"),
+            101 => Some("EmptyAccountNotEmpty"),
+            200 => Some("SignerExpectedWritable"),
+            201 => Some("SignerNotSigner"),
+            300 => Some("SystemProgramInvalidAddress"),
+            400 => Some("TokenAccountInvalidAccountData"),
+            401 => Some("TokenAccountInvalidProgramOwner"),
+            402 => Some("TokenAccountInvalidMint"),
+            403 => Some("TokenAccountInvalidOwner"),
+            500 => Some("TokenMintExpectedWritable"),
+            501 => Some("TokenMintInvalidAccountData"),
+            502 => Some("TokenMintInvalidProgramOwner"),
+            503 => Some("TokenMintInvalidMintAuthority"),
+            504 => Some("TokenMintInvalidDecimals"),
+            600 => Some("TokenProgramInvalidAddress"),
+            700 => Some("Ed25519InstructionsSysvarInvalidAddress"),
+            701 => Some("Ed25519InstructionMissing"),
+            702 => Some("Ed25519InstructionInvalidData"),
+            703 => Some("Ed25519SignerMismatch"),
+            704 => Some("Ed25519MessageMismatch"),
+            800 => Some("ProgramDataInvalidAddress"),
+            801 => Some("ProgramDataInvalidProgramOwner"),
+            802 => Some("ProgramDataInvalidAccountData"),
+            803 => Some("ProgramDataInvalidUpgradeAuthority"),
+            900 => Some("RentPayerParentInsufficientLamports"),
+            950 => Some("RemainingAccountInvalidOwner"),
+            951 => Some("RemainingAccountInvalidDiscriminator"),
+            952 => Some("RemainingAccountNotWritable"),
+            953 => Some("RemainingAccountInvalidAddress"),
+            954 => Some("RemainingAccountDuplicate"),
+            _ => None,
+        }
+    }
 }
 
 impl From<SanitizationError> for ProgramError {
@@ -53,8 +117,29 @@ impl From<SanitizationError> for ProgramError {
             SanitizationError::TokenMintExpectedWritable => Self::Custom(500),
             SanitizationError::TokenMintInvalidAccountData => Self::Custom(501),
             SanitizationError::TokenMintInvalidProgramOwner => Self::Custom(502),
+            SanitizationError::TokenMintInvalidMintAuthority => Self::Custom(503),
+            SanitizationError::TokenMintInvalidDecimals => Self::Custom(504),
 
             SanitizationError::TokenProgramInvalidAddress => Self::Custom(600),
+
+            SanitizationError::Ed25519InstructionsSysvarInvalidAddress => Self::Custom(700),
+            SanitizationError::Ed25519InstructionMissing => Self::Custom(701),
+            SanitizationError::Ed25519InstructionInvalidData => Self::Custom(702),
+            SanitizationError::Ed25519SignerMismatch => Self::Custom(703),
+            SanitizationError::Ed25519MessageMismatch => Self::Custom(704),
+
+            SanitizationError::ProgramDataInvalidAddress => Self::Custom(800),
+            SanitizationError::ProgramDataInvalidProgramOwner => Self::Custom(801),
+            SanitizationError::ProgramDataInvalidAccountData => Self::Custom(802),
+            SanitizationError::ProgramDataInvalidUpgradeAuthority => Self::Custom(803),
+
+            SanitizationError::RentPayerParentInsufficientLamports => Self::Custom(900),
+
+            SanitizationError::RemainingAccountInvalidOwner => Self::Custom(950),
+            SanitizationError::RemainingAccountInvalidDiscriminator => Self::Custom(951),
+            SanitizationError::RemainingAccountNotWritable => Self::Custom(952),
+            SanitizationError::RemainingAccountInvalidAddress => Self::Custom(953),
+            SanitizationError::RemainingAccountDuplicate => Self::Custom(954),
         }
     }
 }
@@ -1,7 +1,10 @@
-use solana_program::{account_info::AccountInfo, program_pack::Pack, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use spl_token::state::Account;
 
-use crate::result::{SanitizationError, SanitizationResult};
+use crate::{
+    result::{SanitizationError, SanitizationResult},
+    token_state::{is_token_program, unpack_token_account},
+};
 
 pub struct SanitizedTokenAccount<'a, 'info> {
     inner: &'a AccountInfo<'info>,
@@ -14,11 +17,11 @@ impl<'a, 'info> SanitizedTokenAccount<'a, 'info> {
         mint: &Pubkey,
         owner: &Pubkey,
     ) -> SanitizationResult<SanitizedTokenAccount<'a, 'info>> {
-        if *account.owner != spl_token::id() {
+        if !is_token_program(account.owner) {
             return Err(SanitizationError::TokenAccountInvalidProgramOwner);
         }
 
-        let token_account = Account::unpack(&account.data.borrow())
+        let token_account = unpack_token_account(account.owner, &account.data.borrow())
             .map_err(|_| SanitizationError::TokenAccountInvalidAccountData)?;
 
         if token_account.mint != *mint {
@@ -45,7 +48,7 @@ impl<'a, 'info> SanitizedTokenAccount<'a, 'info> {
 
     /// Reload needs to be called after CPIs to ensure the data is up-to-date
     pub fn reload(&mut self) -> SanitizationResult<()> {
-        self.token_account = Account::unpack(&self.inner.data.borrow())
+        self.token_account = unpack_token_account(self.inner.owner, &self.inner.data.borrow())
             .map_err(|_| SanitizationError::TokenAccountInvalidAccountData)?;
         Ok(())
     }
@@ -1,12 +1,19 @@
-use solana_program::{account_info::AccountInfo, program_pack::Pack};
+use solana_program::{account_info::AccountInfo, program_option::COption, pubkey::Pubkey};
 use spl_token::state::Mint;
+use spl_token_2022::extension::{transfer_fee::TransferFeeConfig, ExtensionType};
 
-use crate::result::{SanitizationError, SanitizationResult};
+use crate::{
+    result::{SanitizationError, SanitizationResult},
+    token_state::{is_token_program, unpack_mint, MintState},
+};
 
+/// A mint owned by SPL Token or Token-2022
 #[derive(Debug)]
 pub struct SanitizedTokenMint<'a, 'info> {
     account: &'a AccountInfo<'info>,
     mint: Mint,
+    extension_types: Vec<ExtensionType>,
+    transfer_fee_config: Option<TransferFeeConfig>,
 }
 
 impl<'a, 'info> SanitizedTokenMint<'a, 'info> {
@@ -19,14 +26,23 @@ impl<'a, 'info> SanitizedTokenMint<'a, 'info> {
             return Err(SanitizationError::TokenMintExpectedWritable);
         }
 
-        let mint = Mint::unpack(&account.data.borrow())
+        let MintState {
+            mint,
+            extension_types,
+            transfer_fee_config,
+        } = unpack_mint(account.owner, &account.data.borrow())
             .map_err(|_| SanitizationError::TokenMintInvalidAccountData)?;
 
-        if account.owner != &spl_token::id() {
+        if !is_token_program(account.owner) {
             return Err(SanitizationError::TokenMintInvalidProgramOwner);
         }
 
-        Ok(SanitizedTokenMint { account, mint })
+        Ok(SanitizedTokenMint {
+            account,
+            mint,
+            extension_types,
+            transfer_fee_config,
+        })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
@@ -37,10 +53,55 @@ impl<'a, 'info> SanitizedTokenMint<'a, 'info> {
         &self.mint
     }
 
+    /// The token program owning the mint, which token accounts of the mint and CPIs touching
+    /// them shall use
+    pub fn token_program(&self) -> &Pubkey {
+        self.account.owner
+    }
+
+    /// Checks the mint can only be minted by the authority, such as a vault minting its LRT
+    pub fn check_mint_authority(&self, authority: &Pubkey) -> SanitizationResult<()> {
+        if self.mint.mint_authority != COption::Some(*authority) {
+            return Err(SanitizationError::TokenMintInvalidMintAuthority);
+        }
+        Ok(())
+    }
+
+    /// Checks the mint has the decimals, such as an LRT mint matching its supported mint
+    pub fn check_decimals(&self, decimals: u8) -> SanitizationResult<()> {
+        if self.mint.decimals != decimals {
+            return Err(SanitizationError::TokenMintInvalidDecimals);
+        }
+        Ok(())
+    }
+
+    /// The Token-2022 extensions of the mint, empty for SPL Token mints
+    pub fn extension_types(&self) -> &[ExtensionType] {
+        &self.extension_types
+    }
+
+    /// The fee withheld by the token program when transferring the amount during the epoch, zero
+    /// unless the mint has the Token-2022 transfer fee extension
+    pub fn transfer_fee(&self, epoch: u64, amount: u64) -> SanitizationResult<u64> {
+        match self.transfer_fee_config.as_ref() {
+            Some(config) => config
+                .calculate_epoch_fee(epoch, amount)
+                .ok_or(SanitizationError::TokenMintInvalidAccountData),
+            None => Ok(0),
+        }
+    }
+
     /// Reload needs to be called after CPIs to ensure the data is up-to-date
     pub fn reload(&mut self) -> SanitizationResult<()> {
-        self.mint = Mint::unpack(&self.account.data.borrow())
+        let MintState {
+            mint,
+            extension_types,
+            transfer_fee_config,
+        } = unpack_mint(self.account.owner, &self.account.data.borrow())
             .map_err(|_| SanitizationError::TokenMintInvalidAccountData)?;
+        self.mint = mint;
+        self.extension_types = extension_types;
+        self.transfer_fee_config = transfer_fee_config;
         Ok(())
     }
 }
@@ -49,7 +110,8 @@ impl<'a, 'info> SanitizedTokenMint<'a, 'info> {
 mod tests {
     use assert_matches::assert_matches;
     use solana_program::{
-        account_info::AccountInfo, clock::Epoch, program_pack::Pack, pubkey::Pubkey,
+        account_info::AccountInfo, clock::Epoch, program_option::COption, program_pack::Pack,
+        pubkey::Pubkey,
     };
     use spl_token::state::Mint;
 
@@ -187,4 +249,45 @@ mod tests {
         );
         SanitizedTokenMint::sanitize(&account_info, false).unwrap();
     }
+
+    #[test]
+    fn test_check_mint_authority_and_decimals() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+
+        let program_id = spl_token::id();
+        let authority = Pubkey::new_unique();
+        let mint = Mint {
+            mint_authority: COption::Some(authority),
+            supply: 0,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: Default::default(),
+        };
+        let mut data: Vec<_> = vec![0; Mint::LEN];
+        mint.pack_into_slice(&mut data);
+
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            Epoch::MAX,
+        );
+        let mint = SanitizedTokenMint::sanitize(&account_info, false).unwrap();
+
+        mint.check_mint_authority(&authority).unwrap();
+        assert_matches!(
+            mint.check_mint_authority(&Pubkey::new_unique()),
+            Err(SanitizationError::TokenMintInvalidMintAuthority)
+        );
+        mint.check_decimals(9).unwrap();
+        assert_matches!(
+            mint.check_decimals(6),
+            Err(SanitizationError::TokenMintInvalidDecimals)
+        );
+    }
 }
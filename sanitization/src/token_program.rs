@@ -1,6 +1,9 @@
 use solana_program::account_info::AccountInfo;
 
-use crate::result::{SanitizationError, SanitizationResult};
+use crate::{
+    result::{SanitizationError, SanitizationResult},
+    token_state::is_token_program,
+};
 
 #[derive(Debug)]
 pub struct SanitizedTokenProgram<'a, 'info> {
@@ -8,11 +11,13 @@ pub struct SanitizedTokenProgram<'a, 'info> {
 }
 
 impl<'a, 'info> SanitizedTokenProgram<'a, 'info> {
-    /// Sanitizes the TokenProgram so it can be used in a safe context
+    /// Sanitizes the TokenProgram so it can be used in a safe context. Either SPL Token or
+    /// Token-2022 is accepted, the token program CPIs fail if the accounts they touch belong to
+    /// the other one.
     pub fn sanitize(
         account: &'a AccountInfo<'info>,
     ) -> SanitizationResult<SanitizedTokenProgram<'a, 'info>> {
-        if account.key != &spl_token::id() {
+        if !is_token_program(account.key) {
             return Err(SanitizationError::TokenProgramInvalidAddress);
         }
 
@@ -71,4 +76,24 @@ mod tests {
         );
         SanitizedTokenProgram::sanitize(&account_info).unwrap();
     }
+
+    #[test]
+    fn test_token_2022_address_ok() {
+        let mut data: Vec<_> = vec![0];
+        let mut lamports = 0;
+
+        let program_id = spl_token_2022::id();
+        let system_program = system_program::id();
+        let account_info = AccountInfo::new(
+            &program_id,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &system_program,
+            false,
+            Epoch::MAX,
+        );
+        SanitizedTokenProgram::sanitize(&account_info).unwrap();
+    }
 }
@@ -0,0 +1,57 @@
+//! Reads mints and token accounts owned by either SPL Token or Token-2022.
+//!
+//! Token-2022 keeps the SPL Token layout for the base state and appends its extensions after it,
+//! so once a Token-2022 account is validated its base state is read as an SPL Token one. Callers
+//! keep working with [`spl_token::state::Mint`] and [`spl_token::state::Account`] whichever
+//! program owns the account.
+use solana_program::{program_error::ProgramError, program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::{Account, Mint};
+use spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+
+/// Whether the program is SPL Token or Token-2022
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::id() || *program_id == spl_token_2022::id()
+}
+
+/// A mint with the Token-2022 extensions it was initialized with
+#[derive(Debug)]
+pub(crate) struct MintState {
+    pub mint: Mint,
+    pub extension_types: Vec<ExtensionType>,
+    pub transfer_fee_config: Option<TransferFeeConfig>,
+}
+
+/// Unpacks the mint held by an account owned by the program. Accounts owned by anything but
+/// Token-2022 are read as SPL Token mints.
+pub(crate) fn unpack_mint(program_id: &Pubkey, data: &[u8]) -> Result<MintState, ProgramError> {
+    if *program_id != spl_token_2022::id() {
+        return Ok(MintState {
+            mint: Mint::unpack(data)?,
+            extension_types: Vec::new(),
+            transfer_fee_config: None,
+        });
+    }
+
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(data)?;
+    Ok(MintState {
+        mint: Mint::unpack_from_slice(&data[..Mint::LEN])?,
+        extension_types: state.get_extension_types()?,
+        transfer_fee_config: state.get_extension::<TransferFeeConfig>().ok().copied(),
+    })
+}
+
+/// Unpacks the token account held by an account owned by the program. Accounts owned by anything
+/// but Token-2022 are read as SPL Token accounts.
+pub(crate) fn unpack_token_account(
+    program_id: &Pubkey,
+    data: &[u8],
+) -> Result<Account, ProgramError> {
+    if *program_id != spl_token_2022::id() {
+        return Account::unpack(data);
+    }
+
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(data)?;
+    Account::unpack_from_slice(&data[..Account::LEN])
+}
@@ -0,0 +1,434 @@
+//! A [`Signer`] holding its ed25519 key in AWS KMS.
+//!
+//! The key is created in KMS with key spec `ECC_NIST_EDWARDS25519` and usage `SIGN_VERIFY`, and
+//! never leaves it: the public key is read by `GetPublicKey` when the signer is created, and each
+//! message is signed by `Sign` with `ED25519_SHA_512` over the raw message. Requests are signed
+//! with Signature Version 4 using the credentials in `AWS_ACCESS_KEY_ID`,
+//! `AWS_SECRET_ACCESS_KEY` and, for temporary credentials, `AWS_SESSION_TOKEN`, which need
+//! `kms:GetPublicKey` and `kms:Sign` on the key.
+use std::{
+    fmt::Write,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::{Signer, SignerError},
+};
+
+use crate::http::{decode_base64, ed25519_spki_pubkey, send_json};
+
+/// The environment variable holding the access key id
+pub const AWS_ACCESS_KEY_ID_ENV: &str = "AWS_ACCESS_KEY_ID";
+
+/// The environment variable holding the secret access key
+pub const AWS_SECRET_ACCESS_KEY_ENV: &str = "AWS_SECRET_ACCESS_KEY";
+
+/// The environment variable holding the session token of temporary credentials
+pub const AWS_SESSION_TOKEN_ENV: &str = "AWS_SESSION_TOKEN";
+
+/// The name requests are signed for
+const SERVICE: &str = "kms";
+
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PublicKeyData {
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SignData {
+    signature: String,
+}
+
+/// The credentials requests are signed with
+#[derive(Clone)]
+pub struct AwsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+impl AwsCredentials {
+    /// Reads the credentials from [`AWS_ACCESS_KEY_ID_ENV`], [`AWS_SECRET_ACCESS_KEY_ENV`] and, if
+    /// set, [`AWS_SESSION_TOKEN_ENV`]
+    pub fn from_env() -> Result<Self, SignerError> {
+        let var = |name: &str| {
+            std::env::var(name).map_err(|_| SignerError::Custom(format!("{name} is not set")))
+        };
+        Ok(Self {
+            access_key_id: var(AWS_ACCESS_KEY_ID_ENV)?,
+            secret_access_key: var(AWS_SECRET_ACCESS_KEY_ENV)?,
+            session_token: std::env::var(AWS_SESSION_TOKEN_ENV).ok(),
+        })
+    }
+}
+
+impl std::fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+pub struct AwsKmsSigner {
+    endpoint: String,
+    region: String,
+    key_id: String,
+    credentials: AwsCredentials,
+    pubkey: Pubkey,
+}
+
+impl AwsKmsSigner {
+    /// Loads the public key of the KMS key from the endpoint, `key_id` being its id, ARN or alias
+    pub fn new(
+        endpoint: &str,
+        region: &str,
+        key_id: &str,
+        credentials: AwsCredentials,
+    ) -> Result<Self, SignerError> {
+        let mut signer = Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            region: region.to_string(),
+            key_id: key_id.to_string(),
+            credentials,
+            pubkey: Pubkey::default(),
+        };
+        signer.pubkey = signer.load_pubkey()?;
+        Ok(signer)
+    }
+
+    /// Like [`Self::new`], with the regional KMS endpoint and the credentials read from the
+    /// environment
+    pub fn from_env(region: &str, key_id: &str) -> Result<Self, SignerError> {
+        Self::new(
+            &format!("https://kms.{region}.amazonaws.com"),
+            region,
+            key_id,
+            AwsCredentials::from_env()?,
+        )
+    }
+
+    fn load_pubkey(&self) -> Result<Pubkey, SignerError> {
+        let key: PublicKeyData = self.request(
+            "GetPublicKey",
+            json!({ "KeyId": self.key_id }),
+            unix_time()?,
+        )?;
+        ed25519_spki_pubkey("aws kms", &decode(&key.public_key)?)
+    }
+
+    /// Signs the call to the KMS action at `time` and returns the response
+    fn request<T: DeserializeOwned + Send>(
+        &self,
+        action: &str,
+        body: serde_json::Value,
+        time: u64,
+    ) -> Result<T, SignerError> {
+        let body = body.to_string();
+        let headers = self.signed_headers(action, &body, time);
+        send_json("aws kms", action, |client| {
+            headers
+                .iter()
+                .filter(|(name, _)| *name != "host")
+                .fold(client.post(&self.endpoint), |request, (name, value)| {
+                    request.header(*name, value)
+                })
+                .body(body.clone())
+        })
+    }
+
+    /// The headers of the call to the KMS action, including their Signature Version 4
+    /// authorization, sorted by name
+    fn signed_headers(&self, action: &str, body: &str, time: u64) -> Vec<(&'static str, String)> {
+        let (date, date_time) = amz_date(time);
+        let host = self
+            .endpoint
+            .split_once("://")
+            .map_or(self.endpoint.as_str(), |(_, host)| host);
+        let mut headers = vec![
+            ("content-type", CONTENT_TYPE.to_string()),
+            ("host", host.to_string()),
+            ("x-amz-date", date_time.clone()),
+        ];
+        if let Some(session_token) = &self.credentials.session_token {
+            headers.push(("x-amz-security-token", session_token.clone()));
+        }
+        headers.push(("x-amz-target", format!("TrentService.{action}")));
+
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_headers =
+            headers
+                .iter()
+                .fold(String::new(), |mut canonical, (name, value)| {
+                    let _ = writeln!(canonical, "{name}:{value}");
+                    canonical
+                });
+        let canonical_request = format!(
+            "POST\n/\n\n{canonical_headers}\n{signed_headers}\n{}",
+            hex(&Sha256::digest(body))
+        );
+        let scope = format!("{date}/{}/{SERVICE}/aws4_request", self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{date_time}\n{scope}\n{}",
+            hex(&Sha256::digest(canonical_request))
+        );
+        let key = signing_key(
+            &self.credentials.secret_access_key,
+            &date,
+            &self.region,
+            SERVICE,
+        );
+        let signature = hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+        headers.push((
+            "authorization",
+            format!(
+                "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+                self.credentials.access_key_id
+            ),
+        ));
+        headers
+    }
+}
+
+impl Signer for AwsKmsSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let sign: SignData = self.request(
+            "Sign",
+            json!({
+                "KeyId": self.key_id,
+                "Message": STANDARD.encode(message),
+                "MessageType": "RAW",
+                "SigningAlgorithm": "ED25519_SHA_512",
+            }),
+            unix_time()?,
+        )?;
+        let signature = Signature::try_from(decode(&sign.signature)?)
+            .map_err(|_| SignerError::Protocol("aws kms returned an invalid signature".into()))?;
+        // an alias moved to another key would sign with a key that no longer matches
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::KeypairPubkeyMismatch);
+        }
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+fn decode(encoded: &str) -> Result<Vec<u8>, SignerError> {
+    decode_base64("aws kms", encoded)
+}
+
+fn unix_time() -> Result<u64, SignerError> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .map_err(|e| SignerError::Custom(e.to_string()))
+}
+
+/// The date and time of the unix timestamp in UTC, as in `20120215` and `20120215T000000Z`
+fn amz_date(time: u64) -> (String, String) {
+    // the proleptic Gregorian date of the day, counted in 400 year eras starting March 1st
+    let days = time / 86_400 + 719_468;
+    let seconds = time % 86_400;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+
+    let date = format!("{year:04}{month:02}{day:02}");
+    let date_time = format!(
+        "{date}T{:02}{:02}{:02}Z",
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    (date, date_time)
+}
+
+/// The key deriving Signature Version 4 signatures of the day, region and service
+fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    [date, region, service, "aws4_request"].iter().fold(
+        format!("AWS4{secret_access_key}").into_bytes(),
+        |key, data| hmac_sha256(&key, data.as_bytes()),
+    )
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("hmac takes keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::signature::Keypair;
+
+    use super::*;
+    use crate::http::{test_server::serve, ED25519_SPKI_PREFIX};
+
+    fn credentials() -> AwsCredentials {
+        AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: None,
+        }
+    }
+
+    /// Serves the GetPublicKey and Sign actions of a KMS endpoint holding the keypair
+    fn serve_kms(keypair: Keypair) -> String {
+        serve(move |request| {
+            let authorization = request.header("authorization").unwrap_or_default();
+            if !authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/")
+                || request.header("content-type") != Some(CONTENT_TYPE)
+            {
+                return (
+                    "400 Bad Request",
+                    json!({ "__type": "UnrecognizedClientException" }),
+                );
+            }
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            if body["KeyId"] != "alias/admin" {
+                return ("400 Bad Request", json!({ "__type": "NotFoundException" }));
+            }
+            match request.header("x-amz-target") {
+                Some("TrentService.GetPublicKey") => {
+                    let der = [ED25519_SPKI_PREFIX.as_slice(), keypair.pubkey().as_ref()].concat();
+                    (
+                        "200 OK",
+                        json!({ "KeySpec": "ECC_NIST_EDWARDS25519", "PublicKey": STANDARD.encode(der) }),
+                    )
+                }
+                Some("TrentService.Sign") => {
+                    let message = STANDARD.decode(body["Message"].as_str().unwrap()).unwrap();
+                    let signature = STANDARD.encode(keypair.sign_message(&message));
+                    ("200 OK", json!({ "Signature": signature }))
+                }
+                _ => (
+                    "400 Bad Request",
+                    json!({ "__type": "UnknownOperationException" }),
+                ),
+            }
+        })
+    }
+
+    #[test]
+    fn test_aws_kms_signer_ok() {
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let endpoint = serve_kms(keypair);
+
+        let signer =
+            AwsKmsSigner::new(&endpoint, "us-east-1", "alias/admin", credentials()).unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+
+        let signature = signer.try_sign_message(b"message").unwrap();
+        assert!(signature.verify(pubkey.as_ref(), b"message"));
+    }
+
+    #[test]
+    fn test_aws_kms_signer_unknown_key_fails() {
+        let endpoint = serve_kms(Keypair::new());
+        assert!(matches!(
+            AwsKmsSigner::new(&endpoint, "us-east-1", "alias/other", credentials()),
+            Err(SignerError::Protocol(_))
+        ));
+    }
+
+    #[test]
+    fn test_amz_date() {
+        assert_eq!(
+            amz_date(0),
+            ("19700101".to_string(), "19700101T000000Z".to_string())
+        );
+        assert_eq!(
+            amz_date(1_329_264_000),
+            ("20120215".to_string(), "20120215T000000Z".to_string())
+        );
+        assert_eq!(
+            amz_date(1_440_938_160),
+            ("20150830".to_string(), "20150830T123600Z".to_string())
+        );
+        assert_eq!(
+            amz_date(1_709_208_000),
+            ("20240229".to_string(), "20240229T120000Z".to_string())
+        );
+    }
+
+    #[test]
+    fn test_signing_key() {
+        // the example of deriving a signing key in the AWS Signature Version 4 documentation
+        assert_eq!(
+            hex(&signing_key(
+                "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+                "20120215",
+                "us-east-1",
+                "iam"
+            )),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn test_signed_headers_include_session_token() {
+        let signer = AwsKmsSigner {
+            endpoint: "https://kms.us-east-1.amazonaws.com".to_string(),
+            region: "us-east-1".to_string(),
+            key_id: "alias/admin".to_string(),
+            credentials: AwsCredentials {
+                session_token: Some("session".to_string()),
+                ..credentials()
+            },
+            pubkey: Pubkey::default(),
+        };
+        let headers = signer.signed_headers("Sign", "{}", 1_440_938_160);
+        assert_eq!(
+            headers[1],
+            ("host", "kms.us-east-1.amazonaws.com".to_string())
+        );
+        assert_eq!(headers[3], ("x-amz-security-token", "session".to_string()));
+        let (_, authorization) = headers.last().unwrap();
+        assert!(authorization.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/kms/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-date;x-amz-security-token;x-amz-target, \
+             Signature="
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+//! A [`Signer`] holding its ed25519 key in Google Cloud KMS.
+//!
+//! The key is created in Cloud KMS with purpose `ASYMMETRIC_SIGN` and algorithm
+//! `EC_SIGN_ED25519`, and never leaves it: the public key of the key version is read when the
+//! signer is created, and each message is signed by `asymmetricSign` over the raw message.
+//! Requests authenticate with the OAuth access token in `GOOGLE_OAUTH_ACCESS_TOKEN`, as printed by
+//! `gcloud auth print-access-token`, which needs `cloudkms.cryptoKeyVersions.viewPublicKey` and
+//! `cloudkms.cryptoKeyVersions.useToSign` on the key.
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::{Signer, SignerError},
+};
+
+use crate::http::{decode_base64, ed25519_spki_pubkey, send_json};
+
+/// The environment variable holding the OAuth access token
+pub const GOOGLE_OAUTH_ACCESS_TOKEN_ENV: &str = "GOOGLE_OAUTH_ACCESS_TOKEN";
+
+/// The endpoint of the Cloud KMS API
+pub const GCP_KMS_ENDPOINT: &str = "https://cloudkms.googleapis.com";
+
+#[derive(Deserialize)]
+struct PublicKeyData {
+    pem: String,
+}
+
+#[derive(Deserialize)]
+struct SignData {
+    signature: String,
+}
+
+#[derive(Debug)]
+pub struct GcpKmsSigner {
+    endpoint: String,
+    key_version: String,
+    token: String,
+    pubkey: Pubkey,
+}
+
+impl GcpKmsSigner {
+    /// Loads the public key of the key version from the endpoint, `key_version` being its
+    /// resource name, as in
+    /// `projects/<project>/locations/<location>/keyRings/<ring>/cryptoKeys/<key>/cryptoKeyVersions/<version>`
+    pub fn new(endpoint: &str, key_version: &str, token: &str) -> Result<Self, SignerError> {
+        let mut signer = Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            key_version: key_version.to_string(),
+            token: token.to_string(),
+            pubkey: Pubkey::default(),
+        };
+        signer.pubkey = signer.load_pubkey()?;
+        Ok(signer)
+    }
+
+    /// Like [`Self::new`], with the [`GCP_KMS_ENDPOINT`] and the token read from
+    /// [`GOOGLE_OAUTH_ACCESS_TOKEN_ENV`]
+    pub fn from_env(key_version: &str) -> Result<Self, SignerError> {
+        let token = std::env::var(GOOGLE_OAUTH_ACCESS_TOKEN_ENV).map_err(|_| {
+            SignerError::Custom(format!("{GOOGLE_OAUTH_ACCESS_TOKEN_ENV} is not set"))
+        })?;
+        Self::new(GCP_KMS_ENDPOINT, key_version, &token)
+    }
+
+    fn load_pubkey(&self) -> Result<Pubkey, SignerError> {
+        let key: PublicKeyData = self.request("/publicKey", None)?;
+        let encoded: String = key
+            .pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .collect();
+        ed25519_spki_pubkey("gcp kms", &decode(&encoded)?)
+    }
+
+    /// Sends the request for the method of the key version, a GET without a body and a POST with
+    /// it
+    fn request<T: DeserializeOwned + Send>(
+        &self,
+        method: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<T, SignerError> {
+        let url = format!("{}/v1/{}{method}", self.endpoint, self.key_version);
+        send_json("gcp kms", method, |client| {
+            let request = match &body {
+                Some(body) => client.post(&url).json(body),
+                None => client.get(&url),
+            };
+            request.bearer_auth(&self.token)
+        })
+    }
+}
+
+impl Signer for GcpKmsSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let sign: SignData = self.request(
+            ":asymmetricSign",
+            Some(json!({ "data": STANDARD.encode(message) })),
+        )?;
+        let signature = Signature::try_from(decode(&sign.signature)?)
+            .map_err(|_| SignerError::Protocol("gcp kms returned an invalid signature".into()))?;
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::KeypairPubkeyMismatch);
+        }
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+fn decode(encoded: &str) -> Result<Vec<u8>, SignerError> {
+    decode_base64("gcp kms", encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::signature::Keypair;
+
+    use super::*;
+    use crate::http::{test_server::serve, ED25519_SPKI_PREFIX};
+
+    const KEY_VERSION: &str =
+        "projects/jito/locations/global/keyRings/restaking/cryptoKeys/admin/cryptoKeyVersions/1";
+
+    /// Serves the publicKey and asymmetricSign methods of a key version holding the keypair
+    fn serve_kms(keypair: Keypair, token: &'static str) -> String {
+        serve(move |request| {
+            if request.header("authorization") != Some(&format!("Bearer {token}")) {
+                ("401 Unauthorized", json!({ "error": { "code": 401 } }))
+            } else if request.route == format!("GET /v1/{KEY_VERSION}/publicKey") {
+                let der = [ED25519_SPKI_PREFIX.as_slice(), keypair.pubkey().as_ref()].concat();
+                let pem = format!(
+                    "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----\n",
+                    STANDARD.encode(der)
+                );
+                (
+                    "200 OK",
+                    json!({ "pem": pem, "algorithm": "EC_SIGN_ED25519" }),
+                )
+            } else if request.route == format!("POST /v1/{KEY_VERSION}:asymmetricSign") {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                let data = STANDARD.decode(body["data"].as_str().unwrap()).unwrap();
+                let signature = STANDARD.encode(keypair.sign_message(&data));
+                ("200 OK", json!({ "signature": signature }))
+            } else {
+                ("404 Not Found", json!({ "error": { "code": 404 } }))
+            }
+        })
+    }
+
+    #[test]
+    fn test_gcp_kms_signer_ok() {
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let endpoint = serve_kms(keypair, "token");
+
+        let signer = GcpKmsSigner::new(&endpoint, KEY_VERSION, "token").unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+
+        let signature = signer.try_sign_message(b"message").unwrap();
+        assert!(signature.verify(pubkey.as_ref(), b"message"));
+    }
+
+    #[test]
+    fn test_gcp_kms_signer_bad_token_fails() {
+        let endpoint = serve_kms(Keypair::new(), "token");
+        assert!(matches!(
+            GcpKmsSigner::new(&endpoint, KEY_VERSION, "other"),
+            Err(SignerError::Protocol(_))
+        ));
+    }
+}
@@ -0,0 +1,151 @@
+//! The plumbing shared by the remote signers: blocking JSON requests and the encodings the
+//! services return keys and signatures in.
+//!
+//! Requests are blocking. Each one is sent from its own thread, as reqwest's blocking client
+//! refuses to run on the thread of an async runtime and the CLI signs from one.
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::blocking::{Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+use solana_sdk::{pubkey::Pubkey, signer::SignerError};
+
+/// The DER prefix of an ed25519 public key in a SubjectPublicKeyInfo, followed by the 32 bytes of
+/// the key
+pub(crate) const ED25519_SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+
+/// Sends the request built from a new client and parses the JSON body of a successful response.
+/// `service` names the remote service in errors.
+pub(crate) fn send_json<T: DeserializeOwned + Send>(
+    service: &str,
+    path: &str,
+    build: impl FnOnce(&Client) -> RequestBuilder + Send,
+) -> Result<T, SignerError> {
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let response = build(&Client::new())
+                    .send()
+                    .map_err(|e| SignerError::Connection(e.to_string()))?;
+                if !response.status().is_success() {
+                    return Err(SignerError::Protocol(format!(
+                        "{service} responded {} to {path}",
+                        response.status()
+                    )));
+                }
+                response
+                    .json::<T>()
+                    .map_err(|e| SignerError::Protocol(e.to_string()))
+            })
+            .join()
+            .unwrap_or_else(|_| Err(SignerError::Custom(format!("{service} request panicked"))))
+    })
+}
+
+/// Decodes standard base64 returned by the service
+pub(crate) fn decode_base64(service: &str, encoded: &str) -> Result<Vec<u8>, SignerError> {
+    STANDARD
+        .decode(encoded)
+        .map_err(|e| SignerError::Protocol(format!("{service} returned invalid base64: {e}")))
+}
+
+/// The public key of a DER SubjectPublicKeyInfo, which shall hold an ed25519 key
+pub(crate) fn ed25519_spki_pubkey(service: &str, der: &[u8]) -> Result<Pubkey, SignerError> {
+    der.strip_prefix(ED25519_SPKI_PREFIX.as_slice())
+        .and_then(|key| Pubkey::try_from(key).ok())
+        .ok_or_else(|| SignerError::Protocol(format!("{service} returned a non-ed25519 key")))
+}
+
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+    };
+
+    /// A request received by the test server
+    pub struct Request {
+        /// The method and path, as in `GET /v1/transit/keys/admin`
+        pub route: String,
+        /// The headers, with lowercase names
+        pub headers: Vec<(String, String)>,
+        pub body: Vec<u8>,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(header, _)| header == name)
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Serves each request with the status and JSON body the handler responds with, returning the
+    /// address of the server
+    pub fn serve(
+        handler: impl Fn(&Request) -> (&'static str, serde_json::Value) + Send + 'static,
+    ) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let route = request_line
+                    .rsplit_once(' ')
+                    .map(|(route, _)| route.to_string())
+                    .unwrap_or_default();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let Some((name, value)) = header.trim_end().split_once(": ") else {
+                        break;
+                    };
+                    headers.push((name.to_ascii_lowercase(), value.to_string()));
+                }
+                let mut request = Request {
+                    route,
+                    headers,
+                    body: Vec::new(),
+                };
+                let content_length = request
+                    .header("content-length")
+                    .map_or(0, |length| length.parse().unwrap());
+                request.body = vec![0; content_length];
+                reader.read_exact(&mut request.body).unwrap();
+
+                let (status, data) = handler(&request);
+                let data = data.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{data}",
+                    data.len()
+                )
+                .unwrap();
+            }
+        });
+        address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ed25519_spki_pubkey() {
+        let pubkey = Pubkey::new_unique();
+        let der = [ED25519_SPKI_PREFIX.as_slice(), pubkey.as_ref()].concat();
+        assert_eq!(ed25519_spki_pubkey("kms", &der).unwrap(), pubkey);
+
+        // a P-256 key isn't an ed25519 key
+        let mut der = der;
+        der[8] = 0x71;
+        assert!(ed25519_spki_pubkey("kms", &der).is_err());
+        assert!(ed25519_spki_pubkey("kms", &ED25519_SPKI_PREFIX).is_err());
+    }
+}
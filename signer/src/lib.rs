@@ -0,0 +1,127 @@
+//! Off-chain signing for the restaking tooling: unsigned transactions whose signatures are
+//! collected from external signers, and [`Signer`]s whose keys never leave a remote service.
+//!
+//! A remote signer is named by a URI, so it can be passed wherever a keypair path is accepted:
+//! - `vault://<address>/<key_name>`, an ed25519 key of a HashiCorp Vault transit engine, see
+//!   [`vault_transit`]
+//! - `awskms://<region>/<key_id>`, an ed25519 key of AWS KMS named by its id, ARN or alias, see
+//!   [`aws_kms`]
+//! - `gcpkms://projects/<project>/.../cryptoKeyVersions/<version>`, an ed25519 key version of
+//!   Google Cloud KMS, see [`gcp_kms`]
+pub mod aws_kms;
+pub mod gcp_kms;
+mod http;
+pub mod message;
+pub mod vault_transit;
+
+use solana_sdk::signer::{Signer, SignerError};
+
+use crate::{aws_kms::AwsKmsSigner, gcp_kms::GcpKmsSigner, vault_transit::VaultTransitSigner};
+
+/// Identifies a remote signing backend and the key it holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteSignerConfig {
+    /// A HashiCorp Vault transit key
+    Vault { address: String, key_name: String },
+    /// An AWS KMS key
+    AwsKms { region: String, key_id: String },
+    /// A Google Cloud KMS key version, named by its resource name
+    GcpKms { key_version: String },
+}
+
+impl RemoteSignerConfig {
+    /// Parses a signer URI, returning `None` when it doesn't name a remote backend
+    pub fn parse(uri: &str) -> Option<Self> {
+        let (scheme, rest) = uri.split_once("://")?;
+        match scheme {
+            "vault" => {
+                let (address, key_name) = rest.rsplit_once('/')?;
+                if address.is_empty() || key_name.is_empty() {
+                    return None;
+                }
+                Some(Self::Vault {
+                    address: address.to_string(),
+                    key_name: key_name.to_string(),
+                })
+            }
+            "awskms" => {
+                let (region, key_id) = rest.split_once('/')?;
+                if region.is_empty() || key_id.is_empty() {
+                    return None;
+                }
+                Some(Self::AwsKms {
+                    region: region.to_string(),
+                    key_id: key_id.to_string(),
+                })
+            }
+            "gcpkms" => {
+                let (_, version) = rest.split_once("/cryptoKeyVersions/")?;
+                if !rest.starts_with("projects/") || version.is_empty() || version.contains('/') {
+                    return None;
+                }
+                Some(Self::GcpKms {
+                    key_version: rest.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Connects to the backend and loads the public key of the remote keypair
+    pub fn signer(&self) -> Result<Box<dyn Signer>, SignerError> {
+        match self {
+            Self::Vault { address, key_name } => {
+                Ok(Box::new(VaultTransitSigner::from_env(address, key_name)?))
+            }
+            Self::AwsKms { region, key_id } => {
+                Ok(Box::new(AwsKmsSigner::from_env(region, key_id)?))
+            }
+            Self::GcpKms { key_version } => Ok(Box::new(GcpKmsSigner::from_env(key_version)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_remote_signer_config() {
+        assert_eq!(
+            RemoteSignerConfig::parse("vault://https://vault.internal:8200/admin"),
+            Some(RemoteSignerConfig::Vault {
+                address: "https://vault.internal:8200".to_string(),
+                key_name: "admin".to_string(),
+            })
+        );
+        assert_eq!(RemoteSignerConfig::parse("vault://admin"), None);
+        assert_eq!(RemoteSignerConfig::parse("vault://https://vault/"), None);
+        assert_eq!(
+            RemoteSignerConfig::parse(
+                "awskms://us-east-1/arn:aws:kms:us-east-1:111122223333:key/1234abcd"
+            ),
+            Some(RemoteSignerConfig::AwsKms {
+                region: "us-east-1".to_string(),
+                key_id: "arn:aws:kms:us-east-1:111122223333:key/1234abcd".to_string(),
+            })
+        );
+        assert_eq!(RemoteSignerConfig::parse("awskms://us-east-1/"), None);
+        assert_eq!(RemoteSignerConfig::parse("awskms://us-east-1"), None);
+        let key_version =
+            "projects/jito/locations/global/keyRings/restaking/cryptoKeys/admin/cryptoKeyVersions/1";
+        assert_eq!(
+            RemoteSignerConfig::parse(&format!("gcpkms://{key_version}")),
+            Some(RemoteSignerConfig::GcpKms {
+                key_version: key_version.to_string(),
+            })
+        );
+        assert_eq!(
+            RemoteSignerConfig::parse(
+                "gcpkms://projects/jito/locations/global/keyRings/restaking/cryptoKeys/admin"
+            ),
+            None
+        );
+        assert_eq!(RemoteSignerConfig::parse("prompt://"), None);
+        assert_eq!(RemoteSignerConfig::parse("admin.json"), None);
+    }
+}
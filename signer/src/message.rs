@@ -0,0 +1,263 @@
+//! Helpers for building unsigned transaction messages that are signed outside of the process
+//! constructing them, such as by a browser wallet or a KMS.
+//!
+//! A backend builds a [`VersionedMessage`] from instructions produced by the SDKs, hands
+//! [`UnsignedTransaction::message_data`] to each signer, inserts the returned signatures and
+//! serializes the transaction into the wire format expected by `sendTransaction`. Signers
+//! implementing [`Signer`], such as the [`crate::vault_transit`] signer, sign it in place with
+//! [`UnsignedTransaction::sign`].
+use solana_sdk::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, CompileError, Message, VersionedMessage},
+    pubkey::Pubkey,
+    signer::{Signer, SignerError},
+};
+
+/// Length of an ed25519 signature in bytes
+pub const SIGNATURE_LENGTH: usize = 64;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MessageError {
+    Compile(CompileError),
+    UnknownSigner(Pubkey),
+    MissingSignature(Pubkey),
+    Signer(SignerError),
+}
+
+impl From<CompileError> for MessageError {
+    fn from(value: CompileError) -> Self {
+        Self::Compile(value)
+    }
+}
+
+impl From<SignerError> for MessageError {
+    fn from(value: SignerError) -> Self {
+        Self::Signer(value)
+    }
+}
+
+/// Builds a legacy message paid for by `payer`
+pub fn build_legacy_message(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    recent_blockhash: &Hash,
+) -> VersionedMessage {
+    VersionedMessage::Legacy(Message::new_with_blockhash(
+        instructions,
+        Some(payer),
+        recent_blockhash,
+    ))
+}
+
+/// Builds a v0 message paid for by `payer`, compressing account keys found in the lookup tables
+pub fn build_v0_message(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    address_lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: &Hash,
+) -> Result<VersionedMessage, MessageError> {
+    Ok(VersionedMessage::V0(v0::Message::try_compile(
+        payer,
+        instructions,
+        address_lookup_tables,
+        *recent_blockhash,
+    )?))
+}
+
+/// A transaction whose signatures are collected from external signers
+#[derive(Debug, Clone)]
+pub struct UnsignedTransaction {
+    message: VersionedMessage,
+    signatures: Vec<Option<[u8; SIGNATURE_LENGTH]>>,
+}
+
+impl UnsignedTransaction {
+    pub fn new(message: VersionedMessage) -> Self {
+        let num_signers = message.header().num_required_signatures as usize;
+        Self {
+            message,
+            signatures: vec![None; num_signers],
+        }
+    }
+
+    pub const fn message(&self) -> &VersionedMessage {
+        &self.message
+    }
+
+    /// The bytes each signer shall sign
+    pub fn message_data(&self) -> Vec<u8> {
+        self.message.serialize()
+    }
+
+    /// The signers required by the message, in signature order. The first signer is the fee payer.
+    pub fn signers(&self) -> &[Pubkey] {
+        &self.message.static_account_keys()[..self.signatures.len()]
+    }
+
+    /// The signers that haven't provided a signature yet
+    pub fn missing_signers(&self) -> Vec<Pubkey> {
+        self.signers()
+            .iter()
+            .zip(self.signatures.iter())
+            .filter(|(_, signature)| signature.is_none())
+            .map(|(signer, _)| *signer)
+            .collect()
+    }
+
+    pub fn is_fully_signed(&self) -> bool {
+        self.signatures.iter().all(Option::is_some)
+    }
+
+    /// Inserts the signature produced by `signer` over [`Self::message_data`]
+    pub fn insert_signature(
+        &mut self,
+        signer: &Pubkey,
+        signature: [u8; SIGNATURE_LENGTH],
+    ) -> Result<(), MessageError> {
+        let index = self
+            .signers()
+            .iter()
+            .position(|key| key == signer)
+            .ok_or(MessageError::UnknownSigner(*signer))?;
+        self.signatures[index] = Some(signature);
+        Ok(())
+    }
+
+    /// Requests a signature over [`Self::message_data`] from each signer and inserts it
+    pub fn sign(&mut self, signers: &[&dyn Signer]) -> Result<(), MessageError> {
+        let message_data = self.message_data();
+        for signer in signers {
+            let signature = signer.try_sign_message(&message_data)?;
+            self.insert_signature(&signer.try_pubkey()?, signature.into())?;
+        }
+        Ok(())
+    }
+
+    /// Serializes the signed transaction into the wire format accepted by RPC nodes
+    pub fn serialize(&self) -> Result<Vec<u8>, MessageError> {
+        if let Some(signer) = self.missing_signers().first() {
+            return Err(MessageError::MissingSignature(*signer));
+        }
+
+        let mut data = Vec::new();
+        encode_short_u16(&mut data, self.signatures.len() as u16);
+        for signature in self.signatures.iter().flatten() {
+            data.extend_from_slice(signature);
+        }
+        data.extend_from_slice(&self.message_data());
+        Ok(data)
+    }
+}
+
+/// Encodes a length using the compact-u16 format used for transaction arrays
+fn encode_short_u16(data: &mut Vec<u8>, mut value: u16) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            data.push(byte);
+            return;
+        }
+        byte |= 0x80;
+        data.push(byte);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signature},
+    };
+
+    use super::*;
+
+    fn test_instruction(signer: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![AccountMeta::new_readonly(*signer, true)],
+            data: vec![],
+        }
+    }
+
+    #[test]
+    fn test_unsigned_transaction_serialize_ok() {
+        let payer = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+        let message =
+            build_legacy_message(&[test_instruction(&admin)], &payer, &Hash::new_unique());
+
+        let mut transaction = UnsignedTransaction::new(message);
+        assert_eq!(transaction.signers(), &[payer, admin]);
+
+        transaction.insert_signature(&admin, [2; 64]).unwrap();
+        assert_eq!(transaction.missing_signers(), vec![payer]);
+        assert_eq!(
+            transaction.serialize(),
+            Err(MessageError::MissingSignature(payer))
+        );
+
+        transaction.insert_signature(&payer, [1; 64]).unwrap();
+        let data = transaction.serialize().unwrap();
+        assert_eq!(data[0], 2);
+        assert_eq!(&data[1..65], &[1; 64]);
+        assert_eq!(&data[65..129], &[2; 64]);
+        assert_eq!(&data[129..], transaction.message_data().as_slice());
+    }
+
+    #[test]
+    fn test_insert_signature_unknown_signer_fails() {
+        let payer = Pubkey::new_unique();
+        let message = build_v0_message(
+            &[test_instruction(&payer)],
+            &payer,
+            &[],
+            &Hash::new_unique(),
+        )
+        .unwrap();
+
+        let mut transaction = UnsignedTransaction::new(message);
+        let signer = Pubkey::new_unique();
+        assert_eq!(
+            transaction.insert_signature(&signer, [1; 64]),
+            Err(MessageError::UnknownSigner(signer))
+        );
+    }
+
+    #[test]
+    fn test_sign_ok() {
+        let payer = Keypair::new();
+        let message = build_legacy_message(
+            &[test_instruction(&payer.pubkey())],
+            &payer.pubkey(),
+            &Hash::new_unique(),
+        );
+
+        let mut transaction = UnsignedTransaction::new(message);
+        transaction.sign(&[&payer]).unwrap();
+        assert!(transaction.is_fully_signed());
+
+        let data = transaction.serialize().unwrap();
+        let signature = Signature::try_from(&data[1..65]).unwrap();
+        assert!(signature.verify(payer.pubkey().as_ref(), &transaction.message_data()));
+    }
+
+    #[test]
+    fn test_encode_short_u16() {
+        let mut data = Vec::new();
+        encode_short_u16(&mut data, 0x7f);
+        assert_eq!(data, vec![0x7f]);
+
+        let mut data = Vec::new();
+        encode_short_u16(&mut data, 0x80);
+        assert_eq!(data, vec![0x80, 0x01]);
+
+        let mut data = Vec::new();
+        encode_short_u16(&mut data, 0xffff);
+        assert_eq!(data, vec![0xff, 0xff, 0x03]);
+    }
+}
@@ -0,0 +1,206 @@
+//! A [`Signer`] holding its ed25519 key in the transit secrets engine of HashiCorp Vault.
+//!
+//! The key is created in Vault with `vault write transit/keys/<key_name> type=ed25519` and never
+//! leaves it: the public key of its latest version is read when the signer is created, and each
+//! message is signed by `transit/sign/<key_name>`. Requests authenticate with the token in
+//! `VAULT_TOKEN`, which needs read on the key and update on the sign path.
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::{Signer, SignerError},
+};
+
+use crate::http::{decode_base64, send_json};
+
+/// The environment variable holding the Vault token
+pub const VAULT_TOKEN_ENV: &str = "VAULT_TOKEN";
+
+/// The path the transit engine is mounted at
+const TRANSIT_MOUNT: &str = "transit";
+
+#[derive(Deserialize)]
+struct VaultResponse<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct KeyData {
+    #[serde(rename = "type")]
+    key_type: String,
+    latest_version: u32,
+    keys: HashMap<String, KeyVersion>,
+}
+
+#[derive(Deserialize)]
+struct KeyVersion {
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+struct SignData {
+    signature: String,
+}
+
+#[derive(Debug)]
+pub struct VaultTransitSigner {
+    address: String,
+    key_name: String,
+    token: String,
+    pubkey: Pubkey,
+}
+
+impl VaultTransitSigner {
+    /// Loads the public key of the transit key from the Vault server at `address`
+    pub fn new(address: &str, key_name: &str, token: &str) -> Result<Self, SignerError> {
+        let mut signer = Self {
+            address: address.trim_end_matches('/').to_string(),
+            key_name: key_name.to_string(),
+            token: token.to_string(),
+            pubkey: Pubkey::default(),
+        };
+        signer.pubkey = signer.load_pubkey()?;
+        Ok(signer)
+    }
+
+    /// Like [`Self::new`], with the token read from [`VAULT_TOKEN_ENV`]
+    pub fn from_env(address: &str, key_name: &str) -> Result<Self, SignerError> {
+        let token = std::env::var(VAULT_TOKEN_ENV)
+            .map_err(|_| SignerError::Custom(format!("{VAULT_TOKEN_ENV} is not set")))?;
+        Self::new(address, key_name, &token)
+    }
+
+    fn load_pubkey(&self) -> Result<Pubkey, SignerError> {
+        let key: KeyData = self.request(&format!("keys/{}", self.key_name), None)?;
+        if key.key_type != "ed25519" {
+            return Err(SignerError::Custom(format!(
+                "vault key {} is {}, not ed25519",
+                self.key_name, key.key_type
+            )));
+        }
+        let public_key = key
+            .keys
+            .get(&key.latest_version.to_string())
+            .ok_or_else(|| SignerError::Protocol("vault returned no latest key version".into()))?;
+        let bytes = decode(&public_key.public_key)?;
+        Pubkey::try_from(bytes.as_slice())
+            .map_err(|_| SignerError::Protocol("vault returned an invalid public key".into()))
+    }
+
+    /// Sends the request to the transit engine and returns the data of the response, a GET
+    /// without a body and a POST with it
+    fn request<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<T, SignerError> {
+        let url = format!("{}/v1/{TRANSIT_MOUNT}/{path}", self.address);
+        let response: VaultResponse<T> = send_json("vault", path, |client| {
+            let request = match &body {
+                Some(body) => client.post(&url).json(body),
+                None => client.get(&url),
+            };
+            request.header("X-Vault-Token", &self.token)
+        })?;
+        Ok(response.data)
+    }
+}
+
+impl Signer for VaultTransitSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let sign: SignData = self.request(
+            &format!("sign/{}", self.key_name),
+            Some(json!({ "input": STANDARD.encode(message) })),
+        )?;
+        // signatures are prefixed with the key version, as in vault:v1:<base64>
+        let encoded = sign.signature.rsplit(':').next().unwrap_or_default();
+        let signature = Signature::try_from(decode(encoded)?)
+            .map_err(|_| SignerError::Protocol("vault returned an invalid signature".into()))?;
+        // a rotated key would sign with a version that no longer matches the loaded public key
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::KeypairPubkeyMismatch);
+        }
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+fn decode(encoded: &str) -> Result<Vec<u8>, SignerError> {
+    decode_base64("vault", encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::signature::Keypair;
+
+    use super::*;
+    use crate::http::test_server::serve;
+
+    /// Serves the transit key and sign endpoints of a Vault server holding the keypair
+    fn serve_transit(keypair: Keypair, token: &'static str) -> String {
+        serve(move |request| {
+            if request.header("x-vault-token") != Some(token) {
+                ("403 Forbidden", json!({ "errors": ["permission denied"] }))
+            } else if request.route == "GET /v1/transit/keys/admin" {
+                let public_key = STANDARD.encode(keypair.pubkey().to_bytes());
+                (
+                    "200 OK",
+                    json!({ "data": {
+                        "type": "ed25519",
+                        "latest_version": 1,
+                        "keys": { "1": { "public_key": public_key } },
+                    }}),
+                )
+            } else if request.route == "POST /v1/transit/sign/admin" {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                let input = STANDARD.decode(body["input"].as_str().unwrap()).unwrap();
+                let signature = STANDARD.encode(keypair.sign_message(&input));
+                (
+                    "200 OK",
+                    json!({ "data": { "signature": format!("vault:v1:{signature}") } }),
+                )
+            } else {
+                ("404 Not Found", json!({ "errors": [] }))
+            }
+        })
+    }
+
+    #[test]
+    fn test_vault_transit_signer_ok() {
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let address = serve_transit(keypair, "token");
+
+        let signer = VaultTransitSigner::new(&address, "admin", "token").unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+
+        let signature = signer.try_sign_message(b"message").unwrap();
+        assert!(signature.verify(pubkey.as_ref(), b"message"));
+    }
+
+    #[test]
+    fn test_vault_transit_signer_bad_token_fails() {
+        let address = serve_transit(Keypair::new(), "token");
+        assert!(matches!(
+            VaultTransitSigner::new(&address, "admin", "other"),
+            Err(SignerError::Protocol(_))
+        ));
+    }
+
+    #[test]
+    fn test_vault_transit_signer_unknown_key_fails() {
+        let address = serve_transit(Keypair::new(), "token");
+        assert!(VaultTransitSigner::new(&address, "operator", "token").is_err());
+    }
+}
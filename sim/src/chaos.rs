@@ -0,0 +1,279 @@
+//! Replays of action sequences under adverse scheduling.
+//!
+//! Nothing guarantees a crank lands in the slot it was sent in, or at all, nor that transactions
+//! of a slot execute in the order they were sent. [`ChaosConfig::perturb`] rewrites a sequence
+//! accordingly: the actions between two [`VaultAction::WarpSlots`] form a slot, each
+//! [`VaultAction::UpdateDelegations`] crank may be dropped or pushed to the next slot, and the
+//! actions of a slot may be shuffled. [`run`] replays the result, stopping at the first
+//! invariant violation; actions the simulation rejects, like failed transactions, are counted and
+//! skipped. The same seed always yields the same sequence, so a failing run can be replayed.
+use crate::{SimError, SimResult, VaultAction, VaultSim};
+
+const MAX_BPS: u64 = 10_000;
+
+/// How a sequence of actions is perturbed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChaosConfig {
+    pub seed: u64,
+    /// The chance a crank is dropped
+    pub skip_crank_bps: u16,
+    /// The chance a crank that isn't dropped runs in the next slot instead
+    pub delay_crank_bps: u16,
+    /// Whether the actions of a slot are shuffled
+    pub reorder: bool,
+}
+
+/// What happened to a sequence of actions in a chaos run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChaosReport {
+    pub applied: usize,
+    /// Actions the simulation rejected
+    pub failed: usize,
+    pub skipped_cranks: usize,
+    pub delayed_cranks: usize,
+}
+
+impl ChaosConfig {
+    /// Rewrites `actions` as they could land on chain, counting the dropped and delayed cranks
+    pub fn perturb(&self, actions: &[VaultAction]) -> (Vec<VaultAction>, ChaosReport) {
+        let mut rng = ChaosRng::new(self.seed);
+        let mut report = ChaosReport::default();
+        let mut perturbed = Vec::with_capacity(actions.len());
+        let mut slot = Vec::new();
+        let mut delayed = Vec::new();
+
+        for action in actions {
+            match action {
+                VaultAction::UpdateDelegations => {
+                    if rng.chance(self.skip_crank_bps) {
+                        report.skipped_cranks = report.skipped_cranks.saturating_add(1);
+                    } else if rng.chance(self.delay_crank_bps) {
+                        report.delayed_cranks = report.delayed_cranks.saturating_add(1);
+                        delayed.push(action.clone());
+                    } else {
+                        slot.push(action.clone());
+                    }
+                }
+                VaultAction::WarpSlots { .. } => {
+                    self.flush_slot(&mut rng, &mut slot, &mut perturbed);
+                    perturbed.push(action.clone());
+                    slot.append(&mut delayed);
+                }
+                _ => slot.push(action.clone()),
+            }
+        }
+        // cranks delayed past the last slot still land eventually
+        slot.append(&mut delayed);
+        self.flush_slot(&mut rng, &mut slot, &mut perturbed);
+
+        (perturbed, report)
+    }
+
+    fn flush_slot(
+        &self,
+        rng: &mut ChaosRng,
+        slot: &mut Vec<VaultAction>,
+        perturbed: &mut Vec<VaultAction>,
+    ) {
+        if self.reorder {
+            rng.shuffle(slot);
+        }
+        perturbed.append(slot);
+    }
+}
+
+/// Replays `actions` perturbed by `config` on the simulation.
+///
+/// Besides the invariants [`VaultSim::apply`] checks, every crank that lands is checked to be
+/// idempotent, so running it twice never completes a cooldown twice.
+pub fn run(
+    sim: &mut VaultSim,
+    actions: &[VaultAction],
+    config: &ChaosConfig,
+) -> SimResult<ChaosReport> {
+    let (actions, mut report) = config.perturb(actions);
+
+    for action in &actions {
+        match sim.apply(action) {
+            Ok(()) => report.applied = report.applied.saturating_add(1),
+            Err(e @ SimError::InvariantViolated(_)) => return Err(e),
+            Err(_) => report.failed = report.failed.saturating_add(1),
+        }
+
+        if *action == VaultAction::UpdateDelegations {
+            let mut cranked_again = sim.clone();
+            cranked_again.apply(action)?;
+            if format!("{:?}", cranked_again.delegation_list())
+                != format!("{:?}", sim.delegation_list())
+            {
+                return Err(SimError::InvariantViolated(
+                    "repeating a crank changed the delegations",
+                ));
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// xorshift64, good enough to pick what goes wrong and reproducible from the seed alone
+struct ChaosRng(u64);
+
+impl ChaosRng {
+    const fn new(seed: u64) -> Self {
+        // xorshift never leaves zero
+        Self(if seed == 0 {
+            0x2545_f491_4f6c_dd1d
+        } else {
+            seed
+        })
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn chance(&mut self, bps: u16) -> bool {
+        self.next() % MAX_BPS < u64::from(bps)
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self
+                .next()
+                .checked_rem((i as u64).saturating_add(1))
+                .unwrap_or_default() as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        chaos::{run, ChaosConfig},
+        VaultAction, VaultSim,
+    };
+
+    const ALICE: Pubkey = Pubkey::new_from_array([10; 32]);
+    const BOB: Pubkey = Pubkey::new_from_array([11; 32]);
+    const OPERATOR: Pubkey = Pubkey::new_from_array([12; 32]);
+
+    /// Deposits, delegates and undelegates every slot, with a crank after each slot
+    fn schedule() -> Vec<VaultAction> {
+        let mut actions = vec![
+            VaultAction::Airdrop {
+                owner: ALICE,
+                amount: 1_000_000,
+            },
+            VaultAction::Airdrop {
+                owner: BOB,
+                amount: 1_000_000,
+            },
+        ];
+        for i in 0..100_u64 {
+            let amount = 1_000 + i * 37 % 500;
+            actions.extend([
+                VaultAction::MintTo {
+                    depositor: if i % 2 == 0 { ALICE } else { BOB },
+                    amount,
+                },
+                VaultAction::AddDelegation {
+                    operator: OPERATOR,
+                    amount: amount / 2,
+                },
+                VaultAction::RemoveDelegation {
+                    operator: OPERATOR,
+                    amount: amount / 4,
+                },
+                VaultAction::Withdraw {
+                    staker: ALICE,
+                    lrt_amount: amount / 10,
+                },
+                VaultAction::SetEmergencyMode {
+                    enabled: i % 25 == 0,
+                },
+                VaultAction::UpdateDelegations,
+                VaultAction::WarpSlots { slots: 30 },
+            ]);
+        }
+        actions
+    }
+
+    #[test]
+    fn test_perturb_is_reproducible() {
+        let config = ChaosConfig {
+            seed: 7,
+            skip_crank_bps: 2_000,
+            delay_crank_bps: 2_000,
+            reorder: true,
+        };
+        let actions = schedule();
+
+        let (perturbed, report) = config.perturb(&actions);
+        assert_eq!(config.perturb(&actions), (perturbed.clone(), report));
+        assert_eq!(perturbed.len() + report.skipped_cranks, actions.len());
+        assert!(report.skipped_cranks > 0);
+        assert!(report.delayed_cranks > 0);
+        assert_ne!(perturbed, actions);
+    }
+
+    #[test]
+    fn test_perturb_keeps_actions_in_their_slot() {
+        let config = ChaosConfig {
+            seed: 11,
+            skip_crank_bps: 10_000,
+            delay_crank_bps: 0,
+            reorder: true,
+        };
+        let actions = schedule();
+
+        let (perturbed, report) = config.perturb(&actions);
+        assert_eq!(report.skipped_cranks, 100);
+        assert!(!perturbed.contains(&VaultAction::UpdateDelegations));
+        // shuffling never moves an action past a warp
+        let warps = |actions: &[VaultAction]| {
+            actions
+                .iter()
+                .filter(|action| matches!(action, VaultAction::WarpSlots { .. }))
+                .count()
+        };
+        assert_eq!(warps(&perturbed), warps(&actions));
+        for (expected, slot) in actions
+            .split(|action| matches!(action, VaultAction::WarpSlots { .. }))
+            .zip(perturbed.split(|action| matches!(action, VaultAction::WarpSlots { .. })))
+        {
+            let mut expected: Vec<_> = expected
+                .iter()
+                .filter(|action| **action != VaultAction::UpdateDelegations)
+                .map(|action| format!("{action:?}"))
+                .collect();
+            let mut slot: Vec<_> = slot.iter().map(|action| format!("{action:?}")).collect();
+            expected.sort();
+            slot.sort();
+            assert_eq!(slot, expected);
+        }
+    }
+
+    #[test]
+    fn test_chaos_keeps_invariants() {
+        for seed in 1..50 {
+            let config = ChaosConfig {
+                seed,
+                skip_crank_bps: 3_000,
+                delay_crank_bps: 3_000,
+                reorder: true,
+            };
+            let mut sim = VaultSim::new(50, 0, 100);
+            let report = run(&mut sim, &schedule(), &config).unwrap();
+
+            assert!(report.applied > 0);
+            sim.check_invariants().unwrap();
+        }
+    }
+}
@@ -0,0 +1,659 @@
+//! Deterministic off-chain simulation of the vault program.
+//!
+//! [`VaultSim`] keeps a vault, its delegation list and the token balances around it in memory and
+//! applies [`VaultAction`]s with the same business logic the vault program runs, without accounts,
+//! CPIs or a bank. Like a transaction, a failed action leaves the state untouched, and replaying
+//! the same actions always ends in the same state, so sequences found by a fuzzer can be replayed
+//! and shrunk. [`chaos`] replays them with cranks skipped or delayed and transactions reordered.
+pub mod chaos;
+
+use std::collections::BTreeMap;
+
+use jito_vault_core::{
+    result::VaultCoreError,
+    vault::{MintSummary, Vault},
+    vault_delegation_list::VaultDelegationList,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SimError {
+    #[error("vault error: {0:?}")]
+    Vault(VaultCoreError),
+    #[error("program error: {0}")]
+    Program(#[from] ProgramError),
+    #[error("{owner} has {balance} supported tokens, less than {amount}")]
+    InsufficientTokens {
+        owner: Pubkey,
+        balance: u64,
+        amount: u64,
+    },
+    #[error("{owner} has {balance} LRT, less than {amount}")]
+    InsufficientLrt {
+        owner: Pubkey,
+        balance: u64,
+        amount: u64,
+    },
+    #[error("balance overflow")]
+    Overflow,
+    #[error("invariant violated: {0}")]
+    InvariantViolated(&'static str),
+}
+
+impl From<VaultCoreError> for SimError {
+    fn from(value: VaultCoreError) -> Self {
+        Self::Vault(value)
+    }
+}
+
+pub type SimResult<T> = Result<T, SimError>;
+
+/// An action on the simulated vault. Most mirror a [`jito_vault_core`] instruction handler, the
+/// rest stand in for things that happen outside the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VaultAction {
+    /// Gives supported tokens to an owner
+    Airdrop {
+        owner: Pubkey,
+        amount: u64,
+    },
+
+    /// Sends supported tokens straight to the vault token account, bypassing the program
+    Donate {
+        amount: u64,
+    },
+
+    /// Deposits supported tokens and mints LRT, as `MintTo`
+    MintTo {
+        depositor: Pubkey,
+        amount: u64,
+    },
+
+    /// Burns LRT for the supported tokens backing it, as the source vault side of
+    /// `MigrateDeposit`
+    Withdraw {
+        staker: Pubkey,
+        lrt_amount: u64,
+    },
+
+    SetDepositCapacity {
+        capacity: u64,
+    },
+
+    SetEmergencyMode {
+        enabled: bool,
+    },
+
+    AddDelegation {
+        operator: Pubkey,
+        amount: u64,
+    },
+
+    RemoveDelegation {
+        operator: Pubkey,
+        amount: u64,
+    },
+
+    UpdateDelegations,
+
+    /// Harvests donations as rewards, as `UpdateVaultBalance`
+    UpdateVaultBalance,
+
+    /// Slashes stake delegated to the operator, moving the tokens out of the vault
+    Slash {
+        operator: Pubkey,
+        amount: u64,
+    },
+
+    /// Advances the clock
+    WarpSlots {
+        slots: u64,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct VaultSim {
+    vault: Vault,
+    delegation_list: VaultDelegationList,
+    epoch_length: u64,
+    slot: u64,
+    vault_token_balance: u64,
+    slashed: u64,
+    token_balances: BTreeMap<Pubkey, u64>,
+    lrt_balances: BTreeMap<Pubkey, u64>,
+}
+
+impl VaultSim {
+    /// The vault admin, which also owns the deposit fees
+    pub const ADMIN: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    const VAULT: Pubkey = Pubkey::new_from_array([2; 32]);
+
+    pub fn new(deposit_fee_bps: u16, withdrawal_fee_bps: u16, epoch_length: u64) -> Self {
+        let vault = Vault::new(
+            Pubkey::new_from_array([3; 32]),
+            Pubkey::new_from_array([4; 32]),
+            Self::ADMIN,
+            0,
+            Pubkey::new_from_array([5; 32]),
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            0,
+        );
+        Self {
+            vault,
+            delegation_list: VaultDelegationList::new(Self::VAULT, 0),
+            epoch_length: epoch_length.max(1),
+            slot: 0,
+            vault_token_balance: 0,
+            slashed: 0,
+            token_balances: BTreeMap::new(),
+            lrt_balances: BTreeMap::new(),
+        }
+    }
+
+    pub const fn vault(&self) -> &Vault {
+        &self.vault
+    }
+
+    pub const fn delegation_list(&self) -> &VaultDelegationList {
+        &self.delegation_list
+    }
+
+    pub const fn slot(&self) -> u64 {
+        self.slot
+    }
+
+    pub const fn epoch(&self) -> u64 {
+        self.slot / self.epoch_length
+    }
+
+    /// The balance of the vault token account, including donations not yet accounted for
+    pub const fn vault_token_balance(&self) -> u64 {
+        self.vault_token_balance
+    }
+
+    /// The supported tokens slashed out of the vault so far
+    pub const fn slashed(&self) -> u64 {
+        self.slashed
+    }
+
+    pub fn token_balance(&self, owner: &Pubkey) -> u64 {
+        self.token_balances.get(owner).copied().unwrap_or(0)
+    }
+
+    pub fn lrt_balance(&self, owner: &Pubkey) -> u64 {
+        self.lrt_balances.get(owner).copied().unwrap_or(0)
+    }
+
+    /// Applies the action, leaving the state untouched if it fails
+    pub fn apply(&mut self, action: &VaultAction) -> SimResult<()> {
+        let mut next = self.clone();
+        next.apply_unchecked(action)?;
+        next.check_invariants()?;
+        self.check_transition(&next, action)?;
+        *self = next;
+        Ok(())
+    }
+
+    /// Applies the actions in order, returning the result of each
+    pub fn replay(&mut self, actions: &[VaultAction]) -> Vec<SimResult<()>> {
+        actions.iter().map(|action| self.apply(action)).collect()
+    }
+
+    /// Checks the properties that shall hold after every action
+    pub fn check_invariants(&self) -> SimResult<()> {
+        let lrt_total = self
+            .lrt_balances
+            .values()
+            .try_fold(0_u64, |total, balance| total.checked_add(*balance))
+            .ok_or(SimError::Overflow)?;
+        if lrt_total != self.vault.lrt_supply() {
+            return Err(SimError::InvariantViolated(
+                "LRT balances don't add up to the LRT supply",
+            ));
+        }
+        if self.vault.tokens_deposited() > self.vault_token_balance {
+            return Err(SimError::InvariantViolated(
+                "vault accounts for more tokens than it holds",
+            ));
+        }
+        if self.vault.lrt_supply() == 0 && self.vault.tokens_deposited() != 0 {
+            return Err(SimError::InvariantViolated(
+                "vault holds deposits without any LRT outstanding",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the properties that shall hold between the state before and after an action
+    fn check_transition(&self, next: &Self, action: &VaultAction) -> SimResult<()> {
+        let delegated = match *action {
+            VaultAction::AddDelegation { amount, .. } => amount,
+            _ => 0,
+        };
+        let active_limit = active_total(&self.delegation_list)?
+            .checked_add(delegated)
+            .ok_or(SimError::Overflow)?;
+        if active_total(&next.delegation_list)? > active_limit {
+            return Err(SimError::InvariantViolated(
+                "stake became active without being delegated",
+            ));
+        }
+        Ok(())
+    }
+
+    fn apply_unchecked(&mut self, action: &VaultAction) -> SimResult<()> {
+        match *action {
+            VaultAction::Airdrop { owner, amount } => {
+                credit(&mut self.token_balances, owner, amount)?;
+            }
+            VaultAction::Donate { amount } => {
+                self.vault_token_balance = self
+                    .vault_token_balance
+                    .checked_add(amount)
+                    .ok_or(SimError::Overflow)?;
+            }
+            VaultAction::MintTo { depositor, amount } => {
+                let balance = self.token_balance(&depositor);
+                if balance < amount {
+                    return Err(SimError::InsufficientTokens {
+                        owner: depositor,
+                        balance,
+                        amount,
+                    });
+                }
+                let MintSummary {
+                    lrt_to_depositor,
+                    lrt_to_fee,
+                    balance_update,
+                    ..
+                } = self
+                    .vault
+                    .mint_with_fee(self.vault_token_balance, amount, 0, self.epoch())?;
+
+                debit(&mut self.token_balances, depositor, amount)?;
+                self.vault_token_balance = self
+                    .vault_token_balance
+                    .checked_add(amount)
+                    .ok_or(SimError::Overflow)?;
+                credit(&mut self.lrt_balances, depositor, lrt_to_depositor)?;
+                credit(&mut self.lrt_balances, self.vault.fee_owner(), lrt_to_fee)?;
+                credit(
+                    &mut self.lrt_balances,
+                    self.vault.fee_owner(),
+                    balance_update.lrt_to_reward_fee,
+                )?;
+            }
+            VaultAction::Withdraw { staker, lrt_amount } => {
+                let balance = self.lrt_balance(&staker);
+                if balance < lrt_amount {
+                    return Err(SimError::InsufficientLrt {
+                        owner: staker,
+                        balance,
+                        amount: lrt_amount,
+                    });
+                }
+                self.vault
+                    .sync_balance(self.vault_token_balance, self.epoch())?;
+                let amount = self.vault.burn_and_withdraw(lrt_amount)?;
+
+                debit(&mut self.lrt_balances, staker, lrt_amount)?;
+                self.vault_token_balance = self
+                    .vault_token_balance
+                    .checked_sub(amount)
+                    .ok_or(SimError::Overflow)?;
+                credit(&mut self.token_balances, staker, amount)?;
+            }
+            VaultAction::SetDepositCapacity { capacity } => {
+                self.vault.set_capacity(capacity);
+            }
+            VaultAction::SetEmergencyMode { enabled } => {
+                self.vault.set_emergency_mode(enabled);
+            }
+            VaultAction::AddDelegation { operator, amount } => {
+                self.delegation_list.add_delegation(
+                    operator,
+                    amount,
+                    self.vault.tokens_deposited(),
+                    self.slot,
+                    self.epoch_length,
+                )?;
+            }
+            VaultAction::RemoveDelegation { operator, amount } => {
+                self.delegation_list.remove_delegation(
+                    operator,
+                    amount,
+                    self.slot,
+                    self.epoch_length,
+                    self.vault.emergency_mode(),
+                )?;
+            }
+            VaultAction::UpdateDelegations => {
+                self.delegation_list.update(
+                    self.slot,
+                    self.epoch_length,
+                    self.vault.emergency_mode(),
+                );
+            }
+            VaultAction::UpdateVaultBalance => {
+                let balance_update = self
+                    .vault
+                    .update_balance(self.vault_token_balance, self.epoch())?;
+                credit(
+                    &mut self.lrt_balances,
+                    self.vault.fee_owner(),
+                    balance_update.lrt_to_reward_fee,
+                )?;
+            }
+            VaultAction::Slash { operator, amount } => {
+                self.vault
+                    .sync_balance(self.vault_token_balance, self.epoch())?;
+                self.delegation_list.slash(&operator, amount)?;
+
+                // the token transfer out of the vault fails if it holds too little
+                self.vault_token_balance = self.vault_token_balance.checked_sub(amount).ok_or(
+                    SimError::InsufficientTokens {
+                        owner: Self::VAULT,
+                        balance: self.vault_token_balance,
+                        amount,
+                    },
+                )?;
+                self.slashed = self.slashed.checked_add(amount).ok_or(SimError::Overflow)?;
+                self.vault.set_tokens_deposited(self.vault_token_balance);
+            }
+            VaultAction::WarpSlots { slots } => {
+                self.slot = self.slot.checked_add(slots).ok_or(SimError::Overflow)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn active_total(delegation_list: &VaultDelegationList) -> SimResult<u64> {
+    delegation_list
+        .delegations()
+        .iter()
+        .try_fold(0_u64, |total, delegation| {
+            total.checked_add(delegation.active_amount())
+        })
+        .ok_or(SimError::Overflow)
+}
+
+fn credit(balances: &mut BTreeMap<Pubkey, u64>, owner: Pubkey, amount: u64) -> SimResult<()> {
+    let balance = balances.entry(owner).or_default();
+    *balance = balance.checked_add(amount).ok_or(SimError::Overflow)?;
+    Ok(())
+}
+
+fn debit(balances: &mut BTreeMap<Pubkey, u64>, owner: Pubkey, amount: u64) -> SimResult<()> {
+    let balance = balances.entry(owner).or_default();
+    *balance = balance.checked_sub(amount).ok_or(SimError::Overflow)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{SimError, VaultAction, VaultSim};
+
+    const ALICE: Pubkey = Pubkey::new_from_array([10; 32]);
+    const BOB: Pubkey = Pubkey::new_from_array([11; 32]);
+    const OPERATOR: Pubkey = Pubkey::new_from_array([12; 32]);
+
+    #[test]
+    fn test_mint_to_charges_deposit_fee() {
+        let mut sim = VaultSim::new(100, 0, 100);
+        sim.apply(&VaultAction::Airdrop {
+            owner: ALICE,
+            amount: 100_000,
+        })
+        .unwrap();
+        sim.apply(&VaultAction::MintTo {
+            depositor: ALICE,
+            amount: 100_000,
+        })
+        .unwrap();
+
+        assert_eq!(sim.lrt_balance(&ALICE), 99_000);
+        assert_eq!(sim.lrt_balance(&VaultSim::ADMIN), 1_000);
+        assert_eq!(sim.token_balance(&ALICE), 0);
+        assert_eq!(sim.vault().tokens_deposited(), 100_000);
+    }
+
+    #[test]
+    fn test_failed_action_leaves_state_untouched() {
+        let mut sim = VaultSim::new(0, 0, 100);
+        sim.apply(&VaultAction::Airdrop {
+            owner: ALICE,
+            amount: 1_000,
+        })
+        .unwrap();
+        sim.apply(&VaultAction::SetDepositCapacity { capacity: 500 })
+            .unwrap();
+
+        let before = format!("{sim:?}");
+        assert!(matches!(
+            sim.apply(&VaultAction::MintTo {
+                depositor: ALICE,
+                amount: 1_000,
+            }),
+            Err(SimError::Vault(_))
+        ));
+        assert_eq!(format!("{sim:?}"), before);
+    }
+
+    #[test]
+    fn test_donation_accrues_to_stakers() {
+        let mut sim = VaultSim::new(0, 0, 100);
+        let results = sim.replay(&[
+            VaultAction::Airdrop {
+                owner: ALICE,
+                amount: 1_000,
+            },
+            VaultAction::Airdrop {
+                owner: BOB,
+                amount: 2_000,
+            },
+            VaultAction::MintTo {
+                depositor: ALICE,
+                amount: 1_000,
+            },
+            VaultAction::Donate { amount: 1_000 },
+            VaultAction::MintTo {
+                depositor: BOB,
+                amount: 2_000,
+            },
+            VaultAction::Withdraw {
+                staker: ALICE,
+                lrt_amount: 1_000,
+            },
+        ]);
+        assert!(results.iter().all(Result::is_ok));
+
+        // the donation doubled the value of Alice's LRT before Bob deposited
+        assert_eq!(sim.lrt_balance(&BOB), 1_000);
+        assert_eq!(sim.token_balance(&ALICE), 2_000);
+    }
+
+    #[test]
+    fn test_slash_removes_tokens_from_vault() {
+        let mut sim = VaultSim::new(0, 0, 100);
+        let results = sim.replay(&[
+            VaultAction::Airdrop {
+                owner: ALICE,
+                amount: 10_000,
+            },
+            VaultAction::MintTo {
+                depositor: ALICE,
+                amount: 10_000,
+            },
+            VaultAction::AddDelegation {
+                operator: OPERATOR,
+                amount: 5_000,
+            },
+            VaultAction::Slash {
+                operator: OPERATOR,
+                amount: 1_000,
+            },
+            VaultAction::Withdraw {
+                staker: ALICE,
+                lrt_amount: 10_000,
+            },
+        ]);
+        assert!(results.iter().all(Result::is_ok));
+
+        assert_eq!(sim.slashed(), 1_000);
+        assert_eq!(sim.token_balance(&ALICE), 9_000);
+        assert_eq!(
+            sim.delegation_list().delegations()[0].active_amount(),
+            4_000
+        );
+    }
+
+    #[test]
+    fn test_remove_delegation_cools_down_for_a_full_epoch() {
+        let mut sim = VaultSim::new(0, 0, 100);
+        let results = sim.replay(&[
+            VaultAction::Airdrop {
+                owner: ALICE,
+                amount: 10_000,
+            },
+            VaultAction::MintTo {
+                depositor: ALICE,
+                amount: 10_000,
+            },
+            VaultAction::AddDelegation {
+                operator: OPERATOR,
+                amount: 5_000,
+            },
+            VaultAction::RemoveDelegation {
+                operator: OPERATOR,
+                amount: 2_000,
+            },
+        ]);
+        assert!(results.iter().all(Result::is_ok));
+        let delegation = &sim.delegation_list().delegations()[0];
+        assert_eq!(delegation.enqueued_for_cooldown_amount(), 2_000);
+        assert_eq!(delegation.cooling_down_amount(), 0);
+
+        sim.apply(&VaultAction::WarpSlots { slots: 100 }).unwrap();
+        sim.apply(&VaultAction::UpdateDelegations).unwrap();
+        let delegation = &sim.delegation_list().delegations()[0];
+        assert_eq!(delegation.enqueued_for_cooldown_amount(), 0);
+        assert_eq!(delegation.cooling_down_amount(), 2_000);
+
+        sim.apply(&VaultAction::WarpSlots { slots: 100 }).unwrap();
+        sim.apply(&VaultAction::UpdateDelegations).unwrap();
+        assert_eq!(
+            sim.delegation_list().delegations()[0].deactivating_amount(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_slash_takes_deactivating_stake_first() {
+        let mut sim = VaultSim::new(0, 0, 100);
+        let results = sim.replay(&[
+            VaultAction::Airdrop {
+                owner: ALICE,
+                amount: 10_000,
+            },
+            VaultAction::MintTo {
+                depositor: ALICE,
+                amount: 10_000,
+            },
+            VaultAction::AddDelegation {
+                operator: OPERATOR,
+                amount: 5_000,
+            },
+            VaultAction::RemoveDelegation {
+                operator: OPERATOR,
+                amount: 1_000,
+            },
+            VaultAction::WarpSlots { slots: 100 },
+            VaultAction::RemoveDelegation {
+                operator: OPERATOR,
+                amount: 500,
+            },
+            VaultAction::Slash {
+                operator: OPERATOR,
+                amount: 2_000,
+            },
+        ]);
+        assert!(results.iter().all(Result::is_ok));
+
+        let delegation = &sim.delegation_list().delegations()[0];
+        assert_eq!(delegation.enqueued_for_cooldown_amount(), 0);
+        assert_eq!(delegation.cooling_down_amount(), 0);
+        assert_eq!(delegation.active_amount(), 3_000);
+
+        // more than the operator has at stake can't be slashed
+        assert!(sim
+            .apply(&VaultAction::Slash {
+                operator: OPERATOR,
+                amount: 3_001,
+            })
+            .is_err());
+    }
+
+    /// Replays pseudo-random action sequences, checking the invariants hold after every action and
+    /// that the same sequence always ends in the same state
+    #[test]
+    fn test_random_actions_keep_invariants() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        let owners = [ALICE, BOB, VaultSim::ADMIN];
+
+        for _ in 0..20 {
+            let actions: Vec<_> = (0..200)
+                .map(|_| {
+                    let owner = owners[(next() % 3) as usize];
+                    let amount = next() % 10_000;
+                    match next() % 9 {
+                        0 => VaultAction::Airdrop { owner, amount },
+                        1 => VaultAction::Donate { amount },
+                        2 | 3 => VaultAction::MintTo {
+                            depositor: owner,
+                            amount,
+                        },
+                        4 => VaultAction::Withdraw {
+                            staker: owner,
+                            lrt_amount: amount,
+                        },
+                        5 => VaultAction::AddDelegation {
+                            operator: OPERATOR,
+                            amount,
+                        },
+                        6 => VaultAction::RemoveDelegation {
+                            operator: OPERATOR,
+                            amount,
+                        },
+                        7 => VaultAction::Slash {
+                            operator: OPERATOR,
+                            amount: amount % 100,
+                        },
+                        _ => VaultAction::WarpSlots { slots: amount % 50 },
+                    }
+                })
+                .collect();
+
+            let mut sim = VaultSim::new(50, 0, 100);
+            for action in &actions {
+                let _ = sim.apply(action);
+                sim.check_invariants().unwrap();
+            }
+
+            let mut replayed = VaultSim::new(50, 0, 100);
+            replayed.replay(&actions);
+            assert_eq!(format!("{replayed:?}"), format!("{sim:?}"));
+        }
+    }
+}
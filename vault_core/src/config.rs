@@ -1,5 +1,15 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    counter::Counter,
+    pod::{PodBool, PodU16, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use VaultCoreError::ConfigInvalidPda;
 
 use crate::{
@@ -9,39 +19,132 @@ use crate::{
 
 pub const MAX_RESTAKING_PROGRAMS: usize = 8;
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
+/// The epoch length in slots of a new config, about four days at 400ms slots
+pub const DEFAULT_EPOCH_LENGTH: u64 = 864_000;
+
+/// The shortest epoch the admin can set, about two days. Cooldowns and withdrawal tickets last an
+/// epoch or two, so a shorter one would let stake leave before a slashing can land.
+pub const MIN_EPOCH_LENGTH: u64 = 432_000;
+
+/// The longest epoch the admin can set, about forty days
+pub const MAX_EPOCH_LENGTH: u64 = 8_640_000;
+
+/// The number of epochs a raised withdrawal fee waits before applying, unless the admin sets
+/// another grace period
+pub const DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS: u64 = 2;
+
+/// The shortest grace period the admin can set. Stakers always get at least an epoch to withdraw
+/// before a raised fee applies.
+pub const MIN_WITHDRAWAL_FEE_GRACE_EPOCHS: u64 = 1;
+
+/// The longest grace period the admin can set
+pub const MAX_WITHDRAWAL_FEE_GRACE_EPOCHS: u64 = 16;
+
+/// The number of epoch boundaries a staker withdrawal waits for before it can be redeemed, unless
+/// the admin sets another cooldown
+pub const DEFAULT_WITHDRAWAL_COOLDOWN_EPOCHS: u64 = 1;
+
+/// The shortest withdrawal cooldown the admin can set. Withdrawals always wait for an epoch
+/// boundary, so stakers can't exit ahead of a slash for the epoch they were staked in.
+pub const MIN_WITHDRAWAL_COOLDOWN_EPOCHS: u64 = 1;
+
+/// The longest withdrawal cooldown the admin can set
+pub const MAX_WITHDRAWAL_COOLDOWN_EPOCHS: u64 = 8;
+
+/// The hard cap on the program fee, in basis points
+pub const MAX_PROGRAM_FEE_BPS: u16 = 500;
+
+/// The number of epochs the admin waits between two program fee changes
+pub const PROGRAM_FEE_CHANGE_DELAY_EPOCHS: u64 = 4;
+
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
 pub struct Config {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The configuration admin
+    #[serde(serialize_with = "serde_fields::pubkey")]
     admin: Pubkey,
 
     /// The approved restaking program for this vault
+    #[serde(serialize_with = "serde_fields::pubkey")]
     restaking_program: Pubkey,
 
     /// The length of an epoch in slots
-    epoch_length: u64,
+    epoch_length: PodU64,
 
     /// The number of vaults managed by the program
-    num_vaults: u64,
+    num_vaults: Counter,
+
+    /// The number of epochs a raised vault withdrawal fee waits before applying
+    withdrawal_fee_grace_epochs: PodU64,
+
+    /// The key proposed to take over as admin, or the default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    pending_admin: Pubkey,
+
+    /// Set by the admin during an incident to halt deposits, withdrawals, slashing and delegation
+    /// changes in every vault
+    paused: PodBool,
+
+    /// The wallet whose LRT account receives the program fee
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    program_fee_wallet: Pubkey,
+
+    /// The program fee charged on deposits into every vault, in basis points of the LRT minted
+    program_fee_bps: PodU16,
+
+    /// The first epoch the program fee can be changed again
+    program_fee_unlock_epoch: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// The number of epoch boundaries a staker withdrawal waits for, or zero for
+    /// [`DEFAULT_WITHDRAWAL_COOLDOWN_EPOCHS`]
+    withdrawal_cooldown_epochs: PodU64,
 
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 76],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
+impl ZeroCopy for Config {
+    const DISCRIMINATOR: u8 = AccountType::Config as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl Config {
     pub const fn new(admin: Pubkey, restaking_program: Pubkey, bump: u8) -> Self {
         Self {
-            account_type: AccountType::Config,
+            discriminator: AccountType::Config as u8,
             admin,
             restaking_program,
-            epoch_length: 864_000,
-            num_vaults: 0,
-            reserved: [0; 128],
+            epoch_length: PodU64::new(DEFAULT_EPOCH_LENGTH),
+            num_vaults: Counter::new(0),
+            withdrawal_fee_grace_epochs: PodU64::new(DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS),
+            pending_admin: Pubkey::new_from_array([0; 32]),
+            paused: PodBool::new(false),
+            program_fee_wallet: Pubkey::new_from_array([0; 32]),
+            program_fee_bps: PodU16::new(0),
+            program_fee_unlock_epoch: PodU64::new(0),
+            version: Self::VERSION,
+            withdrawal_cooldown_epochs: PodU64::new(DEFAULT_WITHDRAWAL_COOLDOWN_EPOCHS),
+            reserved: [0; 76],
             bump,
         }
     }
@@ -50,21 +153,162 @@ impl Config {
         self.admin
     }
 
+    pub fn check_admin(&self, admin: &Pubkey) -> VaultCoreResult<()> {
+        if self.admin != *admin {
+            return Err(VaultCoreError::ConfigInvalidAdmin);
+        }
+        Ok(())
+    }
+
+    /// The key proposed to take over as admin, if a transfer is pending
+    pub fn pending_admin(&self) -> Option<Pubkey> {
+        if self.pending_admin != Pubkey::default() {
+            Some(self.pending_admin)
+        } else {
+            None
+        }
+    }
+
+    /// Proposes a new admin, replacing any transfer that is already pending. The transfer
+    /// completes once the new admin accepts it.
+    pub fn set_pending_admin(&mut self, pending_admin: Pubkey) {
+        self.pending_admin = pending_admin;
+    }
+
+    /// Completes the pending admin transfer to `new_admin`
+    pub fn accept_admin(&mut self, new_admin: &Pubkey) -> VaultCoreResult<()> {
+        if self.pending_admin() != Some(*new_admin) {
+            return Err(VaultCoreError::ConfigInvalidPendingAdmin);
+        }
+        self.admin = *new_admin;
+        self.pending_admin = Pubkey::default();
+        Ok(())
+    }
+
     pub const fn restaking_program(&self) -> Pubkey {
         self.restaking_program
     }
 
     pub const fn epoch_length(&self) -> u64 {
-        self.epoch_length
+        self.epoch_length.get()
     }
 
-    pub fn increment_vaults(&mut self) -> Option<u64> {
-        self.num_vaults = self.num_vaults.checked_add(1)?;
-        Some(self.num_vaults)
+    /// Sets the epoch length, which shall be within [`MIN_EPOCH_LENGTH`] and [`MAX_EPOCH_LENGTH`].
+    /// It's locked once a vault exists, as the epochs its cooldowns, tickets and slashing caps were
+    /// recorded in would otherwise map to other slots.
+    pub fn set_epoch_length(&mut self, epoch_length: u64) -> VaultCoreResult<()> {
+        if self.vaults_count() > 0 {
+            return Err(VaultCoreError::ConfigEpochLengthLocked);
+        }
+        if !(MIN_EPOCH_LENGTH..=MAX_EPOCH_LENGTH).contains(&epoch_length) {
+            return Err(VaultCoreError::ConfigInvalidEpochLength);
+        }
+        self.epoch_length = PodU64::new(epoch_length);
+        Ok(())
     }
 
-    pub const fn vaults_count(&self) -> u64 {
+    pub const fn withdrawal_fee_grace_epochs(&self) -> u64 {
+        self.withdrawal_fee_grace_epochs.get()
+    }
+
+    /// Sets the withdrawal fee grace period, which shall be within
+    /// [`MIN_WITHDRAWAL_FEE_GRACE_EPOCHS`] and [`MAX_WITHDRAWAL_FEE_GRACE_EPOCHS`]
+    pub fn set_withdrawal_fee_grace_epochs(
+        &mut self,
+        withdrawal_fee_grace_epochs: u64,
+    ) -> VaultCoreResult<()> {
+        if !(MIN_WITHDRAWAL_FEE_GRACE_EPOCHS..=MAX_WITHDRAWAL_FEE_GRACE_EPOCHS)
+            .contains(&withdrawal_fee_grace_epochs)
+        {
+            return Err(VaultCoreError::ConfigInvalidWithdrawalFeeGraceEpochs);
+        }
+        self.withdrawal_fee_grace_epochs = PodU64::new(withdrawal_fee_grace_epochs);
+        Ok(())
+    }
+
+    /// The number of epoch boundaries a staker withdrawal waits for before it can be redeemed.
+    /// Configs written before the cooldown was configurable wait for the default.
+    pub const fn withdrawal_cooldown_epochs(&self) -> u64 {
+        match self.withdrawal_cooldown_epochs.get() {
+            0 => DEFAULT_WITHDRAWAL_COOLDOWN_EPOCHS,
+            epochs => epochs,
+        }
+    }
+
+    /// Sets the withdrawal cooldown, which shall be within [`MIN_WITHDRAWAL_COOLDOWN_EPOCHS`]
+    /// and [`MAX_WITHDRAWAL_COOLDOWN_EPOCHS`]. It applies to tickets already enqueued as well.
+    pub fn set_withdrawal_cooldown_epochs(
+        &mut self,
+        withdrawal_cooldown_epochs: u64,
+    ) -> VaultCoreResult<()> {
+        if !(MIN_WITHDRAWAL_COOLDOWN_EPOCHS..=MAX_WITHDRAWAL_COOLDOWN_EPOCHS)
+            .contains(&withdrawal_cooldown_epochs)
+        {
+            return Err(VaultCoreError::ConfigInvalidWithdrawalCooldownEpochs);
+        }
+        self.withdrawal_cooldown_epochs = PodU64::new(withdrawal_cooldown_epochs);
+        Ok(())
+    }
+
+    pub const fn paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = PodBool::new(paused);
+    }
+
+    /// Fails while the program is paused, see [`Config::paused`]
+    pub fn check_not_paused(&self) -> VaultCoreResult<()> {
+        if self.paused() {
+            return Err(VaultCoreError::ConfigPaused);
+        }
+        Ok(())
+    }
+
+    pub const fn program_fee_wallet(&self) -> Pubkey {
+        self.program_fee_wallet
+    }
+
+    pub const fn program_fee_bps(&self) -> u16 {
+        self.program_fee_bps.get()
+    }
+
+    pub const fn program_fee_unlock_epoch(&self) -> u64 {
+        self.program_fee_unlock_epoch.get()
+    }
+
+    /// Sets the program fee and the wallet it's paid to at `epoch`. The fee is capped at
+    /// [`MAX_PROGRAM_FEE_BPS`], a non-zero fee needs a wallet, and changes are
+    /// [`PROGRAM_FEE_CHANGE_DELAY_EPOCHS`] apart.
+    pub fn set_program_fee(
+        &mut self,
+        program_fee_bps: u16,
+        program_fee_wallet: Pubkey,
+        epoch: u64,
+    ) -> VaultCoreResult<()> {
+        if program_fee_bps > MAX_PROGRAM_FEE_BPS
+            || (program_fee_bps > 0 && program_fee_wallet == Pubkey::default())
+        {
+            return Err(VaultCoreError::ConfigInvalidProgramFee);
+        }
+        if epoch < self.program_fee_unlock_epoch() {
+            return Err(VaultCoreError::ConfigProgramFeeTimelocked);
+        }
+        self.program_fee_bps = PodU16::new(program_fee_bps);
+        self.program_fee_wallet = program_fee_wallet;
+        self.program_fee_unlock_epoch =
+            PodU64::new(epoch.saturating_add(PROGRAM_FEE_CHANGE_DELAY_EPOCHS));
+        Ok(())
+    }
+
+    pub fn increment_vaults(&mut self) -> VaultCoreResult<u64> {
         self.num_vaults
+            .increment("num_vaults", VaultCoreError::ConfigVaultsOverflow)
+    }
+
+    pub const fn vaults_count(&self) -> u64 {
+        self.num_vaults.get()
     }
 
     pub const fn bump(&self) -> u8 {
@@ -72,7 +316,7 @@ impl Config {
     }
 
     pub fn is_struct_valid(&self) -> bool {
-        self.account_type == AccountType::Config
+        self.discriminator == Self::DISCRIMINATOR
     }
 
     pub fn seeds() -> Vec<Vec<u8>> {
@@ -97,11 +341,10 @@ impl Config {
             return Err(VaultCoreError::ConfigInvalidProgramOwner);
         }
 
-        let state = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| VaultCoreError::ConfigInvalidData(e.to_string()))?;
-        if state.account_type != AccountType::Config {
-            return Err(VaultCoreError::ConfigInvalidAccountType);
-        }
+        let state = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => VaultCoreError::ConfigInvalidAccountType,
+            e => VaultCoreError::ConfigInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds();
         seeds.push(vec![state.bump]);
@@ -118,7 +361,6 @@ impl Config {
 
 pub struct SanitizedConfig<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    config: Box<Config>,
 }
 
 impl<'a, 'info> SanitizedConfig<'a, 'info> {
@@ -130,25 +372,24 @@ impl<'a, 'info> SanitizedConfig<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(VaultCoreError::ConfigExpectedWritable);
         }
-        let config = Box::new(Config::deserialize_checked(program_id, account)?);
+        Config::deserialize_checked(program_id, account)?;
 
-        Ok(SanitizedConfig { account, config })
+        Ok(SanitizedConfig { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn config(&self) -> &Config {
-        &self.config
+    pub fn config(&self) -> Ref<'_, Config> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..Config::LEN])
+        })
     }
 
-    pub fn config_mut(&mut self) -> &mut Config {
-        &mut self.config
-    }
-
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(&mut self.account.data.borrow_mut()[..], &self.config)?;
-        Ok(())
+    pub fn config_mut(&mut self) -> RefMut<'_, Config> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..Config::LEN])
+        })
     }
 }
@@ -1,17 +1,22 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 
 pub mod config;
+pub mod migration;
 pub mod result;
+pub mod slash_proposal;
 pub mod vault;
 pub mod vault_avs_slasher_operator_ticket;
 pub mod vault_avs_slasher_ticket;
 pub mod vault_avs_ticket;
 pub mod vault_delegation_list;
+pub mod vault_operator_blacklist;
 pub mod vault_operator_ticket;
+pub mod vault_referrer;
+pub mod vault_staker_withdrawal_ticket;
 
-#[derive(Debug, Clone, Copy, PartialEq, BorshDeserialize, BorshSerialize)]
-#[repr(u32)]
-enum AccountType {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+#[repr(u8)]
+pub enum AccountType {
     Config,
     Vault,
     VaultOperatorTicket,
@@ -19,4 +24,357 @@ enum AccountType {
     VaultAvsTicket,
     VaultDelegationList,
     VaultAvsSlasherOperatorTicket,
+    VaultStakerWithdrawalTicket,
+    VaultOperatorBlacklist,
+    VaultReferrer,
+    SlashProposal,
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jito_jsm_core::zero_copy::ZeroCopy;
+    use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+    use crate::{
+        config::Config, result::VaultCoreError, slash_proposal::SlashProposal, vault::Vault,
+        vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+        vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+        vault_delegation_list::VaultDelegationList,
+        vault_operator_blacklist::VaultOperatorBlacklist,
+        vault_operator_ticket::VaultOperatorTicket, vault_referrer::VaultReferrer,
+        vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    };
+
+    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([0xbb; 32]);
+
+    /// The errors `deserialize_checked` of an account type returns for each way the account can
+    /// be malformed
+    struct Errors {
+        empty: VaultCoreError,
+        invalid_owner: VaultCoreError,
+        invalid_account_type: VaultCoreError,
+        invalid_data: fn(&VaultCoreError) -> bool,
+        invalid_pda: VaultCoreError,
+    }
+
+    macro_rules! errors {
+        ($empty:ident, $owner:ident, $account_type:ident, $data:ident, $pda:ident) => {
+            Errors {
+                empty: VaultCoreError::$empty,
+                invalid_owner: VaultCoreError::$owner,
+                invalid_account_type: VaultCoreError::$account_type,
+                invalid_data: |e| matches!(e, VaultCoreError::$data(_)),
+                invalid_pda: VaultCoreError::$pda,
+            }
+        };
+    }
+
+    /// Feeds `deserialize` the account at `pda` serialized by `data` with its bump, then
+    /// variations of it that shall each fail with the error of the account type
+    #[track_caller]
+    fn assert_deserialize_checked(
+        (pda, bump): (Pubkey, u8),
+        data: impl Fn(u8) -> Vec<u8>,
+        deserialize: impl Fn(&AccountInfo) -> Result<(), VaultCoreError>,
+        errors: Errors,
+    ) {
+        let check = |key: Pubkey, owner: Pubkey, mut data: Vec<u8>| {
+            let mut lamports = 0;
+            let account = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            );
+            deserialize(&account)
+        };
+        let valid = data(bump);
+
+        assert_eq!(check(pda, PROGRAM_ID, valid.clone()), Ok(()));
+        // accounts only grow when their layout is migrated, so data past the layout is ignored
+        let mut oversized = valid.clone();
+        oversized.extend([0; 64]);
+        assert_eq!(check(pda, PROGRAM_ID, oversized), Ok(()));
+
+        assert_eq!(check(pda, PROGRAM_ID, Vec::new()), Err(errors.empty));
+        assert_eq!(
+            check(pda, Pubkey::new_unique(), valid.clone()),
+            Err(errors.invalid_owner)
+        );
+        let mut tampered = valid.clone();
+        tampered[0] = tampered[0].wrapping_add(1);
+        assert_eq!(
+            check(pda, PROGRAM_ID, tampered),
+            Err(errors.invalid_account_type)
+        );
+        let truncated = valid[..valid.len() - 1].to_vec();
+        let error = check(pda, PROGRAM_ID, truncated).unwrap_err();
+        assert!((errors.invalid_data)(&error), "{error:?}");
+        // a bump or address that doesn't derive from the seeds
+        for (key, data) in [
+            (pda, data(bump.wrapping_sub(1))),
+            (Pubkey::new_unique(), valid),
+        ] {
+            assert_eq!(
+                check(key, PROGRAM_ID, data).err().as_ref(),
+                Some(&errors.invalid_pda)
+            );
+        }
+    }
+
+    /// A PDA with its bump, dropping the seeds
+    fn pda((address, bump, _): (Pubkey, u8, Vec<Vec<u8>>)) -> (Pubkey, u8) {
+        (address, bump)
+    }
+
+    #[test]
+    fn test_deserialize_checked_rejects_malformed_accounts() {
+        let [vault, avs, slasher, operator, staker, base] = [(); 6].map(|_| Pubkey::new_unique());
+
+        assert_deserialize_checked(
+            pda(Config::find_program_address(&PROGRAM_ID)),
+            |bump| Config::new(base, avs, bump).as_bytes().to_vec(),
+            |account| Config::deserialize_checked(&PROGRAM_ID, account).map(|_| ()),
+            errors!(
+                ConfigDataEmpty,
+                ConfigInvalidProgramOwner,
+                ConfigInvalidAccountType,
+                ConfigInvalidData,
+                ConfigInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(Vault::find_program_address(&PROGRAM_ID, &base)),
+            |bump| {
+                Vault::new(avs, slasher, operator, 0, base, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| Vault::deserialize_checked(&PROGRAM_ID, account).map(|_| ()),
+            errors!(
+                VaultDataEmpty,
+                VaultInvalidProgramOwner,
+                VaultInvalidAccountType,
+                VaultInvalidData,
+                VaultInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultDelegationList::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+            )),
+            |bump| VaultDelegationList::new(vault, bump).try_to_vec().unwrap(),
+            |account| {
+                VaultDelegationList::deserialize_checked(&PROGRAM_ID, account, &vault).map(|_| ())
+            },
+            errors!(
+                VaultDelegationListDataEmpty,
+                VaultDelegationListInvalidProgramOwner,
+                VaultDelegationListInvalidAccountType,
+                VaultDelegationListInvalidData,
+                VaultDelegationListInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultAvsTicket::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &avs,
+            )),
+            |bump| {
+                VaultAvsTicket::new(vault, avs, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                VaultAvsTicket::deserialize_checked(&PROGRAM_ID, account, &vault, &avs).map(|_| ())
+            },
+            errors!(
+                VaultAvsTicketEmpty,
+                VaultAvsTicketInvalidOwner,
+                VaultAvsTicketInvalidAccountType,
+                VaultAvsTicketInvalidData,
+                VaultAvsTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultOperatorTicket::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &operator,
+            )),
+            |bump| {
+                VaultOperatorTicket::new(vault, operator, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                VaultOperatorTicket::deserialize_checked(&PROGRAM_ID, account, &vault, &operator)
+                    .map(|_| ())
+            },
+            errors!(
+                VaultOperatorTicketEmpty,
+                VaultOperatorTicketInvalidOwner,
+                VaultOperatorTicketInvalidAccountType,
+                VaultOperatorTicketInvalidData,
+                VaultOperatorTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultAvsSlasherTicket::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &avs,
+                &slasher,
+            )),
+            |bump| {
+                VaultAvsSlasherTicket::new(vault, avs, slasher, 0, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                VaultAvsSlasherTicket::deserialize_checked(
+                    &PROGRAM_ID,
+                    account,
+                    &vault,
+                    &avs,
+                    &slasher,
+                )
+                .map(|_| ())
+            },
+            errors!(
+                VaultSlasherTicketEmpty,
+                VaultSlasherTicketInvalidOwner,
+                VaultSlasherTicketInvalidAccountType,
+                VaultSlasherTicketInvalidData,
+                VaultSlasherTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultAvsSlasherOperatorTicket::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &avs,
+                &slasher,
+                &operator,
+                3,
+            )),
+            |bump| {
+                VaultAvsSlasherOperatorTicket::new(vault, avs, slasher, operator, 3, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                VaultAvsSlasherOperatorTicket::deserialize_checked(
+                    &PROGRAM_ID,
+                    account,
+                    &vault,
+                    &avs,
+                    &slasher,
+                    &operator,
+                    3,
+                )
+                .map(|_| ())
+            },
+            errors!(
+                VaultAvsSlasherOperatorDataEmpty,
+                VaultAvsSlasherOperatorInvalidOwner,
+                VaultAvsSlasherOperatorInvalidAccountType,
+                VaultAvsSlasherOperatorInvalidData,
+                VaultAvsSlasherOperatorInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultStakerWithdrawalTicket::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &staker,
+                &base,
+            )),
+            |bump| {
+                VaultStakerWithdrawalTicket::new(vault, staker, base, 0, 0, 0, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| {
+                VaultStakerWithdrawalTicket::deserialize_checked(
+                    &PROGRAM_ID,
+                    account,
+                    &vault,
+                    &staker,
+                )
+                .map(|_| ())
+            },
+            errors!(
+                VaultStakerWithdrawalTicketEmpty,
+                VaultStakerWithdrawalTicketInvalidOwner,
+                VaultStakerWithdrawalTicketInvalidAccountType,
+                VaultStakerWithdrawalTicketInvalidData,
+                VaultStakerWithdrawalTicketInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultOperatorBlacklist::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+            )),
+            |bump| VaultOperatorBlacklist::new(vault, bump).as_bytes().to_vec(),
+            |account| {
+                VaultOperatorBlacklist::deserialize_checked(&PROGRAM_ID, account, &vault)
+                    .map(|_| ())
+            },
+            errors!(
+                VaultOperatorBlacklistEmpty,
+                VaultOperatorBlacklistInvalidOwner,
+                VaultOperatorBlacklistInvalidAccountType,
+                VaultOperatorBlacklistInvalidData,
+                VaultOperatorBlacklistInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(VaultReferrer::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &staker,
+            )),
+            |bump| VaultReferrer::new(vault, staker, bump).as_bytes().to_vec(),
+            |account| VaultReferrer::deserialize_checked(&PROGRAM_ID, account, &vault).map(|_| ()),
+            errors!(
+                VaultReferrerEmpty,
+                VaultReferrerInvalidOwner,
+                VaultReferrerInvalidAccountType,
+                VaultReferrerInvalidData,
+                VaultReferrerInvalidPda
+            ),
+        );
+        assert_deserialize_checked(
+            pda(SlashProposal::find_program_address(
+                &PROGRAM_ID,
+                &vault,
+                &avs,
+                &slasher,
+                &operator,
+                &[7; 32],
+            )),
+            |bump| {
+                SlashProposal::new(vault, avs, slasher, operator, [7; 32], 100, 3, bump)
+                    .as_bytes()
+                    .to_vec()
+            },
+            |account| SlashProposal::deserialize_checked(&PROGRAM_ID, account).map(|_| ()),
+            errors!(
+                SlashProposalEmpty,
+                SlashProposalInvalidOwner,
+                SlashProposalInvalidAccountType,
+                SlashProposalInvalidData,
+                SlashProposalInvalidPda
+            ),
+        );
+    }
 }
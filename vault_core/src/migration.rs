@@ -0,0 +1,138 @@
+//! Migrations of accounts written by earlier versions of the program.
+//!
+//! No vault account has grown since it was introduced, so [`migrate`] only records the current
+//! [`ZeroCopy::VERSION`] of the layout in accounts written before it, or before versioning was
+//! introduced. Fields inserted into a layout later on are migrated the same way as in
+//! `jito_restaking_core::migration`, before the version is recorded.
+use borsh::{BorshDeserialize, BorshSerialize};
+use jito_jsm_core::zero_copy::{ZeroCopy, ZeroCopyError};
+
+use crate::{
+    config::Config,
+    result::{VaultCoreError, VaultCoreResult},
+    slash_proposal::SlashProposal,
+    vault::Vault,
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket,
+    vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList,
+    vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket,
+    vault_referrer::VaultReferrer,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    AccountType,
+};
+
+/// Migrates the account data to the current layout of the account type. Accounts already at
+/// their current layout and version are returned as they are.
+pub fn migrate(account_type: AccountType, data: &[u8]) -> VaultCoreResult<Vec<u8>> {
+    let mut migrated = data.to_vec();
+    match account_type {
+        AccountType::Config => set_current_version::<Config>(&mut migrated)?,
+        AccountType::Vault => set_current_version::<Vault>(&mut migrated)?,
+        AccountType::VaultOperatorTicket => {
+            set_current_version::<VaultOperatorTicket>(&mut migrated)?
+        }
+        AccountType::VaultAvsSlasherTicket => {
+            set_current_version::<VaultAvsSlasherTicket>(&mut migrated)?
+        }
+        AccountType::VaultAvsTicket => set_current_version::<VaultAvsTicket>(&mut migrated)?,
+        AccountType::VaultDelegationList => {
+            let mut list = VaultDelegationList::deserialize(&mut &data[..])
+                .map_err(|_| VaultCoreError::AccountMigrationNotFound)?;
+            if list.version() > VaultDelegationList::VERSION {
+                return Err(VaultCoreError::AccountVersionUnsupported);
+            }
+            list.set_version(VaultDelegationList::VERSION);
+            let serialized = list
+                .try_to_vec()
+                .map_err(|_| VaultCoreError::AccountMigrationNotFound)?;
+            migrated[..serialized.len()].copy_from_slice(&serialized);
+        }
+        AccountType::VaultAvsSlasherOperatorTicket => {
+            set_current_version::<VaultAvsSlasherOperatorTicket>(&mut migrated)?
+        }
+        AccountType::VaultStakerWithdrawalTicket => {
+            set_current_version::<VaultStakerWithdrawalTicket>(&mut migrated)?
+        }
+        AccountType::VaultOperatorBlacklist => {
+            set_current_version::<VaultOperatorBlacklist>(&mut migrated)?
+        }
+        AccountType::VaultReferrer => set_current_version::<VaultReferrer>(&mut migrated)?,
+        AccountType::SlashProposal => set_current_version::<SlashProposal>(&mut migrated)?,
+    }
+    Ok(migrated)
+}
+
+/// Records the current version of the account in the data, which shall be at its current
+/// layout. Accounts written by a newer version of the program are rejected.
+fn set_current_version<T: ZeroCopy>(data: &mut [u8]) -> VaultCoreResult<()> {
+    let account = T::load_mut(data).map_err(|e| match e {
+        ZeroCopyError::UnsupportedVersion { .. } => VaultCoreError::AccountVersionUnsupported,
+        _ => VaultCoreError::AccountMigrationNotFound,
+    })?;
+    account.set_version(T::VERSION);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jito_jsm_core::zero_copy::ZeroCopy;
+    use solana_program::pubkey::Pubkey;
+
+    use super::migrate;
+    use crate::{
+        result::VaultCoreError, vault_delegation_list::VaultDelegationList,
+        vault_referrer::VaultReferrer, AccountType,
+    };
+
+    #[test]
+    fn test_migrate_records_version() {
+        let mut referrer = VaultReferrer::new(Pubkey::new_unique(), Pubkey::new_unique(), 254);
+        let current = referrer.as_bytes().to_vec();
+        referrer.set_version(0);
+
+        let migrated = migrate(AccountType::VaultReferrer, referrer.as_bytes()).unwrap();
+        assert_eq!(migrated, current);
+        assert_eq!(
+            migrate(AccountType::VaultReferrer, &current).unwrap(),
+            current
+        );
+
+        referrer.set_version(VaultReferrer::VERSION + 1);
+        assert_eq!(
+            migrate(AccountType::VaultReferrer, referrer.as_bytes()),
+            Err(VaultCoreError::AccountVersionUnsupported)
+        );
+        assert_eq!(
+            migrate(AccountType::VaultReferrer, &current[..current.len() - 1]),
+            Err(VaultCoreError::AccountMigrationNotFound)
+        );
+    }
+
+    #[test]
+    fn test_migrate_delegation_list_keeps_length() {
+        let mut list = VaultDelegationList::new(Pubkey::new_unique(), 254);
+        let mut current = list.try_to_vec().unwrap();
+        // delegation lists are allocated with room to grow
+        current.extend([0; 32]);
+        list.set_version(0);
+        let mut unversioned = list.try_to_vec().unwrap();
+        unversioned.extend([0; 32]);
+
+        assert_eq!(
+            migrate(AccountType::VaultDelegationList, &unversioned).unwrap(),
+            current
+        );
+
+        list.set_version(VaultDelegationList::VERSION + 1);
+        assert_eq!(
+            migrate(
+                AccountType::VaultDelegationList,
+                &list.try_to_vec().unwrap()
+            ),
+            Err(VaultCoreError::AccountVersionUnsupported)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use jito_restaking_sanitization::error_code::ErrorSource;
 use solana_program::program_error::ProgramError;
 
 pub type VaultCoreResult<T> = Result<T, VaultCoreError>;
@@ -89,23 +90,472 @@ pub enum VaultCoreError {
     VaultAvsSlasherOperatorNotWritable,
     VaultAvsSlasherOperatorOverflow,
     VaultAvsSlasherOperatorMaxSlashableExceeded,
+    VaultStakerWithdrawalTicketEmpty,
+    VaultStakerWithdrawalTicketInvalidOwner,
+    VaultStakerWithdrawalTicketInvalidData(String),
+    VaultStakerWithdrawalTicketInvalidAccountType,
+    VaultStakerWithdrawalTicketInvalidPda,
+    VaultStakerWithdrawalTicketNotWritable,
+    VaultWithdrawalQueueOverflow,
+    VaultWithdrawalQueueOutOfOrder,
+    VaultNotInEmergencyMode,
+    VaultWithdrawalOverflow,
+    ConfigInvalidAdmin,
+    VaultAvsTicketInvalidServiceFee,
+    VaultAvsServiceFeeNotAgreed,
+    VaultAvsServiceFeeAlreadyPaid,
+    VaultAvsServiceFeeOverflow,
+    VaultMintAuthorityHandoffInvalidTarget,
+    VaultMintAuthorityHandoffNotPending,
+    VaultMintAuthorityHandoffTimelocked,
+    VaultMintAuthorityHandoffOverflow,
+    VaultMintAuthorityMismatch,
+    VaultMigrationNotApproved,
+    VaultMigrationSupportedMintMismatch,
+    VaultWithdrawalExceedsSupply,
+    VaultDepositSlippageExceeded,
+    ConfigVaultsOverflow,
+    VaultInvalidWithdrawalFee,
+    VaultInvalidDepositFee,
+    VaultStakerWithdrawalTicketNotWithdrawable,
+    VaultInvalidPendingAdmin,
+    ConfigInvalidPendingAdmin,
+    ConfigInvalidEpochLength,
+    ConfigInvalidWithdrawalFeeGraceEpochs,
+    VaultOperatorBlacklistEmpty,
+    VaultOperatorBlacklistInvalidOwner,
+    VaultOperatorBlacklistInvalidData(String),
+    VaultOperatorBlacklistInvalidAccountType,
+    VaultOperatorBlacklistInvalidPda,
+    VaultOperatorBlacklistNotWritable,
+    VaultOperatorBlacklistFull,
+    VaultOperatorAlreadyBlacklisted,
+    VaultOperatorNotBlacklisted,
+    VaultOperatorBlacklisted,
+    VaultInvalidReferralFee,
+    VaultReferrerEmpty,
+    VaultReferrerInvalidOwner,
+    VaultReferrerInvalidData(String),
+    VaultReferrerInvalidAccountType,
+    VaultReferrerInvalidPda,
+    VaultReferrerNotWritable,
+    VaultReferrerInvalidReferrer,
+    VaultReferrerNothingToClaim,
+    VaultReferrerOverflow,
+    VaultInvalidAccountType,
+    ConfigPaused,
+    VaultPaused,
+    ConfigInvalidProgramFee,
+    ConfigProgramFeeTimelocked,
+    VaultInvalidFeeAdmin,
+    VaultInvalidRewardFee,
+    VaultFeeDeltaExceeded,
+    SlashProposalEmpty,
+    SlashProposalInvalidOwner,
+    SlashProposalInvalidData(String),
+    SlashProposalInvalidAccountType,
+    SlashProposalInvalidPda,
+    SlashProposalNotWritable,
+    SlashProposalNotPending,
+    SlashProposalVetoWindowOpen,
+    SlashProposalVetoWindowClosed,
+    VaultAvsSlasherTicketMaxSlashableExceeded,
+    VaultCrankTipTooHigh,
+    AccountMigrationNotFound,
+    AccountVersionUnsupported,
+    VaultMintBurnAuthorityNotSet,
+    VaultBalanceUpdateRequired,
+    ConfigInvalidWithdrawalCooldownEpochs,
+    VaultMigrationWithdrawalsQueued,
+    VaultMigrationInsufficientLiquidity,
+    VaultMigrationEmergencyMode,
+    VaultAvsTicketWarmingUp,
+    VaultOperatorTicketWarmingUp,
+    ConfigEpochLengthLocked,
 }
 
+impl VaultCoreError {
+    /// The offset of the error within the vault program range in
+    /// [`jito_restaking_sanitization::error_code`]. Offsets follow the declaration order and shall
+    /// never change, so new errors are appended.
+    pub const fn offset(&self) -> u32 {
+        match self {
+            Self::VaultDepositOverflow => 0,
+            Self::VaultDepositExceedsCapacity => 1,
+            Self::VaultFeeCalculationOverflow => 2,
+            Self::VaultDataEmpty => 3,
+            Self::VaultInvalidProgramOwner => 4,
+            Self::VaultInvalidData(_) => 5,
+            Self::VaultInvalidPda => 6,
+            Self::VaultExpectedWritable => 7,
+            Self::VaultSerializationFailed(_) => 8,
+            Self::VaultAvsAlreadyActive => 9,
+            Self::VaultInvalidAdmin => 10,
+            Self::ConfigExpectedWritable => 11,
+            Self::VaultAvsListExpectedWritable => 12,
+            Self::VaultDelegationListExpectedWritable => 13,
+            Self::VaultSlasherListExpectedWritable => 14,
+            Self::VaultAvsListDataEmpty => 15,
+            Self::VaultAvsListInvalidProgramOwner => 16,
+            Self::VaultAvsListInvalidData(_) => 17,
+            Self::VaultAvsListInvalidAccountType => 18,
+            Self::VaultAvsListInvalidPda => 19,
+            Self::VaultDelegationListDataEmpty => 20,
+            Self::VaultDelegationListInvalidProgramOwner => 21,
+            Self::VaultDelegationListInvalidData(_) => 22,
+            Self::VaultDelegationListInvalidAccountType => 23,
+            Self::VaultDelegationListInvalidPda => 24,
+            Self::VaultSlasherListInvalidPda => 25,
+            Self::VaultSlasherListInvalidAccountType => 26,
+            Self::VaultSlasherListInvalidData(_) => 27,
+            Self::VaultSlasherListInvalidProgramOwner => 28,
+            Self::VaultSlasherListDataEmpty => 29,
+            Self::ConfigInvalidPda => 30,
+            Self::ConfigInvalidAccountType => 31,
+            Self::ConfigInvalidData(_) => 32,
+            Self::ConfigInvalidProgramOwner => 33,
+            Self::ConfigDataEmpty => 34,
+            Self::VaultAvsNotSupported => 35,
+            Self::VaultAvsAlreadyInactive => 36,
+            Self::VaultDelegationListOperatorAlreadyAdded => 37,
+            Self::VaultDelegationListOperatorAlreadyRemoved => 38,
+            Self::VaultDelegationListOperatorNotAdded => 39,
+            Self::VaultInvalidDelegationAdmin => 40,
+            Self::VaultSlasherNotFound => 41,
+            Self::VaultSlasherNotActive => 42,
+            Self::VaultAvsNotActive => 43,
+            Self::VaultOperatorNotFound => 44,
+            Self::VaultOperatorNotActive => 45,
+            Self::VaultSlashingOverflow => 46,
+            Self::VaultSlashingUnderflow => 47,
+            Self::VaultAvsTicketEmpty => 48,
+            Self::VaultAvsTicketInvalidOwner => 49,
+            Self::VaultAvsTicketInvalidAccountType => 50,
+            Self::VaultAvsTicketInvalidData(_) => 51,
+            Self::VaultAvsTicketInvalidPda => 52,
+            Self::VaultOperatorTicketEmpty => 53,
+            Self::VaultOperatorTicketInvalidOwner => 54,
+            Self::VaultOperatorTicketInvalidData(_) => 55,
+            Self::VaultOperatorTicketInvalidAccountType => 56,
+            Self::VaultOperatorTicketInvalidPda => 57,
+            Self::VaultSlasherTicketEmpty => 58,
+            Self::VaultSlasherTicketInvalidOwner => 59,
+            Self::VaultSlasherTicketInvalidData(_) => 60,
+            Self::VaultSlasherTicketInvalidAccountType => 61,
+            Self::VaultSlasherTicketInvalidPda => 62,
+            Self::VaultInvalidAvsAdmin => 63,
+            Self::VaultInvalidOperatorAdmin => 64,
+            Self::VaultAvsTicektNotWritable => 65,
+            Self::VaultOperatorTicketNotWritable => 66,
+            Self::VaultSlasherTicketNotWritable => 67,
+            Self::VaultOperatorTicketAlreadyDeactivated => 68,
+            Self::VaultAvsTicketInactive => 69,
+            Self::VaultOperatorTicketInactive => 70,
+            Self::VaultAvsSlasherTicketInactive => 71,
+            Self::VaultInvalidSlasherAdmin => 72,
+            Self::VaultAvsOverflow => 73,
+            Self::VaultSlasherOverflow => 74,
+            Self::VaultOperatorOverflow => 75,
+            Self::VaultAvsSlasherOperatorDataEmpty => 76,
+            Self::VaultAvsSlasherOperatorInvalidOwner => 77,
+            Self::VaultAvsSlasherOperatorInvalidData(_) => 78,
+            Self::VaultAvsSlasherOperatorInvalidAccountType => 79,
+            Self::VaultAvsSlasherOperatorInvalidPda => 80,
+            Self::VaultAvsSlasherOperatorNotWritable => 81,
+            Self::VaultAvsSlasherOperatorOverflow => 82,
+            Self::VaultAvsSlasherOperatorMaxSlashableExceeded => 83,
+            Self::VaultStakerWithdrawalTicketEmpty => 84,
+            Self::VaultStakerWithdrawalTicketInvalidOwner => 85,
+            Self::VaultStakerWithdrawalTicketInvalidData(_) => 86,
+            Self::VaultStakerWithdrawalTicketInvalidAccountType => 87,
+            Self::VaultStakerWithdrawalTicketInvalidPda => 88,
+            Self::VaultStakerWithdrawalTicketNotWritable => 89,
+            Self::VaultWithdrawalQueueOverflow => 90,
+            Self::VaultWithdrawalQueueOutOfOrder => 91,
+            Self::VaultNotInEmergencyMode => 92,
+            Self::VaultWithdrawalOverflow => 93,
+            Self::ConfigInvalidAdmin => 94,
+            Self::VaultAvsTicketInvalidServiceFee => 95,
+            Self::VaultAvsServiceFeeNotAgreed => 96,
+            Self::VaultAvsServiceFeeAlreadyPaid => 97,
+            Self::VaultAvsServiceFeeOverflow => 98,
+            Self::VaultMintAuthorityHandoffInvalidTarget => 99,
+            Self::VaultMintAuthorityHandoffNotPending => 100,
+            Self::VaultMintAuthorityHandoffTimelocked => 101,
+            Self::VaultMintAuthorityHandoffOverflow => 102,
+            Self::VaultMintAuthorityMismatch => 103,
+            Self::VaultMigrationNotApproved => 104,
+            Self::VaultMigrationSupportedMintMismatch => 105,
+            Self::VaultWithdrawalExceedsSupply => 106,
+            Self::VaultDepositSlippageExceeded => 107,
+            Self::ConfigVaultsOverflow => 108,
+            Self::VaultInvalidWithdrawalFee => 109,
+            Self::VaultInvalidDepositFee => 110,
+            Self::VaultStakerWithdrawalTicketNotWithdrawable => 111,
+            Self::VaultInvalidPendingAdmin => 112,
+            Self::ConfigInvalidPendingAdmin => 113,
+            Self::ConfigInvalidEpochLength => 114,
+            Self::ConfigInvalidWithdrawalFeeGraceEpochs => 115,
+            Self::VaultOperatorBlacklistEmpty => 116,
+            Self::VaultOperatorBlacklistInvalidOwner => 117,
+            Self::VaultOperatorBlacklistInvalidData(_) => 118,
+            Self::VaultOperatorBlacklistInvalidAccountType => 119,
+            Self::VaultOperatorBlacklistInvalidPda => 120,
+            Self::VaultOperatorBlacklistNotWritable => 121,
+            Self::VaultOperatorBlacklistFull => 122,
+            Self::VaultOperatorAlreadyBlacklisted => 123,
+            Self::VaultOperatorNotBlacklisted => 124,
+            Self::VaultOperatorBlacklisted => 125,
+            Self::VaultInvalidReferralFee => 126,
+            Self::VaultReferrerEmpty => 127,
+            Self::VaultReferrerInvalidOwner => 128,
+            Self::VaultReferrerInvalidData(_) => 129,
+            Self::VaultReferrerInvalidAccountType => 130,
+            Self::VaultReferrerInvalidPda => 131,
+            Self::VaultReferrerNotWritable => 132,
+            Self::VaultReferrerInvalidReferrer => 133,
+            Self::VaultReferrerNothingToClaim => 134,
+            Self::VaultReferrerOverflow => 135,
+            Self::VaultInvalidAccountType => 136,
+            Self::ConfigPaused => 137,
+            Self::VaultPaused => 138,
+            Self::ConfigInvalidProgramFee => 139,
+            Self::ConfigProgramFeeTimelocked => 140,
+            Self::VaultInvalidFeeAdmin => 141,
+            Self::VaultInvalidRewardFee => 142,
+            Self::VaultFeeDeltaExceeded => 143,
+            Self::SlashProposalEmpty => 144,
+            Self::SlashProposalInvalidOwner => 145,
+            Self::SlashProposalInvalidData(_) => 146,
+            Self::SlashProposalInvalidAccountType => 147,
+            Self::SlashProposalInvalidPda => 148,
+            Self::SlashProposalNotWritable => 149,
+            Self::SlashProposalNotPending => 150,
+            Self::SlashProposalVetoWindowOpen => 151,
+            Self::SlashProposalVetoWindowClosed => 152,
+            Self::VaultAvsSlasherTicketMaxSlashableExceeded => 153,
+            Self::VaultCrankTipTooHigh => 154,
+            Self::AccountMigrationNotFound => 155,
+            Self::AccountVersionUnsupported => 156,
+            Self::VaultMintBurnAuthorityNotSet => 157,
+            Self::VaultBalanceUpdateRequired => 158,
+            Self::ConfigInvalidWithdrawalCooldownEpochs => 159,
+            Self::VaultMigrationWithdrawalsQueued => 160,
+            Self::VaultMigrationInsufficientLiquidity => 161,
+            Self::VaultMigrationEmergencyMode => 162,
+            Self::VaultAvsTicketWarmingUp => 163,
+            Self::VaultOperatorTicketWarmingUp => 164,
+            Self::ConfigEpochLengthLocked => 165,
+        }
+    }
+
+    /// Returns the name of the error at the offset, for decoding error codes off-chain
+    pub fn name(offset: u32) -> Option<&'static str> {
+        ERROR_NAMES.get(offset as usize).copied()
+    }
+}
+
+/// Error names indexed by [`VaultCoreError::offset`]
+const ERROR_NAMES: [&str; 166] = [
+    "VaultDepositOverflow",
+    "VaultDepositExceedsCapacity",
+    "VaultFeeCalculationOverflow",
+    "VaultDataEmpty",
+    "VaultInvalidProgramOwner",
+    "VaultInvalidData",
+    "VaultInvalidPda",
+    "VaultExpectedWritable",
+    "VaultSerializationFailed",
+    "VaultAvsAlreadyActive",
+    "VaultInvalidAdmin",
+    "ConfigExpectedWritable",
+    "VaultAvsListExpectedWritable",
+    "VaultDelegationListExpectedWritable",
+    "VaultSlasherListExpectedWritable",
+    "VaultAvsListDataEmpty",
+    "VaultAvsListInvalidProgramOwner",
+    "VaultAvsListInvalidData",
+    "VaultAvsListInvalidAccountType",
+    "VaultAvsListInvalidPda",
+    "VaultDelegationListDataEmpty",
+    "VaultDelegationListInvalidProgramOwner",
+    "VaultDelegationListInvalidData",
+    "VaultDelegationListInvalidAccountType",
+    "VaultDelegationListInvalidPda",
+    "VaultSlasherListInvalidPda",
+    "VaultSlasherListInvalidAccountType",
+    "VaultSlasherListInvalidData",
+    "VaultSlasherListInvalidProgramOwner",
+    "VaultSlasherListDataEmpty",
+    "ConfigInvalidPda",
+    "ConfigInvalidAccountType",
+    "ConfigInvalidData",
+    "ConfigInvalidProgramOwner",
+    "ConfigDataEmpty",
+    "VaultAvsNotSupported",
+    "VaultAvsAlreadyInactive",
+    "VaultDelegationListOperatorAlreadyAdded",
+    "VaultDelegationListOperatorAlreadyRemoved",
+    "VaultDelegationListOperatorNotAdded",
+    "VaultInvalidDelegationAdmin",
+    "VaultSlasherNotFound",
+    "VaultSlasherNotActive",
+    "VaultAvsNotActive",
+    "VaultOperatorNotFound",
+    "VaultOperatorNotActive",
+    "VaultSlashingOverflow",
+    "VaultSlashingUnderflow",
+    "VaultAvsTicketEmpty",
+    "VaultAvsTicketInvalidOwner",
+    "VaultAvsTicketInvalidAccountType",
+    "VaultAvsTicketInvalidData",
+    "VaultAvsTicketInvalidPda",
+    "VaultOperatorTicketEmpty",
+    "VaultOperatorTicketInvalidOwner",
+    "VaultOperatorTicketInvalidData",
+    "VaultOperatorTicketInvalidAccountType",
+    "VaultOperatorTicketInvalidPda",
+    "VaultSlasherTicketEmpty",
+    "VaultSlasherTicketInvalidOwner",
+    "VaultSlasherTicketInvalidData",
+    "VaultSlasherTicketInvalidAccountType",
+    "VaultSlasherTicketInvalidPda",
+    "VaultInvalidAvsAdmin",
+    "VaultInvalidOperatorAdmin",
+    "VaultAvsTicektNotWritable",
+    "VaultOperatorTicketNotWritable",
+    "VaultSlasherTicketNotWritable",
+    "VaultOperatorTicketAlreadyDeactivated",
+    "VaultAvsTicketInactive",
+    "VaultOperatorTicketInactive",
+    "VaultAvsSlasherTicketInactive",
+    "VaultInvalidSlasherAdmin",
+    "VaultAvsOverflow",
+    "VaultSlasherOverflow",
+    "VaultOperatorOverflow",
+    "VaultAvsSlasherOperatorDataEmpty",
+    "VaultAvsSlasherOperatorInvalidOwner",
+    "VaultAvsSlasherOperatorInvalidData",
+    "VaultAvsSlasherOperatorInvalidAccountType",
+    "VaultAvsSlasherOperatorInvalidPda",
+    "VaultAvsSlasherOperatorNotWritable",
+    "VaultAvsSlasherOperatorOverflow",
+    "VaultAvsSlasherOperatorMaxSlashableExceeded",
+    "VaultStakerWithdrawalTicketEmpty",
+    "VaultStakerWithdrawalTicketInvalidOwner",
+    "VaultStakerWithdrawalTicketInvalidData",
+    "VaultStakerWithdrawalTicketInvalidAccountType",
+    "VaultStakerWithdrawalTicketInvalidPda",
+    "VaultStakerWithdrawalTicketNotWritable",
+    "VaultWithdrawalQueueOverflow",
+    "VaultWithdrawalQueueOutOfOrder",
+    "VaultNotInEmergencyMode",
+    "VaultWithdrawalOverflow",
+    "ConfigInvalidAdmin",
+    "VaultAvsTicketInvalidServiceFee",
+    "VaultAvsServiceFeeNotAgreed",
+    "VaultAvsServiceFeeAlreadyPaid",
+    "VaultAvsServiceFeeOverflow",
+    "VaultMintAuthorityHandoffInvalidTarget",
+    "VaultMintAuthorityHandoffNotPending",
+    "VaultMintAuthorityHandoffTimelocked",
+    "VaultMintAuthorityHandoffOverflow",
+    "VaultMintAuthorityMismatch",
+    "VaultMigrationNotApproved",
+    "VaultMigrationSupportedMintMismatch",
+    "VaultWithdrawalExceedsSupply",
+    "VaultDepositSlippageExceeded",
+    "ConfigVaultsOverflow",
+    "VaultInvalidWithdrawalFee",
+    "VaultInvalidDepositFee",
+    "VaultStakerWithdrawalTicketNotWithdrawable",
+    "VaultInvalidPendingAdmin",
+    "ConfigInvalidPendingAdmin",
+    "ConfigInvalidEpochLength",
+    "ConfigInvalidWithdrawalFeeGraceEpochs",
+    "VaultOperatorBlacklistEmpty",
+    "VaultOperatorBlacklistInvalidOwner",
+    "VaultOperatorBlacklistInvalidData",
+    "VaultOperatorBlacklistInvalidAccountType",
+    "VaultOperatorBlacklistInvalidPda",
+    "VaultOperatorBlacklistNotWritable",
+    "VaultOperatorBlacklistFull",
+    "VaultOperatorAlreadyBlacklisted",
+    "VaultOperatorNotBlacklisted",
+    "VaultOperatorBlacklisted",
+    "VaultInvalidReferralFee",
+    "VaultReferrerEmpty",
+    "VaultReferrerInvalidOwner",
+    "VaultReferrerInvalidData",
+    "VaultReferrerInvalidAccountType",
+    "VaultReferrerInvalidPda",
+    "VaultReferrerNotWritable",
+    "VaultReferrerInvalidReferrer",
+    "VaultReferrerNothingToClaim",
+    "VaultReferrerOverflow",
+    "VaultInvalidAccountType",
+    "ConfigPaused",
+    "VaultPaused",
+    "ConfigInvalidProgramFee",
+    "ConfigProgramFeeTimelocked",
+    "VaultInvalidFeeAdmin",
+    "VaultInvalidRewardFee",
+    "VaultFeeDeltaExceeded",
+    "SlashProposalEmpty",
+    "SlashProposalInvalidOwner",
+    "SlashProposalInvalidData",
+    "SlashProposalInvalidAccountType",
+    "SlashProposalInvalidPda",
+    "SlashProposalNotWritable",
+    "SlashProposalNotPending",
+    "SlashProposalVetoWindowOpen",
+    "SlashProposalVetoWindowClosed",
+    "VaultAvsSlasherTicketMaxSlashableExceeded",
+    "VaultCrankTipTooHigh",
+    "AccountMigrationNotFound",
+    "AccountVersionUnsupported",
+    "VaultMintBurnAuthorityNotSet",
+    "VaultBalanceUpdateRequired",
+    "ConfigInvalidWithdrawalCooldownEpochs",
+    "VaultMigrationWithdrawalsQueued",
+    "VaultMigrationInsufficientLiquidity",
+    "VaultMigrationEmergencyMode",
+    "VaultAvsTicketWarmingUp",
+    "VaultOperatorTicketWarmingUp",
+    "ConfigEpochLengthLocked",
+];
+
 impl From<VaultCoreError> for ProgramError {
-    fn from(_value: VaultCoreError) -> Self {
-        Self::Custom(0)
-        // match value {
-        //     VaultCoreError::VaultDepositOverflow => ProgramError::Custom(1000),
-        //     VaultCoreError::VaultDepositExceedsCapacity => ProgramError::Custom(1001),
-        //     VaultCoreError::VaultFeeCalculationOverflow => ProgramError::Custom(1002),
-        //     VaultCoreError::VaultDataEmpty => ProgramError::Custom(1003),
-        //     VaultCoreError::VaultInvalidProgramOwner => ProgramError::Custom(1004),
-        //     VaultCoreError::VaultInvalidData(_) => ProgramError::Custom(1005),
-        //     VaultCoreError::VaultInvalidPda => ProgramError::Custom(1006),
-        //     VaultCoreError::VaultNotWritable => ProgramError::Custom(1007),
-        //     VaultCoreError::VaultSerializationFailed(_) => ProgramError::Custom(1008),
-        //     VaultCoreError::VaultAvsAlreadyActive => ProgramError::Custom(1009),
-        //     VaultCoreError::VaultInvalidAdmin => ProgramError::Custom(1010),
-        // }
+    fn from(value: VaultCoreError) -> Self {
+        Self::Custom(ErrorSource::Vault.code(value.offset()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_names_match_offsets() {
+        for error in [
+            VaultCoreError::VaultDepositOverflow,
+            VaultCoreError::VaultInvalidData(String::new()),
+            VaultCoreError::VaultInvalidAdmin,
+            VaultCoreError::VaultWithdrawalExceedsSupply,
+            VaultCoreError::VaultDepositSlippageExceeded,
+            VaultCoreError::ConfigVaultsOverflow,
+        ] {
+            let name = format!("{error:?}");
+            assert!(name.starts_with(VaultCoreError::name(error.offset()).unwrap()));
+        }
+        assert_eq!(VaultCoreError::name(ERROR_NAMES.len() as u32), None);
+    }
+
+    #[test]
+    fn test_error_code_in_vault_range() {
+        assert_eq!(
+            ProgramError::from(VaultCoreError::VaultDepositExceedsCapacity),
+            ProgramError::Custom(2_001)
+        );
     }
 }
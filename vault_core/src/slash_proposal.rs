@@ -0,0 +1,370 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{VaultCoreError, VaultCoreResult},
+    AccountType,
+};
+
+/// The number of epochs after a slash is proposed during which the slash veto admin of the AVS
+/// can veto it. The slash can be executed by anyone once the window has passed.
+pub const SLASH_VETO_WINDOW_EPOCHS: u64 = 2;
+
+/// The state of a [`SlashProposal`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SlashProposalState {
+    Pending,
+    Vetoed,
+    Executed,
+}
+
+/// A slash filed by a slasher against an operator of a vault, which can be vetoed by the AVS
+/// during [`SLASH_VETO_WINDOW_EPOCHS`] and executed by anyone afterwards. Proposals are kept once
+/// settled so every slash, vetoed or not, can be audited.
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct SlashProposal {
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The vault to slash
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    vault: Pubkey,
+
+    /// The AVS the slasher slashes for
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    avs: Pubkey,
+
+    /// The slasher
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    slasher: Pubkey,
+
+    /// The operator to slash
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    operator: Pubkey,
+
+    /// The hash of the evidence the slash is based on
+    #[serde(serialize_with = "serde_fields::hash")]
+    evidence_hash: [u8; 32],
+
+    /// The amount to slash
+    amount: PodU64,
+
+    /// The epoch the slash was proposed
+    proposed_epoch: PodU64,
+
+    /// The [`SlashProposalState`]
+    state: u8,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 63],
+
+    bump: u8,
+}
+
+impl ZeroCopy for SlashProposal {
+    const DISCRIMINATOR: u8 = AccountType::SlashProposal as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl SlashProposal {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
+    /// The byte offset of the slasher in the account data, for `memcmp` filters
+    pub const SLASHER_OFFSET: usize = 65;
+
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 97;
+
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        vault: Pubkey,
+        avs: Pubkey,
+        slasher: Pubkey,
+        operator: Pubkey,
+        evidence_hash: [u8; 32],
+        amount: u64,
+        proposed_epoch: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            discriminator: AccountType::SlashProposal as u8,
+            vault,
+            avs,
+            slasher,
+            operator,
+            evidence_hash,
+            amount: PodU64::new(amount),
+            proposed_epoch: PodU64::new(proposed_epoch),
+            state: SlashProposalState::Pending as u8,
+            version: Self::VERSION,
+            reserved: [0; 63],
+            bump,
+        }
+    }
+
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    pub const fn avs(&self) -> Pubkey {
+        self.avs
+    }
+
+    pub const fn slasher(&self) -> Pubkey {
+        self.slasher
+    }
+
+    pub const fn operator(&self) -> Pubkey {
+        self.operator
+    }
+
+    pub const fn evidence_hash(&self) -> [u8; 32] {
+        self.evidence_hash
+    }
+
+    pub const fn amount(&self) -> u64 {
+        self.amount.get()
+    }
+
+    pub const fn proposed_epoch(&self) -> u64 {
+        self.proposed_epoch.get()
+    }
+
+    pub const fn state(&self) -> SlashProposalState {
+        match self.state {
+            0 => SlashProposalState::Pending,
+            1 => SlashProposalState::Vetoed,
+            _ => SlashProposalState::Executed,
+        }
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// The first epoch the slash can be executed in
+    pub fn executable_epoch(&self) -> u64 {
+        self.proposed_epoch()
+            .saturating_add(SLASH_VETO_WINDOW_EPOCHS)
+    }
+
+    /// Vetoes the slash, which is only possible while it's pending and within the veto window
+    pub fn veto(&mut self, epoch: u64) -> VaultCoreResult<()> {
+        if self.state() != SlashProposalState::Pending {
+            return Err(VaultCoreError::SlashProposalNotPending);
+        }
+        if epoch >= self.executable_epoch() {
+            return Err(VaultCoreError::SlashProposalVetoWindowClosed);
+        }
+        self.state = SlashProposalState::Vetoed as u8;
+        Ok(())
+    }
+
+    /// Marks the slash executed, which is only possible while it's pending and once the veto
+    /// window has passed
+    pub fn execute(&mut self, epoch: u64) -> VaultCoreResult<()> {
+        if self.state() != SlashProposalState::Pending {
+            return Err(VaultCoreError::SlashProposalNotPending);
+        }
+        if epoch < self.executable_epoch() {
+            return Err(VaultCoreError::SlashProposalVetoWindowOpen);
+        }
+        self.state = SlashProposalState::Executed as u8;
+        Ok(())
+    }
+
+    pub fn seeds(
+        vault: &Pubkey,
+        avs: &Pubkey,
+        slasher: &Pubkey,
+        operator: &Pubkey,
+        evidence_hash: &[u8; 32],
+    ) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"slash_proposal".to_vec(),
+            vault.to_bytes().to_vec(),
+            avs.to_bytes().to_vec(),
+            slasher.to_bytes().to_vec(),
+            operator.to_bytes().to_vec(),
+            evidence_hash.to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        avs: &Pubkey,
+        slasher: &Pubkey,
+        operator: &Pubkey,
+        evidence_hash: &[u8; 32],
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, avs, slasher, operator, evidence_hash);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Deserializes the proposal and checks it's at the address derived from its own seeds, so
+    /// the vault, AVS, slasher and operator it holds can be trusted
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+    ) -> VaultCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(VaultCoreError::SlashProposalEmpty);
+        }
+        if account.owner != program_id {
+            return Err(VaultCoreError::SlashProposalInvalidOwner);
+        }
+
+        let slash_proposal = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                VaultCoreError::SlashProposalInvalidAccountType
+            }
+            e => VaultCoreError::SlashProposalInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(
+            &slash_proposal.vault,
+            &slash_proposal.avs,
+            &slash_proposal.slasher,
+            &slash_proposal.operator,
+            &slash_proposal.evidence_hash,
+        );
+        seeds.push(vec![slash_proposal.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| VaultCoreError::SlashProposalInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(VaultCoreError::SlashProposalInvalidPda);
+        }
+
+        Ok(slash_proposal)
+    }
+}
+
+pub struct SanitizedSlashProposal<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedSlashProposal<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+    ) -> VaultCoreResult<Self> {
+        if expect_writable && !account.is_writable {
+            return Err(VaultCoreError::SlashProposalNotWritable);
+        }
+        SlashProposal::deserialize_checked(program_id, account)?;
+
+        Ok(Self { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn slash_proposal(&self) -> Ref<'_, SlashProposal> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..SlashProposal::LEN])
+        })
+    }
+
+    pub fn slash_proposal_mut(&mut self) -> RefMut<'_, SlashProposal> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..SlashProposal::LEN])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::{SlashProposal, SlashProposalState, SLASH_VETO_WINDOW_EPOCHS};
+    use crate::result::VaultCoreError;
+
+    fn proposal(proposed_epoch: u64) -> SlashProposal {
+        SlashProposal::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            [1; 32],
+            100,
+            proposed_epoch,
+            254,
+        )
+    }
+
+    #[test]
+    fn test_veto_only_within_the_window() {
+        let mut slash_proposal = proposal(3);
+        assert_eq!(
+            slash_proposal.veto(3 + SLASH_VETO_WINDOW_EPOCHS),
+            Err(VaultCoreError::SlashProposalVetoWindowClosed)
+        );
+        slash_proposal
+            .veto(3 + SLASH_VETO_WINDOW_EPOCHS - 1)
+            .unwrap();
+        assert_eq!(slash_proposal.state(), SlashProposalState::Vetoed);
+
+        // a vetoed slash is settled
+        assert_eq!(
+            slash_proposal.execute(3 + SLASH_VETO_WINDOW_EPOCHS),
+            Err(VaultCoreError::SlashProposalNotPending)
+        );
+    }
+
+    #[test]
+    fn test_execute_only_after_the_window() {
+        let mut slash_proposal = proposal(3);
+        assert_eq!(
+            slash_proposal.execute(3 + SLASH_VETO_WINDOW_EPOCHS - 1),
+            Err(VaultCoreError::SlashProposalVetoWindowOpen)
+        );
+        slash_proposal
+            .execute(3 + SLASH_VETO_WINDOW_EPOCHS)
+            .unwrap();
+        assert_eq!(slash_proposal.state(), SlashProposalState::Executed);
+
+        assert_eq!(
+            slash_proposal.execute(3 + SLASH_VETO_WINDOW_EPOCHS),
+            Err(VaultCoreError::SlashProposalNotPending)
+        );
+        assert_eq!(
+            slash_proposal.veto(3),
+            Err(VaultCoreError::SlashProposalNotPending)
+        );
+    }
+}
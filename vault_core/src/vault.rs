@@ -1,4 +1,14 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    counter::Counter,
+    pod::{PodBool, PodU16, PodU64},
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
@@ -6,69 +16,212 @@ use crate::{
     AccountType,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, BorshDeserialize, BorshSerialize)]
+/// The max deposit or withdrawal fee, in basis points
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// The most a fee can move by in one [`Vault::set_fees`] update, in basis points
+pub const MAX_FEE_DELTA_BPS: u16 = 250;
+
+/// The number of epochs a [`Vault::set_fees`] update waits before applying. A change made at any
+/// point of an epoch waits out the whole next one.
+pub const FEE_CHANGE_DELAY_EPOCHS: u64 = 2;
+
+/// The max tip in lamports a vault pays whoever cranks its delegations into a new epoch
+pub const MAX_CRANK_TIP_LAMPORTS: u64 = 100_000;
+
+/// The rewards recognized by [`Vault::update_balance`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BalanceUpdate {
+    /// The tokens the vault held in excess of the tokens deposited
+    pub rewards: u64,
+    /// The LRT to mint to the fee owner as the reward fee
+    pub lrt_to_reward_fee: u64,
+}
+
+/// The LRT minted for a deposit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintSummary {
+    pub lrt_to_depositor: u64,
+    pub lrt_to_fee: u64,
+    pub lrt_to_program_fee: u64,
+    /// The rewards recognized before the deposit, whose reward fee is minted to the fee owner too
+    pub balance_update: BalanceUpdate,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+    Serialize,
+    Pod,
+    Zeroable,
+)]
+#[repr(C)]
 pub struct Vault {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The base account of the LRT
+    #[serde(serialize_with = "serde_fields::pubkey")]
     base: Pubkey,
 
     /// Mint of the LRT token
+    #[serde(serialize_with = "serde_fields::pubkey")]
     lrt_mint: Pubkey,
 
     /// Mint of the token that is supported by the LRT
+    #[serde(serialize_with = "serde_fields::pubkey")]
     supported_mint: Pubkey,
 
     /// Vault admin
+    #[serde(serialize_with = "serde_fields::pubkey")]
     admin: Pubkey,
 
     /// Delegation admin
+    #[serde(serialize_with = "serde_fields::pubkey")]
     delegation_admin: Pubkey,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator_admin: Pubkey,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs_admin: Pubkey,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     slasher_admin: Pubkey,
 
     /// Fee account owner
+    #[serde(serialize_with = "serde_fields::pubkey")]
     fee_owner: Pubkey,
 
     /// Optional mint signer
+    #[serde(serialize_with = "serde_fields::pubkey")]
     mint_burn_authority: Pubkey,
 
     /// Max capacity of tokens in the vault
-    capacity: u64,
+    capacity: PodU64,
 
     /// The index of the vault in the vault list
-    vault_index: u64,
+    vault_index: PodU64,
 
     /// The total number of LRT in circulation
-    lrt_supply: u64,
+    lrt_supply: PodU64,
 
     /// The total number of tokens deposited
-    tokens_deposited: u64,
+    tokens_deposited: PodU64,
 
     /// The deposit fee in basis points
-    deposit_fee_bps: u16,
+    deposit_fee_bps: PodU16,
 
     /// The withdrawal fee in basis points
-    withdrawal_fee_bps: u16,
+    withdrawal_fee_bps: PodU16,
+
+    avs_count: Counter,
+
+    operator_count: Counter,
+
+    slasher_count: Counter,
+
+    /// The sequence number assigned to the next enqueued withdrawal ticket
+    withdrawal_queue_tail: Counter,
+
+    /// The sequence number of the next withdrawal ticket to be processed
+    withdrawal_queue_head: Counter,
+
+    /// Set by the configuration admin when an AVS or operator is compromised. While active,
+    /// withdrawals are honored pro-rata from liquid assets and delegation cooldowns are finalized
+    /// immediately
+    emergency_mode: PodBool,
 
-    avs_count: u64,
+    /// The account the LRT mint authority is being handed off to, or the default pubkey if no
+    /// handoff is pending
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    mint_authority_handoff_target: Pubkey,
 
-    operator_count: u64,
+    /// The slot the pending mint authority handoff was proposed
+    mint_authority_handoff_slot: PodU64,
 
-    slasher_count: u64,
+    /// The vault stakers may migrate their deposits to, or the default pubkey if migrations out
+    /// of this vault aren't open
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    migration_target: Pubkey,
+
+    /// A raised withdrawal fee in basis points that applies from `pending_withdrawal_fee_epoch`,
+    /// so depositors can still withdraw at the old fee until then
+    pending_withdrawal_fee_bps: PodU16,
+
+    /// The epoch the pending withdrawal fee takes effect, or zero if no fee change is pending
+    pending_withdrawal_fee_epoch: PodU64,
+
+    /// The key proposed to take over as admin, or the default pubkey if none
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    pending_admin: Pubkey,
+
+    /// The share of the deposit fee paid to the referrer of a deposit, in basis points
+    referral_fee_bps: PodU16,
+
+    /// Set by the configuration admin during an incident to halt deposits, withdrawals, slashing
+    /// and delegation changes in this vault only
+    paused: PodBool,
+
+    /// The admin allowed to schedule fee changes with [`Vault::set_fees`]
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    fee_admin: Pubkey,
+
+    /// The fee on vault rewards in basis points
+    reward_fee_bps: PodU16,
+
+    /// The deposit fee in basis points that applies from `pending_fees_epoch`
+    pending_deposit_fee_bps: PodU16,
+
+    /// The reward fee in basis points that applies from `pending_fees_epoch`
+    pending_reward_fee_bps: PodU16,
+
+    /// The epoch the pending deposit and reward fees take effect, or zero if none are pending
+    pending_fees_epoch: PodU64,
+
+    /// The lamports paid to whoever updates the delegations of the vault into a new epoch, out of
+    /// the lamports the vault holds above its rent-exempt minimum
+    crank_tip_lamports: PodU64,
+
+    /// The epoch the tokens deposited were last reconciled with the vault token account, see
+    /// [`Vault::update_balance`]
+    last_balance_update_epoch: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// The AVS service fees accrued on the tickets of the vault and not paid yet, as the vault
+    /// didn't hold enough rewards. Held back from the rewards harvested until paid, see
+    /// [`Vault::update_balance`]
+    service_fees_owed: PodU64,
 
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 54],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
+impl ZeroCopy for Vault {
+    const DISCRIMINATOR: u8 = AccountType::Vault as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl Vault {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -82,7 +235,7 @@ impl Vault {
         bump: u8,
     ) -> Self {
         Self {
-            account_type: AccountType::Vault,
+            discriminator: AccountType::Vault as u8,
             base,
             lrt_mint,
             supported_mint,
@@ -93,53 +246,313 @@ impl Vault {
             slasher_admin: admin,
             fee_owner: admin,
             mint_burn_authority: Pubkey::default(),
-            capacity: u64::MAX,
-            vault_index: lrt_index,
-            lrt_supply: 0,
-            tokens_deposited: 0,
-            deposit_fee_bps,
-            withdrawal_fee_bps,
-            avs_count: 0,
-            operator_count: 0,
-            slasher_count: 0,
-            reserved: [0; 128],
+            capacity: PodU64::new(u64::MAX),
+            vault_index: PodU64::new(lrt_index),
+            lrt_supply: PodU64::new(0),
+            tokens_deposited: PodU64::new(0),
+            deposit_fee_bps: PodU16::new(deposit_fee_bps),
+            withdrawal_fee_bps: PodU16::new(withdrawal_fee_bps),
+            avs_count: Counter::new(0),
+            operator_count: Counter::new(0),
+            slasher_count: Counter::new(0),
+            withdrawal_queue_tail: Counter::new(0),
+            withdrawal_queue_head: Counter::new(0),
+            emergency_mode: PodBool::new(false),
+            mint_authority_handoff_target: Pubkey::default(),
+            mint_authority_handoff_slot: PodU64::new(0),
+            migration_target: Pubkey::default(),
+            pending_withdrawal_fee_bps: PodU16::new(0),
+            pending_withdrawal_fee_epoch: PodU64::new(0),
+            pending_admin: Pubkey::default(),
+            referral_fee_bps: PodU16::new(0),
+            paused: PodBool::new(false),
+            fee_admin: admin,
+            reward_fee_bps: PodU16::new(0),
+            pending_deposit_fee_bps: PodU16::new(0),
+            pending_reward_fee_bps: PodU16::new(0),
+            pending_fees_epoch: PodU64::new(0),
+            crank_tip_lamports: PodU64::new(0),
+            last_balance_update_epoch: PodU64::new(0),
+            version: Self::VERSION,
+            service_fees_owed: PodU64::new(0),
+            reserved: [0; 54],
             bump,
         }
     }
 
     pub const fn avs_count(&self) -> u64 {
-        self.avs_count
+        self.avs_count.get()
     }
 
     pub fn increment_avs_count(&mut self) -> VaultCoreResult<()> {
-        self.avs_count = self
-            .avs_count
-            .checked_add(1)
-            .ok_or(VaultCoreError::VaultAvsOverflow)?;
+        self.avs_count
+            .increment("avs_count", VaultCoreError::VaultAvsOverflow)?;
         Ok(())
     }
 
     pub const fn operator_count(&self) -> u64 {
-        self.operator_count
+        self.operator_count.get()
     }
 
     pub fn increment_operator_count(&mut self) -> VaultCoreResult<()> {
-        self.operator_count = self
-            .operator_count
-            .checked_add(1)
-            .ok_or(VaultCoreError::VaultOperatorOverflow)?;
+        self.operator_count
+            .increment("operator_count", VaultCoreError::VaultOperatorOverflow)?;
         Ok(())
     }
 
     pub const fn slasher_count(&self) -> u64 {
-        self.slasher_count
+        self.slasher_count.get()
     }
 
     pub fn increment_slasher_count(&mut self) -> VaultCoreResult<()> {
-        self.slasher_count = self
-            .slasher_count
-            .checked_add(1)
-            .ok_or(VaultCoreError::VaultSlasherOverflow)?;
+        self.slasher_count
+            .increment("slasher_count", VaultCoreError::VaultSlasherOverflow)?;
+        Ok(())
+    }
+
+    pub const fn withdrawal_queue_tail(&self) -> u64 {
+        self.withdrawal_queue_tail.get()
+    }
+
+    pub const fn withdrawal_queue_head(&self) -> u64 {
+        self.withdrawal_queue_head.get()
+    }
+
+    /// Reserves the next position in the withdrawal queue, returning the sequence number
+    /// that shall be stored in the withdrawal ticket
+    pub fn enqueue_withdrawal(&mut self) -> VaultCoreResult<u64> {
+        let sequence = self.withdrawal_queue_tail.get();
+        self.withdrawal_queue_tail.increment(
+            "withdrawal_queue_tail",
+            VaultCoreError::VaultWithdrawalQueueOverflow,
+        )?;
+        Ok(sequence)
+    }
+
+    /// Withdrawal tickets shall be processed in the order they were enqueued
+    pub const fn check_withdrawal_next_in_queue(&self, sequence: u64) -> VaultCoreResult<()> {
+        if sequence != self.withdrawal_queue_head.get() {
+            return Err(VaultCoreError::VaultWithdrawalQueueOutOfOrder);
+        }
+        Ok(())
+    }
+
+    /// Marks the withdrawal ticket at the front of the queue as processed
+    pub fn dequeue_withdrawal(&mut self, sequence: u64) -> VaultCoreResult<()> {
+        self.check_withdrawal_next_in_queue(sequence)?;
+        self.withdrawal_queue_head.increment(
+            "withdrawal_queue_head",
+            VaultCoreError::VaultWithdrawalQueueOverflow,
+        )?;
+        Ok(())
+    }
+
+    pub const fn emergency_mode(&self) -> bool {
+        self.emergency_mode.get()
+    }
+
+    pub fn set_emergency_mode(&mut self, emergency_mode: bool) {
+        self.emergency_mode = PodBool::new(emergency_mode);
+    }
+
+    pub const fn paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = PodBool::new(paused);
+    }
+
+    /// Fails while the vault is paused, see [`Vault::paused`]
+    pub fn check_not_paused(&self) -> VaultCoreResult<()> {
+        if self.paused() {
+            return Err(VaultCoreError::VaultPaused);
+        }
+        Ok(())
+    }
+
+    /// Calculates the amount of supported tokens owed for an LRT amount while the vault is in
+    /// emergency mode. Every holder receives their pro-rata share of the liquid assets so that
+    /// early withdrawers can't drain the vault at the expense of the rest.
+    ///
+    /// # Arguments
+    /// * `lrt_amount` - The amount of LRT being withdrawn
+    /// * `liquid_amount` - The amount of supported tokens held by the vault and not delegated
+    pub fn calculate_emergency_withdrawal_amount(
+        &self,
+        lrt_amount: u64,
+        liquid_amount: u64,
+    ) -> VaultCoreResult<u64> {
+        if !self.emergency_mode.get() {
+            return Err(VaultCoreError::VaultNotInEmergencyMode);
+        }
+        if self.lrt_supply.get() == 0 {
+            return Ok(0);
+        }
+        let available = liquid_amount.min(self.tokens_deposited.get());
+        let amount = (lrt_amount as u128)
+            .checked_mul(available as u128)
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?
+            .checked_div(self.lrt_supply.get() as u128)
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+        u64::try_from(amount).map_err(|_| VaultCoreError::VaultWithdrawalOverflow)
+    }
+
+    pub fn mint_authority_handoff_target(&self) -> Option<Pubkey> {
+        if self.mint_authority_handoff_target != Pubkey::default() {
+            Some(self.mint_authority_handoff_target)
+        } else {
+            None
+        }
+    }
+
+    pub const fn mint_authority_handoff_slot(&self) -> u64 {
+        self.mint_authority_handoff_slot.get()
+    }
+
+    /// Starts the timelock for handing the LRT mint authority off to `target`, replacing any
+    /// handoff that is already pending
+    pub fn propose_mint_authority_handoff(
+        &mut self,
+        target: Pubkey,
+        slot: u64,
+    ) -> VaultCoreResult<()> {
+        if target == Pubkey::default() {
+            return Err(VaultCoreError::VaultMintAuthorityHandoffInvalidTarget);
+        }
+        self.mint_authority_handoff_target = target;
+        self.mint_authority_handoff_slot = PodU64::new(slot);
+        Ok(())
+    }
+
+    /// Returns the handoff target once `timelock` slots have passed since the handoff was
+    /// proposed
+    pub fn check_mint_authority_handoff_ready(
+        &self,
+        slot: u64,
+        timelock: u64,
+    ) -> VaultCoreResult<Pubkey> {
+        let target = self
+            .mint_authority_handoff_target()
+            .ok_or(VaultCoreError::VaultMintAuthorityHandoffNotPending)?;
+        let unlock_slot = self
+            .mint_authority_handoff_slot()
+            .checked_add(timelock)
+            .ok_or(VaultCoreError::VaultMintAuthorityHandoffOverflow)?;
+        if slot < unlock_slot {
+            return Err(VaultCoreError::VaultMintAuthorityHandoffTimelocked);
+        }
+        Ok(target)
+    }
+
+    pub fn clear_mint_authority_handoff(&mut self) -> VaultCoreResult<()> {
+        if self.mint_authority_handoff_target().is_none() {
+            return Err(VaultCoreError::VaultMintAuthorityHandoffNotPending);
+        }
+        self.mint_authority_handoff_target = Pubkey::default();
+        self.mint_authority_handoff_slot = PodU64::new(0);
+        Ok(())
+    }
+
+    pub fn migration_target(&self) -> Option<Pubkey> {
+        if self.migration_target != Pubkey::default() {
+            Some(self.migration_target)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_migration_target(&mut self, migration_target: Pubkey) {
+        self.migration_target = migration_target;
+    }
+
+    /// Deposits may only be migrated to the vault the configuration admin opened migrations to
+    pub fn check_migration_target(&self, destination: &Pubkey) -> VaultCoreResult<()> {
+        if self.migration_target() != Some(*destination) {
+            return Err(VaultCoreError::VaultMigrationNotApproved);
+        }
+        Ok(())
+    }
+
+    /// Burns LRT, returning the pro-rata amount of supported tokens backing it
+    pub fn burn_and_withdraw(&mut self, lrt_amount: u64) -> VaultCoreResult<u64> {
+        let amount = self.calculate_withdrawal_amount(lrt_amount)?;
+
+        self.burn(lrt_amount, amount)?;
+        Ok(amount)
+    }
+
+    /// Burns LRT migrating to another vault, returning the pro-rata amount of supported tokens
+    /// backing it. Migrations skip the withdrawal cooldown, so they're only served once the
+    /// withdrawal queue is empty and from the tokens that aren't delegated, which queued
+    /// withdrawals are paid from first. They're closed in emergency mode, where withdrawals are
+    /// paid pro-rata instead.
+    ///
+    /// # Arguments
+    /// * `lrt_amount` - The amount of LRT being migrated
+    /// * `liquid_amount` - The amount of supported tokens held by the vault and not delegated
+    pub fn burn_and_migrate(
+        &mut self,
+        lrt_amount: u64,
+        liquid_amount: u64,
+    ) -> VaultCoreResult<u64> {
+        if self.emergency_mode.get() {
+            return Err(VaultCoreError::VaultMigrationEmergencyMode);
+        }
+        if self.withdrawal_queue_head.get() != self.withdrawal_queue_tail.get() {
+            return Err(VaultCoreError::VaultMigrationWithdrawalsQueued);
+        }
+        let amount = self.calculate_withdrawal_amount(lrt_amount)?;
+        if amount > liquid_amount {
+            return Err(VaultCoreError::VaultMigrationInsufficientLiquidity);
+        }
+
+        self.burn(lrt_amount, amount)?;
+        Ok(amount)
+    }
+
+    /// The pro-rata amount of supported tokens backing an LRT amount
+    fn calculate_withdrawal_amount(&self, lrt_amount: u64) -> VaultCoreResult<u64> {
+        if lrt_amount > self.lrt_supply.get() {
+            return Err(VaultCoreError::VaultWithdrawalExceedsSupply);
+        }
+        let amount = (lrt_amount as u128)
+            .checked_mul(self.tokens_deposited.get() as u128)
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?
+            .checked_div(self.lrt_supply.get() as u128)
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+        u64::try_from(amount).map_err(|_| VaultCoreError::VaultWithdrawalOverflow)
+    }
+
+    /// Burns LRT while the vault is in emergency mode, returning the pro-rata share of the liquid
+    /// supported tokens backing it, see [`Vault::calculate_emergency_withdrawal_amount`]
+    pub fn burn_and_withdraw_emergency(
+        &mut self,
+        lrt_amount: u64,
+        liquid_amount: u64,
+    ) -> VaultCoreResult<u64> {
+        if lrt_amount > self.lrt_supply.get() {
+            return Err(VaultCoreError::VaultWithdrawalExceedsSupply);
+        }
+        let amount = self.calculate_emergency_withdrawal_amount(lrt_amount, liquid_amount)?;
+
+        self.burn(lrt_amount, amount)?;
+        Ok(amount)
+    }
+
+    /// Removes the burned LRT from the supply and the withdrawn tokens from the deposits
+    fn burn(&mut self, lrt_amount: u64, amount: u64) -> VaultCoreResult<()> {
+        let lrt_supply = self
+            .lrt_supply()
+            .checked_sub(lrt_amount)
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+        let tokens_deposited = self
+            .tokens_deposited()
+            .checked_sub(amount)
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+        self.lrt_supply = PodU64::new(lrt_supply);
+        self.tokens_deposited = PodU64::new(tokens_deposited);
+
         Ok(())
     }
 
@@ -164,11 +577,199 @@ impl Vault {
     }
 
     pub const fn deposit_fee_bps(&self) -> u16 {
-        self.deposit_fee_bps
+        self.deposit_fee_bps.get()
+    }
+
+    pub const fn referral_fee_bps(&self) -> u16 {
+        self.referral_fee_bps.get()
+    }
+
+    pub const fn crank_tip_lamports(&self) -> u64 {
+        self.crank_tip_lamports.get()
+    }
+
+    /// Sets the tip paid to whoever cranks the delegations into a new epoch, up to
+    /// [`MAX_CRANK_TIP_LAMPORTS`]
+    pub fn set_crank_tip_lamports(&mut self, lamports: u64) -> VaultCoreResult<()> {
+        if lamports > MAX_CRANK_TIP_LAMPORTS {
+            return Err(VaultCoreError::VaultCrankTipTooHigh);
+        }
+        self.crank_tip_lamports = PodU64::new(lamports);
+        Ok(())
+    }
+
+    /// Sets the share of the deposit fee paid to referrers, up to all of it
+    pub fn set_referral_fee_bps(&mut self, fee_bps: u16) -> VaultCoreResult<()> {
+        if fee_bps > MAX_FEE_BPS {
+            return Err(VaultCoreError::VaultInvalidReferralFee);
+        }
+        self.referral_fee_bps = PodU16::new(fee_bps);
+        Ok(())
+    }
+
+    /// Calculates the share of the deposit fee, in LRT, paid to the referrer of a deposit
+    pub fn calculate_referral_fee(&self, lrt_fee: u64) -> VaultCoreResult<u64> {
+        let fee = lrt_fee
+            .checked_mul(self.referral_fee_bps.get() as u64)
+            .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?
+            .checked_div(10_000)
+            .unwrap();
+        Ok(fee)
     }
 
     pub const fn withdrawal_fee_bps(&self) -> u16 {
-        self.withdrawal_fee_bps
+        self.withdrawal_fee_bps.get()
+    }
+
+    /// The raised withdrawal fee and the epoch it takes effect, if a fee increase is pending
+    pub const fn pending_withdrawal_fee(&self) -> Option<(u16, u64)> {
+        if self.pending_withdrawal_fee_epoch.get() == 0 {
+            None
+        } else {
+            Some((
+                self.pending_withdrawal_fee_bps.get(),
+                self.pending_withdrawal_fee_epoch.get(),
+            ))
+        }
+    }
+
+    /// The withdrawal fee in effect at the epoch, which is the pending fee once its grace window
+    /// has passed
+    pub const fn withdrawal_fee_bps_at(&self, epoch: u64) -> u16 {
+        match self.pending_withdrawal_fee() {
+            Some((fee_bps, effective_epoch)) if epoch >= effective_epoch => fee_bps,
+            _ => self.withdrawal_fee_bps.get(),
+        }
+    }
+
+    pub const fn fee_admin(&self) -> Pubkey {
+        self.fee_admin
+    }
+
+    pub fn set_fee_admin(&mut self, fee_admin: Pubkey) {
+        self.fee_admin = fee_admin;
+    }
+
+    pub fn check_fee_admin(&self, fee_admin: &Pubkey) -> VaultCoreResult<()> {
+        if self.fee_admin != *fee_admin {
+            return Err(VaultCoreError::VaultInvalidFeeAdmin);
+        }
+        Ok(())
+    }
+
+    pub const fn reward_fee_bps(&self) -> u16 {
+        self.reward_fee_bps.get()
+    }
+
+    /// The deposit and reward fees scheduled by [`Vault::set_fees`] and the epoch they take
+    /// effect, if a change is pending
+    pub const fn pending_fees(&self) -> Option<(u16, u16, u64)> {
+        if self.pending_fees_epoch.get() == 0 {
+            None
+        } else {
+            Some((
+                self.pending_deposit_fee_bps.get(),
+                self.pending_reward_fee_bps.get(),
+                self.pending_fees_epoch.get(),
+            ))
+        }
+    }
+
+    /// The deposit fee in effect at the epoch
+    pub const fn deposit_fee_bps_at(&self, epoch: u64) -> u16 {
+        match self.pending_fees() {
+            Some((fee_bps, _, effective_epoch)) if epoch >= effective_epoch => fee_bps,
+            _ => self.deposit_fee_bps.get(),
+        }
+    }
+
+    /// The reward fee in effect at the epoch
+    pub const fn reward_fee_bps_at(&self, epoch: u64) -> u16 {
+        match self.pending_fees() {
+            Some((_, fee_bps, effective_epoch)) if epoch >= effective_epoch => fee_bps,
+            _ => self.reward_fee_bps.get(),
+        }
+    }
+
+    /// The deposit, withdrawal and reward fees once every pending change has taken effect
+    pub const fn scheduled_fees(&self) -> (u16, u16, u16) {
+        let (deposit_fee_bps, reward_fee_bps) = match self.pending_fees() {
+            Some((deposit_fee_bps, reward_fee_bps, _)) => (deposit_fee_bps, reward_fee_bps),
+            None => (self.deposit_fee_bps.get(), self.reward_fee_bps.get()),
+        };
+        let withdrawal_fee_bps = match self.pending_withdrawal_fee() {
+            Some((withdrawal_fee_bps, _)) => withdrawal_fee_bps,
+            None => self.withdrawal_fee_bps.get(),
+        };
+        (deposit_fee_bps, withdrawal_fee_bps, reward_fee_bps)
+    }
+
+    /// Applies the pending fees that have taken effect by the epoch
+    pub fn apply_pending_fees(&mut self, epoch: u64) {
+        self.deposit_fee_bps = PodU16::new(self.deposit_fee_bps_at(epoch));
+        self.reward_fee_bps = PodU16::new(self.reward_fee_bps_at(epoch));
+        if self.pending_fees_epoch.get() != 0 && epoch >= self.pending_fees_epoch.get() {
+            self.pending_deposit_fee_bps = PodU16::new(0);
+            self.pending_reward_fee_bps = PodU16::new(0);
+            self.pending_fees_epoch = PodU64::new(0);
+        }
+        self.withdrawal_fee_bps = PodU16::new(self.withdrawal_fee_bps_at(epoch));
+        if self.pending_withdrawal_fee_epoch.get() != 0
+            && epoch >= self.pending_withdrawal_fee_epoch.get()
+        {
+            self.pending_withdrawal_fee_bps = PodU16::new(0);
+            self.pending_withdrawal_fee_epoch = PodU64::new(0);
+        }
+    }
+
+    /// Schedules the deposit, withdrawal and reward fees to apply [`FEE_CHANGE_DELAY_EPOCHS`]
+    /// after the epoch, returning the epochs the deposit and reward fees and the withdrawal fee
+    /// take effect. A raised withdrawal fee also waits out `withdrawal_fee_grace_epochs` and
+    /// never lands before a raise already pending. Each fee can only move by
+    /// [`MAX_FEE_DELTA_BPS`] from the fee in effect, and a new update replaces any pending one,
+    /// so stakers always get a full epoch to withdraw before a bounded change lands.
+    pub fn set_fees(
+        &mut self,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        epoch: u64,
+        withdrawal_fee_grace_epochs: u64,
+    ) -> VaultCoreResult<(u64, u64)> {
+        if deposit_fee_bps > MAX_FEE_BPS {
+            return Err(VaultCoreError::VaultInvalidDepositFee);
+        }
+        if withdrawal_fee_bps > MAX_FEE_BPS {
+            return Err(VaultCoreError::VaultInvalidWithdrawalFee);
+        }
+        if reward_fee_bps > MAX_FEE_BPS {
+            return Err(VaultCoreError::VaultInvalidRewardFee);
+        }
+        self.apply_pending_fees(epoch);
+        for (current, new) in [
+            (self.deposit_fee_bps(), deposit_fee_bps),
+            (self.withdrawal_fee_bps(), withdrawal_fee_bps),
+            (self.reward_fee_bps(), reward_fee_bps),
+        ] {
+            if current.abs_diff(new) > MAX_FEE_DELTA_BPS {
+                return Err(VaultCoreError::VaultFeeDeltaExceeded);
+            }
+        }
+
+        let effective_epoch = epoch.saturating_add(FEE_CHANGE_DELAY_EPOCHS);
+        let withdrawal_fee_epoch = if withdrawal_fee_bps > self.withdrawal_fee_bps() {
+            effective_epoch
+                .max(epoch.saturating_add(withdrawal_fee_grace_epochs))
+                .max(self.pending_withdrawal_fee_epoch.get())
+        } else {
+            effective_epoch
+        };
+        self.pending_deposit_fee_bps = PodU16::new(deposit_fee_bps);
+        self.pending_reward_fee_bps = PodU16::new(reward_fee_bps);
+        self.pending_fees_epoch = PodU64::new(effective_epoch);
+        self.pending_withdrawal_fee_bps = PodU16::new(withdrawal_fee_bps);
+        self.pending_withdrawal_fee_epoch = PodU64::new(withdrawal_fee_epoch);
+        Ok((effective_epoch, withdrawal_fee_epoch))
     }
 
     pub fn mint_burn_authority(&self) -> Option<Pubkey> {
@@ -183,55 +784,194 @@ impl Vault {
         self.mint_burn_authority = mint_burn_authority;
     }
 
+    /// Makes deposits and withdrawals permissionless again
+    pub fn clear_mint_burn_authority(&mut self) -> VaultCoreResult<()> {
+        if self.mint_burn_authority().is_none() {
+            return Err(VaultCoreError::VaultMintBurnAuthorityNotSet);
+        }
+        self.mint_burn_authority = Pubkey::default();
+        Ok(())
+    }
+
     pub fn set_tokens_deposited(&mut self, tokens_deposited: u64) {
-        self.tokens_deposited = tokens_deposited;
+        self.tokens_deposited = PodU64::new(tokens_deposited);
     }
 
     /// Deposit tokens into the vault
     pub fn deposit_and_mint_with_capacity_check(&mut self, amount: u64) -> VaultCoreResult<u64> {
         // the number of tokens to mint is the pro-rata amount of the total tokens deposited and the LRT supply
-        let num_tokens_to_mint = if self.tokens_deposited == 0 {
+        let num_tokens_to_mint = if self.tokens_deposited.get() == 0 {
             amount
         } else {
             amount
-                .checked_mul(self.lrt_supply)
+                .checked_mul(self.lrt_supply.get())
                 .ok_or(VaultCoreError::VaultDepositOverflow)?
-                .checked_div(self.tokens_deposited)
+                .checked_div(self.tokens_deposited.get())
                 .ok_or(VaultCoreError::VaultDepositOverflow)?
         };
 
         // deposit tokens + check against capacity
         let total_post_deposit = self
-            .tokens_deposited
+            .tokens_deposited()
             .checked_add(amount)
             .ok_or(VaultCoreError::VaultDepositOverflow)?;
-        if total_post_deposit > self.capacity {
+        if total_post_deposit > self.capacity.get() {
             return Err(VaultCoreError::VaultDepositExceedsCapacity);
         }
 
         let lrt_supply = self
-            .lrt_supply
+            .lrt_supply()
             .checked_add(num_tokens_to_mint)
             .ok_or(VaultCoreError::VaultDepositOverflow)?;
 
-        self.lrt_supply = lrt_supply;
-        self.tokens_deposited = total_post_deposit;
+        self.lrt_supply = PodU64::new(lrt_supply);
+        self.tokens_deposited = PodU64::new(total_post_deposit);
 
         Ok(num_tokens_to_mint)
     }
 
     pub fn calculate_deposit_fee(&self, lrt_amount: u64) -> VaultCoreResult<u64> {
         let fee = lrt_amount
-            .checked_mul(self.deposit_fee_bps as u64)
+            .checked_mul(self.deposit_fee_bps.get() as u64)
             .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?
             .checked_div(10_000)
             .unwrap();
         Ok(fee)
     }
 
-    pub fn calculate_withdraw_fee(&self, lrt_amount: u64) -> VaultCoreResult<u64> {
+    pub const fn last_balance_update_epoch(&self) -> u64 {
+        self.last_balance_update_epoch.get()
+    }
+
+    pub const fn service_fees_owed(&self) -> u64 {
+        self.service_fees_owed.get()
+    }
+
+    /// Records AVS service fees accrued on a ticket of the vault as owed until they're paid
+    pub fn accrue_service_fees_owed(&mut self, fee: u64) -> VaultCoreResult<()> {
+        let owed = self
+            .service_fees_owed()
+            .checked_add(fee)
+            .ok_or(VaultCoreError::VaultAvsServiceFeeOverflow)?;
+        self.service_fees_owed = PodU64::new(owed);
+        Ok(())
+    }
+
+    /// Clears AVS service fees owed once they're paid to the AVS. Saturates, as fees accrued
+    /// before the liability was tracked on the vault were never recorded.
+    pub fn pay_service_fees_owed(&mut self, amount: u64) {
+        self.service_fees_owed = PodU64::new(self.service_fees_owed().saturating_sub(amount));
+    }
+
+    /// The vault token account balance less the surplus held back for the AVS service fees owed,
+    /// which is the balance the tokens deposited are reconciled with
+    fn balance_after_service_fees_owed(&self, vault_token_balance: u64) -> u64 {
+        let surplus = vault_token_balance.saturating_sub(self.tokens_deposited());
+        vault_token_balance - surplus.min(self.service_fees_owed())
+    }
+
+    /// Reconciles the tokens deposited with the vault token account balance. Tokens the vault
+    /// holds in excess of the tokens deposited, e.g. airdropped or donated to it, are harvested as
+    /// rewards: the reward fee in effect at the epoch is minted to the fee owner as LRT, valued at
+    /// the exchange rate after the rewards, and the rest accrues to the LRT holders. A balance
+    /// below the tokens deposited is recognized as a loss of the holders.
+    ///
+    /// AVS service fees are settled before rewards are harvested: the surplus covering the
+    /// [`Vault::service_fees_owed`] is held back for the AVSs and isn't harvested, so harvesting
+    /// ahead of a service fee payment never erases the fee. Fees accrued since the last payment
+    /// aren't known to the vault yet, so the service fee should be paid first to be settled out
+    /// of the rewards being harvested.
+    pub fn update_balance(
+        &mut self,
+        vault_token_balance: u64,
+        epoch: u64,
+    ) -> VaultCoreResult<BalanceUpdate> {
+        let vault_token_balance = self.balance_after_service_fees_owed(vault_token_balance);
+        let rewards = vault_token_balance.saturating_sub(self.tokens_deposited());
+        let fee_tokens = (rewards as u128)
+            .checked_mul(self.reward_fee_bps_at(epoch) as u128)
+            .and_then(|fee| fee.checked_div(10_000))
+            .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?;
+        // nobody holds LRT to take the fee from while the supply is zero
+        let lrt_to_reward_fee = if fee_tokens == 0 || self.lrt_supply() == 0 {
+            0
+        } else {
+            let lrt_to_reward_fee = fee_tokens
+                .checked_mul(self.lrt_supply() as u128)
+                .and_then(|lrt| lrt.checked_div((vault_token_balance as u128) - fee_tokens))
+                .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?;
+            u64::try_from(lrt_to_reward_fee)
+                .map_err(|_| VaultCoreError::VaultFeeCalculationOverflow)?
+        };
+
+        let lrt_supply = self
+            .lrt_supply()
+            .checked_add(lrt_to_reward_fee)
+            .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?;
+        self.lrt_supply = PodU64::new(lrt_supply);
+        self.tokens_deposited = PodU64::new(vault_token_balance);
+        self.last_balance_update_epoch = PodU64::new(epoch);
+
+        Ok(BalanceUpdate {
+            rewards,
+            lrt_to_reward_fee,
+        })
+    }
+
+    /// Reconciles the tokens deposited with the vault token account balance before a withdrawal
+    /// or slash, which can't mint the reward fee. Rewards carrying a reward fee shall be harvested
+    /// with [`Vault::update_balance`] first; the surplus held back for the AVS service fees owed
+    /// isn't a reward.
+    pub fn sync_balance(&mut self, vault_token_balance: u64, epoch: u64) -> VaultCoreResult<()> {
+        if self.balance_after_service_fees_owed(vault_token_balance) > self.tokens_deposited()
+            && self.reward_fee_bps_at(epoch) > 0
+        {
+            return Err(VaultCoreError::VaultBalanceUpdateRequired);
+        }
+        self.update_balance(vault_token_balance, epoch)?;
+        Ok(())
+    }
+
+    /// Deposits tokens into the vault, returning the LRT to mint to the depositor, to the fee
+    /// owner and to the program fee wallet. `vault_token_balance` is the vault token account
+    /// balance before the deposit, so tokens sent to the vault out-of-band are harvested first,
+    /// see [`Vault::update_balance`]. The program fee is taken out of the LRT minted before the
+    /// deposit fee.
+    pub fn mint_with_fee(
+        &mut self,
+        vault_token_balance: u64,
+        amount: u64,
+        program_fee_bps: u16,
+        epoch: u64,
+    ) -> VaultCoreResult<MintSummary> {
+        let balance_update = self.update_balance(vault_token_balance, epoch)?;
+
+        let lrt_minted = self.deposit_and_mint_with_capacity_check(amount)?;
+        let lrt_to_program_fee = lrt_minted
+            .checked_mul(program_fee_bps as u64)
+            .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?
+            .checked_div(10_000)
+            .unwrap();
+        let lrt_after_program_fee = lrt_minted
+            .checked_sub(lrt_to_program_fee)
+            .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?;
+        let lrt_to_fee = self.calculate_deposit_fee(lrt_after_program_fee)?;
+        let lrt_to_depositor = lrt_after_program_fee
+            .checked_sub(lrt_to_fee)
+            .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?;
+
+        Ok(MintSummary {
+            lrt_to_depositor,
+            lrt_to_fee,
+            lrt_to_program_fee,
+            balance_update,
+        })
+    }
+
+    /// Calculates the withdrawal fee on an LRT amount at the fee in effect at the epoch
+    pub fn calculate_withdraw_fee(&self, lrt_amount: u64, epoch: u64) -> VaultCoreResult<u64> {
         let fee = lrt_amount
-            .checked_mul(self.withdrawal_fee_bps as u64)
+            .checked_mul(self.withdrawal_fee_bps_at(epoch) as u64)
             .ok_or(VaultCoreError::VaultFeeCalculationOverflow)?
             .checked_div(10_000)
             .unwrap();
@@ -239,20 +979,20 @@ impl Vault {
     }
 
     pub const fn tokens_deposited(&self) -> u64 {
-        self.tokens_deposited
+        self.tokens_deposited.get()
     }
 
     pub fn set_lrt_supply(&mut self, lrt_supply: u64) {
-        self.lrt_supply = lrt_supply;
+        self.lrt_supply = PodU64::new(lrt_supply);
     }
 
     pub fn increment_lrt_supply(&mut self, amount: u64) -> Option<u64> {
-        self.lrt_supply = self.lrt_supply.checked_add(amount)?;
-        Some(self.lrt_supply)
+        self.lrt_supply = PodU64::new(self.lrt_supply.get().checked_add(amount)?);
+        Some(self.lrt_supply.get())
     }
 
     pub const fn lrt_supply(&self) -> u64 {
-        self.lrt_supply
+        self.lrt_supply.get()
     }
 
     pub const fn bump(&self) -> u8 {
@@ -260,27 +1000,48 @@ impl Vault {
     }
 
     pub fn is_struct_valid(&self) -> bool {
-        self.account_type == AccountType::Vault
+        self.discriminator == Self::DISCRIMINATOR
     }
 
     pub const fn capacity(&self) -> u64 {
-        self.capacity
+        self.capacity.get()
     }
 
     pub fn set_capacity(&mut self, capacity: u64) {
-        self.capacity = capacity;
+        self.capacity = PodU64::new(capacity);
     }
 
     pub const fn vault_index(&self) -> u64 {
-        self.vault_index
+        self.vault_index.get()
     }
 
     pub const fn admin(&self) -> Pubkey {
         self.admin
     }
 
-    pub fn set_admin(&mut self, admin: Pubkey) {
-        self.admin = admin;
+    /// The key proposed to take over as admin, if a transfer is pending
+    pub fn pending_admin(&self) -> Option<Pubkey> {
+        if self.pending_admin != Pubkey::default() {
+            Some(self.pending_admin)
+        } else {
+            None
+        }
+    }
+
+    /// Proposes a new admin, replacing any transfer that is already pending. The transfer
+    /// completes once the new admin accepts it.
+    pub fn set_pending_admin(&mut self, pending_admin: Pubkey) {
+        self.pending_admin = pending_admin;
+    }
+
+    /// Completes the pending admin transfer to `new_admin`
+    pub fn accept_admin(&mut self, new_admin: &Pubkey) -> VaultCoreResult<()> {
+        if self.pending_admin() != Some(*new_admin) {
+            return Err(VaultCoreError::VaultInvalidPendingAdmin);
+        }
+        self.admin = *new_admin;
+        self.pending_admin = Pubkey::default();
+        Ok(())
     }
 
     pub fn check_admin(&self, admin: &Pubkey) -> VaultCoreResult<()> {
@@ -373,13 +1134,10 @@ impl Vault {
         }
 
         // The AvsState shall be properly deserialized and valid struct
-        let state = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| VaultCoreError::VaultInvalidData(e.to_string()))?;
-        if !state.is_struct_valid() {
-            return Err(VaultCoreError::VaultInvalidData(
-                "Vault account header is invalid".to_string(),
-            ));
-        }
+        let state = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => VaultCoreError::VaultInvalidAccountType,
+            e => VaultCoreError::VaultInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(&state.base);
         seeds.push(vec![state.bump]);
@@ -396,7 +1154,6 @@ impl Vault {
 
 pub struct SanitizedVault<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    vault: Box<Vault>,
 }
 
 impl<'a, 'info> SanitizedVault<'a, 'info> {
@@ -408,27 +1165,25 @@ impl<'a, 'info> SanitizedVault<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(VaultCoreError::VaultExpectedWritable);
         }
-        let vault = Box::new(Vault::deserialize_checked(program_id, account)?);
+        Vault::deserialize_checked(program_id, account)?;
 
-        Ok(SanitizedVault { account, vault })
+        Ok(SanitizedVault { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn vault(&self) -> &Vault {
-        &self.vault
+    pub fn vault(&self) -> Ref<'_, Vault> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..Vault::LEN])
+        })
     }
 
-    pub fn vault_mut(&mut self) -> &mut Vault {
-        &mut self.vault
-    }
-
-    pub fn save(&self) -> VaultCoreResult<()> {
-        borsh::to_writer(&mut self.account.data.borrow_mut()[..], &self.vault)
-            .map_err(|e| VaultCoreError::VaultSerializationFailed(e.to_string()))?;
-        Ok(())
+    pub fn vault_mut(&mut self) -> RefMut<'_, Vault> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..Vault::LEN])
+        })
     }
 }
 
@@ -436,7 +1191,7 @@ impl<'a, 'info> SanitizedVault<'a, 'info> {
 mod tests {
     use solana_program::pubkey::Pubkey;
 
-    use crate::vault::{Vault, VaultCoreError};
+    use crate::vault::{BalanceUpdate, Vault, VaultCoreError};
 
     #[test]
     fn test_deposit_ratio_simple_ok() {
@@ -520,4 +1275,445 @@ mod tests {
             Err(VaultCoreError::VaultDepositExceedsCapacity)
         );
     }
+
+    #[test]
+    fn test_withdrawal_queue_fifo_ok() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        assert_eq!(vault.enqueue_withdrawal().unwrap(), 0);
+        assert_eq!(vault.enqueue_withdrawal().unwrap(), 1);
+        assert_eq!(vault.enqueue_withdrawal().unwrap(), 2);
+        assert_eq!(vault.withdrawal_queue_tail(), 3);
+
+        vault.dequeue_withdrawal(0).unwrap();
+        vault.dequeue_withdrawal(1).unwrap();
+        assert_eq!(vault.withdrawal_queue_head(), 2);
+    }
+
+    #[test]
+    fn test_withdrawal_queue_out_of_order_fails() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        vault.enqueue_withdrawal().unwrap();
+        vault.enqueue_withdrawal().unwrap();
+
+        assert_eq!(
+            vault.dequeue_withdrawal(1),
+            Err(VaultCoreError::VaultWithdrawalQueueOutOfOrder)
+        );
+        assert_eq!(vault.withdrawal_queue_head(), 0);
+
+        vault.dequeue_withdrawal(0).unwrap();
+        assert_eq!(
+            vault.dequeue_withdrawal(0),
+            Err(VaultCoreError::VaultWithdrawalQueueOutOfOrder)
+        );
+        vault.dequeue_withdrawal(1).unwrap();
+    }
+
+    #[test]
+    fn test_emergency_withdrawal_pro_rata_ok() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        vault.deposit_and_mint_with_capacity_check(1_000).unwrap();
+
+        assert_eq!(
+            vault.calculate_emergency_withdrawal_amount(100, 400),
+            Err(VaultCoreError::VaultNotInEmergencyMode)
+        );
+
+        vault.set_emergency_mode(true);
+        // 40% of the vault is liquid, so a holder of 10% of the supply receives 4% of the deposits
+        assert_eq!(
+            vault.calculate_emergency_withdrawal_amount(100, 400),
+            Ok(40)
+        );
+        // liquidity in excess of the deposits isn't paid out
+        assert_eq!(
+            vault.calculate_emergency_withdrawal_amount(100, 2_000),
+            Ok(100)
+        );
+
+        assert_eq!(vault.burn_and_withdraw_emergency(100, 400), Ok(40));
+        assert_eq!(vault.lrt_supply(), 900);
+        assert_eq!(vault.tokens_deposited(), 960);
+    }
+
+    #[test]
+    fn test_burn_and_migrate_liquidity() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        vault.deposit_and_mint_with_capacity_check(1_000).unwrap();
+
+        assert_eq!(
+            vault.burn_and_migrate(500, 400),
+            Err(VaultCoreError::VaultMigrationInsufficientLiquidity)
+        );
+        assert_eq!(vault.burn_and_migrate(400, 400), Ok(400));
+        assert_eq!(vault.lrt_supply(), 600);
+        assert_eq!(vault.tokens_deposited(), 600);
+
+        // queued withdrawals are served before migrations
+        vault.enqueue_withdrawal().unwrap();
+        assert_eq!(
+            vault.burn_and_migrate(100, 600),
+            Err(VaultCoreError::VaultMigrationWithdrawalsQueued)
+        );
+        vault.dequeue_withdrawal(0).unwrap();
+        assert_eq!(vault.burn_and_migrate(100, 600), Ok(100));
+
+        vault.set_emergency_mode(true);
+        assert_eq!(
+            vault.burn_and_migrate(100, 500),
+            Err(VaultCoreError::VaultMigrationEmergencyMode)
+        );
+    }
+
+    #[test]
+    fn test_mint_authority_handoff_timelock() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        assert_eq!(
+            vault.check_mint_authority_handoff_ready(100, 10),
+            Err(VaultCoreError::VaultMintAuthorityHandoffNotPending)
+        );
+        assert_eq!(
+            vault.propose_mint_authority_handoff(Pubkey::default(), 100),
+            Err(VaultCoreError::VaultMintAuthorityHandoffInvalidTarget)
+        );
+
+        let target = Pubkey::new_unique();
+        vault.propose_mint_authority_handoff(target, 100).unwrap();
+        assert_eq!(
+            vault.check_mint_authority_handoff_ready(109, 10),
+            Err(VaultCoreError::VaultMintAuthorityHandoffTimelocked)
+        );
+        assert_eq!(
+            vault.check_mint_authority_handoff_ready(110, 10),
+            Ok(target)
+        );
+
+        vault.clear_mint_authority_handoff().unwrap();
+        assert_eq!(vault.mint_authority_handoff_target(), None);
+        assert_eq!(
+            vault.clear_mint_authority_handoff(),
+            Err(VaultCoreError::VaultMintAuthorityHandoffNotPending)
+        );
+    }
+
+    #[test]
+    fn test_clear_mint_burn_authority() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        assert_eq!(
+            vault.clear_mint_burn_authority(),
+            Err(VaultCoreError::VaultMintBurnAuthorityNotSet)
+        );
+
+        let mint_burn_authority = Pubkey::new_unique();
+        vault.set_mint_burn_authority(mint_burn_authority);
+        assert_eq!(vault.mint_burn_authority(), Some(mint_burn_authority));
+        vault.clear_mint_burn_authority().unwrap();
+        assert_eq!(vault.mint_burn_authority(), None);
+    }
+
+    #[test]
+    fn test_burn_and_withdraw_after_slashed_ok() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        vault.set_tokens_deposited(90);
+        vault.set_lrt_supply(100);
+
+        assert_eq!(
+            vault.burn_and_withdraw(101),
+            Err(VaultCoreError::VaultWithdrawalExceedsSupply)
+        );
+        assert_eq!(vault.burn_and_withdraw(50), Ok(45));
+        assert_eq!(vault.tokens_deposited(), 45);
+        assert_eq!(vault.lrt_supply(), 50);
+    }
+
+    #[test]
+    fn test_referral_fee_is_a_share_of_the_deposit_fee() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+        );
+        assert_eq!(vault.calculate_referral_fee(1_000), Ok(0));
+
+        vault.set_referral_fee_bps(2_500).unwrap();
+        let lrt_fee = vault.calculate_deposit_fee(100_000).unwrap();
+        assert_eq!(lrt_fee, 1_000);
+        assert_eq!(vault.calculate_referral_fee(lrt_fee), Ok(250));
+        assert_eq!(
+            vault.set_referral_fee_bps(10_001),
+            Err(VaultCoreError::VaultInvalidReferralFee)
+        );
+        assert_eq!(vault.referral_fee_bps(), 2_500);
+    }
+
+    #[test]
+    fn test_program_fee_is_taken_before_the_deposit_fee() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+        );
+        let summary = vault.mint_with_fee(0, 100_000, 50, 0).unwrap();
+        assert_eq!(summary.lrt_to_program_fee, 500);
+        assert_eq!(summary.lrt_to_fee, 995);
+        assert_eq!(summary.lrt_to_depositor, 98_505);
+        assert_eq!(vault.lrt_supply(), 100_000);
+    }
+
+    #[test]
+    fn test_update_balance_takes_reward_fee() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        vault.mint_with_fee(0, 100_000, 0, 0).unwrap();
+        assert_eq!(vault.set_fees(0, 0, 250, 0, 0), Ok((2, 2)));
+
+        // 2.5% of the 10_000 tokens of rewards are the fee, so the fee owner ends up with the LRT
+        // worth 250 of the 110_000 tokens
+        let update = vault.update_balance(110_000, 2).unwrap();
+        assert_eq!(
+            update,
+            BalanceUpdate {
+                rewards: 10_000,
+                lrt_to_reward_fee: 227,
+            }
+        );
+        assert_eq!(vault.tokens_deposited(), 110_000);
+        assert_eq!(vault.lrt_supply(), 100_227);
+        assert_eq!(vault.last_balance_update_epoch(), 2);
+
+        // nothing left to harvest, and losses are recognized without a fee
+        assert_eq!(
+            vault.update_balance(110_000, 3),
+            Ok(BalanceUpdate::default())
+        );
+        assert_eq!(
+            vault.update_balance(100_000, 3),
+            Ok(BalanceUpdate::default())
+        );
+        assert_eq!(vault.tokens_deposited(), 100_000);
+    }
+
+    #[test]
+    fn test_update_balance_holds_back_service_fees_owed() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        vault.mint_with_fee(0, 100_000, 0, 0).unwrap();
+        vault.accrue_service_fees_owed(3_000).unwrap();
+
+        // the surplus covering the fees owed isn't harvested
+        let update = vault.update_balance(102_000, 1).unwrap();
+        assert_eq!(update.rewards, 0);
+        assert_eq!(vault.tokens_deposited(), 100_000);
+
+        let update = vault.update_balance(105_000, 1).unwrap();
+        assert_eq!(update.rewards, 2_000);
+        assert_eq!(vault.tokens_deposited(), 102_000);
+
+        // once paid, the surplus left is harvested
+        vault.pay_service_fees_owed(3_000);
+        assert_eq!(vault.service_fees_owed(), 0);
+        let update = vault.update_balance(103_000, 1).unwrap();
+        assert_eq!(update.rewards, 1_000);
+        assert_eq!(vault.tokens_deposited(), 103_000);
+
+        // the fees owed don't shield a loss
+        vault.accrue_service_fees_owed(500).unwrap();
+        vault.update_balance(90_000, 1).unwrap();
+        assert_eq!(vault.tokens_deposited(), 90_000);
+        vault.pay_service_fees_owed(1_000);
+        assert_eq!(vault.service_fees_owed(), 0);
+    }
+
+    #[test]
+    fn test_sync_balance_requires_update_for_rewards_with_fee() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+        );
+        vault.mint_with_fee(0, 100_000, 0, 0).unwrap();
+
+        // without a reward fee the rewards accrue to the holders right away
+        assert_eq!(vault.sync_balance(101_000, 0), Ok(()));
+        assert_eq!(vault.tokens_deposited(), 101_000);
+        assert_eq!(vault.lrt_supply(), 100_000);
+
+        vault.set_fees(0, 0, 250, 0, 0).unwrap();
+        assert_eq!(
+            vault.sync_balance(102_000, 2),
+            Err(VaultCoreError::VaultBalanceUpdateRequired)
+        );
+        assert_eq!(vault.sync_balance(90_000, 2), Ok(()));
+        assert_eq!(vault.tokens_deposited(), 90_000);
+    }
+
+    #[test]
+    fn test_set_fees_waits_a_full_epoch_and_bounds_each_change() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            100,
+            100,
+            0,
+        );
+        assert_eq!(vault.set_fees(300, 350, 250, 5, 2), Ok((7, 7)));
+        assert_eq!(vault.pending_fees(), Some((300, 250, 7)));
+        assert_eq!(vault.deposit_fee_bps_at(6), 100);
+        assert_eq!(vault.withdrawal_fee_bps_at(6), 100);
+        assert_eq!(vault.reward_fee_bps_at(6), 0);
+        assert_eq!(vault.deposit_fee_bps_at(7), 300);
+        assert_eq!(vault.withdrawal_fee_bps_at(7), 350);
+        assert_eq!(vault.reward_fee_bps_at(7), 250);
+
+        // the delta is measured from the fees in effect, so replacing a pending change can't
+        // stack increases
+        assert_eq!(
+            vault.set_fees(400, 100, 0, 6, 2),
+            Err(VaultCoreError::VaultFeeDeltaExceeded)
+        );
+        assert_eq!(
+            vault.set_fees(100, 100, 10_001, 6, 2),
+            Err(VaultCoreError::VaultInvalidRewardFee)
+        );
+
+        vault.apply_pending_fees(7);
+        assert_eq!(vault.pending_fees(), None);
+        assert_eq!(vault.pending_withdrawal_fee(), None);
+        assert_eq!(
+            (
+                vault.deposit_fee_bps(),
+                vault.withdrawal_fee_bps(),
+                vault.reward_fee_bps()
+            ),
+            (300, 350, 250)
+        );
+        assert_eq!(vault.set_fees(550, 100, 0, 7, 2), Ok((9, 9)));
+    }
+
+    #[test]
+    fn test_set_fees_withdrawal_fee_raise_waits_out_grace_period() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            100,
+            100,
+            0,
+        );
+
+        // a raise waits out the longer grace period, other changes the fee change delay
+        assert_eq!(vault.set_fees(100, 300, 0, 5, 4), Ok((7, 9)));
+        assert_eq!(vault.pending_withdrawal_fee(), Some((300, 9)));
+        assert_eq!(vault.withdrawal_fee_bps_at(8), 100);
+        assert_eq!(vault.withdrawal_fee_bps_at(9), 300);
+
+        // updating the other fees keeps the pending raise from landing any earlier
+        let (_, withdrawal_fee_bps, _) = vault.scheduled_fees();
+        assert_eq!(vault.set_fees(200, withdrawal_fee_bps, 0, 6, 1), Ok((8, 9)));
+        assert_eq!(vault.scheduled_fees(), (200, 300, 0));
+
+        // a cut lands after the fee change delay, replacing the pending raise
+        assert_eq!(vault.set_fees(200, 50, 0, 6, 4), Ok((8, 8)));
+        assert_eq!(vault.withdrawal_fee_bps_at(7), 100);
+        assert_eq!(vault.withdrawal_fee_bps_at(8), 50);
+    }
 }
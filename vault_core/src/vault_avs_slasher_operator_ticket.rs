@@ -1,5 +1,14 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{VaultCoreError, VaultCoreResult},
@@ -8,36 +17,72 @@ use crate::{
 
 /// Represents a vault AVS slasher operator ticket, which tracks how much an operator
 /// has been slashed by a slasher for a given AVS and vault for a given epoch.
-#[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
 pub struct VaultAvsSlasherOperatorTicket {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The vault slashed
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
     /// The AVS slashed
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs: Pubkey,
 
     /// The slasher
+    #[serde(serialize_with = "serde_fields::pubkey")]
     slasher: Pubkey,
 
     /// The operator
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator: Pubkey,
 
     /// The epoch
-    epoch: u64,
+    epoch: PodU64,
 
     /// The amount slashed for the given epoch
-    slashed: u64,
+    slashed: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
 
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 127],
 
     bump: u8,
 }
 
+impl ZeroCopy for VaultAvsSlasherOperatorTicket {
+    const DISCRIMINATOR: u8 = AccountType::VaultAvsSlasherOperatorTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl VaultAvsSlasherOperatorTicket {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
+    /// The byte offset of the slasher in the account data, for `memcmp` filters
+    pub const SLASHER_OFFSET: usize = 65;
+
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 97;
+
     pub const fn new(
         vault: Pubkey,
         avs: Pubkey,
@@ -48,14 +93,15 @@ impl VaultAvsSlasherOperatorTicket {
         bump: u8,
     ) -> Self {
         Self {
-            account_type: AccountType::VaultAvsSlasherOperatorTicket,
+            discriminator: AccountType::VaultAvsSlasherOperatorTicket as u8,
             vault,
             avs,
             slasher,
             operator,
-            epoch,
-            slashed,
-            reserved: [0; 128],
+            epoch: PodU64::new(epoch),
+            slashed: PodU64::new(slashed),
+            version: Self::VERSION,
+            reserved: [0; 127],
             bump,
         }
     }
@@ -77,18 +123,19 @@ impl VaultAvsSlasherOperatorTicket {
     }
 
     pub const fn epoch(&self) -> u64 {
-        self.epoch
+        self.epoch.get()
     }
 
     pub const fn slashed(&self) -> u64 {
-        self.slashed
+        self.slashed.get()
     }
 
     pub fn increment_slashed_amount(&mut self, amount: u64) -> VaultCoreResult<()> {
-        self.slashed = self
-            .slashed
+        let slashed = self
+            .slashed()
             .checked_add(amount)
             .ok_or(VaultCoreError::VaultAvsSlasherOperatorOverflow)?;
+        self.slashed = PodU64::new(slashed);
         Ok(())
     }
 
@@ -102,7 +149,7 @@ impl VaultAvsSlasherOperatorTicket {
         max_slashable_per_epoch: u64,
     ) -> VaultCoreResult<()> {
         if self
-            .slashed
+            .slashed()
             .checked_add(slash_amount)
             .ok_or(VaultCoreError::VaultAvsSlasherOperatorOverflow)?
             > max_slashable_per_epoch
@@ -161,13 +208,12 @@ impl VaultAvsSlasherOperatorTicket {
 
         // The AvsState shall be properly deserialized and valid struct
         let vault_avs_slasher_operator_ticket =
-            Self::deserialize(&mut account.data.borrow_mut().as_ref())
-                .map_err(|e| VaultCoreError::VaultAvsSlasherOperatorInvalidData(e.to_string()))?;
-        if vault_avs_slasher_operator_ticket.account_type
-            != AccountType::VaultAvsSlasherOperatorTicket
-        {
-            return Err(VaultCoreError::VaultAvsSlasherOperatorInvalidAccountType);
-        }
+            *Self::load(&account.data.borrow()).map_err(|e| match e {
+                ZeroCopyError::InvalidDiscriminator { .. } => {
+                    VaultCoreError::VaultAvsSlasherOperatorInvalidAccountType
+                }
+                e => VaultCoreError::VaultAvsSlasherOperatorInvalidData(e.to_string()),
+            })?;
 
         let mut seeds = Self::seeds(vault, avs, slasher, operator, epoch);
         seeds.push(vec![vault_avs_slasher_operator_ticket.bump]);
@@ -184,7 +230,6 @@ impl VaultAvsSlasherOperatorTicket {
 
 pub struct SanitizedVaultAvsSlasherOperatorTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    vault_avs_slasher_operator_ticket: Box<VaultAvsSlasherOperatorTicket>,
 }
 
 impl<'a, 'info> SanitizedVaultAvsSlasherOperatorTicket<'a, 'info> {
@@ -202,34 +247,28 @@ impl<'a, 'info> SanitizedVaultAvsSlasherOperatorTicket<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(VaultCoreError::VaultAvsSlasherOperatorNotWritable);
         }
-        let vault_avs_slasher_operator_ticket =
-            Box::new(VaultAvsSlasherOperatorTicket::deserialize_checked(
-                program_id, account, vault, avs, slasher, operator, epoch,
-            )?);
+        VaultAvsSlasherOperatorTicket::deserialize_checked(
+            program_id, account, vault, avs, slasher, operator, epoch,
+        )?;
 
-        Ok(Self {
-            account,
-            vault_avs_slasher_operator_ticket,
-        })
+        Ok(Self { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn vault_avs_slasher_operator_ticket(&self) -> &VaultAvsSlasherOperatorTicket {
-        &self.vault_avs_slasher_operator_ticket
-    }
-
-    pub fn vault_avs_slasher_operator_ticket_mut(&mut self) -> &mut VaultAvsSlasherOperatorTicket {
-        &mut self.vault_avs_slasher_operator_ticket
+    pub fn vault_avs_slasher_operator_ticket(&self) -> Ref<'_, VaultAvsSlasherOperatorTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultAvsSlasherOperatorTicket::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &*self.vault_avs_slasher_operator_ticket,
-        )?;
-        Ok(())
+    pub fn vault_avs_slasher_operator_ticket_mut(
+        &mut self,
+    ) -> RefMut<'_, VaultAvsSlasherOperatorTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..VaultAvsSlasherOperatorTicket::LEN])
+        })
     }
 }
@@ -1,38 +1,85 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    slot_toggled_field::SlotToggle,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{VaultCoreError, VaultCoreResult},
     AccountType,
 };
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
 pub struct VaultAvsSlasherTicket {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs: Pubkey,
 
+    #[serde(serialize_with = "serde_fields::pubkey")]
     slasher: Pubkey,
 
-    max_slashable_per_epoch: u64,
+    max_slashable_per_epoch: PodU64,
 
     /// The index
-    index: u64,
+    index: PodU64,
 
     /// The slot toggle
     state: SlotToggle,
 
+    /// The epoch [`VaultAvsSlasherTicket::slashed_this_epoch`] was last recorded in
+    slashed_epoch: PodU64,
+
+    /// The amount slashed by the slasher across all operators of the vault in `slashed_epoch`
+    slashed_this_epoch: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 111],
 
     bump: u8,
 }
 
+impl ZeroCopy for VaultAvsSlasherTicket {
+    const DISCRIMINATOR: u8 = AccountType::VaultAvsSlasherTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl VaultAvsSlasherTicket {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
+    /// The byte offset of the slasher in the account data, for `memcmp` filters
+    pub const SLASHER_OFFSET: usize = 65;
+
     pub const fn new(
         vault: Pubkey,
         avs: Pubkey,
@@ -43,14 +90,17 @@ impl VaultAvsSlasherTicket {
         bump: u8,
     ) -> Self {
         Self {
-            account_type: AccountType::VaultAvsSlasherTicket,
+            discriminator: AccountType::VaultAvsSlasherTicket as u8,
             vault,
             avs,
             slasher,
-            max_slashable_per_epoch,
-            index,
+            max_slashable_per_epoch: PodU64::new(max_slashable_per_epoch),
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            slashed_epoch: PodU64::new(0),
+            slashed_this_epoch: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 111],
             bump,
         }
     }
@@ -68,17 +118,50 @@ impl VaultAvsSlasherTicket {
     }
 
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn max_slashable_per_epoch(&self) -> u64 {
-        self.max_slashable_per_epoch
+        self.max_slashable_per_epoch.get()
     }
 
     pub const fn state(&self) -> &SlotToggle {
         &self.state
     }
 
+    /// The amount slashed across all operators of the vault in the epoch, which starts from zero
+    /// every epoch
+    pub const fn slashed_amount_in_epoch(&self, epoch: u64) -> u64 {
+        if self.slashed_epoch.get() == epoch {
+            self.slashed_this_epoch.get()
+        } else {
+            0
+        }
+    }
+
+    /// The amount the slasher can still slash from the vault in the epoch under the cap
+    pub const fn remaining_slashable(&self, epoch: u64, max_slashable_per_epoch: u64) -> u64 {
+        max_slashable_per_epoch.saturating_sub(self.slashed_amount_in_epoch(epoch))
+    }
+
+    /// Records a slash in the epoch, failing if it takes the amount slashed in the epoch over
+    /// the cap
+    pub fn record_slash(
+        &mut self,
+        epoch: u64,
+        amount: u64,
+        max_slashable_per_epoch: u64,
+    ) -> VaultCoreResult<()> {
+        let slashed = self
+            .slashed_amount_in_epoch(epoch)
+            .checked_add(amount)
+            .filter(|slashed| *slashed <= max_slashable_per_epoch)
+            .ok_or(VaultCoreError::VaultAvsSlasherTicketMaxSlashableExceeded)?;
+        self.slashed_epoch = PodU64::new(epoch);
+        self.slashed_this_epoch = PodU64::new(slashed);
+        Ok(())
+    }
+
     pub const fn check_active(&self, slot: u64) -> VaultCoreResult<()> {
         if self.state.is_active(slot) {
             Ok(())
@@ -122,11 +205,12 @@ impl VaultAvsSlasherTicket {
             return Err(VaultCoreError::VaultSlasherTicketInvalidOwner);
         }
 
-        let ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| VaultCoreError::VaultSlasherTicketInvalidData(e.to_string()))?;
-        if ticket.account_type != AccountType::VaultAvsSlasherTicket {
-            return Err(VaultCoreError::VaultSlasherTicketInvalidAccountType);
-        }
+        let ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                VaultCoreError::VaultSlasherTicketInvalidAccountType
+            }
+            e => VaultCoreError::VaultSlasherTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(vault, avs, slasher);
         seeds.push(vec![ticket.bump]);
@@ -142,7 +226,6 @@ impl VaultAvsSlasherTicket {
 
 pub struct SanitizedVaultAvsSlasherTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    vault_slasher_ticket: Box<VaultAvsSlasherTicket>,
 }
 
 impl<'a, 'info> SanitizedVaultAvsSlasherTicket<'a, 'info> {
@@ -157,33 +240,58 @@ impl<'a, 'info> SanitizedVaultAvsSlasherTicket<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(VaultCoreError::VaultSlasherTicketNotWritable);
         }
-        let vault_slasher_ticket = Box::new(VaultAvsSlasherTicket::deserialize_checked(
-            program_id, account, vault, avs, slasher,
-        )?);
+        VaultAvsSlasherTicket::deserialize_checked(program_id, account, vault, avs, slasher)?;
 
-        Ok(SanitizedVaultAvsSlasherTicket {
-            account,
-            vault_slasher_ticket,
-        })
+        Ok(SanitizedVaultAvsSlasherTicket { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn vault_avs_slasher_ticket(&self) -> &VaultAvsSlasherTicket {
-        &self.vault_slasher_ticket
+    pub fn vault_avs_slasher_ticket(&self) -> Ref<'_, VaultAvsSlasherTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultAvsSlasherTicket::LEN])
+        })
     }
 
-    pub fn vault_slasher_ticket_mut(&mut self) -> &mut VaultAvsSlasherTicket {
-        &mut self.vault_slasher_ticket
+    pub fn vault_slasher_ticket_mut(&mut self) -> RefMut<'_, VaultAvsSlasherTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..VaultAvsSlasherTicket::LEN])
+        })
     }
+}
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &self.vault_slasher_ticket,
-        )?;
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::VaultAvsSlasherTicket;
+    use crate::result::VaultCoreError;
+
+    #[test]
+    fn test_slashed_amount_resets_every_epoch() {
+        let mut ticket = VaultAvsSlasherTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+            254,
+        );
+        ticket.record_slash(3, 60, 100).unwrap();
+        assert_eq!(ticket.remaining_slashable(3, 100), 40);
+        assert_eq!(
+            ticket.record_slash(3, 41, 100),
+            Err(VaultCoreError::VaultAvsSlasherTicketMaxSlashableExceeded)
+        );
+        ticket.record_slash(3, 40, 100).unwrap();
+        assert_eq!(ticket.slashed_amount_in_epoch(3), 100);
+
+        // a new epoch starts with the full cap
+        assert_eq!(ticket.remaining_slashable(4, 100), 100);
+        ticket.record_slash(4, 100, 100).unwrap();
+        assert_eq!(ticket.slashed_amount_in_epoch(3), 0);
     }
 }
@@ -1,44 +1,104 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::{PodU16, PodU64},
+    serde_fields,
+    slot_toggled_field::{SlotToggle, SlotToggleState},
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{VaultCoreError, VaultCoreResult},
     AccountType,
 };
 
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+/// The maximum service fee a vault can agree to pay an AVS, in basis points
+pub const MAX_SERVICE_FEE_BPS: u16 = 10_000;
+
+#[derive(
+    Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
 pub struct VaultAvsTicket {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The vault account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
     /// The avs account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     avs: Pubkey,
 
     /// The index
-    index: u64,
+    index: PodU64,
 
     /// The slot toggle
     state: SlotToggle,
 
+    /// The service fee the vault has agreed to pay the AVS, in basis points of delegated stake
+    /// per epoch. Shall match the fee on the AVS side for the fee to be collected.
+    service_fee_bps: PodU16,
+
+    /// The last epoch the service fee was paid up to
+    last_service_fee_epoch: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// The slash proposals filed with the vault against operators of the AVS and neither vetoed
+    /// nor executed yet. Proposals filed before the counter was added aren't counted.
+    pending_slash_proposals: PodU64,
+
+    /// The service fee accrued to the AVS and not paid yet, as the vault didn't hold enough
+    /// rewards. Paid before any fee accrued later.
+    service_fee_owed: PodU64,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 111],
 
     bump: u8,
 }
 
+impl ZeroCopy for VaultAvsTicket {
+    const DISCRIMINATOR: u8 = AccountType::VaultAvsTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl VaultAvsTicket {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the AVS in the account data, for `memcmp` filters
+    pub const AVS_OFFSET: usize = 33;
+
     pub const fn new(vault: Pubkey, avs: Pubkey, index: u64, slot_added: u64, bump: u8) -> Self {
         Self {
-            account_type: AccountType::VaultAvsTicket,
+            discriminator: AccountType::VaultAvsTicket as u8,
             vault,
             avs,
-            index,
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            service_fee_bps: PodU16::new(0),
+            last_service_fee_epoch: PodU64::new(0),
+            version: Self::VERSION,
+            pending_slash_proposals: PodU64::new(0),
+            service_fee_owed: PodU64::new(0),
+            reserved: [0; 111],
             bump,
         }
     }
@@ -52,13 +112,112 @@ impl VaultAvsTicket {
     }
 
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn state(&self) -> &SlotToggle {
         &self.state
     }
 
+    pub const fn service_fee_bps(&self) -> u16 {
+        self.service_fee_bps.get()
+    }
+
+    pub const fn last_service_fee_epoch(&self) -> u64 {
+        self.last_service_fee_epoch.get()
+    }
+
+    /// Sets the service fee the vault agrees to pay the AVS. Fees start accruing from the
+    /// given epoch.
+    pub fn set_service_fee_bps(&mut self, service_fee_bps: u16, epoch: u64) -> VaultCoreResult<()> {
+        if service_fee_bps > MAX_SERVICE_FEE_BPS {
+            return Err(VaultCoreError::VaultAvsTicketInvalidServiceFee);
+        }
+        self.service_fee_bps = PodU16::new(service_fee_bps);
+        self.last_service_fee_epoch = PodU64::new(epoch);
+        Ok(())
+    }
+
+    /// Calculates the service fee owed to the AVS for every epoch since the fee was last paid and
+    /// marks the fee as paid up to the given epoch. When the AVS agreed to the fee after the vault
+    /// set it, the fee only accrues from the epoch the AVS agreed in.
+    ///
+    /// # Arguments
+    /// * `avs_service_fee_bps` - The service fee set by the AVS on its side of the relationship
+    /// * `avs_service_fee_epoch` - The epoch the AVS set its service fee in
+    /// * `delegated_amount` - The amount of stake delegated by the vault
+    /// * `epoch` - The current epoch
+    pub fn accrue_service_fee(
+        &mut self,
+        avs_service_fee_bps: u16,
+        avs_service_fee_epoch: u64,
+        delegated_amount: u64,
+        epoch: u64,
+    ) -> VaultCoreResult<u64> {
+        if self.service_fee_bps.get() == 0 || self.service_fee_bps.get() != avs_service_fee_bps {
+            return Err(VaultCoreError::VaultAvsServiceFeeNotAgreed);
+        }
+        if avs_service_fee_epoch > self.last_service_fee_epoch.get() {
+            self.last_service_fee_epoch = PodU64::new(avs_service_fee_epoch);
+        }
+        let epochs_elapsed = epoch
+            .checked_sub(self.last_service_fee_epoch.get())
+            .filter(|epochs| *epochs > 0)
+            .ok_or(VaultCoreError::VaultAvsServiceFeeAlreadyPaid)?;
+
+        let fee = (delegated_amount as u128)
+            .checked_mul(self.service_fee_bps.get() as u128)
+            .and_then(|fee| fee.checked_mul(epochs_elapsed as u128))
+            .and_then(|fee| fee.checked_div(10_000))
+            .and_then(|fee| u64::try_from(fee).ok())
+            .ok_or(VaultCoreError::VaultAvsServiceFeeOverflow)?;
+
+        self.last_service_fee_epoch = PodU64::new(epoch);
+        Ok(fee)
+    }
+
+    pub const fn service_fee_owed(&self) -> u64 {
+        self.service_fee_owed.get()
+    }
+
+    /// Adds a fee accrued to the fee owed to the AVS
+    pub fn add_service_fee_owed(&mut self, fee: u64) -> VaultCoreResult<()> {
+        let owed = self
+            .service_fee_owed()
+            .checked_add(fee)
+            .ok_or(VaultCoreError::VaultAvsServiceFeeOverflow)?;
+        self.service_fee_owed = PodU64::new(owed);
+        Ok(())
+    }
+
+    /// Pays as much of the fee owed to the AVS as the rewards cover, returning the amount paid
+    pub fn pay_service_fee_owed(&mut self, rewards: u64) -> u64 {
+        let amount = self.service_fee_owed().min(rewards);
+        self.service_fee_owed = PodU64::new(self.service_fee_owed() - amount);
+        amount
+    }
+
+    pub const fn pending_slash_proposals(&self) -> u64 {
+        self.pending_slash_proposals.get()
+    }
+
+    /// Counts a slash proposal filed against an operator of the AVS
+    pub fn increment_pending_slash_proposals(&mut self) -> VaultCoreResult<()> {
+        let pending = self
+            .pending_slash_proposals()
+            .checked_add(1)
+            .ok_or(VaultCoreError::VaultAvsOverflow)?;
+        self.pending_slash_proposals = PodU64::new(pending);
+        Ok(())
+    }
+
+    /// Uncounts a slash proposal once it's vetoed or executed. Saturates, as proposals filed
+    /// before the counter was added were never counted.
+    pub fn decrement_pending_slash_proposals(&mut self) {
+        self.pending_slash_proposals =
+            PodU64::new(self.pending_slash_proposals().saturating_sub(1));
+    }
+
     pub fn deactivate(&mut self, slot: u64) -> VaultCoreResult<()> {
         if self.state.deactivate(slot) {
             Ok(())
@@ -67,6 +226,17 @@ impl VaultAvsTicket {
         }
     }
 
+    /// The ticket shall have warmed up, see [`crate::config::Config::epoch_length`]
+    pub const fn check_warmed_up(&self, slot: u64, epoch_length: u64) -> VaultCoreResult<()> {
+        match self.state.state(slot, epoch_length) {
+            SlotToggleState::Active => Ok(()),
+            SlotToggleState::WarmingUp => Err(VaultCoreError::VaultAvsTicketWarmingUp),
+            SlotToggleState::CoolingDown | SlotToggleState::Inactive => {
+                Err(VaultCoreError::VaultAvsTicketInactive)
+            }
+        }
+    }
+
     pub const fn check_active(&self, slot: u64) -> VaultCoreResult<()> {
         if self.state.is_active(slot) {
             Ok(())
@@ -107,11 +277,12 @@ impl VaultAvsTicket {
             return Err(VaultCoreError::VaultAvsTicketInvalidOwner);
         }
 
-        let ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| VaultCoreError::VaultAvsTicketInvalidData(e.to_string()))?;
-        if ticket.account_type != AccountType::VaultAvsTicket {
-            return Err(VaultCoreError::VaultAvsTicketInvalidAccountType);
-        }
+        let ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                VaultCoreError::VaultAvsTicketInvalidAccountType
+            }
+            e => VaultCoreError::VaultAvsTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(vault, avs);
         seeds.push(vec![ticket.bump]);
@@ -127,7 +298,6 @@ impl VaultAvsTicket {
 
 pub struct SanitizedVaultAvsTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    vault_avs_ticket: Box<VaultAvsTicket>,
 }
 
 impl<'a, 'info> SanitizedVaultAvsTicket<'a, 'info> {
@@ -141,33 +311,118 @@ impl<'a, 'info> SanitizedVaultAvsTicket<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(VaultCoreError::VaultAvsTicektNotWritable);
         }
-        let vault_avs_ticket = Box::new(VaultAvsTicket::deserialize_checked(
-            program_id, account, vault, avs,
-        )?);
+        VaultAvsTicket::deserialize_checked(program_id, account, vault, avs)?;
 
-        Ok(SanitizedVaultAvsTicket {
-            account,
-            vault_avs_ticket,
-        })
+        Ok(SanitizedVaultAvsTicket { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn vault_avs_ticket(&self) -> &VaultAvsTicket {
-        &self.vault_avs_ticket
+    pub fn vault_avs_ticket(&self) -> Ref<'_, VaultAvsTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultAvsTicket::LEN])
+        })
     }
 
-    pub fn vault_avs_ticket_mut(&mut self) -> &mut VaultAvsTicket {
-        &mut self.vault_avs_ticket
+    pub fn vault_avs_ticket_mut(&mut self) -> RefMut<'_, VaultAvsTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..VaultAvsTicket::LEN])
+        })
     }
+}
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &self.vault_avs_ticket,
-        )?;
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{result::VaultCoreError, vault_avs_ticket::VaultAvsTicket};
+
+    #[test]
+    fn test_check_warmed_up() {
+        let mut ticket = VaultAvsTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 105, 0);
+        assert_eq!(
+            ticket.check_warmed_up(199, 100),
+            Err(VaultCoreError::VaultAvsTicketWarmingUp)
+        );
+        assert_eq!(ticket.check_warmed_up(200, 100), Ok(()));
+
+        ticket.deactivate(250).unwrap();
+        assert_eq!(
+            ticket.check_warmed_up(250, 100),
+            Err(VaultCoreError::VaultAvsTicketInactive)
+        );
+    }
+
+    #[test]
+    fn test_accrue_service_fee_ok() {
+        let mut ticket = VaultAvsTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        ticket.set_service_fee_bps(100, 5).unwrap();
+
+        // one epoch at 1% of 10_000 delegated
+        assert_eq!(ticket.accrue_service_fee(100, 0, 10_000, 6), Ok(100));
+        assert_eq!(ticket.last_service_fee_epoch(), 6);
+
+        // missed epochs are collected together
+        assert_eq!(ticket.accrue_service_fee(100, 0, 10_000, 9), Ok(300));
+    }
+
+    #[test]
+    fn test_accrue_service_fee_from_avs_agreement() {
+        let mut ticket = VaultAvsTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        ticket.set_service_fee_bps(100, 5).unwrap();
+
+        // the AVS only agreed in epoch 8, so the epochs before aren't charged
+        assert_eq!(ticket.accrue_service_fee(100, 8, 10_000, 9), Ok(100));
+        assert_eq!(ticket.last_service_fee_epoch(), 9);
+    }
+
+    #[test]
+    fn test_pay_service_fee_owed_keeps_shortfall() {
+        let mut ticket = VaultAvsTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        ticket.add_service_fee_owed(300).unwrap();
+
+        assert_eq!(ticket.pay_service_fee_owed(100), 100);
+        assert_eq!(ticket.service_fee_owed(), 200);
+
+        ticket.add_service_fee_owed(100).unwrap();
+        assert_eq!(ticket.pay_service_fee_owed(1_000), 300);
+        assert_eq!(ticket.service_fee_owed(), 0);
+    }
+
+    #[test]
+    fn test_accrue_service_fee_same_epoch_fails() {
+        let mut ticket = VaultAvsTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        ticket.set_service_fee_bps(100, 5).unwrap();
+
+        assert_eq!(
+            ticket.accrue_service_fee(100, 0, 10_000, 5),
+            Err(VaultCoreError::VaultAvsServiceFeeAlreadyPaid)
+        );
+    }
+
+    #[test]
+    fn test_accrue_service_fee_not_agreed_fails() {
+        let mut ticket = VaultAvsTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        assert_eq!(
+            ticket.accrue_service_fee(0, 0, 10_000, 1),
+            Err(VaultCoreError::VaultAvsServiceFeeNotAgreed)
+        );
+
+        ticket.set_service_fee_bps(100, 0).unwrap();
+        assert_eq!(
+            ticket.accrue_service_fee(200, 0, 10_000, 1),
+            Err(VaultCoreError::VaultAvsServiceFeeNotAgreed)
+        );
+    }
+
+    #[test]
+    fn test_set_service_fee_too_large_fails() {
+        let mut ticket = VaultAvsTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        assert_eq!(
+            ticket.set_service_fee_bps(10_001, 0),
+            Err(VaultCoreError::VaultAvsTicketInvalidServiceFee)
+        );
     }
 }
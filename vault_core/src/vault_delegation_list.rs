@@ -1,5 +1,7 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use jito_jsm_core::{serde_fields, zero_copy::ZeroCopyError};
 use jito_restaking_sanitization::{assert_with_msg, realloc};
+use serde::Serialize;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey, rent::Rent,
@@ -11,9 +13,10 @@ use crate::{
 };
 
 /// Represents an operator that has opted-in to the vault and any associated stake on this operator
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize)]
 pub struct OperatorDelegation {
     /// The operator pubkey that has opted-in to the vault
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator: Pubkey,
 
     /// The amount of stake that is currently active on the operator
@@ -21,6 +24,16 @@ pub struct OperatorDelegation {
 
     /// The amount of stake that is currently cooling down on the operator
     cooling_down_amount: u64,
+
+    /// The amount of stake undelegated this epoch, which starts cooling down at the next epoch
+    enqueued_for_cooldown_amount: u64,
+}
+
+/// Where the stake taken by a slash came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlashSummary {
+    pub from_deactivating: u64,
+    pub from_active: u64,
 }
 
 impl OperatorDelegation {
@@ -29,6 +42,7 @@ impl OperatorDelegation {
             operator,
             active_amount: 0,
             cooling_down_amount: 0,
+            enqueued_for_cooldown_amount: 0,
         }
     }
 
@@ -49,15 +63,30 @@ impl OperatorDelegation {
     pub const fn cooling_down_amount(&self) -> u64 {
         self.cooling_down_amount
     }
+
+    /// # Returns
+    /// The amount of stake undelegated this epoch
+    pub const fn enqueued_for_cooldown_amount(&self) -> u64 {
+        self.enqueued_for_cooldown_amount
+    }
+
+    /// # Returns
+    /// The amount of stake that is no longer active but can still be slashed
+    pub fn deactivating_amount(&self) -> Option<u64> {
+        self.cooling_down_amount
+            .checked_add(self.enqueued_for_cooldown_amount)
+    }
 }
 
 /// Represents the operators which have opted-in to this vault
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema, Serialize)]
 pub struct VaultDelegationList {
     /// The account type
+    #[serde(skip)]
     account_type: AccountType,
 
     /// The vault this operator list is associated with
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
     /// the list of delegations
@@ -67,21 +96,29 @@ pub struct VaultDelegationList {
     /// Delegation information here is out of date if the last update epoch < current epoch
     last_slot_updated: u64,
 
+    /// The layout version the account was written with, see [`VaultDelegationList::VERSION`]
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 127],
 
     /// The bump seed for the PDA
     bump: u8,
 }
 
 impl VaultDelegationList {
+    /// The version of the layout written by this program, see [`jito_jsm_core::zero_copy::ZeroCopy::VERSION`]
+    pub const VERSION: u8 = 1;
+
     pub const fn new(vault: Pubkey, bump: u8) -> Self {
         Self {
             account_type: AccountType::VaultDelegationList,
             vault,
             delegations: vec![],
             last_slot_updated: 0,
-            reserved: [0; 128],
+            version: Self::VERSION,
+            reserved: [0; 127],
             bump,
         }
     }
@@ -92,6 +129,16 @@ impl VaultDelegationList {
         self.vault
     }
 
+    /// The version of the layout the account was written with
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Records the version of the layout the account is written with, once migrated to it
+    pub fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
     /// # Returns
     /// The list of operators that have opted-in to this vault
     pub fn delegations(&self) -> &[OperatorDelegation] {
@@ -108,8 +155,15 @@ impl VaultDelegationList {
         let current_epoch = slot.checked_div(epoch_length).unwrap();
 
         if last_epoch_update < current_epoch {
+            // stake undelegated in the last updated epoch cools down for the whole next epoch
+            let next_epoch_only = current_epoch.checked_sub(last_epoch_update) == Some(1);
             for operator in self.delegations.iter_mut() {
-                operator.cooling_down_amount = 0;
+                operator.cooling_down_amount = if next_epoch_only {
+                    operator.enqueued_for_cooldown_amount
+                } else {
+                    0
+                };
+                operator.enqueued_for_cooldown_amount = 0;
             }
             self.last_slot_updated = slot;
             true
@@ -118,6 +172,58 @@ impl VaultDelegationList {
         }
     }
 
+    /// Brings the delegations up to date at the slot. Undelegated stake cools down for the
+    /// full epoch after the one it was undelegated in, or completes right away when the vault is
+    /// in emergency mode.
+    pub fn update(&mut self, slot: u64, epoch_length: u64, emergency_mode: bool) {
+        if emergency_mode {
+            self.finalize_cooldowns(slot);
+        } else {
+            self.update_delegations(slot, epoch_length);
+        }
+    }
+
+    /// Updates the delegations at the slot and delegates stake to the operator
+    pub fn add_delegation(
+        &mut self,
+        operator: Pubkey,
+        amount: u64,
+        total_deposited: u64,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ProgramError> {
+        self.update_delegations(slot, epoch_length);
+        self.delegate(operator, amount, total_deposited)
+    }
+
+    /// Updates the delegations at the slot and starts the cooldown of stake delegated to the
+    /// operator, which completes right away when the vault is in emergency mode
+    pub fn remove_delegation(
+        &mut self,
+        operator: Pubkey,
+        amount: u64,
+        slot: u64,
+        epoch_length: u64,
+        emergency_mode: bool,
+    ) -> Result<(), ProgramError> {
+        self.update_delegations(slot, epoch_length);
+        self.undelegate(operator, amount)?;
+        if emergency_mode {
+            self.finalize_cooldowns(slot);
+        }
+        Ok(())
+    }
+
+    /// Completes the cooldown of all undelegated stake, regardless of whether an epoch boundary
+    /// has been crossed. Only used when the vault is in emergency mode.
+    pub fn finalize_cooldowns(&mut self, slot: u64) {
+        for operator in self.delegations.iter_mut() {
+            operator.cooling_down_amount = 0;
+            operator.enqueued_for_cooldown_amount = 0;
+        }
+        self.last_slot_updated = slot;
+    }
+
     /// Delegates an amount of stake to an operator and ensures the amount delegated doesn't
     /// exceed the total deposited.
     ///
@@ -169,7 +275,8 @@ impl VaultDelegationList {
         Ok(())
     }
 
-    /// Undelegates an amount of stake from an operator
+    /// Undelegates an amount of stake from an operator. The stake is enqueued for cooldown and
+    /// stays slashable until the cooldown completes.
     ///
     /// # Arguments
     /// * `operator` - The operator pubkey to undelegate from
@@ -181,8 +288,8 @@ impl VaultDelegationList {
                     msg!("Delegation underflow");
                     ProgramError::InvalidArgument
                 })?;
-            operator.cooling_down_amount = operator
-                .cooling_down_amount
+            operator.enqueued_for_cooldown_amount = operator
+                .enqueued_for_cooldown_amount
                 .checked_add(amount)
                 .ok_or_else(|| {
                     msg!("Delegation overflow");
@@ -196,57 +303,52 @@ impl VaultDelegationList {
         Ok(())
     }
 
-    pub fn slash(&mut self, operator: &Pubkey, slash_amount: u64) -> VaultCoreResult<()> {
-        if let Some(operator) = self
+    /// Slashes stake delegated to an operator. Deactivating stake is taken first, newest first,
+    /// so undelegating right before a slash doesn't shield stake from it.
+    pub fn slash(&mut self, operator: &Pubkey, slash_amount: u64) -> VaultCoreResult<SlashSummary> {
+        let operator = self
             .delegations
             .iter_mut()
             .find(|x| x.operator == *operator)
-        {
-            let total_staked_amount = operator
-                .active_amount
-                .checked_add(operator.cooling_down_amount)
-                .ok_or(VaultCoreError::VaultSlashingOverflow)?;
-
-            let active_slash_amount = operator
-                .active_amount
-                .checked_mul(slash_amount)
-                .ok_or(VaultCoreError::VaultSlashingOverflow)?
-                .checked_div(total_staked_amount)
-                .unwrap_or(0);
-
-            msg!(
-                "slashing {} from active, {} from cooling down",
-                active_slash_amount,
-                slash_amount
-                    .checked_sub(active_slash_amount)
-                    .unwrap_or(99999999999999)
-            );
-            let cooling_down_slash_amount = slash_amount
-                .checked_sub(active_slash_amount)
-                .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
-
-            operator.active_amount = operator
-                .active_amount
-                .checked_sub(active_slash_amount)
-                .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
-            operator.cooling_down_amount = operator
-                .cooling_down_amount
-                .checked_sub(cooling_down_slash_amount)
-                .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
-
-            Ok(())
-        } else {
-            Err(VaultCoreError::VaultOperatorNotFound)
-        }
+            .ok_or(VaultCoreError::VaultOperatorNotFound)?;
+
+        let from_enqueued = slash_amount.min(operator.enqueued_for_cooldown_amount);
+        let remaining = slash_amount
+            .checked_sub(from_enqueued)
+            .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
+        let from_cooling_down = remaining.min(operator.cooling_down_amount);
+        let from_active = remaining
+            .checked_sub(from_cooling_down)
+            .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
+
+        operator.enqueued_for_cooldown_amount = operator
+            .enqueued_for_cooldown_amount
+            .checked_sub(from_enqueued)
+            .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
+        operator.cooling_down_amount = operator
+            .cooling_down_amount
+            .checked_sub(from_cooling_down)
+            .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
+        operator.active_amount = operator
+            .active_amount
+            .checked_sub(from_active)
+            .ok_or(VaultCoreError::VaultSlashingUnderflow)?;
+
+        Ok(SlashSummary {
+            from_deactivating: from_enqueued
+                .checked_add(from_cooling_down)
+                .ok_or(VaultCoreError::VaultSlashingOverflow)?,
+            from_active,
+        })
     }
 
-    /// Returns the total active + cooling down delegations
+    /// Returns the total active + deactivating delegations
     pub fn total_delegation(&self) -> Option<u64> {
         let mut total: u64 = 0;
         for operator in self.delegations.iter() {
             total = total
                 .checked_add(operator.active_amount)?
-                .checked_add(operator.cooling_down_amount)?;
+                .checked_add(operator.deactivating_amount()?)?;
         }
         Some(total)
     }
@@ -282,6 +384,15 @@ impl VaultDelegationList {
         if state.account_type != AccountType::VaultDelegationList {
             return Err(VaultCoreError::VaultDelegationListInvalidAccountType);
         }
+        if state.version > Self::VERSION {
+            return Err(VaultCoreError::VaultDelegationListInvalidData(
+                ZeroCopyError::UnsupportedVersion {
+                    found: state.version,
+                    supported: Self::VERSION,
+                }
+                .to_string(),
+            ));
+        }
 
         let mut seeds = Self::seeds(vault);
         seeds.push(vec![state.bump]);
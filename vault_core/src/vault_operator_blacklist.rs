@@ -0,0 +1,264 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{VaultCoreError, VaultCoreResult},
+    AccountType,
+};
+
+/// The most operators a vault can blacklist
+pub const MAX_BLACKLISTED_OPERATORS: usize = 32;
+
+/// The operators a vault refuses to delegate to, whatever the state of their tickets. Maintained
+/// by the delegation admin of the vault.
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct VaultOperatorBlacklist {
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The vault account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    vault: Pubkey,
+
+    /// The blacklisted operators, the first `operator_count` entries are in use
+    #[serde(serialize_with = "serde_fields::pubkeys")]
+    operators: [Pubkey; MAX_BLACKLISTED_OPERATORS],
+
+    /// The number of blacklisted operators
+    operator_count: u8,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for VaultOperatorBlacklist {
+    const DISCRIMINATOR: u8 = AccountType::VaultOperatorBlacklist as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl VaultOperatorBlacklist {
+    pub const fn new(vault: Pubkey, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::VaultOperatorBlacklist as u8,
+            vault,
+            operators: [Pubkey::new_from_array([0; 32]); MAX_BLACKLISTED_OPERATORS],
+            operator_count: 0,
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    pub fn operators(&self) -> &[Pubkey] {
+        &self.operators[..self.operator_count as usize]
+    }
+
+    pub fn is_blacklisted(&self, operator: &Pubkey) -> bool {
+        self.operators().contains(operator)
+    }
+
+    pub fn check_not_blacklisted(&self, operator: &Pubkey) -> VaultCoreResult<()> {
+        if self.is_blacklisted(operator) {
+            Err(VaultCoreError::VaultOperatorBlacklisted)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn add(&mut self, operator: Pubkey) -> VaultCoreResult<()> {
+        if self.is_blacklisted(&operator) {
+            return Err(VaultCoreError::VaultOperatorAlreadyBlacklisted);
+        }
+        let count = self.operator_count as usize;
+        if count == MAX_BLACKLISTED_OPERATORS {
+            return Err(VaultCoreError::VaultOperatorBlacklistFull);
+        }
+        self.operators[count] = operator;
+        self.operator_count = self.operator_count.saturating_add(1);
+        Ok(())
+    }
+
+    /// Removes the operator, moving the last entry into its place
+    pub fn remove(&mut self, operator: &Pubkey) -> VaultCoreResult<()> {
+        let index = self
+            .operators()
+            .iter()
+            .position(|o| o == operator)
+            .ok_or(VaultCoreError::VaultOperatorNotBlacklisted)?;
+        self.operator_count = self.operator_count.saturating_sub(1);
+        let last = self.operator_count as usize;
+        self.operators[index] = self.operators[last];
+        self.operators[last] = Pubkey::default();
+        Ok(())
+    }
+
+    pub fn seeds(vault: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_operator_blacklist".to_vec(),
+            vault.as_ref().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        vault: &Pubkey,
+    ) -> VaultCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(VaultCoreError::VaultOperatorBlacklistEmpty);
+        }
+        if account.owner != program_id {
+            return Err(VaultCoreError::VaultOperatorBlacklistInvalidOwner);
+        }
+
+        let blacklist = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                VaultCoreError::VaultOperatorBlacklistInvalidAccountType
+            }
+            e => VaultCoreError::VaultOperatorBlacklistInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(vault);
+        seeds.push(vec![blacklist.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| VaultCoreError::VaultOperatorBlacklistInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(VaultCoreError::VaultOperatorBlacklistInvalidPda);
+        }
+        Ok(blacklist)
+    }
+}
+
+pub struct SanitizedVaultOperatorBlacklist<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedVaultOperatorBlacklist<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        vault: &Pubkey,
+    ) -> VaultCoreResult<SanitizedVaultOperatorBlacklist<'a, 'info>> {
+        if expect_writable && !account.is_writable {
+            return Err(VaultCoreError::VaultOperatorBlacklistNotWritable);
+        }
+        VaultOperatorBlacklist::deserialize_checked(program_id, account, vault)?;
+
+        Ok(SanitizedVaultOperatorBlacklist { account })
+    }
+
+    /// Sanitizes the blacklist of a vault that may not have created one yet, in which case the
+    /// account shall be empty and at the blacklist address
+    pub fn sanitize_optional(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        vault: &Pubkey,
+    ) -> VaultCoreResult<Option<SanitizedVaultOperatorBlacklist<'a, 'info>>> {
+        if account.data_is_empty() {
+            let (expected_pubkey, _, _) =
+                VaultOperatorBlacklist::find_program_address(program_id, vault);
+            if expected_pubkey != *account.key {
+                return Err(VaultCoreError::VaultOperatorBlacklistInvalidPda);
+            }
+            return Ok(None);
+        }
+        Self::sanitize(program_id, account, false, vault).map(Some)
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn vault_operator_blacklist(&self) -> Ref<'_, VaultOperatorBlacklist> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultOperatorBlacklist::LEN])
+        })
+    }
+
+    pub fn vault_operator_blacklist_mut(&mut self) -> RefMut<'_, VaultOperatorBlacklist> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..VaultOperatorBlacklist::LEN])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_remove_operators() {
+        let mut blacklist = VaultOperatorBlacklist::new(Pubkey::new_unique(), 255);
+        let [a, b, c] = [(); 3].map(|_| Pubkey::new_unique());
+
+        blacklist.add(a).unwrap();
+        blacklist.add(b).unwrap();
+        blacklist.add(c).unwrap();
+        assert_eq!(
+            blacklist.add(b),
+            Err(VaultCoreError::VaultOperatorAlreadyBlacklisted)
+        );
+
+        blacklist.remove(&a).unwrap();
+        assert_eq!(blacklist.operators(), &[c, b]);
+        assert_eq!(
+            blacklist.check_not_blacklisted(&b),
+            Err(VaultCoreError::VaultOperatorBlacklisted)
+        );
+        assert!(blacklist.check_not_blacklisted(&a).is_ok());
+        assert_eq!(
+            blacklist.remove(&a),
+            Err(VaultCoreError::VaultOperatorNotBlacklisted)
+        );
+    }
+
+    #[test]
+    fn test_blacklist_full() {
+        let mut blacklist = VaultOperatorBlacklist::new(Pubkey::new_unique(), 255);
+        for _ in 0..MAX_BLACKLISTED_OPERATORS {
+            blacklist.add(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(
+            blacklist.add(Pubkey::new_unique()),
+            Err(VaultCoreError::VaultOperatorBlacklistFull)
+        );
+    }
+}
@@ -1,36 +1,73 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use jito_jsm_core::slot_toggled_field::SlotToggle;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    slot_toggled_field::{SlotToggle, SlotToggleState},
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 use crate::{
     result::{VaultCoreError, VaultCoreResult},
     AccountType,
 };
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
 pub struct VaultOperatorTicket {
-    /// The account type
-    account_type: AccountType,
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
 
     /// The vault account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     vault: Pubkey,
 
     /// The operator account
+    #[serde(serialize_with = "serde_fields::pubkey")]
     operator: Pubkey,
 
     /// The index
-    index: u64,
+    index: PodU64,
 
     /// The slot toggle
     state: SlotToggle,
 
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 128],
+    #[serde(skip)]
+    reserved: [u8; 127],
 
     bump: u8,
 }
 
+impl ZeroCopy for VaultOperatorTicket {
+    const DISCRIMINATOR: u8 = AccountType::VaultOperatorTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
 impl VaultOperatorTicket {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the operator in the account data, for `memcmp` filters
+    pub const OPERATOR_OFFSET: usize = 33;
+
     pub const fn new(
         vault: Pubkey,
         operator: Pubkey,
@@ -39,12 +76,13 @@ impl VaultOperatorTicket {
         bump: u8,
     ) -> Self {
         Self {
-            account_type: AccountType::VaultOperatorTicket,
+            discriminator: AccountType::VaultOperatorTicket as u8,
             vault,
             operator,
-            index,
+            index: PodU64::new(index),
             state: SlotToggle::new(slot_added),
-            reserved: [0; 128],
+            version: Self::VERSION,
+            reserved: [0; 127],
             bump,
         }
     }
@@ -58,7 +96,7 @@ impl VaultOperatorTicket {
     }
 
     pub const fn index(&self) -> u64 {
-        self.index
+        self.index.get()
     }
 
     pub const fn state(&self) -> &SlotToggle {
@@ -73,6 +111,17 @@ impl VaultOperatorTicket {
         }
     }
 
+    /// The ticket shall have warmed up, see [`crate::config::Config::epoch_length`]
+    pub const fn check_warmed_up(&self, slot: u64, epoch_length: u64) -> VaultCoreResult<()> {
+        match self.state.state(slot, epoch_length) {
+            SlotToggleState::Active => Ok(()),
+            SlotToggleState::WarmingUp => Err(VaultCoreError::VaultOperatorTicketWarmingUp),
+            SlotToggleState::CoolingDown | SlotToggleState::Inactive => {
+                Err(VaultCoreError::VaultOperatorTicketInactive)
+            }
+        }
+    }
+
     pub fn deactivate(&mut self, slot: u64) -> VaultCoreResult<()> {
         if self.state.deactivate(slot) {
             Ok(())
@@ -113,11 +162,12 @@ impl VaultOperatorTicket {
             return Err(VaultCoreError::VaultOperatorTicketInvalidOwner);
         }
 
-        let ticket = Self::deserialize(&mut account.data.borrow_mut().as_ref())
-            .map_err(|e| VaultCoreError::VaultOperatorTicketInvalidData(e.to_string()))?;
-        if ticket.account_type != AccountType::VaultOperatorTicket {
-            return Err(VaultCoreError::VaultOperatorTicketInvalidAccountType);
-        }
+        let ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                VaultCoreError::VaultOperatorTicketInvalidAccountType
+            }
+            e => VaultCoreError::VaultOperatorTicketInvalidData(e.to_string()),
+        })?;
 
         let mut seeds = Self::seeds(vault, operator);
         seeds.push(vec![ticket.bump]);
@@ -133,7 +183,6 @@ impl VaultOperatorTicket {
 
 pub struct SanitizedVaultOperatorTicket<'a, 'info> {
     account: &'a AccountInfo<'info>,
-    vault_operator_ticket: Box<VaultOperatorTicket>,
 }
 
 impl<'a, 'info> SanitizedVaultOperatorTicket<'a, 'info> {
@@ -147,33 +196,24 @@ impl<'a, 'info> SanitizedVaultOperatorTicket<'a, 'info> {
         if expect_writable && !account.is_writable {
             return Err(VaultCoreError::VaultOperatorTicketNotWritable);
         }
-        let vault_operator_ticket = Box::new(VaultOperatorTicket::deserialize_checked(
-            program_id, account, vault, avs,
-        )?);
+        VaultOperatorTicket::deserialize_checked(program_id, account, vault, avs)?;
 
-        Ok(SanitizedVaultOperatorTicket {
-            account,
-            vault_operator_ticket,
-        })
+        Ok(SanitizedVaultOperatorTicket { account })
     }
 
     pub const fn account(&self) -> &AccountInfo<'info> {
         self.account
     }
 
-    pub const fn vault_operator_ticket(&self) -> &VaultOperatorTicket {
-        &self.vault_operator_ticket
-    }
-
-    pub fn vault_operator_ticket_mut(&mut self) -> &mut VaultOperatorTicket {
-        &mut self.vault_operator_ticket
+    pub fn vault_operator_ticket(&self) -> Ref<'_, VaultOperatorTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultOperatorTicket::LEN])
+        })
     }
 
-    pub fn save(&self) -> ProgramResult {
-        borsh::to_writer(
-            &mut self.account.data.borrow_mut()[..],
-            &self.vault_operator_ticket,
-        )?;
-        Ok(())
+    pub fn vault_operator_ticket_mut(&mut self) -> RefMut<'_, VaultOperatorTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..VaultOperatorTicket::LEN])
+        })
     }
 }
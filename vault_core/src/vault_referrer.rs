@@ -0,0 +1,222 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{VaultCoreError, VaultCoreResult},
+    AccountType,
+};
+
+/// The share of the deposit fees a referrer, such as a wallet or aggregator, earned on the
+/// deposits it brought to a vault. The LRT accrues here on deposit and is minted to the referrer
+/// when claimed.
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct VaultReferrer {
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The vault account
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    vault: Pubkey,
+
+    /// The key the referral fees are paid to
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    referrer: Pubkey,
+
+    /// The LRT earned and not claimed yet
+    accrued_lrt: PodU64,
+
+    /// The LRT claimed so far
+    claimed_lrt: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 63],
+
+    bump: u8,
+}
+
+impl ZeroCopy for VaultReferrer {
+    const DISCRIMINATOR: u8 = AccountType::VaultReferrer as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl VaultReferrer {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the referrer in the account data, for `memcmp` filters
+    pub const REFERRER_OFFSET: usize = 33;
+
+    pub const fn new(vault: Pubkey, referrer: Pubkey, bump: u8) -> Self {
+        Self {
+            discriminator: AccountType::VaultReferrer as u8,
+            vault,
+            referrer,
+            accrued_lrt: PodU64::new(0),
+            claimed_lrt: PodU64::new(0),
+            version: Self::VERSION,
+            reserved: [0; 63],
+            bump,
+        }
+    }
+
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    pub const fn referrer(&self) -> Pubkey {
+        self.referrer
+    }
+
+    pub const fn accrued_lrt(&self) -> u64 {
+        self.accrued_lrt.get()
+    }
+
+    pub const fn claimed_lrt(&self) -> u64 {
+        self.claimed_lrt.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    pub fn check_referrer(&self, referrer: &Pubkey) -> VaultCoreResult<()> {
+        if self.referrer != *referrer {
+            return Err(VaultCoreError::VaultReferrerInvalidReferrer);
+        }
+        Ok(())
+    }
+
+    /// Adds the referral fee of a deposit
+    pub fn accrue(&mut self, lrt_amount: u64) -> VaultCoreResult<()> {
+        let accrued_lrt = self
+            .accrued_lrt()
+            .checked_add(lrt_amount)
+            .ok_or(VaultCoreError::VaultReferrerOverflow)?;
+        self.accrued_lrt = PodU64::new(accrued_lrt);
+        Ok(())
+    }
+
+    /// Takes the accrued LRT to mint to the referrer
+    pub fn claim(&mut self) -> VaultCoreResult<u64> {
+        let amount = self.accrued_lrt();
+        if amount == 0 {
+            return Err(VaultCoreError::VaultReferrerNothingToClaim);
+        }
+        let claimed_lrt = self
+            .claimed_lrt()
+            .checked_add(amount)
+            .ok_or(VaultCoreError::VaultReferrerOverflow)?;
+        self.accrued_lrt = PodU64::new(0);
+        self.claimed_lrt = PodU64::new(claimed_lrt);
+        Ok(amount)
+    }
+
+    pub fn seeds(vault: &Pubkey, referrer: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_referrer".to_vec(),
+            vault.as_ref().to_vec(),
+            referrer.as_ref().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        referrer: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, referrer);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        vault: &Pubkey,
+    ) -> VaultCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(VaultCoreError::VaultReferrerEmpty);
+        }
+        if account.owner != program_id {
+            return Err(VaultCoreError::VaultReferrerInvalidOwner);
+        }
+
+        let vault_referrer = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                VaultCoreError::VaultReferrerInvalidAccountType
+            }
+            e => VaultCoreError::VaultReferrerInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(vault, &vault_referrer.referrer);
+        seeds.push(vec![vault_referrer.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_ref()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| VaultCoreError::VaultReferrerInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(VaultCoreError::VaultReferrerInvalidPda);
+        }
+        Ok(vault_referrer)
+    }
+}
+
+pub struct SanitizedVaultReferrer<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedVaultReferrer<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        vault: &Pubkey,
+    ) -> VaultCoreResult<SanitizedVaultReferrer<'a, 'info>> {
+        if expect_writable && !account.is_writable {
+            return Err(VaultCoreError::VaultReferrerNotWritable);
+        }
+        VaultReferrer::deserialize_checked(program_id, account, vault)?;
+
+        Ok(SanitizedVaultReferrer { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn vault_referrer(&self) -> Ref<'_, VaultReferrer> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultReferrer::LEN])
+        })
+    }
+
+    pub fn vault_referrer_mut(&mut self) -> RefMut<'_, VaultReferrer> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..VaultReferrer::LEN])
+        })
+    }
+}
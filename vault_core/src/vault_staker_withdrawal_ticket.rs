@@ -0,0 +1,306 @@
+use std::cell::{Ref, RefMut};
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use jito_jsm_core::{
+    pod::PodU64,
+    serde_fields,
+    zero_copy::{ZeroCopy, ZeroCopyError},
+};
+use serde::Serialize;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+use crate::{
+    result::{VaultCoreError, VaultCoreResult},
+    AccountType,
+};
+
+/// Represents a pending withdrawal of LRT from a vault by a staker.
+///
+/// Each ticket is stamped with a sequence number taken from the vault's withdrawal queue when it
+/// is enqueued. Tickets shall be processed in sequence order so that, when the vault doesn't have
+/// enough liquid assets to cover every matured ticket, earlier withdrawals are honored first.
+#[derive(
+    Debug, BorshSerialize, BorshSchema, BorshDeserialize, Serialize, Clone, Copy, Pod, Zeroable,
+)]
+#[repr(C)]
+pub struct VaultStakerWithdrawalTicket {
+    /// The account discriminator, see [`AccountType`]
+    #[serde(skip)]
+    discriminator: u8,
+
+    /// The vault being withdrawn from
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    vault: Pubkey,
+
+    /// The staker withdrawing from the vault
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    staker: Pubkey,
+
+    /// The base account used as a PDA seed
+    #[serde(serialize_with = "serde_fields::pubkey")]
+    base: Pubkey,
+
+    /// The amount of LRT held in the ticket
+    lrt_amount: PodU64,
+
+    /// The position of the ticket in the vault's withdrawal queue
+    sequence: PodU64,
+
+    /// The slot the withdrawal was enqueued
+    slot_unstaked: PodU64,
+
+    /// The layout version the account was written with, see [`ZeroCopy::VERSION`]
+    version: u8,
+
+    /// Reserved space
+    #[serde(skip)]
+    reserved: [u8; 127],
+
+    bump: u8,
+}
+
+impl ZeroCopy for VaultStakerWithdrawalTicket {
+    const DISCRIMINATOR: u8 = AccountType::VaultStakerWithdrawalTicket as u8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+impl VaultStakerWithdrawalTicket {
+    /// The byte offset of the vault in the account data, for `memcmp` filters
+    pub const VAULT_OFFSET: usize = 1;
+
+    /// The byte offset of the staker in the account data, for `memcmp` filters
+    pub const STAKER_OFFSET: usize = 33;
+
+    pub const fn new(
+        vault: Pubkey,
+        staker: Pubkey,
+        base: Pubkey,
+        lrt_amount: u64,
+        sequence: u64,
+        slot_unstaked: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            discriminator: AccountType::VaultStakerWithdrawalTicket as u8,
+            vault,
+            staker,
+            base,
+            lrt_amount: PodU64::new(lrt_amount),
+            sequence: PodU64::new(sequence),
+            slot_unstaked: PodU64::new(slot_unstaked),
+            version: Self::VERSION,
+            reserved: [0; 127],
+            bump,
+        }
+    }
+
+    pub const fn vault(&self) -> Pubkey {
+        self.vault
+    }
+
+    pub const fn staker(&self) -> Pubkey {
+        self.staker
+    }
+
+    pub const fn base(&self) -> Pubkey {
+        self.base
+    }
+
+    pub const fn lrt_amount(&self) -> u64 {
+        self.lrt_amount.get()
+    }
+
+    pub const fn sequence(&self) -> u64 {
+        self.sequence.get()
+    }
+
+    pub const fn slot_unstaked(&self) -> u64 {
+        self.slot_unstaked.get()
+    }
+
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// The epoch the ticket can be redeemed from. Like delegation cooldowns, a withdrawal waits
+    /// for `cooldown_epochs` epoch boundaries after it was enqueued, so stakers can't exit ahead
+    /// of a slash for the epoch they were staked in.
+    pub fn withdrawable_epoch(
+        &self,
+        epoch_length: u64,
+        cooldown_epochs: u64,
+    ) -> VaultCoreResult<u64> {
+        self.slot_unstaked
+            .get()
+            .checked_div(epoch_length)
+            .and_then(|epoch| epoch.checked_add(cooldown_epochs))
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)
+    }
+
+    pub fn check_withdrawable(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+        cooldown_epochs: u64,
+    ) -> VaultCoreResult<()> {
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+        if epoch < self.withdrawable_epoch(epoch_length, cooldown_epochs)? {
+            return Err(VaultCoreError::VaultStakerWithdrawalTicketNotWithdrawable);
+        }
+        Ok(())
+    }
+
+    pub fn seeds(vault: &Pubkey, staker: &Pubkey, base: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_staker_withdrawal_ticket".to_vec(),
+            vault.to_bytes().to_vec(),
+            staker.to_bytes().to_vec(),
+            base.to_bytes().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        staker: &Pubkey,
+        base: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, staker, base);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    pub fn deserialize_checked(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        vault: &Pubkey,
+        staker: &Pubkey,
+    ) -> VaultCoreResult<Self> {
+        if account.data_is_empty() {
+            return Err(VaultCoreError::VaultStakerWithdrawalTicketEmpty);
+        }
+        if account.owner != program_id {
+            return Err(VaultCoreError::VaultStakerWithdrawalTicketInvalidOwner);
+        }
+
+        let ticket = *Self::load(&account.data.borrow()).map_err(|e| match e {
+            ZeroCopyError::InvalidDiscriminator { .. } => {
+                VaultCoreError::VaultStakerWithdrawalTicketInvalidAccountType
+            }
+            e => VaultCoreError::VaultStakerWithdrawalTicketInvalidData(e.to_string()),
+        })?;
+
+        let mut seeds = Self::seeds(vault, staker, &ticket.base);
+        seeds.push(vec![ticket.bump]);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seeds_iter, program_id)
+            .map_err(|_| VaultCoreError::VaultStakerWithdrawalTicketInvalidPda)?;
+        if expected_pubkey != *account.key {
+            return Err(VaultCoreError::VaultStakerWithdrawalTicketInvalidPda);
+        }
+        Ok(ticket)
+    }
+}
+
+pub struct SanitizedVaultStakerWithdrawalTicket<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedVaultStakerWithdrawalTicket<'a, 'info> {
+    pub fn sanitize(
+        program_id: &Pubkey,
+        account: &'a AccountInfo<'info>,
+        expect_writable: bool,
+        vault: &Pubkey,
+        staker: &Pubkey,
+    ) -> VaultCoreResult<SanitizedVaultStakerWithdrawalTicket<'a, 'info>> {
+        if expect_writable && !account.is_writable {
+            return Err(VaultCoreError::VaultStakerWithdrawalTicketNotWritable);
+        }
+        VaultStakerWithdrawalTicket::deserialize_checked(program_id, account, vault, staker)?;
+
+        Ok(SanitizedVaultStakerWithdrawalTicket { account })
+    }
+
+    pub const fn account(&self) -> &AccountInfo<'info> {
+        self.account
+    }
+
+    pub fn vault_staker_withdrawal_ticket(&self) -> Ref<'_, VaultStakerWithdrawalTicket> {
+        Ref::map(self.account.data.borrow(), |data| {
+            bytemuck::from_bytes(&data[..VaultStakerWithdrawalTicket::LEN])
+        })
+    }
+
+    pub fn vault_staker_withdrawal_ticket_mut(
+        &mut self,
+    ) -> RefMut<'_, VaultStakerWithdrawalTicket> {
+        RefMut::map(self.account.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut data[..VaultStakerWithdrawalTicket::LEN])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use crate::{
+        result::VaultCoreError, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    };
+
+    #[test]
+    fn test_check_withdrawable_after_epoch_boundary() {
+        let ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            15,
+            0,
+        );
+
+        assert_eq!(ticket.withdrawable_epoch(10, 1).unwrap(), 2);
+        assert_eq!(
+            ticket.check_withdrawable(19, 10, 1),
+            Err(VaultCoreError::VaultStakerWithdrawalTicketNotWithdrawable)
+        );
+        ticket.check_withdrawable(20, 10, 1).unwrap();
+        assert_eq!(
+            ticket.check_withdrawable(20, 0, 1),
+            Err(VaultCoreError::VaultWithdrawalOverflow)
+        );
+    }
+
+    #[test]
+    fn test_check_withdrawable_longer_cooldown() {
+        let ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            15,
+            0,
+        );
+
+        assert_eq!(ticket.withdrawable_epoch(10, 3).unwrap(), 4);
+        assert_eq!(
+            ticket.check_withdrawable(39, 10, 3),
+            Err(VaultCoreError::VaultStakerWithdrawalTicketNotWithdrawable)
+        );
+        ticket.check_withdrawable(40, 10, 3).unwrap();
+    }
+}
@@ -0,0 +1,49 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::vault::SanitizedVault;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The admin proposed with [`crate::VaultInstruction::SetAdmin`] takes over the vault.
+///
+/// Instruction: [`crate::VaultInstruction::AcceptAdmin`]
+pub fn process_accept_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        mut vault,
+        new_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault_mut().accept_admin(new_admin.account().key)?;
+
+    msg!(
+        "VaultAdminAccepted: vault={} admin={}",
+        vault.account().key,
+        new_admin.account().key
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    new_admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::AcceptAdmin`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let new_admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { vault, new_admin })
+    }
+}
@@ -1,4 +1,4 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{avs::SanitizedAvs, avs_vault_ticket::SanitizedAvsVaultTicket};
 use jito_restaking_sanitization::{
     assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
@@ -7,6 +7,10 @@ use jito_restaking_sanitization::{
 use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault, vault_avs_ticket::VaultAvsTicket,
 };
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -40,6 +44,7 @@ pub fn process_vault_add_avs(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
     vault.vault().check_avs_admin(admin.account().key)?;
 
     let slot = Clock::get()?.slot;
+    let index = vault.vault().avs_count();
     avs_vault_ticket.avs_vault_ticket().check_active(slot)?;
 
     _create_vault_avs_ticket(
@@ -55,7 +60,26 @@ pub fn process_vault_add_avs(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
 
     vault.vault_mut().increment_avs_count()?;
 
-    vault.save()?;
+    msg!(
+        "VaultAvsTicketCreated: vault={} avs={} ticket={} index={} avs_vault_ticket={} avs_vault_ticket_index={} activation_slot={}",
+        vault.account().key,
+        avs.account().key,
+        vault_avs_ticket.account().key,
+        index,
+        avs_vault_ticket.account().key,
+        avs_vault_ticket.avs_vault_ticket().index(),
+        slot
+    );
+    VaultEvent::VaultAvsTicketCreated {
+        vault: *vault.account().key,
+        avs: *avs.account().key,
+        ticket: *vault_avs_ticket.account().key,
+        index,
+        avs_vault_ticket: *avs_vault_ticket.account().key,
+        avs_vault_ticket_index: avs_vault_ticket.avs_vault_ticket().index(),
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -77,7 +101,7 @@ fn _create_vault_avs_ticket<'a, 'info>(
 
     assert_with_msg(
         address == *vault_avs_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        VaultError::VaultAvsTicketInvalidPda,
         "Vault AVS ticket is not at the correct PDA",
     )?;
 
@@ -89,7 +113,7 @@ fn _create_vault_avs_ticket<'a, 'info>(
         bump,
     );
 
-    let serialized = vault_avs_ticket.try_to_vec()?;
+    let serialized = vault_avs_ticket.as_bytes();
     msg!(
         "Creating vault AVS ticket: {:?} with space: {}",
         vault_avs_ticket_account.account().key,
@@ -105,7 +129,7 @@ fn _create_vault_avs_ticket<'a, 'info>(
         &seeds,
     )?;
     vault_avs_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
@@ -1,11 +1,14 @@
+use jito_restaking_core::operator_vault_ticket::SanitizedOperatorVaultTicket;
 use jito_restaking_sanitization::{
     signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
 };
 use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault,
     vault_delegation_list::SanitizedVaultDelegationList,
+    vault_operator_blacklist::SanitizedVaultOperatorBlacklist,
     vault_operator_ticket::SanitizedVaultOperatorTicket,
 };
+use jito_vault_sdk::event::{Event, VaultEvent};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -16,6 +19,13 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
+/// Delegates deposited tokens to an operator. The vault shall have added the operator and the
+/// operator shall still accept delegations from the vault, as shown by the vault operator ticket
+/// and the operator vault ticket being active. The vault operator ticket shall have warmed up, so
+/// stake only reaches an operator the epoch after the vault added it. Operators on the vault's
+/// blacklist can't receive delegations whatever the state of the tickets.
+///
+/// Instruction: [`crate::VaultInstruction::AddDelegation`]
 pub fn process_add_delegation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -25,33 +35,56 @@ pub fn process_add_delegation(
         config,
         vault,
         vault_operator_ticket,
+        operator_vault_ticket,
         mut vault_delegation_list,
         operator,
         delegation_admin,
         payer,
+        vault_operator_blacklist,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
     vault
         .vault()
         .check_delegation_admin(delegation_admin.account().key)?;
 
+    if let Some(vault_operator_blacklist) = vault_operator_blacklist {
+        vault_operator_blacklist
+            .vault_operator_blacklist()
+            .check_not_blacklisted(operator.key)?;
+    }
+
     let slot = Clock::get()?.slot;
 
     vault_operator_ticket
         .vault_operator_ticket()
+        .check_warmed_up(slot, config.config().epoch_length())?;
+    operator_vault_ticket
+        .operator_vault_ticket()
         .check_active(slot)?;
 
     vault_delegation_list
         .vault_delegation_list_mut()
-        .update_delegations(slot, config.config().epoch_length());
-    vault_delegation_list.vault_delegation_list_mut().delegate(
-        *operator.key,
-        amount,
-        vault.vault().tokens_deposited(),
-    )?;
+        .add_delegation(
+            *operator.key,
+            amount,
+            vault.vault().tokens_deposited(),
+            slot,
+            config.config().epoch_length(),
+        )?;
 
     vault_delegation_list.save_with_realloc(&Rent::get()?, payer.account())?;
 
+    VaultEvent::DelegationAdded {
+        vault: *vault.account().key,
+        operator: *operator.key,
+        amount,
+        slot,
+    }
+    .emit()?;
+
     Ok(())
 }
 
@@ -60,9 +93,11 @@ struct SanitizedAccounts<'a, 'info> {
     vault: SanitizedVault<'a, 'info>,
     operator: &'a AccountInfo<'info>,
     vault_operator_ticket: SanitizedVaultOperatorTicket<'a, 'info>,
+    operator_vault_ticket: SanitizedOperatorVaultTicket<'a, 'info>,
     vault_delegation_list: SanitizedVaultDelegationList<'a, 'info>,
     delegation_admin: SanitizedSignerAccount<'a, 'info>,
     payer: SanitizedSignerAccount<'a, 'info>,
+    vault_operator_blacklist: Option<SanitizedVaultOperatorBlacklist<'a, 'info>>,
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
@@ -84,6 +119,13 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             vault.account().key,
             operator.key,
         )?;
+        let operator_vault_ticket = SanitizedOperatorVaultTicket::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(&mut accounts_iter)?,
+            false,
+            operator.key,
+            vault.account().key,
+        )?;
         let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
             program_id,
             next_account_info(&mut accounts_iter)?,
@@ -95,15 +137,22 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
         let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
         let _system_program =
             SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+        let vault_operator_blacklist = SanitizedVaultOperatorBlacklist::sanitize_optional(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            vault.account().key,
+        )?;
 
         Ok(SanitizedAccounts {
             config,
             vault,
             operator,
             vault_operator_ticket,
+            operator_vault_ticket,
             vault_delegation_list,
             delegation_admin,
             payer,
+            vault_operator_blacklist,
         })
     }
 }
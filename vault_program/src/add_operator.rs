@@ -1,4 +1,4 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{
     operator::SanitizedOperator, operator_vault_ticket::SanitizedOperatorVaultTicket,
 };
@@ -9,6 +9,10 @@ use jito_restaking_sanitization::{
 use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault, vault_operator_ticket::VaultOperatorTicket,
 };
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -35,6 +39,7 @@ pub fn process_vault_add_operator(program_id: &Pubkey, accounts: &[AccountInfo])
     vault.vault().check_operator_admin(admin.account().key)?;
 
     let slot = Clock::get()?.slot;
+    let index = vault.vault().operator_count();
     // The operator shall support the vault for it to be added
     operator_vault_ticket
         .operator_vault_ticket()
@@ -53,7 +58,26 @@ pub fn process_vault_add_operator(program_id: &Pubkey, accounts: &[AccountInfo])
 
     vault.vault_mut().increment_operator_count()?;
 
-    vault.save()?;
+    msg!(
+        "VaultOperatorTicketCreated: vault={} operator={} ticket={} index={} operator_vault_ticket={} operator_vault_ticket_index={} activation_slot={}",
+        vault.account().key,
+        operator.account().key,
+        vault_operator_ticket_account.account().key,
+        index,
+        operator_vault_ticket.account().key,
+        operator_vault_ticket.operator_vault_ticket().index(),
+        slot
+    );
+    VaultEvent::VaultOperatorTicketCreated {
+        vault: *vault.account().key,
+        operator: *operator.account().key,
+        ticket: *vault_operator_ticket_account.account().key,
+        index,
+        operator_vault_ticket: *operator_vault_ticket.account().key,
+        operator_vault_ticket_index: operator_vault_ticket.operator_vault_ticket().index(),
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -78,7 +102,7 @@ fn _create_vault_operator_ticket<'a, 'info>(
 
     assert_with_msg(
         address == *vault_operator_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        VaultError::VaultOperatorTicketInvalidPda,
         "Vault operator ticket is not at the correct PDA",
     )?;
 
@@ -94,7 +118,7 @@ fn _create_vault_operator_ticket<'a, 'info>(
         "Creating vault operator ticket: {:?}",
         vault_operator_ticket_account.account().key
     );
-    let serialized = vault_operator_ticket.try_to_vec()?;
+    let serialized = vault_operator_ticket.as_bytes();
     create_account(
         payer.account(),
         vault_operator_ticket_account.account(),
@@ -105,7 +129,7 @@ fn _create_vault_operator_ticket<'a, 'info>(
         &seeds,
     )?;
     vault_operator_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
@@ -1,4 +1,4 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{
     avs::SanitizedAvs, avs_vault_slasher_ticket::SanitizedAvsVaultSlasherTicket,
 };
@@ -9,6 +9,10 @@ use jito_restaking_sanitization::{
 use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault, vault_avs_slasher_ticket::VaultAvsSlasherTicket,
 };
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -35,6 +39,7 @@ pub fn process_add_slasher(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
 
     vault.vault().check_slasher_admin(admin.account().key)?;
     let slot = Clock::get()?.slot;
+    let index = vault.vault().slasher_count();
 
     avs_slasher_ticket
         .avs_vault_slasher_ticket()
@@ -59,7 +64,28 @@ pub fn process_add_slasher(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
 
     vault.vault_mut().increment_slasher_count()?;
 
-    vault.save()?;
+    msg!(
+        "VaultAvsSlasherTicketCreated: vault={} avs={} slasher={} ticket={} index={} avs_vault_slasher_ticket={} avs_vault_slasher_ticket_index={} activation_slot={}",
+        vault.account().key,
+        avs.account().key,
+        slasher.key,
+        vault_avs_slasher_ticket_account.account().key,
+        index,
+        avs_slasher_ticket.account().key,
+        avs_slasher_ticket.avs_vault_slasher_ticket().index(),
+        slot
+    );
+    VaultEvent::VaultAvsSlasherTicketCreated {
+        vault: *vault.account().key,
+        avs: *avs.account().key,
+        slasher: *slasher.key,
+        ticket: *vault_avs_slasher_ticket_account.account().key,
+        index,
+        avs_vault_slasher_ticket: *avs_slasher_ticket.account().key,
+        avs_vault_slasher_ticket_index: avs_slasher_ticket.avs_vault_slasher_ticket().index(),
+        activation_slot: slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -87,7 +113,7 @@ fn _create_vault_avs_slasher_ticket<'a, 'info>(
 
     assert_with_msg(
         address == *vault_avs_slasher_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        VaultError::VaultAvsSlasherTicketInvalidPda,
         "Vault AVS slasher ticket is not at the correct PDA",
     )?;
 
@@ -105,7 +131,7 @@ fn _create_vault_avs_slasher_ticket<'a, 'info>(
         "Creating vault AVS slasher ticket: {:?}",
         vault_avs_slasher_ticket_account.account().key
     );
-    let serialized = vault_avs_slasher_ticket.try_to_vec()?;
+    let serialized = vault_avs_slasher_ticket.as_bytes();
     create_account(
         payer.account(),
         vault_avs_slasher_ticket_account.account(),
@@ -116,7 +142,7 @@ fn _create_vault_avs_slasher_ticket<'a, 'info>(
         &seeds,
     )?;
     vault_avs_slasher_ticket_account.account().data.borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
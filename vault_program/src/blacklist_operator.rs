@@ -0,0 +1,148 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_vault_core::{
+    vault::SanitizedVault,
+    vault_operator_blacklist::{SanitizedVaultOperatorBlacklist, VaultOperatorBlacklist},
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Adds an operator to the vault's blacklist, creating the blacklist on first use. Only the
+/// delegation admin can blacklist operators.
+///
+/// Instruction: [`crate::VaultInstruction::BlacklistOperator`]
+pub fn process_blacklist_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        vault,
+        vault_operator_blacklist,
+        operator,
+        delegation_admin,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault
+        .vault()
+        .check_delegation_admin(delegation_admin.account().key)?;
+
+    if vault_operator_blacklist.data_is_empty() {
+        _create_vault_operator_blacklist(
+            program_id,
+            vault_operator_blacklist,
+            &vault,
+            &payer,
+            &system_program,
+            &Rent::get()?,
+        )?;
+    }
+
+    let mut vault_operator_blacklist = SanitizedVaultOperatorBlacklist::sanitize(
+        program_id,
+        vault_operator_blacklist,
+        true,
+        vault.account().key,
+    )?;
+    vault_operator_blacklist
+        .vault_operator_blacklist_mut()
+        .add(*operator.key)?;
+
+    msg!("Blacklisted operator: {:?}", operator.key);
+
+    VaultEvent::OperatorBlacklisted {
+        vault: *vault.account().key,
+        operator: *operator.key,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+fn _create_vault_operator_blacklist<'a, 'info>(
+    program_id: &Pubkey,
+    vault_operator_blacklist_account: &'a AccountInfo<'info>,
+    vault: &SanitizedVault<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
+    system_program: &SanitizedSystemProgram<'a, 'info>,
+    rent: &Rent,
+) -> ProgramResult {
+    let (address, bump, mut seeds) =
+        VaultOperatorBlacklist::find_program_address(program_id, vault.account().key);
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *vault_operator_blacklist_account.key,
+        VaultError::VaultOperatorBlacklistInvalidPda,
+        "Invalid vault operator blacklist PDA",
+    )?;
+
+    let vault_operator_blacklist = VaultOperatorBlacklist::new(*vault.account().key, bump);
+
+    msg!(
+        "Creating vault operator blacklist: {:?}",
+        vault_operator_blacklist_account.key
+    );
+    let serialized = vault_operator_blacklist.as_bytes();
+    create_account(
+        payer.account(),
+        vault_operator_blacklist_account,
+        system_program.account(),
+        program_id,
+        rent,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    vault_operator_blacklist_account.data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    vault_operator_blacklist: &'a AccountInfo<'info>,
+    operator: &'a AccountInfo<'info>,
+    delegation_admin: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::BlacklistOperator`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        // sanitized once it exists, it may be created by the instruction
+        let vault_operator_blacklist = next_account_info(accounts_iter)?;
+        let operator = next_account_info(accounts_iter)?;
+        let delegation_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            vault,
+            vault_operator_blacklist,
+            operator,
+            delegation_admin,
+            payer,
+            system_program,
+        })
+    }
+}
@@ -0,0 +1,322 @@
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount,
+    close_program_account, token_mint::SanitizedTokenMint, token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig,
+    result::VaultCoreError,
+    vault::{SanitizedVault, Vault},
+    vault_delegation_list::SanitizedVaultDelegationList,
+    vault_staker_withdrawal_ticket::{
+        SanitizedVaultStakerWithdrawalTicket, VaultStakerWithdrawalTicket,
+    },
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::{burn, close_account, transfer_checked};
+
+/// Redeems a staker's withdrawal ticket for the supported tokens backing its LRT.
+///
+/// # Behavior
+/// * The ticket shall have passed the first epoch boundary after it was enqueued, and be the
+///   next one in the vault's withdrawal queue.
+/// * The withdrawal fee in effect at the current epoch is moved to the fee owner in LRT, along
+///   with any LRT sent to the ticket besides the withdrawal itself. The rest is burned at the
+///   vault's current exchange rate and the backing tokens are sent to the staker.
+/// * While the vault is in emergency mode, the LRT is instead redeemed for its pro-rata share of
+///   the tokens that aren't delegated, so stakers withdrawing first can't drain the vault at the
+///   expense of the rest.
+/// * The ticket and its token account are closed, returning their rent to the staker.
+///
+/// Anyone can redeem a matured ticket, so the queue can be worked through by a crank.
+///
+/// Instruction: [`crate::VaultInstruction::BurnWithdrawalTicket`]
+pub fn process_burn_withdrawal_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        vault_token_account,
+        lrt_mint,
+        staker,
+        staker_token_account,
+        vault_staker_withdrawal_ticket,
+        vault_staker_withdrawal_ticket_token_account,
+        vault_fee_token_account,
+        supported_mint,
+        token_program,
+        vault_delegation_list,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
+    assert_with_msg(
+        *lrt_mint.account().key == vault.vault().lrt_mint(),
+        VaultError::LrtMintMismatch,
+        "Mint account does not match LRT mint",
+    )?;
+
+    let ticket = *vault_staker_withdrawal_ticket.vault_staker_withdrawal_ticket();
+    let slot = Clock::get()?.slot;
+    let epoch_length = config.config().epoch_length();
+    ticket.check_withdrawable(
+        slot,
+        epoch_length,
+        config.config().withdrawal_cooldown_epochs(),
+    )?;
+    vault.vault_mut().dequeue_withdrawal(ticket.sequence())?;
+
+    // out-of-band token account increases carrying a reward fee shall be harvested first
+    let epoch = slot.checked_div(epoch_length).unwrap();
+    vault
+        .vault_mut()
+        .sync_balance(vault_token_account.token_account().amount, epoch)?;
+
+    let fee = vault
+        .vault()
+        .calculate_withdraw_fee(ticket.lrt_amount(), epoch)?;
+    let lrt_to_burn = ticket
+        .lrt_amount()
+        .checked_sub(fee)
+        .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+    let lrt_to_fee = vault_staker_withdrawal_ticket_token_account
+        .token_account()
+        .amount
+        .checked_sub(lrt_to_burn)
+        .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+    let amount = if vault.vault().emergency_mode() {
+        let delegated_amount = vault_delegation_list
+            .vault_delegation_list()
+            .total_delegation()
+            .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+        let liquid_amount = vault
+            .vault()
+            .tokens_deposited()
+            .saturating_sub(delegated_amount);
+        vault
+            .vault_mut()
+            .burn_and_withdraw_emergency(lrt_to_burn, liquid_amount)?
+    } else {
+        vault.vault_mut().burn_and_withdraw(lrt_to_burn)?
+    };
+
+    let mut ticket_seeds =
+        VaultStakerWithdrawalTicket::seeds(&ticket.vault(), &ticket.staker(), &ticket.base());
+    ticket_seeds.push(vec![ticket.bump()]);
+    let ticket_seed_slices: Vec<&[u8]> = ticket_seeds.iter().map(|seed| seed.as_slice()).collect();
+
+    if lrt_to_fee > 0 {
+        invoke_signed(
+            &transfer_checked(
+                token_program.account().key,
+                vault_staker_withdrawal_ticket_token_account.account().key,
+                lrt_mint.account().key,
+                vault_fee_token_account.account().key,
+                vault_staker_withdrawal_ticket.account().key,
+                &[],
+                lrt_to_fee,
+                lrt_mint.mint().decimals,
+            )?,
+            &[
+                vault_staker_withdrawal_ticket_token_account
+                    .account()
+                    .clone(),
+                lrt_mint.account().clone(),
+                vault_fee_token_account.account().clone(),
+                vault_staker_withdrawal_ticket.account().clone(),
+            ],
+            &[&ticket_seed_slices],
+        )?;
+    }
+    invoke_signed(
+        &burn(
+            token_program.account().key,
+            vault_staker_withdrawal_ticket_token_account.account().key,
+            lrt_mint.account().key,
+            vault_staker_withdrawal_ticket.account().key,
+            &[],
+            lrt_to_burn,
+        )?,
+        &[
+            vault_staker_withdrawal_ticket_token_account
+                .account()
+                .clone(),
+            lrt_mint.account().clone(),
+            vault_staker_withdrawal_ticket.account().clone(),
+        ],
+        &[&ticket_seed_slices],
+    )?;
+    invoke_signed(
+        &close_account(
+            token_program.account().key,
+            vault_staker_withdrawal_ticket_token_account.account().key,
+            staker.key,
+            vault_staker_withdrawal_ticket.account().key,
+            &[],
+        )?,
+        &[
+            vault_staker_withdrawal_ticket_token_account
+                .account()
+                .clone(),
+            staker.clone(),
+            vault_staker_withdrawal_ticket.account().clone(),
+        ],
+        &[&ticket_seed_slices],
+    )?;
+
+    let (_, bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.vault().base());
+    vault_seeds.push(vec![bump]);
+    let vault_seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
+    invoke_signed(
+        &transfer_checked(
+            token_program.account().key,
+            vault_token_account.account().key,
+            supported_mint.account().key,
+            staker_token_account.account().key,
+            vault.account().key,
+            &[],
+            amount,
+            supported_mint.mint().decimals,
+        )?,
+        &[
+            vault_token_account.account().clone(),
+            supported_mint.account().clone(),
+            staker_token_account.account().clone(),
+            vault.account().clone(),
+        ],
+        &[&vault_seed_slices],
+    )?;
+
+    close_program_account(vault_staker_withdrawal_ticket.account(), staker)?;
+
+    msg!(
+        "WithdrawalTicketBurned: vault={} staker={} ticket={} sequence={} lrt_burned={} lrt_fee={} amount={}",
+        vault.account().key,
+        staker.key,
+        vault_staker_withdrawal_ticket.account().key,
+        ticket.sequence(),
+        lrt_to_burn,
+        lrt_to_fee,
+        amount
+    );
+    VaultEvent::WithdrawalTicketBurned {
+        vault: *vault.account().key,
+        staker: *staker.key,
+        ticket: *vault_staker_withdrawal_ticket.account().key,
+        sequence: ticket.sequence(),
+        lrt_burned: lrt_to_burn,
+        lrt_fee: lrt_to_fee,
+        amount,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    lrt_mint: SanitizedTokenMint<'a, 'info>,
+    staker: &'a AccountInfo<'info>,
+    staker_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    vault_staker_withdrawal_ticket: SanitizedVaultStakerWithdrawalTicket<'a, 'info>,
+    vault_staker_withdrawal_ticket_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    vault_fee_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    supported_mint: SanitizedTokenMint<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+    vault_delegation_list: SanitizedVaultDelegationList<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::BurnWithdrawalTicket`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let vault_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().supported_mint(),
+            vault.account().key,
+        )?;
+        let lrt_mint = SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, true)?;
+        let staker = next_account_info(accounts_iter)?;
+        assert_with_msg(
+            staker.is_writable,
+            VaultError::StakerExpectedWritable,
+            "Staker account shall be writable",
+        )?;
+        let staker_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().supported_mint(),
+            staker.key,
+        )?;
+        let vault_staker_withdrawal_ticket = SanitizedVaultStakerWithdrawalTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            vault.account().key,
+            staker.key,
+        )?;
+        let vault_staker_withdrawal_ticket_token_account =
+            SanitizedAssociatedTokenAccount::sanitize(
+                next_account_info(accounts_iter)?,
+                &vault.vault().lrt_mint(),
+                vault_staker_withdrawal_ticket.account().key,
+            )?;
+        let vault_fee_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().lrt_mint(),
+            &vault.vault().fee_owner(),
+        )?;
+        let supported_mint =
+            SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, false)?;
+        assert_with_msg(
+            *supported_mint.account().key == vault.vault().supported_mint(),
+            VaultError::SupportedMintMismatch,
+            "Mint account does not match the vault supported mint",
+        )?;
+        let token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+        let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            false,
+            vault.account().key,
+        )?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            vault_token_account,
+            lrt_mint,
+            staker,
+            staker_token_account,
+            vault_staker_withdrawal_ticket,
+            vault_staker_withdrawal_ticket_token_account,
+            vault_fee_token_account,
+            supported_mint,
+            token_program,
+            vault_delegation_list,
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::{config::SanitizedConfig, vault::SanitizedVault};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Cancels a pending mint authority handoff.
+///
+/// # Behavior
+/// * Either the vault admin or the configuration admin may cancel, since withdrawing consent
+///   from either side shall be enough to stop the handoff.
+///
+/// Instruction: [`crate::VaultInstruction::CancelMintAuthorityHandoff`]
+pub fn process_cancel_mint_authority_handoff(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    if vault.vault().check_admin(admin.account().key).is_err() {
+        config.config().check_admin(admin.account().key)?;
+    }
+
+    msg!(
+        "Cancelling mint authority handoff of vault {}",
+        vault.account().key
+    );
+    vault.vault_mut().clear_mint_authority_handoff()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::CancelMintAuthorityHandoff`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            admin,
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount,
+    signer::SanitizedSignerAccount, token_mint::SanitizedTokenMint,
+    token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig, vault::SanitizedVault, vault_referrer::SanitizedVaultReferrer,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::mint_to::_mint_lrt;
+
+/// Mints the referral fees a referrer accrued to its LRT token account. The LRT was already
+/// counted in the vault's supply when the deposits were made.
+///
+/// Instruction: [`crate::VaultInstruction::ClaimReferralFees`]
+pub fn process_claim_referral_fees(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        vault,
+        lrt_mint,
+        mut vault_referrer,
+        referrer_lrt_token_account,
+        referrer,
+        token_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
+    assert_with_msg(
+        *lrt_mint.account().key == vault.vault().lrt_mint(),
+        VaultError::LrtMintMismatch,
+        "Mint account does not match LRT mint",
+    )?;
+    vault_referrer
+        .vault_referrer()
+        .check_referrer(referrer.account().key)?;
+
+    let lrt_amount = vault_referrer.vault_referrer_mut().claim()?;
+
+    _mint_lrt(
+        program_id,
+        &token_program,
+        &vault,
+        &lrt_mint,
+        &referrer_lrt_token_account,
+        lrt_amount,
+    )?;
+
+    msg!(
+        "ReferralFeesClaimed: vault={} referrer={} lrt_amount={}",
+        vault.account().key,
+        referrer.account().key,
+        lrt_amount
+    );
+    VaultEvent::ReferralFeesClaimed {
+        vault: *vault.account().key,
+        referrer: *referrer.account().key,
+        lrt_amount,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    lrt_mint: SanitizedTokenMint<'a, 'info>,
+    vault_referrer: SanitizedVaultReferrer<'a, 'info>,
+    referrer_lrt_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    referrer: SanitizedSignerAccount<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ClaimReferralFees`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let lrt_mint = SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, true)?;
+        let vault_referrer = SanitizedVaultReferrer::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            vault.account().key,
+        )?;
+        let referrer_lrt_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().lrt_mint(),
+            &vault_referrer.vault_referrer().referrer(),
+        )?;
+        let referrer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            lrt_mint,
+            vault_referrer,
+            referrer_lrt_token_account,
+            referrer,
+            token_program,
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::vault::SanitizedVault;
+use jito_vault_sdk::event::{Event, VaultEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The vault admin removes the mint burn authority of the vault, so anyone can deposit and
+/// withdraw again without a co-signer.
+///
+/// [`crate::VaultInstruction::ClearMintBurnAuthority`]
+pub fn process_clear_mint_burn_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts { mut vault, admin } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_admin(admin.account().key)?;
+    vault.vault_mut().clear_mint_burn_authority()?;
+
+    msg!("MintBurnAuthorityCleared: vault={}", vault.account().key);
+    VaultEvent::MintBurnAuthorityCleared {
+        vault: *vault.account().key,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ClearMintBurnAuthority`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { vault, admin })
+    }
+}
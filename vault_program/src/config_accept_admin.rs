@@ -0,0 +1,50 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::config::SanitizedConfig;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The admin proposed with [`crate::VaultInstruction::ConfigSetAdmin`] takes over the config.
+///
+/// Instruction: [`crate::VaultInstruction::ConfigAcceptAdmin`]
+pub fn process_config_accept_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        mut config,
+        new_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config_mut().accept_admin(new_admin.account().key)?;
+
+    msg!(
+        "ConfigAdminAccepted: config={} admin={}",
+        config.account().key,
+        new_admin.account().key
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    new_admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ConfigAcceptAdmin`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let new_admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, new_admin })
+    }
+}
@@ -0,0 +1,59 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::config::SanitizedConfig;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin proposes a new admin. The transfer only completes once the new admin signs
+/// [`crate::VaultInstruction::ConfigAcceptAdmin`].
+///
+/// Instruction: [`crate::VaultInstruction::ConfigSetAdmin`]
+pub fn process_config_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        mut config,
+        admin,
+        new_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+    config.config_mut().set_pending_admin(*new_admin.key);
+
+    msg!(
+        "ConfigAdminProposed: config={} pending_admin={}",
+        config.account().key,
+        new_admin.key
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    new_admin: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ConfigSetAdmin`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let new_admin = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            admin,
+            new_admin,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::config::SanitizedConfig;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The configuration admin sets the length of an epoch in slots, which paces delegation
+/// cooldowns, withdrawal tickets and withdrawal fee grace periods of every vault. Lengths outside
+/// [`jito_vault_core::config::MIN_EPOCH_LENGTH`] and [`jito_vault_core::config::MAX_EPOCH_LENGTH`]
+/// are rejected, and the length can't change anymore once a vault is initialized.
+///
+/// Instruction: [`crate::VaultInstruction::ConfigSetEpochLength`]
+pub fn process_config_set_epoch_length(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch_length: u64,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+    set_epoch_length(&mut config, epoch_length)
+}
+
+/// Sets the epoch length of the config and logs the change, for both
+/// [`crate::VaultInstruction::ConfigSetEpochLength`] and the
+/// [`jito_vault_sdk::ConfigParameter::EpochLength`] parameter. The admin shall already be checked.
+pub(crate) fn set_epoch_length(config: &mut SanitizedConfig, epoch_length: u64) -> ProgramResult {
+    let old_epoch_length = config.config().epoch_length();
+    config.config_mut().set_epoch_length(epoch_length)?;
+
+    msg!(
+        "ConfigEpochLengthSet: config={} old_epoch_length={} epoch_length={}",
+        config.account().key,
+        old_epoch_length,
+        epoch_length
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ConfigSetEpochLength`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
@@ -0,0 +1,64 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::config::SanitizedConfig;
+use jito_vault_sdk::ConfigParameter;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::config_set_epoch_length::set_epoch_length;
+
+/// The configuration admin sets a parameter shared by every vault, within the bounds of its
+/// setter on [`jito_vault_core::config::Config`]. A new withdrawal cooldown applies to the tickets
+/// already enqueued, and the epoch length is set like
+/// [`crate::VaultInstruction::ConfigSetEpochLength`] does.
+///
+/// Instruction: [`crate::VaultInstruction::ConfigSetParameter`]
+pub fn process_config_set_parameter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    parameter: ConfigParameter,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!("Setting config parameter {:?}", parameter);
+    match parameter {
+        ConfigParameter::EpochLength(epoch_length) => set_epoch_length(&mut config, epoch_length)?,
+        ConfigParameter::WithdrawalFeeGraceEpochs(epochs) => config
+            .config_mut()
+            .set_withdrawal_fee_grace_epochs(epochs)?,
+        ConfigParameter::WithdrawalCooldownEpochs(epochs) => {
+            config.config_mut().set_withdrawal_cooldown_epochs(epochs)?
+        }
+    }
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ConfigSetParameter`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
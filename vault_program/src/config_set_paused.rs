@@ -0,0 +1,65 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::config::SanitizedConfig;
+use jito_vault_sdk::event::{Event, VaultEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The configuration admin pauses or resumes the vault program, as a circuit breaker during an
+/// incident such as a bad slasher or an exchange rate bug.
+///
+/// # Behavior
+/// * While paused, deposits, withdrawals, slashing and delegation changes fail in every vault.
+/// * Admin instructions stay available, so the admin can remove a compromised AVS, slasher or
+///   operator before resuming.
+///
+/// Instruction: [`crate::VaultInstruction::ConfigSetPaused`]
+pub fn process_config_set_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: bool,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+    config.config_mut().set_paused(paused);
+
+    msg!(
+        "ConfigPauseSet: config={} paused={}",
+        config.account().key,
+        paused
+    );
+    VaultEvent::ConfigPauseSet {
+        config: *config.account().key,
+        paused,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ConfigSetPaused`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
@@ -0,0 +1,88 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::config::SanitizedConfig;
+use jito_vault_sdk::event::{Event, VaultEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The configuration admin sets the program fee charged on deposits into every vault and the
+/// wallet it's paid to.
+///
+/// # Behavior
+/// * The fee is taken in LRT out of the LRT minted on each deposit, before the vault deposit
+///   fee, and minted to the LRT associated token account of the wallet.
+/// * Fees above [`jito_vault_core::config::MAX_PROGRAM_FEE_BPS`] are rejected, and the fee can
+///   only change again [`jito_vault_core::config::PROGRAM_FEE_CHANGE_DELAY_EPOCHS`] later.
+///
+/// Instruction: [`crate::VaultInstruction::ConfigSetProgramFee`]
+pub fn process_config_set_program_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut config,
+        admin,
+        program_fee_wallet,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+    let epoch = Clock::get()?
+        .slot
+        .checked_div(config.config().epoch_length())
+        .unwrap();
+    config
+        .config_mut()
+        .set_program_fee(fee_bps, *program_fee_wallet.key, epoch)?;
+    let unlock_epoch = config.config().program_fee_unlock_epoch();
+
+    msg!(
+        "ProgramFeeSet: config={} fee_bps={} wallet={} unlock_epoch={}",
+        config.account().key,
+        fee_bps,
+        program_fee_wallet.key,
+        unlock_epoch
+    );
+    VaultEvent::ProgramFeeSet {
+        config: *config.account().key,
+        fee_bps,
+        wallet: *program_fee_wallet.key,
+        unlock_epoch,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    program_fee_wallet: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ConfigSetProgramFee`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let program_fee_wallet = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            admin,
+            program_fee_wallet,
+        })
+    }
+}
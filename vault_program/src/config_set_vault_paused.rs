@@ -0,0 +1,70 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::{config::SanitizedConfig, vault::SanitizedVault};
+use jito_vault_sdk::event::{Event, VaultEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The configuration admin pauses or resumes a single vault, halting its deposits, withdrawals,
+/// slashing and delegation changes like [`crate::VaultInstruction::ConfigSetPaused`] does for
+/// every vault.
+///
+/// Instruction: [`crate::VaultInstruction::ConfigSetVaultPaused`]
+pub fn process_config_set_vault_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: bool,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+    vault.vault_mut().set_paused(paused);
+
+    msg!(
+        "VaultPauseSet: vault={} paused={}",
+        vault.account().key,
+        paused
+    );
+    VaultEvent::VaultPauseSet {
+        vault: *vault.account().key,
+        paused,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ConfigSetVaultPaused`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            admin,
+        })
+    }
+}
@@ -1,9 +1,256 @@
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount, create_account,
+    empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram, token_mint::SanitizedTokenMint,
+    token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig, vault::SanitizedVault,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use spl_token_2022::instruction::transfer_checked;
 
-pub const fn process_enqueue_withdrawal(
-    _program_id: &Pubkey,
-    _accounts: &[AccountInfo],
-    _amount: u64,
+/// Enqueues a withdrawal of a staker's LRT.
+///
+/// # Behavior
+/// * A withdrawal ticket is created at a PDA seeded by the vault, the staker and `base`, so a
+///   staker can have several withdrawals pending. It is stamped with the next position in the
+///   vault's withdrawal queue and the current slot.
+/// * The LRT is moved into an associated token account owned by the ticket, both paid for by the
+///   staker, where it stays until the ticket is redeemed with
+///   [`crate::VaultInstruction::BurnWithdrawalTicket`].
+///
+/// Instruction: [`crate::VaultInstruction::EnqueueWithdrawal`]
+pub fn process_enqueue_withdrawal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
 ) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        vault_staker_withdrawal_ticket,
+        vault_staker_withdrawal_ticket_token_account,
+        lrt_mint,
+        staker,
+        staker_lrt_token_account,
+        base,
+        token_program,
+        associated_token_program,
+        system_program,
+        burn_signer,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
+    assert_with_msg(
+        *lrt_mint.account().key == vault.vault().lrt_mint(),
+        VaultError::LrtMintMismatch,
+        "Mint account does not match LRT mint",
+    )?;
+    if let Some(burn_signer) = burn_signer {
+        assert_with_msg(
+            Some(*burn_signer.account().key) == vault.vault().mint_burn_authority(),
+            VaultError::MintBurnSignerMismatch,
+            "Burn signer does not match vault burn signer",
+        )?;
+    }
+    assert_with_msg(
+        amount > 0,
+        VaultError::WithdrawalAmountZero,
+        "Withdrawal amount shall be positive",
+    )?;
+
+    let (address, bump, mut seeds) = VaultStakerWithdrawalTicket::find_program_address(
+        program_id,
+        vault.account().key,
+        staker.account().key,
+        base.account().key,
+    );
+    seeds.push(vec![bump]);
+    assert_with_msg(
+        address == *vault_staker_withdrawal_ticket.account().key,
+        VaultError::VaultStakerWithdrawalTicketInvalidPda,
+        "Invalid vault staker withdrawal ticket PDA",
+    )?;
+
+    let slot = Clock::get()?.slot;
+    let sequence = vault.vault_mut().enqueue_withdrawal()?;
+    let ticket = VaultStakerWithdrawalTicket::new(
+        *vault.account().key,
+        *staker.account().key,
+        *base.account().key,
+        amount,
+        sequence,
+        slot,
+        bump,
+    );
+
+    let serialized = ticket.as_bytes();
+    create_account(
+        staker.account(),
+        vault_staker_withdrawal_ticket.account(),
+        system_program.account(),
+        program_id,
+        &Rent::get()?,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    vault_staker_withdrawal_ticket.account().data.borrow_mut()[..serialized.len()]
+        .copy_from_slice(serialized);
+
+    invoke(
+        &create_associated_token_account_idempotent(
+            staker.account().key,
+            vault_staker_withdrawal_ticket.account().key,
+            lrt_mint.account().key,
+            token_program.account().key,
+        ),
+        &[
+            staker.account().clone(),
+            vault_staker_withdrawal_ticket_token_account.clone(),
+            vault_staker_withdrawal_ticket.account().clone(),
+            lrt_mint.account().clone(),
+            system_program.account().clone(),
+            token_program.account().clone(),
+            associated_token_program.clone(),
+        ],
+    )?;
+    let vault_staker_withdrawal_ticket_token_account = SanitizedAssociatedTokenAccount::sanitize(
+        vault_staker_withdrawal_ticket_token_account,
+        &vault.vault().lrt_mint(),
+        vault_staker_withdrawal_ticket.account().key,
+    )?;
+
+    invoke(
+        &transfer_checked(
+            token_program.account().key,
+            staker_lrt_token_account.account().key,
+            lrt_mint.account().key,
+            vault_staker_withdrawal_ticket_token_account.account().key,
+            staker.account().key,
+            &[],
+            amount,
+            lrt_mint.mint().decimals,
+        )?,
+        &[
+            staker_lrt_token_account.account().clone(),
+            lrt_mint.account().clone(),
+            vault_staker_withdrawal_ticket_token_account
+                .account()
+                .clone(),
+            staker.account().clone(),
+        ],
+    )?;
+
+    msg!(
+        "WithdrawalEnqueued: vault={} staker={} ticket={} lrt_amount={} sequence={} slot={}",
+        vault.account().key,
+        staker.account().key,
+        vault_staker_withdrawal_ticket.account().key,
+        amount,
+        sequence,
+        slot
+    );
+    VaultEvent::WithdrawalEnqueued {
+        vault: *vault.account().key,
+        staker: *staker.account().key,
+        ticket: *vault_staker_withdrawal_ticket.account().key,
+        lrt_amount: amount,
+        sequence,
+        slot,
+    }
+    .emit()?;
+
     Ok(())
 }
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    vault_staker_withdrawal_ticket: EmptyAccount<'a, 'info>,
+    vault_staker_withdrawal_ticket_token_account: &'a AccountInfo<'info>,
+    lrt_mint: SanitizedTokenMint<'a, 'info>,
+    staker: SanitizedSignerAccount<'a, 'info>,
+    staker_lrt_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    base: SanitizedSignerAccount<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+    associated_token_program: &'a AccountInfo<'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+    burn_signer: Option<SanitizedSignerAccount<'a, 'info>>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::EnqueueWithdrawal`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let vault_staker_withdrawal_ticket =
+            EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        // created in the processor, sanitized once it exists
+        let vault_staker_withdrawal_ticket_token_account = next_account_info(accounts_iter)?;
+        let lrt_mint = SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, false)?;
+        let staker = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let staker_lrt_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().lrt_mint(),
+            staker.account().key,
+        )?;
+        let base = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+        let associated_token_program = next_account_info(accounts_iter)?;
+        assert_with_msg(
+            *associated_token_program.key == spl_associated_token_account::id(),
+            VaultError::AssociatedTokenProgramInvalidAddress,
+            "Associated token program address is invalid",
+        )?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+        let burn_signer = if vault.vault().mint_burn_authority().is_some() {
+            Some(SanitizedSignerAccount::sanitize(
+                next_account_info(accounts_iter)?,
+                false,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            vault_staker_withdrawal_ticket,
+            vault_staker_withdrawal_ticket_token_account,
+            lrt_mint,
+            staker,
+            staker_lrt_token_account,
+            base,
+            token_program,
+            associated_token_program,
+            system_program,
+            burn_signer,
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use jito_restaking_sanitization::{
+    signer::SanitizedSignerAccount, token_mint::SanitizedTokenMint,
+    token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig,
+    result::VaultCoreError,
+    vault::{SanitizedVault, Vault},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    program_option::COption,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::{set_authority, AuthorityType};
+
+/// Transfers the mint authority of `lrt_mint` from the vault to the account proposed in
+/// [`crate::VaultInstruction::ProposeMintAuthorityHandoff`].
+///
+/// # Behavior
+/// * The vault admin and the configuration admin shall both sign the execution.
+/// * An epoch shall have passed since the handoff was proposed.
+/// * The vault shall currently hold the mint authority. The mint doesn't need to be the vault's
+///   own LRT mint, so a redeployed vault can hand a mint it received back to the original vault.
+///
+/// Instruction: [`crate::VaultInstruction::ExecuteMintAuthorityHandoff`]
+pub fn process_execute_mint_authority_handoff(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        lrt_mint,
+        admin,
+        config_admin,
+        token_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_admin(admin.account().key)?;
+    config.config().check_admin(config_admin.account().key)?;
+
+    if lrt_mint.mint().mint_authority != COption::Some(*vault.account().key) {
+        msg!(
+            "Vault is not the mint authority of {}",
+            lrt_mint.account().key
+        );
+        return Err(VaultCoreError::VaultMintAuthorityMismatch.into());
+    }
+
+    let slot = Clock::get()?.slot;
+    let new_authority = vault
+        .vault()
+        .check_mint_authority_handoff_ready(slot, config.config().epoch_length())?;
+
+    msg!(
+        "Handing off mint authority of {} from vault {} to {}",
+        lrt_mint.account().key,
+        vault.account().key,
+        new_authority
+    );
+    _set_mint_authority(
+        program_id,
+        &token_program,
+        &vault,
+        &lrt_mint,
+        &new_authority,
+    )?;
+
+    vault.vault_mut().clear_mint_authority_handoff()?;
+
+    Ok(())
+}
+
+fn _set_mint_authority<'a, 'info>(
+    program_id: &Pubkey,
+    token_program: &SanitizedTokenProgram<'a, 'info>,
+    vault: &SanitizedVault<'a, 'info>,
+    lrt_mint: &SanitizedTokenMint<'a, 'info>,
+    new_authority: &Pubkey,
+) -> ProgramResult {
+    let (_, bump, mut seeds) = Vault::find_program_address(program_id, &vault.vault().base());
+    seeds.push(vec![bump]);
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+
+    invoke_signed(
+        &set_authority(
+            token_program.account().key,
+            lrt_mint.account().key,
+            Some(new_authority),
+            AuthorityType::MintTokens,
+            vault.account().key,
+            &[],
+        )?,
+        &[lrt_mint.account().clone(), vault.account().clone()],
+        &[&seed_slices],
+    )
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    lrt_mint: SanitizedTokenMint<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    config_admin: SanitizedSignerAccount<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ExecuteMintAuthorityHandoff`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let lrt_mint = SanitizedTokenMint::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let config_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let token_program =
+            SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            lrt_mint,
+            admin,
+            config_admin,
+            token_program,
+        })
+    }
+}
@@ -0,0 +1,64 @@
+use jito_restaking_sanitization::assert_with_msg;
+use jito_vault_core::slash_proposal::SanitizedSlashProposal;
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::slash::{slash, SanitizedAccounts};
+
+/// Carries out a pending slash proposal once its veto window has passed. Anyone can execute it:
+/// the slash goes through the same checks as [`crate::VaultInstruction::Slash`] in the epoch it's
+/// executed, and the slashed tokens go to the slasher that proposed it.
+///
+/// Instruction: [`crate::VaultInstruction::ExecuteSlash`]
+pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let slot = Clock::get()?.slot;
+    let (slash_proposal_account, slash_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let mut slash_proposal =
+        SanitizedSlashProposal::sanitize(program_id, slash_proposal_account, true)?;
+    let mut slash_accounts = SanitizedAccounts::sanitize(program_id, slash_accounts, slot, false)?;
+
+    {
+        let proposal = slash_proposal.slash_proposal();
+        assert_with_msg(
+            proposal.vault() == *slash_accounts.vault.account().key
+                && proposal.avs() == *slash_accounts.avs.account().key
+                && proposal.operator() == *slash_accounts.operator.account().key
+                && proposal.slasher() == *slash_accounts.slasher.key,
+            VaultError::SlashProposalMismatch,
+            "Slash accounts don't match the slash proposal",
+        )?;
+    }
+
+    let epoch = slot
+        .checked_div(slash_accounts.config.config().epoch_length())
+        .unwrap();
+    slash_proposal.slash_proposal_mut().execute(epoch)?;
+    let amount = slash_proposal.slash_proposal().amount();
+    slash_accounts
+        .vault_avs_ticket
+        .vault_avs_ticket_mut()
+        .decrement_pending_slash_proposals();
+
+    slash(slash_accounts, slot, amount)?;
+
+    msg!(
+        "SlashProposalExecuted: slash_proposal={} epoch={}",
+        slash_proposal.account().key,
+        epoch
+    );
+    VaultEvent::SlashProposalExecuted {
+        slash_proposal: *slash_proposal.account().key,
+        epoch,
+    }
+    .emit()?;
+
+    Ok(())
+}
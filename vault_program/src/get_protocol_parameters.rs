@@ -0,0 +1,50 @@
+use borsh::BorshSerialize;
+use jito_vault_core::{config::SanitizedConfig, vault::SanitizedVault};
+use jito_vault_sdk::protocol_parameters::ProtocolParameters;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Read-only: sets the return data to the Borsh-encoded [`ProtocolParameters`] of the vault as
+/// of the current slot, so integrator programs can quote with a single CPI.
+///
+/// Instruction: [`crate::VaultInstruction::GetProtocolParameters`]
+pub fn process_get_protocol_parameters(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts { config, vault } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let slot = Clock::get()?.slot;
+    let parameters = ProtocolParameters::new(&config.config(), &vault.vault(), slot);
+    set_return_data(&parameters.try_to_vec()?);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::GetProtocolParameters`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, vault })
+    }
+}
@@ -1,4 +1,5 @@
 use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_sanitization::{
     assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
     system_program::SanitizedSystemProgram, token_mint::SanitizedTokenMint,
@@ -7,6 +8,10 @@ use jito_restaking_sanitization::{
 use jito_vault_core::{
     config::SanitizedConfig, vault::Vault, vault_delegation_list::VaultDelegationList,
 };
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -20,6 +25,15 @@ use solana_program::{
     sysvar::Sysvar,
 };
 use spl_token::state::Mint;
+use spl_token_2022::extension::ExtensionType;
+
+/// The Token-2022 extensions a supported mint may have. Extensions that let a third party move,
+/// freeze or hook into the vault's tokens are rejected.
+const ALLOWED_SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 3] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+];
 
 /// Processes the create instruction: [`crate::VaultInstruction::InitializeVault`]
 pub fn process_initialize_vault(
@@ -36,21 +50,41 @@ pub fn process_initialize_vault(
         mint,
         admin,
         base,
+        payer,
         system_program,
         token_program,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     assert_with_msg(
         lrt_mint.account().is_signer,
-        ProgramError::InvalidAccountData,
+        VaultError::LrtMintNotSigner,
         "Mint account is not a signer",
     )?;
+    // the LRT mint is created under the supported mint's token program so every instruction of
+    // the vault takes a single token program
+    assert_with_msg(
+        mint.token_program() == token_program.account().key,
+        VaultError::TokenProgramMismatch,
+        "Token program does not own the supported mint",
+    )?;
+    if let Some(extension_type) = mint
+        .extension_types()
+        .iter()
+        .find(|extension_type| !ALLOWED_SUPPORTED_MINT_EXTENSIONS.contains(extension_type))
+    {
+        msg!(
+            "Supported mint extension {:?} is not allowed",
+            extension_type
+        );
+        return Err(VaultError::SupportedMintExtensionNotAllowed.into());
+    }
 
     let rent = Rent::get()?;
+    let index = config.config().vaults_count();
 
     _create_lrt_mint(
         &lrt_mint,
-        &admin,
+        &payer,
         &system_program,
         &token_program,
         &vault_account,
@@ -65,6 +99,7 @@ pub fn process_initialize_vault(
         &mint,
         &admin,
         &base,
+        &payer,
         &system_program,
         deposit_fee_bps,
         withdrawal_fee_bps,
@@ -75,18 +110,24 @@ pub fn process_initialize_vault(
         program_id,
         &vault_account,
         &vault_delegation_list_account,
-        &admin,
+        &payer,
         &system_program,
         &rent,
     )?;
 
-    let num_vaults = config.config_mut().increment_vaults();
-    assert_with_msg(
-        num_vaults.is_some(),
-        ProgramError::InvalidAccountData,
-        "Overflow when incrementing number of vaults",
-    )?;
-    config.save()?;
+    config.config_mut().increment_vaults()?;
+
+    VaultEvent::VaultInitialized {
+        vault: *vault_account.account().key,
+        admin: *admin.account().key,
+        base: *base.account().key,
+        lrt_mint: *lrt_mint.account().key,
+        supported_mint: *mint.account().key,
+        index,
+        deposit_fee_bps,
+        withdrawal_fee_bps,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -95,7 +136,7 @@ fn _create_vault_delegation_list<'a, 'info>(
     program_id: &Pubkey,
     vault_account: &EmptyAccount<'a, 'info>,
     vault_delegation_list_account: &EmptyAccount<'a, 'info>,
-    admin: &SanitizedSignerAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     rent: &Rent,
 ) -> ProgramResult {
@@ -104,7 +145,7 @@ fn _create_vault_delegation_list<'a, 'info>(
     vault_delegation_list_seeds.push(vec![bump]);
     assert_with_msg(
         vault_delegation_list_address == *vault_delegation_list_account.account().key,
-        ProgramError::InvalidAccountData,
+        VaultError::VaultDelegationListInvalidPda,
         "Vault delegation list account is not at the correct PDA",
     )?;
     let vault_delegation_list = VaultDelegationList::new(*vault_account.account().key, bump);
@@ -115,7 +156,7 @@ fn _create_vault_delegation_list<'a, 'info>(
     );
     let vault_delegation_list_serialized = vault_delegation_list.try_to_vec()?;
     create_account(
-        admin.account(),
+        payer.account(),
         vault_delegation_list_account.account(),
         system_program.account(),
         program_id,
@@ -139,6 +180,7 @@ fn _create_vault<'a, 'info>(
     mint: &SanitizedTokenMint<'a, 'info>,
     admin: &SanitizedSignerAccount<'a, 'info>,
     base: &SanitizedSignerAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     deposit_fee_bps: u16,
     withdrawal_fee_bps: u16,
@@ -149,7 +191,7 @@ fn _create_vault<'a, 'info>(
     vault_seeds.push(vec![bump]);
     assert_with_msg(
         vault_address == *vault_account.account().key,
-        ProgramError::InvalidAccountData,
+        VaultError::VaultInvalidPda,
         "Vault account is not at the correct PDA",
     )?;
     let vault = Vault::new(
@@ -167,9 +209,9 @@ fn _create_vault<'a, 'info>(
         "Initializing vault @ address {}",
         vault_account.account().key
     );
-    let vault_serialized = vault.try_to_vec()?;
+    let vault_serialized = vault.as_bytes();
     create_account(
-        admin.account(),
+        payer.account(),
         vault_account.account(),
         system_program.account(),
         program_id,
@@ -178,14 +220,14 @@ fn _create_vault<'a, 'info>(
         &vault_seeds,
     )?;
     vault_account.account().data.borrow_mut()[..vault_serialized.len()]
-        .copy_from_slice(&vault_serialized);
+        .copy_from_slice(vault_serialized);
 
     Ok(())
 }
 
 fn _create_lrt_mint<'a, 'info>(
     lrt_mint: &EmptyAccount<'a, 'info>,
-    admin: &SanitizedSignerAccount<'a, 'info>,
+    payer: &SanitizedSignerAccount<'a, 'info>,
     system_program: &SanitizedSystemProgram<'a, 'info>,
     token_program: &SanitizedTokenProgram<'a, 'info>,
     vault_account: &EmptyAccount<'a, 'info>,
@@ -194,22 +236,22 @@ fn _create_lrt_mint<'a, 'info>(
     msg!("Initializing mint @ address {}", lrt_mint.account().key);
     invoke(
         &system_instruction::create_account(
-            admin.account().key,
+            payer.account().key,
             lrt_mint.account().key,
             rent.minimum_balance(Mint::get_packed_len()),
             Mint::get_packed_len() as u64,
             token_program.account().key,
         ),
         &[
-            admin.account().clone(),
+            payer.account().clone(),
             lrt_mint.account().clone(),
             system_program.account().clone(),
         ],
     )?;
 
     invoke(
-        &spl_token::instruction::initialize_mint2(
-            &spl_token::id(),
+        &spl_token_2022::instruction::initialize_mint2(
+            token_program.account().key,
             lrt_mint.account().key,
             vault_account.account().key,
             None,
@@ -228,6 +270,7 @@ struct SanitizedAccounts<'a, 'info> {
     mint: SanitizedTokenMint<'a, 'info>,
     admin: SanitizedSignerAccount<'a, 'info>,
     base: SanitizedSignerAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
     system_program: SanitizedSystemProgram<'a, 'info>,
     token_program: SanitizedTokenProgram<'a, 'info>,
 }
@@ -246,8 +289,10 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             EmptyAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
         let lrt_mint = EmptyAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
         let mint = SanitizedTokenMint::sanitize(next_account_info(&mut accounts_iter)?, false)?;
-        let admin = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
         let base = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, true)?;
         let system_program =
             SanitizedSystemProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
         let token_program =
@@ -261,6 +306,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             mint,
             admin,
             base,
+            payer,
             system_program,
             token_program,
         })
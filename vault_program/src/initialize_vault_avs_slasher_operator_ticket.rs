@@ -1,4 +1,4 @@
-use borsh::BorshSerialize;
+use jito_jsm_core::zero_copy::ZeroCopy;
 use jito_restaking_core::{avs::SanitizedAvs, operator::SanitizedOperator};
 use jito_restaking_sanitization::{
     assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
@@ -9,6 +9,10 @@ use jito_vault_core::{
     vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
     vault_avs_slasher_ticket::SanitizedVaultAvsSlasherTicket,
 };
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -37,6 +41,9 @@ pub fn process_initialize_vault_avs_slasher_operator_ticket(
         system_program,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
     let slot = Clock::get()?.slot;
     vault_avs_slasher_ticket
         .vault_avs_slasher_ticket()
@@ -57,6 +64,16 @@ pub fn process_initialize_vault_avs_slasher_operator_ticket(
         epoch,
     )?;
 
+    VaultEvent::VaultAvsSlasherOperatorTicketCreated {
+        vault: *vault.account().key,
+        avs: *avs.account().key,
+        slasher: *slasher.key,
+        operator: *operator.account().key,
+        ticket: *vault_avs_slasher_operator_ticket.account().key,
+        epoch,
+    }
+    .emit()?;
+
     Ok(())
 }
 
@@ -85,7 +102,7 @@ fn _create_vault_avs_slasher_operator_ticket<'a, 'info>(
 
     assert_with_msg(
         address == *vault_avs_slasher_operator_ticket_account.account().key,
-        ProgramError::InvalidAccountData,
+        VaultError::VaultAvsSlasherOperatorTicketInvalidPda,
         "Invalid vault AVS slasher operator ticket PDA",
     )?;
 
@@ -103,7 +120,7 @@ fn _create_vault_avs_slasher_operator_ticket<'a, 'info>(
         "Creating vault AVS slasher operator ticket: {:?}",
         vault_avs_slasher_operator_ticket_account.account().key
     );
-    let serialized = vault_avs_slasher_operator_ticket.try_to_vec()?;
+    let serialized = vault_avs_slasher_operator_ticket.as_bytes();
     create_account(
         payer.account(),
         vault_avs_slasher_operator_ticket_account.account(),
@@ -117,7 +134,7 @@ fn _create_vault_avs_slasher_operator_ticket<'a, 'info>(
         .account()
         .data
         .borrow_mut()[..serialized.len()]
-        .copy_from_slice(&serialized);
+        .copy_from_slice(serialized);
     Ok(())
 }
 
@@ -0,0 +1,95 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_vault_core::{vault::SanitizedVault, vault_referrer::VaultReferrer};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// Creates the account a referrer accrues its share of the deposit fees of a vault in. Anyone
+/// can pay for it, so wallets and aggregators can set themselves up without the vault admin.
+///
+/// Instruction: [`crate::VaultInstruction::InitializeVaultReferrer`]
+pub fn process_initialize_vault_referrer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        vault,
+        vault_referrer,
+        referrer,
+        payer,
+        system_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    let (address, bump, mut seeds) =
+        VaultReferrer::find_program_address(program_id, vault.account().key, referrer.key);
+    seeds.push(vec![bump]);
+
+    assert_with_msg(
+        address == *vault_referrer.account().key,
+        VaultError::VaultReferrerInvalidPda,
+        "Invalid vault referrer PDA",
+    )?;
+
+    let vault_referrer_data = VaultReferrer::new(*vault.account().key, *referrer.key, bump);
+
+    msg!(
+        "Creating vault referrer: {:?}",
+        vault_referrer.account().key
+    );
+    let serialized = vault_referrer_data.as_bytes();
+    create_account(
+        payer.account(),
+        vault_referrer.account(),
+        system_program.account(),
+        program_id,
+        &Rent::get()?,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    vault_referrer.account().data.borrow_mut()[..serialized.len()].copy_from_slice(serialized);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    vault_referrer: EmptyAccount<'a, 'info>,
+    referrer: &'a AccountInfo<'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::InitializeVaultReferrer`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault_referrer = EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let referrer = next_account_info(accounts_iter)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            vault,
+            vault_referrer,
+            referrer,
+            payer,
+            system_program,
+        })
+    }
+}
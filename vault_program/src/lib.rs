@@ -1,25 +1,61 @@
+mod accept_admin;
 mod add_avs;
 mod add_delegation;
 mod add_operator;
 mod add_slasher;
+mod blacklist_operator;
 mod burn;
+mod burn_withdrawal_ticket;
+mod cancel_mint_authority_handoff;
+mod claim_referral_fees;
+mod clear_mint_burn_authority;
+mod config_accept_admin;
+mod config_set_admin;
+mod config_set_epoch_length;
+mod config_set_parameter;
+mod config_set_paused;
+mod config_set_program_fee;
+mod config_set_vault_paused;
 mod create_token_metadata;
 mod enqueue_withdrawal;
+mod execute_mint_authority_handoff;
+mod execute_slash;
+mod get_protocol_parameters;
 mod initialize_config;
 mod initialize_vault;
 mod initialize_vault_avs_slasher_operator_ticket;
+mod initialize_vault_referrer;
 mod initialize_vault_with_mint;
+mod migrate_account;
+mod migrate_deposit;
 mod mint_to;
+mod pay_avs_service_fee;
+mod propose_mint_authority_handoff;
+mod propose_slash;
 mod remove_avs;
 mod remove_delegation;
 mod remove_operator;
 mod set_admin;
+mod set_avs_service_fee;
 mod set_capacity;
+mod set_crank_tip;
+mod set_deposit_fee;
+mod set_emergency_mode;
+mod set_fees;
+mod set_migration_target;
+mod set_mint_burn_authority;
+mod set_referral_fee;
 mod set_secondary_admin;
+mod set_withdrawal_fee;
+mod set_withdrawal_fee_grace_period;
 mod slash;
+mod unblacklist_operator;
 mod update_delegations;
 mod update_token_metadata;
+mod update_vault_balance;
+mod veto_slash;
 mod withdrawal_asset;
+mod zap_deposit;
 
 use borsh::BorshDeserialize;
 use jito_vault_sdk::VaultInstruction;
@@ -31,19 +67,45 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
-    add_avs::process_vault_add_avs, add_delegation::process_add_delegation,
-    add_operator::process_vault_add_operator, add_slasher::process_add_slasher, burn::process_burn,
+    accept_admin::process_accept_admin, add_avs::process_vault_add_avs,
+    add_delegation::process_add_delegation, add_operator::process_vault_add_operator,
+    add_slasher::process_add_slasher, blacklist_operator::process_blacklist_operator,
+    burn::process_burn, burn_withdrawal_ticket::process_burn_withdrawal_ticket,
+    cancel_mint_authority_handoff::process_cancel_mint_authority_handoff,
+    claim_referral_fees::process_claim_referral_fees,
+    clear_mint_burn_authority::process_clear_mint_burn_authority,
+    config_accept_admin::process_config_accept_admin, config_set_admin::process_config_set_admin,
+    config_set_epoch_length::process_config_set_epoch_length,
+    config_set_parameter::process_config_set_parameter,
+    config_set_paused::process_config_set_paused,
+    config_set_program_fee::process_config_set_program_fee,
+    config_set_vault_paused::process_config_set_vault_paused,
     create_token_metadata::process_create_token_metadata,
-    enqueue_withdrawal::process_enqueue_withdrawal, initialize_config::process_initialize_config,
-    initialize_vault::process_initialize_vault,
+    enqueue_withdrawal::process_enqueue_withdrawal,
+    execute_mint_authority_handoff::process_execute_mint_authority_handoff,
+    execute_slash::process_execute_slash, get_protocol_parameters::process_get_protocol_parameters,
+    initialize_config::process_initialize_config, initialize_vault::process_initialize_vault,
     initialize_vault_avs_slasher_operator_ticket::process_initialize_vault_avs_slasher_operator_ticket,
-    initialize_vault_with_mint::process_initialize_vault_with_mint, mint_to::process_mint,
-    remove_avs::process_vault_remove_avs, remove_delegation::process_remove_delegation,
-    remove_operator::process_vault_remove_operator, set_admin::process_set_admin,
-    set_capacity::process_set_capacity, set_secondary_admin::process_set_secondary_admin,
-    slash::process_slash, update_delegations::process_update_delegations,
+    initialize_vault_referrer::process_initialize_vault_referrer,
+    initialize_vault_with_mint::process_initialize_vault_with_mint,
+    migrate_account::process_migrate_account, migrate_deposit::process_migrate_deposit,
+    mint_to::process_mint, pay_avs_service_fee::process_pay_avs_service_fee,
+    propose_mint_authority_handoff::process_propose_mint_authority_handoff,
+    propose_slash::process_propose_slash, remove_avs::process_vault_remove_avs,
+    remove_delegation::process_remove_delegation, remove_operator::process_vault_remove_operator,
+    set_admin::process_set_admin, set_avs_service_fee::process_set_avs_service_fee,
+    set_capacity::process_set_capacity, set_crank_tip::process_set_crank_tip,
+    set_deposit_fee::process_set_deposit_fee, set_emergency_mode::process_set_emergency_mode,
+    set_fees::process_set_fees, set_migration_target::process_set_migration_target,
+    set_mint_burn_authority::process_set_mint_burn_authority,
+    set_referral_fee::process_set_referral_fee, set_secondary_admin::process_set_secondary_admin,
+    set_withdrawal_fee::process_set_withdrawal_fee,
+    set_withdrawal_fee_grace_period::process_set_withdrawal_fee_grace_period, slash::process_slash,
+    unblacklist_operator::process_unblacklist_operator,
+    update_delegations::process_update_delegations,
     update_token_metadata::process_update_token_metadata,
-    withdrawal_asset::process_withdrawal_asset,
+    update_vault_balance::process_update_vault_balance, veto_slash::process_veto_slash,
+    withdrawal_asset::process_withdrawal_asset, zap_deposit::process_zap_deposit,
 };
 
 declare_id!("DVoKuzt4i8EAakix852XwSAYmXnECdhegB6EDtabp4dg");
@@ -82,6 +144,18 @@ pub fn process_instruction(
             msg!("Instruction: InitializeConfig");
             process_initialize_config(program_id, accounts)
         }
+        VaultInstruction::ConfigSetAdmin => {
+            msg!("Instruction: ConfigSetAdmin");
+            process_config_set_admin(program_id, accounts)
+        }
+        VaultInstruction::ConfigAcceptAdmin => {
+            msg!("Instruction: ConfigAcceptAdmin");
+            process_config_accept_admin(program_id, accounts)
+        }
+        VaultInstruction::ConfigSetEpochLength { epoch_length } => {
+            msg!("Instruction: ConfigSetEpochLength");
+            process_config_set_epoch_length(program_id, accounts, epoch_length)
+        }
         VaultInstruction::InitializeVault {
             deposit_fee_bps,
             withdrawal_fee_bps,
@@ -104,10 +178,30 @@ pub fn process_instruction(
             msg!("Instruction: SetAdmin");
             process_set_admin(program_id, accounts)
         }
+        VaultInstruction::AcceptAdmin => {
+            msg!("Instruction: AcceptAdmin");
+            process_accept_admin(program_id, accounts)
+        }
         VaultInstruction::SetDepositCapacity { amount } => {
             msg!("Instruction: SetCapacity");
             process_set_capacity(program_id, accounts, amount)
         }
+        VaultInstruction::SetEmergencyMode { enabled } => {
+            msg!("Instruction: SetEmergencyMode");
+            process_set_emergency_mode(program_id, accounts, enabled)
+        }
+        VaultInstruction::SetWithdrawalFee { fee_bps } => {
+            msg!("Instruction: SetWithdrawalFee");
+            process_set_withdrawal_fee(program_id, accounts, fee_bps)
+        }
+        VaultInstruction::SetWithdrawalFeeGracePeriod { epochs } => {
+            msg!("Instruction: SetWithdrawalFeeGracePeriod");
+            process_set_withdrawal_fee_grace_period(program_id, accounts, epochs)
+        }
+        VaultInstruction::SetDepositFee { fee_bps } => {
+            msg!("Instruction: SetDepositFee");
+            process_set_deposit_fee(program_id, accounts, fee_bps)
+        }
         VaultInstruction::WithdrawalAsset { amount } => {
             msg!("Instruction: WithdrawalAsset");
             process_withdrawal_asset(program_id, accounts, amount)
@@ -123,10 +217,21 @@ pub fn process_instruction(
             msg!("Instruction: Burn");
             process_burn(program_id, accounts, amount)
         }
+        VaultInstruction::ZapDeposit {
+            amount,
+            min_lrt_out,
+        } => {
+            msg!("Instruction: ZapDeposit");
+            process_zap_deposit(program_id, accounts, amount, min_lrt_out)
+        }
         VaultInstruction::EnqueueWithdrawal { amount } => {
             msg!("Instruction: EnqueueWithdrawal");
             process_enqueue_withdrawal(program_id, accounts, amount)
         }
+        VaultInstruction::BurnWithdrawalTicket => {
+            msg!("Instruction: BurnWithdrawalTicket");
+            process_burn_withdrawal_ticket(program_id, accounts)
+        }
         // ------------------------------------------
         // Vault-AVS operations
         // ------------------------------------------
@@ -138,6 +243,14 @@ pub fn process_instruction(
             msg!("Instruction: RemoveAvs");
             process_vault_remove_avs(program_id, accounts)
         }
+        VaultInstruction::SetAvsServiceFee { fee_bps } => {
+            msg!("Instruction: SetAvsServiceFee");
+            process_set_avs_service_fee(program_id, accounts, fee_bps)
+        }
+        VaultInstruction::PayAvsServiceFee => {
+            msg!("Instruction: PayAvsServiceFee");
+            process_pay_avs_service_fee(program_id, accounts)
+        }
         // ------------------------------------------
         // Vault-operator operations
         // ------------------------------------------
@@ -190,5 +303,123 @@ pub fn process_instruction(
             msg!("Instruction: UpdateTokenMetadata");
             process_update_token_metadata(program_id, accounts, name, symbol, uri)
         }
+        // ------------------------------------------
+        // LRT mint authority handoff
+        // ------------------------------------------
+        VaultInstruction::ProposeMintAuthorityHandoff { new_authority } => {
+            msg!("Instruction: ProposeMintAuthorityHandoff");
+            process_propose_mint_authority_handoff(program_id, accounts, new_authority)
+        }
+        VaultInstruction::ExecuteMintAuthorityHandoff => {
+            msg!("Instruction: ExecuteMintAuthorityHandoff");
+            process_execute_mint_authority_handoff(program_id, accounts)
+        }
+        VaultInstruction::CancelMintAuthorityHandoff => {
+            msg!("Instruction: CancelMintAuthorityHandoff");
+            process_cancel_mint_authority_handoff(program_id, accounts)
+        }
+        // ------------------------------------------
+        // Deposit migration
+        // ------------------------------------------
+        VaultInstruction::SetMigrationTarget { migration_target } => {
+            msg!("Instruction: SetMigrationTarget");
+            process_set_migration_target(program_id, accounts, migration_target)
+        }
+        VaultInstruction::MigrateDeposit { amount } => {
+            msg!("Instruction: MigrateDeposit");
+            process_migrate_deposit(program_id, accounts, amount)
+        }
+        // ------------------------------------------
+        // Queries
+        // ------------------------------------------
+        VaultInstruction::GetProtocolParameters => {
+            msg!("Instruction: GetProtocolParameters");
+            process_get_protocol_parameters(program_id, accounts)
+        }
+        VaultInstruction::BlacklistOperator => {
+            msg!("Instruction: BlacklistOperator");
+            process_blacklist_operator(program_id, accounts)
+        }
+        VaultInstruction::UnblacklistOperator => {
+            msg!("Instruction: UnblacklistOperator");
+            process_unblacklist_operator(program_id, accounts)
+        }
+        VaultInstruction::SetReferralFee { fee_bps } => {
+            msg!("Instruction: SetReferralFee");
+            process_set_referral_fee(program_id, accounts, fee_bps)
+        }
+        VaultInstruction::InitializeVaultReferrer => {
+            msg!("Instruction: InitializeVaultReferrer");
+            process_initialize_vault_referrer(program_id, accounts)
+        }
+        VaultInstruction::ClaimReferralFees => {
+            msg!("Instruction: ClaimReferralFees");
+            process_claim_referral_fees(program_id, accounts)
+        }
+        VaultInstruction::ConfigSetPaused { paused } => {
+            msg!("Instruction: ConfigSetPaused");
+            process_config_set_paused(program_id, accounts, paused)
+        }
+        VaultInstruction::ConfigSetVaultPaused { paused } => {
+            msg!("Instruction: ConfigSetVaultPaused");
+            process_config_set_vault_paused(program_id, accounts, paused)
+        }
+        VaultInstruction::ConfigSetProgramFee { fee_bps } => {
+            msg!("Instruction: ConfigSetProgramFee");
+            process_config_set_program_fee(program_id, accounts, fee_bps)
+        }
+        VaultInstruction::SetFees {
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+        } => {
+            msg!("Instruction: SetFees");
+            process_set_fees(
+                program_id,
+                accounts,
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+            )
+        }
+        VaultInstruction::ProposeSlash {
+            amount,
+            evidence_hash,
+        } => {
+            msg!("Instruction: ProposeSlash");
+            process_propose_slash(program_id, accounts, amount, evidence_hash)
+        }
+        VaultInstruction::VetoSlash => {
+            msg!("Instruction: VetoSlash");
+            process_veto_slash(program_id, accounts)
+        }
+        VaultInstruction::ExecuteSlash => {
+            msg!("Instruction: ExecuteSlash");
+            process_execute_slash(program_id, accounts)
+        }
+        VaultInstruction::SetCrankTip { lamports } => {
+            msg!("Instruction: SetCrankTip");
+            process_set_crank_tip(program_id, accounts, lamports)
+        }
+        VaultInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        VaultInstruction::SetMintBurnAuthority => {
+            msg!("Instruction: SetMintBurnAuthority");
+            process_set_mint_burn_authority(program_id, accounts)
+        }
+        VaultInstruction::ClearMintBurnAuthority => {
+            msg!("Instruction: ClearMintBurnAuthority");
+            process_clear_mint_burn_authority(program_id, accounts)
+        }
+        VaultInstruction::UpdateVaultBalance => {
+            msg!("Instruction: UpdateVaultBalance");
+            process_update_vault_balance(program_id, accounts)
+        }
+        VaultInstruction::ConfigSetParameter(parameter) => {
+            msg!("Instruction: ConfigSetParameter");
+            process_config_set_parameter(program_id, accounts, parameter)
+        }
     }
 }
@@ -0,0 +1,312 @@
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount,
+    signer::SanitizedSignerAccount, token_mint::SanitizedTokenMint,
+    token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig,
+    result::VaultCoreError,
+    vault::{SanitizedVault, Vault},
+    vault_delegation_list::SanitizedVaultDelegationList,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::{burn, mint_to, transfer_checked};
+
+/// Moves a staker's deposit from one vault to another in a single instruction.
+///
+/// # Behavior
+/// * The configuration admin shall have opened migrations from the source vault to the
+///   destination vault with [`crate::VaultInstruction::SetMigrationTarget`].
+/// * Both vaults shall support the same token.
+/// * The staker's LRT is burned at the source vault's exchange rate and the supported tokens
+///   backing it are moved to the destination vault, which mints LRT at its own exchange rate.
+///   No deposit or withdrawal fees are charged, and the withdrawal cooldown is skipped since the
+///   tokens never leave the protocol.
+/// * Since it skips the cooldown, a migration is only served while the source vault's withdrawal
+///   queue is empty and from the tokens it hasn't delegated, and not in emergency mode.
+/// * Vaults with a mint burn authority require its signature, the source vault's for the burn and
+///   the destination vault's for the mint.
+///
+/// Instruction: [`crate::VaultInstruction::MigrateDeposit`]
+pub fn process_migrate_deposit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut source_vault,
+        source_lrt_mint,
+        mut destination_vault,
+        destination_lrt_mint,
+        staker,
+        staker_source_lrt_token_account,
+        staker_destination_lrt_token_account,
+        source_vault_token_account,
+        destination_vault_token_account,
+        supported_mint,
+        token_program,
+        source_vault_delegation_list,
+        burn_signer,
+        mint_signer,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    source_vault.vault().check_not_paused()?;
+    destination_vault.vault().check_not_paused()?;
+
+    source_vault
+        .vault()
+        .check_migration_target(destination_vault.account().key)?;
+    if source_vault.vault().supported_mint() != destination_vault.vault().supported_mint() {
+        msg!("Source and destination vaults support different tokens");
+        return Err(VaultCoreError::VaultMigrationSupportedMintMismatch.into());
+    }
+
+    assert_with_msg(
+        *source_lrt_mint.account().key == source_vault.vault().lrt_mint(),
+        VaultError::LrtMintMismatch,
+        "Source mint account does not match LRT mint",
+    )?;
+    assert_with_msg(
+        *destination_lrt_mint.account().key == destination_vault.vault().lrt_mint(),
+        VaultError::LrtMintMismatch,
+        "Destination mint account does not match LRT mint",
+    )?;
+    if let Some(burn_signer) = burn_signer {
+        assert_with_msg(
+            Some(*burn_signer.account().key) == source_vault.vault().mint_burn_authority(),
+            VaultError::MintBurnSignerMismatch,
+            "Burn signer does not match vault burn signer",
+        )?;
+    }
+    if let Some(mint_signer) = mint_signer {
+        assert_with_msg(
+            Some(*mint_signer.account().key) == destination_vault.vault().mint_burn_authority(),
+            VaultError::MintBurnSignerMismatch,
+            "Mint signer does not match vault mint signer",
+        )?;
+    }
+
+    // out-of-band token account increases carrying a reward fee shall be harvested first
+    let epoch = Clock::get()?
+        .slot
+        .checked_div(config.config().epoch_length())
+        .unwrap();
+    source_vault
+        .vault_mut()
+        .sync_balance(source_vault_token_account.token_account().amount, epoch)?;
+    destination_vault.vault_mut().sync_balance(
+        destination_vault_token_account.token_account().amount,
+        epoch,
+    )?;
+
+    let delegated_amount = source_vault_delegation_list
+        .vault_delegation_list()
+        .total_delegation()
+        .ok_or(VaultCoreError::VaultWithdrawalOverflow)?;
+    let liquid_amount = source_vault
+        .vault()
+        .tokens_deposited()
+        .saturating_sub(delegated_amount);
+    let tokens_to_migrate = source_vault
+        .vault_mut()
+        .burn_and_migrate(amount, liquid_amount)?;
+    // the destination vault only receives what's left after the Token-2022 transfer fee
+    let tokens_received = tokens_to_migrate
+        .checked_sub(supported_mint.transfer_fee(Clock::get()?.epoch, tokens_to_migrate)?)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let lrt_to_mint = destination_vault
+        .vault_mut()
+        .deposit_and_mint_with_capacity_check(tokens_received)?;
+
+    msg!(
+        "Migrating {} LRT ({} tokens) from vault {} to vault {} for {} LRT",
+        amount,
+        tokens_to_migrate,
+        source_vault.account().key,
+        destination_vault.account().key,
+        lrt_to_mint
+    );
+
+    invoke(
+        &burn(
+            token_program.account().key,
+            staker_source_lrt_token_account.account().key,
+            source_lrt_mint.account().key,
+            staker.account().key,
+            &[],
+            amount,
+        )?,
+        &[
+            staker_source_lrt_token_account.account().clone(),
+            source_lrt_mint.account().clone(),
+            staker.account().clone(),
+        ],
+    )?;
+
+    let (_, bump, mut seeds) =
+        Vault::find_program_address(program_id, &source_vault.vault().base());
+    seeds.push(vec![bump]);
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+    invoke_signed(
+        &transfer_checked(
+            token_program.account().key,
+            source_vault_token_account.account().key,
+            supported_mint.account().key,
+            destination_vault_token_account.account().key,
+            source_vault.account().key,
+            &[],
+            tokens_to_migrate,
+            supported_mint.mint().decimals,
+        )?,
+        &[
+            source_vault_token_account.account().clone(),
+            supported_mint.account().clone(),
+            destination_vault_token_account.account().clone(),
+            source_vault.account().clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    let (_, bump, mut seeds) =
+        Vault::find_program_address(program_id, &destination_vault.vault().base());
+    seeds.push(vec![bump]);
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+    invoke_signed(
+        &mint_to(
+            token_program.account().key,
+            destination_lrt_mint.account().key,
+            staker_destination_lrt_token_account.account().key,
+            destination_vault.account().key,
+            &[],
+            lrt_to_mint,
+        )?,
+        &[
+            destination_lrt_mint.account().clone(),
+            staker_destination_lrt_token_account.account().clone(),
+            destination_vault.account().clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    source_vault: SanitizedVault<'a, 'info>,
+    source_lrt_mint: SanitizedTokenMint<'a, 'info>,
+    destination_vault: SanitizedVault<'a, 'info>,
+    destination_lrt_mint: SanitizedTokenMint<'a, 'info>,
+    staker: SanitizedSignerAccount<'a, 'info>,
+    staker_source_lrt_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    staker_destination_lrt_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    source_vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    destination_vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    supported_mint: SanitizedTokenMint<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+    source_vault_delegation_list: SanitizedVaultDelegationList<'a, 'info>,
+    burn_signer: Option<SanitizedSignerAccount<'a, 'info>>,
+    mint_signer: Option<SanitizedSignerAccount<'a, 'info>>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::MigrateDeposit`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let source_vault =
+            SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let source_lrt_mint =
+            SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, true)?;
+        let destination_vault =
+            SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let destination_lrt_mint =
+            SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, true)?;
+        let staker = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let staker_source_lrt_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &source_vault.vault().lrt_mint(),
+            staker.account().key,
+        )?;
+        let staker_destination_lrt_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &destination_vault.vault().lrt_mint(),
+            staker.account().key,
+        )?;
+        let source_vault_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &source_vault.vault().supported_mint(),
+            source_vault.account().key,
+        )?;
+        let destination_vault_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &destination_vault.vault().supported_mint(),
+            destination_vault.account().key,
+        )?;
+        let supported_mint =
+            SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, false)?;
+        assert_with_msg(
+            *supported_mint.account().key == source_vault.vault().supported_mint(),
+            VaultError::SupportedMintMismatch,
+            "Mint account does not match the vault supported mint",
+        )?;
+        let token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+        let source_vault_delegation_list = SanitizedVaultDelegationList::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            false,
+            source_vault.account().key,
+        )?;
+        let burn_signer = if source_vault.vault().mint_burn_authority().is_some() {
+            Some(SanitizedSignerAccount::sanitize(
+                next_account_info(accounts_iter)?,
+                false,
+            )?)
+        } else {
+            None
+        };
+        let mint_signer = if destination_vault.vault().mint_burn_authority().is_some() {
+            Some(SanitizedSignerAccount::sanitize(
+                next_account_info(accounts_iter)?,
+                false,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(SanitizedAccounts {
+            config,
+            source_vault,
+            source_lrt_mint,
+            destination_vault,
+            destination_lrt_mint,
+            staker,
+            staker_source_lrt_token_account,
+            staker_destination_lrt_token_account,
+            source_vault_token_account,
+            destination_vault_token_account,
+            supported_mint,
+            token_program,
+            source_vault_delegation_list,
+            burn_signer,
+            mint_signer,
+        })
+    }
+}
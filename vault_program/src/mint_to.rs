@@ -3,19 +3,33 @@ use jito_restaking_sanitization::{
     signer::SanitizedSignerAccount, token_mint::SanitizedTokenMint,
     token_program::SanitizedTokenProgram,
 };
-use jito_vault_core::vault::{SanitizedVault, Vault};
+use jito_vault_core::{
+    config::SanitizedConfig,
+    vault::{MintSummary, SanitizedVault, Vault},
+    vault_referrer::SanitizedVaultReferrer,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvar::Sysvar,
 };
-use spl_token::instruction::{mint_to, transfer};
+use spl_token_2022::instruction::{mint_to, transfer_checked};
+
+use crate::update_vault_balance::_mint_reward_fee;
 
 /// Processes the mint instruction: [`crate::VaultInstruction::MintTo`]
 pub fn process_mint(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
     let SanitizedAccounts {
+        config,
         mut vault,
         lrt_mint,
         depositor,
@@ -23,43 +37,76 @@ pub fn process_mint(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64)
         vault_token_account,
         depositor_lrt_token_account,
         vault_fee_token_account,
+        program_fee_token_account,
+        supported_mint,
         token_program,
         mint_signer,
+        mut vault_referrer,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
     // The LRT mint provided shall be equal to the one the vault supports
     assert_with_msg(
         *lrt_mint.account().key == vault.vault().lrt_mint(),
-        ProgramError::InvalidAccountData,
+        VaultError::LrtMintMismatch,
         "Mint account does not match LRT mint",
     )?;
     // If a mint_signer is set, the signer shall be authorized by the vault to make deposits
     if let Some(mint_signer) = mint_signer {
         assert_with_msg(
             *mint_signer.account().key == vault.vault().mint_burn_authority().unwrap(),
-            ProgramError::InvalidAccountData,
+            VaultError::MintBurnSignerMismatch,
             "Mint signer does not match vault mint signer",
         )?;
     }
 
-    // refresh the amount in the vault in-case out-of-band token account increases
-    vault
-        .vault_mut()
-        .set_tokens_deposited(vault_token_account.token_account().amount);
-
+    // the vault only receives what's left after the Token-2022 transfer fee
+    let amount_received = amount
+        .checked_sub(supported_mint.transfer_fee(Clock::get()?.epoch, amount)?)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
     _transfer_to_vault(
         &token_program,
+        &supported_mint,
         &depositor_token_account,
         &vault_token_account,
         &depositor,
         amount,
     )?;
 
-    let lrt_to_mint = vault
-        .vault_mut()
-        .deposit_and_mint_with_capacity_check(amount)?;
-    let lrt_to_fee_account = vault.vault().calculate_deposit_fee(lrt_to_mint)?;
-    let lrt_to_user = lrt_to_mint.checked_sub(lrt_to_fee_account).unwrap();
+    // fees scheduled by the fee admin apply from their epoch
+    let epoch = Clock::get()?
+        .slot
+        .checked_div(config.config().epoch_length())
+        .unwrap();
+    vault.vault_mut().apply_pending_fees(epoch);
+
+    // the vault token account was read before the transfer, so out-of-band deposits are harvested
+    let MintSummary {
+        lrt_to_depositor,
+        lrt_to_fee,
+        lrt_to_program_fee,
+        balance_update,
+    } = vault.vault_mut().mint_with_fee(
+        vault_token_account.token_account().amount,
+        amount_received,
+        config.config().program_fee_bps(),
+        epoch,
+    )?;
+
+    // the referrer's share of the fee is minted to it when claimed
+    let lrt_to_referrer = match vault_referrer.as_mut() {
+        Some(vault_referrer) => {
+            let lrt_to_referrer = vault.vault().calculate_referral_fee(lrt_to_fee)?;
+            vault_referrer
+                .vault_referrer_mut()
+                .accrue(lrt_to_referrer)?;
+            lrt_to_referrer
+        }
+        None => 0,
+    };
+    let lrt_to_fee = lrt_to_fee.checked_sub(lrt_to_referrer).unwrap();
 
     // mint LRT to user and fee wallet
     _mint_lrt(
@@ -68,7 +115,7 @@ pub fn process_mint(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64)
         &vault,
         &lrt_mint,
         &depositor_lrt_token_account,
-        lrt_to_user,
+        lrt_to_depositor,
     )?;
     _mint_lrt(
         program_id,
@@ -76,15 +123,58 @@ pub fn process_mint(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64)
         &vault,
         &lrt_mint,
         &vault_fee_token_account,
-        lrt_to_fee_account,
+        lrt_to_fee,
+    )?;
+    _mint_program_fee(
+        program_id,
+        &token_program,
+        &config,
+        &vault,
+        &lrt_mint,
+        program_fee_token_account.as_ref(),
+        lrt_to_program_fee,
+    )?;
+    _mint_reward_fee(
+        program_id,
+        &token_program,
+        &vault,
+        &lrt_mint,
+        &vault_fee_token_account,
+        epoch,
+        balance_update,
     )?;
 
-    vault.save()?;
+    VaultEvent::Deposited {
+        vault: *vault.account().key,
+        depositor: *depositor.account().key,
+        recipient: *depositor.account().key,
+        amount: amount_received,
+        lrt_minted: lrt_to_depositor,
+        lrt_fee: lrt_to_fee,
+    }
+    .emit()?;
+
+    if let Some(vault_referrer) = vault_referrer {
+        let referrer = vault_referrer.vault_referrer().referrer();
+        msg!(
+            "ReferralFeeAccrued: vault={} referrer={} lrt_amount={}",
+            vault.account().key,
+            referrer,
+            lrt_to_referrer
+        );
+        VaultEvent::ReferralFeeAccrued {
+            vault: *vault.account().key,
+            referrer,
+            lrt_amount: lrt_to_referrer,
+        }
+        .emit()?;
+    }
 
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
     vault: SanitizedVault<'a, 'info>,
     lrt_mint: SanitizedTokenMint<'a, 'info>,
     depositor: SanitizedSignerAccount<'a, 'info>,
@@ -92,8 +182,11 @@ struct SanitizedAccounts<'a, 'info> {
     vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
     depositor_lrt_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
     vault_fee_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    program_fee_token_account: Option<SanitizedAssociatedTokenAccount<'a, 'info>>,
+    supported_mint: SanitizedTokenMint<'a, 'info>,
     token_program: SanitizedTokenProgram<'a, 'info>,
     mint_signer: Option<SanitizedSignerAccount<'a, 'info>>,
+    vault_referrer: Option<SanitizedVaultReferrer<'a, 'info>>,
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
@@ -104,6 +197,8 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
         let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
         let lrt_mint = SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, true)?;
         let depositor = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
@@ -127,6 +222,18 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             &vault.vault().lrt_mint(),
             &vault.vault().fee_owner(),
         )?;
+        let program_fee_token_account = _sanitize_program_fee_token_account(
+            &config,
+            &vault,
+            next_account_info(accounts_iter)?,
+        )?;
+        let supported_mint =
+            SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, false)?;
+        assert_with_msg(
+            *supported_mint.account().key == vault.vault().supported_mint(),
+            VaultError::SupportedMintMismatch,
+            "Mint account does not match the vault supported mint",
+        )?;
         let token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
         let mint_signer = if vault.vault().mint_burn_authority().is_some() {
             Some(SanitizedSignerAccount::sanitize(
@@ -136,8 +243,18 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
         } else {
             None
         };
+        let vault_referrer = match next_account_info(accounts_iter) {
+            Ok(vault_referrer) => Some(SanitizedVaultReferrer::sanitize(
+                program_id,
+                vault_referrer,
+                true,
+                vault.account().key,
+            )?),
+            Err(_) => None,
+        };
 
         Ok(SanitizedAccounts {
+            config,
             vault,
             lrt_mint,
             depositor,
@@ -145,8 +262,11 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             vault_token_account,
             depositor_lrt_token_account,
             vault_fee_token_account,
+            program_fee_token_account,
+            supported_mint,
             token_program,
             mint_signer,
+            vault_referrer,
         })
     }
 }
@@ -155,35 +275,97 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
 /// using a CPI.
 ///
 /// # Arguments
+/// * `mint` - The mint of the tokens, checked against the decimals by the token program
 /// * `depositor_token_account` - The source token account to transfer from
 /// * `vault_token_account` - The destination token account to transfer to
 /// * `owner` - The owner of the source token account
-/// * `amount` - The amount of tokens to transfer
-fn _transfer_to_vault<'a, 'info>(
+/// * `amount` - The amount of tokens to transfer, before any Token-2022 transfer fee
+pub fn _transfer_to_vault<'a, 'info>(
     token_program: &SanitizedTokenProgram,
+    mint: &SanitizedTokenMint<'a, 'info>,
     depositor_token_account: &SanitizedAssociatedTokenAccount<'a, 'info>,
     vault_token_account: &SanitizedAssociatedTokenAccount<'a, 'info>,
     owner: &SanitizedSignerAccount<'a, 'info>,
     amount: u64,
 ) -> ProgramResult {
     invoke(
-        &transfer(
+        &transfer_checked(
             token_program.account().key,
             depositor_token_account.account().key,
+            mint.account().key,
             vault_token_account.account().key,
             owner.account().key,
             &[],
             amount,
+            mint.mint().decimals,
         )?,
         &[
             depositor_token_account.account().clone(),
+            mint.account().clone(),
             vault_token_account.account().clone(),
             owner.account().clone(),
         ],
     )
 }
 
-fn _mint_lrt<'a, 'info>(
+/// Loads the LRT account of the program fee wallet, which is only checked while a program fee is
+/// set, so deposits don't depend on the wallet before the fee is switched on.
+pub fn _sanitize_program_fee_token_account<'a, 'info>(
+    config: &SanitizedConfig<'a, 'info>,
+    vault: &SanitizedVault<'a, 'info>,
+    program_fee_token_account: &'a AccountInfo<'info>,
+) -> Result<Option<SanitizedAssociatedTokenAccount<'a, 'info>>, ProgramError> {
+    if config.config().program_fee_bps() == 0 {
+        return Ok(None);
+    }
+    Ok(Some(SanitizedAssociatedTokenAccount::sanitize(
+        program_fee_token_account,
+        &vault.vault().lrt_mint(),
+        &config.config().program_fee_wallet(),
+    )?))
+}
+
+/// Mints the program fee of a deposit to the program fee wallet
+pub fn _mint_program_fee<'a, 'info>(
+    program_id: &Pubkey,
+    token_program: &SanitizedTokenProgram,
+    config: &SanitizedConfig<'a, 'info>,
+    vault: &SanitizedVault<'a, 'info>,
+    lrt_mint: &SanitizedTokenMint<'a, 'info>,
+    program_fee_token_account: Option<&SanitizedAssociatedTokenAccount<'a, 'info>>,
+    lrt_to_program_fee: u64,
+) -> ProgramResult {
+    let Some(program_fee_token_account) = program_fee_token_account else {
+        return Ok(());
+    };
+    if lrt_to_program_fee == 0 {
+        return Ok(());
+    }
+    _mint_lrt(
+        program_id,
+        token_program,
+        vault,
+        lrt_mint,
+        program_fee_token_account,
+        lrt_to_program_fee,
+    )?;
+
+    let wallet = config.config().program_fee_wallet();
+    msg!(
+        "ProgramFeeCharged: vault={} wallet={} lrt_amount={}",
+        vault.account().key,
+        wallet,
+        lrt_to_program_fee
+    );
+    VaultEvent::ProgramFeeCharged {
+        vault: *vault.account().key,
+        wallet,
+        lrt_amount: lrt_to_program_fee,
+    }
+    .emit()
+}
+
+pub fn _mint_lrt<'a, 'info>(
     program_id: &Pubkey,
     token_program: &SanitizedTokenProgram,
     vault: &SanitizedVault<'a, 'info>,
@@ -0,0 +1,315 @@
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_operator_ticket::AvsOperatorTicket,
+    avs_vault_ticket::SanitizedAvsVaultTicket, result::RestakingCoreError,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount,
+    token_mint::SanitizedTokenMint, token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig,
+    result::VaultCoreError,
+    vault::{SanitizedVault, Vault},
+    vault_avs_ticket::SanitizedVaultAvsTicket,
+    vault_delegation_list::SanitizedVaultDelegationList,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::transfer_checked;
+
+/// Pays the AVS the service fee the vault and AVS agreed on for every epoch since the fee was
+/// last paid. This instruction is permissionless.
+///
+/// # Behavior
+/// * The fee is a percentage of the stake the vault delegates to operators securing the AVS and
+///   is only collected when the AVS and vault tickets are both active, the vault ticket has
+///   warmed up, and both agree on the fee. It accrues from the epoch the later of the two sides
+///   set it in, so a vault isn't charged for epochs before the AVS agreed.
+/// * The AVS operator ticket of every operator in the vault delegation list is passed in as
+///   remaining accounts, in the order of the delegation list. Only the delegation to operators
+///   whose ticket is active is charged; the ticket of an operator the AVS never added is passed
+///   in empty.
+/// * The fee is paid from vault rewards, which are the tokens held by the vault in excess of the
+///   tokens deposited, so depositors are never charged against their principal. Any fee not
+///   covered by rewards stays owed to the AVS and is paid first once the vault earns rewards.
+///   The fees owed are held back from the rewards harvested by
+///   [`crate::VaultInstruction::UpdateVaultBalance`], which should run after this instruction
+///   so the fee is settled out of the rewards before they're harvested.
+///
+/// Instruction: [`crate::VaultInstruction::PayAvsServiceFee`]
+pub fn process_pay_avs_service_fee(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        avs,
+        avs_vault_ticket,
+        mut vault_avs_ticket,
+        vault_delegation_list,
+        vault_token_account,
+        avs_token_account,
+        supported_mint,
+        token_program,
+        avs_operator_tickets,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
+    let slot = Clock::get()?.slot;
+    let epoch_length = config.config().epoch_length();
+    avs_vault_ticket.avs_vault_ticket().check_active(slot)?;
+    vault_avs_ticket
+        .vault_avs_ticket()
+        .check_warmed_up(slot, epoch_length)?;
+
+    let epoch = slot.checked_div(epoch_length).unwrap();
+    let avs_service_fee_epoch = avs_vault_ticket
+        .avs_vault_ticket()
+        .service_fee_slot()
+        .checked_div(epoch_length)
+        .unwrap();
+    let delegated_amount = _avs_delegated_amount(
+        &config.config().restaking_program(),
+        avs.account().key,
+        &vault_delegation_list,
+        avs_operator_tickets,
+        slot,
+    )?;
+
+    let accrued = vault_avs_ticket.vault_avs_ticket_mut().accrue_service_fee(
+        avs_vault_ticket.avs_vault_ticket().service_fee_bps(),
+        avs_service_fee_epoch,
+        delegated_amount,
+        epoch,
+    );
+    // the fee owed from earlier epochs can still be paid within an epoch
+    let fee = match accrued {
+        Err(VaultCoreError::VaultAvsServiceFeeAlreadyPaid)
+            if vault_avs_ticket.vault_avs_ticket().service_fee_owed() > 0 =>
+        {
+            0
+        }
+        fee => fee?,
+    };
+    vault_avs_ticket
+        .vault_avs_ticket_mut()
+        .add_service_fee_owed(fee)?;
+    vault.vault_mut().accrue_service_fees_owed(fee)?;
+
+    let rewards = vault_token_account
+        .token_account()
+        .amount
+        .saturating_sub(vault.vault().tokens_deposited());
+    let amount = vault_avs_ticket
+        .vault_avs_ticket_mut()
+        .pay_service_fee_owed(rewards);
+    vault.vault_mut().pay_service_fees_owed(amount);
+
+    msg!(
+        "Paying AVS service fee of {} (accrued: {}, rewards: {}, still owed: {})",
+        amount,
+        fee,
+        rewards,
+        vault_avs_ticket.vault_avs_ticket().service_fee_owed()
+    );
+    if amount > 0 {
+        _transfer_service_fee(
+            &token_program,
+            &supported_mint,
+            &vault,
+            &vault_token_account,
+            &avs_token_account,
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Sums the delegation to the operators holding an active ticket for the AVS. The delegation
+/// list and the AVS operator tickets are matched up by position.
+fn _avs_delegated_amount(
+    restaking_program: &Pubkey,
+    avs: &Pubkey,
+    vault_delegation_list: &SanitizedVaultDelegationList,
+    avs_operator_tickets: &[AccountInfo],
+    slot: u64,
+) -> Result<u64, ProgramError> {
+    let vault_delegation_list = vault_delegation_list.vault_delegation_list();
+    let delegations = vault_delegation_list.delegations();
+    assert_with_msg(
+        avs_operator_tickets.len() == delegations.len(),
+        ProgramError::NotEnoughAccountKeys,
+        "An AVS operator ticket shall be passed in for every delegation",
+    )?;
+
+    let mut delegated_amount: u64 = 0;
+    for (delegation, avs_operator_ticket) in delegations.iter().zip(avs_operator_tickets) {
+        if avs_operator_ticket.data_is_empty() {
+            let expected = AvsOperatorTicket::find_program_address(
+                restaking_program,
+                avs,
+                &delegation.operator(),
+            )
+            .0;
+            if *avs_operator_ticket.key != expected {
+                msg!(
+                    "AVS operator ticket {} doesn't match {}",
+                    avs_operator_ticket.key,
+                    expected
+                );
+                return Err(RestakingCoreError::AvsOperatorTicketInvalidPda.into());
+            }
+            continue;
+        }
+        let ticket = AvsOperatorTicket::deserialize_checked(
+            restaking_program,
+            avs_operator_ticket,
+            avs,
+            &delegation.operator(),
+        )?;
+        if !ticket.state().is_active(slot) {
+            continue;
+        }
+        delegated_amount = delegation
+            .deactivating_amount()
+            .and_then(|amount| amount.checked_add(delegation.active_amount()))
+            .and_then(|amount| amount.checked_add(delegated_amount))
+            .ok_or(VaultCoreError::VaultAvsServiceFeeOverflow)?;
+    }
+    Ok(delegated_amount)
+}
+
+fn _transfer_service_fee<'a, 'info>(
+    token_program: &SanitizedTokenProgram<'a, 'info>,
+    supported_mint: &SanitizedTokenMint<'a, 'info>,
+    vault: &SanitizedVault<'a, 'info>,
+    vault_token_account: &SanitizedAssociatedTokenAccount<'a, 'info>,
+    avs_token_account: &SanitizedAssociatedTokenAccount<'a, 'info>,
+    amount: u64,
+) -> ProgramResult {
+    let mut vault_seeds = Vault::seeds(&vault.vault().base());
+    vault_seeds.push(vec![vault.vault().bump()]);
+    let vault_seeds_slice = vault_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+
+    invoke_signed(
+        &transfer_checked(
+            token_program.account().key,
+            vault_token_account.account().key,
+            supported_mint.account().key,
+            avs_token_account.account().key,
+            vault.account().key,
+            &[],
+            amount,
+            supported_mint.mint().decimals,
+        )?,
+        &[
+            vault_token_account.account().clone(),
+            supported_mint.account().clone(),
+            avs_token_account.account().clone(),
+            vault.account().clone(),
+        ],
+        &[vault_seeds_slice.as_slice()],
+    )?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    avs: SanitizedAvs<'a, 'info>,
+    avs_vault_ticket: SanitizedAvsVaultTicket<'a, 'info>,
+    vault_avs_ticket: SanitizedVaultAvsTicket<'a, 'info>,
+    vault_delegation_list: SanitizedVaultDelegationList<'a, 'info>,
+    vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    avs_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    supported_mint: SanitizedTokenMint<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+    avs_operator_tickets: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::PayAvsServiceFee`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let avs = SanitizedAvs::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let avs_vault_ticket = SanitizedAvsVaultTicket::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(&mut accounts_iter)?,
+            false,
+            avs.account().key,
+            vault.account().key,
+        )?;
+        let vault_avs_ticket = SanitizedVaultAvsTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+            vault.account().key,
+            avs.account().key,
+        )?;
+        let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            false,
+            vault.account().key,
+        )?;
+        let vault_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            &vault.vault().supported_mint(),
+            vault.account().key,
+        )?;
+        let avs_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(&mut accounts_iter)?,
+            &vault.vault().supported_mint(),
+            avs.account().key,
+        )?;
+        let supported_mint =
+            SanitizedTokenMint::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        assert_with_msg(
+            *supported_mint.account().key == vault.vault().supported_mint(),
+            VaultError::SupportedMintMismatch,
+            "Mint account does not match the vault supported mint",
+        )?;
+        let token_program =
+            SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            avs,
+            avs_vault_ticket,
+            vault_avs_ticket,
+            vault_delegation_list,
+            vault_token_account,
+            avs_token_account,
+            supported_mint,
+            token_program,
+            avs_operator_tickets: accounts_iter.as_slice(),
+        })
+    }
+}
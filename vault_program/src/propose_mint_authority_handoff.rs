@@ -0,0 +1,82 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::{config::SanitizedConfig, vault::SanitizedVault};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// Proposes handing the LRT mint authority off from the vault to `new_authority`.
+///
+/// # Behavior
+/// * The vault admin and the configuration admin shall both sign the proposal.
+/// * The handoff can only be executed once an epoch has passed, giving LRT holders time to react
+///   before a vault migration takes effect. Proposing again restarts the timelock.
+///
+/// Instruction: [`crate::VaultInstruction::ProposeMintAuthorityHandoff`]
+pub fn process_propose_mint_authority_handoff(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_authority: Pubkey,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        admin,
+        config_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_admin(admin.account().key)?;
+    config.config().check_admin(config_admin.account().key)?;
+
+    let slot = Clock::get()?.slot;
+
+    msg!(
+        "Proposing mint authority handoff of vault {} to {} at slot {}",
+        vault.account().key,
+        new_authority,
+        slot
+    );
+    vault
+        .vault_mut()
+        .propose_mint_authority_handoff(new_authority, slot)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    config_admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ProposeMintAuthorityHandoff`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let config_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            admin,
+            config_admin,
+        })
+    }
+}
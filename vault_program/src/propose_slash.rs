@@ -0,0 +1,252 @@
+use jito_jsm_core::zero_copy::ZeroCopy;
+use jito_restaking_core::{
+    avs::SanitizedAvs, avs_vault_slasher_ticket::SanitizedAvsVaultSlasherTicket,
+    operator::SanitizedOperator,
+};
+use jito_restaking_sanitization::{
+    assert_with_msg, create_account, empty_account::EmptyAccount, signer::SanitizedSignerAccount,
+    system_program::SanitizedSystemProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig, result::VaultCoreError, slash_proposal::SlashProposal,
+    vault::SanitizedVault, vault_avs_slasher_ticket::SanitizedVaultAvsSlasherTicket,
+    vault_avs_ticket::SanitizedVaultAvsTicket, vault_operator_ticket::SanitizedVaultOperatorTicket,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+/// The slasher files a slash of an operator of the vault, backed by the hash of its evidence.
+///
+/// # Behavior
+/// * The slasher shall be active for the AVS and the vault, and the amount shall fit in the
+///   per-epoch cap the AVS approved for the slasher.
+/// * The slash veto admin of the AVS can veto the proposal during
+///   [`jito_vault_core::slash_proposal::SLASH_VETO_WINDOW_EPOCHS`], after which anyone can
+///   execute it with [`crate::VaultInstruction::ExecuteSlash`].
+/// * The proposal is counted as pending on the vault AVS ticket until then, which keeps operators
+///   from being finalized out of the AVS while the slash can still land.
+///
+/// Instruction: [`crate::VaultInstruction::ProposeSlash`]
+pub fn process_propose_slash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    evidence_hash: [u8; 32],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        vault,
+        avs,
+        operator,
+        slasher,
+        avs_vault_slasher_ticket,
+        vault_avs_slasher_ticket,
+        slash_proposal,
+        payer,
+        system_program,
+        vault_operator_ticket,
+        mut vault_avs_ticket,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
+    assert_with_msg(
+        amount > 0,
+        VaultError::SlashAmountZero,
+        "Slash amount shall be greater than zero",
+    )?;
+
+    let slot = Clock::get()?.slot;
+    avs_vault_slasher_ticket
+        .avs_vault_slasher_ticket()
+        .check_active(slot)?;
+    vault_avs_slasher_ticket
+        .vault_avs_slasher_ticket()
+        .check_active(slot)?;
+    if amount
+        > avs_vault_slasher_ticket
+            .avs_vault_slasher_ticket()
+            .max_slashable_per_epoch()
+    {
+        msg!("Slash amount exceeds the max slashable per epoch");
+        return Err(VaultCoreError::VaultAvsSlasherOperatorMaxSlashableExceeded.into());
+    }
+
+    // A slash can only execute against an operator the vault's stake has warmed up with
+    vault_operator_ticket
+        .vault_operator_ticket()
+        .check_warmed_up(slot, config.config().epoch_length())?;
+
+    let epoch = slot.checked_div(config.config().epoch_length()).unwrap();
+
+    let (address, bump, mut seeds) = SlashProposal::find_program_address(
+        program_id,
+        vault.account().key,
+        avs.account().key,
+        slasher.account().key,
+        operator.account().key,
+        &evidence_hash,
+    );
+    seeds.push(vec![bump]);
+    assert_with_msg(
+        address == *slash_proposal.account().key,
+        VaultError::SlashProposalInvalidPda,
+        "Invalid slash proposal PDA",
+    )?;
+
+    let proposal = SlashProposal::new(
+        *vault.account().key,
+        *avs.account().key,
+        *slasher.account().key,
+        *operator.account().key,
+        evidence_hash,
+        amount,
+        epoch,
+        bump,
+    );
+
+    msg!(
+        "Creating slash proposal: {:?}",
+        slash_proposal.account().key
+    );
+    let serialized = proposal.as_bytes();
+    create_account(
+        payer.account(),
+        slash_proposal.account(),
+        system_program.account(),
+        program_id,
+        &Rent::get()?,
+        serialized.len() as u64,
+        &seeds,
+    )?;
+    slash_proposal.account().data.borrow_mut()[..serialized.len()].copy_from_slice(serialized);
+
+    vault_avs_ticket
+        .vault_avs_ticket_mut()
+        .increment_pending_slash_proposals()?;
+
+    msg!(
+        "SlashProposed: slash_proposal={} vault={} avs={} operator={} slasher={} amount={} executable_epoch={}",
+        slash_proposal.account().key,
+        vault.account().key,
+        avs.account().key,
+        operator.account().key,
+        slasher.account().key,
+        amount,
+        proposal.executable_epoch()
+    );
+    VaultEvent::SlashProposed {
+        slash_proposal: *slash_proposal.account().key,
+        vault: *vault.account().key,
+        avs: *avs.account().key,
+        operator: *operator.account().key,
+        slasher: *slasher.account().key,
+        amount,
+        evidence_hash,
+        executable_epoch: proposal.executable_epoch(),
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    avs: SanitizedAvs<'a, 'info>,
+    operator: SanitizedOperator<'a, 'info>,
+    slasher: SanitizedSignerAccount<'a, 'info>,
+    avs_vault_slasher_ticket: SanitizedAvsVaultSlasherTicket<'a, 'info>,
+    vault_avs_slasher_ticket: SanitizedVaultAvsSlasherTicket<'a, 'info>,
+    slash_proposal: EmptyAccount<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+    vault_operator_ticket: SanitizedVaultOperatorTicket<'a, 'info>,
+    vault_avs_ticket: SanitizedVaultAvsTicket<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::ProposeSlash`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
+        let operator = SanitizedOperator::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
+        let slasher = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let avs_vault_slasher_ticket = SanitizedAvsVaultSlasherTicket::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(accounts_iter)?,
+            false,
+            avs.account().key,
+            vault.account().key,
+            slasher.account().key,
+        )?;
+        let vault_avs_slasher_ticket = SanitizedVaultAvsSlasherTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            false,
+            vault.account().key,
+            avs.account().key,
+            slasher.account().key,
+        )?;
+        let slash_proposal = EmptyAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+        let vault_operator_ticket = SanitizedVaultOperatorTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            false,
+            vault.account().key,
+            operator.account().key,
+        )?;
+        let vault_avs_ticket = SanitizedVaultAvsTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            vault.account().key,
+            avs.account().key,
+        )?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            avs,
+            operator,
+            slasher,
+            avs_vault_slasher_ticket,
+            vault_avs_slasher_ticket,
+            slash_proposal,
+            payer,
+            system_program,
+            vault_operator_ticket,
+            vault_avs_ticket,
+        })
+    }
+}
@@ -3,10 +3,12 @@ use jito_restaking_sanitization::signer::SanitizedSignerAccount;
 use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault, vault_avs_ticket::SanitizedVaultAvsTicket,
 };
+use jito_vault_sdk::event::{Event, VaultEvent};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -31,7 +33,22 @@ pub fn process_vault_remove_avs(program_id: &Pubkey, accounts: &[AccountInfo]) -
     let slot = Clock::get()?.slot;
     vault_avs_ticket.vault_avs_ticket_mut().deactivate(slot)?;
 
-    vault_avs_ticket.save()?;
+    msg!(
+        "VaultAvsTicketDeactivated: vault={} avs={} ticket={} index={} slot={}",
+        vault.account().key,
+        vault_avs_ticket.vault_avs_ticket().avs(),
+        vault_avs_ticket.account().key,
+        vault_avs_ticket.vault_avs_ticket().index(),
+        slot
+    );
+    VaultEvent::VaultAvsTicketDeactivated {
+        vault: *vault.account().key,
+        avs: vault_avs_ticket.vault_avs_ticket().avs(),
+        ticket: *vault_avs_ticket.account().key,
+        index: vault_avs_ticket.vault_avs_ticket().index(),
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
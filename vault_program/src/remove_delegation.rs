@@ -3,6 +3,7 @@ use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault,
     vault_delegation_list::SanitizedVaultDelegationList,
 };
+use jito_vault_sdk::event::{Event, VaultEvent};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
@@ -25,18 +26,33 @@ pub fn process_remove_delegation(
         delegation_admin,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
     vault
         .vault()
         .check_delegation_admin(delegation_admin.account().key)?;
+    let slot = Clock::get()?.slot;
     vault_delegation_list
         .vault_delegation_list_mut()
-        .update_delegations(Clock::get()?.slot, config.config().epoch_length());
-    vault_delegation_list
-        .vault_delegation_list_mut()
-        .undelegate(*operator.key, amount)?;
+        .remove_delegation(
+            *operator.key,
+            amount,
+            slot,
+            config.config().epoch_length(),
+            vault.vault().emergency_mode(),
+        )?;
 
     vault_delegation_list.save()?;
 
+    VaultEvent::DelegationRemoved {
+        vault: *vault.account().key,
+        operator: *operator.key,
+        amount,
+        slot,
+    }
+    .emit()?;
+
     Ok(())
 }
 
@@ -59,13 +75,13 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
         let vault =
             SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let operator = next_account_info(&mut accounts_iter)?;
         let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
             program_id,
             next_account_info(&mut accounts_iter)?,
             true,
             vault.account().key,
         )?;
-        let operator = next_account_info(&mut accounts_iter)?;
         let delegation_admin =
             SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
 
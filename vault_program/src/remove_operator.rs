@@ -4,10 +4,12 @@ use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault,
     vault_operator_ticket::SanitizedVaultOperatorTicket,
 };
+use jito_vault_sdk::event::{Event, VaultEvent};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -33,7 +35,22 @@ pub fn process_vault_remove_operator(
 
     // TODO (LB): should one deactivate the stake here as well?
 
-    vault_operator_ticket.save()?;
+    msg!(
+        "VaultOperatorTicketDeactivated: vault={} operator={} ticket={} index={} slot={}",
+        vault.account().key,
+        vault_operator_ticket.vault_operator_ticket().operator(),
+        vault_operator_ticket.account().key,
+        vault_operator_ticket.vault_operator_ticket().index(),
+        slot
+    );
+    VaultEvent::VaultOperatorTicketDeactivated {
+        vault: *vault.account().key,
+        operator: vault_operator_ticket.vault_operator_ticket().operator(),
+        ticket: *vault_operator_ticket.account().key,
+        index: vault_operator_ticket.vault_operator_ticket().index(),
+        slot,
+    }
+    .emit()?;
 
     Ok(())
 }
@@ -3,11 +3,15 @@ use jito_vault_core::vault::SanitizedVault;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
 
-/// Processes the set admin instruction: [`crate::VaultInstruction::SetAdmin`]
+/// The vault admin proposes a new admin. The transfer only completes once the new admin signs
+/// [`crate::VaultInstruction::AcceptAdmin`].
+///
+/// Instruction: [`crate::VaultInstruction::SetAdmin`]
 pub fn process_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let SanitizedAccounts {
         old_admin,
@@ -16,15 +20,20 @@ pub fn process_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progr
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     vault.vault().check_admin(old_admin.account().key)?;
-    vault.vault_mut().set_admin(*new_admin.account().key);
-    vault.save()?;
+    vault.vault_mut().set_pending_admin(*new_admin.key);
+
+    msg!(
+        "VaultAdminProposed: vault={} pending_admin={}",
+        vault.account().key,
+        new_admin.key
+    );
 
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
     old_admin: SanitizedSignerAccount<'a, 'info>,
-    new_admin: SanitizedSignerAccount<'a, 'info>,
+    new_admin: &'a AccountInfo<'info>,
     vault: SanitizedVault<'a, 'info>,
 }
 
@@ -39,8 +48,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
         let old_admin =
             SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
-        let new_admin =
-            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let new_admin = next_account_info(&mut accounts_iter)?;
 
         Ok(SanitizedAccounts {
             old_admin,
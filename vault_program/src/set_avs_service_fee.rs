@@ -0,0 +1,96 @@
+use jito_restaking_core::avs::SanitizedAvs;
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::{
+    config::SanitizedConfig, vault::SanitizedVault, vault_avs_ticket::SanitizedVaultAvsTicket,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The vault agrees to pay the AVS a service fee for the security the AVS provides.
+///
+/// # Behavior
+/// * The vault AVS admin shall have the ability to set the service fee on an active AVS ticket.
+/// * Fees accrue from the current epoch and are only collected if the fee matches the fee set by
+///   the AVS on its side of the relationship.
+///
+/// Instruction: [`crate::VaultInstruction::SetAvsServiceFee`]
+pub fn process_set_avs_service_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        vault,
+        mut vault_avs_ticket,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_avs_admin(admin.account().key)?;
+
+    let slot = Clock::get()?.slot;
+    vault_avs_ticket.vault_avs_ticket().check_active(slot)?;
+
+    let epoch = slot.checked_div(config.config().epoch_length()).unwrap();
+
+    msg!(
+        "Setting service fee on vault AVS ticket {} to {} bps",
+        vault_avs_ticket.account().key,
+        fee_bps
+    );
+    vault_avs_ticket
+        .vault_avs_ticket_mut()
+        .set_service_fee_bps(fee_bps, epoch)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    vault_avs_ticket: SanitizedVaultAvsTicket<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::SetAvsServiceFee`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(&mut accounts_iter)?,
+            false,
+        )?;
+        let vault_avs_ticket = SanitizedVaultAvsTicket::sanitize(
+            program_id,
+            next_account_info(&mut accounts_iter)?,
+            true,
+            vault.account().key,
+            avs.account().key,
+        )?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            vault_avs_ticket,
+            admin,
+        })
+    }
+}
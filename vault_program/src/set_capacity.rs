@@ -16,7 +16,6 @@ pub fn process_set_capacity(
 
     vault.vault().check_admin(admin.account().key)?;
     vault.vault_mut().set_capacity(capacity);
-    vault.save()?;
 
     Ok(())
 }
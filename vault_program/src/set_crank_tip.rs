@@ -0,0 +1,53 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::vault::SanitizedVault;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The vault admin sets the tip paid to whoever cranks the delegations of the vault into a new
+/// epoch with [`crate::VaultInstruction::UpdateDelegations`]. Tips are paid out of lamports sent to
+/// the vault account on top of its rent-exempt minimum, and stop once those run out.
+///
+/// [`crate::VaultInstruction::SetCrankTip`]
+pub fn process_set_crank_tip(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lamports: u64,
+) -> ProgramResult {
+    let SanitizedAccounts { mut vault, admin } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_admin(admin.account().key)?;
+    vault.vault_mut().set_crank_tip_lamports(lamports)?;
+
+    msg!(
+        "CrankTipSet: vault={} lamports={}",
+        vault.account().key,
+        lamports
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::SetCrankTip`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { vault, admin })
+    }
+}
@@ -0,0 +1,19 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::set_fees::schedule_fees;
+
+/// The fee admin schedules a new deposit fee for a vault, keeping the other fees scheduled. The
+/// change is held to the same bound and delay as [`crate::VaultInstruction::SetFees`].
+///
+/// Instruction: [`crate::VaultInstruction::SetDepositFee`]
+pub fn process_set_deposit_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    schedule_fees(
+        program_id,
+        accounts,
+        |(_, withdrawal_fee_bps, reward_fee_bps)| (fee_bps, withdrawal_fee_bps, reward_fee_bps),
+    )
+}
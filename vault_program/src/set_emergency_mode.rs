@@ -0,0 +1,70 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::{config::SanitizedConfig, vault::SanitizedVault};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Activates or deactivates emergency mode on a vault.
+///
+/// # Behavior
+/// * The configuration admin shall have the ability to put a vault into emergency mode if an AVS
+///   or operator the vault is delegated to is compromised.
+/// * While in emergency mode, withdrawals are honored pro-rata from the liquid assets in the vault
+///   and delegation cooldowns are finalized without waiting for the epoch boundary.
+///
+/// Instruction: [`crate::VaultInstruction::SetEmergencyMode`]
+pub fn process_set_emergency_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    enabled: bool,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!(
+        "Setting emergency mode on vault {} to {}",
+        vault.account().key,
+        enabled
+    );
+    vault.vault_mut().set_emergency_mode(enabled);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::SetEmergencyMode`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            admin,
+        })
+    }
+}
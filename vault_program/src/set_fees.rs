@@ -0,0 +1,116 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::{config::SanitizedConfig, vault::SanitizedVault};
+use jito_vault_sdk::event::{Event, VaultEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The fee admin schedules new deposit, withdrawal and reward fees for a vault.
+///
+/// # Behavior
+/// * The fees apply [`jito_vault_core::vault::FEE_CHANGE_DELAY_EPOCHS`] after the current epoch,
+///   so stakers get at least a full epoch to withdraw at the old fees.
+/// * A raised withdrawal fee also waits out the grace period in the configuration, and never
+///   lands before a raise already pending.
+/// * Each fee can only move by [`jito_vault_core::vault::MAX_FEE_DELTA_BPS`] from the fee in
+///   effect, and a new update replaces any pending one.
+///
+/// Instruction: [`crate::VaultInstruction::SetFees`]
+pub fn process_set_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    deposit_fee_bps: u16,
+    withdrawal_fee_bps: u16,
+    reward_fee_bps: u16,
+) -> ProgramResult {
+    schedule_fees(program_id, accounts, |_| {
+        (deposit_fee_bps, withdrawal_fee_bps, reward_fee_bps)
+    })
+}
+
+/// Schedules the fees picked from the deposit, withdrawal and reward fees already scheduled, see
+/// [`jito_vault_core::vault::Vault::scheduled_fees`]. The setters of a single fee keep the others.
+pub(crate) fn schedule_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fees: impl FnOnce((u16, u16, u16)) -> (u16, u16, u16),
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        fee_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_fee_admin(fee_admin.account().key)?;
+
+    let epoch = Clock::get()?
+        .slot
+        .checked_div(config.config().epoch_length())
+        .unwrap();
+    let (deposit_fee_bps, withdrawal_fee_bps, reward_fee_bps) =
+        fees(vault.vault().scheduled_fees());
+    let (effective_epoch, withdrawal_fee_effective_epoch) = vault.vault_mut().set_fees(
+        deposit_fee_bps,
+        withdrawal_fee_bps,
+        reward_fee_bps,
+        epoch,
+        config.config().withdrawal_fee_grace_epochs(),
+    )?;
+
+    msg!(
+        "FeesSet: vault={} deposit_fee_bps={} withdrawal_fee_bps={} reward_fee_bps={} effective_epoch={} withdrawal_fee_effective_epoch={}",
+        vault.account().key,
+        deposit_fee_bps,
+        withdrawal_fee_bps,
+        reward_fee_bps,
+        effective_epoch,
+        withdrawal_fee_effective_epoch
+    );
+    VaultEvent::FeesSet {
+        vault: *vault.account().key,
+        deposit_fee_bps,
+        withdrawal_fee_bps,
+        reward_fee_bps,
+        effective_epoch,
+        withdrawal_fee_effective_epoch,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    fee_admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instructions: [`crate::VaultInstruction::SetFees`],
+    /// [`crate::VaultInstruction::SetDepositFee`] and [`crate::VaultInstruction::SetWithdrawalFee`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let fee_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            fee_admin,
+        })
+    }
+}
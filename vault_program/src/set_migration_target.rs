@@ -0,0 +1,75 @@
+use jito_restaking_sanitization::{assert_with_msg, signer::SanitizedSignerAccount};
+use jito_vault_core::{config::SanitizedConfig, vault::SanitizedVault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Opens migrations of deposits out of a vault into `migration_target`, or closes them when
+/// `migration_target` is the default pubkey.
+///
+/// # Behavior
+/// * Migrations are coordinated by the protocol, so only the configuration admin can open them.
+///
+/// Instruction: [`crate::VaultInstruction::SetMigrationTarget`]
+pub fn process_set_migration_target(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    migration_target: Pubkey,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    assert_with_msg(
+        migration_target != *vault.account().key,
+        VaultError::MigrationTargetIsVault,
+        "Vault can't migrate deposits to itself",
+    )?;
+
+    msg!(
+        "Setting migration target of vault {} to {}",
+        vault.account().key,
+        migration_target
+    );
+    vault.vault_mut().set_migration_target(migration_target);
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::SetMigrationTarget`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, false)?;
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            admin,
+        })
+    }
+}
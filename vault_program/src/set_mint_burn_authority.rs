@@ -0,0 +1,69 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::vault::SanitizedVault;
+use jito_vault_sdk::event::{Event, VaultEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The vault admin sets the mint burn authority of the vault, which from then on shall co-sign
+/// every deposit and withdrawal, e.g. for vaults only open to stakers that passed KYC.
+///
+/// [`crate::VaultInstruction::SetMintBurnAuthority`]
+pub fn process_set_mint_burn_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        mut vault,
+        admin,
+        mint_burn_authority,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_admin(admin.account().key)?;
+    vault
+        .vault_mut()
+        .set_mint_burn_authority(*mint_burn_authority.key);
+
+    msg!(
+        "MintBurnAuthoritySet: vault={} mint_burn_authority={}",
+        vault.account().key,
+        mint_burn_authority.key
+    );
+    VaultEvent::MintBurnAuthoritySet {
+        vault: *vault.account().key,
+        mint_burn_authority: *mint_burn_authority.key,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+    mint_burn_authority: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::SetMintBurnAuthority`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let admin = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let mint_burn_authority = next_account_info(accounts_iter)?;
+
+        Ok(SanitizedAccounts {
+            vault,
+            admin,
+            mint_burn_authority,
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::vault::SanitizedVault;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Sets the share of the deposit fee paid to the referrer of a deposit. Like the deposit fee, a
+/// change applies to the deposits made after it.
+///
+/// Instruction: [`crate::VaultInstruction::SetReferralFee`]
+pub fn process_set_referral_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    let SanitizedAccounts { mut vault, admin } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault.vault().check_admin(admin.account().key)?;
+
+    let old_fee_bps = vault.vault().referral_fee_bps();
+    vault.vault_mut().set_referral_fee_bps(fee_bps)?;
+
+    msg!(
+        "ReferralFeeSet: vault={} old_fee_bps={} new_fee_bps={}",
+        vault.account().key,
+        old_fee_bps,
+        fee_bps
+    );
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::SetReferralFee`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let vault =
+            SanitizedVault::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { vault, admin })
+    }
+}
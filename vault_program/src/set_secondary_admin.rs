@@ -32,10 +32,11 @@ pub fn process_set_secondary_admin(
         VaultAdminRole::MintBurnAuthority => {
             vault.vault_mut().set_mint_burn_authority(*new_admin.key);
         }
+        VaultAdminRole::FeeAdmin => {
+            vault.vault_mut().set_fee_admin(*new_admin.key);
+        }
     }
 
-    vault.save()?;
-
     Ok(())
 }
 
@@ -0,0 +1,23 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::set_fees::schedule_fees;
+
+/// The fee admin schedules a new withdrawal fee for a vault, keeping the other fees scheduled.
+///
+/// # Behavior
+/// * The change is held to the same bound and delay as [`crate::VaultInstruction::SetFees`].
+/// * An increase also waits out the grace period in the configuration, so depositors can
+///   withdraw at the old fee before a raise lands.
+///
+/// Instruction: [`crate::VaultInstruction::SetWithdrawalFee`]
+pub fn process_set_withdrawal_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    schedule_fees(
+        program_id,
+        accounts,
+        |(deposit_fee_bps, _, reward_fee_bps)| (deposit_fee_bps, fee_bps, reward_fee_bps),
+    )
+}
@@ -0,0 +1,55 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::config::SanitizedConfig;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The configuration admin sets the number of epochs a raised vault withdrawal fee waits before
+/// applying. Increases that are already pending keep their effective epoch. Grace periods outside
+/// [`jito_vault_core::config::MIN_WITHDRAWAL_FEE_GRACE_EPOCHS`] and
+/// [`jito_vault_core::config::MAX_WITHDRAWAL_FEE_GRACE_EPOCHS`] are rejected.
+///
+/// Instruction: [`crate::VaultInstruction::SetWithdrawalFeeGracePeriod`]
+pub fn process_set_withdrawal_fee_grace_period(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epochs: u64,
+) -> ProgramResult {
+    let SanitizedAccounts { mut config, admin } =
+        SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_admin(admin.account().key)?;
+
+    msg!("Setting withdrawal fee grace period to {} epochs", epochs);
+    config
+        .config_mut()
+        .set_withdrawal_fee_grace_epochs(epochs)?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::SetWithdrawalFeeGracePeriod`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let mut accounts_iter = accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(&mut accounts_iter)?, true)?;
+        let admin =
+            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts { config, admin })
+    }
+}
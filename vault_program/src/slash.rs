@@ -6,7 +6,8 @@ use jito_restaking_core::{
     operator_vault_ticket::SanitizedOperatorVaultTicket,
 };
 use jito_restaking_sanitization::{
-    associated_token_account::SanitizedAssociatedTokenAccount, signer::SanitizedSignerAccount,
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount,
+    signer::SanitizedSignerAccount, token_mint::SanitizedTokenMint,
     token_program::SanitizedTokenProgram,
 };
 use jito_vault_core::{
@@ -18,25 +19,55 @@ use jito_vault_core::{
     vault_delegation_list::SanitizedVaultDelegationList,
     vault_operator_ticket::SanitizedVaultOperatorTicket,
 };
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
 };
-use spl_token::instruction::transfer;
+use spl_token_2022::instruction::transfer_checked;
 
 /// Processes the vault slash instruction: [`crate::VaultInstruction::Slash`]
+///
+/// Slashes of an AVS that ever set a slash veto admin shall be proposed with
+/// [`crate::VaultInstruction::ProposeSlash`] instead, so they can be vetoed. Clearing the role
+/// doesn't lift this, or the AVS could clear it and slash in one transaction.
 pub fn process_slash(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     slash_amount: u64,
 ) -> ProgramResult {
     let slot = Clock::get()?.slot;
+    let slash_accounts = SanitizedAccounts::sanitize(program_id, accounts, slot, true)?;
+
+    if slash_accounts.avs.avs().slash_proposals_required() {
+        msg!(
+            "AVS {} set a slash veto admin, slashes shall be proposed",
+            slash_accounts.avs.account().key
+        );
+        return Err(VaultError::SlashRequiresProposal.into());
+    }
+
+    slash(slash_accounts, slot, slash_amount)
+}
+
+/// Checks the vault, operator, AVS and slasher are all still opted-in to each other and slashes
+/// the operator's delegation, sending the tokens to the slasher
+pub(crate) fn slash(
+    slash_accounts: SanitizedAccounts,
+    slot: u64,
+    slash_amount: u64,
+) -> ProgramResult {
     let SanitizedAccounts {
+        config,
         mut vault,
         operator,
         avs_operator_ticket,
@@ -46,15 +77,26 @@ pub fn process_slash(
         vault_avs_ticket,
         vault_operator_ticket,
         avs_vault_slasher_ticket,
-        vault_avs_slasher_ticket,
+        mut vault_avs_slasher_ticket,
         mut vault_delegation_list,
         mut vault_avs_slasher_operator_ticket,
         mut vault_token_account,
         slasher_token_account,
-    } = SanitizedAccounts::sanitize(program_id, accounts, slot)?;
+        supported_mint,
+        token_program,
+        ..
+    } = slash_accounts;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
 
-    // The vault shall be opted-in to the AVS and the AVS shall be opted-in to the vault
-    vault_avs_ticket.vault_avs_ticket().check_active(slot)?;
+    // The vault shall be opted-in to the AVS and the AVS shall be opted-in to the vault. The
+    // tickets of the vault shall have warmed up, so it can't stake to an operator or AVS within
+    // an epoch and leave before the epoch ends.
+    let epoch_length = config.config().epoch_length();
+    vault_avs_ticket
+        .vault_avs_ticket()
+        .check_warmed_up(slot, epoch_length)?;
     avs_vault_ticket.avs_vault_ticket().check_active(slot)?;
 
     // The operator shall be opted-in to vault and the vault shall be staked to the operator
@@ -63,15 +105,16 @@ pub fn process_slash(
         .check_active(slot)?;
     vault_operator_ticket
         .vault_operator_ticket()
-        .check_active(slot)?;
+        .check_warmed_up(slot, epoch_length)?;
 
-    // The operator shall be opted-in to the AVS and the AVS shall be opted-in to the operator
+    // The operator shall be opted-in to the AVS and the AVS shall be opted-in to the operator. An
+    // operator removed from the AVS stays slashable until its removal is finalized.
     avs_operator_ticket
         .avs_operator_ticket()
-        .check_active(slot)?;
+        .check_slashable(slot)?;
     operator_avs_ticket
         .operator_avs_ticket()
-        .check_active(slot)?;
+        .check_slashable(slot)?;
     // The slasher shall be active for the AVS and the vault
     avs_vault_slasher_ticket
         .avs_vault_slasher_ticket()
@@ -80,14 +123,27 @@ pub fn process_slash(
         .vault_avs_slasher_ticket()
         .check_active(slot)?;
 
-    let max_slashable_per_epoch = vault_avs_slasher_ticket
-        .vault_avs_slasher_ticket()
+    // The cap approved by the AVS is authoritative, the vault ticket only mirrors it
+    let max_slashable_per_epoch = avs_vault_slasher_ticket
+        .avs_vault_slasher_ticket()
         .max_slashable_per_epoch();
     vault_avs_slasher_operator_ticket
         .vault_avs_slasher_operator_ticket()
         .check_max_slashable_not_exceeded(slash_amount, max_slashable_per_epoch)?;
+    // The cap also bounds what the slasher takes across all operators of the vault in the epoch
+    let epoch = vault_avs_slasher_operator_ticket
+        .vault_avs_slasher_operator_ticket()
+        .epoch();
+    vault_avs_slasher_ticket
+        .vault_slasher_ticket_mut()
+        .record_slash(epoch, slash_amount, max_slashable_per_epoch)?;
 
-    vault_delegation_list
+    // out-of-band token account increases carrying a reward fee shall be harvested first
+    vault
+        .vault_mut()
+        .sync_balance(vault_token_account.token_account().amount, epoch)?;
+
+    let slash_summary = vault_delegation_list
         .vault_delegation_list_mut()
         .slash(operator.account().key, slash_amount)?;
 
@@ -96,6 +152,8 @@ pub fn process_slash(
         .increment_slashed_amount(slash_amount)?;
 
     _transfer_slashed_funds(
+        &token_program,
+        &supported_mint,
         &vault,
         &vault_token_account,
         &slasher_token_account,
@@ -107,15 +165,50 @@ pub fn process_slash(
         .vault_mut()
         .set_tokens_deposited(vault_token_account.token_account().amount);
 
-    vault.save()?;
     vault_delegation_list.save()?;
-    vault_avs_slasher_operator_ticket.save()?;
+
+    msg!(
+        "VaultSlashed: vault={} avs={} operator={} slasher={} epoch={} amount={} from_deactivating={} from_active={} epoch_slashed={} tokens_deposited={}",
+        vault.account().key,
+        vault_avs_slasher_operator_ticket.vault_avs_slasher_operator_ticket().avs(),
+        operator.account().key,
+        vault_avs_slasher_operator_ticket.vault_avs_slasher_operator_ticket().slasher(),
+        vault_avs_slasher_operator_ticket.vault_avs_slasher_operator_ticket().epoch(),
+        slash_amount,
+        slash_summary.from_deactivating,
+        slash_summary.from_active,
+        vault_avs_slasher_operator_ticket.vault_avs_slasher_operator_ticket().slashed(),
+        vault.vault().tokens_deposited()
+    );
+    VaultEvent::VaultSlashed {
+        vault: *vault.account().key,
+        avs: vault_avs_slasher_operator_ticket
+            .vault_avs_slasher_operator_ticket()
+            .avs(),
+        operator: *operator.account().key,
+        slasher: vault_avs_slasher_operator_ticket
+            .vault_avs_slasher_operator_ticket()
+            .slasher(),
+        epoch: vault_avs_slasher_operator_ticket
+            .vault_avs_slasher_operator_ticket()
+            .epoch(),
+        amount: slash_amount,
+        from_deactivating: slash_summary.from_deactivating,
+        from_active: slash_summary.from_active,
+        epoch_slashed: vault_avs_slasher_operator_ticket
+            .vault_avs_slasher_operator_ticket()
+            .slashed(),
+        tokens_deposited: vault.vault().tokens_deposited(),
+    }
+    .emit()?;
 
     Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
 fn _transfer_slashed_funds<'a, 'info>(
+    token_program: &SanitizedTokenProgram<'a, 'info>,
+    supported_mint: &SanitizedTokenMint<'a, 'info>,
     vault: &SanitizedVault<'a, 'info>,
     vault_token_account: &SanitizedAssociatedTokenAccount<'a, 'info>,
     slasher_token_account: &SanitizedAssociatedTokenAccount<'a, 'info>,
@@ -129,16 +222,19 @@ fn _transfer_slashed_funds<'a, 'info>(
         .collect::<Vec<&[u8]>>();
 
     invoke_signed(
-        &transfer(
-            &spl_token::id(),
+        &transfer_checked(
+            token_program.account().key,
             vault_token_account.account().key,
+            supported_mint.account().key,
             slasher_token_account.account().key,
             vault.account().key,
             &[],
             amount,
+            supported_mint.mint().decimals,
         )?,
         &[
             vault_token_account.account().clone(),
+            supported_mint.account().clone(),
             slasher_token_account.account().clone(),
             vault.account().clone(),
         ],
@@ -148,14 +244,17 @@ fn _transfer_slashed_funds<'a, 'info>(
     Ok(())
 }
 
-struct SanitizedAccounts<'a, 'info> {
-    vault: SanitizedVault<'a, 'info>,
-    operator: SanitizedOperator<'a, 'info>,
+pub(crate) struct SanitizedAccounts<'a, 'info> {
+    pub(crate) config: SanitizedConfig<'a, 'info>,
+    pub(crate) vault: SanitizedVault<'a, 'info>,
+    pub(crate) avs: SanitizedAvs<'a, 'info>,
+    pub(crate) operator: SanitizedOperator<'a, 'info>,
+    pub(crate) slasher: &'a AccountInfo<'info>,
     avs_operator_ticket: SanitizedAvsOperatorTicket<'a, 'info>,
     operator_avs_ticket: SanitizedOperatorAvsTicket<'a, 'info>,
     avs_vault_ticket: SanitizedAvsVaultTicket<'a, 'info>,
     operator_vault_ticket: SanitizedOperatorVaultTicket<'a, 'info>,
-    vault_avs_ticket: SanitizedVaultAvsTicket<'a, 'info>,
+    pub(crate) vault_avs_ticket: SanitizedVaultAvsTicket<'a, 'info>,
     vault_operator_ticket: SanitizedVaultOperatorTicket<'a, 'info>,
     avs_vault_slasher_ticket: SanitizedAvsVaultSlasherTicket<'a, 'info>,
     vault_avs_slasher_ticket: SanitizedVaultAvsSlasherTicket<'a, 'info>,
@@ -163,14 +262,18 @@ struct SanitizedAccounts<'a, 'info> {
     vault_avs_slasher_operator_ticket: SanitizedVaultAvsSlasherOperatorTicket<'a, 'info>,
     vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
     slasher_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    supported_mint: SanitizedTokenMint<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
-    /// Sanitizes the accounts for the slash instruction: [`crate::VaultInstruction::Slash`]
-    fn sanitize(
+    /// Sanitizes the accounts for the slash instruction: [`crate::VaultInstruction::Slash`].
+    /// The slasher only signs slashes it carries out itself, not proposed ones.
+    pub(crate) fn sanitize(
         program_id: &Pubkey,
         accounts: &'a [AccountInfo<'info>],
         slot: u64,
+        expect_slasher_signer: bool,
     ) -> Result<Self, ProgramError> {
         let mut accounts_iter = accounts.iter();
 
@@ -188,8 +291,10 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             next_account_info(&mut accounts_iter)?,
             false,
         )?;
-        let slasher =
-            SanitizedSignerAccount::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        let slasher = next_account_info(&mut accounts_iter)?;
+        if expect_slasher_signer {
+            SanitizedSignerAccount::sanitize(slasher, false)?;
+        }
         let avs_operator_ticket = SanitizedAvsOperatorTicket::sanitize(
             &config.config().restaking_program(),
             next_account_info(&mut accounts_iter)?,
@@ -218,10 +323,11 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             operator.account().key,
             vault.account().key,
         )?;
+        // an executed proposal is uncounted from the pending proposals on the ticket
         let vault_avs_ticket = SanitizedVaultAvsTicket::sanitize(
             program_id,
             next_account_info(&mut accounts_iter)?,
-            false,
+            !expect_slasher_signer,
             vault.account().key,
             avs.account().key,
         )?;
@@ -238,15 +344,15 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             false,
             avs.account().key,
             vault.account().key,
-            slasher.account().key,
+            slasher.key,
         )?;
         let vault_avs_slasher_ticket = SanitizedVaultAvsSlasherTicket::sanitize(
             program_id,
             next_account_info(&mut accounts_iter)?,
-            false,
+            true,
             vault.account().key,
             avs.account().key,
-            slasher.account().key,
+            slasher.key,
         )?;
         let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
             program_id,
@@ -262,7 +368,7 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             true,
             vault.account().key,
             avs.account().key,
-            slasher.account().key,
+            slasher.key,
             operator.account().key,
             epoch,
         )?;
@@ -275,13 +381,23 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
         let slasher_token_account = SanitizedAssociatedTokenAccount::sanitize(
             next_account_info(&mut accounts_iter)?,
             &vault.vault().supported_mint(),
-            slasher.account().key,
+            slasher.key,
+        )?;
+        let supported_mint =
+            SanitizedTokenMint::sanitize(next_account_info(&mut accounts_iter)?, false)?;
+        assert_with_msg(
+            *supported_mint.account().key == vault.vault().supported_mint(),
+            VaultError::SupportedMintMismatch,
+            "Mint account does not match the vault supported mint",
         )?;
-        let _token_program =
+        let token_program =
             SanitizedTokenProgram::sanitize(next_account_info(&mut accounts_iter)?)?;
         Ok(Self {
+            config,
             vault,
+            avs,
             operator,
+            slasher,
             avs_operator_ticket,
             operator_avs_ticket,
             avs_vault_ticket,
@@ -294,6 +410,8 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
             vault_avs_slasher_operator_ticket,
             vault_token_account,
             slasher_token_account,
+            supported_mint,
+            token_program,
         })
     }
 }
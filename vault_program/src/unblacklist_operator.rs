@@ -0,0 +1,81 @@
+use jito_restaking_sanitization::signer::SanitizedSignerAccount;
+use jito_vault_core::{
+    vault::SanitizedVault, vault_operator_blacklist::SanitizedVaultOperatorBlacklist,
+};
+use jito_vault_sdk::event::{Event, VaultEvent};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Removes an operator from the vault's blacklist, so the vault can delegate to it again. Only the
+/// delegation admin can unblacklist operators.
+///
+/// Instruction: [`crate::VaultInstruction::UnblacklistOperator`]
+pub fn process_unblacklist_operator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        vault,
+        mut vault_operator_blacklist,
+        operator,
+        delegation_admin,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    vault
+        .vault()
+        .check_delegation_admin(delegation_admin.account().key)?;
+
+    vault_operator_blacklist
+        .vault_operator_blacklist_mut()
+        .remove(operator.key)?;
+
+    msg!("Unblacklisted operator: {:?}", operator.key);
+
+    VaultEvent::OperatorUnblacklisted {
+        vault: *vault.account().key,
+        operator: *operator.key,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    vault: SanitizedVault<'a, 'info>,
+    vault_operator_blacklist: SanitizedVaultOperatorBlacklist<'a, 'info>,
+    operator: &'a AccountInfo<'info>,
+    delegation_admin: SanitizedSignerAccount<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::UnblacklistOperator`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault_operator_blacklist = SanitizedVaultOperatorBlacklist::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            vault.account().key,
+        )?;
+        let operator = next_account_info(accounts_iter)?;
+        let delegation_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+
+        Ok(SanitizedAccounts {
+            vault,
+            vault_operator_blacklist,
+            operator,
+            delegation_admin,
+        })
+    }
+}
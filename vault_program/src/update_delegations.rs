@@ -1,31 +1,87 @@
+use jito_restaking_sanitization::{pay_excess_lamports, signer::SanitizedSignerAccount};
 use jito_vault_core::{
     config::SanitizedConfig, vault::SanitizedVault,
     vault_delegation_list::SanitizedVaultDelegationList,
 };
+use jito_vault_sdk::event::{Event, VaultEvent};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
 };
 
+/// Anyone can bring the delegations of the vault up to date, moving stake undelegated in past
+/// epochs through its cooldown so the vault admin doesn't need to come back online for it.
+///
+/// The first update in a new epoch pays the payer the crank tip set by the vault admin, out of the
+/// lamports the vault holds above its rent-exempt minimum, see
+/// [`jito_vault_core::vault::Vault::crank_tip_lamports`].
+///
+/// [`crate::VaultInstruction::UpdateDelegations`]
 pub fn process_update_delegations(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let SanitizedAccounts {
         config,
+        vault,
         mut vault_delegation_list,
+        payer,
     } = SanitizedAccounts::sanitize(program_id, accounts)?;
 
     let slot = Clock::get()?.slot;
-    vault_delegation_list
-        .vault_delegation_list_mut()
-        .update_delegations(slot, config.config().epoch_length());
+    let epoch_length = config.config().epoch_length();
+    let new_epoch = vault_delegation_list
+        .vault_delegation_list()
+        .needs_update(slot, epoch_length);
+    vault_delegation_list.vault_delegation_list_mut().update(
+        slot,
+        epoch_length,
+        vault.vault().emergency_mode(),
+    );
 
     vault_delegation_list.save()?;
 
+    VaultEvent::DelegationsUpdated {
+        vault: *vault.account().key,
+        slot,
+    }
+    .emit()?;
+
+    let crank_tip_lamports = vault.vault().crank_tip_lamports();
+    if new_epoch && crank_tip_lamports > 0 {
+        let lamports = pay_excess_lamports(
+            vault.account(),
+            payer.account(),
+            &Rent::get()?,
+            crank_tip_lamports,
+        )?;
+        if lamports > 0 {
+            msg!(
+                "CrankTipPaid: vault={} cranker={} lamports={}",
+                vault.account().key,
+                payer.account().key,
+                lamports
+            );
+            VaultEvent::CrankTipPaid {
+                vault: *vault.account().key,
+                cranker: *payer.account().key,
+                lamports,
+            }
+            .emit()?;
+        }
+    }
+
     Ok(())
 }
 
 struct SanitizedAccounts<'a, 'info> {
     config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
     vault_delegation_list: SanitizedVaultDelegationList<'a, 'info>,
+    payer: SanitizedSignerAccount<'a, 'info>,
 }
 
 impl<'a, 'info> SanitizedAccounts<'a, 'info> {
@@ -35,18 +91,23 @@ impl<'a, 'info> SanitizedAccounts<'a, 'info> {
     ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
         let accounts_iter = &mut accounts.iter();
 
-        let config = SanitizedConfig::sanitize(program_id, accounts_iter.next().unwrap(), false)?;
-        let vault = SanitizedVault::sanitize(program_id, accounts_iter.next().unwrap(), false)?;
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
 
         let vault_delegation_list = SanitizedVaultDelegationList::sanitize(
             program_id,
-            accounts_iter.next().unwrap(),
+            next_account_info(accounts_iter)?,
             true,
             vault.account().key,
         )?;
+        let payer = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+
         Ok(SanitizedAccounts {
             config,
+            vault,
             vault_delegation_list,
+            payer,
         })
     }
 }
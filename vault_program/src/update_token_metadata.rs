@@ -1,11 +1,12 @@
+use jito_jsm_core::bounded_string::{BoundedName, BoundedSymbol, BoundedUri};
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 pub fn process_update_token_metadata(
     _program_id: &Pubkey,
     _accounts: &[AccountInfo],
-    _name: String,
-    _symbol: String,
-    _uri: String,
+    _name: BoundedName,
+    _symbol: BoundedSymbol,
+    _uri: BoundedUri,
 ) -> ProgramResult {
     Ok(())
 }
@@ -0,0 +1,170 @@
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount,
+    token_mint::SanitizedTokenMint, token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig,
+    vault::{BalanceUpdate, SanitizedVault},
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::mint_to::_mint_lrt;
+
+/// Anyone can harvest the tokens the vault holds in excess of the tokens deposited, e.g. airdropped
+/// or donated to it, as rewards of the LRT holders.
+///
+/// # Behavior
+/// * The reward fee in effect at the current epoch is minted to the fee owner in LRT.
+/// * Withdrawals and slashes fail while rewards carrying a reward fee are pending, so the crank
+///   shall run before them in an epoch. Deposits harvest the rewards themselves.
+/// * AVS service fees are settled before the rewards are harvested: the surplus covering the
+///   service fees owed by the vault isn't harvested, so cranking ahead of
+///   [`crate::VaultInstruction::PayAvsServiceFee`] never erases a fee. Fees accrued since they
+///   were last paid aren't owed until paid, so the service fees shall be paid first in an epoch
+///   to be settled out of the rewards being harvested; otherwise they're paid out of later rewards.
+///
+/// [`crate::VaultInstruction::UpdateVaultBalance`]
+pub fn process_update_vault_balance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        vault_token_account,
+        lrt_mint,
+        vault_fee_token_account,
+        token_program,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
+    assert_with_msg(
+        *lrt_mint.account().key == vault.vault().lrt_mint(),
+        VaultError::LrtMintMismatch,
+        "Mint account does not match LRT mint",
+    )?;
+
+    let epoch = Clock::get()?
+        .slot
+        .checked_div(config.config().epoch_length())
+        .unwrap();
+    vault.vault_mut().apply_pending_fees(epoch);
+    let balance_update = vault
+        .vault_mut()
+        .update_balance(vault_token_account.token_account().amount, epoch)?;
+
+    _mint_reward_fee(
+        program_id,
+        &token_program,
+        &vault,
+        &lrt_mint,
+        &vault_fee_token_account,
+        epoch,
+        balance_update,
+    )?;
+
+    Ok(())
+}
+
+/// Mints the reward fee of the rewards harvested by [`jito_vault_core::vault::Vault::update_balance`]
+/// to the fee owner
+pub fn _mint_reward_fee<'a, 'info>(
+    program_id: &Pubkey,
+    token_program: &SanitizedTokenProgram,
+    vault: &SanitizedVault<'a, 'info>,
+    lrt_mint: &SanitizedTokenMint<'a, 'info>,
+    vault_fee_token_account: &SanitizedAssociatedTokenAccount<'a, 'info>,
+    epoch: u64,
+    balance_update: BalanceUpdate,
+) -> ProgramResult {
+    if balance_update.rewards == 0 {
+        return Ok(());
+    }
+    if balance_update.lrt_to_reward_fee > 0 {
+        _mint_lrt(
+            program_id,
+            token_program,
+            vault,
+            lrt_mint,
+            vault_fee_token_account,
+            balance_update.lrt_to_reward_fee,
+        )?;
+    }
+
+    msg!(
+        "VaultBalanceUpdated: vault={} epoch={} rewards={} lrt_to_reward_fee={} tokens_deposited={} lrt_supply={}",
+        vault.account().key,
+        epoch,
+        balance_update.rewards,
+        balance_update.lrt_to_reward_fee,
+        vault.vault().tokens_deposited(),
+        vault.vault().lrt_supply()
+    );
+    VaultEvent::VaultBalanceUpdated {
+        vault: *vault.account().key,
+        epoch,
+        rewards: balance_update.rewards,
+        lrt_to_reward_fee: balance_update.lrt_to_reward_fee,
+        tokens_deposited: vault.vault().tokens_deposited(),
+        lrt_supply: vault.vault().lrt_supply(),
+    }
+    .emit()
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    lrt_mint: SanitizedTokenMint<'a, 'info>,
+    vault_fee_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::UpdateVaultBalance`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let vault_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().supported_mint(),
+            vault.account().key,
+        )?;
+        let lrt_mint = SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, true)?;
+        let vault_fee_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().lrt_mint(),
+            &vault.vault().fee_owner(),
+        )?;
+        let token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            vault_token_account,
+            lrt_mint,
+            vault_fee_token_account,
+            token_program,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use jito_restaking_core::avs::SanitizedAvs;
+use jito_restaking_sanitization::{assert_with_msg, signer::SanitizedSignerAccount};
+use jito_vault_core::{
+    config::SanitizedConfig, slash_proposal::SanitizedSlashProposal,
+    vault_avs_ticket::SanitizedVaultAvsTicket,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The slash veto admin of the AVS vetoes a pending slash proposal before its veto window
+/// closes. The proposal is kept, marked vetoed, so it can't be executed or filed again, and is no
+/// longer counted as pending on the vault AVS ticket.
+///
+/// Instruction: [`crate::VaultInstruction::VetoSlash`]
+pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        avs,
+        mut slash_proposal,
+        slash_veto_admin,
+        mut vault_avs_ticket,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    assert_with_msg(
+        slash_proposal.slash_proposal().avs() == *avs.account().key,
+        VaultError::SlashProposalMismatch,
+        "Slash proposal is not for the AVS",
+    )?;
+    avs.avs()
+        .check_slash_veto_admin(slash_veto_admin.account().key)?;
+
+    let epoch = Clock::get()?
+        .slot
+        .checked_div(config.config().epoch_length())
+        .unwrap();
+    slash_proposal.slash_proposal_mut().veto(epoch)?;
+    vault_avs_ticket
+        .vault_avs_ticket_mut()
+        .decrement_pending_slash_proposals();
+
+    msg!(
+        "SlashVetoed: slash_proposal={} slash_veto_admin={} epoch={}",
+        slash_proposal.account().key,
+        slash_veto_admin.account().key,
+        epoch
+    );
+    VaultEvent::SlashVetoed {
+        slash_proposal: *slash_proposal.account().key,
+        slash_veto_admin: *slash_veto_admin.account().key,
+        epoch,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    avs: SanitizedAvs<'a, 'info>,
+    slash_proposal: SanitizedSlashProposal<'a, 'info>,
+    slash_veto_admin: SanitizedSignerAccount<'a, 'info>,
+    vault_avs_ticket: SanitizedVaultAvsTicket<'a, 'info>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Sanitizes the accounts for the instruction: [`crate::VaultInstruction::VetoSlash`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let avs = SanitizedAvs::sanitize(
+            &config.config().restaking_program(),
+            next_account_info(accounts_iter)?,
+            false,
+        )?;
+        let slash_proposal =
+            SanitizedSlashProposal::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let slash_veto_admin =
+            SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, false)?;
+        let vault = slash_proposal.slash_proposal().vault();
+        let vault_avs_ticket = SanitizedVaultAvsTicket::sanitize(
+            program_id,
+            next_account_info(accounts_iter)?,
+            true,
+            &vault,
+            avs.account().key,
+        )?;
+
+        Ok(SanitizedAccounts {
+            config,
+            avs,
+            slash_proposal,
+            slash_veto_admin,
+            vault_avs_ticket,
+        })
+    }
+}
@@ -0,0 +1,305 @@
+use jito_restaking_sanitization::{
+    assert_with_msg, associated_token_account::SanitizedAssociatedTokenAccount,
+    signer::SanitizedSignerAccount, system_program::SanitizedSystemProgram,
+    token_mint::SanitizedTokenMint, token_program::SanitizedTokenProgram,
+};
+use jito_vault_core::{
+    config::SanitizedConfig,
+    result::VaultCoreError,
+    vault::{MintSummary, SanitizedVault},
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    event::{Event, VaultEvent},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::{
+    mint_to::{
+        _mint_lrt, _mint_program_fee, _sanitize_program_fee_token_account, _transfer_to_vault,
+    },
+    update_vault_balance::_mint_reward_fee,
+};
+
+/// Deposits the supported token into the vault and mints the LRT to a recipient in one
+/// instruction, so frontends don't need to set up the recipient's LRT account or guard against
+/// exchange rate changes themselves.
+///
+/// # Behavior
+/// * The recipient's LRT associated token account is created, paid for by the depositor, if it
+///   doesn't exist yet.
+/// * The recipient may be the depositor, or the owner of a position in another program so the
+///   LRT lands staked.
+/// * The instruction fails if the recipient would receive less than `min_lrt_out` after the
+///   program and deposit fees.
+///
+/// Instruction: [`crate::VaultInstruction::ZapDeposit`]
+pub fn process_zap_deposit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    min_lrt_out: u64,
+) -> ProgramResult {
+    let SanitizedAccounts {
+        config,
+        mut vault,
+        lrt_mint,
+        depositor,
+        depositor_token_account,
+        vault_token_account,
+        recipient,
+        recipient_lrt_token_account,
+        vault_fee_token_account,
+        program_fee_token_account,
+        supported_mint,
+        token_program,
+        associated_token_program,
+        system_program,
+        mint_signer,
+    } = SanitizedAccounts::sanitize(program_id, accounts)?;
+
+    config.config().check_not_paused()?;
+    vault.vault().check_not_paused()?;
+
+    assert_with_msg(
+        *lrt_mint.account().key == vault.vault().lrt_mint(),
+        VaultError::LrtMintMismatch,
+        "Mint account does not match LRT mint",
+    )?;
+    if let Some(mint_signer) = mint_signer {
+        assert_with_msg(
+            *mint_signer.account().key == vault.vault().mint_burn_authority().unwrap(),
+            VaultError::MintBurnSignerMismatch,
+            "Mint signer does not match vault mint signer",
+        )?;
+    }
+
+    if recipient_lrt_token_account.data_is_empty() {
+        invoke(
+            &create_associated_token_account_idempotent(
+                depositor.account().key,
+                recipient.key,
+                lrt_mint.account().key,
+                token_program.account().key,
+            ),
+            &[
+                depositor.account().clone(),
+                recipient_lrt_token_account.clone(),
+                recipient.clone(),
+                lrt_mint.account().clone(),
+                system_program.account().clone(),
+                token_program.account().clone(),
+                associated_token_program.clone(),
+            ],
+        )?;
+    }
+    let recipient_lrt_token_account = SanitizedAssociatedTokenAccount::sanitize(
+        recipient_lrt_token_account,
+        &vault.vault().lrt_mint(),
+        recipient.key,
+    )?;
+
+    // the vault only receives what's left after the Token-2022 transfer fee
+    let amount_received = amount
+        .checked_sub(supported_mint.transfer_fee(Clock::get()?.epoch, amount)?)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    _transfer_to_vault(
+        &token_program,
+        &supported_mint,
+        &depositor_token_account,
+        &vault_token_account,
+        &depositor,
+        amount,
+    )?;
+
+    // fees scheduled by the fee admin apply from their epoch
+    let epoch = Clock::get()?
+        .slot
+        .checked_div(config.config().epoch_length())
+        .unwrap();
+    vault.vault_mut().apply_pending_fees(epoch);
+
+    // the vault token account was read before the transfer, so out-of-band deposits are harvested
+    let MintSummary {
+        lrt_to_depositor: lrt_to_recipient,
+        lrt_to_fee,
+        lrt_to_program_fee,
+        balance_update,
+    } = vault.vault_mut().mint_with_fee(
+        vault_token_account.token_account().amount,
+        amount_received,
+        config.config().program_fee_bps(),
+        epoch,
+    )?;
+    if lrt_to_recipient < min_lrt_out {
+        msg!(
+            "Deposit would mint {} LRT, less than the minimum of {}",
+            lrt_to_recipient,
+            min_lrt_out
+        );
+        return Err(VaultCoreError::VaultDepositSlippageExceeded.into());
+    }
+
+    _mint_lrt(
+        program_id,
+        &token_program,
+        &vault,
+        &lrt_mint,
+        &recipient_lrt_token_account,
+        lrt_to_recipient,
+    )?;
+    _mint_lrt(
+        program_id,
+        &token_program,
+        &vault,
+        &lrt_mint,
+        &vault_fee_token_account,
+        lrt_to_fee,
+    )?;
+    _mint_program_fee(
+        program_id,
+        &token_program,
+        &config,
+        &vault,
+        &lrt_mint,
+        program_fee_token_account.as_ref(),
+        lrt_to_program_fee,
+    )?;
+    _mint_reward_fee(
+        program_id,
+        &token_program,
+        &vault,
+        &lrt_mint,
+        &vault_fee_token_account,
+        epoch,
+        balance_update,
+    )?;
+
+    msg!(
+        "ZapDeposit: vault={} depositor={} recipient={} amount={} lrt_minted={}",
+        vault.account().key,
+        depositor.account().key,
+        recipient.key,
+        amount_received,
+        lrt_to_recipient
+    );
+    VaultEvent::Deposited {
+        vault: *vault.account().key,
+        depositor: *depositor.account().key,
+        recipient: *recipient.key,
+        amount: amount_received,
+        lrt_minted: lrt_to_recipient,
+        lrt_fee: lrt_to_fee,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+struct SanitizedAccounts<'a, 'info> {
+    config: SanitizedConfig<'a, 'info>,
+    vault: SanitizedVault<'a, 'info>,
+    lrt_mint: SanitizedTokenMint<'a, 'info>,
+    depositor: SanitizedSignerAccount<'a, 'info>,
+    depositor_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    vault_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    recipient: &'a AccountInfo<'info>,
+    recipient_lrt_token_account: &'a AccountInfo<'info>,
+    vault_fee_token_account: SanitizedAssociatedTokenAccount<'a, 'info>,
+    program_fee_token_account: Option<SanitizedAssociatedTokenAccount<'a, 'info>>,
+    supported_mint: SanitizedTokenMint<'a, 'info>,
+    token_program: SanitizedTokenProgram<'a, 'info>,
+    associated_token_program: &'a AccountInfo<'info>,
+    system_program: SanitizedSystemProgram<'a, 'info>,
+    mint_signer: Option<SanitizedSignerAccount<'a, 'info>>,
+}
+
+impl<'a, 'info> SanitizedAccounts<'a, 'info> {
+    /// Loads accounts for [`crate::VaultInstruction::ZapDeposit`]
+    fn sanitize(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<SanitizedAccounts<'a, 'info>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+
+        let config =
+            SanitizedConfig::sanitize(program_id, next_account_info(accounts_iter)?, false)?;
+        let vault = SanitizedVault::sanitize(program_id, next_account_info(accounts_iter)?, true)?;
+        let lrt_mint = SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, true)?;
+        let depositor = SanitizedSignerAccount::sanitize(next_account_info(accounts_iter)?, true)?;
+        let depositor_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().supported_mint(),
+            depositor.account().key,
+        )?;
+        let vault_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().supported_mint(),
+            vault.account().key,
+        )?;
+        let recipient = next_account_info(accounts_iter)?;
+        // may not exist yet, sanitized once created in the processor
+        let recipient_lrt_token_account = next_account_info(accounts_iter)?;
+        let vault_fee_token_account = SanitizedAssociatedTokenAccount::sanitize(
+            next_account_info(accounts_iter)?,
+            &vault.vault().lrt_mint(),
+            &vault.vault().fee_owner(),
+        )?;
+        let program_fee_token_account = _sanitize_program_fee_token_account(
+            &config,
+            &vault,
+            next_account_info(accounts_iter)?,
+        )?;
+        let supported_mint =
+            SanitizedTokenMint::sanitize(next_account_info(accounts_iter)?, false)?;
+        assert_with_msg(
+            *supported_mint.account().key == vault.vault().supported_mint(),
+            VaultError::SupportedMintMismatch,
+            "Mint account does not match the vault supported mint",
+        )?;
+        let token_program = SanitizedTokenProgram::sanitize(next_account_info(accounts_iter)?)?;
+        let associated_token_program = next_account_info(accounts_iter)?;
+        assert_with_msg(
+            *associated_token_program.key == spl_associated_token_account::id(),
+            VaultError::AssociatedTokenProgramInvalidAddress,
+            "Associated token program address is invalid",
+        )?;
+        let system_program = SanitizedSystemProgram::sanitize(next_account_info(accounts_iter)?)?;
+        let mint_signer = if vault.vault().mint_burn_authority().is_some() {
+            Some(SanitizedSignerAccount::sanitize(
+                next_account_info(accounts_iter)?,
+                false,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(SanitizedAccounts {
+            config,
+            vault,
+            lrt_mint,
+            depositor,
+            depositor_token_account,
+            vault_token_account,
+            recipient,
+            recipient_lrt_token_account,
+            vault_fee_token_account,
+            program_fee_token_account,
+            supported_mint,
+            token_program,
+            associated_token_program,
+            system_program,
+            mint_signer,
+        })
+    }
+}
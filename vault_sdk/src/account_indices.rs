@@ -0,0 +1,428 @@
+//! Account positions for each [`crate::VaultInstruction`] variant.
+//!
+//! These match the `#[account]` attributes on the enum and the order used by the instruction
+//! builders. Transaction inspection tools and external signers can use them to find an account
+//! by role.
+
+/// [`crate::VaultInstruction::InitializeConfig`]
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_CONFIG: usize = 0;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_ADMIN: usize = 1;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_RESTAKING_PROGRAM: usize = 2;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_PAYER: usize = 3;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 4;
+pub const INITIALIZE_CONFIG_IX_ACCOUNT_PROGRAM_DATA: usize = 5;
+
+/// [`crate::VaultInstruction::InitializeVault`]
+pub const INITIALIZE_VAULT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_VAULT: usize = 1;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 2;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_LRT_MINT: usize = 3;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_TOKEN_MINT: usize = 4;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_ADMIN: usize = 5;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_BASE: usize = 6;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_PAYER: usize = 7;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 8;
+pub const INITIALIZE_VAULT_IX_ACCOUNT_TOKEN_PROGRAM: usize = 9;
+
+/// [`crate::VaultInstruction::AddAvs`]
+pub const ADD_AVS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ADD_AVS_IX_ACCOUNT_VAULT: usize = 1;
+pub const ADD_AVS_IX_ACCOUNT_AVS: usize = 2;
+pub const ADD_AVS_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 3;
+pub const ADD_AVS_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 4;
+pub const ADD_AVS_IX_ACCOUNT_ADMIN: usize = 5;
+pub const ADD_AVS_IX_ACCOUNT_PAYER: usize = 6;
+pub const ADD_AVS_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::VaultInstruction::RemoveAvs`]
+pub const REMOVE_AVS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const REMOVE_AVS_IX_ACCOUNT_VAULT: usize = 1;
+pub const REMOVE_AVS_IX_ACCOUNT_AVS: usize = 2;
+pub const REMOVE_AVS_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 3;
+pub const REMOVE_AVS_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::VaultInstruction::AddOperator`]
+pub const ADD_OPERATOR_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ADD_OPERATOR_IX_ACCOUNT_VAULT: usize = 1;
+pub const ADD_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const ADD_OPERATOR_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 3;
+pub const ADD_OPERATOR_IX_ACCOUNT_VAULT_OPERATOR_TICKET: usize = 4;
+pub const ADD_OPERATOR_IX_ACCOUNT_ADMIN: usize = 5;
+pub const ADD_OPERATOR_IX_ACCOUNT_PAYER: usize = 6;
+pub const ADD_OPERATOR_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 7;
+
+/// [`crate::VaultInstruction::RemoveOperator`]
+pub const REMOVE_OPERATOR_IX_ACCOUNT_CONFIG: usize = 0;
+pub const REMOVE_OPERATOR_IX_ACCOUNT_VAULT: usize = 1;
+pub const REMOVE_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const REMOVE_OPERATOR_IX_ACCOUNT_VAULT_OPERATOR_TICKET: usize = 3;
+pub const REMOVE_OPERATOR_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::VaultInstruction::MintTo`]
+pub const MINT_TO_IX_ACCOUNT_CONFIG: usize = 0;
+pub const MINT_TO_IX_ACCOUNT_VAULT: usize = 1;
+pub const MINT_TO_IX_ACCOUNT_LRT_MINT: usize = 2;
+pub const MINT_TO_IX_ACCOUNT_DEPOSITOR: usize = 3;
+pub const MINT_TO_IX_ACCOUNT_DEPOSITOR_TOKEN_ACCOUNT: usize = 4;
+pub const MINT_TO_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 5;
+pub const MINT_TO_IX_ACCOUNT_DEPOSITOR_LRT_TOKEN_ACCOUNT: usize = 6;
+pub const MINT_TO_IX_ACCOUNT_VAULT_FEE_TOKEN_ACCOUNT: usize = 7;
+pub const MINT_TO_IX_ACCOUNT_PROGRAM_FEE_TOKEN_ACCOUNT: usize = 8;
+pub const MINT_TO_IX_ACCOUNT_SUPPORTED_MINT: usize = 9;
+pub const MINT_TO_IX_ACCOUNT_TOKEN_PROGRAM: usize = 10;
+pub const MINT_TO_IX_ACCOUNT_MINT_SIGNER: usize = 11;
+pub const MINT_TO_IX_ACCOUNT_VAULT_REFERRER: usize = 12;
+
+/// [`crate::VaultInstruction::EnqueueWithdrawal`]
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_VAULT: usize = 1;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_VAULT_STAKER_WITHDRAWAL_TICKET: usize = 2;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_VAULT_STAKER_WITHDRAWAL_TICKET_TOKEN_ACCOUNT: usize = 3;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_LRT_MINT: usize = 4;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_STAKER: usize = 5;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_STAKER_LRT_TOKEN_ACCOUNT: usize = 6;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_BASE: usize = 7;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_TOKEN_PROGRAM: usize = 8;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_ASSOCIATED_TOKEN_PROGRAM: usize = 9;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 10;
+pub const ENQUEUE_WITHDRAWAL_IX_ACCOUNT_BURN_SIGNER: usize = 11;
+
+/// [`crate::VaultInstruction::SetDepositCapacity`]
+pub const SET_DEPOSIT_CAPACITY_IX_ACCOUNT_VAULT: usize = 0;
+pub const SET_DEPOSIT_CAPACITY_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::SetAdmin`]
+pub const SET_ADMIN_IX_ACCOUNT_VAULT: usize = 0;
+pub const SET_ADMIN_IX_ACCOUNT_OLD_ADMIN: usize = 1;
+pub const SET_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::SetSecondaryAdmin`]
+pub const SET_SECONDARY_ADMIN_IX_ACCOUNT_VAULT: usize = 0;
+pub const SET_SECONDARY_ADMIN_IX_ACCOUNT_ADMIN: usize = 1;
+pub const SET_SECONDARY_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::AddDelegation`]
+pub const ADD_DELEGATION_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ADD_DELEGATION_IX_ACCOUNT_VAULT: usize = 1;
+pub const ADD_DELEGATION_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const ADD_DELEGATION_IX_ACCOUNT_VAULT_OPERATOR_TICKET: usize = 3;
+pub const ADD_DELEGATION_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 4;
+pub const ADD_DELEGATION_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 5;
+pub const ADD_DELEGATION_IX_ACCOUNT_ADMIN: usize = 6;
+pub const ADD_DELEGATION_IX_ACCOUNT_PAYER: usize = 7;
+pub const ADD_DELEGATION_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 8;
+pub const ADD_DELEGATION_IX_ACCOUNT_VAULT_OPERATOR_BLACKLIST: usize = 9;
+
+/// [`crate::VaultInstruction::RemoveDelegation`]
+pub const REMOVE_DELEGATION_IX_ACCOUNT_CONFIG: usize = 0;
+pub const REMOVE_DELEGATION_IX_ACCOUNT_VAULT: usize = 1;
+pub const REMOVE_DELEGATION_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const REMOVE_DELEGATION_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 3;
+pub const REMOVE_DELEGATION_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::VaultInstruction::UpdateDelegations`]
+pub const UPDATE_DELEGATIONS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const UPDATE_DELEGATIONS_IX_ACCOUNT_VAULT: usize = 1;
+pub const UPDATE_DELEGATIONS_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 2;
+pub const UPDATE_DELEGATIONS_IX_ACCOUNT_PAYER: usize = 3;
+
+/// [`crate::VaultInstruction::AddSlasher`]
+pub const ADD_SLASHER_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ADD_SLASHER_IX_ACCOUNT_VAULT: usize = 1;
+pub const ADD_SLASHER_IX_ACCOUNT_AVS: usize = 2;
+pub const ADD_SLASHER_IX_ACCOUNT_SLASHER: usize = 3;
+pub const ADD_SLASHER_IX_ACCOUNT_AVS_SLASHER_TICKET: usize = 4;
+pub const ADD_SLASHER_IX_ACCOUNT_VAULT_SLASHER_TICKET: usize = 5;
+pub const ADD_SLASHER_IX_ACCOUNT_ADMIN: usize = 6;
+pub const ADD_SLASHER_IX_ACCOUNT_PAYER: usize = 7;
+pub const ADD_SLASHER_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 8;
+
+/// [`crate::VaultInstruction::InitializeVaultAvsSlasherOperatorTicket`]
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_CONFIG: usize = 0;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_VAULT: usize = 1;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_AVS: usize = 2;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_SLASHER: usize = 3;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_OPERATOR: usize = 4;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_VAULT_AVS_SLASHER_TICKET: usize =
+    5;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_VAULT_AVS_SLASHER_OPERATOR_TICKET: usize = 6;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_PAYER: usize = 7;
+pub const INITIALIZE_VAULT_AVS_SLASHER_OPERATOR_TICKET_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 8;
+
+/// [`crate::VaultInstruction::Slash`]
+pub const SLASH_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SLASH_IX_ACCOUNT_VAULT: usize = 1;
+pub const SLASH_IX_ACCOUNT_AVS: usize = 2;
+pub const SLASH_IX_ACCOUNT_OPERATOR: usize = 3;
+pub const SLASH_IX_ACCOUNT_SLASHER: usize = 4;
+pub const SLASH_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 5;
+pub const SLASH_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 6;
+pub const SLASH_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 7;
+pub const SLASH_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 8;
+pub const SLASH_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 9;
+pub const SLASH_IX_ACCOUNT_VAULT_OPERATOR_TICKET: usize = 10;
+pub const SLASH_IX_ACCOUNT_AVS_VAULT_SLASHER_TICKET: usize = 11;
+pub const SLASH_IX_ACCOUNT_VAULT_AVS_SLASHER_TICKET: usize = 12;
+pub const SLASH_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 13;
+pub const SLASH_IX_ACCOUNT_VAULT_AVS_SLASHER_OPERATOR_TICKET: usize = 14;
+pub const SLASH_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 15;
+pub const SLASH_IX_ACCOUNT_SLASHER_TOKEN_ACCOUNT: usize = 16;
+pub const SLASH_IX_ACCOUNT_SUPPORTED_MINT: usize = 17;
+pub const SLASH_IX_ACCOUNT_TOKEN_PROGRAM: usize = 18;
+
+/// [`crate::VaultInstruction::SetEmergencyMode`]
+pub const SET_EMERGENCY_MODE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_EMERGENCY_MODE_IX_ACCOUNT_VAULT: usize = 1;
+pub const SET_EMERGENCY_MODE_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::SetAvsServiceFee`]
+pub const SET_AVS_SERVICE_FEE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_AVS_SERVICE_FEE_IX_ACCOUNT_VAULT: usize = 1;
+pub const SET_AVS_SERVICE_FEE_IX_ACCOUNT_AVS: usize = 2;
+pub const SET_AVS_SERVICE_FEE_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 3;
+pub const SET_AVS_SERVICE_FEE_IX_ACCOUNT_ADMIN: usize = 4;
+
+/// [`crate::VaultInstruction::PayAvsServiceFee`]
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_VAULT: usize = 1;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_AVS: usize = 2;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 3;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 4;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 5;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 6;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_AVS_TOKEN_ACCOUNT: usize = 7;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_SUPPORTED_MINT: usize = 8;
+pub const PAY_AVS_SERVICE_FEE_IX_ACCOUNT_TOKEN_PROGRAM: usize = 9;
+
+/// [`crate::VaultInstruction::ProposeMintAuthorityHandoff`]
+pub const PROPOSE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_CONFIG: usize = 0;
+pub const PROPOSE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_VAULT: usize = 1;
+pub const PROPOSE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_ADMIN: usize = 2;
+pub const PROPOSE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_CONFIG_ADMIN: usize = 3;
+
+/// [`crate::VaultInstruction::ExecuteMintAuthorityHandoff`]
+pub const EXECUTE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_CONFIG: usize = 0;
+pub const EXECUTE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_VAULT: usize = 1;
+pub const EXECUTE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_LRT_MINT: usize = 2;
+pub const EXECUTE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_ADMIN: usize = 3;
+pub const EXECUTE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_CONFIG_ADMIN: usize = 4;
+pub const EXECUTE_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_TOKEN_PROGRAM: usize = 5;
+
+/// [`crate::VaultInstruction::CancelMintAuthorityHandoff`]
+pub const CANCEL_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CANCEL_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_VAULT: usize = 1;
+pub const CANCEL_MINT_AUTHORITY_HANDOFF_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::SetMigrationTarget`]
+pub const SET_MIGRATION_TARGET_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_MIGRATION_TARGET_IX_ACCOUNT_VAULT: usize = 1;
+pub const SET_MIGRATION_TARGET_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::MigrateDeposit`]
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_SOURCE_VAULT: usize = 1;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_SOURCE_LRT_MINT: usize = 2;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_DESTINATION_VAULT: usize = 3;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_DESTINATION_LRT_MINT: usize = 4;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_STAKER: usize = 5;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_STAKER_SOURCE_LRT_TOKEN_ACCOUNT: usize = 6;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_STAKER_DESTINATION_LRT_TOKEN_ACCOUNT: usize = 7;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_SOURCE_VAULT_TOKEN_ACCOUNT: usize = 8;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_DESTINATION_VAULT_TOKEN_ACCOUNT: usize = 9;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_SUPPORTED_MINT: usize = 10;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_TOKEN_PROGRAM: usize = 11;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_SOURCE_VAULT_DELEGATION_LIST: usize = 12;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_BURN_SIGNER: usize = 13;
+pub const MIGRATE_DEPOSIT_IX_ACCOUNT_MINT_SIGNER: usize = 14;
+
+/// [`crate::VaultInstruction::ZapDeposit`]
+pub const ZAP_DEPOSIT_IX_ACCOUNT_CONFIG: usize = 0;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_VAULT: usize = 1;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_LRT_MINT: usize = 2;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_DEPOSITOR: usize = 3;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_DEPOSITOR_TOKEN_ACCOUNT: usize = 4;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 5;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_RECIPIENT: usize = 6;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_RECIPIENT_LRT_TOKEN_ACCOUNT: usize = 7;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_VAULT_FEE_TOKEN_ACCOUNT: usize = 8;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_PROGRAM_FEE_TOKEN_ACCOUNT: usize = 9;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_SUPPORTED_MINT: usize = 10;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_TOKEN_PROGRAM: usize = 11;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_ASSOCIATED_TOKEN_PROGRAM: usize = 12;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 13;
+pub const ZAP_DEPOSIT_IX_ACCOUNT_MINT_SIGNER: usize = 14;
+
+/// [`crate::VaultInstruction::SetWithdrawalFee`]
+pub const SET_WITHDRAWAL_FEE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_WITHDRAWAL_FEE_IX_ACCOUNT_VAULT: usize = 1;
+pub const SET_WITHDRAWAL_FEE_IX_ACCOUNT_FEE_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::SetWithdrawalFeeGracePeriod`]
+pub const SET_WITHDRAWAL_FEE_GRACE_PERIOD_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_WITHDRAWAL_FEE_GRACE_PERIOD_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::SetDepositFee`]
+pub const SET_DEPOSIT_FEE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_DEPOSIT_FEE_IX_ACCOUNT_VAULT: usize = 1;
+pub const SET_DEPOSIT_FEE_IX_ACCOUNT_FEE_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::BurnWithdrawalTicket`]
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_CONFIG: usize = 0;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_VAULT: usize = 1;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 2;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_LRT_MINT: usize = 3;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_STAKER: usize = 4;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_STAKER_TOKEN_ACCOUNT: usize = 5;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_VAULT_STAKER_WITHDRAWAL_TICKET: usize = 6;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_VAULT_STAKER_WITHDRAWAL_TICKET_TOKEN_ACCOUNT: usize = 7;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_VAULT_FEE_TOKEN_ACCOUNT: usize = 8;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_SUPPORTED_MINT: usize = 9;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_TOKEN_PROGRAM: usize = 10;
+pub const BURN_WITHDRAWAL_TICKET_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 11;
+
+/// [`crate::VaultInstruction::AcceptAdmin`]
+pub const ACCEPT_ADMIN_IX_ACCOUNT_VAULT: usize = 0;
+pub const ACCEPT_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::ConfigSetAdmin`]
+pub const CONFIG_SET_ADMIN_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_ADMIN_IX_ACCOUNT_ADMIN: usize = 1;
+pub const CONFIG_SET_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::ConfigAcceptAdmin`]
+pub const CONFIG_ACCEPT_ADMIN_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_ACCEPT_ADMIN_IX_ACCOUNT_NEW_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::ConfigSetEpochLength`]
+pub const CONFIG_SET_EPOCH_LENGTH_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_EPOCH_LENGTH_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::GetProtocolParameters`]
+pub const GET_PROTOCOL_PARAMETERS_IX_ACCOUNT_CONFIG: usize = 0;
+pub const GET_PROTOCOL_PARAMETERS_IX_ACCOUNT_VAULT: usize = 1;
+
+/// [`crate::VaultInstruction::BlacklistOperator`]
+pub const BLACKLIST_OPERATOR_IX_ACCOUNT_VAULT: usize = 0;
+pub const BLACKLIST_OPERATOR_IX_ACCOUNT_VAULT_OPERATOR_BLACKLIST: usize = 1;
+pub const BLACKLIST_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const BLACKLIST_OPERATOR_IX_ACCOUNT_DELEGATION_ADMIN: usize = 3;
+pub const BLACKLIST_OPERATOR_IX_ACCOUNT_PAYER: usize = 4;
+pub const BLACKLIST_OPERATOR_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 5;
+
+/// [`crate::VaultInstruction::UnblacklistOperator`]
+pub const UNBLACKLIST_OPERATOR_IX_ACCOUNT_VAULT: usize = 0;
+pub const UNBLACKLIST_OPERATOR_IX_ACCOUNT_VAULT_OPERATOR_BLACKLIST: usize = 1;
+pub const UNBLACKLIST_OPERATOR_IX_ACCOUNT_OPERATOR: usize = 2;
+pub const UNBLACKLIST_OPERATOR_IX_ACCOUNT_DELEGATION_ADMIN: usize = 3;
+
+/// [`crate::VaultInstruction::SetReferralFee`]
+pub const SET_REFERRAL_FEE_IX_ACCOUNT_VAULT: usize = 0;
+pub const SET_REFERRAL_FEE_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::InitializeVaultReferrer`]
+pub const INITIALIZE_VAULT_REFERRER_IX_ACCOUNT_VAULT: usize = 0;
+pub const INITIALIZE_VAULT_REFERRER_IX_ACCOUNT_VAULT_REFERRER: usize = 1;
+pub const INITIALIZE_VAULT_REFERRER_IX_ACCOUNT_REFERRER: usize = 2;
+pub const INITIALIZE_VAULT_REFERRER_IX_ACCOUNT_PAYER: usize = 3;
+pub const INITIALIZE_VAULT_REFERRER_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 4;
+
+/// [`crate::VaultInstruction::ClaimReferralFees`]
+pub const CLAIM_REFERRAL_FEES_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CLAIM_REFERRAL_FEES_IX_ACCOUNT_VAULT: usize = 1;
+pub const CLAIM_REFERRAL_FEES_IX_ACCOUNT_LRT_MINT: usize = 2;
+pub const CLAIM_REFERRAL_FEES_IX_ACCOUNT_VAULT_REFERRER: usize = 3;
+pub const CLAIM_REFERRAL_FEES_IX_ACCOUNT_REFERRER_LRT_TOKEN_ACCOUNT: usize = 4;
+pub const CLAIM_REFERRAL_FEES_IX_ACCOUNT_REFERRER: usize = 5;
+pub const CLAIM_REFERRAL_FEES_IX_ACCOUNT_TOKEN_PROGRAM: usize = 6;
+
+/// [`crate::VaultInstruction::ConfigSetPaused`]
+pub const CONFIG_SET_PAUSED_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_PAUSED_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::ConfigSetVaultPaused`]
+pub const CONFIG_SET_VAULT_PAUSED_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_VAULT_PAUSED_IX_ACCOUNT_VAULT: usize = 1;
+pub const CONFIG_SET_VAULT_PAUSED_IX_ACCOUNT_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::ConfigSetProgramFee`]
+pub const CONFIG_SET_PROGRAM_FEE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_PROGRAM_FEE_IX_ACCOUNT_ADMIN: usize = 1;
+pub const CONFIG_SET_PROGRAM_FEE_IX_ACCOUNT_PROGRAM_FEE_WALLET: usize = 2;
+
+/// [`crate::VaultInstruction::SetFees`]
+pub const SET_FEES_IX_ACCOUNT_CONFIG: usize = 0;
+pub const SET_FEES_IX_ACCOUNT_VAULT: usize = 1;
+pub const SET_FEES_IX_ACCOUNT_FEE_ADMIN: usize = 2;
+
+/// [`crate::VaultInstruction::ProposeSlash`]
+pub const PROPOSE_SLASH_IX_ACCOUNT_CONFIG: usize = 0;
+pub const PROPOSE_SLASH_IX_ACCOUNT_VAULT: usize = 1;
+pub const PROPOSE_SLASH_IX_ACCOUNT_AVS: usize = 2;
+pub const PROPOSE_SLASH_IX_ACCOUNT_OPERATOR: usize = 3;
+pub const PROPOSE_SLASH_IX_ACCOUNT_SLASHER: usize = 4;
+pub const PROPOSE_SLASH_IX_ACCOUNT_AVS_VAULT_SLASHER_TICKET: usize = 5;
+pub const PROPOSE_SLASH_IX_ACCOUNT_VAULT_AVS_SLASHER_TICKET: usize = 6;
+pub const PROPOSE_SLASH_IX_ACCOUNT_SLASH_PROPOSAL: usize = 7;
+pub const PROPOSE_SLASH_IX_ACCOUNT_PAYER: usize = 8;
+pub const PROPOSE_SLASH_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 9;
+pub const PROPOSE_SLASH_IX_ACCOUNT_VAULT_OPERATOR_TICKET: usize = 10;
+pub const PROPOSE_SLASH_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 11;
+
+/// [`crate::VaultInstruction::VetoSlash`]
+pub const VETO_SLASH_IX_ACCOUNT_CONFIG: usize = 0;
+pub const VETO_SLASH_IX_ACCOUNT_AVS: usize = 1;
+pub const VETO_SLASH_IX_ACCOUNT_SLASH_PROPOSAL: usize = 2;
+pub const VETO_SLASH_IX_ACCOUNT_SLASH_VETO_ADMIN: usize = 3;
+pub const VETO_SLASH_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 4;
+
+/// [`crate::VaultInstruction::ExecuteSlash`]
+pub const EXECUTE_SLASH_IX_ACCOUNT_SLASH_PROPOSAL: usize = 0;
+pub const EXECUTE_SLASH_IX_ACCOUNT_CONFIG: usize = 1;
+pub const EXECUTE_SLASH_IX_ACCOUNT_VAULT: usize = 2;
+pub const EXECUTE_SLASH_IX_ACCOUNT_AVS: usize = 3;
+pub const EXECUTE_SLASH_IX_ACCOUNT_OPERATOR: usize = 4;
+pub const EXECUTE_SLASH_IX_ACCOUNT_SLASHER: usize = 5;
+pub const EXECUTE_SLASH_IX_ACCOUNT_AVS_OPERATOR_TICKET: usize = 6;
+pub const EXECUTE_SLASH_IX_ACCOUNT_OPERATOR_AVS_TICKET: usize = 7;
+pub const EXECUTE_SLASH_IX_ACCOUNT_AVS_VAULT_TICKET: usize = 8;
+pub const EXECUTE_SLASH_IX_ACCOUNT_OPERATOR_VAULT_TICKET: usize = 9;
+pub const EXECUTE_SLASH_IX_ACCOUNT_VAULT_AVS_TICKET: usize = 10;
+pub const EXECUTE_SLASH_IX_ACCOUNT_VAULT_OPERATOR_TICKET: usize = 11;
+pub const EXECUTE_SLASH_IX_ACCOUNT_AVS_VAULT_SLASHER_TICKET: usize = 12;
+pub const EXECUTE_SLASH_IX_ACCOUNT_VAULT_AVS_SLASHER_TICKET: usize = 13;
+pub const EXECUTE_SLASH_IX_ACCOUNT_VAULT_DELEGATION_LIST: usize = 14;
+pub const EXECUTE_SLASH_IX_ACCOUNT_VAULT_AVS_SLASHER_OPERATOR_TICKET: usize = 15;
+pub const EXECUTE_SLASH_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 16;
+pub const EXECUTE_SLASH_IX_ACCOUNT_SLASHER_TOKEN_ACCOUNT: usize = 17;
+pub const EXECUTE_SLASH_IX_ACCOUNT_SUPPORTED_MINT: usize = 18;
+pub const EXECUTE_SLASH_IX_ACCOUNT_TOKEN_PROGRAM: usize = 19;
+
+/// [`crate::VaultInstruction::SetCrankTip`]
+pub const SET_CRANK_TIP_IX_ACCOUNT_VAULT: usize = 0;
+pub const SET_CRANK_TIP_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::MigrateAccount`]
+pub const MIGRATE_ACCOUNT_IX_ACCOUNT_ACCOUNT: usize = 0;
+pub const MIGRATE_ACCOUNT_IX_ACCOUNT_PAYER: usize = 1;
+pub const MIGRATE_ACCOUNT_IX_ACCOUNT_SYSTEM_PROGRAM: usize = 2;
+
+/// [`crate::VaultInstruction::SetMintBurnAuthority`]
+pub const SET_MINT_BURN_AUTHORITY_IX_ACCOUNT_VAULT: usize = 0;
+pub const SET_MINT_BURN_AUTHORITY_IX_ACCOUNT_ADMIN: usize = 1;
+pub const SET_MINT_BURN_AUTHORITY_IX_ACCOUNT_MINT_BURN_AUTHORITY: usize = 2;
+
+/// [`crate::VaultInstruction::ClearMintBurnAuthority`]
+pub const CLEAR_MINT_BURN_AUTHORITY_IX_ACCOUNT_VAULT: usize = 0;
+pub const CLEAR_MINT_BURN_AUTHORITY_IX_ACCOUNT_ADMIN: usize = 1;
+
+/// [`crate::VaultInstruction::UpdateVaultBalance`]
+pub const UPDATE_VAULT_BALANCE_IX_ACCOUNT_CONFIG: usize = 0;
+pub const UPDATE_VAULT_BALANCE_IX_ACCOUNT_VAULT: usize = 1;
+pub const UPDATE_VAULT_BALANCE_IX_ACCOUNT_VAULT_TOKEN_ACCOUNT: usize = 2;
+pub const UPDATE_VAULT_BALANCE_IX_ACCOUNT_LRT_MINT: usize = 3;
+pub const UPDATE_VAULT_BALANCE_IX_ACCOUNT_VAULT_FEE_TOKEN_ACCOUNT: usize = 4;
+pub const UPDATE_VAULT_BALANCE_IX_ACCOUNT_TOKEN_PROGRAM: usize = 5;
+
+/// [`crate::VaultInstruction::ConfigSetParameter`]
+pub const CONFIG_SET_PARAMETER_IX_ACCOUNT_CONFIG: usize = 0;
+pub const CONFIG_SET_PARAMETER_IX_ACCOUNT_ADMIN: usize = 1;
@@ -0,0 +1,141 @@
+//! Decodes the raw data of vault program accounts for indexers and explorers.
+//!
+//! The first byte of every account is its [`AccountType`], which picks the layout
+//! [`decode_vault_account`] loads the data as. The decoded accounts serialize with serde, so
+//! `serde_json::to_value(&account)` gives `{"type": "Vault", "account": {...}}` with pubkeys and
+//! hashes as base58 strings and without the discriminator or reserved space. The account types are
+//! re-exported here so callers only need this crate.
+use borsh::BorshDeserialize;
+use jito_jsm_core::zero_copy::{ZeroCopy, ZeroCopyError};
+pub use jito_vault_core::{
+    config::Config, slash_proposal::SlashProposal, vault::Vault,
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList, vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket, vault_referrer::VaultReferrer,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket, AccountType,
+};
+use serde::Serialize;
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum AccountDecodeError {
+    #[error("account data is empty")]
+    Empty,
+    #[error("unknown account type {0}")]
+    UnknownAccountType(u8),
+    #[error(transparent)]
+    InvalidLayout(#[from] ZeroCopyError),
+    #[error("invalid account data: {0}")]
+    InvalidData(String),
+}
+
+macro_rules! vault_accounts {
+    ($($account:ident),* $(,)?) => {
+        /// An account of the vault program, see [`decode_vault_account`]
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Debug, Clone, Serialize)]
+        #[serde(tag = "type", content = "account")]
+        pub enum VaultAccount {
+            $($account($account),)*
+            VaultDelegationList(VaultDelegationList),
+        }
+
+        impl VaultAccount {
+            pub const fn account_type(&self) -> AccountType {
+                match self {
+                    $(Self::$account(_) => AccountType::$account,)*
+                    Self::VaultDelegationList(_) => AccountType::VaultDelegationList,
+                }
+            }
+        }
+
+        /// Decodes the data of a vault program account by its account type. The delegation list
+        /// is the only account stored with Borsh instead of a zero-copy layout.
+        pub fn decode_vault_account(data: &[u8]) -> Result<VaultAccount, AccountDecodeError> {
+            let discriminator = *data.first().ok_or(AccountDecodeError::Empty)?;
+            let account_type = AccountType::try_from_slice(&[discriminator])
+                .map_err(|_| AccountDecodeError::UnknownAccountType(discriminator))?;
+            match account_type {
+                $(AccountType::$account => Ok(VaultAccount::$account(*$account::load(data)?)),)*
+                AccountType::VaultDelegationList => VaultDelegationList::deserialize(&mut &data[..])
+                    .map(VaultAccount::VaultDelegationList)
+                    .map_err(|e| AccountDecodeError::InvalidData(e.to_string())),
+            }
+        }
+    };
+}
+
+vault_accounts!(
+    Config,
+    Vault,
+    VaultOperatorTicket,
+    VaultAvsSlasherTicket,
+    VaultAvsTicket,
+    VaultAvsSlasherOperatorTicket,
+    VaultStakerWithdrawalTicket,
+    VaultOperatorBlacklist,
+    VaultReferrer,
+    SlashProposal,
+);
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jito_jsm_core::zero_copy::ZeroCopy;
+    use solana_program::pubkey::Pubkey;
+
+    use super::{
+        decode_vault_account, AccountDecodeError, AccountType, VaultAccount, VaultDelegationList,
+        VaultOperatorTicket,
+    };
+
+    #[test]
+    fn test_decode_vault_account() {
+        let (vault, operator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let ticket = VaultOperatorTicket::new(vault, operator, 2, 100, 254);
+        let decoded = decode_vault_account(ticket.as_bytes()).unwrap();
+        assert_eq!(decoded.account_type(), AccountType::VaultOperatorTicket);
+
+        let json = serde_json::to_value(decoded).unwrap();
+        assert_eq!(json["type"], "VaultOperatorTicket");
+        assert_eq!(json["account"]["vault"], vault.to_string());
+        assert_eq!(json["account"]["operator"], operator.to_string());
+        assert_eq!(json["account"]["state"]["slot_added"], 100);
+        assert_eq!(json["account"].get("reserved"), None);
+    }
+
+    #[test]
+    fn test_decode_vault_delegation_list() {
+        let vault = Pubkey::new_unique();
+        let mut data = VaultDelegationList::new(vault, 254).try_to_vec().unwrap();
+        // the account keeps the space it was reallocated to
+        data.extend_from_slice(&[0; 64]);
+        let VaultAccount::VaultDelegationList(decoded) = decode_vault_account(&data).unwrap()
+        else {
+            panic!("decoded as another account type");
+        };
+        assert_eq!(decoded.vault(), vault);
+
+        let json = serde_json::to_value(VaultAccount::VaultDelegationList(decoded)).unwrap();
+        assert_eq!(json["account"]["vault"], vault.to_string());
+        assert_eq!(json["account"]["delegations"], serde_json::json!([]));
+
+        assert!(matches!(
+            decode_vault_account(&data[..10]).unwrap_err(),
+            AccountDecodeError::InvalidData(_)
+        ));
+    }
+
+    #[test]
+    fn test_decode_vault_account_rejects_malformed_data() {
+        assert_eq!(
+            decode_vault_account(&[]).unwrap_err(),
+            AccountDecodeError::Empty
+        );
+        assert_eq!(
+            decode_vault_account(&[u8::MAX]).unwrap_err(),
+            AccountDecodeError::UnknownAccountType(u8::MAX)
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Prints the shank IDL of the vault program, so clients generated from IDLs can call it without
+//! transcribing its instructions from `vault_sdk/idl.json` by hand.
+//!
+//! Regenerate with `cargo run -p jito-vault-sdk --bin vault-idl > vault_sdk/idl.json`
+use jito_jsm_core::idl::IdlExport;
+use jito_restaking_sanitization::error_code::ErrorSource;
+use jito_vault_core::{
+    config::Config, result::VaultCoreError, slash_proposal::SlashProposal, vault::Vault,
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList, vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket, vault_referrer::VaultReferrer,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::{error::VaultError, VaultInstruction};
+
+fn generate() -> String {
+    let mut export = IdlExport::new("jito_vault_program", env!("CARGO_PKG_VERSION"));
+    export.instruction::<VaultInstruction>(include_str!("../lib.rs"));
+    export.account::<Config>();
+    export.account::<Vault>();
+    export.account::<VaultDelegationList>();
+    export.account::<VaultAvsTicket>();
+    export.account::<VaultOperatorTicket>();
+    export.account::<VaultAvsSlasherTicket>();
+    export.account::<VaultAvsSlasherOperatorTicket>();
+    export.account::<VaultStakerWithdrawalTicket>();
+    export.account::<VaultOperatorBlacklist>();
+    export.account::<VaultReferrer>();
+    export.account::<SlashProposal>();
+    export.errors(ErrorSource::Vault, VaultCoreError::name);
+    export.errors(ErrorSource::VaultProgram, VaultError::name);
+    export.to_json()
+}
+
+fn main() {
+    print!("{}", generate());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+
+    #[test]
+    fn test_idl_up_to_date() {
+        assert_eq!(
+            generate(),
+            include_str!("../../idl.json"),
+            "IDL is stale, regenerate it with the vault-idl binary"
+        );
+    }
+}
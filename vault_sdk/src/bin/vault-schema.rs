@@ -0,0 +1,50 @@
+//! Prints the Borsh schema of the vault program's instructions, events and accounts as JSON, so
+//! decoders in other languages can generate their layouts from `vault_sdk/schema.json`.
+//!
+//! Regenerate with `cargo run -p jito-vault-sdk --bin vault-schema > vault_sdk/schema.json`
+use jito_jsm_core::schema::SchemaExport;
+use jito_vault_core::{
+    config::Config, slash_proposal::SlashProposal, vault::Vault,
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList, vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket, vault_referrer::VaultReferrer,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::{event::VaultEvent, VaultInstruction};
+
+fn generate() -> String {
+    let mut export = SchemaExport::default();
+    export.instruction::<VaultInstruction>();
+    export.event::<VaultEvent>();
+    export.account::<Config>();
+    export.account::<Vault>();
+    export.account::<VaultDelegationList>();
+    export.account::<VaultAvsTicket>();
+    export.account::<VaultOperatorTicket>();
+    export.account::<VaultAvsSlasherTicket>();
+    export.account::<VaultAvsSlasherOperatorTicket>();
+    export.account::<VaultStakerWithdrawalTicket>();
+    export.account::<VaultOperatorBlacklist>();
+    export.account::<VaultReferrer>();
+    export.account::<SlashProposal>();
+    export.to_json()
+}
+
+fn main() {
+    print!("{}", generate());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+
+    #[test]
+    fn test_schema_up_to_date() {
+        assert_eq!(
+            generate(),
+            include_str!("../../schema.json"),
+            "schema is stale, regenerate it with the vault-schema binary"
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! Prints deterministic test vectors for the vault program as JSON: instruction data,
+//! PDA derivations and account layouts for fixed inputs. Third-party clients can check their
+//! encoders against `vault_sdk/test_vectors.json`.
+//!
+//! Regenerate with `cargo run -p jito-vault-sdk --bin vault-test-vectors > vault_sdk/test_vectors.json`
+use std::fmt::Write;
+
+use borsh::BorshSerialize;
+use jito_jsm_core::bounded_string::{BoundedName, BoundedSymbol, BoundedUri};
+use jito_vault_core::{
+    config::Config, slash_proposal::SlashProposal, vault::Vault,
+    vault_avs_slasher_operator_ticket::VaultAvsSlasherOperatorTicket,
+    vault_avs_slasher_ticket::VaultAvsSlasherTicket, vault_avs_ticket::VaultAvsTicket,
+    vault_delegation_list::VaultDelegationList, vault_operator_blacklist::VaultOperatorBlacklist,
+    vault_operator_ticket::VaultOperatorTicket, vault_referrer::VaultReferrer,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::{ConfigParameter, VaultAdminRole, VaultInstruction};
+use solana_program::pubkey::Pubkey;
+
+/// Program id the PDAs are derived against. Vectors are about the derivation, so a fixed
+/// key keeps them independent of the deployed program id.
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([0xbb; 32]);
+
+const fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().fold(String::new(), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+#[derive(Default)]
+struct TestVectors {
+    instructions: Vec<String>,
+    pdas: Vec<String>,
+    accounts: Vec<String>,
+}
+
+impl TestVectors {
+    fn instruction(&mut self, name: &str, instruction: VaultInstruction) {
+        self.instructions.push(format!(
+            r#"{{"name": "{name}", "data": "{}"}}"#,
+            hex(&instruction.try_to_vec().unwrap())
+        ));
+    }
+
+    fn pda(&mut self, name: &str, (address, bump, seeds): (Pubkey, u8, Vec<Vec<u8>>)) {
+        let seeds: Vec<_> = seeds.iter().map(|s| format!(r#""{}""#, hex(s))).collect();
+        self.pdas.push(format!(
+            r#"{{"name": "{name}", "seeds": [{}], "address": "{address}", "bump": {bump}}}"#,
+            seeds.join(", ")
+        ));
+    }
+
+    fn account<T: BorshSerialize>(&mut self, name: &str, account: &T) {
+        let data = account.try_to_vec().unwrap();
+        self.accounts.push(format!(
+            r#"{{"name": "{name}", "size": {}, "data": "{}"}}"#,
+            data.len(),
+            hex(&data)
+        ));
+    }
+
+    fn to_json(&self) -> String {
+        let section = |entries: &[String]| format!("[\n    {}\n  ]", entries.join(",\n    "));
+        format!(
+            "{{\n  \"program_id\": \"{PROGRAM_ID}\",\n  \"instructions\": {},\n  \"pdas\": {},\n  \"accounts\": {}\n}}\n",
+            section(&self.instructions),
+            section(&self.pdas),
+            section(&self.accounts)
+        )
+    }
+}
+
+fn generate() -> String {
+    let mut vectors = TestVectors::default();
+
+    vectors.instruction("InitializeConfig", VaultInstruction::InitializeConfig);
+    vectors.instruction(
+        "InitializeVault",
+        VaultInstruction::InitializeVault {
+            deposit_fee_bps: 10,
+            withdrawal_fee_bps: 20,
+        },
+    );
+    vectors.instruction(
+        "InitializeVaultWithMint",
+        VaultInstruction::InitializeVaultWithMint,
+    );
+    vectors.instruction("AddAvs", VaultInstruction::AddAvs);
+    vectors.instruction("RemoveAvs", VaultInstruction::RemoveAvs);
+    vectors.instruction("AddOperator", VaultInstruction::AddOperator);
+    vectors.instruction("RemoveOperator", VaultInstruction::RemoveOperator);
+    vectors.instruction("MintTo", VaultInstruction::MintTo { amount: 1_000_000 });
+    vectors.instruction("Burn", VaultInstruction::Burn { amount: 1_000_000 });
+    vectors.instruction(
+        "EnqueueWithdrawal",
+        VaultInstruction::EnqueueWithdrawal { amount: 1_000_000 },
+    );
+    vectors.instruction(
+        "SetDepositCapacity",
+        VaultInstruction::SetDepositCapacity { amount: 1_000_000 },
+    );
+    vectors.instruction(
+        "WithdrawalAsset",
+        VaultInstruction::WithdrawalAsset { amount: 1_000_000 },
+    );
+    vectors.instruction("SetAdmin", VaultInstruction::SetAdmin);
+    vectors.instruction(
+        "SetSecondaryAdmin",
+        VaultInstruction::SetSecondaryAdmin(VaultAdminRole::FeeOwner),
+    );
+    vectors.instruction(
+        "AddDelegation",
+        VaultInstruction::AddDelegation { amount: 1_000_000 },
+    );
+    vectors.instruction(
+        "RemoveDelegation",
+        VaultInstruction::RemoveDelegation { amount: 1_000_000 },
+    );
+    vectors.instruction("UpdateDelegations", VaultInstruction::UpdateDelegations);
+    vectors.instruction("AddSlasher", VaultInstruction::AddSlasher);
+    vectors.instruction(
+        "CreateTokenMetadata",
+        VaultInstruction::CreateTokenMetadata {
+            name: BoundedName::new("Restaked SOL").unwrap(),
+            symbol: BoundedSymbol::new("rSOL").unwrap(),
+            uri: BoundedUri::new("https://example.com/rsol.json").unwrap(),
+        },
+    );
+    vectors.instruction(
+        "UpdateTokenMetadata",
+        VaultInstruction::UpdateTokenMetadata {
+            name: BoundedName::new("Restaked SOL").unwrap(),
+            symbol: BoundedSymbol::new("rSOL").unwrap(),
+            uri: BoundedUri::new("https://example.com/rsol.json").unwrap(),
+        },
+    );
+    vectors.instruction(
+        "InitializeVaultAvsSlasherOperatorTicket",
+        VaultInstruction::InitializeVaultAvsSlasherOperatorTicket,
+    );
+    vectors.instruction("Slash", VaultInstruction::Slash { amount: 1_000_000 });
+    vectors.instruction(
+        "SetEmergencyMode",
+        VaultInstruction::SetEmergencyMode { enabled: true },
+    );
+    vectors.instruction(
+        "SetAvsServiceFee",
+        VaultInstruction::SetAvsServiceFee { fee_bps: 250 },
+    );
+    vectors.instruction("PayAvsServiceFee", VaultInstruction::PayAvsServiceFee);
+    vectors.instruction(
+        "ProposeMintAuthorityHandoff",
+        VaultInstruction::ProposeMintAuthorityHandoff {
+            new_authority: key(13),
+        },
+    );
+    vectors.instruction(
+        "ExecuteMintAuthorityHandoff",
+        VaultInstruction::ExecuteMintAuthorityHandoff,
+    );
+    vectors.instruction(
+        "CancelMintAuthorityHandoff",
+        VaultInstruction::CancelMintAuthorityHandoff,
+    );
+    vectors.instruction(
+        "SetMigrationTarget",
+        VaultInstruction::SetMigrationTarget {
+            migration_target: key(13),
+        },
+    );
+    vectors.instruction(
+        "MigrateDeposit",
+        VaultInstruction::MigrateDeposit { amount: 1_000_000 },
+    );
+    vectors.instruction(
+        "ZapDeposit",
+        VaultInstruction::ZapDeposit {
+            amount: 1_000_000,
+            min_lrt_out: 990_000,
+        },
+    );
+    vectors.instruction(
+        "SetWithdrawalFee",
+        VaultInstruction::SetWithdrawalFee { fee_bps: 250 },
+    );
+    vectors.instruction(
+        "SetWithdrawalFeeGracePeriod",
+        VaultInstruction::SetWithdrawalFeeGracePeriod { epochs: 3 },
+    );
+    vectors.instruction(
+        "SetDepositFee",
+        VaultInstruction::SetDepositFee { fee_bps: 150 },
+    );
+    vectors.instruction(
+        "BurnWithdrawalTicket",
+        VaultInstruction::BurnWithdrawalTicket,
+    );
+    vectors.instruction("AcceptAdmin", VaultInstruction::AcceptAdmin);
+    vectors.instruction("ConfigSetAdmin", VaultInstruction::ConfigSetAdmin);
+    vectors.instruction("ConfigAcceptAdmin", VaultInstruction::ConfigAcceptAdmin);
+    vectors.instruction(
+        "ConfigSetEpochLength",
+        VaultInstruction::ConfigSetEpochLength {
+            epoch_length: 432_000,
+        },
+    );
+    vectors.instruction(
+        "GetProtocolParameters",
+        VaultInstruction::GetProtocolParameters,
+    );
+    vectors.instruction("BlacklistOperator", VaultInstruction::BlacklistOperator);
+    vectors.instruction("UnblacklistOperator", VaultInstruction::UnblacklistOperator);
+    vectors.instruction(
+        "SetReferralFee",
+        VaultInstruction::SetReferralFee { fee_bps: 2_500 },
+    );
+    vectors.instruction(
+        "InitializeVaultReferrer",
+        VaultInstruction::InitializeVaultReferrer,
+    );
+    vectors.instruction("ClaimReferralFees", VaultInstruction::ClaimReferralFees);
+    vectors.instruction(
+        "ConfigSetPaused",
+        VaultInstruction::ConfigSetPaused { paused: true },
+    );
+    vectors.instruction(
+        "ConfigSetVaultPaused",
+        VaultInstruction::ConfigSetVaultPaused { paused: true },
+    );
+    vectors.instruction(
+        "ConfigSetProgramFee",
+        VaultInstruction::ConfigSetProgramFee { fee_bps: 25 },
+    );
+    vectors.instruction(
+        "SetFees",
+        VaultInstruction::SetFees {
+            deposit_fee_bps: 100,
+            withdrawal_fee_bps: 50,
+            reward_fee_bps: 1_000,
+        },
+    );
+    vectors.instruction(
+        "ProposeSlash",
+        VaultInstruction::ProposeSlash {
+            amount: 1_000,
+            evidence_hash: [7; 32],
+        },
+    );
+    vectors.instruction("VetoSlash", VaultInstruction::VetoSlash);
+    vectors.instruction("ExecuteSlash", VaultInstruction::ExecuteSlash);
+    vectors.instruction(
+        "SetCrankTip",
+        VaultInstruction::SetCrankTip { lamports: 5_000 },
+    );
+    vectors.instruction("MigrateAccount", VaultInstruction::MigrateAccount);
+    vectors.instruction(
+        "SetMintBurnAuthority",
+        VaultInstruction::SetMintBurnAuthority,
+    );
+    vectors.instruction(
+        "ClearMintBurnAuthority",
+        VaultInstruction::ClearMintBurnAuthority,
+    );
+    vectors.instruction("UpdateVaultBalance", VaultInstruction::UpdateVaultBalance);
+    vectors.instruction(
+        "ConfigSetParameter",
+        VaultInstruction::ConfigSetParameter(ConfigParameter::WithdrawalCooldownEpochs(2)),
+    );
+
+    let (vault, avs, operator, slasher, staker) = (key(2), key(3), key(4), key(5), key(6));
+
+    vectors.pda("Config", Config::find_program_address(&PROGRAM_ID));
+    vectors.pda("Vault", Vault::find_program_address(&PROGRAM_ID, &key(7)));
+    vectors.pda(
+        "VaultDelegationList",
+        VaultDelegationList::find_program_address(&PROGRAM_ID, &vault),
+    );
+    vectors.pda(
+        "VaultAvsTicket",
+        VaultAvsTicket::find_program_address(&PROGRAM_ID, &vault, &avs),
+    );
+    vectors.pda(
+        "VaultOperatorTicket",
+        VaultOperatorTicket::find_program_address(&PROGRAM_ID, &vault, &operator),
+    );
+    vectors.pda(
+        "VaultAvsSlasherTicket",
+        VaultAvsSlasherTicket::find_program_address(&PROGRAM_ID, &vault, &avs, &slasher),
+    );
+    vectors.pda(
+        "VaultAvsSlasherOperatorTicket",
+        VaultAvsSlasherOperatorTicket::find_program_address(
+            &PROGRAM_ID,
+            &vault,
+            &avs,
+            &slasher,
+            &operator,
+            10,
+        ),
+    );
+    vectors.pda(
+        "VaultStakerWithdrawalTicket",
+        VaultStakerWithdrawalTicket::find_program_address(&PROGRAM_ID, &vault, &staker, &key(8)),
+    );
+    vectors.pda(
+        "VaultOperatorBlacklist",
+        VaultOperatorBlacklist::find_program_address(&PROGRAM_ID, &vault),
+    );
+    vectors.pda(
+        "VaultReferrer",
+        VaultReferrer::find_program_address(&PROGRAM_ID, &vault, &key(13)),
+    );
+    vectors.pda(
+        "SlashProposal",
+        SlashProposal::find_program_address(
+            &PROGRAM_ID,
+            &vault,
+            &avs,
+            &slasher,
+            &operator,
+            &[7; 32],
+        ),
+    );
+
+    vectors.account("Config", &Config::new(key(9), key(10), 254));
+    vectors.account(
+        "Vault",
+        &Vault::new(key(11), key(12), key(9), 1, key(7), 10, 20, 254),
+    );
+    vectors.account("VaultDelegationList", &VaultDelegationList::new(vault, 254));
+    vectors.account(
+        "VaultAvsTicket",
+        &VaultAvsTicket::new(vault, avs, 1, 100, 254),
+    );
+    vectors.account(
+        "VaultOperatorTicket",
+        &VaultOperatorTicket::new(vault, operator, 1, 100, 254),
+    );
+    vectors.account(
+        "VaultAvsSlasherTicket",
+        &VaultAvsSlasherTicket::new(vault, avs, slasher, 1_000_000, 1, 100, 254),
+    );
+    vectors.account(
+        "VaultAvsSlasherOperatorTicket",
+        &VaultAvsSlasherOperatorTicket::new(vault, avs, slasher, operator, 10, 500, 254),
+    );
+    vectors.account(
+        "VaultStakerWithdrawalTicket",
+        &VaultStakerWithdrawalTicket::new(vault, staker, key(8), 1_000_000, 3, 100, 254),
+    );
+    let mut vault_operator_blacklist = VaultOperatorBlacklist::new(vault, 254);
+    vault_operator_blacklist.add(operator).unwrap();
+    vectors.account("VaultOperatorBlacklist", &vault_operator_blacklist);
+    let mut vault_referrer = VaultReferrer::new(vault, key(13), 254);
+    vault_referrer.accrue(250).unwrap();
+    vectors.account("VaultReferrer", &vault_referrer);
+    vectors.account(
+        "SlashProposal",
+        &SlashProposal::new(vault, avs, slasher, operator, [7; 32], 1_000, 10, 254),
+    );
+
+    vectors.to_json()
+}
+
+fn main() {
+    print!("{}", generate());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+
+    #[test]
+    fn test_vectors_up_to_date() {
+        assert_eq!(
+            generate(),
+            include_str!("../../test_vectors.json"),
+            "test vectors are stale, regenerate them with the vault-test-vectors binary"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! Errors raised by the vault program's processors. Errors about the state of an account are in
+//! [`jito_vault_core::result::VaultCoreError`] instead.
+use jito_restaking_sanitization::error_code::ErrorSource;
+use solana_program::program_error::ProgramError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultError {
+    ConfigInvalidPda,
+    VaultInvalidPda,
+    VaultDelegationListInvalidPda,
+    VaultOperatorTicketInvalidPda,
+    VaultAvsTicketInvalidPda,
+    VaultAvsSlasherTicketInvalidPda,
+    VaultAvsSlasherOperatorTicketInvalidPda,
+    VaultStakerWithdrawalTicketInvalidPda,
+    LrtMintMismatch,
+    MintBurnSignerMismatch,
+    LrtMintNotSigner,
+    StakerExpectedWritable,
+    MigrationTargetIsVault,
+    WithdrawalAmountZero,
+    AssociatedTokenProgramInvalidAddress,
+    VaultOperatorBlacklistInvalidPda,
+    VaultReferrerInvalidPda,
+    SupportedMintMismatch,
+    TokenProgramMismatch,
+    SupportedMintExtensionNotAllowed,
+    SlashRequiresProposal,
+    SlashProposalInvalidPda,
+    SlashProposalMismatch,
+    SlashAmountZero,
+    AccountInvalidType,
+    AccountInvalidProgramOwner,
+    AccountExpectedWritable,
+    AccountAlreadyMigrated,
+}
+
+impl VaultError {
+    /// The offset of the error within the vault program processor range in
+    /// [`jito_restaking_sanitization::error_code`]. Offsets follow the declaration order and shall
+    /// never change, so new errors are appended.
+    pub const fn offset(&self) -> u32 {
+        match self {
+            Self::ConfigInvalidPda => 0,
+            Self::VaultInvalidPda => 1,
+            Self::VaultDelegationListInvalidPda => 2,
+            Self::VaultOperatorTicketInvalidPda => 3,
+            Self::VaultAvsTicketInvalidPda => 4,
+            Self::VaultAvsSlasherTicketInvalidPda => 5,
+            Self::VaultAvsSlasherOperatorTicketInvalidPda => 6,
+            Self::VaultStakerWithdrawalTicketInvalidPda => 7,
+            Self::LrtMintMismatch => 8,
+            Self::MintBurnSignerMismatch => 9,
+            Self::LrtMintNotSigner => 10,
+            Self::StakerExpectedWritable => 11,
+            Self::MigrationTargetIsVault => 12,
+            Self::WithdrawalAmountZero => 13,
+            Self::AssociatedTokenProgramInvalidAddress => 14,
+            Self::VaultOperatorBlacklistInvalidPda => 15,
+            Self::VaultReferrerInvalidPda => 16,
+            Self::SupportedMintMismatch => 17,
+            Self::TokenProgramMismatch => 18,
+            Self::SupportedMintExtensionNotAllowed => 19,
+            Self::SlashRequiresProposal => 20,
+            Self::SlashProposalInvalidPda => 21,
+            Self::SlashProposalMismatch => 22,
+            Self::SlashAmountZero => 23,
+            Self::AccountInvalidType => 24,
+            Self::AccountInvalidProgramOwner => 25,
+            Self::AccountExpectedWritable => 26,
+            Self::AccountAlreadyMigrated => 27,
+        }
+    }
+
+    /// Returns the name of the error at the offset, for decoding error codes off-chain
+    pub fn name(offset: u32) -> Option<&'static str> {
+        ERROR_NAMES.get(offset as usize).copied()
+    }
+}
+
+/// Error names indexed by [`VaultError::offset`]
+const ERROR_NAMES: [&str; 28] = [
+    "ConfigInvalidPda",
+    "VaultInvalidPda",
+    "VaultDelegationListInvalidPda",
+    "VaultOperatorTicketInvalidPda",
+    "VaultAvsTicketInvalidPda",
+    "VaultAvsSlasherTicketInvalidPda",
+    "VaultAvsSlasherOperatorTicketInvalidPda",
+    "VaultStakerWithdrawalTicketInvalidPda",
+    "LrtMintMismatch",
+    "MintBurnSignerMismatch",
+    "LrtMintNotSigner",
+    "StakerExpectedWritable",
+    "MigrationTargetIsVault",
+    "WithdrawalAmountZero",
+    "AssociatedTokenProgramInvalidAddress",
+    "VaultOperatorBlacklistInvalidPda",
+    "VaultReferrerInvalidPda",
+    "SupportedMintMismatch",
+    "TokenProgramMismatch",
+    "SupportedMintExtensionNotAllowed",
+    "SlashRequiresProposal",
+    "SlashProposalInvalidPda",
+    "SlashProposalMismatch",
+    "SlashAmountZero",
+    "AccountInvalidType",
+    "AccountInvalidProgramOwner",
+    "AccountExpectedWritable",
+    "AccountAlreadyMigrated",
+];
+
+impl From<VaultError> for ProgramError {
+    fn from(value: VaultError) -> Self {
+        Self::Custom(ErrorSource::VaultProgram.code(value.offset()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_names_match_offsets() {
+        for error in [
+            VaultError::ConfigInvalidPda,
+            VaultError::VaultStakerWithdrawalTicketInvalidPda,
+            VaultError::LrtMintMismatch,
+            VaultError::AssociatedTokenProgramInvalidAddress,
+        ] {
+            assert_eq!(
+                VaultError::name(error.offset()),
+                Some(format!("{error:?}").as_str())
+            );
+        }
+        assert_eq!(VaultError::name(ERROR_NAMES.len() as u32), None);
+    }
+
+    #[test]
+    fn test_error_code_in_vault_program_range() {
+        assert_eq!(
+            ProgramError::from(VaultError::LrtMintMismatch),
+            ProgramError::Custom(4_008)
+        );
+    }
+}
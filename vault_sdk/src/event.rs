@@ -0,0 +1,255 @@
+//! Events emitted by the vault program. Decode them from a transaction's logs with
+//! [`Event::from_logs`].
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+pub use jito_jsm_core::event::Event;
+use solana_program::pubkey::Pubkey;
+
+/// New variants are appended, since the variant index identifies the event
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub enum VaultEvent {
+    ConfigInitialized {
+        config: Pubkey,
+        admin: Pubkey,
+        restaking_program: Pubkey,
+    },
+    VaultInitialized {
+        vault: Pubkey,
+        admin: Pubkey,
+        base: Pubkey,
+        lrt_mint: Pubkey,
+        supported_mint: Pubkey,
+        index: u64,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+    },
+    VaultAvsTicketCreated {
+        vault: Pubkey,
+        avs: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        avs_vault_ticket: Pubkey,
+        avs_vault_ticket_index: u64,
+        activation_slot: u64,
+    },
+    VaultAvsTicketDeactivated {
+        vault: Pubkey,
+        avs: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        slot: u64,
+    },
+    VaultOperatorTicketCreated {
+        vault: Pubkey,
+        operator: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        operator_vault_ticket: Pubkey,
+        operator_vault_ticket_index: u64,
+        activation_slot: u64,
+    },
+    VaultOperatorTicketDeactivated {
+        vault: Pubkey,
+        operator: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        slot: u64,
+    },
+    VaultAvsSlasherTicketCreated {
+        vault: Pubkey,
+        avs: Pubkey,
+        slasher: Pubkey,
+        ticket: Pubkey,
+        index: u64,
+        avs_vault_slasher_ticket: Pubkey,
+        avs_vault_slasher_ticket_index: u64,
+        activation_slot: u64,
+    },
+    VaultAvsSlasherOperatorTicketCreated {
+        vault: Pubkey,
+        avs: Pubkey,
+        slasher: Pubkey,
+        operator: Pubkey,
+        ticket: Pubkey,
+        epoch: u64,
+    },
+    DelegationAdded {
+        vault: Pubkey,
+        operator: Pubkey,
+        amount: u64,
+        slot: u64,
+    },
+    DelegationRemoved {
+        vault: Pubkey,
+        operator: Pubkey,
+        amount: u64,
+        slot: u64,
+    },
+    DelegationsUpdated {
+        vault: Pubkey,
+        slot: u64,
+    },
+    VaultSlashed {
+        vault: Pubkey,
+        avs: Pubkey,
+        operator: Pubkey,
+        slasher: Pubkey,
+        epoch: u64,
+        amount: u64,
+        from_deactivating: u64,
+        from_active: u64,
+        epoch_slashed: u64,
+        tokens_deposited: u64,
+    },
+    Deposited {
+        vault: Pubkey,
+        depositor: Pubkey,
+        recipient: Pubkey,
+        amount: u64,
+        lrt_minted: u64,
+        lrt_fee: u64,
+    },
+    WithdrawalEnqueued {
+        vault: Pubkey,
+        staker: Pubkey,
+        ticket: Pubkey,
+        lrt_amount: u64,
+        sequence: u64,
+        slot: u64,
+    },
+    WithdrawalTicketBurned {
+        vault: Pubkey,
+        staker: Pubkey,
+        ticket: Pubkey,
+        sequence: u64,
+        lrt_burned: u64,
+        lrt_fee: u64,
+        amount: u64,
+    },
+    OperatorBlacklisted {
+        vault: Pubkey,
+        operator: Pubkey,
+    },
+    OperatorUnblacklisted {
+        vault: Pubkey,
+        operator: Pubkey,
+    },
+    ReferralFeeAccrued {
+        vault: Pubkey,
+        referrer: Pubkey,
+        lrt_amount: u64,
+    },
+    ReferralFeesClaimed {
+        vault: Pubkey,
+        referrer: Pubkey,
+        lrt_amount: u64,
+    },
+    ConfigPauseSet {
+        config: Pubkey,
+        paused: bool,
+    },
+    VaultPauseSet {
+        vault: Pubkey,
+        paused: bool,
+    },
+    ProgramFeeSet {
+        config: Pubkey,
+        fee_bps: u16,
+        wallet: Pubkey,
+        unlock_epoch: u64,
+    },
+    ProgramFeeCharged {
+        vault: Pubkey,
+        wallet: Pubkey,
+        lrt_amount: u64,
+    },
+    FeesSet {
+        vault: Pubkey,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        effective_epoch: u64,
+        withdrawal_fee_effective_epoch: u64,
+    },
+    SlashProposed {
+        slash_proposal: Pubkey,
+        vault: Pubkey,
+        avs: Pubkey,
+        operator: Pubkey,
+        slasher: Pubkey,
+        amount: u64,
+        evidence_hash: [u8; 32],
+        executable_epoch: u64,
+    },
+    SlashVetoed {
+        slash_proposal: Pubkey,
+        slash_veto_admin: Pubkey,
+        epoch: u64,
+    },
+    SlashProposalExecuted {
+        slash_proposal: Pubkey,
+        epoch: u64,
+    },
+    CrankTipPaid {
+        vault: Pubkey,
+        cranker: Pubkey,
+        lamports: u64,
+    },
+    AccountMigrated {
+        account: Pubkey,
+        from_len: u64,
+        to_len: u64,
+    },
+    MintBurnAuthoritySet {
+        vault: Pubkey,
+        mint_burn_authority: Pubkey,
+    },
+    MintBurnAuthorityCleared {
+        vault: Pubkey,
+    },
+    VaultBalanceUpdated {
+        vault: Pubkey,
+        epoch: u64,
+        rewards: u64,
+        lrt_to_reward_fee: u64,
+        tokens_deposited: u64,
+        lrt_supply: u64,
+    },
+}
+
+impl Event for VaultEvent {}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+
+    use super::VaultEvent;
+
+    #[test]
+    fn test_event_discriminators_are_pinned() {
+        assert_eq!(
+            VaultEvent::ConfigInitialized {
+                config: Pubkey::default(),
+                admin: Pubkey::default(),
+                restaking_program: Pubkey::default(),
+            }
+            .try_to_vec()
+            .unwrap()[0],
+            0
+        );
+        assert_eq!(
+            VaultEvent::WithdrawalTicketBurned {
+                vault: Pubkey::default(),
+                staker: Pubkey::default(),
+                ticket: Pubkey::default(),
+                sequence: 0,
+                lrt_burned: 0,
+                lrt_fee: 0,
+                amount: 0,
+            }
+            .try_to_vec()
+            .unwrap()[0],
+            14
+        );
+    }
+}
@@ -1,4 +1,26 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+// the BorshSchema derive on an enum defines a struct per variant whose fields are never read
+#![allow(dead_code)]
+
+pub mod account_indices;
+pub mod accounts;
+pub mod error;
+pub mod event;
+pub mod protocol_parameters;
+pub mod withdrawal_tickets;
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use jito_jsm_core::bounded_string::{BoundedName, BoundedSymbol, BoundedUri};
+use jito_restaking_sanitization::program_data::program_data_address;
+pub use jito_vault_core::config::{
+    DEFAULT_EPOCH_LENGTH, DEFAULT_WITHDRAWAL_COOLDOWN_EPOCHS, DEFAULT_WITHDRAWAL_FEE_GRACE_EPOCHS,
+    MAX_EPOCH_LENGTH, MAX_PROGRAM_FEE_BPS, MAX_WITHDRAWAL_COOLDOWN_EPOCHS,
+    MAX_WITHDRAWAL_FEE_GRACE_EPOCHS, MIN_EPOCH_LENGTH, MIN_WITHDRAWAL_COOLDOWN_EPOCHS,
+    MIN_WITHDRAWAL_FEE_GRACE_EPOCHS, PROGRAM_FEE_CHANGE_DELAY_EPOCHS,
+};
+pub use jito_vault_core::slash_proposal::SLASH_VETO_WINDOW_EPOCHS;
+pub use jito_vault_core::vault::{
+    FEE_CHANGE_DELAY_EPOCHS, MAX_CRANK_TIP_LAMPORTS, MAX_FEE_DELTA_BPS,
+};
 use shank::ShankInstruction;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
@@ -7,13 +29,15 @@ use solana_program::{
 };
 
 #[rustfmt::skip]
-#[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
+#[derive(Debug, BorshSerialize, BorshSchema, BorshDeserialize, ShankInstruction)]
 pub enum VaultInstruction {
     /// Initializes global configuration
     #[account(0, writable, name = "config")]
-    #[account(1, writable, signer, name = "admin")]
+    #[account(1, signer, name = "admin")]
     #[account(2, name = "restaking_program")]
-    #[account(3, name = "system_program")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    #[account(5, name = "program_data")]
     InitializeConfig,
 
     /// Initializes the vault
@@ -22,10 +46,11 @@ pub enum VaultInstruction {
     #[account(2, writable, name = "vault_delegation_list")]
     #[account(3, writable, signer, name = "lrt_mint")]
     #[account(4, name = "token_mint")]
-    #[account(5, writable, signer, name = "admin")]
+    #[account(5, signer, name = "admin")]
     #[account(6, signer, name = "base")]
-    #[account(7, name = "system_program")]
-    #[account(8, name = "token_program")]
+    #[account(7, writable, signer, name = "payer")]
+    #[account(8, name = "system_program")]
+    #[account(9, name = "token_program")]
     InitializeVault {
         deposit_fee_bps: u16,
         withdrawal_fee_bps: u16,
@@ -73,15 +98,19 @@ pub enum VaultInstruction {
     RemoveOperator,
 
     /// Mints LRT by depositing tokens into the vault
-    #[account(0, writable, name = "vault")]
-    #[account(1, writable, name = "lrt_mint")]
-    #[account(2, writable, signer, name = "depositor")]
-    #[account(3, writable, name = "depositor_token_account")]
-    #[account(4, writable, name = "vault_token_account")]
-    #[account(5, writable, name = "depositor_lrt_token_account")]
-    #[account(6, writable, name = "vault_fee_token_account")]
-    #[account(7, name = "token_program")]
-    #[account(8, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "lrt_mint")]
+    #[account(3, writable, signer, name = "depositor")]
+    #[account(4, writable, name = "depositor_token_account")]
+    #[account(5, writable, name = "vault_token_account")]
+    #[account(6, writable, name = "depositor_lrt_token_account")]
+    #[account(7, writable, name = "vault_fee_token_account")]
+    #[account(8, writable, name = "program_fee_token_account", description = "LRT account of the program fee wallet, only checked while a program fee is set")]
+    #[account(9, name = "supported_mint")]
+    #[account(10, name = "token_program")]
+    #[account(11, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    #[account(12, writable, optional, name = "vault_referrer", description = "Referrer of the deposit, paid a share of the deposit fee")]
     MintTo {
         amount: u64
     },
@@ -91,8 +120,20 @@ pub enum VaultInstruction {
         amount: u64
     },
 
-    /// Enqueues a withdrawal of LRT tokens
-    /// Used when there aren't enough idle assets in the vault to cover a withdrawal
+    /// Enqueues a withdrawal of LRT tokens, locking them in a new withdrawal ticket until it can
+    /// be redeemed with [`VaultInstruction::BurnWithdrawalTicket`]
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(3, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    #[account(4, name = "lrt_mint")]
+    #[account(5, writable, signer, name = "staker")]
+    #[account(6, writable, name = "staker_lrt_token_account")]
+    #[account(7, signer, name = "base")]
+    #[account(8, name = "token_program")]
+    #[account(9, name = "associated_token_program")]
+    #[account(10, name = "system_program")]
+    #[account(11, signer, optional, name = "burn_signer", description = "Signer for burning")]
     EnqueueWithdrawal {
         amount: u64
     },
@@ -109,10 +150,10 @@ pub enum VaultInstruction {
         amount: u64
     },
 
-    /// Changes the signer for vault admin
+    /// The vault admin proposes a new admin, who takes over with [`VaultInstruction::AcceptAdmin`]
     #[account(0, writable, name = "vault")]
     #[account(1, signer, name = "old_admin")]
-    #[account(2, signer, name = "new_admin")]
+    #[account(2, name = "new_admin")]
     SetAdmin,
 
     /// Changes the signer for vault delegation
@@ -121,15 +162,19 @@ pub enum VaultInstruction {
     #[account(2, name = "new_admin")]
     SetSecondaryAdmin(VaultAdminRole),
 
-    /// Delegates a token amount to a specific node operator
+    /// Delegates a token amount to a specific node operator. Both the vault and the operator shall
+    /// have opted in to each other and the operator shall not be on the vault's blacklist, which
+    /// may not exist yet.
     #[account(0, name = "config")]
     #[account(1, name = "vault")]
     #[account(2, name = "operator")]
     #[account(3, name = "vault_operator_ticket")]
-    #[account(4, writable, name = "vault_delegation_list")]
-    #[account(5, signer, name = "admin")]
-    #[account(6, writable, signer, name = "payer")]
-    #[account(7, name = "system_program")]
+    #[account(4, name = "operator_vault_ticket")]
+    #[account(5, writable, name = "vault_delegation_list")]
+    #[account(6, signer, name = "admin")]
+    #[account(7, writable, signer, name = "payer")]
+    #[account(8, name = "system_program")]
+    #[account(9, name = "vault_operator_blacklist")]
     AddDelegation {
         amount: u64,
     },
@@ -143,9 +188,10 @@ pub enum VaultInstruction {
         amount: u64,
     },
 
-    /// Updates delegations at epoch boundaries
+    /// Updates delegations at epoch boundaries. Anyone can call it, and the first update in a new
+    /// epoch pays the payer the crank tip set with [`VaultInstruction::SetCrankTip`].
     #[account(0, name = "config")]
-    #[account(1, name = "vault")]
+    #[account(1, writable, name = "vault")]
     #[account(2, writable, name = "vault_delegation_list")]
     #[account(3, writable, signer, name = "payer")]
     UpdateDelegations,
@@ -164,16 +210,16 @@ pub enum VaultInstruction {
 
     /// Creates token metadata for the vault LRT
     CreateTokenMetadata {
-        name: String,
-        symbol: String,
-        uri: String,
+        name: BoundedName,
+        symbol: BoundedSymbol,
+        uri: BoundedUri,
     },
 
     /// Updates token metadata for the vault LRT
     UpdateTokenMetadata {
-        name: String,
-        symbol: String,
-        uri: String,
+        name: BoundedName,
+        symbol: BoundedSymbol,
+        uri: BoundedUri,
     },
 
     /// Initializes the account which keeps track of how much an operator has been slashed
@@ -202,22 +248,400 @@ pub enum VaultInstruction {
     #[account(9, name = "vault_avs_ticket")]
     #[account(10, name = "vault_operator_ticket")]
     #[account(11, name = "avs_vault_slasher_ticket")]
-    #[account(12, name = "vault_avs_slasher_ticket")]
+    #[account(12, writable, name = "vault_avs_slasher_ticket")]
     #[account(13, writable, name = "vault_delegation_list")]
     #[account(14, writable, name = "vault_avs_slasher_operator_ticket")]
     #[account(15, writable, name = "vault_token_account")]
     #[account(16, name = "slasher_token_account")]
-    #[account(17, name = "token_program")]
+    #[account(17, name = "supported_mint")]
+    #[account(18, name = "token_program")]
     Slash {
         amount: u64
     },
+
+    /// Puts the vault into or takes the vault out of emergency mode
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetEmergencyMode {
+        enabled: bool
+    },
+
+    /// Vault agrees to pay the AVS a service fee, in basis points of delegated stake per epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "avs")]
+    #[account(3, writable, name = "vault_avs_ticket")]
+    #[account(4, signer, name = "admin")]
+    SetAvsServiceFee {
+        fee_bps: u16
+    },
+
+    /// Pays the AVS service fee out of vault rewards, charged on the delegation to the operators
+    /// holding an active ticket for the AVS. The AVS operator ticket of every operator in the
+    /// vault delegation list is passed in as remaining accounts, in the order of the list, empty
+    /// for an operator the AVS never added. Any fee not covered by rewards stays owed, held back
+    /// from the rewards harvested by [`VaultInstruction::UpdateVaultBalance`] until paid.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "avs")]
+    #[account(3, name = "avs_vault_ticket")]
+    #[account(4, writable, name = "vault_avs_ticket")]
+    #[account(5, name = "vault_delegation_list")]
+    #[account(6, writable, name = "vault_token_account")]
+    #[account(7, writable, name = "avs_token_account")]
+    #[account(8, name = "supported_mint")]
+    #[account(9, name = "token_program")]
+    PayAvsServiceFee,
+
+    /// Proposes handing the LRT mint authority held by the vault off to another account, such as
+    /// a redeployed vault. Requires the vault admin and configuration admin to co-sign.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    #[account(3, signer, name = "config_admin")]
+    ProposeMintAuthorityHandoff {
+        new_authority: Pubkey
+    },
+
+    /// Transfers the LRT mint authority to the proposed account once the timelock has passed
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "lrt_mint")]
+    #[account(3, signer, name = "admin")]
+    #[account(4, signer, name = "config_admin")]
+    #[account(5, name = "token_program")]
+    ExecuteMintAuthorityHandoff,
+
+    /// Cancels a pending mint authority handoff
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    CancelMintAuthorityHandoff,
+
+    /// Opens or closes migrations of deposits out of the vault into another vault
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetMigrationTarget {
+        migration_target: Pubkey
+    },
+
+    /// Moves a staker's deposit from one vault to another by burning LRT of the source vault and
+    /// minting LRT of the destination vault for the supported tokens backing it
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "source_vault")]
+    #[account(2, writable, name = "source_lrt_mint")]
+    #[account(3, writable, name = "destination_vault")]
+    #[account(4, writable, name = "destination_lrt_mint")]
+    #[account(5, signer, name = "staker")]
+    #[account(6, writable, name = "staker_source_lrt_token_account")]
+    #[account(7, writable, name = "staker_destination_lrt_token_account")]
+    #[account(8, writable, name = "source_vault_token_account")]
+    #[account(9, writable, name = "destination_vault_token_account")]
+    #[account(10, name = "supported_mint")]
+    #[account(11, name = "token_program")]
+    #[account(12, name = "source_vault_delegation_list", description = "Delegations of the source vault, the tokens not delegated are the liquidity migrations are paid from")]
+    #[account(13, signer, optional, name = "burn_signer", description = "Signer for burning, required when the source vault has a mint burn authority")]
+    #[account(14, signer, optional, name = "mint_signer", description = "Signer for minting, required when the destination vault has a mint burn authority")]
+    MigrateDeposit {
+        amount: u64
+    },
+
+    /// Deposits tokens into the vault and mints LRT to a recipient, creating the recipient's LRT
+    /// token account if needed and failing if less than `min_lrt_out` would be minted
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "lrt_mint")]
+    #[account(3, writable, signer, name = "depositor")]
+    #[account(4, writable, name = "depositor_token_account")]
+    #[account(5, writable, name = "vault_token_account")]
+    #[account(6, name = "recipient")]
+    #[account(7, writable, name = "recipient_lrt_token_account")]
+    #[account(8, writable, name = "vault_fee_token_account")]
+    #[account(9, writable, name = "program_fee_token_account", description = "LRT account of the program fee wallet, only checked while a program fee is set")]
+    #[account(10, name = "supported_mint")]
+    #[account(11, name = "token_program")]
+    #[account(12, name = "associated_token_program")]
+    #[account(13, name = "system_program")]
+    #[account(14, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    ZapDeposit {
+        amount: u64,
+        min_lrt_out: u64,
+    },
+
+    /// The fee admin schedules the vault's withdrawal fee like [`VaultInstruction::SetFees`]. An
+    /// increase also waits out the grace period in the configuration.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "fee_admin")]
+    SetWithdrawalFee {
+        fee_bps: u16
+    },
+
+    /// Sets the number of epochs a raised vault withdrawal fee waits before applying, within
+    /// [`MIN_WITHDRAWAL_FEE_GRACE_EPOCHS`] and [`MAX_WITHDRAWAL_FEE_GRACE_EPOCHS`]
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    SetWithdrawalFeeGracePeriod {
+        epochs: u64
+    },
+
+    /// The fee admin schedules the vault's deposit fee like [`VaultInstruction::SetFees`]
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "fee_admin")]
+    SetDepositFee {
+        fee_bps: u16
+    },
+
+    /// Redeems a matured withdrawal ticket for the supported tokens backing its LRT, net of the
+    /// withdrawal fee, and closes it. Tickets are redeemed in the order they were enqueued.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, writable, name = "lrt_mint")]
+    #[account(4, writable, name = "staker")]
+    #[account(5, writable, name = "staker_token_account")]
+    #[account(6, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(7, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    #[account(8, writable, name = "vault_fee_token_account")]
+    #[account(9, name = "supported_mint")]
+    #[account(10, name = "token_program")]
+    #[account(11, name = "vault_delegation_list", description = "Read while the vault is in emergency mode to find the tokens that aren't delegated")]
+    BurnWithdrawalTicket,
+
+    /// The admin proposed with [`VaultInstruction::SetAdmin`] takes over the vault
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "new_admin")]
+    AcceptAdmin,
+
+    /// The config admin proposes a new admin, who takes over with
+    /// [`VaultInstruction::ConfigAcceptAdmin`]
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "new_admin")]
+    ConfigSetAdmin,
+
+    /// The admin proposed with [`VaultInstruction::ConfigSetAdmin`] takes over the config
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "new_admin")]
+    ConfigAcceptAdmin,
+
+    /// Sets the length of an epoch in slots, within [`MIN_EPOCH_LENGTH`] and [`MAX_EPOCH_LENGTH`],
+    /// until the first vault is initialized
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetEpochLength {
+        epoch_length: u64
+    },
+
+    /// Read-only: sets the return data to the vault's
+    /// [`protocol_parameters::ProtocolParameters`] as of the current slot
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    GetProtocolParameters,
+
+    /// Blacklists an operator, so the vault can't delegate to it whatever the state of the
+    /// tickets. Creates the vault's blacklist on first use.
+    #[account(0, name = "vault")]
+    #[account(1, writable, name = "vault_operator_blacklist")]
+    #[account(2, name = "operator")]
+    #[account(3, signer, name = "delegation_admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    BlacklistOperator,
+
+    /// Removes an operator from the vault's blacklist
+    #[account(0, name = "vault")]
+    #[account(1, writable, name = "vault_operator_blacklist")]
+    #[account(2, name = "operator")]
+    #[account(3, signer, name = "delegation_admin")]
+    UnblacklistOperator,
+
+    /// Sets the share of the deposit fee paid to the referrer of a deposit
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "admin")]
+    SetReferralFee {
+        fee_bps: u16
+    },
+
+    /// Creates the account a referrer accrues its share of the deposit fees of a vault in
+    #[account(0, name = "vault")]
+    #[account(1, writable, name = "vault_referrer")]
+    #[account(2, name = "referrer")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    InitializeVaultReferrer,
+
+    /// Mints the referral fees accrued by a referrer to its LRT token account
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "lrt_mint")]
+    #[account(3, writable, name = "vault_referrer")]
+    #[account(4, writable, name = "referrer_lrt_token_account")]
+    #[account(5, signer, name = "referrer")]
+    #[account(6, name = "token_program")]
+    ClaimReferralFees,
+
+    /// Pauses or resumes deposits, withdrawals, slashing and delegation changes in every vault
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetPaused {
+        paused: bool
+    },
+
+    /// Pauses or resumes deposits, withdrawals, slashing and delegation changes in one vault
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    ConfigSetVaultPaused {
+        paused: bool
+    },
+
+    /// Sets the program fee charged in LRT on deposits into every vault and the wallet it's paid
+    /// to, capped at [`MAX_PROGRAM_FEE_BPS`] and at most once every
+    /// [`PROGRAM_FEE_CHANGE_DELAY_EPOCHS`]
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "program_fee_wallet")]
+    ConfigSetProgramFee {
+        fee_bps: u16
+    },
+
+    /// The fee admin schedules the deposit, withdrawal and reward fees of a vault. They apply
+    /// [`FEE_CHANGE_DELAY_EPOCHS`] later and each can move by at most [`MAX_FEE_DELTA_BPS`].
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "fee_admin")]
+    SetFees {
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+    },
+
+    /// The slasher files a slash of an operator of the vault, which the slash veto admin of the
+    /// AVS can veto with [`VaultInstruction::VetoSlash`] for [`SLASH_VETO_WINDOW_EPOCHS`] before
+    /// anyone can carry it out with [`VaultInstruction::ExecuteSlash`]. The proposal is counted
+    /// as pending on the vault AVS ticket until it's vetoed or executed.
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "avs")]
+    #[account(3, name = "operator")]
+    #[account(4, signer, name = "slasher")]
+    #[account(5, name = "avs_vault_slasher_ticket")]
+    #[account(6, name = "vault_avs_slasher_ticket")]
+    #[account(7, writable, name = "slash_proposal")]
+    #[account(8, writable, signer, name = "payer")]
+    #[account(9, name = "system_program")]
+    #[account(10, name = "vault_operator_ticket")]
+    #[account(11, writable, name = "vault_avs_ticket")]
+    ProposeSlash {
+        amount: u64,
+        evidence_hash: [u8; 32],
+    },
+
+    /// The slash veto admin of the AVS vetoes a slash proposal within its veto window
+    #[account(0, name = "config")]
+    #[account(1, name = "avs")]
+    #[account(2, writable, name = "slash_proposal")]
+    #[account(3, signer, name = "slash_veto_admin")]
+    #[account(4, writable, name = "vault_avs_ticket")]
+    VetoSlash,
+
+    /// Carries out a slash proposal once its veto window has passed. Anyone can execute it, the
+    /// accounts after the proposal are those of [`VaultInstruction::Slash`].
+    #[account(0, writable, name = "slash_proposal")]
+    #[account(1, name = "config")]
+    #[account(2, writable, name = "vault")]
+    #[account(3, name = "avs")]
+    #[account(4, name = "operator")]
+    #[account(5, name = "slasher")]
+    #[account(6, name = "avs_operator_ticket")]
+    #[account(7, name = "operator_avs_ticket")]
+    #[account(8, name = "avs_vault_ticket")]
+    #[account(9, name = "operator_vault_ticket")]
+    #[account(10, writable, name = "vault_avs_ticket")]
+    #[account(11, name = "vault_operator_ticket")]
+    #[account(12, name = "avs_vault_slasher_ticket")]
+    #[account(13, writable, name = "vault_avs_slasher_ticket")]
+    #[account(14, writable, name = "vault_delegation_list")]
+    #[account(15, writable, name = "vault_avs_slasher_operator_ticket")]
+    #[account(16, writable, name = "vault_token_account")]
+    #[account(17, name = "slasher_token_account")]
+    #[account(18, name = "supported_mint")]
+    #[account(19, name = "token_program")]
+    ExecuteSlash,
+
+    /// The vault admin sets the tip in lamports paid to whoever cranks the delegations into a new
+    /// epoch with [`VaultInstruction::UpdateDelegations`], up to [`MAX_CRANK_TIP_LAMPORTS`]
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "admin")]
+    SetCrankTip {
+        lamports: u64,
+    },
+
+    /// Migrates an account written by an earlier version of the program to its current layout
+    /// and version, see [`jito_vault_core::migration`]. Anyone can call it, the payer covers the
+    /// rent of the grown account.
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+    /// The vault admin gates deposits and withdrawals behind the mint burn authority, which then
+    /// co-signs every [`VaultInstruction::MintTo`] and [`VaultInstruction::EnqueueWithdrawal`]
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "mint_burn_authority")]
+    SetMintBurnAuthority,
+
+    /// The vault admin removes the mint burn authority, making deposits and withdrawals
+    /// permissionless again
+    #[account(0, writable, name = "vault")]
+    #[account(1, signer, name = "admin")]
+    ClearMintBurnAuthority,
+
+    /// Harvests the tokens the vault holds in excess of the tokens deposited as rewards, minting
+    /// the reward fee to the fee owner. The AVS service fees owed are held back from the harvest,
+    /// and [`VaultInstruction::PayAvsServiceFee`] shall run first to settle the fees accrued since
+    /// they were last paid out of the same rewards.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "vault_token_account")]
+    #[account(3, writable, name = "lrt_mint")]
+    #[account(4, writable, name = "vault_fee_token_account")]
+    #[account(5, name = "token_program")]
+    UpdateVaultBalance,
+
+    /// Sets a deployment parameter of every vault, see [`ConfigParameter`]
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetParameter(ConfigParameter),
+}
+
+/// A deployment parameter the config admin sets with [`VaultInstruction::ConfigSetParameter`]
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshSchema, BorshDeserialize, PartialEq, Eq)]
+pub enum ConfigParameter {
+    /// The length of an epoch in slots, within [`MIN_EPOCH_LENGTH`] and [`MAX_EPOCH_LENGTH`],
+    /// until the first vault is initialized
+    EpochLength(u64),
+
+    /// The number of epochs a raised vault withdrawal fee waits before applying, within
+    /// [`MIN_WITHDRAWAL_FEE_GRACE_EPOCHS`] and [`MAX_WITHDRAWAL_FEE_GRACE_EPOCHS`]
+    WithdrawalFeeGraceEpochs(u64),
+
+    /// The number of epoch boundaries a staker withdrawal waits for, within
+    /// [`MIN_WITHDRAWAL_COOLDOWN_EPOCHS`] and [`MAX_WITHDRAWAL_COOLDOWN_EPOCHS`]
+    WithdrawalCooldownEpochs(u64),
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, BorshSerialize, BorshSchema, BorshDeserialize)]
 pub enum VaultAdminRole {
     Delegataion,
     FeeOwner,
     MintBurnAuthority,
+    FeeAdmin,
 }
 
 pub fn initialize_config(
@@ -225,12 +649,15 @@ pub fn initialize_config(
     config: &Pubkey,
     admin: &Pubkey,
     restaking_program: &Pubkey,
+    payer: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*config, false),
-        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(*admin, true),
         AccountMeta::new_readonly(*restaking_program, false),
+        AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(program_data_address(program_id), false),
     ];
     Instruction {
         program_id: *program_id,
@@ -249,6 +676,8 @@ pub fn initialize_vault(
     token_mint: &Pubkey,
     admin: &Pubkey,
     base: &Pubkey,
+    payer: &Pubkey,
+    token_program: &Pubkey,
     deposit_fee_bps: u16,
     withdrawal_fee_bps: u16,
 ) -> Instruction {
@@ -258,10 +687,11 @@ pub fn initialize_vault(
         AccountMeta::new(*vault_delegation_list, false),
         AccountMeta::new(*lrt_mint, true),
         AccountMeta::new_readonly(*token_mint, false),
-        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(*admin, true),
         AccountMeta::new_readonly(*base, true),
+        AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
-        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(*token_program, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -378,6 +808,7 @@ pub fn remove_operator(
 #[allow(clippy::too_many_arguments)]
 pub fn mint_to(
     program_id: &Pubkey,
+    config: &Pubkey,
     vault: &Pubkey,
     lrt_mint: &Pubkey,
     depositor: &Pubkey,
@@ -385,10 +816,15 @@ pub fn mint_to(
     vault_token_account: &Pubkey,
     depositor_lrt_token_account: &Pubkey,
     vault_fee_token_account: &Pubkey,
+    program_fee_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    token_program: &Pubkey,
     mint_signer: Option<&Pubkey>,
+    vault_referrer: Option<&Pubkey>,
     amount: u64,
 ) -> Instruction {
     let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
         AccountMeta::new(*lrt_mint, false),
         AccountMeta::new(*depositor, true),
@@ -396,11 +832,16 @@ pub fn mint_to(
         AccountMeta::new(*vault_token_account, false),
         AccountMeta::new(*depositor_lrt_token_account, false),
         AccountMeta::new(*vault_fee_token_account, false),
-        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(*program_fee_token_account, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
     ];
     if let Some(signer) = mint_signer {
         accounts.push(AccountMeta::new_readonly(*signer, true));
     }
+    if let Some(vault_referrer) = vault_referrer {
+        accounts.push(AccountMeta::new(*vault_referrer, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -416,10 +857,40 @@ pub fn burn(program_id: &Pubkey, amount: u64) -> Instruction {
     }
 }
 
-pub fn enqueue_withdrawal(program_id: &Pubkey, amount: u64) -> Instruction {
+#[allow(clippy::too_many_arguments)]
+pub fn enqueue_withdrawal(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    vault_staker_withdrawal_ticket_token_account: &Pubkey,
+    lrt_mint: &Pubkey,
+    staker: &Pubkey,
+    staker_lrt_token_account: &Pubkey,
+    base: &Pubkey,
+    token_program: &Pubkey,
+    burn_signer: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket_token_account, false),
+        AccountMeta::new_readonly(*lrt_mint, false),
+        AccountMeta::new(*staker, true),
+        AccountMeta::new(*staker_lrt_token_account, false),
+        AccountMeta::new_readonly(*base, true),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if let Some(signer) = burn_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
     Instruction {
         program_id: *program_id,
-        accounts: vec![],
+        accounts,
         data: VaultInstruction::EnqueueWithdrawal { amount }
             .try_to_vec()
             .unwrap(),
@@ -464,7 +935,7 @@ pub fn set_admin(
     let accounts = vec![
         AccountMeta::new(*vault, false),
         AccountMeta::new_readonly(*old_admin, true),
-        AccountMeta::new_readonly(*new_admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -501,10 +972,11 @@ pub fn add_delegation(
     vault: &Pubkey,
     operator: &Pubkey,
     vault_operator_ticket: &Pubkey,
+    operator_vault_ticket: &Pubkey,
     vault_delegation_list: &Pubkey,
     admin: &Pubkey,
     payer: &Pubkey,
-
+    vault_operator_blacklist: &Pubkey,
     amount: u64,
 ) -> Instruction {
     let accounts = vec![
@@ -512,10 +984,12 @@ pub fn add_delegation(
         AccountMeta::new_readonly(*vault, false),
         AccountMeta::new_readonly(*operator, false),
         AccountMeta::new_readonly(*vault_operator_ticket, false),
+        AccountMeta::new_readonly(*operator_vault_ticket, false),
         AccountMeta::new(*vault_delegation_list, false),
         AccountMeta::new_readonly(*admin, true),
         AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*vault_operator_blacklist, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -560,7 +1034,7 @@ pub fn update_delegations(
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
-        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault, false),
         AccountMeta::new(*vault_delegation_list, false),
         AccountMeta::new(*payer, true),
     ];
@@ -603,9 +1077,9 @@ pub fn add_slasher(
 
 pub fn create_token_metadata(
     program_id: &Pubkey,
-    name: String,
-    symbol: String,
-    uri: String,
+    name: BoundedName,
+    symbol: BoundedSymbol,
+    uri: BoundedUri,
 ) -> Instruction {
     Instruction {
         program_id: *program_id,
@@ -618,9 +1092,9 @@ pub fn create_token_metadata(
 
 pub fn update_token_metadata(
     program_id: &Pubkey,
-    name: String,
-    symbol: String,
-    uri: String,
+    name: BoundedName,
+    symbol: BoundedSymbol,
+    uri: BoundedUri,
 ) -> Instruction {
     Instruction {
         program_id: *program_id,
@@ -683,6 +1157,8 @@ pub fn slash(
     vault_avs_slasher_operator_ticket: &Pubkey,
     vault_token_account: &Pubkey,
     slasher_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    token_program: &Pubkey,
     amount: u64,
 ) -> Instruction {
     let accounts = vec![
@@ -698,12 +1174,13 @@ pub fn slash(
         AccountMeta::new_readonly(*vault_avs_ticket, false),
         AccountMeta::new_readonly(*vault_operator_ticket, false),
         AccountMeta::new_readonly(*avs_vault_slasher_ticket, false),
-        AccountMeta::new_readonly(*vault_avs_slasher_ticket, false),
+        AccountMeta::new(*vault_avs_slasher_ticket, false),
         AccountMeta::new(*vault_delegation_list, false),
         AccountMeta::new(*vault_avs_slasher_operator_ticket, false),
         AccountMeta::new(*vault_token_account, false),
         AccountMeta::new(*slasher_token_account, false),
-        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -711,3 +1188,897 @@ pub fn slash(
         data: VaultInstruction::Slash { amount }.try_to_vec().unwrap(),
     }
 }
+
+pub fn set_emergency_mode(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    enabled: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetEmergencyMode { enabled }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_withdrawal_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    fee_admin: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*fee_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetWithdrawalFee { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_withdrawal_fee_grace_period(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    epochs: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetWithdrawalFeeGracePeriod { epochs }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_deposit_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    fee_admin: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*fee_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetDepositFee { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_avs_service_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    avs: &Pubkey,
+    vault_avs_ticket: &Pubkey,
+    admin: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new(*vault_avs_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetAvsServiceFee { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn pay_avs_service_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    avs: &Pubkey,
+    avs_vault_ticket: &Pubkey,
+    vault_avs_ticket: &Pubkey,
+    vault_delegation_list: &Pubkey,
+    vault_token_account: &Pubkey,
+    avs_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    token_program: &Pubkey,
+    avs_operator_tickets: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*avs_vault_ticket, false),
+        AccountMeta::new(*vault_avs_ticket, false),
+        AccountMeta::new_readonly(*vault_delegation_list, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*avs_token_account, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    accounts.extend(
+        avs_operator_tickets
+            .iter()
+            .map(|ticket| AccountMeta::new_readonly(*ticket, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::PayAvsServiceFee.try_to_vec().unwrap(),
+    }
+}
+
+pub fn propose_mint_authority_handoff(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    config_admin: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*config_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ProposeMintAuthorityHandoff {
+            new_authority: *new_authority,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn execute_mint_authority_handoff(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    lrt_mint: &Pubkey,
+    admin: &Pubkey,
+    config_admin: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*lrt_mint, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*config_admin, true),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ExecuteMintAuthorityHandoff
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn cancel_mint_authority_handoff(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CancelMintAuthorityHandoff
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_migration_target(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    migration_target: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetMigrationTarget {
+            migration_target: *migration_target,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_deposit(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    source_vault: &Pubkey,
+    source_lrt_mint: &Pubkey,
+    destination_vault: &Pubkey,
+    destination_lrt_mint: &Pubkey,
+    staker: &Pubkey,
+    staker_source_lrt_token_account: &Pubkey,
+    staker_destination_lrt_token_account: &Pubkey,
+    source_vault_token_account: &Pubkey,
+    destination_vault_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    token_program: &Pubkey,
+    source_vault_delegation_list: &Pubkey,
+    burn_signer: Option<&Pubkey>,
+    mint_signer: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*source_vault, false),
+        AccountMeta::new(*source_lrt_mint, false),
+        AccountMeta::new(*destination_vault, false),
+        AccountMeta::new(*destination_lrt_mint, false),
+        AccountMeta::new_readonly(*staker, true),
+        AccountMeta::new(*staker_source_lrt_token_account, false),
+        AccountMeta::new(*staker_destination_lrt_token_account, false),
+        AccountMeta::new(*source_vault_token_account, false),
+        AccountMeta::new(*destination_vault_token_account, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(*source_vault_delegation_list, false),
+    ];
+    if let Some(signer) = burn_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    if let Some(signer) = mint_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MigrateDeposit { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn zap_deposit(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    lrt_mint: &Pubkey,
+    depositor: &Pubkey,
+    depositor_token_account: &Pubkey,
+    vault_token_account: &Pubkey,
+    recipient: &Pubkey,
+    recipient_lrt_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    program_fee_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    token_program: &Pubkey,
+    mint_signer: Option<&Pubkey>,
+    amount: u64,
+    min_lrt_out: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*lrt_mint, false),
+        AccountMeta::new(*depositor, true),
+        AccountMeta::new(*depositor_token_account, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new_readonly(*recipient, false),
+        AccountMeta::new(*recipient_lrt_token_account, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new(*program_fee_token_account, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if let Some(signer) = mint_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ZapDeposit {
+            amount,
+            min_lrt_out,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn burn_withdrawal_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    lrt_mint: &Pubkey,
+    staker: &Pubkey,
+    staker_token_account: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    vault_staker_withdrawal_ticket_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    token_program: &Pubkey,
+    vault_delegation_list: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*lrt_mint, false),
+        AccountMeta::new(*staker, false),
+        AccountMeta::new(*staker_token_account, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket_token_account, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(*vault_delegation_list, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::BurnWithdrawalTicket.try_to_vec().unwrap(),
+    }
+}
+
+pub fn accept_admin(program_id: &Pubkey, vault: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AcceptAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn config_set_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ConfigSetAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn config_accept_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ConfigAcceptAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn config_set_epoch_length(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    epoch_length: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ConfigSetEpochLength { epoch_length }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn get_protocol_parameters(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::GetProtocolParameters
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn blacklist_operator(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    vault_operator_blacklist: &Pubkey,
+    operator: &Pubkey,
+    delegation_admin: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_operator_blacklist, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*delegation_admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::BlacklistOperator.try_to_vec().unwrap(),
+    }
+}
+
+pub fn unblacklist_operator(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    vault_operator_blacklist: &Pubkey,
+    operator: &Pubkey,
+    delegation_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_operator_blacklist, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*delegation_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::UnblacklistOperator.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_referral_fee(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetReferralFee { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn initialize_vault_referrer(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    vault_referrer: &Pubkey,
+    referrer: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_referrer, false),
+        AccountMeta::new_readonly(*referrer, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeVaultReferrer
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn claim_referral_fees(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    lrt_mint: &Pubkey,
+    vault_referrer: &Pubkey,
+    referrer_lrt_token_account: &Pubkey,
+    referrer: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*lrt_mint, false),
+        AccountMeta::new(*vault_referrer, false),
+        AccountMeta::new(*referrer_lrt_token_account, false),
+        AccountMeta::new_readonly(*referrer, true),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ClaimReferralFees.try_to_vec().unwrap(),
+    }
+}
+
+pub fn config_set_paused(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    paused: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ConfigSetPaused { paused }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_set_vault_paused(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    paused: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ConfigSetVaultPaused { paused }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_set_program_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    program_fee_wallet: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*program_fee_wallet, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ConfigSetProgramFee { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_fees(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    fee_admin: &Pubkey,
+    deposit_fee_bps: u16,
+    withdrawal_fee_bps: u16,
+    reward_fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*fee_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetFees {
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn propose_slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    avs_vault_slasher_ticket: &Pubkey,
+    vault_avs_slasher_ticket: &Pubkey,
+    slash_proposal: &Pubkey,
+    payer: &Pubkey,
+    vault_operator_ticket: &Pubkey,
+    vault_avs_ticket: &Pubkey,
+    amount: u64,
+    evidence_hash: [u8; 32],
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, true),
+        AccountMeta::new_readonly(*avs_vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*vault_avs_slasher_ticket, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*vault_operator_ticket, false),
+        AccountMeta::new(*vault_avs_ticket, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ProposeSlash {
+            amount,
+            evidence_hash,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn veto_slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    avs: &Pubkey,
+    slash_proposal: &Pubkey,
+    slash_veto_admin: &Pubkey,
+    vault_avs_ticket: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*slash_veto_admin, true),
+        AccountMeta::new(*vault_avs_ticket, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::VetoSlash.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_slash(
+    program_id: &Pubkey,
+    slash_proposal: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    avs: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    avs_operator_ticket: &Pubkey,
+    operator_avs_ticket: &Pubkey,
+    avs_vault_ticket: &Pubkey,
+    operator_vault_ticket: &Pubkey,
+    vault_avs_ticket: &Pubkey,
+    vault_operator_ticket: &Pubkey,
+    avs_vault_slasher_ticket: &Pubkey,
+    vault_avs_slasher_ticket: &Pubkey,
+    vault_delegation_list: &Pubkey,
+    vault_avs_slasher_operator_ticket: &Pubkey,
+    vault_token_account: &Pubkey,
+    slasher_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*avs, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*avs_operator_ticket, false),
+        AccountMeta::new_readonly(*operator_avs_ticket, false),
+        AccountMeta::new_readonly(*avs_vault_ticket, false),
+        AccountMeta::new_readonly(*operator_vault_ticket, false),
+        AccountMeta::new(*vault_avs_ticket, false),
+        AccountMeta::new_readonly(*vault_operator_ticket, false),
+        AccountMeta::new_readonly(*avs_vault_slasher_ticket, false),
+        AccountMeta::new(*vault_avs_slasher_ticket, false),
+        AccountMeta::new(*vault_delegation_list, false),
+        AccountMeta::new(*vault_avs_slasher_operator_ticket, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*slasher_token_account, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ExecuteSlash.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_crank_tip(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetCrankTip { lamports }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`VaultInstruction::MigrateAccount`]
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
+
+/// Builds [`VaultInstruction::SetMintBurnAuthority`]
+pub fn set_mint_burn_authority(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    mint_burn_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*mint_burn_authority, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetMintBurnAuthority.try_to_vec().unwrap(),
+    }
+}
+
+/// Builds [`VaultInstruction::ClearMintBurnAuthority`]
+pub fn clear_mint_burn_authority(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ClearMintBurnAuthority
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Builds [`VaultInstruction::UpdateVaultBalance`]
+pub fn update_vault_balance(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    lrt_mint: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*vault_token_account, false),
+        AccountMeta::new(*lrt_mint, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::UpdateVaultBalance.try_to_vec().unwrap(),
+    }
+}
+
+/// Builds [`VaultInstruction::ConfigSetParameter`]
+pub fn config_set_parameter(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    parameter: ConfigParameter,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ConfigSetParameter(parameter)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
@@ -0,0 +1,82 @@
+//! The parameters an integrator needs to quote deposits and withdrawals against a vault.
+//!
+//! [`crate::VaultInstruction::GetProtocolParameters`] sets the return data to a Borsh-encoded
+//! [`ProtocolParameters`], so a program can read them with one CPI and `get_return_data` instead
+//! of loading and decoding the config and vault accounts itself. Off-chain, build them from the
+//! accounts with [`ProtocolParameters::new`].
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::pubkey::Pubkey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct ProtocolParameters {
+    /// The length of an epoch in slots
+    pub epoch_length: u64,
+
+    /// The epoch the parameters were read at
+    pub epoch: u64,
+
+    /// The number of epochs a raised withdrawal fee waits before applying
+    pub withdrawal_fee_grace_epochs: u64,
+
+    /// The deposit fee in basis points in effect at `epoch`
+    pub deposit_fee_bps: u16,
+
+    /// The withdrawal fee in basis points in effect at `epoch`
+    pub withdrawal_fee_bps: u16,
+
+    /// A raised withdrawal fee in basis points and the epoch it applies from, if still pending
+    pub pending_withdrawal_fee: Option<(u16, u64)>,
+
+    /// Max capacity of tokens in the vault
+    pub capacity: u64,
+
+    /// The total number of tokens deposited
+    pub tokens_deposited: u64,
+
+    /// The total number of LRT in circulation
+    pub lrt_supply: u64,
+
+    /// Whether the vault is in emergency mode
+    pub emergency_mode: bool,
+
+    /// The signer deposits and withdrawals require, if any
+    pub mint_burn_authority: Option<Pubkey>,
+
+    /// The program fee taken out of the LRT minted on deposits, in basis points
+    pub program_fee_bps: u16,
+
+    /// The reward fee in basis points in effect at `epoch`
+    pub reward_fee_bps: u16,
+
+    /// The deposit and reward fees in basis points scheduled by the fee admin and the epoch they
+    /// apply from, if still pending. A scheduled withdrawal fee is in `pending_withdrawal_fee`.
+    pub pending_fees: Option<(u16, u16, u64)>,
+}
+
+impl ProtocolParameters {
+    /// The parameters of the vault as of `slot`
+    pub fn new(config: &Config, vault: &Vault, slot: u64) -> Self {
+        let epoch = slot.checked_div(config.epoch_length()).unwrap();
+        Self {
+            epoch_length: config.epoch_length(),
+            epoch,
+            withdrawal_fee_grace_epochs: config.withdrawal_fee_grace_epochs(),
+            deposit_fee_bps: vault.deposit_fee_bps_at(epoch),
+            withdrawal_fee_bps: vault.withdrawal_fee_bps_at(epoch),
+            pending_withdrawal_fee: vault
+                .pending_withdrawal_fee()
+                .filter(|(_, effective_epoch)| *effective_epoch > epoch),
+            capacity: vault.capacity(),
+            tokens_deposited: vault.tokens_deposited(),
+            lrt_supply: vault.lrt_supply(),
+            emergency_mode: vault.emergency_mode(),
+            mint_burn_authority: vault.mint_burn_authority(),
+            program_fee_bps: config.program_fee_bps(),
+            reward_fee_bps: vault.reward_fee_bps_at(epoch),
+            pending_fees: vault
+                .pending_fees()
+                .filter(|(_, _, effective_epoch)| *effective_epoch > epoch),
+        }
+    }
+}
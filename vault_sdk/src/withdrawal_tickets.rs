@@ -0,0 +1,224 @@
+//! Helpers for indexers and wallets rendering a staker's pending withdrawals.
+//!
+//! A view of a staker's withdrawals in a vault takes two RPC calls: `getProgramAccounts` on the
+//! vault program with [`staker_withdrawal_ticket_filters`], and `getMultipleAccounts` on the vault
+//! config and the vault. [`pending_withdrawals`] then turns the raw ticket accounts into
+//! [`PendingWithdrawal`]s, using the context slot of the second response as the current slot.
+//!
+//! A withdrawal matures once the withdrawal cooldown of the config has passed, by default at the
+//! first epoch boundary after it was enqueued. Claimable amounts are estimates: they use the vault's current exchange rate, which
+//! moves with rewards and slashing until the ticket is claimed, and the withdrawal fee in effect
+//! at the later of the current epoch and the maturation epoch.
+use borsh::BorshDeserialize;
+use jito_vault_core::{
+    vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket, AccountType,
+};
+use solana_program::pubkey::Pubkey;
+
+/// The offset of the vault in a serialized [`VaultStakerWithdrawalTicket`], after the account type
+pub const WITHDRAWAL_TICKET_VAULT_OFFSET: usize = 1;
+/// The offset of the staker in a serialized [`VaultStakerWithdrawalTicket`]
+pub const WITHDRAWAL_TICKET_STAKER_OFFSET: usize = 33;
+
+/// A `memcmp` filter of a `getProgramAccounts` request: the account data at `offset` shall start
+/// with `bytes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemcmpFilter {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+/// The filters selecting the withdrawal tickets of a staker in a vault
+pub fn staker_withdrawal_ticket_filters(vault: &Pubkey, staker: &Pubkey) -> [MemcmpFilter; 3] {
+    [
+        MemcmpFilter {
+            offset: 0,
+            bytes: vec![AccountType::VaultStakerWithdrawalTicket as u8],
+        },
+        MemcmpFilter {
+            offset: WITHDRAWAL_TICKET_VAULT_OFFSET,
+            bytes: vault.to_bytes().to_vec(),
+        },
+        MemcmpFilter {
+            offset: WITHDRAWAL_TICKET_STAKER_OFFSET,
+            bytes: staker.to_bytes().to_vec(),
+        },
+    ]
+}
+
+/// The epoch a withdrawal enqueued at `slot_unstaked` can be claimed from, given the
+/// [`jito_vault_core::config::Config::withdrawal_cooldown_epochs`]
+pub fn withdrawal_maturation_epoch(
+    slot_unstaked: u64,
+    epoch_length: u64,
+    cooldown_epochs: u64,
+) -> u64 {
+    slot_unstaked
+        .checked_div(epoch_length)
+        .unwrap_or_default()
+        .saturating_add(cooldown_epochs)
+}
+
+/// Estimates the supported tokens a withdrawal of `lrt_amount` claimed at `epoch` pays out.
+///
+/// Uses the vault's current exchange rate, net of the withdrawal fee. Returns None if the amount
+/// exceeds the LRT supply or the calculation overflows.
+pub fn estimate_claimable_amount(vault: &Vault, lrt_amount: u64, epoch: u64) -> Option<u64> {
+    let fee = vault.calculate_withdraw_fee(lrt_amount, epoch).ok()?;
+    let mut vault = *vault;
+    vault.burn_and_withdraw(lrt_amount.checked_sub(fee)?).ok()
+}
+
+/// A staker's outstanding withdrawal, as shown in a pending withdrawals view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingWithdrawal {
+    pub ticket: Pubkey,
+    pub lrt_amount: u64,
+    /// The position in the vault's withdrawal queue, earlier withdrawals are honored first
+    pub sequence: u64,
+    pub maturation_epoch: u64,
+    pub is_matured: bool,
+    /// The estimated supported tokens paid out on claim, None if it can't be computed
+    pub estimated_claimable_amount: Option<u64>,
+}
+
+/// Builds the pending withdrawals of `vault_pubkey` from the accounts of `getProgramAccounts`.
+///
+/// Withdrawals are ordered by their position in the withdrawal queue. Accounts that aren't
+/// withdrawal tickets of the vault are skipped.
+pub fn pending_withdrawals<'a>(
+    vault_pubkey: &Pubkey,
+    vault: &Vault,
+    epoch_length: u64,
+    cooldown_epochs: u64,
+    slot: u64,
+    ticket_accounts: impl IntoIterator<Item = (Pubkey, &'a [u8])>,
+) -> Vec<PendingWithdrawal> {
+    let current_epoch = slot.checked_div(epoch_length).unwrap_or_default();
+    let discriminator = AccountType::VaultStakerWithdrawalTicket as u8;
+    let mut withdrawals: Vec<_> = ticket_accounts
+        .into_iter()
+        .filter(|(_, data)| data.first() == Some(&discriminator))
+        .filter_map(|(key, mut data)| {
+            let ticket = VaultStakerWithdrawalTicket::deserialize(&mut data).ok()?;
+            (ticket.vault() == *vault_pubkey).then_some((key, ticket))
+        })
+        .map(|(ticket_pubkey, ticket)| {
+            let maturation_epoch =
+                withdrawal_maturation_epoch(ticket.slot_unstaked(), epoch_length, cooldown_epochs);
+            let claim_epoch = maturation_epoch.max(current_epoch);
+            PendingWithdrawal {
+                ticket: ticket_pubkey,
+                lrt_amount: ticket.lrt_amount(),
+                sequence: ticket.sequence(),
+                maturation_epoch,
+                is_matured: current_epoch >= maturation_epoch,
+                estimated_claimable_amount: estimate_claimable_amount(
+                    vault,
+                    ticket.lrt_amount(),
+                    claim_epoch,
+                ),
+            }
+        })
+        .collect();
+    withdrawals.sort_by_key(|withdrawal| withdrawal.sequence);
+    withdrawals
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use jito_vault_core::{
+        vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    };
+    use solana_program::pubkey::Pubkey;
+
+    use crate::withdrawal_tickets::{
+        pending_withdrawals, staker_withdrawal_ticket_filters, withdrawal_maturation_epoch,
+    };
+
+    fn ticket_data(
+        vault: Pubkey,
+        staker: Pubkey,
+        lrt_amount: u64,
+        sequence: u64,
+        slot: u64,
+    ) -> Vec<u8> {
+        VaultStakerWithdrawalTicket::new(
+            vault,
+            staker,
+            Pubkey::new_unique(),
+            lrt_amount,
+            sequence,
+            slot,
+            0,
+        )
+        .try_to_vec()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_filters_match_ticket_layout() {
+        let vault = Pubkey::new_unique();
+        let staker = Pubkey::new_unique();
+        let data = ticket_data(vault, staker, 100, 0, 0);
+
+        for filter in staker_withdrawal_ticket_filters(&vault, &staker) {
+            assert_eq!(
+                &data[filter.offset..filter.offset + filter.bytes.len()],
+                filter.bytes.as_slice()
+            );
+        }
+    }
+
+    #[test]
+    fn test_withdrawal_maturation_epoch() {
+        assert_eq!(withdrawal_maturation_epoch(0, 10, 1), 1);
+        assert_eq!(withdrawal_maturation_epoch(9, 10, 1), 1);
+        assert_eq!(withdrawal_maturation_epoch(10, 10, 1), 2);
+        assert_eq!(withdrawal_maturation_epoch(10, 10, 3), 4);
+    }
+
+    #[test]
+    fn test_pending_withdrawals() {
+        let vault_pubkey = Pubkey::new_unique();
+        let staker = Pubkey::new_unique();
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            100,
+            0,
+        );
+        vault.set_lrt_supply(1_000);
+        vault.set_tokens_deposited(900);
+
+        let matured = ticket_data(vault_pubkey, staker, 100, 1, 5);
+        let cooling_down = ticket_data(vault_pubkey, staker, 200, 0, 12);
+        let other_vault = ticket_data(Pubkey::new_unique(), staker, 300, 2, 0);
+        let not_a_ticket = vault.try_to_vec().unwrap();
+        let accounts = [
+            (Pubkey::new_unique(), matured.as_slice()),
+            (Pubkey::new_unique(), cooling_down.as_slice()),
+            (Pubkey::new_unique(), other_vault.as_slice()),
+            (Pubkey::new_unique(), not_a_ticket.as_slice()),
+        ];
+
+        let withdrawals = pending_withdrawals(&vault_pubkey, &vault, 10, 1, 15, accounts);
+        assert_eq!(withdrawals.len(), 2);
+
+        assert_eq!(withdrawals[0].ticket, accounts[1].0);
+        assert_eq!(withdrawals[0].maturation_epoch, 2);
+        assert!(!withdrawals[0].is_matured);
+        // 198 LRT after the 1% fee, at 0.9 tokens per LRT
+        assert_eq!(withdrawals[0].estimated_claimable_amount, Some(178));
+
+        assert_eq!(withdrawals[1].ticket, accounts[0].0);
+        assert_eq!(withdrawals[1].maturation_epoch, 1);
+        assert!(withdrawals[1].is_matured);
+        assert_eq!(withdrawals[1].estimated_claimable_amount, Some(89));
+    }
+}